@@ -0,0 +1,41 @@
+//! Compares the cost of `syscall!`/`raw_syscall!` with `libc::syscall`.
+//!
+//! `getppid` is used because it cannot fail and does almost no work in the
+//! kernel, so the numbers are dominated by the calling sequence.
+
+#[cfg(not(rawsys_backend = "none"))]
+mod getppid {
+    use std::hint::black_box;
+
+    use criterion::{Criterion, criterion_group};
+    use rawsys_linux::{Sysno, raw_syscall, syscall};
+
+    fn getppid(c: &mut Criterion) {
+        let mut group = c.benchmark_group("getppid");
+        group.bench_function("syscall!", |b| {
+            b.iter(|| unsafe {
+                black_box(syscall!(black_box(Sysno::getppid)))
+            });
+        });
+        group.bench_function("raw_syscall!", |b| {
+            b.iter(|| unsafe {
+                black_box(raw_syscall!(black_box(Sysno::getppid)))
+            });
+        });
+        group.bench_function("libc::syscall", |b| {
+            b.iter(|| unsafe {
+                black_box(libc::syscall(black_box(libc::SYS_getppid)))
+            });
+        });
+        group.finish();
+    }
+
+    criterion_group!(benches, getppid);
+}
+
+#[cfg(not(rawsys_backend = "none"))]
+criterion::criterion_main!(getppid::benches);
+
+// Without the syscall functions (`tables-only`), there is nothing to time.
+#[cfg(rawsys_backend = "none")]
+fn main() {}
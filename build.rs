@@ -2,6 +2,9 @@ use std::env;
 
 fn main() {
     println!("cargo:rerun-if-changed=build.rs");
+    println!(
+        "cargo::rustc-check-cfg=cfg(rawsys_backend, values(\"inline\", \"outline\", \"stub\", \"libc\", \"none\"))"
+    );
 
     let kernel_features = [
         "CARGO_FEATURE_DEFAULT_KERNEL_5_4",
@@ -30,16 +33,93 @@ fn main() {
         );
     }
 
-    // Automatically detect if thumb-mode is an available feature by looking at
-    // the prefix of the target. Currently, the thumb-mode target feature is
-    // only set automatically in nightly builds, so we must do the manual
-    // feature detect here.
-    //
-    // "armv7-linux-androideabi" is a special case that has thumb-mode enabled,
-    // but does not start with the "thumb" prefix.
-    if env::var("TARGET")
-        .is_ok_and(|t| t.starts_with("thumb") || t == "armv7-linux-androideabi")
-    {
+    if thumb_mode() {
         println!("cargo:rustc-cfg=feature=\"thumb-mode\"");
     }
+
+    let backend = select_backend();
+    #[cfg(feature = "outline-asm")]
+    if backend == "outline" {
+        println!("cargo:rerun-if-changed=src/syscall/outline.c");
+        cc::Build::new()
+            .file("src/syscall/outline.c")
+            .compile("rawsys_linux_outline");
+    }
+    println!("cargo:rustc-cfg=rawsys_backend=\"{backend}\"");
+}
+
+/// Detects whether the target generates Thumb code, in which case r7 is the
+/// frame pointer and `src/syscall/arm_thumb.rs` must be used.
+///
+/// The `thumb-mode` target feature is only visible to build scripts on
+/// nightly, so the target name is checked as well: `thumb*` targets, and
+/// Android's `armv7-linux-androideabi` (ABI `eabi`), which defaults to Thumb
+/// without saying so in its name.
+fn thumb_mode() -> bool {
+    let var = |name: &str| env::var(name).unwrap_or_default();
+    if var("CARGO_CFG_TARGET_ARCH") != "arm" {
+        return false;
+    }
+    if var("CARGO_CFG_TARGET_FEATURE")
+        .split(',')
+        .any(|feature| feature == "thumb-mode")
+    {
+        return true;
+    }
+
+    let target = var("TARGET");
+    target.starts_with("thumb")
+        || (target.starts_with("armv7")
+            && var("CARGO_CFG_TARGET_OS") == "android"
+            && var("CARGO_CFG_TARGET_ABI") == "eabi")
+}
+
+/// Picks the syscall backend (see `src/syscall/mod.rs`).
+///
+/// `tables-only` (or a non-Linux target) wins over everything else, then
+/// `libc-backend` wins over `stub-section`, which wins over `outline-asm`.
+/// Without any of them, the inline `asm!` backend for the target is used.
+fn select_backend() -> &'static str {
+    let arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap_or_default();
+    let os = env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+    let feature =
+        |name: &str| env::var(format!("CARGO_FEATURE_{name}")).is_ok();
+
+    // Only the tables (`Sysno`, `Errno`, `SysnoSet`, ...) are usable when
+    // there is no Linux kernel to call into.
+    if feature("TABLES_ONLY") || !matches!(os.as_str(), "linux" | "android") {
+        return "none";
+    }
+
+    if feature("LIBC_BACKEND") {
+        return "libc";
+    }
+
+    if feature("STUB_SECTION") {
+        assert!(
+            matches!(arch.as_str(), "x86" | "x86_64" | "aarch64" | "riscv64"),
+            "💥 The stub-section feature is not supported on {arch}."
+        );
+        return "stub";
+    }
+
+    // Inline assembly is unstable on these architectures. With `outline-asm`,
+    // the syscall stub is compiled from C instead and `src/syscall/outline.rs`
+    // is used as the backend.
+    if feature("OUTLINE_ASM")
+        && matches!(
+            arch.as_str(),
+            "mips"
+                | "mips64"
+                | "powerpc"
+                | "powerpc64"
+                | "s390x"
+                | "sparc"
+                | "sparc64"
+        )
+    {
+        return "outline";
+    }
+
+    "inline"
 }
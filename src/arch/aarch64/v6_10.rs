@@ -4,604 +4,1186 @@
 
 syscall_enum! {
     pub enum Sysno {
+        /// Create an asynchronous I/O context.
+        ///
         /// See [io_setup(2)](https://man7.org/linux/man-pages/man2/io_setup.2.html) for more info on this syscall.
         io_setup = 0,
+        /// Destroy an asynchronous I/O context.
+        ///
         /// See [io_destroy(2)](https://man7.org/linux/man-pages/man2/io_destroy.2.html) for more info on this syscall.
         io_destroy = 1,
+        /// Submit asynchronous I/O blocks for processing.
+        ///
         /// See [io_submit(2)](https://man7.org/linux/man-pages/man2/io_submit.2.html) for more info on this syscall.
         io_submit = 2,
+        /// Cancel an outstanding asynchronous I/O operation.
+        ///
         /// See [io_cancel(2)](https://man7.org/linux/man-pages/man2/io_cancel.2.html) for more info on this syscall.
         io_cancel = 3,
+        /// Read asynchronous I/O events from the completion queue.
+        ///
         /// See [io_getevents(2)](https://man7.org/linux/man-pages/man2/io_getevents.2.html) for more info on this syscall.
         io_getevents = 4,
+        /// Set an extended attribute value.
+        ///
         /// See [setxattr(2)](https://man7.org/linux/man-pages/man2/setxattr.2.html) for more info on this syscall.
         setxattr = 5,
+        /// Set an extended attribute value.
+        ///
         /// See [lsetxattr(2)](https://man7.org/linux/man-pages/man2/lsetxattr.2.html) for more info on this syscall.
         lsetxattr = 6,
+        /// Set an extended attribute value.
+        ///
         /// See [fsetxattr(2)](https://man7.org/linux/man-pages/man2/fsetxattr.2.html) for more info on this syscall.
         fsetxattr = 7,
+        /// Retrieve an extended attribute value.
+        ///
         /// See [getxattr(2)](https://man7.org/linux/man-pages/man2/getxattr.2.html) for more info on this syscall.
         getxattr = 8,
+        /// Retrieve an extended attribute value.
+        ///
         /// See [lgetxattr(2)](https://man7.org/linux/man-pages/man2/lgetxattr.2.html) for more info on this syscall.
         lgetxattr = 9,
+        /// Retrieve an extended attribute value.
+        ///
         /// See [fgetxattr(2)](https://man7.org/linux/man-pages/man2/fgetxattr.2.html) for more info on this syscall.
         fgetxattr = 10,
+        /// List extended attribute names.
+        ///
         /// See [listxattr(2)](https://man7.org/linux/man-pages/man2/listxattr.2.html) for more info on this syscall.
         listxattr = 11,
+        /// List extended attribute names.
+        ///
         /// See [llistxattr(2)](https://man7.org/linux/man-pages/man2/llistxattr.2.html) for more info on this syscall.
         llistxattr = 12,
+        /// List extended attribute names.
+        ///
         /// See [flistxattr(2)](https://man7.org/linux/man-pages/man2/flistxattr.2.html) for more info on this syscall.
         flistxattr = 13,
+        /// Remove an extended attribute.
+        ///
         /// See [removexattr(2)](https://man7.org/linux/man-pages/man2/removexattr.2.html) for more info on this syscall.
         removexattr = 14,
+        /// Remove an extended attribute.
+        ///
         /// See [lremovexattr(2)](https://man7.org/linux/man-pages/man2/lremovexattr.2.html) for more info on this syscall.
         lremovexattr = 15,
+        /// Remove an extended attribute.
+        ///
         /// See [fremovexattr(2)](https://man7.org/linux/man-pages/man2/fremovexattr.2.html) for more info on this syscall.
         fremovexattr = 16,
+        /// Get current working directory.
+        ///
         /// See [getcwd(2)](https://man7.org/linux/man-pages/man2/getcwd.2.html) for more info on this syscall.
         getcwd = 17,
+        /// Return a directory entry's path.
+        ///
         /// See [lookup_dcookie(2)](https://man7.org/linux/man-pages/man2/lookup_dcookie.2.html) for more info on this syscall.
         lookup_dcookie = 18,
         /// See [eventfd2(2)](https://man7.org/linux/man-pages/man2/eventfd2.2.html) for more info on this syscall.
         eventfd2 = 19,
+        /// Open an epoll file descriptor.
+        ///
         /// See [epoll_create1(2)](https://man7.org/linux/man-pages/man2/epoll_create1.2.html) for more info on this syscall.
         epoll_create1 = 20,
+        /// Control interface for an epoll file descriptor.
+        ///
         /// See [epoll_ctl(2)](https://man7.org/linux/man-pages/man2/epoll_ctl.2.html) for more info on this syscall.
         epoll_ctl = 21,
+        /// Wait for an I/O event on an epoll file descriptor.
+        ///
         /// See [epoll_pwait(2)](https://man7.org/linux/man-pages/man2/epoll_pwait.2.html) for more info on this syscall.
         epoll_pwait = 22,
+        /// Duplicate a file descriptor.
+        ///
         /// See [dup(2)](https://man7.org/linux/man-pages/man2/dup.2.html) for more info on this syscall.
         dup = 23,
+        /// Duplicate a file descriptor.
+        ///
         /// See [dup3(2)](https://man7.org/linux/man-pages/man2/dup3.2.html) for more info on this syscall.
         dup3 = 24,
+        /// Manipulate file descriptor.
+        ///
         /// See [fcntl(2)](https://man7.org/linux/man-pages/man2/fcntl.2.html) for more info on this syscall.
         fcntl = 25,
+        /// Initialize an inotify instance.
+        ///
         /// See [inotify_init1(2)](https://man7.org/linux/man-pages/man2/inotify_init1.2.html) for more info on this syscall.
         inotify_init1 = 26,
+        /// Add a watch to an initialized inotify instance.
+        ///
         /// See [inotify_add_watch(2)](https://man7.org/linux/man-pages/man2/inotify_add_watch.2.html) for more info on this syscall.
         inotify_add_watch = 27,
+        /// Remove an existing watch from an inotify instance.
+        ///
         /// See [inotify_rm_watch(2)](https://man7.org/linux/man-pages/man2/inotify_rm_watch.2.html) for more info on this syscall.
         inotify_rm_watch = 28,
+        /// Control device.
+        ///
         /// See [ioctl(2)](https://man7.org/linux/man-pages/man2/ioctl.2.html) for more info on this syscall.
         ioctl = 29,
+        /// Get/set I/O scheduling class and priority.
+        ///
         /// See [ioprio_set(2)](https://man7.org/linux/man-pages/man2/ioprio_set.2.html) for more info on this syscall.
         ioprio_set = 30,
+        /// Get/set I/O scheduling class and priority.
+        ///
         /// See [ioprio_get(2)](https://man7.org/linux/man-pages/man2/ioprio_get.2.html) for more info on this syscall.
         ioprio_get = 31,
+        /// Apply or remove an advisory lock on an open file.
+        ///
         /// See [flock(2)](https://man7.org/linux/man-pages/man2/flock.2.html) for more info on this syscall.
         flock = 32,
+        /// Create a special or ordinary file.
+        ///
         /// See [mknodat(2)](https://man7.org/linux/man-pages/man2/mknodat.2.html) for more info on this syscall.
         mknodat = 33,
+        /// Create a directory.
+        ///
         /// See [mkdirat(2)](https://man7.org/linux/man-pages/man2/mkdirat.2.html) for more info on this syscall.
         mkdirat = 34,
+        /// Delete a name and possibly the file it refers to.
+        ///
         /// See [unlinkat(2)](https://man7.org/linux/man-pages/man2/unlinkat.2.html) for more info on this syscall.
         unlinkat = 35,
+        /// Make a new name for a file.
+        ///
         /// See [symlinkat(2)](https://man7.org/linux/man-pages/man2/symlinkat.2.html) for more info on this syscall.
         symlinkat = 36,
+        /// Make a new name for a file.
+        ///
         /// See [linkat(2)](https://man7.org/linux/man-pages/man2/linkat.2.html) for more info on this syscall.
         linkat = 37,
+        /// Change the name or location of a file.
+        ///
         /// See [renameat(2)](https://man7.org/linux/man-pages/man2/renameat.2.html) for more info on this syscall.
         renameat = 38,
+        /// Unmount filesystem.
+        ///
         /// See [umount2(2)](https://man7.org/linux/man-pages/man2/umount2.2.html) for more info on this syscall.
         umount2 = 39,
+        /// Mount filesystem.
+        ///
         /// See [mount(2)](https://man7.org/linux/man-pages/man2/mount.2.html) for more info on this syscall.
         mount = 40,
+        /// Change the root mount.
+        ///
         /// See [pivot_root(2)](https://man7.org/linux/man-pages/man2/pivot_root.2.html) for more info on this syscall.
         pivot_root = 41,
+        /// Syscall interface to kernel nfs daemon.
+        ///
         /// See [nfsservctl(2)](https://man7.org/linux/man-pages/man2/nfsservctl.2.html) for more info on this syscall.
         nfsservctl = 42,
+        /// Get filesystem statistics.
+        ///
         /// See [statfs(2)](https://man7.org/linux/man-pages/man2/statfs.2.html) for more info on this syscall.
         statfs = 43,
+        /// Get filesystem statistics.
+        ///
         /// See [fstatfs(2)](https://man7.org/linux/man-pages/man2/fstatfs.2.html) for more info on this syscall.
         fstatfs = 44,
+        /// Truncate a file to a specified length.
+        ///
         /// See [truncate(2)](https://man7.org/linux/man-pages/man2/truncate.2.html) for more info on this syscall.
         truncate = 45,
+        /// Truncate a file to a specified length.
+        ///
         /// See [ftruncate(2)](https://man7.org/linux/man-pages/man2/ftruncate.2.html) for more info on this syscall.
         ftruncate = 46,
+        /// Manipulate file space.
+        ///
         /// See [fallocate(2)](https://man7.org/linux/man-pages/man2/fallocate.2.html) for more info on this syscall.
         fallocate = 47,
+        /// Check user's permissions for a file.
+        ///
         /// See [faccessat(2)](https://man7.org/linux/man-pages/man2/faccessat.2.html) for more info on this syscall.
         faccessat = 48,
+        /// Change working directory.
+        ///
         /// See [chdir(2)](https://man7.org/linux/man-pages/man2/chdir.2.html) for more info on this syscall.
         chdir = 49,
+        /// Change working directory.
+        ///
         /// See [fchdir(2)](https://man7.org/linux/man-pages/man2/fchdir.2.html) for more info on this syscall.
         fchdir = 50,
+        /// Change root directory.
+        ///
         /// See [chroot(2)](https://man7.org/linux/man-pages/man2/chroot.2.html) for more info on this syscall.
         chroot = 51,
+        /// Change permissions of a file.
+        ///
         /// See [fchmod(2)](https://man7.org/linux/man-pages/man2/fchmod.2.html) for more info on this syscall.
         fchmod = 52,
+        /// Change permissions of a file.
+        ///
         /// See [fchmodat(2)](https://man7.org/linux/man-pages/man2/fchmodat.2.html) for more info on this syscall.
         fchmodat = 53,
+        /// Change ownership of a file.
+        ///
         /// See [fchownat(2)](https://man7.org/linux/man-pages/man2/fchownat.2.html) for more info on this syscall.
         fchownat = 54,
+        /// Change ownership of a file.
+        ///
         /// See [fchown(2)](https://man7.org/linux/man-pages/man2/fchown.2.html) for more info on this syscall.
         fchown = 55,
+        /// Open and possibly create a file.
+        ///
         /// See [openat(2)](https://man7.org/linux/man-pages/man2/openat.2.html) for more info on this syscall.
         openat = 56,
+        /// Close a file descriptor.
+        ///
         /// See [close(2)](https://man7.org/linux/man-pages/man2/close.2.html) for more info on this syscall.
         close = 57,
+        /// Virtually hangup the current terminal.
+        ///
         /// See [vhangup(2)](https://man7.org/linux/man-pages/man2/vhangup.2.html) for more info on this syscall.
         vhangup = 58,
+        /// Create pipe.
+        ///
         /// See [pipe2(2)](https://man7.org/linux/man-pages/man2/pipe2.2.html) for more info on this syscall.
         pipe2 = 59,
+        /// Manipulate disk quotas.
+        ///
         /// See [quotactl(2)](https://man7.org/linux/man-pages/man2/quotactl.2.html) for more info on this syscall.
         quotactl = 60,
+        /// Get directory entries.
+        ///
         /// See [getdents64(2)](https://man7.org/linux/man-pages/man2/getdents64.2.html) for more info on this syscall.
         getdents64 = 61,
+        /// Reposition read/write file offset.
+        ///
         /// See [lseek(2)](https://man7.org/linux/man-pages/man2/lseek.2.html) for more info on this syscall.
         lseek = 62,
+        /// Read from a file descriptor.
+        ///
         /// See [read(2)](https://man7.org/linux/man-pages/man2/read.2.html) for more info on this syscall.
         read = 63,
+        /// Write to a file descriptor.
+        ///
         /// See [write(2)](https://man7.org/linux/man-pages/man2/write.2.html) for more info on this syscall.
         write = 64,
+        /// Read or write data into multiple buffers.
+        ///
         /// See [readv(2)](https://man7.org/linux/man-pages/man2/readv.2.html) for more info on this syscall.
         readv = 65,
+        /// Read or write data into multiple buffers.
+        ///
         /// See [writev(2)](https://man7.org/linux/man-pages/man2/writev.2.html) for more info on this syscall.
         writev = 66,
+        /// Read from or write to a file descriptor at a given offset.
+        ///
         /// See [pread64(2)](https://man7.org/linux/man-pages/man2/pread64.2.html) for more info on this syscall.
         pread64 = 67,
+        /// Read from or write to a file descriptor at a given offset.
+        ///
         /// See [pwrite64(2)](https://man7.org/linux/man-pages/man2/pwrite64.2.html) for more info on this syscall.
         pwrite64 = 68,
+        /// Read or write data into multiple buffers.
+        ///
         /// See [preadv(2)](https://man7.org/linux/man-pages/man2/preadv.2.html) for more info on this syscall.
         preadv = 69,
+        /// Read or write data into multiple buffers.
+        ///
         /// See [pwritev(2)](https://man7.org/linux/man-pages/man2/pwritev.2.html) for more info on this syscall.
         pwritev = 70,
+        /// Transfer data between file descriptors.
+        ///
         /// See [sendfile(2)](https://man7.org/linux/man-pages/man2/sendfile.2.html) for more info on this syscall.
         sendfile = 71,
         /// See [pselect6(2)](https://man7.org/linux/man-pages/man2/pselect6.2.html) for more info on this syscall.
         pselect6 = 72,
+        /// Wait for some event on a file descriptor.
+        ///
         /// See [ppoll(2)](https://man7.org/linux/man-pages/man2/ppoll.2.html) for more info on this syscall.
         ppoll = 73,
         /// See [signalfd4(2)](https://man7.org/linux/man-pages/man2/signalfd4.2.html) for more info on this syscall.
         signalfd4 = 74,
+        /// Splice user pages to/from a pipe.
+        ///
         /// See [vmsplice(2)](https://man7.org/linux/man-pages/man2/vmsplice.2.html) for more info on this syscall.
         vmsplice = 75,
+        /// Splice data to/from a pipe.
+        ///
         /// See [splice(2)](https://man7.org/linux/man-pages/man2/splice.2.html) for more info on this syscall.
         splice = 76,
+        /// Duplicating pipe content.
+        ///
         /// See [tee(2)](https://man7.org/linux/man-pages/man2/tee.2.html) for more info on this syscall.
         tee = 77,
+        /// Read value of a symbolic link.
+        ///
         /// See [readlinkat(2)](https://man7.org/linux/man-pages/man2/readlinkat.2.html) for more info on this syscall.
         readlinkat = 78,
+        /// Get file status.
+        ///
         /// See [fstatat(2)](https://man7.org/linux/man-pages/man2/fstatat.2.html) for more info on this syscall.
         fstatat = 79,
+        /// Get file status.
+        ///
         /// See [fstat(2)](https://man7.org/linux/man-pages/man2/fstat.2.html) for more info on this syscall.
         fstat = 80,
+        /// Commit filesystem caches to disk.
+        ///
         /// See [sync(2)](https://man7.org/linux/man-pages/man2/sync.2.html) for more info on this syscall.
         sync = 81,
+        /// Synchronize a file's in-core state with storage device.
+        ///
         /// See [fsync(2)](https://man7.org/linux/man-pages/man2/fsync.2.html) for more info on this syscall.
         fsync = 82,
+        /// Synchronize a file's in-core state with storage device.
+        ///
         /// See [fdatasync(2)](https://man7.org/linux/man-pages/man2/fdatasync.2.html) for more info on this syscall.
         fdatasync = 83,
+        /// Sync a file segment with disk.
+        ///
         /// See [sync_file_range(2)](https://man7.org/linux/man-pages/man2/sync_file_range.2.html) for more info on this syscall.
         sync_file_range = 84,
+        /// Timers that notify via file descriptors.
+        ///
         /// See [timerfd_create(2)](https://man7.org/linux/man-pages/man2/timerfd_create.2.html) for more info on this syscall.
         timerfd_create = 85,
+        /// Timers that notify via file descriptors.
+        ///
         /// See [timerfd_settime(2)](https://man7.org/linux/man-pages/man2/timerfd_settime.2.html) for more info on this syscall.
         timerfd_settime = 86,
+        /// Timers that notify via file descriptors.
+        ///
         /// See [timerfd_gettime(2)](https://man7.org/linux/man-pages/man2/timerfd_gettime.2.html) for more info on this syscall.
         timerfd_gettime = 87,
+        /// Change file timestamps with nanosecond precision.
+        ///
         /// See [utimensat(2)](https://man7.org/linux/man-pages/man2/utimensat.2.html) for more info on this syscall.
         utimensat = 88,
+        /// Switch process accounting on or off.
+        ///
         /// See [acct(2)](https://man7.org/linux/man-pages/man2/acct.2.html) for more info on this syscall.
         acct = 89,
+        /// Set/get capabilities of thread(s).
+        ///
         /// See [capget(2)](https://man7.org/linux/man-pages/man2/capget.2.html) for more info on this syscall.
         capget = 90,
+        /// Set/get capabilities of thread(s).
+        ///
         /// See [capset(2)](https://man7.org/linux/man-pages/man2/capset.2.html) for more info on this syscall.
         capset = 91,
+        /// Set the process execution domain.
+        ///
         /// See [personality(2)](https://man7.org/linux/man-pages/man2/personality.2.html) for more info on this syscall.
         personality = 92,
         /// See [exit(2)](https://man7.org/linux/man-pages/man2/exit.2.html) for more info on this syscall.
         exit = 93,
+        /// Exit all threads in a process.
+        ///
         /// See [exit_group(2)](https://man7.org/linux/man-pages/man2/exit_group.2.html) for more info on this syscall.
         exit_group = 94,
+        /// Wait for process to change state.
+        ///
         /// See [waitid(2)](https://man7.org/linux/man-pages/man2/waitid.2.html) for more info on this syscall.
         waitid = 95,
+        /// Set pointer to thread ID.
+        ///
         /// See [set_tid_address(2)](https://man7.org/linux/man-pages/man2/set_tid_address.2.html) for more info on this syscall.
         set_tid_address = 96,
+        /// Disassociate parts of the process execution context.
+        ///
         /// See [unshare(2)](https://man7.org/linux/man-pages/man2/unshare.2.html) for more info on this syscall.
         unshare = 97,
+        /// Fast user-space locking.
+        ///
         /// See [futex(2)](https://man7.org/linux/man-pages/man2/futex.2.html) for more info on this syscall.
         futex = 98,
+        /// Get/set list of robust futexes.
+        ///
         /// See [set_robust_list(2)](https://man7.org/linux/man-pages/man2/set_robust_list.2.html) for more info on this syscall.
         set_robust_list = 99,
+        /// Get/set list of robust futexes.
+        ///
         /// See [get_robust_list(2)](https://man7.org/linux/man-pages/man2/get_robust_list.2.html) for more info on this syscall.
         get_robust_list = 100,
+        /// High-resolution sleep.
+        ///
         /// See [nanosleep(2)](https://man7.org/linux/man-pages/man2/nanosleep.2.html) for more info on this syscall.
         nanosleep = 101,
+        /// Get or set value of an interval timer.
+        ///
         /// See [getitimer(2)](https://man7.org/linux/man-pages/man2/getitimer.2.html) for more info on this syscall.
         getitimer = 102,
+        /// Get or set value of an interval timer.
+        ///
         /// See [setitimer(2)](https://man7.org/linux/man-pages/man2/setitimer.2.html) for more info on this syscall.
         setitimer = 103,
+        /// Load a new kernel for later execution.
+        ///
         /// See [kexec_load(2)](https://man7.org/linux/man-pages/man2/kexec_load.2.html) for more info on this syscall.
         kexec_load = 104,
+        /// Load a kernel module.
+        ///
         /// See [init_module(2)](https://man7.org/linux/man-pages/man2/init_module.2.html) for more info on this syscall.
         init_module = 105,
+        /// Unload a kernel module.
+        ///
         /// See [delete_module(2)](https://man7.org/linux/man-pages/man2/delete_module.2.html) for more info on this syscall.
         delete_module = 106,
+        /// Create a POSIX per-process timer.
+        ///
         /// See [timer_create(2)](https://man7.org/linux/man-pages/man2/timer_create.2.html) for more info on this syscall.
         timer_create = 107,
+        /// Arm/disarm and fetch state of POSIX per-process timer.
+        ///
         /// See [timer_gettime(2)](https://man7.org/linux/man-pages/man2/timer_gettime.2.html) for more info on this syscall.
         timer_gettime = 108,
+        /// Get overrun count for a POSIX per-process timer.
+        ///
         /// See [timer_getoverrun(2)](https://man7.org/linux/man-pages/man2/timer_getoverrun.2.html) for more info on this syscall.
         timer_getoverrun = 109,
+        /// Arm/disarm and fetch state of POSIX per-process timer.
+        ///
         /// See [timer_settime(2)](https://man7.org/linux/man-pages/man2/timer_settime.2.html) for more info on this syscall.
         timer_settime = 110,
+        /// Delete a POSIX per-process timer.
+        ///
         /// See [timer_delete(2)](https://man7.org/linux/man-pages/man2/timer_delete.2.html) for more info on this syscall.
         timer_delete = 111,
+        /// Clock and time functions.
+        ///
         /// See [clock_settime(2)](https://man7.org/linux/man-pages/man2/clock_settime.2.html) for more info on this syscall.
         clock_settime = 112,
+        /// Clock and time functions.
+        ///
         /// See [clock_gettime(2)](https://man7.org/linux/man-pages/man2/clock_gettime.2.html) for more info on this syscall.
         clock_gettime = 113,
+        /// Clock and time functions.
+        ///
         /// See [clock_getres(2)](https://man7.org/linux/man-pages/man2/clock_getres.2.html) for more info on this syscall.
         clock_getres = 114,
+        /// High-resolution sleep with specifiable clock.
+        ///
         /// See [clock_nanosleep(2)](https://man7.org/linux/man-pages/man2/clock_nanosleep.2.html) for more info on this syscall.
         clock_nanosleep = 115,
+        /// Read and/or clear kernel message ring buffer; set `console_loglevel`.
+        ///
         /// See [syslog(2)](https://man7.org/linux/man-pages/man2/syslog.2.html) for more info on this syscall.
         syslog = 116,
+        /// Process trace.
+        ///
         /// See [ptrace(2)](https://man7.org/linux/man-pages/man2/ptrace.2.html) for more info on this syscall.
         ptrace = 117,
+        /// Set and get scheduling parameters.
+        ///
         /// See [sched_setparam(2)](https://man7.org/linux/man-pages/man2/sched_setparam.2.html) for more info on this syscall.
         sched_setparam = 118,
+        /// Set and get scheduling policy/parameters.
+        ///
         /// See [sched_setscheduler(2)](https://man7.org/linux/man-pages/man2/sched_setscheduler.2.html) for more info on this syscall.
         sched_setscheduler = 119,
+        /// Set and get scheduling policy/parameters.
+        ///
         /// See [sched_getscheduler(2)](https://man7.org/linux/man-pages/man2/sched_getscheduler.2.html) for more info on this syscall.
         sched_getscheduler = 120,
+        /// Set and get scheduling parameters.
+        ///
         /// See [sched_getparam(2)](https://man7.org/linux/man-pages/man2/sched_getparam.2.html) for more info on this syscall.
         sched_getparam = 121,
+        /// Set and get a thread's CPU affinity mask.
+        ///
         /// See [sched_setaffinity(2)](https://man7.org/linux/man-pages/man2/sched_setaffinity.2.html) for more info on this syscall.
         sched_setaffinity = 122,
+        /// Set and get a thread's CPU affinity mask.
+        ///
         /// See [sched_getaffinity(2)](https://man7.org/linux/man-pages/man2/sched_getaffinity.2.html) for more info on this syscall.
         sched_getaffinity = 123,
+        /// Yield the processor.
+        ///
         /// See [sched_yield(2)](https://man7.org/linux/man-pages/man2/sched_yield.2.html) for more info on this syscall.
         sched_yield = 124,
+        /// Get static priority range.
+        ///
         /// See [sched_get_priority_max(2)](https://man7.org/linux/man-pages/man2/sched_get_priority_max.2.html) for more info on this syscall.
         sched_get_priority_max = 125,
+        /// Get static priority range.
+        ///
         /// See [sched_get_priority_min(2)](https://man7.org/linux/man-pages/man2/sched_get_priority_min.2.html) for more info on this syscall.
         sched_get_priority_min = 126,
+        /// Get the `SCHED_RR` interval for the named process.
+        ///
         /// See [sched_rr_get_interval(2)](https://man7.org/linux/man-pages/man2/sched_rr_get_interval.2.html) for more info on this syscall.
         sched_rr_get_interval = 127,
+        /// Restart a system call after interruption by a stop signal.
+        ///
         /// See [restart_syscall(2)](https://man7.org/linux/man-pages/man2/restart_syscall.2.html) for more info on this syscall.
         restart_syscall = 128,
+        /// Send signal to a process.
+        ///
         /// See [kill(2)](https://man7.org/linux/man-pages/man2/kill.2.html) for more info on this syscall.
         kill = 129,
+        /// Send a signal to a thread.
+        ///
         /// See [tkill(2)](https://man7.org/linux/man-pages/man2/tkill.2.html) for more info on this syscall.
         tkill = 130,
+        /// Send a signal to a thread.
+        ///
         /// See [tgkill(2)](https://man7.org/linux/man-pages/man2/tgkill.2.html) for more info on this syscall.
         tgkill = 131,
+        /// Set and/or get signal stack context.
+        ///
         /// See [sigaltstack(2)](https://man7.org/linux/man-pages/man2/sigaltstack.2.html) for more info on this syscall.
         sigaltstack = 132,
+        /// Wait for a signal.
+        ///
         /// See [rt_sigsuspend(2)](https://man7.org/linux/man-pages/man2/rt_sigsuspend.2.html) for more info on this syscall.
         rt_sigsuspend = 133,
+        /// Examine and change a signal action.
+        ///
         /// See [rt_sigaction(2)](https://man7.org/linux/man-pages/man2/rt_sigaction.2.html) for more info on this syscall.
         rt_sigaction = 134,
+        /// Examine and change blocked signals.
+        ///
         /// See [rt_sigprocmask(2)](https://man7.org/linux/man-pages/man2/rt_sigprocmask.2.html) for more info on this syscall.
         rt_sigprocmask = 135,
+        /// Examine pending signals.
+        ///
         /// See [rt_sigpending(2)](https://man7.org/linux/man-pages/man2/rt_sigpending.2.html) for more info on this syscall.
         rt_sigpending = 136,
+        /// Synchronously wait for queued signals.
+        ///
         /// See [rt_sigtimedwait(2)](https://man7.org/linux/man-pages/man2/rt_sigtimedwait.2.html) for more info on this syscall.
         rt_sigtimedwait = 137,
+        /// Queue a signal and data.
+        ///
         /// See [rt_sigqueueinfo(2)](https://man7.org/linux/man-pages/man2/rt_sigqueueinfo.2.html) for more info on this syscall.
         rt_sigqueueinfo = 138,
+        /// Return from signal handler and cleanup stack frame.
+        ///
         /// See [rt_sigreturn(2)](https://man7.org/linux/man-pages/man2/rt_sigreturn.2.html) for more info on this syscall.
         rt_sigreturn = 139,
+        /// Get/set program scheduling priority.
+        ///
         /// See [setpriority(2)](https://man7.org/linux/man-pages/man2/setpriority.2.html) for more info on this syscall.
         setpriority = 140,
+        /// Get/set program scheduling priority.
+        ///
         /// See [getpriority(2)](https://man7.org/linux/man-pages/man2/getpriority.2.html) for more info on this syscall.
         getpriority = 141,
+        /// Reboot or enable/disable Ctrl-Alt-Del.
+        ///
         /// See [reboot(2)](https://man7.org/linux/man-pages/man2/reboot.2.html) for more info on this syscall.
         reboot = 142,
+        /// Set real and/or effective user or group ID.
+        ///
         /// See [setregid(2)](https://man7.org/linux/man-pages/man2/setregid.2.html) for more info on this syscall.
         setregid = 143,
+        /// Set group identity.
+        ///
         /// See [setgid(2)](https://man7.org/linux/man-pages/man2/setgid.2.html) for more info on this syscall.
         setgid = 144,
+        /// Set real and/or effective user or group ID.
+        ///
         /// See [setreuid(2)](https://man7.org/linux/man-pages/man2/setreuid.2.html) for more info on this syscall.
         setreuid = 145,
+        /// Set user identity.
+        ///
         /// See [setuid(2)](https://man7.org/linux/man-pages/man2/setuid.2.html) for more info on this syscall.
         setuid = 146,
+        /// Set real, effective, and saved user or group ID.
+        ///
         /// See [setresuid(2)](https://man7.org/linux/man-pages/man2/setresuid.2.html) for more info on this syscall.
         setresuid = 147,
+        /// Get real, effective, and saved user/group IDs.
+        ///
         /// See [getresuid(2)](https://man7.org/linux/man-pages/man2/getresuid.2.html) for more info on this syscall.
         getresuid = 148,
+        /// Set real, effective, and saved user or group ID.
+        ///
         /// See [setresgid(2)](https://man7.org/linux/man-pages/man2/setresgid.2.html) for more info on this syscall.
         setresgid = 149,
+        /// Get real, effective, and saved user/group IDs.
+        ///
         /// See [getresgid(2)](https://man7.org/linux/man-pages/man2/getresgid.2.html) for more info on this syscall.
         getresgid = 150,
+        /// Set user identity used for filesystem checks.
+        ///
         /// See [setfsuid(2)](https://man7.org/linux/man-pages/man2/setfsuid.2.html) for more info on this syscall.
         setfsuid = 151,
+        /// Set group identity used for filesystem checks.
+        ///
         /// See [setfsgid(2)](https://man7.org/linux/man-pages/man2/setfsgid.2.html) for more info on this syscall.
         setfsgid = 152,
+        /// Get process times.
+        ///
         /// See [times(2)](https://man7.org/linux/man-pages/man2/times.2.html) for more info on this syscall.
         times = 153,
+        /// Set/get process group.
+        ///
         /// See [setpgid(2)](https://man7.org/linux/man-pages/man2/setpgid.2.html) for more info on this syscall.
         setpgid = 154,
+        /// Set/get process group.
+        ///
         /// See [getpgid(2)](https://man7.org/linux/man-pages/man2/getpgid.2.html) for more info on this syscall.
         getpgid = 155,
+        /// Get session ID.
+        ///
         /// See [getsid(2)](https://man7.org/linux/man-pages/man2/getsid.2.html) for more info on this syscall.
         getsid = 156,
+        /// Creates a session and sets the process group ID.
+        ///
         /// See [setsid(2)](https://man7.org/linux/man-pages/man2/setsid.2.html) for more info on this syscall.
         setsid = 157,
+        /// Get/set list of supplementary group IDs.
+        ///
         /// See [getgroups(2)](https://man7.org/linux/man-pages/man2/getgroups.2.html) for more info on this syscall.
         getgroups = 158,
+        /// Get/set list of supplementary group IDs.
+        ///
         /// See [setgroups(2)](https://man7.org/linux/man-pages/man2/setgroups.2.html) for more info on this syscall.
         setgroups = 159,
+        /// Get name and information about current kernel.
+        ///
         /// See [uname(2)](https://man7.org/linux/man-pages/man2/uname.2.html) for more info on this syscall.
         uname = 160,
+        /// Get/set hostname.
+        ///
         /// See [sethostname(2)](https://man7.org/linux/man-pages/man2/sethostname.2.html) for more info on this syscall.
         sethostname = 161,
+        /// Get/set NIS domain name.
+        ///
         /// See [setdomainname(2)](https://man7.org/linux/man-pages/man2/setdomainname.2.html) for more info on this syscall.
         setdomainname = 162,
+        /// Get/set resource limits.
+        ///
         /// See [getrlimit(2)](https://man7.org/linux/man-pages/man2/getrlimit.2.html) for more info on this syscall.
         getrlimit = 163,
+        /// Get/set resource limits.
+        ///
         /// See [setrlimit(2)](https://man7.org/linux/man-pages/man2/setrlimit.2.html) for more info on this syscall.
         setrlimit = 164,
+        /// Get resource usage.
+        ///
         /// See [getrusage(2)](https://man7.org/linux/man-pages/man2/getrusage.2.html) for more info on this syscall.
         getrusage = 165,
+        /// Set file mode creation mask.
+        ///
         /// See [umask(2)](https://man7.org/linux/man-pages/man2/umask.2.html) for more info on this syscall.
         umask = 166,
+        /// Operations on a process or thread.
+        ///
         /// See [prctl(2)](https://man7.org/linux/man-pages/man2/prctl.2.html) for more info on this syscall.
         prctl = 167,
+        /// Determine CPU and NUMA node on which the calling thread is running.
+        ///
         /// See [getcpu(2)](https://man7.org/linux/man-pages/man2/getcpu.2.html) for more info on this syscall.
         getcpu = 168,
+        /// Get / set time.
+        ///
         /// See [gettimeofday(2)](https://man7.org/linux/man-pages/man2/gettimeofday.2.html) for more info on this syscall.
         gettimeofday = 169,
+        /// Get / set time.
+        ///
         /// See [settimeofday(2)](https://man7.org/linux/man-pages/man2/settimeofday.2.html) for more info on this syscall.
         settimeofday = 170,
+        /// Tune kernel clock.
+        ///
         /// See [adjtimex(2)](https://man7.org/linux/man-pages/man2/adjtimex.2.html) for more info on this syscall.
         adjtimex = 171,
+        /// Get process identification.
+        ///
         /// See [getpid(2)](https://man7.org/linux/man-pages/man2/getpid.2.html) for more info on this syscall.
         getpid = 172,
+        /// Get process identification.
+        ///
         /// See [getppid(2)](https://man7.org/linux/man-pages/man2/getppid.2.html) for more info on this syscall.
         getppid = 173,
+        /// Get user identity.
+        ///
         /// See [getuid(2)](https://man7.org/linux/man-pages/man2/getuid.2.html) for more info on this syscall.
         getuid = 174,
+        /// Get user identity.
+        ///
         /// See [geteuid(2)](https://man7.org/linux/man-pages/man2/geteuid.2.html) for more info on this syscall.
         geteuid = 175,
+        /// Get group identity.
+        ///
         /// See [getgid(2)](https://man7.org/linux/man-pages/man2/getgid.2.html) for more info on this syscall.
         getgid = 176,
+        /// Get group identity.
+        ///
         /// See [getegid(2)](https://man7.org/linux/man-pages/man2/getegid.2.html) for more info on this syscall.
         getegid = 177,
+        /// Get thread identification.
+        ///
         /// See [gettid(2)](https://man7.org/linux/man-pages/man2/gettid.2.html) for more info on this syscall.
         gettid = 178,
+        /// Return system information.
+        ///
         /// See [sysinfo(2)](https://man7.org/linux/man-pages/man2/sysinfo.2.html) for more info on this syscall.
         sysinfo = 179,
+        /// Open a message queue.
+        ///
         /// See [mq_open(2)](https://man7.org/linux/man-pages/man2/mq_open.2.html) for more info on this syscall.
         mq_open = 180,
+        /// Remove a message queue.
+        ///
         /// See [mq_unlink(2)](https://man7.org/linux/man-pages/man2/mq_unlink.2.html) for more info on this syscall.
         mq_unlink = 181,
+        /// Send a message to a message queue.
+        ///
         /// See [mq_timedsend(2)](https://man7.org/linux/man-pages/man2/mq_timedsend.2.html) for more info on this syscall.
         mq_timedsend = 182,
+        /// Receive a message from a message queue.
+        ///
         /// See [mq_timedreceive(2)](https://man7.org/linux/man-pages/man2/mq_timedreceive.2.html) for more info on this syscall.
         mq_timedreceive = 183,
+        /// Register for notification when a message is available.
+        ///
         /// See [mq_notify(2)](https://man7.org/linux/man-pages/man2/mq_notify.2.html) for more info on this syscall.
         mq_notify = 184,
+        /// Get/set message queue attributes.
+        ///
         /// See [mq_getsetattr(2)](https://man7.org/linux/man-pages/man2/mq_getsetattr.2.html) for more info on this syscall.
         mq_getsetattr = 185,
+        /// Get a System V message queue identifier.
+        ///
         /// See [msgget(2)](https://man7.org/linux/man-pages/man2/msgget.2.html) for more info on this syscall.
         msgget = 186,
+        /// System V message control operations.
+        ///
         /// See [msgctl(2)](https://man7.org/linux/man-pages/man2/msgctl.2.html) for more info on this syscall.
         msgctl = 187,
+        /// System V message queue operations.
+        ///
         /// See [msgrcv(2)](https://man7.org/linux/man-pages/man2/msgrcv.2.html) for more info on this syscall.
         msgrcv = 188,
+        /// System V message queue operations.
+        ///
         /// See [msgsnd(2)](https://man7.org/linux/man-pages/man2/msgsnd.2.html) for more info on this syscall.
         msgsnd = 189,
+        /// Get a System V semaphore set identifier.
+        ///
         /// See [semget(2)](https://man7.org/linux/man-pages/man2/semget.2.html) for more info on this syscall.
         semget = 190,
+        /// System V semaphore control operations.
+        ///
         /// See [semctl(2)](https://man7.org/linux/man-pages/man2/semctl.2.html) for more info on this syscall.
         semctl = 191,
+        /// System V semaphore operations.
+        ///
         /// See [semtimedop(2)](https://man7.org/linux/man-pages/man2/semtimedop.2.html) for more info on this syscall.
         semtimedop = 192,
+        /// System V semaphore operations.
+        ///
         /// See [semop(2)](https://man7.org/linux/man-pages/man2/semop.2.html) for more info on this syscall.
         semop = 193,
+        /// Allocates a System V shared memory segment.
+        ///
         /// See [shmget(2)](https://man7.org/linux/man-pages/man2/shmget.2.html) for more info on this syscall.
         shmget = 194,
+        /// System V shared memory control.
+        ///
         /// See [shmctl(2)](https://man7.org/linux/man-pages/man2/shmctl.2.html) for more info on this syscall.
         shmctl = 195,
+        /// System V shared memory operations.
+        ///
         /// See [shmat(2)](https://man7.org/linux/man-pages/man2/shmat.2.html) for more info on this syscall.
         shmat = 196,
+        /// System V shared memory operations.
+        ///
         /// See [shmdt(2)](https://man7.org/linux/man-pages/man2/shmdt.2.html) for more info on this syscall.
         shmdt = 197,
+        /// Create an endpoint for communication.
+        ///
         /// See [socket(2)](https://man7.org/linux/man-pages/man2/socket.2.html) for more info on this syscall.
         socket = 198,
+        /// Create a pair of connected sockets.
+        ///
         /// See [socketpair(2)](https://man7.org/linux/man-pages/man2/socketpair.2.html) for more info on this syscall.
         socketpair = 199,
+        /// Bind a name to a socket.
+        ///
         /// See [bind(2)](https://man7.org/linux/man-pages/man2/bind.2.html) for more info on this syscall.
         bind = 200,
+        /// Listen for connections on a socket.
+        ///
         /// See [listen(2)](https://man7.org/linux/man-pages/man2/listen.2.html) for more info on this syscall.
         listen = 201,
+        /// Accept a connection on a socket.
+        ///
         /// See [accept(2)](https://man7.org/linux/man-pages/man2/accept.2.html) for more info on this syscall.
         accept = 202,
+        /// Initiate a connection on a socket.
+        ///
         /// See [connect(2)](https://man7.org/linux/man-pages/man2/connect.2.html) for more info on this syscall.
         connect = 203,
+        /// Get socket name.
+        ///
         /// See [getsockname(2)](https://man7.org/linux/man-pages/man2/getsockname.2.html) for more info on this syscall.
         getsockname = 204,
+        /// Get name of connected peer socket.
+        ///
         /// See [getpeername(2)](https://man7.org/linux/man-pages/man2/getpeername.2.html) for more info on this syscall.
         getpeername = 205,
+        /// Send a message on a socket.
+        ///
         /// See [sendto(2)](https://man7.org/linux/man-pages/man2/sendto.2.html) for more info on this syscall.
         sendto = 206,
+        /// Receive a message from a socket.
+        ///
         /// See [recvfrom(2)](https://man7.org/linux/man-pages/man2/recvfrom.2.html) for more info on this syscall.
         recvfrom = 207,
+        /// Get and set options on sockets.
+        ///
         /// See [setsockopt(2)](https://man7.org/linux/man-pages/man2/setsockopt.2.html) for more info on this syscall.
         setsockopt = 208,
+        /// Get and set options on sockets.
+        ///
         /// See [getsockopt(2)](https://man7.org/linux/man-pages/man2/getsockopt.2.html) for more info on this syscall.
         getsockopt = 209,
+        /// Shut down part of a full-duplex connection.
+        ///
         /// See [shutdown(2)](https://man7.org/linux/man-pages/man2/shutdown.2.html) for more info on this syscall.
         shutdown = 210,
+        /// Send a message on a socket.
+        ///
         /// See [sendmsg(2)](https://man7.org/linux/man-pages/man2/sendmsg.2.html) for more info on this syscall.
         sendmsg = 211,
+        /// Receive a message from a socket.
+        ///
         /// See [recvmsg(2)](https://man7.org/linux/man-pages/man2/recvmsg.2.html) for more info on this syscall.
         recvmsg = 212,
+        /// Initiate file readahead into page cache.
+        ///
         /// See [readahead(2)](https://man7.org/linux/man-pages/man2/readahead.2.html) for more info on this syscall.
         readahead = 213,
+        /// Change data segment size.
+        ///
         /// See [brk(2)](https://man7.org/linux/man-pages/man2/brk.2.html) for more info on this syscall.
         brk = 214,
+        /// Map or unmap files or devices into memory.
+        ///
         /// See [munmap(2)](https://man7.org/linux/man-pages/man2/munmap.2.html) for more info on this syscall.
         munmap = 215,
+        /// Remap a virtual memory address.
+        ///
         /// See [mremap(2)](https://man7.org/linux/man-pages/man2/mremap.2.html) for more info on this syscall.
         mremap = 216,
+        /// Add a key to the kernel's key management facility.
+        ///
         /// See [add_key(2)](https://man7.org/linux/man-pages/man2/add_key.2.html) for more info on this syscall.
         add_key = 217,
+        /// Request a key from the kernel's key management facility.
+        ///
         /// See [request_key(2)](https://man7.org/linux/man-pages/man2/request_key.2.html) for more info on this syscall.
         request_key = 218,
+        /// Manipulate the kernel's key management facility.
+        ///
         /// See [keyctl(2)](https://man7.org/linux/man-pages/man2/keyctl.2.html) for more info on this syscall.
         keyctl = 219,
+        /// Create a child process.
+        ///
         /// See [clone(2)](https://man7.org/linux/man-pages/man2/clone.2.html) for more info on this syscall.
         clone = 220,
+        /// Execute program.
+        ///
         /// See [execve(2)](https://man7.org/linux/man-pages/man2/execve.2.html) for more info on this syscall.
         execve = 221,
+        /// Map or unmap files or devices into memory.
+        ///
         /// See [mmap(2)](https://man7.org/linux/man-pages/man2/mmap.2.html) for more info on this syscall.
         mmap = 222,
         /// See [fadvise64(2)](https://man7.org/linux/man-pages/man2/fadvise64.2.html) for more info on this syscall.
         fadvise64 = 223,
+        /// Start/stop swapping to file/device.
+        ///
         /// See [swapon(2)](https://man7.org/linux/man-pages/man2/swapon.2.html) for more info on this syscall.
         swapon = 224,
+        /// Start/stop swapping to file/device.
+        ///
         /// See [swapoff(2)](https://man7.org/linux/man-pages/man2/swapoff.2.html) for more info on this syscall.
         swapoff = 225,
+        /// Set protection on a region of memory.
+        ///
         /// See [mprotect(2)](https://man7.org/linux/man-pages/man2/mprotect.2.html) for more info on this syscall.
         mprotect = 226,
+        /// Synchronize a file with a memory map.
+        ///
         /// See [msync(2)](https://man7.org/linux/man-pages/man2/msync.2.html) for more info on this syscall.
         msync = 227,
+        /// Lock and unlock memory.
+        ///
         /// See [mlock(2)](https://man7.org/linux/man-pages/man2/mlock.2.html) for more info on this syscall.
         mlock = 228,
+        /// Lock and unlock memory.
+        ///
         /// See [munlock(2)](https://man7.org/linux/man-pages/man2/munlock.2.html) for more info on this syscall.
         munlock = 229,
+        /// Lock and unlock memory.
+        ///
         /// See [mlockall(2)](https://man7.org/linux/man-pages/man2/mlockall.2.html) for more info on this syscall.
         mlockall = 230,
+        /// Lock and unlock memory.
+        ///
         /// See [munlockall(2)](https://man7.org/linux/man-pages/man2/munlockall.2.html) for more info on this syscall.
         munlockall = 231,
+        /// Determine whether pages are resident in memory.
+        ///
         /// See [mincore(2)](https://man7.org/linux/man-pages/man2/mincore.2.html) for more info on this syscall.
         mincore = 232,
+        /// Give advice about use of memory.
+        ///
         /// See [madvise(2)](https://man7.org/linux/man-pages/man2/madvise.2.html) for more info on this syscall.
         madvise = 233,
+        /// Create a nonlinear file mapping.
+        ///
         /// See [remap_file_pages(2)](https://man7.org/linux/man-pages/man2/remap_file_pages.2.html) for more info on this syscall.
         remap_file_pages = 234,
+        /// Set memory policy for a memory range.
+        ///
         /// See [mbind(2)](https://man7.org/linux/man-pages/man2/mbind.2.html) for more info on this syscall.
         mbind = 235,
+        /// Retrieve NUMA memory policy for a thread.
+        ///
         /// See [get_mempolicy(2)](https://man7.org/linux/man-pages/man2/get_mempolicy.2.html) for more info on this syscall.
         get_mempolicy = 236,
+        /// Set default NUMA memory policy for a thread and its children.
+        ///
         /// See [set_mempolicy(2)](https://man7.org/linux/man-pages/man2/set_mempolicy.2.html) for more info on this syscall.
         set_mempolicy = 237,
+        /// Move all pages in a process to another set of nodes.
+        ///
         /// See [migrate_pages(2)](https://man7.org/linux/man-pages/man2/migrate_pages.2.html) for more info on this syscall.
         migrate_pages = 238,
+        /// Move individual pages of a process to another node.
+        ///
         /// See [move_pages(2)](https://man7.org/linux/man-pages/man2/move_pages.2.html) for more info on this syscall.
         move_pages = 239,
+        /// Queue a signal and data.
+        ///
         /// See [rt_tgsigqueueinfo(2)](https://man7.org/linux/man-pages/man2/rt_tgsigqueueinfo.2.html) for more info on this syscall.
         rt_tgsigqueueinfo = 240,
+        /// Set up performance monitoring.
+        ///
         /// See [perf_event_open(2)](https://man7.org/linux/man-pages/man2/perf_event_open.2.html) for more info on this syscall.
         perf_event_open = 241,
+        /// Accept a connection on a socket.
+        ///
         /// See [accept4(2)](https://man7.org/linux/man-pages/man2/accept4.2.html) for more info on this syscall.
         accept4 = 242,
+        /// Receive multiple messages on a socket.
+        ///
         /// See [recvmmsg(2)](https://man7.org/linux/man-pages/man2/recvmmsg.2.html) for more info on this syscall.
         recvmmsg = 243,
+        /// Wait for process to change state, BSD style.
+        ///
         /// See [wait4(2)](https://man7.org/linux/man-pages/man2/wait4.2.html) for more info on this syscall.
         wait4 = 260,
+        /// Get/set resource limits.
+        ///
         /// See [prlimit64(2)](https://man7.org/linux/man-pages/man2/prlimit64.2.html) for more info on this syscall.
         prlimit64 = 261,
+        /// Create and initialize fanotify group.
+        ///
         /// See [fanotify_init(2)](https://man7.org/linux/man-pages/man2/fanotify_init.2.html) for more info on this syscall.
         fanotify_init = 262,
+        /// Add, remove, or modify an fanotify mark on a filesystem object.
+        ///
         /// See [fanotify_mark(2)](https://man7.org/linux/man-pages/man2/fanotify_mark.2.html) for more info on this syscall.
         fanotify_mark = 263,
+        /// Obtain handle for a pathname and open file via a handle.
+        ///
         /// See [name_to_handle_at(2)](https://man7.org/linux/man-pages/man2/name_to_handle_at.2.html) for more info on this syscall.
         name_to_handle_at = 264,
+        /// Obtain handle for a pathname and open file via a handle.
+        ///
         /// See [open_by_handle_at(2)](https://man7.org/linux/man-pages/man2/open_by_handle_at.2.html) for more info on this syscall.
         open_by_handle_at = 265,
+        /// Tune kernel clock.
+        ///
         /// See [clock_adjtime(2)](https://man7.org/linux/man-pages/man2/clock_adjtime.2.html) for more info on this syscall.
         clock_adjtime = 266,
+        /// Commit filesystem caches to disk.
+        ///
         /// See [syncfs(2)](https://man7.org/linux/man-pages/man2/syncfs.2.html) for more info on this syscall.
         syncfs = 267,
+        /// Reassociate thread with a namespace.
+        ///
         /// See [setns(2)](https://man7.org/linux/man-pages/man2/setns.2.html) for more info on this syscall.
         setns = 268,
+        /// Send multiple messages on a socket.
+        ///
         /// See [sendmmsg(2)](https://man7.org/linux/man-pages/man2/sendmmsg.2.html) for more info on this syscall.
         sendmmsg = 269,
+        /// Transfer data between process address spaces.
+        ///
         /// See [process_vm_readv(2)](https://man7.org/linux/man-pages/man2/process_vm_readv.2.html) for more info on this syscall.
         process_vm_readv = 270,
+        /// Transfer data between process address spaces.
+        ///
         /// See [process_vm_writev(2)](https://man7.org/linux/man-pages/man2/process_vm_writev.2.html) for more info on this syscall.
         process_vm_writev = 271,
+        /// Compare two processes to determine if they share a kernel resource.
+        ///
         /// See [kcmp(2)](https://man7.org/linux/man-pages/man2/kcmp.2.html) for more info on this syscall.
         kcmp = 272,
+        /// Load a kernel module.
+        ///
         /// See [finit_module(2)](https://man7.org/linux/man-pages/man2/finit_module.2.html) for more info on this syscall.
         finit_module = 273,
+        /// Set and get scheduling policy and attributes.
+        ///
         /// See [sched_setattr(2)](https://man7.org/linux/man-pages/man2/sched_setattr.2.html) for more info on this syscall.
         sched_setattr = 274,
+        /// Set and get scheduling policy and attributes.
+        ///
         /// See [sched_getattr(2)](https://man7.org/linux/man-pages/man2/sched_getattr.2.html) for more info on this syscall.
         sched_getattr = 275,
+        /// Change the name or location of a file.
+        ///
         /// See [renameat2(2)](https://man7.org/linux/man-pages/man2/renameat2.2.html) for more info on this syscall.
         renameat2 = 276,
+        /// Operate on Secure Computing state of the process.
+        ///
         /// See [seccomp(2)](https://man7.org/linux/man-pages/man2/seccomp.2.html) for more info on this syscall.
         seccomp = 277,
+        /// Obtain a series of random bytes.
+        ///
         /// See [getrandom(2)](https://man7.org/linux/man-pages/man2/getrandom.2.html) for more info on this syscall.
         getrandom = 278,
+        /// Create an anonymous file.
+        ///
         /// See [memfd_create(2)](https://man7.org/linux/man-pages/man2/memfd_create.2.html) for more info on this syscall.
         memfd_create = 279,
+        /// Perform a command on an extended BPF map or program.
+        ///
         /// See [bpf(2)](https://man7.org/linux/man-pages/man2/bpf.2.html) for more info on this syscall.
         bpf = 280,
+        /// Execute program relative to a directory file descriptor.
+        ///
         /// See [execveat(2)](https://man7.org/linux/man-pages/man2/execveat.2.html) for more info on this syscall.
         execveat = 281,
+        /// Create a file descriptor for handling page faults in user space.
+        ///
         /// See [userfaultfd(2)](https://man7.org/linux/man-pages/man2/userfaultfd.2.html) for more info on this syscall.
         userfaultfd = 282,
+        /// Issue memory barriers on a set of threads.
+        ///
         /// See [membarrier(2)](https://man7.org/linux/man-pages/man2/membarrier.2.html) for more info on this syscall.
         membarrier = 283,
+        /// Lock and unlock memory.
+        ///
         /// See [mlock2(2)](https://man7.org/linux/man-pages/man2/mlock2.2.html) for more info on this syscall.
         mlock2 = 284,
+        /// Copy a range of data from one file to another.
+        ///
         /// See [copy_file_range(2)](https://man7.org/linux/man-pages/man2/copy_file_range.2.html) for more info on this syscall.
         copy_file_range = 285,
+        /// Read or write data into multiple buffers.
+        ///
         /// See [preadv2(2)](https://man7.org/linux/man-pages/man2/preadv2.2.html) for more info on this syscall.
         preadv2 = 286,
+        /// Read or write data into multiple buffers.
+        ///
         /// See [pwritev2(2)](https://man7.org/linux/man-pages/man2/pwritev2.2.html) for more info on this syscall.
         pwritev2 = 287,
+        /// Set protection on a region of memory.
+        ///
         /// See [pkey_mprotect(2)](https://man7.org/linux/man-pages/man2/pkey_mprotect.2.html) for more info on this syscall.
         pkey_mprotect = 288,
+        /// Allocate or free a protection key.
+        ///
         /// See [pkey_alloc(2)](https://man7.org/linux/man-pages/man2/pkey_alloc.2.html) for more info on this syscall.
         pkey_alloc = 289,
+        /// Allocate or free a protection key.
+        ///
         /// See [pkey_free(2)](https://man7.org/linux/man-pages/man2/pkey_free.2.html) for more info on this syscall.
         pkey_free = 290,
+        /// Get file status (extended).
+        ///
         /// See [statx(2)](https://man7.org/linux/man-pages/man2/statx.2.html) for more info on this syscall.
         statx = 291,
         /// See [io_pgetevents(2)](https://man7.org/linux/man-pages/man2/io_pgetevents.2.html) for more info on this syscall.
         io_pgetevents = 292,
         /// See [rseq(2)](https://man7.org/linux/man-pages/man2/rseq.2.html) for more info on this syscall.
         rseq = 293,
+        /// Load a new kernel for later execution.
+        ///
         /// See [kexec_file_load(2)](https://man7.org/linux/man-pages/man2/kexec_file_load.2.html) for more info on this syscall.
         kexec_file_load = 294,
+        /// Clock and time functions.
+        ///
         /// See [clock_gettime64(2)](https://man7.org/linux/man-pages/man2/clock_gettime64.2.html) for more info on this syscall.
         clock_gettime64 = 403,
+        /// Clock and time functions.
+        ///
         /// See [clock_settime64(2)](https://man7.org/linux/man-pages/man2/clock_settime64.2.html) for more info on this syscall.
         clock_settime64 = 404,
+        /// Tune kernel clock.
+        ///
         /// See [clock_adjtime64(2)](https://man7.org/linux/man-pages/man2/clock_adjtime64.2.html) for more info on this syscall.
         clock_adjtime64 = 405,
+        /// Clock and time functions.
+        ///
         /// See [clock_getres_time64(2)](https://man7.org/linux/man-pages/man2/clock_getres_time64.2.html) for more info on this syscall.
         clock_getres_time64 = 406,
+        /// High-resolution sleep with specifiable clock.
+        ///
         /// See [clock_nanosleep_time64(2)](https://man7.org/linux/man-pages/man2/clock_nanosleep_time64.2.html) for more info on this syscall.
         clock_nanosleep_time64 = 407,
+        /// Arm/disarm and fetch state of POSIX per-process timer.
+        ///
         /// See [timer_gettime64(2)](https://man7.org/linux/man-pages/man2/timer_gettime64.2.html) for more info on this syscall.
         timer_gettime64 = 408,
+        /// Arm/disarm and fetch state of POSIX per-process timer.
+        ///
         /// See [timer_settime64(2)](https://man7.org/linux/man-pages/man2/timer_settime64.2.html) for more info on this syscall.
         timer_settime64 = 409,
+        /// Timers that notify via file descriptors.
+        ///
         /// See [timerfd_gettime64(2)](https://man7.org/linux/man-pages/man2/timerfd_gettime64.2.html) for more info on this syscall.
         timerfd_gettime64 = 410,
+        /// Timers that notify via file descriptors.
+        ///
         /// See [timerfd_settime64(2)](https://man7.org/linux/man-pages/man2/timerfd_settime64.2.html) for more info on this syscall.
         timerfd_settime64 = 411,
+        /// Change file timestamps with nanosecond precision.
+        ///
         /// See [utimensat_time64(2)](https://man7.org/linux/man-pages/man2/utimensat_time64.2.html) for more info on this syscall.
         utimensat_time64 = 412,
         /// See [pselect6_time64(2)](https://man7.org/linux/man-pages/man2/pselect6_time64.2.html) for more info on this syscall.
         pselect6_time64 = 413,
+        /// Wait for some event on a file descriptor.
+        ///
         /// See [ppoll_time64(2)](https://man7.org/linux/man-pages/man2/ppoll_time64.2.html) for more info on this syscall.
         ppoll_time64 = 414,
         /// See [io_pgetevents_time64(2)](https://man7.org/linux/man-pages/man2/io_pgetevents_time64.2.html) for more info on this syscall.
         io_pgetevents_time64 = 416,
+        /// Receive multiple messages on a socket.
+        ///
         /// See [recvmmsg_time64(2)](https://man7.org/linux/man-pages/man2/recvmmsg_time64.2.html) for more info on this syscall.
         recvmmsg_time64 = 417,
+        /// Send a message to a message queue.
+        ///
         /// See [mq_timedsend_time64(2)](https://man7.org/linux/man-pages/man2/mq_timedsend_time64.2.html) for more info on this syscall.
         mq_timedsend_time64 = 418,
+        /// Receive a message from a message queue.
+        ///
         /// See [mq_timedreceive_time64(2)](https://man7.org/linux/man-pages/man2/mq_timedreceive_time64.2.html) for more info on this syscall.
         mq_timedreceive_time64 = 419,
+        /// System V semaphore operations.
+        ///
         /// See [semtimedop_time64(2)](https://man7.org/linux/man-pages/man2/semtimedop_time64.2.html) for more info on this syscall.
         semtimedop_time64 = 420,
+        /// Synchronously wait for queued signals.
+        ///
         /// See [rt_sigtimedwait_time64(2)](https://man7.org/linux/man-pages/man2/rt_sigtimedwait_time64.2.html) for more info on this syscall.
         rt_sigtimedwait_time64 = 421,
+        /// Fast user-space locking.
+        ///
         /// See [futex_time64(2)](https://man7.org/linux/man-pages/man2/futex_time64.2.html) for more info on this syscall.
         futex_time64 = 422,
+        /// Get the `SCHED_RR` interval for the named process.
+        ///
         /// See [sched_rr_get_interval_time64(2)](https://man7.org/linux/man-pages/man2/sched_rr_get_interval_time64.2.html) for more info on this syscall.
         sched_rr_get_interval_time64 = 423,
+        /// Send a signal to a process specified by a file descriptor.
+        ///
         /// See [pidfd_send_signal(2)](https://man7.org/linux/man-pages/man2/pidfd_send_signal.2.html) for more info on this syscall.
         pidfd_send_signal = 424,
         /// See [io_uring_setup(2)](https://man7.org/linux/man-pages/man2/io_uring_setup.2.html) for more info on this syscall.
@@ -622,32 +1204,58 @@ syscall_enum! {
         fsmount = 432,
         /// See [fspick(2)](https://man7.org/linux/man-pages/man2/fspick.2.html) for more info on this syscall.
         fspick = 433,
+        /// Obtain a file descriptor that refers to a process.
+        ///
         /// See [pidfd_open(2)](https://man7.org/linux/man-pages/man2/pidfd_open.2.html) for more info on this syscall.
         pidfd_open = 434,
+        /// Create a child process.
+        ///
         /// See [clone3(2)](https://man7.org/linux/man-pages/man2/clone3.2.html) for more info on this syscall.
         clone3 = 435,
+        /// Close all file descriptors in a given range.
+        ///
         /// See [close_range(2)](https://man7.org/linux/man-pages/man2/close_range.2.html) for more info on this syscall.
         close_range = 436,
+        /// Open and possibly create a file (extended).
+        ///
         /// See [openat2(2)](https://man7.org/linux/man-pages/man2/openat2.2.html) for more info on this syscall.
         openat2 = 437,
+        /// Obtain a duplicate of another process's file descriptor.
+        ///
         /// See [pidfd_getfd(2)](https://man7.org/linux/man-pages/man2/pidfd_getfd.2.html) for more info on this syscall.
         pidfd_getfd = 438,
+        /// Check user's permissions for a file.
+        ///
         /// See [faccessat2(2)](https://man7.org/linux/man-pages/man2/faccessat2.2.html) for more info on this syscall.
         faccessat2 = 439,
+        /// Give advice about use of memory to a process.
+        ///
         /// See [process_madvise(2)](https://man7.org/linux/man-pages/man2/process_madvise.2.html) for more info on this syscall.
         process_madvise = 440,
+        /// Wait for an I/O event on an epoll file descriptor.
+        ///
         /// See [epoll_pwait2(2)](https://man7.org/linux/man-pages/man2/epoll_pwait2.2.html) for more info on this syscall.
         epoll_pwait2 = 441,
+        /// Change properties of a mount or mount tree.
+        ///
         /// See [mount_setattr(2)](https://man7.org/linux/man-pages/man2/mount_setattr.2.html) for more info on this syscall.
         mount_setattr = 442,
         /// See [quotactl_fd(2)](https://man7.org/linux/man-pages/man2/quotactl_fd.2.html) for more info on this syscall.
         quotactl_fd = 443,
+        /// Create a new Landlock ruleset.
+        ///
         /// See [landlock_create_ruleset(2)](https://man7.org/linux/man-pages/man2/landlock_create_ruleset.2.html) for more info on this syscall.
         landlock_create_ruleset = 444,
+        /// Add a new Landlock rule to a ruleset.
+        ///
         /// See [landlock_add_rule(2)](https://man7.org/linux/man-pages/man2/landlock_add_rule.2.html) for more info on this syscall.
         landlock_add_rule = 445,
+        /// Enforce a Landlock ruleset.
+        ///
         /// See [landlock_restrict_self(2)](https://man7.org/linux/man-pages/man2/landlock_restrict_self.2.html) for more info on this syscall.
         landlock_restrict_self = 446,
+        /// Create an anonymous RAM-based file to access secret memory regions.
+        ///
         /// See [memfd_secret(2)](https://man7.org/linux/man-pages/man2/memfd_secret.2.html) for more info on this syscall.
         memfd_secret = 447,
         /// See [process_mrelease(2)](https://man7.org/linux/man-pages/man2/process_mrelease.2.html) for more info on this syscall.
@@ -682,4 +1290,2717 @@ syscall_enum! {
         mseal = 462,
     }
     LAST: mseal;
+    SIGNATURES: signatures;
+}
+
+/// Syscall signatures from the kernel's `SYSCALL_DEFINEn` definitions,
+/// indexed by `id - Sysno::first().id()`.
+pub(crate) mod signatures {
+    /// Number of arguments, or `None` for gaps in the table and syscalls
+    /// without a known definition.
+    pub(crate) static ARG_COUNTS: [Option<u8>; 463] = [
+        Some(2), // io_setup
+        Some(1), // io_destroy
+        Some(3), // io_submit
+        Some(3), // io_cancel
+        Some(5), // io_getevents
+        Some(5), // setxattr
+        Some(5), // lsetxattr
+        Some(5), // fsetxattr
+        Some(4), // getxattr
+        Some(4), // lgetxattr
+        Some(4), // fgetxattr
+        Some(3), // listxattr
+        Some(3), // llistxattr
+        Some(3), // flistxattr
+        Some(2), // removexattr
+        Some(2), // lremovexattr
+        Some(2), // fremovexattr
+        Some(2), // getcwd
+        None,    // lookup_dcookie
+        Some(2), // eventfd2
+        Some(1), // epoll_create1
+        Some(4), // epoll_ctl
+        Some(6), // epoll_pwait
+        Some(1), // dup
+        Some(3), // dup3
+        Some(3), // fcntl
+        Some(1), // inotify_init1
+        Some(3), // inotify_add_watch
+        Some(2), // inotify_rm_watch
+        Some(3), // ioctl
+        Some(3), // ioprio_set
+        Some(2), // ioprio_get
+        Some(2), // flock
+        Some(4), // mknodat
+        Some(3), // mkdirat
+        Some(3), // unlinkat
+        Some(3), // symlinkat
+        Some(5), // linkat
+        Some(4), // renameat
+        Some(2), // umount2
+        Some(5), // mount
+        Some(2), // pivot_root
+        None,    // nfsservctl
+        Some(2), // statfs
+        Some(2), // fstatfs
+        Some(2), // truncate
+        Some(2), // ftruncate
+        Some(4), // fallocate
+        Some(3), // faccessat
+        Some(1), // chdir
+        Some(1), // fchdir
+        Some(1), // chroot
+        Some(2), // fchmod
+        Some(3), // fchmodat
+        Some(5), // fchownat
+        Some(3), // fchown
+        Some(4), // openat
+        Some(1), // close
+        Some(0), // vhangup
+        Some(2), // pipe2
+        Some(4), // quotactl
+        Some(3), // getdents64
+        Some(3), // lseek
+        Some(3), // read
+        Some(3), // write
+        Some(3), // readv
+        Some(3), // writev
+        Some(4), // pread64
+        Some(4), // pwrite64
+        Some(5), // preadv
+        Some(5), // pwritev
+        Some(4), // sendfile
+        Some(6), // pselect6
+        Some(5), // ppoll
+        Some(4), // signalfd4
+        Some(4), // vmsplice
+        Some(6), // splice
+        Some(4), // tee
+        Some(4), // readlinkat
+        Some(4), // fstatat
+        Some(2), // fstat
+        Some(0), // sync
+        Some(1), // fsync
+        Some(1), // fdatasync
+        Some(4), // sync_file_range
+        Some(2), // timerfd_create
+        Some(4), // timerfd_settime
+        Some(2), // timerfd_gettime
+        Some(4), // utimensat
+        Some(1), // acct
+        Some(2), // capget
+        Some(2), // capset
+        Some(1), // personality
+        Some(1), // exit
+        Some(1), // exit_group
+        Some(5), // waitid
+        Some(1), // set_tid_address
+        Some(1), // unshare
+        Some(6), // futex
+        Some(2), // set_robust_list
+        Some(3), // get_robust_list
+        Some(2), // nanosleep
+        Some(2), // getitimer
+        Some(3), // setitimer
+        Some(4), // kexec_load
+        Some(3), // init_module
+        Some(2), // delete_module
+        Some(3), // timer_create
+        Some(2), // timer_gettime
+        Some(1), // timer_getoverrun
+        Some(4), // timer_settime
+        Some(1), // timer_delete
+        Some(2), // clock_settime
+        Some(2), // clock_gettime
+        Some(2), // clock_getres
+        Some(4), // clock_nanosleep
+        Some(3), // syslog
+        Some(4), // ptrace
+        Some(2), // sched_setparam
+        Some(3), // sched_setscheduler
+        Some(1), // sched_getscheduler
+        Some(2), // sched_getparam
+        Some(3), // sched_setaffinity
+        Some(3), // sched_getaffinity
+        Some(0), // sched_yield
+        Some(1), // sched_get_priority_max
+        Some(1), // sched_get_priority_min
+        Some(2), // sched_rr_get_interval
+        Some(0), // restart_syscall
+        Some(2), // kill
+        Some(2), // tkill
+        Some(3), // tgkill
+        Some(2), // sigaltstack
+        Some(2), // rt_sigsuspend
+        Some(4), // rt_sigaction
+        Some(4), // rt_sigprocmask
+        Some(2), // rt_sigpending
+        Some(4), // rt_sigtimedwait
+        Some(3), // rt_sigqueueinfo
+        Some(0), // rt_sigreturn
+        Some(3), // setpriority
+        Some(2), // getpriority
+        Some(4), // reboot
+        Some(2), // setregid
+        Some(1), // setgid
+        Some(2), // setreuid
+        Some(1), // setuid
+        Some(3), // setresuid
+        Some(3), // getresuid
+        Some(3), // setresgid
+        Some(3), // getresgid
+        Some(1), // setfsuid
+        Some(1), // setfsgid
+        Some(1), // times
+        Some(2), // setpgid
+        Some(1), // getpgid
+        Some(1), // getsid
+        Some(0), // setsid
+        Some(2), // getgroups
+        Some(2), // setgroups
+        Some(1), // uname
+        Some(2), // sethostname
+        Some(2), // setdomainname
+        Some(2), // getrlimit
+        Some(2), // setrlimit
+        Some(2), // getrusage
+        Some(1), // umask
+        Some(5), // prctl
+        Some(3), // getcpu
+        Some(2), // gettimeofday
+        Some(2), // settimeofday
+        Some(1), // adjtimex
+        Some(0), // getpid
+        Some(0), // getppid
+        Some(0), // getuid
+        Some(0), // geteuid
+        Some(0), // getgid
+        Some(0), // getegid
+        Some(0), // gettid
+        Some(1), // sysinfo
+        Some(4), // mq_open
+        Some(1), // mq_unlink
+        Some(5), // mq_timedsend
+        Some(5), // mq_timedreceive
+        Some(2), // mq_notify
+        Some(3), // mq_getsetattr
+        Some(2), // msgget
+        Some(3), // msgctl
+        Some(5), // msgrcv
+        Some(4), // msgsnd
+        Some(3), // semget
+        Some(4), // semctl
+        Some(4), // semtimedop
+        Some(3), // semop
+        Some(3), // shmget
+        Some(3), // shmctl
+        Some(3), // shmat
+        Some(1), // shmdt
+        Some(3), // socket
+        Some(4), // socketpair
+        Some(3), // bind
+        Some(2), // listen
+        Some(3), // accept
+        Some(3), // connect
+        Some(3), // getsockname
+        Some(3), // getpeername
+        Some(6), // sendto
+        Some(6), // recvfrom
+        Some(5), // setsockopt
+        Some(5), // getsockopt
+        Some(2), // shutdown
+        Some(3), // sendmsg
+        Some(3), // recvmsg
+        Some(3), // readahead
+        Some(1), // brk
+        Some(2), // munmap
+        Some(5), // mremap
+        Some(5), // add_key
+        Some(4), // request_key
+        Some(5), // keyctl
+        Some(5), // clone
+        Some(3), // execve
+        Some(6), // mmap
+        Some(4), // fadvise64
+        Some(2), // swapon
+        Some(1), // swapoff
+        Some(3), // mprotect
+        Some(3), // msync
+        Some(2), // mlock
+        Some(2), // munlock
+        Some(1), // mlockall
+        Some(0), // munlockall
+        Some(3), // mincore
+        Some(3), // madvise
+        Some(5), // remap_file_pages
+        Some(6), // mbind
+        Some(5), // get_mempolicy
+        Some(3), // set_mempolicy
+        Some(4), // migrate_pages
+        Some(6), // move_pages
+        Some(4), // rt_tgsigqueueinfo
+        Some(5), // perf_event_open
+        Some(4), // accept4
+        Some(5), // recvmmsg
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(4), // wait4
+        Some(4), // prlimit64
+        Some(2), // fanotify_init
+        Some(5), // fanotify_mark
+        Some(5), // name_to_handle_at
+        Some(3), // open_by_handle_at
+        Some(2), // clock_adjtime
+        Some(1), // syncfs
+        Some(2), // setns
+        Some(4), // sendmmsg
+        Some(6), // process_vm_readv
+        Some(6), // process_vm_writev
+        Some(5), // kcmp
+        Some(3), // finit_module
+        Some(3), // sched_setattr
+        Some(4), // sched_getattr
+        Some(5), // renameat2
+        Some(3), // seccomp
+        Some(3), // getrandom
+        Some(2), // memfd_create
+        Some(3), // bpf
+        Some(5), // execveat
+        Some(1), // userfaultfd
+        Some(3), // membarrier
+        Some(3), // mlock2
+        Some(6), // copy_file_range
+        Some(6), // preadv2
+        Some(6), // pwritev2
+        Some(4), // pkey_mprotect
+        Some(2), // pkey_alloc
+        Some(1), // pkey_free
+        Some(5), // statx
+        Some(6), // io_pgetevents
+        Some(4), // rseq
+        Some(5), // kexec_file_load
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(2), // clock_gettime64
+        Some(2), // clock_settime64
+        Some(2), // clock_adjtime64
+        Some(2), // clock_getres_time64
+        Some(4), // clock_nanosleep_time64
+        Some(2), // timer_gettime64
+        Some(4), // timer_settime64
+        Some(2), // timerfd_gettime64
+        Some(4), // timerfd_settime64
+        Some(4), // utimensat_time64
+        Some(6), // pselect6_time64
+        Some(5), // ppoll_time64
+        None,
+        Some(6), // io_pgetevents_time64
+        Some(5), // recvmmsg_time64
+        Some(5), // mq_timedsend_time64
+        Some(5), // mq_timedreceive_time64
+        Some(4), // semtimedop_time64
+        Some(4), // rt_sigtimedwait_time64
+        Some(6), // futex_time64
+        Some(2), // sched_rr_get_interval_time64
+        Some(4), // pidfd_send_signal
+        Some(2), // io_uring_setup
+        Some(6), // io_uring_enter
+        Some(4), // io_uring_register
+        Some(3), // open_tree
+        Some(5), // move_mount
+        Some(2), // fsopen
+        Some(5), // fsconfig
+        Some(3), // fsmount
+        Some(3), // fspick
+        Some(2), // pidfd_open
+        Some(2), // clone3
+        Some(3), // close_range
+        Some(4), // openat2
+        Some(3), // pidfd_getfd
+        Some(4), // faccessat2
+        Some(5), // process_madvise
+        Some(6), // epoll_pwait2
+        Some(5), // mount_setattr
+        Some(4), // quotactl_fd
+        Some(3), // landlock_create_ruleset
+        Some(4), // landlock_add_rule
+        Some(2), // landlock_restrict_self
+        Some(1), // memfd_secret
+        Some(2), // process_mrelease
+        Some(5), // futex_waitv
+        Some(4), // set_mempolicy_home_node
+        Some(4), // cachestat
+        Some(4), // fchmodat2
+        None,    // map_shadow_stack
+        Some(4), // futex_wake
+        Some(6), // futex_wait
+        Some(4), // futex_requeue
+        Some(4), // statmount
+        Some(4), // listmount
+        Some(4), // lsm_get_self_attr
+        Some(4), // lsm_set_self_attr
+        Some(3), // lsm_list_modules
+        Some(3), // mseal
+    ];
+
+    /// Argument types, as written in the kernel source.
+    #[allow(dead_code)]
+    pub(crate) static ARG_TYPES: [&[&str]; 463] = [
+        &["unsigned", "aio_context_t __user *"], // io_setup
+        &["aio_context_t"],                      // io_destroy
+        &["aio_context_t", "long", "struct iocb __user * __user *"], // io_submit
+        &[
+            "aio_context_t",
+            "struct iocb __user *",
+            "struct io_event __user *",
+        ], // io_cancel
+        &[
+            "aio_context_t",
+            "long",
+            "long",
+            "struct io_event __user *",
+            "struct __kernel_timespec __user *",
+        ], // io_getevents
+        &[
+            "const char __user *",
+            "const char __user *",
+            "const void __user *",
+            "size_t",
+            "int",
+        ], // setxattr
+        &[
+            "const char __user *",
+            "const char __user *",
+            "const void __user *",
+            "size_t",
+            "int",
+        ], // lsetxattr
+        &[
+            "int",
+            "const char __user *",
+            "const void __user *",
+            "size_t",
+            "int",
+        ], // fsetxattr
+        &[
+            "const char __user *",
+            "const char __user *",
+            "void __user *",
+            "size_t",
+        ], // getxattr
+        &[
+            "const char __user *",
+            "const char __user *",
+            "void __user *",
+            "size_t",
+        ], // lgetxattr
+        &["int", "const char __user *", "void __user *", "size_t"], // fgetxattr
+        &["const char __user *", "char __user *", "size_t"],        // listxattr
+        &["const char __user *", "char __user *", "size_t"], // llistxattr
+        &["int", "char __user *", "size_t"],                 // flistxattr
+        &["const char __user *", "const char __user *"],     // removexattr
+        &["const char __user *", "const char __user *"],     // lremovexattr
+        &["int", "const char __user *"],                     // fremovexattr
+        &["char __user *", "unsigned long"],                 // getcwd
+        &[],                                                 // lookup_dcookie
+        &["unsigned int", "int"],                            // eventfd2
+        &["int"],                                            // epoll_create1
+        &["int", "int", "int", "struct epoll_event __user *"], // epoll_ctl
+        &[
+            "int",
+            "struct epoll_event __user *",
+            "int",
+            "int",
+            "const sigset_t __user *",
+            "size_t",
+        ], // epoll_pwait
+        &["unsigned int"],                                   // dup
+        &["unsigned int", "unsigned int", "int"],            // dup3
+        &["unsigned int", "unsigned int", "unsigned long"],  // fcntl
+        &["int"],                                            // inotify_init1
+        &["int", "const char __user *", "u32"], // inotify_add_watch
+        &["int", "__s32"],                      // inotify_rm_watch
+        &["unsigned int", "unsigned int", "unsigned long"], // ioctl
+        &["int", "int", "int"],                 // ioprio_set
+        &["int", "int"],                        // ioprio_get
+        &["unsigned int", "unsigned int"],      // flock
+        &["int", "const char __user *", "umode_t", "unsigned int"], // mknodat
+        &["int", "const char __user *", "umode_t"], // mkdirat
+        &["int", "const char __user *", "int"], // unlinkat
+        &["const char __user *", "int", "const char __user *"], // symlinkat
+        &[
+            "int",
+            "const char __user *",
+            "int",
+            "const char __user *",
+            "int",
+        ], // linkat
+        &["int", "const char __user *", "int", "const char __user *"], // renameat
+        &["char __user *", "int"], // umount2
+        &[
+            "char __user *",
+            "char __user *",
+            "char __user *",
+            "unsigned long",
+            "void __user *",
+        ], // mount
+        &["const char __user *", "const char __user *"], // pivot_root
+        &[],                       // nfsservctl
+        &["const char __user *", "struct statfs __user *"], // statfs
+        &["unsigned int", "struct statfs __user *"], // fstatfs
+        &["const char __user *", "long"], // truncate
+        &["unsigned int", "off_t"], // ftruncate
+        &["int", "int", "loff_t", "loff_t"], // fallocate
+        &["int", "const char __user *", "int"], // faccessat
+        &["const char __user *"],  // chdir
+        &["unsigned int"],         // fchdir
+        &["const char __user *"],  // chroot
+        &["unsigned int", "umode_t"], // fchmod
+        &["int", "const char __user *", "umode_t"], // fchmodat
+        &["int", "const char __user *", "uid_t", "gid_t", "int"], // fchownat
+        &["unsigned int", "uid_t", "gid_t"], // fchown
+        &["int", "const char __user *", "int", "umode_t"], // openat
+        &["unsigned int"],         // close
+        &[],                       // vhangup
+        &["int __user *", "int"],  // pipe2
+        &[
+            "unsigned int",
+            "const char __user *",
+            "qid_t",
+            "void __user *",
+        ], // quotactl
+        &[
+            "unsigned int",
+            "struct linux_dirent64 __user *",
+            "unsigned int",
+        ], // getdents64
+        &["unsigned int", "off_t", "unsigned int"], // lseek
+        &["unsigned int", "char __user *", "size_t"], // read
+        &["unsigned int", "const char __user *", "size_t"], // write
+        &[
+            "unsigned long",
+            "const struct iovec __user *",
+            "unsigned long",
+        ], // readv
+        &[
+            "unsigned long",
+            "const struct iovec __user *",
+            "unsigned long",
+        ], // writev
+        &["unsigned int", "char __user *", "size_t", "loff_t"], // pread64
+        &["unsigned int", "const char __user *", "size_t", "loff_t"], // pwrite64
+        &[
+            "unsigned long",
+            "const struct iovec __user *",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+        ], // preadv
+        &[
+            "unsigned long",
+            "const struct iovec __user *",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+        ], // pwritev
+        &["int", "int", "loff_t __user *", "size_t"], // sendfile
+        &[
+            "int",
+            "fd_set __user *",
+            "fd_set __user *",
+            "fd_set __user *",
+            "struct __kernel_timespec __user *",
+            "void __user *",
+        ], // pselect6
+        &[
+            "struct pollfd __user *",
+            "unsigned int",
+            "struct __kernel_timespec __user *",
+            "const sigset_t __user *",
+            "size_t",
+        ], // ppoll
+        &["int", "sigset_t __user *", "size_t", "int"], // signalfd4
+        &[
+            "int",
+            "const struct iovec __user *",
+            "unsigned long",
+            "unsigned int",
+        ], // vmsplice
+        &[
+            "int",
+            "loff_t __user *",
+            "int",
+            "loff_t __user *",
+            "size_t",
+            "unsigned int",
+        ], // splice
+        &["int", "int", "size_t", "unsigned int"],    // tee
+        &["int", "const char __user *", "char __user *", "int"], // readlinkat
+        &["int", "const char __user *", "struct stat __user *", "int"], // fstatat
+        &["unsigned int", "struct stat __user *"],                      // fstat
+        &[],                                                            // sync
+        &["unsigned int"],                                              // fsync
+        &["unsigned int"], // fdatasync
+        &["int", "loff_t", "loff_t", "unsigned int"], // sync_file_range
+        &["int", "int"],   // timerfd_create
+        &[
+            "int",
+            "int",
+            "const struct __kernel_itimerspec __user *",
+            "struct __kernel_itimerspec __user *",
+        ], // timerfd_settime
+        &["int", "struct __kernel_itimerspec __user *"], // timerfd_gettime
+        &[
+            "int",
+            "const char __user *",
+            "struct __kernel_timespec __user *",
+            "int",
+        ], // utimensat
+        &["const char __user *"], // acct
+        &["cap_user_header_t", "cap_user_data_t"], // capget
+        &["cap_user_header_t", "const cap_user_data_t"], // capset
+        &["unsigned int"], // personality
+        &["int"],          // exit
+        &["int"],          // exit_group
+        &[
+            "int",
+            "pid_t",
+            "struct siginfo __user *",
+            "int",
+            "struct rusage __user *",
+        ], // waitid
+        &["int __user *"], // set_tid_address
+        &["unsigned long"], // unshare
+        &[
+            "u32 __user *",
+            "int",
+            "u32",
+            "const struct __kernel_timespec __user *",
+            "u32 __user *",
+            "u32",
+        ], // futex
+        &["struct robust_list_head __user *", "size_t"], // set_robust_list
+        &[
+            "int",
+            "struct robust_list_head __user * __user *",
+            "size_t __user *",
+        ], // get_robust_list
+        &[
+            "struct __kernel_timespec __user *",
+            "struct __kernel_timespec __user *",
+        ], // nanosleep
+        &["int", "struct __kernel_old_itimerval __user *"], // getitimer
+        &[
+            "int",
+            "struct __kernel_old_itimerval __user *",
+            "struct __kernel_old_itimerval __user *",
+        ], // setitimer
+        &[
+            "unsigned long",
+            "unsigned long",
+            "struct kexec_segment __user *",
+            "unsigned long",
+        ], // kexec_load
+        &["void __user *", "unsigned long", "const char __user *"], // init_module
+        &["const char __user *", "unsigned int"], // delete_module
+        &[
+            "const clockid_t",
+            "struct sigevent __user *",
+            "timer_t __user *",
+        ], // timer_create
+        &["timer_t", "struct __kernel_itimerspec __user *"], // timer_gettime
+        &["timer_t"],                             // timer_getoverrun
+        &[
+            "timer_t",
+            "int",
+            "const struct __kernel_itimerspec __user *",
+            "struct __kernel_itimerspec __user *",
+        ], // timer_settime
+        &["timer_t"],                             // timer_delete
+        &["const clockid_t", "const struct __kernel_timespec __user *"], // clock_settime
+        &["const clockid_t", "struct __kernel_timespec __user *"], // clock_gettime
+        &["const clockid_t", "struct __kernel_timespec __user *"], // clock_getres
+        &[
+            "const clockid_t",
+            "int",
+            "const struct __kernel_timespec __user *",
+            "struct __kernel_timespec __user *",
+        ], // clock_nanosleep
+        &["int", "char __user *", "int"],                          // syslog
+        &["long", "long", "unsigned long", "unsigned long"],       // ptrace
+        &["pid_t", "struct sched_param __user *"], // sched_setparam
+        &["pid_t", "int", "struct sched_param __user *"], // sched_setscheduler
+        &["pid_t"],                                // sched_getscheduler
+        &["pid_t", "struct sched_param __user *"], // sched_getparam
+        &["pid_t", "unsigned int", "unsigned long __user *"], // sched_setaffinity
+        &["pid_t", "unsigned int", "unsigned long __user *"], // sched_getaffinity
+        &[],                                                  // sched_yield
+        &["int"], // sched_get_priority_max
+        &["int"], // sched_get_priority_min
+        &["pid_t", "struct __kernel_timespec __user *"], // sched_rr_get_interval
+        &[],                                             // restart_syscall
+        &["pid_t", "int"],                               // kill
+        &["pid_t", "int"],                               // tkill
+        &["pid_t", "pid_t", "int"],                      // tgkill
+        &["const stack_t __user *", "stack_t __user *"], // sigaltstack
+        &["sigset_t __user *", "size_t"],                // rt_sigsuspend
+        &[
+            "int",
+            "const struct sigaction __user *",
+            "struct sigaction __user *",
+            "size_t",
+        ], // rt_sigaction
+        &["int", "sigset_t __user *", "sigset_t __user *", "size_t"], // rt_sigprocmask
+        &["sigset_t __user *", "size_t"], // rt_sigpending
+        &[
+            "const sigset_t __user *",
+            "siginfo_t __user *",
+            "const struct __kernel_timespec __user *",
+            "size_t",
+        ], // rt_sigtimedwait
+        &["pid_t", "int", "siginfo_t __user *"], // rt_sigqueueinfo
+        &[],                              // rt_sigreturn
+        &["int", "int", "int"],           // setpriority
+        &["int", "int"],                  // getpriority
+        &["int", "int", "unsigned int", "void __user *"], // reboot
+        &["gid_t", "gid_t"],              // setregid
+        &["gid_t"],                       // setgid
+        &["uid_t", "uid_t"],              // setreuid
+        &["uid_t"],                       // setuid
+        &["uid_t", "uid_t", "uid_t"],     // setresuid
+        &["uid_t __user *", "uid_t __user *", "uid_t __user *"], // getresuid
+        &["gid_t", "gid_t", "gid_t"],     // setresgid
+        &["gid_t __user *", "gid_t __user *", "gid_t __user *"], // getresgid
+        &["uid_t"],                       // setfsuid
+        &["gid_t"],                       // setfsgid
+        &["struct tms __user *"],         // times
+        &["pid_t", "pid_t"],              // setpgid
+        &["pid_t"],                       // getpgid
+        &["pid_t"],                       // getsid
+        &[],                              // setsid
+        &["int", "gid_t __user *"],       // getgroups
+        &["int", "gid_t __user *"],       // setgroups
+        &["struct new_utsname __user *"], // uname
+        &["char __user *", "int"],        // sethostname
+        &["char __user *", "int"],        // setdomainname
+        &["unsigned int", "struct rlimit __user *"], // getrlimit
+        &["unsigned int", "struct rlimit __user *"], // setrlimit
+        &["int", "struct rusage __user *"], // getrusage
+        &["int"],                         // umask
+        &[
+            "int",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+        ], // prctl
+        &[
+            "unsigned __user *",
+            "unsigned __user *",
+            "struct getcpu_cache __user *",
+        ], // getcpu
+        &[
+            "struct __kernel_old_timeval __user *",
+            "struct timezone __user *",
+        ], // gettimeofday
+        &[
+            "struct __kernel_old_timeval __user *",
+            "struct timezone __user *",
+        ], // settimeofday
+        &["struct __kernel_timex __user *"], // adjtimex
+        &[],                              // getpid
+        &[],                              // getppid
+        &[],                              // getuid
+        &[],                              // geteuid
+        &[],                              // getgid
+        &[],                              // getegid
+        &[],                              // gettid
+        &["struct sysinfo __user *"],     // sysinfo
+        &[
+            "const char __user *",
+            "int",
+            "umode_t",
+            "struct mq_attr __user *",
+        ], // mq_open
+        &["const char __user *"],         // mq_unlink
+        &[
+            "mqd_t",
+            "const char __user *",
+            "size_t",
+            "unsigned int",
+            "const struct __kernel_timespec __user *",
+        ], // mq_timedsend
+        &[
+            "mqd_t",
+            "char __user *",
+            "size_t",
+            "unsigned int __user *",
+            "const struct __kernel_timespec __user *",
+        ], // mq_timedreceive
+        &["mqd_t", "const struct sigevent __user *"], // mq_notify
+        &[
+            "mqd_t",
+            "const struct mq_attr __user *",
+            "struct mq_attr __user *",
+        ], // mq_getsetattr
+        &["key_t", "int"],                // msgget
+        &["int", "int", "struct msqid_ds __user *"], // msgctl
+        &["int", "struct msgbuf __user *", "size_t", "long", "int"], // msgrcv
+        &["int", "struct msgbuf __user *", "size_t", "int"], // msgsnd
+        &["key_t", "int", "int"],         // semget
+        &["int", "int", "int", "unsigned long"], // semctl
+        &[
+            "int",
+            "struct sembuf __user *",
+            "unsigned int",
+            "const struct __kernel_timespec __user *",
+        ], // semtimedop
+        &["int", "struct sembuf __user *", "unsigned"], // semop
+        &["key_t", "size_t", "int"],      // shmget
+        &["int", "int", "struct shmid_ds __user *"], // shmctl
+        &["int", "char __user *", "int"], // shmat
+        &["char __user *"],               // shmdt
+        &["int", "int", "int"],           // socket
+        &["int", "int", "int", "int __user *"], // socketpair
+        &["int", "struct sockaddr __user *", "int"], // bind
+        &["int", "int"],                  // listen
+        &["int", "struct sockaddr __user *", "int __user *"], // accept
+        &["int", "struct sockaddr __user *", "int"], // connect
+        &["int", "struct sockaddr __user *", "int __user *"], // getsockname
+        &["int", "struct sockaddr __user *", "int __user *"], // getpeername
+        &[
+            "int",
+            "void __user *",
+            "size_t",
+            "unsigned int",
+            "struct sockaddr __user *",
+            "int",
+        ], // sendto
+        &[
+            "int",
+            "void __user *",
+            "size_t",
+            "unsigned int",
+            "struct sockaddr __user *",
+            "int __user *",
+        ], // recvfrom
+        &["int", "int", "int", "char __user *", "int"], // setsockopt
+        &["int", "int", "int", "char __user *", "int __user *"], // getsockopt
+        &["int", "int"],                  // shutdown
+        &["int", "struct user_msghdr __user *", "unsigned int"], // sendmsg
+        &["int", "struct user_msghdr __user *", "unsigned int"], // recvmsg
+        &["int", "loff_t", "size_t"],     // readahead
+        &["unsigned long"],               // brk
+        &["unsigned long", "size_t"],     // munmap
+        &[
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+        ], // mremap
+        &[
+            "const char __user *",
+            "const char __user *",
+            "const void __user *",
+            "size_t",
+            "key_serial_t",
+        ], // add_key
+        &[
+            "const char __user *",
+            "const char __user *",
+            "const char __user *",
+            "key_serial_t",
+        ], // request_key
+        &[
+            "int",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+        ], // keyctl
+        &[
+            "unsigned long",
+            "unsigned long",
+            "int __user *",
+            "int __user *",
+            "unsigned long",
+        ], // clone
+        &[
+            "const char __user *",
+            "const char __user *const __user *",
+            "const char __user *const __user *",
+        ], // execve
+        &[
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+        ], // mmap
+        &["int", "loff_t", "loff_t", "int"], // fadvise64
+        &["const char __user *", "int"],  // swapon
+        &["const char __user *"],         // swapoff
+        &["unsigned long", "size_t", "unsigned long"], // mprotect
+        &["unsigned long", "size_t", "int"], // msync
+        &["unsigned long", "size_t"],     // mlock
+        &["unsigned long", "size_t"],     // munlock
+        &["int"],                         // mlockall
+        &[],                              // munlockall
+        &["unsigned long", "size_t", "unsigned char __user *"], // mincore
+        &["unsigned long", "size_t", "int"], // madvise
+        &[
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+        ], // remap_file_pages
+        &[
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+            "const unsigned long __user *",
+            "unsigned long",
+            "unsigned int",
+        ], // mbind
+        &[
+            "int __user *",
+            "unsigned long __user *",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+        ], // get_mempolicy
+        &["int", "const unsigned long __user *", "unsigned long"], // set_mempolicy
+        &[
+            "pid_t",
+            "unsigned long",
+            "const unsigned long __user *",
+            "const unsigned long __user *",
+        ], // migrate_pages
+        &[
+            "pid_t",
+            "unsigned long",
+            "const void __user * __user *",
+            "const int __user *",
+            "int __user *",
+            "int",
+        ], // move_pages
+        &["pid_t", "pid_t", "int", "siginfo_t __user *"], // rt_tgsigqueueinfo
+        &[
+            "struct perf_event_attr __user *",
+            "pid_t",
+            "int",
+            "int",
+            "unsigned long",
+        ], // perf_event_open
+        &["int", "struct sockaddr __user *", "int __user *", "int"], // accept4
+        &[
+            "int",
+            "struct mmsghdr __user *",
+            "unsigned int",
+            "unsigned int",
+            "struct __kernel_timespec __user *",
+        ], // recvmmsg
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &["pid_t", "int __user *", "int", "struct rusage __user *"], // wait4
+        &[
+            "pid_t",
+            "unsigned int",
+            "const struct rlimit64 __user *",
+            "struct rlimit64 __user *",
+        ], // prlimit64
+        &["unsigned int", "unsigned int"], // fanotify_init
+        &["int", "unsigned int", "__u64", "int", "const char __user *"], // fanotify_mark
+        &[
+            "int",
+            "const char __user *",
+            "struct file_handle __user *",
+            "int __user *",
+            "int",
+        ], // name_to_handle_at
+        &["int", "struct file_handle __user *", "int"], // open_by_handle_at
+        &["const clockid_t", "struct __kernel_timex __user *"], // clock_adjtime
+        &["int"],                                       // syncfs
+        &["int", "int"],                                // setns
+        &[
+            "int",
+            "struct mmsghdr __user *",
+            "unsigned int",
+            "unsigned int",
+        ], // sendmmsg
+        &[
+            "pid_t",
+            "const struct iovec __user *",
+            "unsigned long",
+            "const struct iovec __user *",
+            "unsigned long",
+            "unsigned long",
+        ], // process_vm_readv
+        &[
+            "pid_t",
+            "const struct iovec __user *",
+            "unsigned long",
+            "const struct iovec __user *",
+            "unsigned long",
+            "unsigned long",
+        ], // process_vm_writev
+        &["pid_t", "pid_t", "int", "unsigned long", "unsigned long"], // kcmp
+        &["int", "const char __user *", "int"],         // finit_module
+        &["pid_t", "struct sched_attr __user *", "unsigned int"], // sched_setattr
+        &[
+            "pid_t",
+            "struct sched_attr __user *",
+            "unsigned int",
+            "unsigned int",
+        ], // sched_getattr
+        &[
+            "int",
+            "const char __user *",
+            "int",
+            "const char __user *",
+            "unsigned int",
+        ], // renameat2
+        &["unsigned int", "unsigned int", "void __user *"],       // seccomp
+        &["char __user *", "size_t", "unsigned int"],             // getrandom
+        &["const char __user *", "unsigned int"], // memfd_create
+        &["int", "union bpf_attr __user *", "unsigned int"], // bpf
+        &[
+            "int",
+            "const char __user *",
+            "const char __user *const __user *",
+            "const char __user *const __user *",
+            "int",
+        ], // execveat
+        &["int"],                                 // userfaultfd
+        &["int", "unsigned int", "int"],          // membarrier
+        &["unsigned long", "size_t", "int"],      // mlock2
+        &[
+            "int",
+            "loff_t __user *",
+            "int",
+            "loff_t __user *",
+            "size_t",
+            "unsigned int",
+        ], // copy_file_range
+        &[
+            "unsigned long",
+            "const struct iovec __user *",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+            "rwf_t",
+        ], // preadv2
+        &[
+            "unsigned long",
+            "const struct iovec __user *",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+            "rwf_t",
+        ], // pwritev2
+        &["unsigned long", "size_t", "unsigned long", "int"], // pkey_mprotect
+        &["unsigned long", "unsigned long"],      // pkey_alloc
+        &["int"],                                 // pkey_free
+        &[
+            "int",
+            "const char __user *",
+            "unsigned",
+            "unsigned int",
+            "struct statx __user *",
+        ], // statx
+        &[
+            "aio_context_t",
+            "long",
+            "long",
+            "struct io_event __user *",
+            "struct __kernel_timespec __user *",
+            "const struct __aio_sigset __user *",
+        ], // io_pgetevents
+        &["struct rseq __user *", "u32", "int", "u32"], // rseq
+        &[
+            "int",
+            "int",
+            "unsigned long",
+            "const char __user *",
+            "unsigned long",
+        ], // kexec_file_load
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &["const clockid_t", "struct __kernel_timespec __user *"], // clock_gettime64
+        &["const clockid_t", "const struct __kernel_timespec __user *"], // clock_settime64
+        &["const clockid_t", "struct __kernel_timex __user *"], // clock_adjtime64
+        &["const clockid_t", "struct __kernel_timespec __user *"], // clock_getres_time64
+        &[
+            "const clockid_t",
+            "int",
+            "const struct __kernel_timespec __user *",
+            "struct __kernel_timespec __user *",
+        ], // clock_nanosleep_time64
+        &["timer_t", "struct __kernel_itimerspec __user *"], // timer_gettime64
+        &[
+            "timer_t",
+            "int",
+            "const struct __kernel_itimerspec __user *",
+            "struct __kernel_itimerspec __user *",
+        ], // timer_settime64
+        &["int", "struct __kernel_itimerspec __user *"], // timerfd_gettime64
+        &[
+            "int",
+            "int",
+            "const struct __kernel_itimerspec __user *",
+            "struct __kernel_itimerspec __user *",
+        ], // timerfd_settime64
+        &[
+            "int",
+            "const char __user *",
+            "struct __kernel_timespec __user *",
+            "int",
+        ], // utimensat_time64
+        &[
+            "int",
+            "fd_set __user *",
+            "fd_set __user *",
+            "fd_set __user *",
+            "struct __kernel_timespec __user *",
+            "void __user *",
+        ], // pselect6_time64
+        &[
+            "struct pollfd __user *",
+            "unsigned int",
+            "struct __kernel_timespec __user *",
+            "const sigset_t __user *",
+            "size_t",
+        ], // ppoll_time64
+        &[],
+        &[
+            "aio_context_t",
+            "long",
+            "long",
+            "struct io_event __user *",
+            "struct __kernel_timespec __user *",
+            "const struct __aio_sigset __user *",
+        ], // io_pgetevents_time64
+        &[
+            "int",
+            "struct mmsghdr __user *",
+            "unsigned int",
+            "unsigned int",
+            "struct __kernel_timespec __user *",
+        ], // recvmmsg_time64
+        &[
+            "mqd_t",
+            "const char __user *",
+            "size_t",
+            "unsigned int",
+            "const struct __kernel_timespec __user *",
+        ], // mq_timedsend_time64
+        &[
+            "mqd_t",
+            "char __user *",
+            "size_t",
+            "unsigned int __user *",
+            "const struct __kernel_timespec __user *",
+        ], // mq_timedreceive_time64
+        &[
+            "int",
+            "struct sembuf __user *",
+            "unsigned int",
+            "const struct __kernel_timespec __user *",
+        ], // semtimedop_time64
+        &[
+            "const sigset_t __user *",
+            "siginfo_t __user *",
+            "const struct __kernel_timespec __user *",
+            "size_t",
+        ], // rt_sigtimedwait_time64
+        &[
+            "u32 __user *",
+            "int",
+            "u32",
+            "const struct __kernel_timespec __user *",
+            "u32 __user *",
+            "u32",
+        ], // futex_time64
+        &["pid_t", "struct __kernel_timespec __user *"], // sched_rr_get_interval_time64
+        &["int", "int", "siginfo_t __user *", "unsigned int"], // pidfd_send_signal
+        &["u32", "struct io_uring_params __user *"],           // io_uring_setup
+        &[
+            "unsigned int",
+            "u32",
+            "u32",
+            "u32",
+            "const void __user *",
+            "size_t",
+        ], // io_uring_enter
+        &[
+            "unsigned int",
+            "unsigned int",
+            "void __user *",
+            "unsigned int",
+        ], // io_uring_register
+        &["int", "const char __user *", "unsigned"],           // open_tree
+        &[
+            "int",
+            "const char __user *",
+            "int",
+            "const char __user *",
+            "unsigned int",
+        ], // move_mount
+        &["const char __user *", "unsigned int"],              // fsopen
+        &[
+            "int",
+            "unsigned int",
+            "const char __user *",
+            "const void __user *",
+            "int",
+        ], // fsconfig
+        &["int", "unsigned int", "unsigned int"],              // fsmount
+        &["int", "const char __user *", "unsigned int"],       // fspick
+        &["pid_t", "unsigned int"],                            // pidfd_open
+        &["struct clone_args __user *", "size_t"],             // clone3
+        &["unsigned int", "unsigned int", "unsigned int"],     // close_range
+        &[
+            "int",
+            "const char __user *",
+            "struct open_how __user *",
+            "size_t",
+        ], // openat2
+        &["int", "int", "unsigned int"],                       // pidfd_getfd
+        &["int", "const char __user *", "int", "int"],         // faccessat2
+        &[
+            "int",
+            "const struct iovec __user *",
+            "size_t",
+            "int",
+            "unsigned int",
+        ], // process_madvise
+        &[
+            "int",
+            "struct epoll_event __user *",
+            "int",
+            "const struct __kernel_timespec __user *",
+            "const sigset_t __user *",
+            "size_t",
+        ], // epoll_pwait2
+        &[
+            "int",
+            "const char __user *",
+            "unsigned int",
+            "struct mount_attr __user *",
+            "size_t",
+        ], // mount_setattr
+        &["unsigned int", "unsigned int", "qid_t", "void __user *"], // quotactl_fd
+        &[
+            "const struct landlock_ruleset_attr __user *const",
+            "const size_t",
+            "const __u32",
+        ], // landlock_create_ruleset
+        &[
+            "const int",
+            "const enum landlock_rule_type",
+            "const void __user *const",
+            "const __u32",
+        ], // landlock_add_rule
+        &["const int", "const __u32"], // landlock_restrict_self
+        &["unsigned int"],             // memfd_secret
+        &["int", "unsigned int"],      // process_mrelease
+        &[
+            "struct futex_waitv __user *",
+            "unsigned int",
+            "unsigned int",
+            "struct __kernel_timespec __user *",
+            "clockid_t",
+        ], // futex_waitv
+        &[
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+        ], // set_mempolicy_home_node
+        &[
+            "unsigned int",
+            "struct cachestat_range __user *",
+            "struct cachestat __user *",
+            "unsigned int",
+        ], // cachestat
+        &["int", "const char __user *", "umode_t", "unsigned int"], // fchmodat2
+        &[],                           // map_shadow_stack
+        &["void __user *", "unsigned long", "int", "unsigned int"], // futex_wake
+        &[
+            "void __user *",
+            "unsigned long",
+            "unsigned long",
+            "unsigned int",
+            "struct __kernel_timespec __user *",
+            "clockid_t",
+        ], // futex_wait
+        &["struct futex_waitv __user *", "unsigned int", "int", "int"], // futex_requeue
+        &[
+            "const struct mnt_id_req __user *",
+            "struct statmount __user *",
+            "size_t",
+            "unsigned int",
+        ], // statmount
+        &[
+            "const struct mnt_id_req __user *",
+            "u64 __user *",
+            "size_t",
+            "unsigned int",
+        ], // listmount
+        &[
+            "unsigned int",
+            "struct lsm_ctx __user *",
+            "u32 __user *",
+            "u32",
+        ], // lsm_get_self_attr
+        &["unsigned int", "struct lsm_ctx __user *", "u32", "u32"], // lsm_set_self_attr
+        &["u64 __user *", "u32 __user *", "u32"], // lsm_list_modules
+        &["unsigned long", "size_t", "unsigned long"], // mseal
+    ];
+
+    /// Argument names.
+    pub(crate) static ARG_NAMES: [&[&str]; 463] = [
+        &["nr_events", "ctxp"],        // io_setup
+        &["ctx"],                      // io_destroy
+        &["ctx_id", "nr", "iocbpp"],   // io_submit
+        &["ctx_id", "iocb", "result"], // io_cancel
+        &["ctx_id", "min_nr", "nr", "events", "timeout"], // io_getevents
+        &["pathname", "name", "value", "size", "flags"], // setxattr
+        &["pathname", "name", "value", "size", "flags"], // lsetxattr
+        &["fd", "name", "value", "size", "flags"], // fsetxattr
+        &["pathname", "name", "value", "size"], // getxattr
+        &["pathname", "name", "value", "size"], // lgetxattr
+        &["fd", "name", "value", "size"], // fgetxattr
+        &["pathname", "list", "size"], // listxattr
+        &["pathname", "list", "size"], // llistxattr
+        &["fd", "list", "size"],       // flistxattr
+        &["pathname", "name"],         // removexattr
+        &["pathname", "name"],         // lremovexattr
+        &["fd", "name"],               // fremovexattr
+        &["buf", "size"],              // getcwd
+        &[],                           // lookup_dcookie
+        &["count", "flags"],           // eventfd2
+        &["flags"],                    // epoll_create1
+        &["epfd", "op", "fd", "event"], // epoll_ctl
+        &[
+            "epfd",
+            "events",
+            "maxevents",
+            "timeout",
+            "sigmask",
+            "sigsetsize",
+        ], // epoll_pwait
+        &["fildes"],                   // dup
+        &["oldfd", "newfd", "flags"],  // dup3
+        &["fd", "cmd", "arg"],         // fcntl
+        &["flags"],                    // inotify_init1
+        &["fd", "pathname", "mask"],   // inotify_add_watch
+        &["fd", "wd"],                 // inotify_rm_watch
+        &["fd", "cmd", "arg"],         // ioctl
+        &["which", "who", "ioprio"],   // ioprio_set
+        &["which", "who"],             // ioprio_get
+        &["fd", "cmd"],                // flock
+        &["dfd", "filename", "mode", "dev"], // mknodat
+        &["dfd", "pathname", "mode"],  // mkdirat
+        &["dfd", "pathname", "flag"],  // unlinkat
+        &["oldname", "newdfd", "newname"], // symlinkat
+        &["olddfd", "oldname", "newdfd", "newname", "flags"], // linkat
+        &["olddfd", "oldname", "newdfd", "newname"], // renameat
+        &["name", "flags"],            // umount2
+        &["dev_name", "dir_name", "type", "flags", "data"], // mount
+        &["new_root", "put_old"],      // pivot_root
+        &[],                           // nfsservctl
+        &["pathname", "buf"],          // statfs
+        &["fd", "buf"],                // fstatfs
+        &["path", "length"],           // truncate
+        &["fd", "length"],             // ftruncate
+        &["fd", "mode", "offset", "len"], // fallocate
+        &["dfd", "filename", "mode"],  // faccessat
+        &["filename"],                 // chdir
+        &["fd"],                       // fchdir
+        &["filename"],                 // chroot
+        &["fd", "mode"],               // fchmod
+        &["dfd", "filename", "mode"],  // fchmodat
+        &["dfd", "filename", "user", "group", "flag"], // fchownat
+        &["fd", "user", "group"],      // fchown
+        &["dfd", "filename", "flags", "mode"], // openat
+        &["fd"],                       // close
+        &[],                           // vhangup
+        &["fildes", "flags"],          // pipe2
+        &["cmd", "special", "id", "addr"], // quotactl
+        &["fd", "dirent", "count"],    // getdents64
+        &["fd", "offset", "whence"],   // lseek
+        &["fd", "buf", "count"],       // read
+        &["fd", "buf", "count"],       // write
+        &["fd", "vec", "vlen"],        // readv
+        &["fd", "vec", "vlen"],        // writev
+        &["fd", "buf", "count", "pos"], // pread64
+        &["fd", "buf", "count", "pos"], // pwrite64
+        &["fd", "vec", "vlen", "pos_l", "pos_h"], // preadv
+        &["fd", "vec", "vlen", "pos_l", "pos_h"], // pwritev
+        &["out_fd", "in_fd", "offset", "count"], // sendfile
+        &["n", "inp", "outp", "exp", "tsp", "sig"], // pselect6
+        &["ufds", "nfds", "tsp", "sigmask", "sigsetsize"], // ppoll
+        &["ufd", "user_mask", "sizemask", "flags"], // signalfd4
+        &["fd", "uiov", "nr_segs", "flags"], // vmsplice
+        &["fd_in", "off_in", "fd_out", "off_out", "len", "flags"], // splice
+        &["fdin", "fdout", "len", "flags"], // tee
+        &["dfd", "pathname", "buf", "bufsiz"], // readlinkat
+        &["dfd", "filename", "statbuf", "flag"], // fstatat
+        &["fd", "statbuf"],            // fstat
+        &[],                           // sync
+        &["fd"],                       // fsync
+        &["fd"],                       // fdatasync
+        &["fd", "offset", "nbytes", "flags"], // sync_file_range
+        &["clockid", "flags"],         // timerfd_create
+        &["ufd", "flags", "utmr", "otmr"], // timerfd_settime
+        &["ufd", "otmr"],              // timerfd_gettime
+        &["dfd", "filename", "utimes", "flags"], // utimensat
+        &["name"],                     // acct
+        &["header", "dataptr"],        // capget
+        &["header", "data"],           // capset
+        &["personality"],              // personality
+        &["error_code"],               // exit
+        &["error_code"],               // exit_group
+        &["which", "upid", "infop", "options", "ru"], // waitid
+        &["tidptr"],                   // set_tid_address
+        &["unshare_flags"],            // unshare
+        &["uaddr", "op", "val", "utime", "uaddr2", "val3"], // futex
+        &["head", "len"],              // set_robust_list
+        &["pid", "head_ptr", "len_ptr"], // get_robust_list
+        &["rqtp", "rmtp"],             // nanosleep
+        &["which", "value"],           // getitimer
+        &["which", "value", "ovalue"], // setitimer
+        &["entry", "nr_segments", "segments", "flags"], // kexec_load
+        &["umod", "len", "uargs"],     // init_module
+        &["name_user", "flags"],       // delete_module
+        &["which_clock", "timer_event_spec", "created_timer_id"], // timer_create
+        &["timer_id", "setting"], // timer_gettime
+        &["timer_id"],            // timer_getoverrun
+        &["timer_id", "flags", "new_setting", "old_setting"], // timer_settime
+        &["timer_id"],            // timer_delete
+        &["which_clock", "tp"],   // clock_settime
+        &["which_clock", "tp"],   // clock_gettime
+        &["which_clock", "tp"],   // clock_getres
+        &["which_clock", "flags", "rqtp", "rmtp"], // clock_nanosleep
+        &["type", "buf", "len"],  // syslog
+        &["request", "pid", "addr", "data"], // ptrace
+        &["pid", "param"],        // sched_setparam
+        &["pid", "policy", "param"], // sched_setscheduler
+        &["pid"],                 // sched_getscheduler
+        &["pid", "param"],        // sched_getparam
+        &["pid", "len", "user_mask_ptr"], // sched_setaffinity
+        &["pid", "len", "user_mask_ptr"], // sched_getaffinity
+        &[],                      // sched_yield
+        &["policy"],              // sched_get_priority_max
+        &["policy"],              // sched_get_priority_min
+        &["pid", "interval"],     // sched_rr_get_interval
+        &[],                      // restart_syscall
+        &["pid", "sig"],          // kill
+        &["pid", "sig"],          // tkill
+        &["tgid", "pid", "sig"],  // tgkill
+        &["uss", "uoss"],         // sigaltstack
+        &["unewset", "sigsetsize"], // rt_sigsuspend
+        &["sig", "act", "oact", "sigsetsize"], // rt_sigaction
+        &["how", "nset", "oset", "sigsetsize"], // rt_sigprocmask
+        &["uset", "sigsetsize"],  // rt_sigpending
+        &["uthese", "uinfo", "uts", "sigsetsize"], // rt_sigtimedwait
+        &["pid", "sig", "uinfo"], // rt_sigqueueinfo
+        &[],                      // rt_sigreturn
+        &["which", "who", "niceval"], // setpriority
+        &["which", "who"],        // getpriority
+        &["magic1", "magic2", "cmd", "arg"], // reboot
+        &["rgid", "egid"],        // setregid
+        &["gid"],                 // setgid
+        &["ruid", "euid"],        // setreuid
+        &["uid"],                 // setuid
+        &["ruid", "euid", "suid"], // setresuid
+        &["ruidp", "euidp", "suidp"], // getresuid
+        &["rgid", "egid", "sgid"], // setresgid
+        &["rgidp", "egidp", "sgidp"], // getresgid
+        &["uid"],                 // setfsuid
+        &["gid"],                 // setfsgid
+        &["tbuf"],                // times
+        &["pid", "pgid"],         // setpgid
+        &["pid"],                 // getpgid
+        &["pid"],                 // getsid
+        &[],                      // setsid
+        &["gidsetsize", "grouplist"], // getgroups
+        &["gidsetsize", "grouplist"], // setgroups
+        &["name"],                // uname
+        &["name", "len"],         // sethostname
+        &["name", "len"],         // setdomainname
+        &["resource", "rlim"],    // getrlimit
+        &["resource", "rlim"],    // setrlimit
+        &["who", "ru"],           // getrusage
+        &["mask"],                // umask
+        &["option", "arg2", "arg3", "arg4", "arg5"], // prctl
+        &["cpup", "nodep", "unused"], // getcpu
+        &["tv", "tz"],            // gettimeofday
+        &["tv", "tz"],            // settimeofday
+        &["txc_p"],               // adjtimex
+        &[],                      // getpid
+        &[],                      // getppid
+        &[],                      // getuid
+        &[],                      // geteuid
+        &[],                      // getgid
+        &[],                      // getegid
+        &[],                      // gettid
+        &["info"],                // sysinfo
+        &["u_name", "oflag", "mode", "u_attr"], // mq_open
+        &["u_name"],              // mq_unlink
+        &["mqdes", "u_msg_ptr", "msg_len", "msg_prio", "u_abs_timeout"], // mq_timedsend
+        &[
+            "mqdes",
+            "u_msg_ptr",
+            "msg_len",
+            "u_msg_prio",
+            "u_abs_timeout",
+        ], // mq_timedreceive
+        &["mqdes", "u_notification"], // mq_notify
+        &["mqdes", "u_mqstat", "u_omqstat"], // mq_getsetattr
+        &["key", "msgflg"],           // msgget
+        &["msqid", "cmd", "buf"],     // msgctl
+        &["msqid", "msgp", "msgsz", "msgtyp", "msgflg"], // msgrcv
+        &["msqid", "msgp", "msgsz", "msgflg"], // msgsnd
+        &["key", "nsems", "semflg"],  // semget
+        &["semid", "semnum", "cmd", "arg"], // semctl
+        &["semid", "tsops", "nsops", "timeout"], // semtimedop
+        &["semid", "tsops", "nsops"], // semop
+        &["key", "size", "shmflg"],   // shmget
+        &["shmid", "cmd", "buf"],     // shmctl
+        &["shmid", "shmaddr", "shmflg"], // shmat
+        &["shmaddr"],                 // shmdt
+        &["family", "type", "protocol"], // socket
+        &["family", "type", "protocol", "usockvec"], // socketpair
+        &["fd", "umyaddr", "addrlen"], // bind
+        &["fd", "backlog"],           // listen
+        &["fd", "upeer_sockaddr", "upeer_addrlen"], // accept
+        &["fd", "uservaddr", "addrlen"], // connect
+        &["fd", "usockaddr", "usockaddr_len"], // getsockname
+        &["fd", "usockaddr", "usockaddr_len"], // getpeername
+        &["fd", "buff", "len", "flags", "addr", "addr_len"], // sendto
+        &["fd", "ubuf", "size", "flags", "addr", "addr_len"], // recvfrom
+        &["fd", "level", "optname", "optval", "optlen"], // setsockopt
+        &["fd", "level", "optname", "optval", "optlen"], // getsockopt
+        &["fd", "how"],               // shutdown
+        &["fd", "msg", "flags"],      // sendmsg
+        &["fd", "msg", "flags"],      // recvmsg
+        &["fd", "offset", "count"],   // readahead
+        &["brk"],                     // brk
+        &["addr", "len"],             // munmap
+        &["addr", "old_len", "new_len", "flags", "new_addr"], // mremap
+        &["_type", "_description", "_payload", "plen", "ringid"], // add_key
+        &["_type", "_description", "_callout_info", "destringid"], // request_key
+        &["option", "arg2", "arg3", "arg4", "arg5"],               // keyctl
+        &[
+            "clone_flags",
+            "newsp",
+            "parent_tidptr",
+            "child_tidptr",
+            "tls",
+        ], // clone
+        &["filename", "argv", "envp"],                             // execve
+        &["addr", "len", "prot", "flags", "fd", "off"],            // mmap
+        &["fd", "offset", "len", "advice"],                        // fadvise64
+        &["specialfile", "swap_flags"],                            // swapon
+        &["specialfile"],                                          // swapoff
+        &["start", "len", "prot"],                                 // mprotect
+        &["start", "len", "flags"],                                // msync
+        &["start", "len"],                                         // mlock
+        &["start", "len"],                                         // munlock
+        &["flags"],                                                // mlockall
+        &[],                                                       // munlockall
+        &["start", "len", "vec"],                                  // mincore
+        &["start", "len_in", "behavior"],                          // madvise
+        &["start", "size", "prot", "pgoff", "flags"], // remap_file_pages
+        &["start", "len", "mode", "nmask", "maxnode", "flags"], // mbind
+        &["policy", "nmask", "maxnode", "addr", "flags"], // get_mempolicy
+        &["mode", "nmask", "maxnode"],                // set_mempolicy
+        &["pid", "maxnode", "old_nodes", "new_nodes"], // migrate_pages
+        &["pid", "nr_pages", "pages", "nodes", "status", "flags"], // move_pages
+        &["tgid", "pid", "sig", "uinfo"],             // rt_tgsigqueueinfo
+        &["attr_uptr", "pid", "cpu", "group_fd", "flags"], // perf_event_open
+        &["fd", "upeer_sockaddr", "upeer_addrlen", "flags"], // accept4
+        &["fd", "mmsg", "vlen", "flags", "timeout"],  // recvmmsg
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &["upid", "stat_addr", "options", "ru"], // wait4
+        &["pid", "resource", "new_rlim", "old_rlim"], // prlimit64
+        &["flags", "event_f_flags"],             // fanotify_init
+        &["fanotify_fd", "flags", "mask", "dfd", "pathname"], // fanotify_mark
+        &["dfd", "name", "handle", "mnt_id", "flag"], // name_to_handle_at
+        &["mountdirfd", "handle", "flags"],      // open_by_handle_at
+        &["which_clock", "utx"],                 // clock_adjtime
+        &["fd"],                                 // syncfs
+        &["fd", "flags"],                        // setns
+        &["fd", "mmsg", "vlen", "flags"],        // sendmmsg
+        &["pid", "lvec", "liovcnt", "rvec", "riovcnt", "flags"], // process_vm_readv
+        &["pid", "lvec", "liovcnt", "rvec", "riovcnt", "flags"], // process_vm_writev
+        &["pid1", "pid2", "type", "idx1", "idx2"],               // kcmp
+        &["fd", "uargs", "flags"],                               // finit_module
+        &["pid", "uattr", "flags"], // sched_setattr
+        &["pid", "uattr", "usize", "flags"], // sched_getattr
+        &["olddfd", "oldname", "newdfd", "newname", "flags"], // renameat2
+        &["op", "flags", "uargs"],  // seccomp
+        &["ubuf", "len", "flags"],  // getrandom
+        &["uname", "flags"],        // memfd_create
+        &["cmd", "uattr", "size"],  // bpf
+        &["fd", "filename", "argv", "envp", "flags"], // execveat
+        &["flags"],                 // userfaultfd
+        &["cmd", "flags", "cpu_id"], // membarrier
+        &["start", "len", "flags"], // mlock2
+        &["fd_in", "off_in", "fd_out", "off_out", "len", "flags"], // copy_file_range
+        &["fd", "vec", "vlen", "pos_l", "pos_h", "flags"],         // preadv2
+        &["fd", "vec", "vlen", "pos_l", "pos_h", "flags"],         // pwritev2
+        &["start", "len", "prot", "pkey"], // pkey_mprotect
+        &["flags", "init_val"],            // pkey_alloc
+        &["pkey"],                         // pkey_free
+        &["dfd", "filename", "flags", "mask", "buffer"], // statx
+        &["ctx_id", "min_nr", "nr", "events", "timeout", "usig"], // io_pgetevents
+        &["rseq", "rseq_len", "flags", "sig"],                    // rseq
+        &[
+            "kernel_fd",
+            "initrd_fd",
+            "cmdline_len",
+            "cmdline_ptr",
+            "flags",
+        ], // kexec_file_load
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &["which_clock", "tp"],  // clock_gettime64
+        &["which_clock", "tp"],  // clock_settime64
+        &["which_clock", "utx"], // clock_adjtime64
+        &["which_clock", "tp"],  // clock_getres_time64
+        &["which_clock", "flags", "rqtp", "rmtp"], // clock_nanosleep_time64
+        &["timer_id", "setting"], // timer_gettime64
+        &["timer_id", "flags", "new_setting", "old_setting"], // timer_settime64
+        &["ufd", "otmr"],        // timerfd_gettime64
+        &["ufd", "flags", "utmr", "otmr"], // timerfd_settime64
+        &["dfd", "filename", "utimes", "flags"], // utimensat_time64
+        &["n", "inp", "outp", "exp", "tsp", "sig"], // pselect6_time64
+        &["ufds", "nfds", "tsp", "sigmask", "sigsetsize"], // ppoll_time64
+        &[],
+        &["ctx_id", "min_nr", "nr", "events", "timeout", "usig"], // io_pgetevents_time64
+        &["fd", "mmsg", "vlen", "flags", "timeout"], // recvmmsg_time64
+        &["mqdes", "u_msg_ptr", "msg_len", "msg_prio", "u_abs_timeout"], // mq_timedsend_time64
+        &[
+            "mqdes",
+            "u_msg_ptr",
+            "msg_len",
+            "u_msg_prio",
+            "u_abs_timeout",
+        ], // mq_timedreceive_time64
+        &["semid", "tsops", "nsops", "timeout"], // semtimedop_time64
+        &["uthese", "uinfo", "uts", "sigsetsize"], // rt_sigtimedwait_time64
+        &["uaddr", "op", "val", "utime", "uaddr2", "val3"], // futex_time64
+        &["pid", "interval"],                    // sched_rr_get_interval_time64
+        &["pidfd", "sig", "info", "flags"],      // pidfd_send_signal
+        &["entries", "params"],                  // io_uring_setup
+        &["fd", "to_submit", "min_complete", "flags", "argp", "argsz"], // io_uring_enter
+        &["fd", "opcode", "arg", "nr_args"], // io_uring_register
+        &["dfd", "filename", "flags"],       // open_tree
+        &[
+            "from_dfd",
+            "from_pathname",
+            "to_dfd",
+            "to_pathname",
+            "flags",
+        ], // move_mount
+        &["_fs_name", "flags"],              // fsopen
+        &["fd", "cmd", "_key", "_value", "aux"], // fsconfig
+        &["fs_fd", "flags", "attr_flags"],   // fsmount
+        &["dfd", "path", "flags"],           // fspick
+        &["pid", "flags"],                   // pidfd_open
+        &["uargs", "size"],                  // clone3
+        &["fd", "max_fd", "flags"],          // close_range
+        &["dfd", "filename", "how", "usize"], // openat2
+        &["pidfd", "fd", "flags"],           // pidfd_getfd
+        &["dfd", "filename", "mode", "flags"], // faccessat2
+        &["pidfd", "vec", "vlen", "behavior", "flags"], // process_madvise
+        &[
+            "epfd",
+            "events",
+            "maxevents",
+            "timeout",
+            "sigmask",
+            "sigsetsize",
+        ], // epoll_pwait2
+        &["dfd", "path", "flags", "uattr", "usize"], // mount_setattr
+        &["fd", "cmd", "id", "addr"],        // quotactl_fd
+        &["attr", "size", "flags"],          // landlock_create_ruleset
+        &["ruleset_fd", "rule_type", "rule_attr", "flags"], // landlock_add_rule
+        &["ruleset_fd", "flags"],            // landlock_restrict_self
+        &["flags"],                          // memfd_secret
+        &["pidfd", "flags"],                 // process_mrelease
+        &["waiters", "nr_futexes", "flags", "timeout", "clockid"], // futex_waitv
+        &["start", "len", "home_node", "flags"], // set_mempolicy_home_node
+        &["fd", "cstat_range", "cstat", "flags"], // cachestat
+        &["dfd", "filename", "mode", "flags"],   // fchmodat2
+        &[],                                     // map_shadow_stack
+        &["uaddr", "mask", "nr", "flags"],       // futex_wake
+        &["uaddr", "val", "mask", "flags", "timeout", "clockid"], // futex_wait
+        &["waiters", "flags", "nr_wake", "nr_requeue"], // futex_requeue
+        &["req", "buf", "bufsize", "flags"],     // statmount
+        &["req", "mnt_ids", "nr_mnt_ids", "flags"], // listmount
+        &["attr", "ctx", "size", "flags"],       // lsm_get_self_attr
+        &["attr", "ctx", "size", "flags"],       // lsm_set_self_attr
+        &["ids", "size", "flags"],               // lsm_list_modules
+        &["start", "len", "flags"],              // mseal
+    ];
+
+    use crate::format::ArgFormat::{
+        self, Buf, Fd, Flags, Hex, Int, Mode, Str, Struct, Uint,
+    };
+
+    /// How arguments are shown by `format::Call`.
+    pub(crate) static ARG_FORMATS: [&[ArgFormat]; 463] = [
+        &[Uint, Hex],                                // io_setup
+        &[Uint],                                     // io_destroy
+        &[Uint, Int, Hex],                           // io_submit
+        &[Uint, Struct("iocb"), Struct("io_event")], // io_cancel
+        &[
+            Uint,
+            Int,
+            Int,
+            Struct("io_event"),
+            Struct("__kernel_timespec"),
+        ], // io_getevents
+        &[Str, Str, Hex, Uint, Int],                 // setxattr
+        &[Str, Str, Hex, Uint, Int],                 // lsetxattr
+        &[Fd, Str, Hex, Uint, Int],                  // fsetxattr
+        &[Str, Str, Hex, Uint],                      // getxattr
+        &[Str, Str, Hex, Uint],                      // lgetxattr
+        &[Fd, Str, Hex, Uint],                       // fgetxattr
+        &[Str, Str, Uint],                           // listxattr
+        &[Str, Str, Uint],                           // llistxattr
+        &[Fd, Str, Uint],                            // flistxattr
+        &[Str, Str],                                 // removexattr
+        &[Str, Str],                                 // lremovexattr
+        &[Fd, Str],                                  // fremovexattr
+        &[Buf, Uint],                                // getcwd
+        &[],                                         // lookup_dcookie
+        &[Uint, Int],                                // eventfd2
+        &[Int],                                      // epoll_create1
+        &[Fd, Int, Fd, Struct("epoll_event")],       // epoll_ctl
+        &[Fd, Struct("epoll_event"), Int, Int, Hex, Uint], // epoll_pwait
+        &[Fd],                                       // dup
+        &[Fd, Fd, Flags(O_FLAGS)],                   // dup3
+        &[Fd, Uint, Uint],                           // fcntl
+        &[Int],                                      // inotify_init1
+        &[Fd, Str, Uint],                            // inotify_add_watch
+        &[Fd, Int],                                  // inotify_rm_watch
+        &[Fd, Uint, Uint],                           // ioctl
+        &[Int, Int, Int],                            // ioprio_set
+        &[Int, Int],                                 // ioprio_get
+        &[Fd, Uint],                                 // flock
+        &[Fd, Str, Mode, Uint],                      // mknodat
+        &[Fd, Str, Mode],                            // mkdirat
+        &[Fd, Str, Flags(AT_FLAGS)],                 // unlinkat
+        &[Str, Fd, Str],                             // symlinkat
+        &[Fd, Str, Fd, Str, Flags(AT_FLAGS)],        // linkat
+        &[Fd, Str, Fd, Str],                         // renameat
+        &[Str, Int],                                 // umount2
+        &[Str, Str, Str, Flags(MS_FLAGS), Hex],      // mount
+        &[Str, Str],                                 // pivot_root
+        &[],                                         // nfsservctl
+        &[Str, Struct("statfs")],                    // statfs
+        &[Fd, Struct("statfs")],                     // fstatfs
+        &[Str, Int],                                 // truncate
+        &[Fd, Int],                                  // ftruncate
+        &[Fd, Int, Int, Int],                        // fallocate
+        &[Fd, Str, Int],                             // faccessat
+        &[Str],                                      // chdir
+        &[Fd],                                       // fchdir
+        &[Str],                                      // chroot
+        &[Fd, Mode],                                 // fchmod
+        &[Fd, Str, Mode],                            // fchmodat
+        &[Fd, Str, Uint, Uint, Flags(AT_FLAGS)],     // fchownat
+        &[Fd, Uint, Uint],                           // fchown
+        &[Fd, Str, Flags(O_FLAGS), Mode],            // openat
+        &[Fd],                                       // close
+        &[],                                         // vhangup
+        &[Hex, Flags(O_FLAGS)],                      // pipe2
+        &[Uint, Str, Uint, Hex],                     // quotactl
+        &[Fd, Struct("linux_dirent64"), Uint],       // getdents64
+        &[Fd, Int, Uint],                            // lseek
+        &[Fd, Buf, Uint],                            // read
+        &[Fd, Buf, Uint],                            // write
+        &[Fd, Struct("iovec"), Uint],                // readv
+        &[Fd, Struct("iovec"), Uint],                // writev
+        &[Fd, Buf, Uint, Int],                       // pread64
+        &[Fd, Buf, Uint, Int],                       // pwrite64
+        &[Fd, Struct("iovec"), Uint, Uint, Uint],    // preadv
+        &[Fd, Struct("iovec"), Uint, Uint, Uint],    // pwritev
+        &[Fd, Fd, Hex, Uint],                        // sendfile
+        &[Int, Hex, Hex, Hex, Struct("__kernel_timespec"), Hex], // pselect6
+        &[
+            Struct("pollfd"),
+            Uint,
+            Struct("__kernel_timespec"),
+            Hex,
+            Uint,
+        ], // ppoll
+        &[Int, Hex, Uint, Int],                      // signalfd4
+        &[Fd, Struct("iovec"), Uint, Uint],          // vmsplice
+        &[Fd, Hex, Fd, Hex, Uint, Uint],             // splice
+        &[Int, Int, Uint, Uint],                     // tee
+        &[Fd, Str, Buf, Int],                        // readlinkat
+        &[Fd, Str, Struct("stat"), Int],             // fstatat
+        &[Fd, Struct("stat")],                       // fstat
+        &[],                                         // sync
+        &[Fd],                                       // fsync
+        &[Fd],                                       // fdatasync
+        &[Fd, Int, Int, Uint],                       // sync_file_range
+        &[Int, Int],                                 // timerfd_create
+        &[
+            Int,
+            Int,
+            Struct("__kernel_itimerspec"),
+            Struct("__kernel_itimerspec"),
+        ], // timerfd_settime
+        &[Int, Struct("__kernel_itimerspec")],       // timerfd_gettime
+        &[Fd, Str, Struct("__kernel_timespec"), Flags(AT_FLAGS)], // utimensat
+        &[Str],                                      // acct
+        &[Hex, Hex],                                 // capget
+        &[Hex, Hex],                                 // capset
+        &[Uint],                                     // personality
+        &[Int],                                      // exit
+        &[Int],                                      // exit_group
+        &[Int, Int, Struct("siginfo"), Int, Struct("rusage")], // waitid
+        &[Hex],                                      // set_tid_address
+        &[Flags(CLONE_FLAGS)],                       // unshare
+        &[Hex, Int, Uint, Struct("__kernel_timespec"), Hex, Uint], // futex
+        &[Struct("robust_list_head"), Uint],         // set_robust_list
+        &[Int, Hex, Hex],                            // get_robust_list
+        &[Struct("__kernel_timespec"), Struct("__kernel_timespec")], // nanosleep
+        &[Int, Struct("__kernel_old_itimerval")], // getitimer
+        &[
+            Int,
+            Struct("__kernel_old_itimerval"),
+            Struct("__kernel_old_itimerval"),
+        ], // setitimer
+        &[Uint, Uint, Struct("kexec_segment"), Uint], // kexec_load
+        &[Hex, Uint, Str],                        // init_module
+        &[Str, Uint],                             // delete_module
+        &[Int, Struct("sigevent"), Hex],          // timer_create
+        &[Int, Struct("__kernel_itimerspec")],    // timer_gettime
+        &[Int],                                   // timer_getoverrun
+        &[
+            Int,
+            Int,
+            Struct("__kernel_itimerspec"),
+            Struct("__kernel_itimerspec"),
+        ], // timer_settime
+        &[Int],                                   // timer_delete
+        &[Int, Struct("__kernel_timespec")],      // clock_settime
+        &[Int, Struct("__kernel_timespec")],      // clock_gettime
+        &[Int, Struct("__kernel_timespec")],      // clock_getres
+        &[
+            Int,
+            Int,
+            Struct("__kernel_timespec"),
+            Struct("__kernel_timespec"),
+        ], // clock_nanosleep
+        &[Int, Buf, Int],                         // syslog
+        &[Int, Int, Hex, Uint],                   // ptrace
+        &[Int, Struct("sched_param")],            // sched_setparam
+        &[Int, Int, Struct("sched_param")],       // sched_setscheduler
+        &[Int],                                   // sched_getscheduler
+        &[Int, Struct("sched_param")],            // sched_getparam
+        &[Int, Uint, Hex],                        // sched_setaffinity
+        &[Int, Uint, Hex],                        // sched_getaffinity
+        &[],                                      // sched_yield
+        &[Int],                                   // sched_get_priority_max
+        &[Int],                                   // sched_get_priority_min
+        &[Int, Struct("__kernel_timespec")],      // sched_rr_get_interval
+        &[],                                      // restart_syscall
+        &[Int, Int],                              // kill
+        &[Int, Int],                              // tkill
+        &[Int, Int, Int],                         // tgkill
+        &[Hex, Hex],                              // sigaltstack
+        &[Hex, Uint],                             // rt_sigsuspend
+        &[Int, Struct("sigaction"), Struct("sigaction"), Uint], // rt_sigaction
+        &[Int, Hex, Hex, Uint],                   // rt_sigprocmask
+        &[Hex, Uint],                             // rt_sigpending
+        &[Hex, Hex, Struct("__kernel_timespec"), Uint], // rt_sigtimedwait
+        &[Int, Int, Hex],                         // rt_sigqueueinfo
+        &[],                                      // rt_sigreturn
+        &[Int, Int, Int],                         // setpriority
+        &[Int, Int],                              // getpriority
+        &[Int, Int, Uint, Hex],                   // reboot
+        &[Uint, Uint],                            // setregid
+        &[Uint],                                  // setgid
+        &[Uint, Uint],                            // setreuid
+        &[Uint],                                  // setuid
+        &[Uint, Uint, Uint],                      // setresuid
+        &[Hex, Hex, Hex],                         // getresuid
+        &[Uint, Uint, Uint],                      // setresgid
+        &[Hex, Hex, Hex],                         // getresgid
+        &[Uint],                                  // setfsuid
+        &[Uint],                                  // setfsgid
+        &[Struct("tms")],                         // times
+        &[Int, Int],                              // setpgid
+        &[Int],                                   // getpgid
+        &[Int],                                   // getsid
+        &[],                                      // setsid
+        &[Int, Hex],                              // getgroups
+        &[Int, Hex],                              // setgroups
+        &[Struct("new_utsname")],                 // uname
+        &[Str, Int],                              // sethostname
+        &[Str, Int],                              // setdomainname
+        &[Uint, Struct("rlimit")],                // getrlimit
+        &[Uint, Struct("rlimit")],                // setrlimit
+        &[Int, Struct("rusage")],                 // getrusage
+        &[Int],                                   // umask
+        &[Int, Uint, Uint, Uint, Uint],           // prctl
+        &[Hex, Hex, Struct("getcpu_cache")],      // getcpu
+        &[Struct("__kernel_old_timeval"), Struct("timezone")], // gettimeofday
+        &[Struct("__kernel_old_timeval"), Struct("timezone")], // settimeofday
+        &[Struct("__kernel_timex")],              // adjtimex
+        &[],                                      // getpid
+        &[],                                      // getppid
+        &[],                                      // getuid
+        &[],                                      // geteuid
+        &[],                                      // getgid
+        &[],                                      // getegid
+        &[],                                      // gettid
+        &[Struct("sysinfo")],                     // sysinfo
+        &[Str, Int, Mode, Struct("mq_attr")],     // mq_open
+        &[Str],                                   // mq_unlink
+        &[Uint, Str, Uint, Uint, Struct("__kernel_timespec")], // mq_timedsend
+        &[Uint, Str, Uint, Hex, Struct("__kernel_timespec")], // mq_timedreceive
+        &[Uint, Struct("sigevent")],              // mq_notify
+        &[Uint, Struct("mq_attr"), Struct("mq_attr")], // mq_getsetattr
+        &[Uint, Int],                             // msgget
+        &[Int, Int, Struct("msqid_ds")],          // msgctl
+        &[Int, Struct("msgbuf"), Uint, Int, Int], // msgrcv
+        &[Int, Struct("msgbuf"), Uint, Int],      // msgsnd
+        &[Uint, Int, Int],                        // semget
+        &[Int, Int, Int, Uint],                   // semctl
+        &[Int, Struct("sembuf"), Uint, Struct("__kernel_timespec")], // semtimedop
+        &[Int, Struct("sembuf"), Uint],                              // semop
+        &[Uint, Uint, Int],                                          // shmget
+        &[Int, Int, Struct("shmid_ds")],                             // shmctl
+        &[Int, Str, Int],                                            // shmat
+        &[Str],                                                      // shmdt
+        &[Int, Int, Int],                                            // socket
+        &[Int, Int, Int, Hex],          // socketpair
+        &[Fd, Struct("sockaddr"), Int], // bind
+        &[Fd, Int],                     // listen
+        &[Fd, Struct("sockaddr"), Hex], // accept
+        &[Fd, Struct("sockaddr"), Int], // connect
+        &[Fd, Struct("sockaddr"), Hex], // getsockname
+        &[Fd, Struct("sockaddr"), Hex], // getpeername
+        &[Fd, Hex, Uint, Uint, Struct("sockaddr"), Int], // sendto
+        &[Fd, Hex, Uint, Uint, Struct("sockaddr"), Hex], // recvfrom
+        &[Fd, Int, Int, Str, Int],      // setsockopt
+        &[Fd, Int, Int, Str, Hex],      // getsockopt
+        &[Fd, Int],                     // shutdown
+        &[Fd, Struct("user_msghdr"), Uint], // sendmsg
+        &[Fd, Struct("user_msghdr"), Uint], // recvmsg
+        &[Fd, Int, Uint],               // readahead
+        &[Hex],                         // brk
+        &[Hex, Uint],                   // munmap
+        &[Hex, Uint, Uint, Uint, Hex],  // mremap
+        &[Str, Str, Hex, Uint, Int],    // add_key
+        &[Str, Str, Str, Int],          // request_key
+        &[Int, Uint, Uint, Uint, Uint], // keyctl
+        &[Flags(CLONE_FLAGS), Uint, Hex, Hex, Uint], // clone
+        &[Str, Hex, Hex],               // execve
+        &[Hex, Uint, Flags(PROT_FLAGS), Flags(MAP_FLAGS), Fd, Uint], // mmap
+        &[Fd, Int, Int, Int],           // fadvise64
+        &[Str, Int],                    // swapon
+        &[Str],                         // swapoff
+        &[Hex, Uint, Flags(PROT_FLAGS)], // mprotect
+        &[Hex, Uint, Int],              // msync
+        &[Hex, Uint],                   // mlock
+        &[Hex, Uint],                   // munlock
+        &[Int],                         // mlockall
+        &[],                            // munlockall
+        &[Hex, Uint, Hex],              // mincore
+        &[Hex, Uint, Int],              // madvise
+        &[Hex, Uint, Flags(PROT_FLAGS), Uint, Uint], // remap_file_pages
+        &[Hex, Uint, Uint, Hex, Uint, Uint], // mbind
+        &[Hex, Hex, Uint, Hex, Uint],   // get_mempolicy
+        &[Int, Hex, Uint],              // set_mempolicy
+        &[Int, Uint, Hex, Hex],         // migrate_pages
+        &[Int, Uint, Hex, Hex, Hex, Int], // move_pages
+        &[Int, Int, Int, Hex],          // rt_tgsigqueueinfo
+        &[Struct("perf_event_attr"), Int, Int, Int, Uint], // perf_event_open
+        &[Fd, Struct("sockaddr"), Hex, Int], // accept4
+        &[
+            Fd,
+            Struct("mmsghdr"),
+            Uint,
+            Uint,
+            Struct("__kernel_timespec"),
+        ], // recvmmsg
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[Int, Hex, Int, Struct("rusage")], // wait4
+        &[Int, Uint, Struct("rlimit64"), Struct("rlimit64")], // prlimit64
+        &[Uint, Uint],                      // fanotify_init
+        &[Int, Uint, Uint, Fd, Str],        // fanotify_mark
+        &[Fd, Str, Struct("file_handle"), Hex, Flags(AT_FLAGS)], // name_to_handle_at
+        &[Int, Struct("file_handle"), Int], // open_by_handle_at
+        &[Int, Struct("__kernel_timex")],   // clock_adjtime
+        &[Fd],                              // syncfs
+        &[Fd, Flags(CLONE_FLAGS)],          // setns
+        &[Fd, Struct("mmsghdr"), Uint, Uint], // sendmmsg
+        &[Int, Struct("iovec"), Uint, Struct("iovec"), Uint, Uint], // process_vm_readv
+        &[Int, Struct("iovec"), Uint, Struct("iovec"), Uint, Uint], // process_vm_writev
+        &[Int, Int, Int, Uint, Uint],                               // kcmp
+        &[Fd, Str, Int],                          // finit_module
+        &[Int, Struct("sched_attr"), Uint],       // sched_setattr
+        &[Int, Struct("sched_attr"), Uint, Uint], // sched_getattr
+        &[Fd, Str, Fd, Str, Uint],                // renameat2
+        &[Uint, Uint, Hex],                       // seccomp
+        &[Buf, Uint, Uint],                       // getrandom
+        &[Str, Uint],                             // memfd_create
+        &[Int, Hex, Uint],                        // bpf
+        &[Fd, Str, Hex, Hex, Flags(AT_FLAGS)],    // execveat
+        &[Int],                                   // userfaultfd
+        &[Int, Uint, Int],                        // membarrier
+        &[Hex, Uint, Int],                        // mlock2
+        &[Fd, Hex, Fd, Hex, Uint, Uint],          // copy_file_range
+        &[Fd, Struct("iovec"), Uint, Uint, Uint, Int], // preadv2
+        &[Fd, Struct("iovec"), Uint, Uint, Uint, Int], // pwritev2
+        &[Hex, Uint, Flags(PROT_FLAGS), Int],     // pkey_mprotect
+        &[Uint, Uint],                            // pkey_alloc
+        &[Int],                                   // pkey_free
+        &[Fd, Str, Flags(AT_FLAGS), Uint, Struct("statx")], // statx
+        &[
+            Uint,
+            Int,
+            Int,
+            Struct("io_event"),
+            Struct("__kernel_timespec"),
+            Struct("__aio_sigset"),
+        ], // io_pgetevents
+        &[Struct("rseq"), Uint, Int, Uint],       // rseq
+        &[Int, Int, Uint, Str, Uint],             // kexec_file_load
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[Int, Struct("__kernel_timespec")], // clock_gettime64
+        &[Int, Struct("__kernel_timespec")], // clock_settime64
+        &[Int, Struct("__kernel_timex")],    // clock_adjtime64
+        &[Int, Struct("__kernel_timespec")], // clock_getres_time64
+        &[
+            Int,
+            Int,
+            Struct("__kernel_timespec"),
+            Struct("__kernel_timespec"),
+        ], // clock_nanosleep_time64
+        &[Int, Struct("__kernel_itimerspec")], // timer_gettime64
+        &[
+            Int,
+            Int,
+            Struct("__kernel_itimerspec"),
+            Struct("__kernel_itimerspec"),
+        ], // timer_settime64
+        &[Int, Struct("__kernel_itimerspec")], // timerfd_gettime64
+        &[
+            Int,
+            Int,
+            Struct("__kernel_itimerspec"),
+            Struct("__kernel_itimerspec"),
+        ], // timerfd_settime64
+        &[Fd, Str, Struct("__kernel_timespec"), Int], // utimensat_time64
+        &[Int, Hex, Hex, Hex, Struct("__kernel_timespec"), Hex], // pselect6_time64
+        &[
+            Struct("pollfd"),
+            Uint,
+            Struct("__kernel_timespec"),
+            Hex,
+            Uint,
+        ], // ppoll_time64
+        &[],
+        &[
+            Uint,
+            Int,
+            Int,
+            Struct("io_event"),
+            Struct("__kernel_timespec"),
+            Struct("__aio_sigset"),
+        ], // io_pgetevents_time64
+        &[
+            Fd,
+            Struct("mmsghdr"),
+            Uint,
+            Uint,
+            Struct("__kernel_timespec"),
+        ], // recvmmsg_time64
+        &[Uint, Str, Uint, Uint, Struct("__kernel_timespec")], // mq_timedsend_time64
+        &[Uint, Str, Uint, Hex, Struct("__kernel_timespec")], // mq_timedreceive_time64
+        &[Int, Struct("sembuf"), Uint, Struct("__kernel_timespec")], // semtimedop_time64
+        &[Hex, Hex, Struct("__kernel_timespec"), Uint], // rt_sigtimedwait_time64
+        &[Hex, Int, Uint, Struct("__kernel_timespec"), Hex, Uint], // futex_time64
+        &[Int, Struct("__kernel_timespec")], // sched_rr_get_interval_time64
+        &[Fd, Int, Hex, Uint],               // pidfd_send_signal
+        &[Uint, Struct("io_uring_params")],  // io_uring_setup
+        &[Fd, Uint, Uint, Uint, Hex, Uint],  // io_uring_enter
+        &[Fd, Uint, Hex, Uint],              // io_uring_register
+        &[Fd, Str, Uint],                    // open_tree
+        &[Int, Str, Int, Str, Uint],         // move_mount
+        &[Str, Uint],                        // fsopen
+        &[Fd, Uint, Str, Hex, Int],          // fsconfig
+        &[Int, Uint, Uint],                  // fsmount
+        &[Fd, Str, Uint],                    // fspick
+        &[Int, Uint],                        // pidfd_open
+        &[Struct("clone_args"), Uint],       // clone3
+        &[Fd, Uint, Uint],                   // close_range
+        &[Fd, Str, Struct("open_how"), Uint], // openat2
+        &[Fd, Fd, Uint],                     // pidfd_getfd
+        &[Fd, Str, Int, Flags(AT_FLAGS)],    // faccessat2
+        &[Fd, Struct("iovec"), Uint, Int, Uint], // process_madvise
+        &[
+            Fd,
+            Struct("epoll_event"),
+            Int,
+            Struct("__kernel_timespec"),
+            Hex,
+            Uint,
+        ], // epoll_pwait2
+        &[Fd, Str, Uint, Struct("mount_attr"), Uint], // mount_setattr
+        &[Fd, Uint, Uint, Hex],              // quotactl_fd
+        &[Struct("landlock_ruleset_attr"), Uint, Uint], // landlock_create_ruleset
+        &[Int, Hex, Hex, Uint],                         // landlock_add_rule
+        &[Int, Uint], // landlock_restrict_self
+        &[Uint],      // memfd_secret
+        &[Fd, Uint],  // process_mrelease
+        &[
+            Struct("futex_waitv"),
+            Uint,
+            Uint,
+            Struct("__kernel_timespec"),
+            Int,
+        ], // futex_waitv
+        &[Hex, Uint, Uint, Uint], // set_mempolicy_home_node
+        &[Fd, Struct("cachestat_range"), Struct("cachestat"), Uint], // cachestat
+        &[Fd, Str, Mode, Flags(AT_FLAGS)], // fchmodat2
+        &[],                               // map_shadow_stack
+        &[Hex, Uint, Int, Uint],           // futex_wake
+        &[Hex, Uint, Uint, Uint, Struct("__kernel_timespec"), Int], // futex_wait
+        &[Struct("futex_waitv"), Uint, Int, Int], // futex_requeue
+        &[Struct("mnt_id_req"), Struct("statmount"), Uint, Uint], // statmount
+        &[Struct("mnt_id_req"), Hex, Uint, Uint], // listmount
+        &[Uint, Struct("lsm_ctx"), Hex, Uint],    // lsm_get_self_attr
+        &[Uint, Struct("lsm_ctx"), Uint, Uint],   // lsm_set_self_attr
+        &[Hex, Hex, Uint],                        // lsm_list_modules
+        &[Hex, Uint, Uint],                       // mseal
+    ];
+
+    static O_FLAGS: &[(&str, u64)] = &[
+        ("O_SYNC", 0x0010_1000),
+        ("O_TMPFILE", 0x0040_4000),
+        ("O_APPEND", 0x400),
+        ("O_CLOEXEC", 0x0008_0000),
+        ("O_CREAT", 0x40),
+        ("O_DIRECT", 0x0001_0000),
+        ("O_DIRECTORY", 0x4000),
+        ("O_DSYNC", 0x1000),
+        ("O_EXCL", 0x80),
+        ("O_LARGEFILE", 0x0002_0000),
+        ("O_NOATIME", 0x0004_0000),
+        ("O_NOCTTY", 0x100),
+        ("O_NOFOLLOW", 0x8000),
+        ("O_NONBLOCK", 0x800),
+        ("O_PATH", 0x0020_0000),
+        ("O_RDWR", 0x2),
+        ("O_TRUNC", 0x200),
+        ("O_WRONLY", 0x1),
+        ("O_RDONLY", 0x0),
+    ];
+
+    static AT_FLAGS: &[(&str, u64)] = &[
+        ("AT_EMPTY_PATH", 0x1000),
+        ("AT_NO_AUTOMOUNT", 0x800),
+        ("AT_RECURSIVE", 0x8000),
+        ("AT_REMOVEDIR", 0x200),
+        ("AT_STATX_DONT_SYNC", 0x4000),
+        ("AT_STATX_FORCE_SYNC", 0x2000),
+        ("AT_SYMLINK_FOLLOW", 0x400),
+        ("AT_SYMLINK_NOFOLLOW", 0x100),
+    ];
+
+    static CLONE_FLAGS: &[(&str, u64)] = &[
+        ("CLONE_CHILD_CLEARTID", 0x0020_0000),
+        ("CLONE_CHILD_SETTID", 0x0100_0000),
+        ("CLONE_CLEAR_SIGHAND", 0x0001_0000_0000),
+        ("CLONE_DETACHED", 0x0040_0000),
+        ("CLONE_FILES", 0x400),
+        ("CLONE_FS", 0x200),
+        ("CLONE_INTO_CGROUP", 0x0002_0000_0000),
+        ("CLONE_IO", 0x8000_0000),
+        ("CLONE_NEWCGROUP", 0x0200_0000),
+        ("CLONE_NEWIPC", 0x0800_0000),
+        ("CLONE_NEWNET", 0x4000_0000),
+        ("CLONE_NEWNS", 0x0002_0000),
+        ("CLONE_NEWPID", 0x2000_0000),
+        ("CLONE_NEWTIME", 0x80),
+        ("CLONE_NEWUSER", 0x1000_0000),
+        ("CLONE_NEWUTS", 0x0400_0000),
+        ("CLONE_PARENT", 0x8000),
+        ("CLONE_PARENT_SETTID", 0x0010_0000),
+        ("CLONE_PIDFD", 0x1000),
+        ("CLONE_PTRACE", 0x2000),
+        ("CLONE_SETTLS", 0x0008_0000),
+        ("CLONE_SIGHAND", 0x800),
+        ("CLONE_SYSVSEM", 0x0004_0000),
+        ("CLONE_THREAD", 0x0001_0000),
+        ("CLONE_UNTRACED", 0x0080_0000),
+        ("CLONE_VFORK", 0x4000),
+        ("CLONE_VM", 0x100),
+    ];
+
+    static PROT_FLAGS: &[(&str, u64)] = &[
+        ("PROT_BTI", 0x10),
+        ("PROT_EXEC", 0x4),
+        ("PROT_GROWSDOWN", 0x0100_0000),
+        ("PROT_GROWSUP", 0x0200_0000),
+        ("PROT_MTE", 0x20),
+        ("PROT_READ", 0x1),
+        ("PROT_SEM", 0x8),
+        ("PROT_WRITE", 0x2),
+        ("PROT_NONE", 0x0),
+    ];
+
+    static MAP_FLAGS: &[(&str, u64)] = &[
+        ("MAP_SHARED_VALIDATE", 0x3),
+        ("MAP_ANONYMOUS", 0x20),
+        ("MAP_DENYWRITE", 0x800),
+        ("MAP_EXECUTABLE", 0x1000),
+        ("MAP_FIXED", 0x10),
+        ("MAP_FIXED_NOREPLACE", 0x0010_0000),
+        ("MAP_GROWSDOWN", 0x100),
+        ("MAP_HUGETLB", 0x0004_0000),
+        ("MAP_LOCKED", 0x2000),
+        ("MAP_NONBLOCK", 0x0001_0000),
+        ("MAP_NORESERVE", 0x4000),
+        ("MAP_POPULATE", 0x8000),
+        ("MAP_PRIVATE", 0x2),
+        ("MAP_SHARED", 0x1),
+        ("MAP_STACK", 0x0002_0000),
+        ("MAP_SYNC", 0x0008_0000),
+        ("MAP_UNINITIALIZED", 0x0400_0000),
+    ];
+
+    static MS_FLAGS: &[(&str, u64)] = &[
+        ("MS_ACTIVE", 0x4000_0000),
+        ("MS_BIND", 0x1000),
+        ("MS_BORN", 0x2000_0000),
+        ("MS_DIRSYNC", 0x80),
+        ("MS_I_VERSION", 0x0080_0000),
+        ("MS_KERNMOUNT", 0x0040_0000),
+        ("MS_LAZYTIME", 0x0200_0000),
+        ("MS_MANDLOCK", 0x40),
+        ("MS_MOVE", 0x2000),
+        ("MS_NOATIME", 0x400),
+        ("MS_NODEV", 0x4),
+        ("MS_NODIRATIME", 0x800),
+        ("MS_NOEXEC", 0x8),
+        ("MS_NOREMOTELOCK", 0x0800_0000),
+        ("MS_NOSEC", 0x1000_0000),
+        ("MS_NOSUID", 0x2),
+        ("MS_NOSYMFOLLOW", 0x100),
+        ("MS_NOUSER", 0x8000_0000),
+        ("MS_POSIXACL", 0x0001_0000),
+        ("MS_PRIVATE", 0x0004_0000),
+        ("MS_RDONLY", 0x1),
+        ("MS_REC", 0x4000),
+        ("MS_RELATIME", 0x0020_0000),
+        ("MS_REMOUNT", 0x20),
+        ("MS_SHARED", 0x0010_0000),
+        ("MS_SILENT", 0x8000),
+        ("MS_SLAVE", 0x0008_0000),
+        ("MS_STRICTATIME", 0x0100_0000),
+        ("MS_SUBMOUNT", 0x0400_0000),
+        ("MS_SYNCHRONOUS", 0x10),
+        ("MS_UNBINDABLE", 0x0002_0000),
+    ];
 }
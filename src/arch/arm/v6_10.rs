@@ -4,318 +4,622 @@
 
 syscall_enum! {
     pub enum Sysno {
+        /// Restart a system call after interruption by a stop signal.
+        ///
         /// See [restart_syscall(2)](https://man7.org/linux/man-pages/man2/restart_syscall.2.html) for more info on this syscall.
         restart_syscall = 0,
         /// See [exit(2)](https://man7.org/linux/man-pages/man2/exit.2.html) for more info on this syscall.
         exit = 1,
+        /// Create a child process.
+        ///
         /// See [fork(2)](https://man7.org/linux/man-pages/man2/fork.2.html) for more info on this syscall.
         fork = 2,
+        /// Read from a file descriptor.
+        ///
         /// See [read(2)](https://man7.org/linux/man-pages/man2/read.2.html) for more info on this syscall.
         read = 3,
+        /// Write to a file descriptor.
+        ///
         /// See [write(2)](https://man7.org/linux/man-pages/man2/write.2.html) for more info on this syscall.
         write = 4,
+        /// Open and possibly create a file.
+        ///
         /// See [open(2)](https://man7.org/linux/man-pages/man2/open.2.html) for more info on this syscall.
         open = 5,
+        /// Close a file descriptor.
+        ///
         /// See [close(2)](https://man7.org/linux/man-pages/man2/close.2.html) for more info on this syscall.
         close = 6,
+        /// Open and possibly create a file.
+        ///
         /// See [creat(2)](https://man7.org/linux/man-pages/man2/creat.2.html) for more info on this syscall.
         creat = 8,
+        /// Make a new name for a file.
+        ///
         /// See [link(2)](https://man7.org/linux/man-pages/man2/link.2.html) for more info on this syscall.
         link = 9,
+        /// Delete a name and possibly the file it refers to.
+        ///
         /// See [unlink(2)](https://man7.org/linux/man-pages/man2/unlink.2.html) for more info on this syscall.
         unlink = 10,
+        /// Execute program.
+        ///
         /// See [execve(2)](https://man7.org/linux/man-pages/man2/execve.2.html) for more info on this syscall.
         execve = 11,
+        /// Change working directory.
+        ///
         /// See [chdir(2)](https://man7.org/linux/man-pages/man2/chdir.2.html) for more info on this syscall.
         chdir = 12,
+        /// Create a special or ordinary file.
+        ///
         /// See [mknod(2)](https://man7.org/linux/man-pages/man2/mknod.2.html) for more info on this syscall.
         mknod = 14,
+        /// Change permissions of a file.
+        ///
         /// See [chmod(2)](https://man7.org/linux/man-pages/man2/chmod.2.html) for more info on this syscall.
         chmod = 15,
+        /// Change ownership of a file.
+        ///
         /// See [lchown(2)](https://man7.org/linux/man-pages/man2/lchown.2.html) for more info on this syscall.
         lchown = 16,
+        /// Reposition read/write file offset.
+        ///
         /// See [lseek(2)](https://man7.org/linux/man-pages/man2/lseek.2.html) for more info on this syscall.
         lseek = 19,
+        /// Get process identification.
+        ///
         /// See [getpid(2)](https://man7.org/linux/man-pages/man2/getpid.2.html) for more info on this syscall.
         getpid = 20,
+        /// Mount filesystem.
+        ///
         /// See [mount(2)](https://man7.org/linux/man-pages/man2/mount.2.html) for more info on this syscall.
         mount = 21,
+        /// Set user identity.
+        ///
         /// See [setuid(2)](https://man7.org/linux/man-pages/man2/setuid.2.html) for more info on this syscall.
         setuid = 23,
+        /// Get user identity.
+        ///
         /// See [getuid(2)](https://man7.org/linux/man-pages/man2/getuid.2.html) for more info on this syscall.
         getuid = 24,
+        /// Process trace.
+        ///
         /// See [ptrace(2)](https://man7.org/linux/man-pages/man2/ptrace.2.html) for more info on this syscall.
         ptrace = 26,
+        /// Wait for signal.
+        ///
         /// See [pause(2)](https://man7.org/linux/man-pages/man2/pause.2.html) for more info on this syscall.
         pause = 29,
+        /// Check user's permissions for a file.
+        ///
         /// See [access(2)](https://man7.org/linux/man-pages/man2/access.2.html) for more info on this syscall.
         access = 33,
+        /// Change process priority.
+        ///
         /// See [nice(2)](https://man7.org/linux/man-pages/man2/nice.2.html) for more info on this syscall.
         nice = 34,
+        /// Commit filesystem caches to disk.
+        ///
         /// See [sync(2)](https://man7.org/linux/man-pages/man2/sync.2.html) for more info on this syscall.
         sync = 36,
+        /// Send signal to a process.
+        ///
         /// See [kill(2)](https://man7.org/linux/man-pages/man2/kill.2.html) for more info on this syscall.
         kill = 37,
+        /// Change the name or location of a file.
+        ///
         /// See [rename(2)](https://man7.org/linux/man-pages/man2/rename.2.html) for more info on this syscall.
         rename = 38,
+        /// Create a directory.
+        ///
         /// See [mkdir(2)](https://man7.org/linux/man-pages/man2/mkdir.2.html) for more info on this syscall.
         mkdir = 39,
+        /// Delete a directory.
+        ///
         /// See [rmdir(2)](https://man7.org/linux/man-pages/man2/rmdir.2.html) for more info on this syscall.
         rmdir = 40,
+        /// Duplicate a file descriptor.
+        ///
         /// See [dup(2)](https://man7.org/linux/man-pages/man2/dup.2.html) for more info on this syscall.
         dup = 41,
+        /// Create pipe.
+        ///
         /// See [pipe(2)](https://man7.org/linux/man-pages/man2/pipe.2.html) for more info on this syscall.
         pipe = 42,
+        /// Get process times.
+        ///
         /// See [times(2)](https://man7.org/linux/man-pages/man2/times.2.html) for more info on this syscall.
         times = 43,
+        /// Change data segment size.
+        ///
         /// See [brk(2)](https://man7.org/linux/man-pages/man2/brk.2.html) for more info on this syscall.
         brk = 45,
+        /// Set group identity.
+        ///
         /// See [setgid(2)](https://man7.org/linux/man-pages/man2/setgid.2.html) for more info on this syscall.
         setgid = 46,
+        /// Get group identity.
+        ///
         /// See [getgid(2)](https://man7.org/linux/man-pages/man2/getgid.2.html) for more info on this syscall.
         getgid = 47,
+        /// Get user identity.
+        ///
         /// See [geteuid(2)](https://man7.org/linux/man-pages/man2/geteuid.2.html) for more info on this syscall.
         geteuid = 49,
+        /// Get group identity.
+        ///
         /// See [getegid(2)](https://man7.org/linux/man-pages/man2/getegid.2.html) for more info on this syscall.
         getegid = 50,
+        /// Switch process accounting on or off.
+        ///
         /// See [acct(2)](https://man7.org/linux/man-pages/man2/acct.2.html) for more info on this syscall.
         acct = 51,
+        /// Unmount filesystem.
+        ///
         /// See [umount2(2)](https://man7.org/linux/man-pages/man2/umount2.2.html) for more info on this syscall.
         umount2 = 52,
+        /// Control device.
+        ///
         /// See [ioctl(2)](https://man7.org/linux/man-pages/man2/ioctl.2.html) for more info on this syscall.
         ioctl = 54,
+        /// Manipulate file descriptor.
+        ///
         /// See [fcntl(2)](https://man7.org/linux/man-pages/man2/fcntl.2.html) for more info on this syscall.
         fcntl = 55,
+        /// Set/get process group.
+        ///
         /// See [setpgid(2)](https://man7.org/linux/man-pages/man2/setpgid.2.html) for more info on this syscall.
         setpgid = 57,
+        /// Set file mode creation mask.
+        ///
         /// See [umask(2)](https://man7.org/linux/man-pages/man2/umask.2.html) for more info on this syscall.
         umask = 60,
+        /// Change root directory.
+        ///
         /// See [chroot(2)](https://man7.org/linux/man-pages/man2/chroot.2.html) for more info on this syscall.
         chroot = 61,
+        /// Get filesystem statistics.
+        ///
         /// See [ustat(2)](https://man7.org/linux/man-pages/man2/ustat.2.html) for more info on this syscall.
         ustat = 62,
+        /// Duplicate a file descriptor.
+        ///
         /// See [dup2(2)](https://man7.org/linux/man-pages/man2/dup2.2.html) for more info on this syscall.
         dup2 = 63,
+        /// Get process identification.
+        ///
         /// See [getppid(2)](https://man7.org/linux/man-pages/man2/getppid.2.html) for more info on this syscall.
         getppid = 64,
+        /// Set/get process group.
+        ///
         /// See [getpgrp(2)](https://man7.org/linux/man-pages/man2/getpgrp.2.html) for more info on this syscall.
         getpgrp = 65,
+        /// Creates a session and sets the process group ID.
+        ///
         /// See [setsid(2)](https://man7.org/linux/man-pages/man2/setsid.2.html) for more info on this syscall.
         setsid = 66,
+        /// Examine and change a signal action.
+        ///
         /// See [sigaction(2)](https://man7.org/linux/man-pages/man2/sigaction.2.html) for more info on this syscall.
         sigaction = 67,
+        /// Set real and/or effective user or group ID.
+        ///
         /// See [setreuid(2)](https://man7.org/linux/man-pages/man2/setreuid.2.html) for more info on this syscall.
         setreuid = 70,
+        /// Set real and/or effective user or group ID.
+        ///
         /// See [setregid(2)](https://man7.org/linux/man-pages/man2/setregid.2.html) for more info on this syscall.
         setregid = 71,
+        /// Wait for a signal.
+        ///
         /// See [sigsuspend(2)](https://man7.org/linux/man-pages/man2/sigsuspend.2.html) for more info on this syscall.
         sigsuspend = 72,
+        /// Examine pending signals.
+        ///
         /// See [sigpending(2)](https://man7.org/linux/man-pages/man2/sigpending.2.html) for more info on this syscall.
         sigpending = 73,
+        /// Get/set hostname.
+        ///
         /// See [sethostname(2)](https://man7.org/linux/man-pages/man2/sethostname.2.html) for more info on this syscall.
         sethostname = 74,
+        /// Get/set resource limits.
+        ///
         /// See [setrlimit(2)](https://man7.org/linux/man-pages/man2/setrlimit.2.html) for more info on this syscall.
         setrlimit = 75,
+        /// Get resource usage.
+        ///
         /// See [getrusage(2)](https://man7.org/linux/man-pages/man2/getrusage.2.html) for more info on this syscall.
         getrusage = 77,
+        /// Get / set time.
+        ///
         /// See [gettimeofday(2)](https://man7.org/linux/man-pages/man2/gettimeofday.2.html) for more info on this syscall.
         gettimeofday = 78,
+        /// Get / set time.
+        ///
         /// See [settimeofday(2)](https://man7.org/linux/man-pages/man2/settimeofday.2.html) for more info on this syscall.
         settimeofday = 79,
+        /// Get/set list of supplementary group IDs.
+        ///
         /// See [getgroups(2)](https://man7.org/linux/man-pages/man2/getgroups.2.html) for more info on this syscall.
         getgroups = 80,
+        /// Get/set list of supplementary group IDs.
+        ///
         /// See [setgroups(2)](https://man7.org/linux/man-pages/man2/setgroups.2.html) for more info on this syscall.
         setgroups = 81,
+        /// Make a new name for a file.
+        ///
         /// See [symlink(2)](https://man7.org/linux/man-pages/man2/symlink.2.html) for more info on this syscall.
         symlink = 83,
+        /// Read value of a symbolic link.
+        ///
         /// See [readlink(2)](https://man7.org/linux/man-pages/man2/readlink.2.html) for more info on this syscall.
         readlink = 85,
+        /// Load shared library.
+        ///
         /// See [uselib(2)](https://man7.org/linux/man-pages/man2/uselib.2.html) for more info on this syscall.
         uselib = 86,
+        /// Start/stop swapping to file/device.
+        ///
         /// See [swapon(2)](https://man7.org/linux/man-pages/man2/swapon.2.html) for more info on this syscall.
         swapon = 87,
+        /// Reboot or enable/disable Ctrl-Alt-Del.
+        ///
         /// See [reboot(2)](https://man7.org/linux/man-pages/man2/reboot.2.html) for more info on this syscall.
         reboot = 88,
+        /// Map or unmap files or devices into memory.
+        ///
         /// See [munmap(2)](https://man7.org/linux/man-pages/man2/munmap.2.html) for more info on this syscall.
         munmap = 91,
+        /// Truncate a file to a specified length.
+        ///
         /// See [truncate(2)](https://man7.org/linux/man-pages/man2/truncate.2.html) for more info on this syscall.
         truncate = 92,
+        /// Truncate a file to a specified length.
+        ///
         /// See [ftruncate(2)](https://man7.org/linux/man-pages/man2/ftruncate.2.html) for more info on this syscall.
         ftruncate = 93,
+        /// Change permissions of a file.
+        ///
         /// See [fchmod(2)](https://man7.org/linux/man-pages/man2/fchmod.2.html) for more info on this syscall.
         fchmod = 94,
+        /// Change ownership of a file.
+        ///
         /// See [fchown(2)](https://man7.org/linux/man-pages/man2/fchown.2.html) for more info on this syscall.
         fchown = 95,
+        /// Get/set program scheduling priority.
+        ///
         /// See [getpriority(2)](https://man7.org/linux/man-pages/man2/getpriority.2.html) for more info on this syscall.
         getpriority = 96,
+        /// Get/set program scheduling priority.
+        ///
         /// See [setpriority(2)](https://man7.org/linux/man-pages/man2/setpriority.2.html) for more info on this syscall.
         setpriority = 97,
+        /// Get filesystem statistics.
+        ///
         /// See [statfs(2)](https://man7.org/linux/man-pages/man2/statfs.2.html) for more info on this syscall.
         statfs = 99,
+        /// Get filesystem statistics.
+        ///
         /// See [fstatfs(2)](https://man7.org/linux/man-pages/man2/fstatfs.2.html) for more info on this syscall.
         fstatfs = 100,
+        /// Read and/or clear kernel message ring buffer; set `console_loglevel`.
+        ///
         /// See [syslog(2)](https://man7.org/linux/man-pages/man2/syslog.2.html) for more info on this syscall.
         syslog = 103,
+        /// Get or set value of an interval timer.
+        ///
         /// See [setitimer(2)](https://man7.org/linux/man-pages/man2/setitimer.2.html) for more info on this syscall.
         setitimer = 104,
+        /// Get or set value of an interval timer.
+        ///
         /// See [getitimer(2)](https://man7.org/linux/man-pages/man2/getitimer.2.html) for more info on this syscall.
         getitimer = 105,
+        /// Get file status.
+        ///
         /// See [stat(2)](https://man7.org/linux/man-pages/man2/stat.2.html) for more info on this syscall.
         stat = 106,
+        /// Get file status.
+        ///
         /// See [lstat(2)](https://man7.org/linux/man-pages/man2/lstat.2.html) for more info on this syscall.
         lstat = 107,
+        /// Get file status.
+        ///
         /// See [fstat(2)](https://man7.org/linux/man-pages/man2/fstat.2.html) for more info on this syscall.
         fstat = 108,
+        /// Virtually hangup the current terminal.
+        ///
         /// See [vhangup(2)](https://man7.org/linux/man-pages/man2/vhangup.2.html) for more info on this syscall.
         vhangup = 111,
+        /// Wait for process to change state, BSD style.
+        ///
         /// See [wait4(2)](https://man7.org/linux/man-pages/man2/wait4.2.html) for more info on this syscall.
         wait4 = 114,
+        /// Start/stop swapping to file/device.
+        ///
         /// See [swapoff(2)](https://man7.org/linux/man-pages/man2/swapoff.2.html) for more info on this syscall.
         swapoff = 115,
+        /// Return system information.
+        ///
         /// See [sysinfo(2)](https://man7.org/linux/man-pages/man2/sysinfo.2.html) for more info on this syscall.
         sysinfo = 116,
+        /// Synchronize a file's in-core state with storage device.
+        ///
         /// See [fsync(2)](https://man7.org/linux/man-pages/man2/fsync.2.html) for more info on this syscall.
         fsync = 118,
+        /// Return from signal handler and cleanup stack frame.
+        ///
         /// See [sigreturn(2)](https://man7.org/linux/man-pages/man2/sigreturn.2.html) for more info on this syscall.
         sigreturn = 119,
+        /// Create a child process.
+        ///
         /// See [clone(2)](https://man7.org/linux/man-pages/man2/clone.2.html) for more info on this syscall.
         clone = 120,
+        /// Get/set NIS domain name.
+        ///
         /// See [setdomainname(2)](https://man7.org/linux/man-pages/man2/setdomainname.2.html) for more info on this syscall.
         setdomainname = 121,
+        /// Get name and information about current kernel.
+        ///
         /// See [uname(2)](https://man7.org/linux/man-pages/man2/uname.2.html) for more info on this syscall.
         uname = 122,
+        /// Tune kernel clock.
+        ///
         /// See [adjtimex(2)](https://man7.org/linux/man-pages/man2/adjtimex.2.html) for more info on this syscall.
         adjtimex = 124,
+        /// Set protection on a region of memory.
+        ///
         /// See [mprotect(2)](https://man7.org/linux/man-pages/man2/mprotect.2.html) for more info on this syscall.
         mprotect = 125,
+        /// Examine and change blocked signals.
+        ///
         /// See [sigprocmask(2)](https://man7.org/linux/man-pages/man2/sigprocmask.2.html) for more info on this syscall.
         sigprocmask = 126,
+        /// Load a kernel module.
+        ///
         /// See [init_module(2)](https://man7.org/linux/man-pages/man2/init_module.2.html) for more info on this syscall.
         init_module = 128,
+        /// Unload a kernel module.
+        ///
         /// See [delete_module(2)](https://man7.org/linux/man-pages/man2/delete_module.2.html) for more info on this syscall.
         delete_module = 129,
+        /// Manipulate disk quotas.
+        ///
         /// See [quotactl(2)](https://man7.org/linux/man-pages/man2/quotactl.2.html) for more info on this syscall.
         quotactl = 131,
+        /// Set/get process group.
+        ///
         /// See [getpgid(2)](https://man7.org/linux/man-pages/man2/getpgid.2.html) for more info on this syscall.
         getpgid = 132,
+        /// Change working directory.
+        ///
         /// See [fchdir(2)](https://man7.org/linux/man-pages/man2/fchdir.2.html) for more info on this syscall.
         fchdir = 133,
+        /// Start, flush, or tune buffer-dirty-flush daemon.
+        ///
         /// See [bdflush(2)](https://man7.org/linux/man-pages/man2/bdflush.2.html) for more info on this syscall.
         bdflush = 134,
+        /// Get filesystem type information.
+        ///
         /// See [sysfs(2)](https://man7.org/linux/man-pages/man2/sysfs.2.html) for more info on this syscall.
         sysfs = 135,
+        /// Set the process execution domain.
+        ///
         /// See [personality(2)](https://man7.org/linux/man-pages/man2/personality.2.html) for more info on this syscall.
         personality = 136,
+        /// Set user identity used for filesystem checks.
+        ///
         /// See [setfsuid(2)](https://man7.org/linux/man-pages/man2/setfsuid.2.html) for more info on this syscall.
         setfsuid = 138,
+        /// Set group identity used for filesystem checks.
+        ///
         /// See [setfsgid(2)](https://man7.org/linux/man-pages/man2/setfsgid.2.html) for more info on this syscall.
         setfsgid = 139,
+        /// Reposition read/write file offset.
+        ///
         /// See [_llseek(2)](https://man7.org/linux/man-pages/man2/_llseek.2.html) for more info on this syscall.
         _llseek = 140,
+        /// Get directory entries.
+        ///
         /// See [getdents(2)](https://man7.org/linux/man-pages/man2/getdents.2.html) for more info on this syscall.
         getdents = 141,
         /// See [_newselect(2)](https://man7.org/linux/man-pages/man2/_newselect.2.html) for more info on this syscall.
         _newselect = 142,
+        /// Apply or remove an advisory lock on an open file.
+        ///
         /// See [flock(2)](https://man7.org/linux/man-pages/man2/flock.2.html) for more info on this syscall.
         flock = 143,
+        /// Synchronize a file with a memory map.
+        ///
         /// See [msync(2)](https://man7.org/linux/man-pages/man2/msync.2.html) for more info on this syscall.
         msync = 144,
+        /// Read or write data into multiple buffers.
+        ///
         /// See [readv(2)](https://man7.org/linux/man-pages/man2/readv.2.html) for more info on this syscall.
         readv = 145,
+        /// Read or write data into multiple buffers.
+        ///
         /// See [writev(2)](https://man7.org/linux/man-pages/man2/writev.2.html) for more info on this syscall.
         writev = 146,
+        /// Get session ID.
+        ///
         /// See [getsid(2)](https://man7.org/linux/man-pages/man2/getsid.2.html) for more info on this syscall.
         getsid = 147,
+        /// Synchronize a file's in-core state with storage device.
+        ///
         /// See [fdatasync(2)](https://man7.org/linux/man-pages/man2/fdatasync.2.html) for more info on this syscall.
         fdatasync = 148,
         /// See [_sysctl(2)](https://man7.org/linux/man-pages/man2/_sysctl.2.html) for more info on this syscall.
         _sysctl = 149,
+        /// Lock and unlock memory.
+        ///
         /// See [mlock(2)](https://man7.org/linux/man-pages/man2/mlock.2.html) for more info on this syscall.
         mlock = 150,
+        /// Lock and unlock memory.
+        ///
         /// See [munlock(2)](https://man7.org/linux/man-pages/man2/munlock.2.html) for more info on this syscall.
         munlock = 151,
+        /// Lock and unlock memory.
+        ///
         /// See [mlockall(2)](https://man7.org/linux/man-pages/man2/mlockall.2.html) for more info on this syscall.
         mlockall = 152,
+        /// Lock and unlock memory.
+        ///
         /// See [munlockall(2)](https://man7.org/linux/man-pages/man2/munlockall.2.html) for more info on this syscall.
         munlockall = 153,
+        /// Set and get scheduling parameters.
+        ///
         /// See [sched_setparam(2)](https://man7.org/linux/man-pages/man2/sched_setparam.2.html) for more info on this syscall.
         sched_setparam = 154,
+        /// Set and get scheduling parameters.
+        ///
         /// See [sched_getparam(2)](https://man7.org/linux/man-pages/man2/sched_getparam.2.html) for more info on this syscall.
         sched_getparam = 155,
+        /// Set and get scheduling policy/parameters.
+        ///
         /// See [sched_setscheduler(2)](https://man7.org/linux/man-pages/man2/sched_setscheduler.2.html) for more info on this syscall.
         sched_setscheduler = 156,
+        /// Set and get scheduling policy/parameters.
+        ///
         /// See [sched_getscheduler(2)](https://man7.org/linux/man-pages/man2/sched_getscheduler.2.html) for more info on this syscall.
         sched_getscheduler = 157,
+        /// Yield the processor.
+        ///
         /// See [sched_yield(2)](https://man7.org/linux/man-pages/man2/sched_yield.2.html) for more info on this syscall.
         sched_yield = 158,
+        /// Get static priority range.
+        ///
         /// See [sched_get_priority_max(2)](https://man7.org/linux/man-pages/man2/sched_get_priority_max.2.html) for more info on this syscall.
         sched_get_priority_max = 159,
+        /// Get static priority range.
+        ///
         /// See [sched_get_priority_min(2)](https://man7.org/linux/man-pages/man2/sched_get_priority_min.2.html) for more info on this syscall.
         sched_get_priority_min = 160,
+        /// Get the `SCHED_RR` interval for the named process.
+        ///
         /// See [sched_rr_get_interval(2)](https://man7.org/linux/man-pages/man2/sched_rr_get_interval.2.html) for more info on this syscall.
         sched_rr_get_interval = 161,
+        /// High-resolution sleep.
+        ///
         /// See [nanosleep(2)](https://man7.org/linux/man-pages/man2/nanosleep.2.html) for more info on this syscall.
         nanosleep = 162,
+        /// Remap a virtual memory address.
+        ///
         /// See [mremap(2)](https://man7.org/linux/man-pages/man2/mremap.2.html) for more info on this syscall.
         mremap = 163,
+        /// Set real, effective, and saved user or group ID.
+        ///
         /// See [setresuid(2)](https://man7.org/linux/man-pages/man2/setresuid.2.html) for more info on this syscall.
         setresuid = 164,
+        /// Get real, effective, and saved user/group IDs.
+        ///
         /// See [getresuid(2)](https://man7.org/linux/man-pages/man2/getresuid.2.html) for more info on this syscall.
         getresuid = 165,
+        /// Wait for some event on a file descriptor.
+        ///
         /// See [poll(2)](https://man7.org/linux/man-pages/man2/poll.2.html) for more info on this syscall.
         poll = 168,
         /// NOTE: `nfsservctl` is not implemented in the kernel.
         nfsservctl = 169,
+        /// Set real, effective, and saved user or group ID.
+        ///
         /// See [setresgid(2)](https://man7.org/linux/man-pages/man2/setresgid.2.html) for more info on this syscall.
         setresgid = 170,
+        /// Get real, effective, and saved user/group IDs.
+        ///
         /// See [getresgid(2)](https://man7.org/linux/man-pages/man2/getresgid.2.html) for more info on this syscall.
         getresgid = 171,
+        /// Operations on a process or thread.
+        ///
         /// See [prctl(2)](https://man7.org/linux/man-pages/man2/prctl.2.html) for more info on this syscall.
         prctl = 172,
+        /// Return from signal handler and cleanup stack frame.
+        ///
         /// See [rt_sigreturn(2)](https://man7.org/linux/man-pages/man2/rt_sigreturn.2.html) for more info on this syscall.
         rt_sigreturn = 173,
+        /// Examine and change a signal action.
+        ///
         /// See [rt_sigaction(2)](https://man7.org/linux/man-pages/man2/rt_sigaction.2.html) for more info on this syscall.
         rt_sigaction = 174,
+        /// Examine and change blocked signals.
+        ///
         /// See [rt_sigprocmask(2)](https://man7.org/linux/man-pages/man2/rt_sigprocmask.2.html) for more info on this syscall.
         rt_sigprocmask = 175,
+        /// Examine pending signals.
+        ///
         /// See [rt_sigpending(2)](https://man7.org/linux/man-pages/man2/rt_sigpending.2.html) for more info on this syscall.
         rt_sigpending = 176,
+        /// Synchronously wait for queued signals.
+        ///
         /// See [rt_sigtimedwait(2)](https://man7.org/linux/man-pages/man2/rt_sigtimedwait.2.html) for more info on this syscall.
         rt_sigtimedwait = 177,
+        /// Queue a signal and data.
+        ///
         /// See [rt_sigqueueinfo(2)](https://man7.org/linux/man-pages/man2/rt_sigqueueinfo.2.html) for more info on this syscall.
         rt_sigqueueinfo = 178,
+        /// Wait for a signal.
+        ///
         /// See [rt_sigsuspend(2)](https://man7.org/linux/man-pages/man2/rt_sigsuspend.2.html) for more info on this syscall.
         rt_sigsuspend = 179,
+        /// Read from or write to a file descriptor at a given offset.
+        ///
         /// See [pread64(2)](https://man7.org/linux/man-pages/man2/pread64.2.html) for more info on this syscall.
         pread64 = 180,
+        /// Read from or write to a file descriptor at a given offset.
+        ///
         /// See [pwrite64(2)](https://man7.org/linux/man-pages/man2/pwrite64.2.html) for more info on this syscall.
         pwrite64 = 181,
+        /// Change ownership of a file.
+        ///
         /// See [chown(2)](https://man7.org/linux/man-pages/man2/chown.2.html) for more info on this syscall.
         chown = 182,
+        /// Get current working directory.
+        ///
         /// See [getcwd(2)](https://man7.org/linux/man-pages/man2/getcwd.2.html) for more info on this syscall.
         getcwd = 183,
+        /// Set/get capabilities of thread(s).
+        ///
         /// See [capget(2)](https://man7.org/linux/man-pages/man2/capget.2.html) for more info on this syscall.
         capget = 184,
+        /// Set/get capabilities of thread(s).
+        ///
         /// See [capset(2)](https://man7.org/linux/man-pages/man2/capset.2.html) for more info on this syscall.
         capset = 185,
+        /// Set and/or get signal stack context.
+        ///
         /// See [sigaltstack(2)](https://man7.org/linux/man-pages/man2/sigaltstack.2.html) for more info on this syscall.
         sigaltstack = 186,
+        /// Transfer data between file descriptors.
+        ///
         /// See [sendfile(2)](https://man7.org/linux/man-pages/man2/sendfile.2.html) for more info on this syscall.
         sendfile = 187,
+        /// Create a child process and block parent.
+        ///
         /// See [vfork(2)](https://man7.org/linux/man-pages/man2/vfork.2.html) for more info on this syscall.
         vfork = 190,
         /// See [ugetrlimit(2)](https://man7.org/linux/man-pages/man2/ugetrlimit.2.html) for more info on this syscall.
         ugetrlimit = 191,
+        /// Map files or devices into memory.
+        ///
         /// See [mmap2(2)](https://man7.org/linux/man-pages/man2/mmap2.2.html) for more info on this syscall.
         mmap2 = 192,
+        /// Truncate a file to a specified length.
+        ///
         /// See [truncate64(2)](https://man7.org/linux/man-pages/man2/truncate64.2.html) for more info on this syscall.
         truncate64 = 193,
+        /// Truncate a file to a specified length.
+        ///
         /// See [ftruncate64(2)](https://man7.org/linux/man-pages/man2/ftruncate64.2.html) for more info on this syscall.
         ftruncate64 = 194,
+        /// Get file status.
+        ///
         /// See [stat64(2)](https://man7.org/linux/man-pages/man2/stat64.2.html) for more info on this syscall.
         stat64 = 195,
+        /// Get file status.
+        ///
         /// See [lstat64(2)](https://man7.org/linux/man-pages/man2/lstat64.2.html) for more info on this syscall.
         lstat64 = 196,
+        /// Get file status.
+        ///
         /// See [fstat64(2)](https://man7.org/linux/man-pages/man2/fstat64.2.html) for more info on this syscall.
         fstat64 = 197,
         /// See [lchown32(2)](https://man7.org/linux/man-pages/man2/lchown32.2.html) for more info on this syscall.
@@ -356,408 +660,792 @@ syscall_enum! {
         setfsuid32 = 215,
         /// See [setfsgid32(2)](https://man7.org/linux/man-pages/man2/setfsgid32.2.html) for more info on this syscall.
         setfsgid32 = 216,
+        /// Get directory entries.
+        ///
         /// See [getdents64(2)](https://man7.org/linux/man-pages/man2/getdents64.2.html) for more info on this syscall.
         getdents64 = 217,
+        /// Change the root mount.
+        ///
         /// See [pivot_root(2)](https://man7.org/linux/man-pages/man2/pivot_root.2.html) for more info on this syscall.
         pivot_root = 218,
+        /// Determine whether pages are resident in memory.
+        ///
         /// See [mincore(2)](https://man7.org/linux/man-pages/man2/mincore.2.html) for more info on this syscall.
         mincore = 219,
+        /// Give advice about use of memory.
+        ///
         /// See [madvise(2)](https://man7.org/linux/man-pages/man2/madvise.2.html) for more info on this syscall.
         madvise = 220,
+        /// Manipulate file descriptor.
+        ///
         /// See [fcntl64(2)](https://man7.org/linux/man-pages/man2/fcntl64.2.html) for more info on this syscall.
         fcntl64 = 221,
+        /// Get thread identification.
+        ///
         /// See [gettid(2)](https://man7.org/linux/man-pages/man2/gettid.2.html) for more info on this syscall.
         gettid = 224,
+        /// Initiate file readahead into page cache.
+        ///
         /// See [readahead(2)](https://man7.org/linux/man-pages/man2/readahead.2.html) for more info on this syscall.
         readahead = 225,
+        /// Set an extended attribute value.
+        ///
         /// See [setxattr(2)](https://man7.org/linux/man-pages/man2/setxattr.2.html) for more info on this syscall.
         setxattr = 226,
+        /// Set an extended attribute value.
+        ///
         /// See [lsetxattr(2)](https://man7.org/linux/man-pages/man2/lsetxattr.2.html) for more info on this syscall.
         lsetxattr = 227,
+        /// Set an extended attribute value.
+        ///
         /// See [fsetxattr(2)](https://man7.org/linux/man-pages/man2/fsetxattr.2.html) for more info on this syscall.
         fsetxattr = 228,
+        /// Retrieve an extended attribute value.
+        ///
         /// See [getxattr(2)](https://man7.org/linux/man-pages/man2/getxattr.2.html) for more info on this syscall.
         getxattr = 229,
+        /// Retrieve an extended attribute value.
+        ///
         /// See [lgetxattr(2)](https://man7.org/linux/man-pages/man2/lgetxattr.2.html) for more info on this syscall.
         lgetxattr = 230,
+        /// Retrieve an extended attribute value.
+        ///
         /// See [fgetxattr(2)](https://man7.org/linux/man-pages/man2/fgetxattr.2.html) for more info on this syscall.
         fgetxattr = 231,
+        /// List extended attribute names.
+        ///
         /// See [listxattr(2)](https://man7.org/linux/man-pages/man2/listxattr.2.html) for more info on this syscall.
         listxattr = 232,
+        /// List extended attribute names.
+        ///
         /// See [llistxattr(2)](https://man7.org/linux/man-pages/man2/llistxattr.2.html) for more info on this syscall.
         llistxattr = 233,
+        /// List extended attribute names.
+        ///
         /// See [flistxattr(2)](https://man7.org/linux/man-pages/man2/flistxattr.2.html) for more info on this syscall.
         flistxattr = 234,
+        /// Remove an extended attribute.
+        ///
         /// See [removexattr(2)](https://man7.org/linux/man-pages/man2/removexattr.2.html) for more info on this syscall.
         removexattr = 235,
+        /// Remove an extended attribute.
+        ///
         /// See [lremovexattr(2)](https://man7.org/linux/man-pages/man2/lremovexattr.2.html) for more info on this syscall.
         lremovexattr = 236,
+        /// Remove an extended attribute.
+        ///
         /// See [fremovexattr(2)](https://man7.org/linux/man-pages/man2/fremovexattr.2.html) for more info on this syscall.
         fremovexattr = 237,
+        /// Send a signal to a thread.
+        ///
         /// See [tkill(2)](https://man7.org/linux/man-pages/man2/tkill.2.html) for more info on this syscall.
         tkill = 238,
+        /// Transfer data between file descriptors.
+        ///
         /// See [sendfile64(2)](https://man7.org/linux/man-pages/man2/sendfile64.2.html) for more info on this syscall.
         sendfile64 = 239,
+        /// Fast user-space locking.
+        ///
         /// See [futex(2)](https://man7.org/linux/man-pages/man2/futex.2.html) for more info on this syscall.
         futex = 240,
+        /// Set and get a thread's CPU affinity mask.
+        ///
         /// See [sched_setaffinity(2)](https://man7.org/linux/man-pages/man2/sched_setaffinity.2.html) for more info on this syscall.
         sched_setaffinity = 241,
+        /// Set and get a thread's CPU affinity mask.
+        ///
         /// See [sched_getaffinity(2)](https://man7.org/linux/man-pages/man2/sched_getaffinity.2.html) for more info on this syscall.
         sched_getaffinity = 242,
+        /// Create an asynchronous I/O context.
+        ///
         /// See [io_setup(2)](https://man7.org/linux/man-pages/man2/io_setup.2.html) for more info on this syscall.
         io_setup = 243,
+        /// Destroy an asynchronous I/O context.
+        ///
         /// See [io_destroy(2)](https://man7.org/linux/man-pages/man2/io_destroy.2.html) for more info on this syscall.
         io_destroy = 244,
+        /// Read asynchronous I/O events from the completion queue.
+        ///
         /// See [io_getevents(2)](https://man7.org/linux/man-pages/man2/io_getevents.2.html) for more info on this syscall.
         io_getevents = 245,
+        /// Submit asynchronous I/O blocks for processing.
+        ///
         /// See [io_submit(2)](https://man7.org/linux/man-pages/man2/io_submit.2.html) for more info on this syscall.
         io_submit = 246,
+        /// Cancel an outstanding asynchronous I/O operation.
+        ///
         /// See [io_cancel(2)](https://man7.org/linux/man-pages/man2/io_cancel.2.html) for more info on this syscall.
         io_cancel = 247,
+        /// Exit all threads in a process.
+        ///
         /// See [exit_group(2)](https://man7.org/linux/man-pages/man2/exit_group.2.html) for more info on this syscall.
         exit_group = 248,
+        /// Return a directory entry's path.
+        ///
         /// See [lookup_dcookie(2)](https://man7.org/linux/man-pages/man2/lookup_dcookie.2.html) for more info on this syscall.
         lookup_dcookie = 249,
+        /// Open an epoll file descriptor.
+        ///
         /// See [epoll_create(2)](https://man7.org/linux/man-pages/man2/epoll_create.2.html) for more info on this syscall.
         epoll_create = 250,
+        /// Control interface for an epoll file descriptor.
+        ///
         /// See [epoll_ctl(2)](https://man7.org/linux/man-pages/man2/epoll_ctl.2.html) for more info on this syscall.
         epoll_ctl = 251,
+        /// Wait for an I/O event on an epoll file descriptor.
+        ///
         /// See [epoll_wait(2)](https://man7.org/linux/man-pages/man2/epoll_wait.2.html) for more info on this syscall.
         epoll_wait = 252,
+        /// Create a nonlinear file mapping.
+        ///
         /// See [remap_file_pages(2)](https://man7.org/linux/man-pages/man2/remap_file_pages.2.html) for more info on this syscall.
         remap_file_pages = 253,
+        /// Set pointer to thread ID.
+        ///
         /// See [set_tid_address(2)](https://man7.org/linux/man-pages/man2/set_tid_address.2.html) for more info on this syscall.
         set_tid_address = 256,
+        /// Create a POSIX per-process timer.
+        ///
         /// See [timer_create(2)](https://man7.org/linux/man-pages/man2/timer_create.2.html) for more info on this syscall.
         timer_create = 257,
+        /// Arm/disarm and fetch state of POSIX per-process timer.
+        ///
         /// See [timer_settime(2)](https://man7.org/linux/man-pages/man2/timer_settime.2.html) for more info on this syscall.
         timer_settime = 258,
+        /// Arm/disarm and fetch state of POSIX per-process timer.
+        ///
         /// See [timer_gettime(2)](https://man7.org/linux/man-pages/man2/timer_gettime.2.html) for more info on this syscall.
         timer_gettime = 259,
+        /// Get overrun count for a POSIX per-process timer.
+        ///
         /// See [timer_getoverrun(2)](https://man7.org/linux/man-pages/man2/timer_getoverrun.2.html) for more info on this syscall.
         timer_getoverrun = 260,
+        /// Delete a POSIX per-process timer.
+        ///
         /// See [timer_delete(2)](https://man7.org/linux/man-pages/man2/timer_delete.2.html) for more info on this syscall.
         timer_delete = 261,
+        /// Clock and time functions.
+        ///
         /// See [clock_settime(2)](https://man7.org/linux/man-pages/man2/clock_settime.2.html) for more info on this syscall.
         clock_settime = 262,
+        /// Clock and time functions.
+        ///
         /// See [clock_gettime(2)](https://man7.org/linux/man-pages/man2/clock_gettime.2.html) for more info on this syscall.
         clock_gettime = 263,
+        /// Clock and time functions.
+        ///
         /// See [clock_getres(2)](https://man7.org/linux/man-pages/man2/clock_getres.2.html) for more info on this syscall.
         clock_getres = 264,
+        /// High-resolution sleep with specifiable clock.
+        ///
         /// See [clock_nanosleep(2)](https://man7.org/linux/man-pages/man2/clock_nanosleep.2.html) for more info on this syscall.
         clock_nanosleep = 265,
+        /// Get filesystem statistics.
+        ///
         /// See [statfs64(2)](https://man7.org/linux/man-pages/man2/statfs64.2.html) for more info on this syscall.
         statfs64 = 266,
+        /// Get filesystem statistics.
+        ///
         /// See [fstatfs64(2)](https://man7.org/linux/man-pages/man2/fstatfs64.2.html) for more info on this syscall.
         fstatfs64 = 267,
+        /// Send a signal to a thread.
+        ///
         /// See [tgkill(2)](https://man7.org/linux/man-pages/man2/tgkill.2.html) for more info on this syscall.
         tgkill = 268,
+        /// Change file last access and modification times.
+        ///
         /// See [utimes(2)](https://man7.org/linux/man-pages/man2/utimes.2.html) for more info on this syscall.
         utimes = 269,
         /// See [arm_fadvise64_64(2)](https://man7.org/linux/man-pages/man2/arm_fadvise64_64.2.html) for more info on this syscall.
         arm_fadvise64_64 = 270,
+        /// Pci device information handling.
+        ///
         /// See [pciconfig_iobase(2)](https://man7.org/linux/man-pages/man2/pciconfig_iobase.2.html) for more info on this syscall.
         pciconfig_iobase = 271,
+        /// Pci device information handling.
+        ///
         /// See [pciconfig_read(2)](https://man7.org/linux/man-pages/man2/pciconfig_read.2.html) for more info on this syscall.
         pciconfig_read = 272,
+        /// Pci device information handling.
+        ///
         /// See [pciconfig_write(2)](https://man7.org/linux/man-pages/man2/pciconfig_write.2.html) for more info on this syscall.
         pciconfig_write = 273,
+        /// Open a message queue.
+        ///
         /// See [mq_open(2)](https://man7.org/linux/man-pages/man2/mq_open.2.html) for more info on this syscall.
         mq_open = 274,
+        /// Remove a message queue.
+        ///
         /// See [mq_unlink(2)](https://man7.org/linux/man-pages/man2/mq_unlink.2.html) for more info on this syscall.
         mq_unlink = 275,
+        /// Send a message to a message queue.
+        ///
         /// See [mq_timedsend(2)](https://man7.org/linux/man-pages/man2/mq_timedsend.2.html) for more info on this syscall.
         mq_timedsend = 276,
+        /// Receive a message from a message queue.
+        ///
         /// See [mq_timedreceive(2)](https://man7.org/linux/man-pages/man2/mq_timedreceive.2.html) for more info on this syscall.
         mq_timedreceive = 277,
+        /// Register for notification when a message is available.
+        ///
         /// See [mq_notify(2)](https://man7.org/linux/man-pages/man2/mq_notify.2.html) for more info on this syscall.
         mq_notify = 278,
+        /// Get/set message queue attributes.
+        ///
         /// See [mq_getsetattr(2)](https://man7.org/linux/man-pages/man2/mq_getsetattr.2.html) for more info on this syscall.
         mq_getsetattr = 279,
+        /// Wait for process to change state.
+        ///
         /// See [waitid(2)](https://man7.org/linux/man-pages/man2/waitid.2.html) for more info on this syscall.
         waitid = 280,
+        /// Create an endpoint for communication.
+        ///
         /// See [socket(2)](https://man7.org/linux/man-pages/man2/socket.2.html) for more info on this syscall.
         socket = 281,
+        /// Bind a name to a socket.
+        ///
         /// See [bind(2)](https://man7.org/linux/man-pages/man2/bind.2.html) for more info on this syscall.
         bind = 282,
+        /// Initiate a connection on a socket.
+        ///
         /// See [connect(2)](https://man7.org/linux/man-pages/man2/connect.2.html) for more info on this syscall.
         connect = 283,
+        /// Listen for connections on a socket.
+        ///
         /// See [listen(2)](https://man7.org/linux/man-pages/man2/listen.2.html) for more info on this syscall.
         listen = 284,
+        /// Accept a connection on a socket.
+        ///
         /// See [accept(2)](https://man7.org/linux/man-pages/man2/accept.2.html) for more info on this syscall.
         accept = 285,
+        /// Get socket name.
+        ///
         /// See [getsockname(2)](https://man7.org/linux/man-pages/man2/getsockname.2.html) for more info on this syscall.
         getsockname = 286,
+        /// Get name of connected peer socket.
+        ///
         /// See [getpeername(2)](https://man7.org/linux/man-pages/man2/getpeername.2.html) for more info on this syscall.
         getpeername = 287,
+        /// Create a pair of connected sockets.
+        ///
         /// See [socketpair(2)](https://man7.org/linux/man-pages/man2/socketpair.2.html) for more info on this syscall.
         socketpair = 288,
+        /// Send a message on a socket.
+        ///
         /// See [send(2)](https://man7.org/linux/man-pages/man2/send.2.html) for more info on this syscall.
         send = 289,
+        /// Send a message on a socket.
+        ///
         /// See [sendto(2)](https://man7.org/linux/man-pages/man2/sendto.2.html) for more info on this syscall.
         sendto = 290,
+        /// Receive a message from a socket.
+        ///
         /// See [recv(2)](https://man7.org/linux/man-pages/man2/recv.2.html) for more info on this syscall.
         recv = 291,
+        /// Receive a message from a socket.
+        ///
         /// See [recvfrom(2)](https://man7.org/linux/man-pages/man2/recvfrom.2.html) for more info on this syscall.
         recvfrom = 292,
+        /// Shut down part of a full-duplex connection.
+        ///
         /// See [shutdown(2)](https://man7.org/linux/man-pages/man2/shutdown.2.html) for more info on this syscall.
         shutdown = 293,
+        /// Get and set options on sockets.
+        ///
         /// See [setsockopt(2)](https://man7.org/linux/man-pages/man2/setsockopt.2.html) for more info on this syscall.
         setsockopt = 294,
+        /// Get and set options on sockets.
+        ///
         /// See [getsockopt(2)](https://man7.org/linux/man-pages/man2/getsockopt.2.html) for more info on this syscall.
         getsockopt = 295,
+        /// Send a message on a socket.
+        ///
         /// See [sendmsg(2)](https://man7.org/linux/man-pages/man2/sendmsg.2.html) for more info on this syscall.
         sendmsg = 296,
+        /// Receive a message from a socket.
+        ///
         /// See [recvmsg(2)](https://man7.org/linux/man-pages/man2/recvmsg.2.html) for more info on this syscall.
         recvmsg = 297,
+        /// System V semaphore operations.
+        ///
         /// See [semop(2)](https://man7.org/linux/man-pages/man2/semop.2.html) for more info on this syscall.
         semop = 298,
+        /// Get a System V semaphore set identifier.
+        ///
         /// See [semget(2)](https://man7.org/linux/man-pages/man2/semget.2.html) for more info on this syscall.
         semget = 299,
+        /// System V semaphore control operations.
+        ///
         /// See [semctl(2)](https://man7.org/linux/man-pages/man2/semctl.2.html) for more info on this syscall.
         semctl = 300,
+        /// System V message queue operations.
+        ///
         /// See [msgsnd(2)](https://man7.org/linux/man-pages/man2/msgsnd.2.html) for more info on this syscall.
         msgsnd = 301,
+        /// System V message queue operations.
+        ///
         /// See [msgrcv(2)](https://man7.org/linux/man-pages/man2/msgrcv.2.html) for more info on this syscall.
         msgrcv = 302,
+        /// Get a System V message queue identifier.
+        ///
         /// See [msgget(2)](https://man7.org/linux/man-pages/man2/msgget.2.html) for more info on this syscall.
         msgget = 303,
+        /// System V message control operations.
+        ///
         /// See [msgctl(2)](https://man7.org/linux/man-pages/man2/msgctl.2.html) for more info on this syscall.
         msgctl = 304,
+        /// System V shared memory operations.
+        ///
         /// See [shmat(2)](https://man7.org/linux/man-pages/man2/shmat.2.html) for more info on this syscall.
         shmat = 305,
+        /// System V shared memory operations.
+        ///
         /// See [shmdt(2)](https://man7.org/linux/man-pages/man2/shmdt.2.html) for more info on this syscall.
         shmdt = 306,
+        /// Allocates a System V shared memory segment.
+        ///
         /// See [shmget(2)](https://man7.org/linux/man-pages/man2/shmget.2.html) for more info on this syscall.
         shmget = 307,
+        /// System V shared memory control.
+        ///
         /// See [shmctl(2)](https://man7.org/linux/man-pages/man2/shmctl.2.html) for more info on this syscall.
         shmctl = 308,
+        /// Add a key to the kernel's key management facility.
+        ///
         /// See [add_key(2)](https://man7.org/linux/man-pages/man2/add_key.2.html) for more info on this syscall.
         add_key = 309,
+        /// Request a key from the kernel's key management facility.
+        ///
         /// See [request_key(2)](https://man7.org/linux/man-pages/man2/request_key.2.html) for more info on this syscall.
         request_key = 310,
+        /// Manipulate the kernel's key management facility.
+        ///
         /// See [keyctl(2)](https://man7.org/linux/man-pages/man2/keyctl.2.html) for more info on this syscall.
         keyctl = 311,
+        /// System V semaphore operations.
+        ///
         /// See [semtimedop(2)](https://man7.org/linux/man-pages/man2/semtimedop.2.html) for more info on this syscall.
         semtimedop = 312,
         /// NOTE: `vserver` is not implemented in the kernel.
         vserver = 313,
+        /// Get/set I/O scheduling class and priority.
+        ///
         /// See [ioprio_set(2)](https://man7.org/linux/man-pages/man2/ioprio_set.2.html) for more info on this syscall.
         ioprio_set = 314,
+        /// Get/set I/O scheduling class and priority.
+        ///
         /// See [ioprio_get(2)](https://man7.org/linux/man-pages/man2/ioprio_get.2.html) for more info on this syscall.
         ioprio_get = 315,
+        /// Initialize an inotify instance.
+        ///
         /// See [inotify_init(2)](https://man7.org/linux/man-pages/man2/inotify_init.2.html) for more info on this syscall.
         inotify_init = 316,
+        /// Add a watch to an initialized inotify instance.
+        ///
         /// See [inotify_add_watch(2)](https://man7.org/linux/man-pages/man2/inotify_add_watch.2.html) for more info on this syscall.
         inotify_add_watch = 317,
+        /// Remove an existing watch from an inotify instance.
+        ///
         /// See [inotify_rm_watch(2)](https://man7.org/linux/man-pages/man2/inotify_rm_watch.2.html) for more info on this syscall.
         inotify_rm_watch = 318,
+        /// Set memory policy for a memory range.
+        ///
         /// See [mbind(2)](https://man7.org/linux/man-pages/man2/mbind.2.html) for more info on this syscall.
         mbind = 319,
+        /// Retrieve NUMA memory policy for a thread.
+        ///
         /// See [get_mempolicy(2)](https://man7.org/linux/man-pages/man2/get_mempolicy.2.html) for more info on this syscall.
         get_mempolicy = 320,
+        /// Set default NUMA memory policy for a thread and its children.
+        ///
         /// See [set_mempolicy(2)](https://man7.org/linux/man-pages/man2/set_mempolicy.2.html) for more info on this syscall.
         set_mempolicy = 321,
+        /// Open and possibly create a file.
+        ///
         /// See [openat(2)](https://man7.org/linux/man-pages/man2/openat.2.html) for more info on this syscall.
         openat = 322,
+        /// Create a directory.
+        ///
         /// See [mkdirat(2)](https://man7.org/linux/man-pages/man2/mkdirat.2.html) for more info on this syscall.
         mkdirat = 323,
+        /// Create a special or ordinary file.
+        ///
         /// See [mknodat(2)](https://man7.org/linux/man-pages/man2/mknodat.2.html) for more info on this syscall.
         mknodat = 324,
+        /// Change ownership of a file.
+        ///
         /// See [fchownat(2)](https://man7.org/linux/man-pages/man2/fchownat.2.html) for more info on this syscall.
         fchownat = 325,
+        /// Change timestamps of a file relative to a directory file descriptor.
+        ///
         /// See [futimesat(2)](https://man7.org/linux/man-pages/man2/futimesat.2.html) for more info on this syscall.
         futimesat = 326,
+        /// Get file status.
+        ///
         /// See [fstatat64(2)](https://man7.org/linux/man-pages/man2/fstatat64.2.html) for more info on this syscall.
         fstatat64 = 327,
+        /// Delete a name and possibly the file it refers to.
+        ///
         /// See [unlinkat(2)](https://man7.org/linux/man-pages/man2/unlinkat.2.html) for more info on this syscall.
         unlinkat = 328,
+        /// Change the name or location of a file.
+        ///
         /// See [renameat(2)](https://man7.org/linux/man-pages/man2/renameat.2.html) for more info on this syscall.
         renameat = 329,
+        /// Make a new name for a file.
+        ///
         /// See [linkat(2)](https://man7.org/linux/man-pages/man2/linkat.2.html) for more info on this syscall.
         linkat = 330,
+        /// Make a new name for a file.
+        ///
         /// See [symlinkat(2)](https://man7.org/linux/man-pages/man2/symlinkat.2.html) for more info on this syscall.
         symlinkat = 331,
+        /// Read value of a symbolic link.
+        ///
         /// See [readlinkat(2)](https://man7.org/linux/man-pages/man2/readlinkat.2.html) for more info on this syscall.
         readlinkat = 332,
+        /// Change permissions of a file.
+        ///
         /// See [fchmodat(2)](https://man7.org/linux/man-pages/man2/fchmodat.2.html) for more info on this syscall.
         fchmodat = 333,
+        /// Check user's permissions for a file.
+        ///
         /// See [faccessat(2)](https://man7.org/linux/man-pages/man2/faccessat.2.html) for more info on this syscall.
         faccessat = 334,
         /// See [pselect6(2)](https://man7.org/linux/man-pages/man2/pselect6.2.html) for more info on this syscall.
         pselect6 = 335,
+        /// Wait for some event on a file descriptor.
+        ///
         /// See [ppoll(2)](https://man7.org/linux/man-pages/man2/ppoll.2.html) for more info on this syscall.
         ppoll = 336,
+        /// Disassociate parts of the process execution context.
+        ///
         /// See [unshare(2)](https://man7.org/linux/man-pages/man2/unshare.2.html) for more info on this syscall.
         unshare = 337,
+        /// Get/set list of robust futexes.
+        ///
         /// See [set_robust_list(2)](https://man7.org/linux/man-pages/man2/set_robust_list.2.html) for more info on this syscall.
         set_robust_list = 338,
+        /// Get/set list of robust futexes.
+        ///
         /// See [get_robust_list(2)](https://man7.org/linux/man-pages/man2/get_robust_list.2.html) for more info on this syscall.
         get_robust_list = 339,
+        /// Splice data to/from a pipe.
+        ///
         /// See [splice(2)](https://man7.org/linux/man-pages/man2/splice.2.html) for more info on this syscall.
         splice = 340,
         /// See [arm_sync_file_range(2)](https://man7.org/linux/man-pages/man2/arm_sync_file_range.2.html) for more info on this syscall.
         arm_sync_file_range = 341,
+        /// Duplicating pipe content.
+        ///
         /// See [tee(2)](https://man7.org/linux/man-pages/man2/tee.2.html) for more info on this syscall.
         tee = 342,
+        /// Splice user pages to/from a pipe.
+        ///
         /// See [vmsplice(2)](https://man7.org/linux/man-pages/man2/vmsplice.2.html) for more info on this syscall.
         vmsplice = 343,
+        /// Move individual pages of a process to another node.
+        ///
         /// See [move_pages(2)](https://man7.org/linux/man-pages/man2/move_pages.2.html) for more info on this syscall.
         move_pages = 344,
+        /// Determine CPU and NUMA node on which the calling thread is running.
+        ///
         /// See [getcpu(2)](https://man7.org/linux/man-pages/man2/getcpu.2.html) for more info on this syscall.
         getcpu = 345,
+        /// Wait for an I/O event on an epoll file descriptor.
+        ///
         /// See [epoll_pwait(2)](https://man7.org/linux/man-pages/man2/epoll_pwait.2.html) for more info on this syscall.
         epoll_pwait = 346,
+        /// Load a new kernel for later execution.
+        ///
         /// See [kexec_load(2)](https://man7.org/linux/man-pages/man2/kexec_load.2.html) for more info on this syscall.
         kexec_load = 347,
+        /// Change file timestamps with nanosecond precision.
+        ///
         /// See [utimensat(2)](https://man7.org/linux/man-pages/man2/utimensat.2.html) for more info on this syscall.
         utimensat = 348,
+        /// Create a file descriptor for accepting signals.
+        ///
         /// See [signalfd(2)](https://man7.org/linux/man-pages/man2/signalfd.2.html) for more info on this syscall.
         signalfd = 349,
+        /// Timers that notify via file descriptors.
+        ///
         /// See [timerfd_create(2)](https://man7.org/linux/man-pages/man2/timerfd_create.2.html) for more info on this syscall.
         timerfd_create = 350,
+        /// Create a file descriptor for event notification.
+        ///
         /// See [eventfd(2)](https://man7.org/linux/man-pages/man2/eventfd.2.html) for more info on this syscall.
         eventfd = 351,
+        /// Manipulate file space.
+        ///
         /// See [fallocate(2)](https://man7.org/linux/man-pages/man2/fallocate.2.html) for more info on this syscall.
         fallocate = 352,
+        /// Timers that notify via file descriptors.
+        ///
         /// See [timerfd_settime(2)](https://man7.org/linux/man-pages/man2/timerfd_settime.2.html) for more info on this syscall.
         timerfd_settime = 353,
+        /// Timers that notify via file descriptors.
+        ///
         /// See [timerfd_gettime(2)](https://man7.org/linux/man-pages/man2/timerfd_gettime.2.html) for more info on this syscall.
         timerfd_gettime = 354,
         /// See [signalfd4(2)](https://man7.org/linux/man-pages/man2/signalfd4.2.html) for more info on this syscall.
         signalfd4 = 355,
         /// See [eventfd2(2)](https://man7.org/linux/man-pages/man2/eventfd2.2.html) for more info on this syscall.
         eventfd2 = 356,
+        /// Open an epoll file descriptor.
+        ///
         /// See [epoll_create1(2)](https://man7.org/linux/man-pages/man2/epoll_create1.2.html) for more info on this syscall.
         epoll_create1 = 357,
+        /// Duplicate a file descriptor.
+        ///
         /// See [dup3(2)](https://man7.org/linux/man-pages/man2/dup3.2.html) for more info on this syscall.
         dup3 = 358,
+        /// Create pipe.
+        ///
         /// See [pipe2(2)](https://man7.org/linux/man-pages/man2/pipe2.2.html) for more info on this syscall.
         pipe2 = 359,
+        /// Initialize an inotify instance.
+        ///
         /// See [inotify_init1(2)](https://man7.org/linux/man-pages/man2/inotify_init1.2.html) for more info on this syscall.
         inotify_init1 = 360,
+        /// Read or write data into multiple buffers.
+        ///
         /// See [preadv(2)](https://man7.org/linux/man-pages/man2/preadv.2.html) for more info on this syscall.
         preadv = 361,
+        /// Read or write data into multiple buffers.
+        ///
         /// See [pwritev(2)](https://man7.org/linux/man-pages/man2/pwritev.2.html) for more info on this syscall.
         pwritev = 362,
+        /// Queue a signal and data.
+        ///
         /// See [rt_tgsigqueueinfo(2)](https://man7.org/linux/man-pages/man2/rt_tgsigqueueinfo.2.html) for more info on this syscall.
         rt_tgsigqueueinfo = 363,
+        /// Set up performance monitoring.
+        ///
         /// See [perf_event_open(2)](https://man7.org/linux/man-pages/man2/perf_event_open.2.html) for more info on this syscall.
         perf_event_open = 364,
+        /// Receive multiple messages on a socket.
+        ///
         /// See [recvmmsg(2)](https://man7.org/linux/man-pages/man2/recvmmsg.2.html) for more info on this syscall.
         recvmmsg = 365,
+        /// Accept a connection on a socket.
+        ///
         /// See [accept4(2)](https://man7.org/linux/man-pages/man2/accept4.2.html) for more info on this syscall.
         accept4 = 366,
+        /// Create and initialize fanotify group.
+        ///
         /// See [fanotify_init(2)](https://man7.org/linux/man-pages/man2/fanotify_init.2.html) for more info on this syscall.
         fanotify_init = 367,
+        /// Add, remove, or modify an fanotify mark on a filesystem object.
+        ///
         /// See [fanotify_mark(2)](https://man7.org/linux/man-pages/man2/fanotify_mark.2.html) for more info on this syscall.
         fanotify_mark = 368,
+        /// Get/set resource limits.
+        ///
         /// See [prlimit64(2)](https://man7.org/linux/man-pages/man2/prlimit64.2.html) for more info on this syscall.
         prlimit64 = 369,
+        /// Obtain handle for a pathname and open file via a handle.
+        ///
         /// See [name_to_handle_at(2)](https://man7.org/linux/man-pages/man2/name_to_handle_at.2.html) for more info on this syscall.
         name_to_handle_at = 370,
+        /// Obtain handle for a pathname and open file via a handle.
+        ///
         /// See [open_by_handle_at(2)](https://man7.org/linux/man-pages/man2/open_by_handle_at.2.html) for more info on this syscall.
         open_by_handle_at = 371,
+        /// Tune kernel clock.
+        ///
         /// See [clock_adjtime(2)](https://man7.org/linux/man-pages/man2/clock_adjtime.2.html) for more info on this syscall.
         clock_adjtime = 372,
+        /// Commit filesystem caches to disk.
+        ///
         /// See [syncfs(2)](https://man7.org/linux/man-pages/man2/syncfs.2.html) for more info on this syscall.
         syncfs = 373,
+        /// Send multiple messages on a socket.
+        ///
         /// See [sendmmsg(2)](https://man7.org/linux/man-pages/man2/sendmmsg.2.html) for more info on this syscall.
         sendmmsg = 374,
+        /// Reassociate thread with a namespace.
+        ///
         /// See [setns(2)](https://man7.org/linux/man-pages/man2/setns.2.html) for more info on this syscall.
         setns = 375,
+        /// Transfer data between process address spaces.
+        ///
         /// See [process_vm_readv(2)](https://man7.org/linux/man-pages/man2/process_vm_readv.2.html) for more info on this syscall.
         process_vm_readv = 376,
+        /// Transfer data between process address spaces.
+        ///
         /// See [process_vm_writev(2)](https://man7.org/linux/man-pages/man2/process_vm_writev.2.html) for more info on this syscall.
         process_vm_writev = 377,
+        /// Compare two processes to determine if they share a kernel resource.
+        ///
         /// See [kcmp(2)](https://man7.org/linux/man-pages/man2/kcmp.2.html) for more info on this syscall.
         kcmp = 378,
+        /// Load a kernel module.
+        ///
         /// See [finit_module(2)](https://man7.org/linux/man-pages/man2/finit_module.2.html) for more info on this syscall.
         finit_module = 379,
+        /// Set and get scheduling policy and attributes.
+        ///
         /// See [sched_setattr(2)](https://man7.org/linux/man-pages/man2/sched_setattr.2.html) for more info on this syscall.
         sched_setattr = 380,
+        /// Set and get scheduling policy and attributes.
+        ///
         /// See [sched_getattr(2)](https://man7.org/linux/man-pages/man2/sched_getattr.2.html) for more info on this syscall.
         sched_getattr = 381,
+        /// Change the name or location of a file.
+        ///
         /// See [renameat2(2)](https://man7.org/linux/man-pages/man2/renameat2.2.html) for more info on this syscall.
         renameat2 = 382,
+        /// Operate on Secure Computing state of the process.
+        ///
         /// See [seccomp(2)](https://man7.org/linux/man-pages/man2/seccomp.2.html) for more info on this syscall.
         seccomp = 383,
+        /// Obtain a series of random bytes.
+        ///
         /// See [getrandom(2)](https://man7.org/linux/man-pages/man2/getrandom.2.html) for more info on this syscall.
         getrandom = 384,
+        /// Create an anonymous file.
+        ///
         /// See [memfd_create(2)](https://man7.org/linux/man-pages/man2/memfd_create.2.html) for more info on this syscall.
         memfd_create = 385,
+        /// Perform a command on an extended BPF map or program.
+        ///
         /// See [bpf(2)](https://man7.org/linux/man-pages/man2/bpf.2.html) for more info on this syscall.
         bpf = 386,
+        /// Execute program relative to a directory file descriptor.
+        ///
         /// See [execveat(2)](https://man7.org/linux/man-pages/man2/execveat.2.html) for more info on this syscall.
         execveat = 387,
+        /// Create a file descriptor for handling page faults in user space.
+        ///
         /// See [userfaultfd(2)](https://man7.org/linux/man-pages/man2/userfaultfd.2.html) for more info on this syscall.
         userfaultfd = 388,
+        /// Issue memory barriers on a set of threads.
+        ///
         /// See [membarrier(2)](https://man7.org/linux/man-pages/man2/membarrier.2.html) for more info on this syscall.
         membarrier = 389,
+        /// Lock and unlock memory.
+        ///
         /// See [mlock2(2)](https://man7.org/linux/man-pages/man2/mlock2.2.html) for more info on this syscall.
         mlock2 = 390,
+        /// Copy a range of data from one file to another.
+        ///
         /// See [copy_file_range(2)](https://man7.org/linux/man-pages/man2/copy_file_range.2.html) for more info on this syscall.
         copy_file_range = 391,
+        /// Read or write data into multiple buffers.
+        ///
         /// See [preadv2(2)](https://man7.org/linux/man-pages/man2/preadv2.2.html) for more info on this syscall.
         preadv2 = 392,
+        /// Read or write data into multiple buffers.
+        ///
         /// See [pwritev2(2)](https://man7.org/linux/man-pages/man2/pwritev2.2.html) for more info on this syscall.
         pwritev2 = 393,
+        /// Set protection on a region of memory.
+        ///
         /// See [pkey_mprotect(2)](https://man7.org/linux/man-pages/man2/pkey_mprotect.2.html) for more info on this syscall.
         pkey_mprotect = 394,
+        /// Allocate or free a protection key.
+        ///
         /// See [pkey_alloc(2)](https://man7.org/linux/man-pages/man2/pkey_alloc.2.html) for more info on this syscall.
         pkey_alloc = 395,
+        /// Allocate or free a protection key.
+        ///
         /// See [pkey_free(2)](https://man7.org/linux/man-pages/man2/pkey_free.2.html) for more info on this syscall.
         pkey_free = 396,
+        /// Get file status (extended).
+        ///
         /// See [statx(2)](https://man7.org/linux/man-pages/man2/statx.2.html) for more info on this syscall.
         statx = 397,
         /// See [rseq(2)](https://man7.org/linux/man-pages/man2/rseq.2.html) for more info on this syscall.
         rseq = 398,
         /// See [io_pgetevents(2)](https://man7.org/linux/man-pages/man2/io_pgetevents.2.html) for more info on this syscall.
         io_pgetevents = 399,
+        /// Move all pages in a process to another set of nodes.
+        ///
         /// See [migrate_pages(2)](https://man7.org/linux/man-pages/man2/migrate_pages.2.html) for more info on this syscall.
         migrate_pages = 400,
+        /// Load a new kernel for later execution.
+        ///
         /// See [kexec_file_load(2)](https://man7.org/linux/man-pages/man2/kexec_file_load.2.html) for more info on this syscall.
         kexec_file_load = 401,
+        /// Clock and time functions.
+        ///
         /// See [clock_gettime64(2)](https://man7.org/linux/man-pages/man2/clock_gettime64.2.html) for more info on this syscall.
         clock_gettime64 = 403,
+        /// Clock and time functions.
+        ///
         /// See [clock_settime64(2)](https://man7.org/linux/man-pages/man2/clock_settime64.2.html) for more info on this syscall.
         clock_settime64 = 404,
+        /// Tune kernel clock.
+        ///
         /// See [clock_adjtime64(2)](https://man7.org/linux/man-pages/man2/clock_adjtime64.2.html) for more info on this syscall.
         clock_adjtime64 = 405,
+        /// Clock and time functions.
+        ///
         /// See [clock_getres_time64(2)](https://man7.org/linux/man-pages/man2/clock_getres_time64.2.html) for more info on this syscall.
         clock_getres_time64 = 406,
+        /// High-resolution sleep with specifiable clock.
+        ///
         /// See [clock_nanosleep_time64(2)](https://man7.org/linux/man-pages/man2/clock_nanosleep_time64.2.html) for more info on this syscall.
         clock_nanosleep_time64 = 407,
+        /// Arm/disarm and fetch state of POSIX per-process timer.
+        ///
         /// See [timer_gettime64(2)](https://man7.org/linux/man-pages/man2/timer_gettime64.2.html) for more info on this syscall.
         timer_gettime64 = 408,
+        /// Arm/disarm and fetch state of POSIX per-process timer.
+        ///
         /// See [timer_settime64(2)](https://man7.org/linux/man-pages/man2/timer_settime64.2.html) for more info on this syscall.
         timer_settime64 = 409,
+        /// Timers that notify via file descriptors.
+        ///
         /// See [timerfd_gettime64(2)](https://man7.org/linux/man-pages/man2/timerfd_gettime64.2.html) for more info on this syscall.
         timerfd_gettime64 = 410,
+        /// Timers that notify via file descriptors.
+        ///
         /// See [timerfd_settime64(2)](https://man7.org/linux/man-pages/man2/timerfd_settime64.2.html) for more info on this syscall.
         timerfd_settime64 = 411,
+        /// Change file timestamps with nanosecond precision.
+        ///
         /// See [utimensat_time64(2)](https://man7.org/linux/man-pages/man2/utimensat_time64.2.html) for more info on this syscall.
         utimensat_time64 = 412,
         /// See [pselect6_time64(2)](https://man7.org/linux/man-pages/man2/pselect6_time64.2.html) for more info on this syscall.
         pselect6_time64 = 413,
+        /// Wait for some event on a file descriptor.
+        ///
         /// See [ppoll_time64(2)](https://man7.org/linux/man-pages/man2/ppoll_time64.2.html) for more info on this syscall.
         ppoll_time64 = 414,
         /// See [io_pgetevents_time64(2)](https://man7.org/linux/man-pages/man2/io_pgetevents_time64.2.html) for more info on this syscall.
         io_pgetevents_time64 = 416,
+        /// Receive multiple messages on a socket.
+        ///
         /// See [recvmmsg_time64(2)](https://man7.org/linux/man-pages/man2/recvmmsg_time64.2.html) for more info on this syscall.
         recvmmsg_time64 = 417,
+        /// Send a message to a message queue.
+        ///
         /// See [mq_timedsend_time64(2)](https://man7.org/linux/man-pages/man2/mq_timedsend_time64.2.html) for more info on this syscall.
         mq_timedsend_time64 = 418,
+        /// Receive a message from a message queue.
+        ///
         /// See [mq_timedreceive_time64(2)](https://man7.org/linux/man-pages/man2/mq_timedreceive_time64.2.html) for more info on this syscall.
         mq_timedreceive_time64 = 419,
+        /// System V semaphore operations.
+        ///
         /// See [semtimedop_time64(2)](https://man7.org/linux/man-pages/man2/semtimedop_time64.2.html) for more info on this syscall.
         semtimedop_time64 = 420,
+        /// Synchronously wait for queued signals.
+        ///
         /// See [rt_sigtimedwait_time64(2)](https://man7.org/linux/man-pages/man2/rt_sigtimedwait_time64.2.html) for more info on this syscall.
         rt_sigtimedwait_time64 = 421,
+        /// Fast user-space locking.
+        ///
         /// See [futex_time64(2)](https://man7.org/linux/man-pages/man2/futex_time64.2.html) for more info on this syscall.
         futex_time64 = 422,
+        /// Get the `SCHED_RR` interval for the named process.
+        ///
         /// See [sched_rr_get_interval_time64(2)](https://man7.org/linux/man-pages/man2/sched_rr_get_interval_time64.2.html) for more info on this syscall.
         sched_rr_get_interval_time64 = 423,
+        /// Send a signal to a process specified by a file descriptor.
+        ///
         /// See [pidfd_send_signal(2)](https://man7.org/linux/man-pages/man2/pidfd_send_signal.2.html) for more info on this syscall.
         pidfd_send_signal = 424,
         /// See [io_uring_setup(2)](https://man7.org/linux/man-pages/man2/io_uring_setup.2.html) for more info on this syscall.
@@ -778,30 +1466,54 @@ syscall_enum! {
         fsmount = 432,
         /// See [fspick(2)](https://man7.org/linux/man-pages/man2/fspick.2.html) for more info on this syscall.
         fspick = 433,
+        /// Obtain a file descriptor that refers to a process.
+        ///
         /// See [pidfd_open(2)](https://man7.org/linux/man-pages/man2/pidfd_open.2.html) for more info on this syscall.
         pidfd_open = 434,
+        /// Create a child process.
+        ///
         /// See [clone3(2)](https://man7.org/linux/man-pages/man2/clone3.2.html) for more info on this syscall.
         clone3 = 435,
+        /// Close all file descriptors in a given range.
+        ///
         /// See [close_range(2)](https://man7.org/linux/man-pages/man2/close_range.2.html) for more info on this syscall.
         close_range = 436,
+        /// Open and possibly create a file (extended).
+        ///
         /// See [openat2(2)](https://man7.org/linux/man-pages/man2/openat2.2.html) for more info on this syscall.
         openat2 = 437,
+        /// Obtain a duplicate of another process's file descriptor.
+        ///
         /// See [pidfd_getfd(2)](https://man7.org/linux/man-pages/man2/pidfd_getfd.2.html) for more info on this syscall.
         pidfd_getfd = 438,
+        /// Check user's permissions for a file.
+        ///
         /// See [faccessat2(2)](https://man7.org/linux/man-pages/man2/faccessat2.2.html) for more info on this syscall.
         faccessat2 = 439,
+        /// Give advice about use of memory to a process.
+        ///
         /// See [process_madvise(2)](https://man7.org/linux/man-pages/man2/process_madvise.2.html) for more info on this syscall.
         process_madvise = 440,
+        /// Wait for an I/O event on an epoll file descriptor.
+        ///
         /// See [epoll_pwait2(2)](https://man7.org/linux/man-pages/man2/epoll_pwait2.2.html) for more info on this syscall.
         epoll_pwait2 = 441,
+        /// Change properties of a mount or mount tree.
+        ///
         /// See [mount_setattr(2)](https://man7.org/linux/man-pages/man2/mount_setattr.2.html) for more info on this syscall.
         mount_setattr = 442,
         /// See [quotactl_fd(2)](https://man7.org/linux/man-pages/man2/quotactl_fd.2.html) for more info on this syscall.
         quotactl_fd = 443,
+        /// Create a new Landlock ruleset.
+        ///
         /// See [landlock_create_ruleset(2)](https://man7.org/linux/man-pages/man2/landlock_create_ruleset.2.html) for more info on this syscall.
         landlock_create_ruleset = 444,
+        /// Add a new Landlock rule to a ruleset.
+        ///
         /// See [landlock_add_rule(2)](https://man7.org/linux/man-pages/man2/landlock_add_rule.2.html) for more info on this syscall.
         landlock_add_rule = 445,
+        /// Enforce a Landlock ruleset.
+        ///
         /// See [landlock_restrict_self(2)](https://man7.org/linux/man-pages/man2/landlock_restrict_self.2.html) for more info on this syscall.
         landlock_restrict_self = 446,
         /// See [process_mrelease(2)](https://man7.org/linux/man-pages/man2/process_mrelease.2.html) for more info on this syscall.
@@ -836,4 +1548,2714 @@ syscall_enum! {
         mseal = 462,
     }
     LAST: mseal;
+    SIGNATURES: signatures;
+}
+
+/// Syscall signatures from the kernel's `SYSCALL_DEFINEn` definitions,
+/// indexed by `id - Sysno::first().id()`.
+pub(crate) mod signatures {
+    /// Number of arguments, or `None` for gaps in the table and syscalls
+    /// without a known definition.
+    pub(crate) static ARG_COUNTS: [Option<u8>; 463] = [
+        Some(0), // restart_syscall
+        Some(1), // exit
+        Some(0), // fork
+        Some(3), // read
+        Some(3), // write
+        Some(3), // open
+        Some(1), // close
+        None,
+        Some(2), // creat
+        Some(2), // link
+        Some(1), // unlink
+        Some(3), // execve
+        Some(1), // chdir
+        None,
+        Some(3), // mknod
+        Some(2), // chmod
+        Some(3), // lchown
+        None,
+        None,
+        Some(3), // lseek
+        Some(0), // getpid
+        Some(5), // mount
+        None,
+        Some(1), // setuid
+        Some(0), // getuid
+        None,
+        Some(4), // ptrace
+        None,
+        None,
+        Some(0), // pause
+        None,
+        None,
+        None,
+        Some(2), // access
+        Some(1), // nice
+        None,
+        Some(0), // sync
+        Some(2), // kill
+        Some(2), // rename
+        Some(2), // mkdir
+        Some(1), // rmdir
+        Some(1), // dup
+        Some(1), // pipe
+        Some(1), // times
+        None,
+        Some(1), // brk
+        Some(1), // setgid
+        Some(0), // getgid
+        None,
+        Some(0), // geteuid
+        Some(0), // getegid
+        Some(1), // acct
+        Some(2), // umount2
+        None,
+        Some(3), // ioctl
+        Some(3), // fcntl
+        None,
+        Some(2), // setpgid
+        None,
+        None,
+        Some(1), // umask
+        Some(1), // chroot
+        Some(2), // ustat
+        Some(2), // dup2
+        Some(0), // getppid
+        Some(0), // getpgrp
+        Some(0), // setsid
+        Some(3), // sigaction
+        None,
+        None,
+        Some(2), // setreuid
+        Some(2), // setregid
+        Some(3), // sigsuspend
+        Some(1), // sigpending
+        Some(2), // sethostname
+        Some(2), // setrlimit
+        None,
+        Some(2), // getrusage
+        Some(2), // gettimeofday
+        Some(2), // settimeofday
+        Some(2), // getgroups
+        Some(2), // setgroups
+        None,
+        Some(2), // symlink
+        None,
+        Some(3), // readlink
+        None,    // uselib
+        Some(2), // swapon
+        Some(4), // reboot
+        None,
+        None,
+        Some(2), // munmap
+        Some(2), // truncate
+        Some(2), // ftruncate
+        Some(2), // fchmod
+        Some(3), // fchown
+        Some(2), // getpriority
+        Some(3), // setpriority
+        None,
+        Some(2), // statfs
+        Some(2), // fstatfs
+        None,
+        None,
+        Some(3), // syslog
+        Some(3), // setitimer
+        Some(2), // getitimer
+        Some(2), // stat
+        Some(2), // lstat
+        Some(2), // fstat
+        None,
+        None,
+        Some(0), // vhangup
+        None,
+        None,
+        Some(4), // wait4
+        Some(1), // swapoff
+        Some(1), // sysinfo
+        None,
+        Some(1), // fsync
+        None,    // sigreturn
+        Some(5), // clone
+        Some(2), // setdomainname
+        Some(1), // uname
+        None,
+        Some(1), // adjtimex
+        Some(3), // mprotect
+        Some(3), // sigprocmask
+        None,
+        Some(3), // init_module
+        Some(2), // delete_module
+        None,
+        Some(4), // quotactl
+        Some(1), // getpgid
+        Some(1), // fchdir
+        Some(2), // bdflush
+        Some(3), // sysfs
+        Some(1), // personality
+        None,
+        Some(1), // setfsuid
+        Some(1), // setfsgid
+        Some(5), // _llseek
+        Some(3), // getdents
+        Some(5), // _newselect
+        Some(2), // flock
+        Some(3), // msync
+        Some(3), // readv
+        Some(3), // writev
+        Some(1), // getsid
+        Some(1), // fdatasync
+        None,    // _sysctl
+        Some(2), // mlock
+        Some(2), // munlock
+        Some(1), // mlockall
+        Some(0), // munlockall
+        Some(2), // sched_setparam
+        Some(2), // sched_getparam
+        Some(3), // sched_setscheduler
+        Some(1), // sched_getscheduler
+        Some(0), // sched_yield
+        Some(1), // sched_get_priority_max
+        Some(1), // sched_get_priority_min
+        Some(2), // sched_rr_get_interval
+        Some(2), // nanosleep
+        Some(5), // mremap
+        Some(3), // setresuid
+        Some(3), // getresuid
+        None,
+        None,
+        Some(3), // poll
+        None,    // nfsservctl
+        Some(3), // setresgid
+        Some(3), // getresgid
+        Some(5), // prctl
+        None,    // rt_sigreturn
+        Some(4), // rt_sigaction
+        Some(4), // rt_sigprocmask
+        Some(2), // rt_sigpending
+        Some(4), // rt_sigtimedwait
+        Some(3), // rt_sigqueueinfo
+        Some(2), // rt_sigsuspend
+        Some(4), // pread64
+        Some(4), // pwrite64
+        Some(3), // chown
+        Some(2), // getcwd
+        Some(2), // capget
+        Some(2), // capset
+        Some(2), // sigaltstack
+        Some(4), // sendfile
+        None,
+        None,
+        Some(0), // vfork
+        Some(2), // ugetrlimit
+        None,    // mmap2
+        Some(2), // truncate64
+        Some(2), // ftruncate64
+        Some(2), // stat64
+        Some(2), // lstat64
+        Some(2), // fstat64
+        Some(3), // lchown32
+        Some(0), // getuid32
+        Some(0), // getgid32
+        Some(0), // geteuid32
+        Some(0), // getegid32
+        Some(2), // setreuid32
+        Some(2), // setregid32
+        Some(2), // getgroups32
+        Some(2), // setgroups32
+        Some(3), // fchown32
+        Some(3), // setresuid32
+        Some(3), // getresuid32
+        Some(3), // setresgid32
+        Some(3), // getresgid32
+        Some(3), // chown32
+        Some(1), // setuid32
+        Some(1), // setgid32
+        Some(1), // setfsuid32
+        Some(1), // setfsgid32
+        Some(3), // getdents64
+        Some(2), // pivot_root
+        Some(3), // mincore
+        Some(3), // madvise
+        Some(3), // fcntl64
+        None,
+        None,
+        Some(0), // gettid
+        Some(3), // readahead
+        Some(5), // setxattr
+        Some(5), // lsetxattr
+        Some(5), // fsetxattr
+        Some(4), // getxattr
+        Some(4), // lgetxattr
+        Some(4), // fgetxattr
+        Some(3), // listxattr
+        Some(3), // llistxattr
+        Some(3), // flistxattr
+        Some(2), // removexattr
+        Some(2), // lremovexattr
+        Some(2), // fremovexattr
+        Some(2), // tkill
+        Some(4), // sendfile64
+        Some(6), // futex
+        Some(3), // sched_setaffinity
+        Some(3), // sched_getaffinity
+        Some(2), // io_setup
+        Some(1), // io_destroy
+        Some(5), // io_getevents
+        Some(3), // io_submit
+        Some(3), // io_cancel
+        Some(1), // exit_group
+        None,    // lookup_dcookie
+        Some(1), // epoll_create
+        Some(4), // epoll_ctl
+        Some(4), // epoll_wait
+        Some(5), // remap_file_pages
+        None,
+        None,
+        Some(1), // set_tid_address
+        Some(3), // timer_create
+        Some(4), // timer_settime
+        Some(2), // timer_gettime
+        Some(1), // timer_getoverrun
+        Some(1), // timer_delete
+        Some(2), // clock_settime
+        Some(2), // clock_gettime
+        Some(2), // clock_getres
+        Some(4), // clock_nanosleep
+        Some(3), // statfs64
+        Some(3), // fstatfs64
+        Some(3), // tgkill
+        Some(2), // utimes
+        Some(4), // arm_fadvise64_64
+        None,    // pciconfig_iobase
+        Some(5), // pciconfig_read
+        Some(5), // pciconfig_write
+        Some(4), // mq_open
+        Some(1), // mq_unlink
+        Some(5), // mq_timedsend
+        Some(5), // mq_timedreceive
+        Some(2), // mq_notify
+        Some(3), // mq_getsetattr
+        Some(5), // waitid
+        Some(3), // socket
+        Some(3), // bind
+        Some(3), // connect
+        Some(2), // listen
+        Some(3), // accept
+        Some(3), // getsockname
+        Some(3), // getpeername
+        Some(4), // socketpair
+        Some(4), // send
+        Some(6), // sendto
+        Some(4), // recv
+        Some(6), // recvfrom
+        Some(2), // shutdown
+        Some(5), // setsockopt
+        Some(5), // getsockopt
+        Some(3), // sendmsg
+        Some(3), // recvmsg
+        Some(3), // semop
+        Some(3), // semget
+        Some(4), // semctl
+        Some(4), // msgsnd
+        Some(5), // msgrcv
+        Some(2), // msgget
+        Some(3), // msgctl
+        Some(3), // shmat
+        Some(1), // shmdt
+        Some(3), // shmget
+        Some(3), // shmctl
+        Some(5), // add_key
+        Some(4), // request_key
+        Some(5), // keyctl
+        Some(4), // semtimedop
+        None,    // vserver
+        Some(3), // ioprio_set
+        Some(2), // ioprio_get
+        Some(0), // inotify_init
+        Some(3), // inotify_add_watch
+        Some(2), // inotify_rm_watch
+        Some(6), // mbind
+        Some(5), // get_mempolicy
+        Some(3), // set_mempolicy
+        Some(4), // openat
+        Some(3), // mkdirat
+        Some(4), // mknodat
+        Some(5), // fchownat
+        Some(3), // futimesat
+        Some(4), // fstatat64
+        Some(3), // unlinkat
+        Some(4), // renameat
+        Some(5), // linkat
+        Some(3), // symlinkat
+        Some(4), // readlinkat
+        Some(3), // fchmodat
+        Some(3), // faccessat
+        Some(6), // pselect6
+        Some(5), // ppoll
+        Some(1), // unshare
+        Some(2), // set_robust_list
+        Some(3), // get_robust_list
+        Some(6), // splice
+        Some(4), // arm_sync_file_range
+        Some(4), // tee
+        Some(4), // vmsplice
+        Some(6), // move_pages
+        Some(3), // getcpu
+        Some(6), // epoll_pwait
+        Some(4), // kexec_load
+        Some(4), // utimensat
+        Some(3), // signalfd
+        Some(2), // timerfd_create
+        Some(1), // eventfd
+        Some(4), // fallocate
+        Some(4), // timerfd_settime
+        Some(2), // timerfd_gettime
+        Some(4), // signalfd4
+        Some(2), // eventfd2
+        Some(1), // epoll_create1
+        Some(3), // dup3
+        Some(2), // pipe2
+        Some(1), // inotify_init1
+        Some(5), // preadv
+        Some(5), // pwritev
+        Some(4), // rt_tgsigqueueinfo
+        Some(5), // perf_event_open
+        Some(5), // recvmmsg
+        Some(4), // accept4
+        Some(2), // fanotify_init
+        Some(5), // fanotify_mark
+        Some(4), // prlimit64
+        Some(5), // name_to_handle_at
+        Some(3), // open_by_handle_at
+        Some(2), // clock_adjtime
+        Some(1), // syncfs
+        Some(4), // sendmmsg
+        Some(2), // setns
+        Some(6), // process_vm_readv
+        Some(6), // process_vm_writev
+        Some(5), // kcmp
+        Some(3), // finit_module
+        Some(3), // sched_setattr
+        Some(4), // sched_getattr
+        Some(5), // renameat2
+        Some(3), // seccomp
+        Some(3), // getrandom
+        Some(2), // memfd_create
+        Some(3), // bpf
+        Some(5), // execveat
+        Some(1), // userfaultfd
+        Some(3), // membarrier
+        Some(3), // mlock2
+        Some(6), // copy_file_range
+        Some(6), // preadv2
+        Some(6), // pwritev2
+        Some(4), // pkey_mprotect
+        Some(2), // pkey_alloc
+        Some(1), // pkey_free
+        Some(5), // statx
+        Some(4), // rseq
+        Some(6), // io_pgetevents
+        Some(4), // migrate_pages
+        Some(5), // kexec_file_load
+        None,
+        Some(2), // clock_gettime64
+        Some(2), // clock_settime64
+        Some(2), // clock_adjtime64
+        Some(2), // clock_getres_time64
+        Some(4), // clock_nanosleep_time64
+        Some(2), // timer_gettime64
+        Some(4), // timer_settime64
+        Some(2), // timerfd_gettime64
+        Some(4), // timerfd_settime64
+        Some(4), // utimensat_time64
+        Some(6), // pselect6_time64
+        Some(5), // ppoll_time64
+        None,
+        Some(6), // io_pgetevents_time64
+        Some(5), // recvmmsg_time64
+        Some(5), // mq_timedsend_time64
+        Some(5), // mq_timedreceive_time64
+        Some(4), // semtimedop_time64
+        Some(4), // rt_sigtimedwait_time64
+        Some(6), // futex_time64
+        Some(2), // sched_rr_get_interval_time64
+        Some(4), // pidfd_send_signal
+        Some(2), // io_uring_setup
+        Some(6), // io_uring_enter
+        Some(4), // io_uring_register
+        Some(3), // open_tree
+        Some(5), // move_mount
+        Some(2), // fsopen
+        Some(5), // fsconfig
+        Some(3), // fsmount
+        Some(3), // fspick
+        Some(2), // pidfd_open
+        Some(2), // clone3
+        Some(3), // close_range
+        Some(4), // openat2
+        Some(3), // pidfd_getfd
+        Some(4), // faccessat2
+        Some(5), // process_madvise
+        Some(6), // epoll_pwait2
+        Some(5), // mount_setattr
+        Some(4), // quotactl_fd
+        Some(3), // landlock_create_ruleset
+        Some(4), // landlock_add_rule
+        Some(2), // landlock_restrict_self
+        None,
+        Some(2), // process_mrelease
+        Some(5), // futex_waitv
+        Some(4), // set_mempolicy_home_node
+        Some(4), // cachestat
+        Some(4), // fchmodat2
+        None,    // map_shadow_stack
+        Some(4), // futex_wake
+        Some(6), // futex_wait
+        Some(4), // futex_requeue
+        Some(4), // statmount
+        Some(4), // listmount
+        Some(4), // lsm_get_self_attr
+        Some(4), // lsm_set_self_attr
+        Some(3), // lsm_list_modules
+        Some(3), // mseal
+    ];
+
+    /// Argument types, as written in the kernel source.
+    #[allow(dead_code)]
+    pub(crate) static ARG_TYPES: [&[&str]; 463] = [
+        &[],                                                // restart_syscall
+        &["int"],                                           // exit
+        &[],                                                // fork
+        &["unsigned int", "char __user *", "size_t"],       // read
+        &["unsigned int", "const char __user *", "size_t"], // write
+        &["const char __user *", "int", "umode_t"],         // open
+        &["unsigned int"],                                  // close
+        &[],
+        &["const char __user *", "umode_t"], // creat
+        &["const char __user *", "const char __user *"], // link
+        &["const char __user *"],            // unlink
+        &[
+            "const char __user *",
+            "const char __user *const __user *",
+            "const char __user *const __user *",
+        ], // execve
+        &["const char __user *"],            // chdir
+        &[],
+        &["const char __user *", "umode_t", "unsigned"], // mknod
+        &["const char __user *", "umode_t"],             // chmod
+        &["const char __user *", "old_uid_t", "old_gid_t"], // lchown
+        &[],
+        &[],
+        &["unsigned int", "off_t", "unsigned int"], // lseek
+        &[],                                        // getpid
+        &[
+            "char __user *",
+            "char __user *",
+            "char __user *",
+            "unsigned long",
+            "void __user *",
+        ], // mount
+        &[],
+        &["old_uid_t"], // setuid
+        &[],            // getuid
+        &[],
+        &["long", "long", "unsigned long", "unsigned long"], // ptrace
+        &[],
+        &[],
+        &[], // pause
+        &[],
+        &[],
+        &[],
+        &["const char __user *", "int"], // access
+        &["int"],                        // nice
+        &[],
+        &[],                                             // sync
+        &["pid_t", "int"],                               // kill
+        &["const char __user *", "const char __user *"], // rename
+        &["const char __user *", "umode_t"],             // mkdir
+        &["const char __user *"],                        // rmdir
+        &["unsigned int"],                               // dup
+        &["int __user *"],                               // pipe
+        &["struct tms __user *"],                        // times
+        &[],
+        &["unsigned long"], // brk
+        &["old_gid_t"],     // setgid
+        &[],                // getgid
+        &[],
+        &[],                       // geteuid
+        &[],                       // getegid
+        &["const char __user *"],  // acct
+        &["char __user *", "int"], // umount2
+        &[],
+        &["unsigned int", "unsigned int", "unsigned long"], // ioctl
+        &["unsigned int", "unsigned int", "unsigned long"], // fcntl
+        &[],
+        &["pid_t", "pid_t"], // setpgid
+        &[],
+        &[],
+        &["int"],                               // umask
+        &["const char __user *"],               // chroot
+        &["unsigned", "struct ustat __user *"], // ustat
+        &["unsigned int", "unsigned int"],      // dup2
+        &[],                                    // getppid
+        &[],                                    // getpgrp
+        &[],                                    // setsid
+        &[
+            "int",
+            "const struct old_sigaction __user *",
+            "struct old_sigaction __user *",
+        ], // sigaction
+        &[],
+        &[],
+        &["old_uid_t", "old_uid_t"],     // setreuid
+        &["old_gid_t", "old_gid_t"],     // setregid
+        &["int", "int", "old_sigset_t"], // sigsuspend
+        &["old_sigset_t __user *"],      // sigpending
+        &["char __user *", "int"],       // sethostname
+        &["unsigned int", "struct rlimit __user *"], // setrlimit
+        &[],
+        &["int", "struct rusage __user *"], // getrusage
+        &[
+            "struct __kernel_old_timeval __user *",
+            "struct timezone __user *",
+        ], // gettimeofday
+        &[
+            "struct __kernel_old_timeval __user *",
+            "struct timezone __user *",
+        ], // settimeofday
+        &["int", "old_gid_t __user *"],     // getgroups
+        &["int", "old_gid_t __user *"],     // setgroups
+        &[],
+        &["const char __user *", "const char __user *"], // symlink
+        &[],
+        &["const char __user *", "char __user *", "int"], // readlink
+        &[],                                              // uselib
+        &["const char __user *", "int"],                  // swapon
+        &["int", "int", "unsigned int", "void __user *"], // reboot
+        &[],
+        &[],
+        &["unsigned long", "size_t"],     // munmap
+        &["const char __user *", "long"], // truncate
+        &["unsigned int", "off_t"],       // ftruncate
+        &["unsigned int", "umode_t"],     // fchmod
+        &["unsigned int", "old_uid_t", "old_gid_t"], // fchown
+        &["int", "int"],                  // getpriority
+        &["int", "int", "int"],           // setpriority
+        &[],
+        &["const char __user *", "struct statfs __user *"], // statfs
+        &["unsigned int", "struct statfs __user *"],        // fstatfs
+        &[],
+        &[],
+        &["int", "char __user *", "int"], // syslog
+        &[
+            "int",
+            "struct __kernel_old_itimerval __user *",
+            "struct __kernel_old_itimerval __user *",
+        ], // setitimer
+        &["int", "struct __kernel_old_itimerval __user *"], // getitimer
+        &["const char __user *", "struct stat __user *"], // stat
+        &["const char __user *", "struct stat __user *"], // lstat
+        &["unsigned int", "struct stat __user *"], // fstat
+        &[],
+        &[],
+        &[], // vhangup
+        &[],
+        &[],
+        &["pid_t", "int __user *", "int", "struct rusage __user *"], // wait4
+        &["const char __user *"],                                    // swapoff
+        &["struct sysinfo __user *"],                                // sysinfo
+        &[],
+        &["unsigned int"], // fsync
+        &[],               // sigreturn
+        &[
+            "unsigned long",
+            "unsigned long",
+            "int __user *",
+            "int __user *",
+            "unsigned long",
+        ], // clone
+        &["char __user *", "int"], // setdomainname
+        &["struct new_utsname __user *"], // uname
+        &[],
+        &["struct old_timex32 __user *"], // adjtimex
+        &["unsigned long", "size_t", "unsigned long"], // mprotect
+        &["int", "old_sigset_t __user *", "old_sigset_t __user *"], // sigprocmask
+        &[],
+        &["void __user *", "unsigned long", "const char __user *"], // init_module
+        &["const char __user *", "unsigned int"], // delete_module
+        &[],
+        &[
+            "unsigned int",
+            "const char __user *",
+            "qid_t",
+            "void __user *",
+        ], // quotactl
+        &["pid_t"],                                 // getpgid
+        &["unsigned int"],                          // fchdir
+        &["int", "long"],                           // bdflush
+        &["int", "unsigned long", "unsigned long"], // sysfs
+        &["unsigned int"],                          // personality
+        &[],
+        &["old_uid_t"], // setfsuid
+        &["old_gid_t"], // setfsgid
+        &[
+            "unsigned int",
+            "unsigned long",
+            "unsigned long",
+            "loff_t __user *",
+            "unsigned int",
+        ], // _llseek
+        &[
+            "unsigned int",
+            "struct linux_dirent __user *",
+            "unsigned int",
+        ], // getdents
+        &[
+            "int",
+            "fd_set __user *",
+            "fd_set __user *",
+            "fd_set __user *",
+            "struct __kernel_old_timeval __user *",
+        ], // _newselect
+        &["unsigned int", "unsigned int"], // flock
+        &["unsigned long", "size_t", "int"], // msync
+        &[
+            "unsigned long",
+            "const struct iovec __user *",
+            "unsigned long",
+        ], // readv
+        &[
+            "unsigned long",
+            "const struct iovec __user *",
+            "unsigned long",
+        ], // writev
+        &["pid_t"],     // getsid
+        &["unsigned int"], // fdatasync
+        &[],            // _sysctl
+        &["unsigned long", "size_t"], // mlock
+        &["unsigned long", "size_t"], // munlock
+        &["int"],       // mlockall
+        &[],            // munlockall
+        &["pid_t", "struct sched_param __user *"], // sched_setparam
+        &["pid_t", "struct sched_param __user *"], // sched_getparam
+        &["pid_t", "int", "struct sched_param __user *"], // sched_setscheduler
+        &["pid_t"],     // sched_getscheduler
+        &[],            // sched_yield
+        &["int"],       // sched_get_priority_max
+        &["int"],       // sched_get_priority_min
+        &["pid_t", "struct old_timespec32 __user *"], // sched_rr_get_interval
+        &[
+            "struct old_timespec32 __user *",
+            "struct old_timespec32 __user *",
+        ], // nanosleep
+        &[
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+        ], // mremap
+        &["old_uid_t", "old_uid_t", "old_uid_t"], // setresuid
+        &[
+            "old_uid_t __user *",
+            "old_uid_t __user *",
+            "old_uid_t __user *",
+        ], // getresuid
+        &[],
+        &[],
+        &["struct pollfd __user *", "unsigned int", "int"], // poll
+        &[],                                                // nfsservctl
+        &["old_gid_t", "old_gid_t", "old_gid_t"],           // setresgid
+        &[
+            "old_gid_t __user *",
+            "old_gid_t __user *",
+            "old_gid_t __user *",
+        ], // getresgid
+        &[
+            "int",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+        ], // prctl
+        &[],                                                // rt_sigreturn
+        &[
+            "int",
+            "const struct sigaction __user *",
+            "struct sigaction __user *",
+            "size_t",
+        ], // rt_sigaction
+        &["int", "sigset_t __user *", "sigset_t __user *", "size_t"], // rt_sigprocmask
+        &["sigset_t __user *", "size_t"], // rt_sigpending
+        &[
+            "const sigset_t __user *",
+            "siginfo_t __user *",
+            "const struct old_timespec32 __user *",
+            "size_t",
+        ], // rt_sigtimedwait
+        &["pid_t", "int", "siginfo_t __user *"], // rt_sigqueueinfo
+        &["sigset_t __user *", "size_t"], // rt_sigsuspend
+        &["unsigned int", "char __user *", "size_t", "loff_t"], // pread64
+        &["unsigned int", "const char __user *", "size_t", "loff_t"], // pwrite64
+        &["const char __user *", "old_uid_t", "old_gid_t"],           // chown
+        &["char __user *", "unsigned long"],                          // getcwd
+        &["cap_user_header_t", "cap_user_data_t"],                    // capget
+        &["cap_user_header_t", "const cap_user_data_t"],              // capset
+        &["const stack_t __user *", "stack_t __user *"], // sigaltstack
+        &["int", "int", "off_t __user *", "size_t"],     // sendfile
+        &[],
+        &[],
+        &[],                                                     // vfork
+        &["unsigned int", "struct rlimit __user *"],             // ugetrlimit
+        &[],                                                     // mmap2
+        &["const char __user *", "loff_t"],                      // truncate64
+        &["unsigned int", "loff_t"],                             // ftruncate64
+        &["const char __user *", "struct stat64 __user *"],      // stat64
+        &["const char __user *", "struct stat64 __user *"],      // lstat64
+        &["unsigned long", "struct stat64 __user *"],            // fstat64
+        &["const char __user *", "uid_t", "gid_t"],              // lchown32
+        &[],                                                     // getuid32
+        &[],                                                     // getgid32
+        &[],                                                     // geteuid32
+        &[],                                                     // getegid32
+        &["uid_t", "uid_t"],                                     // setreuid32
+        &["gid_t", "gid_t"],                                     // setregid32
+        &["int", "gid_t __user *"],                              // getgroups32
+        &["int", "gid_t __user *"],                              // setgroups32
+        &["unsigned int", "uid_t", "gid_t"],                     // fchown32
+        &["uid_t", "uid_t", "uid_t"],                            // setresuid32
+        &["uid_t __user *", "uid_t __user *", "uid_t __user *"], // getresuid32
+        &["gid_t", "gid_t", "gid_t"],                            // setresgid32
+        &["gid_t __user *", "gid_t __user *", "gid_t __user *"], // getresgid32
+        &["const char __user *", "uid_t", "gid_t"],              // chown32
+        &["uid_t"],                                              // setuid32
+        &["gid_t"],                                              // setgid32
+        &["uid_t"],                                              // setfsuid32
+        &["gid_t"],                                              // setfsgid32
+        &[
+            "unsigned int",
+            "struct linux_dirent64 __user *",
+            "unsigned int",
+        ], // getdents64
+        &["const char __user *", "const char __user *"],         // pivot_root
+        &["unsigned long", "size_t", "unsigned char __user *"],  // mincore
+        &["unsigned long", "size_t", "int"],                     // madvise
+        &["unsigned int", "unsigned int", "unsigned long"],      // fcntl64
+        &[],
+        &[],
+        &[],                          // gettid
+        &["int", "loff_t", "size_t"], // readahead
+        &[
+            "const char __user *",
+            "const char __user *",
+            "const void __user *",
+            "size_t",
+            "int",
+        ], // setxattr
+        &[
+            "const char __user *",
+            "const char __user *",
+            "const void __user *",
+            "size_t",
+            "int",
+        ], // lsetxattr
+        &[
+            "int",
+            "const char __user *",
+            "const void __user *",
+            "size_t",
+            "int",
+        ], // fsetxattr
+        &[
+            "const char __user *",
+            "const char __user *",
+            "void __user *",
+            "size_t",
+        ], // getxattr
+        &[
+            "const char __user *",
+            "const char __user *",
+            "void __user *",
+            "size_t",
+        ], // lgetxattr
+        &["int", "const char __user *", "void __user *", "size_t"], // fgetxattr
+        &["const char __user *", "char __user *", "size_t"], // listxattr
+        &["const char __user *", "char __user *", "size_t"], // llistxattr
+        &["int", "char __user *", "size_t"], // flistxattr
+        &["const char __user *", "const char __user *"], // removexattr
+        &["const char __user *", "const char __user *"], // lremovexattr
+        &["int", "const char __user *"], // fremovexattr
+        &["pid_t", "int"],            // tkill
+        &["int", "int", "loff_t __user *", "size_t"], // sendfile64
+        &[
+            "u32 __user *",
+            "int",
+            "u32",
+            "const struct old_timespec32 __user *",
+            "u32 __user *",
+            "u32",
+        ], // futex
+        &["pid_t", "unsigned int", "unsigned long __user *"], // sched_setaffinity
+        &["pid_t", "unsigned int", "unsigned long __user *"], // sched_getaffinity
+        &["unsigned", "aio_context_t __user *"],              // io_setup
+        &["aio_context_t"],                                   // io_destroy
+        &[
+            "__u32",
+            "__s32",
+            "__s32",
+            "struct io_event __user *",
+            "struct old_timespec32 __user *",
+        ], // io_getevents
+        &["aio_context_t", "long", "struct iocb __user * __user *"], // io_submit
+        &[
+            "aio_context_t",
+            "struct iocb __user *",
+            "struct io_event __user *",
+        ], // io_cancel
+        &["int"], // exit_group
+        &[],      // lookup_dcookie
+        &["int"], // epoll_create
+        &["int", "int", "int", "struct epoll_event __user *"], // epoll_ctl
+        &["int", "struct epoll_event __user *", "int", "int"], // epoll_wait
+        &[
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+        ], // remap_file_pages
+        &[],
+        &[],
+        &["int __user *"], // set_tid_address
+        &[
+            "const clockid_t",
+            "struct sigevent __user *",
+            "timer_t __user *",
+        ], // timer_create
+        &[
+            "timer_t",
+            "int",
+            "struct old_itimerspec32 __user *",
+            "struct old_itimerspec32 __user *",
+        ], // timer_settime
+        &["timer_t", "struct old_itimerspec32 __user *"], // timer_gettime
+        &["timer_t"],      // timer_getoverrun
+        &["timer_t"],      // timer_delete
+        &["clockid_t", "struct old_timespec32 __user *"], // clock_settime
+        &["clockid_t", "struct old_timespec32 __user *"], // clock_gettime
+        &["clockid_t", "struct old_timespec32 __user *"], // clock_getres
+        &[
+            "clockid_t",
+            "int",
+            "struct old_timespec32 __user *",
+            "struct old_timespec32 __user *",
+        ], // clock_nanosleep
+        &["const char __user *", "size_t", "struct statfs64 __user *"], // statfs64
+        &["unsigned int", "size_t", "struct statfs64 __user *"], // fstatfs64
+        &["pid_t", "pid_t", "int"],                              // tgkill
+        &["const char __user *", "struct old_timeval32 __user *"], // utimes
+        &["int", "loff_t", "loff_t", "int"], // arm_fadvise64_64
+        &[],                                 // pciconfig_iobase
+        &[
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+            "void __user *",
+        ], // pciconfig_read
+        &[
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+            "void __user *",
+        ], // pciconfig_write
+        &[
+            "const char __user *",
+            "int",
+            "umode_t",
+            "struct mq_attr __user *",
+        ], // mq_open
+        &["const char __user *"],            // mq_unlink
+        &[
+            "mqd_t",
+            "const char __user *",
+            "unsigned int",
+            "unsigned int",
+            "const struct old_timespec32 __user *",
+        ], // mq_timedsend
+        &[
+            "mqd_t",
+            "char __user *",
+            "unsigned int",
+            "unsigned int __user *",
+            "const struct old_timespec32 __user *",
+        ], // mq_timedreceive
+        &["mqd_t", "const struct sigevent __user *"], // mq_notify
+        &[
+            "mqd_t",
+            "const struct mq_attr __user *",
+            "struct mq_attr __user *",
+        ], // mq_getsetattr
+        &[
+            "int",
+            "pid_t",
+            "struct siginfo __user *",
+            "int",
+            "struct rusage __user *",
+        ], // waitid
+        &["int", "int", "int"],              // socket
+        &["int", "struct sockaddr __user *", "int"], // bind
+        &["int", "struct sockaddr __user *", "int"], // connect
+        &["int", "int"],                     // listen
+        &["int", "struct sockaddr __user *", "int __user *"], // accept
+        &["int", "struct sockaddr __user *", "int __user *"], // getsockname
+        &["int", "struct sockaddr __user *", "int __user *"], // getpeername
+        &["int", "int", "int", "int __user *"], // socketpair
+        &["int", "void __user *", "size_t", "unsigned int"], // send
+        &[
+            "int",
+            "void __user *",
+            "size_t",
+            "unsigned int",
+            "struct sockaddr __user *",
+            "int",
+        ], // sendto
+        &["int", "void __user *", "size_t", "unsigned int"], // recv
+        &[
+            "int",
+            "void __user *",
+            "size_t",
+            "unsigned int",
+            "struct sockaddr __user *",
+            "int __user *",
+        ], // recvfrom
+        &["int", "int"],                     // shutdown
+        &["int", "int", "int", "char __user *", "int"], // setsockopt
+        &["int", "int", "int", "char __user *", "int __user *"], // getsockopt
+        &["int", "struct user_msghdr __user *", "unsigned int"], // sendmsg
+        &["int", "struct user_msghdr __user *", "unsigned int"], // recvmsg
+        &["int", "struct sembuf __user *", "unsigned"], // semop
+        &["key_t", "int", "int"],            // semget
+        &["int", "int", "int", "unsigned long"], // semctl
+        &["int", "struct msgbuf __user *", "size_t", "int"], // msgsnd
+        &["int", "struct msgbuf __user *", "size_t", "long", "int"], // msgrcv
+        &["key_t", "int"],                   // msgget
+        &["int", "int", "struct msqid_ds __user *"], // msgctl
+        &["int", "char __user *", "int"],    // shmat
+        &["char __user *"],                  // shmdt
+        &["key_t", "size_t", "int"],         // shmget
+        &["int", "int", "struct shmid_ds __user *"], // shmctl
+        &[
+            "const char __user *",
+            "const char __user *",
+            "const void __user *",
+            "size_t",
+            "key_serial_t",
+        ], // add_key
+        &[
+            "const char __user *",
+            "const char __user *",
+            "const char __user *",
+            "key_serial_t",
+        ], // request_key
+        &[
+            "int",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+        ], // keyctl
+        &[
+            "int",
+            "struct sembuf __user *",
+            "unsigned int",
+            "const struct old_timespec32 __user *",
+        ], // semtimedop
+        &[],                                 // vserver
+        &["int", "int", "int"],              // ioprio_set
+        &["int", "int"],                     // ioprio_get
+        &[],                                 // inotify_init
+        &["int", "const char __user *", "u32"], // inotify_add_watch
+        &["int", "__s32"],                   // inotify_rm_watch
+        &[
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+            "const unsigned long __user *",
+            "unsigned long",
+            "unsigned int",
+        ], // mbind
+        &[
+            "int __user *",
+            "unsigned long __user *",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+        ], // get_mempolicy
+        &["int", "const unsigned long __user *", "unsigned long"], // set_mempolicy
+        &["int", "const char __user *", "int", "umode_t"],         // openat
+        &["int", "const char __user *", "umode_t"],                // mkdirat
+        &["int", "const char __user *", "umode_t", "unsigned int"], // mknodat
+        &["int", "const char __user *", "uid_t", "gid_t", "int"],  // fchownat
+        &[
+            "unsigned int",
+            "const char __user *",
+            "struct old_timeval32 __user *",
+        ], // futimesat
+        &[
+            "int",
+            "const char __user *",
+            "struct stat64 __user *",
+            "int",
+        ], // fstatat64
+        &["int", "const char __user *", "int"],                    // unlinkat
+        &["int", "const char __user *", "int", "const char __user *"], // renameat
+        &[
+            "int",
+            "const char __user *",
+            "int",
+            "const char __user *",
+            "int",
+        ], // linkat
+        &["const char __user *", "int", "const char __user *"], // symlinkat
+        &["int", "const char __user *", "char __user *", "int"], // readlinkat
+        &["int", "const char __user *", "umode_t"],             // fchmodat
+        &["int", "const char __user *", "int"],                 // faccessat
+        &[
+            "int",
+            "fd_set __user *",
+            "fd_set __user *",
+            "fd_set __user *",
+            "struct old_timespec32 __user *",
+            "void __user *",
+        ], // pselect6
+        &[
+            "struct pollfd __user *",
+            "unsigned int",
+            "struct old_timespec32 __user *",
+            "const sigset_t __user *",
+            "size_t",
+        ], // ppoll
+        &["unsigned long"],                                     // unshare
+        &["struct robust_list_head __user *", "size_t"], // set_robust_list
+        &[
+            "int",
+            "struct robust_list_head __user * __user *",
+            "size_t __user *",
+        ], // get_robust_list
+        &[
+            "int",
+            "loff_t __user *",
+            "int",
+            "loff_t __user *",
+            "size_t",
+            "unsigned int",
+        ], // splice
+        &["int", "unsigned int", "loff_t", "loff_t"],    // arm_sync_file_range
+        &["int", "int", "size_t", "unsigned int"],       // tee
+        &[
+            "int",
+            "const struct iovec __user *",
+            "unsigned long",
+            "unsigned int",
+        ], // vmsplice
+        &[
+            "pid_t",
+            "unsigned long",
+            "const void __user * __user *",
+            "const int __user *",
+            "int __user *",
+            "int",
+        ], // move_pages
+        &[
+            "unsigned __user *",
+            "unsigned __user *",
+            "struct getcpu_cache __user *",
+        ], // getcpu
+        &[
+            "int",
+            "struct epoll_event __user *",
+            "int",
+            "int",
+            "const sigset_t __user *",
+            "size_t",
+        ], // epoll_pwait
+        &[
+            "unsigned long",
+            "unsigned long",
+            "struct kexec_segment __user *",
+            "unsigned long",
+        ], // kexec_load
+        &[
+            "unsigned int",
+            "const char __user *",
+            "struct old_timespec32 __user *",
+            "int",
+        ], // utimensat
+        &["int", "sigset_t __user *", "size_t"],         // signalfd
+        &["int", "int"],                                 // timerfd_create
+        &["unsigned int"],                               // eventfd
+        &["int", "int", "loff_t", "loff_t"],             // fallocate
+        &[
+            "int",
+            "int",
+            "const struct old_itimerspec32 __user *",
+            "struct old_itimerspec32 __user *",
+        ], // timerfd_settime
+        &["int", "struct old_itimerspec32 __user *"],    // timerfd_gettime
+        &["int", "sigset_t __user *", "size_t", "int"],  // signalfd4
+        &["unsigned int", "int"],                        // eventfd2
+        &["int"],                                        // epoll_create1
+        &["unsigned int", "unsigned int", "int"],        // dup3
+        &["int __user *", "int"],                        // pipe2
+        &["int"],                                        // inotify_init1
+        &[
+            "unsigned long",
+            "const struct iovec __user *",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+        ], // preadv
+        &[
+            "unsigned long",
+            "const struct iovec __user *",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+        ], // pwritev
+        &["pid_t", "pid_t", "int", "siginfo_t __user *"], // rt_tgsigqueueinfo
+        &[
+            "struct perf_event_attr __user *",
+            "pid_t",
+            "int",
+            "int",
+            "unsigned long",
+        ], // perf_event_open
+        &[
+            "int",
+            "struct mmsghdr __user *",
+            "unsigned int",
+            "unsigned int",
+            "struct old_timespec32 __user *",
+        ], // recvmmsg
+        &["int", "struct sockaddr __user *", "int __user *", "int"], // accept4
+        &["unsigned int", "unsigned int"],               // fanotify_init
+        &["int", "unsigned int", "__u64", "int", "const char __user *"], // fanotify_mark
+        &[
+            "pid_t",
+            "unsigned int",
+            "const struct rlimit64 __user *",
+            "struct rlimit64 __user *",
+        ], // prlimit64
+        &[
+            "int",
+            "const char __user *",
+            "struct file_handle __user *",
+            "int __user *",
+            "int",
+        ], // name_to_handle_at
+        &["int", "struct file_handle __user *", "int"], // open_by_handle_at
+        &["clockid_t", "struct old_timex32 __user *"],  // clock_adjtime
+        &["int"],                                       // syncfs
+        &[
+            "int",
+            "struct mmsghdr __user *",
+            "unsigned int",
+            "unsigned int",
+        ], // sendmmsg
+        &["int", "int"],                                // setns
+        &[
+            "pid_t",
+            "const struct iovec __user *",
+            "unsigned long",
+            "const struct iovec __user *",
+            "unsigned long",
+            "unsigned long",
+        ], // process_vm_readv
+        &[
+            "pid_t",
+            "const struct iovec __user *",
+            "unsigned long",
+            "const struct iovec __user *",
+            "unsigned long",
+            "unsigned long",
+        ], // process_vm_writev
+        &["pid_t", "pid_t", "int", "unsigned long", "unsigned long"], // kcmp
+        &["int", "const char __user *", "int"],         // finit_module
+        &["pid_t", "struct sched_attr __user *", "unsigned int"], // sched_setattr
+        &[
+            "pid_t",
+            "struct sched_attr __user *",
+            "unsigned int",
+            "unsigned int",
+        ], // sched_getattr
+        &[
+            "int",
+            "const char __user *",
+            "int",
+            "const char __user *",
+            "unsigned int",
+        ], // renameat2
+        &["unsigned int", "unsigned int", "void __user *"],       // seccomp
+        &["char __user *", "size_t", "unsigned int"],             // getrandom
+        &["const char __user *", "unsigned int"], // memfd_create
+        &["int", "union bpf_attr __user *", "unsigned int"], // bpf
+        &[
+            "int",
+            "const char __user *",
+            "const char __user *const __user *",
+            "const char __user *const __user *",
+            "int",
+        ], // execveat
+        &["int"],                                 // userfaultfd
+        &["int", "unsigned int", "int"],          // membarrier
+        &["unsigned long", "size_t", "int"],      // mlock2
+        &[
+            "int",
+            "loff_t __user *",
+            "int",
+            "loff_t __user *",
+            "size_t",
+            "unsigned int",
+        ], // copy_file_range
+        &[
+            "unsigned long",
+            "const struct iovec __user *",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+            "rwf_t",
+        ], // preadv2
+        &[
+            "unsigned long",
+            "const struct iovec __user *",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+            "rwf_t",
+        ], // pwritev2
+        &["unsigned long", "size_t", "unsigned long", "int"], // pkey_mprotect
+        &["unsigned long", "unsigned long"],      // pkey_alloc
+        &["int"],                                 // pkey_free
+        &[
+            "int",
+            "const char __user *",
+            "unsigned",
+            "unsigned int",
+            "struct statx __user *",
+        ], // statx
+        &["struct rseq __user *", "u32", "int", "u32"], // rseq
+        &[
+            "aio_context_t",
+            "long",
+            "long",
+            "struct io_event __user *",
+            "struct old_timespec32 __user *",
+            "const struct __aio_sigset __user *",
+        ], // io_pgetevents
+        &[
+            "pid_t",
+            "unsigned long",
+            "const unsigned long __user *",
+            "const unsigned long __user *",
+        ], // migrate_pages
+        &[
+            "int",
+            "int",
+            "unsigned long",
+            "const char __user *",
+            "unsigned long",
+        ], // kexec_file_load
+        &[],
+        &["const clockid_t", "struct __kernel_timespec __user *"], // clock_gettime64
+        &["const clockid_t", "const struct __kernel_timespec __user *"], // clock_settime64
+        &["const clockid_t", "struct __kernel_timex __user *"], // clock_adjtime64
+        &["const clockid_t", "struct __kernel_timespec __user *"], // clock_getres_time64
+        &[
+            "const clockid_t",
+            "int",
+            "const struct __kernel_timespec __user *",
+            "struct __kernel_timespec __user *",
+        ], // clock_nanosleep_time64
+        &["timer_t", "struct __kernel_itimerspec __user *"], // timer_gettime64
+        &[
+            "timer_t",
+            "int",
+            "const struct __kernel_itimerspec __user *",
+            "struct __kernel_itimerspec __user *",
+        ], // timer_settime64
+        &["int", "struct __kernel_itimerspec __user *"], // timerfd_gettime64
+        &[
+            "int",
+            "int",
+            "const struct __kernel_itimerspec __user *",
+            "struct __kernel_itimerspec __user *",
+        ], // timerfd_settime64
+        &[
+            "int",
+            "const char __user *",
+            "struct __kernel_timespec __user *",
+            "int",
+        ], // utimensat_time64
+        &[
+            "int",
+            "fd_set __user *",
+            "fd_set __user *",
+            "fd_set __user *",
+            "struct __kernel_timespec __user *",
+            "void __user *",
+        ], // pselect6_time64
+        &[
+            "struct pollfd __user *",
+            "unsigned int",
+            "struct __kernel_timespec __user *",
+            "const sigset_t __user *",
+            "size_t",
+        ], // ppoll_time64
+        &[],
+        &[
+            "aio_context_t",
+            "long",
+            "long",
+            "struct io_event __user *",
+            "struct __kernel_timespec __user *",
+            "const struct __aio_sigset __user *",
+        ], // io_pgetevents_time64
+        &[
+            "int",
+            "struct mmsghdr __user *",
+            "unsigned int",
+            "unsigned int",
+            "struct __kernel_timespec __user *",
+        ], // recvmmsg_time64
+        &[
+            "mqd_t",
+            "const char __user *",
+            "size_t",
+            "unsigned int",
+            "const struct __kernel_timespec __user *",
+        ], // mq_timedsend_time64
+        &[
+            "mqd_t",
+            "char __user *",
+            "size_t",
+            "unsigned int __user *",
+            "const struct __kernel_timespec __user *",
+        ], // mq_timedreceive_time64
+        &[
+            "int",
+            "struct sembuf __user *",
+            "unsigned int",
+            "const struct __kernel_timespec __user *",
+        ], // semtimedop_time64
+        &[
+            "const sigset_t __user *",
+            "siginfo_t __user *",
+            "const struct __kernel_timespec __user *",
+            "size_t",
+        ], // rt_sigtimedwait_time64
+        &[
+            "u32 __user *",
+            "int",
+            "u32",
+            "const struct __kernel_timespec __user *",
+            "u32 __user *",
+            "u32",
+        ], // futex_time64
+        &["pid_t", "struct __kernel_timespec __user *"], // sched_rr_get_interval_time64
+        &["int", "int", "siginfo_t __user *", "unsigned int"], // pidfd_send_signal
+        &["u32", "struct io_uring_params __user *"],           // io_uring_setup
+        &[
+            "unsigned int",
+            "u32",
+            "u32",
+            "u32",
+            "const void __user *",
+            "size_t",
+        ], // io_uring_enter
+        &[
+            "unsigned int",
+            "unsigned int",
+            "void __user *",
+            "unsigned int",
+        ], // io_uring_register
+        &["int", "const char __user *", "unsigned"],           // open_tree
+        &[
+            "int",
+            "const char __user *",
+            "int",
+            "const char __user *",
+            "unsigned int",
+        ], // move_mount
+        &["const char __user *", "unsigned int"],              // fsopen
+        &[
+            "int",
+            "unsigned int",
+            "const char __user *",
+            "const void __user *",
+            "int",
+        ], // fsconfig
+        &["int", "unsigned int", "unsigned int"],              // fsmount
+        &["int", "const char __user *", "unsigned int"],       // fspick
+        &["pid_t", "unsigned int"],                            // pidfd_open
+        &["struct clone_args __user *", "size_t"],             // clone3
+        &["unsigned int", "unsigned int", "unsigned int"],     // close_range
+        &[
+            "int",
+            "const char __user *",
+            "struct open_how __user *",
+            "size_t",
+        ], // openat2
+        &["int", "int", "unsigned int"],                       // pidfd_getfd
+        &["int", "const char __user *", "int", "int"],         // faccessat2
+        &[
+            "int",
+            "const struct iovec __user *",
+            "size_t",
+            "int",
+            "unsigned int",
+        ], // process_madvise
+        &[
+            "int",
+            "struct epoll_event __user *",
+            "int",
+            "const struct __kernel_timespec __user *",
+            "const sigset_t __user *",
+            "size_t",
+        ], // epoll_pwait2
+        &[
+            "int",
+            "const char __user *",
+            "unsigned int",
+            "struct mount_attr __user *",
+            "size_t",
+        ], // mount_setattr
+        &["unsigned int", "unsigned int", "qid_t", "void __user *"], // quotactl_fd
+        &[
+            "const struct landlock_ruleset_attr __user *const",
+            "const size_t",
+            "const __u32",
+        ], // landlock_create_ruleset
+        &[
+            "const int",
+            "const enum landlock_rule_type",
+            "const void __user *const",
+            "const __u32",
+        ], // landlock_add_rule
+        &["const int", "const __u32"], // landlock_restrict_self
+        &[],
+        &["int", "unsigned int"], // process_mrelease
+        &[
+            "struct futex_waitv __user *",
+            "unsigned int",
+            "unsigned int",
+            "struct __kernel_timespec __user *",
+            "clockid_t",
+        ], // futex_waitv
+        &[
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+        ], // set_mempolicy_home_node
+        &[
+            "unsigned int",
+            "struct cachestat_range __user *",
+            "struct cachestat __user *",
+            "unsigned int",
+        ], // cachestat
+        &["int", "const char __user *", "umode_t", "unsigned int"], // fchmodat2
+        &[],                      // map_shadow_stack
+        &["void __user *", "unsigned long", "int", "unsigned int"], // futex_wake
+        &[
+            "void __user *",
+            "unsigned long",
+            "unsigned long",
+            "unsigned int",
+            "struct __kernel_timespec __user *",
+            "clockid_t",
+        ], // futex_wait
+        &["struct futex_waitv __user *", "unsigned int", "int", "int"], // futex_requeue
+        &[
+            "const struct mnt_id_req __user *",
+            "struct statmount __user *",
+            "size_t",
+            "unsigned int",
+        ], // statmount
+        &[
+            "const struct mnt_id_req __user *",
+            "u64 __user *",
+            "size_t",
+            "unsigned int",
+        ], // listmount
+        &[
+            "unsigned int",
+            "struct lsm_ctx __user *",
+            "u32 __user *",
+            "u32",
+        ], // lsm_get_self_attr
+        &["unsigned int", "struct lsm_ctx __user *", "u32", "u32"], // lsm_set_self_attr
+        &["u64 __user *", "u32 __user *", "u32"], // lsm_list_modules
+        &["unsigned long", "size_t", "unsigned long"], // mseal
+    ];
+
+    /// Argument names.
+    pub(crate) static ARG_NAMES: [&[&str]; 463] = [
+        &[],                            // restart_syscall
+        &["error_code"],                // exit
+        &[],                            // fork
+        &["fd", "buf", "count"],        // read
+        &["fd", "buf", "count"],        // write
+        &["filename", "flags", "mode"], // open
+        &["fd"],                        // close
+        &[],
+        &["pathname", "mode"],         // creat
+        &["oldname", "newname"],       // link
+        &["pathname"],                 // unlink
+        &["filename", "argv", "envp"], // execve
+        &["filename"],                 // chdir
+        &[],
+        &["filename", "mode", "dev"],   // mknod
+        &["filename", "mode"],          // chmod
+        &["filename", "user", "group"], // lchown
+        &[],
+        &[],
+        &["fd", "offset", "whence"], // lseek
+        &[],                         // getpid
+        &["dev_name", "dir_name", "type", "flags", "data"], // mount
+        &[],
+        &["uid"], // setuid
+        &[],      // getuid
+        &[],
+        &["request", "pid", "addr", "data"], // ptrace
+        &[],
+        &[],
+        &[], // pause
+        &[],
+        &[],
+        &[],
+        &["filename", "mode"], // access
+        &["increment"],        // nice
+        &[],
+        &[],                     // sync
+        &["pid", "sig"],         // kill
+        &["oldname", "newname"], // rename
+        &["pathname", "mode"],   // mkdir
+        &["pathname"],           // rmdir
+        &["fildes"],             // dup
+        &["fildes"],             // pipe
+        &["tbuf"],               // times
+        &[],
+        &["brk"], // brk
+        &["gid"], // setgid
+        &[],      // getgid
+        &[],
+        &[],                // geteuid
+        &[],                // getegid
+        &["name"],          // acct
+        &["name", "flags"], // umount2
+        &[],
+        &["fd", "cmd", "arg"], // ioctl
+        &["fd", "cmd", "arg"], // fcntl
+        &[],
+        &["pid", "pgid"], // setpgid
+        &[],
+        &[],
+        &["mask"],               // umask
+        &["filename"],           // chroot
+        &["dev", "ubuf"],        // ustat
+        &["oldfd", "newfd"],     // dup2
+        &[],                     // getppid
+        &[],                     // getpgrp
+        &[],                     // setsid
+        &["sig", "act", "oact"], // sigaction
+        &[],
+        &[],
+        &["ruid", "euid"],               // setreuid
+        &["rgid", "egid"],               // setregid
+        &["unused1", "unused2", "mask"], // sigsuspend
+        &["uset"],                       // sigpending
+        &["name", "len"],                // sethostname
+        &["resource", "rlim"],           // setrlimit
+        &[],
+        &["who", "ru"],               // getrusage
+        &["tv", "tz"],                // gettimeofday
+        &["tv", "tz"],                // settimeofday
+        &["gidsetsize", "grouplist"], // getgroups
+        &["gidsetsize", "grouplist"], // setgroups
+        &[],
+        &["oldname", "newname"], // symlink
+        &[],
+        &["path", "buf", "bufsiz"],     // readlink
+        &[],                            // uselib
+        &["specialfile", "swap_flags"], // swapon
+        &["magic1", "magic2", "cmd", "arg"], // reboot
+        &[],
+        &[],
+        &["addr", "len"],             // munmap
+        &["path", "length"],          // truncate
+        &["fd", "length"],            // ftruncate
+        &["fd", "mode"],              // fchmod
+        &["fd", "user", "group"],     // fchown
+        &["which", "who"],            // getpriority
+        &["which", "who", "niceval"], // setpriority
+        &[],
+        &["pathname", "buf"], // statfs
+        &["fd", "buf"],       // fstatfs
+        &[],
+        &[],
+        &["type", "buf", "len"],       // syslog
+        &["which", "value", "ovalue"], // setitimer
+        &["which", "value"],           // getitimer
+        &["filename", "statbuf"],      // stat
+        &["filename", "statbuf"],      // lstat
+        &["fd", "statbuf"],            // fstat
+        &[],
+        &[],
+        &[], // vhangup
+        &[],
+        &[],
+        &["upid", "stat_addr", "options", "ru"], // wait4
+        &["specialfile"],                        // swapoff
+        &["info"],                               // sysinfo
+        &[],
+        &["fd"], // fsync
+        &[],     // sigreturn
+        &[
+            "clone_flags",
+            "newsp",
+            "parent_tidptr",
+            "child_tidptr",
+            "tls",
+        ], // clone
+        &["name", "len"], // setdomainname
+        &["name"], // uname
+        &[],
+        &["utp"],                  // adjtimex
+        &["start", "len", "prot"], // mprotect
+        &["how", "nset", "oset"],  // sigprocmask
+        &[],
+        &["umod", "len", "uargs"], // init_module
+        &["name_user", "flags"],   // delete_module
+        &[],
+        &["cmd", "special", "id", "addr"], // quotactl
+        &["pid"],                          // getpgid
+        &["fd"],                           // fchdir
+        &["func", "data"],                 // bdflush
+        &["option", "arg1", "arg2"],       // sysfs
+        &["personality"],                  // personality
+        &[],
+        &["uid"], // setfsuid
+        &["gid"], // setfsgid
+        &["fd", "offset_high", "offset_low", "result", "whence"], // _llseek
+        &["fd", "dirent", "count"], // getdents
+        &["n", "inp", "outp", "exp", "tvp"], // _newselect
+        &["fd", "cmd"], // flock
+        &["start", "len", "flags"], // msync
+        &["fd", "vec", "vlen"], // readv
+        &["fd", "vec", "vlen"], // writev
+        &["pid"], // getsid
+        &["fd"],  // fdatasync
+        &[],      // _sysctl
+        &["start", "len"], // mlock
+        &["start", "len"], // munlock
+        &["flags"], // mlockall
+        &[],      // munlockall
+        &["pid", "param"], // sched_setparam
+        &["pid", "param"], // sched_getparam
+        &["pid", "policy", "param"], // sched_setscheduler
+        &["pid"], // sched_getscheduler
+        &[],      // sched_yield
+        &["policy"], // sched_get_priority_max
+        &["policy"], // sched_get_priority_min
+        &["pid", "interval"], // sched_rr_get_interval
+        &["rqtp", "rmtp"], // nanosleep
+        &["addr", "old_len", "new_len", "flags", "new_addr"], // mremap
+        &["ruid", "euid", "suid"], // setresuid
+        &["ruidp", "euidp", "suidp"], // getresuid
+        &[],
+        &[],
+        &["ufds", "nfds", "timeout_msecs"], // poll
+        &[],                                // nfsservctl
+        &["rgid", "egid", "sgid"],          // setresgid
+        &["rgidp", "egidp", "sgidp"],       // getresgid
+        &["option", "arg2", "arg3", "arg4", "arg5"], // prctl
+        &[],                                // rt_sigreturn
+        &["sig", "act", "oact", "sigsetsize"], // rt_sigaction
+        &["how", "nset", "oset", "sigsetsize"], // rt_sigprocmask
+        &["uset", "sigsetsize"],            // rt_sigpending
+        &["uthese", "uinfo", "uts", "sigsetsize"], // rt_sigtimedwait
+        &["pid", "sig", "uinfo"],           // rt_sigqueueinfo
+        &["unewset", "sigsetsize"],         // rt_sigsuspend
+        &["fd", "buf", "count", "pos"],     // pread64
+        &["fd", "buf", "count", "pos"],     // pwrite64
+        &["filename", "user", "group"],     // chown
+        &["buf", "size"],                   // getcwd
+        &["header", "dataptr"],             // capget
+        &["header", "data"],                // capset
+        &["uss", "uoss"],                   // sigaltstack
+        &["out_fd", "in_fd", "offset", "count"], // sendfile
+        &[],
+        &[],
+        &[],                              // vfork
+        &["resource", "rlim"],            // ugetrlimit
+        &[],                              // mmap2
+        &["path", "length"],              // truncate64
+        &["fd", "length"],                // ftruncate64
+        &["filename", "statbuf"],         // stat64
+        &["filename", "statbuf"],         // lstat64
+        &["fd", "statbuf"],               // fstat64
+        &["filename", "user", "group"],   // lchown32
+        &[],                              // getuid32
+        &[],                              // getgid32
+        &[],                              // geteuid32
+        &[],                              // getegid32
+        &["ruid", "euid"],                // setreuid32
+        &["rgid", "egid"],                // setregid32
+        &["gidsetsize", "grouplist"],     // getgroups32
+        &["gidsetsize", "grouplist"],     // setgroups32
+        &["fd", "user", "group"],         // fchown32
+        &["ruid", "euid", "suid"],        // setresuid32
+        &["ruidp", "euidp", "suidp"],     // getresuid32
+        &["rgid", "egid", "sgid"],        // setresgid32
+        &["rgidp", "egidp", "sgidp"],     // getresgid32
+        &["filename", "user", "group"],   // chown32
+        &["uid"],                         // setuid32
+        &["gid"],                         // setgid32
+        &["uid"],                         // setfsuid32
+        &["gid"],                         // setfsgid32
+        &["fd", "dirent", "count"],       // getdents64
+        &["new_root", "put_old"],         // pivot_root
+        &["start", "len", "vec"],         // mincore
+        &["start", "len_in", "behavior"], // madvise
+        &["fd", "cmd", "arg"],            // fcntl64
+        &[],
+        &[],
+        &[],                                                // gettid
+        &["fd", "offset", "count"],                         // readahead
+        &["pathname", "name", "value", "size", "flags"],    // setxattr
+        &["pathname", "name", "value", "size", "flags"],    // lsetxattr
+        &["fd", "name", "value", "size", "flags"],          // fsetxattr
+        &["pathname", "name", "value", "size"],             // getxattr
+        &["pathname", "name", "value", "size"],             // lgetxattr
+        &["fd", "name", "value", "size"],                   // fgetxattr
+        &["pathname", "list", "size"],                      // listxattr
+        &["pathname", "list", "size"],                      // llistxattr
+        &["fd", "list", "size"],                            // flistxattr
+        &["pathname", "name"],                              // removexattr
+        &["pathname", "name"],                              // lremovexattr
+        &["fd", "name"],                                    // fremovexattr
+        &["pid", "sig"],                                    // tkill
+        &["out_fd", "in_fd", "offset", "count"],            // sendfile64
+        &["uaddr", "op", "val", "utime", "uaddr2", "val3"], // futex
+        &["pid", "len", "user_mask_ptr"],                   // sched_setaffinity
+        &["pid", "len", "user_mask_ptr"],                   // sched_getaffinity
+        &["nr_events", "ctxp"],                             // io_setup
+        &["ctx"],                                           // io_destroy
+        &["ctx_id", "min_nr", "nr", "events", "timeout"],   // io_getevents
+        &["ctx_id", "nr", "iocbpp"],                        // io_submit
+        &["ctx_id", "iocb", "result"],                      // io_cancel
+        &["error_code"],                                    // exit_group
+        &[],                                                // lookup_dcookie
+        &["size"],                                          // epoll_create
+        &["epfd", "op", "fd", "event"],                     // epoll_ctl
+        &["epfd", "events", "maxevents", "timeout"],        // epoll_wait
+        &["start", "size", "prot", "pgoff", "flags"],       // remap_file_pages
+        &[],
+        &[],
+        &["tidptr"], // set_tid_address
+        &["which_clock", "timer_event_spec", "created_timer_id"], // timer_create
+        &["timer_id", "flags", "new", "old"], // timer_settime
+        &["timer_id", "setting"],             // timer_gettime
+        &["timer_id"],                        // timer_getoverrun
+        &["timer_id"],                        // timer_delete
+        &["which_clock", "tp"],               // clock_settime
+        &["which_clock", "tp"],               // clock_gettime
+        &["which_clock", "tp"],               // clock_getres
+        &["which_clock", "flags", "rqtp", "rmtp"], // clock_nanosleep
+        &["pathname", "sz", "buf"],           // statfs64
+        &["fd", "sz", "buf"],                 // fstatfs64
+        &["tgid", "pid", "sig"],              // tgkill
+        &["filename", "t"],                   // utimes
+        &["fd", "offset", "len", "advice"],   // arm_fadvise64_64
+        &[],                                  // pciconfig_iobase
+        &["bus", "dfn", "off", "len", "buf"], // pciconfig_read
+        &["bus", "dfn", "off", "len", "buf"], // pciconfig_write
+        &["u_name", "oflag", "mode", "u_attr"], // mq_open
+        &["u_name"],                          // mq_unlink
+        &["mqdes", "u_msg_ptr", "msg_len", "msg_prio", "u_abs_timeout"], // mq_timedsend
+        &[
+            "mqdes",
+            "u_msg_ptr",
+            "msg_len",
+            "u_msg_prio",
+            "u_abs_timeout",
+        ], // mq_timedreceive
+        &["mqdes", "u_notification"], // mq_notify
+        &["mqdes", "u_mqstat", "u_omqstat"], // mq_getsetattr
+        &["which", "upid", "infop", "options", "ru"], // waitid
+        &["family", "type", "protocol"], // socket
+        &["fd", "umyaddr", "addrlen"], // bind
+        &["fd", "uservaddr", "addrlen"], // connect
+        &["fd", "backlog"],           // listen
+        &["fd", "upeer_sockaddr", "upeer_addrlen"], // accept
+        &["fd", "usockaddr", "usockaddr_len"], // getsockname
+        &["fd", "usockaddr", "usockaddr_len"], // getpeername
+        &["family", "type", "protocol", "usockvec"], // socketpair
+        &["fd", "buff", "len", "flags"], // send
+        &["fd", "buff", "len", "flags", "addr", "addr_len"], // sendto
+        &["fd", "ubuf", "size", "flags"], // recv
+        &["fd", "ubuf", "size", "flags", "addr", "addr_len"], // recvfrom
+        &["fd", "how"],               // shutdown
+        &["fd", "level", "optname", "optval", "optlen"], // setsockopt
+        &["fd", "level", "optname", "optval", "optlen"], // getsockopt
+        &["fd", "msg", "flags"],      // sendmsg
+        &["fd", "msg", "flags"],      // recvmsg
+        &["semid", "tsops", "nsops"], // semop
+        &["key", "nsems", "semflg"],  // semget
+        &["semid", "semnum", "cmd", "arg"], // semctl
+        &["msqid", "msgp", "msgsz", "msgflg"], // msgsnd
+        &["msqid", "msgp", "msgsz", "msgtyp", "msgflg"], // msgrcv
+        &["key", "msgflg"],           // msgget
+        &["msqid", "cmd", "buf"],     // msgctl
+        &["shmid", "shmaddr", "shmflg"], // shmat
+        &["shmaddr"],                 // shmdt
+        &["key", "size", "shmflg"],   // shmget
+        &["shmid", "cmd", "buf"],     // shmctl
+        &["_type", "_description", "_payload", "plen", "ringid"], // add_key
+        &["_type", "_description", "_callout_info", "destringid"], // request_key
+        &["option", "arg2", "arg3", "arg4", "arg5"],               // keyctl
+        &["semid", "tsems", "nsops", "timeout"],                   // semtimedop
+        &[],                                                       // vserver
+        &["which", "who", "ioprio"],                               // ioprio_set
+        &["which", "who"],                                         // ioprio_get
+        &[],                         // inotify_init
+        &["fd", "pathname", "mask"], // inotify_add_watch
+        &["fd", "wd"],               // inotify_rm_watch
+        &["start", "len", "mode", "nmask", "maxnode", "flags"], // mbind
+        &["policy", "nmask", "maxnode", "addr", "flags"], // get_mempolicy
+        &["mode", "nmask", "maxnode"], // set_mempolicy
+        &["dfd", "filename", "flags", "mode"], // openat
+        &["dfd", "pathname", "mode"], // mkdirat
+        &["dfd", "filename", "mode", "dev"], // mknodat
+        &["dfd", "filename", "user", "group", "flag"], // fchownat
+        &["dfd", "filename", "t"],   // futimesat
+        &["dfd", "filename", "statbuf", "flag"], // fstatat64
+        &["dfd", "pathname", "flag"], // unlinkat
+        &["olddfd", "oldname", "newdfd", "newname"], // renameat
+        &["olddfd", "oldname", "newdfd", "newname", "flags"], // linkat
+        &["oldname", "newdfd", "newname"], // symlinkat
+        &["dfd", "pathname", "buf", "bufsiz"], // readlinkat
+        &["dfd", "filename", "mode"], // fchmodat
+        &["dfd", "filename", "mode"], // faccessat
+        &["n", "inp", "outp", "exp", "tsp", "sig"], // pselect6
+        &["ufds", "nfds", "tsp", "sigmask", "sigsetsize"], // ppoll
+        &["unshare_flags"],          // unshare
+        &["head", "len"],            // set_robust_list
+        &["pid", "head_ptr", "len_ptr"], // get_robust_list
+        &["fd_in", "off_in", "fd_out", "off_out", "len", "flags"], // splice
+        &["fd", "flags", "offset", "nbytes"], // arm_sync_file_range
+        &["fdin", "fdout", "len", "flags"], // tee
+        &["fd", "uiov", "nr_segs", "flags"], // vmsplice
+        &["pid", "nr_pages", "pages", "nodes", "status", "flags"], // move_pages
+        &["cpup", "nodep", "unused"], // getcpu
+        &[
+            "epfd",
+            "events",
+            "maxevents",
+            "timeout",
+            "sigmask",
+            "sigsetsize",
+        ], // epoll_pwait
+        &["entry", "nr_segments", "segments", "flags"], // kexec_load
+        &["dfd", "filename", "t", "flags"], // utimensat
+        &["ufd", "user_mask", "sizemask"], // signalfd
+        &["clockid", "flags"],       // timerfd_create
+        &["count"],                  // eventfd
+        &["fd", "mode", "offset", "len"], // fallocate
+        &["ufd", "flags", "utmr", "otmr"], // timerfd_settime
+        &["ufd", "otmr"],            // timerfd_gettime
+        &["ufd", "user_mask", "sizemask", "flags"], // signalfd4
+        &["count", "flags"],         // eventfd2
+        &["flags"],                  // epoll_create1
+        &["oldfd", "newfd", "flags"], // dup3
+        &["fildes", "flags"],        // pipe2
+        &["flags"],                  // inotify_init1
+        &["fd", "vec", "vlen", "pos_l", "pos_h"], // preadv
+        &["fd", "vec", "vlen", "pos_l", "pos_h"], // pwritev
+        &["tgid", "pid", "sig", "uinfo"], // rt_tgsigqueueinfo
+        &["attr_uptr", "pid", "cpu", "group_fd", "flags"], // perf_event_open
+        &["fd", "mmsg", "vlen", "flags", "timeout"], // recvmmsg
+        &["fd", "upeer_sockaddr", "upeer_addrlen", "flags"], // accept4
+        &["flags", "event_f_flags"], // fanotify_init
+        &["fanotify_fd", "flags", "mask", "dfd", "pathname"], // fanotify_mark
+        &["pid", "resource", "new_rlim", "old_rlim"], // prlimit64
+        &["dfd", "name", "handle", "mnt_id", "flag"], // name_to_handle_at
+        &["mountdirfd", "handle", "flags"], // open_by_handle_at
+        &["which_clock", "utp"],     // clock_adjtime
+        &["fd"],                     // syncfs
+        &["fd", "mmsg", "vlen", "flags"], // sendmmsg
+        &["fd", "flags"],            // setns
+        &["pid", "lvec", "liovcnt", "rvec", "riovcnt", "flags"], // process_vm_readv
+        &["pid", "lvec", "liovcnt", "rvec", "riovcnt", "flags"], // process_vm_writev
+        &["pid1", "pid2", "type", "idx1", "idx2"],               // kcmp
+        &["fd", "uargs", "flags"],                               // finit_module
+        &["pid", "uattr", "flags"], // sched_setattr
+        &["pid", "uattr", "usize", "flags"], // sched_getattr
+        &["olddfd", "oldname", "newdfd", "newname", "flags"], // renameat2
+        &["op", "flags", "uargs"],  // seccomp
+        &["ubuf", "len", "flags"],  // getrandom
+        &["uname", "flags"],        // memfd_create
+        &["cmd", "uattr", "size"],  // bpf
+        &["fd", "filename", "argv", "envp", "flags"], // execveat
+        &["flags"],                 // userfaultfd
+        &["cmd", "flags", "cpu_id"], // membarrier
+        &["start", "len", "flags"], // mlock2
+        &["fd_in", "off_in", "fd_out", "off_out", "len", "flags"], // copy_file_range
+        &["fd", "vec", "vlen", "pos_l", "pos_h", "flags"],         // preadv2
+        &["fd", "vec", "vlen", "pos_l", "pos_h", "flags"],         // pwritev2
+        &["start", "len", "prot", "pkey"], // pkey_mprotect
+        &["flags", "init_val"],            // pkey_alloc
+        &["pkey"],                         // pkey_free
+        &["dfd", "filename", "flags", "mask", "buffer"], // statx
+        &["rseq", "rseq_len", "flags", "sig"], // rseq
+        &["ctx_id", "min_nr", "nr", "events", "timeout", "usig"], // io_pgetevents
+        &["pid", "maxnode", "old_nodes", "new_nodes"], // migrate_pages
+        &[
+            "kernel_fd",
+            "initrd_fd",
+            "cmdline_len",
+            "cmdline_ptr",
+            "flags",
+        ], // kexec_file_load
+        &[],
+        &["which_clock", "tp"],  // clock_gettime64
+        &["which_clock", "tp"],  // clock_settime64
+        &["which_clock", "utx"], // clock_adjtime64
+        &["which_clock", "tp"],  // clock_getres_time64
+        &["which_clock", "flags", "rqtp", "rmtp"], // clock_nanosleep_time64
+        &["timer_id", "setting"], // timer_gettime64
+        &["timer_id", "flags", "new_setting", "old_setting"], // timer_settime64
+        &["ufd", "otmr"],        // timerfd_gettime64
+        &["ufd", "flags", "utmr", "otmr"], // timerfd_settime64
+        &["dfd", "filename", "utimes", "flags"], // utimensat_time64
+        &["n", "inp", "outp", "exp", "tsp", "sig"], // pselect6_time64
+        &["ufds", "nfds", "tsp", "sigmask", "sigsetsize"], // ppoll_time64
+        &[],
+        &["ctx_id", "min_nr", "nr", "events", "timeout", "usig"], // io_pgetevents_time64
+        &["fd", "mmsg", "vlen", "flags", "timeout"], // recvmmsg_time64
+        &["mqdes", "u_msg_ptr", "msg_len", "msg_prio", "u_abs_timeout"], // mq_timedsend_time64
+        &[
+            "mqdes",
+            "u_msg_ptr",
+            "msg_len",
+            "u_msg_prio",
+            "u_abs_timeout",
+        ], // mq_timedreceive_time64
+        &["semid", "tsops", "nsops", "timeout"], // semtimedop_time64
+        &["uthese", "uinfo", "uts", "sigsetsize"], // rt_sigtimedwait_time64
+        &["uaddr", "op", "val", "utime", "uaddr2", "val3"], // futex_time64
+        &["pid", "interval"],                    // sched_rr_get_interval_time64
+        &["pidfd", "sig", "info", "flags"],      // pidfd_send_signal
+        &["entries", "params"],                  // io_uring_setup
+        &["fd", "to_submit", "min_complete", "flags", "argp", "argsz"], // io_uring_enter
+        &["fd", "opcode", "arg", "nr_args"], // io_uring_register
+        &["dfd", "filename", "flags"],       // open_tree
+        &[
+            "from_dfd",
+            "from_pathname",
+            "to_dfd",
+            "to_pathname",
+            "flags",
+        ], // move_mount
+        &["_fs_name", "flags"],              // fsopen
+        &["fd", "cmd", "_key", "_value", "aux"], // fsconfig
+        &["fs_fd", "flags", "attr_flags"],   // fsmount
+        &["dfd", "path", "flags"],           // fspick
+        &["pid", "flags"],                   // pidfd_open
+        &["uargs", "size"],                  // clone3
+        &["fd", "max_fd", "flags"],          // close_range
+        &["dfd", "filename", "how", "usize"], // openat2
+        &["pidfd", "fd", "flags"],           // pidfd_getfd
+        &["dfd", "filename", "mode", "flags"], // faccessat2
+        &["pidfd", "vec", "vlen", "behavior", "flags"], // process_madvise
+        &[
+            "epfd",
+            "events",
+            "maxevents",
+            "timeout",
+            "sigmask",
+            "sigsetsize",
+        ], // epoll_pwait2
+        &["dfd", "path", "flags", "uattr", "usize"], // mount_setattr
+        &["fd", "cmd", "id", "addr"],        // quotactl_fd
+        &["attr", "size", "flags"],          // landlock_create_ruleset
+        &["ruleset_fd", "rule_type", "rule_attr", "flags"], // landlock_add_rule
+        &["ruleset_fd", "flags"],            // landlock_restrict_self
+        &[],
+        &["pidfd", "flags"], // process_mrelease
+        &["waiters", "nr_futexes", "flags", "timeout", "clockid"], // futex_waitv
+        &["start", "len", "home_node", "flags"], // set_mempolicy_home_node
+        &["fd", "cstat_range", "cstat", "flags"], // cachestat
+        &["dfd", "filename", "mode", "flags"],   // fchmodat2
+        &[],                                     // map_shadow_stack
+        &["uaddr", "mask", "nr", "flags"],       // futex_wake
+        &["uaddr", "val", "mask", "flags", "timeout", "clockid"], // futex_wait
+        &["waiters", "flags", "nr_wake", "nr_requeue"], // futex_requeue
+        &["req", "buf", "bufsize", "flags"],     // statmount
+        &["req", "mnt_ids", "nr_mnt_ids", "flags"], // listmount
+        &["attr", "ctx", "size", "flags"],       // lsm_get_self_attr
+        &["attr", "ctx", "size", "flags"],       // lsm_set_self_attr
+        &["ids", "size", "flags"],               // lsm_list_modules
+        &["start", "len", "flags"],              // mseal
+    ];
+
+    use crate::format::ArgFormat::{
+        self, Buf, Fd, Flags, Hex, Int, Mode, Str, Struct, Uint,
+    };
+
+    /// How arguments are shown by `format::Call`.
+    pub(crate) static ARG_FORMATS: [&[ArgFormat]; 463] = [
+        &[],                          // restart_syscall
+        &[Int],                       // exit
+        &[],                          // fork
+        &[Fd, Buf, Uint],             // read
+        &[Fd, Buf, Uint],             // write
+        &[Str, Flags(O_FLAGS), Mode], // open
+        &[Fd],                        // close
+        &[],
+        &[Str, Mode],     // creat
+        &[Str, Str],      // link
+        &[Str],           // unlink
+        &[Str, Hex, Hex], // execve
+        &[Str],           // chdir
+        &[],
+        &[Str, Mode, Uint], // mknod
+        &[Str, Mode],       // chmod
+        &[Str, Uint, Uint], // lchown
+        &[],
+        &[],
+        &[Fd, Int, Uint],                       // lseek
+        &[],                                    // getpid
+        &[Str, Str, Str, Flags(MS_FLAGS), Hex], // mount
+        &[],
+        &[Uint], // setuid
+        &[],     // getuid
+        &[],
+        &[Int, Int, Hex, Uint], // ptrace
+        &[],
+        &[],
+        &[], // pause
+        &[],
+        &[],
+        &[],
+        &[Str, Int], // access
+        &[Int],      // nice
+        &[],
+        &[],              // sync
+        &[Int, Int],      // kill
+        &[Str, Str],      // rename
+        &[Str, Mode],     // mkdir
+        &[Str],           // rmdir
+        &[Fd],            // dup
+        &[Hex],           // pipe
+        &[Struct("tms")], // times
+        &[],
+        &[Hex],  // brk
+        &[Uint], // setgid
+        &[],     // getgid
+        &[],
+        &[],         // geteuid
+        &[],         // getegid
+        &[Str],      // acct
+        &[Str, Int], // umount2
+        &[],
+        &[Fd, Uint, Uint], // ioctl
+        &[Fd, Uint, Uint], // fcntl
+        &[],
+        &[Int, Int], // setpgid
+        &[],
+        &[],
+        &[Int],                                                   // umask
+        &[Str],                                                   // chroot
+        &[Uint, Struct("ustat")],                                 // ustat
+        &[Fd, Fd],                                                // dup2
+        &[],                                                      // getppid
+        &[],                                                      // getpgrp
+        &[],                                                      // setsid
+        &[Int, Struct("old_sigaction"), Struct("old_sigaction")], // sigaction
+        &[],
+        &[],
+        &[Uint, Uint],             // setreuid
+        &[Uint, Uint],             // setregid
+        &[Int, Int, Hex],          // sigsuspend
+        &[Hex],                    // sigpending
+        &[Str, Int],               // sethostname
+        &[Uint, Struct("rlimit")], // setrlimit
+        &[],
+        &[Int, Struct("rusage")], // getrusage
+        &[Struct("__kernel_old_timeval"), Struct("timezone")], // gettimeofday
+        &[Struct("__kernel_old_timeval"), Struct("timezone")], // settimeofday
+        &[Int, Hex],              // getgroups
+        &[Int, Hex],              // setgroups
+        &[],
+        &[Str, Str], // symlink
+        &[],
+        &[Str, Buf, Int],       // readlink
+        &[],                    // uselib
+        &[Str, Int],            // swapon
+        &[Int, Int, Uint, Hex], // reboot
+        &[],
+        &[],
+        &[Hex, Uint],      // munmap
+        &[Str, Int],       // truncate
+        &[Fd, Int],        // ftruncate
+        &[Fd, Mode],       // fchmod
+        &[Fd, Uint, Uint], // fchown
+        &[Int, Int],       // getpriority
+        &[Int, Int, Int],  // setpriority
+        &[],
+        &[Str, Struct("statfs")], // statfs
+        &[Fd, Struct("statfs")],  // fstatfs
+        &[],
+        &[],
+        &[Int, Buf, Int], // syslog
+        &[
+            Int,
+            Struct("__kernel_old_itimerval"),
+            Struct("__kernel_old_itimerval"),
+        ], // setitimer
+        &[Int, Struct("__kernel_old_itimerval")], // getitimer
+        &[Str, Struct("stat")], // stat
+        &[Str, Struct("stat")], // lstat
+        &[Fd, Struct("stat")], // fstat
+        &[],
+        &[],
+        &[], // vhangup
+        &[],
+        &[],
+        &[Int, Hex, Int, Struct("rusage")], // wait4
+        &[Str],                             // swapoff
+        &[Struct("sysinfo")],               // sysinfo
+        &[],
+        &[Fd],                                       // fsync
+        &[],                                         // sigreturn
+        &[Flags(CLONE_FLAGS), Uint, Hex, Hex, Uint], // clone
+        &[Str, Int],                                 // setdomainname
+        &[Struct("new_utsname")],                    // uname
+        &[],
+        &[Struct("old_timex32")],        // adjtimex
+        &[Hex, Uint, Flags(PROT_FLAGS)], // mprotect
+        &[Int, Hex, Hex],                // sigprocmask
+        &[],
+        &[Hex, Uint, Str], // init_module
+        &[Str, Uint],      // delete_module
+        &[],
+        &[Uint, Str, Uint, Hex], // quotactl
+        &[Int],                  // getpgid
+        &[Fd],                   // fchdir
+        &[Int, Int],             // bdflush
+        &[Int, Uint, Uint],      // sysfs
+        &[Uint],                 // personality
+        &[],
+        &[Uint],                                               // setfsuid
+        &[Uint],                                               // setfsgid
+        &[Fd, Uint, Uint, Hex, Uint],                          // _llseek
+        &[Fd, Struct("linux_dirent"), Uint],                   // getdents
+        &[Int, Hex, Hex, Hex, Struct("__kernel_old_timeval")], // _newselect
+        &[Fd, Uint],                                           // flock
+        &[Hex, Uint, Int],                                     // msync
+        &[Fd, Struct("iovec"), Uint],                          // readv
+        &[Fd, Struct("iovec"), Uint],                          // writev
+        &[Int],                                                // getsid
+        &[Fd],                                                 // fdatasync
+        &[],                                                   // _sysctl
+        &[Hex, Uint],                                          // mlock
+        &[Hex, Uint],                                          // munlock
+        &[Int],                                                // mlockall
+        &[],                                                   // munlockall
+        &[Int, Struct("sched_param")],                         // sched_setparam
+        &[Int, Struct("sched_param")],                         // sched_getparam
+        &[Int, Int, Struct("sched_param")], // sched_setscheduler
+        &[Int],                             // sched_getscheduler
+        &[],                                // sched_yield
+        &[Int],                             // sched_get_priority_max
+        &[Int],                             // sched_get_priority_min
+        &[Int, Struct("old_timespec32")],   // sched_rr_get_interval
+        &[Struct("old_timespec32"), Struct("old_timespec32")], // nanosleep
+        &[Hex, Uint, Uint, Uint, Hex],      // mremap
+        &[Uint, Uint, Uint],                // setresuid
+        &[Hex, Hex, Hex],                   // getresuid
+        &[],
+        &[],
+        &[Struct("pollfd"), Uint, Int], // poll
+        &[],                            // nfsservctl
+        &[Uint, Uint, Uint],            // setresgid
+        &[Hex, Hex, Hex],               // getresgid
+        &[Int, Uint, Uint, Uint, Uint], // prctl
+        &[],                            // rt_sigreturn
+        &[Int, Struct("sigaction"), Struct("sigaction"), Uint], // rt_sigaction
+        &[Int, Hex, Hex, Uint],         // rt_sigprocmask
+        &[Hex, Uint],                   // rt_sigpending
+        &[Hex, Hex, Struct("old_timespec32"), Uint], // rt_sigtimedwait
+        &[Int, Int, Hex],               // rt_sigqueueinfo
+        &[Hex, Uint],                   // rt_sigsuspend
+        &[Fd, Buf, Uint, Int],          // pread64
+        &[Fd, Buf, Uint, Int],          // pwrite64
+        &[Str, Uint, Uint],             // chown
+        &[Buf, Uint],                   // getcwd
+        &[Hex, Hex],                    // capget
+        &[Hex, Hex],                    // capset
+        &[Hex, Hex],                    // sigaltstack
+        &[Fd, Fd, Hex, Uint],           // sendfile
+        &[],
+        &[],
+        &[],                                   // vfork
+        &[Uint, Struct("rlimit")],             // ugetrlimit
+        &[],                                   // mmap2
+        &[Str, Int],                           // truncate64
+        &[Fd, Int],                            // ftruncate64
+        &[Str, Struct("stat64")],              // stat64
+        &[Str, Struct("stat64")],              // lstat64
+        &[Fd, Struct("stat64")],               // fstat64
+        &[Str, Uint, Uint],                    // lchown32
+        &[],                                   // getuid32
+        &[],                                   // getgid32
+        &[],                                   // geteuid32
+        &[],                                   // getegid32
+        &[Uint, Uint],                         // setreuid32
+        &[Uint, Uint],                         // setregid32
+        &[Int, Hex],                           // getgroups32
+        &[Int, Hex],                           // setgroups32
+        &[Fd, Uint, Uint],                     // fchown32
+        &[Uint, Uint, Uint],                   // setresuid32
+        &[Hex, Hex, Hex],                      // getresuid32
+        &[Uint, Uint, Uint],                   // setresgid32
+        &[Hex, Hex, Hex],                      // getresgid32
+        &[Str, Uint, Uint],                    // chown32
+        &[Uint],                               // setuid32
+        &[Uint],                               // setgid32
+        &[Uint],                               // setfsuid32
+        &[Uint],                               // setfsgid32
+        &[Fd, Struct("linux_dirent64"), Uint], // getdents64
+        &[Str, Str],                           // pivot_root
+        &[Hex, Uint, Hex],                     // mincore
+        &[Hex, Uint, Int],                     // madvise
+        &[Fd, Uint, Uint],                     // fcntl64
+        &[],
+        &[],
+        &[],                                                    // gettid
+        &[Fd, Int, Uint],                                       // readahead
+        &[Str, Str, Hex, Uint, Int],                            // setxattr
+        &[Str, Str, Hex, Uint, Int],                            // lsetxattr
+        &[Fd, Str, Hex, Uint, Int],                             // fsetxattr
+        &[Str, Str, Hex, Uint],                                 // getxattr
+        &[Str, Str, Hex, Uint],                                 // lgetxattr
+        &[Fd, Str, Hex, Uint],                                  // fgetxattr
+        &[Str, Str, Uint],                                      // listxattr
+        &[Str, Str, Uint],                                      // llistxattr
+        &[Fd, Str, Uint],                                       // flistxattr
+        &[Str, Str],                                            // removexattr
+        &[Str, Str],                                            // lremovexattr
+        &[Fd, Str],                                             // fremovexattr
+        &[Int, Int],                                            // tkill
+        &[Fd, Fd, Hex, Uint],                                   // sendfile64
+        &[Hex, Int, Uint, Struct("old_timespec32"), Hex, Uint], // futex
+        &[Int, Uint, Hex], // sched_setaffinity
+        &[Int, Uint, Hex], // sched_getaffinity
+        &[Uint, Hex],      // io_setup
+        &[Uint],           // io_destroy
+        &[Uint, Int, Int, Struct("io_event"), Struct("old_timespec32")], // io_getevents
+        &[Uint, Int, Hex], // io_submit
+        &[Uint, Struct("iocb"), Struct("io_event")], // io_cancel
+        &[Int],            // exit_group
+        &[],               // lookup_dcookie
+        &[Int],            // epoll_create
+        &[Fd, Int, Fd, Struct("epoll_event")], // epoll_ctl
+        &[Fd, Struct("epoll_event"), Int, Int], // epoll_wait
+        &[Hex, Uint, Flags(PROT_FLAGS), Uint, Uint], // remap_file_pages
+        &[],
+        &[],
+        &[Hex],                          // set_tid_address
+        &[Int, Struct("sigevent"), Hex], // timer_create
+        &[
+            Int,
+            Int,
+            Struct("old_itimerspec32"),
+            Struct("old_itimerspec32"),
+        ], // timer_settime
+        &[Int, Struct("old_itimerspec32")], // timer_gettime
+        &[Int],                          // timer_getoverrun
+        &[Int],                          // timer_delete
+        &[Int, Struct("old_timespec32")], // clock_settime
+        &[Int, Struct("old_timespec32")], // clock_gettime
+        &[Int, Struct("old_timespec32")], // clock_getres
+        &[Int, Int, Struct("old_timespec32"), Struct("old_timespec32")], // clock_nanosleep
+        &[Str, Uint, Struct("statfs64")], // statfs64
+        &[Fd, Uint, Struct("statfs64")],  // fstatfs64
+        &[Int, Int, Int],                 // tgkill
+        &[Str, Struct("old_timeval32")],  // utimes
+        &[Fd, Int, Int, Int],             // arm_fadvise64_64
+        &[],                              // pciconfig_iobase
+        &[Uint, Uint, Uint, Uint, Hex],   // pciconfig_read
+        &[Uint, Uint, Uint, Uint, Hex],   // pciconfig_write
+        &[Str, Int, Mode, Struct("mq_attr")], // mq_open
+        &[Str],                           // mq_unlink
+        &[Uint, Str, Uint, Uint, Struct("old_timespec32")], // mq_timedsend
+        &[Uint, Str, Uint, Hex, Struct("old_timespec32")], // mq_timedreceive
+        &[Uint, Struct("sigevent")],      // mq_notify
+        &[Uint, Struct("mq_attr"), Struct("mq_attr")], // mq_getsetattr
+        &[Int, Int, Struct("siginfo"), Int, Struct("rusage")], // waitid
+        &[Int, Int, Int],                 // socket
+        &[Fd, Struct("sockaddr"), Int],   // bind
+        &[Fd, Struct("sockaddr"), Int],   // connect
+        &[Fd, Int],                       // listen
+        &[Fd, Struct("sockaddr"), Hex],   // accept
+        &[Fd, Struct("sockaddr"), Hex],   // getsockname
+        &[Fd, Struct("sockaddr"), Hex],   // getpeername
+        &[Int, Int, Int, Hex],            // socketpair
+        &[Fd, Hex, Uint, Uint],           // send
+        &[Fd, Hex, Uint, Uint, Struct("sockaddr"), Int], // sendto
+        &[Fd, Hex, Uint, Uint],           // recv
+        &[Fd, Hex, Uint, Uint, Struct("sockaddr"), Hex], // recvfrom
+        &[Fd, Int],                       // shutdown
+        &[Fd, Int, Int, Str, Int],        // setsockopt
+        &[Fd, Int, Int, Str, Hex],        // getsockopt
+        &[Fd, Struct("user_msghdr"), Uint], // sendmsg
+        &[Fd, Struct("user_msghdr"), Uint], // recvmsg
+        &[Int, Struct("sembuf"), Uint],   // semop
+        &[Uint, Int, Int],                // semget
+        &[Int, Int, Int, Uint],           // semctl
+        &[Int, Struct("msgbuf"), Uint, Int], // msgsnd
+        &[Int, Struct("msgbuf"), Uint, Int, Int], // msgrcv
+        &[Uint, Int],                     // msgget
+        &[Int, Int, Struct("msqid_ds")],  // msgctl
+        &[Int, Str, Int],                 // shmat
+        &[Str],                           // shmdt
+        &[Uint, Uint, Int],               // shmget
+        &[Int, Int, Struct("shmid_ds")],  // shmctl
+        &[Str, Str, Hex, Uint, Int],      // add_key
+        &[Str, Str, Str, Int],            // request_key
+        &[Int, Uint, Uint, Uint, Uint],   // keyctl
+        &[Int, Struct("sembuf"), Uint, Struct("old_timespec32")], // semtimedop
+        &[],                              // vserver
+        &[Int, Int, Int],                 // ioprio_set
+        &[Int, Int],                      // ioprio_get
+        &[],                              // inotify_init
+        &[Fd, Str, Uint],                 // inotify_add_watch
+        &[Fd, Int],                       // inotify_rm_watch
+        &[Hex, Uint, Uint, Hex, Uint, Uint], // mbind
+        &[Hex, Hex, Uint, Hex, Uint],     // get_mempolicy
+        &[Int, Hex, Uint],                // set_mempolicy
+        &[Fd, Str, Flags(O_FLAGS), Mode], // openat
+        &[Fd, Str, Mode],                 // mkdirat
+        &[Fd, Str, Mode, Uint],           // mknodat
+        &[Fd, Str, Uint, Uint, Flags(AT_FLAGS)], // fchownat
+        &[Fd, Str, Struct("old_timeval32")], // futimesat
+        &[Fd, Str, Struct("stat64"), Flags(AT_FLAGS)], // fstatat64
+        &[Fd, Str, Flags(AT_FLAGS)],      // unlinkat
+        &[Fd, Str, Fd, Str],              // renameat
+        &[Fd, Str, Fd, Str, Flags(AT_FLAGS)], // linkat
+        &[Str, Fd, Str],                  // symlinkat
+        &[Fd, Str, Buf, Int],             // readlinkat
+        &[Fd, Str, Mode],                 // fchmodat
+        &[Fd, Str, Int],                  // faccessat
+        &[Int, Hex, Hex, Hex, Struct("old_timespec32"), Hex], // pselect6
+        &[Struct("pollfd"), Uint, Struct("old_timespec32"), Hex, Uint], // ppoll
+        &[Flags(CLONE_FLAGS)],            // unshare
+        &[Struct("robust_list_head"), Uint], // set_robust_list
+        &[Int, Hex, Hex],                 // get_robust_list
+        &[Fd, Hex, Fd, Hex, Uint, Uint],  // splice
+        &[Fd, Uint, Int, Int],            // arm_sync_file_range
+        &[Int, Int, Uint, Uint],          // tee
+        &[Fd, Struct("iovec"), Uint, Uint], // vmsplice
+        &[Int, Uint, Hex, Hex, Hex, Int], // move_pages
+        &[Hex, Hex, Struct("getcpu_cache")], // getcpu
+        &[Fd, Struct("epoll_event"), Int, Int, Hex, Uint], // epoll_pwait
+        &[Uint, Uint, Struct("kexec_segment"), Uint], // kexec_load
+        &[Fd, Str, Struct("old_timespec32"), Flags(AT_FLAGS)], // utimensat
+        &[Int, Hex, Uint],                // signalfd
+        &[Int, Int],                      // timerfd_create
+        &[Uint],                          // eventfd
+        &[Fd, Int, Int, Int],             // fallocate
+        &[
+            Int,
+            Int,
+            Struct("old_itimerspec32"),
+            Struct("old_itimerspec32"),
+        ], // timerfd_settime
+        &[Int, Struct("old_itimerspec32")], // timerfd_gettime
+        &[Int, Hex, Uint, Int],           // signalfd4
+        &[Uint, Int],                     // eventfd2
+        &[Int],                           // epoll_create1
+        &[Fd, Fd, Flags(O_FLAGS)],        // dup3
+        &[Hex, Flags(O_FLAGS)],           // pipe2
+        &[Int],                           // inotify_init1
+        &[Fd, Struct("iovec"), Uint, Uint, Uint], // preadv
+        &[Fd, Struct("iovec"), Uint, Uint, Uint], // pwritev
+        &[Int, Int, Int, Hex],            // rt_tgsigqueueinfo
+        &[Struct("perf_event_attr"), Int, Int, Int, Uint], // perf_event_open
+        &[Fd, Struct("mmsghdr"), Uint, Uint, Struct("old_timespec32")], // recvmmsg
+        &[Fd, Struct("sockaddr"), Hex, Int], // accept4
+        &[Uint, Uint],                       // fanotify_init
+        &[Int, Uint, Uint, Fd, Str],         // fanotify_mark
+        &[Int, Uint, Struct("rlimit64"), Struct("rlimit64")], // prlimit64
+        &[Fd, Str, Struct("file_handle"), Hex, Flags(AT_FLAGS)], // name_to_handle_at
+        &[Int, Struct("file_handle"), Int], // open_by_handle_at
+        &[Int, Struct("old_timex32")],      // clock_adjtime
+        &[Fd],                              // syncfs
+        &[Fd, Struct("mmsghdr"), Uint, Uint], // sendmmsg
+        &[Fd, Flags(CLONE_FLAGS)],          // setns
+        &[Int, Struct("iovec"), Uint, Struct("iovec"), Uint, Uint], // process_vm_readv
+        &[Int, Struct("iovec"), Uint, Struct("iovec"), Uint, Uint], // process_vm_writev
+        &[Int, Int, Int, Uint, Uint],                               // kcmp
+        &[Fd, Str, Int],                          // finit_module
+        &[Int, Struct("sched_attr"), Uint],       // sched_setattr
+        &[Int, Struct("sched_attr"), Uint, Uint], // sched_getattr
+        &[Fd, Str, Fd, Str, Uint],                // renameat2
+        &[Uint, Uint, Hex],                       // seccomp
+        &[Buf, Uint, Uint],                       // getrandom
+        &[Str, Uint],                             // memfd_create
+        &[Int, Hex, Uint],                        // bpf
+        &[Fd, Str, Hex, Hex, Flags(AT_FLAGS)],    // execveat
+        &[Int],                                   // userfaultfd
+        &[Int, Uint, Int],                        // membarrier
+        &[Hex, Uint, Int],                        // mlock2
+        &[Fd, Hex, Fd, Hex, Uint, Uint],          // copy_file_range
+        &[Fd, Struct("iovec"), Uint, Uint, Uint, Int], // preadv2
+        &[Fd, Struct("iovec"), Uint, Uint, Uint, Int], // pwritev2
+        &[Hex, Uint, Flags(PROT_FLAGS), Int],     // pkey_mprotect
+        &[Uint, Uint],                            // pkey_alloc
+        &[Int],                                   // pkey_free
+        &[Fd, Str, Flags(AT_FLAGS), Uint, Struct("statx")], // statx
+        &[Struct("rseq"), Uint, Int, Uint],       // rseq
+        &[
+            Uint,
+            Int,
+            Int,
+            Struct("io_event"),
+            Struct("old_timespec32"),
+            Struct("__aio_sigset"),
+        ], // io_pgetevents
+        &[Int, Uint, Hex, Hex],                   // migrate_pages
+        &[Int, Int, Uint, Str, Uint],             // kexec_file_load
+        &[],
+        &[Int, Struct("__kernel_timespec")], // clock_gettime64
+        &[Int, Struct("__kernel_timespec")], // clock_settime64
+        &[Int, Struct("__kernel_timex")],    // clock_adjtime64
+        &[Int, Struct("__kernel_timespec")], // clock_getres_time64
+        &[
+            Int,
+            Int,
+            Struct("__kernel_timespec"),
+            Struct("__kernel_timespec"),
+        ], // clock_nanosleep_time64
+        &[Int, Struct("__kernel_itimerspec")], // timer_gettime64
+        &[
+            Int,
+            Int,
+            Struct("__kernel_itimerspec"),
+            Struct("__kernel_itimerspec"),
+        ], // timer_settime64
+        &[Int, Struct("__kernel_itimerspec")], // timerfd_gettime64
+        &[
+            Int,
+            Int,
+            Struct("__kernel_itimerspec"),
+            Struct("__kernel_itimerspec"),
+        ], // timerfd_settime64
+        &[Fd, Str, Struct("__kernel_timespec"), Int], // utimensat_time64
+        &[Int, Hex, Hex, Hex, Struct("__kernel_timespec"), Hex], // pselect6_time64
+        &[
+            Struct("pollfd"),
+            Uint,
+            Struct("__kernel_timespec"),
+            Hex,
+            Uint,
+        ], // ppoll_time64
+        &[],
+        &[
+            Uint,
+            Int,
+            Int,
+            Struct("io_event"),
+            Struct("__kernel_timespec"),
+            Struct("__aio_sigset"),
+        ], // io_pgetevents_time64
+        &[
+            Fd,
+            Struct("mmsghdr"),
+            Uint,
+            Uint,
+            Struct("__kernel_timespec"),
+        ], // recvmmsg_time64
+        &[Uint, Str, Uint, Uint, Struct("__kernel_timespec")], // mq_timedsend_time64
+        &[Uint, Str, Uint, Hex, Struct("__kernel_timespec")], // mq_timedreceive_time64
+        &[Int, Struct("sembuf"), Uint, Struct("__kernel_timespec")], // semtimedop_time64
+        &[Hex, Hex, Struct("__kernel_timespec"), Uint], // rt_sigtimedwait_time64
+        &[Hex, Int, Uint, Struct("__kernel_timespec"), Hex, Uint], // futex_time64
+        &[Int, Struct("__kernel_timespec")], // sched_rr_get_interval_time64
+        &[Fd, Int, Hex, Uint],               // pidfd_send_signal
+        &[Uint, Struct("io_uring_params")],  // io_uring_setup
+        &[Fd, Uint, Uint, Uint, Hex, Uint],  // io_uring_enter
+        &[Fd, Uint, Hex, Uint],              // io_uring_register
+        &[Fd, Str, Uint],                    // open_tree
+        &[Int, Str, Int, Str, Uint],         // move_mount
+        &[Str, Uint],                        // fsopen
+        &[Fd, Uint, Str, Hex, Int],          // fsconfig
+        &[Int, Uint, Uint],                  // fsmount
+        &[Fd, Str, Uint],                    // fspick
+        &[Int, Uint],                        // pidfd_open
+        &[Struct("clone_args"), Uint],       // clone3
+        &[Fd, Uint, Uint],                   // close_range
+        &[Fd, Str, Struct("open_how"), Uint], // openat2
+        &[Fd, Fd, Uint],                     // pidfd_getfd
+        &[Fd, Str, Int, Flags(AT_FLAGS)],    // faccessat2
+        &[Fd, Struct("iovec"), Uint, Int, Uint], // process_madvise
+        &[
+            Fd,
+            Struct("epoll_event"),
+            Int,
+            Struct("__kernel_timespec"),
+            Hex,
+            Uint,
+        ], // epoll_pwait2
+        &[Fd, Str, Uint, Struct("mount_attr"), Uint], // mount_setattr
+        &[Fd, Uint, Uint, Hex],              // quotactl_fd
+        &[Struct("landlock_ruleset_attr"), Uint, Uint], // landlock_create_ruleset
+        &[Int, Hex, Hex, Uint],                         // landlock_add_rule
+        &[Int, Uint], // landlock_restrict_self
+        &[],
+        &[Fd, Uint], // process_mrelease
+        &[
+            Struct("futex_waitv"),
+            Uint,
+            Uint,
+            Struct("__kernel_timespec"),
+            Int,
+        ], // futex_waitv
+        &[Hex, Uint, Uint, Uint], // set_mempolicy_home_node
+        &[Fd, Struct("cachestat_range"), Struct("cachestat"), Uint], // cachestat
+        &[Fd, Str, Mode, Flags(AT_FLAGS)], // fchmodat2
+        &[],                               // map_shadow_stack
+        &[Hex, Uint, Int, Uint],           // futex_wake
+        &[Hex, Uint, Uint, Uint, Struct("__kernel_timespec"), Int], // futex_wait
+        &[Struct("futex_waitv"), Uint, Int, Int], // futex_requeue
+        &[Struct("mnt_id_req"), Struct("statmount"), Uint, Uint], // statmount
+        &[Struct("mnt_id_req"), Hex, Uint, Uint], // listmount
+        &[Uint, Struct("lsm_ctx"), Hex, Uint],    // lsm_get_self_attr
+        &[Uint, Struct("lsm_ctx"), Uint, Uint],   // lsm_set_self_attr
+        &[Hex, Hex, Uint],                        // lsm_list_modules
+        &[Hex, Uint, Uint],                       // mseal
+    ];
+
+    static O_FLAGS: &[(&str, u64)] = &[
+        ("O_SYNC", 0x0010_1000),
+        ("O_TMPFILE", 0x0040_4000),
+        ("O_APPEND", 0x400),
+        ("O_CLOEXEC", 0x0008_0000),
+        ("O_CREAT", 0x40),
+        ("O_DIRECT", 0x0001_0000),
+        ("O_DIRECTORY", 0x4000),
+        ("O_DSYNC", 0x1000),
+        ("O_EXCL", 0x80),
+        ("O_LARGEFILE", 0x0002_0000),
+        ("O_NOATIME", 0x0004_0000),
+        ("O_NOCTTY", 0x100),
+        ("O_NOFOLLOW", 0x8000),
+        ("O_NONBLOCK", 0x800),
+        ("O_PATH", 0x0020_0000),
+        ("O_RDWR", 0x2),
+        ("O_TRUNC", 0x200),
+        ("O_WRONLY", 0x1),
+        ("O_RDONLY", 0x0),
+    ];
+
+    static AT_FLAGS: &[(&str, u64)] = &[
+        ("AT_EMPTY_PATH", 0x1000),
+        ("AT_NO_AUTOMOUNT", 0x800),
+        ("AT_RECURSIVE", 0x8000),
+        ("AT_REMOVEDIR", 0x200),
+        ("AT_STATX_DONT_SYNC", 0x4000),
+        ("AT_STATX_FORCE_SYNC", 0x2000),
+        ("AT_SYMLINK_FOLLOW", 0x400),
+        ("AT_SYMLINK_NOFOLLOW", 0x100),
+    ];
+
+    static CLONE_FLAGS: &[(&str, u64)] = &[
+        ("CLONE_CHILD_CLEARTID", 0x0020_0000),
+        ("CLONE_CHILD_SETTID", 0x0100_0000),
+        ("CLONE_CLEAR_SIGHAND", 0x0001_0000_0000),
+        ("CLONE_DETACHED", 0x0040_0000),
+        ("CLONE_FILES", 0x400),
+        ("CLONE_FS", 0x200),
+        ("CLONE_INTO_CGROUP", 0x0002_0000_0000),
+        ("CLONE_IO", 0x8000_0000),
+        ("CLONE_NEWCGROUP", 0x0200_0000),
+        ("CLONE_NEWIPC", 0x0800_0000),
+        ("CLONE_NEWNET", 0x4000_0000),
+        ("CLONE_NEWNS", 0x0002_0000),
+        ("CLONE_NEWPID", 0x2000_0000),
+        ("CLONE_NEWTIME", 0x80),
+        ("CLONE_NEWUSER", 0x1000_0000),
+        ("CLONE_NEWUTS", 0x0400_0000),
+        ("CLONE_PARENT", 0x8000),
+        ("CLONE_PARENT_SETTID", 0x0010_0000),
+        ("CLONE_PIDFD", 0x1000),
+        ("CLONE_PTRACE", 0x2000),
+        ("CLONE_SETTLS", 0x0008_0000),
+        ("CLONE_SIGHAND", 0x800),
+        ("CLONE_SYSVSEM", 0x0004_0000),
+        ("CLONE_THREAD", 0x0001_0000),
+        ("CLONE_UNTRACED", 0x0080_0000),
+        ("CLONE_VFORK", 0x4000),
+        ("CLONE_VM", 0x100),
+    ];
+
+    static PROT_FLAGS: &[(&str, u64)] = &[
+        ("PROT_EXEC", 0x4),
+        ("PROT_GROWSDOWN", 0x0100_0000),
+        ("PROT_GROWSUP", 0x0200_0000),
+        ("PROT_READ", 0x1),
+        ("PROT_SEM", 0x8),
+        ("PROT_WRITE", 0x2),
+        ("PROT_NONE", 0x0),
+    ];
+
+    static MS_FLAGS: &[(&str, u64)] = &[
+        ("MS_ACTIVE", 0x4000_0000),
+        ("MS_BIND", 0x1000),
+        ("MS_BORN", 0x2000_0000),
+        ("MS_DIRSYNC", 0x80),
+        ("MS_I_VERSION", 0x0080_0000),
+        ("MS_KERNMOUNT", 0x0040_0000),
+        ("MS_LAZYTIME", 0x0200_0000),
+        ("MS_MANDLOCK", 0x40),
+        ("MS_MOVE", 0x2000),
+        ("MS_NOATIME", 0x400),
+        ("MS_NODEV", 0x4),
+        ("MS_NODIRATIME", 0x800),
+        ("MS_NOEXEC", 0x8),
+        ("MS_NOREMOTELOCK", 0x0800_0000),
+        ("MS_NOSEC", 0x1000_0000),
+        ("MS_NOSUID", 0x2),
+        ("MS_NOSYMFOLLOW", 0x100),
+        ("MS_NOUSER", 0x8000_0000),
+        ("MS_POSIXACL", 0x0001_0000),
+        ("MS_PRIVATE", 0x0004_0000),
+        ("MS_RDONLY", 0x1),
+        ("MS_REC", 0x4000),
+        ("MS_RELATIME", 0x0020_0000),
+        ("MS_REMOUNT", 0x20),
+        ("MS_SHARED", 0x0010_0000),
+        ("MS_SILENT", 0x8000),
+        ("MS_SLAVE", 0x0008_0000),
+        ("MS_STRICTATIME", 0x0100_0000),
+        ("MS_SUBMOUNT", 0x0400_0000),
+        ("MS_SYNCHRONOUS", 0x10),
+        ("MS_UNBINDABLE", 0x0002_0000),
+    ];
 }
@@ -0,0 +1,43 @@
+//! `ia64` architecture syscall definitions.
+//!
+//! ia64 was removed from the kernel in Linux 6.7, so the newest table here is
+//! v6.6. These tables are provided for decoding syscall numbers recorded on
+//! older systems; there is no raw syscall backend for this architecture.
+
+pub mod v5_10;
+pub mod v5_15;
+pub mod v5_4;
+pub mod v6_1;
+pub mod v6_6;
+pub mod versions;
+
+// Select kernel version by feature; default to the last version that still
+// shipped ia64 (v6.6).
+#[cfg(all(not(docsrs), feature = "default_kernel_5_4"))]
+pub use v5_4::*;
+#[cfg(all(not(docsrs), feature = "default_kernel_5_10"))]
+pub use v5_10::*;
+#[cfg(all(not(docsrs), feature = "default_kernel_5_15"))]
+pub use v5_15::*;
+#[cfg(all(not(docsrs), feature = "default_kernel_6_1"))]
+pub use v6_1::*;
+#[cfg(all(not(docsrs), feature = "default_kernel_6_6"))]
+pub use v6_6::*;
+
+// Fallback if no default_kernel_* feature is chosen, or if the chosen kernel
+// no longer supports ia64.
+#[cfg(all(
+    not(docsrs),
+    not(any(
+        feature = "default_kernel_5_4",
+        feature = "default_kernel_5_10",
+        feature = "default_kernel_5_15",
+        feature = "default_kernel_6_1",
+        feature = "default_kernel_6_6",
+    ))
+))]
+pub use v6_6::*;
+
+// On docs.rs, avoid enabling multiple versions; always show latest.
+#[cfg(docsrs)]
+pub use v6_6::*;
@@ -0,0 +1,3879 @@
+//! Syscalls for the `ia64` architecture (Linux v6.1).
+
+// This file is automatically generated. Do not edit!
+
+syscall_enum! {
+    pub enum Sysno {
+        /// See [ni_syscall(2)](https://man7.org/linux/man-pages/man2/ni_syscall.2.html) for more info on this syscall.
+        ni_syscall = 1024,
+        /// See [exit(2)](https://man7.org/linux/man-pages/man2/exit.2.html) for more info on this syscall.
+        exit = 1025,
+        /// Read from a file descriptor.
+        ///
+        /// See [read(2)](https://man7.org/linux/man-pages/man2/read.2.html) for more info on this syscall.
+        read = 1026,
+        /// Write to a file descriptor.
+        ///
+        /// See [write(2)](https://man7.org/linux/man-pages/man2/write.2.html) for more info on this syscall.
+        write = 1027,
+        /// Open and possibly create a file.
+        ///
+        /// See [open(2)](https://man7.org/linux/man-pages/man2/open.2.html) for more info on this syscall.
+        open = 1028,
+        /// Close a file descriptor.
+        ///
+        /// See [close(2)](https://man7.org/linux/man-pages/man2/close.2.html) for more info on this syscall.
+        close = 1029,
+        /// Open and possibly create a file.
+        ///
+        /// See [creat(2)](https://man7.org/linux/man-pages/man2/creat.2.html) for more info on this syscall.
+        creat = 1030,
+        /// Make a new name for a file.
+        ///
+        /// See [link(2)](https://man7.org/linux/man-pages/man2/link.2.html) for more info on this syscall.
+        link = 1031,
+        /// Delete a name and possibly the file it refers to.
+        ///
+        /// See [unlink(2)](https://man7.org/linux/man-pages/man2/unlink.2.html) for more info on this syscall.
+        unlink = 1032,
+        /// Execute program.
+        ///
+        /// See [execve(2)](https://man7.org/linux/man-pages/man2/execve.2.html) for more info on this syscall.
+        execve = 1033,
+        /// Change working directory.
+        ///
+        /// See [chdir(2)](https://man7.org/linux/man-pages/man2/chdir.2.html) for more info on this syscall.
+        chdir = 1034,
+        /// Change working directory.
+        ///
+        /// See [fchdir(2)](https://man7.org/linux/man-pages/man2/fchdir.2.html) for more info on this syscall.
+        fchdir = 1035,
+        /// Change file last access and modification times.
+        ///
+        /// See [utimes(2)](https://man7.org/linux/man-pages/man2/utimes.2.html) for more info on this syscall.
+        utimes = 1036,
+        /// Create a special or ordinary file.
+        ///
+        /// See [mknod(2)](https://man7.org/linux/man-pages/man2/mknod.2.html) for more info on this syscall.
+        mknod = 1037,
+        /// Change permissions of a file.
+        ///
+        /// See [chmod(2)](https://man7.org/linux/man-pages/man2/chmod.2.html) for more info on this syscall.
+        chmod = 1038,
+        /// Change ownership of a file.
+        ///
+        /// See [chown(2)](https://man7.org/linux/man-pages/man2/chown.2.html) for more info on this syscall.
+        chown = 1039,
+        /// Reposition read/write file offset.
+        ///
+        /// See [lseek(2)](https://man7.org/linux/man-pages/man2/lseek.2.html) for more info on this syscall.
+        lseek = 1040,
+        /// Get process identification.
+        ///
+        /// See [getpid(2)](https://man7.org/linux/man-pages/man2/getpid.2.html) for more info on this syscall.
+        getpid = 1041,
+        /// Get process identification.
+        ///
+        /// See [getppid(2)](https://man7.org/linux/man-pages/man2/getppid.2.html) for more info on this syscall.
+        getppid = 1042,
+        /// Mount filesystem.
+        ///
+        /// See [mount(2)](https://man7.org/linux/man-pages/man2/mount.2.html) for more info on this syscall.
+        mount = 1043,
+        /// Unmount filesystem.
+        ///
+        /// See [umount2(2)](https://man7.org/linux/man-pages/man2/umount2.2.html) for more info on this syscall.
+        umount2 = 1044,
+        /// Set user identity.
+        ///
+        /// See [setuid(2)](https://man7.org/linux/man-pages/man2/setuid.2.html) for more info on this syscall.
+        setuid = 1045,
+        /// Get user identity.
+        ///
+        /// See [getuid(2)](https://man7.org/linux/man-pages/man2/getuid.2.html) for more info on this syscall.
+        getuid = 1046,
+        /// Get user identity.
+        ///
+        /// See [geteuid(2)](https://man7.org/linux/man-pages/man2/geteuid.2.html) for more info on this syscall.
+        geteuid = 1047,
+        /// Process trace.
+        ///
+        /// See [ptrace(2)](https://man7.org/linux/man-pages/man2/ptrace.2.html) for more info on this syscall.
+        ptrace = 1048,
+        /// Check user's permissions for a file.
+        ///
+        /// See [access(2)](https://man7.org/linux/man-pages/man2/access.2.html) for more info on this syscall.
+        access = 1049,
+        /// Commit filesystem caches to disk.
+        ///
+        /// See [sync(2)](https://man7.org/linux/man-pages/man2/sync.2.html) for more info on this syscall.
+        sync = 1050,
+        /// Synchronize a file's in-core state with storage device.
+        ///
+        /// See [fsync(2)](https://man7.org/linux/man-pages/man2/fsync.2.html) for more info on this syscall.
+        fsync = 1051,
+        /// Synchronize a file's in-core state with storage device.
+        ///
+        /// See [fdatasync(2)](https://man7.org/linux/man-pages/man2/fdatasync.2.html) for more info on this syscall.
+        fdatasync = 1052,
+        /// Send signal to a process.
+        ///
+        /// See [kill(2)](https://man7.org/linux/man-pages/man2/kill.2.html) for more info on this syscall.
+        kill = 1053,
+        /// Change the name or location of a file.
+        ///
+        /// See [rename(2)](https://man7.org/linux/man-pages/man2/rename.2.html) for more info on this syscall.
+        rename = 1054,
+        /// Create a directory.
+        ///
+        /// See [mkdir(2)](https://man7.org/linux/man-pages/man2/mkdir.2.html) for more info on this syscall.
+        mkdir = 1055,
+        /// Delete a directory.
+        ///
+        /// See [rmdir(2)](https://man7.org/linux/man-pages/man2/rmdir.2.html) for more info on this syscall.
+        rmdir = 1056,
+        /// Duplicate a file descriptor.
+        ///
+        /// See [dup(2)](https://man7.org/linux/man-pages/man2/dup.2.html) for more info on this syscall.
+        dup = 1057,
+        /// Create pipe.
+        ///
+        /// See [pipe(2)](https://man7.org/linux/man-pages/man2/pipe.2.html) for more info on this syscall.
+        pipe = 1058,
+        /// Get process times.
+        ///
+        /// See [times(2)](https://man7.org/linux/man-pages/man2/times.2.html) for more info on this syscall.
+        times = 1059,
+        /// Change data segment size.
+        ///
+        /// See [brk(2)](https://man7.org/linux/man-pages/man2/brk.2.html) for more info on this syscall.
+        brk = 1060,
+        /// Set group identity.
+        ///
+        /// See [setgid(2)](https://man7.org/linux/man-pages/man2/setgid.2.html) for more info on this syscall.
+        setgid = 1061,
+        /// Get group identity.
+        ///
+        /// See [getgid(2)](https://man7.org/linux/man-pages/man2/getgid.2.html) for more info on this syscall.
+        getgid = 1062,
+        /// Get group identity.
+        ///
+        /// See [getegid(2)](https://man7.org/linux/man-pages/man2/getegid.2.html) for more info on this syscall.
+        getegid = 1063,
+        /// Switch process accounting on or off.
+        ///
+        /// See [acct(2)](https://man7.org/linux/man-pages/man2/acct.2.html) for more info on this syscall.
+        acct = 1064,
+        /// Control device.
+        ///
+        /// See [ioctl(2)](https://man7.org/linux/man-pages/man2/ioctl.2.html) for more info on this syscall.
+        ioctl = 1065,
+        /// Manipulate file descriptor.
+        ///
+        /// See [fcntl(2)](https://man7.org/linux/man-pages/man2/fcntl.2.html) for more info on this syscall.
+        fcntl = 1066,
+        /// Set file mode creation mask.
+        ///
+        /// See [umask(2)](https://man7.org/linux/man-pages/man2/umask.2.html) for more info on this syscall.
+        umask = 1067,
+        /// Change root directory.
+        ///
+        /// See [chroot(2)](https://man7.org/linux/man-pages/man2/chroot.2.html) for more info on this syscall.
+        chroot = 1068,
+        /// Get filesystem statistics.
+        ///
+        /// See [ustat(2)](https://man7.org/linux/man-pages/man2/ustat.2.html) for more info on this syscall.
+        ustat = 1069,
+        /// Duplicate a file descriptor.
+        ///
+        /// See [dup2(2)](https://man7.org/linux/man-pages/man2/dup2.2.html) for more info on this syscall.
+        dup2 = 1070,
+        /// Set real and/or effective user or group ID.
+        ///
+        /// See [setreuid(2)](https://man7.org/linux/man-pages/man2/setreuid.2.html) for more info on this syscall.
+        setreuid = 1071,
+        /// Set real and/or effective user or group ID.
+        ///
+        /// See [setregid(2)](https://man7.org/linux/man-pages/man2/setregid.2.html) for more info on this syscall.
+        setregid = 1072,
+        /// Get real, effective, and saved user/group IDs.
+        ///
+        /// See [getresuid(2)](https://man7.org/linux/man-pages/man2/getresuid.2.html) for more info on this syscall.
+        getresuid = 1073,
+        /// Set real, effective, and saved user or group ID.
+        ///
+        /// See [setresuid(2)](https://man7.org/linux/man-pages/man2/setresuid.2.html) for more info on this syscall.
+        setresuid = 1074,
+        /// Get real, effective, and saved user/group IDs.
+        ///
+        /// See [getresgid(2)](https://man7.org/linux/man-pages/man2/getresgid.2.html) for more info on this syscall.
+        getresgid = 1075,
+        /// Set real, effective, and saved user or group ID.
+        ///
+        /// See [setresgid(2)](https://man7.org/linux/man-pages/man2/setresgid.2.html) for more info on this syscall.
+        setresgid = 1076,
+        /// Get/set list of supplementary group IDs.
+        ///
+        /// See [getgroups(2)](https://man7.org/linux/man-pages/man2/getgroups.2.html) for more info on this syscall.
+        getgroups = 1077,
+        /// Get/set list of supplementary group IDs.
+        ///
+        /// See [setgroups(2)](https://man7.org/linux/man-pages/man2/setgroups.2.html) for more info on this syscall.
+        setgroups = 1078,
+        /// Set/get process group.
+        ///
+        /// See [getpgid(2)](https://man7.org/linux/man-pages/man2/getpgid.2.html) for more info on this syscall.
+        getpgid = 1079,
+        /// Set/get process group.
+        ///
+        /// See [setpgid(2)](https://man7.org/linux/man-pages/man2/setpgid.2.html) for more info on this syscall.
+        setpgid = 1080,
+        /// Creates a session and sets the process group ID.
+        ///
+        /// See [setsid(2)](https://man7.org/linux/man-pages/man2/setsid.2.html) for more info on this syscall.
+        setsid = 1081,
+        /// Get session ID.
+        ///
+        /// See [getsid(2)](https://man7.org/linux/man-pages/man2/getsid.2.html) for more info on this syscall.
+        getsid = 1082,
+        /// Get/set hostname.
+        ///
+        /// See [sethostname(2)](https://man7.org/linux/man-pages/man2/sethostname.2.html) for more info on this syscall.
+        sethostname = 1083,
+        /// Get/set resource limits.
+        ///
+        /// See [setrlimit(2)](https://man7.org/linux/man-pages/man2/setrlimit.2.html) for more info on this syscall.
+        setrlimit = 1084,
+        /// Get/set resource limits.
+        ///
+        /// See [getrlimit(2)](https://man7.org/linux/man-pages/man2/getrlimit.2.html) for more info on this syscall.
+        getrlimit = 1085,
+        /// Get resource usage.
+        ///
+        /// See [getrusage(2)](https://man7.org/linux/man-pages/man2/getrusage.2.html) for more info on this syscall.
+        getrusage = 1086,
+        /// Get / set time.
+        ///
+        /// See [gettimeofday(2)](https://man7.org/linux/man-pages/man2/gettimeofday.2.html) for more info on this syscall.
+        gettimeofday = 1087,
+        /// Get / set time.
+        ///
+        /// See [settimeofday(2)](https://man7.org/linux/man-pages/man2/settimeofday.2.html) for more info on this syscall.
+        settimeofday = 1088,
+        /// Synchronous I/O multiplexing.
+        ///
+        /// See [select(2)](https://man7.org/linux/man-pages/man2/select.2.html) for more info on this syscall.
+        select = 1089,
+        /// Wait for some event on a file descriptor.
+        ///
+        /// See [poll(2)](https://man7.org/linux/man-pages/man2/poll.2.html) for more info on this syscall.
+        poll = 1090,
+        /// Make a new name for a file.
+        ///
+        /// See [symlink(2)](https://man7.org/linux/man-pages/man2/symlink.2.html) for more info on this syscall.
+        symlink = 1091,
+        /// Read value of a symbolic link.
+        ///
+        /// See [readlink(2)](https://man7.org/linux/man-pages/man2/readlink.2.html) for more info on this syscall.
+        readlink = 1092,
+        /// Load shared library.
+        ///
+        /// See [uselib(2)](https://man7.org/linux/man-pages/man2/uselib.2.html) for more info on this syscall.
+        uselib = 1093,
+        /// Start/stop swapping to file/device.
+        ///
+        /// See [swapon(2)](https://man7.org/linux/man-pages/man2/swapon.2.html) for more info on this syscall.
+        swapon = 1094,
+        /// Start/stop swapping to file/device.
+        ///
+        /// See [swapoff(2)](https://man7.org/linux/man-pages/man2/swapoff.2.html) for more info on this syscall.
+        swapoff = 1095,
+        /// Reboot or enable/disable Ctrl-Alt-Del.
+        ///
+        /// See [reboot(2)](https://man7.org/linux/man-pages/man2/reboot.2.html) for more info on this syscall.
+        reboot = 1096,
+        /// Truncate a file to a specified length.
+        ///
+        /// See [truncate(2)](https://man7.org/linux/man-pages/man2/truncate.2.html) for more info on this syscall.
+        truncate = 1097,
+        /// Truncate a file to a specified length.
+        ///
+        /// See [ftruncate(2)](https://man7.org/linux/man-pages/man2/ftruncate.2.html) for more info on this syscall.
+        ftruncate = 1098,
+        /// Change permissions of a file.
+        ///
+        /// See [fchmod(2)](https://man7.org/linux/man-pages/man2/fchmod.2.html) for more info on this syscall.
+        fchmod = 1099,
+        /// Change ownership of a file.
+        ///
+        /// See [fchown(2)](https://man7.org/linux/man-pages/man2/fchown.2.html) for more info on this syscall.
+        fchown = 1100,
+        /// Get/set program scheduling priority.
+        ///
+        /// See [getpriority(2)](https://man7.org/linux/man-pages/man2/getpriority.2.html) for more info on this syscall.
+        getpriority = 1101,
+        /// Get/set program scheduling priority.
+        ///
+        /// See [setpriority(2)](https://man7.org/linux/man-pages/man2/setpriority.2.html) for more info on this syscall.
+        setpriority = 1102,
+        /// Get filesystem statistics.
+        ///
+        /// See [statfs(2)](https://man7.org/linux/man-pages/man2/statfs.2.html) for more info on this syscall.
+        statfs = 1103,
+        /// Get filesystem statistics.
+        ///
+        /// See [fstatfs(2)](https://man7.org/linux/man-pages/man2/fstatfs.2.html) for more info on this syscall.
+        fstatfs = 1104,
+        /// Get thread identification.
+        ///
+        /// See [gettid(2)](https://man7.org/linux/man-pages/man2/gettid.2.html) for more info on this syscall.
+        gettid = 1105,
+        /// Get a System V semaphore set identifier.
+        ///
+        /// See [semget(2)](https://man7.org/linux/man-pages/man2/semget.2.html) for more info on this syscall.
+        semget = 1106,
+        /// System V semaphore operations.
+        ///
+        /// See [semop(2)](https://man7.org/linux/man-pages/man2/semop.2.html) for more info on this syscall.
+        semop = 1107,
+        /// System V semaphore control operations.
+        ///
+        /// See [semctl(2)](https://man7.org/linux/man-pages/man2/semctl.2.html) for more info on this syscall.
+        semctl = 1108,
+        /// Get a System V message queue identifier.
+        ///
+        /// See [msgget(2)](https://man7.org/linux/man-pages/man2/msgget.2.html) for more info on this syscall.
+        msgget = 1109,
+        /// System V message queue operations.
+        ///
+        /// See [msgsnd(2)](https://man7.org/linux/man-pages/man2/msgsnd.2.html) for more info on this syscall.
+        msgsnd = 1110,
+        /// System V message queue operations.
+        ///
+        /// See [msgrcv(2)](https://man7.org/linux/man-pages/man2/msgrcv.2.html) for more info on this syscall.
+        msgrcv = 1111,
+        /// System V message control operations.
+        ///
+        /// See [msgctl(2)](https://man7.org/linux/man-pages/man2/msgctl.2.html) for more info on this syscall.
+        msgctl = 1112,
+        /// Allocates a System V shared memory segment.
+        ///
+        /// See [shmget(2)](https://man7.org/linux/man-pages/man2/shmget.2.html) for more info on this syscall.
+        shmget = 1113,
+        /// System V shared memory operations.
+        ///
+        /// See [shmat(2)](https://man7.org/linux/man-pages/man2/shmat.2.html) for more info on this syscall.
+        shmat = 1114,
+        /// System V shared memory operations.
+        ///
+        /// See [shmdt(2)](https://man7.org/linux/man-pages/man2/shmdt.2.html) for more info on this syscall.
+        shmdt = 1115,
+        /// System V shared memory control.
+        ///
+        /// See [shmctl(2)](https://man7.org/linux/man-pages/man2/shmctl.2.html) for more info on this syscall.
+        shmctl = 1116,
+        /// Read and/or clear kernel message ring buffer; set `console_loglevel`.
+        ///
+        /// See [syslog(2)](https://man7.org/linux/man-pages/man2/syslog.2.html) for more info on this syscall.
+        syslog = 1117,
+        /// Get or set value of an interval timer.
+        ///
+        /// See [setitimer(2)](https://man7.org/linux/man-pages/man2/setitimer.2.html) for more info on this syscall.
+        setitimer = 1118,
+        /// Get or set value of an interval timer.
+        ///
+        /// See [getitimer(2)](https://man7.org/linux/man-pages/man2/getitimer.2.html) for more info on this syscall.
+        getitimer = 1119,
+        /// Virtually hangup the current terminal.
+        ///
+        /// See [vhangup(2)](https://man7.org/linux/man-pages/man2/vhangup.2.html) for more info on this syscall.
+        vhangup = 1123,
+        /// Change ownership of a file.
+        ///
+        /// See [lchown(2)](https://man7.org/linux/man-pages/man2/lchown.2.html) for more info on this syscall.
+        lchown = 1124,
+        /// Create a nonlinear file mapping.
+        ///
+        /// See [remap_file_pages(2)](https://man7.org/linux/man-pages/man2/remap_file_pages.2.html) for more info on this syscall.
+        remap_file_pages = 1125,
+        /// Wait for process to change state, BSD style.
+        ///
+        /// See [wait4(2)](https://man7.org/linux/man-pages/man2/wait4.2.html) for more info on this syscall.
+        wait4 = 1126,
+        /// Return system information.
+        ///
+        /// See [sysinfo(2)](https://man7.org/linux/man-pages/man2/sysinfo.2.html) for more info on this syscall.
+        sysinfo = 1127,
+        /// Create a child process.
+        ///
+        /// See [clone(2)](https://man7.org/linux/man-pages/man2/clone.2.html) for more info on this syscall.
+        clone = 1128,
+        /// Get/set NIS domain name.
+        ///
+        /// See [setdomainname(2)](https://man7.org/linux/man-pages/man2/setdomainname.2.html) for more info on this syscall.
+        setdomainname = 1129,
+        /// Get name and information about current kernel.
+        ///
+        /// See [uname(2)](https://man7.org/linux/man-pages/man2/uname.2.html) for more info on this syscall.
+        uname = 1130,
+        /// Tune kernel clock.
+        ///
+        /// See [adjtimex(2)](https://man7.org/linux/man-pages/man2/adjtimex.2.html) for more info on this syscall.
+        adjtimex = 1131,
+        /// Load a kernel module.
+        ///
+        /// See [init_module(2)](https://man7.org/linux/man-pages/man2/init_module.2.html) for more info on this syscall.
+        init_module = 1133,
+        /// Unload a kernel module.
+        ///
+        /// See [delete_module(2)](https://man7.org/linux/man-pages/man2/delete_module.2.html) for more info on this syscall.
+        delete_module = 1134,
+        /// Manipulate disk quotas.
+        ///
+        /// See [quotactl(2)](https://man7.org/linux/man-pages/man2/quotactl.2.html) for more info on this syscall.
+        quotactl = 1137,
+        /// Start, flush, or tune buffer-dirty-flush daemon.
+        ///
+        /// See [bdflush(2)](https://man7.org/linux/man-pages/man2/bdflush.2.html) for more info on this syscall.
+        bdflush = 1138,
+        /// Get filesystem type information.
+        ///
+        /// See [sysfs(2)](https://man7.org/linux/man-pages/man2/sysfs.2.html) for more info on this syscall.
+        sysfs = 1139,
+        /// Set the process execution domain.
+        ///
+        /// See [personality(2)](https://man7.org/linux/man-pages/man2/personality.2.html) for more info on this syscall.
+        personality = 1140,
+        /// Unimplemented system calls.
+        ///
+        /// See [afs_syscall(2)](https://man7.org/linux/man-pages/man2/afs_syscall.2.html) for more info on this syscall.
+        afs_syscall = 1141,
+        /// Set user identity used for filesystem checks.
+        ///
+        /// See [setfsuid(2)](https://man7.org/linux/man-pages/man2/setfsuid.2.html) for more info on this syscall.
+        setfsuid = 1142,
+        /// Set group identity used for filesystem checks.
+        ///
+        /// See [setfsgid(2)](https://man7.org/linux/man-pages/man2/setfsgid.2.html) for more info on this syscall.
+        setfsgid = 1143,
+        /// Get directory entries.
+        ///
+        /// See [getdents(2)](https://man7.org/linux/man-pages/man2/getdents.2.html) for more info on this syscall.
+        getdents = 1144,
+        /// Apply or remove an advisory lock on an open file.
+        ///
+        /// See [flock(2)](https://man7.org/linux/man-pages/man2/flock.2.html) for more info on this syscall.
+        flock = 1145,
+        /// Read or write data into multiple buffers.
+        ///
+        /// See [readv(2)](https://man7.org/linux/man-pages/man2/readv.2.html) for more info on this syscall.
+        readv = 1146,
+        /// Read or write data into multiple buffers.
+        ///
+        /// See [writev(2)](https://man7.org/linux/man-pages/man2/writev.2.html) for more info on this syscall.
+        writev = 1147,
+        /// Read from or write to a file descriptor at a given offset.
+        ///
+        /// See [pread64(2)](https://man7.org/linux/man-pages/man2/pread64.2.html) for more info on this syscall.
+        pread64 = 1148,
+        /// Read from or write to a file descriptor at a given offset.
+        ///
+        /// See [pwrite64(2)](https://man7.org/linux/man-pages/man2/pwrite64.2.html) for more info on this syscall.
+        pwrite64 = 1149,
+        /// See [_sysctl(2)](https://man7.org/linux/man-pages/man2/_sysctl.2.html) for more info on this syscall.
+        _sysctl = 1150,
+        /// Map or unmap files or devices into memory.
+        ///
+        /// See [mmap(2)](https://man7.org/linux/man-pages/man2/mmap.2.html) for more info on this syscall.
+        mmap = 1151,
+        /// Map or unmap files or devices into memory.
+        ///
+        /// See [munmap(2)](https://man7.org/linux/man-pages/man2/munmap.2.html) for more info on this syscall.
+        munmap = 1152,
+        /// Lock and unlock memory.
+        ///
+        /// See [mlock(2)](https://man7.org/linux/man-pages/man2/mlock.2.html) for more info on this syscall.
+        mlock = 1153,
+        /// Lock and unlock memory.
+        ///
+        /// See [mlockall(2)](https://man7.org/linux/man-pages/man2/mlockall.2.html) for more info on this syscall.
+        mlockall = 1154,
+        /// Set protection on a region of memory.
+        ///
+        /// See [mprotect(2)](https://man7.org/linux/man-pages/man2/mprotect.2.html) for more info on this syscall.
+        mprotect = 1155,
+        /// Remap a virtual memory address.
+        ///
+        /// See [mremap(2)](https://man7.org/linux/man-pages/man2/mremap.2.html) for more info on this syscall.
+        mremap = 1156,
+        /// Synchronize a file with a memory map.
+        ///
+        /// See [msync(2)](https://man7.org/linux/man-pages/man2/msync.2.html) for more info on this syscall.
+        msync = 1157,
+        /// Lock and unlock memory.
+        ///
+        /// See [munlock(2)](https://man7.org/linux/man-pages/man2/munlock.2.html) for more info on this syscall.
+        munlock = 1158,
+        /// Lock and unlock memory.
+        ///
+        /// See [munlockall(2)](https://man7.org/linux/man-pages/man2/munlockall.2.html) for more info on this syscall.
+        munlockall = 1159,
+        /// Set and get scheduling parameters.
+        ///
+        /// See [sched_getparam(2)](https://man7.org/linux/man-pages/man2/sched_getparam.2.html) for more info on this syscall.
+        sched_getparam = 1160,
+        /// Set and get scheduling parameters.
+        ///
+        /// See [sched_setparam(2)](https://man7.org/linux/man-pages/man2/sched_setparam.2.html) for more info on this syscall.
+        sched_setparam = 1161,
+        /// Set and get scheduling policy/parameters.
+        ///
+        /// See [sched_getscheduler(2)](https://man7.org/linux/man-pages/man2/sched_getscheduler.2.html) for more info on this syscall.
+        sched_getscheduler = 1162,
+        /// Set and get scheduling policy/parameters.
+        ///
+        /// See [sched_setscheduler(2)](https://man7.org/linux/man-pages/man2/sched_setscheduler.2.html) for more info on this syscall.
+        sched_setscheduler = 1163,
+        /// Yield the processor.
+        ///
+        /// See [sched_yield(2)](https://man7.org/linux/man-pages/man2/sched_yield.2.html) for more info on this syscall.
+        sched_yield = 1164,
+        /// Get static priority range.
+        ///
+        /// See [sched_get_priority_max(2)](https://man7.org/linux/man-pages/man2/sched_get_priority_max.2.html) for more info on this syscall.
+        sched_get_priority_max = 1165,
+        /// Get static priority range.
+        ///
+        /// See [sched_get_priority_min(2)](https://man7.org/linux/man-pages/man2/sched_get_priority_min.2.html) for more info on this syscall.
+        sched_get_priority_min = 1166,
+        /// Get the `SCHED_RR` interval for the named process.
+        ///
+        /// See [sched_rr_get_interval(2)](https://man7.org/linux/man-pages/man2/sched_rr_get_interval.2.html) for more info on this syscall.
+        sched_rr_get_interval = 1167,
+        /// High-resolution sleep.
+        ///
+        /// See [nanosleep(2)](https://man7.org/linux/man-pages/man2/nanosleep.2.html) for more info on this syscall.
+        nanosleep = 1168,
+        /// Syscall interface to kernel nfs daemon.
+        ///
+        /// See [nfsservctl(2)](https://man7.org/linux/man-pages/man2/nfsservctl.2.html) for more info on this syscall.
+        nfsservctl = 1169,
+        /// Operations on a process or thread.
+        ///
+        /// See [prctl(2)](https://man7.org/linux/man-pages/man2/prctl.2.html) for more info on this syscall.
+        prctl = 1170,
+        /// See [old_getpagesize(2)](https://man7.org/linux/man-pages/man2/old_getpagesize.2.html) for more info on this syscall.
+        old_getpagesize = 1171,
+        /// Map files or devices into memory.
+        ///
+        /// See [mmap2(2)](https://man7.org/linux/man-pages/man2/mmap2.2.html) for more info on this syscall.
+        mmap2 = 1172,
+        /// Pci device information handling.
+        ///
+        /// See [pciconfig_read(2)](https://man7.org/linux/man-pages/man2/pciconfig_read.2.html) for more info on this syscall.
+        pciconfig_read = 1173,
+        /// Pci device information handling.
+        ///
+        /// See [pciconfig_write(2)](https://man7.org/linux/man-pages/man2/pciconfig_write.2.html) for more info on this syscall.
+        pciconfig_write = 1174,
+        /// Interface to IA-64 performance monitoring unit.
+        ///
+        /// See [perfmonctl(2)](https://man7.org/linux/man-pages/man2/perfmonctl.2.html) for more info on this syscall.
+        perfmonctl = 1175,
+        /// Set and/or get signal stack context.
+        ///
+        /// See [sigaltstack(2)](https://man7.org/linux/man-pages/man2/sigaltstack.2.html) for more info on this syscall.
+        sigaltstack = 1176,
+        /// Examine and change a signal action.
+        ///
+        /// See [rt_sigaction(2)](https://man7.org/linux/man-pages/man2/rt_sigaction.2.html) for more info on this syscall.
+        rt_sigaction = 1177,
+        /// Examine pending signals.
+        ///
+        /// See [rt_sigpending(2)](https://man7.org/linux/man-pages/man2/rt_sigpending.2.html) for more info on this syscall.
+        rt_sigpending = 1178,
+        /// Examine and change blocked signals.
+        ///
+        /// See [rt_sigprocmask(2)](https://man7.org/linux/man-pages/man2/rt_sigprocmask.2.html) for more info on this syscall.
+        rt_sigprocmask = 1179,
+        /// Queue a signal and data.
+        ///
+        /// See [rt_sigqueueinfo(2)](https://man7.org/linux/man-pages/man2/rt_sigqueueinfo.2.html) for more info on this syscall.
+        rt_sigqueueinfo = 1180,
+        /// Return from signal handler and cleanup stack frame.
+        ///
+        /// See [rt_sigreturn(2)](https://man7.org/linux/man-pages/man2/rt_sigreturn.2.html) for more info on this syscall.
+        rt_sigreturn = 1181,
+        /// Wait for a signal.
+        ///
+        /// See [rt_sigsuspend(2)](https://man7.org/linux/man-pages/man2/rt_sigsuspend.2.html) for more info on this syscall.
+        rt_sigsuspend = 1182,
+        /// Synchronously wait for queued signals.
+        ///
+        /// See [rt_sigtimedwait(2)](https://man7.org/linux/man-pages/man2/rt_sigtimedwait.2.html) for more info on this syscall.
+        rt_sigtimedwait = 1183,
+        /// Get current working directory.
+        ///
+        /// See [getcwd(2)](https://man7.org/linux/man-pages/man2/getcwd.2.html) for more info on this syscall.
+        getcwd = 1184,
+        /// Set/get capabilities of thread(s).
+        ///
+        /// See [capget(2)](https://man7.org/linux/man-pages/man2/capget.2.html) for more info on this syscall.
+        capget = 1185,
+        /// Set/get capabilities of thread(s).
+        ///
+        /// See [capset(2)](https://man7.org/linux/man-pages/man2/capset.2.html) for more info on this syscall.
+        capset = 1186,
+        /// Transfer data between file descriptors.
+        ///
+        /// See [sendfile(2)](https://man7.org/linux/man-pages/man2/sendfile.2.html) for more info on this syscall.
+        sendfile = 1187,
+        /// Unimplemented system calls.
+        ///
+        /// See [getpmsg(2)](https://man7.org/linux/man-pages/man2/getpmsg.2.html) for more info on this syscall.
+        getpmsg = 1188,
+        /// Unimplemented system calls.
+        ///
+        /// See [putpmsg(2)](https://man7.org/linux/man-pages/man2/putpmsg.2.html) for more info on this syscall.
+        putpmsg = 1189,
+        /// Create an endpoint for communication.
+        ///
+        /// See [socket(2)](https://man7.org/linux/man-pages/man2/socket.2.html) for more info on this syscall.
+        socket = 1190,
+        /// Bind a name to a socket.
+        ///
+        /// See [bind(2)](https://man7.org/linux/man-pages/man2/bind.2.html) for more info on this syscall.
+        bind = 1191,
+        /// Initiate a connection on a socket.
+        ///
+        /// See [connect(2)](https://man7.org/linux/man-pages/man2/connect.2.html) for more info on this syscall.
+        connect = 1192,
+        /// Listen for connections on a socket.
+        ///
+        /// See [listen(2)](https://man7.org/linux/man-pages/man2/listen.2.html) for more info on this syscall.
+        listen = 1193,
+        /// Accept a connection on a socket.
+        ///
+        /// See [accept(2)](https://man7.org/linux/man-pages/man2/accept.2.html) for more info on this syscall.
+        accept = 1194,
+        /// Get socket name.
+        ///
+        /// See [getsockname(2)](https://man7.org/linux/man-pages/man2/getsockname.2.html) for more info on this syscall.
+        getsockname = 1195,
+        /// Get name of connected peer socket.
+        ///
+        /// See [getpeername(2)](https://man7.org/linux/man-pages/man2/getpeername.2.html) for more info on this syscall.
+        getpeername = 1196,
+        /// Create a pair of connected sockets.
+        ///
+        /// See [socketpair(2)](https://man7.org/linux/man-pages/man2/socketpair.2.html) for more info on this syscall.
+        socketpair = 1197,
+        /// Send a message on a socket.
+        ///
+        /// See [send(2)](https://man7.org/linux/man-pages/man2/send.2.html) for more info on this syscall.
+        send = 1198,
+        /// Send a message on a socket.
+        ///
+        /// See [sendto(2)](https://man7.org/linux/man-pages/man2/sendto.2.html) for more info on this syscall.
+        sendto = 1199,
+        /// Receive a message from a socket.
+        ///
+        /// See [recv(2)](https://man7.org/linux/man-pages/man2/recv.2.html) for more info on this syscall.
+        recv = 1200,
+        /// Receive a message from a socket.
+        ///
+        /// See [recvfrom(2)](https://man7.org/linux/man-pages/man2/recvfrom.2.html) for more info on this syscall.
+        recvfrom = 1201,
+        /// Shut down part of a full-duplex connection.
+        ///
+        /// See [shutdown(2)](https://man7.org/linux/man-pages/man2/shutdown.2.html) for more info on this syscall.
+        shutdown = 1202,
+        /// Get and set options on sockets.
+        ///
+        /// See [setsockopt(2)](https://man7.org/linux/man-pages/man2/setsockopt.2.html) for more info on this syscall.
+        setsockopt = 1203,
+        /// Get and set options on sockets.
+        ///
+        /// See [getsockopt(2)](https://man7.org/linux/man-pages/man2/getsockopt.2.html) for more info on this syscall.
+        getsockopt = 1204,
+        /// Send a message on a socket.
+        ///
+        /// See [sendmsg(2)](https://man7.org/linux/man-pages/man2/sendmsg.2.html) for more info on this syscall.
+        sendmsg = 1205,
+        /// Receive a message from a socket.
+        ///
+        /// See [recvmsg(2)](https://man7.org/linux/man-pages/man2/recvmsg.2.html) for more info on this syscall.
+        recvmsg = 1206,
+        /// Change the root mount.
+        ///
+        /// See [pivot_root(2)](https://man7.org/linux/man-pages/man2/pivot_root.2.html) for more info on this syscall.
+        pivot_root = 1207,
+        /// Determine whether pages are resident in memory.
+        ///
+        /// See [mincore(2)](https://man7.org/linux/man-pages/man2/mincore.2.html) for more info on this syscall.
+        mincore = 1208,
+        /// Give advice about use of memory.
+        ///
+        /// See [madvise(2)](https://man7.org/linux/man-pages/man2/madvise.2.html) for more info on this syscall.
+        madvise = 1209,
+        /// Get file status.
+        ///
+        /// See [stat(2)](https://man7.org/linux/man-pages/man2/stat.2.html) for more info on this syscall.
+        stat = 1210,
+        /// Get file status.
+        ///
+        /// See [lstat(2)](https://man7.org/linux/man-pages/man2/lstat.2.html) for more info on this syscall.
+        lstat = 1211,
+        /// Get file status.
+        ///
+        /// See [fstat(2)](https://man7.org/linux/man-pages/man2/fstat.2.html) for more info on this syscall.
+        fstat = 1212,
+        /// See [clone2(2)](https://man7.org/linux/man-pages/man2/clone2.2.html) for more info on this syscall.
+        clone2 = 1213,
+        /// Get directory entries.
+        ///
+        /// See [getdents64(2)](https://man7.org/linux/man-pages/man2/getdents64.2.html) for more info on this syscall.
+        getdents64 = 1214,
+        /// Copy the unwind data to caller's buffer.
+        ///
+        /// See [getunwind(2)](https://man7.org/linux/man-pages/man2/getunwind.2.html) for more info on this syscall.
+        getunwind = 1215,
+        /// Initiate file readahead into page cache.
+        ///
+        /// See [readahead(2)](https://man7.org/linux/man-pages/man2/readahead.2.html) for more info on this syscall.
+        readahead = 1216,
+        /// Set an extended attribute value.
+        ///
+        /// See [setxattr(2)](https://man7.org/linux/man-pages/man2/setxattr.2.html) for more info on this syscall.
+        setxattr = 1217,
+        /// Set an extended attribute value.
+        ///
+        /// See [lsetxattr(2)](https://man7.org/linux/man-pages/man2/lsetxattr.2.html) for more info on this syscall.
+        lsetxattr = 1218,
+        /// Set an extended attribute value.
+        ///
+        /// See [fsetxattr(2)](https://man7.org/linux/man-pages/man2/fsetxattr.2.html) for more info on this syscall.
+        fsetxattr = 1219,
+        /// Retrieve an extended attribute value.
+        ///
+        /// See [getxattr(2)](https://man7.org/linux/man-pages/man2/getxattr.2.html) for more info on this syscall.
+        getxattr = 1220,
+        /// Retrieve an extended attribute value.
+        ///
+        /// See [lgetxattr(2)](https://man7.org/linux/man-pages/man2/lgetxattr.2.html) for more info on this syscall.
+        lgetxattr = 1221,
+        /// Retrieve an extended attribute value.
+        ///
+        /// See [fgetxattr(2)](https://man7.org/linux/man-pages/man2/fgetxattr.2.html) for more info on this syscall.
+        fgetxattr = 1222,
+        /// List extended attribute names.
+        ///
+        /// See [listxattr(2)](https://man7.org/linux/man-pages/man2/listxattr.2.html) for more info on this syscall.
+        listxattr = 1223,
+        /// List extended attribute names.
+        ///
+        /// See [llistxattr(2)](https://man7.org/linux/man-pages/man2/llistxattr.2.html) for more info on this syscall.
+        llistxattr = 1224,
+        /// List extended attribute names.
+        ///
+        /// See [flistxattr(2)](https://man7.org/linux/man-pages/man2/flistxattr.2.html) for more info on this syscall.
+        flistxattr = 1225,
+        /// Remove an extended attribute.
+        ///
+        /// See [removexattr(2)](https://man7.org/linux/man-pages/man2/removexattr.2.html) for more info on this syscall.
+        removexattr = 1226,
+        /// Remove an extended attribute.
+        ///
+        /// See [lremovexattr(2)](https://man7.org/linux/man-pages/man2/lremovexattr.2.html) for more info on this syscall.
+        lremovexattr = 1227,
+        /// Remove an extended attribute.
+        ///
+        /// See [fremovexattr(2)](https://man7.org/linux/man-pages/man2/fremovexattr.2.html) for more info on this syscall.
+        fremovexattr = 1228,
+        /// Send a signal to a thread.
+        ///
+        /// See [tkill(2)](https://man7.org/linux/man-pages/man2/tkill.2.html) for more info on this syscall.
+        tkill = 1229,
+        /// Fast user-space locking.
+        ///
+        /// See [futex(2)](https://man7.org/linux/man-pages/man2/futex.2.html) for more info on this syscall.
+        futex = 1230,
+        /// Set and get a thread's CPU affinity mask.
+        ///
+        /// See [sched_setaffinity(2)](https://man7.org/linux/man-pages/man2/sched_setaffinity.2.html) for more info on this syscall.
+        sched_setaffinity = 1231,
+        /// Set and get a thread's CPU affinity mask.
+        ///
+        /// See [sched_getaffinity(2)](https://man7.org/linux/man-pages/man2/sched_getaffinity.2.html) for more info on this syscall.
+        sched_getaffinity = 1232,
+        /// Set pointer to thread ID.
+        ///
+        /// See [set_tid_address(2)](https://man7.org/linux/man-pages/man2/set_tid_address.2.html) for more info on this syscall.
+        set_tid_address = 1233,
+        /// See [fadvise64(2)](https://man7.org/linux/man-pages/man2/fadvise64.2.html) for more info on this syscall.
+        fadvise64 = 1234,
+        /// Send a signal to a thread.
+        ///
+        /// See [tgkill(2)](https://man7.org/linux/man-pages/man2/tgkill.2.html) for more info on this syscall.
+        tgkill = 1235,
+        /// Exit all threads in a process.
+        ///
+        /// See [exit_group(2)](https://man7.org/linux/man-pages/man2/exit_group.2.html) for more info on this syscall.
+        exit_group = 1236,
+        /// Return a directory entry's path.
+        ///
+        /// See [lookup_dcookie(2)](https://man7.org/linux/man-pages/man2/lookup_dcookie.2.html) for more info on this syscall.
+        lookup_dcookie = 1237,
+        /// Create an asynchronous I/O context.
+        ///
+        /// See [io_setup(2)](https://man7.org/linux/man-pages/man2/io_setup.2.html) for more info on this syscall.
+        io_setup = 1238,
+        /// Destroy an asynchronous I/O context.
+        ///
+        /// See [io_destroy(2)](https://man7.org/linux/man-pages/man2/io_destroy.2.html) for more info on this syscall.
+        io_destroy = 1239,
+        /// Read asynchronous I/O events from the completion queue.
+        ///
+        /// See [io_getevents(2)](https://man7.org/linux/man-pages/man2/io_getevents.2.html) for more info on this syscall.
+        io_getevents = 1240,
+        /// Submit asynchronous I/O blocks for processing.
+        ///
+        /// See [io_submit(2)](https://man7.org/linux/man-pages/man2/io_submit.2.html) for more info on this syscall.
+        io_submit = 1241,
+        /// Cancel an outstanding asynchronous I/O operation.
+        ///
+        /// See [io_cancel(2)](https://man7.org/linux/man-pages/man2/io_cancel.2.html) for more info on this syscall.
+        io_cancel = 1242,
+        /// Open an epoll file descriptor.
+        ///
+        /// See [epoll_create(2)](https://man7.org/linux/man-pages/man2/epoll_create.2.html) for more info on this syscall.
+        epoll_create = 1243,
+        /// Control interface for an epoll file descriptor.
+        ///
+        /// See [epoll_ctl(2)](https://man7.org/linux/man-pages/man2/epoll_ctl.2.html) for more info on this syscall.
+        epoll_ctl = 1244,
+        /// Wait for an I/O event on an epoll file descriptor.
+        ///
+        /// See [epoll_wait(2)](https://man7.org/linux/man-pages/man2/epoll_wait.2.html) for more info on this syscall.
+        epoll_wait = 1245,
+        /// Restart a system call after interruption by a stop signal.
+        ///
+        /// See [restart_syscall(2)](https://man7.org/linux/man-pages/man2/restart_syscall.2.html) for more info on this syscall.
+        restart_syscall = 1246,
+        /// System V semaphore operations.
+        ///
+        /// See [semtimedop(2)](https://man7.org/linux/man-pages/man2/semtimedop.2.html) for more info on this syscall.
+        semtimedop = 1247,
+        /// Create a POSIX per-process timer.
+        ///
+        /// See [timer_create(2)](https://man7.org/linux/man-pages/man2/timer_create.2.html) for more info on this syscall.
+        timer_create = 1248,
+        /// Arm/disarm and fetch state of POSIX per-process timer.
+        ///
+        /// See [timer_settime(2)](https://man7.org/linux/man-pages/man2/timer_settime.2.html) for more info on this syscall.
+        timer_settime = 1249,
+        /// Arm/disarm and fetch state of POSIX per-process timer.
+        ///
+        /// See [timer_gettime(2)](https://man7.org/linux/man-pages/man2/timer_gettime.2.html) for more info on this syscall.
+        timer_gettime = 1250,
+        /// Get overrun count for a POSIX per-process timer.
+        ///
+        /// See [timer_getoverrun(2)](https://man7.org/linux/man-pages/man2/timer_getoverrun.2.html) for more info on this syscall.
+        timer_getoverrun = 1251,
+        /// Delete a POSIX per-process timer.
+        ///
+        /// See [timer_delete(2)](https://man7.org/linux/man-pages/man2/timer_delete.2.html) for more info on this syscall.
+        timer_delete = 1252,
+        /// Clock and time functions.
+        ///
+        /// See [clock_settime(2)](https://man7.org/linux/man-pages/man2/clock_settime.2.html) for more info on this syscall.
+        clock_settime = 1253,
+        /// Clock and time functions.
+        ///
+        /// See [clock_gettime(2)](https://man7.org/linux/man-pages/man2/clock_gettime.2.html) for more info on this syscall.
+        clock_gettime = 1254,
+        /// Clock and time functions.
+        ///
+        /// See [clock_getres(2)](https://man7.org/linux/man-pages/man2/clock_getres.2.html) for more info on this syscall.
+        clock_getres = 1255,
+        /// High-resolution sleep with specifiable clock.
+        ///
+        /// See [clock_nanosleep(2)](https://man7.org/linux/man-pages/man2/clock_nanosleep.2.html) for more info on this syscall.
+        clock_nanosleep = 1256,
+        /// Get filesystem statistics.
+        ///
+        /// See [fstatfs64(2)](https://man7.org/linux/man-pages/man2/fstatfs64.2.html) for more info on this syscall.
+        fstatfs64 = 1257,
+        /// Get filesystem statistics.
+        ///
+        /// See [statfs64(2)](https://man7.org/linux/man-pages/man2/statfs64.2.html) for more info on this syscall.
+        statfs64 = 1258,
+        /// Set memory policy for a memory range.
+        ///
+        /// See [mbind(2)](https://man7.org/linux/man-pages/man2/mbind.2.html) for more info on this syscall.
+        mbind = 1259,
+        /// Retrieve NUMA memory policy for a thread.
+        ///
+        /// See [get_mempolicy(2)](https://man7.org/linux/man-pages/man2/get_mempolicy.2.html) for more info on this syscall.
+        get_mempolicy = 1260,
+        /// Set default NUMA memory policy for a thread and its children.
+        ///
+        /// See [set_mempolicy(2)](https://man7.org/linux/man-pages/man2/set_mempolicy.2.html) for more info on this syscall.
+        set_mempolicy = 1261,
+        /// Open a message queue.
+        ///
+        /// See [mq_open(2)](https://man7.org/linux/man-pages/man2/mq_open.2.html) for more info on this syscall.
+        mq_open = 1262,
+        /// Remove a message queue.
+        ///
+        /// See [mq_unlink(2)](https://man7.org/linux/man-pages/man2/mq_unlink.2.html) for more info on this syscall.
+        mq_unlink = 1263,
+        /// Send a message to a message queue.
+        ///
+        /// See [mq_timedsend(2)](https://man7.org/linux/man-pages/man2/mq_timedsend.2.html) for more info on this syscall.
+        mq_timedsend = 1264,
+        /// Receive a message from a message queue.
+        ///
+        /// See [mq_timedreceive(2)](https://man7.org/linux/man-pages/man2/mq_timedreceive.2.html) for more info on this syscall.
+        mq_timedreceive = 1265,
+        /// Register for notification when a message is available.
+        ///
+        /// See [mq_notify(2)](https://man7.org/linux/man-pages/man2/mq_notify.2.html) for more info on this syscall.
+        mq_notify = 1266,
+        /// Get/set message queue attributes.
+        ///
+        /// See [mq_getsetattr(2)](https://man7.org/linux/man-pages/man2/mq_getsetattr.2.html) for more info on this syscall.
+        mq_getsetattr = 1267,
+        /// Load a new kernel for later execution.
+        ///
+        /// See [kexec_load(2)](https://man7.org/linux/man-pages/man2/kexec_load.2.html) for more info on this syscall.
+        kexec_load = 1268,
+        /// Unimplemented system calls.
+        ///
+        /// See [vserver(2)](https://man7.org/linux/man-pages/man2/vserver.2.html) for more info on this syscall.
+        vserver = 1269,
+        /// Wait for process to change state.
+        ///
+        /// See [waitid(2)](https://man7.org/linux/man-pages/man2/waitid.2.html) for more info on this syscall.
+        waitid = 1270,
+        /// Add a key to the kernel's key management facility.
+        ///
+        /// See [add_key(2)](https://man7.org/linux/man-pages/man2/add_key.2.html) for more info on this syscall.
+        add_key = 1271,
+        /// Request a key from the kernel's key management facility.
+        ///
+        /// See [request_key(2)](https://man7.org/linux/man-pages/man2/request_key.2.html) for more info on this syscall.
+        request_key = 1272,
+        /// Manipulate the kernel's key management facility.
+        ///
+        /// See [keyctl(2)](https://man7.org/linux/man-pages/man2/keyctl.2.html) for more info on this syscall.
+        keyctl = 1273,
+        /// Get/set I/O scheduling class and priority.
+        ///
+        /// See [ioprio_set(2)](https://man7.org/linux/man-pages/man2/ioprio_set.2.html) for more info on this syscall.
+        ioprio_set = 1274,
+        /// Get/set I/O scheduling class and priority.
+        ///
+        /// See [ioprio_get(2)](https://man7.org/linux/man-pages/man2/ioprio_get.2.html) for more info on this syscall.
+        ioprio_get = 1275,
+        /// Move individual pages of a process to another node.
+        ///
+        /// See [move_pages(2)](https://man7.org/linux/man-pages/man2/move_pages.2.html) for more info on this syscall.
+        move_pages = 1276,
+        /// Initialize an inotify instance.
+        ///
+        /// See [inotify_init(2)](https://man7.org/linux/man-pages/man2/inotify_init.2.html) for more info on this syscall.
+        inotify_init = 1277,
+        /// Add a watch to an initialized inotify instance.
+        ///
+        /// See [inotify_add_watch(2)](https://man7.org/linux/man-pages/man2/inotify_add_watch.2.html) for more info on this syscall.
+        inotify_add_watch = 1278,
+        /// Remove an existing watch from an inotify instance.
+        ///
+        /// See [inotify_rm_watch(2)](https://man7.org/linux/man-pages/man2/inotify_rm_watch.2.html) for more info on this syscall.
+        inotify_rm_watch = 1279,
+        /// Move all pages in a process to another set of nodes.
+        ///
+        /// See [migrate_pages(2)](https://man7.org/linux/man-pages/man2/migrate_pages.2.html) for more info on this syscall.
+        migrate_pages = 1280,
+        /// Open and possibly create a file.
+        ///
+        /// See [openat(2)](https://man7.org/linux/man-pages/man2/openat.2.html) for more info on this syscall.
+        openat = 1281,
+        /// Create a directory.
+        ///
+        /// See [mkdirat(2)](https://man7.org/linux/man-pages/man2/mkdirat.2.html) for more info on this syscall.
+        mkdirat = 1282,
+        /// Create a special or ordinary file.
+        ///
+        /// See [mknodat(2)](https://man7.org/linux/man-pages/man2/mknodat.2.html) for more info on this syscall.
+        mknodat = 1283,
+        /// Change ownership of a file.
+        ///
+        /// See [fchownat(2)](https://man7.org/linux/man-pages/man2/fchownat.2.html) for more info on this syscall.
+        fchownat = 1284,
+        /// Change timestamps of a file relative to a directory file descriptor.
+        ///
+        /// See [futimesat(2)](https://man7.org/linux/man-pages/man2/futimesat.2.html) for more info on this syscall.
+        futimesat = 1285,
+        /// Get file status.
+        ///
+        /// See [newfstatat(2)](https://man7.org/linux/man-pages/man2/newfstatat.2.html) for more info on this syscall.
+        newfstatat = 1286,
+        /// Delete a name and possibly the file it refers to.
+        ///
+        /// See [unlinkat(2)](https://man7.org/linux/man-pages/man2/unlinkat.2.html) for more info on this syscall.
+        unlinkat = 1287,
+        /// Change the name or location of a file.
+        ///
+        /// See [renameat(2)](https://man7.org/linux/man-pages/man2/renameat.2.html) for more info on this syscall.
+        renameat = 1288,
+        /// Make a new name for a file.
+        ///
+        /// See [linkat(2)](https://man7.org/linux/man-pages/man2/linkat.2.html) for more info on this syscall.
+        linkat = 1289,
+        /// Make a new name for a file.
+        ///
+        /// See [symlinkat(2)](https://man7.org/linux/man-pages/man2/symlinkat.2.html) for more info on this syscall.
+        symlinkat = 1290,
+        /// Read value of a symbolic link.
+        ///
+        /// See [readlinkat(2)](https://man7.org/linux/man-pages/man2/readlinkat.2.html) for more info on this syscall.
+        readlinkat = 1291,
+        /// Change permissions of a file.
+        ///
+        /// See [fchmodat(2)](https://man7.org/linux/man-pages/man2/fchmodat.2.html) for more info on this syscall.
+        fchmodat = 1292,
+        /// Check user's permissions for a file.
+        ///
+        /// See [faccessat(2)](https://man7.org/linux/man-pages/man2/faccessat.2.html) for more info on this syscall.
+        faccessat = 1293,
+        /// See [pselect6(2)](https://man7.org/linux/man-pages/man2/pselect6.2.html) for more info on this syscall.
+        pselect6 = 1294,
+        /// Wait for some event on a file descriptor.
+        ///
+        /// See [ppoll(2)](https://man7.org/linux/man-pages/man2/ppoll.2.html) for more info on this syscall.
+        ppoll = 1295,
+        /// Disassociate parts of the process execution context.
+        ///
+        /// See [unshare(2)](https://man7.org/linux/man-pages/man2/unshare.2.html) for more info on this syscall.
+        unshare = 1296,
+        /// Splice data to/from a pipe.
+        ///
+        /// See [splice(2)](https://man7.org/linux/man-pages/man2/splice.2.html) for more info on this syscall.
+        splice = 1297,
+        /// Get/set list of robust futexes.
+        ///
+        /// See [set_robust_list(2)](https://man7.org/linux/man-pages/man2/set_robust_list.2.html) for more info on this syscall.
+        set_robust_list = 1298,
+        /// Get/set list of robust futexes.
+        ///
+        /// See [get_robust_list(2)](https://man7.org/linux/man-pages/man2/get_robust_list.2.html) for more info on this syscall.
+        get_robust_list = 1299,
+        /// Sync a file segment with disk.
+        ///
+        /// See [sync_file_range(2)](https://man7.org/linux/man-pages/man2/sync_file_range.2.html) for more info on this syscall.
+        sync_file_range = 1300,
+        /// Duplicating pipe content.
+        ///
+        /// See [tee(2)](https://man7.org/linux/man-pages/man2/tee.2.html) for more info on this syscall.
+        tee = 1301,
+        /// Splice user pages to/from a pipe.
+        ///
+        /// See [vmsplice(2)](https://man7.org/linux/man-pages/man2/vmsplice.2.html) for more info on this syscall.
+        vmsplice = 1302,
+        /// Manipulate file space.
+        ///
+        /// See [fallocate(2)](https://man7.org/linux/man-pages/man2/fallocate.2.html) for more info on this syscall.
+        fallocate = 1303,
+        /// Determine CPU and NUMA node on which the calling thread is running.
+        ///
+        /// See [getcpu(2)](https://man7.org/linux/man-pages/man2/getcpu.2.html) for more info on this syscall.
+        getcpu = 1304,
+        /// Wait for an I/O event on an epoll file descriptor.
+        ///
+        /// See [epoll_pwait(2)](https://man7.org/linux/man-pages/man2/epoll_pwait.2.html) for more info on this syscall.
+        epoll_pwait = 1305,
+        /// Change file timestamps with nanosecond precision.
+        ///
+        /// See [utimensat(2)](https://man7.org/linux/man-pages/man2/utimensat.2.html) for more info on this syscall.
+        utimensat = 1306,
+        /// Create a file descriptor for accepting signals.
+        ///
+        /// See [signalfd(2)](https://man7.org/linux/man-pages/man2/signalfd.2.html) for more info on this syscall.
+        signalfd = 1307,
+        /// See [timerfd(2)](https://man7.org/linux/man-pages/man2/timerfd.2.html) for more info on this syscall.
+        timerfd = 1308,
+        /// Create a file descriptor for event notification.
+        ///
+        /// See [eventfd(2)](https://man7.org/linux/man-pages/man2/eventfd.2.html) for more info on this syscall.
+        eventfd = 1309,
+        /// Timers that notify via file descriptors.
+        ///
+        /// See [timerfd_create(2)](https://man7.org/linux/man-pages/man2/timerfd_create.2.html) for more info on this syscall.
+        timerfd_create = 1310,
+        /// Timers that notify via file descriptors.
+        ///
+        /// See [timerfd_settime(2)](https://man7.org/linux/man-pages/man2/timerfd_settime.2.html) for more info on this syscall.
+        timerfd_settime = 1311,
+        /// Timers that notify via file descriptors.
+        ///
+        /// See [timerfd_gettime(2)](https://man7.org/linux/man-pages/man2/timerfd_gettime.2.html) for more info on this syscall.
+        timerfd_gettime = 1312,
+        /// See [signalfd4(2)](https://man7.org/linux/man-pages/man2/signalfd4.2.html) for more info on this syscall.
+        signalfd4 = 1313,
+        /// See [eventfd2(2)](https://man7.org/linux/man-pages/man2/eventfd2.2.html) for more info on this syscall.
+        eventfd2 = 1314,
+        /// Open an epoll file descriptor.
+        ///
+        /// See [epoll_create1(2)](https://man7.org/linux/man-pages/man2/epoll_create1.2.html) for more info on this syscall.
+        epoll_create1 = 1315,
+        /// Duplicate a file descriptor.
+        ///
+        /// See [dup3(2)](https://man7.org/linux/man-pages/man2/dup3.2.html) for more info on this syscall.
+        dup3 = 1316,
+        /// Create pipe.
+        ///
+        /// See [pipe2(2)](https://man7.org/linux/man-pages/man2/pipe2.2.html) for more info on this syscall.
+        pipe2 = 1317,
+        /// Initialize an inotify instance.
+        ///
+        /// See [inotify_init1(2)](https://man7.org/linux/man-pages/man2/inotify_init1.2.html) for more info on this syscall.
+        inotify_init1 = 1318,
+        /// Read or write data into multiple buffers.
+        ///
+        /// See [preadv(2)](https://man7.org/linux/man-pages/man2/preadv.2.html) for more info on this syscall.
+        preadv = 1319,
+        /// Read or write data into multiple buffers.
+        ///
+        /// See [pwritev(2)](https://man7.org/linux/man-pages/man2/pwritev.2.html) for more info on this syscall.
+        pwritev = 1320,
+        /// Queue a signal and data.
+        ///
+        /// See [rt_tgsigqueueinfo(2)](https://man7.org/linux/man-pages/man2/rt_tgsigqueueinfo.2.html) for more info on this syscall.
+        rt_tgsigqueueinfo = 1321,
+        /// Receive multiple messages on a socket.
+        ///
+        /// See [recvmmsg(2)](https://man7.org/linux/man-pages/man2/recvmmsg.2.html) for more info on this syscall.
+        recvmmsg = 1322,
+        /// Create and initialize fanotify group.
+        ///
+        /// See [fanotify_init(2)](https://man7.org/linux/man-pages/man2/fanotify_init.2.html) for more info on this syscall.
+        fanotify_init = 1323,
+        /// Add, remove, or modify an fanotify mark on a filesystem object.
+        ///
+        /// See [fanotify_mark(2)](https://man7.org/linux/man-pages/man2/fanotify_mark.2.html) for more info on this syscall.
+        fanotify_mark = 1324,
+        /// Get/set resource limits.
+        ///
+        /// See [prlimit64(2)](https://man7.org/linux/man-pages/man2/prlimit64.2.html) for more info on this syscall.
+        prlimit64 = 1325,
+        /// Obtain handle for a pathname and open file via a handle.
+        ///
+        /// See [name_to_handle_at(2)](https://man7.org/linux/man-pages/man2/name_to_handle_at.2.html) for more info on this syscall.
+        name_to_handle_at = 1326,
+        /// Obtain handle for a pathname and open file via a handle.
+        ///
+        /// See [open_by_handle_at(2)](https://man7.org/linux/man-pages/man2/open_by_handle_at.2.html) for more info on this syscall.
+        open_by_handle_at = 1327,
+        /// Tune kernel clock.
+        ///
+        /// See [clock_adjtime(2)](https://man7.org/linux/man-pages/man2/clock_adjtime.2.html) for more info on this syscall.
+        clock_adjtime = 1328,
+        /// Commit filesystem caches to disk.
+        ///
+        /// See [syncfs(2)](https://man7.org/linux/man-pages/man2/syncfs.2.html) for more info on this syscall.
+        syncfs = 1329,
+        /// Reassociate thread with a namespace.
+        ///
+        /// See [setns(2)](https://man7.org/linux/man-pages/man2/setns.2.html) for more info on this syscall.
+        setns = 1330,
+        /// Send multiple messages on a socket.
+        ///
+        /// See [sendmmsg(2)](https://man7.org/linux/man-pages/man2/sendmmsg.2.html) for more info on this syscall.
+        sendmmsg = 1331,
+        /// Transfer data between process address spaces.
+        ///
+        /// See [process_vm_readv(2)](https://man7.org/linux/man-pages/man2/process_vm_readv.2.html) for more info on this syscall.
+        process_vm_readv = 1332,
+        /// Transfer data between process address spaces.
+        ///
+        /// See [process_vm_writev(2)](https://man7.org/linux/man-pages/man2/process_vm_writev.2.html) for more info on this syscall.
+        process_vm_writev = 1333,
+        /// Accept a connection on a socket.
+        ///
+        /// See [accept4(2)](https://man7.org/linux/man-pages/man2/accept4.2.html) for more info on this syscall.
+        accept4 = 1334,
+        /// Load a kernel module.
+        ///
+        /// See [finit_module(2)](https://man7.org/linux/man-pages/man2/finit_module.2.html) for more info on this syscall.
+        finit_module = 1335,
+        /// Set and get scheduling policy and attributes.
+        ///
+        /// See [sched_setattr(2)](https://man7.org/linux/man-pages/man2/sched_setattr.2.html) for more info on this syscall.
+        sched_setattr = 1336,
+        /// Set and get scheduling policy and attributes.
+        ///
+        /// See [sched_getattr(2)](https://man7.org/linux/man-pages/man2/sched_getattr.2.html) for more info on this syscall.
+        sched_getattr = 1337,
+        /// Change the name or location of a file.
+        ///
+        /// See [renameat2(2)](https://man7.org/linux/man-pages/man2/renameat2.2.html) for more info on this syscall.
+        renameat2 = 1338,
+        /// Obtain a series of random bytes.
+        ///
+        /// See [getrandom(2)](https://man7.org/linux/man-pages/man2/getrandom.2.html) for more info on this syscall.
+        getrandom = 1339,
+        /// Create an anonymous file.
+        ///
+        /// See [memfd_create(2)](https://man7.org/linux/man-pages/man2/memfd_create.2.html) for more info on this syscall.
+        memfd_create = 1340,
+        /// Perform a command on an extended BPF map or program.
+        ///
+        /// See [bpf(2)](https://man7.org/linux/man-pages/man2/bpf.2.html) for more info on this syscall.
+        bpf = 1341,
+        /// Execute program relative to a directory file descriptor.
+        ///
+        /// See [execveat(2)](https://man7.org/linux/man-pages/man2/execveat.2.html) for more info on this syscall.
+        execveat = 1342,
+        /// Create a file descriptor for handling page faults in user space.
+        ///
+        /// See [userfaultfd(2)](https://man7.org/linux/man-pages/man2/userfaultfd.2.html) for more info on this syscall.
+        userfaultfd = 1343,
+        /// Issue memory barriers on a set of threads.
+        ///
+        /// See [membarrier(2)](https://man7.org/linux/man-pages/man2/membarrier.2.html) for more info on this syscall.
+        membarrier = 1344,
+        /// Compare two processes to determine if they share a kernel resource.
+        ///
+        /// See [kcmp(2)](https://man7.org/linux/man-pages/man2/kcmp.2.html) for more info on this syscall.
+        kcmp = 1345,
+        /// Lock and unlock memory.
+        ///
+        /// See [mlock2(2)](https://man7.org/linux/man-pages/man2/mlock2.2.html) for more info on this syscall.
+        mlock2 = 1346,
+        /// Copy a range of data from one file to another.
+        ///
+        /// See [copy_file_range(2)](https://man7.org/linux/man-pages/man2/copy_file_range.2.html) for more info on this syscall.
+        copy_file_range = 1347,
+        /// Read or write data into multiple buffers.
+        ///
+        /// See [preadv2(2)](https://man7.org/linux/man-pages/man2/preadv2.2.html) for more info on this syscall.
+        preadv2 = 1348,
+        /// Read or write data into multiple buffers.
+        ///
+        /// See [pwritev2(2)](https://man7.org/linux/man-pages/man2/pwritev2.2.html) for more info on this syscall.
+        pwritev2 = 1349,
+        /// Get file status (extended).
+        ///
+        /// See [statx(2)](https://man7.org/linux/man-pages/man2/statx.2.html) for more info on this syscall.
+        statx = 1350,
+        /// See [io_pgetevents(2)](https://man7.org/linux/man-pages/man2/io_pgetevents.2.html) for more info on this syscall.
+        io_pgetevents = 1351,
+        /// Set up performance monitoring.
+        ///
+        /// See [perf_event_open(2)](https://man7.org/linux/man-pages/man2/perf_event_open.2.html) for more info on this syscall.
+        perf_event_open = 1352,
+        /// Operate on Secure Computing state of the process.
+        ///
+        /// See [seccomp(2)](https://man7.org/linux/man-pages/man2/seccomp.2.html) for more info on this syscall.
+        seccomp = 1353,
+        /// Set protection on a region of memory.
+        ///
+        /// See [pkey_mprotect(2)](https://man7.org/linux/man-pages/man2/pkey_mprotect.2.html) for more info on this syscall.
+        pkey_mprotect = 1354,
+        /// Allocate or free a protection key.
+        ///
+        /// See [pkey_alloc(2)](https://man7.org/linux/man-pages/man2/pkey_alloc.2.html) for more info on this syscall.
+        pkey_alloc = 1355,
+        /// Allocate or free a protection key.
+        ///
+        /// See [pkey_free(2)](https://man7.org/linux/man-pages/man2/pkey_free.2.html) for more info on this syscall.
+        pkey_free = 1356,
+        /// See [rseq(2)](https://man7.org/linux/man-pages/man2/rseq.2.html) for more info on this syscall.
+        rseq = 1357,
+        /// Send a signal to a process specified by a file descriptor.
+        ///
+        /// See [pidfd_send_signal(2)](https://man7.org/linux/man-pages/man2/pidfd_send_signal.2.html) for more info on this syscall.
+        pidfd_send_signal = 1448,
+        /// See [io_uring_setup(2)](https://man7.org/linux/man-pages/man2/io_uring_setup.2.html) for more info on this syscall.
+        io_uring_setup = 1449,
+        /// See [io_uring_enter(2)](https://man7.org/linux/man-pages/man2/io_uring_enter.2.html) for more info on this syscall.
+        io_uring_enter = 1450,
+        /// See [io_uring_register(2)](https://man7.org/linux/man-pages/man2/io_uring_register.2.html) for more info on this syscall.
+        io_uring_register = 1451,
+        /// See [open_tree(2)](https://man7.org/linux/man-pages/man2/open_tree.2.html) for more info on this syscall.
+        open_tree = 1452,
+        /// See [move_mount(2)](https://man7.org/linux/man-pages/man2/move_mount.2.html) for more info on this syscall.
+        move_mount = 1453,
+        /// See [fsopen(2)](https://man7.org/linux/man-pages/man2/fsopen.2.html) for more info on this syscall.
+        fsopen = 1454,
+        /// See [fsconfig(2)](https://man7.org/linux/man-pages/man2/fsconfig.2.html) for more info on this syscall.
+        fsconfig = 1455,
+        /// See [fsmount(2)](https://man7.org/linux/man-pages/man2/fsmount.2.html) for more info on this syscall.
+        fsmount = 1456,
+        /// See [fspick(2)](https://man7.org/linux/man-pages/man2/fspick.2.html) for more info on this syscall.
+        fspick = 1457,
+        /// Obtain a file descriptor that refers to a process.
+        ///
+        /// See [pidfd_open(2)](https://man7.org/linux/man-pages/man2/pidfd_open.2.html) for more info on this syscall.
+        pidfd_open = 1458,
+        /// Close all file descriptors in a given range.
+        ///
+        /// See [close_range(2)](https://man7.org/linux/man-pages/man2/close_range.2.html) for more info on this syscall.
+        close_range = 1460,
+        /// Open and possibly create a file (extended).
+        ///
+        /// See [openat2(2)](https://man7.org/linux/man-pages/man2/openat2.2.html) for more info on this syscall.
+        openat2 = 1461,
+        /// Obtain a duplicate of another process's file descriptor.
+        ///
+        /// See [pidfd_getfd(2)](https://man7.org/linux/man-pages/man2/pidfd_getfd.2.html) for more info on this syscall.
+        pidfd_getfd = 1462,
+        /// Check user's permissions for a file.
+        ///
+        /// See [faccessat2(2)](https://man7.org/linux/man-pages/man2/faccessat2.2.html) for more info on this syscall.
+        faccessat2 = 1463,
+        /// Give advice about use of memory to a process.
+        ///
+        /// See [process_madvise(2)](https://man7.org/linux/man-pages/man2/process_madvise.2.html) for more info on this syscall.
+        process_madvise = 1464,
+        /// Wait for an I/O event on an epoll file descriptor.
+        ///
+        /// See [epoll_pwait2(2)](https://man7.org/linux/man-pages/man2/epoll_pwait2.2.html) for more info on this syscall.
+        epoll_pwait2 = 1465,
+        /// Change properties of a mount or mount tree.
+        ///
+        /// See [mount_setattr(2)](https://man7.org/linux/man-pages/man2/mount_setattr.2.html) for more info on this syscall.
+        mount_setattr = 1466,
+        /// See [quotactl_fd(2)](https://man7.org/linux/man-pages/man2/quotactl_fd.2.html) for more info on this syscall.
+        quotactl_fd = 1467,
+        /// Create a new Landlock ruleset.
+        ///
+        /// See [landlock_create_ruleset(2)](https://man7.org/linux/man-pages/man2/landlock_create_ruleset.2.html) for more info on this syscall.
+        landlock_create_ruleset = 1468,
+        /// Add a new Landlock rule to a ruleset.
+        ///
+        /// See [landlock_add_rule(2)](https://man7.org/linux/man-pages/man2/landlock_add_rule.2.html) for more info on this syscall.
+        landlock_add_rule = 1469,
+        /// Enforce a Landlock ruleset.
+        ///
+        /// See [landlock_restrict_self(2)](https://man7.org/linux/man-pages/man2/landlock_restrict_self.2.html) for more info on this syscall.
+        landlock_restrict_self = 1470,
+        /// See [process_mrelease(2)](https://man7.org/linux/man-pages/man2/process_mrelease.2.html) for more info on this syscall.
+        process_mrelease = 1472,
+        /// See [futex_waitv(2)](https://man7.org/linux/man-pages/man2/futex_waitv.2.html) for more info on this syscall.
+        futex_waitv = 1473,
+        /// See [set_mempolicy_home_node(2)](https://man7.org/linux/man-pages/man2/set_mempolicy_home_node.2.html) for more info on this syscall.
+        set_mempolicy_home_node = 1474,
+    }
+    LAST: set_mempolicy_home_node;
+    SIGNATURES: signatures;
+}
+
+/// Syscall signatures from the kernel's `SYSCALL_DEFINEn` definitions,
+/// indexed by `id - Sysno::first().id()`.
+pub(crate) mod signatures {
+    /// Number of arguments, or `None` for gaps in the table and syscalls
+    /// without a known definition.
+    pub(crate) static ARG_COUNTS: [Option<u8>; 451] = [
+        None,    // ni_syscall
+        Some(1), // exit
+        Some(3), // read
+        Some(3), // write
+        Some(3), // open
+        Some(1), // close
+        Some(2), // creat
+        Some(2), // link
+        Some(1), // unlink
+        Some(3), // execve
+        Some(1), // chdir
+        Some(1), // fchdir
+        Some(2), // utimes
+        Some(3), // mknod
+        Some(2), // chmod
+        Some(3), // chown
+        Some(3), // lseek
+        Some(0), // getpid
+        Some(0), // getppid
+        Some(5), // mount
+        Some(2), // umount2
+        Some(1), // setuid
+        Some(0), // getuid
+        Some(0), // geteuid
+        Some(4), // ptrace
+        Some(2), // access
+        Some(0), // sync
+        Some(1), // fsync
+        Some(1), // fdatasync
+        Some(2), // kill
+        Some(2), // rename
+        Some(2), // mkdir
+        Some(1), // rmdir
+        Some(1), // dup
+        Some(1), // pipe
+        Some(1), // times
+        Some(1), // brk
+        Some(1), // setgid
+        Some(0), // getgid
+        Some(0), // getegid
+        Some(1), // acct
+        Some(3), // ioctl
+        Some(3), // fcntl
+        Some(1), // umask
+        Some(1), // chroot
+        Some(2), // ustat
+        Some(2), // dup2
+        Some(2), // setreuid
+        Some(2), // setregid
+        Some(3), // getresuid
+        Some(3), // setresuid
+        Some(3), // getresgid
+        Some(3), // setresgid
+        Some(2), // getgroups
+        Some(2), // setgroups
+        Some(1), // getpgid
+        Some(2), // setpgid
+        Some(0), // setsid
+        Some(1), // getsid
+        Some(2), // sethostname
+        Some(2), // setrlimit
+        Some(2), // getrlimit
+        Some(2), // getrusage
+        Some(2), // gettimeofday
+        Some(2), // settimeofday
+        Some(5), // select
+        Some(3), // poll
+        Some(2), // symlink
+        Some(3), // readlink
+        None,    // uselib
+        Some(2), // swapon
+        Some(1), // swapoff
+        Some(4), // reboot
+        Some(2), // truncate
+        Some(2), // ftruncate
+        Some(2), // fchmod
+        Some(3), // fchown
+        Some(2), // getpriority
+        Some(3), // setpriority
+        Some(2), // statfs
+        Some(2), // fstatfs
+        Some(0), // gettid
+        Some(3), // semget
+        Some(3), // semop
+        Some(4), // semctl
+        Some(2), // msgget
+        Some(4), // msgsnd
+        Some(5), // msgrcv
+        Some(3), // msgctl
+        Some(3), // shmget
+        Some(3), // shmat
+        Some(1), // shmdt
+        Some(3), // shmctl
+        Some(3), // syslog
+        Some(3), // setitimer
+        Some(2), // getitimer
+        None,
+        None,
+        None,
+        Some(0), // vhangup
+        Some(3), // lchown
+        Some(5), // remap_file_pages
+        Some(4), // wait4
+        Some(1), // sysinfo
+        Some(5), // clone
+        Some(2), // setdomainname
+        Some(1), // uname
+        Some(1), // adjtimex
+        None,
+        Some(3), // init_module
+        Some(2), // delete_module
+        None,
+        None,
+        Some(4), // quotactl
+        Some(2), // bdflush
+        Some(3), // sysfs
+        Some(1), // personality
+        None,    // afs_syscall
+        Some(1), // setfsuid
+        Some(1), // setfsgid
+        Some(3), // getdents
+        Some(2), // flock
+        Some(3), // readv
+        Some(3), // writev
+        Some(4), // pread64
+        Some(4), // pwrite64
+        None,    // _sysctl
+        None,    // mmap
+        Some(2), // munmap
+        Some(2), // mlock
+        Some(1), // mlockall
+        Some(3), // mprotect
+        Some(5), // mremap
+        Some(3), // msync
+        Some(2), // munlock
+        Some(0), // munlockall
+        Some(2), // sched_getparam
+        Some(2), // sched_setparam
+        Some(1), // sched_getscheduler
+        Some(3), // sched_setscheduler
+        Some(0), // sched_yield
+        Some(1), // sched_get_priority_max
+        Some(1), // sched_get_priority_min
+        Some(2), // sched_rr_get_interval
+        Some(2), // nanosleep
+        None,    // nfsservctl
+        Some(5), // prctl
+        None,    // old_getpagesize
+        None,    // mmap2
+        Some(5), // pciconfig_read
+        Some(5), // pciconfig_write
+        None,    // perfmonctl
+        Some(2), // sigaltstack
+        Some(4), // rt_sigaction
+        Some(2), // rt_sigpending
+        Some(4), // rt_sigprocmask
+        Some(3), // rt_sigqueueinfo
+        None,    // rt_sigreturn
+        Some(2), // rt_sigsuspend
+        Some(4), // rt_sigtimedwait
+        Some(2), // getcwd
+        Some(2), // capget
+        Some(2), // capset
+        Some(4), // sendfile
+        None,    // getpmsg
+        None,    // putpmsg
+        Some(3), // socket
+        Some(3), // bind
+        Some(3), // connect
+        Some(2), // listen
+        Some(3), // accept
+        Some(3), // getsockname
+        Some(3), // getpeername
+        Some(4), // socketpair
+        Some(4), // send
+        Some(6), // sendto
+        Some(4), // recv
+        Some(6), // recvfrom
+        Some(2), // shutdown
+        Some(5), // setsockopt
+        Some(5), // getsockopt
+        Some(3), // sendmsg
+        Some(3), // recvmsg
+        Some(2), // pivot_root
+        Some(3), // mincore
+        Some(3), // madvise
+        Some(2), // stat
+        Some(2), // lstat
+        Some(2), // fstat
+        None,    // clone2
+        Some(3), // getdents64
+        None,    // getunwind
+        Some(3), // readahead
+        Some(5), // setxattr
+        Some(5), // lsetxattr
+        Some(5), // fsetxattr
+        Some(4), // getxattr
+        Some(4), // lgetxattr
+        Some(4), // fgetxattr
+        Some(3), // listxattr
+        Some(3), // llistxattr
+        Some(3), // flistxattr
+        Some(2), // removexattr
+        Some(2), // lremovexattr
+        Some(2), // fremovexattr
+        Some(2), // tkill
+        Some(6), // futex
+        Some(3), // sched_setaffinity
+        Some(3), // sched_getaffinity
+        Some(1), // set_tid_address
+        Some(4), // fadvise64
+        Some(3), // tgkill
+        Some(1), // exit_group
+        None,    // lookup_dcookie
+        Some(2), // io_setup
+        Some(1), // io_destroy
+        Some(5), // io_getevents
+        Some(3), // io_submit
+        Some(3), // io_cancel
+        Some(1), // epoll_create
+        Some(4), // epoll_ctl
+        Some(4), // epoll_wait
+        Some(0), // restart_syscall
+        Some(4), // semtimedop
+        Some(3), // timer_create
+        Some(4), // timer_settime
+        Some(2), // timer_gettime
+        Some(1), // timer_getoverrun
+        Some(1), // timer_delete
+        Some(2), // clock_settime
+        Some(2), // clock_gettime
+        Some(2), // clock_getres
+        Some(4), // clock_nanosleep
+        Some(3), // fstatfs64
+        Some(3), // statfs64
+        Some(6), // mbind
+        Some(5), // get_mempolicy
+        Some(3), // set_mempolicy
+        Some(4), // mq_open
+        Some(1), // mq_unlink
+        Some(5), // mq_timedsend
+        Some(5), // mq_timedreceive
+        Some(2), // mq_notify
+        Some(3), // mq_getsetattr
+        Some(4), // kexec_load
+        None,    // vserver
+        Some(5), // waitid
+        Some(5), // add_key
+        Some(4), // request_key
+        Some(5), // keyctl
+        Some(3), // ioprio_set
+        Some(2), // ioprio_get
+        Some(6), // move_pages
+        Some(0), // inotify_init
+        Some(3), // inotify_add_watch
+        Some(2), // inotify_rm_watch
+        Some(4), // migrate_pages
+        Some(4), // openat
+        Some(3), // mkdirat
+        Some(4), // mknodat
+        Some(5), // fchownat
+        Some(3), // futimesat
+        Some(4), // newfstatat
+        Some(3), // unlinkat
+        Some(4), // renameat
+        Some(5), // linkat
+        Some(3), // symlinkat
+        Some(4), // readlinkat
+        Some(3), // fchmodat
+        Some(3), // faccessat
+        Some(6), // pselect6
+        Some(5), // ppoll
+        Some(1), // unshare
+        Some(6), // splice
+        Some(2), // set_robust_list
+        Some(3), // get_robust_list
+        Some(4), // sync_file_range
+        Some(4), // tee
+        Some(4), // vmsplice
+        Some(4), // fallocate
+        Some(3), // getcpu
+        Some(6), // epoll_pwait
+        Some(4), // utimensat
+        Some(3), // signalfd
+        None,    // timerfd
+        Some(1), // eventfd
+        Some(2), // timerfd_create
+        Some(4), // timerfd_settime
+        Some(2), // timerfd_gettime
+        Some(4), // signalfd4
+        Some(2), // eventfd2
+        Some(1), // epoll_create1
+        Some(3), // dup3
+        Some(2), // pipe2
+        Some(1), // inotify_init1
+        Some(5), // preadv
+        Some(5), // pwritev
+        Some(4), // rt_tgsigqueueinfo
+        Some(5), // recvmmsg
+        Some(2), // fanotify_init
+        Some(5), // fanotify_mark
+        Some(4), // prlimit64
+        Some(5), // name_to_handle_at
+        Some(3), // open_by_handle_at
+        Some(2), // clock_adjtime
+        Some(1), // syncfs
+        Some(2), // setns
+        Some(4), // sendmmsg
+        Some(6), // process_vm_readv
+        Some(6), // process_vm_writev
+        Some(4), // accept4
+        Some(3), // finit_module
+        Some(3), // sched_setattr
+        Some(4), // sched_getattr
+        Some(5), // renameat2
+        Some(3), // getrandom
+        Some(2), // memfd_create
+        Some(3), // bpf
+        Some(5), // execveat
+        Some(1), // userfaultfd
+        Some(3), // membarrier
+        Some(5), // kcmp
+        Some(3), // mlock2
+        Some(6), // copy_file_range
+        Some(6), // preadv2
+        Some(6), // pwritev2
+        Some(5), // statx
+        Some(6), // io_pgetevents
+        Some(5), // perf_event_open
+        Some(3), // seccomp
+        Some(4), // pkey_mprotect
+        Some(2), // pkey_alloc
+        Some(1), // pkey_free
+        Some(4), // rseq
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(4), // pidfd_send_signal
+        Some(2), // io_uring_setup
+        Some(6), // io_uring_enter
+        Some(4), // io_uring_register
+        Some(3), // open_tree
+        Some(5), // move_mount
+        Some(2), // fsopen
+        Some(5), // fsconfig
+        Some(3), // fsmount
+        Some(3), // fspick
+        Some(2), // pidfd_open
+        None,
+        Some(3), // close_range
+        Some(4), // openat2
+        Some(3), // pidfd_getfd
+        Some(4), // faccessat2
+        Some(5), // process_madvise
+        Some(6), // epoll_pwait2
+        Some(5), // mount_setattr
+        Some(4), // quotactl_fd
+        Some(3), // landlock_create_ruleset
+        Some(4), // landlock_add_rule
+        Some(2), // landlock_restrict_self
+        None,
+        Some(2), // process_mrelease
+        Some(5), // futex_waitv
+        Some(4), // set_mempolicy_home_node
+    ];
+
+    /// Argument types, as written in the kernel source.
+    #[allow(dead_code)]
+    pub(crate) static ARG_TYPES: [&[&str]; 451] = [
+        &[],                                                // ni_syscall
+        &["int"],                                           // exit
+        &["unsigned int", "char __user *", "size_t"],       // read
+        &["unsigned int", "const char __user *", "size_t"], // write
+        &["const char __user *", "int", "umode_t"],         // open
+        &["unsigned int"],                                  // close
+        &["const char __user *", "umode_t"],                // creat
+        &["const char __user *", "const char __user *"],    // link
+        &["const char __user *"],                           // unlink
+        &[
+            "const char __user *",
+            "const char __user *const __user *",
+            "const char __user *const __user *",
+        ], // execve
+        &["const char __user *"],                           // chdir
+        &["unsigned int"],                                  // fchdir
+        &["char __user *", "struct __kernel_old_timeval __user *"], // utimes
+        &["const char __user *", "umode_t", "unsigned"],    // mknod
+        &["const char __user *", "umode_t"],                // chmod
+        &["const char __user *", "uid_t", "gid_t"],         // chown
+        &["unsigned int", "off_t", "unsigned int"],         // lseek
+        &[],                                                // getpid
+        &[],                                                // getppid
+        &[
+            "char __user *",
+            "char __user *",
+            "char __user *",
+            "unsigned long",
+            "void __user *",
+        ], // mount
+        &["char __user *", "int"],                          // umount2
+        &["uid_t"],                                         // setuid
+        &[],                                                // getuid
+        &[],                                                // geteuid
+        &["long", "long", "unsigned long", "unsigned long"], // ptrace
+        &["const char __user *", "int"],                    // access
+        &[],                                                // sync
+        &["unsigned int"],                                  // fsync
+        &["unsigned int"],                                  // fdatasync
+        &["pid_t", "int"],                                  // kill
+        &["const char __user *", "const char __user *"],    // rename
+        &["const char __user *", "umode_t"],                // mkdir
+        &["const char __user *"],                           // rmdir
+        &["unsigned int"],                                  // dup
+        &["int __user *"],                                  // pipe
+        &["struct tms __user *"],                           // times
+        &["unsigned long"],                                 // brk
+        &["gid_t"],                                         // setgid
+        &[],                                                // getgid
+        &[],                                                // getegid
+        &["const char __user *"],                           // acct
+        &["unsigned int", "unsigned int", "unsigned long"], // ioctl
+        &["unsigned int", "unsigned int", "unsigned long"], // fcntl
+        &["int"],                                           // umask
+        &["const char __user *"],                           // chroot
+        &["unsigned", "struct ustat __user *"],             // ustat
+        &["unsigned int", "unsigned int"],                  // dup2
+        &["uid_t", "uid_t"],                                // setreuid
+        &["gid_t", "gid_t"],                                // setregid
+        &["uid_t __user *", "uid_t __user *", "uid_t __user *"], // getresuid
+        &["uid_t", "uid_t", "uid_t"],                       // setresuid
+        &["gid_t __user *", "gid_t __user *", "gid_t __user *"], // getresgid
+        &["gid_t", "gid_t", "gid_t"],                       // setresgid
+        &["int", "gid_t __user *"],                         // getgroups
+        &["int", "gid_t __user *"],                         // setgroups
+        &["pid_t"],                                         // getpgid
+        &["pid_t", "pid_t"],                                // setpgid
+        &[],                                                // setsid
+        &["pid_t"],                                         // getsid
+        &["char __user *", "int"],                          // sethostname
+        &["unsigned int", "struct rlimit __user *"],        // setrlimit
+        &["unsigned int", "struct rlimit __user *"],        // getrlimit
+        &["int", "struct rusage __user *"],                 // getrusage
+        &[
+            "struct __kernel_old_timeval __user *",
+            "struct timezone __user *",
+        ], // gettimeofday
+        &[
+            "struct __kernel_old_timeval __user *",
+            "struct timezone __user *",
+        ], // settimeofday
+        &[
+            "int",
+            "fd_set __user *",
+            "fd_set __user *",
+            "fd_set __user *",
+            "struct __kernel_old_timeval __user *",
+        ], // select
+        &["struct pollfd __user *", "unsigned int", "int"], // poll
+        &["const char __user *", "const char __user *"],    // symlink
+        &["const char __user *", "char __user *", "int"],   // readlink
+        &[],                                                // uselib
+        &["const char __user *", "int"],                    // swapon
+        &["const char __user *"],                           // swapoff
+        &["int", "int", "unsigned int", "void __user *"],   // reboot
+        &["const char __user *", "long"],                   // truncate
+        &["unsigned int", "unsigned long"],                 // ftruncate
+        &["unsigned int", "umode_t"],                       // fchmod
+        &["unsigned int", "uid_t", "gid_t"],                // fchown
+        &["int", "int"],                                    // getpriority
+        &["int", "int", "int"],                             // setpriority
+        &["const char __user *", "struct statfs __user *"], // statfs
+        &["unsigned int", "struct statfs __user *"],        // fstatfs
+        &[],                                                // gettid
+        &["key_t", "int", "int"],                           // semget
+        &["int", "struct sembuf __user *", "unsigned"],     // semop
+        &["int", "int", "int", "unsigned long"],            // semctl
+        &["key_t", "int"],                                  // msgget
+        &["int", "struct msgbuf __user *", "size_t", "int"], // msgsnd
+        &["int", "struct msgbuf __user *", "size_t", "long", "int"], // msgrcv
+        &["int", "int", "struct msqid_ds __user *"],        // msgctl
+        &["key_t", "size_t", "int"],                        // shmget
+        &["int", "char __user *", "int"],                   // shmat
+        &["char __user *"],                                 // shmdt
+        &["int", "int", "struct shmid_ds __user *"],        // shmctl
+        &["int", "char __user *", "int"],                   // syslog
+        &[
+            "int",
+            "struct __kernel_old_itimerval __user *",
+            "struct __kernel_old_itimerval __user *",
+        ], // setitimer
+        &["int", "struct __kernel_old_itimerval __user *"], // getitimer
+        &[],
+        &[],
+        &[],
+        &[],                                        // vhangup
+        &["const char __user *", "uid_t", "gid_t"], // lchown
+        &[
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+        ], // remap_file_pages
+        &["pid_t", "int __user *", "int", "struct rusage __user *"], // wait4
+        &["struct sysinfo __user *"],               // sysinfo
+        &[
+            "unsigned long",
+            "unsigned long",
+            "int __user *",
+            "int __user *",
+            "unsigned long",
+        ], // clone
+        &["char __user *", "int"],                  // setdomainname
+        &["struct new_utsname __user *"],           // uname
+        &["struct __kernel_timex __user *"],        // adjtimex
+        &[],
+        &["void __user *", "unsigned long", "const char __user *"], // init_module
+        &["const char __user *", "unsigned int"], // delete_module
+        &[],
+        &[],
+        &[
+            "unsigned int",
+            "const char __user *",
+            "qid_t",
+            "void __user *",
+        ], // quotactl
+        &["int", "long"],                           // bdflush
+        &["int", "unsigned long", "unsigned long"], // sysfs
+        &["unsigned int"],                          // personality
+        &[],                                        // afs_syscall
+        &["uid_t"],                                 // setfsuid
+        &["gid_t"],                                 // setfsgid
+        &[
+            "unsigned int",
+            "struct linux_dirent __user *",
+            "unsigned int",
+        ], // getdents
+        &["unsigned int", "unsigned int"],          // flock
+        &[
+            "unsigned long",
+            "const struct iovec __user *",
+            "unsigned long",
+        ], // readv
+        &[
+            "unsigned long",
+            "const struct iovec __user *",
+            "unsigned long",
+        ], // writev
+        &["unsigned int", "char __user *", "size_t", "loff_t"], // pread64
+        &["unsigned int", "const char __user *", "size_t", "loff_t"], // pwrite64
+        &[],                                                          // _sysctl
+        &[],                                                          // mmap
+        &["unsigned long", "size_t"],                                 // munmap
+        &["unsigned long", "size_t"],                                 // mlock
+        &["int"],                                      // mlockall
+        &["unsigned long", "size_t", "unsigned long"], // mprotect
+        &[
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+        ], // mremap
+        &["unsigned long", "size_t", "int"],           // msync
+        &["unsigned long", "size_t"],                  // munlock
+        &[],                                           // munlockall
+        &["pid_t", "struct sched_param __user *"],     // sched_getparam
+        &["pid_t", "struct sched_param __user *"],     // sched_setparam
+        &["pid_t"],                                    // sched_getscheduler
+        &["pid_t", "int", "struct sched_param __user *"], // sched_setscheduler
+        &[],                                           // sched_yield
+        &["int"],                                      // sched_get_priority_max
+        &["int"],                                      // sched_get_priority_min
+        &["pid_t", "struct __kernel_timespec __user *"], // sched_rr_get_interval
+        &[
+            "struct __kernel_timespec __user *",
+            "struct __kernel_timespec __user *",
+        ], // nanosleep
+        &[],                                             // nfsservctl
+        &[
+            "int",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+        ], // prctl
+        &[],                                             // old_getpagesize
+        &[],                                             // mmap2
+        &[
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+            "void __user *",
+        ], // pciconfig_read
+        &[
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+            "void __user *",
+        ], // pciconfig_write
+        &[],                                             // perfmonctl
+        &["const stack_t __user *", "stack_t __user *"], // sigaltstack
+        &[
+            "int",
+            "const struct sigaction __user *",
+            "struct sigaction __user *",
+            "size_t",
+        ], // rt_sigaction
+        &["sigset_t __user *", "size_t"],                // rt_sigpending
+        &["int", "sigset_t __user *", "sigset_t __user *", "size_t"], // rt_sigprocmask
+        &["pid_t", "int", "siginfo_t __user *"], // rt_sigqueueinfo
+        &[],                                     // rt_sigreturn
+        &["sigset_t __user *", "size_t"],        // rt_sigsuspend
+        &[
+            "const sigset_t __user *",
+            "siginfo_t __user *",
+            "const struct __kernel_timespec __user *",
+            "size_t",
+        ], // rt_sigtimedwait
+        &["char __user *", "unsigned long"],     // getcwd
+        &["cap_user_header_t", "cap_user_data_t"], // capget
+        &["cap_user_header_t", "const cap_user_data_t"], // capset
+        &["int", "int", "loff_t __user *", "size_t"], // sendfile
+        &[],                                     // getpmsg
+        &[],                                     // putpmsg
+        &["int", "int", "int"],                  // socket
+        &["int", "struct sockaddr __user *", "int"], // bind
+        &["int", "struct sockaddr __user *", "int"], // connect
+        &["int", "int"],                         // listen
+        &["int", "struct sockaddr __user *", "int __user *"], // accept
+        &["int", "struct sockaddr __user *", "int __user *"], // getsockname
+        &["int", "struct sockaddr __user *", "int __user *"], // getpeername
+        &["int", "int", "int", "int __user *"],  // socketpair
+        &["int", "void __user *", "size_t", "unsigned int"], // send
+        &[
+            "int",
+            "void __user *",
+            "size_t",
+            "unsigned int",
+            "struct sockaddr __user *",
+            "int",
+        ], // sendto
+        &["int", "void __user *", "size_t", "unsigned int"], // recv
+        &[
+            "int",
+            "void __user *",
+            "size_t",
+            "unsigned int",
+            "struct sockaddr __user *",
+            "int __user *",
+        ], // recvfrom
+        &["int", "int"],                         // shutdown
+        &["int", "int", "int", "char __user *", "int"], // setsockopt
+        &["int", "int", "int", "char __user *", "int __user *"], // getsockopt
+        &["int", "struct user_msghdr __user *", "unsigned int"], // sendmsg
+        &["int", "struct user_msghdr __user *", "unsigned int"], // recvmsg
+        &["const char __user *", "const char __user *"], // pivot_root
+        &["unsigned long", "size_t", "unsigned char __user *"], // mincore
+        &["unsigned long", "size_t", "int"],     // madvise
+        &["const char __user *", "struct stat __user *"], // stat
+        &["const char __user *", "struct stat __user *"], // lstat
+        &["unsigned int", "struct stat __user *"], // fstat
+        &[],                                     // clone2
+        &[
+            "unsigned int",
+            "struct linux_dirent64 __user *",
+            "unsigned int",
+        ], // getdents64
+        &[],                                     // getunwind
+        &["int", "loff_t", "size_t"],            // readahead
+        &[
+            "const char __user *",
+            "const char __user *",
+            "const void __user *",
+            "size_t",
+            "int",
+        ], // setxattr
+        &[
+            "const char __user *",
+            "const char __user *",
+            "const void __user *",
+            "size_t",
+            "int",
+        ], // lsetxattr
+        &[
+            "int",
+            "const char __user *",
+            "const void __user *",
+            "size_t",
+            "int",
+        ], // fsetxattr
+        &[
+            "const char __user *",
+            "const char __user *",
+            "void __user *",
+            "size_t",
+        ], // getxattr
+        &[
+            "const char __user *",
+            "const char __user *",
+            "void __user *",
+            "size_t",
+        ], // lgetxattr
+        &["int", "const char __user *", "void __user *", "size_t"], // fgetxattr
+        &["const char __user *", "char __user *", "size_t"], // listxattr
+        &["const char __user *", "char __user *", "size_t"], // llistxattr
+        &["int", "char __user *", "size_t"],     // flistxattr
+        &["const char __user *", "const char __user *"], // removexattr
+        &["const char __user *", "const char __user *"], // lremovexattr
+        &["int", "const char __user *"],         // fremovexattr
+        &["pid_t", "int"],                       // tkill
+        &[
+            "u32 __user *",
+            "int",
+            "u32",
+            "const struct __kernel_timespec __user *",
+            "u32 __user *",
+            "u32",
+        ], // futex
+        &["pid_t", "unsigned int", "unsigned long __user *"], // sched_setaffinity
+        &["pid_t", "unsigned int", "unsigned long __user *"], // sched_getaffinity
+        &["int __user *"],                                    // set_tid_address
+        &["int", "loff_t", "loff_t", "int"],                  // fadvise64
+        &["pid_t", "pid_t", "int"],                           // tgkill
+        &["int"],                                             // exit_group
+        &[],                                                  // lookup_dcookie
+        &["unsigned", "aio_context_t __user *"],              // io_setup
+        &["aio_context_t"],                                   // io_destroy
+        &[
+            "aio_context_t",
+            "long",
+            "long",
+            "struct io_event __user *",
+            "struct __kernel_timespec __user *",
+        ], // io_getevents
+        &["aio_context_t", "long", "struct iocb __user * __user *"], // io_submit
+        &[
+            "aio_context_t",
+            "struct iocb __user *",
+            "struct io_event __user *",
+        ], // io_cancel
+        &["int"], // epoll_create
+        &["int", "int", "int", "struct epoll_event __user *"], // epoll_ctl
+        &["int", "struct epoll_event __user *", "int", "int"], // epoll_wait
+        &[],      // restart_syscall
+        &[
+            "int",
+            "struct sembuf __user *",
+            "unsigned int",
+            "const struct __kernel_timespec __user *",
+        ], // semtimedop
+        &[
+            "const clockid_t",
+            "struct sigevent __user *",
+            "timer_t __user *",
+        ], // timer_create
+        &[
+            "timer_t",
+            "int",
+            "const struct __kernel_itimerspec __user *",
+            "struct __kernel_itimerspec __user *",
+        ], // timer_settime
+        &["timer_t", "struct __kernel_itimerspec __user *"], // timer_gettime
+        &["timer_t"], // timer_getoverrun
+        &["timer_t"], // timer_delete
+        &["const clockid_t", "const struct __kernel_timespec __user *"], // clock_settime
+        &["const clockid_t", "struct __kernel_timespec __user *"], // clock_gettime
+        &["const clockid_t", "struct __kernel_timespec __user *"], // clock_getres
+        &[
+            "const clockid_t",
+            "int",
+            "const struct __kernel_timespec __user *",
+            "struct __kernel_timespec __user *",
+        ], // clock_nanosleep
+        &["unsigned int", "size_t", "struct statfs64 __user *"],   // fstatfs64
+        &["const char __user *", "size_t", "struct statfs64 __user *"], // statfs64
+        &[
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+            "const unsigned long __user *",
+            "unsigned long",
+            "unsigned int",
+        ], // mbind
+        &[
+            "int __user *",
+            "unsigned long __user *",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+        ], // get_mempolicy
+        &["int", "const unsigned long __user *", "unsigned long"], // set_mempolicy
+        &[
+            "const char __user *",
+            "int",
+            "umode_t",
+            "struct mq_attr __user *",
+        ], // mq_open
+        &["const char __user *"],                                  // mq_unlink
+        &[
+            "mqd_t",
+            "const char __user *",
+            "size_t",
+            "unsigned int",
+            "const struct __kernel_timespec __user *",
+        ], // mq_timedsend
+        &[
+            "mqd_t",
+            "char __user *",
+            "size_t",
+            "unsigned int __user *",
+            "const struct __kernel_timespec __user *",
+        ], // mq_timedreceive
+        &["mqd_t", "const struct sigevent __user *"],              // mq_notify
+        &[
+            "mqd_t",
+            "const struct mq_attr __user *",
+            "struct mq_attr __user *",
+        ], // mq_getsetattr
+        &[
+            "unsigned long",
+            "unsigned long",
+            "struct kexec_segment __user *",
+            "unsigned long",
+        ], // kexec_load
+        &[],                                                       // vserver
+        &[
+            "int",
+            "pid_t",
+            "struct siginfo __user *",
+            "int",
+            "struct rusage __user *",
+        ], // waitid
+        &[
+            "const char __user *",
+            "const char __user *",
+            "const void __user *",
+            "size_t",
+            "key_serial_t",
+        ], // add_key
+        &[
+            "const char __user *",
+            "const char __user *",
+            "const char __user *",
+            "key_serial_t",
+        ], // request_key
+        &[
+            "int",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+        ], // keyctl
+        &["int", "int", "int"],                                    // ioprio_set
+        &["int", "int"],                                           // ioprio_get
+        &[
+            "pid_t",
+            "unsigned long",
+            "const void __user * __user *",
+            "const int __user *",
+            "int __user *",
+            "int",
+        ], // move_pages
+        &[],                                    // inotify_init
+        &["int", "const char __user *", "u32"], // inotify_add_watch
+        &["int", "__s32"],                      // inotify_rm_watch
+        &[
+            "pid_t",
+            "unsigned long",
+            "const unsigned long __user *",
+            "const unsigned long __user *",
+        ], // migrate_pages
+        &["int", "const char __user *", "int", "umode_t"], // openat
+        &["int", "const char __user *", "umode_t"], // mkdirat
+        &["int", "const char __user *", "umode_t", "unsigned int"], // mknodat
+        &["int", "const char __user *", "uid_t", "gid_t", "int"], // fchownat
+        &[
+            "int",
+            "const char __user *",
+            "struct __kernel_old_timeval __user *",
+        ], // futimesat
+        &["int", "const char __user *", "struct stat __user *", "int"], // newfstatat
+        &["int", "const char __user *", "int"], // unlinkat
+        &["int", "const char __user *", "int", "const char __user *"], // renameat
+        &[
+            "int",
+            "const char __user *",
+            "int",
+            "const char __user *",
+            "int",
+        ], // linkat
+        &["const char __user *", "int", "const char __user *"], // symlinkat
+        &["int", "const char __user *", "char __user *", "int"], // readlinkat
+        &["int", "const char __user *", "umode_t"],             // fchmodat
+        &["int", "const char __user *", "int"],                 // faccessat
+        &[
+            "int",
+            "fd_set __user *",
+            "fd_set __user *",
+            "fd_set __user *",
+            "struct __kernel_timespec __user *",
+            "void __user *",
+        ], // pselect6
+        &[
+            "struct pollfd __user *",
+            "unsigned int",
+            "struct __kernel_timespec __user *",
+            "const sigset_t __user *",
+            "size_t",
+        ], // ppoll
+        &["unsigned long"],                                     // unshare
+        &[
+            "int",
+            "loff_t __user *",
+            "int",
+            "loff_t __user *",
+            "size_t",
+            "unsigned int",
+        ], // splice
+        &["struct robust_list_head __user *", "size_t"], // set_robust_list
+        &[
+            "int",
+            "struct robust_list_head __user * __user *",
+            "size_t __user *",
+        ], // get_robust_list
+        &["int", "loff_t", "loff_t", "unsigned int"],    // sync_file_range
+        &["int", "int", "size_t", "unsigned int"],       // tee
+        &[
+            "int",
+            "const struct iovec __user *",
+            "unsigned long",
+            "unsigned int",
+        ], // vmsplice
+        &["int", "int", "loff_t", "loff_t"],             // fallocate
+        &[
+            "unsigned __user *",
+            "unsigned __user *",
+            "struct getcpu_cache __user *",
+        ], // getcpu
+        &[
+            "int",
+            "struct epoll_event __user *",
+            "int",
+            "int",
+            "const sigset_t __user *",
+            "size_t",
+        ], // epoll_pwait
+        &[
+            "int",
+            "const char __user *",
+            "struct __kernel_timespec __user *",
+            "int",
+        ], // utimensat
+        &["int", "sigset_t __user *", "size_t"],         // signalfd
+        &[],                                             // timerfd
+        &["unsigned int"],                               // eventfd
+        &["int", "int"],                                 // timerfd_create
+        &[
+            "int",
+            "int",
+            "const struct __kernel_itimerspec __user *",
+            "struct __kernel_itimerspec __user *",
+        ], // timerfd_settime
+        &["int", "struct __kernel_itimerspec __user *"], // timerfd_gettime
+        &["int", "sigset_t __user *", "size_t", "int"],  // signalfd4
+        &["unsigned int", "int"],                        // eventfd2
+        &["int"],                                        // epoll_create1
+        &["unsigned int", "unsigned int", "int"],        // dup3
+        &["int __user *", "int"],                        // pipe2
+        &["int"],                                        // inotify_init1
+        &[
+            "unsigned long",
+            "const struct iovec __user *",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+        ], // preadv
+        &[
+            "unsigned long",
+            "const struct iovec __user *",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+        ], // pwritev
+        &["pid_t", "pid_t", "int", "siginfo_t __user *"], // rt_tgsigqueueinfo
+        &[
+            "int",
+            "struct mmsghdr __user *",
+            "unsigned int",
+            "unsigned int",
+            "struct __kernel_timespec __user *",
+        ], // recvmmsg
+        &["unsigned int", "unsigned int"],               // fanotify_init
+        &["int", "unsigned int", "__u64", "int", "const char __user *"], // fanotify_mark
+        &[
+            "pid_t",
+            "unsigned int",
+            "const struct rlimit64 __user *",
+            "struct rlimit64 __user *",
+        ], // prlimit64
+        &[
+            "int",
+            "const char __user *",
+            "struct file_handle __user *",
+            "int __user *",
+            "int",
+        ], // name_to_handle_at
+        &["int", "struct file_handle __user *", "int"], // open_by_handle_at
+        &["const clockid_t", "struct __kernel_timex __user *"], // clock_adjtime
+        &["int"],                                       // syncfs
+        &["int", "int"],                                // setns
+        &[
+            "int",
+            "struct mmsghdr __user *",
+            "unsigned int",
+            "unsigned int",
+        ], // sendmmsg
+        &[
+            "pid_t",
+            "const struct iovec __user *",
+            "unsigned long",
+            "const struct iovec __user *",
+            "unsigned long",
+            "unsigned long",
+        ], // process_vm_readv
+        &[
+            "pid_t",
+            "const struct iovec __user *",
+            "unsigned long",
+            "const struct iovec __user *",
+            "unsigned long",
+            "unsigned long",
+        ], // process_vm_writev
+        &["int", "struct sockaddr __user *", "int __user *", "int"], // accept4
+        &["int", "const char __user *", "int"],         // finit_module
+        &["pid_t", "struct sched_attr __user *", "unsigned int"], // sched_setattr
+        &[
+            "pid_t",
+            "struct sched_attr __user *",
+            "unsigned int",
+            "unsigned int",
+        ], // sched_getattr
+        &[
+            "int",
+            "const char __user *",
+            "int",
+            "const char __user *",
+            "unsigned int",
+        ], // renameat2
+        &["char __user *", "size_t", "unsigned int"],             // getrandom
+        &["const char __user *", "unsigned int"], // memfd_create
+        &["int", "union bpf_attr __user *", "unsigned int"], // bpf
+        &[
+            "int",
+            "const char __user *",
+            "const char __user *const __user *",
+            "const char __user *const __user *",
+            "int",
+        ], // execveat
+        &["int"],                                 // userfaultfd
+        &["int", "unsigned int", "int"],          // membarrier
+        &["pid_t", "pid_t", "int", "unsigned long", "unsigned long"], // kcmp
+        &["unsigned long", "size_t", "int"],      // mlock2
+        &[
+            "int",
+            "loff_t __user *",
+            "int",
+            "loff_t __user *",
+            "size_t",
+            "unsigned int",
+        ], // copy_file_range
+        &[
+            "unsigned long",
+            "const struct iovec __user *",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+            "rwf_t",
+        ], // preadv2
+        &[
+            "unsigned long",
+            "const struct iovec __user *",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+            "rwf_t",
+        ], // pwritev2
+        &[
+            "int",
+            "const char __user *",
+            "unsigned",
+            "unsigned int",
+            "struct statx __user *",
+        ], // statx
+        &[
+            "aio_context_t",
+            "long",
+            "long",
+            "struct io_event __user *",
+            "struct __kernel_timespec __user *",
+            "const struct __aio_sigset __user *",
+        ], // io_pgetevents
+        &[
+            "struct perf_event_attr __user *",
+            "pid_t",
+            "int",
+            "int",
+            "unsigned long",
+        ], // perf_event_open
+        &["unsigned int", "unsigned int", "void __user *"], // seccomp
+        &["unsigned long", "size_t", "unsigned long", "int"], // pkey_mprotect
+        &["unsigned long", "unsigned long"],      // pkey_alloc
+        &["int"],                                 // pkey_free
+        &["struct rseq __user *", "u32", "int", "u32"], // rseq
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &["int", "int", "siginfo_t __user *", "unsigned int"], // pidfd_send_signal
+        &["u32", "struct io_uring_params __user *"],           // io_uring_setup
+        &[
+            "unsigned int",
+            "u32",
+            "u32",
+            "u32",
+            "const void __user *",
+            "size_t",
+        ], // io_uring_enter
+        &[
+            "unsigned int",
+            "unsigned int",
+            "void __user *",
+            "unsigned int",
+        ], // io_uring_register
+        &["int", "const char __user *", "unsigned"],           // open_tree
+        &[
+            "int",
+            "const char __user *",
+            "int",
+            "const char __user *",
+            "unsigned int",
+        ], // move_mount
+        &["const char __user *", "unsigned int"],              // fsopen
+        &[
+            "int",
+            "unsigned int",
+            "const char __user *",
+            "const void __user *",
+            "int",
+        ], // fsconfig
+        &["int", "unsigned int", "unsigned int"],              // fsmount
+        &["int", "const char __user *", "unsigned int"],       // fspick
+        &["pid_t", "unsigned int"],                            // pidfd_open
+        &[],
+        &["unsigned int", "unsigned int", "unsigned int"], // close_range
+        &[
+            "int",
+            "const char __user *",
+            "struct open_how __user *",
+            "size_t",
+        ], // openat2
+        &["int", "int", "unsigned int"],                   // pidfd_getfd
+        &["int", "const char __user *", "int", "int"],     // faccessat2
+        &[
+            "int",
+            "const struct iovec __user *",
+            "size_t",
+            "int",
+            "unsigned int",
+        ], // process_madvise
+        &[
+            "int",
+            "struct epoll_event __user *",
+            "int",
+            "const struct __kernel_timespec __user *",
+            "const sigset_t __user *",
+            "size_t",
+        ], // epoll_pwait2
+        &[
+            "int",
+            "const char __user *",
+            "unsigned int",
+            "struct mount_attr __user *",
+            "size_t",
+        ], // mount_setattr
+        &["unsigned int", "unsigned int", "qid_t", "void __user *"], // quotactl_fd
+        &[
+            "const struct landlock_ruleset_attr __user *const",
+            "const size_t",
+            "const __u32",
+        ], // landlock_create_ruleset
+        &[
+            "const int",
+            "const enum landlock_rule_type",
+            "const void __user *const",
+            "const __u32",
+        ], // landlock_add_rule
+        &["const int", "const __u32"], // landlock_restrict_self
+        &[],
+        &["int", "unsigned int"], // process_mrelease
+        &[
+            "struct futex_waitv __user *",
+            "unsigned int",
+            "unsigned int",
+            "struct __kernel_timespec __user *",
+            "clockid_t",
+        ], // futex_waitv
+        &[
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+            "unsigned long",
+        ], // set_mempolicy_home_node
+    ];
+
+    /// Argument names.
+    pub(crate) static ARG_NAMES: [&[&str]; 451] = [
+        &[],                                                // ni_syscall
+        &["error_code"],                                    // exit
+        &["fd", "buf", "count"],                            // read
+        &["fd", "buf", "count"],                            // write
+        &["filename", "flags", "mode"],                     // open
+        &["fd"],                                            // close
+        &["pathname", "mode"],                              // creat
+        &["oldname", "newname"],                            // link
+        &["pathname"],                                      // unlink
+        &["filename", "argv", "envp"],                      // execve
+        &["filename"],                                      // chdir
+        &["fd"],                                            // fchdir
+        &["filename", "utimes"],                            // utimes
+        &["filename", "mode", "dev"],                       // mknod
+        &["filename", "mode"],                              // chmod
+        &["filename", "user", "group"],                     // chown
+        &["fd", "offset", "whence"],                        // lseek
+        &[],                                                // getpid
+        &[],                                                // getppid
+        &["dev_name", "dir_name", "type", "flags", "data"], // mount
+        &["name", "flags"],                                 // umount2
+        &["uid"],                                           // setuid
+        &[],                                                // getuid
+        &[],                                                // geteuid
+        &["request", "pid", "addr", "data"],                // ptrace
+        &["filename", "mode"],                              // access
+        &[],                                                // sync
+        &["fd"],                                            // fsync
+        &["fd"],                                            // fdatasync
+        &["pid", "sig"],                                    // kill
+        &["oldname", "newname"],                            // rename
+        &["pathname", "mode"],                              // mkdir
+        &["pathname"],                                      // rmdir
+        &["fildes"],                                        // dup
+        &["fildes"],                                        // pipe
+        &["tbuf"],                                          // times
+        &["brk"],                                           // brk
+        &["gid"],                                           // setgid
+        &[],                                                // getgid
+        &[],                                                // getegid
+        &["name"],                                          // acct
+        &["fd", "cmd", "arg"],                              // ioctl
+        &["fd", "cmd", "arg"],                              // fcntl
+        &["mask"],                                          // umask
+        &["filename"],                                      // chroot
+        &["dev", "ubuf"],                                   // ustat
+        &["oldfd", "newfd"],                                // dup2
+        &["ruid", "euid"],                                  // setreuid
+        &["rgid", "egid"],                                  // setregid
+        &["ruidp", "euidp", "suidp"],                       // getresuid
+        &["ruid", "euid", "suid"],                          // setresuid
+        &["rgidp", "egidp", "sgidp"],                       // getresgid
+        &["rgid", "egid", "sgid"],                          // setresgid
+        &["gidsetsize", "grouplist"],                       // getgroups
+        &["gidsetsize", "grouplist"],                       // setgroups
+        &["pid"],                                           // getpgid
+        &["pid", "pgid"],                                   // setpgid
+        &[],                                                // setsid
+        &["pid"],                                           // getsid
+        &["name", "len"],                                   // sethostname
+        &["resource", "rlim"],                              // setrlimit
+        &["resource", "rlim"],                              // getrlimit
+        &["who", "ru"],                                     // getrusage
+        &["tv", "tz"],                                      // gettimeofday
+        &["tv", "tz"],                                      // settimeofday
+        &["n", "inp", "outp", "exp", "tvp"],                // select
+        &["ufds", "nfds", "timeout_msecs"],                 // poll
+        &["oldname", "newname"],                            // symlink
+        &["path", "buf", "bufsiz"],                         // readlink
+        &[],                                                // uselib
+        &["specialfile", "swap_flags"],                     // swapon
+        &["specialfile"],                                   // swapoff
+        &["magic1", "magic2", "cmd", "arg"],                // reboot
+        &["path", "length"],                                // truncate
+        &["fd", "length"],                                  // ftruncate
+        &["fd", "mode"],                                    // fchmod
+        &["fd", "user", "group"],                           // fchown
+        &["which", "who"],                                  // getpriority
+        &["which", "who", "niceval"],                       // setpriority
+        &["pathname", "buf"],                               // statfs
+        &["fd", "buf"],                                     // fstatfs
+        &[],                                                // gettid
+        &["key", "nsems", "semflg"],                        // semget
+        &["semid", "tsops", "nsops"],                       // semop
+        &["semid", "semnum", "cmd", "arg"],                 // semctl
+        &["key", "msgflg"],                                 // msgget
+        &["msqid", "msgp", "msgsz", "msgflg"],              // msgsnd
+        &["msqid", "msgp", "msgsz", "msgtyp", "msgflg"],    // msgrcv
+        &["msqid", "cmd", "buf"],                           // msgctl
+        &["key", "size", "shmflg"],                         // shmget
+        &["shmid", "shmaddr", "shmflg"],                    // shmat
+        &["shmaddr"],                                       // shmdt
+        &["shmid", "cmd", "buf"],                           // shmctl
+        &["type", "buf", "len"],                            // syslog
+        &["which", "value", "ovalue"],                      // setitimer
+        &["which", "value"],                                // getitimer
+        &[],
+        &[],
+        &[],
+        &[],                                          // vhangup
+        &["filename", "user", "group"],               // lchown
+        &["start", "size", "prot", "pgoff", "flags"], // remap_file_pages
+        &["upid", "stat_addr", "options", "ru"],      // wait4
+        &["info"],                                    // sysinfo
+        &[
+            "clone_flags",
+            "newsp",
+            "parent_tidptr",
+            "child_tidptr",
+            "tls",
+        ], // clone
+        &["name", "len"],                             // setdomainname
+        &["name"],                                    // uname
+        &["txc_p"],                                   // adjtimex
+        &[],
+        &["umod", "len", "uargs"], // init_module
+        &["name_user", "flags"],   // delete_module
+        &[],
+        &[],
+        &["cmd", "special", "id", "addr"], // quotactl
+        &["func", "data"],                 // bdflush
+        &["option", "arg1", "arg2"],       // sysfs
+        &["personality"],                  // personality
+        &[],                               // afs_syscall
+        &["uid"],                          // setfsuid
+        &["gid"],                          // setfsgid
+        &["fd", "dirent", "count"],        // getdents
+        &["fd", "cmd"],                    // flock
+        &["fd", "vec", "vlen"],            // readv
+        &["fd", "vec", "vlen"],            // writev
+        &["fd", "buf", "count", "pos"],    // pread64
+        &["fd", "buf", "count", "pos"],    // pwrite64
+        &[],                               // _sysctl
+        &[],                               // mmap
+        &["addr", "len"],                  // munmap
+        &["start", "len"],                 // mlock
+        &["flags"],                        // mlockall
+        &["start", "len", "prot"],         // mprotect
+        &["addr", "old_len", "new_len", "flags", "new_addr"], // mremap
+        &["start", "len", "flags"],        // msync
+        &["start", "len"],                 // munlock
+        &[],                               // munlockall
+        &["pid", "param"],                 // sched_getparam
+        &["pid", "param"],                 // sched_setparam
+        &["pid"],                          // sched_getscheduler
+        &["pid", "policy", "param"],       // sched_setscheduler
+        &[],                               // sched_yield
+        &["policy"],                       // sched_get_priority_max
+        &["policy"],                       // sched_get_priority_min
+        &["pid", "interval"],              // sched_rr_get_interval
+        &["rqtp", "rmtp"],                 // nanosleep
+        &[],                               // nfsservctl
+        &["option", "arg2", "arg3", "arg4", "arg5"], // prctl
+        &[],                               // old_getpagesize
+        &[],                               // mmap2
+        &["bus", "dfn", "off", "len", "buf"], // pciconfig_read
+        &["bus", "dfn", "off", "len", "buf"], // pciconfig_write
+        &[],                               // perfmonctl
+        &["uss", "uoss"],                  // sigaltstack
+        &["sig", "act", "oact", "sigsetsize"], // rt_sigaction
+        &["uset", "sigsetsize"],           // rt_sigpending
+        &["how", "nset", "oset", "sigsetsize"], // rt_sigprocmask
+        &["pid", "sig", "uinfo"],          // rt_sigqueueinfo
+        &[],                               // rt_sigreturn
+        &["unewset", "sigsetsize"],        // rt_sigsuspend
+        &["uthese", "uinfo", "uts", "sigsetsize"], // rt_sigtimedwait
+        &["buf", "size"],                  // getcwd
+        &["header", "dataptr"],            // capget
+        &["header", "data"],               // capset
+        &["out_fd", "in_fd", "offset", "count"], // sendfile
+        &[],                               // getpmsg
+        &[],                               // putpmsg
+        &["family", "type", "protocol"],   // socket
+        &["fd", "umyaddr", "addrlen"],     // bind
+        &["fd", "uservaddr", "addrlen"],   // connect
+        &["fd", "backlog"],                // listen
+        &["fd", "upeer_sockaddr", "upeer_addrlen"], // accept
+        &["fd", "usockaddr", "usockaddr_len"], // getsockname
+        &["fd", "usockaddr", "usockaddr_len"], // getpeername
+        &["family", "type", "protocol", "usockvec"], // socketpair
+        &["fd", "buff", "len", "flags"],   // send
+        &["fd", "buff", "len", "flags", "addr", "addr_len"], // sendto
+        &["fd", "ubuf", "size", "flags"],  // recv
+        &["fd", "ubuf", "size", "flags", "addr", "addr_len"], // recvfrom
+        &["fd", "how"],                    // shutdown
+        &["fd", "level", "optname", "optval", "optlen"], // setsockopt
+        &["fd", "level", "optname", "optval", "optlen"], // getsockopt
+        &["fd", "msg", "flags"],           // sendmsg
+        &["fd", "msg", "flags"],           // recvmsg
+        &["new_root", "put_old"],          // pivot_root
+        &["start", "len", "vec"],          // mincore
+        &["start", "len_in", "behavior"],  // madvise
+        &["filename", "statbuf"],          // stat
+        &["filename", "statbuf"],          // lstat
+        &["fd", "statbuf"],                // fstat
+        &[],                               // clone2
+        &["fd", "dirent", "count"],        // getdents64
+        &[],                               // getunwind
+        &["fd", "offset", "count"],        // readahead
+        &["pathname", "name", "value", "size", "flags"], // setxattr
+        &["pathname", "name", "value", "size", "flags"], // lsetxattr
+        &["fd", "name", "value", "size", "flags"], // fsetxattr
+        &["pathname", "name", "value", "size"], // getxattr
+        &["pathname", "name", "value", "size"], // lgetxattr
+        &["fd", "name", "value", "size"],  // fgetxattr
+        &["pathname", "list", "size"],     // listxattr
+        &["pathname", "list", "size"],     // llistxattr
+        &["fd", "list", "size"],           // flistxattr
+        &["pathname", "name"],             // removexattr
+        &["pathname", "name"],             // lremovexattr
+        &["fd", "name"],                   // fremovexattr
+        &["pid", "sig"],                   // tkill
+        &["uaddr", "op", "val", "utime", "uaddr2", "val3"], // futex
+        &["pid", "len", "user_mask_ptr"],  // sched_setaffinity
+        &["pid", "len", "user_mask_ptr"],  // sched_getaffinity
+        &["tidptr"],                       // set_tid_address
+        &["fd", "offset", "len", "advice"], // fadvise64
+        &["tgid", "pid", "sig"],           // tgkill
+        &["error_code"],                   // exit_group
+        &[],                               // lookup_dcookie
+        &["nr_events", "ctxp"],            // io_setup
+        &["ctx"],                          // io_destroy
+        &["ctx_id", "min_nr", "nr", "events", "timeout"], // io_getevents
+        &["ctx_id", "nr", "iocbpp"],       // io_submit
+        &["ctx_id", "iocb", "result"],     // io_cancel
+        &["size"],                         // epoll_create
+        &["epfd", "op", "fd", "event"],    // epoll_ctl
+        &["epfd", "events", "maxevents", "timeout"], // epoll_wait
+        &[],                               // restart_syscall
+        &["semid", "tsops", "nsops", "timeout"], // semtimedop
+        &["which_clock", "timer_event_spec", "created_timer_id"], // timer_create
+        &["timer_id", "flags", "new_setting", "old_setting"], // timer_settime
+        &["timer_id", "setting"],                             // timer_gettime
+        &["timer_id"],          // timer_getoverrun
+        &["timer_id"],          // timer_delete
+        &["which_clock", "tp"], // clock_settime
+        &["which_clock", "tp"], // clock_gettime
+        &["which_clock", "tp"], // clock_getres
+        &["which_clock", "flags", "rqtp", "rmtp"], // clock_nanosleep
+        &["fd", "sz", "buf"],   // fstatfs64
+        &["pathname", "sz", "buf"], // statfs64
+        &["start", "len", "mode", "nmask", "maxnode", "flags"], // mbind
+        &["policy", "nmask", "maxnode", "addr", "flags"], // get_mempolicy
+        &["mode", "nmask", "maxnode"], // set_mempolicy
+        &["u_name", "oflag", "mode", "u_attr"], // mq_open
+        &["u_name"],            // mq_unlink
+        &["mqdes", "u_msg_ptr", "msg_len", "msg_prio", "u_abs_timeout"], // mq_timedsend
+        &[
+            "mqdes",
+            "u_msg_ptr",
+            "msg_len",
+            "u_msg_prio",
+            "u_abs_timeout",
+        ], // mq_timedreceive
+        &["mqdes", "u_notification"], // mq_notify
+        &["mqdes", "u_mqstat", "u_omqstat"], // mq_getsetattr
+        &["entry", "nr_segments", "segments", "flags"], // kexec_load
+        &[],                          // vserver
+        &["which", "upid", "infop", "options", "ru"], // waitid
+        &["_type", "_description", "_payload", "plen", "ringid"], // add_key
+        &["_type", "_description", "_callout_info", "destringid"], // request_key
+        &["option", "arg2", "arg3", "arg4", "arg5"],               // keyctl
+        &["which", "who", "ioprio"],                               // ioprio_set
+        &["which", "who"],                                         // ioprio_get
+        &["pid", "nr_pages", "pages", "nodes", "status", "flags"], // move_pages
+        &[],                                                  // inotify_init
+        &["fd", "pathname", "mask"], // inotify_add_watch
+        &["fd", "wd"],               // inotify_rm_watch
+        &["pid", "maxnode", "old_nodes", "new_nodes"], // migrate_pages
+        &["dfd", "filename", "flags", "mode"], // openat
+        &["dfd", "pathname", "mode"], // mkdirat
+        &["dfd", "filename", "mode", "dev"], // mknodat
+        &["dfd", "filename", "user", "group", "flag"], // fchownat
+        &["dfd", "filename", "utimes"], // futimesat
+        &["dfd", "filename", "statbuf", "flag"], // newfstatat
+        &["dfd", "pathname", "flag"], // unlinkat
+        &["olddfd", "oldname", "newdfd", "newname"], // renameat
+        &["olddfd", "oldname", "newdfd", "newname", "flags"], // linkat
+        &["oldname", "newdfd", "newname"], // symlinkat
+        &["dfd", "pathname", "buf", "bufsiz"], // readlinkat
+        &["dfd", "filename", "mode"], // fchmodat
+        &["dfd", "filename", "mode"], // faccessat
+        &["n", "inp", "outp", "exp", "tsp", "sig"], // pselect6
+        &["ufds", "nfds", "tsp", "sigmask", "sigsetsize"], // ppoll
+        &["unshare_flags"],          // unshare
+        &["fd_in", "off_in", "fd_out", "off_out", "len", "flags"], // splice
+        &["head", "len"],            // set_robust_list
+        &["pid", "head_ptr", "len_ptr"], // get_robust_list
+        &["fd", "offset", "nbytes", "flags"], // sync_file_range
+        &["fdin", "fdout", "len", "flags"], // tee
+        &["fd", "uiov", "nr_segs", "flags"], // vmsplice
+        &["fd", "mode", "offset", "len"], // fallocate
+        &["cpup", "nodep", "unused"], // getcpu
+        &[
+            "epfd",
+            "events",
+            "maxevents",
+            "timeout",
+            "sigmask",
+            "sigsetsize",
+        ], // epoll_pwait
+        &["dfd", "filename", "utimes", "flags"], // utimensat
+        &["ufd", "user_mask", "sizemask"], // signalfd
+        &[],                         // timerfd
+        &["count"],                  // eventfd
+        &["clockid", "flags"],       // timerfd_create
+        &["ufd", "flags", "utmr", "otmr"], // timerfd_settime
+        &["ufd", "otmr"],            // timerfd_gettime
+        &["ufd", "user_mask", "sizemask", "flags"], // signalfd4
+        &["count", "flags"],         // eventfd2
+        &["flags"],                  // epoll_create1
+        &["oldfd", "newfd", "flags"], // dup3
+        &["fildes", "flags"],        // pipe2
+        &["flags"],                  // inotify_init1
+        &["fd", "vec", "vlen", "pos_l", "pos_h"], // preadv
+        &["fd", "vec", "vlen", "pos_l", "pos_h"], // pwritev
+        &["tgid", "pid", "sig", "uinfo"], // rt_tgsigqueueinfo
+        &["fd", "mmsg", "vlen", "flags", "timeout"], // recvmmsg
+        &["flags", "event_f_flags"], // fanotify_init
+        &["fanotify_fd", "flags", "mask", "dfd", "pathname"], // fanotify_mark
+        &["pid", "resource", "new_rlim", "old_rlim"], // prlimit64
+        &["dfd", "name", "handle", "mnt_id", "flag"], // name_to_handle_at
+        &["mountdirfd", "handle", "flags"], // open_by_handle_at
+        &["which_clock", "utx"],     // clock_adjtime
+        &["fd"],                     // syncfs
+        &["fd", "flags"],            // setns
+        &["fd", "mmsg", "vlen", "flags"], // sendmmsg
+        &["pid", "lvec", "liovcnt", "rvec", "riovcnt", "flags"], // process_vm_readv
+        &["pid", "lvec", "liovcnt", "rvec", "riovcnt", "flags"], // process_vm_writev
+        &["fd", "upeer_sockaddr", "upeer_addrlen", "flags"],     // accept4
+        &["fd", "uargs", "flags"],                               // finit_module
+        &["pid", "uattr", "flags"], // sched_setattr
+        &["pid", "uattr", "usize", "flags"], // sched_getattr
+        &["olddfd", "oldname", "newdfd", "newname", "flags"], // renameat2
+        &["ubuf", "len", "flags"],  // getrandom
+        &["uname", "flags"],        // memfd_create
+        &["cmd", "uattr", "size"],  // bpf
+        &["fd", "filename", "argv", "envp", "flags"], // execveat
+        &["flags"],                 // userfaultfd
+        &["cmd", "flags", "cpu_id"], // membarrier
+        &["pid1", "pid2", "type", "idx1", "idx2"], // kcmp
+        &["start", "len", "flags"], // mlock2
+        &["fd_in", "off_in", "fd_out", "off_out", "len", "flags"], // copy_file_range
+        &["fd", "vec", "vlen", "pos_l", "pos_h", "flags"],         // preadv2
+        &["fd", "vec", "vlen", "pos_l", "pos_h", "flags"],         // pwritev2
+        &["dfd", "filename", "flags", "mask", "buffer"],           // statx
+        &["ctx_id", "min_nr", "nr", "events", "timeout", "usig"], // io_pgetevents
+        &["attr_uptr", "pid", "cpu", "group_fd", "flags"], // perf_event_open
+        &["op", "flags", "uargs"],                         // seccomp
+        &["start", "len", "prot", "pkey"],                 // pkey_mprotect
+        &["flags", "init_val"],                            // pkey_alloc
+        &["pkey"],                                         // pkey_free
+        &["rseq", "rseq_len", "flags", "sig"],             // rseq
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &["pidfd", "sig", "info", "flags"], // pidfd_send_signal
+        &["entries", "params"],             // io_uring_setup
+        &["fd", "to_submit", "min_complete", "flags", "argp", "argsz"], // io_uring_enter
+        &["fd", "opcode", "arg", "nr_args"], // io_uring_register
+        &["dfd", "filename", "flags"],       // open_tree
+        &[
+            "from_dfd",
+            "from_pathname",
+            "to_dfd",
+            "to_pathname",
+            "flags",
+        ], // move_mount
+        &["_fs_name", "flags"],              // fsopen
+        &["fd", "cmd", "_key", "_value", "aux"], // fsconfig
+        &["fs_fd", "flags", "attr_flags"],   // fsmount
+        &["dfd", "path", "flags"],           // fspick
+        &["pid", "flags"],                   // pidfd_open
+        &[],
+        &["fd", "max_fd", "flags"], // close_range
+        &["dfd", "filename", "how", "usize"], // openat2
+        &["pidfd", "fd", "flags"],  // pidfd_getfd
+        &["dfd", "filename", "mode", "flags"], // faccessat2
+        &["pidfd", "vec", "vlen", "behavior", "flags"], // process_madvise
+        &[
+            "epfd",
+            "events",
+            "maxevents",
+            "timeout",
+            "sigmask",
+            "sigsetsize",
+        ], // epoll_pwait2
+        &["dfd", "path", "flags", "uattr", "usize"], // mount_setattr
+        &["fd", "cmd", "id", "addr"], // quotactl_fd
+        &["attr", "size", "flags"], // landlock_create_ruleset
+        &["ruleset_fd", "rule_type", "rule_attr", "flags"], // landlock_add_rule
+        &["ruleset_fd", "flags"],   // landlock_restrict_self
+        &[],
+        &["pidfd", "flags"], // process_mrelease
+        &["waiters", "nr_futexes", "flags", "timeout", "clockid"], // futex_waitv
+        &["start", "len", "home_node", "flags"], // set_mempolicy_home_node
+    ];
+
+    use crate::format::ArgFormat::{
+        self, Buf, Fd, Flags, Hex, Int, Mode, Str, Struct, Uint,
+    };
+
+    /// How arguments are shown by `format::Call`.
+    pub(crate) static ARG_FORMATS: [&[ArgFormat]; 451] = [
+        &[],                                                   // ni_syscall
+        &[Int],                                                // exit
+        &[Fd, Buf, Uint],                                      // read
+        &[Fd, Buf, Uint],                                      // write
+        &[Str, Flags(O_FLAGS), Mode],                          // open
+        &[Fd],                                                 // close
+        &[Str, Mode],                                          // creat
+        &[Str, Str],                                           // link
+        &[Str],                                                // unlink
+        &[Str, Hex, Hex],                                      // execve
+        &[Str],                                                // chdir
+        &[Fd],                                                 // fchdir
+        &[Str, Struct("__kernel_old_timeval")],                // utimes
+        &[Str, Mode, Uint],                                    // mknod
+        &[Str, Mode],                                          // chmod
+        &[Str, Uint, Uint],                                    // chown
+        &[Fd, Int, Uint],                                      // lseek
+        &[],                                                   // getpid
+        &[],                                                   // getppid
+        &[Str, Str, Str, Flags(MS_FLAGS), Hex],                // mount
+        &[Str, Int],                                           // umount2
+        &[Uint],                                               // setuid
+        &[],                                                   // getuid
+        &[],                                                   // geteuid
+        &[Int, Int, Hex, Uint],                                // ptrace
+        &[Str, Int],                                           // access
+        &[],                                                   // sync
+        &[Fd],                                                 // fsync
+        &[Fd],                                                 // fdatasync
+        &[Int, Int],                                           // kill
+        &[Str, Str],                                           // rename
+        &[Str, Mode],                                          // mkdir
+        &[Str],                                                // rmdir
+        &[Fd],                                                 // dup
+        &[Hex],                                                // pipe
+        &[Struct("tms")],                                      // times
+        &[Hex],                                                // brk
+        &[Uint],                                               // setgid
+        &[],                                                   // getgid
+        &[],                                                   // getegid
+        &[Str],                                                // acct
+        &[Fd, Uint, Uint],                                     // ioctl
+        &[Fd, Uint, Uint],                                     // fcntl
+        &[Int],                                                // umask
+        &[Str],                                                // chroot
+        &[Uint, Struct("ustat")],                              // ustat
+        &[Fd, Fd],                                             // dup2
+        &[Uint, Uint],                                         // setreuid
+        &[Uint, Uint],                                         // setregid
+        &[Hex, Hex, Hex],                                      // getresuid
+        &[Uint, Uint, Uint],                                   // setresuid
+        &[Hex, Hex, Hex],                                      // getresgid
+        &[Uint, Uint, Uint],                                   // setresgid
+        &[Int, Hex],                                           // getgroups
+        &[Int, Hex],                                           // setgroups
+        &[Int],                                                // getpgid
+        &[Int, Int],                                           // setpgid
+        &[],                                                   // setsid
+        &[Int],                                                // getsid
+        &[Str, Int],                                           // sethostname
+        &[Uint, Struct("rlimit")],                             // setrlimit
+        &[Uint, Struct("rlimit")],                             // getrlimit
+        &[Int, Struct("rusage")],                              // getrusage
+        &[Struct("__kernel_old_timeval"), Struct("timezone")], // gettimeofday
+        &[Struct("__kernel_old_timeval"), Struct("timezone")], // settimeofday
+        &[Int, Hex, Hex, Hex, Struct("__kernel_old_timeval")], // select
+        &[Struct("pollfd"), Uint, Int],                        // poll
+        &[Str, Str],                                           // symlink
+        &[Str, Buf, Int],                                      // readlink
+        &[],                                                   // uselib
+        &[Str, Int],                                           // swapon
+        &[Str],                                                // swapoff
+        &[Int, Int, Uint, Hex],                                // reboot
+        &[Str, Int],                                           // truncate
+        &[Fd, Uint],                                           // ftruncate
+        &[Fd, Mode],                                           // fchmod
+        &[Fd, Uint, Uint],                                     // fchown
+        &[Int, Int],                                           // getpriority
+        &[Int, Int, Int],                                      // setpriority
+        &[Str, Struct("statfs")],                              // statfs
+        &[Fd, Struct("statfs")],                               // fstatfs
+        &[],                                                   // gettid
+        &[Uint, Int, Int],                                     // semget
+        &[Int, Struct("sembuf"), Uint],                        // semop
+        &[Int, Int, Int, Uint],                                // semctl
+        &[Uint, Int],                                          // msgget
+        &[Int, Struct("msgbuf"), Uint, Int],                   // msgsnd
+        &[Int, Struct("msgbuf"), Uint, Int, Int],              // msgrcv
+        &[Int, Int, Struct("msqid_ds")],                       // msgctl
+        &[Uint, Uint, Int],                                    // shmget
+        &[Int, Str, Int],                                      // shmat
+        &[Str],                                                // shmdt
+        &[Int, Int, Struct("shmid_ds")],                       // shmctl
+        &[Int, Buf, Int],                                      // syslog
+        &[
+            Int,
+            Struct("__kernel_old_itimerval"),
+            Struct("__kernel_old_itimerval"),
+        ], // setitimer
+        &[Int, Struct("__kernel_old_itimerval")],              // getitimer
+        &[],
+        &[],
+        &[],
+        &[],                                         // vhangup
+        &[Str, Uint, Uint],                          // lchown
+        &[Hex, Uint, Flags(PROT_FLAGS), Uint, Uint], // remap_file_pages
+        &[Int, Hex, Int, Struct("rusage")],          // wait4
+        &[Struct("sysinfo")],                        // sysinfo
+        &[Flags(CLONE_FLAGS), Uint, Hex, Hex, Uint], // clone
+        &[Str, Int],                                 // setdomainname
+        &[Struct("new_utsname")],                    // uname
+        &[Struct("__kernel_timex")],                 // adjtimex
+        &[],
+        &[Hex, Uint, Str], // init_module
+        &[Str, Uint],      // delete_module
+        &[],
+        &[],
+        &[Uint, Str, Uint, Hex],             // quotactl
+        &[Int, Int],                         // bdflush
+        &[Int, Uint, Uint],                  // sysfs
+        &[Uint],                             // personality
+        &[],                                 // afs_syscall
+        &[Uint],                             // setfsuid
+        &[Uint],                             // setfsgid
+        &[Fd, Struct("linux_dirent"), Uint], // getdents
+        &[Fd, Uint],                         // flock
+        &[Fd, Struct("iovec"), Uint],        // readv
+        &[Fd, Struct("iovec"), Uint],        // writev
+        &[Fd, Buf, Uint, Int],               // pread64
+        &[Fd, Buf, Uint, Int],               // pwrite64
+        &[],                                 // _sysctl
+        &[],                                 // mmap
+        &[Hex, Uint],                        // munmap
+        &[Hex, Uint],                        // mlock
+        &[Int],                              // mlockall
+        &[Hex, Uint, Flags(PROT_FLAGS)],     // mprotect
+        &[Hex, Uint, Uint, Uint, Hex],       // mremap
+        &[Hex, Uint, Int],                   // msync
+        &[Hex, Uint],                        // munlock
+        &[],                                 // munlockall
+        &[Int, Struct("sched_param")],       // sched_getparam
+        &[Int, Struct("sched_param")],       // sched_setparam
+        &[Int],                              // sched_getscheduler
+        &[Int, Int, Struct("sched_param")],  // sched_setscheduler
+        &[],                                 // sched_yield
+        &[Int],                              // sched_get_priority_max
+        &[Int],                              // sched_get_priority_min
+        &[Int, Struct("__kernel_timespec")], // sched_rr_get_interval
+        &[Struct("__kernel_timespec"), Struct("__kernel_timespec")], // nanosleep
+        &[],                                                    // nfsservctl
+        &[Int, Uint, Uint, Uint, Uint],                         // prctl
+        &[],                            // old_getpagesize
+        &[],                            // mmap2
+        &[Uint, Uint, Uint, Uint, Hex], // pciconfig_read
+        &[Uint, Uint, Uint, Uint, Hex], // pciconfig_write
+        &[],                            // perfmonctl
+        &[Hex, Hex],                    // sigaltstack
+        &[Int, Struct("sigaction"), Struct("sigaction"), Uint], // rt_sigaction
+        &[Hex, Uint],                   // rt_sigpending
+        &[Int, Hex, Hex, Uint],         // rt_sigprocmask
+        &[Int, Int, Hex],               // rt_sigqueueinfo
+        &[],                            // rt_sigreturn
+        &[Hex, Uint],                   // rt_sigsuspend
+        &[Hex, Hex, Struct("__kernel_timespec"), Uint], // rt_sigtimedwait
+        &[Buf, Uint],                   // getcwd
+        &[Hex, Hex],                    // capget
+        &[Hex, Hex],                    // capset
+        &[Fd, Fd, Hex, Uint],           // sendfile
+        &[],                            // getpmsg
+        &[],                            // putpmsg
+        &[Int, Int, Int],               // socket
+        &[Fd, Struct("sockaddr"), Int], // bind
+        &[Fd, Struct("sockaddr"), Int], // connect
+        &[Fd, Int],                     // listen
+        &[Fd, Struct("sockaddr"), Hex], // accept
+        &[Fd, Struct("sockaddr"), Hex], // getsockname
+        &[Fd, Struct("sockaddr"), Hex], // getpeername
+        &[Int, Int, Int, Hex],          // socketpair
+        &[Fd, Hex, Uint, Uint],         // send
+        &[Fd, Hex, Uint, Uint, Struct("sockaddr"), Int], // sendto
+        &[Fd, Hex, Uint, Uint],         // recv
+        &[Fd, Hex, Uint, Uint, Struct("sockaddr"), Hex], // recvfrom
+        &[Fd, Int],                     // shutdown
+        &[Fd, Int, Int, Str, Int],      // setsockopt
+        &[Fd, Int, Int, Str, Hex],      // getsockopt
+        &[Fd, Struct("user_msghdr"), Uint], // sendmsg
+        &[Fd, Struct("user_msghdr"), Uint], // recvmsg
+        &[Str, Str],                    // pivot_root
+        &[Hex, Uint, Hex],              // mincore
+        &[Hex, Uint, Int],              // madvise
+        &[Str, Struct("stat")],         // stat
+        &[Str, Struct("stat")],         // lstat
+        &[Fd, Struct("stat")],          // fstat
+        &[],                            // clone2
+        &[Fd, Struct("linux_dirent64"), Uint], // getdents64
+        &[],                            // getunwind
+        &[Fd, Int, Uint],               // readahead
+        &[Str, Str, Hex, Uint, Int],    // setxattr
+        &[Str, Str, Hex, Uint, Int],    // lsetxattr
+        &[Fd, Str, Hex, Uint, Int],     // fsetxattr
+        &[Str, Str, Hex, Uint],         // getxattr
+        &[Str, Str, Hex, Uint],         // lgetxattr
+        &[Fd, Str, Hex, Uint],          // fgetxattr
+        &[Str, Str, Uint],              // listxattr
+        &[Str, Str, Uint],              // llistxattr
+        &[Fd, Str, Uint],               // flistxattr
+        &[Str, Str],                    // removexattr
+        &[Str, Str],                    // lremovexattr
+        &[Fd, Str],                     // fremovexattr
+        &[Int, Int],                    // tkill
+        &[Hex, Int, Uint, Struct("__kernel_timespec"), Hex, Uint], // futex
+        &[Int, Uint, Hex],              // sched_setaffinity
+        &[Int, Uint, Hex],              // sched_getaffinity
+        &[Hex],                         // set_tid_address
+        &[Fd, Int, Int, Int],           // fadvise64
+        &[Int, Int, Int],               // tgkill
+        &[Int],                         // exit_group
+        &[],                            // lookup_dcookie
+        &[Uint, Hex],                   // io_setup
+        &[Uint],                        // io_destroy
+        &[
+            Uint,
+            Int,
+            Int,
+            Struct("io_event"),
+            Struct("__kernel_timespec"),
+        ], // io_getevents
+        &[Uint, Int, Hex],              // io_submit
+        &[Uint, Struct("iocb"), Struct("io_event")], // io_cancel
+        &[Int],                         // epoll_create
+        &[Fd, Int, Fd, Struct("epoll_event")], // epoll_ctl
+        &[Fd, Struct("epoll_event"), Int, Int], // epoll_wait
+        &[],                            // restart_syscall
+        &[Int, Struct("sembuf"), Uint, Struct("__kernel_timespec")], // semtimedop
+        &[Int, Struct("sigevent"), Hex], // timer_create
+        &[
+            Int,
+            Int,
+            Struct("__kernel_itimerspec"),
+            Struct("__kernel_itimerspec"),
+        ], // timer_settime
+        &[Int, Struct("__kernel_itimerspec")], // timer_gettime
+        &[Int],                          // timer_getoverrun
+        &[Int],                          // timer_delete
+        &[Int, Struct("__kernel_timespec")], // clock_settime
+        &[Int, Struct("__kernel_timespec")], // clock_gettime
+        &[Int, Struct("__kernel_timespec")], // clock_getres
+        &[
+            Int,
+            Int,
+            Struct("__kernel_timespec"),
+            Struct("__kernel_timespec"),
+        ], // clock_nanosleep
+        &[Fd, Uint, Struct("statfs64")], // fstatfs64
+        &[Str, Uint, Struct("statfs64")], // statfs64
+        &[Hex, Uint, Uint, Hex, Uint, Uint], // mbind
+        &[Hex, Hex, Uint, Hex, Uint],    // get_mempolicy
+        &[Int, Hex, Uint],               // set_mempolicy
+        &[Str, Int, Mode, Struct("mq_attr")], // mq_open
+        &[Str],                          // mq_unlink
+        &[Uint, Str, Uint, Uint, Struct("__kernel_timespec")], // mq_timedsend
+        &[Uint, Str, Uint, Hex, Struct("__kernel_timespec")], // mq_timedreceive
+        &[Uint, Struct("sigevent")],     // mq_notify
+        &[Uint, Struct("mq_attr"), Struct("mq_attr")], // mq_getsetattr
+        &[Uint, Uint, Struct("kexec_segment"), Uint], // kexec_load
+        &[],                             // vserver
+        &[Int, Int, Struct("siginfo"), Int, Struct("rusage")], // waitid
+        &[Str, Str, Hex, Uint, Int],     // add_key
+        &[Str, Str, Str, Int],           // request_key
+        &[Int, Uint, Uint, Uint, Uint],  // keyctl
+        &[Int, Int, Int],                // ioprio_set
+        &[Int, Int],                     // ioprio_get
+        &[Int, Uint, Hex, Hex, Hex, Int], // move_pages
+        &[],                             // inotify_init
+        &[Fd, Str, Uint],                // inotify_add_watch
+        &[Fd, Int],                      // inotify_rm_watch
+        &[Int, Uint, Hex, Hex],          // migrate_pages
+        &[Fd, Str, Flags(O_FLAGS), Mode], // openat
+        &[Fd, Str, Mode],                // mkdirat
+        &[Fd, Str, Mode, Uint],          // mknodat
+        &[Fd, Str, Uint, Uint, Flags(AT_FLAGS)], // fchownat
+        &[Fd, Str, Struct("__kernel_old_timeval")], // futimesat
+        &[Fd, Str, Struct("stat"), Flags(AT_FLAGS)], // newfstatat
+        &[Fd, Str, Flags(AT_FLAGS)],     // unlinkat
+        &[Fd, Str, Fd, Str],             // renameat
+        &[Fd, Str, Fd, Str, Flags(AT_FLAGS)], // linkat
+        &[Str, Fd, Str],                 // symlinkat
+        &[Fd, Str, Buf, Int],            // readlinkat
+        &[Fd, Str, Mode],                // fchmodat
+        &[Fd, Str, Int],                 // faccessat
+        &[Int, Hex, Hex, Hex, Struct("__kernel_timespec"), Hex], // pselect6
+        &[
+            Struct("pollfd"),
+            Uint,
+            Struct("__kernel_timespec"),
+            Hex,
+            Uint,
+        ], // ppoll
+        &[Flags(CLONE_FLAGS)],           // unshare
+        &[Fd, Hex, Fd, Hex, Uint, Uint], // splice
+        &[Struct("robust_list_head"), Uint], // set_robust_list
+        &[Int, Hex, Hex],                // get_robust_list
+        &[Fd, Int, Int, Uint],           // sync_file_range
+        &[Int, Int, Uint, Uint],         // tee
+        &[Fd, Struct("iovec"), Uint, Uint], // vmsplice
+        &[Fd, Int, Int, Int],            // fallocate
+        &[Hex, Hex, Struct("getcpu_cache")], // getcpu
+        &[Fd, Struct("epoll_event"), Int, Int, Hex, Uint], // epoll_pwait
+        &[Fd, Str, Struct("__kernel_timespec"), Flags(AT_FLAGS)], // utimensat
+        &[Int, Hex, Uint],               // signalfd
+        &[],                             // timerfd
+        &[Uint],                         // eventfd
+        &[Int, Int],                     // timerfd_create
+        &[
+            Int,
+            Int,
+            Struct("__kernel_itimerspec"),
+            Struct("__kernel_itimerspec"),
+        ], // timerfd_settime
+        &[Int, Struct("__kernel_itimerspec")], // timerfd_gettime
+        &[Int, Hex, Uint, Int],          // signalfd4
+        &[Uint, Int],                    // eventfd2
+        &[Int],                          // epoll_create1
+        &[Fd, Fd, Flags(O_FLAGS)],       // dup3
+        &[Hex, Flags(O_FLAGS)],          // pipe2
+        &[Int],                          // inotify_init1
+        &[Fd, Struct("iovec"), Uint, Uint, Uint], // preadv
+        &[Fd, Struct("iovec"), Uint, Uint, Uint], // pwritev
+        &[Int, Int, Int, Hex],           // rt_tgsigqueueinfo
+        &[
+            Fd,
+            Struct("mmsghdr"),
+            Uint,
+            Uint,
+            Struct("__kernel_timespec"),
+        ], // recvmmsg
+        &[Uint, Uint],                   // fanotify_init
+        &[Int, Uint, Uint, Fd, Str],     // fanotify_mark
+        &[Int, Uint, Struct("rlimit64"), Struct("rlimit64")], // prlimit64
+        &[Fd, Str, Struct("file_handle"), Hex, Flags(AT_FLAGS)], // name_to_handle_at
+        &[Int, Struct("file_handle"), Int], // open_by_handle_at
+        &[Int, Struct("__kernel_timex")],   // clock_adjtime
+        &[Fd],                              // syncfs
+        &[Fd, Flags(CLONE_FLAGS)],          // setns
+        &[Fd, Struct("mmsghdr"), Uint, Uint], // sendmmsg
+        &[Int, Struct("iovec"), Uint, Struct("iovec"), Uint, Uint], // process_vm_readv
+        &[Int, Struct("iovec"), Uint, Struct("iovec"), Uint, Uint], // process_vm_writev
+        &[Fd, Struct("sockaddr"), Hex, Int],                        // accept4
+        &[Fd, Str, Int],                          // finit_module
+        &[Int, Struct("sched_attr"), Uint],       // sched_setattr
+        &[Int, Struct("sched_attr"), Uint, Uint], // sched_getattr
+        &[Fd, Str, Fd, Str, Uint],                // renameat2
+        &[Buf, Uint, Uint],                       // getrandom
+        &[Str, Uint],                             // memfd_create
+        &[Int, Hex, Uint],                        // bpf
+        &[Fd, Str, Hex, Hex, Flags(AT_FLAGS)],    // execveat
+        &[Int],                                   // userfaultfd
+        &[Int, Uint, Int],                        // membarrier
+        &[Int, Int, Int, Uint, Uint],             // kcmp
+        &[Hex, Uint, Int],                        // mlock2
+        &[Fd, Hex, Fd, Hex, Uint, Uint],          // copy_file_range
+        &[Fd, Struct("iovec"), Uint, Uint, Uint, Int], // preadv2
+        &[Fd, Struct("iovec"), Uint, Uint, Uint, Int], // pwritev2
+        &[Fd, Str, Flags(AT_FLAGS), Uint, Struct("statx")], // statx
+        &[
+            Uint,
+            Int,
+            Int,
+            Struct("io_event"),
+            Struct("__kernel_timespec"),
+            Struct("__aio_sigset"),
+        ], // io_pgetevents
+        &[Struct("perf_event_attr"), Int, Int, Int, Uint], // perf_event_open
+        &[Uint, Uint, Hex],                       // seccomp
+        &[Hex, Uint, Flags(PROT_FLAGS), Int],     // pkey_mprotect
+        &[Uint, Uint],                            // pkey_alloc
+        &[Int],                                   // pkey_free
+        &[Struct("rseq"), Uint, Int, Uint],       // rseq
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[],
+        &[Fd, Int, Hex, Uint], // pidfd_send_signal
+        &[Uint, Struct("io_uring_params")], // io_uring_setup
+        &[Fd, Uint, Uint, Uint, Hex, Uint], // io_uring_enter
+        &[Fd, Uint, Hex, Uint], // io_uring_register
+        &[Fd, Str, Uint],      // open_tree
+        &[Int, Str, Int, Str, Uint], // move_mount
+        &[Str, Uint],          // fsopen
+        &[Fd, Uint, Str, Hex, Int], // fsconfig
+        &[Int, Uint, Uint],    // fsmount
+        &[Fd, Str, Uint],      // fspick
+        &[Int, Uint],          // pidfd_open
+        &[],
+        &[Fd, Uint, Uint],                       // close_range
+        &[Fd, Str, Struct("open_how"), Uint],    // openat2
+        &[Fd, Fd, Uint],                         // pidfd_getfd
+        &[Fd, Str, Int, Flags(AT_FLAGS)],        // faccessat2
+        &[Fd, Struct("iovec"), Uint, Int, Uint], // process_madvise
+        &[
+            Fd,
+            Struct("epoll_event"),
+            Int,
+            Struct("__kernel_timespec"),
+            Hex,
+            Uint,
+        ], // epoll_pwait2
+        &[Fd, Str, Uint, Struct("mount_attr"), Uint], // mount_setattr
+        &[Fd, Uint, Uint, Hex],                  // quotactl_fd
+        &[Struct("landlock_ruleset_attr"), Uint, Uint], // landlock_create_ruleset
+        &[Int, Hex, Hex, Uint],                         // landlock_add_rule
+        &[Int, Uint], // landlock_restrict_self
+        &[],
+        &[Fd, Uint], // process_mrelease
+        &[
+            Struct("futex_waitv"),
+            Uint,
+            Uint,
+            Struct("__kernel_timespec"),
+            Int,
+        ], // futex_waitv
+        &[Hex, Uint, Uint, Uint], // set_mempolicy_home_node
+    ];
+
+    static O_FLAGS: &[(&str, u64)] = &[
+        ("O_SYNC", 0x0010_1000),
+        ("O_TMPFILE", 0x0041_0000),
+        ("O_APPEND", 0x400),
+        ("O_CLOEXEC", 0x0008_0000),
+        ("O_CREAT", 0x40),
+        ("O_DIRECT", 0x4000),
+        ("O_DIRECTORY", 0x0001_0000),
+        ("O_DSYNC", 0x1000),
+        ("O_EXCL", 0x80),
+        ("O_LARGEFILE", 0x8000),
+        ("O_NOATIME", 0x0004_0000),
+        ("O_NOCTTY", 0x100),
+        ("O_NOFOLLOW", 0x0002_0000),
+        ("O_NONBLOCK", 0x800),
+        ("O_PATH", 0x0020_0000),
+        ("O_RDWR", 0x2),
+        ("O_TRUNC", 0x200),
+        ("O_WRONLY", 0x1),
+        ("O_RDONLY", 0x0),
+    ];
+
+    static AT_FLAGS: &[(&str, u64)] = &[
+        ("AT_EMPTY_PATH", 0x1000),
+        ("AT_NO_AUTOMOUNT", 0x800),
+        ("AT_RECURSIVE", 0x8000),
+        ("AT_REMOVEDIR", 0x200),
+        ("AT_STATX_DONT_SYNC", 0x4000),
+        ("AT_STATX_FORCE_SYNC", 0x2000),
+        ("AT_SYMLINK_FOLLOW", 0x400),
+        ("AT_SYMLINK_NOFOLLOW", 0x100),
+    ];
+
+    static CLONE_FLAGS: &[(&str, u64)] = &[
+        ("CLONE_CHILD_CLEARTID", 0x0020_0000),
+        ("CLONE_CHILD_SETTID", 0x0100_0000),
+        ("CLONE_CLEAR_SIGHAND", 0x0001_0000_0000),
+        ("CLONE_DETACHED", 0x0040_0000),
+        ("CLONE_FILES", 0x400),
+        ("CLONE_FS", 0x200),
+        ("CLONE_INTO_CGROUP", 0x0002_0000_0000),
+        ("CLONE_IO", 0x8000_0000),
+        ("CLONE_NEWCGROUP", 0x0200_0000),
+        ("CLONE_NEWIPC", 0x0800_0000),
+        ("CLONE_NEWNET", 0x4000_0000),
+        ("CLONE_NEWNS", 0x0002_0000),
+        ("CLONE_NEWPID", 0x2000_0000),
+        ("CLONE_NEWTIME", 0x80),
+        ("CLONE_NEWUSER", 0x1000_0000),
+        ("CLONE_NEWUTS", 0x0400_0000),
+        ("CLONE_PARENT", 0x8000),
+        ("CLONE_PARENT_SETTID", 0x0010_0000),
+        ("CLONE_PIDFD", 0x1000),
+        ("CLONE_PTRACE", 0x2000),
+        ("CLONE_SETTLS", 0x0008_0000),
+        ("CLONE_SIGHAND", 0x800),
+        ("CLONE_SYSVSEM", 0x0004_0000),
+        ("CLONE_THREAD", 0x0001_0000),
+        ("CLONE_UNTRACED", 0x0080_0000),
+        ("CLONE_VFORK", 0x4000),
+        ("CLONE_VM", 0x100),
+    ];
+
+    static PROT_FLAGS: &[(&str, u64)] = &[
+        ("PROT_EXEC", 0x4),
+        ("PROT_GROWSDOWN", 0x0100_0000),
+        ("PROT_GROWSUP", 0x0200_0000),
+        ("PROT_READ", 0x1),
+        ("PROT_SEM", 0x8),
+        ("PROT_WRITE", 0x2),
+        ("PROT_NONE", 0x0),
+    ];
+
+    static MS_FLAGS: &[(&str, u64)] = &[
+        ("MS_ACTIVE", 0x4000_0000),
+        ("MS_BIND", 0x1000),
+        ("MS_BORN", 0x2000_0000),
+        ("MS_DIRSYNC", 0x80),
+        ("MS_I_VERSION", 0x0080_0000),
+        ("MS_KERNMOUNT", 0x0040_0000),
+        ("MS_LAZYTIME", 0x0200_0000),
+        ("MS_MANDLOCK", 0x40),
+        ("MS_MOVE", 0x2000),
+        ("MS_NOATIME", 0x400),
+        ("MS_NODEV", 0x4),
+        ("MS_NODIRATIME", 0x800),
+        ("MS_NOEXEC", 0x8),
+        ("MS_NOREMOTELOCK", 0x0800_0000),
+        ("MS_NOSEC", 0x1000_0000),
+        ("MS_NOSUID", 0x2),
+        ("MS_NOSYMFOLLOW", 0x100),
+        ("MS_NOUSER", 0x8000_0000),
+        ("MS_POSIXACL", 0x0001_0000),
+        ("MS_PRIVATE", 0x0004_0000),
+        ("MS_RDONLY", 0x1),
+        ("MS_REC", 0x4000),
+        ("MS_RELATIME", 0x0020_0000),
+        ("MS_REMOUNT", 0x20),
+        ("MS_SHARED", 0x0010_0000),
+        ("MS_SILENT", 0x8000),
+        ("MS_SLAVE", 0x0008_0000),
+        ("MS_STRICTATIME", 0x0100_0000),
+        ("MS_SUBMOUNT", 0x0400_0000),
+        ("MS_SYNCHRONOUS", 0x10),
+        ("MS_UNBINDABLE", 0x0002_0000),
+    ];
+}
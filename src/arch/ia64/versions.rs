@@ -0,0 +1,41 @@
+//! Syscalls added and removed between kernel versions.
+
+// This file is automatically generated. Do not edit!
+
+/// Syscalls added in Linux v5.10 (since v5.4).
+pub const ADDED_IN_V5_10: &[&str] = &[
+    "close_range",
+    "openat2",
+    "pidfd_getfd",
+    "faccessat2",
+    "process_madvise",
+];
+
+/// Syscalls removed in Linux v5.10 (since v5.4).
+pub const REMOVED_IN_V5_10: &[&str] = &[];
+
+/// Syscalls added in Linux v5.15 (since v5.10).
+pub const ADDED_IN_V5_15: &[&str] = &[
+    "epoll_pwait2",
+    "mount_setattr",
+    "quotactl_fd",
+    "landlock_create_ruleset",
+    "landlock_add_rule",
+    "landlock_restrict_self",
+    "process_mrelease",
+];
+
+/// Syscalls removed in Linux v5.15 (since v5.10).
+pub const REMOVED_IN_V5_15: &[&str] = &[];
+
+/// Syscalls added in Linux v6.1 (since v5.15).
+pub const ADDED_IN_V6_1: &[&str] = &["futex_waitv", "set_mempolicy_home_node"];
+
+/// Syscalls removed in Linux v6.1 (since v5.15).
+pub const REMOVED_IN_V6_1: &[&str] = &[];
+
+/// Syscalls added in Linux v6.6 (since v6.1).
+pub const ADDED_IN_V6_6: &[&str] = &["cachestat", "fchmodat2"];
+
+/// Syscalls removed in Linux v6.6 (since v6.1).
+pub const REMOVED_IN_V6_6: &[&str] = &[];
@@ -12,6 +12,7 @@ macro_rules! syscall_enum {
         }
 
         LAST: $last_syscall:ident;
+        $(SIGNATURES: $signatures:ident;)?
     ) => {
         /// Complete list of Linux syscalls.
         $(#[$outer])*
@@ -128,6 +129,41 @@ macro_rules! syscall_enum {
             pub fn iter() -> impl Iterator<Item = Self> {
                 core::iter::successors(Some(Self::first()), |x| x.next())
             }
+
+            /// Returns the names of the syscall's arguments, as given in its
+            /// `SYSCALL_DEFINEn` definition in the kernel (e.g. `["fd", "buf",
+            /// "count"]` for `read`).
+            ///
+            /// Returns `None` if the definition is not known, such as for
+            /// syscalls that are not implemented by the kernel, and for every
+            /// syscall of a table generated without argument metadata
+            /// (`syscalls-gen --signatures`).
+            #[allow(clippy::unused_self)]
+            pub fn arg_names(&self) -> Option<&'static [&'static str]> {
+                $(
+                    let index = (self.id() - Self::first().id()) as usize;
+                    if $signatures::ARG_COUNTS[index].is_some() {
+                        return Some($signatures::ARG_NAMES[index]);
+                    }
+                )?
+                None
+            }
+
+            /// Returns how each argument of the syscall is shown by
+            /// [`format::Call`](crate::format::Call), or `None` under the same
+            /// conditions as [`arg_names`](Self::arg_names).
+            #[allow(clippy::unused_self)]
+            pub fn arg_formats(
+                &self,
+            ) -> Option<&'static [crate::format::ArgFormat]> {
+                $(
+                    let index = (self.id() - Self::first().id()) as usize;
+                    if $signatures::ARG_COUNTS[index].is_some() {
+                        return Some($signatures::ARG_FORMATS[index]);
+                    }
+                )?
+                None
+            }
         }
 
         impl core::str::FromStr for $Name {
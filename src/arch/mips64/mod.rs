@@ -7,6 +7,7 @@ pub mod v6_1;
 pub mod v6_10;
 pub mod v6_12;
 pub mod v6_6;
+pub mod versions;
 
 // Select kernel version by feature; default to latest (v6.12).
 #[cfg(all(not(docsrs), feature = "default_kernel_5_4"))]
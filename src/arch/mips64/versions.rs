@@ -0,0 +1,136 @@
+//! Syscalls added and removed between kernel versions.
+
+// This file is automatically generated. Do not edit!
+
+/// Syscalls added in Linux v5.10 (since v5.4).
+pub const ADDED_IN_V5_10: &[&str] = &[
+    "close_range",
+    "openat2",
+    "pidfd_getfd",
+    "faccessat2",
+    "process_madvise",
+];
+
+/// [`ADDED_IN_V5_10`] as a set. Only the syscalls in the selected table are
+/// included.
+#[cfg(target_arch = "mips64")]
+pub const ADDED_IN_V5_10_SET: crate::SysnoSet =
+    crate::SysnoSet::from_names(ADDED_IN_V5_10);
+
+/// Syscalls removed in Linux v5.10 (since v5.4).
+pub const REMOVED_IN_V5_10: &[&str] = &[];
+
+/// [`REMOVED_IN_V5_10`] as a set. Only the syscalls in the selected table are
+/// included.
+#[cfg(target_arch = "mips64")]
+pub const REMOVED_IN_V5_10_SET: crate::SysnoSet =
+    crate::SysnoSet::from_names(REMOVED_IN_V5_10);
+
+/// Syscalls added in Linux v5.15 (since v5.10).
+pub const ADDED_IN_V5_15: &[&str] = &[
+    "epoll_pwait2",
+    "mount_setattr",
+    "quotactl_fd",
+    "landlock_create_ruleset",
+    "landlock_add_rule",
+    "landlock_restrict_self",
+    "process_mrelease",
+];
+
+/// [`ADDED_IN_V5_15`] as a set. Only the syscalls in the selected table are
+/// included.
+#[cfg(target_arch = "mips64")]
+pub const ADDED_IN_V5_15_SET: crate::SysnoSet =
+    crate::SysnoSet::from_names(ADDED_IN_V5_15);
+
+/// Syscalls removed in Linux v5.15 (since v5.10).
+pub const REMOVED_IN_V5_15: &[&str] = &[];
+
+/// [`REMOVED_IN_V5_15`] as a set. Only the syscalls in the selected table are
+/// included.
+#[cfg(target_arch = "mips64")]
+pub const REMOVED_IN_V5_15_SET: crate::SysnoSet =
+    crate::SysnoSet::from_names(REMOVED_IN_V5_15);
+
+/// Syscalls added in Linux v6.1 (since v5.15).
+pub const ADDED_IN_V6_1: &[&str] = &["futex_waitv"];
+
+/// [`ADDED_IN_V6_1`] as a set. Only the syscalls in the selected table are
+/// included.
+#[cfg(target_arch = "mips64")]
+pub const ADDED_IN_V6_1_SET: crate::SysnoSet =
+    crate::SysnoSet::from_names(ADDED_IN_V6_1);
+
+/// Syscalls removed in Linux v6.1 (since v5.15).
+pub const REMOVED_IN_V6_1: &[&str] = &[];
+
+/// [`REMOVED_IN_V6_1`] as a set. Only the syscalls in the selected table are
+/// included.
+#[cfg(target_arch = "mips64")]
+pub const REMOVED_IN_V6_1_SET: crate::SysnoSet =
+    crate::SysnoSet::from_names(REMOVED_IN_V6_1);
+
+/// Syscalls added in Linux v6.6 (since v6.1).
+pub const ADDED_IN_V6_6: &[&str] = &["cachestat", "fchmodat2"];
+
+/// [`ADDED_IN_V6_6`] as a set. Only the syscalls in the selected table are
+/// included.
+#[cfg(target_arch = "mips64")]
+pub const ADDED_IN_V6_6_SET: crate::SysnoSet =
+    crate::SysnoSet::from_names(ADDED_IN_V6_6);
+
+/// Syscalls removed in Linux v6.6 (since v6.1).
+pub const REMOVED_IN_V6_6: &[&str] = &[];
+
+/// [`REMOVED_IN_V6_6`] as a set. Only the syscalls in the selected table are
+/// included.
+#[cfg(target_arch = "mips64")]
+pub const REMOVED_IN_V6_6_SET: crate::SysnoSet =
+    crate::SysnoSet::from_names(REMOVED_IN_V6_6);
+
+/// Syscalls added in Linux v6.10 (since v6.6).
+pub const ADDED_IN_V6_10: &[&str] = &[
+    "map_shadow_stack",
+    "futex_wake",
+    "futex_wait",
+    "futex_requeue",
+    "statmount",
+    "listmount",
+    "lsm_get_self_attr",
+    "lsm_set_self_attr",
+    "lsm_list_modules",
+    "mseal",
+];
+
+/// [`ADDED_IN_V6_10`] as a set. Only the syscalls in the selected table are
+/// included.
+#[cfg(target_arch = "mips64")]
+pub const ADDED_IN_V6_10_SET: crate::SysnoSet =
+    crate::SysnoSet::from_names(ADDED_IN_V6_10);
+
+/// Syscalls removed in Linux v6.10 (since v6.6).
+pub const REMOVED_IN_V6_10: &[&str] = &[];
+
+/// [`REMOVED_IN_V6_10`] as a set. Only the syscalls in the selected table are
+/// included.
+#[cfg(target_arch = "mips64")]
+pub const REMOVED_IN_V6_10_SET: crate::SysnoSet =
+    crate::SysnoSet::from_names(REMOVED_IN_V6_10);
+
+/// Syscalls added in Linux v6.12 (since v6.10).
+pub const ADDED_IN_V6_12: &[&str] = &[];
+
+/// [`ADDED_IN_V6_12`] as a set. Only the syscalls in the selected table are
+/// included.
+#[cfg(target_arch = "mips64")]
+pub const ADDED_IN_V6_12_SET: crate::SysnoSet =
+    crate::SysnoSet::from_names(ADDED_IN_V6_12);
+
+/// Syscalls removed in Linux v6.12 (since v6.10).
+pub const REMOVED_IN_V6_12: &[&str] = &[];
+
+/// [`REMOVED_IN_V6_12`] as a set. Only the syscalls in the selected table are
+/// included.
+#[cfg(target_arch = "mips64")]
+pub const REMOVED_IN_V6_12_SET: crate::SysnoSet =
+    crate::SysnoSet::from_names(REMOVED_IN_V6_12);
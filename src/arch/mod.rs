@@ -5,6 +5,9 @@ mod macros;
 pub mod aarch64;
 #[cfg(any(target_arch = "arm", feature = "arm"))]
 pub mod arm;
+// ia64 has no Rust target; its tables are only available via the feature.
+#[cfg(feature = "ia64")]
+pub mod ia64;
 #[cfg(any(target_arch = "loongarch64", feature = "loongarch64"))]
 pub mod loongarch64;
 #[cfg(any(target_arch = "mips", feature = "mips"))]
@@ -0,0 +1,114 @@
+//! `AUDIT_ARCH_*` tokens from `linux/audit.h` (Linux v6.12).
+
+// This file is automatically generated. Do not edit!
+
+/// The token of `aarch64` syscalls.
+pub const AUDIT_ARCH_AARCH64: u32 = 0xc000_00b7;
+/// The token of little-endian `arm` syscalls.
+pub const AUDIT_ARCH_ARM: u32 = 0x4000_0028;
+/// The token of big-endian `arm` syscalls.
+pub const AUDIT_ARCH_ARMEB: u32 = 0x28;
+/// The token of `ia64` syscalls.
+pub const AUDIT_ARCH_IA64: u32 = 0xc000_0032;
+/// The token of `loongarch64` syscalls.
+pub const AUDIT_ARCH_LOONGARCH64: u32 = 0xc000_0102;
+/// The token of big-endian `mips` syscalls.
+pub const AUDIT_ARCH_MIPS: u32 = 0x8;
+/// The token of little-endian `mips` syscalls.
+pub const AUDIT_ARCH_MIPSEL: u32 = 0x4000_0008;
+/// The token of big-endian `mips64` syscalls.
+pub const AUDIT_ARCH_MIPS64: u32 = 0x8000_0008;
+/// The token of little-endian `mips64` syscalls.
+pub const AUDIT_ARCH_MIPSEL64: u32 = 0xc000_0008;
+/// The token of `powerpc` syscalls.
+pub const AUDIT_ARCH_PPC: u32 = 0x14;
+/// The token of big-endian `powerpc64` syscalls.
+pub const AUDIT_ARCH_PPC64: u32 = 0x8000_0015;
+/// The token of little-endian `powerpc64` syscalls.
+pub const AUDIT_ARCH_PPC64LE: u32 = 0xc000_0015;
+/// The token of `riscv32` syscalls.
+pub const AUDIT_ARCH_RISCV32: u32 = 0x4000_00f3;
+/// The token of `riscv64` syscalls.
+pub const AUDIT_ARCH_RISCV64: u32 = 0xc000_00f3;
+/// The token of `s390x` syscalls.
+pub const AUDIT_ARCH_S390X: u32 = 0x8000_0016;
+/// The token of `sparc` syscalls.
+pub const AUDIT_ARCH_SPARC: u32 = 0x2;
+/// The token of `sparc64` syscalls.
+pub const AUDIT_ARCH_SPARC64: u32 = 0x8000_002b;
+/// The token of `x86` syscalls.
+pub const AUDIT_ARCH_I386: u32 = 0x4000_0003;
+/// The token of `x86_64` syscalls.
+pub const AUDIT_ARCH_X86_64: u32 = 0xc000_003e;
+
+/// The token of the target's syscalls.
+#[cfg(target_arch = "aarch64")]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_AARCH64;
+
+/// The token of the target's syscalls.
+#[cfg(all(target_arch = "arm", target_endian = "little"))]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_ARM;
+
+/// The token of the target's syscalls.
+#[cfg(all(target_arch = "arm", target_endian = "big"))]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_ARMEB;
+
+/// The token of the target's syscalls.
+#[cfg(target_arch = "loongarch64")]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_LOONGARCH64;
+
+/// The token of the target's syscalls.
+#[cfg(all(target_arch = "mips", target_endian = "big"))]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_MIPS;
+
+/// The token of the target's syscalls.
+#[cfg(all(target_arch = "mips", target_endian = "little"))]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_MIPSEL;
+
+/// The token of the target's syscalls.
+#[cfg(all(target_arch = "mips64", target_endian = "big"))]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_MIPS64;
+
+/// The token of the target's syscalls.
+#[cfg(all(target_arch = "mips64", target_endian = "little"))]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_MIPSEL64;
+
+/// The token of the target's syscalls.
+#[cfg(target_arch = "powerpc")]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_PPC;
+
+/// The token of the target's syscalls.
+#[cfg(all(target_arch = "powerpc64", target_endian = "big"))]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_PPC64;
+
+/// The token of the target's syscalls.
+#[cfg(all(target_arch = "powerpc64", target_endian = "little"))]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_PPC64LE;
+
+/// The token of the target's syscalls.
+#[cfg(target_arch = "riscv32")]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_RISCV32;
+
+/// The token of the target's syscalls.
+#[cfg(target_arch = "riscv64")]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_RISCV64;
+
+/// The token of the target's syscalls.
+#[cfg(target_arch = "s390x")]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_S390X;
+
+/// The token of the target's syscalls.
+#[cfg(target_arch = "sparc")]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_SPARC;
+
+/// The token of the target's syscalls.
+#[cfg(target_arch = "sparc64")]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_SPARC64;
+
+/// The token of the target's syscalls.
+#[cfg(target_arch = "x86")]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_I386;
+
+/// The token of the target's syscalls.
+#[cfg(target_arch = "x86_64")]
+pub const AUDIT_ARCH: u32 = AUDIT_ARCH_X86_64;
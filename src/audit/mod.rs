@@ -0,0 +1,156 @@
+//! `AUDIT_ARCH_*` tokens
+//!
+//! The kernel tells seccomp filters (`seccomp_data.arch`), the audit
+//! subsystem, and tracers (`PTRACE_GET_SYSCALL_INFO`) which calling
+//! convention a syscall was made with through an `AUDIT_ARCH_*` token. A
+//! seccomp filter must check it before trusting the syscall number, since
+//! the same number means different syscalls for, say, `x86_64` and the i386
+//! syscalls an `x86_64` process can also make.
+//!
+//! The tokens are generated from the kernel's `linux/audit.h` for every
+//! architecture with a syscall table, and [`AUDIT_ARCH`] is the one of the
+//! target, matching the [`Sysno`](crate::Sysno) table:
+//!
+//! ```
+//! use rawsys_linux::audit::AUDIT_ARCH;
+//!
+//! # #[cfg(target_arch = "x86_64")]
+//! assert_eq!(AUDIT_ARCH, rawsys_linux::audit::AUDIT_ARCH_X86_64);
+//! ```
+//!
+//! [`Arch`] names the architecture of a token, e.g. of a syscall reported
+//! by the kernel.
+//!
+//! A syscall that a seccomp filter kills or traps is reported with its token,
+//! in a `SECCOMP` audit record ([`SeccompRecord`]) or in the `siginfo_t` of
+//! the `SIGSYS` ([`FilteredSyscall::from_siginfo`]), e.g. for a crash handler
+//! that tells which syscall was blocked:
+//!
+//! ```
+//! use rawsys_linux::audit::SeccompRecord;
+//!
+//! let line = "type=SECCOMP msg=audit(1700000000.123:45): pid=4321 \
+//!     comm=\"a.out\" sig=31 arch=c000003e syscall=59 ip=0x7f0a1b2c3d4e \
+//!     code=0x80000000";
+//! let record = SeccompRecord::parse(line).unwrap();
+//! assert_eq!(record.syscall.nr, 59);
+//! # #[cfg(target_arch = "x86_64")]
+//! assert_eq!(record.syscall.to_string(), "execve");
+//! ```
+
+mod generated;
+mod record;
+
+pub use self::generated::*;
+pub use self::record::{FilteredSyscall, SeccompRecord};
+
+/// An architecture with a syscall table, as named by its `AUDIT_ARCH_*`
+/// tokens. Both byte orders of an architecture map to the same variant,
+/// since they share the syscall table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Arch {
+    Aarch64,
+    Arm,
+    Ia64,
+    Loongarch64,
+    Mips,
+    Mips64,
+    Powerpc,
+    Powerpc64,
+    Riscv32,
+    Riscv64,
+    S390x,
+    Sparc,
+    Sparc64,
+    X86,
+    X86_64,
+}
+
+impl Arch {
+    /// The architecture of the target, i.e. of [`AUDIT_ARCH`].
+    pub const TARGET: Self = match Self::from_audit_arch(AUDIT_ARCH) {
+        Some(arch) => arch,
+        None => panic!("AUDIT_ARCH is not a known token"),
+    };
+
+    /// Looks up the architecture of an `AUDIT_ARCH_*` token, e.g. from
+    /// `seccomp_data.arch`.
+    pub const fn from_audit_arch(token: u32) -> Option<Self> {
+        Some(match token {
+            AUDIT_ARCH_AARCH64 => Self::Aarch64,
+            AUDIT_ARCH_ARM | AUDIT_ARCH_ARMEB => Self::Arm,
+            AUDIT_ARCH_IA64 => Self::Ia64,
+            AUDIT_ARCH_LOONGARCH64 => Self::Loongarch64,
+            AUDIT_ARCH_MIPS | AUDIT_ARCH_MIPSEL => Self::Mips,
+            AUDIT_ARCH_MIPS64 | AUDIT_ARCH_MIPSEL64 => Self::Mips64,
+            AUDIT_ARCH_PPC => Self::Powerpc,
+            AUDIT_ARCH_PPC64 | AUDIT_ARCH_PPC64LE => Self::Powerpc64,
+            AUDIT_ARCH_RISCV32 => Self::Riscv32,
+            AUDIT_ARCH_RISCV64 => Self::Riscv64,
+            AUDIT_ARCH_S390X => Self::S390x,
+            AUDIT_ARCH_SPARC => Self::Sparc,
+            AUDIT_ARCH_SPARC64 => Self::Sparc64,
+            AUDIT_ARCH_I386 => Self::X86,
+            AUDIT_ARCH_X86_64 => Self::X86_64,
+            _ => return None,
+        })
+    }
+
+    /// The name of the architecture, as in `src/arch/<name>`.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Aarch64 => "aarch64",
+            Self::Arm => "arm",
+            Self::Ia64 => "ia64",
+            Self::Loongarch64 => "loongarch64",
+            Self::Mips => "mips",
+            Self::Mips64 => "mips64",
+            Self::Powerpc => "powerpc",
+            Self::Powerpc64 => "powerpc64",
+            Self::Riscv32 => "riscv32",
+            Self::Riscv64 => "riscv64",
+            Self::S390x => "s390x",
+            Self::Sparc => "sparc",
+            Self::Sparc64 => "sparc64",
+            Self::X86 => "x86",
+            Self::X86_64 => "x86_64",
+        }
+    }
+}
+
+impl core::fmt::Display for Arch {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Bits of the token that encode the word size and the byte order.
+    const AUDIT_ARCH_64BIT: u32 = 0x8000_0000;
+    const AUDIT_ARCH_LE: u32 = 0x4000_0000;
+
+    #[test]
+    fn arch() {
+        assert_eq!(Arch::from_audit_arch(AUDIT_ARCH), Some(Arch::TARGET));
+        assert_eq!(Arch::from_audit_arch(AUDIT_ARCH_MIPSEL), Some(Arch::Mips));
+        assert_eq!(Arch::from_audit_arch(0), None);
+        #[cfg(target_arch = "x86_64")]
+        assert_eq!(Arch::TARGET.name(), "x86_64");
+    }
+
+    #[test]
+    fn matches_target() {
+        assert_eq!(
+            AUDIT_ARCH & AUDIT_ARCH_64BIT != 0,
+            cfg!(target_pointer_width = "64")
+        );
+        assert_eq!(
+            AUDIT_ARCH & AUDIT_ARCH_LE != 0,
+            cfg!(target_endian = "little")
+        );
+    }
+}
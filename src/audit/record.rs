@@ -0,0 +1,252 @@
+//! Syscalls reported by seccomp through the audit log and `SIGSYS`.
+
+use core::ffi::c_void;
+use core::fmt;
+
+use super::Arch;
+use crate::Sysno;
+use crate::seccomp::Action;
+use crate::siginfo::{SYS_SECCOMP, Siginfo, Sigsys};
+
+/// A syscall that a seccomp filter stopped, with the calling convention it
+/// was made with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FilteredSyscall {
+    /// The architecture of `audit_arch`, or `None` for an unknown token.
+    pub arch: Option<Arch>,
+    /// The `AUDIT_ARCH_*` token of the calling convention.
+    pub audit_arch: u32,
+    /// The syscall number, in the calling convention of `audit_arch`.
+    pub nr: i32,
+    /// The address of the instruction after the syscall instruction.
+    pub addr: u64,
+}
+
+impl FilteredSyscall {
+    /// Decodes the `siginfo_t` of a `SIGSYS` that seccomp raised for
+    /// [`Action::Trap`] (`si_syscall`, `si_arch`, and `si_call_addr`), or
+    /// returns `None` for another signal.
+    pub fn decode(info: &Siginfo) -> Option<Self> {
+        if info.code() != SYS_SECCOMP {
+            return None;
+        }
+        let Sigsys {
+            nr,
+            arch,
+            call_addr,
+        } = info.syscall()?;
+        Some(Self {
+            arch: Arch::from_audit_arch(arch),
+            audit_arch: arch,
+            nr,
+            addr: call_addr as u64,
+        })
+    }
+
+    /// Decodes the bytes of a `siginfo_t`, e.g. from `PTRACE_GETSIGINFO`,
+    /// see [`decode`](Self::decode).
+    pub fn from_siginfo_bytes(siginfo: &[u8; 128]) -> Option<Self> {
+        Self::decode(&Siginfo::from_bytes(siginfo))
+    }
+
+    /// Decodes the `siginfo_t` passed to an `SA_SIGINFO` `SIGSYS` handler,
+    /// see [`decode`](Self::decode).
+    ///
+    /// # Safety
+    ///
+    /// `info` must point to a `siginfo_t`.
+    pub unsafe fn from_siginfo(info: *const c_void) -> Option<Self> {
+        Self::decode(unsafe { &*info.cast::<Siginfo>() })
+    }
+
+    /// The syscall, if it was made with the target's calling convention and
+    /// is in its table.
+    pub fn sysno(&self) -> Option<Sysno> {
+        if self.arch != Some(Arch::TARGET) {
+            return None;
+        }
+        Sysno::new(usize::try_from(self.nr).ok()?)
+    }
+}
+
+impl fmt::Display for FilteredSyscall {
+    /// Shows the syscall by name if [`sysno`](Self::sysno) knows it, e.g.
+    /// `openat`, and by number otherwise, e.g. `syscall 5 (x86)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.sysno(), self.arch) {
+            (Some(sysno), _) => f.write_str(sysno.name()),
+            (None, Some(arch)) => write!(f, "syscall {} ({arch})", self.nr),
+            (None, None) => {
+                write!(f, "syscall {} (arch {:x})", self.nr, self.audit_arch)
+            }
+        }
+    }
+}
+
+/// A `SECCOMP` (1326) audit record, which the kernel logs for the
+/// [`Action::KillProcess`] and [`Action::KillThread`] actions, and for the
+/// others with `SECCOMP_FILTER_FLAG_LOG` or [`Action::Log`]:
+///
+/// ```text
+/// type=SECCOMP msg=audit(1700000000.123:45): auid=1000 uid=1000 gid=1000 ses=2 pid=4321 comm="a.out" exe="/tmp/a.out" sig=31 arch=c000003e syscall=59 compat=0 ip=0x7f0a1b2c3d4e code=0x80000000
+/// ```
+///
+/// `comm` and `exe` are as logged, so they are hex-encoded instead of quoted
+/// if they have spaces or other special characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SeccompRecord<'a> {
+    pub syscall: FilteredSyscall,
+    pub pid: Option<i32>,
+    pub comm: Option<&'a str>,
+    pub exe: Option<&'a str>,
+    /// The signal that killed the process, or 0.
+    pub sig: Option<i32>,
+    /// The return value of the filter (`SECCOMP_RET_*`).
+    pub code: Option<u32>,
+}
+
+impl<'a> SeccompRecord<'a> {
+    /// Parses a line of the audit log or the kernel log (`audit: type=1326
+    /// ...`). Returns `None` if it isn't a seccomp record or lacks `arch`,
+    /// `syscall`, or `ip`.
+    pub fn parse(line: &'a str) -> Option<Self> {
+        let mut audit_arch = None;
+        let mut nr = None;
+        let mut addr = None;
+        let mut record = Self {
+            syscall: FilteredSyscall {
+                arch: None,
+                audit_arch: 0,
+                nr: 0,
+                addr: 0,
+            },
+            pid: None,
+            comm: None,
+            exe: None,
+            sig: None,
+            code: None,
+        };
+
+        for field in line.split_ascii_whitespace() {
+            let Some((key, value)) = field.split_once('=') else {
+                continue;
+            };
+            match key {
+                "type" if value != "SECCOMP" && value != "1326" => {
+                    return None;
+                }
+                "arch" => audit_arch = u32::from_str_radix(value, 16).ok(),
+                "syscall" => nr = value.parse().ok(),
+                "ip" => addr = parse_hex(value),
+                "pid" => record.pid = value.parse().ok(),
+                "comm" => record.comm = Some(unquote(value)),
+                "exe" => record.exe = Some(unquote(value)),
+                "sig" => record.sig = value.parse().ok(),
+                "code" => {
+                    record.code = parse_hex(value).map(|code| code as u32);
+                }
+                _ => {}
+            }
+        }
+
+        let audit_arch = audit_arch?;
+        record.syscall = FilteredSyscall {
+            arch: Arch::from_audit_arch(audit_arch),
+            audit_arch,
+            nr: nr?,
+            addr: addr?,
+        };
+        Some(record)
+    }
+
+    /// The action of the filter, from `code`.
+    pub fn action(&self) -> Option<Action> {
+        Action::from_raw(self.code?)
+    }
+}
+
+fn parse_hex(s: &str) -> Option<u64> {
+    u64::from_str_radix(s.strip_prefix("0x")?, 16).ok()
+}
+
+fn unquote(s: &str) -> &str {
+    s.strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .unwrap_or(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::{AUDIT_ARCH, AUDIT_ARCH_I386, AUDIT_ARCH_X86_64};
+    use crate::format::Buffer;
+
+    #[test]
+    fn parse_record() {
+        let line = r#"type=SECCOMP msg=audit(1700000000.123:45): auid=1000 uid=1000 gid=1000 ses=2 subj=unconfined pid=4321 comm="a.out" exe="/tmp/a.out" sig=31 arch=c000003e syscall=59 compat=0 ip=0x7f0a1b2c3d4e code=0x80000000"#;
+        let record = SeccompRecord::parse(line).unwrap();
+        assert_eq!(
+            record.syscall,
+            FilteredSyscall {
+                arch: Some(Arch::X86_64),
+                audit_arch: AUDIT_ARCH_X86_64,
+                nr: 59,
+                addr: 0x7f0a_1b2c_3d4e,
+            }
+        );
+        assert_eq!(record.pid, Some(4321));
+        assert_eq!(record.comm, Some("a.out"));
+        assert_eq!(record.exe, Some("/tmp/a.out"));
+        assert_eq!(record.sig, Some(31));
+        assert_eq!(record.action(), Some(Action::KillProcess));
+        #[cfg(target_arch = "x86_64")]
+        assert_eq!(Buffer::<32>::format(record.syscall), "execve");
+
+        // The kernel log has the same fields.
+        let line = "audit: type=1326 audit(1700000000.123:45): pid=1 comm=2F62696E sig=0 arch=40000003 syscall=5 compat=1 ip=0xf7f1 code=0x7ffc0000";
+        let record = SeccompRecord::parse(line).unwrap();
+        assert_eq!(record.syscall.audit_arch, AUDIT_ARCH_I386);
+        assert_eq!(record.comm, Some("2F62696E"));
+        assert_eq!(record.action(), Some(Action::Log));
+        #[cfg(target_arch = "x86_64")]
+        assert_eq!(Buffer::<32>::format(record.syscall), "syscall 5 (x86)");
+
+        assert_eq!(
+            SeccompRecord::parse(
+                "type=SYSCALL arch=c000003e syscall=59 ip=0x1"
+            ),
+            None
+        );
+        assert_eq!(SeccompRecord::parse("type=SECCOMP arch=c000003e"), None);
+    }
+
+    #[test]
+    fn siginfo() {
+        let ptr_size = core::mem::size_of::<usize>();
+        let mut info = Siginfo {
+            signo: crate::siginfo::SIGSYS,
+            code: SYS_SECCOMP,
+            ..Siginfo::default()
+        };
+        info.fields[0] = 0x1234;
+        let nr = Sysno::close.id();
+        info.set_int(ptr_size, nr);
+        info.set_int(ptr_size + 4, AUDIT_ARCH.cast_signed());
+        let mut siginfo =
+            unsafe { core::mem::transmute::<Siginfo, [u8; 128]>(info) };
+
+        let syscall = FilteredSyscall::from_siginfo_bytes(&siginfo).unwrap();
+        assert_eq!(syscall.arch, Some(Arch::TARGET));
+        assert_eq!(syscall.nr, nr);
+        assert_eq!(syscall.addr, 0x1234);
+        assert_eq!(syscall.sysno(), Some(Sysno::close));
+        assert_eq!(Buffer::<32>::format(syscall), "close");
+
+        assert_eq!(FilteredSyscall::decode(&info), Some(syscall));
+
+        // A `SIGSYS` from Syscall User Dispatch.
+        info.code = crate::siginfo::SYS_USER_DISPATCH;
+        siginfo = unsafe { core::mem::transmute::<Siginfo, [u8; 128]>(info) };
+        assert_eq!(FilteredSyscall::from_siginfo_bytes(&siginfo), None);
+    }
+}
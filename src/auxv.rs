@@ -0,0 +1,191 @@
+//! Auxiliary vector (`getauxval` without libc)
+//!
+//! The kernel passes an auxiliary vector of `(key, value)` pairs to every new
+//! program, right after the environment on the initial stack. It carries the
+//! page size, the hardware capability bits (`AT_HWCAP`, `AT_HWCAP2`), the
+//! address of the vDSO (`AT_SYSINFO_EHDR`), and more.
+//!
+//! - [`getauxval`] looks up a single entry. It reads the vector recorded with
+//!   [`init`]/[`init_from_stack`] if there is one, and `/proc/self/auxv`
+//!   otherwise. Nothing is cached: without `init`, every lookup (including
+//!   the shortcuts below) opens and reads `/proc/self/auxv` again.
+//! - [`init_from_stack`] is meant for `no_std` programs with their own
+//!   `_start`: it records the vector from the initial stack pointer, which
+//!   also works when `/proc` is not mounted.
+//! - [`hwcap`], [`hwcap2`], [`page_size`], and [`sysinfo_ehdr`] are shortcuts
+//!   for the common entries.
+//!
+//! Example
+//! ```no_run
+//! use rawsys_linux::auxv::{self, AT_PAGESZ};
+//!
+//! let page_size = auxv::getauxval(AT_PAGESZ).unwrap_or(4096);
+//! let hwcap = auxv::hwcap();
+//! ```
+
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+/// End of the vector.
+pub const AT_NULL: usize = 0;
+/// Address of the program headers of the executable.
+pub const AT_PHDR: usize = 3;
+/// Size of a program header entry.
+pub const AT_PHENT: usize = 4;
+/// Number of program headers.
+pub const AT_PHNUM: usize = 5;
+/// System page size.
+pub const AT_PAGESZ: usize = 6;
+/// Base address of the program interpreter.
+pub const AT_BASE: usize = 7;
+/// Flags.
+pub const AT_FLAGS: usize = 8;
+/// Entry point of the executable.
+pub const AT_ENTRY: usize = 9;
+/// Real user ID.
+pub const AT_UID: usize = 11;
+/// Effective user ID.
+pub const AT_EUID: usize = 12;
+/// Real group ID.
+pub const AT_GID: usize = 13;
+/// Effective group ID.
+pub const AT_EGID: usize = 14;
+/// Address of a string identifying the platform.
+pub const AT_PLATFORM: usize = 15;
+/// Hardware capability bits.
+pub const AT_HWCAP: usize = 16;
+/// Frequency of `times()`.
+pub const AT_CLKTCK: usize = 17;
+/// Non-zero if the program runs with elevated privileges (setuid, ...).
+pub const AT_SECURE: usize = 23;
+/// Address of a string identifying the real platform.
+pub const AT_BASE_PLATFORM: usize = 24;
+/// Address of 16 random bytes.
+pub const AT_RANDOM: usize = 25;
+/// More hardware capability bits.
+pub const AT_HWCAP2: usize = 26;
+/// The third word of hardware capability bits, which powerpc fills in
+/// first (Linux 6.15+).
+pub const AT_HWCAP3: usize = 29;
+/// The fourth word of hardware capability bits, which was defined with
+/// [`AT_HWCAP3`], and which kernels leave out where the architecture
+/// doesn't use it.
+pub const AT_HWCAP4: usize = 30;
+/// Address of the file name of the executable.
+pub const AT_EXECFN: usize = 31;
+/// Entry point of the vDSO's system call trampoline (x86).
+pub const AT_SYSINFO: usize = 32;
+/// Address of the vDSO's ELF header.
+pub const AT_SYSINFO_EHDR: usize = 33;
+/// Minimal stack size for signal delivery.
+pub const AT_MINSIGSTKSZ: usize = 51;
+
+/// The vector recorded by [`init`], or null.
+static AUXV: AtomicPtr<usize> = AtomicPtr::new(core::ptr::null_mut());
+
+/// Records the auxiliary vector at `auxv` for later lookups.
+///
+/// # Safety
+///
+/// `auxv` must point to a vector of `(key, value)` pairs terminated by
+/// [`AT_NULL`] that stays valid (and unchanged) for the rest of the program.
+pub unsafe fn init(auxv: *const usize) {
+    AUXV.store(auxv.cast_mut(), Ordering::Release);
+}
+
+/// Records the auxiliary vector from the initial stack pointer, as seen by
+/// the program's entry point (`argc`, `argv`, `envp`, then the vector).
+///
+/// # Safety
+///
+/// `sp` must be the stack pointer the kernel started the process with, and
+/// that part of the stack must never be overwritten.
+pub unsafe fn init_from_stack(sp: *const usize) {
+    unsafe { init(from_stack(sp)) }
+}
+
+/// Finds the auxiliary vector on the initial stack.
+unsafe fn from_stack(sp: *const usize) -> *const usize {
+    unsafe {
+        let argc = *sp;
+        // Skip argc, the arguments, and their terminating null.
+        let mut envp = sp.add(argc + 2);
+        while *envp != 0 {
+            envp = envp.add(1);
+        }
+        envp.add(1)
+    }
+}
+
+/// Looks up `key` in the vector at `auxv`.
+unsafe fn find(mut auxv: *const usize, key: usize) -> Option<usize> {
+    unsafe {
+        loop {
+            match *auxv {
+                AT_NULL => return None,
+                k if k == key => return Some(*auxv.add(1)),
+                _ => auxv = auxv.add(2),
+            }
+        }
+    }
+}
+
+/// Returns the value of the auxiliary vector entry `key`, or `None` if it is
+/// not present (or the vector cannot be read).
+///
+/// If no vector was recorded with [`init`], each call reads
+/// `/proc/self/auxv`, which costs an `openat`, a few `read`s, and a `close`.
+/// Callers that look up entries often should call [`init`] first, or keep
+/// the values they need.
+pub fn getauxval(key: usize) -> Option<usize> {
+    let auxv = AUXV.load(Ordering::Acquire);
+    if auxv.is_null() {
+        crate::syscall::auxv::getauxval(key)
+    } else {
+        unsafe { find(auxv, key) }
+    }
+}
+
+/// Returns the `AT_HWCAP` bits, or 0 if unknown.
+pub fn hwcap() -> usize {
+    getauxval(AT_HWCAP).unwrap_or(0)
+}
+
+/// Returns the `AT_HWCAP2` bits, or 0 if unknown.
+pub fn hwcap2() -> usize {
+    getauxval(AT_HWCAP2).unwrap_or(0)
+}
+
+/// Returns the system page size.
+pub fn page_size() -> Option<usize> {
+    getauxval(AT_PAGESZ)
+}
+
+/// Returns the address of the vDSO's ELF header, if the kernel mapped one.
+pub fn sysinfo_ehdr() -> Option<usize> {
+    getauxval(AT_SYSINFO_EHDR)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_initial_stack() {
+        // argc, argv[0..2], NULL, envp[0], NULL, auxv
+        let stack = [
+            2, 0x1000, 0x1001, 0, 0x2000, 0, AT_PAGESZ, 4096, AT_HWCAP, 0xff,
+            AT_NULL, 0,
+        ];
+        let auxv = unsafe { from_stack(stack.as_ptr()) };
+        assert_eq!(unsafe { find(auxv, AT_PAGESZ) }, Some(4096));
+        assert_eq!(unsafe { find(auxv, AT_HWCAP) }, Some(0xff));
+        assert_eq!(unsafe { find(auxv, AT_HWCAP2) }, None);
+    }
+
+    #[test]
+    fn proc_fallback() {
+        let page_size = page_size().unwrap();
+        assert!(page_size.is_power_of_two());
+        assert!(sysinfo_ehdr().is_some());
+    }
+}
@@ -0,0 +1,201 @@
+//! UAPI constants for the `mips64` architecture (Linux v6.12).
+
+// This file is automatically generated. Do not edit!
+
+pub const O_ACCMODE: u32 = 3;
+pub const O_APPEND: u32 = 8;
+pub const O_CLOEXEC: u32 = 0x80000;
+pub const O_CREAT: u32 = 0x100;
+pub const O_DIRECT: u32 = 0x8000;
+pub const O_DIRECTORY: u32 = 0x10000;
+pub const O_DSYNC: u32 = 0x10;
+pub const O_EXCL: u32 = 0x400;
+pub const O_LARGEFILE: u32 = 0x2000;
+pub const O_NDELAY: u32 = 0x80;
+pub const O_NOATIME: u32 = 0x40000;
+pub const O_NOCTTY: u32 = 0x800;
+pub const O_NOFOLLOW: u32 = 0x20000;
+pub const O_NONBLOCK: u32 = 0x80;
+pub const O_PATH: u32 = 0x200000;
+pub const O_RDONLY: u32 = 0;
+pub const O_RDWR: u32 = 2;
+pub const O_SYNC: u32 = 0x4010;
+pub const O_TMPFILE: u32 = 0x410000;
+pub const O_TRUNC: u32 = 0x200;
+pub const O_WRONLY: u32 = 1;
+
+pub const AT_EACCESS: i32 = 0x200;
+pub const AT_EMPTY_PATH: i32 = 0x1000;
+pub const AT_FDCWD: i32 = -100;
+pub const AT_HANDLE_FID: i32 = 0x200;
+pub const AT_HANDLE_MNT_ID_UNIQUE: i32 = 1;
+pub const AT_NO_AUTOMOUNT: i32 = 0x800;
+pub const AT_RECURSIVE: i32 = 0x8000;
+pub const AT_REMOVEDIR: i32 = 0x200;
+pub const AT_RENAME_EXCHANGE: i32 = 2;
+pub const AT_RENAME_NOREPLACE: i32 = 1;
+pub const AT_RENAME_WHITEOUT: i32 = 4;
+pub const AT_STATX_DONT_SYNC: i32 = 0x4000;
+pub const AT_STATX_FORCE_SYNC: i32 = 0x2000;
+pub const AT_STATX_SYNC_AS_STAT: i32 = 0;
+pub const AT_STATX_SYNC_TYPE: i32 = 0x6000;
+pub const AT_SYMLINK_FOLLOW: i32 = 0x400;
+pub const AT_SYMLINK_NOFOLLOW: i32 = 0x100;
+
+pub const CLONE_ARGS_SIZE_VER0: u64 = 0x40;
+pub const CLONE_ARGS_SIZE_VER1: u64 = 0x50;
+pub const CLONE_ARGS_SIZE_VER2: u64 = 0x58;
+pub const CLONE_CHILD_CLEARTID: u64 = 0x200000;
+pub const CLONE_CHILD_SETTID: u64 = 0x1000000;
+pub const CLONE_CLEAR_SIGHAND: u64 = 0x100000000;
+pub const CLONE_DETACHED: u64 = 0x400000;
+pub const CLONE_FILES: u64 = 0x400;
+pub const CLONE_FS: u64 = 0x200;
+pub const CLONE_INTO_CGROUP: u64 = 0x200000000;
+pub const CLONE_IO: u64 = 0x80000000;
+pub const CLONE_NEWCGROUP: u64 = 0x2000000;
+pub const CLONE_NEWIPC: u64 = 0x8000000;
+pub const CLONE_NEWNET: u64 = 0x40000000;
+pub const CLONE_NEWNS: u64 = 0x20000;
+pub const CLONE_NEWPID: u64 = 0x20000000;
+pub const CLONE_NEWTIME: u64 = 0x80;
+pub const CLONE_NEWUSER: u64 = 0x10000000;
+pub const CLONE_NEWUTS: u64 = 0x4000000;
+pub const CLONE_PARENT: u64 = 0x8000;
+pub const CLONE_PARENT_SETTID: u64 = 0x100000;
+pub const CLONE_PIDFD: u64 = 0x1000;
+pub const CLONE_PTRACE: u64 = 0x2000;
+pub const CLONE_SETTLS: u64 = 0x80000;
+pub const CLONE_SIGHAND: u64 = 0x800;
+pub const CLONE_SYSVSEM: u64 = 0x40000;
+pub const CLONE_THREAD: u64 = 0x10000;
+pub const CLONE_UNTRACED: u64 = 0x800000;
+pub const CLONE_VFORK: u64 = 0x4000;
+pub const CLONE_VM: u64 = 0x100;
+
+pub const PROT_EXEC: u32 = 4;
+pub const PROT_GROWSDOWN: u32 = 0x1000000;
+pub const PROT_GROWSUP: u32 = 0x2000000;
+pub const PROT_NONE: u32 = 0;
+pub const PROT_READ: u32 = 1;
+pub const PROT_SEM: u32 = 0x10;
+pub const PROT_WRITE: u32 = 2;
+
+pub const MAP_ANONYMOUS: u32 = 0x800;
+pub const MAP_AUTOGROW: u32 = 0x40;
+pub const MAP_AUTORSRV: u32 = 0x100;
+pub const MAP_DENYWRITE: u32 = 0x2000;
+pub const MAP_DROPPABLE: u32 = 8;
+pub const MAP_EXECUTABLE: u32 = 0x4000;
+pub const MAP_FILE: u32 = 0;
+pub const MAP_FIXED: u32 = 0x10;
+pub const MAP_FIXED_NOREPLACE: u32 = 0x100000;
+pub const MAP_GROWSDOWN: u32 = 0x1000;
+pub const MAP_HUGETLB: u32 = 0x80000;
+pub const MAP_HUGE_16GB: u32 = 0x88000000;
+pub const MAP_HUGE_16KB: u32 = 0x38000000;
+pub const MAP_HUGE_16MB: u32 = 0x60000000;
+pub const MAP_HUGE_1GB: u32 = 0x78000000;
+pub const MAP_HUGE_1MB: u32 = 0x50000000;
+pub const MAP_HUGE_256MB: u32 = 0x70000000;
+pub const MAP_HUGE_2GB: u32 = 0x7c000000;
+pub const MAP_HUGE_2MB: u32 = 0x54000000;
+pub const MAP_HUGE_32MB: u32 = 0x64000000;
+pub const MAP_HUGE_512KB: u32 = 0x4c000000;
+pub const MAP_HUGE_512MB: u32 = 0x74000000;
+pub const MAP_HUGE_64KB: u32 = 0x40000000;
+pub const MAP_HUGE_8MB: u32 = 0x5c000000;
+pub const MAP_HUGE_MASK: u32 = 0x3f;
+pub const MAP_HUGE_SHIFT: u32 = 0x1a;
+pub const MAP_LOCAL: u32 = 0x80;
+pub const MAP_LOCKED: u32 = 0x8000;
+pub const MAP_NONBLOCK: u32 = 0x20000;
+pub const MAP_NORESERVE: u32 = 0x400;
+pub const MAP_POPULATE: u32 = 0x10000;
+pub const MAP_PRIVATE: u32 = 2;
+pub const MAP_RENAME: u32 = 0x20;
+pub const MAP_SHARED: u32 = 1;
+pub const MAP_SHARED_VALIDATE: u32 = 3;
+pub const MAP_STACK: u32 = 0x40000;
+pub const MAP_TYPE: u32 = 15;
+
+pub const MS_ACTIVE: u32 = 0x40000000;
+pub const MS_ASYNC: u32 = 1;
+pub const MS_BIND: u32 = 0x1000;
+pub const MS_BORN: u32 = 0x20000000;
+pub const MS_DIRSYNC: u32 = 0x80;
+pub const MS_INVALIDATE: u32 = 2;
+pub const MS_I_VERSION: u32 = 0x800000;
+pub const MS_KERNMOUNT: u32 = 0x400000;
+pub const MS_LAZYTIME: u32 = 0x2000000;
+pub const MS_MANDLOCK: u32 = 0x40;
+pub const MS_MGC_MSK: u32 = 0xffff0000;
+pub const MS_MGC_VAL: u32 = 0xc0ed0000;
+pub const MS_MOVE: u32 = 0x2000;
+pub const MS_NOATIME: u32 = 0x400;
+pub const MS_NODEV: u32 = 4;
+pub const MS_NODIRATIME: u32 = 0x800;
+pub const MS_NOEXEC: u32 = 8;
+pub const MS_NOREMOTELOCK: u32 = 0x8000000;
+pub const MS_NOSEC: u32 = 0x10000000;
+pub const MS_NOSUID: u32 = 2;
+pub const MS_NOSYMFOLLOW: u32 = 0x100;
+pub const MS_NOUSER: u32 = 0x80000000;
+pub const MS_POSIXACL: u32 = 0x10000;
+pub const MS_PRIVATE: u32 = 0x40000;
+pub const MS_RDONLY: u32 = 1;
+pub const MS_REC: u32 = 0x4000;
+pub const MS_RELATIME: u32 = 0x200000;
+pub const MS_REMOUNT: u32 = 0x20;
+pub const MS_RMT_MASK: u32 = 0x2800051;
+pub const MS_SHARED: u32 = 0x100000;
+pub const MS_SILENT: u32 = 0x8000;
+pub const MS_SLAVE: u32 = 0x80000;
+pub const MS_STRICTATIME: u32 = 0x1000000;
+pub const MS_SUBMOUNT: u32 = 0x4000000;
+pub const MS_SYNC: u32 = 4;
+pub const MS_SYNCHRONOUS: u32 = 0x10;
+pub const MS_UNBINDABLE: u32 = 0x20000;
+pub const MS_VERBOSE: u32 = 0x8000;
+
+pub const OPEN_TREE_CLOEXEC: u32 = 0x80000;
+pub const OPEN_TREE_CLONE: u32 = 1;
+
+pub const MOVE_MOUNT_BENEATH: u32 = 0x200;
+pub const MOVE_MOUNT_F_AUTOMOUNTS: u32 = 2;
+pub const MOVE_MOUNT_F_EMPTY_PATH: u32 = 4;
+pub const MOVE_MOUNT_F_SYMLINKS: u32 = 1;
+pub const MOVE_MOUNT_SET_GROUP: u32 = 0x100;
+pub const MOVE_MOUNT_T_AUTOMOUNTS: u32 = 0x20;
+pub const MOVE_MOUNT_T_EMPTY_PATH: u32 = 0x40;
+pub const MOVE_MOUNT_T_SYMLINKS: u32 = 0x10;
+pub const MOVE_MOUNT__MASK: u32 = 0x377;
+
+pub const FSOPEN_CLOEXEC: u32 = 1;
+
+pub const FSPICK_CLOEXEC: u32 = 1;
+pub const FSPICK_EMPTY_PATH: u32 = 8;
+pub const FSPICK_NO_AUTOMOUNT: u32 = 4;
+pub const FSPICK_SYMLINK_NOFOLLOW: u32 = 2;
+
+pub const FSMOUNT_CLOEXEC: u32 = 1;
+
+pub const MOUNT_ATTR_IDMAP: u64 = 0x100000;
+pub const MOUNT_ATTR_NOATIME: u64 = 0x10;
+pub const MOUNT_ATTR_NODEV: u64 = 4;
+pub const MOUNT_ATTR_NODIRATIME: u64 = 0x80;
+pub const MOUNT_ATTR_NOEXEC: u64 = 8;
+pub const MOUNT_ATTR_NOSUID: u64 = 2;
+pub const MOUNT_ATTR_NOSYMFOLLOW: u64 = 0x200000;
+pub const MOUNT_ATTR_RDONLY: u64 = 1;
+pub const MOUNT_ATTR_RELATIME: u64 = 0;
+pub const MOUNT_ATTR_SIZE_VER0: u64 = 0x20;
+pub const MOUNT_ATTR_STRICTATIME: u64 = 0x20;
+pub const MOUNT_ATTR__ATIME: u64 = 0x70;
+
+pub const RESOLVE_BENEATH: u64 = 8;
+pub const RESOLVE_CACHED: u64 = 0x20;
+pub const RESOLVE_IN_ROOT: u64 = 0x10;
+pub const RESOLVE_NO_MAGICLINKS: u64 = 2;
+pub const RESOLVE_NO_SYMLINKS: u64 = 4;
+pub const RESOLVE_NO_XDEV: u64 = 1;
@@ -0,0 +1,112 @@
+//! Flag constants from the kernel's uapi headers
+//!
+//! The `O_*`, `AT_*`, `CLONE_*`, `PROT_*`, `MAP_*`, `MS_*`, `RESOLVE_*`, and
+//! mount API (`FSOPEN_*`, `FSPICK_*`, `FSMOUNT_*`, `MOVE_MOUNT_*`,
+//! `OPEN_TREE_*`, `MOUNT_ATTR_*`) constants that libc would normally provide,
+//! generated from the Linux v6.12 headers. Some of them differ by
+//! architecture (e.g. `O_DIRECTORY` on arm/aarch64, or `MAP_ANONYMOUS` on
+//! mips, powerpc, and sparc), so there is one module per architecture, and
+//! the one for the target is re-exported here. The others are available with
+//! the architecture's feature, like the syscall tables.
+//!
+//! The types follow the kernel's: `AT_*` is `i32` so that `AT_FDCWD` is
+//! negative, `CLONE_*` is `u64` because `clone3` takes flags above bit 31,
+//! `RESOLVE_*` and `MOUNT_ATTR_*` are `u64` like the fields of `struct
+//! open_how` and `struct mount_attr`, and the rest are `u32`. Pass them to
+//! [`syscall!`](crate::syscall!) as they are; it casts every argument to
+//! [`SyscallWord`](crate::SyscallWord).
+//!
+//! Example
+//! ```no_run
+//! use rawsys_linux::consts::{AT_FDCWD, O_CLOEXEC, O_RDONLY};
+//! use rawsys_linux::{Sysno, syscall};
+//!
+//! let fd = unsafe {
+//!     syscall!(Sysno::openat, AT_FDCWD, c"/etc/hostname".as_ptr(), O_RDONLY | O_CLOEXEC)
+//! };
+//! ```
+
+#[cfg(any(target_arch = "aarch64", feature = "aarch64"))]
+#[allow(clippy::all, clippy::pedantic)]
+pub mod aarch64;
+#[cfg(any(target_arch = "arm", feature = "arm"))]
+#[allow(clippy::all, clippy::pedantic)]
+pub mod arm;
+#[cfg(any(target_arch = "loongarch64", feature = "loongarch64"))]
+#[allow(clippy::all, clippy::pedantic)]
+pub mod loongarch64;
+#[cfg(any(target_arch = "mips", feature = "mips"))]
+#[allow(clippy::all, clippy::pedantic)]
+pub mod mips;
+#[cfg(any(target_arch = "mips64", feature = "mips64"))]
+#[allow(clippy::all, clippy::pedantic)]
+pub mod mips64;
+#[cfg(any(target_arch = "powerpc", feature = "powerpc"))]
+#[allow(clippy::all, clippy::pedantic)]
+pub mod powerpc;
+#[cfg(any(target_arch = "powerpc64", feature = "powerpc64"))]
+#[allow(clippy::all, clippy::pedantic)]
+pub mod powerpc64;
+#[cfg(any(target_arch = "riscv32", feature = "riscv32"))]
+#[allow(clippy::all, clippy::pedantic)]
+pub mod riscv32;
+#[cfg(any(target_arch = "riscv64", feature = "riscv64"))]
+#[allow(clippy::all, clippy::pedantic)]
+pub mod riscv64;
+#[cfg(any(target_arch = "s390x", feature = "s390x"))]
+#[allow(clippy::all, clippy::pedantic)]
+pub mod s390x;
+#[cfg(any(target_arch = "sparc", feature = "sparc"))]
+#[allow(clippy::all, clippy::pedantic)]
+pub mod sparc;
+#[cfg(any(target_arch = "sparc64", feature = "sparc64"))]
+#[allow(clippy::all, clippy::pedantic)]
+pub mod sparc64;
+#[cfg(any(target_arch = "x86", feature = "x86"))]
+#[allow(clippy::all, clippy::pedantic)]
+pub mod x86;
+#[cfg(any(target_arch = "x86_64", feature = "x86_64"))]
+#[allow(clippy::all, clippy::pedantic)]
+pub mod x86_64;
+
+#[cfg(target_arch = "aarch64")]
+pub use aarch64::*;
+
+#[cfg(target_arch = "arm")]
+pub use arm::*;
+
+#[cfg(target_arch = "loongarch64")]
+pub use loongarch64::*;
+
+#[cfg(target_arch = "mips")]
+pub use mips::*;
+
+#[cfg(target_arch = "mips64")]
+pub use mips64::*;
+
+#[cfg(target_arch = "powerpc")]
+pub use powerpc::*;
+
+#[cfg(target_arch = "powerpc64")]
+pub use powerpc64::*;
+
+#[cfg(target_arch = "riscv32")]
+pub use riscv32::*;
+
+#[cfg(target_arch = "riscv64")]
+pub use riscv64::*;
+
+#[cfg(target_arch = "s390x")]
+pub use s390x::*;
+
+#[cfg(target_arch = "sparc")]
+pub use sparc::*;
+
+#[cfg(target_arch = "sparc64")]
+pub use sparc64::*;
+
+#[cfg(target_arch = "x86")]
+pub use x86::*;
+
+#[cfg(target_arch = "x86_64")]
+pub use x86_64::*;
@@ -20,11 +20,24 @@
 #[macro_use]
 mod macros;
 
+// `generated.rs` has the `asm-generic` numbers used by most architectures.
+// MIPS and SPARC number most error codes differently.
 #[allow(clippy::all, clippy::pedantic)]
+#[cfg_attr(
+    any(target_arch = "mips", target_arch = "mips64"),
+    path = "arch/mips.rs"
+)]
+#[cfg_attr(
+    any(target_arch = "sparc", target_arch = "sparc64"),
+    path = "arch/sparc.rs"
+)]
 mod generated;
 
-#[cfg(feature = "std")]
-mod last;
+#[cfg(all(
+    any(feature = "std", feature = "libc-backend"),
+    not(rawsys_backend = "none")
+))]
+pub(crate) mod last;
 
 use core::fmt;
 
@@ -34,7 +47,14 @@ impl Errno {
     /// Operation would block. This is the same as [`Errno::EAGAIN`].
     pub const EWOULDBLOCK: Self = Self::EAGAIN;
 
-    /// Same as [`Errno::EDEADLK`].
+    /// Same as [`Errno::EDEADLK`]. (On MIPS and SPARC, `EDEADLOCK` has a
+    /// number of its own.)
+    #[cfg(not(any(
+        target_arch = "mips",
+        target_arch = "mips64",
+        target_arch = "sparc",
+        target_arch = "sparc64"
+    )))]
     pub const EDEADLOCK: Self = Self::EDEADLK;
 
     /// Creates a new `Errno`.
@@ -95,13 +115,13 @@ impl Errno {
         }
     }
     /// Returns the last error that occurred.
-    #[cfg(feature = "std")]
+    #[cfg(all(feature = "std", not(rawsys_backend = "none")))]
     pub fn last() -> Self {
         Self(unsafe { *last::errno() })
     }
 
     /// Converts a value into an `Errno`.
-    #[cfg(feature = "std")]
+    #[cfg(all(feature = "std", not(rawsys_backend = "none")))]
     pub fn result<T>(value: T) -> Result<T, Errno>
     where
         T: ErrnoSentinel + PartialEq<T>,
@@ -261,7 +281,7 @@ mod test {
         );
     }
 
-    #[cfg(feature = "std")]
+    #[cfg(all(feature = "std", not(rawsys_backend = "none")))]
     #[test]
     fn last_errno() {
         assert_eq!(
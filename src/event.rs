@@ -0,0 +1,160 @@
+//! Syscall events for logs
+//!
+//! A [`SyscallEvent`] records one syscall with who made it, what it returned,
+//! and how long it took, in a form that stays the same across releases and
+//! serializes well (with the `serde` feature), e.g. as JSON lines for a log
+//! pipeline. Events come from a [`Tracer`](crate::tracer::Tracer), or are
+//! made from what ptrace ([`SyscallEvent::from_syscall_info`]) and seccomp
+//! user notifications ([`SyscallEvent::from_notif`]) report.
+
+use core::time::Duration;
+
+use crate::audit::Arch;
+use crate::id::{Pid, Tid};
+use crate::ptrace::{SyscallInfo, SyscallStop};
+use crate::seccomp::SeccompNotif;
+use crate::{Errno, SyscallArgs, SyscallWord, Sysno};
+
+/// A syscall made by a thread.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SyscallEvent {
+    /// The process (thread group) of the thread.
+    pub pid: Pid,
+    /// The thread that made the syscall.
+    pub tid: Tid,
+    /// The architecture of the syscall's calling convention, if known.
+    pub arch: Option<Arch>,
+    pub sysno: Sysno,
+    pub args: SyscallArgs,
+    /// The result, or `None` if the syscall hasn't returned (yet), e.g.
+    /// `exit` or when the thread was killed.
+    pub result: Option<Result<SyscallWord, Errno>>,
+    /// How long the syscall took, if it is known.
+    pub duration: Option<Duration>,
+}
+
+impl SyscallEvent {
+    /// Makes the event of a syscall that hasn't returned yet.
+    pub fn new(pid: Pid, tid: Tid, sysno: Sysno, args: SyscallArgs) -> Self {
+        Self {
+            pid,
+            tid,
+            arch: Some(Arch::TARGET),
+            sysno,
+            args,
+            result: None,
+            duration: None,
+        }
+    }
+
+    /// Makes the event of the syscall at a ptrace entry or seccomp stop of
+    /// the thread `tid` of `pid`. Returns `None` for other stops and for
+    /// syscalls that [`SyscallStop`] has no [`Sysno`] for.
+    pub fn from_syscall_info(
+        pid: Pid,
+        tid: Tid,
+        info: &SyscallInfo,
+    ) -> Option<Self> {
+        let (SyscallStop::Entry {
+            sysno: Some(sysno),
+            args,
+            ..
+        }
+        | SyscallStop::Seccomp {
+            sysno: Some(sysno),
+            args,
+            ..
+        }) = info.stop
+        else {
+            return None;
+        };
+        Some(Self {
+            arch: info.arch,
+            ..Self::new(pid, tid, sysno, args)
+        })
+    }
+
+    /// Makes the event of the syscall of a seccomp notification. The
+    /// notification only has the thread id, so the process is `pid` (see
+    /// [`procfs::tgid`](crate::procfs::tgid)). Returns `None` for syscalls
+    /// that [`SeccompNotif::sysno`] doesn't know.
+    pub fn from_notif(pid: Pid, notif: &SeccompNotif) -> Option<Self> {
+        #[allow(clippy::cast_possible_wrap)]
+        let tid = Tid::from_raw(notif.pid as i32);
+        Some(Self {
+            arch: Arch::from_audit_arch(notif.data.arch),
+            ..Self::new(pid, tid, notif.sysno()?, notif.args())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ptrace::{
+        PTRACE_SYSCALL_INFO_ENTRY, PtraceSyscallInfo, PtraceSyscallInfoEntry,
+    };
+    use crate::seccomp::SeccompData;
+
+    #[test]
+    fn conversions() {
+        let expected = SyscallEvent {
+            pid: Pid::from_raw(10),
+            tid: Tid::from_raw(11),
+            arch: Some(Arch::TARGET),
+            sysno: Sysno::close,
+            args: SyscallArgs::new(3, 0, 0, 0, 0, 0),
+            result: None,
+            duration: None,
+        };
+
+        let mut info = PtraceSyscallInfo {
+            op: PTRACE_SYSCALL_INFO_ENTRY,
+            arch: crate::audit::AUDIT_ARCH,
+            ..Default::default()
+        };
+        info.data.entry = PtraceSyscallInfoEntry {
+            nr: Sysno::close as u64,
+            args: [3, 0, 0, 0, 0, 0],
+        };
+        let info = SyscallInfo::from(&info);
+        assert_eq!(
+            SyscallEvent::from_syscall_info(expected.pid, expected.tid, &info),
+            Some(expected)
+        );
+
+        let notif = SeccompNotif {
+            pid: 11,
+            data: SeccompData {
+                nr: Sysno::close.id(),
+                arch: crate::audit::AUDIT_ARCH,
+                args: [3, 0, 0, 0, 0, 0],
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert_eq!(
+            SyscallEvent::from_notif(expected.pid, &notif),
+            Some(expected)
+        );
+    }
+
+    #[cfg(all(feature = "serde", feature = "std"))]
+    #[test]
+    fn serde_roundtrip() {
+        let event = SyscallEvent {
+            pid: Pid::from_raw(10),
+            tid: Tid::from_raw(11),
+            arch: Some(Arch::TARGET),
+            sysno: Sysno::openat,
+            args: SyscallArgs::new(1, 2, 3, 0, 0, 0),
+            result: Some(Err(Errno::ENOENT)),
+            duration: Some(Duration::from_micros(15)),
+        };
+        let json = serde_json::to_string(&event).unwrap();
+        assert!(!json.contains('\n'));
+        assert!(json.starts_with(r#"{"pid":10,"tid":11,"#));
+        assert_eq!(serde_json::from_str::<SyscallEvent>(&json).unwrap(), event);
+    }
+}
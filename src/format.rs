@@ -0,0 +1,634 @@
+//! strace-style formatting of syscall arguments
+//!
+//! Tables generated with argument metadata (`syscalls-gen --signatures`)
+//! describe how each argument of a syscall should be shown: as a file
+//! descriptor, a set of flags with their names, a pointer to a string or a
+//! struct, and so on. [`Sysno::arg_formats`](crate::Sysno::arg_formats)
+//! returns these descriptors, and [`Call`] uses them to print a call the way
+//! strace would:
+//!
+//! ```
+//! use rawsys_linux::format::Call;
+//! use rawsys_linux::{Sysno, SyscallArgs};
+//!
+//! let args = SyscallArgs::from(&[-100isize as _, 0x1000, 0x80000]);
+//! // `openat(AT_FDCWD, 0x1000, O_RDONLY|O_CLOEXEC, 0000)` with argument
+//! // metadata, `openat(0xff...9c, 0x1000, 0x80000, 0, 0, 0)` without.
+//! println!("{}", Call::new(Sysno::openat, &args));
+//! ```
+//!
+//! Pointers are shown as addresses, except for strings when the call has a
+//! [`RemoteMem`] to read them from (the memory of a traced process, for
+//! example), and results are shown after an `=`:
+//!
+//! ```no_run
+//! # use rawsys_linux::format::Call;
+//! # use rawsys_linux::id::Pid;
+//! # use rawsys_linux::remote::ProcessVm;
+//! # use rawsys_linux::{Errno, Sysno, SyscallArgs};
+//! # let (pid, args) = (Pid::from_raw(0), SyscallArgs::from(&[0]));
+//! // `openat(AT_FDCWD, "/etc/passwd", O_RDONLY, 0000) = -1 ENOENT (No such
+//! // file or directory)`
+//! let mem = ProcessVm::new(pid);
+//! let call = Call::new(Sysno::openat, &args)
+//!     .mem(&mem)
+//!     .result(Err(Errno::ENOENT));
+//! println!("{call}");
+//! ```
+//!
+//! None of this allocates, so it also works without `std` and where the heap
+//! can't be used, such as in a `SIGSYS` handler: [`format_syscall`] writes
+//! to any [`fmt::Write`], such as a fixed-size [`Buffer`]:
+//!
+//! ```no_run
+//! use rawsys_linux::format::{Buffer, format_syscall};
+//! use rawsys_linux::{Sysno, SyscallArgs};
+//!
+//! # let (sysno, args) = (Sysno::getpid, SyscallArgs::from(&[0]));
+//! let mut line = Buffer::<256>::new();
+//! let _ = format_syscall(&mut line, sysno, &args, None);
+//! let _ = unsafe {
+//!     rawsys_linux::syscall3(
+//!         Sysno::write,
+//!         2,
+//!         line.as_bytes().as_ptr() as _,
+//!         line.len() as _,
+//!     )
+//! };
+//! ```
+
+use crate::remote::RemoteMem;
+use crate::{Errno, SyscallArgs, SyscallWord, Sysno};
+use core::fmt;
+
+/// `AT_FDCWD`, which is the same on every architecture.
+const AT_FDCWD: isize = -100;
+
+/// `O_ACCMODE`, which is the same on every architecture, as are the access
+/// modes it masks.
+const O_ACCMODE: u64 = 3;
+
+/// Names of the values of `flags & O_ACCMODE`.
+const ACCESS_MODES: [&str; 4] = ["O_RDONLY", "O_WRONLY", "O_RDWR", "O_ACCMODE"];
+
+/// How a syscall argument is shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArgFormat {
+    /// A signed integer.
+    Int,
+    /// An unsigned integer.
+    Uint,
+    /// An address, or an opaque value, in hex.
+    Hex,
+    /// A file descriptor. `AT_FDCWD` is shown by name.
+    Fd,
+    /// A pointer to a NUL-terminated string, such as a path. Like the other
+    /// pointers, it is shown as `NULL` if it is null.
+    Str,
+    /// A pointer to a buffer of bytes.
+    Buf,
+    /// A pointer to the named struct (e.g. `stat`).
+    Struct(&'static str),
+    /// A set of flags, shown as `NAME|NAME|0x...`. The table is ordered so
+    /// that flags spanning several bits come before the single bits they
+    /// contain, and may have one entry for the value `0`. For the `O_*`
+    /// flags, the access mode in `flags & O_ACCMODE` is shown first, as a
+    /// value rather than bits (`O_RDONLY|O_CLOEXEC`).
+    Flags(&'static [(&'static str, u64)]),
+    /// File permission bits, in octal.
+    Mode,
+}
+
+impl ArgFormat {
+    /// Returns a [`Display`](fmt::Display) implementation for `value`.
+    pub fn display(self, value: SyscallWord) -> FormattedArg {
+        FormattedArg {
+            format: self,
+            value,
+        }
+    }
+}
+
+/// An argument formatted according to its [`ArgFormat`].
+#[derive(Debug, Clone, Copy)]
+pub struct FormattedArg {
+    format: ArgFormat,
+    value: SyscallWord,
+}
+
+impl fmt::Display for FormattedArg {
+    #[allow(clippy::unnecessary_cast)] // `SyscallWord` differs per target.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = self.value;
+        // Sign-extend from the width of a register.
+        let signed = value as usize as isize;
+
+        match self.format {
+            ArgFormat::Fd if signed == AT_FDCWD => f.write_str("AT_FDCWD"),
+            ArgFormat::Int | ArgFormat::Fd => write!(f, "{signed}"),
+            ArgFormat::Uint => write!(f, "{value}"),
+            ArgFormat::Hex if value == 0 => f.write_str("0"),
+            ArgFormat::Hex => write!(f, "{value:#x}"),
+            ArgFormat::Str | ArgFormat::Buf | ArgFormat::Struct(_) => {
+                if value == 0 {
+                    f.write_str("NULL")
+                } else {
+                    write!(f, "{value:#x}")
+                }
+            }
+            ArgFormat::Mode => write!(f, "0{value:03o}"),
+            ArgFormat::Flags(flags) => write_flags(f, flags, value as u64),
+        }
+    }
+}
+
+fn write_flags(
+    f: &mut fmt::Formatter,
+    flags: &[(&str, u64)],
+    value: u64,
+) -> fmt::Result {
+    let mut rest = value;
+    let mut first = true;
+
+    if flags.iter().any(|&(name, _)| name == "O_RDONLY") {
+        // The access mode is a field, not a set of bits: 3 is `O_ACCMODE`,
+        // not `O_RDWR|O_WRONLY`. Like strace, it's shown even when it's 0.
+        f.write_str(ACCESS_MODES[(value & O_ACCMODE) as usize])?;
+        rest &= !O_ACCMODE;
+        first = false;
+    } else if value == 0 {
+        return match flags.iter().find(|&&(_, bits)| bits == 0) {
+            Some((name, _)) => f.write_str(name),
+            None => f.write_str("0"),
+        };
+    }
+
+    for &(name, bits) in flags {
+        if bits != 0 && rest & bits == bits {
+            if !first {
+                f.write_str("|")?;
+            }
+            f.write_str(name)?;
+            rest &= !bits;
+            first = false;
+        }
+    }
+    if rest != 0 {
+        if !first {
+            f.write_str("|")?;
+        }
+        write!(f, "{rest:#x}")?;
+    }
+    Ok(())
+}
+
+/// How many bytes of a string are shown before it is cut off with `...`,
+/// like strace's default `-s 32`.
+pub const MAX_STRING: usize = 32;
+
+/// A syscall with its arguments, shown as `name(arg, arg, ...)`, and its
+/// result if there is one, as in `openat(AT_FDCWD, "/etc/passwd",
+/// O_RDONLY, 0000) = 3`.
+///
+/// Without argument metadata for the syscall, all six arguments are shown in
+/// hex. String arguments are shown as addresses unless there is a
+/// [`RemoteMem`] to read them from, which can be a
+/// [`ProcessVm`](crate::remote::ProcessVm) of the own process for local
+/// pointers.
+#[derive(Clone, Copy)]
+pub struct Call<'a> {
+    sysno: Sysno,
+    args: &'a SyscallArgs,
+    mem: Option<&'a dyn RemoteMem>,
+    result: Option<Result<SyscallWord, Errno>>,
+}
+
+impl<'a> Call<'a> {
+    pub fn new(sysno: Sysno, args: &'a SyscallArgs) -> Self {
+        Self {
+            sysno,
+            args,
+            mem: None,
+            result: None,
+        }
+    }
+
+    /// Reads string arguments from `mem`, the memory of the process that
+    /// made the syscall.
+    #[must_use]
+    pub fn mem(mut self, mem: &'a dyn RemoteMem) -> Self {
+        self.mem = Some(mem);
+        self
+    }
+
+    /// Shows `result` after the call, see [`Return`].
+    #[must_use]
+    pub fn result(mut self, result: Result<SyscallWord, Errno>) -> Self {
+        self.result = Some(result);
+        self
+    }
+}
+
+impl fmt::Debug for Call<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Call")
+            .field("sysno", &self.sysno)
+            .field("args", self.args)
+            .field("result", &self.result)
+            .finish_non_exhaustive()
+    }
+}
+
+impl fmt::Display for Call<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        const HEX: [ArgFormat; 6] = [ArgFormat::Hex; 6];
+
+        let args = [
+            self.args.arg0,
+            self.args.arg1,
+            self.args.arg2,
+            self.args.arg3,
+            self.args.arg4,
+            self.args.arg5,
+        ];
+        let formats = self.sysno.arg_formats().unwrap_or(&HEX);
+
+        write!(f, "{}(", self.sysno)?;
+        for (i, (&format, value)) in formats.iter().zip(args).enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            match (format, self.mem) {
+                (ArgFormat::Str, Some(mem)) if value != 0 => {
+                    write_string(f, mem, value)?;
+                }
+                _ => write!(f, "{}", format.display(value))?,
+            }
+        }
+        f.write_str(")")?;
+
+        if let Some(result) = self.result {
+            write!(f, " = {}", Return::new(self.sysno, result))?;
+        }
+        Ok(())
+    }
+}
+
+/// Shows the string at `addr`, or the address if it can't be read.
+fn write_string(
+    f: &mut fmt::Formatter,
+    mem: &dyn RemoteMem,
+    addr: SyscallWord,
+) -> fmt::Result {
+    let mut buf = [0u8; MAX_STRING + 1];
+    #[allow(clippy::unnecessary_cast)] // `SyscallWord` differs per target.
+    let (len, cut) = match mem.read_cstr(addr as u64, &mut buf) {
+        Ok(s) => (s.to_bytes().len(), false),
+        // `buf` is filled without finding the NUL.
+        Err(Errno::ENAMETOOLONG) => (MAX_STRING, true),
+        Err(_) => return write!(f, "{addr:#x}"),
+    };
+
+    f.write_str("\"")?;
+    for (i, &c) in buf[..len].iter().enumerate() {
+        match c {
+            b'"' => f.write_str("\\\"")?,
+            b'\\' => f.write_str("\\\\")?,
+            b'\t' => f.write_str("\\t")?,
+            b'\n' => f.write_str("\\n")?,
+            b'\r' => f.write_str("\\r")?,
+            0x0b => f.write_str("\\v")?,
+            0x0c => f.write_str("\\f")?,
+            b' '..=b'~' => write!(f, "{}", char::from(c))?,
+            // Octal, padded if a digit follows.
+            _ if buf[..len].get(i + 1).is_some_and(u8::is_ascii_digit) => {
+                write!(f, "\\{c:03o}")?;
+            }
+            _ => write!(f, "\\{c:o}")?,
+        }
+    }
+    f.write_str("\"")?;
+    if cut {
+        f.write_str("...")?;
+    }
+    Ok(())
+}
+
+/// The result of a syscall, shown like strace: `3`, `0x7f3e2c000000` for
+/// the addresses returned by `mmap` and the like, or
+/// `-1 ENOENT (No such file or directory)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Return {
+    sysno: Sysno,
+    result: Result<SyscallWord, Errno>,
+}
+
+impl Return {
+    pub fn new(sysno: Sysno, result: Result<SyscallWord, Errno>) -> Self {
+        Self { sysno, result }
+    }
+}
+
+impl fmt::Display for Return {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.result {
+            Ok(value) => {
+                if matches!(
+                    self.sysno.name(),
+                    "mmap" | "mmap2" | "mremap" | "brk" | "shmat"
+                ) {
+                    write!(f, "{value:#x}")
+                } else {
+                    write!(f, "{}", value as usize as isize)
+                }
+            }
+            Err(errno) => {
+                if let Some((name, description)) = errno.name_and_description()
+                {
+                    write!(f, "-1 {name} ({description})")
+                } else {
+                    let errno = errno.into_raw();
+                    write!(f, "-1 ERRNO_{errno} (Unknown error {errno})")
+                }
+            }
+        }
+    }
+}
+
+/// Writes `sysno` with its arguments to `w` like [`Call`], followed by
+/// `result` if there is one, without allocating.
+pub fn format_syscall(
+    w: &mut impl fmt::Write,
+    sysno: Sysno,
+    args: &SyscallArgs,
+    result: Option<Result<SyscallWord, Errno>>,
+) -> fmt::Result {
+    let mut call = Call::new(sysno, args);
+    if let Some(result) = result {
+        call = call.result(result);
+    }
+    write!(w, "{call}")
+}
+
+/// A fixed-size buffer to format text into without allocating, such as a
+/// syscall in a `SIGSYS` handler.
+///
+/// Text that doesn't fit is cut off at a character boundary, and nothing is
+/// added after that; [`is_truncated`](Self::is_truncated) tells if it
+/// happened. Writing never fails.
+#[derive(Clone, Copy)]
+pub struct Buffer<const N: usize> {
+    buf: [u8; N],
+    len: usize,
+    truncated: bool,
+}
+
+impl<const N: usize> Buffer<N> {
+    pub const fn new() -> Self {
+        Self {
+            buf: [0; N],
+            len: 0,
+            truncated: false,
+        }
+    }
+
+    /// Formats `value` into a new buffer.
+    pub fn format(value: impl fmt::Display) -> Self {
+        let mut buf = Self::new();
+        let _ = fmt::Write::write_fmt(&mut buf, format_args!("{value}"));
+        buf
+    }
+
+    pub fn as_str(&self) -> &str {
+        // Only whole characters are written.
+        core::str::from_utf8(self.as_bytes()).unwrap_or_default()
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Whether text was cut off because the buffer was full.
+    pub const fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    pub fn clear(&mut self) {
+        self.len = 0;
+        self.truncated = false;
+    }
+}
+
+impl<const N: usize> Default for Buffer<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> fmt::Write for Buffer<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.truncated {
+            return Ok(());
+        }
+        let mut end = s.len().min(N - self.len);
+        if end < s.len() {
+            while !s.is_char_boundary(end) {
+                end -= 1;
+            }
+            self.truncated = true;
+        }
+        self.buf[self.len..self.len + end]
+            .copy_from_slice(&s.as_bytes()[..end]);
+        self.len += end;
+        Ok(())
+    }
+}
+
+impl<const N: usize> fmt::Display for Buffer<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<const N: usize> fmt::Debug for Buffer<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize, const M: usize> PartialEq<Buffer<M>> for Buffer<N> {
+    fn eq(&self, other: &Buffer<M>) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl<const N: usize> Eq for Buffer<N> {}
+
+impl<const N: usize> PartialEq<str> for Buffer<N> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for Buffer<N> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static O_FLAGS: &[(&str, u64)] = &[
+        ("O_SYNC", 0x10_1000),
+        ("O_CLOEXEC", 0x8_0000),
+        ("O_DSYNC", 0x1000),
+        ("O_CREAT", 0x40),
+        ("O_RDWR", 2),
+        ("O_WRONLY", 1),
+        ("O_RDONLY", 0),
+    ];
+
+    fn show(value: impl fmt::Display) -> Buffer<128> {
+        Buffer::format(value)
+    }
+
+    #[test]
+    fn flags() {
+        let flags = ArgFormat::Flags(O_FLAGS);
+        assert_eq!(show(flags.display(0)), "O_RDONLY");
+        assert_eq!(show(flags.display(0x8_0042)), "O_RDWR|O_CLOEXEC|O_CREAT");
+        assert_eq!(show(flags.display(0x10_1000)), "O_RDONLY|O_SYNC");
+        assert_eq!(show(flags.display(0x1000)), "O_RDONLY|O_DSYNC");
+        assert_eq!(show(flags.display(0x4001)), "O_WRONLY|0x4000");
+        assert_eq!(show(flags.display(3)), "O_ACCMODE");
+        assert_eq!(show(ArgFormat::Flags(&[]).display(0)), "0");
+
+        let prot = ArgFormat::Flags(&[
+            ("PROT_READ", 1),
+            ("PROT_WRITE", 2),
+            ("PROT_NONE", 0),
+        ]);
+        assert_eq!(show(prot.display(0)), "PROT_NONE");
+        assert_eq!(show(prot.display(3)), "PROT_READ|PROT_WRITE");
+    }
+
+    #[test]
+    fn values() {
+        assert_eq!(show(ArgFormat::Fd.display(3)), "3");
+        assert_eq!(
+            show(ArgFormat::Fd.display(AT_FDCWD as SyscallWord)),
+            "AT_FDCWD"
+        );
+        assert_eq!(show(ArgFormat::Int.display(SyscallWord::MAX)), "-1");
+        assert_eq!(show(ArgFormat::Mode.display(0o644)), "0644");
+        assert_eq!(show(ArgFormat::Hex.display(0)), "0");
+        assert_eq!(show(ArgFormat::Str.display(0)), "NULL");
+        assert_eq!(show(ArgFormat::Struct("stat").display(0x1000)), "0x1000");
+    }
+
+    // Every table is generated with argument metadata.
+    #[test]
+    fn call() {
+        let args = SyscallArgs::from(&[1, 2, 3]);
+        assert_eq!(show(Call::new(Sysno::write, &args)), "write(1, 0x2, 3)");
+
+        // `O_CLOEXEC` differs by architecture.
+        let Some(&[_, _, ArgFormat::Flags(o_flags), _]) =
+            Sysno::openat.arg_formats()
+        else {
+            panic!("{:?}", Sysno::openat.arg_formats());
+        };
+        let (_, o_cloexec) = o_flags
+            .iter()
+            .find(|(name, _)| *name == "O_CLOEXEC")
+            .unwrap();
+        let args = SyscallArgs::from(&[
+            AT_FDCWD as SyscallWord,
+            0x1000,
+            *o_cloexec as SyscallWord,
+        ]);
+        assert_eq!(
+            show(Call::new(Sysno::openat, &args)),
+            "openat(AT_FDCWD, 0x1000, O_RDONLY|O_CLOEXEC, 0000)"
+        );
+    }
+
+    #[test]
+    fn result() {
+        let ret = |sysno, result| show(Return::new(sysno, result));
+        assert_eq!(ret(Sysno::openat, Ok(3)), "3");
+        assert_eq!(ret(Sysno::lseek, Ok(SyscallWord::MAX)), "-1");
+        assert_eq!(ret(Sysno::brk, Ok(0x5000)), "0x5000");
+        assert_eq!(
+            ret(Sysno::openat, Err(Errno::ENOENT)),
+            "-1 ENOENT (No such file or directory)"
+        );
+        assert_eq!(
+            ret(Sysno::openat, Err(Errno::new(4000))),
+            "-1 ERRNO_4000 (Unknown error 4000)"
+        );
+
+        let args = SyscallArgs::from(&[3]);
+        let call = show(Call::new(Sysno::close, &args).result(Ok(0)));
+        assert!(call.as_str().ends_with(") = 0"), "{call}");
+    }
+
+    #[cfg(not(rawsys_backend = "none"))]
+    #[test]
+    fn strings() {
+        use crate::remote::ProcessVm;
+
+        struct Str<'a>(&'a ProcessVm, &'a core::ffi::CStr);
+        impl fmt::Display for Str<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write_string(f, self.0, self.1.as_ptr() as SyscallWord)
+            }
+        }
+
+        let pid = unsafe { crate::syscall0(Sysno::getpid) }.unwrap();
+        let mem = ProcessVm::new(crate::id::Pid::from_raw(pid as i32));
+        let string = |s| show(Str(&mem, s));
+        assert_eq!(string(c"/etc/passwd"), r#""/etc/passwd""#);
+        assert_eq!(string(c"a\"b\\\n\x1b[0m\x011"), r#""a\"b\\\n\33[0m\0011""#);
+        assert_eq!(
+            string(c"0123456789abcdef0123456789abcdef!"),
+            r#""0123456789abcdef0123456789abcdef"..."#
+        );
+    }
+
+    #[test]
+    fn buffer() {
+        use core::fmt::Write;
+
+        let mut buf = Buffer::<8>::new();
+        write!(buf, "ab{}", 12).unwrap();
+        assert_eq!(buf, "ab12");
+        assert!(!buf.is_truncated());
+        // "é" doesn't fit in the last byte, and "!" isn't added after it.
+        write!(buf, "cdeé!").unwrap();
+        assert_eq!(buf, "ab12cde");
+        assert!(buf.is_truncated());
+        buf.clear();
+        assert!(buf.is_empty() && !buf.is_truncated());
+
+        let args = SyscallArgs::from(&[3]);
+        let mut buf = Buffer::<128>::new();
+        format_syscall(&mut buf, Sysno::close, &args, Some(Err(Errno::EBADF)))
+            .unwrap();
+        assert_eq!(
+            buf,
+            show(Call::new(Sysno::close, &args).result(Err(Errno::EBADF)))
+        );
+        assert!(buf.as_str().ends_with(") = -1 EBADF (Bad file number)"));
+    }
+}
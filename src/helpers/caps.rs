@@ -0,0 +1,259 @@
+//! Capabilities of threads, and the syscalls that read and change them.
+
+use super::Tid;
+use crate::{Errno, SyscallWord, Sysno};
+
+/// The version of the layout of [`CapUserHeader`] and [`CapUserData`] with
+/// 64-bit sets, in two [`CapUserData`]s (Linux 2.6.26).
+pub const _LINUX_CAPABILITY_VERSION_3: u32 = 0x2008_0522;
+
+/// Defines the capability numbers, and the flags of [`CapSet`] for them.
+macro_rules! caps {
+    ($(
+        $(#[$attr:meta])*
+        $name:ident($flag:ident) = $value:literal;
+    )*) => {
+        $(
+            $(#[$attr])*
+            pub const $name: u32 = $value;
+        )*
+
+        flags! {
+            /// A set of capabilities, with a bit per `CAP_*` number, as in
+            /// the effective, permitted, and inheritable sets of a thread.
+            pub struct CapSet: u64 {
+                $(
+                    $(#[$attr])*
+                    const $flag = 1 << $name;
+                )*
+            }
+        }
+    };
+}
+
+caps! {
+    /// Changes the owners and groups of files.
+    CAP_CHOWN(CHOWN) = 0;
+    /// Bypasses the permission checks of files.
+    CAP_DAC_OVERRIDE(DAC_OVERRIDE) = 1;
+    /// Bypasses the permission checks of reading files and searching
+    /// directories.
+    CAP_DAC_READ_SEARCH(DAC_READ_SEARCH) = 2;
+    /// Bypasses the checks that the user owns a file.
+    CAP_FOWNER(FOWNER) = 3;
+    /// Keeps the setuid and setgid bits when files are changed.
+    CAP_FSETID(FSETID) = 4;
+    /// Sends signals to any process.
+    CAP_KILL(KILL) = 5;
+    CAP_SETGID(SETGID) = 6;
+    CAP_SETUID(SETUID) = 7;
+    /// Changes the bounding set and the securebits, and adds any permitted
+    /// capability to the inheritable set.
+    CAP_SETPCAP(SETPCAP) = 8;
+    CAP_LINUX_IMMUTABLE(LINUX_IMMUTABLE) = 9;
+    /// Binds to ports below 1024.
+    CAP_NET_BIND_SERVICE(NET_BIND_SERVICE) = 10;
+    CAP_NET_BROADCAST(NET_BROADCAST) = 11;
+    CAP_NET_ADMIN(NET_ADMIN) = 12;
+    /// Opens raw and packet sockets.
+    CAP_NET_RAW(NET_RAW) = 13;
+    CAP_IPC_LOCK(IPC_LOCK) = 14;
+    CAP_IPC_OWNER(IPC_OWNER) = 15;
+    CAP_SYS_MODULE(SYS_MODULE) = 16;
+    CAP_SYS_RAWIO(SYS_RAWIO) = 17;
+    CAP_SYS_CHROOT(SYS_CHROOT) = 18;
+    /// Traces any process.
+    CAP_SYS_PTRACE(SYS_PTRACE) = 19;
+    CAP_SYS_PACCT(SYS_PACCT) = 20;
+    /// Most of the administration, e.g. mounts.
+    CAP_SYS_ADMIN(SYS_ADMIN) = 21;
+    CAP_SYS_BOOT(SYS_BOOT) = 22;
+    /// Raises priorities, and sets the scheduling of any process.
+    CAP_SYS_NICE(SYS_NICE) = 23;
+    /// Overrides the resource limits.
+    CAP_SYS_RESOURCE(SYS_RESOURCE) = 24;
+    CAP_SYS_TIME(SYS_TIME) = 25;
+    CAP_SYS_TTY_CONFIG(SYS_TTY_CONFIG) = 26;
+    CAP_MKNOD(MKNOD) = 27;
+    CAP_LEASE(LEASE) = 28;
+    CAP_AUDIT_WRITE(AUDIT_WRITE) = 29;
+    CAP_AUDIT_CONTROL(AUDIT_CONTROL) = 30;
+    /// Sets the capabilities of files.
+    CAP_SETFCAP(SETFCAP) = 31;
+    CAP_MAC_OVERRIDE(MAC_OVERRIDE) = 32;
+    CAP_MAC_ADMIN(MAC_ADMIN) = 33;
+    CAP_SYSLOG(SYSLOG) = 34;
+    CAP_WAKE_ALARM(WAKE_ALARM) = 35;
+    CAP_BLOCK_SUSPEND(BLOCK_SUSPEND) = 36;
+    CAP_AUDIT_READ(AUDIT_READ) = 37;
+    /// Uses `perf_event_open` (Linux 5.8).
+    CAP_PERFMON(PERFMON) = 38;
+    /// Loads BPF programs and maps (Linux 5.8).
+    CAP_BPF(BPF) = 39;
+    /// Sets the pids of new processes, e.g. with `set_tid` of `clone3`
+    /// (Linux 5.9).
+    CAP_CHECKPOINT_RESTORE(CHECKPOINT_RESTORE) = 40;
+}
+
+/// The last capability that this crate knows of, which older kernels may
+/// not have.
+pub const CAP_LAST_CAP: u32 = CAP_CHECKPOINT_RESTORE;
+
+impl CapSet {
+    /// The set of the capability `cap`, or no capabilities if it is not
+    /// below 64.
+    pub const fn of(cap: u32) -> Self {
+        Self(if cap < 64 { 1 << cap } else { 0 })
+    }
+}
+
+/// What `capget` and `capset` read and change (`struct
+/// __user_cap_header_struct`).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CapUserHeader {
+    /// [`_LINUX_CAPABILITY_VERSION_3`], which the kernel replaces with its
+    /// own when it fails with `EINVAL` for another one.
+    pub version: u32,
+    /// The thread, or the calling thread if it is 0.
+    pub pid: i32,
+}
+
+/// 32 bits of each set (`struct __user_cap_data_struct`), of which version
+/// 3 takes two, the low bits first.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CapUserData {
+    pub effective: u32,
+    pub permitted: u32,
+    pub inheritable: u32,
+}
+
+/// The capability sets of a thread.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Capabilities {
+    /// What the kernel checks.
+    pub effective: CapSet,
+    /// What the thread may add to its effective set, a superset of it.
+    pub permitted: CapSet,
+    /// What `execve` keeps, for files whose inheritable set has them.
+    pub inheritable: CapSet,
+}
+
+impl Capabilities {
+    fn from_data(data: &[CapUserData; 2]) -> Self {
+        let join = |low: u32, high: u32| {
+            CapSet::from_bits_retain(u64::from(low) | (u64::from(high) << 32))
+        };
+        Self {
+            effective: join(data[0].effective, data[1].effective),
+            permitted: join(data[0].permitted, data[1].permitted),
+            inheritable: join(data[0].inheritable, data[1].inheritable),
+        }
+    }
+
+    fn to_data(self) -> [CapUserData; 2] {
+        let half = |shift: u32| CapUserData {
+            effective: (self.effective.bits() >> shift) as u32,
+            permitted: (self.permitted.bits() >> shift) as u32,
+            inheritable: (self.inheritable.bits() >> shift) as u32,
+        };
+        [half(0), half(32)]
+    }
+}
+
+/// Returns the capability sets of the thread `tid`, or of the calling
+/// thread if it is 0.
+pub fn capget(tid: Tid) -> Result<Capabilities, Errno> {
+    let mut header = CapUserHeader {
+        version: _LINUX_CAPABILITY_VERSION_3,
+        pid: tid.as_raw(),
+    };
+    let mut data = [CapUserData::default(); 2];
+    unsafe {
+        crate::syscall2(
+            Sysno::capget,
+            core::ptr::from_mut(&mut header) as SyscallWord,
+            data.as_mut_ptr() as SyscallWord,
+        )
+    }?;
+    Ok(Capabilities::from_data(&data))
+}
+
+/// Sets the capability sets of the calling thread to `caps`. Fails with
+/// `EPERM` if that adds to the permitted set, or adds to the effective set
+/// what isn't permitted, or to the inheritable set what isn't permitted or
+/// in the bounding set, without `CAP_SETPCAP`.
+///
+/// ```no_run
+/// use rawsys_linux::helpers::{self, CapSet, Tid};
+///
+/// // Keep nothing but binding to low ports.
+/// let mut caps = helpers::capget(Tid::from_raw(0))?;
+/// caps.permitted = caps.permitted & CapSet::NET_BIND_SERVICE;
+/// caps.effective = caps.permitted;
+/// caps.inheritable = CapSet::empty();
+/// helpers::capset(&caps)?;
+/// # Ok::<(), rawsys_linux::Errno>(())
+/// ```
+pub fn capset(caps: &Capabilities) -> Result<(), Errno> {
+    let mut header = CapUserHeader {
+        version: _LINUX_CAPABILITY_VERSION_3,
+        pid: 0,
+    };
+    let data = caps.to_data();
+    unsafe {
+        crate::syscall2(
+            Sysno::capset,
+            core::ptr::from_mut(&mut header) as SyscallWord,
+            data.as_ptr() as SyscallWord,
+        )
+    }?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::format::Buffer;
+    use crate::helpers::gettid;
+
+    #[test]
+    fn sets() {
+        assert_eq!(CapSet::of(CAP_BPF), CapSet::BPF);
+        assert_eq!(CapSet::of(64), CapSet::empty());
+        let set = CapSet::CHOWN | CapSet::CHECKPOINT_RESTORE;
+        assert_eq!(
+            Buffer::<64>::format(format_args!("{set:?}")),
+            "CapSet(CHOWN | CHECKPOINT_RESTORE)"
+        );
+        let caps = Capabilities {
+            effective: set,
+            permitted: set | CapSet::SETUID,
+            inheritable: CapSet::empty(),
+        };
+        assert_eq!(caps.to_data()[1].permitted, 1 << (40 - 32));
+        assert_eq!(Capabilities::from_data(&caps.to_data()), caps);
+    }
+
+    #[test]
+    fn current() {
+        let caps = capget(Tid::from_raw(0)).unwrap();
+        assert!(caps.permitted.contains(caps.effective));
+        assert_eq!(capget(gettid()), Ok(caps));
+        capset(&caps).unwrap();
+
+        // A capability that isn't permitted can't be made effective.
+        let Some(cap) = (0..=CAP_LAST_CAP)
+            .map(CapSet::of)
+            .find(|&cap| !caps.permitted.contains(cap))
+        else {
+            return;
+        };
+        let raised = Capabilities {
+            effective: caps.effective | cap,
+            ..caps
+        };
+        assert_eq!(capset(&raised), Err(Errno::EPERM));
+    }
+}
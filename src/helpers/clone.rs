@@ -0,0 +1,220 @@
+//! `clone3`, for new processes.
+
+use core::marker::PhantomData;
+
+use super::{Pid, Tid};
+use crate::consts::{
+    CLONE_ARGS_SIZE_VER0, CLONE_ARGS_SIZE_VER1, CLONE_ARGS_SIZE_VER2,
+    CLONE_INTO_CGROUP, CLONE_PARENT_SETTID, CLONE_PIDFD, CLONE_VM,
+};
+use crate::{Errno, SyscallWord, Sysno};
+
+/// `struct clone_args`, of its latest version.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+struct RawCloneArgs {
+    flags: u64,
+    pidfd: u64,
+    child_tid: u64,
+    parent_tid: u64,
+    exit_signal: u64,
+    stack: u64,
+    stack_size: u64,
+    tls: u64,
+    set_tid: u64,
+    set_tid_size: u64,
+    cgroup: u64,
+}
+
+/// The arguments of [`clone3`] (`struct clone_args`, Linux 5.3), built up
+/// like a `Command`:
+///
+/// ```no_run
+/// use rawsys_linux::consts::CLONE_NEWPID;
+/// use rawsys_linux::helpers::{self, CloneArgs, CloneResult};
+///
+/// let mut pidfd = -1;
+/// let args = CloneArgs::new()
+///     .flags(CLONE_NEWPID)
+///     .pidfd(&mut pidfd)
+///     .exit_signal(17); // SIGCHLD
+/// match unsafe { helpers::clone3(&args) }? {
+///     CloneResult::Child => helpers::exit_group(0),
+///     CloneResult::Parent(pid) => println!("{pid} is pid 1 in its namespace"),
+/// }
+/// # Ok::<(), rawsys_linux::Errno>(())
+/// ```
+///
+/// The kernel takes the size of the struct along with it, so that it can
+/// grow. The smallest size that has the fields that are set is passed, so
+/// that older kernels accept what they support.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CloneArgs<'a> {
+    raw: RawCloneArgs,
+    /// The kernel writes to `pidfd` and `parent_tid`, and reads `set_tid`.
+    _borrows: PhantomData<&'a mut i32>,
+}
+
+impl<'a> CloneArgs<'a> {
+    /// Creates a copy of the calling process, like `fork` without an exit
+    /// signal, which makes it invisible to `wait` without `__WALL`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the `CLONE_*` flags, e.g. the `CLONE_NEW*` namespaces.
+    #[must_use]
+    pub fn flags(mut self, flags: u64) -> Self {
+        self.raw.flags |= flags;
+        self
+    }
+
+    /// Stores a pidfd of the child (`CLONE_PIDFD`, Linux 5.2) in `pidfd`,
+    /// which has `O_CLOEXEC`.
+    #[must_use]
+    pub fn pidfd(mut self, pidfd: &'a mut i32) -> Self {
+        self.raw.flags |= CLONE_PIDFD;
+        self.raw.pidfd = core::ptr::from_mut(pidfd) as u64;
+        self
+    }
+
+    /// Stores the id of the child in `tid`, in the parent's memory
+    /// (`CLONE_PARENT_SETTID`).
+    #[must_use]
+    pub fn parent_tid(mut self, tid: &'a mut Tid) -> Self {
+        self.raw.flags |= CLONE_PARENT_SETTID;
+        self.raw.parent_tid = core::ptr::from_mut(tid) as u64;
+        self
+    }
+
+    /// Sends `signal` to the parent when the child exits, e.g. `SIGCHLD`.
+    #[must_use]
+    pub fn exit_signal(mut self, signal: i32) -> Self {
+        self.raw.exit_signal = signal as u64;
+        self
+    }
+
+    /// Gives the child the ids of `tids`, from the innermost pid namespace
+    /// out, which needs `CAP_SYS_ADMIN` in them (Linux 5.5). Used to
+    /// restore checkpointed processes.
+    #[must_use]
+    pub fn set_tid(mut self, tids: &'a [Pid]) -> Self {
+        self.raw.set_tid = tids.as_ptr() as u64;
+        self.raw.set_tid_size = tids.len() as u64;
+        self
+    }
+
+    /// Starts the child in the cgroup of the directory `cgroup`
+    /// (`CLONE_INTO_CGROUP`, Linux 5.7).
+    #[must_use]
+    pub fn cgroup(mut self, cgroup: i32) -> Self {
+        self.raw.flags |= CLONE_INTO_CGROUP;
+        self.raw.cgroup = cgroup as u64;
+        self
+    }
+
+    /// The size of the first version of `struct clone_args` that has the
+    /// fields that are set.
+    fn size(&self) -> u64 {
+        if self.raw.cgroup != 0 || self.raw.flags & CLONE_INTO_CGROUP != 0 {
+            CLONE_ARGS_SIZE_VER2
+        } else if self.raw.set_tid_size != 0 {
+            CLONE_ARGS_SIZE_VER1
+        } else {
+            CLONE_ARGS_SIZE_VER0
+        }
+    }
+}
+
+/// Which side of [`clone3`] returned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CloneResult {
+    /// In the new process.
+    Child,
+    /// In the calling process, with the id of the child.
+    Parent(Pid),
+}
+
+/// Creates a process as `args` says, and returns in both it and the caller,
+/// like `fork`.
+///
+/// Only copies of the caller's memory are supported: `CLONE_VM`, with which
+/// the child would run on the caller's stack, fails with `EINVAL`. See the
+/// [`thread`](crate::thread) module for that.
+///
+/// # Safety
+///
+/// If the process has other threads, the child only has a copy of the
+/// calling one, and may find locks held by the others forever. Until it
+/// calls `execve` or exits, it should only do what is safe in a signal
+/// handler, such as making syscalls with this crate.
+pub unsafe fn clone3(args: &CloneArgs<'_>) -> Result<CloneResult, Errno> {
+    if args.raw.flags & CLONE_VM != 0 {
+        return Err(Errno::EINVAL);
+    }
+    let pid = unsafe {
+        crate::syscall2(
+            Sysno::clone3,
+            core::ptr::from_ref(&args.raw) as SyscallWord,
+            args.size() as SyscallWord,
+        )
+    }?;
+    Ok(match pid {
+        0 => CloneResult::Child,
+        pid => CloneResult::Parent(Pid::from_raw(pid as i32)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::{close, exit_group};
+
+    /// Waits for `pid` and returns its `wait` status.
+    fn wait(pid: Pid) -> i32 {
+        let mut status = 0i32;
+        // `__WALL`, as the child may have no exit signal.
+        unsafe {
+            crate::syscall4(
+                Sysno::wait4,
+                SyscallWord::from(pid),
+                core::ptr::from_mut(&mut status) as SyscallWord,
+                0x4000_0000,
+                0,
+            )
+        }
+        .unwrap();
+        status
+    }
+
+    #[test]
+    fn fork() {
+        let mut pidfd = -1;
+        let mut tid = Tid::from_raw(0);
+        let args = CloneArgs::new()
+            .pidfd(&mut pidfd)
+            .parent_tid(&mut tid)
+            .exit_signal(17);
+        assert_eq!(args.size(), CLONE_ARGS_SIZE_VER0);
+        let pid = match unsafe { clone3(&args) } {
+            Ok(CloneResult::Child) => exit_group(7),
+            Ok(CloneResult::Parent(pid)) => pid,
+            // Blocked by the container.
+            Err(Errno::ENOSYS | Errno::EPERM) => return,
+            Err(errno) => panic!("clone3: {errno}"),
+        };
+        assert!(pidfd >= 0);
+        assert_eq!(tid.as_raw(), pid.as_raw());
+        assert_eq!(wait(pid), 7 << 8);
+        unsafe { close(pidfd) }.unwrap();
+
+        let args = CloneArgs::new().flags(CLONE_VM);
+        assert_eq!(unsafe { clone3(&args) }, Err(Errno::EINVAL));
+        let tids = [Pid::from_raw(1)];
+        assert_eq!(
+            CloneArgs::new().set_tid(&tids).size(),
+            CLONE_ARGS_SIZE_VER1
+        );
+        assert_eq!(CloneArgs::new().cgroup(3).size(), CLONE_ARGS_SIZE_VER2);
+    }
+}
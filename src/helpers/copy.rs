@@ -0,0 +1,257 @@
+//! Copies between fds in the kernel: `copy_file_range` and `sendfile`, and
+//! the loops that copy all of a range, with `read` and `write` where the
+//! kernel can't.
+
+use super::{pread, pwrite, read, write};
+use crate::{Errno, SyscallWord, Sysno};
+
+/// `sendfile64` on the 32-bit targets that have it, whose `sendfile` takes
+/// a 32-bit offset.
+#[cfg(any(
+    target_arch = "x86",
+    target_arch = "arm",
+    target_arch = "mips",
+    target_arch = "powerpc",
+    target_arch = "sparc"
+))]
+const SENDFILE: Sysno = Sysno::sendfile64;
+#[cfg(not(any(
+    target_arch = "x86",
+    target_arch = "arm",
+    target_arch = "mips",
+    target_arch = "powerpc",
+    target_arch = "sparc"
+)))]
+const SENDFILE: Sysno = Sysno::sendfile;
+
+/// The size of the buffer on the stack of the `read` and `write` loop.
+const CHUNK: usize = 16 * 1024;
+
+/// Copies up to `len` bytes from `fd_in` to `fd_out` in the kernel (Linux
+/// 4.5), at the offsets `off_in` and `off_out`, which it advances, or at
+/// and advancing the file positions where they are `None`, and returns the
+/// number of bytes copied, which is 0 at the end of the file.
+///
+/// Fails with `EXDEV` between some filesystems, e.g. before Linux 5.3 or
+/// between filesystems of different types since 5.19, and with
+/// `EOPNOTSUPP` or `EINVAL` for files that it doesn't support, such as
+/// pipes. `flags` must be 0.
+pub fn copy_file_range(
+    fd_in: i32,
+    off_in: Option<&mut u64>,
+    fd_out: i32,
+    off_out: Option<&mut u64>,
+    len: usize,
+    flags: u32,
+) -> Result<usize, Errno> {
+    let copied = unsafe {
+        crate::syscall6(
+            Sysno::copy_file_range,
+            fd_in as SyscallWord,
+            off_in.map_or(0, |off| core::ptr::from_mut(off) as SyscallWord),
+            fd_out as SyscallWord,
+            off_out.map_or(0, |off| core::ptr::from_mut(off) as SyscallWord),
+            len as SyscallWord,
+            SyscallWord::from(flags),
+        )
+    }?;
+    Ok(copied as usize)
+}
+
+/// Copies up to `count` bytes from `in_fd`, which must be a file that can
+/// be mapped, to `out_fd`, e.g. a socket, in the kernel, at `offset`, which
+/// it advances, or at and advancing the file position of `in_fd` if it is
+/// `None`, and returns the number of bytes copied.
+///
+/// Fails with `EINVAL` for an `in_fd` that it doesn't support.
+pub fn sendfile(
+    out_fd: i32,
+    in_fd: i32,
+    offset: Option<&mut u64>,
+    count: usize,
+) -> Result<usize, Errno> {
+    let copied = unsafe {
+        crate::syscall4(
+            SENDFILE,
+            out_fd as SyscallWord,
+            in_fd as SyscallWord,
+            offset.map_or(0, |off| core::ptr::from_mut(off) as SyscallWord),
+            count as SyscallWord,
+        )
+    }?;
+    Ok(copied as usize)
+}
+
+/// Copies `len` bytes from `fd_in` to `fd_out` as [`copy_file_range`]
+/// does, calling it until they are copied or the end of `fd_in`, and
+/// returns the number of bytes copied.
+///
+/// Where the kernel can't copy the files, failing with `EXDEV`,
+/// `EOPNOTSUPP`, `EINVAL`, or `ENOSYS`, it copies the rest with `read` and
+/// `write` through a buffer on the stack, at the same offsets. That is also
+/// how it copies overlapping ranges of one file, front to back.
+pub fn copy_file_range_all(
+    fd_in: i32,
+    mut off_in: Option<&mut u64>,
+    fd_out: i32,
+    mut off_out: Option<&mut u64>,
+    len: u64,
+) -> Result<u64, Errno> {
+    let mut copied = 0;
+    while copied < len {
+        let chunk = usize::try_from(len - copied).unwrap_or(usize::MAX);
+        match copy_file_range(
+            fd_in,
+            off_in.as_deref_mut(),
+            fd_out,
+            off_out.as_deref_mut(),
+            chunk,
+            0,
+        ) {
+            Ok(0) => break,
+            Ok(n) => copied += n as u64,
+            Err(
+                Errno::EXDEV
+                | Errno::EOPNOTSUPP
+                | Errno::EINVAL
+                | Errno::ENOSYS,
+            ) => {
+                return copy_fallback(
+                    fd_in,
+                    off_in,
+                    fd_out,
+                    off_out,
+                    len - copied,
+                )
+                .map(|n| copied + n);
+            }
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(copied)
+}
+
+/// Copies `count` bytes from `in_fd` to `out_fd` as [`sendfile`] does,
+/// calling it until they are copied or the end of `in_fd`, and returns the
+/// number of bytes copied.
+///
+/// Where the kernel can't copy the files, failing with `EINVAL`,
+/// `EOPNOTSUPP`, or `ENOSYS`, it copies the rest with `read` and `write`
+/// through a buffer on the stack.
+pub fn sendfile_all(
+    out_fd: i32,
+    in_fd: i32,
+    mut offset: Option<&mut u64>,
+    count: u64,
+) -> Result<u64, Errno> {
+    let mut copied = 0;
+    while copied < count {
+        let chunk = usize::try_from(count - copied).unwrap_or(usize::MAX);
+        match sendfile(out_fd, in_fd, offset.as_deref_mut(), chunk) {
+            Ok(0) => break,
+            Ok(n) => copied += n as u64,
+            Err(Errno::EINVAL | Errno::EOPNOTSUPP | Errno::ENOSYS) => {
+                return copy_fallback(
+                    in_fd,
+                    offset,
+                    out_fd,
+                    None,
+                    count - copied,
+                )
+                .map(|n| copied + n);
+            }
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(copied)
+}
+
+/// Copies `len` bytes from `fd_in` to `fd_out` with `read` and `write`, or
+/// `pread` and `pwrite` at the offsets that are given, which it advances.
+fn copy_fallback(
+    fd_in: i32,
+    mut off_in: Option<&mut u64>,
+    fd_out: i32,
+    mut off_out: Option<&mut u64>,
+    len: u64,
+) -> Result<u64, Errno> {
+    let mut buf = [0u8; CHUNK];
+    let mut copied = 0;
+    while copied < len {
+        let chunk =
+            usize::try_from(len - copied).map_or(CHUNK, |n| n.min(CHUNK));
+        let buf = &mut buf[..chunk];
+        let n = match off_in.as_deref_mut() {
+            Some(off) => {
+                let n = pread(fd_in, buf, *off)?;
+                *off += n as u64;
+                n
+            }
+            None => read(fd_in, buf)?,
+        };
+        if n == 0 {
+            break;
+        }
+        let mut written = 0;
+        while written < n {
+            let rest = &buf[written..n];
+            let m = match off_out.as_deref_mut() {
+                Some(off) => {
+                    let m = pwrite(fd_out, rest, *off)?;
+                    *off += m as u64;
+                    m
+                }
+                None => write(fd_out, rest)?,
+            };
+            if m == 0 {
+                return Err(Errno::EIO);
+            }
+            written += m;
+        }
+        copied += n as u64;
+    }
+    Ok(copied)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consts::{AT_FDCWD, O_CLOEXEC, O_RDONLY};
+    use crate::helpers::{close, openat, pipe2};
+
+    #[test]
+    fn copies() {
+        let flags = O_RDONLY | O_CLOEXEC;
+        let exe = openat(AT_FDCWD, c"/proc/self/exe", flags, 0).unwrap();
+        let [r, w] = pipe2(O_CLOEXEC).unwrap();
+        let mut offset = 1;
+        assert_eq!(sendfile(w, exe, Some(&mut offset), 3), Ok(3));
+        assert_eq!(offset, 4);
+        let mut buf = [0u8; 8];
+        assert_eq!(read(r, &mut buf), Ok(3));
+        assert_eq!(&buf[..3], b"ELF");
+
+        // Pipes aren't files that `copy_file_range` can write to.
+        let mut offset = 0;
+        assert_eq!(
+            copy_file_range_all(exe, Some(&mut offset), w, None, 4),
+            Ok(4)
+        );
+        assert_eq!(offset, 4);
+        assert_eq!(read(r, &mut buf), Ok(4));
+        assert_eq!(&buf[..4], b"\x7fELF");
+
+        write(w, b"tail").unwrap();
+        unsafe { close(w) }.unwrap();
+        let [r2, w2] = pipe2(O_CLOEXEC).unwrap();
+        assert_eq!(sendfile_all(w2, r, None, 16), Ok(4));
+        assert_eq!(read(r2, &mut buf), Ok(4));
+        assert_eq!(&buf[..4], b"tail");
+        unsafe {
+            close(exe).unwrap();
+            close(r).unwrap();
+            close(r2).unwrap();
+            close(w2).unwrap();
+        }
+    }
+}
@@ -0,0 +1,213 @@
+//! epoll, which waits for events on many fds at once.
+
+use core::fmt;
+
+use super::EpollFlags;
+#[cfg(not(any(
+    feature = "default_kernel_5_4",
+    feature = "default_kernel_5_10"
+)))]
+use super::Timespec;
+use crate::{Errno, SyscallWord, Sysno};
+
+/// `flags` of [`epoll_create1`]: the epoll fd has `O_CLOEXEC`.
+pub const EPOLL_CLOEXEC: u32 = crate::consts::O_CLOEXEC;
+
+/// `op` of [`epoll_ctl`]: adds `fd` with `event`.
+pub const EPOLL_CTL_ADD: u32 = 1;
+/// `op` of [`epoll_ctl`]: removes `fd`.
+pub const EPOLL_CTL_DEL: u32 = 2;
+/// `op` of [`epoll_ctl`]: changes the `event` of `fd`.
+pub const EPOLL_CTL_MOD: u32 = 3;
+
+/// The events of an fd and the data that comes back with them (`struct
+/// epoll_event`), which is packed to 12 bytes on `x86_64` to match i386,
+/// and 16 bytes elsewhere.
+#[repr(C)]
+#[cfg_attr(target_arch = "x86_64", repr(packed))]
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub struct EpollEvent {
+    events: u32,
+    data: u64,
+}
+
+impl EpollEvent {
+    /// Waits for `events` on an fd, and returns `data` with them, e.g. the
+    /// fd or an index.
+    pub const fn new(events: EpollFlags, data: u64) -> Self {
+        Self {
+            events: events.bits(),
+            data,
+        }
+    }
+
+    /// The events that are waited for, or that happened.
+    pub const fn events(&self) -> EpollFlags {
+        EpollFlags::from_bits_retain(self.events)
+    }
+
+    /// The data that was given with the events.
+    pub const fn data(&self) -> u64 {
+        self.data
+    }
+}
+
+impl fmt::Debug for EpollEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EpollEvent")
+            .field("events", &self.events())
+            .field("data", &self.data())
+            .finish()
+    }
+}
+
+/// Makes an epoll instance, and returns its fd. `flags` may have
+/// [`EPOLL_CLOEXEC`].
+pub fn epoll_create1(flags: u32) -> Result<i32, Errno> {
+    let fd = unsafe {
+        crate::syscall1(Sysno::epoll_create1, SyscallWord::from(flags))
+    }?;
+    Ok(fd as i32)
+}
+
+/// Adds `fd` to the epoll instance `epfd`, changes its events, or removes
+/// it, as `op` says. `event` is only `None` for [`EPOLL_CTL_DEL`].
+pub fn epoll_ctl(
+    epfd: i32,
+    op: u32,
+    fd: i32,
+    event: Option<&EpollEvent>,
+) -> Result<(), Errno> {
+    unsafe {
+        crate::syscall4(
+            Sysno::epoll_ctl,
+            epfd as SyscallWord,
+            SyscallWord::from(op),
+            fd as SyscallWord,
+            event.map_or(0, |event| core::ptr::from_ref(event) as SyscallWord),
+        )
+    }?;
+    Ok(())
+}
+
+/// Waits until there are events on the fds of `epfd`, or until `timeout`
+/// milliseconds pass if it isn't -1. Fills in the start of `events`, and
+/// returns how many there are, which is 0 after the timeout.
+///
+/// Unlike `epoll_wait`, which aarch64, loongarch64, and riscv don't have,
+/// it is on every target.
+pub fn epoll_wait(
+    epfd: i32,
+    events: &mut [EpollEvent],
+    timeout: i32,
+) -> Result<usize, Errno> {
+    let count = unsafe {
+        crate::syscall6(
+            Sysno::epoll_pwait,
+            epfd as SyscallWord,
+            events.as_mut_ptr() as SyscallWord,
+            max_events(events),
+            timeout as SyscallWord,
+            0,
+            0,
+        )
+    }?;
+    Ok(count as usize)
+}
+
+/// As [`epoll_wait`], but with a timeout in nanoseconds, or none to wait
+/// for as long as it takes (Linux 5.11).
+#[cfg(not(any(
+    feature = "default_kernel_5_4",
+    feature = "default_kernel_5_10"
+)))]
+pub fn epoll_pwait2(
+    epfd: i32,
+    events: &mut [EpollEvent],
+    timeout: Option<&Timespec>,
+) -> Result<usize, Errno> {
+    let count = unsafe {
+        crate::syscall6(
+            Sysno::epoll_pwait2,
+            epfd as SyscallWord,
+            events.as_mut_ptr() as SyscallWord,
+            max_events(events),
+            timeout.map_or(0, |timeout| {
+                core::ptr::from_ref(timeout) as SyscallWord
+            }),
+            0,
+            0,
+        )
+    }?;
+    Ok(count as usize)
+}
+
+/// `maxevents` for `events`, which the kernel limits to `INT_MAX /
+/// sizeof(struct epoll_event)`.
+fn max_events(events: &[EpollEvent]) -> SyscallWord {
+    let max = i32::MAX as usize / size_of::<EpollEvent>();
+    events.len().min(max) as SyscallWord
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::{close, pipe2, write};
+
+    #[test]
+    fn layout() {
+        let size = if cfg!(target_arch = "x86_64") { 12 } else { 16 };
+        assert_eq!(size_of::<EpollEvent>(), size);
+    }
+
+    #[test]
+    fn pipe() {
+        let epfd = epoll_create1(EPOLL_CLOEXEC).unwrap();
+        let [rd, wr] = pipe2(0).unwrap();
+        let event = EpollEvent::new(EpollFlags::IN, 7);
+        epoll_ctl(epfd, EPOLL_CTL_ADD, rd, Some(&event)).unwrap();
+        assert_eq!(
+            epoll_ctl(epfd, EPOLL_CTL_ADD, rd, Some(&event)),
+            Err(Errno::EEXIST)
+        );
+
+        let mut events = [EpollEvent::default(); 4];
+        assert_eq!(epoll_wait(epfd, &mut events, 0), Ok(0));
+        write(wr, b"x").unwrap();
+        assert_eq!(epoll_wait(epfd, &mut events, -1), Ok(1));
+        assert_eq!(events[0].events(), EpollFlags::IN);
+        assert_eq!(events[0].data(), 7);
+
+        unsafe { close(wr) }.unwrap();
+        let event = EpollEvent::new(EpollFlags::IN | EpollFlags::ET, 8);
+        epoll_ctl(epfd, EPOLL_CTL_MOD, rd, Some(&event)).unwrap();
+        assert_eq!(epoll_wait(epfd, &mut events, 0), Ok(1));
+        assert!(events[0].events().contains(EpollFlags::HUP));
+        assert_eq!(events[0].data(), 8);
+
+        epoll_ctl(epfd, EPOLL_CTL_DEL, rd, None).unwrap();
+        assert_eq!(epoll_wait(epfd, &mut events, 0), Ok(0));
+        unsafe {
+            close(rd).unwrap();
+            close(epfd).unwrap();
+        }
+    }
+
+    #[test]
+    #[cfg(not(any(
+        feature = "default_kernel_5_4",
+        feature = "default_kernel_5_10"
+    )))]
+    fn pwait2() {
+        use core::time::Duration;
+
+        let epfd = epoll_create1(EPOLL_CLOEXEC).unwrap();
+        let mut events = [EpollEvent::default(); 1];
+        let timeout = Timespec::from(Duration::from_millis(1));
+        match epoll_pwait2(epfd, &mut events, Some(&timeout)) {
+            Ok(0) | Err(Errno::ENOSYS) => {}
+            result => panic!("epoll_pwait2: {result:?}"),
+        }
+        unsafe { close(epfd) }.unwrap();
+    }
+}
@@ -0,0 +1,75 @@
+//! eventfds, counters that threads and processes signal each other with.
+
+use super::{read, write};
+use crate::{Errno, SyscallWord, Sysno};
+
+/// `flags` of [`eventfd`]: the fd has `O_CLOEXEC`.
+pub const EFD_CLOEXEC: u32 = crate::consts::O_CLOEXEC;
+/// `flags` of [`eventfd`]: reads and writes fail with `EAGAIN` instead of
+/// blocking.
+pub const EFD_NONBLOCK: u32 = crate::consts::O_NONBLOCK;
+/// `flags` of [`eventfd`]: reads take 1 from the counter instead of all of
+/// it.
+pub const EFD_SEMAPHORE: u32 = 1;
+
+/// Makes an eventfd whose counter starts at `initval`, and returns it.
+/// `flags` may have [`EFD_CLOEXEC`], [`EFD_NONBLOCK`], and
+/// [`EFD_SEMAPHORE`].
+///
+/// ```no_run
+/// use rawsys_linux::helpers::{EFD_CLOEXEC, eventfd, eventfd_read, eventfd_write};
+///
+/// let fd = eventfd(0, EFD_CLOEXEC)?;
+/// eventfd_write(fd, 3)?;
+/// assert_eq!(eventfd_read(fd)?, 3);
+/// # Ok::<(), rawsys_linux::Errno>(())
+/// ```
+pub fn eventfd(initval: u32, flags: u32) -> Result<i32, Errno> {
+    let fd = unsafe {
+        crate::syscall2(
+            Sysno::eventfd2,
+            SyscallWord::from(initval),
+            SyscallWord::from(flags),
+        )
+    }?;
+    Ok(fd as i32)
+}
+
+/// Takes the counter of the eventfd `fd` and resets it to 0, or takes 1
+/// with [`EFD_SEMAPHORE`]. Waits while it is 0, unless `fd` has
+/// [`EFD_NONBLOCK`].
+pub fn eventfd_read(fd: i32) -> Result<u64, Errno> {
+    let mut value = [0u8; 8];
+    read(fd, &mut value)?;
+    Ok(u64::from_ne_bytes(value))
+}
+
+/// Adds `value` to the counter of the eventfd `fd`, waking its readers.
+/// Waits while that would make it larger than `u64::MAX - 1`, unless `fd`
+/// has [`EFD_NONBLOCK`].
+pub fn eventfd_write(fd: i32, value: u64) -> Result<(), Errno> {
+    write(fd, &value.to_ne_bytes())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::close;
+
+    #[test]
+    fn counter() {
+        let fd = eventfd(2, EFD_CLOEXEC | EFD_NONBLOCK).unwrap();
+        eventfd_write(fd, 3).unwrap();
+        assert_eq!(eventfd_read(fd), Ok(5));
+        assert_eq!(eventfd_read(fd), Err(Errno::EAGAIN));
+        assert_eq!(eventfd_write(fd, u64::MAX), Err(Errno::EINVAL));
+        unsafe { close(fd) }.unwrap();
+
+        let fd = eventfd(2, EFD_SEMAPHORE | EFD_NONBLOCK).unwrap();
+        assert_eq!(eventfd_read(fd), Ok(1));
+        assert_eq!(eventfd_read(fd), Ok(1));
+        assert_eq!(eventfd_read(fd), Err(Errno::EAGAIN));
+        unsafe { close(fd) }.unwrap();
+    }
+}
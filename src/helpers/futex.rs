@@ -0,0 +1,338 @@
+//! Futexes, which threads wait on and wake each other through.
+
+#[cfg(not(any(
+    feature = "default_kernel_5_4",
+    feature = "default_kernel_5_10",
+    feature = "default_kernel_5_15"
+)))]
+use core::marker::PhantomData;
+use core::sync::atomic::AtomicU32;
+
+use super::Timespec;
+use crate::{Errno, SyscallWord, Sysno};
+
+/// Waits while the futex holds a value.
+pub const FUTEX_WAIT: u32 = 0;
+/// Wakes waiters.
+pub const FUTEX_WAKE: u32 = 1;
+/// Wakes waiters and moves the others to a second futex.
+pub const FUTEX_REQUEUE: u32 = 3;
+/// [`FUTEX_REQUEUE`] if the futex holds a value.
+pub const FUTEX_CMP_REQUEUE: u32 = 4;
+/// Changes a second futex and wakes the waiters of both.
+pub const FUTEX_WAKE_OP: u32 = 5;
+/// Locks a priority-inheritance futex.
+pub const FUTEX_LOCK_PI: u32 = 6;
+/// Unlocks a priority-inheritance futex.
+pub const FUTEX_UNLOCK_PI: u32 = 7;
+/// Locks a priority-inheritance futex without waiting.
+pub const FUTEX_TRYLOCK_PI: u32 = 8;
+/// [`FUTEX_WAIT`] for the wakes that match a bitset, until an absolute
+/// time.
+pub const FUTEX_WAIT_BITSET: u32 = 9;
+/// [`FUTEX_WAKE`] for the waiters that match a bitset.
+pub const FUTEX_WAKE_BITSET: u32 = 10;
+/// Waits on a futex to lock a priority-inheritance futex.
+pub const FUTEX_WAIT_REQUEUE_PI: u32 = 11;
+/// Requeues the waiters of [`FUTEX_WAIT_REQUEUE_PI`].
+pub const FUTEX_CMP_REQUEUE_PI: u32 = 12;
+/// [`FUTEX_LOCK_PI`] on `CLOCK_MONOTONIC` (Linux 5.14).
+pub const FUTEX_LOCK_PI2: u32 = 13;
+
+/// Flag of the operations: the futex is only used by the threads of the
+/// process, which is faster.
+pub const FUTEX_PRIVATE_FLAG: u32 = 128;
+/// Flag of the operations: timeouts are on `CLOCK_REALTIME`, not
+/// `CLOCK_MONOTONIC`.
+pub const FUTEX_CLOCK_REALTIME: u32 = 256;
+
+/// The bitset that matches every other.
+pub const FUTEX_BITSET_MATCH_ANY: u32 = u32::MAX;
+
+/// Bit of a priority-inheritance futex: there are waiters.
+pub const FUTEX_WAITERS: u32 = 0x8000_0000;
+/// Bit of a priority-inheritance futex: its owner died.
+pub const FUTEX_OWNER_DIED: u32 = 0x4000_0000;
+/// The thread id of the owner of a priority-inheritance futex.
+pub const FUTEX_TID_MASK: u32 = 0x3fff_ffff;
+
+/// Flag of the `futex2` interface: the futex is a `u8`.
+pub const FUTEX2_SIZE_U8: u32 = 0;
+/// Flag of the `futex2` interface: the futex is a `u16`.
+pub const FUTEX2_SIZE_U16: u32 = 1;
+/// Flag of the `futex2` interface: the futex is a `u32`, the only size
+/// that the kernel supports so far.
+pub const FUTEX2_SIZE_U32: u32 = 2;
+/// Flag of the `futex2` interface: the futex is a `u64`.
+pub const FUTEX2_SIZE_U64: u32 = 3;
+/// Flag of the `futex2` interface: the futex is followed by a NUMA node.
+pub const FUTEX2_NUMA: u32 = 4;
+/// Flag of the `futex2` interface, as [`FUTEX_PRIVATE_FLAG`].
+pub const FUTEX2_PRIVATE: u32 = FUTEX_PRIVATE_FLAG;
+
+/// The most futexes that [`futex_waitv`] waits on.
+pub const FUTEX_WAITV_MAX: usize = 128;
+
+/// `futex` with a 64-bit `struct timespec`, which is `futex_time64` on
+/// 32-bit targets.
+#[cfg(target_pointer_width = "64")]
+const FUTEX: Sysno = Sysno::futex;
+#[cfg(target_pointer_width = "32")]
+const FUTEX: Sysno = Sysno::futex_time64;
+
+/// Makes the futex operation `op` on `futex`.
+fn futex(
+    futex: &AtomicU32,
+    op: u32,
+    val: u32,
+    timeout: SyscallWord,
+    uaddr2: SyscallWord,
+    val3: u32,
+) -> Result<u32, Errno> {
+    let ret = unsafe {
+        crate::syscall6(
+            FUTEX,
+            futex.as_ptr() as SyscallWord,
+            SyscallWord::from(op),
+            SyscallWord::from(val),
+            timeout,
+            uaddr2,
+            SyscallWord::from(val3),
+        )
+    }?;
+    Ok(ret as u32)
+}
+
+/// Waits until `futex` is woken, if it holds `expected`, or until the
+/// relative `timeout` passes on `CLOCK_MONOTONIC`. `flags` may have
+/// [`FUTEX_PRIVATE_FLAG`].
+///
+/// Fails with `EAGAIN` if `futex` doesn't hold `expected`, `ETIMEDOUT`
+/// when the timeout passes, and `EINTR` when a signal is handled. It can
+/// also return without a wake, so check the value again afterwards:
+///
+/// ```no_run
+/// use core::sync::atomic::{AtomicU32, Ordering};
+/// use rawsys_linux::helpers::{FUTEX_PRIVATE_FLAG, futex_wait};
+///
+/// let ready = AtomicU32::new(0);
+/// while ready.load(Ordering::Acquire) == 0 {
+///     let _ = futex_wait(&ready, 0, None, FUTEX_PRIVATE_FLAG);
+/// }
+/// ```
+pub fn futex_wait(
+    futex: &AtomicU32,
+    expected: u32,
+    timeout: Option<&Timespec>,
+    flags: u32,
+) -> Result<(), Errno> {
+    let timeout = timeout
+        .map_or(0, |timeout| core::ptr::from_ref(timeout) as SyscallWord);
+    self::futex(futex, FUTEX_WAIT | flags, expected, timeout, 0, 0).map(drop)
+}
+
+/// Wakes up to `count` of the threads that wait on `futex`, e.g. 1 or
+/// `i32::MAX as u32` for all, and returns how many were woken. `flags`
+/// must match those of the waiters.
+pub fn futex_wake(
+    futex: &AtomicU32,
+    count: u32,
+    flags: u32,
+) -> Result<u32, Errno> {
+    self::futex(futex, FUTEX_WAKE | flags, count, 0, 0, 0)
+}
+
+/// Wakes up to `wake` of the threads that wait on `futex`, if it holds
+/// `expected`, and moves up to `requeue` of the others to wait on `target`
+/// (`FUTEX_CMP_REQUEUE`). Returns how many were woken or moved.
+///
+/// Fails with `EAGAIN` if `futex` doesn't hold `expected`, so that a
+/// condition variable doesn't move waiters that missed a change.
+pub fn futex_requeue(
+    futex: &AtomicU32,
+    expected: u32,
+    wake: u32,
+    requeue: u32,
+    target: &AtomicU32,
+    flags: u32,
+) -> Result<u32, Errno> {
+    self::futex(
+        futex,
+        FUTEX_CMP_REQUEUE | flags,
+        wake,
+        // `val2`, which takes the place of the timeout.
+        SyscallWord::from(requeue),
+        target.as_ptr() as SyscallWord,
+        expected,
+    )
+}
+
+/// One of the futexes of [`futex_waitv`] (`struct futex_waitv`).
+#[cfg(not(any(
+    feature = "default_kernel_5_4",
+    feature = "default_kernel_5_10",
+    feature = "default_kernel_5_15"
+)))]
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct FutexWaitv<'a> {
+    val: u64,
+    uaddr: u64,
+    flags: u32,
+    reserved: u32,
+    _futex: PhantomData<&'a AtomicU32>,
+}
+
+#[cfg(not(any(
+    feature = "default_kernel_5_4",
+    feature = "default_kernel_5_10",
+    feature = "default_kernel_5_15"
+)))]
+impl<'a> FutexWaitv<'a> {
+    /// Waits on `futex` if it holds `expected`. `flags` may have
+    /// [`FUTEX2_PRIVATE`].
+    pub fn new(futex: &'a AtomicU32, expected: u32, flags: u32) -> Self {
+        Self {
+            val: u64::from(expected),
+            uaddr: futex.as_ptr() as u64,
+            flags: FUTEX2_SIZE_U32 | flags,
+            reserved: 0,
+            _futex: PhantomData,
+        }
+    }
+}
+
+/// Waits until one of the `waiters` is woken, if each holds its expected
+/// value, or until the absolute `deadline` on `clock`, which is
+/// [`CLOCK_MONOTONIC`](super::CLOCK_MONOTONIC) or
+/// [`CLOCK_REALTIME`](super::CLOCK_REALTIME) (Linux 5.16). Returns the
+/// index of the one that was woken.
+///
+/// Fails with `EAGAIN` if one doesn't hold its expected value,
+/// `ETIMEDOUT` at the deadline, and `EINVAL` for more than
+/// [`FUTEX_WAITV_MAX`] waiters.
+///
+/// ```no_run
+/// use core::sync::atomic::AtomicU32;
+/// use core::time::Duration;
+/// use rawsys_linux::helpers::{
+///     self, CLOCK_MONOTONIC, FUTEX2_PRIVATE, FutexWaitv,
+/// };
+///
+/// let (a, b) = (AtomicU32::new(0), AtomicU32::new(0));
+/// let waiters = [
+///     FutexWaitv::new(&a, 0, FUTEX2_PRIVATE),
+///     FutexWaitv::new(&b, 0, FUTEX2_PRIVATE),
+/// ];
+/// let now = helpers::clock_gettime(CLOCK_MONOTONIC)?;
+/// let deadline = now + Duration::from_secs(1);
+/// match helpers::futex_waitv(&waiters, Some(&deadline), CLOCK_MONOTONIC) {
+///     Ok(index) => println!("futex {index} was woken"),
+///     Err(errno) => println!("{errno}"),
+/// }
+/// # Ok::<(), rawsys_linux::Errno>(())
+/// ```
+///
+/// The waiters are woken by [`futex_wake`] with [`FUTEX_PRIVATE_FLAG`] if
+/// they have [`FUTEX2_PRIVATE`].
+#[cfg(not(any(
+    feature = "default_kernel_5_4",
+    feature = "default_kernel_5_10",
+    feature = "default_kernel_5_15"
+)))]
+pub fn futex_waitv(
+    waiters: &[FutexWaitv<'_>],
+    deadline: Option<&Timespec>,
+    clock: i32,
+) -> Result<usize, Errno> {
+    let index = unsafe {
+        crate::syscall5(
+            Sysno::futex_waitv,
+            waiters.as_ptr() as SyscallWord,
+            waiters.len() as SyscallWord,
+            0,
+            deadline.map_or(0, |deadline| {
+                core::ptr::from_ref(deadline) as SyscallWord
+            }),
+            clock as SyscallWord,
+        )
+    }?;
+    Ok(index as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::time::Duration;
+
+    #[test]
+    fn single() {
+        let futex = AtomicU32::new(1);
+        let other = AtomicU32::new(0);
+        let flags = FUTEX_PRIVATE_FLAG;
+        assert_eq!(futex_wait(&futex, 0, None, flags), Err(Errno::EAGAIN));
+        let timeout = Timespec::from(Duration::from_millis(1));
+        assert_eq!(
+            futex_wait(&futex, 1, Some(&timeout), flags),
+            Err(Errno::ETIMEDOUT)
+        );
+        assert_eq!(futex_wake(&futex, 1, flags), Ok(0));
+        assert_eq!(
+            futex_requeue(&futex, 0, 1, 1, &other, flags),
+            Err(Errno::EAGAIN)
+        );
+        assert_eq!(futex_requeue(&futex, 1, 1, 1, &other, flags), Ok(0));
+    }
+
+    #[test]
+    #[cfg(not(any(
+        feature = "default_kernel_5_4",
+        feature = "default_kernel_5_10",
+        feature = "default_kernel_5_15"
+    )))]
+    fn waitv() {
+        use crate::helpers::{CLOCK_MONOTONIC, clock_gettime};
+
+        assert_eq!(core::mem::size_of::<FutexWaitv>(), 24);
+        let (a, b) = (AtomicU32::new(0), AtomicU32::new(1));
+        let waiters = [
+            FutexWaitv::new(&a, 0, FUTEX2_PRIVATE),
+            FutexWaitv::new(&b, 1, FUTEX2_PRIVATE),
+        ];
+        let now = clock_gettime(CLOCK_MONOTONIC).unwrap();
+        let deadline = now + Duration::from_millis(1);
+        match futex_waitv(&waiters, Some(&deadline), CLOCK_MONOTONIC) {
+            Err(Errno::ETIMEDOUT) => {}
+            Err(Errno::ENOSYS) => return,
+            result => panic!("futex_waitv: {result:?}"),
+        }
+        b.store(2, core::sync::atomic::Ordering::Relaxed);
+        assert_eq!(
+            futex_waitv(&waiters, Some(&deadline), CLOCK_MONOTONIC),
+            Err(Errno::EAGAIN)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn threads() {
+        use core::sync::atomic::Ordering;
+
+        let futex = AtomicU32::new(0);
+        std::thread::scope(|scope| {
+            let waiter = scope.spawn(|| {
+                while futex.load(Ordering::Acquire) == 0 {
+                    let flags = FUTEX_PRIVATE_FLAG;
+                    match futex_wait(&futex, 0, None, flags) {
+                        Ok(()) | Err(Errno::EAGAIN | Errno::EINTR) => {}
+                        Err(errno) => panic!("futex_wait: {errno}"),
+                    }
+                }
+            });
+            std::thread::sleep(Duration::from_millis(10));
+            futex.store(1, Ordering::Release);
+            futex_wake(&futex, 1, FUTEX_PRIVATE_FLAG).unwrap();
+            waiter.join().unwrap();
+        });
+    }
+}
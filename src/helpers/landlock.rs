@@ -0,0 +1,348 @@
+//! Landlock, which lets unprivileged processes sandbox themselves.
+
+use super::close;
+use crate::{Errno, SyscallWord, Sysno};
+
+/// Execute a file.
+pub const LANDLOCK_ACCESS_FS_EXECUTE: u64 = 1 << 0;
+/// Open a file for writing.
+pub const LANDLOCK_ACCESS_FS_WRITE_FILE: u64 = 1 << 1;
+/// Open a file for reading.
+pub const LANDLOCK_ACCESS_FS_READ_FILE: u64 = 1 << 2;
+/// Open a directory or list its contents.
+pub const LANDLOCK_ACCESS_FS_READ_DIR: u64 = 1 << 3;
+/// Remove an empty directory or rename one.
+pub const LANDLOCK_ACCESS_FS_REMOVE_DIR: u64 = 1 << 4;
+/// Unlink or rename a file.
+pub const LANDLOCK_ACCESS_FS_REMOVE_FILE: u64 = 1 << 5;
+/// Create, rename, or link a character device.
+pub const LANDLOCK_ACCESS_FS_MAKE_CHAR: u64 = 1 << 6;
+/// Create or rename a directory.
+pub const LANDLOCK_ACCESS_FS_MAKE_DIR: u64 = 1 << 7;
+/// Create, rename, or link a regular file.
+pub const LANDLOCK_ACCESS_FS_MAKE_REG: u64 = 1 << 8;
+/// Create, rename, or link a UNIX domain socket.
+pub const LANDLOCK_ACCESS_FS_MAKE_SOCK: u64 = 1 << 9;
+/// Create, rename, or link a named pipe.
+pub const LANDLOCK_ACCESS_FS_MAKE_FIFO: u64 = 1 << 10;
+/// Create, rename, or link a block device.
+pub const LANDLOCK_ACCESS_FS_MAKE_BLOCK: u64 = 1 << 11;
+/// Create, rename, or link a symlink.
+pub const LANDLOCK_ACCESS_FS_MAKE_SYM: u64 = 1 << 12;
+/// Link or rename a file from or to a different directory (ABI 2).
+pub const LANDLOCK_ACCESS_FS_REFER: u64 = 1 << 13;
+/// Truncate a file (ABI 3).
+pub const LANDLOCK_ACCESS_FS_TRUNCATE: u64 = 1 << 14;
+/// Use `ioctl` on a character or block device (ABI 5).
+pub const LANDLOCK_ACCESS_FS_IOCTL_DEV: u64 = 1 << 15;
+
+/// Bind a TCP socket to a port (ABI 4).
+pub const LANDLOCK_ACCESS_NET_BIND_TCP: u64 = 1 << 0;
+/// Connect a TCP socket to a port (ABI 4).
+pub const LANDLOCK_ACCESS_NET_CONNECT_TCP: u64 = 1 << 1;
+
+/// Connect to abstract UNIX sockets of processes outside of the sandbox
+/// (ABI 6).
+pub const LANDLOCK_SCOPE_ABSTRACT_UNIX_SOCKET: u64 = 1 << 0;
+/// Send signals to processes outside of the sandbox (ABI 6).
+pub const LANDLOCK_SCOPE_SIGNAL: u64 = 1 << 1;
+
+/// `flags` of `landlock_create_ruleset`: return the ABI version.
+const LANDLOCK_CREATE_RULESET_VERSION: u32 = 1 << 0;
+
+/// `rule_type` of `landlock_add_rule`.
+const LANDLOCK_RULE_PATH_BENEATH: u32 = 1;
+const LANDLOCK_RULE_NET_PORT: u32 = 2;
+
+/// `struct landlock_path_beneath_attr`, which is packed.
+#[repr(C, packed)]
+struct PathBeneathAttr {
+    allowed_access: u64,
+    parent_fd: i32,
+}
+
+/// `struct landlock_net_port_attr`
+#[repr(C)]
+struct NetPortAttr {
+    allowed_access: u64,
+    port: u64,
+}
+
+/// Returns the version of the Landlock ABI of the kernel, which grows with
+/// the access rights that it knows: 1 in Linux 5.13, 2 in 5.19, 3 in 6.2,
+/// 4 in 6.7, 5 in 6.10, and 6 in 6.12.
+///
+/// Fails with `ENOSYS` if the kernel was built without Landlock, and with
+/// `EOPNOTSUPP` if it was disabled at boot.
+pub fn landlock_abi() -> Result<u32, Errno> {
+    let abi = unsafe {
+        crate::syscall3(
+            Sysno::landlock_create_ruleset,
+            0,
+            0,
+            SyscallWord::from(LANDLOCK_CREATE_RULESET_VERSION),
+        )
+    }?;
+    Ok(abi as u32)
+}
+
+/// The access rights that a [`Ruleset`] handles, i.e. denies unless a rule
+/// allows them (`struct landlock_ruleset_attr`).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RulesetAttr {
+    /// The `LANDLOCK_ACCESS_FS_*` rights.
+    pub handled_access_fs: u64,
+    /// The `LANDLOCK_ACCESS_NET_*` rights (ABI 4).
+    pub handled_access_net: u64,
+    /// The `LANDLOCK_SCOPE_*` restrictions, which can't be allowed by
+    /// rules (ABI 6).
+    pub scoped: u64,
+}
+
+impl RulesetAttr {
+    /// Handles every access right of the ABI version `abi`, from
+    /// [`landlock_abi`].
+    pub const fn all(abi: u32) -> Self {
+        Self {
+            handled_access_fs: u64::MAX,
+            handled_access_net: u64::MAX,
+            scoped: u64::MAX,
+        }
+        .for_abi(abi)
+    }
+
+    /// Drops the access rights that the ABI version `abi` doesn't know,
+    /// which the kernel would reject with `EINVAL`, so that the ruleset
+    /// restricts as much as the kernel can.
+    #[must_use]
+    pub const fn for_abi(mut self, abi: u32) -> Self {
+        let fs_bits = match abi {
+            0 => 0,
+            1 => 13,
+            2 => 14,
+            3 | 4 => 15,
+            _ => 16,
+        };
+        self.handled_access_fs &= (1 << fs_bits) - 1;
+        self.handled_access_net &= if abi < 4 { 0 } else { 0b11 };
+        self.scoped &= if abi < 6 { 0 } else { 0b11 };
+        self
+    }
+
+    /// The size of the first version of `struct landlock_ruleset_attr`
+    /// that has the fields that are set.
+    fn size(&self) -> usize {
+        if self.scoped != 0 {
+            24
+        } else if self.handled_access_net != 0 {
+            16
+        } else {
+            8
+        }
+    }
+}
+
+/// A Landlock ruleset (Linux 5.13), which is closed when dropped.
+///
+/// The access rights that its [`RulesetAttr`] handles are denied to the
+/// thread that [enforces](Self::restrict_self) it, except where its rules
+/// allow them:
+///
+/// ```no_run
+/// use rawsys_linux::consts::{AT_FDCWD, O_CLOEXEC, O_PATH};
+/// use rawsys_linux::helpers::{self, Ruleset, RulesetAttr};
+/// use rawsys_linux::helpers::{
+///     LANDLOCK_ACCESS_FS_READ_DIR, LANDLOCK_ACCESS_FS_READ_FILE,
+/// };
+///
+/// let abi = helpers::landlock_abi()?;
+/// let ruleset = Ruleset::create(&RulesetAttr::all(abi))?;
+/// let usr = helpers::openat(AT_FDCWD, c"/usr", O_PATH | O_CLOEXEC, 0)?;
+/// let read = LANDLOCK_ACCESS_FS_READ_FILE | LANDLOCK_ACCESS_FS_READ_DIR;
+/// ruleset.add_path_beneath(usr, read)?;
+/// unsafe { helpers::close(usr) }?;
+/// rawsys_linux::seccomp::no_new_privs()?;
+/// ruleset.restrict_self()?;
+/// // Only reading under /usr is left.
+/// # Ok::<(), rawsys_linux::Errno>(())
+/// ```
+///
+/// Access rights that the kernel doesn't know fail with `EINVAL`, so check
+/// [`landlock_abi`] and use [`RulesetAttr::for_abi`] for new ones.
+#[derive(Debug)]
+pub struct Ruleset(i32);
+
+impl Ruleset {
+    /// Creates a ruleset that handles the access rights of `attr`, which
+    /// has no rules yet. Fails with `ENOMSG` if `attr` has none.
+    pub fn create(attr: &RulesetAttr) -> Result<Self, Errno> {
+        let fd = unsafe {
+            crate::syscall3(
+                Sysno::landlock_create_ruleset,
+                core::ptr::from_ref(attr) as SyscallWord,
+                attr.size() as SyscallWord,
+                0,
+            )
+        }?;
+        Ok(Self(fd as i32))
+    }
+
+    /// Allows the `LANDLOCK_ACCESS_FS_*` rights of `access` on the file or
+    /// directory `parent_fd`, which may be opened with `O_PATH`, and on
+    /// everything under it. Only the rights that make sense for files are
+    /// allowed on a file.
+    pub fn add_path_beneath(
+        &self,
+        parent_fd: i32,
+        access: u64,
+    ) -> Result<(), Errno> {
+        let attr = PathBeneathAttr {
+            allowed_access: access,
+            parent_fd,
+        };
+        self.add_rule(
+            LANDLOCK_RULE_PATH_BENEATH,
+            core::ptr::from_ref(&attr) as SyscallWord,
+        )
+    }
+
+    /// Allows the `LANDLOCK_ACCESS_NET_*` rights of `access` on the TCP
+    /// port `port` (ABI 4).
+    pub fn add_net_port(&self, port: u16, access: u64) -> Result<(), Errno> {
+        let attr = NetPortAttr {
+            allowed_access: access,
+            port: u64::from(port),
+        };
+        self.add_rule(
+            LANDLOCK_RULE_NET_PORT,
+            core::ptr::from_ref(&attr) as SyscallWord,
+        )
+    }
+
+    fn add_rule(&self, rule_type: u32, attr: SyscallWord) -> Result<(), Errno> {
+        unsafe {
+            crate::syscall4(
+                Sysno::landlock_add_rule,
+                self.0 as SyscallWord,
+                SyscallWord::from(rule_type),
+                attr,
+                0,
+            )
+        }
+        .map(drop)
+    }
+
+    /// Enforces the ruleset on the calling thread, and the threads and
+    /// processes that it creates afterwards. Rulesets stack, so this can
+    /// only restrict further, and can't be undone.
+    ///
+    /// Fails with `EPERM` unless the thread has `no_new_privs`
+    /// ([`seccomp::no_new_privs`](crate::seccomp::no_new_privs)) or
+    /// `CAP_SYS_ADMIN`.
+    pub fn restrict_self(&self) -> Result<(), Errno> {
+        unsafe {
+            crate::syscall2(
+                Sysno::landlock_restrict_self,
+                self.0 as SyscallWord,
+                0,
+            )
+        }
+        .map(drop)
+    }
+
+    /// The fd, which stays owned by `self`.
+    pub fn as_raw(&self) -> i32 {
+        self.0
+    }
+}
+
+impl Drop for Ruleset {
+    fn drop(&mut self) {
+        let _ = unsafe { close(self.0) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consts::{AT_FDCWD, O_CLOEXEC, O_PATH, O_RDONLY};
+    use crate::helpers::{
+        CloneArgs, CloneResult, PidFd, WEXITED, WaitStatus, clone3, exit_group,
+        openat,
+    };
+
+    #[test]
+    fn attr() {
+        assert_eq!(core::mem::size_of::<PathBeneathAttr>(), 12);
+        assert_eq!(core::mem::size_of::<RulesetAttr>(), 24);
+        let v1 = RulesetAttr::all(1);
+        assert_eq!(v1.handled_access_fs, (1 << 13) - 1);
+        assert_eq!((v1.handled_access_net, v1.scoped), (0, 0));
+        assert_eq!(v1.size(), 8);
+        let v6 = RulesetAttr::all(6);
+        assert_eq!(v6.handled_access_fs, (1 << 16) - 1);
+        assert_eq!(v6.handled_access_net, 3);
+        assert_eq!(v6.size(), 24);
+        assert_eq!(RulesetAttr::all(7), v6);
+        assert_eq!(RulesetAttr::all(0), RulesetAttr::default());
+    }
+
+    #[test]
+    fn sandbox() {
+        let abi = match landlock_abi() {
+            Ok(abi) => abi,
+            Err(Errno::ENOSYS | Errno::EOPNOTSUPP) => return,
+            Err(errno) => panic!("landlock_abi: {errno}"),
+        };
+        assert!(abi >= 1);
+        assert_eq!(
+            Ruleset::create(&RulesetAttr::default()).map(drop),
+            Err(Errno::ENOMSG)
+        );
+
+        let attr = RulesetAttr {
+            handled_access_fs: LANDLOCK_ACCESS_FS_READ_FILE,
+            ..RulesetAttr::default()
+        };
+        let ruleset = Ruleset::create(&attr).unwrap();
+        let dev = openat(AT_FDCWD, c"/dev", O_PATH | O_CLOEXEC, 0).unwrap();
+        ruleset
+            .add_path_beneath(dev, LANDLOCK_ACCESS_FS_READ_FILE)
+            .unwrap();
+        // Not handled by the ruleset.
+        assert_eq!(
+            ruleset.add_path_beneath(dev, LANDLOCK_ACCESS_FS_WRITE_FILE),
+            Err(Errno::EINVAL)
+        );
+        unsafe { close(dev) }.unwrap();
+
+        // In a child, as the test threads share the process.
+        let mut pidfd = -1;
+        let args = CloneArgs::new().pidfd(&mut pidfd).exit_signal(17);
+        match unsafe { clone3(&args) } {
+            Ok(CloneResult::Child) => {
+                let status = if crate::seccomp::no_new_privs().is_err()
+                    || ruleset.restrict_self().is_err()
+                {
+                    1
+                } else if openat(AT_FDCWD, c"/dev/null", O_RDONLY, 0).is_err() {
+                    2
+                } else if openat(AT_FDCWD, c"/proc/self/stat", O_RDONLY, 0)
+                    != Err(Errno::EACCES)
+                {
+                    3
+                } else {
+                    0
+                };
+                exit_group(status);
+            }
+            Ok(CloneResult::Parent(_)) => {}
+            // Blocked by the container.
+            Err(Errno::ENOSYS | Errno::EPERM) => return,
+            Err(errno) => panic!("clone3: {errno}"),
+        }
+        let pidfd = unsafe { PidFd::from_raw(pidfd) };
+        assert_eq!(pidfd.wait(WEXITED), Ok(Some(WaitStatus::Exited(0))));
+    }
+}
@@ -0,0 +1,1501 @@
+//! Typed wrappers of common syscalls
+//!
+//! Thin functions for the syscalls that code using this crate instead of
+//! libc needs most, taking slices and `CStr`s instead of pointers and
+//! returning counts, fds, and offsets with their own types:
+//!
+//! ```no_run
+//! use rawsys_linux::consts::{AT_FDCWD, O_CLOEXEC, O_RDONLY};
+//! use rawsys_linux::helpers;
+//!
+//! let flags = O_RDONLY | O_CLOEXEC;
+//! let fd = helpers::openat(AT_FDCWD, c"/etc/hostname", flags, 0)?;
+//! let size = helpers::fstat(fd)?.size;
+//! let mut buf = [0u8; 64];
+//! let len = helpers::read(fd, &mut buf)?;
+//! unsafe { helpers::close(fd) }?;
+//! # Ok::<(), rawsys_linux::Errno>(())
+//! ```
+//!
+//! Fds are `i32`s, as in the kernel's interface, and flags are the `u32`s
+//! of [`consts`], except for the memory mappings, whose flags are the
+//! [`ProtFlags`] and [`MapFlags`] sets since their values differ the most
+//! between architectures. Offsets are 64 bits wide on every target: on
+//! 32-bit targets, the functions use the syscalls that take them in two
+//! words (`_llseek`, `preadv`, `statx`) or in pages (`mmap2`). Process,
+//! thread, user, and group ids are the types of [`id`](crate::id), such as
+//! [`Pid`] and [`Tid`], and the buffers of [`readv`], [`writev`], and the
+//! like are the [`IoVec`]s and [`IoVecMut`]s of [`iovec`](crate::iovec).
+//!
+//! Functions that can close an fd or unmap memory that something else owns
+//! are unsafe; the others, such as [`getpid`], are safe to call.
+//! [`MappedRegion`] owns a mapping and unmaps it when dropped, and
+//! [`clone3`] creates processes as a [`CloneArgs`] builder says, which
+//! [`PidFd`]s refer to without the races of pids. [`FsContext`] and
+//! [`MountFd`] build mounts with the mount API. A Landlock [`Ruleset`]
+//! sandboxes the calling thread, and the futex functions, such as
+//! [`futex_wait`], are the layer under locks and condition variables, such
+//! as [`RawMutex`], [`Once`], and [`Parker`], which work without `std`.
+//! Event loops wait on many fds at once with [`epoll_wait`], and on
+//! counters, timers, and signals as fds with [`eventfd`],
+//! [`timerfd_create`], and [`signalfd`], signal handlers are installed
+//! with [`install_handler`], and [`kill`] and [`tgkill`] send [`Signal`]s
+//! to processes and threads by their [`Pid`]s and [`Tid`]s. [`prctl`] takes
+//! its operations as a [`Prctl`] with their arguments, and [`capget`] and
+//! [`capset`] read and change the [`Capabilities`] of threads, and
+//! [`prlimit`] the limits of processes. [`sched_setattr`] sets how threads
+//! are scheduled, and [`sched_setaffinity`] pins them to the CPUs of a
+//! [`CpuSet`]. [`unshare`] and [`setns`] leave and join [`Namespaces`].
+//! Network services [`bind`] and [`connect`] [`socket`]s to addresses such
+//! as a [`SockaddrIn`], through `socketcall` on the 32-bit targets whose
+//! older kernels only have that, and [`sendmsg`] passes fds to other
+//! processes in [`ControlMessages`].
+//! [`copy_file_range_all`] and [`sendfile_all`] copy between fds in the
+//! kernel, with `read` and `write` where it can't, and [`pipe_pump`] moves
+//! data between them through a pipe with [`splice`].
+//! [`perf_event_open`] counts and samples the events of a [`PerfEventAttr`].
+//! [`CStrPath`] builds the paths that syscalls take on the stack, and
+//! [`getrandom`] fills buffers with random bytes.
+//!
+//! Wrappers of syscalls that are newer than the table of the
+//! `default_kernel_*` feature, such as `openat2` with `default_kernel_5_4`,
+//! are left out.
+
+use core::ffi::CStr;
+use core::fmt;
+use core::ptr::NonNull;
+use core::time::Duration;
+
+use crate::consts;
+use crate::iovec::RawIoVec;
+use crate::{Errno, SyscallWord, Sysno};
+
+/// Defines a set of flags, as a `u32`, or the integer after the name, with
+/// a constant per flag.
+macro_rules! flags {
+    (
+        $(#[$meta:meta])*
+        pub struct $name:ident {
+            $($flags:tt)*
+        }
+    ) => {
+        flags! {
+            $(#[$meta])*
+            pub struct $name: u32 {
+                $($flags)*
+            }
+        }
+    };
+    (
+        $(#[$meta:meta])*
+        pub struct $name:ident: $raw:ty {
+            $(
+                $(#[$flag_meta:meta])*
+                const $flag:ident = $value:expr;
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+        pub struct $name($raw);
+
+        impl $name {
+            $(
+                $(#[$flag_meta])*
+                pub const $flag: Self = Self($value);
+            )*
+
+            /// The flags that have names, with their names.
+            const NAMED: &[(&str, Self)] =
+                &[$((stringify!($flag), Self::$flag)),*];
+
+            /// No flags.
+            pub const fn empty() -> Self {
+                Self(0)
+            }
+
+            /// Makes a set of the bits of `bits`, including unknown ones.
+            pub const fn from_bits_retain(bits: $raw) -> Self {
+                Self(bits)
+            }
+
+            /// The bits of the flags, as passed to the kernel.
+            pub const fn bits(self) -> $raw {
+                self.0
+            }
+
+            /// Whether there are no flags.
+            pub const fn is_empty(self) -> bool {
+                self.0 == 0
+            }
+
+            /// Whether all of `other` is set.
+            pub const fn contains(self, other: Self) -> bool {
+                self.0 & other.0 == other.0
+            }
+
+            /// The flags that are set here but not in `other`.
+            #[must_use]
+            pub const fn difference(self, other: Self) -> Self {
+                Self(self.0 & !other.0)
+            }
+        }
+
+        impl core::ops::BitOr for $name {
+            type Output = Self;
+
+            fn bitor(self, other: Self) -> Self {
+                Self(self.0 | other.0)
+            }
+        }
+
+        impl core::ops::BitOrAssign for $name {
+            fn bitor_assign(&mut self, other: Self) {
+                self.0 |= other.0;
+            }
+        }
+
+        impl core::ops::BitAnd for $name {
+            type Output = Self;
+
+            fn bitand(self, other: Self) -> Self {
+                Self(self.0 & other.0)
+            }
+        }
+
+        impl core::fmt::Debug for $name {
+            /// Shows the names of the flags, e.g. `ProtFlags(READ | WRITE)`,
+            /// and the unknown bits in hex.
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let named = Self::NAMED
+                    .iter()
+                    .map(|&(name, flag)| (name, u64::from(flag.0)));
+                $crate::helpers::write_flags(
+                    f,
+                    stringify!($name),
+                    u64::from(self.0),
+                    named,
+                )
+            }
+        }
+    };
+}
+
+mod caps;
+mod clone;
+mod copy;
+mod epoll;
+mod eventfd;
+mod futex;
+#[cfg(not(any(
+    feature = "default_kernel_5_4",
+    feature = "default_kernel_5_10"
+)))]
+mod landlock;
+mod mount;
+mod msg;
+mod ns;
+mod path;
+mod perf;
+mod pidfd;
+mod prctl;
+mod random;
+mod region;
+mod rlimit;
+mod sched;
+// sparc takes the trampoline as an argument of `rt_sigaction` instead.
+#[cfg(not(any(target_arch = "sparc", target_arch = "sparc64")))]
+mod sigaction;
+mod siginfo;
+mod signal;
+mod signalfd;
+mod socket;
+mod socketcall;
+mod splice;
+mod sync;
+mod time;
+mod timerfd;
+mod wait;
+
+pub use crate::id::{Fd, Gid, Pid, Tid, Uid};
+pub use crate::iovec::{IoVec, IoVecMut};
+pub use crate::siginfo::{SYS_SECCOMP, SYS_USER_DISPATCH, Siginfo, Sigsys};
+pub use crate::time::{
+    CLOCK_BOOTTIME, CLOCK_BOOTTIME_ALARM, CLOCK_MONOTONIC,
+    CLOCK_MONOTONIC_COARSE, CLOCK_MONOTONIC_RAW, CLOCK_PROCESS_CPUTIME_ID,
+    CLOCK_REALTIME, CLOCK_REALTIME_ALARM, CLOCK_REALTIME_COARSE, CLOCK_TAI,
+    CLOCK_THREAD_CPUTIME_ID, Timespec, Timeval,
+};
+
+pub use self::caps::{
+    _LINUX_CAPABILITY_VERSION_3, CAP_AUDIT_CONTROL, CAP_AUDIT_READ,
+    CAP_AUDIT_WRITE, CAP_BLOCK_SUSPEND, CAP_BPF, CAP_CHECKPOINT_RESTORE,
+    CAP_CHOWN, CAP_DAC_OVERRIDE, CAP_DAC_READ_SEARCH, CAP_FOWNER, CAP_FSETID,
+    CAP_IPC_LOCK, CAP_IPC_OWNER, CAP_KILL, CAP_LAST_CAP, CAP_LEASE,
+    CAP_LINUX_IMMUTABLE, CAP_MAC_ADMIN, CAP_MAC_OVERRIDE, CAP_MKNOD,
+    CAP_NET_ADMIN, CAP_NET_BIND_SERVICE, CAP_NET_BROADCAST, CAP_NET_RAW,
+    CAP_PERFMON, CAP_SETFCAP, CAP_SETGID, CAP_SETPCAP, CAP_SETUID,
+    CAP_SYS_ADMIN, CAP_SYS_BOOT, CAP_SYS_CHROOT, CAP_SYS_MODULE, CAP_SYS_NICE,
+    CAP_SYS_PACCT, CAP_SYS_PTRACE, CAP_SYS_RAWIO, CAP_SYS_RESOURCE,
+    CAP_SYS_TIME, CAP_SYS_TTY_CONFIG, CAP_SYSLOG, CAP_WAKE_ALARM, CapSet,
+    CapUserData, CapUserHeader, Capabilities, capget, capset,
+};
+pub use self::clone::{CloneArgs, CloneResult, clone3};
+pub use self::copy::{
+    copy_file_range, copy_file_range_all, sendfile, sendfile_all,
+};
+#[cfg(not(any(
+    feature = "default_kernel_5_4",
+    feature = "default_kernel_5_10"
+)))]
+pub use self::epoll::epoll_pwait2;
+pub use self::epoll::{
+    EPOLL_CLOEXEC, EPOLL_CTL_ADD, EPOLL_CTL_DEL, EPOLL_CTL_MOD, EpollEvent,
+    epoll_create1, epoll_ctl, epoll_wait,
+};
+pub use self::eventfd::{
+    EFD_CLOEXEC, EFD_NONBLOCK, EFD_SEMAPHORE, eventfd, eventfd_read,
+    eventfd_write,
+};
+pub use self::futex::{
+    FUTEX_BITSET_MATCH_ANY, FUTEX_CLOCK_REALTIME, FUTEX_CMP_REQUEUE,
+    FUTEX_CMP_REQUEUE_PI, FUTEX_LOCK_PI, FUTEX_LOCK_PI2, FUTEX_OWNER_DIED,
+    FUTEX_PRIVATE_FLAG, FUTEX_REQUEUE, FUTEX_TID_MASK, FUTEX_TRYLOCK_PI,
+    FUTEX_UNLOCK_PI, FUTEX_WAIT, FUTEX_WAIT_BITSET, FUTEX_WAIT_REQUEUE_PI,
+    FUTEX_WAITERS, FUTEX_WAITV_MAX, FUTEX_WAKE, FUTEX_WAKE_BITSET,
+    FUTEX_WAKE_OP, FUTEX2_NUMA, FUTEX2_PRIVATE, FUTEX2_SIZE_U8,
+    FUTEX2_SIZE_U16, FUTEX2_SIZE_U32, FUTEX2_SIZE_U64, futex_requeue,
+    futex_wait, futex_wake,
+};
+#[cfg(not(any(
+    feature = "default_kernel_5_4",
+    feature = "default_kernel_5_10",
+    feature = "default_kernel_5_15"
+)))]
+pub use self::futex::{FutexWaitv, futex_waitv};
+#[cfg(not(any(
+    feature = "default_kernel_5_4",
+    feature = "default_kernel_5_10"
+)))]
+pub use self::landlock::{
+    LANDLOCK_ACCESS_FS_EXECUTE, LANDLOCK_ACCESS_FS_IOCTL_DEV,
+    LANDLOCK_ACCESS_FS_MAKE_BLOCK, LANDLOCK_ACCESS_FS_MAKE_CHAR,
+    LANDLOCK_ACCESS_FS_MAKE_DIR, LANDLOCK_ACCESS_FS_MAKE_FIFO,
+    LANDLOCK_ACCESS_FS_MAKE_REG, LANDLOCK_ACCESS_FS_MAKE_SOCK,
+    LANDLOCK_ACCESS_FS_MAKE_SYM, LANDLOCK_ACCESS_FS_READ_DIR,
+    LANDLOCK_ACCESS_FS_READ_FILE, LANDLOCK_ACCESS_FS_REFER,
+    LANDLOCK_ACCESS_FS_REMOVE_DIR, LANDLOCK_ACCESS_FS_REMOVE_FILE,
+    LANDLOCK_ACCESS_FS_TRUNCATE, LANDLOCK_ACCESS_FS_WRITE_FILE,
+    LANDLOCK_ACCESS_NET_BIND_TCP, LANDLOCK_ACCESS_NET_CONNECT_TCP,
+    LANDLOCK_SCOPE_ABSTRACT_UNIX_SOCKET, LANDLOCK_SCOPE_SIGNAL, Ruleset,
+    RulesetAttr, landlock_abi,
+};
+pub use self::mount::{FsContext, FsError, MountFd, move_mount};
+#[cfg(not(any(
+    feature = "default_kernel_5_4",
+    feature = "default_kernel_5_10"
+)))]
+pub use self::mount::{MountAttr, mount_setattr};
+pub use self::msg::{
+    ControlMessage, ControlMessageIter, ControlMessages, MSG_CMSG_CLOEXEC,
+    MSG_CTRUNC, MSG_DONTWAIT, MSG_EOR, MSG_NOSIGNAL, MSG_OOB, MSG_PEEK,
+    MSG_TRUNC, MSG_WAITALL, RecvMsg, Rights, SCM_CREDENTIALS, SCM_MAX_FD,
+    SCM_RIGHTS, Ucred, cmsg_align, cmsg_len, cmsg_space, recvmsg, sendmsg,
+};
+pub use self::ns::{Namespace, Namespaces, ns_path, open_ns, setns, unshare};
+pub use self::path::{CStrPath, fd_path, proc_fd_path, readlinkat};
+pub use self::perf::{
+    PERF_COUNT_HW_BRANCH_INSTRUCTIONS, PERF_COUNT_HW_BRANCH_MISSES,
+    PERF_COUNT_HW_BUS_CYCLES, PERF_COUNT_HW_CACHE_BPU,
+    PERF_COUNT_HW_CACHE_DTLB, PERF_COUNT_HW_CACHE_ITLB,
+    PERF_COUNT_HW_CACHE_L1D, PERF_COUNT_HW_CACHE_L1I, PERF_COUNT_HW_CACHE_LL,
+    PERF_COUNT_HW_CACHE_MISSES, PERF_COUNT_HW_CACHE_NODE,
+    PERF_COUNT_HW_CACHE_OP_PREFETCH, PERF_COUNT_HW_CACHE_OP_READ,
+    PERF_COUNT_HW_CACHE_OP_WRITE, PERF_COUNT_HW_CACHE_REFERENCES,
+    PERF_COUNT_HW_CACHE_RESULT_ACCESS, PERF_COUNT_HW_CACHE_RESULT_MISS,
+    PERF_COUNT_HW_CPU_CYCLES, PERF_COUNT_HW_INSTRUCTIONS,
+    PERF_COUNT_HW_REF_CPU_CYCLES, PERF_COUNT_HW_STALLED_CYCLES_BACKEND,
+    PERF_COUNT_HW_STALLED_CYCLES_FRONTEND, PERF_COUNT_SW_ALIGNMENT_FAULTS,
+    PERF_COUNT_SW_CONTEXT_SWITCHES, PERF_COUNT_SW_CPU_CLOCK,
+    PERF_COUNT_SW_CPU_MIGRATIONS, PERF_COUNT_SW_DUMMY,
+    PERF_COUNT_SW_EMULATION_FAULTS, PERF_COUNT_SW_PAGE_FAULTS,
+    PERF_COUNT_SW_PAGE_FAULTS_MAJ, PERF_COUNT_SW_PAGE_FAULTS_MIN,
+    PERF_COUNT_SW_TASK_CLOCK, PERF_EVENT_IOC_DISABLE, PERF_EVENT_IOC_ENABLE,
+    PERF_EVENT_IOC_ID, PERF_EVENT_IOC_PERIOD, PERF_EVENT_IOC_REFRESH,
+    PERF_EVENT_IOC_RESET, PERF_FLAG_FD_CLOEXEC, PERF_FLAG_FD_NO_GROUP,
+    PERF_FLAG_FD_OUTPUT, PERF_FLAG_PID_CGROUP, PERF_FORMAT_GROUP,
+    PERF_FORMAT_ID, PERF_FORMAT_LOST, PERF_FORMAT_TOTAL_TIME_ENABLED,
+    PERF_FORMAT_TOTAL_TIME_RUNNING, PERF_IOC_FLAG_GROUP, PERF_RECORD_COMM,
+    PERF_RECORD_EXIT, PERF_RECORD_FORK, PERF_RECORD_LOST, PERF_RECORD_MMAP,
+    PERF_RECORD_MMAP2, PERF_RECORD_SAMPLE, PERF_SAMPLE_ADDR,
+    PERF_SAMPLE_BRANCH_STACK, PERF_SAMPLE_CALLCHAIN, PERF_SAMPLE_CPU,
+    PERF_SAMPLE_ID, PERF_SAMPLE_IP, PERF_SAMPLE_PERIOD, PERF_SAMPLE_RAW,
+    PERF_SAMPLE_READ, PERF_SAMPLE_REGS_USER, PERF_SAMPLE_STACK_USER,
+    PERF_SAMPLE_STREAM_ID, PERF_SAMPLE_TID, PERF_SAMPLE_TIME,
+    PERF_TYPE_BREAKPOINT, PERF_TYPE_HARDWARE, PERF_TYPE_HW_CACHE,
+    PERF_TYPE_RAW, PERF_TYPE_SOFTWARE, PERF_TYPE_TRACEPOINT, PerfEventAttr,
+    PerfEventFlags, PerfEventHeader, PerfEventMmapPage, hw_cache_config,
+    perf_event_open,
+};
+pub use self::pidfd::PidFd;
+pub use self::prctl::{CapAmbient, Prctl, SeccompMode, prctl};
+pub use self::random::getrandom;
+pub use self::region::MappedRegion;
+pub use self::rlimit::{
+    RLIM_INFINITY, RLIMIT_AS, RLIMIT_CORE, RLIMIT_CPU, RLIMIT_DATA,
+    RLIMIT_FSIZE, RLIMIT_LOCKS, RLIMIT_MEMLOCK, RLIMIT_MSGQUEUE, RLIMIT_NICE,
+    RLIMIT_NOFILE, RLIMIT_NPROC, RLIMIT_RSS, RLIMIT_RTPRIO, RLIMIT_RTTIME,
+    RLIMIT_SIGPENDING, RLIMIT_STACK, Rlimit, getrlimit, prlimit, setrlimit,
+};
+pub use self::sched::{
+    CpuSet, SCHED_BATCH, SCHED_DEADLINE, SCHED_FIFO, SCHED_FLAG_DL_OVERRUN,
+    SCHED_FLAG_KEEP_PARAMS, SCHED_FLAG_KEEP_POLICY, SCHED_FLAG_RECLAIM,
+    SCHED_FLAG_RESET_ON_FORK, SCHED_FLAG_UTIL_CLAMP_MAX,
+    SCHED_FLAG_UTIL_CLAMP_MIN, SCHED_IDLE, SCHED_NORMAL, SCHED_RR, SchedAttr,
+    sched_getaffinity, sched_getattr, sched_setaffinity, sched_setattr,
+};
+#[cfg(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "arm"
+))]
+pub use self::sigaction::SA_RESTORER;
+#[cfg(not(any(target_arch = "sparc", target_arch = "sparc64")))]
+pub use self::sigaction::{
+    KernelSigaction, SA_NOCLDSTOP, SA_NOCLDWAIT, SA_NODEFER, SA_ONSTACK,
+    SA_RESETHAND, SA_RESTART, SA_SIGINFO, SigHandler, install_handler,
+    sigaction,
+};
+pub use self::siginfo::{
+    CLD_CONTINUED, CLD_DUMPED, CLD_EXITED, CLD_KILLED, CLD_STOPPED,
+    CLD_TRAPPED, SEGV_ACCERR, SEGV_MAPERR, SI_KERNEL, SI_QUEUE, SI_TIMER,
+    SI_TKILL, SI_USER, sigqueueinfo, sigtimedwait, tgsigqueueinfo,
+};
+pub use self::signal::{
+    KernelSigset, SIG_BLOCK, SIG_SETMASK, SIG_UNBLOCK, SIGABRT, SIGALRM,
+    SIGBUS, SIGCHLD, SIGCONT, SIGFPE, SIGHUP, SIGILL, SIGINT, SIGIO, SIGKILL,
+    SIGPIPE, SIGPROF, SIGPWR, SIGQUIT, SIGRTMAX, SIGRTMIN, SIGSEGV, SIGSTOP,
+    SIGSYS, SIGTERM, SIGTRAP, SIGTSTP, SIGTTIN, SIGTTOU, SIGURG, SIGUSR1,
+    SIGUSR2, SIGVTALRM, SIGWINCH, SIGXCPU, SIGXFSZ, Signal, kill, killpg,
+    sigpending, sigprocmask, tgkill, tkill,
+};
+pub use self::signalfd::{
+    SFD_CLOEXEC, SFD_NONBLOCK, SignalfdSiginfo, signalfd, signalfd_read,
+};
+pub use self::socket::{
+    AF_INET, AF_INET6, AF_NETLINK, AF_PACKET, AF_UNIX, AF_UNSPEC, SHUT_RD,
+    SHUT_RDWR, SHUT_WR, SO_BROADCAST, SO_ERROR, SO_KEEPALIVE, SO_PASSCRED,
+    SO_PEERCRED, SO_RCVBUF, SO_REUSEADDR, SO_REUSEPORT, SO_SNDBUF, SO_TYPE,
+    SOCK_CLOEXEC, SOCK_DGRAM, SOCK_NONBLOCK, SOCK_RAW, SOCK_SEQPACKET,
+    SOCK_STREAM, SOL_IP, SOL_IPV6, SOL_SOCKET, SOL_TCP, SOL_UDP, SockAddr,
+    SockaddrIn, SockaddrIn6, SockaddrNl, SockaddrStorage, SockaddrUn, accept4,
+    bind, connect, getpeername, getsockname, getsockopt, getsockopt_int,
+    listen, setsockopt, setsockopt_int, shutdown, socket, socketpair,
+};
+pub use self::socketcall::{
+    MSGCTL, MSGGET, MSGRCV, MSGSND, SEMCTL, SEMGET, SEMOP, SEMTIMEDOP, SHMAT,
+    SHMCTL, SHMDT, SHMGET, SYS_ACCEPT, SYS_ACCEPT4, SYS_BIND, SYS_CONNECT,
+    SYS_GETPEERNAME, SYS_GETSOCKNAME, SYS_GETSOCKOPT, SYS_LISTEN, SYS_RECV,
+    SYS_RECVFROM, SYS_RECVMMSG, SYS_RECVMSG, SYS_SEND, SYS_SENDMMSG,
+    SYS_SENDMSG, SYS_SENDTO, SYS_SETSOCKOPT, SYS_SHUTDOWN, SYS_SOCKET,
+    SYS_SOCKETPAIR,
+};
+#[cfg(any(
+    target_arch = "x86",
+    target_arch = "mips",
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+    target_arch = "s390x",
+    target_arch = "sparc",
+    target_arch = "sparc64"
+))]
+pub use self::socketcall::{ipc, socketcall};
+pub use self::splice::{
+    SPLICE_F_GIFT, SPLICE_F_MORE, SPLICE_F_MOVE, SPLICE_F_NONBLOCK, pipe_pump,
+    splice, tee, vmsplice,
+};
+pub use self::sync::{Once, Parker, RawMutex};
+pub use self::time::{
+    TIMER_ABSTIME, clock_getres, clock_gettime, clock_nanosleep, clock_settime,
+    sleep_for, sleep_until,
+};
+pub use self::timerfd::{
+    ItimerSpec, TFD_CLOEXEC, TFD_NONBLOCK, TFD_TIMER_ABSTIME,
+    TFD_TIMER_CANCEL_ON_SET, timerfd_create, timerfd_gettime, timerfd_read,
+    timerfd_settime,
+};
+pub use self::wait::{
+    __WALL, __WCLONE, __WNOTHREAD, P_ALL, P_PGID, P_PID, P_PIDFD, Rusage,
+    WCONTINUED, WEXITED, WNOHANG, WNOWAIT, WSTOPPED, WUNTRACED, WaitStatus,
+    wait4, waitid,
+};
+
+/// `whence` of [`lseek`]: the offset is from the start of the file.
+pub const SEEK_SET: u32 = 0;
+/// `whence` of [`lseek`]: the offset is from the current position.
+pub const SEEK_CUR: u32 = 1;
+/// `whence` of [`lseek`]: the offset is from the end of the file.
+pub const SEEK_END: u32 = 2;
+/// `whence` of [`lseek`]: the next data at or after the offset.
+pub const SEEK_DATA: u32 = 3;
+/// `whence` of [`lseek`]: the next hole at or after the offset.
+pub const SEEK_HOLE: u32 = 4;
+
+/// `mask` of [`statx`]: `mode & S_IFMT`.
+pub const STATX_TYPE: u32 = 0x1;
+/// `mask` of [`statx`]: `mode & !S_IFMT`.
+pub const STATX_MODE: u32 = 0x2;
+/// `mask` of [`statx`]: `nlink`.
+pub const STATX_NLINK: u32 = 0x4;
+/// `mask` of [`statx`]: `uid`.
+pub const STATX_UID: u32 = 0x8;
+/// `mask` of [`statx`]: `gid`.
+pub const STATX_GID: u32 = 0x10;
+/// `mask` of [`statx`]: `atime`.
+pub const STATX_ATIME: u32 = 0x20;
+/// `mask` of [`statx`]: `mtime`.
+pub const STATX_MTIME: u32 = 0x40;
+/// `mask` of [`statx`]: `ctime`.
+pub const STATX_CTIME: u32 = 0x80;
+/// `mask` of [`statx`]: `ino`.
+pub const STATX_INO: u32 = 0x100;
+/// `mask` of [`statx`]: `size`.
+pub const STATX_SIZE: u32 = 0x200;
+/// `mask` of [`statx`]: `blocks`.
+pub const STATX_BLOCKS: u32 = 0x400;
+/// `mask` of [`statx`]: the fields that [`fstat`] asks for, which are those
+/// of `struct stat`.
+pub const STATX_BASIC_STATS: u32 = 0x7ff;
+/// `mask` of [`statx`]: `btime`, the creation time.
+pub const STATX_BTIME: u32 = 0x800;
+/// `mask` of [`statx`]: `mnt_id` (Linux 5.8).
+pub const STATX_MNT_ID: u32 = 0x1000;
+/// `mask` of [`statx`]: `dio_mem_align` and `dio_offset_align` (Linux
+/// 6.1).
+pub const STATX_DIOALIGN: u32 = 0x2000;
+/// `mask` of [`statx`]: `mnt_id` as the unique id of `statmount` (Linux
+/// 6.8).
+pub const STATX_MNT_ID_UNIQUE: u32 = 0x4000;
+
+/// `advice` of [`madvise`]: no special treatment.
+pub const MADV_NORMAL: u32 = 0;
+/// `advice` of [`madvise`]: expect random accesses.
+pub const MADV_RANDOM: u32 = 1;
+/// `advice` of [`madvise`]: expect sequential accesses.
+pub const MADV_SEQUENTIAL: u32 = 2;
+/// `advice` of [`madvise`]: expect accesses soon.
+pub const MADV_WILLNEED: u32 = 3;
+/// `advice` of [`madvise`]: free the pages now; private anonymous pages
+/// read as zeros afterwards.
+pub const MADV_DONTNEED: u32 = 4;
+/// `advice` of [`madvise`]: free the pages when memory is needed.
+pub const MADV_FREE: u32 = 8;
+/// `advice` of [`madvise`]: don't copy the pages into a child on `fork`.
+pub const MADV_DONTFORK: u32 = 10;
+/// `advice` of [`madvise`]: undoes `MADV_DONTFORK`.
+pub const MADV_DOFORK: u32 = 11;
+/// `advice` of [`madvise`]: back the pages with transparent huge pages.
+pub const MADV_HUGEPAGE: u32 = 14;
+/// `advice` of [`madvise`]: undoes `MADV_HUGEPAGE`.
+pub const MADV_NOHUGEPAGE: u32 = 15;
+/// `advice` of [`madvise`]: leave the pages out of core dumps.
+pub const MADV_DONTDUMP: u32 = 16;
+/// `advice` of [`madvise`]: undoes `MADV_DONTDUMP`.
+pub const MADV_DODUMP: u32 = 17;
+
+/// Writes `bits` as `name(FLAG | FLAG | 0x...)`.
+fn write_flags(
+    f: &mut fmt::Formatter<'_>,
+    name: &str,
+    bits: u64,
+    named: impl Iterator<Item = (&'static str, u64)>,
+) -> fmt::Result {
+    write!(f, "{name}(")?;
+    let mut remaining = bits;
+    let mut first = true;
+    for (flag, value) in named {
+        // Like `bitflags`, a flag that only has bits already shown is left
+        // out.
+        if value == 0 || bits & value != value || remaining & value == 0 {
+            continue;
+        }
+        remaining &= !value;
+        if !first {
+            f.write_str(" | ")?;
+        }
+        first = false;
+        f.write_str(flag)?;
+    }
+    if remaining != 0 {
+        if !first {
+            f.write_str(" | ")?;
+        }
+        first = false;
+        write!(f, "{remaining:#x}")?;
+    }
+    if first {
+        f.write_str("empty")?;
+    }
+    f.write_str(")")
+}
+
+flags! {
+    /// The access of a memory mapping (`PROT_*`), for [`mmap`] and
+    /// [`mprotect`]. No flags is `PROT_NONE`.
+    pub struct ProtFlags {
+        const READ = consts::PROT_READ;
+        const WRITE = consts::PROT_WRITE;
+        const EXEC = consts::PROT_EXEC;
+        /// For [`mprotect`]: extends to the start of a `GROWSDOWN` mapping.
+        const GROWSDOWN = consts::PROT_GROWSDOWN;
+        /// For [`mprotect`]: extends to the end of a `GROWSUP` mapping.
+        const GROWSUP = consts::PROT_GROWSUP;
+    }
+}
+
+flags! {
+    /// The kind and options of a memory mapping (`MAP_*`), for [`mmap`],
+    /// with the values of the target, which differ on mips, powerpc, and
+    /// sparc. It needs one of `SHARED`, `PRIVATE`, and `SHARED_VALIDATE`.
+    pub struct MapFlags {
+        const SHARED = consts::MAP_SHARED;
+        const PRIVATE = consts::MAP_PRIVATE;
+        /// `SHARED`, failing with `EOPNOTSUPP` for unknown flags.
+        const SHARED_VALIDATE = consts::MAP_SHARED_VALIDATE;
+        /// Maps at exactly `addr`, replacing what was there.
+        const FIXED = consts::MAP_FIXED;
+        /// Maps at exactly `addr`, failing with `EEXIST` if something is
+        /// there (Linux 4.17).
+        const FIXED_NOREPLACE = consts::MAP_FIXED_NOREPLACE;
+        /// Not backed by a file; `fd` should be -1 and `offset` 0.
+        const ANONYMOUS = consts::MAP_ANONYMOUS;
+        const NORESERVE = consts::MAP_NORESERVE;
+        const POPULATE = consts::MAP_POPULATE;
+        const NONBLOCK = consts::MAP_NONBLOCK;
+        const LOCKED = consts::MAP_LOCKED;
+        const STACK = consts::MAP_STACK;
+        const GROWSDOWN = consts::MAP_GROWSDOWN;
+        const HUGETLB = consts::MAP_HUGETLB;
+    }
+}
+
+flags! {
+    /// The events of an [`EpollEvent`] (`EPOLL*`), which are also those of
+    /// `poll`, and the options of how they are waited for.
+    pub struct EpollFlags {
+        /// There is data to read.
+        const IN = 0x1;
+        /// There is urgent data to read.
+        const PRI = 0x2;
+        /// Writing won't block.
+        const OUT = 0x4;
+        /// An error happened; always waited for.
+        const ERR = 0x8;
+        /// The other end hung up; always waited for.
+        const HUP = 0x10;
+        const RDNORM = 0x40;
+        const RDBAND = 0x80;
+        const WRNORM = 0x100;
+        const WRBAND = 0x200;
+        const MSG = 0x400;
+        /// The other end of a stream socket stopped writing.
+        const RDHUP = 0x2000;
+        /// Wakes only some of the epoll instances that wait on the same fd.
+        const EXCLUSIVE = 1 << 28;
+        /// Keeps the system awake until the events are waited for.
+        const WAKEUP = 1 << 29;
+        /// Stops waiting for the fd after an event, until `EPOLL_CTL_MOD`.
+        const ONESHOT = 1 << 30;
+        /// Edge-triggered: only reports changes.
+        const ET = 1 << 31;
+    }
+}
+
+flags! {
+    /// The options of [`getrandom`] (`GRND_*`).
+    pub struct GrndFlags {
+        /// Fails with `EAGAIN` instead of waiting for the CSPRNG to be
+        /// seeded.
+        const NONBLOCK = 0x1;
+        /// Reads from the blocking pool, which is the same as the CSPRNG
+        /// since Linux 5.6.
+        const RANDOM = 0x2;
+        /// Doesn't wait for the CSPRNG to be seeded (Linux 5.6).
+        const INSECURE = 0x4;
+    }
+}
+
+/// A timestamp of a [`Statx`] (`struct statx_timestamp`).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StatxTimestamp {
+    pub sec: i64,
+    pub nsec: u32,
+    reserved: i32,
+}
+
+impl StatxTimestamp {
+    /// The time since the Unix epoch, or `None` if it is before it.
+    pub fn since_epoch(&self) -> Option<Duration> {
+        let sec = u64::try_from(self.sec).ok()?;
+        Some(Duration::new(sec, self.nsec))
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<StatxTimestamp> for std::time::SystemTime {
+    fn from(time: StatxTimestamp) -> Self {
+        let nsec = Duration::from_nanos(u64::from(time.nsec));
+        let sec = Duration::from_secs(time.sec.unsigned_abs());
+        if time.sec >= 0 {
+            Self::UNIX_EPOCH + sec + nsec
+        } else {
+            Self::UNIX_EPOCH - sec + nsec
+        }
+    }
+}
+
+/// The attributes of a file (`struct statx`), of which `mask` tells which
+/// were filled in.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Statx {
+    pub mask: u32,
+    pub blksize: u32,
+    pub attributes: u64,
+    pub nlink: u32,
+    pub uid: u32,
+    pub gid: u32,
+    /// The file type and permissions (`S_IF*` and mode bits).
+    pub mode: u16,
+    spare0: u16,
+    pub ino: u64,
+    pub size: u64,
+    pub blocks: u64,
+    pub attributes_mask: u64,
+    pub atime: StatxTimestamp,
+    pub btime: StatxTimestamp,
+    pub ctime: StatxTimestamp,
+    pub mtime: StatxTimestamp,
+    pub rdev_major: u32,
+    pub rdev_minor: u32,
+    pub dev_major: u32,
+    pub dev_minor: u32,
+    pub mnt_id: u64,
+    pub dio_mem_align: u32,
+    pub dio_offset_align: u32,
+    /// Fields of newer kernels.
+    spare: [u64; 12],
+}
+
+/// Reads from `fd` into `buf`, and returns the number of bytes read, which
+/// is 0 at the end of the file.
+pub fn read(fd: i32, buf: &mut [u8]) -> Result<usize, Errno> {
+    let read = unsafe {
+        crate::syscall3(
+            Sysno::read,
+            fd as SyscallWord,
+            buf.as_mut_ptr() as SyscallWord,
+            buf.len() as SyscallWord,
+        )
+    }?;
+    Ok(read as usize)
+}
+
+/// Writes `buf` to `fd`, and returns the number of bytes written.
+pub fn write(fd: i32, buf: &[u8]) -> Result<usize, Errno> {
+    let written = unsafe {
+        crate::syscall3(
+            Sysno::write,
+            fd as SyscallWord,
+            buf.as_ptr() as SyscallWord,
+            buf.len() as SyscallWord,
+        )
+    }?;
+    Ok(written as usize)
+}
+
+/// Reads from `fd` at `offset` into `buf`, without moving the file
+/// position, like `pread64`.
+pub fn pread(fd: i32, buf: &mut [u8], offset: u64) -> Result<usize, Errno> {
+    let iov = IoVecMut::new(buf).as_raw();
+    vectored(Sysno::preadv, fd, &[iov], offset, 0)
+}
+
+/// Writes `buf` to `fd` at `offset`, without moving the file position, like
+/// `pwrite64`.
+pub fn pwrite(fd: i32, buf: &[u8], offset: u64) -> Result<usize, Errno> {
+    let iov = IoVec::new(buf).as_raw();
+    vectored(Sysno::pwritev, fd, &[iov], offset, 0)
+}
+
+/// `flags` of [`preadv2`] and [`pwritev2`]: poll for the completion, on
+/// block devices and files opened with `O_DIRECT`.
+pub const RWF_HIPRI: u32 = 0x1;
+/// `flags` of [`pwritev2`]: write as with `O_DSYNC`.
+pub const RWF_DSYNC: u32 = 0x2;
+/// `flags` of [`pwritev2`]: write as with `O_SYNC`.
+pub const RWF_SYNC: u32 = 0x4;
+/// `flags` of [`preadv2`]: fail with `EAGAIN` instead of waiting for the
+/// storage device (Linux 4.14).
+pub const RWF_NOWAIT: u32 = 0x8;
+/// `flags` of [`pwritev2`]: write at the end of the file, as with
+/// `O_APPEND`, ignoring the offset (Linux 4.16).
+pub const RWF_APPEND: u32 = 0x10;
+
+/// Reads from `fd` into the buffers `bufs`, in order, and returns the
+/// number of bytes read.
+pub fn readv(fd: i32, bufs: &mut [IoVecMut<'_>]) -> Result<usize, Errno> {
+    let read = unsafe {
+        crate::syscall3(
+            Sysno::readv,
+            fd as SyscallWord,
+            IoVecMut::as_raw_slice(bufs).as_ptr() as SyscallWord,
+            bufs.len() as SyscallWord,
+        )
+    }?;
+    Ok(read as usize)
+}
+
+/// Writes the buffers `bufs` to `fd`, at once as far as the file is
+/// concerned, and returns the number of bytes written.
+pub fn writev(fd: i32, bufs: &[IoVec<'_>]) -> Result<usize, Errno> {
+    let written = unsafe {
+        crate::syscall3(
+            Sysno::writev,
+            fd as SyscallWord,
+            IoVec::as_raw_slice(bufs).as_ptr() as SyscallWord,
+            bufs.len() as SyscallWord,
+        )
+    }?;
+    Ok(written as usize)
+}
+
+/// Reads from `fd` into `bufs`, at `offset` without moving the file
+/// position, or at the file position if `offset` is `None`, with the flags
+/// `flags`, e.g. [`RWF_NOWAIT`] (Linux 4.6).
+pub fn preadv2(
+    fd: i32,
+    bufs: &mut [IoVecMut<'_>],
+    offset: Option<u64>,
+    flags: u32,
+) -> Result<usize, Errno> {
+    let offset = offset.unwrap_or(u64::MAX);
+    vectored(
+        Sysno::preadv2,
+        fd,
+        IoVecMut::as_raw_slice(bufs),
+        offset,
+        flags,
+    )
+}
+
+/// Writes `bufs` to `fd`, at `offset` without moving the file position, or
+/// at the file position if `offset` is `None`, with the flags `flags`,
+/// e.g. [`RWF_DSYNC`] (Linux 4.6).
+pub fn pwritev2(
+    fd: i32,
+    bufs: &[IoVec<'_>],
+    offset: Option<u64>,
+    flags: u32,
+) -> Result<usize, Errno> {
+    let offset = offset.unwrap_or(u64::MAX);
+    vectored(
+        Sysno::pwritev2,
+        fd,
+        IoVec::as_raw_slice(bufs),
+        offset,
+        flags,
+    )
+}
+
+/// `preadv`, `pwritev`, or their `*2` versions with `flags`, which take the
+/// offset as two words on every target, unlike `pread64` and `pwrite64`,
+/// and -1 for the file position.
+fn vectored(
+    sysno: Sysno,
+    fd: i32,
+    iovs: &[RawIoVec],
+    offset: u64,
+    flags: u32,
+) -> Result<usize, Errno> {
+    let transferred = unsafe {
+        crate::syscall6(
+            sysno,
+            fd as SyscallWord,
+            iovs.as_ptr() as SyscallWord,
+            iovs.len() as SyscallWord,
+            offset as SyscallWord,
+            (offset >> 32) as SyscallWord,
+            SyscallWord::from(flags),
+        )
+    }?;
+    Ok(transferred as usize)
+}
+
+/// Opens `path`, relative to the directory `dirfd` (or `AT_FDCWD`), and
+/// returns the new fd. `mode` is only used when a file is created.
+pub fn openat(
+    dirfd: i32,
+    path: &CStr,
+    flags: u32,
+    mode: u32,
+) -> Result<i32, Errno> {
+    let fd = unsafe {
+        crate::syscall4(
+            Sysno::openat,
+            dirfd as SyscallWord,
+            path.as_ptr() as SyscallWord,
+            SyscallWord::from(flags),
+            SyscallWord::from(mode),
+        )
+    }?;
+    Ok(fd as i32)
+}
+
+/// How to open a file with [`openat2`] (`struct open_how`, Linux 5.6).
+///
+/// The kernel takes the size of the struct along with it, so that it can
+/// grow; this is the first version, of 24 bytes.
+#[cfg(not(feature = "default_kernel_5_4"))]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct OpenHow {
+    /// The `O_*` flags. Unlike with [`openat`], unknown flags fail with
+    /// `EINVAL`.
+    pub flags: u64,
+    /// The mode of a created file, which must be 0 without `O_CREAT` and
+    /// `O_TMPFILE`.
+    pub mode: u64,
+    /// The `RESOLVE_*` flags, which restrict how `path` is resolved.
+    pub resolve: u64,
+}
+
+#[cfg(not(feature = "default_kernel_5_4"))]
+impl OpenHow {
+    /// Opens with `flags` (`O_*`).
+    pub const fn new(flags: u32) -> Self {
+        Self {
+            flags: flags as u64,
+            mode: 0,
+            resolve: 0,
+        }
+    }
+
+    /// Creates files with `mode`.
+    #[must_use]
+    pub const fn mode(mut self, mode: u32) -> Self {
+        self.mode = mode as u64;
+        self
+    }
+
+    /// Resolves the path with `resolve` (`RESOLVE_*`), e.g.
+    /// `RESOLVE_BENEATH` to stay in the directory.
+    #[must_use]
+    pub const fn resolve(mut self, resolve: u64) -> Self {
+        self.resolve = resolve;
+        self
+    }
+}
+
+/// Opens `path`, relative to the directory `dirfd` (or `AT_FDCWD`), as `how`
+/// says, and returns the new fd.
+///
+/// Kernels older than 5.6 lack `openat2`, in which case it falls back to
+/// [`openat`] if `how` has no `resolve` flags, which `openat` couldn't
+/// honor, and fails with `ENOSYS` otherwise.
+#[cfg(not(feature = "default_kernel_5_4"))]
+pub fn openat2(dirfd: i32, path: &CStr, how: &OpenHow) -> Result<i32, Errno> {
+    let fd = unsafe {
+        crate::syscall4(
+            Sysno::openat2,
+            dirfd as SyscallWord,
+            path.as_ptr() as SyscallWord,
+            core::ptr::from_ref(how) as SyscallWord,
+            core::mem::size_of::<OpenHow>() as SyscallWord,
+        )
+    };
+    match fd {
+        Ok(fd) => Ok(fd as i32),
+        Err(Errno::ENOSYS) if how.resolve == 0 => {
+            let flags = u32::try_from(how.flags).map_err(|_| Errno::EINVAL)?;
+            let mode = u32::try_from(how.mode).map_err(|_| Errno::EINVAL)?;
+            openat(dirfd, path, flags, mode)
+        }
+        Err(errno) => Err(errno),
+    }
+}
+
+/// Closes `fd`. The fd is closed even if this fails with `EINTR` or `EIO`,
+/// so it must not be closed again.
+///
+/// # Safety
+///
+/// Nothing else may use `fd` afterwards, such as a `File` that owns it.
+pub unsafe fn close(fd: i32) -> Result<(), Errno> {
+    unsafe { crate::syscall1(Sysno::close, fd as SyscallWord) }.map(drop)
+}
+
+/// Moves the file position of `fd` to `offset` from `whence` (`SEEK_*`),
+/// and returns the new position.
+#[cfg(target_pointer_width = "64")]
+pub fn lseek(fd: i32, offset: i64, whence: u32) -> Result<u64, Errno> {
+    let pos = unsafe {
+        crate::syscall3(
+            Sysno::lseek,
+            fd as SyscallWord,
+            offset as SyscallWord,
+            SyscallWord::from(whence),
+        )
+    }?;
+    Ok(pos)
+}
+
+/// Moves the file position of `fd` to `offset` from `whence` (`SEEK_*`),
+/// and returns the new position.
+#[cfg(target_pointer_width = "32")]
+pub fn lseek(fd: i32, offset: i64, whence: u32) -> Result<u64, Errno> {
+    // `llseek` has the number of `lseek` in the generic table.
+    #[cfg(target_arch = "riscv32")]
+    const LLSEEK: Sysno = Sysno::lseek;
+    #[cfg(not(target_arch = "riscv32"))]
+    const LLSEEK: Sysno = Sysno::_llseek;
+
+    let mut pos = 0u64;
+    unsafe {
+        crate::syscall5(
+            LLSEEK,
+            fd as SyscallWord,
+            (offset >> 32) as SyscallWord,
+            offset as SyscallWord,
+            core::ptr::from_mut(&mut pos) as SyscallWord,
+            SyscallWord::from(whence),
+        )
+    }?;
+    Ok(pos)
+}
+
+/// Returns the attributes of `path`, relative to the directory `dirfd` (or
+/// `AT_FDCWD`), that `mask` (`STATX_*`) asks for (Linux 4.11). `flags` are
+/// `AT_*` flags, such as `AT_SYMLINK_NOFOLLOW`, or `AT_EMPTY_PATH` for the
+/// file of `dirfd` itself. The kernel may fill in fewer fields or more, as
+/// `mask` of the result tells.
+pub fn statx(
+    dirfd: i32,
+    path: &CStr,
+    flags: i32,
+    mask: u32,
+) -> Result<Statx, Errno> {
+    let mut statx = Statx::default();
+    unsafe {
+        crate::syscall5(
+            Sysno::statx,
+            dirfd as SyscallWord,
+            path.as_ptr() as SyscallWord,
+            flags as SyscallWord,
+            SyscallWord::from(mask),
+            core::ptr::from_mut(&mut statx) as SyscallWord,
+        )
+    }?;
+    Ok(statx)
+}
+
+/// Returns the attributes of the file of `fd` that `struct stat` has, with
+/// [`statx`], whose layout is the same on every target.
+pub fn fstat(fd: i32) -> Result<Statx, Errno> {
+    statx(fd, c"", consts::AT_EMPTY_PATH, STATX_BASIC_STATS)
+}
+
+/// Duplicates `fd` to the lowest free fd, and returns it.
+pub fn dup(fd: i32) -> Result<i32, Errno> {
+    let fd = unsafe { crate::syscall1(Sysno::dup, fd as SyscallWord) }?;
+    Ok(fd as i32)
+}
+
+/// Duplicates `oldfd` to `newfd`, closing what `newfd` was first, with the
+/// `O_CLOEXEC` flag or none. Fails with `EINVAL` if they are the same.
+///
+/// # Safety
+///
+/// Nothing else may use what `newfd` was before.
+pub unsafe fn dup3(oldfd: i32, newfd: i32, flags: u32) -> Result<i32, Errno> {
+    let fd = unsafe {
+        crate::syscall3(
+            Sysno::dup3,
+            oldfd as SyscallWord,
+            newfd as SyscallWord,
+            SyscallWord::from(flags),
+        )
+    }?;
+    Ok(fd as i32)
+}
+
+/// Creates a pipe with the `O_CLOEXEC`, `O_NONBLOCK`, and `O_DIRECT` flags
+/// or none, and returns `[read_end, write_end]`.
+pub fn pipe2(flags: u32) -> Result<[i32; 2], Errno> {
+    let mut fds = [0i32; 2];
+    unsafe {
+        crate::syscall2(
+            Sysno::pipe2,
+            fds.as_mut_ptr() as SyscallWord,
+            SyscallWord::from(flags),
+        )
+    }?;
+    Ok(fds)
+}
+
+/// Flushes the data and metadata of `fd` to its storage device.
+pub fn fsync(fd: i32) -> Result<(), Errno> {
+    unsafe { crate::syscall1(Sysno::fsync, fd as SyscallWord) }.map(drop)
+}
+
+/// Maps `len` bytes of `fd` from `offset` (or anonymous memory), at `addr`
+/// or near it unless it is null, and returns where.
+///
+/// On 32-bit targets, `offset` must be a multiple of 4096, as `mmap2` takes
+/// it in units of 4096 bytes.
+///
+/// # Safety
+///
+/// With `FIXED`, the mapping replaces anything at `addr`, which nothing may
+/// use afterwards.
+pub unsafe fn mmap(
+    addr: *mut u8,
+    len: usize,
+    prot: ProtFlags,
+    flags: MapFlags,
+    fd: i32,
+    offset: u64,
+) -> Result<NonNull<u8>, Errno> {
+    // The kernel returns `MAP_FAILED` (-1) as `-errno` like other errors, so
+    // the checked wrapper turns it into `Err`.
+    #[cfg(all(target_pointer_width = "64", not(target_arch = "s390x")))]
+    let addr = unsafe {
+        crate::syscall6(
+            Sysno::mmap,
+            addr as SyscallWord,
+            len as SyscallWord,
+            SyscallWord::from(prot.bits()),
+            SyscallWord::from(flags.bits()),
+            fd as SyscallWord,
+            offset as SyscallWord,
+        )
+    }?;
+    // `mmap` takes a pointer to its arguments on s390x.
+    #[cfg(target_arch = "s390x")]
+    let addr = {
+        let args: [SyscallWord; 6] = [
+            addr as SyscallWord,
+            len as SyscallWord,
+            SyscallWord::from(prot.bits()),
+            SyscallWord::from(flags.bits()),
+            fd as SyscallWord,
+            offset as SyscallWord,
+        ];
+        unsafe { crate::syscall1(Sysno::mmap, args.as_ptr() as SyscallWord) }?
+    };
+    #[cfg(target_pointer_width = "32")]
+    let addr = {
+        // `mmap2` has the number of `mmap` in the generic table.
+        #[cfg(target_arch = "riscv32")]
+        const MMAP2: Sysno = Sysno::mmap;
+        #[cfg(not(target_arch = "riscv32"))]
+        const MMAP2: Sysno = Sysno::mmap2;
+
+        if offset % 4096 != 0 {
+            return Err(Errno::EINVAL);
+        }
+        let pages =
+            SyscallWord::try_from(offset / 4096).map_err(|_| Errno::EINVAL)?;
+        unsafe {
+            crate::syscall6(
+                MMAP2,
+                addr as SyscallWord,
+                len as SyscallWord,
+                prot.bits(),
+                flags.bits(),
+                fd as SyscallWord,
+                pages,
+            )
+        }?
+    };
+    NonNull::new(addr as *mut u8).ok_or(Errno::EINVAL)
+}
+
+/// Unmaps the pages of `len` bytes from `addr`, which must be page-aligned.
+///
+/// # Safety
+///
+/// Nothing may use the memory afterwards.
+pub unsafe fn munmap(addr: NonNull<u8>, len: usize) -> Result<(), Errno> {
+    unsafe {
+        crate::syscall2(
+            Sysno::munmap,
+            addr.as_ptr() as SyscallWord,
+            len as SyscallWord,
+        )
+    }
+    .map(drop)
+}
+
+/// Changes the access of the pages of `len` bytes from `addr`, which must be
+/// page-aligned.
+///
+/// # Safety
+///
+/// Nothing may access the memory in a way that `prot` no longer allows,
+/// such as Rust references to it without `READ`.
+pub unsafe fn mprotect(
+    addr: NonNull<u8>,
+    len: usize,
+    prot: ProtFlags,
+) -> Result<(), Errno> {
+    unsafe {
+        crate::syscall3(
+            Sysno::mprotect,
+            addr.as_ptr() as SyscallWord,
+            len as SyscallWord,
+            SyscallWord::from(prot.bits()),
+        )
+    }
+    .map(drop)
+}
+
+/// Gives `advice` (`MADV_*`) about the pages of `len` bytes from `addr`,
+/// which must be page-aligned.
+///
+/// # Safety
+///
+/// Some advice changes the contents of the memory, such as `MADV_DONTNEED`
+/// and `MADV_FREE`, which nothing may rely on afterwards.
+pub unsafe fn madvise(
+    addr: NonNull<u8>,
+    len: usize,
+    advice: u32,
+) -> Result<(), Errno> {
+    unsafe {
+        crate::syscall3(
+            Sysno::madvise,
+            addr.as_ptr() as SyscallWord,
+            len as SyscallWord,
+            SyscallWord::from(advice),
+        )
+    }
+    .map(drop)
+}
+
+/// `getuid` and friends, which return 16-bit ids on the 32-bit targets that
+/// have `getuid32` and friends.
+#[cfg(any(target_arch = "x86", target_arch = "arm", target_arch = "sparc"))]
+mod ids {
+    use crate::Sysno;
+
+    pub const GETUID: Sysno = Sysno::getuid32;
+    pub const GETEUID: Sysno = Sysno::geteuid32;
+    pub const GETGID: Sysno = Sysno::getgid32;
+    pub const GETEGID: Sysno = Sysno::getegid32;
+}
+#[cfg(not(any(
+    target_arch = "x86",
+    target_arch = "arm",
+    target_arch = "sparc"
+)))]
+mod ids {
+    use crate::Sysno;
+
+    pub const GETUID: Sysno = Sysno::getuid;
+    pub const GETEUID: Sysno = Sysno::geteuid;
+    pub const GETGID: Sysno = Sysno::getgid;
+    pub const GETEGID: Sysno = Sysno::getegid;
+}
+
+/// Makes a syscall that takes no arguments and can't fail.
+fn infallible(sysno: Sysno) -> SyscallWord {
+    unsafe { crate::syscall0(sysno) }.unwrap_or_default()
+}
+
+/// Returns the id of the calling process.
+pub fn getpid() -> Pid {
+    Pid::from_raw(infallible(Sysno::getpid) as i32)
+}
+
+/// Returns the id of the parent process, or 0 if it is outside of the
+/// caller's pid namespace.
+pub fn getppid() -> Pid {
+    Pid::from_raw(infallible(Sysno::getppid) as i32)
+}
+
+/// Returns the id of the calling thread.
+pub fn gettid() -> Tid {
+    Tid::from_raw(infallible(Sysno::gettid) as i32)
+}
+
+/// Returns the real user id of the calling process.
+pub fn getuid() -> Uid {
+    Uid::from_raw(infallible(ids::GETUID) as u32)
+}
+
+/// Returns the effective user id of the calling process.
+pub fn geteuid() -> Uid {
+    Uid::from_raw(infallible(ids::GETEUID) as u32)
+}
+
+/// Returns the real group id of the calling process.
+pub fn getgid() -> Gid {
+    Gid::from_raw(infallible(ids::GETGID) as u32)
+}
+
+/// Returns the effective group id of the calling process.
+pub fn getegid() -> Gid {
+    Gid::from_raw(infallible(ids::GETEGID) as u32)
+}
+
+/// Lets the other threads that are ready run before the calling thread.
+pub fn sched_yield() {
+    infallible(Sysno::sched_yield);
+}
+
+/// Starts writing the dirty data of all filesystems to their storage
+/// devices.
+pub fn sync() {
+    infallible(Sysno::sync);
+}
+
+/// Exits all threads of the process with `status`, without running any
+/// destructors or `atexit` handlers.
+pub fn exit_group(status: i32) -> ! {
+    let _ =
+        unsafe { crate::syscall1(Sysno::exit_group, status as SyscallWord) };
+    unreachable!("exit_group returned");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::format::Buffer;
+
+    #[test]
+    fn layout() {
+        assert_eq!(core::mem::size_of::<Statx>(), 0x100);
+        assert_eq!(core::mem::offset_of!(Statx, atime), 0x40);
+        assert_eq!(core::mem::offset_of!(Statx, rdev_major), 0x80);
+        assert_eq!(core::mem::offset_of!(Statx, dio_offset_align), 0x9c);
+    }
+
+    #[test]
+    fn times() {
+        use consts::{AT_FDCWD, AT_SYMLINK_NOFOLLOW};
+
+        let statx =
+            statx(AT_FDCWD, c"/", AT_SYMLINK_NOFOLLOW, STATX_MTIME).unwrap();
+        assert!(statx.mask & STATX_MTIME != 0);
+        // It's been a while.
+        let mtime = statx.mtime.since_epoch().unwrap();
+        assert!(mtime.as_secs() > 1_000_000_000);
+
+        let time = StatxTimestamp {
+            sec: -2,
+            nsec: 500_000_000,
+            ..Default::default()
+        };
+        assert_eq!(time.since_epoch(), None);
+
+        let timespec = Timespec {
+            sec: 1,
+            nsec: 999_999_999,
+        };
+        let sum = timespec + Duration::from_nanos(2);
+        assert_eq!((sum.sec, sum.nsec), (2, 1));
+        let max = Timespec::from(Duration::MAX) + Duration::from_secs(1);
+        assert_eq!(max.sec, i64::MAX);
+        let now = clock_gettime(CLOCK_MONOTONIC).unwrap();
+        assert!(now.sec > 0 && (0..1_000_000_000).contains(&now.nsec));
+        #[cfg(feature = "std")]
+        {
+            use std::time::{Duration, SystemTime};
+            assert_eq!(
+                SystemTime::from(time),
+                SystemTime::UNIX_EPOCH - Duration::from_millis(1500)
+            );
+            assert_eq!(
+                SystemTime::from(statx.mtime),
+                SystemTime::UNIX_EPOCH + mtime
+            );
+        }
+    }
+
+    #[test]
+    fn pipe() {
+        let [r, w] = pipe2(0).unwrap();
+        assert_eq!(write(w, b"hello"), Ok(5));
+        let mut buf = [0u8; 8];
+        assert_eq!(read(r, &mut buf), Ok(5));
+        assert_eq!(&buf[..5], b"hello");
+        assert_eq!(lseek(r, 0, SEEK_SET), Err(Errno::ESPIPE));
+        assert_eq!(writev(w, &[IoVec::new(b"ab"), IoVec::new(b"cd")]), Ok(4));
+        let (head, tail) = buf.split_at_mut(1);
+        let mut bufs = [IoVecMut::new(head), IoVecMut::new(&mut tail[..2])];
+        assert_eq!(readv(r, &mut bufs), Ok(3));
+        assert_eq!(&buf[..3], b"abc");
+
+        // S_IFIFO
+        assert_eq!(fstat(r).unwrap().mode & 0o170_000, 0o010_000);
+        let dup = dup(w).unwrap();
+        assert!(dup > w.max(r));
+        unsafe {
+            close(r).unwrap();
+            close(w).unwrap();
+            close(dup).unwrap();
+            assert_eq!(close(dup), Err(Errno::EBADF));
+        }
+        assert_eq!(read(r, &mut buf), Err(Errno::EBADF));
+    }
+
+    #[test]
+    fn file() {
+        let fd = openat(consts::AT_FDCWD, c"/proc/self/exe", 0, 0).unwrap();
+        let size = fstat(fd).unwrap().size;
+        assert!(size > 4);
+        let mut magic = [0u8; 4];
+        assert_eq!(pread(fd, &mut magic, 0), Ok(4));
+        assert_eq!(&magic, b"\x7fELF");
+        assert_eq!(lseek(fd, -1, SEEK_END), Ok(size - 1));
+        assert_eq!(lseek(fd, 0, SEEK_CUR), Ok(size - 1));
+        assert_eq!(pwrite(fd, b"x", 0), Err(Errno::EBADF));
+        let mut bufs = [IoVecMut::new(&mut magic[..3])];
+        assert_eq!(preadv2(fd, &mut bufs, Some(1), 0), Ok(3));
+        assert_eq!(&magic, b"ELFF");
+        let mut bufs = [IoVecMut::new(&mut magic)];
+        // At the end of the file.
+        assert_eq!(preadv2(fd, &mut bufs, None, 0), Ok(1));
+        assert_eq!(
+            preadv2(fd, &mut bufs, None, 1 << 31),
+            Err(Errno::EOPNOTSUPP)
+        );
+        unsafe { close(fd) }.unwrap();
+
+        assert_eq!(
+            openat(consts::AT_FDCWD, c"/nonexistent", 0, 0),
+            Err(Errno::ENOENT)
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "default_kernel_5_4"))]
+    fn resolve() {
+        use consts::{
+            AT_FDCWD, O_CLOEXEC, O_DIRECTORY, O_PATH, O_RDONLY,
+            RESOLVE_BENEATH, RESOLVE_NO_SYMLINKS,
+        };
+
+        assert_eq!(core::mem::size_of::<OpenHow>(), 24);
+        let dir = OpenHow::new(O_PATH | O_DIRECTORY | O_CLOEXEC);
+        let proc = openat2(AT_FDCWD, c"/proc", &dir).unwrap();
+        let how = OpenHow::new(O_RDONLY | O_CLOEXEC).resolve(RESOLVE_BENEATH);
+        let fd = openat2(proc, c"self/status", &how).unwrap();
+        assert!(fd >= 0);
+        assert_eq!(openat2(proc, c"../etc/hostname", &how), Err(Errno::EXDEV));
+        assert_eq!(openat2(proc, c"/etc/hostname", &how), Err(Errno::EXDEV));
+        // `self` is a symlink.
+        let how = how.resolve(RESOLVE_NO_SYMLINKS);
+        assert_eq!(openat2(proc, c"self/status", &how), Err(Errno::ELOOP));
+        // A mode without `O_CREAT`.
+        let how = OpenHow::new(O_RDONLY).mode(0o644);
+        assert_eq!(openat2(proc, c"self", &how), Err(Errno::EINVAL));
+        unsafe {
+            close(fd).unwrap();
+            close(proc).unwrap();
+        }
+    }
+
+    #[test]
+    fn mappings() {
+        let len = 2 * 4096;
+        let anon = MapFlags::PRIVATE | MapFlags::ANONYMOUS;
+        let rw = ProtFlags::READ | ProtFlags::WRITE;
+        unsafe {
+            let addr =
+                mmap(core::ptr::null_mut(), len, rw, anon, -1, 0).unwrap();
+            let mem = core::slice::from_raw_parts_mut(addr.as_ptr(), len);
+            mem[0] = 1;
+            madvise(addr, len, MADV_DONTNEED).unwrap();
+            assert_eq!(mem[0], 0);
+            mprotect(addr, len, ProtFlags::READ).unwrap();
+            assert_eq!(mem[len - 1], 0);
+
+            // Something is there now.
+            assert_eq!(
+                mmap(
+                    addr.as_ptr(),
+                    len,
+                    rw,
+                    anon | MapFlags::FIXED_NOREPLACE,
+                    -1,
+                    0
+                ),
+                Err(Errno::EEXIST)
+            );
+            munmap(addr, len).unwrap();
+            assert_eq!(
+                mmap(core::ptr::null_mut(), 0, rw, anon, -1, 0),
+                Err(Errno::EINVAL)
+            );
+        }
+    }
+
+    #[test]
+    fn flags() {
+        let flags = MapFlags::PRIVATE | MapFlags::ANONYMOUS;
+        assert!(flags.contains(MapFlags::ANONYMOUS));
+        assert!(!flags.contains(MapFlags::SHARED));
+        assert_eq!(flags.bits(), consts::MAP_PRIVATE | consts::MAP_ANONYMOUS);
+        assert_eq!(
+            Buffer::<64>::format(format_args!("{flags:?}")),
+            "MapFlags(PRIVATE | ANONYMOUS)"
+        );
+        assert_eq!(
+            Buffer::<64>::format(format_args!(
+                "{:?}",
+                MapFlags::SHARED_VALIDATE
+            )),
+            "MapFlags(SHARED | PRIVATE)"
+        );
+        assert_eq!(
+            Buffer::<64>::format(format_args!(
+                "{:?}",
+                ProtFlags::from_bits_retain(0x101)
+            )),
+            "ProtFlags(READ | 0x100)"
+        );
+        assert_eq!(
+            Buffer::<64>::format(format_args!("{:?}", ProtFlags::empty())),
+            "ProtFlags(empty)"
+        );
+    }
+
+    #[test]
+    fn ids() {
+        assert!(getpid().as_raw() > 0);
+        assert!(getppid().as_raw() >= 0);
+        assert!(gettid().as_raw() > 0);
+        let fd = openat(consts::AT_FDCWD, c"/proc/self/status", 0, 0).unwrap();
+        let mut buf = [0u8; 4096];
+        let len = read(fd, &mut buf).unwrap();
+        unsafe { close(fd) }.unwrap();
+        let status = core::str::from_utf8(&buf[..len]).unwrap();
+        let ids = |key| {
+            status
+                .lines()
+                .find_map(|line| line.strip_prefix(key))
+                .unwrap()
+                .split_ascii_whitespace()
+                .map(|id| id.parse::<u32>().unwrap())
+        };
+        let uids = [getuid(), geteuid()].map(Uid::as_raw);
+        assert!(ids("Uid:").take(2).eq(uids));
+        let gids = [getgid(), getegid()].map(Gid::as_raw);
+        assert!(ids("Gid:").take(2).eq(gids));
+        assert!(ids("PPid:").eq([getppid().as_raw() as u32]));
+        sched_yield();
+    }
+}
@@ -0,0 +1,405 @@
+//! The mount API of Linux 5.2, which builds mounts fd by fd.
+
+use core::ffi::CStr;
+use core::fmt;
+
+use super::{close, read};
+use crate::consts::MOVE_MOUNT_F_EMPTY_PATH;
+use crate::format::Buffer;
+use crate::{Errno, SyscallWord, Sysno};
+
+/// `cmd` of `fsconfig` (`enum fsconfig_command`).
+const FSCONFIG_SET_FLAG: u32 = 0;
+const FSCONFIG_SET_STRING: u32 = 1;
+const FSCONFIG_SET_BINARY: u32 = 2;
+const FSCONFIG_SET_PATH: u32 = 3;
+const FSCONFIG_SET_FD: u32 = 5;
+const FSCONFIG_CMD_CREATE: u32 = 6;
+const FSCONFIG_CMD_RECONFIGURE: u32 = 7;
+const FSCONFIG_CMD_CREATE_EXCL: u32 = 8;
+
+/// A failed operation on an [`FsContext`], with the message that the
+/// filesystem logged for it, if any.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FsError {
+    pub errno: Errno,
+    /// The last error message, e.g. `tmpfs: Bad value for 'size'`, which
+    /// is cut off after 96 bytes.
+    pub message: Option<Buffer<96>>,
+}
+
+impl fmt::Display for FsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.message {
+            Some(message) => write!(f, "{message}: {}", self.errno),
+            None => write!(f, "{}", self.errno),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FsError {}
+
+impl From<FsError> for Errno {
+    fn from(err: FsError) -> Self {
+        err.errno
+    }
+}
+
+/// A filesystem context (`fsopen`), which configures a new superblock or,
+/// with [`pick`](Self::pick), an existing one, and is closed when dropped:
+///
+/// ```no_run
+/// use rawsys_linux::consts::{AT_FDCWD, FSOPEN_CLOEXEC, FSMOUNT_CLOEXEC, MOUNT_ATTR_NODEV};
+/// use rawsys_linux::helpers::FsContext;
+///
+/// let fs = FsContext::open(c"tmpfs", FSOPEN_CLOEXEC)?;
+/// fs.set_string(c"size", c"16m")?;
+/// fs.set_string(c"mode", c"0700")?;
+/// fs.create()?;
+/// let mount = fs.mount(FSMOUNT_CLOEXEC, MOUNT_ATTR_NODEV)?;
+/// mount.move_to(AT_FDCWD, c"/mnt")?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// The errors of the filesystem come with the messages that it logs to the
+/// context, instead of just `EINVAL`.
+#[derive(Debug)]
+pub struct FsContext(i32);
+
+impl FsContext {
+    /// Creates a context for a new superblock of the filesystem type
+    /// `fs_name`, e.g. `tmpfs`, with `flags` (`FSOPEN_CLOEXEC`).
+    pub fn open(fs_name: &CStr, flags: u32) -> Result<Self, Errno> {
+        let fd = unsafe {
+            crate::syscall2(
+                Sysno::fsopen,
+                fs_name.as_ptr() as SyscallWord,
+                SyscallWord::from(flags),
+            )
+        }?;
+        Ok(Self(fd as i32))
+    }
+
+    /// Creates a context for the superblock mounted at `path`, relative to
+    /// `dirfd`, to [`reconfigure`](Self::reconfigure) it, with `flags`
+    /// (`FSPICK_*`).
+    pub fn pick(dirfd: i32, path: &CStr, flags: u32) -> Result<Self, Errno> {
+        let fd = unsafe {
+            crate::syscall3(
+                Sysno::fspick,
+                dirfd as SyscallWord,
+                path.as_ptr() as SyscallWord,
+                SyscallWord::from(flags),
+            )
+        }?;
+        Ok(Self(fd as i32))
+    }
+
+    /// Sets the flag parameter `key`, e.g. `ro`.
+    pub fn set_flag(&self, key: &CStr) -> Result<(), FsError> {
+        self.config(FSCONFIG_SET_FLAG, Some(key), 0, 0)
+    }
+
+    /// Sets the parameter `key` to `value`, e.g. `size` to `16m`.
+    pub fn set_string(&self, key: &CStr, value: &CStr) -> Result<(), FsError> {
+        let value = value.as_ptr() as SyscallWord;
+        self.config(FSCONFIG_SET_STRING, Some(key), value, 0)
+    }
+
+    /// Sets the parameter `key` to the blob `value`.
+    pub fn set_binary(&self, key: &CStr, value: &[u8]) -> Result<(), FsError> {
+        let len = i32::try_from(value.len()).map_err(|_| FsError {
+            errno: Errno::EINVAL,
+            message: None,
+        })?;
+        let value = value.as_ptr() as SyscallWord;
+        self.config(FSCONFIG_SET_BINARY, Some(key), value, len)
+    }
+
+    /// Sets the parameter `key` to the object at `path`, relative to
+    /// `dirfd`, e.g. `source` to a block device.
+    pub fn set_path(
+        &self,
+        key: &CStr,
+        dirfd: i32,
+        path: &CStr,
+    ) -> Result<(), FsError> {
+        let path = path.as_ptr() as SyscallWord;
+        self.config(FSCONFIG_SET_PATH, Some(key), path, dirfd)
+    }
+
+    /// Sets the parameter `key` to the object of `fd`.
+    pub fn set_fd(&self, key: &CStr, fd: i32) -> Result<(), FsError> {
+        self.config(FSCONFIG_SET_FD, Some(key), 0, fd)
+    }
+
+    /// Creates the superblock, or uses an existing one that matches the
+    /// parameters.
+    pub fn create(&self) -> Result<(), FsError> {
+        self.config(FSCONFIG_CMD_CREATE, None, 0, 0)
+    }
+
+    /// Creates the superblock, failing with `EBUSY` if an existing one
+    /// would be used (Linux 6.6).
+    pub fn create_excl(&self) -> Result<(), FsError> {
+        self.config(FSCONFIG_CMD_CREATE_EXCL, None, 0, 0)
+    }
+
+    /// Applies the parameters to the superblock of a [`pick`](Self::pick)ed
+    /// context.
+    pub fn reconfigure(&self) -> Result<(), FsError> {
+        self.config(FSCONFIG_CMD_RECONFIGURE, None, 0, 0)
+    }
+
+    /// Mounts the created superblock, with `flags` (`FSMOUNT_CLOEXEC`) and
+    /// the `MOUNT_ATTR_*` attributes of the mount. The mount isn't attached
+    /// anywhere until [`MountFd::move_to`].
+    pub fn mount(&self, flags: u32, attr: u64) -> Result<MountFd, FsError> {
+        let fd = unsafe {
+            crate::syscall3(
+                Sysno::fsmount,
+                self.0 as SyscallWord,
+                SyscallWord::from(flags),
+                attr as SyscallWord,
+            )
+        }
+        .map_err(|errno| self.error(errno))?;
+        Ok(MountFd(fd as i32))
+    }
+
+    fn config(
+        &self,
+        cmd: u32,
+        key: Option<&CStr>,
+        value: SyscallWord,
+        aux: i32,
+    ) -> Result<(), FsError> {
+        unsafe {
+            crate::syscall5(
+                Sysno::fsconfig,
+                self.0 as SyscallWord,
+                SyscallWord::from(cmd),
+                key.map_or(0, |key| key.as_ptr() as SyscallWord),
+                value,
+                aux as SyscallWord,
+            )
+        }
+        .map(drop)
+        .map_err(|errno| self.error(errno))
+    }
+
+    /// Makes an error of `errno`, with the last error message logged.
+    fn error(&self, errno: Errno) -> FsError {
+        let mut message = None;
+        let mut buf = [0; 256];
+        while let Some(line) = self.read_message(&mut buf) {
+            if let Some(line) = line.strip_prefix("e ") {
+                message = Some(Buffer::format(line));
+            }
+        }
+        FsError { errno, message }
+    }
+
+    /// Reads the next message that the filesystem logged, which starts
+    /// with `e ` for errors, `w ` for warnings, or `i ` for information.
+    /// Returns `None` if there are no more.
+    pub fn read_message<'a>(&self, buf: &'a mut [u8]) -> Option<&'a str> {
+        let len = read(self.0, buf).ok()?;
+        let message = &buf[..len];
+        let message = message.strip_suffix(b"\n").unwrap_or(message);
+        core::str::from_utf8(message).ok()
+    }
+
+    /// The fd, which stays owned by `self`.
+    pub fn as_raw(&self) -> i32 {
+        self.0
+    }
+}
+
+impl Drop for FsContext {
+    fn drop(&mut self) {
+        let _ = unsafe { close(self.0) };
+    }
+}
+
+/// The attributes to change with [`MountFd::set_attr`] or [`mount_setattr`]
+/// (`struct mount_attr`).
+#[cfg(not(any(
+    feature = "default_kernel_5_4",
+    feature = "default_kernel_5_10"
+)))]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MountAttr {
+    /// The `MOUNT_ATTR_*` attributes to set.
+    pub attr_set: u64,
+    /// The `MOUNT_ATTR_*` attributes to clear, which must include
+    /// `MOUNT_ATTR__ATIME` to change the atime mode.
+    pub attr_clr: u64,
+    /// The propagation type (`MS_SHARED`, `MS_SLAVE`, `MS_PRIVATE`, or
+    /// `MS_UNBINDABLE`), or 0 to keep it.
+    pub propagation: u64,
+    /// The user namespace of `MOUNT_ATTR_IDMAP`.
+    pub userns_fd: u64,
+}
+
+/// Changes the attributes of the mount at `path`, relative to `dirfd`,
+/// with `flags` (`AT_RECURSIVE` for the mounts under it too, and
+/// `AT_EMPTY_PATH`, `AT_SYMLINK_NOFOLLOW`, `AT_NO_AUTOMOUNT`). Linux 5.12.
+#[cfg(not(any(
+    feature = "default_kernel_5_4",
+    feature = "default_kernel_5_10"
+)))]
+pub fn mount_setattr(
+    dirfd: i32,
+    path: &CStr,
+    flags: i32,
+    attr: &MountAttr,
+) -> Result<(), Errno> {
+    unsafe {
+        crate::syscall5(
+            Sysno::mount_setattr,
+            dirfd as SyscallWord,
+            path.as_ptr() as SyscallWord,
+            flags as SyscallWord,
+            core::ptr::from_ref(attr) as SyscallWord,
+            crate::consts::MOUNT_ATTR_SIZE_VER0 as SyscallWord,
+        )
+    }
+    .map(drop)
+}
+
+/// Moves the mount at `from_path`, relative to `from_dirfd`, to `to_path`,
+/// relative to `to_dirfd`, with `flags` (`MOVE_MOUNT_*`).
+pub fn move_mount(
+    from_dirfd: i32,
+    from_path: &CStr,
+    to_dirfd: i32,
+    to_path: &CStr,
+    flags: u32,
+) -> Result<(), Errno> {
+    unsafe {
+        crate::syscall5(
+            Sysno::move_mount,
+            from_dirfd as SyscallWord,
+            from_path.as_ptr() as SyscallWord,
+            to_dirfd as SyscallWord,
+            to_path.as_ptr() as SyscallWord,
+            SyscallWord::from(flags),
+        )
+    }
+    .map(drop)
+}
+
+/// A mount, from [`FsContext::mount`] or [`open_tree`](Self::open_tree),
+/// which is closed when dropped. A mount that isn't attached anywhere is
+/// unmounted when its last fd is closed.
+#[derive(Debug)]
+pub struct MountFd(i32);
+
+impl MountFd {
+    /// Opens the mount at `path`, relative to `dirfd`, with `flags`
+    /// (`OPEN_TREE_CLONE` for a copy that can be attached elsewhere, like a
+    /// bind mount, `OPEN_TREE_CLOEXEC`, and `AT_RECURSIVE` with
+    /// `OPEN_TREE_CLONE` for the mounts under it too).
+    pub fn open_tree(
+        dirfd: i32,
+        path: &CStr,
+        flags: u32,
+    ) -> Result<Self, Errno> {
+        let fd = unsafe {
+            crate::syscall3(
+                Sysno::open_tree,
+                dirfd as SyscallWord,
+                path.as_ptr() as SyscallWord,
+                SyscallWord::from(flags),
+            )
+        }?;
+        Ok(Self(fd as i32))
+    }
+
+    /// Attaches the mount at `path`, relative to `dirfd`.
+    pub fn move_to(&self, dirfd: i32, path: &CStr) -> Result<(), Errno> {
+        move_mount(self.0, c"", dirfd, path, MOVE_MOUNT_F_EMPTY_PATH)
+    }
+
+    /// Changes the attributes of the mount, with `flags` as
+    /// [`mount_setattr`] takes them.
+    #[cfg(not(any(
+        feature = "default_kernel_5_4",
+        feature = "default_kernel_5_10"
+    )))]
+    pub fn set_attr(&self, attr: &MountAttr, flags: i32) -> Result<(), Errno> {
+        let flags = flags | crate::consts::AT_EMPTY_PATH;
+        mount_setattr(self.0, c"", flags, attr)
+    }
+
+    /// The fd, which stays owned by `self`.
+    pub fn as_raw(&self) -> i32 {
+        self.0
+    }
+}
+
+impl Drop for MountFd {
+    fn drop(&mut self) {
+        let _ = unsafe { close(self.0) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consts::{FSMOUNT_CLOEXEC, FSOPEN_CLOEXEC, MOUNT_ATTR_NODEV};
+    use crate::helpers::{fstat, openat};
+
+    #[test]
+    fn tmpfs() {
+        let fs = match FsContext::open(c"tmpfs", FSOPEN_CLOEXEC) {
+            Ok(fs) => fs,
+            // Needs `CAP_SYS_ADMIN`.
+            Err(Errno::EPERM | Errno::ENOSYS) => return,
+            Err(errno) => panic!("fsopen: {errno}"),
+        };
+        let err = fs.set_string(c"size", c"lots").unwrap_err();
+        assert_eq!(err.errno, Errno::EINVAL);
+        assert_eq!(err.message.unwrap(), "tmpfs: Bad value for 'size'");
+        fs.set_string(c"size", c"1m").unwrap();
+        fs.set_flag(c"noswap").ok();
+        fs.create().unwrap();
+
+        let mount = fs.mount(FSMOUNT_CLOEXEC, MOUNT_ATTR_NODEV).unwrap();
+        // The root of the new filesystem, without attaching it.
+        let root = openat(mount.as_raw(), c".", 0, 0).unwrap();
+        assert_eq!(fstat(root).unwrap().mode & 0o170_000, 0o040_000);
+        unsafe { close(root) }.unwrap();
+
+        #[cfg(not(any(
+            feature = "default_kernel_5_4",
+            feature = "default_kernel_5_10"
+        )))]
+        {
+            let attr = MountAttr {
+                attr_set: crate::consts::MOUNT_ATTR_RDONLY,
+                ..MountAttr::default()
+            };
+            mount.set_attr(&attr, 0).unwrap();
+            assert_eq!(
+                openat(mount.as_raw(), c"file", crate::consts::O_CREAT, 0o600),
+                Err(Errno::EROFS)
+            );
+        }
+    }
+
+    #[test]
+    fn error() {
+        let err = FsError {
+            errno: Errno::EINVAL,
+            message: Some(Buffer::format("tmpfs: Bad value for 'size'")),
+        };
+        assert_eq!(
+            Buffer::<128>::format(err),
+            "tmpfs: Bad value for 'size': -22 EINVAL (Invalid argument)"
+        );
+    }
+}
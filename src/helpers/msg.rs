@@ -0,0 +1,488 @@
+//! Messages of sockets with control messages: [`sendmsg`] and [`recvmsg`].
+
+use core::fmt;
+
+use super::socketcall::{SYS_RECVMSG, SYS_SENDMSG, socket_syscall};
+use super::{IoVec, IoVecMut, SockAddr, SockaddrStorage};
+use crate::id::{Gid, Pid, Uid};
+use crate::{Errno, SyscallWord, Sysno};
+
+/// `flags` of [`sendmsg`] and [`recvmsg`]: send or receive out-of-band
+/// data.
+pub const MSG_OOB: u32 = 0x1;
+/// `flags` of [`recvmsg`]: return the data without removing it from the
+/// queue.
+pub const MSG_PEEK: u32 = 0x2;
+/// [`RecvMsg::flags`]: the control messages were cut off, and the fds of
+/// [`SCM_RIGHTS`] that didn't fit were closed.
+pub const MSG_CTRUNC: u32 = 0x8;
+/// [`RecvMsg::flags`]: the datagram was cut off. As `flags` of
+/// [`recvmsg`]: return its whole length.
+pub const MSG_TRUNC: u32 = 0x20;
+/// `flags` of [`sendmsg`] and [`recvmsg`]: fail with `EAGAIN` instead of
+/// blocking, as if the socket was nonblocking.
+pub const MSG_DONTWAIT: u32 = 0x40;
+/// `flags` of [`sendmsg`]: end a record of a [`SOCK_SEQPACKET`] socket.
+///
+/// [`SOCK_SEQPACKET`]: super::SOCK_SEQPACKET
+pub const MSG_EOR: u32 = 0x80;
+/// `flags` of [`recvmsg`]: wait for all of the data of a stream.
+pub const MSG_WAITALL: u32 = 0x100;
+/// `flags` of [`sendmsg`]: fail with `EPIPE` without sending `SIGPIPE`
+/// when the peer is gone.
+pub const MSG_NOSIGNAL: u32 = 0x4000;
+/// `flags` of [`recvmsg`]: open the fds of [`SCM_RIGHTS`] with
+/// `O_CLOEXEC`.
+pub const MSG_CMSG_CLOEXEC: u32 = 0x4000_0000;
+
+/// `ty` of a control message of [`SOL_SOCKET`](super::SOL_SOCKET): fds,
+/// which the receiver gets copies of.
+pub const SCM_RIGHTS: u32 = 1;
+/// `ty` of a control message of [`SOL_SOCKET`](super::SOL_SOCKET): the
+/// credentials of the sender, a [`Ucred`], which the receiver gets with
+/// [`SO_PASSCRED`](super::SO_PASSCRED).
+pub const SCM_CREDENTIALS: u32 = 2;
+
+/// The most fds that one [`SCM_RIGHTS`] message can take
+/// (`SCM_MAX_FD`).
+pub const SCM_MAX_FD: usize = 253;
+
+/// The credentials of a process (`struct ucred`), as of
+/// [`SCM_CREDENTIALS`] and [`SO_PEERCRED`](super::SO_PEERCRED).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Ucred {
+    pub pid: Pid,
+    pub uid: Uid,
+    pub gid: Gid,
+}
+
+/// `struct user_msghdr`
+#[repr(C)]
+struct MsgHdr {
+    name: usize,
+    namelen: u32,
+    iov: usize,
+    iovlen: usize,
+    control: usize,
+    controllen: usize,
+    flags: u32,
+}
+
+/// `struct cmsghdr`, which the data of the message follows, after
+/// padding.
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct CmsgHdr {
+    /// The length of the header and the data, without the padding after
+    /// it.
+    len: usize,
+    level: u32,
+    ty: u32,
+}
+
+/// `len` rounded up to the alignment of control messages, that of a word
+/// (`CMSG_ALIGN`).
+pub const fn cmsg_align(len: usize) -> usize {
+    len.next_multiple_of(align_of::<usize>())
+}
+
+/// The length of a control message with `len` bytes of data, without the
+/// padding after it (`CMSG_LEN`), which is its header's `cmsg_len`.
+pub const fn cmsg_len(len: usize) -> usize {
+    cmsg_align(size_of::<CmsgHdr>()) + len
+}
+
+/// The bytes that a control message with `len` bytes of data takes in a
+/// buffer, with the padding after it (`CMSG_SPACE`), e.g. to size a
+/// [`ControlMessages`] for several messages.
+pub const fn cmsg_space(len: usize) -> usize {
+    cmsg_align(size_of::<CmsgHdr>()) + cmsg_align(len)
+}
+
+/// A buffer of up to `N` bytes of control messages, aligned as the kernel
+/// reads and writes them, which [`sendmsg`] sends and [`recvmsg`] fills:
+///
+/// ```no_run
+/// use rawsys_linux::helpers::{self, ControlMessages, IoVec, cmsg_space};
+///
+/// # let (sock, fd) = (3, 4);
+/// let mut control = ControlMessages::<{ cmsg_space(4) }>::new();
+/// control.push_rights(&[fd])?;
+/// helpers::sendmsg(sock, None, &[IoVec::new(b"fd")], &control, 0)?;
+/// # Ok::<(), rawsys_linux::Errno>(())
+/// ```
+///
+/// Adding a message fails with `ENOBUFS` when it doesn't fit, leaving it as
+/// it was.
+#[repr(C, align(8))]
+#[derive(Clone, Copy)]
+pub struct ControlMessages<const N: usize> {
+    buf: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> ControlMessages<N> {
+    /// No messages.
+    pub const fn new() -> Self {
+        Self {
+            buf: [0; N],
+            len: 0,
+        }
+    }
+
+    /// The bytes of the messages, with their headers and padding.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Removes the messages.
+    pub fn clear(&mut self) {
+        self.buf[..self.len].fill(0);
+        self.len = 0;
+    }
+
+    /// Adds a message of the type `ty` of `level`, e.g. [`SCM_RIGHTS`] of
+    /// [`SOL_SOCKET`](super::SOL_SOCKET), with the data `data`.
+    pub fn push(
+        &mut self,
+        level: u32,
+        ty: u32,
+        data: &[u8],
+    ) -> Result<(), Errno> {
+        let start = self.len;
+        let end = start
+            .checked_add(cmsg_space(data.len()))
+            .filter(|&end| end <= N)
+            .ok_or(Errno::ENOBUFS)?;
+        let header = CmsgHdr {
+            len: cmsg_len(data.len()),
+            level,
+            ty,
+        };
+        // The padding after the data must be zeros, which `clear` keeps.
+        unsafe {
+            self.buf
+                .as_mut_ptr()
+                .add(start)
+                .cast::<CmsgHdr>()
+                .write_unaligned(header);
+        }
+        let data_start = start + cmsg_len(0);
+        self.buf[data_start..data_start + data.len()].copy_from_slice(data);
+        self.len = end;
+        Ok(())
+    }
+
+    /// Adds an [`SCM_RIGHTS`] message with the fds `fds`, of which it can
+    /// take up to [`SCM_MAX_FD`].
+    pub fn push_rights(&mut self, fds: &[i32]) -> Result<(), Errno> {
+        if fds.len() > SCM_MAX_FD {
+            return Err(Errno::EINVAL);
+        }
+        let mut data = [0u8; SCM_MAX_FD * 4];
+        for (bytes, fd) in data.chunks_exact_mut(4).zip(fds) {
+            bytes.copy_from_slice(&fd.to_ne_bytes());
+        }
+        self.push(super::SOL_SOCKET, SCM_RIGHTS, &data[..fds.len() * 4])
+    }
+
+    /// Adds an [`SCM_CREDENTIALS`] message with `cred`, which must be the
+    /// sender's unless it has `CAP_SYS_ADMIN`, `CAP_SETUID`, or
+    /// `CAP_SETGID` for the ids that differ.
+    pub fn push_credentials(&mut self, cred: &Ucred) -> Result<(), Errno> {
+        let data = unsafe {
+            core::slice::from_raw_parts(
+                core::ptr::from_ref(cred).cast::<u8>(),
+                size_of::<Ucred>(),
+            )
+        };
+        self.push(super::SOL_SOCKET, SCM_CREDENTIALS, data)
+    }
+
+    /// The messages, parsed.
+    pub fn iter(&self) -> ControlMessageIter<'_> {
+        ControlMessageIter {
+            buf: self.as_bytes(),
+        }
+    }
+}
+
+impl<const N: usize> Default for ControlMessages<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> fmt::Debug for ControlMessages<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, const N: usize> IntoIterator for &'a ControlMessages<N> {
+    type Item = ControlMessage<'a>;
+    type IntoIter = ControlMessageIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// A control message of a [`ControlMessages`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ControlMessage<'a> {
+    /// [`SCM_RIGHTS`]: fds, which the receiver owns and closes.
+    Rights(Rights<'a>),
+    /// [`SCM_CREDENTIALS`].
+    Credentials(Ucred),
+    /// A message of another type, or one that is too short for its type.
+    Other { level: u32, ty: u32, data: &'a [u8] },
+}
+
+/// The fds of an [`SCM_RIGHTS`] message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rights<'a>(&'a [u8]);
+
+impl Iterator for Rights<'_> {
+    type Item = i32;
+
+    fn next(&mut self) -> Option<i32> {
+        let (fd, rest) = self.0.split_first_chunk::<4>()?;
+        self.0 = rest;
+        Some(i32::from_ne_bytes(*fd))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len() / 4, Some(self.0.len() / 4))
+    }
+}
+
+impl ExactSizeIterator for Rights<'_> {}
+
+/// The iterator of the messages of a [`ControlMessages`], which stops at
+/// the first malformed header.
+#[derive(Debug, Clone)]
+pub struct ControlMessageIter<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> Iterator for ControlMessageIter<'a> {
+    type Item = ControlMessage<'a>;
+
+    fn next(&mut self) -> Option<ControlMessage<'a>> {
+        if self.buf.len() < size_of::<CmsgHdr>() {
+            return None;
+        }
+        let header =
+            unsafe { self.buf.as_ptr().cast::<CmsgHdr>().read_unaligned() };
+        if header.len < cmsg_len(0) || header.len > self.buf.len() {
+            self.buf = &[];
+            return None;
+        }
+        let data = &self.buf[cmsg_len(0)..header.len];
+        // The last message may be without the padding after it.
+        self.buf = self.buf.get(cmsg_align(header.len)..).unwrap_or(&[]);
+        Some(match (header.level, header.ty) {
+            (super::SOL_SOCKET, SCM_RIGHTS) => {
+                ControlMessage::Rights(Rights(&data[..data.len() / 4 * 4]))
+            }
+            (super::SOL_SOCKET, SCM_CREDENTIALS)
+                if data.len() >= size_of::<Ucred>() =>
+            {
+                let cred =
+                    unsafe { data.as_ptr().cast::<Ucred>().read_unaligned() };
+                ControlMessage::Credentials(cred)
+            }
+            (level, ty) => ControlMessage::Other { level, ty, data },
+        })
+    }
+}
+
+/// What [`recvmsg`] received.
+#[derive(Debug, Clone, Copy)]
+pub struct RecvMsg {
+    /// The bytes of data, or the whole length of the datagram with
+    /// [`MSG_TRUNC`].
+    pub len: usize,
+    /// `MSG_*`, e.g. [`MSG_CTRUNC`].
+    pub flags: u32,
+    /// The address of the sender, of an unconnected datagram socket, or
+    /// [`AF_UNSPEC`](super::AF_UNSPEC).
+    pub addr: SockaddrStorage,
+}
+
+/// Sends the buffers `bufs` with the control messages `control` on the socket `fd`, to
+/// `addr` if it isn't connected, with the flags `flags`, e.g.
+/// [`MSG_NOSIGNAL`], and returns the number of bytes sent.
+pub fn sendmsg<const N: usize>(
+    fd: i32,
+    addr: Option<&dyn SockAddr>,
+    bufs: &[IoVec<'_>],
+    control: &ControlMessages<N>,
+    flags: u32,
+) -> Result<usize, Errno> {
+    let (name, namelen) = addr.map_or((0, 0), |addr| {
+        (
+            core::ptr::from_ref(addr).cast::<u8>() as usize,
+            addr.addr_len(),
+        )
+    });
+    let msg = MsgHdr {
+        name,
+        namelen,
+        iov: IoVec::as_raw_slice(bufs).as_ptr() as usize,
+        iovlen: bufs.len(),
+        control: if control.is_empty() {
+            0
+        } else {
+            control.buf.as_ptr() as usize
+        },
+        controllen: control.len,
+        flags: 0,
+    };
+    let sent = unsafe {
+        socket_syscall(
+            Sysno::sendmsg,
+            SYS_SENDMSG,
+            &[
+                fd as SyscallWord,
+                core::ptr::from_ref(&msg) as SyscallWord,
+                SyscallWord::from(flags),
+            ],
+        )
+    }?;
+    Ok(sent as usize)
+}
+
+/// Receives data into the buffers `bufs`, in order, and control messages into `control`, which it
+/// clears first, on the socket `fd`, with the flags `flags`, e.g.
+/// [`MSG_CMSG_CLOEXEC`].
+///
+/// The fds of [`SCM_RIGHTS`] messages are the caller's to close, even if
+/// the data is discarded.
+pub fn recvmsg<const N: usize>(
+    fd: i32,
+    bufs: &mut [IoVecMut<'_>],
+    control: &mut ControlMessages<N>,
+    flags: u32,
+) -> Result<RecvMsg, Errno> {
+    control.clear();
+    let mut msg = MsgHdr {
+        name: 0,
+        namelen: 0,
+        iov: IoVecMut::as_raw_slice(bufs).as_ptr() as usize,
+        iovlen: bufs.len(),
+        control: control.buf.as_mut_ptr() as usize,
+        controllen: N,
+        flags: 0,
+    };
+    let (len, addr) = SockaddrStorage::fill(|addr, len| {
+        msg.name = addr as usize;
+        msg.namelen = unsafe { *(len as *const u32) };
+        let received = unsafe {
+            socket_syscall(
+                Sysno::recvmsg,
+                SYS_RECVMSG,
+                &[
+                    fd as SyscallWord,
+                    core::ptr::from_mut(&mut msg) as SyscallWord,
+                    SyscallWord::from(flags),
+                ],
+            )
+        }?;
+        unsafe { *(len as *mut u32) = msg.namelen };
+        Ok(received)
+    })?;
+    control.len = msg.controllen.min(N);
+    Ok(RecvMsg {
+        len: len as usize,
+        flags: msg.flags,
+        addr,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::{
+        AF_UNIX, SO_PASSCRED, SOCK_CLOEXEC, SOCK_DGRAM, SOL_SOCKET, close,
+        eventfd, eventfd_read, eventfd_write, getgid, getpid, getuid,
+        setsockopt_int, socketpair,
+    };
+
+    #[test]
+    fn layout() {
+        let word = size_of::<usize>();
+        assert_eq!(cmsg_len(4), 2 * word + 4);
+        assert_eq!(cmsg_space(4), 3 * word);
+        assert_eq!(cmsg_space(12), 2 * word + 16);
+
+        let mut control =
+            ControlMessages::<{ cmsg_space(8) + cmsg_space(1) }>::new();
+        control.push_rights(&[3, 4]).unwrap();
+        assert_eq!(control.push(1, 2, b"123456789"), Err(Errno::ENOBUFS));
+        control.push(1, 2, b"a").unwrap();
+        let mut messages = control.iter();
+        let Some(ControlMessage::Rights(fds)) = messages.next() else {
+            panic!("{control:?}");
+        };
+        assert_eq!(fds.len(), 2);
+        assert!(fds.eq([3, 4]));
+        assert_eq!(
+            messages.next(),
+            Some(ControlMessage::Other {
+                level: 1,
+                ty: 2,
+                data: b"a"
+            })
+        );
+        assert_eq!(messages.next(), None);
+    }
+
+    #[test]
+    fn pass() {
+        let [a, b] = socketpair(AF_UNIX, SOCK_DGRAM | SOCK_CLOEXEC, 0).unwrap();
+        setsockopt_int(b, SOL_SOCKET, SO_PASSCRED, 1).unwrap();
+        let event = eventfd(0, 0).unwrap();
+        let cred = Ucred {
+            pid: getpid(),
+            uid: getuid(),
+            gid: getgid(),
+        };
+        let mut control =
+            ControlMessages::<{ cmsg_space(4) + cmsg_space(12) }>::new();
+        control.push_rights(&[event]).unwrap();
+        control.push_credentials(&cred).unwrap();
+        let bufs = [IoVec::new(b"f"), IoVec::new(b"d")];
+        assert_eq!(sendmsg(a, None, &bufs, &control, 0), Ok(2));
+
+        let mut data = [0u8; 1];
+        let mut bufs = [IoVecMut::new(&mut data)];
+        let received =
+            recvmsg(b, &mut bufs, &mut control, MSG_CMSG_CLOEXEC).unwrap();
+        assert_eq!((received.len, &data), (1, b"f"));
+        assert_eq!(received.flags & MSG_TRUNC, MSG_TRUNC);
+        let mut fds = None;
+        for message in &control {
+            match message {
+                ControlMessage::Rights(mut rights) => fds = rights.next(),
+                ControlMessage::Credentials(got) => assert_eq!(got, cred),
+                ControlMessage::Other { .. } => panic!("{control:?}"),
+            }
+        }
+        let copy = fds.unwrap();
+        eventfd_write(copy, 7).unwrap();
+        assert_eq!(eventfd_read(event), Ok(7));
+        unsafe {
+            close(copy).unwrap();
+            close(event).unwrap();
+            close(a).unwrap();
+            close(b).unwrap();
+        }
+    }
+}
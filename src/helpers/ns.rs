@@ -0,0 +1,183 @@
+//! Namespaces: leaving them with `unshare`, and joining them with `setns`.
+
+use super::{CStrPath, Pid, openat};
+use crate::consts::{self, AT_FDCWD, O_CLOEXEC, O_RDONLY};
+use crate::{Errno, SyscallWord, Sysno};
+
+flags! {
+    /// The namespaces (`CLONE_NEW*`) for [`unshare`] and [`setns`], as for
+    /// [`CloneArgs::flags`](super::CloneArgs::flags), and the other
+    /// resources that [`unshare`] can stop sharing.
+    pub struct Namespaces: u64 {
+        /// The mounts.
+        const NEWNS = consts::CLONE_NEWNS;
+        /// The cgroup root (Linux 4.6).
+        const NEWCGROUP = consts::CLONE_NEWCGROUP;
+        /// The host and domain names.
+        const NEWUTS = consts::CLONE_NEWUTS;
+        /// The System V IPC objects and POSIX message queues.
+        const NEWIPC = consts::CLONE_NEWIPC;
+        /// The user and group ids, and the capabilities.
+        const NEWUSER = consts::CLONE_NEWUSER;
+        /// The pids, for the children of the caller, not the caller.
+        const NEWPID = consts::CLONE_NEWPID;
+        /// The network devices, addresses, and ports.
+        const NEWNET = consts::CLONE_NEWNET;
+        /// The offsets of `CLOCK_MONOTONIC` and `CLOCK_BOOTTIME`, for the
+        /// children of the caller (Linux 5.6).
+        const NEWTIME = consts::CLONE_NEWTIME;
+        /// For [`unshare`]: the fd table.
+        const FILES = consts::CLONE_FILES;
+        /// For [`unshare`]: the root, working directory, and umask.
+        const FS = consts::CLONE_FS;
+        /// For [`unshare`]: the System V semaphore adjustments.
+        const SYSVSEM = consts::CLONE_SYSVSEM;
+    }
+}
+
+/// A namespace of a process, as in `/proc/<pid>/ns`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Namespace {
+    Cgroup,
+    Ipc,
+    Mnt,
+    Net,
+    Pid,
+    /// The pid namespace of the children, which [`Namespaces::NEWPID`] and
+    /// [`setns`] change.
+    PidForChildren,
+    Time,
+    /// The time namespace of the children.
+    TimeForChildren,
+    User,
+    Uts,
+}
+
+impl Namespace {
+    /// Its name in `/proc/<pid>/ns`, e.g. `mnt`.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Cgroup => "cgroup",
+            Self::Ipc => "ipc",
+            Self::Mnt => "mnt",
+            Self::Net => "net",
+            Self::Pid => "pid",
+            Self::PidForChildren => "pid_for_children",
+            Self::Time => "time",
+            Self::TimeForChildren => "time_for_children",
+            Self::User => "user",
+            Self::Uts => "uts",
+        }
+    }
+
+    /// Its `CLONE_NEW*` flag.
+    pub const fn flag(self) -> Namespaces {
+        match self {
+            Self::Cgroup => Namespaces::NEWCGROUP,
+            Self::Ipc => Namespaces::NEWIPC,
+            Self::Mnt => Namespaces::NEWNS,
+            Self::Net => Namespaces::NEWNET,
+            Self::Pid | Self::PidForChildren => Namespaces::NEWPID,
+            Self::Time | Self::TimeForChildren => Namespaces::NEWTIME,
+            Self::User => Namespaces::NEWUSER,
+            Self::Uts => Namespaces::NEWUTS,
+        }
+    }
+}
+
+/// Stops sharing the namespaces and the other resources of `flags` with
+/// the other processes and threads, and moves the calling thread to new
+/// namespaces, as the first and only member, or to those of its children
+/// for [`Namespaces::NEWPID`] and [`Namespaces::NEWTIME`].
+///
+/// The namespaces other than [`Namespaces::NEWUSER`] need `CAP_SYS_ADMIN`,
+/// which a new user namespace grants in it, so unprivileged processes
+/// unshare it with them. Fails with `EINVAL` for a user namespace in a
+/// process with more than one thread.
+pub fn unshare(flags: Namespaces) -> Result<(), Errno> {
+    unsafe { crate::syscall1(Sysno::unshare, flags.bits() as SyscallWord) }?;
+    Ok(())
+}
+
+/// Moves the calling thread to the namespace that the fd `fd` refers to,
+/// e.g. from [`open_ns`], which must be of a type of `nstype` unless it is
+/// empty. With a pidfd, moves it to the namespaces of `nstype` of the
+/// process at once (Linux 5.8).
+///
+/// ```no_run
+/// use rawsys_linux::helpers::{self, Namespace, Namespaces, Pid};
+///
+/// # let pid = Pid::from_raw(1);
+/// let fd = helpers::open_ns(pid, Namespace::Net)?;
+/// helpers::setns(fd, Namespaces::NEWNET)?;
+/// unsafe { helpers::close(fd) }?;
+/// # Ok::<(), rawsys_linux::Errno>(())
+/// ```
+pub fn setns(fd: i32, nstype: Namespaces) -> Result<(), Errno> {
+    unsafe {
+        crate::syscall2(
+            Sysno::setns,
+            fd as SyscallWord,
+            nstype.bits() as SyscallWord,
+        )
+    }?;
+    Ok(())
+}
+
+/// The path of the namespace `ns` of the process `pid` in procfs, or of
+/// the calling thread if it is 0, e.g. `/proc/1/ns/net`.
+pub fn ns_path(pid: Pid, ns: Namespace) -> CStrPath<48> {
+    let mut path = CStrPath::literal("/proc");
+    // "/proc/thread-self/ns/time_for_children" and the 11 characters of a
+    // pid fit.
+    let _ = if pid.as_raw() == 0 {
+        path.push(b"thread-self")
+    } else {
+        path.push_int(pid.as_raw())
+    };
+    let _ = path.push(b"ns");
+    let _ = path.push(ns.name().as_bytes());
+    path
+}
+
+/// Opens the namespace `ns` of the process `pid`, or of the calling thread
+/// if it is 0, for [`setns`], with `O_CLOEXEC`. Fails with `ENOENT` if the
+/// kernel doesn't have that type of namespace.
+pub fn open_ns(pid: Pid, ns: Namespace) -> Result<i32, Errno> {
+    openat(
+        AT_FDCWD,
+        ns_path(pid, ns).as_cstr(),
+        O_RDONLY | O_CLOEXEC,
+        0,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::{close, fd_path, getpid};
+
+    #[test]
+    fn paths() {
+        let path = ns_path(Pid::from_raw(0), Namespace::TimeForChildren);
+        assert_eq!(path.as_cstr(), c"/proc/thread-self/ns/time_for_children");
+        let path = ns_path(Pid::from_raw(i32::MIN), Namespace::Net);
+        assert_eq!(path.as_cstr(), c"/proc/-2147483648/ns/net");
+        assert_eq!(Namespace::Mnt.flag(), Namespaces::NEWNS);
+    }
+
+    #[test]
+    fn join() {
+        let fd = open_ns(getpid(), Namespace::Uts).unwrap();
+        let mut buf = [0u8; 32];
+        assert!(fd_path(fd, &mut buf).unwrap().starts_with(b"uts:["));
+        assert_eq!(setns(fd, Namespaces::NEWNET), Err(Errno::EINVAL));
+        match setns(fd, Namespaces::NEWUTS) {
+            // Without `CAP_SYS_ADMIN`.
+            Ok(()) | Err(Errno::EPERM) => {}
+            result => panic!("setns: {result:?}"),
+        }
+        unsafe { close(fd) }.unwrap();
+        unshare(Namespaces::empty()).unwrap();
+    }
+}
@@ -0,0 +1,276 @@
+//! Paths in fixed-size buffers, and the targets of symbolic links.
+
+use core::ffi::CStr;
+use core::fmt;
+
+use crate::{Errno, SyscallWord, Sysno};
+
+/// A path of up to `N - 1` bytes and its NUL, built on the stack for the
+/// syscalls that take paths, without allocating a `CString`:
+///
+/// ```no_run
+/// use rawsys_linux::consts::{AT_FDCWD, O_RDONLY};
+/// use rawsys_linux::helpers::{self, CStrPath};
+///
+/// const PROC: CStrPath<64> = CStrPath::literal("/proc");
+///
+/// # let pid = 1;
+/// let mut path = PROC;
+/// path.push_int(pid)?;
+/// path.push(b"status")?;
+/// let fd = helpers::openat(AT_FDCWD, path.as_cstr(), O_RDONLY, 0)?;
+/// # Ok::<(), rawsys_linux::Errno>(())
+/// ```
+///
+/// Adding to it fails with `ENAMETOOLONG` when it doesn't fit, and with
+/// `EINVAL` for a NUL, leaving it as it was.
+#[derive(Clone, Copy)]
+pub struct CStrPath<const N: usize> {
+    /// The path and a NUL, then zeros.
+    buf: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> CStrPath<N> {
+    /// An empty path.
+    ///
+    /// # Panics
+    ///
+    /// If `N` is 0, which leaves no room for the NUL.
+    pub const fn new() -> Self {
+        assert!(N > 0, "a `CStrPath` needs room for the NUL");
+        Self {
+            buf: [0; N],
+            len: 0,
+        }
+    }
+
+    /// The path `path`, checked when it is built: in a `const`, a path that
+    /// has a NUL or doesn't fit fails to compile.
+    ///
+    /// # Panics
+    ///
+    /// If `path` has a NUL or is `N` bytes or longer.
+    pub const fn literal(path: &str) -> Self {
+        match Self::from_bytes(path.as_bytes()) {
+            Ok(path) => path,
+            Err(_) => panic!("the path doesn't fit or has a NUL"),
+        }
+    }
+
+    /// The path `path`.
+    pub const fn from_bytes(path: &[u8]) -> Result<Self, Errno> {
+        let mut new = Self::new();
+        if path.len() >= N {
+            return Err(Errno::ENAMETOOLONG);
+        }
+        let mut i = 0;
+        while i < path.len() {
+            if path[i] == 0 {
+                return Err(Errno::EINVAL);
+            }
+            new.buf[i] = path[i];
+            i += 1;
+        }
+        new.len = path.len();
+        Ok(new)
+    }
+
+    /// Adds `bytes` to the end, without a `/`.
+    pub fn push_bytes(&mut self, bytes: &[u8]) -> Result<(), Errno> {
+        if bytes.contains(&0) {
+            return Err(Errno::EINVAL);
+        }
+        let end = self.len + bytes.len();
+        if end >= N {
+            return Err(Errno::ENAMETOOLONG);
+        }
+        self.buf[self.len..end].copy_from_slice(bytes);
+        self.len = end;
+        Ok(())
+    }
+
+    /// Adds the path component `component`, after a `/` unless the path is
+    /// empty or ends with one.
+    pub fn push(&mut self, component: &[u8]) -> Result<(), Errno> {
+        let len = self.len;
+        if len > 0 && self.buf[len - 1] != b'/' {
+            self.push_bytes(b"/")?;
+        }
+        self.push_bytes(component)
+            .inspect_err(|_| self.truncate(len))
+    }
+
+    /// Adds `n` in decimal as a path component, e.g. a pid or an fd.
+    pub fn push_int(&mut self, n: i32) -> Result<(), Errno> {
+        let mut digits = [0u8; 11];
+        let mut start = digits.len();
+        let mut rest = n.unsigned_abs();
+        loop {
+            start -= 1;
+            digits[start] = b'0' + (rest % 10) as u8;
+            rest /= 10;
+            if rest == 0 {
+                break;
+            }
+        }
+        if n < 0 {
+            start -= 1;
+            digits[start] = b'-';
+        }
+        self.push(&digits[start..])
+    }
+
+    /// Cuts the path off after `len` bytes, if it is longer.
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len {
+            self.buf[len..self.len].fill(0);
+            self.len = len;
+        }
+    }
+
+    /// The path, without the NUL.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+
+    /// The path, for a syscall.
+    pub fn as_cstr(&self) -> &CStr {
+        // There is a NUL after the path, and none in it.
+        unsafe { CStr::from_bytes_with_nul_unchecked(&self.buf[..=self.len]) }
+    }
+
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<const N: usize> Default for CStrPath<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> fmt::Debug for CStrPath<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_cstr(), f)
+    }
+}
+
+impl<const N: usize> PartialEq for CStrPath<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl<const N: usize> Eq for CStrPath<N> {}
+
+impl<const N: usize> AsRef<CStr> for CStrPath<N> {
+    fn as_ref(&self) -> &CStr {
+        self.as_cstr()
+    }
+}
+
+/// Reads the target of the symbolic link `path`, relative to the directory
+/// `dirfd` (or `AT_FDCWD`), into `buf`, and returns its length. The target
+/// has no NUL, and is cut off if it is longer than `buf`.
+///
+/// With an empty `path`, it reads the link that `dirfd` was opened on with
+/// `O_PATH | O_NOFOLLOW`.
+pub fn readlinkat(
+    dirfd: i32,
+    path: &CStr,
+    buf: &mut [u8],
+) -> Result<usize, Errno> {
+    let len = unsafe {
+        crate::syscall4(
+            Sysno::readlinkat,
+            dirfd as SyscallWord,
+            path.as_ptr() as SyscallWord,
+            buf.as_mut_ptr() as SyscallWord,
+            buf.len() as SyscallWord,
+        )
+    }?;
+    Ok(len as usize)
+}
+
+/// The path of the fd `fd` in procfs, `/proc/self/fd/<fd>`, which opens
+/// what `fd` refers to, even if it was removed.
+pub fn proc_fd_path(fd: i32) -> CStrPath<32> {
+    let mut path = CStrPath::literal("/proc/self/fd");
+    // "/proc/self/fd/" and 11 characters fit.
+    let _ = path.push_int(fd);
+    path
+}
+
+/// Reads what the fd `fd` refers to from procfs into `buf`, e.g.
+/// `/etc/hostname`, `pipe:[1234]`, or `/tmp/x (deleted)`, and returns it.
+/// Fails with `ENAMETOOLONG` if it doesn't fit in `buf`.
+pub fn fd_path(fd: i32, buf: &mut [u8]) -> Result<&[u8], Errno> {
+    let len =
+        readlinkat(crate::consts::AT_FDCWD, proc_fd_path(fd).as_cstr(), buf)?;
+    // The target may have been cut off.
+    if len == buf.len() {
+        return Err(Errno::ENAMETOOLONG);
+    }
+    Ok(&buf[..len])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consts::{AT_FDCWD, O_RDONLY};
+    use crate::helpers::{close, openat, pipe2};
+
+    #[test]
+    fn build() {
+        const ROOT: CStrPath<8> = CStrPath::literal("/");
+        let mut path = ROOT;
+        path.push(b"a").unwrap();
+        path.push_int(-12).unwrap();
+        assert_eq!(path.as_cstr(), c"/a/-12");
+        assert_eq!(path.push(b"bc"), Err(Errno::ENAMETOOLONG));
+        assert_eq!(path.push_bytes(b"\0"), Err(Errno::EINVAL));
+        assert_eq!(path.as_bytes(), b"/a/-12");
+        path.push_bytes(b"x").unwrap();
+        assert_eq!(path.as_cstr(), c"/a/-12x");
+        path.truncate(2);
+        assert_eq!(path.as_cstr(), c"/a");
+        assert_eq!(
+            CStrPath::<4>::from_bytes(b"/abc"),
+            Err(Errno::ENAMETOOLONG)
+        );
+        assert_eq!(CStrPath::<8>::from_bytes(b"a\0b"), Err(Errno::EINVAL));
+        assert!(CStrPath::<1>::new().is_empty());
+        assert_eq!(
+            proc_fd_path(i32::MIN).as_cstr(),
+            c"/proc/self/fd/-2147483648"
+        );
+    }
+
+    #[test]
+    fn links() {
+        let mut buf = [0u8; 64];
+        let fd = openat(AT_FDCWD, c"/proc/self/exe", O_RDONLY, 0).unwrap();
+        let exe = fd_path(fd, &mut buf).unwrap();
+        assert!(exe.starts_with(b"/"));
+        let mut link = [0u8; 64];
+        let len = readlinkat(AT_FDCWD, c"/proc/self/exe", &mut link).unwrap();
+        assert_eq!(&link[..len], exe);
+        assert_eq!(fd_path(fd, &mut [0u8; 1]), Err(Errno::ENAMETOOLONG));
+        unsafe { close(fd) }.unwrap();
+
+        let [rd, wr] = pipe2(0).unwrap();
+        assert!(fd_path(rd, &mut buf).unwrap().starts_with(b"pipe:["));
+        assert_eq!(fd_path(-1, &mut buf), Err(Errno::ENOENT));
+        assert_eq!(readlinkat(AT_FDCWD, c"/", &mut buf), Err(Errno::EINVAL));
+        unsafe {
+            close(rd).unwrap();
+            close(wr).unwrap();
+        }
+    }
+}
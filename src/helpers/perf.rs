@@ -0,0 +1,607 @@
+//! Performance counters: `perf_event_open`, its `struct perf_event_attr`,
+//! and the first page of the ring buffer that the fd maps.
+
+use core::ptr::NonNull;
+use core::sync::atomic::{Ordering, fence};
+
+use super::Pid;
+use crate::ioctl::{io, ior, iow};
+use crate::{Errno, SyscallWord, Sysno};
+
+/// `ty` of [`PerfEventAttr::new`]: the hardware events of the CPU, one of
+/// `PERF_COUNT_HW_*`.
+pub const PERF_TYPE_HARDWARE: u32 = 0;
+/// `ty` of [`PerfEventAttr::new`]: the events of the kernel, one of
+/// `PERF_COUNT_SW_*`.
+pub const PERF_TYPE_SOFTWARE: u32 = 1;
+/// `ty` of [`PerfEventAttr::new`]: a tracepoint, by its id in tracefs.
+pub const PERF_TYPE_TRACEPOINT: u32 = 2;
+/// `ty` of [`PerfEventAttr::new`]: a cache event, of [`hw_cache_config`].
+pub const PERF_TYPE_HW_CACHE: u32 = 3;
+/// `ty` of [`PerfEventAttr::new`]: a raw event of the CPU's PMU.
+pub const PERF_TYPE_RAW: u32 = 4;
+/// `ty` of [`PerfEventAttr::new`]: a hardware breakpoint, of
+/// [`PerfEventAttr::bp_type`].
+pub const PERF_TYPE_BREAKPOINT: u32 = 5;
+
+/// `config` of [`PERF_TYPE_HARDWARE`]: the cycles, which frequency scaling
+/// changes.
+pub const PERF_COUNT_HW_CPU_CYCLES: u64 = 0;
+/// `config` of [`PERF_TYPE_HARDWARE`]: the retired instructions.
+pub const PERF_COUNT_HW_INSTRUCTIONS: u64 = 1;
+/// `config` of [`PERF_TYPE_HARDWARE`]: the accesses to the last level
+/// cache.
+pub const PERF_COUNT_HW_CACHE_REFERENCES: u64 = 2;
+/// `config` of [`PERF_TYPE_HARDWARE`]: the misses of the last level cache.
+pub const PERF_COUNT_HW_CACHE_MISSES: u64 = 3;
+/// `config` of [`PERF_TYPE_HARDWARE`]: the retired branch instructions.
+pub const PERF_COUNT_HW_BRANCH_INSTRUCTIONS: u64 = 4;
+/// `config` of [`PERF_TYPE_HARDWARE`]: the mispredicted branches.
+pub const PERF_COUNT_HW_BRANCH_MISSES: u64 = 5;
+/// `config` of [`PERF_TYPE_HARDWARE`]: the bus cycles.
+pub const PERF_COUNT_HW_BUS_CYCLES: u64 = 6;
+/// `config` of [`PERF_TYPE_HARDWARE`]: the cycles stalled in the front
+/// end.
+pub const PERF_COUNT_HW_STALLED_CYCLES_FRONTEND: u64 = 7;
+/// `config` of [`PERF_TYPE_HARDWARE`]: the cycles stalled in the back end.
+pub const PERF_COUNT_HW_STALLED_CYCLES_BACKEND: u64 = 8;
+/// `config` of [`PERF_TYPE_HARDWARE`]: the cycles at the reference
+/// frequency, which frequency scaling doesn't change.
+pub const PERF_COUNT_HW_REF_CPU_CYCLES: u64 = 9;
+
+/// `config` of [`PERF_TYPE_SOFTWARE`]: the time on the CPU, in
+/// nanoseconds.
+pub const PERF_COUNT_SW_CPU_CLOCK: u64 = 0;
+/// `config` of [`PERF_TYPE_SOFTWARE`]: the time that the task ran, in
+/// nanoseconds.
+pub const PERF_COUNT_SW_TASK_CLOCK: u64 = 1;
+/// `config` of [`PERF_TYPE_SOFTWARE`]: the page faults.
+pub const PERF_COUNT_SW_PAGE_FAULTS: u64 = 2;
+/// `config` of [`PERF_TYPE_SOFTWARE`]: the context switches.
+pub const PERF_COUNT_SW_CONTEXT_SWITCHES: u64 = 3;
+/// `config` of [`PERF_TYPE_SOFTWARE`]: the moves to other CPUs.
+pub const PERF_COUNT_SW_CPU_MIGRATIONS: u64 = 4;
+/// `config` of [`PERF_TYPE_SOFTWARE`]: the page faults without I/O.
+pub const PERF_COUNT_SW_PAGE_FAULTS_MIN: u64 = 5;
+/// `config` of [`PERF_TYPE_SOFTWARE`]: the page faults with I/O.
+pub const PERF_COUNT_SW_PAGE_FAULTS_MAJ: u64 = 6;
+/// `config` of [`PERF_TYPE_SOFTWARE`]: the unaligned accesses that the
+/// kernel fixed up.
+pub const PERF_COUNT_SW_ALIGNMENT_FAULTS: u64 = 7;
+/// `config` of [`PERF_TYPE_SOFTWARE`]: the instructions that the kernel
+/// emulated.
+pub const PERF_COUNT_SW_EMULATION_FAULTS: u64 = 8;
+/// `config` of [`PERF_TYPE_SOFTWARE`]: nothing, for the records of
+/// [`PerfEventFlags::MMAP`] and the like without counting anything.
+pub const PERF_COUNT_SW_DUMMY: u64 = 9;
+
+/// `id` of [`hw_cache_config`]: the L1 data cache.
+pub const PERF_COUNT_HW_CACHE_L1D: u64 = 0;
+/// `id` of [`hw_cache_config`]: the L1 instruction cache.
+pub const PERF_COUNT_HW_CACHE_L1I: u64 = 1;
+/// `id` of [`hw_cache_config`]: the last level cache.
+pub const PERF_COUNT_HW_CACHE_LL: u64 = 2;
+/// `id` of [`hw_cache_config`]: the data TLB.
+pub const PERF_COUNT_HW_CACHE_DTLB: u64 = 3;
+/// `id` of [`hw_cache_config`]: the instruction TLB.
+pub const PERF_COUNT_HW_CACHE_ITLB: u64 = 4;
+/// `id` of [`hw_cache_config`]: the branch prediction unit.
+pub const PERF_COUNT_HW_CACHE_BPU: u64 = 5;
+/// `id` of [`hw_cache_config`]: the accesses to local memory.
+pub const PERF_COUNT_HW_CACHE_NODE: u64 = 6;
+/// `op` of [`hw_cache_config`].
+pub const PERF_COUNT_HW_CACHE_OP_READ: u64 = 0;
+/// `op` of [`hw_cache_config`].
+pub const PERF_COUNT_HW_CACHE_OP_WRITE: u64 = 1;
+/// `op` of [`hw_cache_config`].
+pub const PERF_COUNT_HW_CACHE_OP_PREFETCH: u64 = 2;
+/// `result` of [`hw_cache_config`].
+pub const PERF_COUNT_HW_CACHE_RESULT_ACCESS: u64 = 0;
+/// `result` of [`hw_cache_config`].
+pub const PERF_COUNT_HW_CACHE_RESULT_MISS: u64 = 1;
+
+/// The `config` of a [`PERF_TYPE_HW_CACHE`] event of the cache `id`, the
+/// operation `op`, and the result `result`, e.g. the read misses of
+/// [`PERF_COUNT_HW_CACHE_L1D`].
+pub const fn hw_cache_config(id: u64, op: u64, result: u64) -> u64 {
+    id | (op << 8) | (result << 16)
+}
+
+/// [`PerfEventAttr::sample_type`]: the instruction pointer.
+pub const PERF_SAMPLE_IP: u64 = 1 << 0;
+/// [`PerfEventAttr::sample_type`]: the pid and the tid.
+pub const PERF_SAMPLE_TID: u64 = 1 << 1;
+/// [`PerfEventAttr::sample_type`]: the time.
+pub const PERF_SAMPLE_TIME: u64 = 1 << 2;
+/// [`PerfEventAttr::sample_type`]: the address, e.g. of a page fault.
+pub const PERF_SAMPLE_ADDR: u64 = 1 << 3;
+/// [`PerfEventAttr::sample_type`]: the counts, as of
+/// [`PerfEventAttr::read_format`].
+pub const PERF_SAMPLE_READ: u64 = 1 << 4;
+/// [`PerfEventAttr::sample_type`]: the call chain.
+pub const PERF_SAMPLE_CALLCHAIN: u64 = 1 << 5;
+/// [`PerfEventAttr::sample_type`]: the id of the event.
+pub const PERF_SAMPLE_ID: u64 = 1 << 6;
+/// [`PerfEventAttr::sample_type`]: the CPU.
+pub const PERF_SAMPLE_CPU: u64 = 1 << 7;
+/// [`PerfEventAttr::sample_type`]: the sampling period.
+pub const PERF_SAMPLE_PERIOD: u64 = 1 << 8;
+/// [`PerfEventAttr::sample_type`]: the id of the group leader.
+pub const PERF_SAMPLE_STREAM_ID: u64 = 1 << 9;
+/// [`PerfEventAttr::sample_type`]: the raw data of the event.
+pub const PERF_SAMPLE_RAW: u64 = 1 << 10;
+/// [`PerfEventAttr::sample_type`]: the recent branches.
+pub const PERF_SAMPLE_BRANCH_STACK: u64 = 1 << 11;
+/// [`PerfEventAttr::sample_type`]: the user registers of
+/// [`PerfEventAttr::sample_regs_user`].
+pub const PERF_SAMPLE_REGS_USER: u64 = 1 << 12;
+/// [`PerfEventAttr::sample_type`]: the user stack, up to
+/// [`PerfEventAttr::sample_stack_user`] bytes.
+pub const PERF_SAMPLE_STACK_USER: u64 = 1 << 13;
+
+/// [`PerfEventAttr::read_format`]: the time that the event was enabled.
+pub const PERF_FORMAT_TOTAL_TIME_ENABLED: u64 = 1 << 0;
+/// [`PerfEventAttr::read_format`]: the time that the event was on the PMU,
+/// less than enabled when events take turns.
+pub const PERF_FORMAT_TOTAL_TIME_RUNNING: u64 = 1 << 1;
+/// [`PerfEventAttr::read_format`]: the id of the event.
+pub const PERF_FORMAT_ID: u64 = 1 << 2;
+/// [`PerfEventAttr::read_format`]: the counts of the whole group.
+pub const PERF_FORMAT_GROUP: u64 = 1 << 3;
+/// [`PerfEventAttr::read_format`]: the lost samples (Linux 6.0).
+pub const PERF_FORMAT_LOST: u64 = 1 << 4;
+
+/// `flags` of [`perf_event_open`]: don't add the event to the group of
+/// `group_fd`, only its output.
+pub const PERF_FLAG_FD_NO_GROUP: u32 = 1 << 0;
+/// `flags` of [`perf_event_open`]: write the samples to the ring buffer of
+/// `group_fd`.
+pub const PERF_FLAG_FD_OUTPUT: u32 = 1 << 1;
+/// `flags` of [`perf_event_open`]: `pid` is an fd of a cgroup directory.
+pub const PERF_FLAG_PID_CGROUP: u32 = 1 << 2;
+/// `flags` of [`perf_event_open`]: open the fd with `O_CLOEXEC`.
+pub const PERF_FLAG_FD_CLOEXEC: u32 = 1 << 3;
+
+/// ioctl of an event: start counting.
+pub const PERF_EVENT_IOC_ENABLE: u32 = io(b'$', 0);
+/// ioctl of an event: stop counting.
+pub const PERF_EVENT_IOC_DISABLE: u32 = io(b'$', 1);
+/// ioctl of an event: enable it for the number of overflows of the
+/// argument.
+pub const PERF_EVENT_IOC_REFRESH: u32 = io(b'$', 2);
+/// ioctl of an event: set the count to 0.
+pub const PERF_EVENT_IOC_RESET: u32 = io(b'$', 3);
+/// ioctl of an event: set the sampling period to the `u64` that the
+/// argument points to.
+pub const PERF_EVENT_IOC_PERIOD: u32 = iow::<u64>(b'$', 4);
+/// ioctl of an event: write the id of the event to the `u64` that the
+/// argument points to.
+pub const PERF_EVENT_IOC_ID: u32 = ior::<*mut u64>(b'$', 7);
+/// The argument of [`PERF_EVENT_IOC_ENABLE`], [`PERF_EVENT_IOC_DISABLE`],
+/// and [`PERF_EVENT_IOC_RESET`] that applies them to the whole group.
+pub const PERF_IOC_FLAG_GROUP: u32 = 1;
+
+/// The shift of the bit field of `width` bits at bit `bit` of the flags of
+/// `struct perf_event_attr`, which C puts from the most significant bit on
+/// big-endian targets.
+const fn attr_shift(bit: u32, width: u32) -> u32 {
+    if cfg!(target_endian = "big") {
+        64 - bit - width
+    } else {
+        bit
+    }
+}
+
+/// `value` in the bit field of `width` bits at bit `bit`.
+const fn attr_bits(bit: u32, width: u32, value: u64) -> u64 {
+    value << attr_shift(bit, width)
+}
+
+flags! {
+    /// The bit fields of [`PerfEventAttr`] that are flags.
+    pub struct PerfEventFlags: u64 {
+        /// Start disabled, for `PERF_EVENT_IOC_ENABLE`.
+        const DISABLED = attr_bits(0, 1, 1);
+        /// Count the children that the task creates later too.
+        const INHERIT = attr_bits(1, 1, 1);
+        /// Always be on the PMU, or fail reads when it can't.
+        const PINNED = attr_bits(2, 1, 1);
+        /// Be the only group on the PMU when it is on.
+        const EXCLUSIVE = attr_bits(3, 1, 1);
+        /// Don't count in user space.
+        const EXCLUDE_USER = attr_bits(4, 1, 1);
+        /// Don't count in the kernel, which unprivileged callers need with
+        /// `perf_event_paranoid` 2.
+        const EXCLUDE_KERNEL = attr_bits(5, 1, 1);
+        /// Don't count in the hypervisor.
+        const EXCLUDE_HV = attr_bits(6, 1, 1);
+        /// Don't count when idle.
+        const EXCLUDE_IDLE = attr_bits(7, 1, 1);
+        /// Record the executable mappings.
+        const MMAP = attr_bits(8, 1, 1);
+        /// Record the names of the processes.
+        const COMM = attr_bits(9, 1, 1);
+        /// [`PerfEventAttr::sample_period`] is a frequency in hertz.
+        const FREQ = attr_bits(10, 1, 1);
+        /// Count the inherited events separately.
+        const INHERIT_STAT = attr_bits(11, 1, 1);
+        /// Enable the event at the next `execve`.
+        const ENABLE_ON_EXEC = attr_bits(12, 1, 1);
+        /// Record the forks and exits.
+        const TASK = attr_bits(13, 1, 1);
+        /// [`PerfEventAttr::wakeup_events`] is a number of bytes.
+        const WATERMARK = attr_bits(14, 1, 1);
+        /// Record the data mappings too.
+        const MMAP_DATA = attr_bits(17, 1, 1);
+        /// Add the fields of `sample_type` to the records other than
+        /// samples.
+        const SAMPLE_ID_ALL = attr_bits(18, 1, 1);
+        /// Don't count in the host, for guests.
+        const EXCLUDE_HOST = attr_bits(19, 1, 1);
+        /// Don't count in guests.
+        const EXCLUDE_GUEST = attr_bits(20, 1, 1);
+        /// Leave the kernel out of the call chains.
+        const EXCLUDE_CALLCHAIN_KERNEL = attr_bits(21, 1, 1);
+        /// Leave user space out of the call chains.
+        const EXCLUDE_CALLCHAIN_USER = attr_bits(22, 1, 1);
+        /// Record the mappings with their inodes.
+        const MMAP2 = attr_bits(23, 1, 1);
+        /// Mark the records of the names that `execve` changes.
+        const COMM_EXEC = attr_bits(24, 1, 1);
+        /// Take the times of [`PerfEventAttr::clockid`].
+        const USE_CLOCKID = attr_bits(25, 1, 1);
+        /// Record the context switches.
+        const CONTEXT_SWITCH = attr_bits(26, 1, 1);
+        /// Write the ring buffer from the end backwards.
+        const WRITE_BACKWARD = attr_bits(27, 1, 1);
+        /// Record the namespaces of new tasks.
+        const NAMESPACES = attr_bits(28, 1, 1);
+        /// Send `SIGTRAP` to the task at each overflow (Linux 5.13).
+        const SIGTRAP = attr_bits(37, 1, 1);
+    }
+}
+
+/// How an event is counted or sampled (`struct perf_event_attr`, of Linux
+/// 6.3, which older kernels take as long as the fields that they don't
+/// know are 0):
+///
+/// ```no_run
+/// use rawsys_linux::helpers::{
+///     self, PERF_COUNT_SW_PAGE_FAULTS, PERF_FLAG_FD_CLOEXEC,
+///     PERF_TYPE_SOFTWARE, PerfEventAttr, PerfEventFlags, Pid,
+/// };
+///
+/// let attr = PerfEventAttr::new(PERF_TYPE_SOFTWARE, PERF_COUNT_SW_PAGE_FAULTS)
+///     .flags(PerfEventFlags::EXCLUDE_KERNEL);
+/// let fd = unsafe {
+///     helpers::perf_event_open(&attr, Pid::from_raw(0), -1, -1, PERF_FLAG_FD_CLOEXEC)
+/// }?;
+/// let mut count = [0u8; 8];
+/// helpers::read(fd, &mut count)?;
+/// # Ok::<(), rawsys_linux::Errno>(())
+/// ```
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PerfEventAttr {
+    /// `PERF_TYPE_*`, or the type of a PMU in
+    /// `/sys/bus/event_source/devices/*/type`.
+    pub ty: u32,
+    /// The size of the struct, which [`new`](Self::new) fills in.
+    pub size: u32,
+    /// The event of the type, e.g. `PERF_COUNT_HW_*`.
+    pub config: u64,
+    /// The events between samples, or their frequency with
+    /// [`PerfEventFlags::FREQ`], or 0 for no samples.
+    pub sample_period: u64,
+    /// `PERF_SAMPLE_*`: what samples have.
+    pub sample_type: u64,
+    /// `PERF_FORMAT_*`: what reading the fd returns.
+    pub read_format: u64,
+    /// The flags and `precise_ip`.
+    pub flags: PerfEventFlags,
+    /// The samples, or bytes with [`PerfEventFlags::WATERMARK`], between
+    /// wakeups of `poll`.
+    pub wakeup_events: u32,
+    /// `HW_BREAKPOINT_*` for [`PERF_TYPE_BREAKPOINT`].
+    pub bp_type: u32,
+    /// The address of a breakpoint, or more of `config` for other types.
+    pub config1: u64,
+    /// The length of a breakpoint, or more of `config` for other types.
+    pub config2: u64,
+    pub branch_sample_type: u64,
+    /// The registers of [`PERF_SAMPLE_REGS_USER`], as a mask of the
+    /// architecture's `PERF_REG_*`.
+    pub sample_regs_user: u64,
+    /// The bytes of the stack of [`PERF_SAMPLE_STACK_USER`].
+    pub sample_stack_user: u32,
+    /// The clock of [`PerfEventFlags::USE_CLOCKID`], e.g.
+    /// [`CLOCK_MONOTONIC`](super::CLOCK_MONOTONIC).
+    pub clockid: i32,
+    pub sample_regs_intr: u64,
+    pub aux_watermark: u32,
+    pub sample_max_stack: u16,
+    reserved_2: u16,
+    pub aux_sample_size: u32,
+    reserved_3: u32,
+    /// The `si_perf_data` of [`PerfEventFlags::SIGTRAP`] signals.
+    pub sig_data: u64,
+    pub config3: u64,
+}
+
+impl PerfEventAttr {
+    /// Counts the event `config` of the type `ty` from when it is opened,
+    /// without sampling.
+    pub const fn new(ty: u32, config: u64) -> Self {
+        Self {
+            ty,
+            size: size_of::<Self>() as u32,
+            config,
+            sample_period: 0,
+            sample_type: 0,
+            read_format: 0,
+            flags: PerfEventFlags::empty(),
+            wakeup_events: 0,
+            bp_type: 0,
+            config1: 0,
+            config2: 0,
+            branch_sample_type: 0,
+            sample_regs_user: 0,
+            sample_stack_user: 0,
+            clockid: 0,
+            sample_regs_intr: 0,
+            aux_watermark: 0,
+            sample_max_stack: 0,
+            reserved_2: 0,
+            aux_sample_size: 0,
+            reserved_3: 0,
+            sig_data: 0,
+            config3: 0,
+        }
+    }
+
+    /// Adds `flags`.
+    #[must_use]
+    pub const fn flags(mut self, flags: PerfEventFlags) -> Self {
+        self.flags =
+            PerfEventFlags::from_bits_retain(self.flags.bits() | flags.bits());
+        self
+    }
+
+    /// Samples every `period` events, with the fields `sample_type`
+    /// (`PERF_SAMPLE_*`).
+    #[must_use]
+    pub const fn sample_period(
+        mut self,
+        period: u64,
+        sample_type: u64,
+    ) -> Self {
+        self.sample_period = period;
+        self.sample_type = sample_type;
+        self
+    }
+
+    /// Samples `freq` times a second, with the fields `sample_type`.
+    #[must_use]
+    pub const fn sample_freq(self, freq: u64, sample_type: u64) -> Self {
+        self.sample_period(freq, sample_type)
+            .flags(PerfEventFlags::FREQ)
+    }
+
+    /// Reads the fd with the fields `read_format` (`PERF_FORMAT_*`).
+    #[must_use]
+    pub const fn read_format(mut self, read_format: u64) -> Self {
+        self.read_format = read_format;
+        self
+    }
+
+    /// Wakes up `poll` every `events` samples.
+    #[must_use]
+    pub const fn wakeup_events(mut self, events: u32) -> Self {
+        self.wakeup_events = events;
+        self
+    }
+
+    /// How exact the instruction pointers of samples must be, from 0 for
+    /// any skid to 3 for none.
+    #[must_use]
+    pub const fn precise_ip(mut self, level: u8) -> Self {
+        let mask = attr_bits(15, 2, 0b11);
+        let bits = attr_bits(15, 2, level as u64 & 0b11);
+        self.flags =
+            PerfEventFlags::from_bits_retain(self.flags.bits() & !mask | bits);
+        self
+    }
+
+    /// The precision of [`precise_ip`](Self::precise_ip).
+    pub const fn precise_ip_level(&self) -> u8 {
+        ((self.flags.bits() >> attr_shift(15, 2)) & 0b11) as u8
+    }
+}
+
+/// Opens an event of `attr` and returns its fd, which reading returns the
+/// count of, mapping returns the [`PerfEventMmapPage`] and ring buffer of,
+/// and `PERF_EVENT_IOC_*` control.
+///
+/// The event counts the process or thread `pid`, or the caller if it is 0,
+/// or every process if it is -1, on the CPU `cpu`, or on any CPU if it is
+/// -1. `group_fd` is the fd of the leader of the group to add the event
+/// to, or -1 for a new group. Unprivileged callers can count their own
+/// processes as `/proc/sys/kernel/perf_event_paranoid` allows, and fail
+/// with `EACCES` or `EPERM` otherwise.
+///
+/// # Safety
+///
+/// The event can send signals to the caller, such as `SIGTRAP` with
+/// [`PerfEventFlags::SIGTRAP`], and break at breakpoints of
+/// [`PERF_TYPE_BREAKPOINT`], which the caller must be ready for.
+pub unsafe fn perf_event_open(
+    attr: &PerfEventAttr,
+    pid: Pid,
+    cpu: i32,
+    group_fd: i32,
+    flags: u32,
+) -> Result<i32, Errno> {
+    let fd = unsafe {
+        crate::syscall5(
+            Sysno::perf_event_open,
+            core::ptr::from_ref(attr) as SyscallWord,
+            SyscallWord::from(pid),
+            cpu as SyscallWord,
+            group_fd as SyscallWord,
+            SyscallWord::from(flags),
+        )
+    }?;
+    Ok(fd as i32)
+}
+
+/// The first page that mapping the fd of an event maps
+/// (`struct perf_event_mmap_page`), which the ring buffer of the samples
+/// follows, at `data_offset` for `data_size` bytes.
+///
+/// The kernel changes it while it is mapped, so the fields that it writes
+/// are read with the volatile reads of [`data_head`](Self::data_head) and
+/// the like.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct PerfEventMmapPage {
+    pub version: u32,
+    pub compat_version: u32,
+    /// The sequence count of the fields up to `time_mask`, odd while the
+    /// kernel writes them.
+    pub lock: u32,
+    /// One more than the counter of `rdpmc`, or 0.
+    pub index: u32,
+    pub offset: i64,
+    pub time_enabled: u64,
+    pub time_running: u64,
+    /// `cap_user_rdpmc` and the other capability bits.
+    pub capabilities: u64,
+    pub pmc_width: u16,
+    pub time_shift: u16,
+    pub time_mult: u32,
+    pub time_offset: u64,
+    pub time_zero: u64,
+    pub size: u32,
+    reserved_1: u32,
+    pub time_cycles: u64,
+    pub time_mask: u64,
+    reserved: [u64; 116],
+    /// The end of the data that the kernel wrote, as a position in the
+    /// ring buffer that only grows.
+    pub data_head: u64,
+    /// The end of the data that the reader read, which the reader writes.
+    pub data_tail: u64,
+    pub data_offset: u64,
+    pub data_size: u64,
+    pub aux_head: u64,
+    pub aux_tail: u64,
+    pub aux_offset: u64,
+    pub aux_size: u64,
+}
+
+impl PerfEventMmapPage {
+    /// Reads `data_head` of the mapped page `page`, before the data that it
+    /// covers.
+    ///
+    /// # Safety
+    ///
+    /// `page` must be the mapped first page of an event. On 32-bit targets,
+    /// the read may be torn, as the kernel's documentation warns.
+    pub unsafe fn data_head(page: NonNull<Self>) -> u64 {
+        let head = unsafe {
+            core::ptr::addr_of!((*page.as_ptr()).data_head).read_volatile()
+        };
+        fence(Ordering::Acquire);
+        head
+    }
+
+    /// Writes `data_tail` of the mapped page `page`, after reading the data
+    /// up to it, which lets the kernel reuse that space.
+    ///
+    /// # Safety
+    ///
+    /// As for [`data_head`](Self::data_head), and the mapping must be
+    /// writable.
+    pub unsafe fn set_data_tail(page: NonNull<Self>, tail: u64) {
+        fence(Ordering::Release);
+        unsafe {
+            core::ptr::addr_of_mut!((*page.as_ptr()).data_tail)
+                .write_volatile(tail);
+        }
+    }
+}
+
+/// The header of each record in the ring buffer
+/// (`struct perf_event_header`).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PerfEventHeader {
+    /// `PERF_RECORD_*`.
+    pub ty: u32,
+    pub misc: u16,
+    /// The size of the record, with the header.
+    pub size: u16,
+}
+
+/// [`PerfEventHeader::ty`]: a mapping, of [`PerfEventFlags::MMAP`].
+pub const PERF_RECORD_MMAP: u32 = 1;
+/// [`PerfEventHeader::ty`]: samples were lost.
+pub const PERF_RECORD_LOST: u32 = 2;
+/// [`PerfEventHeader::ty`]: a name of a process.
+pub const PERF_RECORD_COMM: u32 = 3;
+/// [`PerfEventHeader::ty`]: an exit.
+pub const PERF_RECORD_EXIT: u32 = 4;
+/// [`PerfEventHeader::ty`]: a fork.
+pub const PERF_RECORD_FORK: u32 = 7;
+/// [`PerfEventHeader::ty`]: a sample.
+pub const PERF_RECORD_SAMPLE: u32 = 9;
+/// [`PerfEventHeader::ty`]: a mapping, of [`PerfEventFlags::MMAP2`].
+pub const PERF_RECORD_MMAP2: u32 = 10;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::{close, read};
+    use crate::ioctl::ioctl;
+
+    #[test]
+    fn layout() {
+        assert_eq!(size_of::<PerfEventAttr>(), 136);
+        assert_eq!(core::mem::offset_of!(PerfEventAttr, flags), 40);
+        assert_eq!(core::mem::offset_of!(PerfEventAttr, sig_data), 120);
+        assert_eq!(core::mem::offset_of!(PerfEventMmapPage, data_head), 1024);
+        let attr = PerfEventAttr::new(PERF_TYPE_HARDWARE, 0).precise_ip(2);
+        assert_eq!(attr.precise_ip_level(), 2);
+        if cfg!(target_endian = "little") {
+            assert_eq!(PerfEventFlags::EXCLUDE_KERNEL.bits(), 1 << 5);
+            assert_eq!(attr.flags.bits(), 2 << 15);
+        }
+    }
+
+    #[test]
+    fn count() {
+        let attr = PerfEventAttr::new(PERF_TYPE_SOFTWARE, PERF_COUNT_SW_DUMMY)
+            .flags(PerfEventFlags::DISABLED | PerfEventFlags::EXCLUDE_KERNEL);
+        let flags = PERF_FLAG_FD_CLOEXEC;
+        let fd = match unsafe {
+            perf_event_open(&attr, Pid::from_raw(0), -1, -1, flags)
+        } {
+            Ok(fd) => fd,
+            // Without perf events, or without the permission for them.
+            Err(
+                Errno::EACCES | Errno::EPERM | Errno::ENOENT | Errno::ENOSYS,
+            ) => {
+                return;
+            }
+            Err(err) => panic!("perf_event_open: {err}"),
+        };
+        unsafe {
+            ioctl(fd, PERF_EVENT_IOC_ENABLE, 0).unwrap();
+            ioctl(fd, PERF_EVENT_IOC_RESET, 0).unwrap();
+        }
+        let mut count = [0u8; 8];
+        assert_eq!(read(fd, &mut count), Ok(8));
+        assert_eq!(u64::from_ne_bytes(count), 0);
+        unsafe { close(fd) }.unwrap();
+    }
+}
@@ -0,0 +1,220 @@
+//! Process file descriptors.
+
+use core::time::Duration;
+
+#[cfg(not(feature = "default_kernel_5_4"))]
+use super::Fd;
+use super::{P_PIDFD, Pid, Timespec, WaitStatus, close, waitid};
+use crate::{Errno, SyscallWord, Sysno};
+
+const POLLIN: i16 = 1;
+
+/// `ppoll` with a 64-bit `struct timespec`, which is `ppoll_time64` on
+/// 32-bit targets.
+#[cfg(target_pointer_width = "64")]
+const PPOLL: Sysno = Sysno::ppoll;
+#[cfg(target_pointer_width = "32")]
+const PPOLL: Sysno = Sysno::ppoll_time64;
+
+/// `struct pollfd`
+#[repr(C)]
+struct PollFd {
+    fd: i32,
+    events: i16,
+    revents: i16,
+}
+
+/// A file descriptor that refers to a process (Linux 5.3), and is closed
+/// when dropped.
+///
+/// Unlike a pid, it keeps referring to the same process after it exits and
+/// its pid is reused, so signals can't reach the wrong process:
+///
+/// ```no_run
+/// use rawsys_linux::helpers::{Pid, PidFd};
+/// use std::time::Duration;
+///
+/// # let pid = Pid::from_raw(1);
+/// let pidfd = PidFd::open(pid, 0)?;
+/// pidfd.send_signal(15)?; // SIGTERM
+/// if !pidfd.poll(Some(Duration::from_secs(1)))? {
+///     pidfd.send_signal(9)?; // SIGKILL
+/// }
+/// # Ok::<(), rawsys_linux::Errno>(())
+/// ```
+///
+/// [`CloneArgs::pidfd`](super::CloneArgs::pidfd) gets one for a new child
+/// without the race of opening it by pid.
+#[derive(Debug)]
+pub struct PidFd(i32);
+
+impl PidFd {
+    /// Opens the process `pid`, which must be a thread group leader, with
+    /// `flags` (`O_NONBLOCK` for [`wait`](Self::wait) to fail with `EAGAIN`
+    /// instead of waiting, Linux 5.10). The fd has `O_CLOEXEC`.
+    pub fn open(pid: Pid, flags: u32) -> Result<Self, Errno> {
+        let fd = unsafe {
+            crate::syscall2(
+                Sysno::pidfd_open,
+                SyscallWord::from(pid),
+                SyscallWord::from(flags),
+            )
+        }?;
+        Ok(Self(fd as i32))
+    }
+
+    /// Takes ownership of the pidfd `fd`, e.g. from `clone3`.
+    ///
+    /// # Safety
+    ///
+    /// `fd` must be a pidfd that nothing else uses or closes.
+    pub unsafe fn from_raw(fd: i32) -> Self {
+        Self(fd)
+    }
+
+    /// The fd, which stays owned by `self`.
+    pub fn as_raw(&self) -> i32 {
+        self.0
+    }
+
+    /// Gives up ownership of the fd without closing it.
+    pub fn into_raw(self) -> i32 {
+        let fd = self.0;
+        core::mem::forget(self);
+        fd
+    }
+
+    /// Sends `signal` to the process, as `kill` would, or checks that it
+    /// could if `signal` is 0 (Linux 5.1). Fails with `ESRCH` if it has
+    /// exited.
+    pub fn send_signal(&self, signal: i32) -> Result<(), Errno> {
+        unsafe {
+            crate::syscall4(
+                Sysno::pidfd_send_signal,
+                self.0 as SyscallWord,
+                signal as SyscallWord,
+                0,
+                0,
+            )
+        }
+        .map(drop)
+    }
+
+    /// Duplicates the fd `fd` of the process into the calling one, and
+    /// returns the copy, which has `O_CLOEXEC` (Linux 5.6). Needs the
+    /// permission to `ptrace` the process.
+    #[cfg(not(feature = "default_kernel_5_4"))]
+    pub fn get_fd(&self, fd: Fd) -> Result<i32, Errno> {
+        let fd = unsafe {
+            crate::syscall3(
+                Sysno::pidfd_getfd,
+                self.0 as SyscallWord,
+                SyscallWord::from(fd),
+                0,
+            )
+        }?;
+        Ok(fd as i32)
+    }
+
+    /// Waits until the process exits, and returns whether it did, or `false`
+    /// if `timeout` passed first. `None` waits as long as it takes.
+    pub fn poll(&self, timeout: Option<Duration>) -> Result<bool, Errno> {
+        let mut pollfd = PollFd {
+            fd: self.0,
+            events: POLLIN,
+            revents: 0,
+        };
+        let timespec = timeout.map(Timespec::from);
+        let ready = unsafe {
+            crate::syscall5(
+                PPOLL,
+                core::ptr::from_mut(&mut pollfd) as SyscallWord,
+                1,
+                timespec.as_ref().map_or(0, |timespec| {
+                    core::ptr::from_ref(timespec) as SyscallWord
+                }),
+                0,
+                0,
+            )
+        }?;
+        Ok(ready > 0)
+    }
+
+    /// Waits for a change in the state of the process, which must be a
+    /// child, with `waitid(P_PIDFD)` (Linux 5.4). `options` needs one of
+    /// `WEXITED`, `WSTOPPED`, and `WCONTINUED`. Returns `None` with
+    /// `WNOHANG` if there is no change yet.
+    pub fn wait(&self, options: u32) -> Result<Option<WaitStatus>, Errno> {
+        let status = waitid(P_PIDFD, self.0.cast_unsigned(), options, None)?;
+        Ok(status.map(|(_, status)| status))
+    }
+}
+
+impl Drop for PidFd {
+    fn drop(&mut self) {
+        let _ = unsafe { close(self.0) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::{
+        CloneArgs, CloneResult, WEXITED, WNOHANG, WNOWAIT, clone3, exit_group,
+        getpid, pipe2, read,
+    };
+
+    #[test]
+    fn child() {
+        let [r, w] = pipe2(0).unwrap();
+        let mut pidfd = -1;
+        let args = CloneArgs::new().pidfd(&mut pidfd).exit_signal(17);
+        match unsafe { clone3(&args) } {
+            Ok(CloneResult::Child) => {
+                // Wait until killed.
+                let _ = read(r, &mut [0]);
+                exit_group(1);
+            }
+            Ok(CloneResult::Parent(_)) => {}
+            // Blocked by the container.
+            Err(Errno::ENOSYS | Errno::EPERM) => return,
+            Err(errno) => panic!("clone3: {errno}"),
+        }
+        let pidfd = unsafe { PidFd::from_raw(pidfd) };
+        assert_eq!(pidfd.poll(Some(Duration::ZERO)), Ok(false));
+        assert_eq!(pidfd.wait(WEXITED | WNOHANG), Ok(None));
+
+        // The child's end of the pipe, which `ptrace` restrictions may
+        // keep from us.
+        #[cfg(not(feature = "default_kernel_5_4"))]
+        match pidfd.get_fd(Fd::from_raw(w)) {
+            Ok(fd) => unsafe { close(fd) }.unwrap(),
+            Err(errno) => assert_eq!(errno, Errno::EPERM),
+        }
+
+        pidfd.send_signal(0).unwrap();
+        pidfd.send_signal(9).unwrap();
+        assert_eq!(pidfd.poll(None), Ok(true));
+        let killed = WaitStatus::Signaled {
+            signal: 9,
+            core_dumped: false,
+        };
+        assert_eq!(pidfd.wait(WEXITED | WNOWAIT), Ok(Some(killed)));
+        assert_eq!(pidfd.wait(WEXITED), Ok(Some(killed)));
+        assert_eq!(pidfd.send_signal(0), Err(Errno::ESRCH));
+        unsafe {
+            close(r).unwrap();
+            close(w).unwrap();
+        }
+    }
+
+    #[test]
+    fn open() {
+        let pidfd = PidFd::open(getpid(), 0).unwrap();
+        assert_eq!(pidfd.poll(Some(Duration::ZERO)), Ok(false));
+        // Not a child.
+        assert_eq!(pidfd.wait(WEXITED), Err(Errno::ECHILD));
+        let fd = pidfd.into_raw();
+        unsafe { close(fd) }.unwrap();
+    }
+}
@@ -0,0 +1,221 @@
+//! `prctl` with typed operations.
+
+use core::ffi::CStr;
+use core::ops::Range;
+use core::ptr::NonNull;
+
+use super::Signal;
+use crate::seccomp::SockFprog;
+use crate::sud::Selector;
+use crate::{Errno, SyscallWord, Sysno};
+
+const PR_SET_PDEATHSIG: u32 = 1;
+const PR_SET_DUMPABLE: u32 = 4;
+const PR_SET_NAME: u32 = 15;
+const PR_SET_SECCOMP: u32 = 22;
+const PR_SET_NO_NEW_PRIVS: u32 = 38;
+const PR_CAP_AMBIENT: u32 = 47;
+const PR_SET_SYSCALL_USER_DISPATCH: u32 = 59;
+const PR_SET_VMA: u32 = 0x5356_4d41;
+
+const PR_CAP_AMBIENT_IS_SET: u32 = 1;
+const PR_CAP_AMBIENT_RAISE: u32 = 2;
+const PR_CAP_AMBIENT_LOWER: u32 = 3;
+const PR_CAP_AMBIENT_CLEAR_ALL: u32 = 4;
+
+const SECCOMP_MODE_STRICT: u32 = 1;
+const SECCOMP_MODE_FILTER: u32 = 2;
+
+const PR_SYS_DISPATCH_OFF: u32 = 0;
+const PR_SYS_DISPATCH_ON: u32 = 1;
+
+const PR_SET_VMA_ANON_NAME: u32 = 0;
+
+/// An operation on the ambient capabilities of the calling thread, which
+/// are kept across `execve` of files without file capabilities, by their
+/// numbers, such as [`CAP_NET_BIND_SERVICE`](super::CAP_NET_BIND_SERVICE).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CapAmbient {
+    /// Whether the capability is ambient, which [`prctl`] returns as 1 or 0.
+    IsSet(u32),
+    /// Makes the capability ambient, which it must be permitted and
+    /// inheritable for.
+    Raise(u32),
+    Lower(u32),
+    ClearAll,
+}
+
+/// The seccomp mode of [`Prctl::SetSeccomp`].
+#[derive(Debug, Clone, Copy)]
+pub enum SeccompMode<'a> {
+    /// Only `read`, `write`, `_exit`, and `sigreturn` are allowed, and the
+    /// other syscalls kill the thread.
+    Strict,
+    /// The filter program, as from
+    /// [`Program::fprog`](crate::seccomp::Program::fprog).
+    Filter(&'a SockFprog<'a>),
+}
+
+/// A `prctl` operation on the calling thread or process, with its
+/// arguments.
+#[derive(Debug, Clone)]
+pub enum Prctl<'a> {
+    /// Names the calling thread (`PR_SET_NAME`), as `/proc/self/comm`
+    /// shows. Names of more than 15 bytes are cut.
+    SetName(&'a CStr),
+    /// Sends the signal to the calling thread when the thread that created
+    /// it exits (`PR_SET_PDEATHSIG`), or nothing with `None`. It is cleared
+    /// in the children, and by `execve` of setuid binaries.
+    SetPdeathsig(Option<Signal>),
+    /// Sets `no_new_privs` (`PR_SET_NO_NEW_PRIVS`), as
+    /// [`seccomp::no_new_privs`](crate::seccomp::no_new_privs) does.
+    SetNoNewPrivs,
+    /// Changes or checks the ambient capabilities (`PR_CAP_AMBIENT`).
+    CapAmbient(CapAmbient),
+    /// Whether the process may dump core and be attached to with ptrace by
+    /// its user (`PR_SET_DUMPABLE`).
+    SetDumpable(bool),
+    /// Puts the calling thread in a seccomp mode (`PR_SET_SECCOMP`), as
+    /// [`seccomp::install`](crate::seccomp::install) does without flags.
+    SetSeccomp(SeccompMode<'a>),
+    /// Turns on Syscall User Dispatch for the calling thread with the
+    /// region that is always allowed and the selector, or turns it off with
+    /// `None` (`PR_SET_SYSCALL_USER_DISPATCH`, Linux 5.11), as
+    /// [`sud::enable`](crate::sud::enable) and
+    /// [`sud::disable`](crate::sud::disable) do.
+    SetSyscallUserDispatch(Option<(Range<usize>, &'static Selector)>),
+    /// Names the anonymous mapping of `len` bytes from `addr`, as
+    /// `/proc/self/maps` shows (`[anon:name]`), or removes its name with
+    /// `None` (`PR_SET_VMA_ANON_NAME`, Linux 5.17). Fails with `EINVAL`
+    /// without `CONFIG_ANON_VMA_NAME`.
+    SetVmaAnonName {
+        addr: NonNull<u8>,
+        len: usize,
+        name: Option<&'a CStr>,
+    },
+}
+
+impl Prctl<'_> {
+    /// The option and the other arguments.
+    fn args(&self) -> [SyscallWord; 5] {
+        let word = SyscallWord::from;
+        let cstr = |name: &CStr| name.as_ptr() as SyscallWord;
+        match *self {
+            Self::SetName(name) => [word(PR_SET_NAME), cstr(name), 0, 0, 0],
+            Self::SetPdeathsig(signal) => {
+                let signal = signal.map_or(0, Signal::to_raw);
+                [word(PR_SET_PDEATHSIG), signal as SyscallWord, 0, 0, 0]
+            }
+            Self::SetNoNewPrivs => [word(PR_SET_NO_NEW_PRIVS), 1, 0, 0, 0],
+            Self::CapAmbient(op) => {
+                let (op, cap) = match op {
+                    CapAmbient::IsSet(cap) => (PR_CAP_AMBIENT_IS_SET, cap),
+                    CapAmbient::Raise(cap) => (PR_CAP_AMBIENT_RAISE, cap),
+                    CapAmbient::Lower(cap) => (PR_CAP_AMBIENT_LOWER, cap),
+                    CapAmbient::ClearAll => (PR_CAP_AMBIENT_CLEAR_ALL, 0),
+                };
+                [word(PR_CAP_AMBIENT), word(op), word(cap), 0, 0]
+            }
+            Self::SetDumpable(dumpable) => {
+                [word(PR_SET_DUMPABLE), SyscallWord::from(dumpable), 0, 0, 0]
+            }
+            Self::SetSeccomp(SeccompMode::Strict) => {
+                [word(PR_SET_SECCOMP), word(SECCOMP_MODE_STRICT), 0, 0, 0]
+            }
+            Self::SetSeccomp(SeccompMode::Filter(fprog)) => [
+                word(PR_SET_SECCOMP),
+                word(SECCOMP_MODE_FILTER),
+                core::ptr::from_ref(fprog) as SyscallWord,
+                0,
+                0,
+            ],
+            Self::SetSyscallUserDispatch(None) => [
+                word(PR_SET_SYSCALL_USER_DISPATCH),
+                word(PR_SYS_DISPATCH_OFF),
+                0,
+                0,
+                0,
+            ],
+            Self::SetSyscallUserDispatch(Some((ref region, selector))) => [
+                word(PR_SET_SYSCALL_USER_DISPATCH),
+                word(PR_SYS_DISPATCH_ON),
+                region.start as SyscallWord,
+                region.end.saturating_sub(region.start) as SyscallWord,
+                core::ptr::from_ref(selector) as SyscallWord,
+            ],
+            Self::SetVmaAnonName { addr, len, name } => [
+                word(PR_SET_VMA),
+                word(PR_SET_VMA_ANON_NAME),
+                addr.as_ptr() as SyscallWord,
+                len as SyscallWord,
+                name.map_or(0, cstr),
+            ],
+        }
+    }
+}
+
+/// Makes the `prctl` operation `op`, and returns what it returns, which is
+/// 0 except for [`CapAmbient::IsSet`].
+///
+/// ```no_run
+/// use rawsys_linux::helpers::{self, Prctl, Signal};
+///
+/// unsafe { helpers::prctl(Prctl::SetName(c"worker")) }?;
+/// unsafe { helpers::prctl(Prctl::SetPdeathsig(Some(Signal::Kill))) }?;
+/// # Ok::<(), rawsys_linux::Errno>(())
+/// ```
+///
+/// # Safety
+///
+/// [`Prctl::SetSeccomp`] and [`Prctl::SetSyscallUserDispatch`] change what
+/// every later syscall of the thread does, as for
+/// [`Program::install`](crate::seccomp::Program::install) and
+/// [`sud::enable`](crate::sud::enable). The other operations are always
+/// sound.
+pub unsafe fn prctl(op: Prctl<'_>) -> Result<usize, Errno> {
+    let [option, arg2, arg3, arg4, arg5] = op.args();
+    let ret = unsafe {
+        crate::syscall5(Sysno::prctl, option, arg2, arg3, arg4, arg5)
+    }?;
+    Ok(ret as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::{MappedRegion, ProtFlags, close, openat, read};
+
+    #[test]
+    fn thread() {
+        unsafe { prctl(Prctl::SetName(c"prctl-test")) }.unwrap();
+        let comm = c"/proc/thread-self/comm";
+        let fd = openat(crate::consts::AT_FDCWD, comm, 0, 0).unwrap();
+        let mut buf = [0u8; 16];
+        let len = read(fd, &mut buf).unwrap();
+        unsafe { close(fd) }.unwrap();
+        assert_eq!(&buf[..len], b"prctl-test\n");
+
+        unsafe { prctl(Prctl::SetPdeathsig(None)) }.unwrap();
+        unsafe { prctl(Prctl::SetDumpable(true)) }.unwrap();
+        let is_set = Prctl::CapAmbient(CapAmbient::IsSet(0));
+        assert!(unsafe { prctl(is_set) }.unwrap() <= 1);
+        let is_set = Prctl::CapAmbient(CapAmbient::IsSet(u32::MAX));
+        assert_eq!(unsafe { prctl(is_set) }, Err(Errno::EINVAL));
+    }
+
+    #[test]
+    fn vma_name() {
+        let region = MappedRegion::anonymous(4096, ProtFlags::READ).unwrap();
+        let addr = NonNull::new(region.as_ptr()).unwrap();
+        let name = Prctl::SetVmaAnonName {
+            addr,
+            len: region.len(),
+            name: Some(c"prctl-test"),
+        };
+        match unsafe { prctl(name) } {
+            // Without `CONFIG_ANON_VMA_NAME`.
+            Ok(0) | Err(Errno::EINVAL) => {}
+            result => panic!("PR_SET_VMA: {result:?}"),
+        }
+    }
+}
@@ -0,0 +1,91 @@
+//! Random bytes from the kernel's CSPRNG.
+
+use super::GrndFlags;
+use crate::Errno;
+#[cfg(not(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "riscv64",
+    target_arch = "loongarch64",
+    target_arch = "mips",
+    target_arch = "mips64",
+    target_arch = "s390x"
+)))]
+use crate::{SyscallWord, Sysno};
+
+/// Fills all of `buf` with random bytes, calling `getrandom` again after
+/// short reads and signals.
+///
+/// Without [`GrndFlags::NONBLOCK`], it waits until the CSPRNG is seeded at
+/// boot; with it, it fails with `EAGAIN` instead, and with
+/// [`GrndFlags::INSECURE`] it doesn't wait or fail (Linux 5.6). Where there
+/// is a vDSO, it goes through [`vdso::getrandom`](crate::vdso::getrandom),
+/// which doesn't enter the kernel with `std` on Linux 6.11 and later.
+pub fn getrandom(mut buf: &mut [u8], flags: GrndFlags) -> Result<(), Errno> {
+    while !buf.is_empty() {
+        match fill(buf, flags.bits()) {
+            Ok(len) => buf = &mut buf[len..],
+            Err(Errno::EINTR) => {}
+            Err(errno) => return Err(errno),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "riscv64",
+    target_arch = "loongarch64",
+    target_arch = "mips",
+    target_arch = "mips64",
+    target_arch = "s390x"
+))]
+fn fill(buf: &mut [u8], flags: u32) -> Result<usize, Errno> {
+    crate::vdso::getrandom(buf, flags)
+}
+
+#[cfg(not(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "riscv64",
+    target_arch = "loongarch64",
+    target_arch = "mips",
+    target_arch = "mips64",
+    target_arch = "s390x"
+)))]
+fn fill(buf: &mut [u8], flags: u32) -> Result<usize, Errno> {
+    let len = unsafe {
+        crate::syscall3(
+            Sysno::getrandom,
+            buf.as_mut_ptr() as SyscallWord,
+            buf.len() as SyscallWord,
+            SyscallWord::from(flags),
+        )
+    }?;
+    Ok(len as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn whole_buffer() {
+        let mut buf = [0u8; 4096];
+        getrandom(&mut buf, GrndFlags::empty()).unwrap();
+        assert_ne!(buf[4032..], [0; 64]);
+        getrandom(&mut [], GrndFlags::NONBLOCK).unwrap();
+        getrandom(&mut buf[..16], GrndFlags::NONBLOCK).unwrap();
+        assert_eq!(
+            getrandom(&mut buf, GrndFlags::from_bits_retain(0x80)),
+            Err(Errno::EINVAL)
+        );
+    }
+}
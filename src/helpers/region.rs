@@ -0,0 +1,242 @@
+//! An owned memory mapping.
+
+use core::ptr::NonNull;
+
+use super::{MapFlags, ProtFlags, mmap, mprotect, munmap};
+use crate::{Errno, SyscallWord, Sysno};
+
+/// `flags` of `mremap`: the mapping may move if it can't grow in place.
+const MREMAP_MAYMOVE: u32 = 1;
+
+/// A memory mapping that is unmapped when dropped, like a `Box<[u8]>` that
+/// comes straight from the kernel:
+///
+/// ```no_run
+/// use rawsys_linux::helpers::{MappedRegion, ProtFlags};
+///
+/// let mut region =
+///     MappedRegion::anonymous(4096, ProtFlags::READ | ProtFlags::WRITE)?;
+/// region.as_mut_slice().unwrap()[0] = 0xc3;
+/// region.protect(ProtFlags::READ | ProtFlags::EXEC)?;
+/// assert!(region.as_mut_slice().is_none());
+/// # Ok::<(), rawsys_linux::Errno>(())
+/// ```
+///
+/// The slices are only available while the access allows them, and
+/// [`protect`](Self::protect) and [`remap`](Self::remap) take `&mut self`,
+/// so no slice outlives the access it was made with.
+#[derive(Debug)]
+pub struct MappedRegion {
+    addr: NonNull<u8>,
+    len: usize,
+    prot: ProtFlags,
+}
+
+// It owns its memory like a `Box<[u8]>`.
+unsafe impl Send for MappedRegion {}
+unsafe impl Sync for MappedRegion {}
+
+impl MappedRegion {
+    /// Maps `len` bytes of private memory, which start as zeros.
+    pub fn anonymous(len: usize, prot: ProtFlags) -> Result<Self, Errno> {
+        let flags = MapFlags::PRIVATE | MapFlags::ANONYMOUS;
+        let addr =
+            unsafe { mmap(core::ptr::null_mut(), len, prot, flags, -1, 0) }?;
+        Ok(Self { addr, len, prot })
+    }
+
+    /// Maps `len` bytes of `fd` from `offset`, with `flags`, which should
+    /// include `SHARED` or `PRIVATE` but not `FIXED`. The fd can be closed
+    /// afterwards.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be truncated or changed by others while the
+    /// mapping exists, or its slices would change under Rust's feet or
+    /// fault with `SIGBUS`.
+    pub unsafe fn file(
+        fd: i32,
+        offset: u64,
+        len: usize,
+        prot: ProtFlags,
+        flags: MapFlags,
+    ) -> Result<Self, Errno> {
+        let addr = unsafe {
+            mmap(core::ptr::null_mut(), len, prot, flags, fd, offset)
+        }?;
+        Ok(Self { addr, len, prot })
+    }
+
+    /// Takes ownership of the mapping of `len` bytes at `addr`.
+    ///
+    /// # Safety
+    ///
+    /// The mapping must have been made with `prot`, and nothing else may
+    /// use or unmap it.
+    pub unsafe fn from_raw(
+        addr: NonNull<u8>,
+        len: usize,
+        prot: ProtFlags,
+    ) -> Self {
+        Self { addr, len, prot }
+    }
+
+    /// Gives up ownership of the mapping without unmapping it, and returns
+    /// its address and length.
+    pub fn into_raw(self) -> (NonNull<u8>, usize) {
+        let raw = (self.addr, self.len);
+        core::mem::forget(self);
+        raw
+    }
+
+    /// The start of the mapping, which is page-aligned.
+    pub fn as_ptr(&self) -> *mut u8 {
+        self.addr.as_ptr()
+    }
+
+    /// The length that was asked for. The mapping covers whole pages.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Always false, as mappings can't be empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The access of the mapping.
+    pub fn prot(&self) -> ProtFlags {
+        self.prot
+    }
+
+    /// The memory, if it is readable.
+    pub fn as_slice(&self) -> Option<&[u8]> {
+        self.prot.contains(ProtFlags::READ).then(|| unsafe {
+            core::slice::from_raw_parts(self.addr.as_ptr(), self.len)
+        })
+    }
+
+    /// The memory, if it is readable and writable.
+    pub fn as_mut_slice(&mut self) -> Option<&mut [u8]> {
+        self.prot
+            .contains(ProtFlags::READ | ProtFlags::WRITE)
+            .then(|| unsafe {
+                core::slice::from_raw_parts_mut(self.addr.as_ptr(), self.len)
+            })
+    }
+
+    /// Changes the access of the whole mapping, e.g. from writable to
+    /// executable for a JIT.
+    pub fn protect(&mut self, prot: ProtFlags) -> Result<(), Errno> {
+        unsafe { mprotect(self.addr, self.len, prot) }?;
+        self.prot = prot;
+        Ok(())
+    }
+
+    /// Locks the pages of the mapping in memory, so that they aren't
+    /// swapped out, within `RLIMIT_MEMLOCK`.
+    pub fn lock(&self) -> Result<(), Errno> {
+        self.pages(Sysno::mlock)
+    }
+
+    /// Undoes [`lock`](Self::lock).
+    pub fn unlock(&self) -> Result<(), Errno> {
+        self.pages(Sysno::munlock)
+    }
+
+    fn pages(&self, sysno: Sysno) -> Result<(), Errno> {
+        unsafe {
+            crate::syscall2(
+                sysno,
+                self.addr.as_ptr() as SyscallWord,
+                self.len as SyscallWord,
+            )
+        }
+        .map(drop)
+    }
+
+    /// Grows or shrinks the mapping to `len` bytes, moving it if it can't
+    /// grow in place. Anonymous memory that is added starts as zeros.
+    pub fn remap(&mut self, len: usize) -> Result<(), Errno> {
+        let addr = unsafe {
+            crate::syscall4(
+                Sysno::mremap,
+                self.addr.as_ptr() as SyscallWord,
+                self.len as SyscallWord,
+                len as SyscallWord,
+                SyscallWord::from(MREMAP_MAYMOVE),
+            )
+        }?;
+        self.addr = NonNull::new(addr as *mut u8).ok_or(Errno::EINVAL)?;
+        self.len = len;
+        Ok(())
+    }
+}
+
+impl Drop for MappedRegion {
+    fn drop(&mut self) {
+        let _ = unsafe { munmap(self.addr, self.len) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consts::{AT_FDCWD, O_RDONLY};
+    use crate::helpers::{close, openat, pipe2};
+
+    #[test]
+    fn anonymous() {
+        let rw = ProtFlags::READ | ProtFlags::WRITE;
+        let mut region = MappedRegion::anonymous(100, rw).unwrap();
+        assert_eq!(region.len(), 100);
+        assert_eq!(region.as_ptr() as usize % 4096, 0);
+        assert!(region.as_slice().unwrap().iter().all(|&byte| byte == 0));
+        region.as_mut_slice().unwrap()[99] = 7;
+
+        region.protect(ProtFlags::READ).unwrap();
+        assert!(region.as_mut_slice().is_none());
+        assert_eq!(region.as_slice().unwrap()[99], 7);
+        region.protect(ProtFlags::empty()).unwrap();
+        assert!(region.as_slice().is_none());
+
+        region.protect(rw).unwrap();
+        region.remap(1 << 20).unwrap();
+        let mem = region.as_mut_slice().unwrap();
+        assert_eq!(mem.len(), 1 << 20);
+        assert_eq!((mem[99], mem[(1 << 20) - 1]), (7, 0));
+        // `RLIMIT_MEMLOCK` may be too low.
+        if region.lock().is_ok() {
+            region.unlock().unwrap();
+        }
+
+        let (addr, len) = region.into_raw();
+        let region = unsafe { MappedRegion::from_raw(addr, len, rw) };
+        assert_eq!(region.as_slice().unwrap()[99], 7);
+    }
+
+    #[test]
+    fn file() {
+        let fd = openat(AT_FDCWD, c"/proc/self/exe", O_RDONLY, 0).unwrap();
+        let region = unsafe {
+            MappedRegion::file(fd, 0, 4, ProtFlags::READ, MapFlags::PRIVATE)
+        }
+        .unwrap();
+        unsafe { close(fd) }.unwrap();
+        assert_eq!(region.as_slice(), Some(&b"\x7fELF"[..]));
+
+        // A pipe can't be mapped.
+        let [r, w] = pipe2(0).unwrap();
+        assert_eq!(
+            unsafe {
+                MappedRegion::file(r, 0, 4, ProtFlags::READ, MapFlags::PRIVATE)
+            }
+            .map(drop),
+            Err(Errno::ENODEV)
+        );
+        unsafe {
+            close(r).unwrap();
+            close(w).unwrap();
+        }
+    }
+}
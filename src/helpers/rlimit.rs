@@ -0,0 +1,137 @@
+//! Limits of the resources that processes use.
+
+use super::Pid;
+use crate::{Errno, SyscallWord, Sysno};
+
+/// Defines the resource numbers, which differ on mips and sparc.
+macro_rules! resources {
+    ($(
+        $(#[$attr:meta])*
+        $name:ident = $other:literal, $mips:literal, $sparc:literal;
+    )*) => {
+        $(
+            $(#[$attr])*
+            pub const $name: u32 = if cfg!(any(
+                target_arch = "mips",
+                target_arch = "mips64"
+            )) {
+                $mips
+            } else if cfg!(any(
+                target_arch = "sparc",
+                target_arch = "sparc64"
+            )) {
+                $sparc
+            } else {
+                $other
+            };
+        )*
+    };
+}
+
+resources! {
+    /// The CPU time in seconds, after which the process gets `SIGXCPU`,
+    /// and `SIGKILL` at the hard limit.
+    RLIMIT_CPU = 0, 0, 0;
+    /// The size of the files that the process writes, past which it gets
+    /// `SIGXFSZ`.
+    RLIMIT_FSIZE = 1, 1, 1;
+    /// The size of the data segment and the heap.
+    RLIMIT_DATA = 2, 2, 2;
+    /// The size of the stack of the main thread.
+    RLIMIT_STACK = 3, 3, 3;
+    /// The size of core dumps, which 0 turns off.
+    RLIMIT_CORE = 4, 4, 4;
+    /// Unused.
+    RLIMIT_RSS = 5, 7, 5;
+    /// The processes and threads of the real user id.
+    RLIMIT_NPROC = 6, 8, 7;
+    /// One more than the largest fd that the process can open.
+    RLIMIT_NOFILE = 7, 5, 6;
+    /// The bytes of memory that the process can lock.
+    RLIMIT_MEMLOCK = 8, 9, 8;
+    /// The size of the address space.
+    RLIMIT_AS = 9, 6, 9;
+    /// Unused.
+    RLIMIT_LOCKS = 10, 10, 10;
+    /// The signals queued for the real user id.
+    RLIMIT_SIGPENDING = 11, 11, 11;
+    /// The bytes in POSIX message queues of the real user id.
+    RLIMIT_MSGQUEUE = 12, 12, 12;
+    /// The highest nice value, as 20 minus the value.
+    RLIMIT_NICE = 13, 13, 13;
+    /// The highest real-time priority.
+    RLIMIT_RTPRIO = 14, 14, 14;
+    /// The CPU time in microseconds that a real-time thread can use without
+    /// blocking.
+    RLIMIT_RTTIME = 15, 15, 15;
+}
+
+/// No limit.
+pub const RLIM_INFINITY: u64 = u64::MAX;
+
+/// A limit of a resource (`struct rlimit64`), whose values are 64 bits wide
+/// on every target.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Rlimit {
+    /// The soft limit, which the kernel enforces.
+    pub cur: u64,
+    /// The hard limit, up to which the process can raise the soft one.
+    /// Raising it needs `CAP_SYS_RESOURCE`.
+    pub max: u64,
+}
+
+/// Returns the limit of `resource` of the process `pid`, or of the caller
+/// if it is 0, and sets it to `new` if given. Fails with `EPERM` for
+/// another process of another user without `CAP_SYS_RESOURCE`.
+pub fn prlimit(
+    pid: Pid,
+    resource: u32,
+    new: Option<&Rlimit>,
+) -> Result<Rlimit, Errno> {
+    let mut old = Rlimit::default();
+    unsafe {
+        crate::syscall4(
+            Sysno::prlimit64,
+            SyscallWord::from(pid),
+            SyscallWord::from(resource),
+            new.map_or(0, |new| core::ptr::from_ref(new) as SyscallWord),
+            core::ptr::from_mut(&mut old) as SyscallWord,
+        )
+    }?;
+    Ok(old)
+}
+
+/// Returns the limit of `resource`, e.g. [`RLIMIT_NOFILE`], of the calling
+/// process.
+pub fn getrlimit(resource: u32) -> Result<Rlimit, Errno> {
+    prlimit(Pid::from_raw(0), resource, None)
+}
+
+/// Sets the limit of `resource` of the calling process. Fails with
+/// `EINVAL` if `limit.cur` is above `limit.max`.
+pub fn setrlimit(resource: u32, limit: &Rlimit) -> Result<(), Errno> {
+    prlimit(Pid::from_raw(0), resource, Some(limit)).map(drop)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::getpid;
+
+    #[test]
+    fn limits() {
+        let limit = getrlimit(RLIMIT_NOFILE).unwrap();
+        assert!(limit.cur <= limit.max);
+        assert_eq!(prlimit(getpid(), RLIMIT_NOFILE, Some(&limit)), Ok(limit));
+        setrlimit(RLIMIT_NOFILE, &limit).unwrap();
+        let inverted = Rlimit {
+            cur: limit.max.saturating_add(1),
+            max: limit.max,
+        };
+        if limit.max != RLIM_INFINITY {
+            assert_eq!(setrlimit(RLIMIT_NOFILE, &inverted), Err(Errno::EINVAL));
+        }
+        assert_eq!(getrlimit(16), Err(Errno::EINVAL));
+    }
+}
@@ -0,0 +1,389 @@
+//! How threads are scheduled, and the CPUs that they run on.
+
+use core::fmt;
+
+use super::Tid;
+use super::time::ULong;
+use crate::{Errno, SyscallWord, Sysno};
+
+/// `policy` of [`SchedAttr`]: the default time-sharing policy, also known
+/// as `SCHED_OTHER`.
+pub const SCHED_NORMAL: u32 = 0;
+/// `policy` of [`SchedAttr`]: real-time, first in, first out, with a
+/// `priority` from 1 to 99.
+pub const SCHED_FIFO: u32 = 1;
+/// `policy` of [`SchedAttr`]: real-time, round-robin, with a `priority`
+/// from 1 to 99.
+pub const SCHED_RR: u32 = 2;
+/// `policy` of [`SchedAttr`]: [`SCHED_NORMAL`] for CPU-bound threads,
+/// which are woken up less eagerly.
+pub const SCHED_BATCH: u32 = 3;
+/// `policy` of [`SchedAttr`]: lower than any nice value.
+pub const SCHED_IDLE: u32 = 5;
+/// `policy` of [`SchedAttr`]: earliest deadline first, with `runtime`
+/// in every `period`, by `deadline` into it. Needs `CAP_SYS_NICE`.
+pub const SCHED_DEADLINE: u32 = 6;
+
+/// `flags` of [`SchedAttr`]: children start with the default policy.
+pub const SCHED_FLAG_RESET_ON_FORK: u64 = 0x01;
+/// `flags` of [`SchedAttr`]: a [`SCHED_DEADLINE`] thread may use the time
+/// that others left.
+pub const SCHED_FLAG_RECLAIM: u64 = 0x02;
+/// `flags` of [`SchedAttr`]: a [`SCHED_DEADLINE`] thread gets `SIGXCPU`
+/// when it overruns its runtime.
+pub const SCHED_FLAG_DL_OVERRUN: u64 = 0x04;
+/// `flags` of [`SchedAttr`]: leave the policy as it is (Linux 4.20).
+pub const SCHED_FLAG_KEEP_POLICY: u64 = 0x08;
+/// `flags` of [`SchedAttr`]: leave the parameters of the policy as they
+/// are (Linux 4.20).
+pub const SCHED_FLAG_KEEP_PARAMS: u64 = 0x10;
+/// `flags` of [`SchedAttr`]: set `util_min` (Linux 5.3).
+pub const SCHED_FLAG_UTIL_CLAMP_MIN: u64 = 0x20;
+/// `flags` of [`SchedAttr`]: set `util_max` (Linux 5.3).
+pub const SCHED_FLAG_UTIL_CLAMP_MAX: u64 = 0x40;
+
+/// The CPUs that a [`CpuSet`] has room for without allocating, as many as
+/// in the `cpu_set_t` of the C libraries.
+const INLINE_CPUS: usize = 1024;
+/// The words of a mask of [`INLINE_CPUS`].
+const INLINE: usize = INLINE_CPUS / ULong::BITS as usize;
+/// The most CPUs that [`sched_getaffinity`] makes room for, past which the
+/// `EINVAL` isn't about the size.
+#[cfg(feature = "std")]
+const MAX_CPUS: usize = 1 << 16;
+
+#[derive(Clone)]
+enum Words {
+    Inline([ULong; INLINE]),
+    #[cfg(feature = "std")]
+    Heap(Vec<ULong>),
+}
+
+/// A set of CPUs, as a mask of `unsigned long`s with a bit per CPU, for
+/// [`sched_getaffinity`] and [`sched_setaffinity`].
+///
+/// It has room for 1024 CPUs on the stack, and for more on the heap with
+/// [`with_capacity`](Self::with_capacity) and `std`.
+#[derive(Clone)]
+pub struct CpuSet {
+    words: Words,
+}
+
+impl CpuSet {
+    /// No CPUs, with room for 1024.
+    pub const fn new() -> Self {
+        Self {
+            words: Words::Inline([0; INLINE]),
+        }
+    }
+
+    /// No CPUs, with room for at least `cpus`, on the heap if they are more
+    /// than 1024.
+    #[cfg(feature = "std")]
+    pub fn with_capacity(cpus: usize) -> Self {
+        if cpus <= INLINE_CPUS {
+            return Self::new();
+        }
+        let words = cpus.div_ceil(ULong::BITS as usize);
+        Self {
+            words: Words::Heap(vec![0; words]),
+        }
+    }
+
+    /// How many CPUs it has room for.
+    pub fn capacity(&self) -> usize {
+        self.words().len() * ULong::BITS as usize
+    }
+
+    /// Adds the CPU `cpu`.
+    ///
+    /// # Panics
+    ///
+    /// If `cpu` isn't below [`capacity`](Self::capacity).
+    pub fn insert(&mut self, cpu: usize) {
+        let (word, bit) = Self::position(cpu);
+        assert!(cpu < self.capacity(), "CPU {cpu} is out of range");
+        self.words_mut()[word] |= bit;
+    }
+
+    /// Removes the CPU `cpu`, if it is there.
+    pub fn remove(&mut self, cpu: usize) {
+        let (word, bit) = Self::position(cpu);
+        if let Some(word) = self.words_mut().get_mut(word) {
+            *word &= !bit;
+        }
+    }
+
+    /// Whether the CPU `cpu` is there.
+    pub fn contains(&self, cpu: usize) -> bool {
+        let (word, bit) = Self::position(cpu);
+        self.words().get(word).is_some_and(|word| word & bit != 0)
+    }
+
+    /// How many CPUs are there.
+    pub fn count(&self) -> usize {
+        self.words()
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Whether there are no CPUs.
+    pub fn is_empty(&self) -> bool {
+        self.words().iter().all(|&word| word == 0)
+    }
+
+    /// The CPUs that are there, in increasing order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.capacity()).filter(|&cpu| self.contains(cpu))
+    }
+
+    /// The word of `cpu`, and its bit in it.
+    fn position(cpu: usize) -> (usize, ULong) {
+        let bits = ULong::BITS as usize;
+        (cpu / bits, 1 << (cpu % bits))
+    }
+
+    fn words(&self) -> &[ULong] {
+        match &self.words {
+            Words::Inline(words) => words,
+            #[cfg(feature = "std")]
+            Words::Heap(words) => words,
+        }
+    }
+
+    fn words_mut(&mut self) -> &mut [ULong] {
+        match &mut self.words {
+            Words::Inline(words) => words,
+            #[cfg(feature = "std")]
+            Words::Heap(words) => words,
+        }
+    }
+}
+
+impl Default for CpuSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PartialEq for CpuSet {
+    /// Whether the same CPUs are there, whatever the capacities.
+    fn eq(&self, other: &Self) -> bool {
+        let (short, long) = if self.words().len() <= other.words().len() {
+            (self.words(), other.words())
+        } else {
+            (other.words(), self.words())
+        };
+        long[..short.len()] == *short
+            && long[short.len()..].iter().all(|&word| word == 0)
+    }
+}
+
+impl Eq for CpuSet {}
+
+impl fmt::Debug for CpuSet {
+    /// Shows the CPUs, e.g. `{0, 2}`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// Returns the CPUs that the thread `tid`, or the calling thread if it is
+/// 0, may run on.
+///
+/// The kernel fails with `EINVAL` if the set has no room for all of the
+/// CPUs that the system may have, so with `std`, this tries again with
+/// larger sets, and without it, fails for more than 1024 CPUs.
+// Without `std`, the set can't grow, and the loop doesn't loop.
+#[cfg_attr(not(feature = "std"), allow(clippy::never_loop))]
+pub fn sched_getaffinity(tid: Tid) -> Result<CpuSet, Errno> {
+    let mut set = CpuSet::new();
+    loop {
+        // The kernel returns how many bytes it filled in, which are those
+        // of the possible CPUs; the rest stays empty.
+        let words = set.words_mut();
+        let result = unsafe {
+            crate::syscall3(
+                Sysno::sched_getaffinity,
+                SyscallWord::from(tid),
+                size_of_val(words) as SyscallWord,
+                words.as_mut_ptr() as SyscallWord,
+            )
+        };
+        match result {
+            #[cfg(feature = "std")]
+            Err(Errno::EINVAL) if set.capacity() < MAX_CPUS => {
+                set = CpuSet::with_capacity(set.capacity() * 2);
+            }
+            result => return result.map(|_| set),
+        }
+    }
+}
+
+/// Makes the thread `tid`, or the calling thread if it is 0, run only on
+/// the CPUs of `set`. CPUs that the system doesn't have are left out, and
+/// it fails with `EINVAL` if no CPU is left.
+pub fn sched_setaffinity(tid: Tid, set: &CpuSet) -> Result<(), Errno> {
+    let words = set.words();
+    unsafe {
+        crate::syscall3(
+            Sysno::sched_setaffinity,
+            SyscallWord::from(tid),
+            size_of_val(words) as SyscallWord,
+            words.as_ptr() as SyscallWord,
+        )
+    }?;
+    Ok(())
+}
+
+/// The scheduling policy of a thread and its parameters (`struct
+/// sched_attr`), of which the kernel reads those of the policy.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SchedAttr {
+    /// The size of the struct, which [`sched_setattr`] fills in, and which
+    /// [`sched_getattr`] returns as the kernel's if it is smaller, e.g. 48
+    /// before the utilization clamps.
+    pub size: u32,
+    /// `SCHED_*`, e.g. [`SCHED_FIFO`].
+    pub policy: u32,
+    /// `SCHED_FLAG_*`, e.g. [`SCHED_FLAG_RESET_ON_FORK`].
+    pub flags: u64,
+    /// The nice value of [`SCHED_NORMAL`] and [`SCHED_BATCH`], from -20 to
+    /// 19.
+    pub nice: i32,
+    /// The priority of [`SCHED_FIFO`] and [`SCHED_RR`].
+    pub priority: u32,
+    /// The CPU time in nanoseconds that a [`SCHED_DEADLINE`] thread gets in
+    /// each period.
+    pub runtime: u64,
+    /// The nanoseconds into each period by which a [`SCHED_DEADLINE`]
+    /// thread gets its runtime.
+    pub deadline: u64,
+    /// The nanoseconds of a period of [`SCHED_DEADLINE`], or the deadline
+    /// if it is 0.
+    pub period: u64,
+    /// The lowest utilization, from 0 to 1024, that the thread is run as
+    /// if it had, with [`SCHED_FLAG_UTIL_CLAMP_MIN`].
+    pub util_min: u32,
+    /// The highest utilization, with [`SCHED_FLAG_UTIL_CLAMP_MAX`].
+    pub util_max: u32,
+}
+
+/// Returns the scheduling policy and parameters of the thread `tid`, or of
+/// the calling thread if it is 0.
+pub fn sched_getattr(tid: Tid) -> Result<SchedAttr, Errno> {
+    let mut attr = SchedAttr::default();
+    unsafe {
+        crate::syscall4(
+            Sysno::sched_getattr,
+            SyscallWord::from(tid),
+            core::ptr::from_mut(&mut attr) as SyscallWord,
+            size_of::<SchedAttr>() as SyscallWord,
+            0,
+        )
+    }?;
+    Ok(attr)
+}
+
+/// Sets the scheduling policy and parameters of the thread `tid`, or of the
+/// calling thread if it is 0, to `attr`. Fails with `EPERM` for real-time
+/// policies and lower nice values without `CAP_SYS_NICE` or
+/// `RLIMIT_RTPRIO` and `RLIMIT_NICE`, with `EBUSY` if the CPUs have no room
+/// for a [`SCHED_DEADLINE`] thread, and with `EOPNOTSUPP` for utilization
+/// clamps without `CONFIG_UCLAMP_TASK`.
+///
+/// ```no_run
+/// use rawsys_linux::helpers::{self, SCHED_FIFO, SchedAttr, Tid};
+///
+/// let attr = SchedAttr {
+///     policy: SCHED_FIFO,
+///     priority: 10,
+///     ..SchedAttr::default()
+/// };
+/// helpers::sched_setattr(Tid::from_raw(0), &attr)?;
+/// # Ok::<(), rawsys_linux::Errno>(())
+/// ```
+pub fn sched_setattr(tid: Tid, attr: &SchedAttr) -> Result<(), Errno> {
+    let attr = SchedAttr {
+        size: size_of::<SchedAttr>() as u32,
+        ..*attr
+    };
+    unsafe {
+        crate::syscall3(
+            Sysno::sched_setattr,
+            SyscallWord::from(tid),
+            core::ptr::from_ref(&attr) as SyscallWord,
+            0,
+        )
+    }?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::format::Buffer;
+
+    #[test]
+    fn sets() {
+        let mut set = CpuSet::new();
+        assert_eq!(set.capacity(), 1024);
+        set.insert(0);
+        set.insert(65);
+        set.insert(1023);
+        set.remove(65);
+        set.remove(5000);
+        assert!(set.contains(1023) && !set.contains(65));
+        assert!(!set.contains(5000));
+        assert_eq!(set.count(), 2);
+        assert_eq!(Buffer::<32>::format(format_args!("{set:?}")), "{0, 1023}");
+
+        #[cfg(feature = "std")]
+        {
+            let mut large = CpuSet::with_capacity(4000);
+            assert!(large.capacity() >= 4000);
+            large.insert(0);
+            large.insert(1023);
+            assert_eq!(large, set);
+            large.insert(3999);
+            assert_ne!(set, large);
+            assert_eq!(large.iter().last(), Some(3999));
+        }
+    }
+
+    #[test]
+    fn affinity() {
+        let tid = Tid::from_raw(0);
+        let set = sched_getaffinity(tid).unwrap();
+        assert!(!set.is_empty());
+        assert_eq!(set.iter().count(), set.count());
+        sched_setaffinity(tid, &set).unwrap();
+        assert_eq!(sched_getaffinity(tid), Ok(set));
+        assert_eq!(sched_setaffinity(tid, &CpuSet::new()), Err(Errno::EINVAL));
+    }
+
+    #[test]
+    fn attr() {
+        assert_eq!(size_of::<SchedAttr>(), 56);
+        let tid = Tid::from_raw(0);
+        let old = sched_getattr(tid).unwrap();
+        let batch = SchedAttr {
+            policy: SCHED_BATCH,
+            ..old
+        };
+        sched_setattr(tid, &batch).unwrap();
+        assert_eq!(sched_getattr(tid).map(|attr| attr.policy), Ok(SCHED_BATCH));
+        sched_setattr(tid, &old).unwrap();
+
+        // The runtime can't be longer than the deadline.
+        let deadline = SchedAttr {
+            policy: SCHED_DEADLINE,
+            runtime: 2_000_000,
+            deadline: 1_000_000,
+            ..SchedAttr::default()
+        };
+        assert_eq!(sched_setattr(tid, &deadline), Err(Errno::EINVAL));
+    }
+}
@@ -0,0 +1,327 @@
+//! Signal handlers, installed with `rt_sigaction` and the trampolines that
+//! return from them.
+
+use core::ffi::c_void;
+use core::fmt;
+
+use super::{KernelSigset, Siginfo};
+use crate::{Errno, SyscallWord, Sysno};
+
+/// `flags` of a [`KernelSigaction`]: no `SIGCHLD` when a child stops or
+/// continues.
+pub const SA_NOCLDSTOP: u32 = 1;
+/// `flags` of a [`KernelSigaction`]: children that exit don't become
+/// zombies, for `SIGCHLD`.
+pub const SA_NOCLDWAIT: u32 =
+    if cfg!(any(target_arch = "mips", target_arch = "mips64")) {
+        0x1_0000
+    } else {
+        2
+    };
+/// `flags` of a [`KernelSigaction`]: the handler takes the `siginfo_t` and
+/// the context, as [`SigHandler::Action`] does.
+pub const SA_SIGINFO: u32 =
+    if cfg!(any(target_arch = "mips", target_arch = "mips64")) {
+        8
+    } else {
+        4
+    };
+/// `flags` of a [`KernelSigaction`]: the trampoline that returns from the
+/// handler is in `restorer`. [`KernelSigaction::new`] sets it where the
+/// kernel needs it.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "arm"))]
+pub const SA_RESTORER: u32 = 0x0400_0000;
+/// `flags` of a [`KernelSigaction`]: the handler runs on the alternate
+/// signal stack of `sigaltstack`.
+pub const SA_ONSTACK: u32 = 0x0800_0000;
+/// `flags` of a [`KernelSigaction`]: the syscalls that the signal
+/// interrupts are restarted, instead of failing with `EINTR`.
+pub const SA_RESTART: u32 = 0x1000_0000;
+/// `flags` of a [`KernelSigaction`]: the signal isn't blocked while its
+/// handler runs.
+pub const SA_NODEFER: u32 = 0x4000_0000;
+/// `flags` of a [`KernelSigaction`]: the handler is reset to
+/// [`SigHandler::Default`] when the signal comes.
+pub const SA_RESETHAND: u32 = 0x8000_0000;
+
+/// What a signal does when it comes.
+#[derive(Debug, Clone, Copy)]
+pub enum SigHandler {
+    /// Its default action (`SIG_DFL`), e.g. exiting for `SIGTERM`.
+    Default,
+    /// Nothing (`SIG_IGN`).
+    Ignore,
+    /// Calls the function with the signal number.
+    Handler(extern "C" fn(i32)),
+    /// Calls the function with the signal number, its [`Siginfo`], and the
+    /// `ucontext_t` of where the thread was, with [`SA_SIGINFO`].
+    Action(extern "C" fn(i32, *mut Siginfo, *mut c_void)),
+}
+
+/// What a signal does (`struct sigaction` of the kernel), which is not the
+/// `struct sigaction` of the C libraries.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct KernelSigaction {
+    #[cfg(any(target_arch = "mips", target_arch = "mips64"))]
+    flags: u32,
+    handler: usize,
+    #[cfg(not(any(target_arch = "mips", target_arch = "mips64")))]
+    flags: usize,
+    #[cfg(any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "powerpc",
+        target_arch = "powerpc64",
+        target_arch = "s390x"
+    ))]
+    restorer: usize,
+    mask: KernelSigset,
+}
+
+impl KernelSigaction {
+    /// Does what `handler` says, with the signals of `mask` and the signal
+    /// itself, unless `flags` has [`SA_NODEFER`], blocked while it runs.
+    /// `flags` are the `SA_*` ones, and [`SA_SIGINFO`] is added for
+    /// [`SigHandler::Action`].
+    pub fn new(handler: SigHandler, flags: u32, mask: KernelSigset) -> Self {
+        let (handler, flags) = match handler {
+            SigHandler::Default => (0, flags),
+            SigHandler::Ignore => (1, flags),
+            SigHandler::Handler(f) => (f as usize, flags & !SA_SIGINFO),
+            SigHandler::Action(f) => (f as usize, flags | SA_SIGINFO),
+        };
+        // The other targets return through a trampoline of the vDSO.
+        #[cfg(any(
+            target_arch = "x86",
+            target_arch = "x86_64",
+            target_arch = "arm"
+        ))]
+        let flags = flags | SA_RESTORER;
+        Self {
+            handler,
+            #[cfg(any(target_arch = "mips", target_arch = "mips64"))]
+            flags,
+            #[cfg(not(any(target_arch = "mips", target_arch = "mips64")))]
+            flags: flags as usize,
+            #[cfg(any(
+                target_arch = "x86",
+                target_arch = "x86_64",
+                target_arch = "arm"
+            ))]
+            restorer: restore_rt as *const () as usize,
+            #[cfg(any(
+                target_arch = "aarch64",
+                target_arch = "powerpc",
+                target_arch = "powerpc64",
+                target_arch = "s390x"
+            ))]
+            restorer: 0,
+            mask,
+        }
+    }
+
+    /// What the signal does.
+    pub fn handler(&self) -> SigHandler {
+        match self.handler {
+            0 => SigHandler::Default,
+            1 => SigHandler::Ignore,
+            // The kernel only hands back the handlers that it was given.
+            f if self.flags() & SA_SIGINFO != 0 => SigHandler::Action(unsafe {
+                core::mem::transmute::<
+                    usize,
+                    extern "C" fn(i32, *mut Siginfo, *mut c_void),
+                >(f)
+            }),
+            f => SigHandler::Handler(unsafe {
+                core::mem::transmute::<usize, extern "C" fn(i32)>(f)
+            }),
+        }
+    }
+
+    /// The `SA_*` flags.
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn flags(&self) -> u32 {
+        self.flags as u32
+    }
+
+    /// The signals that are blocked while the handler runs.
+    pub const fn mask(&self) -> KernelSigset {
+        self.mask
+    }
+}
+
+impl Default for KernelSigaction {
+    fn default() -> Self {
+        Self::new(SigHandler::Default, 0, KernelSigset::empty())
+    }
+}
+
+impl fmt::Debug for KernelSigaction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KernelSigaction")
+            .field("handler", &self.handler())
+            .field("flags", &self.flags())
+            .field("mask", &self.mask)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Returns from a signal handler, which returns here: the kernel puts the
+/// address on the stack, or in `lr` on arm, as that of the caller.
+#[cfg(target_arch = "x86_64")]
+#[unsafe(naked)]
+unsafe extern "C" fn restore_rt() -> ! {
+    core::arch::naked_asm!(
+        "mov eax, {rt_sigreturn}",
+        "syscall",
+        rt_sigreturn = const Sysno::rt_sigreturn as usize,
+    )
+}
+
+/// Returns from a signal handler, which returns here: the kernel puts the
+/// address on the stack, or in `lr` on arm, as that of the caller.
+#[cfg(target_arch = "x86")]
+#[unsafe(naked)]
+unsafe extern "C" fn restore_rt() -> ! {
+    core::arch::naked_asm!(
+        "mov eax, {rt_sigreturn}",
+        "int 0x80",
+        rt_sigreturn = const Sysno::rt_sigreturn as usize,
+    )
+}
+
+/// Returns from a signal handler, which returns here: the kernel puts the
+/// address on the stack, or in `lr` on arm, as that of the caller.
+#[cfg(target_arch = "arm")]
+#[unsafe(naked)]
+unsafe extern "C" fn restore_rt() -> ! {
+    core::arch::naked_asm!(
+        "mov r7, #{rt_sigreturn}",
+        "svc 0",
+        rt_sigreturn = const Sysno::rt_sigreturn as usize,
+    )
+}
+
+/// Makes `signal` do what `action` says, unless it is `None`, and returns
+/// what it did. Fails with `EINVAL` for `SIGKILL` and `SIGSTOP`.
+///
+/// # Safety
+///
+/// The handler runs in the middle of whatever the thread was doing, so it
+/// may only do what is async-signal-safe, e.g. store to atomics or call
+/// syscalls, and not lock, allocate, or unwind. It also replaces the handler
+/// that something else may depend on, such as that of `std` for stack
+/// overflows.
+pub unsafe fn sigaction(
+    signal: i32,
+    action: Option<&KernelSigaction>,
+) -> Result<KernelSigaction, Errno> {
+    let mut old = KernelSigaction::default();
+    unsafe {
+        crate::syscall4(
+            Sysno::rt_sigaction,
+            signal as SyscallWord,
+            action
+                .map_or(0, |action| core::ptr::from_ref(action) as SyscallWord),
+            core::ptr::from_mut(&mut old) as SyscallWord,
+            size_of::<KernelSigset>() as SyscallWord,
+        )
+    }?;
+    Ok(old)
+}
+
+/// Makes `signal` call `handler`, with the `SA_*` `flags`, e.g.
+/// [`SA_RESTART`], and returns what it did, to put it back with
+/// [`sigaction`]:
+///
+/// ```no_run
+/// use core::sync::atomic::{AtomicBool, Ordering};
+/// use rawsys_linux::helpers::{self, SA_RESTART, SIGINT, SigHandler};
+///
+/// static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+///
+/// extern "C" fn interrupt(_: i32) {
+///     INTERRUPTED.store(true, Ordering::Relaxed);
+/// }
+///
+/// let handler = SigHandler::Handler(interrupt);
+/// unsafe { helpers::install_handler(SIGINT, handler, SA_RESTART) }?;
+/// # Ok::<(), rawsys_linux::Errno>(())
+/// ```
+///
+/// # Safety
+///
+/// As for [`sigaction`].
+pub unsafe fn install_handler(
+    signal: i32,
+    handler: SigHandler,
+    flags: u32,
+) -> Result<KernelSigaction, Errno> {
+    let action = KernelSigaction::new(handler, flags, KernelSigset::empty());
+    unsafe { sigaction(signal, Some(&action)) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::{SIGKILL, SIGRTMIN, Signal, getpid, gettid, tgkill};
+    use core::sync::atomic::{AtomicI32, Ordering};
+
+    /// A signal that nothing else uses, unlike the first few real-time
+    /// ones, which the C libraries use.
+    const SIGNAL: i32 = SIGRTMIN + 5;
+
+    static RECEIVED: AtomicI32 = AtomicI32::new(0);
+
+    extern "C" fn handler(signal: i32) {
+        RECEIVED.store(signal, Ordering::Relaxed);
+    }
+
+    extern "C" fn action(signal: i32, info: *mut Siginfo, _: *mut c_void) {
+        let signo = unsafe { (*info).signo() };
+        RECEIVED.store(signal + signo, Ordering::Relaxed);
+    }
+
+    /// Sends `SIGNAL` to the calling thread, whose handler runs before the
+    /// syscall returns.
+    fn raise() {
+        tgkill(getpid(), gettid(), Signal::Realtime(5)).unwrap();
+    }
+
+    #[test]
+    fn handlers() {
+        let old =
+            unsafe { install_handler(SIGNAL, SigHandler::Handler(handler), 0) }
+                .unwrap();
+        assert!(matches!(old.handler(), SigHandler::Default));
+        raise();
+        assert_eq!(RECEIVED.load(Ordering::Relaxed), SIGNAL);
+
+        let old = unsafe {
+            install_handler(SIGNAL, SigHandler::Action(action), SA_RESTART)
+        }
+        .unwrap();
+        assert!(matches!(old.handler(), SigHandler::Handler(_)));
+        raise();
+        assert_eq!(RECEIVED.load(Ordering::Relaxed), 2 * SIGNAL);
+
+        let old =
+            unsafe { install_handler(SIGNAL, SigHandler::Ignore, 0) }.unwrap();
+        assert!(matches!(old.handler(), SigHandler::Action(_)));
+        let flags = SA_SIGINFO | SA_RESTART;
+        assert_eq!(old.flags() & flags, flags);
+        raise();
+        assert_eq!(RECEIVED.load(Ordering::Relaxed), 2 * SIGNAL);
+
+        let default = KernelSigaction::default();
+        unsafe { sigaction(SIGNAL, Some(&default)) }.unwrap();
+        assert_eq!(
+            unsafe { install_handler(SIGKILL, SigHandler::Ignore, 0) }
+                .map(|old| old.flags()),
+            Err(Errno::EINVAL)
+        );
+    }
+}
@@ -0,0 +1,212 @@
+//! What a signal carries (`siginfo_t`), and the syscalls that wait for and
+//! send one.
+
+use super::{KernelSigset, Pid, Tid, Timespec, Uid, WaitStatus};
+use super::{getpid, getuid};
+use crate::siginfo::Siginfo;
+use crate::{Errno, SyscallWord, Sysno};
+
+/// `code` of a [`Siginfo`]: sent by `kill`.
+pub const SI_USER: i32 = 0;
+/// `code` of a [`Siginfo`]: sent by the kernel.
+pub const SI_KERNEL: i32 = 0x80;
+/// `code` of a [`Siginfo`]: sent by `sigqueue` or [`sigqueueinfo`], with a
+/// value.
+pub const SI_QUEUE: i32 = -1;
+/// `code` of a [`Siginfo`]: a POSIX timer expired.
+pub const SI_TIMER: i32 =
+    if cfg!(any(target_arch = "mips", target_arch = "mips64")) {
+        -3
+    } else {
+        -2
+    };
+/// `code` of a [`Siginfo`]: sent by `tkill` or `tgkill`.
+pub const SI_TKILL: i32 = -6;
+
+/// `code` of a `SIGCHLD`: the child exited.
+pub const CLD_EXITED: i32 = 1;
+/// `code` of a `SIGCHLD`: the child was killed.
+pub const CLD_KILLED: i32 = 2;
+/// `code` of a `SIGCHLD`: the child was killed and dumped core.
+pub const CLD_DUMPED: i32 = 3;
+/// `code` of a `SIGCHLD`: the traced child stopped.
+pub const CLD_TRAPPED: i32 = 4;
+/// `code` of a `SIGCHLD`: the child stopped.
+pub const CLD_STOPPED: i32 = 5;
+/// `code` of a `SIGCHLD`: the stopped child continued.
+pub const CLD_CONTINUED: i32 = 6;
+
+/// `code` of a `SIGSEGV`: the address isn't mapped.
+pub const SEGV_MAPERR: i32 = 1;
+/// `code` of a `SIGSEGV`: the mapping doesn't allow the access.
+pub const SEGV_ACCERR: i32 = 2;
+
+/// `rt_sigtimedwait` with a 64-bit `struct timespec`, which is
+/// `rt_sigtimedwait_time64` on 32-bit targets.
+#[cfg(target_pointer_width = "64")]
+const RT_SIGTIMEDWAIT: Sysno = Sysno::rt_sigtimedwait;
+#[cfg(target_pointer_width = "32")]
+const RT_SIGTIMEDWAIT: Sysno = Sysno::rt_sigtimedwait_time64;
+
+impl Siginfo {
+    /// A signal that carries `value`, with the pid and uid of the caller,
+    /// for [`sigqueueinfo`] and [`tgsigqueueinfo`].
+    pub fn queue(signo: i32, value: usize) -> Self {
+        let mut info = Self {
+            signo,
+            code: SI_QUEUE,
+            ..Self::default()
+        };
+        info.set_int(0, getpid().as_raw());
+        info.set_int(4, getuid().as_raw().cast_signed());
+        info.fields[8 / size_of::<usize>()] = value;
+        info
+    }
+
+    /// The process that sent the signal, or whose state changed, for the
+    /// signals from `kill` and `sigqueue`, and `SIGCHLD`.
+    pub fn pid(&self) -> Pid {
+        Pid::from_raw(self.int(0))
+    }
+
+    /// The real user id of the process that sent the signal, as for
+    /// [`pid`](Self::pid).
+    pub fn uid(&self) -> Uid {
+        Uid::from_raw(self.int(4).cast_unsigned())
+    }
+
+    /// The value that a signal of `sigqueue` or a POSIX timer carries.
+    pub fn value(&self) -> usize {
+        self.fields[8 / size_of::<usize>()]
+    }
+
+    /// The state of the child of a `SIGCHLD`, or of `waitid`.
+    pub fn child_status(&self) -> Option<WaitStatus> {
+        // `si_status`, which is after `si_pid` and `si_uid`.
+        WaitStatus::from_siginfo(self.code, self.int(8))
+    }
+
+    /// The address that a `SIGSEGV`, `SIGBUS`, `SIGILL`, `SIGFPE`, or
+    /// `SIGTRAP` from the kernel faulted on.
+    pub fn fault_addr(&self) -> Option<usize> {
+        use super::{SIGBUS, SIGFPE, SIGILL, SIGSEGV, SIGTRAP};
+
+        let fault = [SIGSEGV, SIGBUS, SIGILL, SIGFPE, SIGTRAP];
+        (fault.contains(&self.signo) && self.code > 0).then_some(self.fields[0])
+    }
+
+    /// How many more times the POSIX timer of an [`SI_TIMER`] signal
+    /// expired before the signal was taken.
+    pub fn timer_overrun(&self) -> Option<i32> {
+        (self.code == SI_TIMER).then(|| self.int(4))
+    }
+}
+
+/// Waits until one of the signals of `set` is pending, and takes it, or
+/// until `timeout` passes, unless it is `None`. Fails with `EAGAIN` after
+/// the timeout, and with `EINTR` when another signal is handled.
+///
+/// The signals should be blocked, or they may be handled first.
+pub fn sigtimedwait(
+    set: &KernelSigset,
+    timeout: Option<&Timespec>,
+) -> Result<Siginfo, Errno> {
+    let mut info = Siginfo::default();
+    unsafe {
+        crate::syscall4(
+            RT_SIGTIMEDWAIT,
+            core::ptr::from_ref(set) as SyscallWord,
+            core::ptr::from_mut(&mut info) as SyscallWord,
+            timeout.map_or(0, |timeout| {
+                core::ptr::from_ref(timeout) as SyscallWord
+            }),
+            size_of::<KernelSigset>() as SyscallWord,
+        )
+    }?;
+    Ok(info)
+}
+
+/// Sends `signal` to the process `pid` with `info`, e.g. from
+/// [`Siginfo::queue`]. Fails with `EPERM` for a `code` other than a
+/// negative one, such as [`SI_QUEUE`], to another process.
+pub fn sigqueueinfo(
+    pid: Pid,
+    signal: i32,
+    info: &Siginfo,
+) -> Result<(), Errno> {
+    unsafe {
+        crate::syscall3(
+            Sysno::rt_sigqueueinfo,
+            SyscallWord::from(pid),
+            signal as SyscallWord,
+            core::ptr::from_ref(info) as SyscallWord,
+        )
+    }?;
+    Ok(())
+}
+
+/// As [`sigqueueinfo`], but to the thread `tid` of the process `pid`.
+pub fn tgsigqueueinfo(
+    pid: Pid,
+    tid: Tid,
+    signal: i32,
+    info: &Siginfo,
+) -> Result<(), Errno> {
+    unsafe {
+        crate::syscall4(
+            Sysno::rt_tgsigqueueinfo,
+            SyscallWord::from(pid),
+            SyscallWord::from(tid),
+            signal as SyscallWord,
+            core::ptr::from_ref(info) as SyscallWord,
+        )
+    }?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::{
+        SIG_BLOCK, SIG_SETMASK, SIGRTMIN, SIGSEGV, gettid, sigprocmask,
+    };
+    use core::time::Duration;
+
+    #[test]
+    fn layout() {
+        assert_eq!(size_of::<Siginfo>(), 128);
+        let info = Siginfo::queue(SIGRTMIN, 42);
+        assert_eq!((info.code(), info.value()), (SI_QUEUE, 42));
+        assert_eq!((info.pid(), info.uid()), (getpid(), getuid()));
+        assert_eq!(info.fault_addr(), None);
+        assert_eq!(info.child_status(), None);
+        let mut info = Siginfo {
+            signo: SIGSEGV,
+            code: SEGV_MAPERR,
+            ..Siginfo::default()
+        };
+        info.fields[0] = 0x1000;
+        assert_eq!(info.fault_addr(), Some(0x1000));
+        assert_eq!(info.syscall(), None);
+    }
+
+    #[test]
+    fn queue() {
+        let signal = SIGRTMIN + 6;
+        let set = KernelSigset::empty().with(signal);
+        let old = sigprocmask(SIG_BLOCK, Some(&set)).unwrap();
+        let timeout = Timespec::from(Duration::ZERO);
+        assert_eq!(
+            sigtimedwait(&set, Some(&timeout)).map(|info| info.signo()),
+            Err(Errno::EAGAIN)
+        );
+
+        let info = Siginfo::queue(signal, 7);
+        tgsigqueueinfo(getpid(), gettid(), signal, &info).unwrap();
+        let info = sigtimedwait(&set, None).unwrap();
+        assert_eq!((info.signo(), info.code()), (signal, SI_QUEUE));
+        assert_eq!((info.pid(), info.value()), (getpid(), 7));
+        assert_eq!(sigqueueinfo(getpid(), 0, &Siginfo::queue(0, 0)), Ok(()));
+        sigprocmask(SIG_SETMASK, Some(&old)).unwrap();
+    }
+}
@@ -0,0 +1,418 @@
+//! Signal numbers, the sets of signals that threads block, and the
+//! syscalls that send signals.
+
+use super::{Pid, Tid};
+use crate::{Errno, SyscallWord, Sysno};
+
+/// Defines the signal numbers, which are the same on every target except
+/// mips and sparc, and the variants of [`Signal`] for them.
+macro_rules! signals {
+    ($(
+        $(#[$attr:meta])*
+        $name:ident($variant:ident) = $other:literal, $mips:literal, $sparc:literal;
+    )*) => {
+        $(
+            $(#[$attr])*
+            pub const $name: i32 = if cfg!(any(
+                target_arch = "mips",
+                target_arch = "mips64"
+            )) {
+                $mips
+            } else if cfg!(any(
+                target_arch = "sparc",
+                target_arch = "sparc64"
+            )) {
+                $sparc
+            } else {
+                $other
+            };
+        )*
+
+        /// A signal to send, e.g. with [`kill`], which is one of the
+        /// `SIG*` numbers.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum Signal {
+            $(
+                $(#[$attr])*
+                $variant,
+            )*
+            /// The real-time signal [`SIGRTMIN`] plus this, up to
+            /// [`SIGRTMAX`]. The syscalls fail with `EINVAL` past it.
+            Realtime(u8),
+        }
+
+        impl Signal {
+            /// The number of the signal, e.g. [`SIGTERM`] for
+            /// [`Signal::Term`].
+            #[allow(clippy::cast_lossless)]
+            pub const fn to_raw(self) -> i32 {
+                match self {
+                    $(Self::$variant => $name,)*
+                    Self::Realtime(offset) => SIGRTMIN + offset as i32,
+                }
+            }
+
+            /// The signal of the number `raw`, or `None` if it isn't one.
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            pub const fn from_raw(raw: i32) -> Option<Self> {
+                Some(match raw {
+                    $($name => Self::$variant,)*
+                    SIGRTMIN..=SIGRTMAX => Self::Realtime((raw - SIGRTMIN) as u8),
+                    _ => return None,
+                })
+            }
+        }
+    };
+}
+
+signals! {
+    /// The terminal hung up, or a daemon should reload.
+    SIGHUP(Hup) = 1, 1, 1;
+    /// Ctrl-C.
+    SIGINT(Int) = 2, 2, 2;
+    /// Ctrl-\, which dumps core.
+    SIGQUIT(Quit) = 3, 3, 3;
+    /// An illegal instruction.
+    SIGILL(Ill) = 4, 4, 4;
+    /// A breakpoint or trace trap.
+    SIGTRAP(Trap) = 5, 5, 5;
+    /// `abort`.
+    SIGABRT(Abrt) = 6, 6, 6;
+    /// A bad memory access, e.g. past the end of a mapped file.
+    SIGBUS(Bus) = 7, 10, 10;
+    /// An arithmetic error, e.g. an integer division by zero.
+    SIGFPE(Fpe) = 8, 8, 8;
+    /// Kills the process; can't be handled or blocked.
+    SIGKILL(Kill) = 9, 9, 9;
+    SIGUSR1(Usr1) = 10, 16, 30;
+    /// An access to memory that isn't mapped, or without permission.
+    SIGSEGV(Segv) = 11, 11, 11;
+    SIGUSR2(Usr2) = 12, 17, 31;
+    /// A write to a pipe with no readers.
+    SIGPIPE(Pipe) = 13, 13, 13;
+    /// The timer of `alarm` expired.
+    SIGALRM(Alrm) = 14, 14, 14;
+    /// Asks the process to exit.
+    SIGTERM(Term) = 15, 15, 15;
+    /// A child stopped, continued, or exited.
+    SIGCHLD(Chld) = 17, 18, 20;
+    /// Continues a stopped process.
+    SIGCONT(Cont) = 18, 25, 19;
+    /// Stops the process; can't be handled or blocked.
+    SIGSTOP(Stop) = 19, 23, 17;
+    /// Ctrl-Z.
+    SIGTSTP(Tstp) = 20, 24, 18;
+    /// A read from the terminal in the background.
+    SIGTTIN(Ttin) = 21, 26, 21;
+    /// A write to the terminal in the background.
+    SIGTTOU(Ttou) = 22, 27, 22;
+    /// Urgent data on a socket.
+    SIGURG(Urg) = 23, 21, 16;
+    /// The CPU time limit was exceeded.
+    SIGXCPU(Xcpu) = 24, 30, 24;
+    /// The file size limit was exceeded.
+    SIGXFSZ(Xfsz) = 25, 31, 25;
+    SIGVTALRM(Vtalrm) = 26, 28, 26;
+    SIGPROF(Prof) = 27, 29, 27;
+    /// The terminal was resized.
+    SIGWINCH(Winch) = 28, 20, 28;
+    /// I/O is possible on an fd with `O_ASYNC`.
+    SIGIO(Io) = 29, 22, 23;
+    /// The power is failing.
+    SIGPWR(Pwr) = 30, 19, 29;
+    /// A bad syscall, e.g. one that seccomp traps.
+    SIGSYS(Sys) = 31, 12, 12;
+}
+
+/// The first real-time signal. The C libraries keep the first few for
+/// themselves, e.g. for thread cancellation.
+pub const SIGRTMIN: i32 = 32;
+/// The last real-time signal, which is 128 on mips and 64 elsewhere.
+pub const SIGRTMAX: i32 = SIGSET_WORDS as i32 * usize::BITS as i32;
+
+/// The number of `long`s in the kernel's `sigset_t`, which has 128 signals
+/// on mips and 64 elsewhere.
+const SIGSET_WORDS: usize =
+    if cfg!(any(target_arch = "mips", target_arch = "mips64")) {
+        128 / usize::BITS as usize
+    } else {
+        64 / usize::BITS as usize
+    };
+
+/// `how` of [`sigprocmask`]: blocks the signals of the set too.
+pub const SIG_BLOCK: u32 = if cfg!(any(
+    target_arch = "mips",
+    target_arch = "mips64",
+    target_arch = "sparc",
+    target_arch = "sparc64"
+)) {
+    1
+} else {
+    0
+};
+/// `how` of [`sigprocmask`]: unblocks the signals of the set.
+pub const SIG_UNBLOCK: u32 = SIG_BLOCK + 1;
+/// `how` of [`sigprocmask`]: blocks exactly the signals of the set.
+pub const SIG_SETMASK: u32 =
+    if cfg!(any(target_arch = "sparc", target_arch = "sparc64")) {
+        4
+    } else {
+        SIG_BLOCK + 2
+    };
+
+/// A set of signals as the kernel takes it, which is 8 bytes, or 16 on
+/// mips, unlike the 128-byte `sigset_t` of glibc. The syscalls that take it
+/// fail with `EINVAL` for any other size.
+///
+/// ```
+/// use rawsys_linux::helpers::{KernelSigset, SIGINT, SIGTERM};
+///
+/// const EXIT: KernelSigset =
+///     KernelSigset::empty().with(SIGINT).with(SIGTERM);
+/// assert!(EXIT.contains(SIGTERM));
+/// ```
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct KernelSigset {
+    /// Bit `n - 1` is signal `n`, in `long`s, so that the order of the
+    /// signals is the same on 32-bit big-endian targets.
+    words: [usize; SIGSET_WORDS],
+}
+
+impl KernelSigset {
+    /// The set with no signals.
+    pub const fn empty() -> Self {
+        Self {
+            words: [0; SIGSET_WORDS],
+        }
+    }
+
+    /// The set with every signal.
+    pub const fn full() -> Self {
+        Self {
+            words: [usize::MAX; SIGSET_WORDS],
+        }
+    }
+
+    /// The set with `signal` too.
+    ///
+    /// # Panics
+    ///
+    /// If `signal` isn't from 1 to [`SIGRTMAX`].
+    #[must_use]
+    pub const fn with(mut self, signal: i32) -> Self {
+        let (word, bit) = Self::position(signal);
+        self.words[word] |= bit;
+        self
+    }
+
+    /// Adds `signal`.
+    ///
+    /// # Panics
+    ///
+    /// If `signal` isn't from 1 to [`SIGRTMAX`].
+    pub fn insert(&mut self, signal: i32) {
+        *self = self.with(signal);
+    }
+
+    /// Removes `signal`.
+    ///
+    /// # Panics
+    ///
+    /// If `signal` isn't from 1 to [`SIGRTMAX`].
+    pub fn remove(&mut self, signal: i32) {
+        let (word, bit) = Self::position(signal);
+        self.words[word] &= !bit;
+    }
+
+    /// Whether the set has `signal`, which is false for the numbers that
+    /// aren't signals.
+    pub const fn contains(&self, signal: i32) -> bool {
+        if signal < 1 || signal > SIGRTMAX {
+            return false;
+        }
+        let (word, bit) = Self::position(signal);
+        self.words[word] & bit != 0
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        let mut word = 0;
+        while word < SIGSET_WORDS {
+            if self.words[word] != 0 {
+                return false;
+            }
+            word += 1;
+        }
+        true
+    }
+
+    /// The word and the bit of `signal`.
+    const fn position(signal: i32) -> (usize, usize) {
+        assert!(signal >= 1 && signal <= SIGRTMAX, "not a signal");
+        let index = (signal - 1) as usize;
+        let bits = usize::BITS as usize;
+        (index / bits, 1 << (index % bits))
+    }
+}
+
+/// Blocks or unblocks the signals of `set` in the calling thread, as `how`
+/// says, and returns the signals that were blocked. With no `set`, it only
+/// returns them.
+///
+/// `SIGKILL` and `SIGSTOP` can't be blocked, and are left out silently.
+pub fn sigprocmask(
+    how: u32,
+    set: Option<&KernelSigset>,
+) -> Result<KernelSigset, Errno> {
+    let mut old = KernelSigset::empty();
+    unsafe {
+        crate::syscall4(
+            Sysno::rt_sigprocmask,
+            SyscallWord::from(how),
+            set.map_or(0, |set| core::ptr::from_ref(set) as SyscallWord),
+            core::ptr::from_mut(&mut old) as SyscallWord,
+            size_of::<KernelSigset>() as SyscallWord,
+        )
+    }?;
+    Ok(old)
+}
+
+/// Returns the signals that are pending for the calling thread or its
+/// process because they are blocked.
+pub fn sigpending() -> Result<KernelSigset, Errno> {
+    let mut set = KernelSigset::empty();
+    unsafe {
+        crate::syscall2(
+            Sysno::rt_sigpending,
+            core::ptr::from_mut(&mut set) as SyscallWord,
+            size_of::<KernelSigset>() as SyscallWord,
+        )
+    }?;
+    Ok(set)
+}
+
+/// Sends `signal` to the process `pid`. Fails with `EINVAL` unless `pid`
+/// is positive, since the kernel would take the others as process groups
+/// or every process: [`killpg`] sends to a group. Fails with `ESRCH` if
+/// there is no such process, and with `EPERM` if the caller may not signal
+/// it.
+pub fn kill(pid: Pid, signal: Signal) -> Result<(), Errno> {
+    if pid.as_raw() <= 0 {
+        return Err(Errno::EINVAL);
+    }
+    unsafe {
+        crate::syscall2(
+            Sysno::kill,
+            SyscallWord::from(pid),
+            signal.to_raw() as SyscallWord,
+        )
+    }?;
+    Ok(())
+}
+
+/// Sends `signal` to every process of the process group `pgid`, or of that
+/// of the caller if it is 0. Fails with `EINVAL` if `pgid` is negative.
+pub fn killpg(pgid: Pid, signal: Signal) -> Result<(), Errno> {
+    if pgid.as_raw() < 0 {
+        return Err(Errno::EINVAL);
+    }
+    unsafe {
+        crate::syscall2(
+            Sysno::kill,
+            SyscallWord::from(Pid::from_raw(-pgid.as_raw())),
+            signal.to_raw() as SyscallWord,
+        )
+    }?;
+    Ok(())
+}
+
+/// Sends `signal` to the thread `tid`, if it is in the process `pid`, so
+/// that a thread that exited and whose id was reused elsewhere isn't
+/// signalled instead. Fails with `ESRCH` if there is no such thread.
+pub fn tgkill(pid: Pid, tid: Tid, signal: Signal) -> Result<(), Errno> {
+    unsafe {
+        crate::syscall3(
+            Sysno::tgkill,
+            SyscallWord::from(pid),
+            SyscallWord::from(tid),
+            signal.to_raw() as SyscallWord,
+        )
+    }?;
+    Ok(())
+}
+
+/// Sends `signal` to the thread `tid` of any process. Prefer [`tgkill`],
+/// unless `tid` is known to be alive, e.g. that of the caller.
+pub fn tkill(tid: Tid, signal: Signal) -> Result<(), Errno> {
+    unsafe {
+        crate::syscall2(
+            Sysno::tkill,
+            SyscallWord::from(tid),
+            signal.to_raw() as SyscallWord,
+        )
+    }?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::{
+        SFD_CLOEXEC, close, getpid, gettid, signalfd, signalfd_read,
+    };
+
+    #[test]
+    fn set() {
+        let size = if cfg!(any(target_arch = "mips", target_arch = "mips64")) {
+            16
+        } else {
+            8
+        };
+        assert_eq!(size_of::<KernelSigset>(), size);
+        let mut set = KernelSigset::empty().with(SIGHUP).with(SIGRTMAX);
+        assert!(set.contains(SIGHUP) && set.contains(SIGRTMAX));
+        assert!(!set.contains(SIGINT) && !set.contains(0));
+        set.remove(SIGHUP);
+        set.remove(SIGRTMAX);
+        assert!(set.is_empty());
+        set.insert(SIGRTMIN);
+        assert!(set.contains(SIGRTMIN));
+        assert!(KernelSigset::full().contains(SIGKILL));
+    }
+
+    #[test]
+    fn pending() {
+        let set = KernelSigset::empty().with(SIGUSR2);
+        let old = sigprocmask(SIG_BLOCK, Some(&set)).unwrap();
+        let blocked = sigprocmask(SIG_BLOCK, None).unwrap();
+        assert!(blocked.contains(SIGUSR2));
+        assert!(!sigpending().unwrap().contains(SIGUSR2));
+        tgkill(getpid(), gettid(), Signal::Usr2).unwrap();
+        assert!(sigpending().unwrap().contains(SIGUSR2));
+
+        // Takes the signal before it is unblocked.
+        let fd = signalfd(-1, &set, SFD_CLOEXEC).unwrap();
+        assert_eq!(signalfd_read(fd).unwrap().signo, SIGUSR2 as u32);
+        unsafe { close(fd) }.unwrap();
+        assert!(!sigpending().unwrap().contains(SIGUSR2));
+        sigprocmask(SIG_SETMASK, Some(&old)).unwrap();
+        let full = KernelSigset::full();
+        assert_eq!(sigprocmask(u32::MAX, Some(&full)), Err(Errno::EINVAL));
+    }
+
+    #[test]
+    fn send() {
+        assert_eq!(Signal::from_raw(SIGTERM), Some(Signal::Term));
+        let max = Signal::from_raw(SIGRTMAX);
+        assert_eq!(max.map(Signal::to_raw), Some(SIGRTMAX));
+        assert_eq!(Signal::Realtime(1).to_raw(), SIGRTMIN + 1);
+        assert_eq!(Signal::from_raw(0), None);
+
+        let pid = Pid::from_raw(-1);
+        assert_eq!(kill(pid, Signal::Kill), Err(Errno::EINVAL));
+        assert_eq!(killpg(pid, Signal::Kill), Err(Errno::EINVAL));
+        let signal = Signal::Realtime(u8::MAX);
+        assert_eq!(kill(getpid(), signal), Err(Errno::EINVAL));
+        assert_eq!(tkill(gettid(), signal), Err(Errno::EINVAL));
+    }
+}
@@ -0,0 +1,110 @@
+//! signalfds, which signals are read from instead of being handled.
+
+use super::{KernelSigset, read};
+use crate::{Errno, SyscallWord, Sysno};
+
+/// `flags` of [`signalfd`]: the fd has `O_CLOEXEC`.
+pub const SFD_CLOEXEC: u32 = crate::consts::O_CLOEXEC;
+/// `flags` of [`signalfd`]: reads fail with `EAGAIN` instead of blocking.
+pub const SFD_NONBLOCK: u32 = crate::consts::O_NONBLOCK;
+
+/// A signal read from a signalfd (`struct signalfd_siginfo`), with the
+/// fields of its `siginfo_t` that the signal has.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SignalfdSiginfo {
+    pub signo: u32,
+    pub errno: i32,
+    pub code: i32,
+    pub pid: u32,
+    pub uid: u32,
+    pub fd: i32,
+    pub tid: u32,
+    pub band: u32,
+    pub overrun: u32,
+    pub trapno: u32,
+    pub status: i32,
+    pub int: i32,
+    pub ptr: u64,
+    pub utime: u64,
+    pub stime: u64,
+    pub addr: u64,
+    pub addr_lsb: u16,
+    pad2: u16,
+    pub syscall: i32,
+    pub call_addr: u64,
+    pub arch: u32,
+    pad: [u8; 28],
+}
+
+impl Default for SignalfdSiginfo {
+    fn default() -> Self {
+        // All of the fields are integers.
+        unsafe { core::mem::zeroed() }
+    }
+}
+
+/// Makes a signalfd that reads the signals of `mask`, and returns it, or
+/// changes the mask of the signalfd `fd` unless it is -1. `flags` may have
+/// [`SFD_CLOEXEC`] and [`SFD_NONBLOCK`].
+///
+/// The signals must be blocked, e.g. with
+/// [`sigprocmask`](super::sigprocmask), or they are handled as usual
+/// instead of being read.
+pub fn signalfd(
+    fd: i32,
+    mask: &KernelSigset,
+    flags: u32,
+) -> Result<i32, Errno> {
+    let fd = unsafe {
+        crate::syscall4(
+            Sysno::signalfd4,
+            fd as SyscallWord,
+            core::ptr::from_ref(mask) as SyscallWord,
+            size_of::<KernelSigset>() as SyscallWord,
+            SyscallWord::from(flags),
+        )
+    }?;
+    Ok(fd as i32)
+}
+
+/// Takes the next pending signal of the signalfd `fd`, waiting until there
+/// is one, unless `fd` has [`SFD_NONBLOCK`].
+pub fn signalfd_read(fd: i32) -> Result<SignalfdSiginfo, Errno> {
+    let mut info = SignalfdSiginfo::default();
+    let buf = unsafe {
+        core::slice::from_raw_parts_mut(
+            core::ptr::from_mut(&mut info).cast::<u8>(),
+            size_of::<SignalfdSiginfo>(),
+        )
+    };
+    read(fd, buf)?;
+    Ok(info)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::{
+        SIG_BLOCK, SIG_SETMASK, SIGUSR1, Signal, close, getpid, gettid,
+        sigprocmask, tgkill,
+    };
+
+    #[test]
+    fn signal() {
+        assert_eq!(size_of::<SignalfdSiginfo>(), 128);
+        let mask = KernelSigset::empty().with(SIGUSR1);
+        let old = sigprocmask(SIG_BLOCK, Some(&mask)).unwrap();
+        let fd = signalfd(-1, &mask, SFD_CLOEXEC | SFD_NONBLOCK).unwrap();
+        assert_eq!(signalfd_read(fd), Err(Errno::EAGAIN));
+        tgkill(getpid(), gettid(), Signal::Usr1).unwrap();
+        let info = signalfd_read(fd).unwrap();
+        assert_eq!(
+            (info.signo, info.pid),
+            (SIGUSR1 as u32, getpid().as_raw() as u32)
+        );
+        assert_eq!(signalfd(fd, &KernelSigset::empty(), 0), Ok(fd));
+        unsafe { close(fd) }.unwrap();
+        sigprocmask(SIG_SETMASK, Some(&old)).unwrap();
+    }
+}
@@ -0,0 +1,760 @@
+//! Sockets, and the addresses that they are bound and connected to.
+
+use core::fmt;
+use core::net::{Ipv4Addr, Ipv6Addr, SocketAddrV4, SocketAddrV6};
+
+use super::socketcall::{
+    SYS_ACCEPT4, SYS_BIND, SYS_CONNECT, SYS_GETPEERNAME, SYS_GETSOCKNAME,
+    SYS_GETSOCKOPT, SYS_LISTEN, SYS_SETSOCKOPT, SYS_SHUTDOWN, SYS_SOCKET,
+    SYS_SOCKETPAIR, socket_syscall,
+};
+use crate::consts;
+use crate::{Errno, SyscallWord, Sysno};
+
+/// Defines constants whose values differ on mips, sparc, and powerpc.
+macro_rules! socket_consts {
+    ($(
+        $(#[$attr:meta])*
+        $name:ident = $other:literal, $mips:literal, $sparc:literal, $powerpc:literal;
+    )*) => {
+        $(
+            $(#[$attr])*
+            pub const $name: u32 = if cfg!(any(
+                target_arch = "mips",
+                target_arch = "mips64"
+            )) {
+                $mips
+            } else if cfg!(any(
+                target_arch = "sparc",
+                target_arch = "sparc64"
+            )) {
+                $sparc
+            } else if cfg!(any(
+                target_arch = "powerpc",
+                target_arch = "powerpc64"
+            )) {
+                $powerpc
+            } else {
+                $other
+            };
+        )*
+    };
+}
+
+/// `domain` of [`socket`]: none, as the family of an unset address.
+pub const AF_UNSPEC: u32 = 0;
+/// `domain` of [`socket`]: local sockets, at paths or abstract names.
+pub const AF_UNIX: u32 = 1;
+/// `domain` of [`socket`]: IPv4.
+pub const AF_INET: u32 = 2;
+/// `domain` of [`socket`]: IPv6.
+pub const AF_INET6: u32 = 10;
+/// `domain` of [`socket`]: messages to and from the kernel, e.g. for
+/// `NETLINK_ROUTE`.
+pub const AF_NETLINK: u32 = 16;
+/// `domain` of [`socket`]: raw packets of network devices.
+pub const AF_PACKET: u32 = 17;
+
+socket_consts! {
+    /// `type` of [`socket`]: a connected stream of bytes, e.g. TCP.
+    SOCK_STREAM = 1, 2, 1, 1;
+    /// `type` of [`socket`]: messages, e.g. UDP.
+    SOCK_DGRAM = 2, 1, 2, 2;
+    /// `type` of [`socket`]: the packets of a protocol, with their headers.
+    SOCK_RAW = 3, 3, 3, 3;
+    /// `type` of [`socket`]: connected messages that keep their bounds.
+    SOCK_SEQPACKET = 5, 5, 5, 5;
+}
+
+/// `type` of [`socket`] and `flags` of [`accept4`]: the new fd has
+/// `O_CLOEXEC`.
+pub const SOCK_CLOEXEC: u32 = consts::O_CLOEXEC;
+/// `type` of [`socket`] and `flags` of [`accept4`]: the new fd has
+/// `O_NONBLOCK`, which is 0x4004 on sparc, unlike this.
+pub const SOCK_NONBLOCK: u32 =
+    if cfg!(any(target_arch = "sparc", target_arch = "sparc64")) {
+        0x4000
+    } else {
+        consts::O_NONBLOCK
+    };
+
+/// `level` of [`getsockopt`] and [`setsockopt`]: the socket itself.
+pub const SOL_SOCKET: u32 = if cfg!(any(
+    target_arch = "mips",
+    target_arch = "mips64",
+    target_arch = "sparc",
+    target_arch = "sparc64"
+)) {
+    0xffff
+} else {
+    1
+};
+/// `level` of [`getsockopt`] and [`setsockopt`]: IPv4.
+pub const SOL_IP: u32 = 0;
+/// `level` of [`getsockopt`] and [`setsockopt`]: TCP, which is also its
+/// protocol number.
+pub const SOL_TCP: u32 = 6;
+/// `level` of [`getsockopt`] and [`setsockopt`]: UDP, which is also its
+/// protocol number.
+pub const SOL_UDP: u32 = 17;
+/// `level` of [`getsockopt`] and [`setsockopt`]: IPv6.
+pub const SOL_IPV6: u32 = 41;
+
+socket_consts! {
+    /// `name` of [`SOL_SOCKET`]: the type, e.g. [`SOCK_STREAM`].
+    SO_TYPE = 3, 0x1008, 0x1008, 3;
+    /// `name` of [`SOL_SOCKET`]: the pending error, which reading clears,
+    /// e.g. of a nonblocking `connect`.
+    SO_ERROR = 4, 0x1007, 0x1007, 4;
+    /// `name` of [`SOL_SOCKET`]: binds to addresses that sockets in
+    /// `TIME_WAIT` still have.
+    SO_REUSEADDR = 2, 0x0004, 0x0004, 2;
+    /// `name` of [`SOL_SOCKET`]: sockets of the same user bind to the same
+    /// address, and share the connections.
+    SO_REUSEPORT = 15, 0x0200, 0x0200, 15;
+    /// `name` of [`SOL_SOCKET`]: sends keepalive probes.
+    SO_KEEPALIVE = 9, 0x0008, 0x0008, 9;
+    /// `name` of [`SOL_SOCKET`]: sends datagrams to broadcast addresses.
+    SO_BROADCAST = 6, 0x0020, 0x0020, 6;
+    /// `name` of [`SOL_SOCKET`]: the size of the send buffer, which the
+    /// kernel doubles.
+    SO_SNDBUF = 7, 0x1001, 0x1001, 7;
+    /// `name` of [`SOL_SOCKET`]: the size of the receive buffer, which the
+    /// kernel doubles.
+    SO_RCVBUF = 8, 0x1002, 0x1002, 8;
+    /// `name` of [`SOL_SOCKET`]: receives the credentials of the sender of
+    /// each message of a local socket.
+    SO_PASSCRED = 16, 17, 0x0002, 20;
+    /// `name` of [`SOL_SOCKET`]: the credentials of the peer of a local
+    /// socket, as of `connect` or [`socketpair`].
+    SO_PEERCRED = 17, 18, 0x0040, 21;
+}
+
+/// `how` of [`shutdown`]: stop receiving.
+pub const SHUT_RD: u32 = 0;
+/// `how` of [`shutdown`]: stop sending, which the peer reads as the end.
+pub const SHUT_WR: u32 = 1;
+/// `how` of [`shutdown`]: stop both.
+pub const SHUT_RDWR: u32 = 2;
+
+/// A socket address in the layout that the kernel takes, of some family.
+///
+/// # Safety
+///
+/// The first [`addr_len`](Self::addr_len) bytes of the value must be a
+/// socket address, starting with its family as a `u16`.
+pub unsafe trait SockAddr {
+    /// How many bytes of it the kernel reads.
+    fn addr_len(&self) -> u32 {
+        size_of_val(self) as u32
+    }
+}
+
+/// An IPv4 address and port (`struct sockaddr_in`).
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SockaddrIn {
+    family: u16,
+    /// In network byte order.
+    port: u16,
+    addr: [u8; 4],
+    zero: [u8; 8],
+}
+
+impl SockaddrIn {
+    pub const fn new(ip: Ipv4Addr, port: u16) -> Self {
+        Self {
+            family: AF_INET as u16,
+            port: port.to_be(),
+            addr: ip.octets(),
+            zero: [0; 8],
+        }
+    }
+
+    pub const fn ip(&self) -> Ipv4Addr {
+        let [a, b, c, d] = self.addr;
+        Ipv4Addr::new(a, b, c, d)
+    }
+
+    pub const fn port(&self) -> u16 {
+        u16::from_be(self.port)
+    }
+}
+
+unsafe impl SockAddr for SockaddrIn {}
+
+impl From<SocketAddrV4> for SockaddrIn {
+    fn from(addr: SocketAddrV4) -> Self {
+        Self::new(*addr.ip(), addr.port())
+    }
+}
+
+impl From<SockaddrIn> for SocketAddrV4 {
+    fn from(addr: SockaddrIn) -> Self {
+        Self::new(addr.ip(), addr.port())
+    }
+}
+
+impl fmt::Debug for SockaddrIn {
+    /// Shows the address as `SockaddrIn(127.0.0.1:80)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SockaddrIn({}:{})", self.ip(), self.port())
+    }
+}
+
+/// An IPv6 address and port (`struct sockaddr_in6`).
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SockaddrIn6 {
+    family: u16,
+    /// In network byte order.
+    port: u16,
+    /// In network byte order.
+    flowinfo: u32,
+    addr: [u8; 16],
+    scope_id: u32,
+}
+
+impl SockaddrIn6 {
+    /// The address `ip` and port `port`, on the interface of the index
+    /// `scope_id` for link-local addresses.
+    pub const fn new(ip: Ipv6Addr, port: u16, scope_id: u32) -> Self {
+        Self {
+            family: AF_INET6 as u16,
+            port: port.to_be(),
+            flowinfo: 0,
+            addr: ip.octets(),
+            scope_id,
+        }
+    }
+
+    pub const fn ip(&self) -> Ipv6Addr {
+        Ipv6Addr::from_bits(u128::from_be_bytes(self.addr))
+    }
+
+    pub const fn port(&self) -> u16 {
+        u16::from_be(self.port)
+    }
+
+    pub const fn scope_id(&self) -> u32 {
+        self.scope_id
+    }
+}
+
+unsafe impl SockAddr for SockaddrIn6 {}
+
+impl From<SocketAddrV6> for SockaddrIn6 {
+    fn from(addr: SocketAddrV6) -> Self {
+        Self {
+            flowinfo: addr.flowinfo().to_be(),
+            ..Self::new(*addr.ip(), addr.port(), addr.scope_id())
+        }
+    }
+}
+
+impl From<SockaddrIn6> for SocketAddrV6 {
+    fn from(addr: SockaddrIn6) -> Self {
+        let flowinfo = u32::from_be(addr.flowinfo);
+        Self::new(addr.ip(), addr.port(), flowinfo, addr.scope_id)
+    }
+}
+
+impl fmt::Debug for SockaddrIn6 {
+    /// Shows the address as `SockaddrIn6([::1]:80)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SockaddrIn6({})", SocketAddrV6::from(*self))
+    }
+}
+
+/// The path or abstract name of a local socket (`struct sockaddr_un`), and
+/// its length.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct SockaddrUn {
+    family: u16,
+    path: [u8; 108],
+    /// The length of the family and the path, with its NUL, if any.
+    len: u32,
+}
+
+impl SockaddrUn {
+    /// The path `path`, or the abstract name after the NUL that it starts
+    /// with, which isn't a file. Fails with `ENAMETOOLONG` for paths of
+    /// more than 107 bytes and names of more than 107, and with `EINVAL`
+    /// for a path with a NUL.
+    pub fn new(path: &[u8]) -> Result<Self, Errno> {
+        let abstract_name = path.first() == Some(&0);
+        // The NUL of a path, which the kernel doesn't need but the C
+        // libraries expect.
+        let nul = usize::from(!abstract_name);
+        if path.len() + nul > 108 {
+            return Err(Errno::ENAMETOOLONG);
+        }
+        if !abstract_name && path.contains(&0) {
+            return Err(Errno::EINVAL);
+        }
+        let mut addr = Self {
+            family: AF_UNIX as u16,
+            path: [0; 108],
+            len: (2 + path.len() + nul) as u32,
+        };
+        addr.path[..path.len()].copy_from_slice(path);
+        Ok(addr)
+    }
+
+    /// The path, or the abstract name with the NUL that it starts with,
+    /// which is empty for an unbound socket.
+    pub fn path(&self) -> &[u8] {
+        let path = &self.path[..(self.len as usize).saturating_sub(2)];
+        match path.split_first() {
+            Some((&0, _)) => path,
+            _ => path.split(|&b| b == 0).next().unwrap_or_default(),
+        }
+    }
+}
+
+unsafe impl SockAddr for SockaddrUn {
+    fn addr_len(&self) -> u32 {
+        self.len
+    }
+}
+
+impl PartialEq for SockaddrUn {
+    fn eq(&self, other: &Self) -> bool {
+        self.path() == other.path()
+    }
+}
+
+impl Eq for SockaddrUn {}
+
+impl fmt::Debug for SockaddrUn {
+    /// Shows the path as a string, e.g. `SockaddrUn("/run/x.sock")`, and
+    /// the NUL of an abstract name as `@`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path = self.path();
+        let (at, name) = match path.split_first() {
+            Some((&0, name)) => ("@", name),
+            _ => ("", path),
+        };
+        write!(f, "SockaddrUn(\"{at}{}\")", name.escape_ascii())
+    }
+}
+
+/// A netlink address (`struct sockaddr_nl`): the port id, which is the
+/// kernel's if it is 0, and the multicast groups.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SockaddrNl {
+    family: u16,
+    pad: u16,
+    pub pid: u32,
+    pub groups: u32,
+}
+
+impl SockaddrNl {
+    /// The address `pid` with the groups `groups`, where 0 is the kernel's
+    /// address to send to, or the one that the kernel picks to bind to.
+    pub const fn new(pid: u32, groups: u32) -> Self {
+        Self {
+            family: AF_NETLINK as u16,
+            pad: 0,
+            pid,
+            groups,
+        }
+    }
+}
+
+unsafe impl SockAddr for SockaddrNl {}
+
+/// A socket address of any family (`struct sockaddr_storage`), as the
+/// kernel returns it, and its length.
+#[repr(C, align(8))]
+#[derive(Clone, Copy)]
+pub struct SockaddrStorage {
+    family: u16,
+    data: [u8; 126],
+    len: u32,
+}
+
+impl SockaddrStorage {
+    /// `AF_*`, which is [`AF_UNSPEC`] for no address.
+    pub fn family(&self) -> u32 {
+        u32::from(self.family)
+    }
+
+    /// The address as the type of its family, if it is `T`'s, e.g.
+    /// [`AF_INET`] for [`SockaddrIn`].
+    fn read<T: SockAddr + Copy>(&self, family: u32) -> Option<T> {
+        (self.family() == family && size_of::<T>() <= size_of::<Self>())
+            .then(|| unsafe { core::ptr::from_ref(self).cast::<T>().read() })
+    }
+
+    pub fn as_in(&self) -> Option<SockaddrIn> {
+        self.read(AF_INET)
+    }
+
+    pub fn as_in6(&self) -> Option<SockaddrIn6> {
+        self.read(AF_INET6)
+    }
+
+    pub fn as_un(&self) -> Option<SockaddrUn> {
+        let mut addr = self.read::<SockaddrUn>(AF_UNIX)?;
+        addr.len = self.len.min(2 + 108);
+        Some(addr)
+    }
+
+    pub fn as_nl(&self) -> Option<SockaddrNl> {
+        self.read(AF_NETLINK)
+    }
+
+    /// Fills in the address with `fill`, which takes the pointer and a
+    /// length to update, as `accept4` does.
+    pub(super) fn fill(
+        fill: impl FnOnce(SyscallWord, SyscallWord) -> Result<SyscallWord, Errno>,
+    ) -> Result<(SyscallWord, Self), Errno> {
+        let mut addr = Self::default();
+        let mut len = 128u32;
+        let ret = fill(
+            core::ptr::from_mut(&mut addr) as SyscallWord,
+            core::ptr::from_mut(&mut len) as SyscallWord,
+        )?;
+        // The kernel returns the whole length if the address was cut off.
+        addr.len = len.min(128);
+        Ok((ret, addr))
+    }
+}
+
+unsafe impl SockAddr for SockaddrStorage {
+    fn addr_len(&self) -> u32 {
+        self.len
+    }
+}
+
+impl Default for SockaddrStorage {
+    fn default() -> Self {
+        Self {
+            family: AF_UNSPEC as u16,
+            data: [0; 126],
+            len: 0,
+        }
+    }
+}
+
+impl fmt::Debug for SockaddrStorage {
+    /// Shows the address as that of its family, if it is one of those of
+    /// this module.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(addr) = self.as_in() {
+            addr.fmt(f)
+        } else if let Some(addr) = self.as_in6() {
+            addr.fmt(f)
+        } else if let Some(addr) = self.as_un() {
+            addr.fmt(f)
+        } else if let Some(addr) = self.as_nl() {
+            addr.fmt(f)
+        } else {
+            f.debug_struct("SockaddrStorage")
+                .field("family", &self.family)
+                .finish_non_exhaustive()
+        }
+    }
+}
+
+/// Creates a socket of the family `domain`, e.g. [`AF_INET`], the type
+/// `ty`, e.g. [`SOCK_STREAM`], with [`SOCK_CLOEXEC`] and [`SOCK_NONBLOCK`],
+/// and the protocol `protocol`, which is the default of the type if it is
+/// 0, and returns its fd.
+pub fn socket(domain: u32, ty: u32, protocol: u32) -> Result<i32, Errno> {
+    let fd = unsafe {
+        socket_syscall(
+            Sysno::socket,
+            SYS_SOCKET,
+            &[
+                SyscallWord::from(domain),
+                SyscallWord::from(ty),
+                SyscallWord::from(protocol),
+            ],
+        )
+    }?;
+    Ok(fd as i32)
+}
+
+/// Creates a pair of connected sockets, as [`socket`] does, which only
+/// [`AF_UNIX`] supports.
+pub fn socketpair(
+    domain: u32,
+    ty: u32,
+    protocol: u32,
+) -> Result<[i32; 2], Errno> {
+    let mut fds = [0i32; 2];
+    unsafe {
+        socket_syscall(
+            Sysno::socketpair,
+            SYS_SOCKETPAIR,
+            &[
+                SyscallWord::from(domain),
+                SyscallWord::from(ty),
+                SyscallWord::from(protocol),
+                fds.as_mut_ptr() as SyscallWord,
+            ],
+        )
+    }?;
+    Ok(fds)
+}
+
+/// Binds the socket `fd` to `addr`, e.g. with port 0 for one that the
+/// kernel picks, which [`getsockname`] returns.
+pub fn bind(fd: i32, addr: &impl SockAddr) -> Result<(), Errno> {
+    unsafe {
+        socket_syscall(
+            Sysno::bind,
+            SYS_BIND,
+            &[
+                fd as SyscallWord,
+                core::ptr::from_ref(addr).cast::<u8>() as SyscallWord,
+                SyscallWord::from(addr.addr_len()),
+            ],
+        )
+    }?;
+    Ok(())
+}
+
+/// Connects the socket `fd` to `addr`, or sets the address of the
+/// datagrams that it sends. Fails with `EINPROGRESS` for a nonblocking
+/// socket that is still connecting, whose result [`SO_ERROR`] gives when
+/// it is writable.
+pub fn connect(fd: i32, addr: &impl SockAddr) -> Result<(), Errno> {
+    unsafe {
+        socket_syscall(
+            Sysno::connect,
+            SYS_CONNECT,
+            &[
+                fd as SyscallWord,
+                core::ptr::from_ref(addr).cast::<u8>() as SyscallWord,
+                SyscallWord::from(addr.addr_len()),
+            ],
+        )
+    }?;
+    Ok(())
+}
+
+/// Makes the socket `fd` accept connections, with up to `backlog` of them
+/// waiting, which the kernel caps at `net.core.somaxconn`.
+pub fn listen(fd: i32, backlog: u32) -> Result<(), Errno> {
+    unsafe {
+        socket_syscall(
+            Sysno::listen,
+            SYS_LISTEN,
+            &[fd as SyscallWord, SyscallWord::from(backlog)],
+        )
+    }?;
+    Ok(())
+}
+
+/// Accepts a connection of the listening socket `fd`, with the flags
+/// [`SOCK_CLOEXEC`] and [`SOCK_NONBLOCK`], and returns its fd and the
+/// address of the peer. Fails with `EAGAIN` if `fd` is nonblocking and no
+/// connection is waiting.
+pub fn accept4(fd: i32, flags: u32) -> Result<(i32, SockaddrStorage), Errno> {
+    let (fd, addr) = SockaddrStorage::fill(|addr, len| unsafe {
+        socket_syscall(
+            Sysno::accept4,
+            SYS_ACCEPT4,
+            &[fd as SyscallWord, addr, len, SyscallWord::from(flags)],
+        )
+    })?;
+    Ok((fd as i32, addr))
+}
+
+/// Returns the address that the socket `fd` is bound to.
+pub fn getsockname(fd: i32) -> Result<SockaddrStorage, Errno> {
+    SockaddrStorage::fill(|addr, len| unsafe {
+        socket_syscall(
+            Sysno::getsockname,
+            SYS_GETSOCKNAME,
+            &[fd as SyscallWord, addr, len],
+        )
+    })
+    .map(|(_, addr)| addr)
+}
+
+/// Returns the address that the socket `fd` is connected to.
+pub fn getpeername(fd: i32) -> Result<SockaddrStorage, Errno> {
+    SockaddrStorage::fill(|addr, len| unsafe {
+        socket_syscall(
+            Sysno::getpeername,
+            SYS_GETPEERNAME,
+            &[fd as SyscallWord, addr, len],
+        )
+    })
+    .map(|(_, addr)| addr)
+}
+
+/// Reads the option `name` of `level` of the socket `fd`, e.g. [`SO_TYPE`]
+/// of [`SOL_SOCKET`], into `value`, and returns its length.
+pub fn getsockopt(
+    fd: i32,
+    level: u32,
+    name: u32,
+    value: &mut [u8],
+) -> Result<usize, Errno> {
+    let mut len = value.len() as u32;
+    unsafe {
+        socket_syscall(
+            Sysno::getsockopt,
+            SYS_GETSOCKOPT,
+            &[
+                fd as SyscallWord,
+                SyscallWord::from(level),
+                SyscallWord::from(name),
+                value.as_mut_ptr() as SyscallWord,
+                core::ptr::from_mut(&mut len) as SyscallWord,
+            ],
+        )
+    }?;
+    Ok(len as usize)
+}
+
+/// Sets the option `name` of `level` of the socket `fd` to `value`.
+pub fn setsockopt(
+    fd: i32,
+    level: u32,
+    name: u32,
+    value: &[u8],
+) -> Result<(), Errno> {
+    unsafe {
+        socket_syscall(
+            Sysno::setsockopt,
+            SYS_SETSOCKOPT,
+            &[
+                fd as SyscallWord,
+                SyscallWord::from(level),
+                SyscallWord::from(name),
+                value.as_ptr() as SyscallWord,
+                value.len() as SyscallWord,
+            ],
+        )
+    }?;
+    Ok(())
+}
+
+/// Reads an option that is an `int`, as [`getsockopt`] does.
+pub fn getsockopt_int(fd: i32, level: u32, name: u32) -> Result<i32, Errno> {
+    let mut value = [0u8; 4];
+    getsockopt(fd, level, name, &mut value)?;
+    Ok(i32::from_ne_bytes(value))
+}
+
+/// Sets an option that is an `int`, e.g. 1 to turn on [`SO_REUSEADDR`], as
+/// [`setsockopt`] does.
+pub fn setsockopt_int(
+    fd: i32,
+    level: u32,
+    name: u32,
+    value: i32,
+) -> Result<(), Errno> {
+    setsockopt(fd, level, name, &value.to_ne_bytes())
+}
+
+/// Stops receiving, with [`SHUT_RD`], sending, with [`SHUT_WR`], or both
+/// on the socket `fd`, for every fd of it.
+pub fn shutdown(fd: i32, how: u32) -> Result<(), Errno> {
+    unsafe {
+        socket_syscall(
+            Sysno::shutdown,
+            SYS_SHUTDOWN,
+            &[fd as SyscallWord, SyscallWord::from(how)],
+        )
+    }?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::format::Buffer;
+    use crate::helpers::{close, getpid, read, write};
+
+    #[test]
+    fn addresses() {
+        assert_eq!(size_of::<SockaddrIn>(), 16);
+        assert_eq!(size_of::<SockaddrIn6>(), 28);
+        assert_eq!(size_of::<SockaddrNl>(), 12);
+        let addr = SockaddrIn::new(Ipv4Addr::LOCALHOST, 8080);
+        assert_eq!(addr.port.to_ne_bytes(), [0x1f, 0x90]);
+        assert_eq!(
+            Buffer::<32>::format(format_args!("{addr:?}")),
+            "SockaddrIn(127.0.0.1:8080)"
+        );
+        let v6 = SocketAddrV6::new(Ipv6Addr::LOCALHOST, 80, 7, 2);
+        assert_eq!(SocketAddrV6::from(SockaddrIn6::from(v6)), v6);
+
+        let path = SockaddrUn::new(b"/run/x.sock").unwrap();
+        assert_eq!((path.path(), path.addr_len()), (&b"/run/x.sock"[..], 14));
+        let name = SockaddrUn::new(b"\0name").unwrap();
+        assert_eq!((name.path(), name.addr_len()), (&b"\0name"[..], 7));
+        assert_eq!(
+            Buffer::<32>::format(format_args!("{name:?}")),
+            "SockaddrUn(\"@name\")"
+        );
+        assert_eq!(
+            SockaddrUn::new(&[b'a'; 108]).err(),
+            Some(Errno::ENAMETOOLONG)
+        );
+        assert_eq!(SockaddrUn::new(b"a\0b").err(), Some(Errno::EINVAL));
+    }
+
+    #[test]
+    fn tcp() {
+        let ty = SOCK_STREAM | SOCK_CLOEXEC;
+        let server = socket(AF_INET, ty | SOCK_NONBLOCK, 0).unwrap();
+        setsockopt_int(server, SOL_SOCKET, SO_REUSEADDR, 1).unwrap();
+        bind(server, &SockaddrIn::new(Ipv4Addr::LOCALHOST, 0)).unwrap();
+        listen(server, 1).unwrap();
+        let addr = getsockname(server).unwrap().as_in().unwrap();
+        assert_ne!(addr.port(), 0);
+        assert_eq!(accept4(server, 0).err(), Some(Errno::EAGAIN));
+
+        let client = socket(AF_INET, ty, 0).unwrap();
+        // The connection is made, and waits, before `connect` returns.
+        connect(client, &addr).unwrap();
+        let (conn, peer) = accept4(server, SOCK_CLOEXEC).unwrap();
+        assert_eq!(peer.as_in(), getsockname(client).unwrap().as_in());
+        assert_eq!(getpeername(client).unwrap().as_in(), Some(addr));
+        let ty = getsockopt_int(conn, SOL_SOCKET, SO_TYPE).unwrap();
+        assert_eq!(ty.cast_unsigned(), SOCK_STREAM);
+
+        write(client, b"ping").unwrap();
+        shutdown(client, SHUT_WR).unwrap();
+        let mut buf = [0u8; 8];
+        assert_eq!(read(conn, &mut buf), Ok(4));
+        assert_eq!(read(conn, &mut buf), Ok(0));
+        unsafe {
+            close(conn).unwrap();
+            close(client).unwrap();
+            close(server).unwrap();
+        }
+    }
+
+    #[test]
+    fn unix() {
+        let name = Buffer::<32>::format(format_args!("\0rawsys-{}", getpid()));
+        let addr = SockaddrUn::new(name.as_bytes()).unwrap();
+        let ty = SOCK_SEQPACKET | SOCK_CLOEXEC;
+        let server = socket(AF_UNIX, ty, 0).unwrap();
+        bind(server, &addr).unwrap();
+        assert_eq!(getsockname(server).unwrap().as_un(), Some(addr));
+        unsafe { close(server) }.unwrap();
+
+        let [a, b] = socketpair(AF_UNIX, ty, 0).unwrap();
+        write(a, b"x").unwrap();
+        assert_eq!(read(b, &mut [0u8; 4]), Ok(1));
+        let peer = getpeername(a).unwrap();
+        assert_eq!(peer.family(), AF_UNIX);
+        assert_eq!(peer.as_un().map(|addr| addr.path().len()), Some(0));
+        unsafe {
+            close(a).unwrap();
+            close(b).unwrap();
+        }
+    }
+}
@@ -0,0 +1,219 @@
+//! `socketcall` and `ipc`, the syscalls that multiplex the socket and
+//! System V IPC syscalls on x86, mips, powerpc, s390x, and sparc, whose
+//! older kernels don't have a syscall for each.
+
+use crate::{Errno, SyscallWord, Sysno};
+
+/// `call` of `socketcall`: `socket`.
+pub const SYS_SOCKET: u32 = 1;
+/// `call` of `socketcall`: `bind`.
+pub const SYS_BIND: u32 = 2;
+/// `call` of `socketcall`: `connect`.
+pub const SYS_CONNECT: u32 = 3;
+/// `call` of `socketcall`: `listen`.
+pub const SYS_LISTEN: u32 = 4;
+/// `call` of `socketcall`: `accept`.
+pub const SYS_ACCEPT: u32 = 5;
+/// `call` of `socketcall`: `getsockname`.
+pub const SYS_GETSOCKNAME: u32 = 6;
+/// `call` of `socketcall`: `getpeername`.
+pub const SYS_GETPEERNAME: u32 = 7;
+/// `call` of `socketcall`: `socketpair`.
+pub const SYS_SOCKETPAIR: u32 = 8;
+/// `call` of `socketcall`: `send`.
+pub const SYS_SEND: u32 = 9;
+/// `call` of `socketcall`: `recv`.
+pub const SYS_RECV: u32 = 10;
+/// `call` of `socketcall`: `sendto`.
+pub const SYS_SENDTO: u32 = 11;
+/// `call` of `socketcall`: `recvfrom`.
+pub const SYS_RECVFROM: u32 = 12;
+/// `call` of `socketcall`: `shutdown`.
+pub const SYS_SHUTDOWN: u32 = 13;
+/// `call` of `socketcall`: `setsockopt`.
+pub const SYS_SETSOCKOPT: u32 = 14;
+/// `call` of `socketcall`: `getsockopt`.
+pub const SYS_GETSOCKOPT: u32 = 15;
+/// `call` of `socketcall`: `sendmsg`.
+pub const SYS_SENDMSG: u32 = 16;
+/// `call` of `socketcall`: `recvmsg`.
+pub const SYS_RECVMSG: u32 = 17;
+/// `call` of `socketcall`: `accept4`.
+pub const SYS_ACCEPT4: u32 = 18;
+/// `call` of `socketcall`: `recvmmsg`.
+pub const SYS_RECVMMSG: u32 = 19;
+/// `call` of `socketcall`: `sendmmsg`.
+pub const SYS_SENDMMSG: u32 = 20;
+
+/// `call` of `ipc`: `semop`.
+pub const SEMOP: u32 = 1;
+/// `call` of `ipc`: `semget`.
+pub const SEMGET: u32 = 2;
+/// `call` of `ipc`: `semctl`.
+pub const SEMCTL: u32 = 3;
+/// `call` of `ipc`: `semtimedop`.
+pub const SEMTIMEDOP: u32 = 4;
+/// `call` of `ipc`: `msgsnd`.
+pub const MSGSND: u32 = 11;
+/// `call` of `ipc`: `msgrcv`.
+pub const MSGRCV: u32 = 12;
+/// `call` of `ipc`: `msgget`.
+pub const MSGGET: u32 = 13;
+/// `call` of `ipc`: `msgctl`.
+pub const MSGCTL: u32 = 14;
+/// `call` of `ipc`: `shmat`.
+pub const SHMAT: u32 = 21;
+/// `call` of `ipc`: `shmdt`.
+pub const SHMDT: u32 = 22;
+/// `call` of `ipc`: `shmget`.
+pub const SHMGET: u32 = 23;
+/// `call` of `ipc`: `shmctl`.
+pub const SHMCTL: u32 = 24;
+
+/// Makes the socket syscall `call`, e.g. [`SYS_SOCKET`], with the arguments
+/// `args`, of which the kernel reads as many as the syscall takes.
+///
+/// # Safety
+///
+/// As for the syscall of `call`: the pointers of `args` must be valid for
+/// what the syscall does with them.
+#[cfg(any(
+    target_arch = "x86",
+    target_arch = "mips",
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+    target_arch = "s390x",
+    target_arch = "sparc",
+    target_arch = "sparc64"
+))]
+pub unsafe fn socketcall(
+    call: u32,
+    args: &[SyscallWord; 6],
+) -> Result<SyscallWord, Errno> {
+    unsafe {
+        crate::syscall2(
+            Sysno::socketcall,
+            SyscallWord::from(call),
+            args.as_ptr() as SyscallWord,
+        )
+    }
+}
+
+/// Makes the System V IPC syscall `call`, e.g. [`SEMGET`], with its
+/// arguments in the order of `sys_ipc`, which differs from that of the
+/// syscall: `ptr` is the pointer that the syscall takes, and `fifth` the
+/// type of `msgrcv` or the timeout of `semtimedop`. The version of the
+/// structs goes in the high 16 bits of `call`.
+///
+/// # Safety
+///
+/// As for the syscall of `call`: `ptr` must be valid for what the syscall
+/// does with it, and `SHMDT` unmaps the memory at `ptr`.
+#[cfg(any(
+    target_arch = "x86",
+    target_arch = "mips",
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+    target_arch = "s390x",
+    target_arch = "sparc",
+    target_arch = "sparc64"
+))]
+pub unsafe fn ipc(
+    call: u32,
+    first: SyscallWord,
+    second: SyscallWord,
+    third: SyscallWord,
+    ptr: SyscallWord,
+    fifth: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    unsafe {
+        crate::syscall6(
+            Sysno::ipc,
+            SyscallWord::from(call),
+            first,
+            second,
+            third,
+            ptr,
+            fifth,
+        )
+    }
+}
+
+/// Makes the socket syscall `direct` with `args`, or, if the kernel doesn't
+/// have it, `call` of `socketcall` on the targets that have it, e.g. on
+/// x86 before Linux 4.3.
+pub(super) unsafe fn socket_syscall(
+    direct: Sysno,
+    #[cfg_attr(
+        not(any(
+            target_arch = "x86",
+            target_arch = "mips",
+            target_arch = "powerpc",
+            target_arch = "powerpc64",
+            target_arch = "s390x",
+            target_arch = "sparc",
+            target_arch = "sparc64"
+        )),
+        allow(unused_variables)
+    )]
+    call: u32,
+    args: &[SyscallWord],
+) -> Result<SyscallWord, Errno> {
+    let mut words = [0; 6];
+    words[..args.len()].copy_from_slice(args);
+    let [first, second, third, fourth, fifth, sixth] = words;
+    let result = unsafe {
+        crate::syscall6(direct, first, second, third, fourth, fifth, sixth)
+    };
+    match result {
+        #[cfg(any(
+            target_arch = "x86",
+            target_arch = "mips",
+            target_arch = "powerpc",
+            target_arch = "powerpc64",
+            target_arch = "s390x",
+            target_arch = "sparc",
+            target_arch = "sparc64"
+        ))]
+        Err(Errno::ENOSYS) => unsafe { socketcall(call, &words) },
+        result => result,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::{AF_UNIX, SOCK_CLOEXEC, SOCK_STREAM, close};
+
+    #[test]
+    fn multiplexed() {
+        let args =
+            [AF_UNIX, SOCK_STREAM | SOCK_CLOEXEC, 0].map(SyscallWord::from);
+        let fd = unsafe { socket_syscall(Sysno::socket, SYS_SOCKET, &args) };
+        unsafe { close(fd.unwrap() as i32) }.unwrap();
+        let args = [SyscallWord::MAX, 1];
+        let result =
+            unsafe { socket_syscall(Sysno::listen, SYS_LISTEN, &args) };
+        assert_eq!(result, Err(Errno::EBADF));
+        #[cfg(any(
+            target_arch = "x86",
+            target_arch = "mips",
+            target_arch = "powerpc",
+            target_arch = "powerpc64",
+            target_arch = "s390x",
+            target_arch = "sparc",
+            target_arch = "sparc64"
+        ))]
+        {
+            let mut fds = [0i32; 2];
+            let mut args = [0; 6];
+            args[..3].copy_from_slice(
+                &[AF_UNIX, SOCK_STREAM, 0].map(SyscallWord::from),
+            );
+            args[3] = fds.as_mut_ptr() as SyscallWord;
+            unsafe { socketcall(SYS_SOCKETPAIR, &args) }.unwrap();
+            unsafe { close(fds[0]) }.unwrap();
+            unsafe { close(fds[1]) }.unwrap();
+        }
+    }
+}
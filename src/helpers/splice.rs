@@ -0,0 +1,158 @@
+//! Moving data through pipes in the kernel: `splice`, `tee`, and
+//! `vmsplice`.
+
+use super::{IoVec, close, pipe2};
+use crate::consts::O_CLOEXEC;
+use crate::{Errno, SyscallWord, Sysno};
+
+/// `flags` of [`splice`]: move the pages instead of copying them, which is
+/// only a hint.
+pub const SPLICE_F_MOVE: u32 = 0x1;
+/// `flags` of [`splice`], [`tee`], and [`vmsplice`]: fail with `EAGAIN`
+/// instead of blocking on the pipes, though not on the other fd.
+pub const SPLICE_F_NONBLOCK: u32 = 0x2;
+/// `flags` of [`splice`]: more data follows, as with `MSG_MORE` for a
+/// socket.
+pub const SPLICE_F_MORE: u32 = 0x4;
+/// `flags` of [`vmsplice`]: give the pages to the kernel, which is only a
+/// hint.
+pub const SPLICE_F_GIFT: u32 = 0x8;
+
+/// The most bytes that [`pipe_pump`] moves at a time, the default size of
+/// a pipe.
+const CHUNK: usize = 64 * 1024;
+
+/// Moves up to `len` bytes from `fd_in` to `fd_out`, one of which must be
+/// a pipe, without copying them through user space, at the offsets
+/// `off_in` and `off_out` of the one that isn't, which it advances, or at
+/// its file position if they are `None`, and returns the number of bytes
+/// moved, which is 0 at the end of `fd_in`.
+pub fn splice(
+    fd_in: i32,
+    off_in: Option<&mut u64>,
+    fd_out: i32,
+    off_out: Option<&mut u64>,
+    len: usize,
+    flags: u32,
+) -> Result<usize, Errno> {
+    let moved = unsafe {
+        crate::syscall6(
+            Sysno::splice,
+            fd_in as SyscallWord,
+            off_in.map_or(0, |off| core::ptr::from_mut(off) as SyscallWord),
+            fd_out as SyscallWord,
+            off_out.map_or(0, |off| core::ptr::from_mut(off) as SyscallWord),
+            len as SyscallWord,
+            SyscallWord::from(flags),
+        )
+    }?;
+    Ok(moved as usize)
+}
+
+/// Copies up to `len` bytes from the pipe `fd_in` to the pipe `fd_out`
+/// without removing them from `fd_in`, and returns the number of bytes
+/// copied.
+pub fn tee(
+    fd_in: i32,
+    fd_out: i32,
+    len: usize,
+    flags: u32,
+) -> Result<usize, Errno> {
+    let copied = unsafe {
+        crate::syscall4(
+            Sysno::tee,
+            fd_in as SyscallWord,
+            fd_out as SyscallWord,
+            len as SyscallWord,
+            SyscallWord::from(flags),
+        )
+    }?;
+    Ok(copied as usize)
+}
+
+/// Adds the buffers `bufs` to the pipe `fd`, and returns the number of
+/// bytes added.
+///
+/// The pipe may refer to the pages of the buffers rather than copies of
+/// them, so what the reader gets can change if the buffers do before it
+/// reads them, which [`SPLICE_F_GIFT`] promises they won't.
+pub fn vmsplice(
+    fd: i32,
+    bufs: &[IoVec<'_>],
+    flags: u32,
+) -> Result<usize, Errno> {
+    let added = unsafe {
+        crate::syscall4(
+            Sysno::vmsplice,
+            fd as SyscallWord,
+            IoVec::as_raw_slice(bufs).as_ptr() as SyscallWord,
+            bufs.len() as SyscallWord,
+            SyscallWord::from(flags),
+        )
+    }?;
+    Ok(added as usize)
+}
+
+/// Moves all of the data of `from_fd` to `to_fd` with [`splice`], through
+/// a pipe of its own, until the end of `from_fd`, and returns the number of
+/// bytes moved, e.g. between two sockets of a proxy.
+///
+/// With an error, some of the data may have been moved to the pipe and
+/// lost.
+pub fn pipe_pump(from_fd: i32, to_fd: i32) -> Result<u64, Errno> {
+    let [r, w] = pipe2(O_CLOEXEC)?;
+    let pump = || {
+        let flags = SPLICE_F_MOVE | SPLICE_F_MORE;
+        let mut moved = 0;
+        loop {
+            let mut len = splice(from_fd, None, w, None, CHUNK, flags)?;
+            if len == 0 {
+                return Ok(moved);
+            }
+            while len > 0 {
+                let out = splice(r, None, to_fd, None, len, flags)?;
+                if out == 0 {
+                    return Err(Errno::EIO);
+                }
+                len -= out;
+                moved += out as u64;
+            }
+        }
+    };
+    let result = pump();
+    unsafe {
+        let _ = close(r);
+        let _ = close(w);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::{read, write};
+
+    #[test]
+    fn pipes() {
+        let [a, b] = pipe2(O_CLOEXEC).unwrap();
+        let [c, d] = pipe2(O_CLOEXEC).unwrap();
+        let bufs = [IoVec::new(b"hel"), IoVec::new(b"lo")];
+        assert_eq!(vmsplice(b, &bufs, 0), Ok(5));
+        assert_eq!(tee(a, d, 16, 0), Ok(5));
+        let mut buf = [0u8; 8];
+        assert_eq!(read(c, &mut buf), Ok(5));
+        assert_eq!(&buf[..5], b"hello");
+
+        write(b, b"!").unwrap();
+        unsafe { close(b) }.unwrap();
+        assert_eq!(pipe_pump(a, d), Ok(6));
+        assert_eq!(read(c, &mut buf), Ok(6));
+        assert_eq!(&buf[..6], b"hello!");
+        assert_eq!(splice(a, None, d, None, 1, 0), Ok(0));
+        unsafe {
+            close(a).unwrap();
+            close(c).unwrap();
+            close(d).unwrap();
+        }
+    }
+}
@@ -0,0 +1,402 @@
+//! Locks and one-time initialization on futexes, for code that can't use
+//! `std::sync`.
+
+use core::sync::atomic::{AtomicU32, Ordering};
+use core::time::Duration;
+
+use super::{FUTEX_PRIVATE_FLAG, Timespec, futex_wait, futex_wake};
+
+/// How many times a waiter checks the state again before it sleeps.
+const SPINS: u32 = 100;
+
+/// Spins until `state` is no longer `busy`, or for [`SPINS`] rounds, and
+/// returns the last state seen.
+fn spin(state: &AtomicU32, busy: u32) -> u32 {
+    for _ in 0..SPINS {
+        let current = state.load(Ordering::Relaxed);
+        if current != busy {
+            return current;
+        }
+        core::hint::spin_loop();
+    }
+    state.load(Ordering::Relaxed)
+}
+
+/// Sleeps while `state` holds `expected`. Spurious wakes, signals, and a
+/// changed value all return, so callers check the state again.
+fn wait(state: &AtomicU32, expected: u32, timeout: Option<&Timespec>) {
+    let _ = futex_wait(state, expected, timeout, FUTEX_PRIVATE_FLAG);
+}
+
+/// Wakes up to `count` of the threads that sleep on `state`.
+fn wake(state: &AtomicU32, count: u32) {
+    let _ = futex_wake(state, count, FUTEX_PRIVATE_FLAG);
+}
+
+/// Not locked.
+const UNLOCKED: u32 = 0;
+/// Locked, and nobody sleeps on it.
+const LOCKED: u32 = 1;
+/// Locked, and threads may sleep on it.
+const CONTENDED: u32 = 2;
+
+/// A mutual exclusion lock without data or guards, which spins briefly
+/// before it sleeps on a futex:
+///
+/// ```no_run
+/// use rawsys_linux::helpers::RawMutex;
+///
+/// static LOCK: RawMutex = RawMutex::new();
+///
+/// LOCK.lock();
+/// // ...
+/// unsafe { LOCK.unlock() };
+/// ```
+///
+/// It is 4 bytes, is not recursive, and is only shared by the threads of
+/// one process.
+#[derive(Debug, Default)]
+pub struct RawMutex {
+    state: AtomicU32,
+}
+
+impl RawMutex {
+    /// An unlocked mutex.
+    pub const fn new() -> Self {
+        Self {
+            state: AtomicU32::new(UNLOCKED),
+        }
+    }
+
+    /// Locks the mutex, waiting until other threads unlock it.
+    pub fn lock(&self) {
+        if !self.try_lock() {
+            self.lock_contended();
+        }
+    }
+
+    #[cold]
+    fn lock_contended(&self) {
+        let mut state = spin(&self.state, LOCKED);
+        if state == UNLOCKED {
+            match self.state.compare_exchange(
+                UNLOCKED,
+                LOCKED,
+                Ordering::Acquire,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return,
+                Err(current) => state = current,
+            }
+        }
+        loop {
+            // Whoever unlocks it next has to wake a thread, since this one
+            // is about to sleep. Taking it as `CONTENDED` may wake one
+            // needlessly, but never leaves one asleep.
+            if state != CONTENDED
+                && self.state.swap(CONTENDED, Ordering::Acquire) == UNLOCKED
+            {
+                return;
+            }
+            wait(&self.state, CONTENDED, None);
+            state = spin(&self.state, CONTENDED);
+        }
+    }
+
+    /// Locks the mutex if it is unlocked, and returns whether it did.
+    pub fn try_lock(&self) -> bool {
+        self.state
+            .compare_exchange(
+                UNLOCKED,
+                LOCKED,
+                Ordering::Acquire,
+                Ordering::Relaxed,
+            )
+            .is_ok()
+    }
+
+    /// Unlocks the mutex, waking a thread that waits on it.
+    ///
+    /// # Safety
+    ///
+    /// The mutex must be locked by the caller's context, whatever locked
+    /// it, since what it protects becomes available to others.
+    pub unsafe fn unlock(&self) {
+        if self.state.swap(UNLOCKED, Ordering::Release) == CONTENDED {
+            wake(&self.state, 1);
+        }
+    }
+
+    /// Whether the mutex is locked, which may change right after.
+    pub fn is_locked(&self) -> bool {
+        self.state.load(Ordering::Relaxed) != UNLOCKED
+    }
+}
+
+/// Not run yet, or the last run panicked.
+const INCOMPLETE: u32 = 0;
+/// Running, and nobody sleeps on it.
+const RUNNING: u32 = 1;
+/// Running, and threads may sleep on it.
+const QUEUED: u32 = 2;
+/// Has run.
+const COMPLETE: u32 = 3;
+
+/// Runs a function once, even when several threads call it at the same
+/// time, like `std::sync::Once`:
+///
+/// ```no_run
+/// use rawsys_linux::helpers::Once;
+///
+/// static INIT: Once = Once::new();
+///
+/// INIT.call_once(|| {
+///     // ...
+/// });
+/// assert!(INIT.is_completed());
+/// ```
+///
+/// The threads that call it while the function runs wait until it
+/// returns. If it panics, the next call runs its own function instead.
+#[derive(Debug, Default)]
+pub struct Once {
+    state: AtomicU32,
+}
+
+impl Once {
+    /// A `Once` that hasn't run.
+    pub const fn new() -> Self {
+        Self {
+            state: AtomicU32::new(INCOMPLETE),
+        }
+    }
+
+    /// Runs `f` if no call has completed yet, and returns once one has.
+    pub fn call_once(&self, f: impl FnOnce()) {
+        if !self.is_completed() {
+            self.call_once_slow(f);
+        }
+    }
+
+    #[cold]
+    fn call_once_slow(&self, f: impl FnOnce()) {
+        let mut state = self.state.load(Ordering::Acquire);
+        loop {
+            match state {
+                COMPLETE => return,
+                INCOMPLETE => {
+                    if let Err(current) = self.state.compare_exchange(
+                        INCOMPLETE,
+                        RUNNING,
+                        Ordering::Acquire,
+                        Ordering::Acquire,
+                    ) {
+                        state = current;
+                        continue;
+                    }
+                    // Makes it `INCOMPLETE` again if `f` unwinds.
+                    let mut run = Run {
+                        state: &self.state,
+                        end: INCOMPLETE,
+                    };
+                    f();
+                    run.end = COMPLETE;
+                    return;
+                }
+                RUNNING => {
+                    if let Err(current) = self.state.compare_exchange(
+                        RUNNING,
+                        QUEUED,
+                        Ordering::Acquire,
+                        Ordering::Acquire,
+                    ) {
+                        state = current;
+                        continue;
+                    }
+                    state = QUEUED;
+                }
+                _ => {
+                    wait(&self.state, QUEUED, None);
+                    state = self.state.load(Ordering::Acquire);
+                }
+            }
+        }
+    }
+
+    /// Whether a call has completed, after which none will run again.
+    pub fn is_completed(&self) -> bool {
+        self.state.load(Ordering::Acquire) == COMPLETE
+    }
+}
+
+/// The running call of a [`Once`], which sets its end state and wakes the
+/// waiters when dropped.
+struct Run<'a> {
+    state: &'a AtomicU32,
+    end: u32,
+}
+
+impl Drop for Run<'_> {
+    fn drop(&mut self) {
+        if self.state.swap(self.end, Ordering::Release) == QUEUED {
+            wake(self.state, i32::MAX as u32);
+        }
+    }
+}
+
+/// No token, and not parked.
+const EMPTY: u32 = 0;
+/// Has a token from [`Parker::unpark`].
+const NOTIFIED: u32 = 1;
+/// Parked, or about to be.
+const PARKED: u32 = u32::MAX;
+
+/// Blocks a thread until another one unparks it, like
+/// `std::thread::park` with a parker that isn't tied to the thread:
+///
+/// ```no_run
+/// use rawsys_linux::helpers::Parker;
+///
+/// static PARKER: Parker = Parker::new();
+///
+/// // On the thread that waits, which must be the only one to park:
+/// PARKER.park();
+/// // On another one:
+/// PARKER.unpark();
+/// ```
+///
+/// [`unpark`](Self::unpark) leaves a token when the thread isn't parked,
+/// which the next [`park`](Self::park) takes instead of blocking. Tokens
+/// don't add up, and `park` can return without one, so it is called in a
+/// loop that checks the condition it waits for.
+#[derive(Debug, Default)]
+pub struct Parker {
+    state: AtomicU32,
+}
+
+impl Parker {
+    /// A parker without a token.
+    pub const fn new() -> Self {
+        Self {
+            state: AtomicU32::new(EMPTY),
+        }
+    }
+
+    /// Blocks until there is a token, and takes it. Only one thread may
+    /// park on a parker at a time.
+    pub fn park(&self) {
+        self.park_until(None);
+    }
+
+    /// As [`park`](Self::park), but returns after `timeout` at the latest.
+    pub fn park_timeout(&self, timeout: Duration) {
+        self.park_until(Some(&Timespec::from(timeout)));
+    }
+
+    fn park_until(&self, timeout: Option<&Timespec>) {
+        // `NOTIFIED` becomes `EMPTY` and `EMPTY` becomes `PARKED`.
+        if self.state.fetch_sub(1, Ordering::Acquire) == NOTIFIED {
+            return;
+        }
+        if spin(&self.state, PARKED) == PARKED {
+            wait(&self.state, PARKED, timeout);
+        }
+        // Takes the token if there is one, or stops being parked.
+        self.state.swap(EMPTY, Ordering::Acquire);
+    }
+
+    /// Wakes the parked thread, or leaves a token for its next
+    /// [`park`](Self::park).
+    pub fn unpark(&self) {
+        if self.state.swap(NOTIFIED, Ordering::Release) == PARKED {
+            wake(&self.state, 1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single() {
+        let mutex = RawMutex::new();
+        mutex.lock();
+        assert!(mutex.is_locked());
+        assert!(!mutex.try_lock());
+        unsafe { mutex.unlock() };
+        assert!(mutex.try_lock());
+        unsafe { mutex.unlock() };
+
+        let once = Once::new();
+        let mut runs = 0;
+        once.call_once(|| runs += 1);
+        once.call_once(|| runs += 1);
+        assert_eq!(runs, 1);
+        assert!(once.is_completed());
+
+        let parker = Parker::new();
+        parker.unpark();
+        parker.unpark();
+        parker.park();
+        parker.park_timeout(Duration::from_millis(1));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn threads() {
+        use core::cell::UnsafeCell;
+
+        struct Counter(RawMutex, UnsafeCell<u32>);
+        unsafe impl Sync for Counter {}
+
+        let counter = Counter(RawMutex::new(), UnsafeCell::new(0));
+        let once = Once::new();
+        let runs = AtomicU32::new(0);
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                let (counter, once, runs) = (&counter, &once, &runs);
+                scope.spawn(move || {
+                    once.call_once(|| {
+                        std::thread::sleep(Duration::from_millis(10));
+                        runs.fetch_add(1, Ordering::Relaxed);
+                    });
+                    assert_eq!(runs.load(Ordering::Relaxed), 1);
+                    for _ in 0..1000 {
+                        counter.0.lock();
+                        unsafe { *counter.1.get() += 1 };
+                        unsafe { counter.0.unlock() };
+                    }
+                });
+            }
+        });
+        assert_eq!(counter.1.into_inner(), 4000);
+
+        let parker = Parker::new();
+        let ready = AtomicU32::new(0);
+        std::thread::scope(|scope| {
+            scope.spawn(|| {
+                while ready.load(Ordering::Acquire) == 0 {
+                    parker.park();
+                }
+            });
+            std::thread::sleep(Duration::from_millis(10));
+            ready.store(1, Ordering::Release);
+            parker.unpark();
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn once_panic() {
+        let once = Once::new();
+        let result = std::panic::catch_unwind(|| {
+            once.call_once(|| panic!("first"));
+        });
+        assert!(result.is_err());
+        assert!(!once.is_completed());
+        let mut ran = false;
+        once.call_once(|| ran = true);
+        assert!(ran);
+    }
+}
@@ -0,0 +1,160 @@
+//! Clocks, and sleeping on them.
+
+use core::time::Duration;
+
+use crate::time::{CLOCK_MONOTONIC, Timespec};
+use crate::{Errno, SyscallWord, Sysno};
+
+/// `flags` of [`clock_nanosleep`]: the time is a time of the clock, not a
+/// time from now.
+pub const TIMER_ABSTIME: u32 = 1;
+
+/// `clock_gettime`, `clock_getres`, `clock_settime`, and `clock_nanosleep`
+/// with 64-bit times, which are the `*64` ones on 32-bit targets.
+#[cfg(target_pointer_width = "64")]
+const CLOCK_GETTIME: Sysno = Sysno::clock_gettime;
+#[cfg(target_pointer_width = "32")]
+const CLOCK_GETTIME: Sysno = Sysno::clock_gettime64;
+#[cfg(target_pointer_width = "64")]
+const CLOCK_GETRES: Sysno = Sysno::clock_getres;
+#[cfg(target_pointer_width = "32")]
+const CLOCK_GETRES: Sysno = Sysno::clock_getres_time64;
+#[cfg(target_pointer_width = "64")]
+const CLOCK_SETTIME: Sysno = Sysno::clock_settime;
+#[cfg(target_pointer_width = "32")]
+const CLOCK_SETTIME: Sysno = Sysno::clock_settime64;
+#[cfg(target_pointer_width = "64")]
+const CLOCK_NANOSLEEP: Sysno = Sysno::clock_nanosleep;
+#[cfg(target_pointer_width = "32")]
+const CLOCK_NANOSLEEP: Sysno = Sysno::clock_nanosleep_time64;
+
+/// `unsigned long` of the kernel.
+#[cfg(any(target_pointer_width = "64", target_arch = "x86_64"))]
+pub(super) type ULong = u64;
+#[cfg(not(any(target_pointer_width = "64", target_arch = "x86_64")))]
+pub(super) type ULong = u32;
+
+/// Returns the time of the clock `clock`, e.g. [`CLOCK_MONOTONIC`].
+///
+/// Unlike [`vdso::clock_gettime`](crate::vdso::clock_gettime), it always
+/// enters the kernel, but it is available on every target.
+pub fn clock_gettime(clock: i32) -> Result<Timespec, Errno> {
+    let mut time = Timespec::default();
+    unsafe {
+        crate::syscall2(
+            CLOCK_GETTIME,
+            clock as SyscallWord,
+            core::ptr::from_mut(&mut time) as SyscallWord,
+        )
+    }?;
+    Ok(time)
+}
+
+/// Returns the resolution of the clock `clock`, e.g. 1 ns for
+/// [`CLOCK_MONOTONIC`] with high-resolution timers.
+pub fn clock_getres(clock: i32) -> Result<Timespec, Errno> {
+    let mut res = Timespec::default();
+    unsafe {
+        crate::syscall2(
+            CLOCK_GETRES,
+            clock as SyscallWord,
+            core::ptr::from_mut(&mut res) as SyscallWord,
+        )
+    }?;
+    Ok(res)
+}
+
+/// Sets the clock `clock`, which can only be
+/// [`CLOCK_REALTIME`](crate::time::CLOCK_REALTIME), to `time`. Needs
+/// `CAP_SYS_TIME`.
+pub fn clock_settime(clock: i32, time: &Timespec) -> Result<(), Errno> {
+    unsafe {
+        crate::syscall2(
+            CLOCK_SETTIME,
+            clock as SyscallWord,
+            core::ptr::from_ref(time) as SyscallWord,
+        )
+    }?;
+    Ok(())
+}
+
+/// Sleeps on the clock `clock` until the time `time`, with
+/// [`TIMER_ABSTIME`], or for `time` otherwise.
+///
+/// Fails with `EINTR` when a signal is handled, and then, for a relative
+/// `time`, fills in `remain` with the time that was left, to sleep again
+/// with it. Prefer [`sleep_until`] and [`sleep_for`], which don't drift
+/// when they are interrupted again and again.
+pub fn clock_nanosleep(
+    clock: i32,
+    flags: u32,
+    time: &Timespec,
+    remain: Option<&mut Timespec>,
+) -> Result<(), Errno> {
+    unsafe {
+        crate::syscall4(
+            CLOCK_NANOSLEEP,
+            clock as SyscallWord,
+            SyscallWord::from(flags),
+            core::ptr::from_ref(time) as SyscallWord,
+            remain
+                .map_or(0, |remain| core::ptr::from_mut(remain) as SyscallWord),
+        )
+    }?;
+    Ok(())
+}
+
+/// Sleeps until the clock `clock` reaches `deadline`, sleeping again after
+/// signals.
+pub fn sleep_until(clock: i32, deadline: &Timespec) -> Result<(), Errno> {
+    loop {
+        match clock_nanosleep(clock, TIMER_ABSTIME, deadline, None) {
+            Err(Errno::EINTR) => {}
+            result => return result,
+        }
+    }
+}
+
+/// Sleeps for `duration` on [`CLOCK_MONOTONIC`], sleeping again after
+/// signals until the time that it would have woken up.
+pub fn sleep_for(duration: Duration) -> Result<(), Errno> {
+    let deadline = clock_gettime(CLOCK_MONOTONIC)? + duration;
+    sleep_until(CLOCK_MONOTONIC, &deadline)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::CLOCK_REALTIME;
+
+    #[test]
+    fn clocks() {
+        let res = clock_getres(CLOCK_MONOTONIC).unwrap();
+        assert!(res > Timespec::default());
+        let now = clock_gettime(CLOCK_REALTIME).unwrap();
+        assert_eq!(clock_getres(-1), Err(Errno::EINVAL));
+        assert_eq!(clock_settime(CLOCK_MONOTONIC, &now), Err(Errno::EINVAL));
+        match clock_settime(CLOCK_REALTIME, &Timespec { sec: 0, nsec: -1 }) {
+            Err(Errno::EINVAL | Errno::EPERM) => {}
+            result => panic!("clock_settime: {result:?}"),
+        }
+    }
+
+    #[test]
+    fn sleep() {
+        let start = clock_gettime(CLOCK_MONOTONIC).unwrap();
+        sleep_for(Duration::from_millis(2)).unwrap();
+        let deadline = start + Duration::from_millis(2);
+        assert!(clock_gettime(CLOCK_MONOTONIC).unwrap() >= deadline);
+        sleep_until(CLOCK_MONOTONIC, &start).unwrap();
+
+        let time = Timespec::from(Duration::from_micros(100));
+        let mut remain = Timespec::default();
+        clock_nanosleep(CLOCK_MONOTONIC, 0, &time, Some(&mut remain)).unwrap();
+        let time = Timespec { sec: 0, nsec: -1 };
+        assert_eq!(
+            clock_nanosleep(CLOCK_MONOTONIC, 0, &time, None),
+            Err(Errno::EINVAL)
+        );
+    }
+}
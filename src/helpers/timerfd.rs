@@ -0,0 +1,145 @@
+//! timerfds, timers that are read like files.
+
+use super::{Timespec, read};
+use crate::{Errno, SyscallWord, Sysno};
+
+/// `flags` of [`timerfd_create`]: the fd has `O_CLOEXEC`.
+pub const TFD_CLOEXEC: u32 = crate::consts::O_CLOEXEC;
+/// `flags` of [`timerfd_create`]: reads fail with `EAGAIN` instead of
+/// blocking.
+pub const TFD_NONBLOCK: u32 = crate::consts::O_NONBLOCK;
+/// `flags` of [`timerfd_settime`]: `value` is a time of the clock, not a
+/// time from now.
+pub const TFD_TIMER_ABSTIME: u32 = 1;
+/// `flags` of [`timerfd_settime`]: with [`TFD_TIMER_ABSTIME`] on
+/// `CLOCK_REALTIME`, reads fail with `ECANCELED` when the clock is set.
+pub const TFD_TIMER_CANCEL_ON_SET: u32 = 2;
+
+/// `timerfd_settime` and `timerfd_gettime` with 64-bit times, which are
+/// `timerfd_settime64` and `timerfd_gettime64` on 32-bit targets.
+#[cfg(target_pointer_width = "64")]
+const TIMERFD_SETTIME: Sysno = Sysno::timerfd_settime;
+#[cfg(target_pointer_width = "32")]
+const TIMERFD_SETTIME: Sysno = Sysno::timerfd_settime64;
+#[cfg(target_pointer_width = "64")]
+const TIMERFD_GETTIME: Sysno = Sysno::timerfd_gettime;
+#[cfg(target_pointer_width = "32")]
+const TIMERFD_GETTIME: Sysno = Sysno::timerfd_gettime64;
+
+/// When a timer expires and then how often (`struct __kernel_itimerspec`).
+/// A zero `value` disarms it, and a zero `interval` makes it expire once.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ItimerSpec {
+    pub interval: Timespec,
+    pub value: Timespec,
+}
+
+/// Makes a timerfd on `clock`, e.g.
+/// [`CLOCK_MONOTONIC`](super::CLOCK_MONOTONIC), and returns it. `flags`
+/// may have [`TFD_CLOEXEC`] and [`TFD_NONBLOCK`]. It is disarmed until
+/// [`timerfd_settime`].
+///
+/// ```no_run
+/// use core::time::Duration;
+/// use rawsys_linux::helpers::{
+///     self, CLOCK_MONOTONIC, ItimerSpec, TFD_CLOEXEC,
+/// };
+///
+/// let fd = helpers::timerfd_create(CLOCK_MONOTONIC, TFD_CLOEXEC)?;
+/// let tick = Duration::from_millis(100).into();
+/// let spec = ItimerSpec { interval: tick, value: tick };
+/// helpers::timerfd_settime(fd, 0, &spec)?;
+/// loop {
+///     let expirations = helpers::timerfd_read(fd)?;
+///     println!("{expirations} ticks");
+/// }
+/// # Ok::<(), rawsys_linux::Errno>(())
+/// ```
+pub fn timerfd_create(clock: i32, flags: u32) -> Result<i32, Errno> {
+    let fd = unsafe {
+        crate::syscall2(
+            Sysno::timerfd_create,
+            clock as SyscallWord,
+            SyscallWord::from(flags),
+        )
+    }?;
+    Ok(fd as i32)
+}
+
+/// Arms or disarms the timerfd `fd` as `spec` says, and returns how it was
+/// set before. `flags` may have [`TFD_TIMER_ABSTIME`] and
+/// [`TFD_TIMER_CANCEL_ON_SET`].
+pub fn timerfd_settime(
+    fd: i32,
+    flags: u32,
+    spec: &ItimerSpec,
+) -> Result<ItimerSpec, Errno> {
+    let mut old = ItimerSpec::default();
+    unsafe {
+        crate::syscall4(
+            TIMERFD_SETTIME,
+            fd as SyscallWord,
+            SyscallWord::from(flags),
+            core::ptr::from_ref(spec) as SyscallWord,
+            core::ptr::from_mut(&mut old) as SyscallWord,
+        )
+    }?;
+    Ok(old)
+}
+
+/// Returns the time until the timerfd `fd` expires, which is zero if it is
+/// disarmed, and its interval.
+pub fn timerfd_gettime(fd: i32) -> Result<ItimerSpec, Errno> {
+    let mut spec = ItimerSpec::default();
+    unsafe {
+        crate::syscall2(
+            TIMERFD_GETTIME,
+            fd as SyscallWord,
+            core::ptr::from_mut(&mut spec) as SyscallWord,
+        )
+    }?;
+    Ok(spec)
+}
+
+/// Returns how many times the timerfd `fd` expired since it was last read
+/// or set, waiting until it expires if it hasn't, unless `fd` has
+/// [`TFD_NONBLOCK`].
+pub fn timerfd_read(fd: i32) -> Result<u64, Errno> {
+    let mut expirations = [0u8; 8];
+    read(fd, &mut expirations)?;
+    Ok(u64::from_ne_bytes(expirations))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::{CLOCK_MONOTONIC, close};
+    use core::time::Duration;
+
+    #[test]
+    fn timer() {
+        assert_eq!(size_of::<ItimerSpec>(), 32);
+        let fd = timerfd_create(CLOCK_MONOTONIC, TFD_CLOEXEC | TFD_NONBLOCK)
+            .unwrap();
+        assert_eq!(timerfd_read(fd), Err(Errno::EAGAIN));
+        let spec = ItimerSpec {
+            interval: Timespec::default(),
+            value: Duration::from_secs(10).into(),
+        };
+        assert_eq!(timerfd_settime(fd, 0, &spec), Ok(ItimerSpec::default()));
+        let left = timerfd_gettime(fd).unwrap();
+        assert!(left.value > Timespec::default() && left.value <= spec.value);
+        unsafe { close(fd) }.unwrap();
+
+        let fd = timerfd_create(CLOCK_MONOTONIC, TFD_CLOEXEC).unwrap();
+        let tick = Timespec::from(Duration::from_millis(1));
+        let spec = ItimerSpec {
+            interval: tick,
+            value: tick,
+        };
+        timerfd_settime(fd, 0, &spec).unwrap();
+        assert!(timerfd_read(fd).unwrap() >= 1);
+        unsafe { close(fd) }.unwrap();
+    }
+}
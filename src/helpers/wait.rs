@@ -0,0 +1,266 @@
+//! Waiting for children to change state, and what they used.
+
+use super::{CLD_CONTINUED, CLD_DUMPED, CLD_EXITED, CLD_KILLED, CLD_STOPPED};
+use super::{CLD_TRAPPED, Pid, Siginfo, Timeval};
+use crate::time::Long;
+use crate::{Errno, SyscallWord, Sysno};
+
+/// `options` of [`waitid`]: wait for the children to exit.
+pub const WEXITED: u32 = 4;
+/// `options` of [`waitid`]: wait for the children to stop.
+pub const WSTOPPED: u32 = 2;
+/// `options` of [`wait4`]: wait for the children to stop, as
+/// [`WSTOPPED`].
+pub const WUNTRACED: u32 = WSTOPPED;
+/// `options`: wait for the stopped children to continue.
+pub const WCONTINUED: u32 = 8;
+/// `options`: return `None` instead of waiting.
+pub const WNOHANG: u32 = 1;
+/// `options` of [`waitid`]: leave the child waitable.
+pub const WNOWAIT: u32 = 0x0100_0000;
+/// `options`: only wait for the children of the calling thread, not for
+/// those of the other threads of the process.
+pub const __WNOTHREAD: u32 = 0x2000_0000;
+/// `options`: wait for every child, whatever its exit signal.
+pub const __WALL: u32 = 0x4000_0000;
+/// `options`: only wait for the children whose exit signal isn't
+/// `SIGCHLD`, or that have none.
+pub const __WCLONE: u32 = 0x8000_0000;
+
+/// `idtype` of [`waitid`]: any child.
+pub const P_ALL: u32 = 0;
+/// `idtype` of [`waitid`]: the child whose pid is `id`.
+pub const P_PID: u32 = 1;
+/// `idtype` of [`waitid`]: the children in the process group `id`, or in
+/// that of the caller if it is 0.
+pub const P_PGID: u32 = 2;
+/// `idtype` of [`waitid`]: the child that the pidfd `id` refers to (Linux
+/// 5.4).
+pub const P_PIDFD: u32 = 3;
+
+/// A change in the state of a child.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WaitStatus {
+    /// It exited with this status.
+    Exited(i32),
+    /// It was killed by `signal`, and dumped core if `core_dumped`.
+    Signaled { signal: i32, core_dumped: bool },
+    /// It was stopped by this signal, by job control or by its tracer.
+    Stopped(i32),
+    /// Its tracer stopped it with `signal`, which is `SIGTRAP`, for the
+    /// `PTRACE_EVENT_*` `event`.
+    PtraceEvent { signal: i32, event: i32 },
+    /// It was continued by `SIGCONT`.
+    Continued,
+}
+
+impl WaitStatus {
+    /// Decodes the status of `wait4`, as the `W*` macros of the C libraries
+    /// do.
+    pub const fn from_raw(status: i32) -> Self {
+        let signal = (status >> 8) & 0xff;
+        let low = status & 0x7f;
+        if low == 0 {
+            Self::Exited(signal)
+        } else if status == 0xffff {
+            Self::Continued
+        } else if status & 0xff == 0x7f {
+            match status >> 16 {
+                0 => Self::Stopped(signal),
+                event => Self::PtraceEvent { signal, event },
+            }
+        } else {
+            Self::Signaled {
+                signal: low,
+                core_dumped: status & 0x80 != 0,
+            }
+        }
+    }
+
+    /// Decodes `si_code` and `si_status` of a `SIGCHLD` or of `waitid`.
+    pub(crate) const fn from_siginfo(code: i32, status: i32) -> Option<Self> {
+        Some(match code {
+            CLD_EXITED => Self::Exited(status),
+            CLD_KILLED | CLD_DUMPED => Self::Signaled {
+                signal: status,
+                core_dumped: code == CLD_DUMPED,
+            },
+            CLD_TRAPPED if status >> 8 != 0 => Self::PtraceEvent {
+                signal: status & 0xff,
+                event: status >> 8,
+            },
+            CLD_TRAPPED | CLD_STOPPED => Self::Stopped(status),
+            CLD_CONTINUED => Self::Continued,
+            _ => return None,
+        })
+    }
+}
+
+/// The resources that a process and its children used (`struct rusage`),
+/// in `long`s.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Rusage {
+    /// The CPU time in user mode.
+    pub utime: Timeval,
+    /// The CPU time in kernel mode.
+    pub stime: Timeval,
+    /// The largest resident set, in KiB.
+    pub maxrss: Long,
+    pub ixrss: Long,
+    pub idrss: Long,
+    pub isrss: Long,
+    /// The page faults that didn't need I/O.
+    pub minflt: Long,
+    /// The page faults that needed I/O.
+    pub majflt: Long,
+    pub nswap: Long,
+    /// The blocks that the filesystems read.
+    pub inblock: Long,
+    /// The blocks that the filesystems wrote.
+    pub oublock: Long,
+    pub msgsnd: Long,
+    pub msgrcv: Long,
+    pub nsignals: Long,
+    /// The voluntary context switches, e.g. to wait.
+    pub nvcsw: Long,
+    /// The involuntary context switches, e.g. at the end of a time slice.
+    pub nivcsw: Long,
+}
+
+/// Waits for a child that `idtype` and `id` select to change state, and
+/// returns its pid and status, and fills in `rusage` with what it used,
+/// if it was given. `options` needs one of [`WEXITED`], [`WSTOPPED`], and
+/// [`WCONTINUED`]. Returns `None` with [`WNOHANG`] if there is no change
+/// yet, and fails with `ECHILD` if there is no such child.
+pub fn waitid(
+    idtype: u32,
+    id: u32,
+    options: u32,
+    rusage: Option<&mut Rusage>,
+) -> Result<Option<(Pid, WaitStatus)>, Errno> {
+    let mut info = Siginfo::default();
+    unsafe {
+        crate::syscall5(
+            Sysno::waitid,
+            SyscallWord::from(idtype),
+            SyscallWord::from(id),
+            core::ptr::from_mut(&mut info) as SyscallWord,
+            SyscallWord::from(options),
+            rusage
+                .map_or(0, |rusage| core::ptr::from_mut(rusage) as SyscallWord),
+        )
+    }?;
+    // `si_pid` is 0 if `WNOHANG` found nothing.
+    if info.pid().as_raw() == 0 {
+        return Ok(None);
+    }
+    let status = info.child_status().ok_or(Errno::EINVAL)?;
+    Ok(Some((info.pid(), status)))
+}
+
+/// Waits for the child `pid` to change state, or any child if it is -1,
+/// any in the process group `-pid` if it is below -1, or any in that of
+/// the caller if it is 0. Returns its pid and status, and fills in
+/// `rusage` with what it used, if it was given. `options` may have
+/// [`WNOHANG`], [`WUNTRACED`], [`WCONTINUED`], and [`__WALL`].
+pub fn wait4(
+    pid: Pid,
+    options: u32,
+    rusage: Option<&mut Rusage>,
+) -> Result<Option<(Pid, WaitStatus)>, Errno> {
+    let mut status = 0i32;
+    let pid = unsafe {
+        crate::syscall4(
+            Sysno::wait4,
+            SyscallWord::from(pid),
+            core::ptr::from_mut(&mut status) as SyscallWord,
+            SyscallWord::from(options),
+            rusage
+                .map_or(0, |rusage| core::ptr::from_mut(rusage) as SyscallWord),
+        )
+    }?;
+    // 0 if `WNOHANG` found nothing.
+    if pid == 0 {
+        return Ok(None);
+    }
+    Ok(Some((
+        Pid::from_raw(pid as i32),
+        WaitStatus::from_raw(status),
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::{
+        CloneArgs, CloneResult, SIGCHLD, SIGKILL, Signal, clone3, exit_group,
+        kill, sleep_for,
+    };
+    use core::time::Duration;
+
+    #[test]
+    fn raw() {
+        assert_eq!(WaitStatus::from_raw(0x0300), WaitStatus::Exited(3));
+        assert_eq!(
+            WaitStatus::from_raw(0x0089),
+            WaitStatus::Signaled {
+                signal: 9,
+                core_dumped: true
+            }
+        );
+        assert_eq!(WaitStatus::from_raw(0x137f), WaitStatus::Stopped(0x13));
+        assert_eq!(
+            WaitStatus::from_raw(0x0003_057f),
+            WaitStatus::PtraceEvent {
+                signal: 5,
+                event: 3
+            }
+        );
+        assert_eq!(WaitStatus::from_raw(0xffff), WaitStatus::Continued);
+    }
+
+    /// Starts a child that exits with `status`, or waits to be killed,
+    /// unless the container blocks `clone3`.
+    fn child(status: Option<i32>) -> Option<Pid> {
+        let args = CloneArgs::new().exit_signal(SIGCHLD);
+        match unsafe { clone3(&args) } {
+            Ok(CloneResult::Child) => loop {
+                if let Some(status) = status {
+                    exit_group(status);
+                }
+                let _ = sleep_for(Duration::from_secs(1));
+            },
+            Ok(CloneResult::Parent(pid)) => Some(pid),
+            Err(Errno::ENOSYS | Errno::EPERM) => None,
+            Err(errno) => panic!("clone3: {errno}"),
+        }
+    }
+
+    #[test]
+    fn children() {
+        let Some(pid) = child(Some(7)) else { return };
+        let mut rusage = Rusage::default();
+        let id = pid.as_raw().cast_unsigned();
+        assert_eq!(
+            waitid(P_PID, id, WEXITED | WNOWAIT, Some(&mut rusage)),
+            Ok(Some((pid, WaitStatus::Exited(7))))
+        );
+        assert_eq!(
+            wait4(pid, 0, Some(&mut rusage)),
+            Ok(Some((pid, WaitStatus::Exited(7))))
+        );
+        assert!(rusage.maxrss > 0);
+        assert_eq!(wait4(pid, WNOHANG, None), Err(Errno::ECHILD));
+
+        let Some(pid) = child(None) else { return };
+        let id = pid.as_raw().cast_unsigned();
+        assert_eq!(waitid(P_PID, id, WEXITED | WNOHANG, None), Ok(None));
+        kill(pid, Signal::Kill).unwrap();
+        let killed = WaitStatus::Signaled {
+            signal: SIGKILL,
+            core_dumped: false,
+        };
+        assert_eq!(waitid(P_PID, id, WEXITED, None), Ok(Some((pid, killed))));
+    }
+}
@@ -0,0 +1,103 @@
+//! Process, thread, user, and group ids, and fd numbers
+//!
+//! Each id is an `i32` or a `u32` of a type of its own, so that a pid isn't
+//! passed where a tid is meant, or a uid where a gid is. They convert to
+//! [`SyscallWord`]s as the kernel reads them: the signed ones are
+//! sign-extended, so that -1 stays -1 in a 64-bit register, and the unsigned
+//! ones are zero-extended.
+//!
+//! ```
+//! use rawsys_linux::SyscallWord;
+//! use rawsys_linux::id::{Pid, Uid};
+//!
+//! assert_eq!(SyscallWord::from(Pid::from_raw(-1)), SyscallWord::MAX);
+//! assert_eq!(SyscallWord::from(Uid::from_raw(u32::MAX)), 0xffff_ffff);
+//! ```
+
+use core::fmt;
+
+use crate::SyscallWord;
+
+/// Defines the id types, with their raw types.
+macro_rules! ids {
+    ($(
+        $(#[$attr:meta])*
+        $name:ident($raw:ty);
+    )*) => {
+        $(
+            $(#[$attr])*
+            #[repr(transparent)]
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+            #[cfg_attr(
+                feature = "serde",
+                derive(serde::Serialize, serde::Deserialize),
+                serde(transparent)
+            )]
+            pub struct $name($raw);
+
+            impl $name {
+                pub const fn from_raw(raw: $raw) -> Self {
+                    Self(raw)
+                }
+
+                pub const fn as_raw(self) -> $raw {
+                    self.0
+                }
+            }
+
+            impl fmt::Display for $name {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(&self.0, f)
+                }
+            }
+
+            impl From<$name> for SyscallWord {
+                #[allow(clippy::cast_lossless, clippy::unnecessary_cast)]
+                fn from(id: $name) -> Self {
+                    id.0 as Self
+                }
+            }
+        )*
+    };
+}
+
+ids! {
+    /// A process id, which is also that of its main thread, or the id of a
+    /// process group where a function says so.
+    Pid(i32);
+    /// A thread id, which ptrace and the thread-directed signals take.
+    Tid(i32);
+    /// A user id. `u32::MAX` means "unchanged" to the `setres*id` syscalls.
+    Uid(u32);
+    /// A group id.
+    Gid(u32);
+    /// An fd number, which this type doesn't own or close, e.g. one of
+    /// another process.
+    Fd(i32);
+}
+
+impl Tid {
+    /// The id of the main thread of the process `pid`, which is the same.
+    pub const fn main_thread(pid: Pid) -> Self {
+        Self(pid.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::format::Buffer;
+
+    #[test]
+    fn words() {
+        assert_eq!(SyscallWord::from(Tid::from_raw(7)), 7);
+        let fd = SyscallWord::from(Fd::from_raw(-100));
+        assert_eq!(fd, (-100isize) as SyscallWord);
+        assert_eq!(SyscallWord::from(Gid::from_raw(u32::MAX)), 0xffff_ffff);
+        assert_eq!(Tid::main_thread(Pid::from_raw(3)).as_raw(), 3);
+        assert_eq!(
+            Buffer::<8>::format(format_args!("{}", Pid::from_raw(-42))),
+            "-42"
+        );
+    }
+}
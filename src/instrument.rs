@@ -0,0 +1,58 @@
+//! `tracing` events of the checked syscall wrappers
+//!
+//! Each syscall made through [`syscall0`](crate::syscall0) to
+//! [`syscall6`](crate::syscall6), which the `syscall!` macro and the modules
+//! of this crate use too, emits a `trace`-level event with the target
+//! `rawsys_linux::syscall`:
+//!
+//! ```text
+//! TRACE rawsys_linux::syscall: sysno="openat" args=[0xffffff9c, 0x7ffc1000, 0x80000] errno="ENOENT" code=2
+//! ```
+//!
+//! The subscriber must not make syscalls through this crate, since those
+//! would emit events while it handles one.
+
+use core::fmt;
+
+use crate::{Errno, SyscallWord, Sysno};
+
+/// Shows the arguments as `[0x3, 0x7ffc1000]`.
+struct Hex<'a>(&'a [SyscallWord]);
+
+impl fmt::Display for Hex<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[")?;
+        for (i, arg) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{arg:#x}")?;
+        }
+        f.write_str("]")
+    }
+}
+
+/// Emits the event for the syscall `nr` with `args`, which returned
+/// `result`.
+#[inline]
+pub(crate) fn record(
+    nr: Sysno,
+    args: &[SyscallWord],
+    result: &Result<SyscallWord, Errno>,
+) {
+    match result {
+        Ok(ret) => tracing::trace!(
+            target: "rawsys_linux::syscall",
+            sysno = nr.name(),
+            args = %Hex(args),
+            ret,
+        ),
+        Err(errno) => tracing::trace!(
+            target: "rawsys_linux::syscall",
+            sysno = nr.name(),
+            args = %Hex(args),
+            errno = errno.name().unwrap_or("?"),
+            code = errno.into_raw(),
+        ),
+    }
+}
@@ -0,0 +1,397 @@
+//! Batches of common syscalls, submitted at once.
+
+use core::ffi::CStr;
+use core::marker::PhantomData;
+use core::mem::ManuallyDrop;
+
+use super::{
+    IORING_OP_CLOSE, IORING_OP_OPENAT, IORING_OP_READ, IORING_OP_STATX,
+    IORING_OP_WRITE, IORING_SETUP_SQPOLL, IoUring, Sqe,
+};
+use crate::helpers::Statx;
+use crate::{Errno, Sysno};
+
+/// The kinds of operations, in the upper half of the `user_data`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+enum Kind {
+    Read = 1,
+    Write,
+    Openat,
+    Statx,
+    Close,
+}
+
+impl Kind {
+    fn from_user_data(user_data: u64) -> Option<Self> {
+        Some(match (user_data >> 32) as u8 {
+            1 => Self::Read,
+            2 => Self::Write,
+            3 => Self::Openat,
+            4 => Self::Statx,
+            5 => Self::Close,
+            _ => return None,
+        })
+    }
+}
+
+/// Syscalls that are queued in an [`IoUring`], and run by the kernel
+/// together when [`submit`](Self::submit) enters it once, instead of once
+/// per syscall:
+///
+/// ```no_run
+/// use rawsys_linux::consts::{AT_FDCWD, O_RDONLY};
+/// use rawsys_linux::helpers::{STATX_SIZE, Statx};
+/// use rawsys_linux::io_uring::{Batch, Completion, IoUring};
+///
+/// let mut ring = IoUring::new(64)?;
+/// let paths = [c"/etc/hostname", c"/etc/os-release"];
+/// let mut stats = [Statx::default(); 2];
+/// let mut batch = Batch::new(&mut ring)?;
+/// for (path, stat) in paths.iter().zip(&mut stats) {
+///     batch.statx(AT_FDCWD, path, 0, STATX_SIZE, stat)?;
+///     batch.openat(AT_FDCWD, path, O_RDONLY, 0)?;
+/// }
+/// for (index, completion) in batch.submit()? {
+///     match completion {
+///         Completion::Openat(Ok(fd)) => println!("{index}: fd {fd}"),
+///         completion => println!("{index}: {completion:?}"),
+///     }
+/// }
+/// # Ok::<(), rawsys_linux::Errno>(())
+/// ```
+///
+/// The operations borrow their buffers and paths until `submit` returns,
+/// after all of them have completed, so the buffers can't be used or freed
+/// while the kernel uses them. A batch has at most as many operations as
+/// the ring has submission entries, and operations that aren't submitted
+/// are dropped with the batch.
+///
+/// The operations need Linux 5.6.
+#[derive(Debug)]
+pub struct Batch<'ring, 'buf> {
+    ring: &'ring mut IoUring,
+    queued: u32,
+    _buf: PhantomData<&'buf mut [u8]>,
+}
+
+impl<'ring, 'buf> Batch<'ring, 'buf> {
+    /// Starts a batch on `ring`, which must have no pending entries or
+    /// completions (`EBUSY`), and not be [`IORING_SETUP_SQPOLL`]
+    /// (`EINVAL`).
+    pub fn new(ring: &'ring mut IoUring) -> Result<Self, Errno> {
+        if ring.params().flags & IORING_SETUP_SQPOLL != 0 {
+            return Err(Errno::EINVAL);
+        }
+        if ring.sq_space_left() != ring.params().sq_entries
+            || ring.cq_ready() != 0
+        {
+            return Err(Errno::EBUSY);
+        }
+        Ok(Self {
+            ring,
+            queued: 0,
+            _buf: PhantomData,
+        })
+    }
+
+    /// How many operations are queued.
+    pub fn len(&self) -> usize {
+        self.queued as usize
+    }
+
+    /// Whether no operations are queued.
+    pub fn is_empty(&self) -> bool {
+        self.queued == 0
+    }
+
+    /// Queues `sqe` as an operation of `kind`, and returns its index.
+    fn queue(&mut self, kind: Kind, sqe: Sqe) -> Result<usize, Errno> {
+        let index = self.queued;
+        let sqe = sqe.user_data(((kind as u64) << 32) | u64::from(index));
+        // The buffers of `sqe` are borrowed for `'buf`, which lasts until
+        // `submit` has waited for all of the operations.
+        unsafe { self.ring.push(&sqe) }?;
+        self.queued += 1;
+        Ok(index as usize)
+    }
+
+    /// Queues a read from `fd` into `buf`, at `offset` or at the file
+    /// position if it is `None`, like [`pread`](crate::helpers::pread) or
+    /// [`read`](crate::helpers::read). Returns the index of the operation.
+    /// Fails with `EBUSY` if the batch is full.
+    pub fn read(
+        &mut self,
+        fd: i32,
+        buf: &'buf mut [u8],
+        offset: Option<u64>,
+    ) -> Result<usize, Errno> {
+        let mut sqe = Sqe::new(IORING_OP_READ, fd);
+        sqe.addr = buf.as_mut_ptr() as u64;
+        sqe.len = buf.len().try_into().unwrap_or(u32::MAX);
+        sqe.off = offset.unwrap_or(u64::MAX);
+        self.queue(Kind::Read, sqe)
+    }
+
+    /// Queues a write of `buf` to `fd`, at `offset` or at the file position
+    /// if it is `None`, like [`pwrite`](crate::helpers::pwrite) or
+    /// [`write`](crate::helpers::write).
+    pub fn write(
+        &mut self,
+        fd: i32,
+        buf: &'buf [u8],
+        offset: Option<u64>,
+    ) -> Result<usize, Errno> {
+        let mut sqe = Sqe::new(IORING_OP_WRITE, fd);
+        sqe.addr = buf.as_ptr() as u64;
+        sqe.len = buf.len().try_into().unwrap_or(u32::MAX);
+        sqe.off = offset.unwrap_or(u64::MAX);
+        self.queue(Kind::Write, sqe)
+    }
+
+    /// Queues an [`openat`](crate::helpers::openat) of `path`, relative to
+    /// `dirfd`. The kernel adds `O_LARGEFILE` to `flags`.
+    pub fn openat(
+        &mut self,
+        dirfd: i32,
+        path: &'buf CStr,
+        flags: u32,
+        mode: u32,
+    ) -> Result<usize, Errno> {
+        let mut sqe = Sqe::new(IORING_OP_OPENAT, dirfd);
+        sqe.addr = path.as_ptr() as u64;
+        sqe.len = mode;
+        sqe.op_flags = flags;
+        self.queue(Kind::Openat, sqe)
+    }
+
+    /// Queues a [`statx`](crate::helpers::statx) of `path`, relative to
+    /// `dirfd`, into `statx`.
+    pub fn statx(
+        &mut self,
+        dirfd: i32,
+        path: &'buf CStr,
+        flags: u32,
+        mask: u32,
+        statx: &'buf mut Statx,
+    ) -> Result<usize, Errno> {
+        let mut sqe = Sqe::new(IORING_OP_STATX, dirfd);
+        sqe.addr = path.as_ptr() as u64;
+        sqe.len = mask;
+        sqe.op_flags = flags;
+        // `addr2`
+        sqe.off = core::ptr::from_mut(statx) as u64;
+        self.queue(Kind::Statx, sqe)
+    }
+
+    /// Queues a [`close`](crate::helpers::close) of `fd`.
+    ///
+    /// # Safety
+    ///
+    /// As for `close`, nothing else may own `fd`. It may be closed before
+    /// the other operations of the batch run, so none of them may use it.
+    pub unsafe fn close(&mut self, fd: i32) -> Result<usize, Errno> {
+        self.queue(Kind::Close, Sqe::new(IORING_OP_CLOSE, fd))
+    }
+
+    /// Submits the operations with one `io_uring_enter`, or more if it is
+    /// interrupted, and waits until all of them have completed.
+    ///
+    /// If the kernel stops taking operations, the ones that it took are
+    /// still waited for and have completions, and the rest are dropped.
+    /// Fails with its errno if it took none.
+    pub fn submit(self) -> Result<BatchCompletions<'ring>, Errno> {
+        let mut queued = self.queued;
+        loop {
+            let ready = self.ring.cq_ready();
+            if ready >= queued {
+                break;
+            }
+            match self.ring.submit_and_wait(queued - ready) {
+                Ok(_) | Err(Errno::EINTR | Errno::EAGAIN | Errno::EBUSY) => {}
+                Err(errno) => {
+                    // Without `SQPOLL`, the kernel only takes entries in
+                    // `io_uring_enter`, which failed.
+                    queued -= self.ring.retract();
+                    if queued == 0 {
+                        return Err(errno);
+                    }
+                }
+            }
+        }
+        // Moves the ring out, since there is nothing left to drop.
+        let batch = ManuallyDrop::new(self);
+        let ring = unsafe { core::ptr::read(&raw const batch.ring) };
+        Ok(BatchCompletions { ring })
+    }
+}
+
+impl Drop for Batch<'_, '_> {
+    /// Drops the operations, which weren't submitted.
+    fn drop(&mut self) {
+        self.ring.discard_pending();
+    }
+}
+
+/// The result of an operation of a [`Batch`], with the type that the
+/// syscall's helper returns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Completion {
+    /// How many bytes were read.
+    Read(Result<usize, Errno>),
+    /// How many bytes were written.
+    Write(Result<usize, Errno>),
+    /// The new fd.
+    Openat(Result<i32, Errno>),
+    /// Whether the `Statx` was filled in.
+    Statx(Result<(), Errno>),
+    /// Whether the fd was closed.
+    Close(Result<(), Errno>),
+}
+
+impl Completion {
+    /// The syscall that the operation is like.
+    pub fn sysno(&self) -> Sysno {
+        match self {
+            Self::Read(_) => Sysno::read,
+            Self::Write(_) => Sysno::write,
+            Self::Openat(_) => Sysno::openat,
+            Self::Statx(_) => Sysno::statx,
+            Self::Close(_) => Sysno::close,
+        }
+    }
+}
+
+/// The completions of a submitted [`Batch`], with the index of each
+/// operation, in the order the operations completed.
+#[derive(Debug)]
+pub struct BatchCompletions<'ring> {
+    ring: &'ring mut IoUring,
+}
+
+impl Iterator for BatchCompletions<'_> {
+    type Item = (usize, Completion);
+
+    fn next(&mut self) -> Option<(usize, Completion)> {
+        loop {
+            let cqe = self.ring.pop_completion()?;
+            let index = cqe.user_data as u32 as usize;
+            let result = cqe.result();
+            let completion = match Kind::from_user_data(cqe.user_data) {
+                Some(Kind::Read) => {
+                    Completion::Read(result.map(|n| n as usize))
+                }
+                Some(Kind::Write) => {
+                    Completion::Write(result.map(|n| n as usize))
+                }
+                Some(Kind::Openat) => {
+                    Completion::Openat(result.map(|fd| fd as i32))
+                }
+                Some(Kind::Statx) => Completion::Statx(result.map(drop)),
+                Some(Kind::Close) => Completion::Close(result.map(drop)),
+                // Not of the batch.
+                None => continue,
+            };
+            return Some((index, completion));
+        }
+    }
+}
+
+impl Drop for BatchCompletions<'_> {
+    /// Takes the completions that weren't iterated over, so that the ring
+    /// can be used for another batch.
+    fn drop(&mut self) {
+        for _ in self.by_ref() {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consts::{AT_FDCWD, O_CLOEXEC, O_RDONLY};
+    use crate::helpers::{STATX_SIZE, close, pipe2};
+
+    #[test]
+    fn batch() {
+        let mut ring = match IoUring::new(8) {
+            Ok(ring) => ring,
+            Err(Errno::ENOSYS | Errno::EPERM) => return,
+            Err(errno) => panic!("io_uring_setup: {errno}"),
+        };
+        let [rd, wr] = pipe2(O_CLOEXEC).unwrap();
+        let mut buf = [0u8; 8];
+        let mut stat = Statx::default();
+        let mut batch = Batch::new(&mut ring).unwrap();
+        assert!(batch.is_empty());
+        assert_eq!(batch.write(wr, b"batch", None), Ok(0));
+        assert_eq!(batch.read(rd, &mut buf, None), Ok(1));
+        assert_eq!(
+            batch.statx(AT_FDCWD, c"/", 0, STATX_SIZE, &mut stat),
+            Ok(2)
+        );
+        assert_eq!(
+            batch.openat(AT_FDCWD, c"/", O_RDONLY | O_CLOEXEC, 0),
+            Ok(3)
+        );
+        assert_eq!(batch.openat(AT_FDCWD, c"/nonexistent", O_RDONLY, 0), Ok(4));
+        assert_eq!(batch.len(), 5);
+
+        let mut seen = 0;
+        for (index, completion) in batch.submit().unwrap() {
+            seen |= 1 << index;
+            match (index, completion) {
+                (0, Completion::Write(Ok(5)))
+                | (1, Completion::Read(Ok(5)))
+                | (2, Completion::Statx(Ok(()))) => {}
+                (3, Completion::Openat(Ok(fd))) => unsafe {
+                    close(fd).unwrap();
+                },
+                (4, completion) => {
+                    assert_eq!(
+                        completion,
+                        Completion::Openat(Err(Errno::ENOENT))
+                    );
+                    assert_eq!(completion.sysno(), Sysno::openat);
+                }
+                completion => panic!("unexpected {completion:?}"),
+            }
+        }
+        assert_eq!(seen, 0b11111);
+        assert_eq!(&buf[..5], b"batch");
+        assert_ne!(stat.mask & STATX_SIZE, 0);
+
+        // Dropped without being submitted.
+        let mut batch = Batch::new(&mut ring).unwrap();
+        unsafe { batch.close(rd) }.unwrap();
+        drop(batch);
+        let mut batch = Batch::new(&mut ring).unwrap();
+        unsafe {
+            batch.close(rd).unwrap();
+            batch.close(wr).unwrap();
+        }
+        let closed = batch
+            .submit()
+            .unwrap()
+            .filter(|(_, completion)| *completion == Completion::Close(Ok(())));
+        assert_eq!(closed.count(), 2);
+    }
+
+    #[test]
+    fn full() {
+        let Ok(mut ring) = IoUring::new(2) else {
+            return;
+        };
+        let mut batch = Batch::new(&mut ring).unwrap();
+        let mut bufs = [[0u8; 1]; 3];
+        let [a, b, c] = &mut bufs;
+        assert_eq!(batch.read(-1, a, None), Ok(0));
+        assert_eq!(batch.read(-1, b, None), Ok(1));
+        assert_eq!(batch.read(-1, c, None), Err(Errno::EBUSY));
+        let mut completions = batch.submit().unwrap();
+        let (_, completion) = completions.next().unwrap();
+        assert_eq!(completion, Completion::Read(Err(Errno::EBADF)));
+        // The other one is taken when they are dropped.
+        drop(completions);
+        assert!(Batch::new(&mut ring).is_ok());
+    }
+}
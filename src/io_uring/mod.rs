@@ -0,0 +1,507 @@
+//! `io_uring` rings
+//!
+//! The layouts of the kernel's `io_uring` interface (Linux 5.1), thin
+//! wrappers of its three syscalls, and [`IoUring`], which sets up a ring,
+//! maps its queues, and gives access to them:
+//!
+//! ```no_run
+//! use rawsys_linux::io_uring::{IoUring, Sqe};
+//!
+//! let mut ring = IoUring::new(8)?;
+//! unsafe { ring.push(&Sqe::nop().user_data(42)) }?;
+//! ring.submit_and_wait(1)?;
+//! let cqe = ring.completions().next().unwrap();
+//! assert_eq!((cqe.user_data, cqe.res), (42, 0));
+//! # Ok::<(), rawsys_linux::Errno>(())
+//! ```
+//!
+//! Entries are the plain [`Sqe`] and [`Cqe`] structs, whose fields mean
+//! what the opcode says, as in the kernel's `struct io_uring_sqe`. The
+//! rings with 128-byte submission entries or 32-byte completion entries
+//! (`IORING_SETUP_SQE128` and `IORING_SETUP_CQE32`) aren't supported.
+//!
+//! A [`Batch`] queues common syscalls, such as reads and `statx`es, with
+//! the borrows of their buffers checked, and runs them all at once.
+
+use core::ffi::c_void;
+
+use crate::{Errno, SyscallWord, Sysno};
+
+mod batch;
+mod ring;
+
+pub use self::batch::{Batch, BatchCompletions, Completion};
+pub use self::ring::{Completions, IoUring};
+
+/// `flags` of [`Params`]: busy-waits for completions instead of
+/// interrupts, for files opened with `O_DIRECT`.
+pub const IORING_SETUP_IOPOLL: u32 = 1 << 0;
+/// `flags` of [`Params`]: a kernel thread polls the submission queue, so
+/// submitting doesn't need [`enter`].
+pub const IORING_SETUP_SQPOLL: u32 = 1 << 1;
+/// `flags` of [`Params`]: pins the polling thread to `sq_thread_cpu`.
+pub const IORING_SETUP_SQ_AFF: u32 = 1 << 2;
+/// `flags` of [`Params`]: the completion queue has `cq_entries` entries.
+pub const IORING_SETUP_CQSIZE: u32 = 1 << 3;
+/// `flags` of [`Params`]: clamps too many entries instead of failing.
+pub const IORING_SETUP_CLAMP: u32 = 1 << 4;
+/// `flags` of [`Params`]: shares the workers of the ring `wq_fd`.
+pub const IORING_SETUP_ATTACH_WQ: u32 = 1 << 5;
+/// `flags` of [`Params`]: starts disabled, until
+/// [`IORING_REGISTER_ENABLE_RINGS`] (Linux 5.10).
+pub const IORING_SETUP_R_DISABLED: u32 = 1 << 6;
+/// `flags` of [`Params`]: keeps submitting after an entry fails (Linux
+/// 5.18).
+pub const IORING_SETUP_SUBMIT_ALL: u32 = 1 << 7;
+/// `flags` of [`Params`]: runs completion work when the task enters the
+/// kernel instead of interrupting it (Linux 5.19).
+pub const IORING_SETUP_COOP_TASKRUN: u32 = 1 << 8;
+/// `flags` of [`Params`]: sets `IORING_SQ_TASKRUN` when completion work is
+/// waiting (Linux 5.19).
+pub const IORING_SETUP_TASKRUN_FLAG: u32 = 1 << 9;
+/// `flags` of [`Params`]: 128-byte submission entries, which [`IoUring`]
+/// doesn't support (Linux 5.19).
+pub const IORING_SETUP_SQE128: u32 = 1 << 10;
+/// `flags` of [`Params`]: 32-byte completion entries, which [`IoUring`]
+/// doesn't support (Linux 5.19).
+pub const IORING_SETUP_CQE32: u32 = 1 << 11;
+/// `flags` of [`Params`]: only one task submits (Linux 6.0).
+pub const IORING_SETUP_SINGLE_ISSUER: u32 = 1 << 12;
+/// `flags` of [`Params`]: runs completion work only when waiting for
+/// completions (Linux 6.1).
+pub const IORING_SETUP_DEFER_TASKRUN: u32 = 1 << 13;
+
+/// `flags` of [`enter`]: waits for `min_complete` completions.
+pub const IORING_ENTER_GETEVENTS: u32 = 1 << 0;
+/// `flags` of [`enter`]: wakes the polling thread of an
+/// [`IORING_SETUP_SQPOLL`] ring.
+pub const IORING_ENTER_SQ_WAKEUP: u32 = 1 << 1;
+/// `flags` of [`enter`]: waits until the submission queue has room.
+pub const IORING_ENTER_SQ_WAIT: u32 = 1 << 2;
+/// `flags` of [`enter`]: `arg` is a `struct io_uring_getevents_arg`
+/// (Linux 5.11).
+pub const IORING_ENTER_EXT_ARG: u32 = 1 << 3;
+/// `flags` of [`enter`]: `fd` is the index of a registered ring (Linux
+/// 5.18).
+pub const IORING_ENTER_REGISTERED_RING: u32 = 1 << 4;
+
+/// `features` of [`Params`]: both rings are in one mapping (Linux 5.4).
+pub const IORING_FEAT_SINGLE_MMAP: u32 = 1 << 0;
+/// `features` of [`Params`]: completions aren't dropped when the
+/// completion queue is full (Linux 5.5).
+pub const IORING_FEAT_NODROP: u32 = 1 << 1;
+/// `features` of [`Params`]: the data of submitted entries can change
+/// (Linux 5.5).
+pub const IORING_FEAT_SUBMIT_STABLE: u32 = 1 << 2;
+/// `features` of [`Params`]: an offset of -1 is the file position (Linux
+/// 5.6).
+pub const IORING_FEAT_RW_CUR_POS: u32 = 1 << 3;
+/// `features` of [`Params`]: operations have the credentials of the
+/// submitter (Linux 5.6).
+pub const IORING_FEAT_CUR_PERSONALITY: u32 = 1 << 4;
+/// `features` of [`Params`]: polls files that aren't ready instead of
+/// using a worker (Linux 5.7).
+pub const IORING_FEAT_FAST_POLL: u32 = 1 << 5;
+/// `features` of [`Params`]: [`IORING_ENTER_EXT_ARG`] (Linux 5.11).
+pub const IORING_FEAT_EXT_ARG: u32 = 1 << 8;
+
+/// `flags` of the submission ring: the polling thread sleeps, and needs
+/// [`IORING_ENTER_SQ_WAKEUP`].
+pub const IORING_SQ_NEED_WAKEUP: u32 = 1 << 0;
+/// `flags` of the submission ring: completions overflowed.
+pub const IORING_SQ_CQ_OVERFLOW: u32 = 1 << 1;
+
+/// `flags` of an [`Sqe`]: `fd` is the index of a registered file.
+pub const IOSQE_FIXED_FILE: u8 = 1 << 0;
+/// `flags` of an [`Sqe`]: starts after all earlier entries complete.
+pub const IOSQE_IO_DRAIN: u8 = 1 << 1;
+/// `flags` of an [`Sqe`]: the next entry starts after this one succeeds.
+pub const IOSQE_IO_LINK: u8 = 1 << 2;
+/// `flags` of an [`Sqe`]: the next entry starts after this one completes.
+pub const IOSQE_IO_HARDLINK: u8 = 1 << 3;
+/// `flags` of an [`Sqe`]: always runs in a worker.
+pub const IOSQE_ASYNC: u8 = 1 << 4;
+/// `flags` of an [`Sqe`]: picks a buffer of the group `buf_index`.
+pub const IOSQE_BUFFER_SELECT: u8 = 1 << 5;
+/// `flags` of an [`Sqe`]: no completion if it succeeds (Linux 5.17).
+pub const IOSQE_CQE_SKIP_SUCCESS: u8 = 1 << 6;
+
+/// `flags` of a [`Cqe`]: the upper 16 bits are the selected buffer.
+pub const IORING_CQE_F_BUFFER: u32 = 1 << 0;
+/// `flags` of a [`Cqe`]: more completions of the entry will follow.
+pub const IORING_CQE_F_MORE: u32 = 1 << 1;
+
+/// `opcode` of an [`Sqe`]: does nothing.
+pub const IORING_OP_NOP: u8 = 0;
+/// `opcode` of an [`Sqe`]: `preadv2`.
+pub const IORING_OP_READV: u8 = 1;
+/// `opcode` of an [`Sqe`]: `pwritev2`.
+pub const IORING_OP_WRITEV: u8 = 2;
+/// `opcode` of an [`Sqe`]: `fsync`, or `fdatasync` with
+/// `IORING_FSYNC_DATASYNC`.
+pub const IORING_OP_FSYNC: u8 = 3;
+/// `opcode` of an [`Sqe`]: reads into a registered buffer.
+pub const IORING_OP_READ_FIXED: u8 = 4;
+/// `opcode` of an [`Sqe`]: writes from a registered buffer.
+pub const IORING_OP_WRITE_FIXED: u8 = 5;
+/// `opcode` of an [`Sqe`]: waits for poll events.
+pub const IORING_OP_POLL_ADD: u8 = 6;
+/// `opcode` of an [`Sqe`]: cancels an [`IORING_OP_POLL_ADD`].
+pub const IORING_OP_POLL_REMOVE: u8 = 7;
+/// `opcode` of an [`Sqe`]: `sync_file_range`.
+pub const IORING_OP_SYNC_FILE_RANGE: u8 = 8;
+/// `opcode` of an [`Sqe`]: `sendmsg` (Linux 5.3).
+pub const IORING_OP_SENDMSG: u8 = 9;
+/// `opcode` of an [`Sqe`]: `recvmsg` (Linux 5.3).
+pub const IORING_OP_RECVMSG: u8 = 10;
+/// `opcode` of an [`Sqe`]: completes after a time or a number of
+/// completions (Linux 5.4).
+pub const IORING_OP_TIMEOUT: u8 = 11;
+/// `opcode` of an [`Sqe`]: cancels an [`IORING_OP_TIMEOUT`] (Linux 5.5).
+pub const IORING_OP_TIMEOUT_REMOVE: u8 = 12;
+/// `opcode` of an [`Sqe`]: `accept4` (Linux 5.5).
+pub const IORING_OP_ACCEPT: u8 = 13;
+/// `opcode` of an [`Sqe`]: cancels the entry with `addr` as its
+/// `user_data` (Linux 5.5).
+pub const IORING_OP_ASYNC_CANCEL: u8 = 14;
+/// `opcode` of an [`Sqe`]: a timeout for the entry it is linked to (Linux
+/// 5.5).
+pub const IORING_OP_LINK_TIMEOUT: u8 = 15;
+/// `opcode` of an [`Sqe`]: `connect` (Linux 5.5).
+pub const IORING_OP_CONNECT: u8 = 16;
+/// `opcode` of an [`Sqe`]: `fallocate` (Linux 5.6).
+pub const IORING_OP_FALLOCATE: u8 = 17;
+/// `opcode` of an [`Sqe`]: `openat` (Linux 5.6).
+pub const IORING_OP_OPENAT: u8 = 18;
+/// `opcode` of an [`Sqe`]: `close` (Linux 5.6).
+pub const IORING_OP_CLOSE: u8 = 19;
+/// `opcode` of an [`Sqe`]: updates registered files (Linux 5.6).
+pub const IORING_OP_FILES_UPDATE: u8 = 20;
+/// `opcode` of an [`Sqe`]: `statx` (Linux 5.6).
+pub const IORING_OP_STATX: u8 = 21;
+/// `opcode` of an [`Sqe`]: `pread`, or `read` at offset -1 (Linux 5.6).
+pub const IORING_OP_READ: u8 = 22;
+/// `opcode` of an [`Sqe`]: `pwrite`, or `write` at offset -1 (Linux 5.6).
+pub const IORING_OP_WRITE: u8 = 23;
+/// `opcode` of an [`Sqe`]: `posix_fadvise` (Linux 5.6).
+pub const IORING_OP_FADVISE: u8 = 24;
+/// `opcode` of an [`Sqe`]: `madvise` (Linux 5.6).
+pub const IORING_OP_MADVISE: u8 = 25;
+/// `opcode` of an [`Sqe`]: `send` (Linux 5.6).
+pub const IORING_OP_SEND: u8 = 26;
+/// `opcode` of an [`Sqe`]: `recv` (Linux 5.6).
+pub const IORING_OP_RECV: u8 = 27;
+/// `opcode` of an [`Sqe`]: `openat2` (Linux 5.6).
+pub const IORING_OP_OPENAT2: u8 = 28;
+/// `opcode` of an [`Sqe`]: `epoll_ctl` (Linux 5.6).
+pub const IORING_OP_EPOLL_CTL: u8 = 29;
+/// `opcode` of an [`Sqe`]: `splice` (Linux 5.7).
+pub const IORING_OP_SPLICE: u8 = 30;
+/// `opcode` of an [`Sqe`]: `tee` (Linux 5.8).
+pub const IORING_OP_TEE: u8 = 33;
+/// `opcode` of an [`Sqe`]: `shutdown` (Linux 5.11).
+pub const IORING_OP_SHUTDOWN: u8 = 34;
+/// `opcode` of an [`Sqe`]: `renameat2` (Linux 5.11).
+pub const IORING_OP_RENAMEAT: u8 = 35;
+/// `opcode` of an [`Sqe`]: `unlinkat` (Linux 5.11).
+pub const IORING_OP_UNLINKAT: u8 = 36;
+/// `opcode` of an [`Sqe`]: `mkdirat` (Linux 5.15).
+pub const IORING_OP_MKDIRAT: u8 = 37;
+/// `opcode` of an [`Sqe`]: `symlinkat` (Linux 5.15).
+pub const IORING_OP_SYMLINKAT: u8 = 38;
+/// `opcode` of an [`Sqe`]: `linkat` (Linux 5.15).
+pub const IORING_OP_LINKAT: u8 = 39;
+/// `opcode` of an [`Sqe`]: `socket` (Linux 5.19).
+pub const IORING_OP_SOCKET: u8 = 45;
+
+/// `opcode` of [`register`]: registers buffers, from an array of
+/// `struct iovec`.
+pub const IORING_REGISTER_BUFFERS: u32 = 0;
+/// `opcode` of [`register`]: unregisters the buffers.
+pub const IORING_UNREGISTER_BUFFERS: u32 = 1;
+/// `opcode` of [`register`]: registers files, from an array of fds.
+pub const IORING_REGISTER_FILES: u32 = 2;
+/// `opcode` of [`register`]: unregisters the files.
+pub const IORING_UNREGISTER_FILES: u32 = 3;
+/// `opcode` of [`register`]: signals an eventfd on completions.
+pub const IORING_REGISTER_EVENTFD: u32 = 4;
+/// `opcode` of [`register`]: unregisters the eventfd.
+pub const IORING_UNREGISTER_EVENTFD: u32 = 5;
+/// `opcode` of [`register`]: replaces registered files (Linux 5.5).
+pub const IORING_REGISTER_FILES_UPDATE: u32 = 6;
+/// `opcode` of [`register`]: signals an eventfd on the completions of
+/// workers (Linux 5.6).
+pub const IORING_REGISTER_EVENTFD_ASYNC: u32 = 7;
+/// `opcode` of [`register`]: tells which opcodes are supported (Linux
+/// 5.6).
+pub const IORING_REGISTER_PROBE: u32 = 8;
+/// `opcode` of [`register`]: registers the current credentials (Linux
+/// 5.6).
+pub const IORING_REGISTER_PERSONALITY: u32 = 9;
+/// `opcode` of [`register`]: unregisters credentials (Linux 5.6).
+pub const IORING_UNREGISTER_PERSONALITY: u32 = 10;
+/// `opcode` of [`register`]: limits what the ring can do (Linux 5.10).
+pub const IORING_REGISTER_RESTRICTIONS: u32 = 11;
+/// `opcode` of [`register`]: enables an [`IORING_SETUP_R_DISABLED`] ring
+/// (Linux 5.10).
+pub const IORING_REGISTER_ENABLE_RINGS: u32 = 12;
+
+/// `offset` of `mmap` on a ring fd: the submission ring.
+pub const IORING_OFF_SQ_RING: u64 = 0;
+/// `offset` of `mmap` on a ring fd: the completion ring.
+pub const IORING_OFF_CQ_RING: u64 = 0x800_0000;
+/// `offset` of `mmap` on a ring fd: the array of [`Sqe`]s.
+pub const IORING_OFF_SQES: u64 = 0x1000_0000;
+
+/// Where the fields of the submission ring are in its mapping (`struct
+/// io_sqring_offsets`).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SqringOffsets {
+    pub head: u32,
+    pub tail: u32,
+    pub ring_mask: u32,
+    pub ring_entries: u32,
+    pub flags: u32,
+    pub dropped: u32,
+    /// The array of indexes into the [`Sqe`]s.
+    pub array: u32,
+    resv1: u32,
+    pub user_addr: u64,
+}
+
+/// Where the fields of the completion ring are in its mapping (`struct
+/// io_cqring_offsets`).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CqringOffsets {
+    pub head: u32,
+    pub tail: u32,
+    pub ring_mask: u32,
+    pub ring_entries: u32,
+    pub overflow: u32,
+    /// The array of [`Cqe`]s.
+    pub cqes: u32,
+    pub flags: u32,
+    resv1: u32,
+    pub user_addr: u64,
+}
+
+/// The parameters of [`setup`] (`struct io_uring_params`). The caller sets
+/// `flags` and the fields they ask for, and the kernel fills in the rest.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Params {
+    pub sq_entries: u32,
+    pub cq_entries: u32,
+    /// `IORING_SETUP_*`
+    pub flags: u32,
+    pub sq_thread_cpu: u32,
+    /// Milliseconds until the polling thread sleeps.
+    pub sq_thread_idle: u32,
+    /// `IORING_FEAT_*`, filled in by the kernel.
+    pub features: u32,
+    pub wq_fd: u32,
+    resv: [u32; 3],
+    pub sq_off: SqringOffsets,
+    pub cq_off: CqringOffsets,
+}
+
+/// A submission queue entry (`struct io_uring_sqe`), whose fields mean
+/// what the opcode says. The names are those of the first member of each
+/// union of the kernel's struct.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Sqe {
+    /// `IORING_OP_*`
+    pub opcode: u8,
+    /// `IOSQE_*`
+    pub flags: u8,
+    pub ioprio: u16,
+    pub fd: i32,
+    /// The offset, or `addr2`.
+    pub off: u64,
+    /// The buffer or path, or `splice_off_in`.
+    pub addr: u64,
+    pub len: u32,
+    /// The flags of the operation, e.g. `rw_flags` or `open_flags`.
+    pub op_flags: u32,
+    /// Comes back in the [`Cqe`].
+    pub user_data: u64,
+    /// `buf_index`, or `buf_group`.
+    pub buf_index: u16,
+    pub personality: u16,
+    /// `splice_fd_in`, or `file_index`.
+    pub file_index: u32,
+    pub addr3: u64,
+    pad: u64,
+}
+
+impl Sqe {
+    /// An entry that does nothing and completes with 0.
+    pub const fn nop() -> Self {
+        Self {
+            opcode: IORING_OP_NOP,
+            flags: 0,
+            ioprio: 0,
+            fd: -1,
+            off: 0,
+            addr: 0,
+            len: 0,
+            op_flags: 0,
+            user_data: 0,
+            buf_index: 0,
+            personality: 0,
+            file_index: 0,
+            addr3: 0,
+            pad: 0,
+        }
+    }
+
+    /// An entry of `opcode` on `fd`, with the other fields zero.
+    pub const fn new(opcode: u8, fd: i32) -> Self {
+        Self {
+            opcode,
+            fd,
+            ..Self::nop()
+        }
+    }
+
+    /// Sets `user_data`, which comes back in the [`Cqe`].
+    #[must_use]
+    pub const fn user_data(mut self, user_data: u64) -> Self {
+        self.user_data = user_data;
+        self
+    }
+
+    /// Adds the `IOSQE_*` `flags`.
+    #[must_use]
+    pub const fn flags(mut self, flags: u8) -> Self {
+        self.flags |= flags;
+        self
+    }
+}
+
+/// A completion queue entry (`struct io_uring_cqe`).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Cqe {
+    /// The `user_data` of the [`Sqe`].
+    pub user_data: u64,
+    /// What the syscall would return, or a negative errno.
+    pub res: i32,
+    /// `IORING_CQE_F_*`
+    pub flags: u32,
+}
+
+impl Cqe {
+    /// `res` as a `Result`, with the errno of a negative one.
+    pub fn result(&self) -> Result<u32, Errno> {
+        if self.res < 0 {
+            Err(Errno::new(-self.res))
+        } else {
+            Ok(self.res as u32)
+        }
+    }
+}
+
+/// Makes a ring with at least `entries` submission entries, and returns
+/// its fd, which has `O_CLOEXEC`. The kernel fills in the rest of `params`,
+/// including where the fields of the rings are once they are mapped.
+///
+/// [`IoUring::with_params`] also maps the rings.
+pub fn setup(entries: u32, params: &mut Params) -> Result<i32, Errno> {
+    let fd = unsafe {
+        crate::syscall2(
+            Sysno::io_uring_setup,
+            SyscallWord::from(entries),
+            core::ptr::from_mut(params) as SyscallWord,
+        )
+    }?;
+    Ok(fd as i32)
+}
+
+/// Submits up to `to_submit` entries of the ring `fd`, and with
+/// [`IORING_ENTER_GETEVENTS`] waits until there are `min_complete`
+/// completions. Returns how many entries were submitted.
+///
+/// # Safety
+///
+/// The submitted entries must point to memory that is valid for what they
+/// do until they complete.
+pub unsafe fn enter(
+    fd: i32,
+    to_submit: u32,
+    min_complete: u32,
+    flags: u32,
+) -> Result<u32, Errno> {
+    let submitted = unsafe {
+        crate::syscall6(
+            Sysno::io_uring_enter,
+            fd as SyscallWord,
+            SyscallWord::from(to_submit),
+            SyscallWord::from(min_complete),
+            SyscallWord::from(flags),
+            0,
+            0,
+        )
+    }?;
+    Ok(submitted as u32)
+}
+
+/// Registers or unregisters resources of the ring `fd`, as `opcode` says,
+/// with `nr_args` of them at `arg`. Returns what the opcode returns, which
+/// is mostly 0.
+///
+/// # Safety
+///
+/// `arg` must point to what the opcode takes, and registered buffers must
+/// stay valid until they are unregistered.
+pub unsafe fn register(
+    fd: i32,
+    opcode: u32,
+    arg: *const c_void,
+    nr_args: u32,
+) -> Result<u32, Errno> {
+    let ret = unsafe {
+        crate::syscall4(
+            Sysno::io_uring_register,
+            fd as SyscallWord,
+            SyscallWord::from(opcode),
+            arg as SyscallWord,
+            SyscallWord::from(nr_args),
+        )
+    }?;
+    Ok(ret as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layout() {
+        assert_eq!(core::mem::size_of::<SqringOffsets>(), 40);
+        assert_eq!(core::mem::size_of::<CqringOffsets>(), 40);
+        assert_eq!(core::mem::size_of::<Params>(), 120);
+        assert_eq!(core::mem::offset_of!(Params, sq_off), 40);
+        assert_eq!(core::mem::size_of::<Sqe>(), 64);
+        assert_eq!(core::mem::offset_of!(Sqe, user_data), 32);
+        assert_eq!(core::mem::offset_of!(Sqe, addr3), 48);
+        assert_eq!(core::mem::size_of::<Cqe>(), 16);
+    }
+
+    #[test]
+    fn cqe_result() {
+        let cqe = Cqe {
+            res: -(Errno::EBADF.into_raw()),
+            ..Cqe::default()
+        };
+        assert_eq!(cqe.result(), Err(Errno::EBADF));
+        let cqe = Cqe {
+            res: 3,
+            ..Cqe::default()
+        };
+        assert_eq!(cqe.result(), Ok(3));
+    }
+}
@@ -0,0 +1,374 @@
+//! A ring with its queues mapped.
+
+use core::sync::atomic::{AtomicU32, Ordering};
+
+use super::{
+    Cqe, IORING_ENTER_GETEVENTS, IORING_ENTER_SQ_WAKEUP,
+    IORING_FEAT_SINGLE_MMAP, IORING_OFF_CQ_RING, IORING_OFF_SQ_RING,
+    IORING_OFF_SQES, IORING_SETUP_CQE32, IORING_SETUP_SQE128,
+    IORING_SETUP_SQPOLL, IORING_SQ_NEED_WAKEUP, Params, Sqe, enter, setup,
+};
+use crate::Errno;
+use crate::helpers::{MapFlags, MappedRegion, ProtFlags, close};
+
+/// An `io_uring` whose fd is closed and whose queues are unmapped when
+/// dropped.
+///
+/// Entries are copied into the submission queue with
+/// [`push`](Self::push), handed to the kernel with
+/// [`submit`](Self::submit) or [`submit_and_wait`](Self::submit_and_wait),
+/// and their completions are taken with
+/// [`completions`](Self::completions):
+///
+/// ```no_run
+/// use rawsys_linux::io_uring::{IORING_OP_READ, IoUring, Sqe};
+///
+/// let mut ring = IoUring::new(8)?;
+/// let mut buf = [0u8; 64];
+/// let mut read = Sqe::new(IORING_OP_READ, 0).user_data(1);
+/// read.addr = buf.as_mut_ptr() as u64;
+/// read.len = buf.len() as u32;
+/// // `buf` outlives the read, since it is waited for right away.
+/// unsafe { ring.push(&read) }?;
+/// ring.submit_and_wait(1)?;
+/// for cqe in ring.completions() {
+///     println!("read {:?} bytes", cqe.result());
+/// }
+/// # Ok::<(), rawsys_linux::Errno>(())
+/// ```
+#[derive(Debug)]
+pub struct IoUring {
+    fd: i32,
+    params: Params,
+    sq_ring: MappedRegion,
+    /// `None` with [`IORING_FEAT_SINGLE_MMAP`], where it is in `sq_ring`.
+    cq_ring: Option<MappedRegion>,
+    sqes: MappedRegion,
+    /// The tail of the entries pushed but not yet submitted.
+    sq_tail: u32,
+}
+
+// It owns the rings, and the kernel only reads the entries it is given.
+unsafe impl Send for IoUring {}
+
+impl IoUring {
+    /// Makes a ring with at least `entries` submission entries, a
+    /// power of two of at most 32768, and twice as many completion
+    /// entries.
+    pub fn new(entries: u32) -> Result<Self, Errno> {
+        Self::with_params(entries, Params::default())
+    }
+
+    /// Makes a ring with the `flags` and other fields of `params` set by
+    /// the caller. Fails with `EINVAL` for [`IORING_SETUP_SQE128`] and
+    /// [`IORING_SETUP_CQE32`], whose entries are larger than [`Sqe`] and
+    /// [`Cqe`].
+    pub fn with_params(
+        entries: u32,
+        mut params: Params,
+    ) -> Result<Self, Errno> {
+        if params.flags & (IORING_SETUP_SQE128 | IORING_SETUP_CQE32) != 0 {
+            return Err(Errno::EINVAL);
+        }
+        let fd = setup(entries, &mut params)?;
+        match unsafe { Self::map(fd, params) } {
+            Ok(ring) => Ok(ring),
+            Err(errno) => {
+                let _ = unsafe { close(fd) };
+                Err(errno)
+            }
+        }
+    }
+
+    /// Maps the queues of the ring `fd`, which has `params`.
+    unsafe fn map(fd: i32, params: Params) -> Result<Self, Errno> {
+        let sq_len = params.sq_off.array as usize
+            + params.sq_entries as usize * size_of::<u32>();
+        let cq_len = params.cq_off.cqes as usize
+            + params.cq_entries as usize * size_of::<Cqe>();
+        let single = params.features & IORING_FEAT_SINGLE_MMAP != 0;
+        let prot = ProtFlags::READ | ProtFlags::WRITE;
+        let flags = MapFlags::SHARED | MapFlags::POPULATE;
+        let sq_ring = unsafe {
+            let len = if single { sq_len.max(cq_len) } else { sq_len };
+            MappedRegion::file(fd, IORING_OFF_SQ_RING, len, prot, flags)
+        }?;
+        let cq_ring = if single {
+            None
+        } else {
+            Some(unsafe {
+                MappedRegion::file(fd, IORING_OFF_CQ_RING, cq_len, prot, flags)
+            }?)
+        };
+        let sqes = unsafe {
+            let len = params.sq_entries as usize * size_of::<Sqe>();
+            MappedRegion::file(fd, IORING_OFF_SQES, len, prot, flags)
+        }?;
+        let mut ring = Self {
+            fd,
+            params,
+            sq_ring,
+            cq_ring,
+            sqes,
+            sq_tail: 0,
+        };
+        ring.sq_tail = ring.sq_word(params.sq_off.tail).load(Ordering::Relaxed);
+        // Entry `i` of the array always points to `Sqe` `i`, so the
+        // entries are filled in place.
+        let array = at::<u32>(&ring.sq_ring, params.sq_off.array);
+        for i in 0..params.sq_entries {
+            unsafe { array.add(i as usize).write(i) };
+        }
+        Ok(ring)
+    }
+
+    /// The fd of the ring.
+    pub fn as_raw(&self) -> i32 {
+        self.fd
+    }
+
+    /// The parameters of the ring, as the kernel filled them in.
+    pub fn params(&self) -> &Params {
+        &self.params
+    }
+
+    /// The word at `offset` in the submission ring.
+    fn sq_word(&self, offset: u32) -> &AtomicU32 {
+        unsafe { &*at(&self.sq_ring, offset) }
+    }
+
+    /// The word at `offset` in the completion ring.
+    fn cq_word(&self, offset: u32) -> &AtomicU32 {
+        let ring = self.cq_ring.as_ref().unwrap_or(&self.sq_ring);
+        unsafe { &*at(ring, offset) }
+    }
+
+    /// How many more entries can be pushed before the submission queue is
+    /// full.
+    pub fn sq_space_left(&self) -> u32 {
+        self.params.sq_entries - self.sq_pending()
+    }
+
+    /// How many entries were pushed but not yet taken by the kernel, which
+    /// stops taking them at one that fails.
+    pub fn sq_pending(&self) -> u32 {
+        let head = self.sq_word(self.params.sq_off.head);
+        self.sq_tail.wrapping_sub(head.load(Ordering::Acquire))
+    }
+
+    /// Copies `sqe` into the submission queue, to be submitted by the next
+    /// [`submit`](Self::submit). Fails with `EBUSY` if the queue is full.
+    ///
+    /// # Safety
+    ///
+    /// What `sqe` points to, such as the buffer of a read, must be valid
+    /// for what the opcode does until it completes, even if the ring is
+    /// dropped before then.
+    pub unsafe fn push(&mut self, sqe: &Sqe) -> Result<(), Errno> {
+        if self.sq_space_left() == 0 {
+            return Err(Errno::EBUSY);
+        }
+        let mask = self.sq_word(self.params.sq_off.ring_mask);
+        let index = self.sq_tail & mask.load(Ordering::Relaxed);
+        unsafe { at::<Sqe>(&self.sqes, 0).add(index as usize).write(*sqe) };
+        self.sq_tail = self.sq_tail.wrapping_add(1);
+        Ok(())
+    }
+
+    /// Drops the entries that were pushed but not yet submitted.
+    pub(super) fn discard_pending(&mut self) {
+        let tail = self.sq_word(self.params.sq_off.tail);
+        self.sq_tail = tail.load(Ordering::Relaxed);
+    }
+
+    /// Drops the submitted entries that the kernel hasn't taken, which it
+    /// only takes in [`enter`] without [`IORING_SETUP_SQPOLL`], and returns
+    /// how many there were.
+    pub(super) fn retract(&mut self) -> u32 {
+        let head = self
+            .sq_word(self.params.sq_off.head)
+            .load(Ordering::Acquire);
+        let dropped = self.sq_tail.wrapping_sub(head);
+        self.sq_tail = head;
+        self.sq_word(self.params.sq_off.tail)
+            .store(head, Ordering::Release);
+        dropped
+    }
+
+    /// Hands the pushed entries to the kernel, and returns how many it
+    /// took.
+    pub fn submit(&mut self) -> Result<u32, Errno> {
+        self.submit_and_wait(0)
+    }
+
+    /// Hands the pushed entries to the kernel, waits until there are at
+    /// least `want` completions, and returns how many entries it took.
+    pub fn submit_and_wait(&mut self, want: u32) -> Result<u32, Errno> {
+        let to_submit = self.sq_pending();
+        // Publishes the entries before the kernel can see the new tail.
+        self.sq_word(self.params.sq_off.tail)
+            .store(self.sq_tail, Ordering::Release);
+        let mut flags = 0;
+        if self.params.flags & IORING_SETUP_SQPOLL != 0 {
+            let sq_flags = self.sq_word(self.params.sq_off.flags);
+            if sq_flags.load(Ordering::Acquire) & IORING_SQ_NEED_WAKEUP != 0 {
+                flags |= IORING_ENTER_SQ_WAKEUP;
+            } else if want == 0 {
+                // The polling thread takes them.
+                return Ok(to_submit);
+            }
+        }
+        if want > 0 {
+            flags |= IORING_ENTER_GETEVENTS;
+        }
+        // The entries were pushed with `push`, whose caller vouched for
+        // them.
+        unsafe { enter(self.fd, to_submit, want, flags) }
+    }
+
+    /// How many completions are ready to be taken.
+    pub fn cq_ready(&self) -> u32 {
+        let head = self.cq_word(self.params.cq_off.head);
+        let tail = self.cq_word(self.params.cq_off.tail);
+        tail.load(Ordering::Acquire)
+            .wrapping_sub(head.load(Ordering::Relaxed))
+    }
+
+    /// Takes the completions that are ready, in the order they completed.
+    /// Each one makes room for another in the completion queue.
+    pub fn completions(&mut self) -> Completions<'_> {
+        Completions { ring: self }
+    }
+
+    /// Takes the next completion, if there is one.
+    pub(super) fn pop_completion(&mut self) -> Option<Cqe> {
+        let off = self.params.cq_off;
+        let head = self.cq_word(off.head).load(Ordering::Relaxed);
+        if self.cq_word(off.tail).load(Ordering::Acquire) == head {
+            return None;
+        }
+        let mask = self.cq_word(off.ring_mask).load(Ordering::Relaxed);
+        let ring = self.cq_ring.as_ref().unwrap_or(&self.sq_ring);
+        let cqes = at::<Cqe>(ring, off.cqes);
+        let cqe = unsafe { cqes.add((head & mask) as usize).read() };
+        // Hands the entry back to the kernel once it has been read.
+        self.cq_word(off.head)
+            .store(head.wrapping_add(1), Ordering::Release);
+        Some(cqe)
+    }
+}
+
+/// The `T` at `offset` in `region`, which the kernel aligns for it in the
+/// page-aligned mappings.
+#[allow(clippy::cast_ptr_alignment)]
+fn at<T>(region: &MappedRegion, offset: u32) -> *mut T {
+    region.as_ptr().wrapping_add(offset as usize).cast::<T>()
+}
+
+impl Drop for IoUring {
+    fn drop(&mut self) {
+        let _ = unsafe { close(self.fd) };
+    }
+}
+
+/// The completions that are ready in an [`IoUring`], from
+/// [`IoUring::completions`].
+#[derive(Debug)]
+pub struct Completions<'a> {
+    ring: &'a mut IoUring,
+}
+
+impl Iterator for Completions<'_> {
+    type Item = Cqe;
+
+    fn next(&mut self) -> Option<Cqe> {
+        self.ring.pop_completion()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::{pipe2, write};
+    use crate::io_uring::{IORING_OP_READ, IORING_OP_WRITE};
+
+    /// A ring, or `None` where `io_uring` is unavailable or disabled.
+    fn ring(entries: u32) -> Option<IoUring> {
+        match IoUring::new(entries) {
+            Ok(ring) => Some(ring),
+            Err(Errno::ENOSYS | Errno::EPERM) => None,
+            Err(errno) => panic!("io_uring_setup: {errno}"),
+        }
+    }
+
+    #[test]
+    fn nop() {
+        let Some(mut ring) = ring(4) else { return };
+        assert_eq!(ring.sq_space_left(), 4);
+        for i in 0..4 {
+            unsafe { ring.push(&Sqe::nop().user_data(i)) }.unwrap();
+        }
+        assert_eq!(unsafe { ring.push(&Sqe::nop()) }, Err(Errno::EBUSY));
+        assert_eq!(ring.sq_pending(), 4);
+        assert_eq!(ring.submit_and_wait(4), Ok(4));
+        assert_eq!(ring.cq_ready(), 4);
+        let mut cqes = 0;
+        for (i, cqe) in ring.completions().enumerate() {
+            assert_eq!((cqe.user_data, cqe.result()), (i as u64, Ok(0)));
+            cqes += 1;
+        }
+        assert_eq!(cqes, 4);
+        assert_eq!(ring.cq_ready(), 0);
+        assert_eq!(ring.sq_space_left(), 4);
+    }
+
+    #[test]
+    fn pipe() {
+        let Some(mut ring) = ring(8) else { return };
+        let [rd, wr] = pipe2(0).unwrap();
+        let mut msg = *b"hello";
+        let mut buf = [0u8; 16];
+        let mut sqe = Sqe::new(IORING_OP_WRITE, wr).user_data(1);
+        sqe.addr = msg.as_mut_ptr() as u64;
+        sqe.len = msg.len() as u32;
+        unsafe { ring.push(&sqe) }.unwrap();
+        ring.submit_and_wait(1).unwrap();
+        assert_eq!(ring.completions().next().unwrap().result(), Ok(5));
+
+        let mut sqe = Sqe::new(IORING_OP_READ, rd).user_data(2);
+        sqe.addr = buf.as_mut_ptr() as u64;
+        sqe.len = buf.len() as u32;
+        // Reads from the current position of the pipe.
+        sqe.off = u64::MAX;
+        unsafe { ring.push(&sqe) }.unwrap();
+        ring.submit_and_wait(1).unwrap();
+        let cqe = ring.completions().next().unwrap();
+        assert_eq!((cqe.user_data, cqe.result()), (2, Ok(5)));
+        assert_eq!(&buf[..5], b"hello");
+
+        // A read that waits for data, which arrives after it is submitted.
+        unsafe { ring.push(&sqe.user_data(3)) }.unwrap();
+        ring.submit().unwrap();
+        assert_eq!(write(wr, b"later"), Ok(5));
+        ring.submit_and_wait(1).unwrap();
+        let cqe = ring.completions().next().unwrap();
+        assert_eq!((cqe.user_data, cqe.result()), (3, Ok(5)));
+        assert_eq!(&buf[..5], b"later");
+        unsafe {
+            close(rd).unwrap();
+            close(wr).unwrap();
+        }
+    }
+
+    #[test]
+    fn unsupported() {
+        let params = Params {
+            flags: IORING_SETUP_CQE32,
+            ..Params::default()
+        };
+        assert_eq!(
+            IoUring::with_params(4, params).map(drop),
+            Err(Errno::EINVAL)
+        );
+    }
+}
@@ -0,0 +1,270 @@
+//! ioctl request numbers and the `ioctl` syscall
+//!
+//! Request numbers encode the direction of the transfer, a type (usually a
+//! character for the driver), a number, and the size of the argument, as the
+//! kernel's `_IO`, `_IOR`, `_IOW`, and `_IOWR` macros do. The widths of the
+//! fields and the direction bits differ on mips, powerpc, and sparc, which
+//! [`io`], [`ior`], [`iow`], and [`iowr`] account for:
+//!
+//! ```
+//! use rawsys_linux::ioctl;
+//!
+//! // `SECCOMP_IOCTL_NOTIF_ID_VALID` of `linux/seccomp.h`.
+//! const ID_VALID: u32 = ioctl::iow::<u64>(b'!', 2);
+//! assert_eq!(ioctl::ioc_size(ID_VALID), 8);
+//! assert_eq!(ioctl::ioc_dir(ID_VALID), ioctl::IOC_WRITE);
+//! ```
+//!
+//! Older requests, such as `TCGETS`, predate the encoding and are plain
+//! numbers, which also differ between architectures.
+
+#[cfg(not(rawsys_backend = "none"))]
+use crate::{Errno, SyscallWord, Sysno};
+
+/// The `_IOC` encoding on mips, powerpc, and sparc.
+#[cfg(any(
+    target_arch = "mips",
+    target_arch = "mips64",
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+    target_arch = "sparc",
+    target_arch = "sparc64"
+))]
+mod arch {
+    pub const NONE: u32 = 1;
+    pub const READ: u32 = 2;
+    pub const WRITE: u32 = 4;
+    pub const SIZEBITS: u32 = 13;
+    pub const DIRBITS: u32 = 3;
+}
+/// The generic `_IOC` encoding.
+#[cfg(not(any(
+    target_arch = "mips",
+    target_arch = "mips64",
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+    target_arch = "sparc",
+    target_arch = "sparc64"
+)))]
+mod arch {
+    pub const NONE: u32 = 0;
+    pub const READ: u32 = 2;
+    pub const WRITE: u32 = 1;
+    pub const SIZEBITS: u32 = 14;
+    pub const DIRBITS: u32 = 2;
+}
+
+/// The direction of a request without an argument to transfer.
+pub const IOC_NONE: u32 = arch::NONE;
+/// The direction of a request whose argument the kernel writes for the
+/// caller to read.
+pub const IOC_READ: u32 = arch::READ;
+/// The direction of a request whose argument the caller writes for the
+/// kernel to read.
+pub const IOC_WRITE: u32 = arch::WRITE;
+
+/// The bits of the number.
+pub const IOC_NRBITS: u32 = 8;
+/// The bits of the type.
+pub const IOC_TYPEBITS: u32 = 8;
+/// The bits of the size, 13 on mips, powerpc, and sparc, and 14 elsewhere.
+pub const IOC_SIZEBITS: u32 = arch::SIZEBITS;
+/// The bits of the direction, 3 on mips, powerpc, and sparc, and 2
+/// elsewhere.
+pub const IOC_DIRBITS: u32 = arch::DIRBITS;
+
+const NRSHIFT: u32 = 0;
+const TYPESHIFT: u32 = NRSHIFT + IOC_NRBITS;
+const SIZESHIFT: u32 = TYPESHIFT + IOC_TYPEBITS;
+const DIRSHIFT: u32 = SIZESHIFT + IOC_SIZEBITS;
+
+/// The request of the direction `dir` (`IOC_*`), the type `ty`, the number
+/// `nr`, and an argument of `size` bytes (`_IOC`).
+///
+/// # Panics
+///
+/// If `size` doesn't fit in [`IOC_SIZEBITS`], which fails to compile in a
+/// `const`.
+pub const fn ioc(dir: u32, ty: u8, nr: u8, size: usize) -> u32 {
+    assert!(
+        size < 1 << IOC_SIZEBITS,
+        "the size of an ioctl is too large"
+    );
+    (dir << DIRSHIFT)
+        | ((size as u32) << SIZESHIFT)
+        | ((ty as u32) << TYPESHIFT)
+        | ((nr as u32) << NRSHIFT)
+}
+
+/// A request without an argument, or with an integer argument (`_IO`).
+pub const fn io(ty: u8, nr: u8) -> u32 {
+    ioc(IOC_NONE, ty, nr, 0)
+}
+
+/// A request that writes a `T` for the caller (`_IOR`).
+pub const fn ior<T>(ty: u8, nr: u8) -> u32 {
+    ioc(IOC_READ, ty, nr, size_of::<T>())
+}
+
+/// A request that reads a `T` of the caller (`_IOW`).
+pub const fn iow<T>(ty: u8, nr: u8) -> u32 {
+    ioc(IOC_WRITE, ty, nr, size_of::<T>())
+}
+
+/// A request that reads a `T` of the caller and writes it back (`_IOWR`).
+pub const fn iowr<T>(ty: u8, nr: u8) -> u32 {
+    ioc(IOC_READ | IOC_WRITE, ty, nr, size_of::<T>())
+}
+
+/// The direction of `request` (`_IOC_DIR`).
+pub const fn ioc_dir(request: u32) -> u32 {
+    (request >> DIRSHIFT) & ((1 << IOC_DIRBITS) - 1)
+}
+
+/// The type of `request` (`_IOC_TYPE`).
+pub const fn ioc_type(request: u32) -> u8 {
+    (request >> TYPESHIFT) as u8
+}
+
+/// The number of `request` (`_IOC_NR`).
+pub const fn ioc_nr(request: u32) -> u8 {
+    (request >> NRSHIFT) as u8
+}
+
+/// The size of the argument of `request` (`_IOC_SIZE`).
+pub const fn ioc_size(request: u32) -> usize {
+    ((request >> SIZESHIFT) & ((1 << IOC_SIZEBITS) - 1)) as usize
+}
+
+/// Makes the request `request` of the fd `fd` with the argument `arg`, and
+/// returns what the driver returns.
+///
+/// # Safety
+///
+/// Requests can do anything that their drivers do, such as reading and
+/// writing memory at `arg` or closing fds.
+#[cfg(not(rawsys_backend = "none"))]
+pub unsafe fn ioctl(
+    fd: i32,
+    request: u32,
+    arg: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    unsafe {
+        crate::syscall3(
+            Sysno::ioctl,
+            fd as SyscallWord,
+            SyscallWord::from(request),
+            arg,
+        )
+    }
+}
+
+/// Makes a request without an argument, such as one of [`io`].
+///
+/// # Safety
+///
+/// As for [`ioctl`].
+#[cfg(not(rawsys_backend = "none"))]
+pub unsafe fn ioctl_none(fd: i32, request: u32) -> Result<SyscallWord, Errno> {
+    unsafe { ioctl(fd, request, 0) }
+}
+
+/// Makes a request that writes a `T`, such as one of [`ior`], and returns
+/// it.
+///
+/// # Safety
+///
+/// As for [`ioctl`], and the request must write a valid `T`, or leave it
+/// zeroed where it doesn't.
+#[cfg(not(rawsys_backend = "none"))]
+pub unsafe fn ioctl_read<T>(fd: i32, request: u32) -> Result<T, Errno> {
+    let mut value = core::mem::MaybeUninit::<T>::zeroed();
+    unsafe {
+        ioctl(fd, request, value.as_mut_ptr() as SyscallWord)?;
+        Ok(value.assume_init())
+    }
+}
+
+/// Makes a request that reads `value`, such as one of [`iow`].
+///
+/// # Safety
+///
+/// As for [`ioctl`], and the request must read no more than a `T`.
+#[cfg(not(rawsys_backend = "none"))]
+pub unsafe fn ioctl_write<T>(
+    fd: i32,
+    request: u32,
+    value: &T,
+) -> Result<SyscallWord, Errno> {
+    unsafe { ioctl(fd, request, core::ptr::from_ref(value) as SyscallWord) }
+}
+
+/// Makes a request that reads and writes `value`, such as one of [`iowr`].
+///
+/// # Safety
+///
+/// As for [`ioctl`], and the request must read no more than a `T` and
+/// write a valid one.
+#[cfg(not(rawsys_backend = "none"))]
+pub unsafe fn ioctl_readwrite<T>(
+    fd: i32,
+    request: u32,
+    value: &mut T,
+) -> Result<SyscallWord, Errno> {
+    unsafe { ioctl(fd, request, core::ptr::from_mut(value) as SyscallWord) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers() {
+        let request = iowr::<[u8; 80]>(b'!', 7);
+        assert_eq!(ioc_dir(request), IOC_READ | IOC_WRITE);
+        assert_eq!((ioc_type(request), ioc_nr(request)), (b'!', 7));
+        assert_eq!(ioc_size(request), 80);
+        assert_eq!(ioc_dir(io(b'T', 1)), IOC_NONE);
+        if cfg!(any(target_arch = "x86_64", target_arch = "aarch64")) {
+            // `SECCOMP_IOCTL_NOTIF_RECV` and `FS_IOC_GETFLAGS`.
+            assert_eq!(request & !0xff, 0xc050_2100);
+            assert_eq!(ior::<u64>(b'f', 1), 0x8008_6601);
+        }
+    }
+
+    #[cfg(all(
+        not(rawsys_backend = "none"),
+        any(target_arch = "x86_64", target_arch = "aarch64")
+    ))]
+    #[test]
+    fn pipe() {
+        // Not encoded.
+        const FIONREAD: u32 = 0x541b;
+
+        let mut fds = [0i32; 2];
+        let flags = 0o2_000_000; // O_CLOEXEC
+        unsafe {
+            crate::syscall2(
+                Sysno::pipe2,
+                fds.as_mut_ptr() as SyscallWord,
+                flags,
+            )
+            .unwrap();
+            crate::syscall3(
+                Sysno::write,
+                fds[1] as SyscallWord,
+                b"abc".as_ptr() as SyscallWord,
+                3,
+            )
+            .unwrap();
+            assert_eq!(ioctl_read::<i32>(fds[0], FIONREAD), Ok(3));
+            let mut pending = 0i32;
+            ioctl_readwrite(fds[0], FIONREAD, &mut pending).unwrap();
+            assert_eq!(pending, 3);
+            assert_eq!(ioctl_none(fds[0], io(b'!', 0)), Err(Errno::ENOTTY));
+            for fd in fds {
+                crate::syscall1(Sysno::close, fd as SyscallWord).unwrap();
+            }
+        }
+    }
+}
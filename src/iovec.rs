@@ -0,0 +1,188 @@
+//! Buffers of vectored I/O
+//!
+//! [`IoVec`] and [`IoVecMut`] borrow a slice each, in the layout of the
+//! kernel's `struct iovec`, so that a slice of them is an array that
+//! `readv`, `writev`, `sendmsg`, and the like take as is. [`RawIoVec`] is
+//! the layout without the borrow, for memory that isn't the caller's, such
+//! as that of another process.
+//!
+//! ```
+//! use rawsys_linux::iovec::{IoVec, IoVecMut};
+//!
+//! let bufs = [IoVec::new(b"head"), IoVec::new(b"body")];
+//! assert_eq!(bufs.iter().map(|buf| buf.len()).sum::<usize>(), 8);
+//!
+//! let mut buf = [0u8; 4];
+//! let mut iov = IoVecMut::new(&mut buf);
+//! iov.copy_from_slice(b"data");
+//! assert_eq!(&buf, b"data");
+//! ```
+
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
+
+/// `struct iovec`: the address and the length of a buffer.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RawIoVec {
+    pub base: usize,
+    pub len: usize,
+}
+
+/// A buffer to write from, as a `struct iovec`.
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct IoVec<'a> {
+    raw: RawIoVec,
+    buf: PhantomData<&'a [u8]>,
+}
+
+impl<'a> IoVec<'a> {
+    pub fn new(buf: &'a [u8]) -> Self {
+        Self {
+            raw: RawIoVec {
+                base: buf.as_ptr() as usize,
+                len: buf.len(),
+            },
+            buf: PhantomData,
+        }
+    }
+
+    /// The buffer, for as long as it is borrowed.
+    pub fn as_slice(&self) -> &'a [u8] {
+        unsafe {
+            core::slice::from_raw_parts(
+                self.raw.base as *const u8,
+                self.raw.len,
+            )
+        }
+    }
+
+    pub const fn as_raw(&self) -> RawIoVec {
+        self.raw
+    }
+
+    /// The buffers as the array of `struct iovec` that the kernel reads.
+    pub const fn as_raw_slice(bufs: &[Self]) -> &[RawIoVec] {
+        unsafe { core::slice::from_raw_parts(bufs.as_ptr().cast(), bufs.len()) }
+    }
+}
+
+impl Default for IoVec<'_> {
+    fn default() -> Self {
+        Self::new(&[])
+    }
+}
+
+impl Deref for IoVec<'_> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl<'a> From<&'a [u8]> for IoVec<'a> {
+    fn from(buf: &'a [u8]) -> Self {
+        Self::new(buf)
+    }
+}
+
+impl fmt::Debug for IoVec<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IoVec").field(&self.as_slice()).finish()
+    }
+}
+
+/// A buffer to read into, as a `struct iovec`.
+#[repr(transparent)]
+pub struct IoVecMut<'a> {
+    raw: RawIoVec,
+    buf: PhantomData<&'a mut [u8]>,
+}
+
+impl<'a> IoVecMut<'a> {
+    pub fn new(buf: &'a mut [u8]) -> Self {
+        Self {
+            raw: RawIoVec {
+                base: buf.as_mut_ptr() as usize,
+                len: buf.len(),
+            },
+            buf: PhantomData,
+        }
+    }
+
+    pub const fn as_raw(&self) -> RawIoVec {
+        self.raw
+    }
+
+    /// The buffers as the array of `struct iovec` that the kernel writes
+    /// through, which borrows them mutably as long as it is used.
+    pub fn as_raw_slice(bufs: &mut [Self]) -> &[RawIoVec] {
+        unsafe { core::slice::from_raw_parts(bufs.as_ptr().cast(), bufs.len()) }
+    }
+}
+
+impl Default for IoVecMut<'_> {
+    fn default() -> Self {
+        Self::new(&mut [])
+    }
+}
+
+impl Deref for IoVecMut<'_> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        unsafe {
+            core::slice::from_raw_parts(
+                self.raw.base as *const u8,
+                self.raw.len,
+            )
+        }
+    }
+}
+
+impl DerefMut for IoVecMut<'_> {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        unsafe {
+            core::slice::from_raw_parts_mut(
+                self.raw.base as *mut u8,
+                self.raw.len,
+            )
+        }
+    }
+}
+
+impl<'a> From<&'a mut [u8]> for IoVecMut<'a> {
+    fn from(buf: &'a mut [u8]) -> Self {
+        Self::new(buf)
+    }
+}
+
+impl fmt::Debug for IoVecMut<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IoVecMut").field(&&**self).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layout() {
+        let buf = *b"abc";
+        let bufs = [IoVec::new(&buf), IoVec::default()];
+        let raw = IoVec::as_raw_slice(&bufs);
+        assert_eq!(raw[0].base, buf.as_ptr() as usize);
+        assert_eq!((raw[0].len, raw[1].len), (3, 0));
+        assert_eq!(&*bufs[0], b"abc");
+
+        let mut buf = [0u8; 2];
+        let mut bufs = [IoVecMut::new(&mut buf[..1])];
+        assert_eq!(IoVecMut::as_raw_slice(&mut bufs)[0].len, 1);
+        bufs[0].fill(1);
+        assert_eq!(buf, [1, 0]);
+    }
+}
@@ -11,16 +11,32 @@
     clippy::ptr_as_ptr,
     clippy::unsafe_derive_deserialize
 )]
+// The unit tests predate these lints.
+#![cfg_attr(
+    test,
+    allow(
+        clippy::io_other_error,
+        clippy::manual_c_str_literals,
+        clippy::ptr_cast_constness,
+        clippy::uninlined_format_args
+    )
+)]
 #![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(
-    // These architectures require nightly to use inline assembly.
+    // These architectures require nightly to use inline assembly, unless the
+    // `outline-asm` backend is used.
     // See https://github.com/rust-lang/rust/issues/93335
-    any(
-        target_arch = "mips",
-        target_arch = "mips64",
-        target_arch = "s390x",
-        target_arch = "powerpc",
-        target_arch = "powerpc64",
+    all(
+        any(
+            target_arch = "mips",
+            target_arch = "mips64",
+            target_arch = "s390x",
+            target_arch = "powerpc",
+            target_arch = "powerpc64",
+            target_arch = "sparc",
+            target_arch = "sparc64",
+        ),
+        rawsys_backend = "inline"
     ),
     feature(asm_experimental_arch)
 )]
@@ -35,6 +51,64 @@ mod map;
 mod set;
 mod syscall;
 
+pub mod audit;
+#[cfg(not(rawsys_backend = "none"))]
+pub mod auxv;
+#[cfg(feature = "consts")]
+pub mod consts;
+pub mod event;
+pub mod format;
+#[cfg(all(feature = "helpers", not(rawsys_backend = "none")))]
+pub mod helpers;
+pub mod id;
+#[cfg(all(feature = "tracing", not(rawsys_backend = "none")))]
+mod instrument;
+#[cfg(all(feature = "io_uring", not(rawsys_backend = "none")))]
+pub mod io_uring;
+pub mod ioctl;
+pub mod iovec;
+pub mod procfs;
+#[cfg(all(feature = "profile", not(rawsys_backend = "none")))]
+pub mod profile;
+pub mod ptrace;
+pub mod regs;
+pub mod remote;
+#[cfg(not(rawsys_backend = "none"))]
+pub mod rseq;
+pub mod seccomp;
+pub mod siginfo;
+#[cfg(not(rawsys_backend = "none"))]
+pub mod sud;
+// Only these architectures have `clone` trampolines.
+#[cfg(all(
+    not(rawsys_backend = "none"),
+    any(
+        target_arch = "x86_64",
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "loongarch64"
+    )
+))]
+pub mod thread;
+pub mod time;
+#[cfg(all(feature = "std", not(rawsys_backend = "none")))]
+pub mod tracer;
+#[cfg(all(
+    not(rawsys_backend = "none"),
+    any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "riscv64",
+        target_arch = "loongarch64",
+        target_arch = "mips",
+        target_arch = "mips64",
+        target_arch = "s390x"
+    )
+))]
+pub mod vdso;
+
 pub use arch::*;
 pub use args::SyscallArgs;
 pub use errno::{Errno, ErrnoSentinel};
@@ -42,6 +116,7 @@ pub use map::*;
 pub use set::*;
 pub use syscall::SyscallWord;
 
+#[cfg(not(rawsys_backend = "none"))]
 pub mod raw {
     //! Exposes raw syscalls that simply return a `SyscallWord` instead of a `Result`.
 
@@ -52,26 +127,13 @@ pub mod raw {
     pub use super::syscall::syscall4;
     pub use super::syscall::syscall5;
     pub use super::syscall::syscall6;
-}
 
-// NOTE on x86_64 x32 ABI
-// -----------------------
-// Some targets use 32-bit pointers but still return syscall results in a
-// 64-bit register width. In particular, the x86_64 x32 ABI has
-// `target_arch = "x86_64"` with `target_pointer_width = "32"`, but the
-// syscall return value is still delivered in a 64-bit register (RAX), and the
-// kernel reports errors by returning negative values truncated to the machine
-// word size. If we naïvely keyed off pointer width alone (treating all 32-bit
-// targets as returning a 32-bit value), negative return codes on x32 would be
-// misinterpreted and error conversion (Errno) would be wrong.
-//
-// To handle this correctly, we special-case x86_64 + 32-bit pointer-width to
-// convert using Errno::from_ret_u64, while other 32-bit targets continue to
-// use Errno::from_ret_u32 and 64-bit targets use Errno::from_ret_u64.
-//
-// Test status: we have not run CI on an actual x32 target here. The logic is
-// based on the ABI specification and should be correct, but x32-specific
-// testing remains outstanding.
+    #[cfg(all(target_arch = "x86_64", rawsys_backend = "inline"))]
+    pub use super::syscall::compat;
+
+    #[cfg(rawsys_backend = "stub")]
+    pub use super::syscall::stub_region;
+}
 
 /// Issues a system call with 0 arguments.
 ///
@@ -80,24 +142,16 @@ pub mod raw {
 /// Running a system call is inherently unsafe. It is the caller's
 /// responsibility to ensure safety.
 #[inline]
+#[cfg(not(rawsys_backend = "none"))]
 pub unsafe fn syscall0(nr: Sysno) -> Result<SyscallWord, Errno> {
-    let ret = unsafe { raw::syscall0(nr as SyscallWord) };
-
-    // x86_64 x32 ABI: 32-bit pointers with 64-bit syscall return width.
-    #[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
-    return Errno::from_ret_u64(ret as u64);
-
-    #[cfg(all(
-        not(all(target_arch = "x86_64", target_pointer_width = "32")),
-        target_pointer_width = "64"
-    ))]
-    return Errno::from_ret_u64(ret as u64);
-
-    #[cfg(all(
-        not(all(target_arch = "x86_64", target_pointer_width = "32")),
-        target_pointer_width = "32"
-    ))]
-    return Errno::from_ret_u32(ret as u32);
+    #[cfg(feature = "profile")]
+    let start = profile::start();
+    let result = unsafe { syscall::syscall0_checked(nr as SyscallWord) };
+    #[cfg(feature = "profile")]
+    profile::record(nr, start);
+    #[cfg(feature = "tracing")]
+    instrument::record(nr, &[], &result);
+    result
 }
 
 /// Issues a system call with 1 argument.
@@ -107,27 +161,19 @@ pub unsafe fn syscall0(nr: Sysno) -> Result<SyscallWord, Errno> {
 /// Running a system call is inherently unsafe. It is the caller's
 /// responsibility to ensure safety.
 #[inline]
+#[cfg(not(rawsys_backend = "none"))]
 pub unsafe fn syscall1(
     nr: Sysno,
     a1: SyscallWord,
 ) -> Result<SyscallWord, Errno> {
-    let ret = unsafe { raw::syscall1(nr as SyscallWord, a1) };
-
-    // x86_64 x32 ABI
-    #[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
-    return Errno::from_ret_u64(ret as u64);
-
-    #[cfg(all(
-        not(all(target_arch = "x86_64", target_pointer_width = "32")),
-        target_pointer_width = "64"
-    ))]
-    return Errno::from_ret_u64(ret as u64);
-
-    #[cfg(all(
-        not(all(target_arch = "x86_64", target_pointer_width = "32")),
-        target_pointer_width = "32"
-    ))]
-    return Errno::from_ret_u32(ret as u32);
+    #[cfg(feature = "profile")]
+    let start = profile::start();
+    let result = unsafe { syscall::syscall1_checked(nr as SyscallWord, a1) };
+    #[cfg(feature = "profile")]
+    profile::record(nr, start);
+    #[cfg(feature = "tracing")]
+    instrument::record(nr, &[a1], &result);
+    result
 }
 
 /// Issues a system call with 2 arguments.
@@ -137,28 +183,21 @@ pub unsafe fn syscall1(
 /// Running a system call is inherently unsafe. It is the caller's
 /// responsibility to ensure safety.
 #[inline]
+#[cfg(not(rawsys_backend = "none"))]
 pub unsafe fn syscall2(
     nr: Sysno,
     a1: SyscallWord,
     a2: SyscallWord,
 ) -> Result<SyscallWord, Errno> {
-    let ret = unsafe { raw::syscall2(nr as SyscallWord, a1, a2) };
-
-    // x86_64 x32 ABI
-    #[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
-    return Errno::from_ret_u64(ret as u64);
-
-    #[cfg(all(
-        not(all(target_arch = "x86_64", target_pointer_width = "32")),
-        target_pointer_width = "64"
-    ))]
-    return Errno::from_ret_u64(ret as u64);
-
-    #[cfg(all(
-        not(all(target_arch = "x86_64", target_pointer_width = "32")),
-        target_pointer_width = "32"
-    ))]
-    return Errno::from_ret_u32(ret as u32);
+    #[cfg(feature = "profile")]
+    let start = profile::start();
+    let result =
+        unsafe { syscall::syscall2_checked(nr as SyscallWord, a1, a2) };
+    #[cfg(feature = "profile")]
+    profile::record(nr, start);
+    #[cfg(feature = "tracing")]
+    instrument::record(nr, &[a1, a2], &result);
+    result
 }
 
 /// Issues a system call with 3 arguments.
@@ -168,29 +207,22 @@ pub unsafe fn syscall2(
 /// Running a system call is inherently unsafe. It is the caller's
 /// responsibility to ensure safety.
 #[inline]
+#[cfg(not(rawsys_backend = "none"))]
 pub unsafe fn syscall3(
     nr: Sysno,
     a1: SyscallWord,
     a2: SyscallWord,
     a3: SyscallWord,
 ) -> Result<SyscallWord, Errno> {
-    let ret = unsafe { raw::syscall3(nr as SyscallWord, a1, a2, a3) };
-
-    // x86_64 x32 ABI
-    #[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
-    return Errno::from_ret_u64(ret as u64);
-
-    #[cfg(all(
-        not(all(target_arch = "x86_64", target_pointer_width = "32")),
-        target_pointer_width = "64"
-    ))]
-    return Errno::from_ret_u64(ret as u64);
-
-    #[cfg(all(
-        not(all(target_arch = "x86_64", target_pointer_width = "32")),
-        target_pointer_width = "32"
-    ))]
-    return Errno::from_ret_u32(ret as u32);
+    #[cfg(feature = "profile")]
+    let start = profile::start();
+    let result =
+        unsafe { syscall::syscall3_checked(nr as SyscallWord, a1, a2, a3) };
+    #[cfg(feature = "profile")]
+    profile::record(nr, start);
+    #[cfg(feature = "tracing")]
+    instrument::record(nr, &[a1, a2, a3], &result);
+    result
 }
 
 /// Issues a system call with 4 arguments.
@@ -200,6 +232,7 @@ pub unsafe fn syscall3(
 /// Running a system call is inherently unsafe. It is the caller's
 /// responsibility to ensure safety.
 #[inline]
+#[cfg(not(rawsys_backend = "none"))]
 pub unsafe fn syscall4(
     nr: Sysno,
     a1: SyscallWord,
@@ -207,23 +240,15 @@ pub unsafe fn syscall4(
     a3: SyscallWord,
     a4: SyscallWord,
 ) -> Result<SyscallWord, Errno> {
-    let ret = unsafe { raw::syscall4(nr as SyscallWord, a1, a2, a3, a4) };
-
-    // x86_64 x32 ABI
-    #[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
-    return Errno::from_ret_u64(ret as u64);
-
-    #[cfg(all(
-        not(all(target_arch = "x86_64", target_pointer_width = "32")),
-        target_pointer_width = "64"
-    ))]
-    return Errno::from_ret_u64(ret as u64);
-
-    #[cfg(all(
-        not(all(target_arch = "x86_64", target_pointer_width = "32")),
-        target_pointer_width = "32"
-    ))]
-    return Errno::from_ret_u32(ret as u32);
+    #[cfg(feature = "profile")]
+    let start = profile::start();
+    let result =
+        unsafe { syscall::syscall4_checked(nr as SyscallWord, a1, a2, a3, a4) };
+    #[cfg(feature = "profile")]
+    profile::record(nr, start);
+    #[cfg(feature = "tracing")]
+    instrument::record(nr, &[a1, a2, a3, a4], &result);
+    result
 }
 
 /// Issues a system call with 5 arguments.
@@ -233,6 +258,7 @@ pub unsafe fn syscall4(
 /// Running a system call is inherently unsafe. It is the caller's
 /// responsibility to ensure safety.
 #[inline]
+#[cfg(not(rawsys_backend = "none"))]
 pub unsafe fn syscall5(
     nr: Sysno,
     a1: SyscallWord,
@@ -241,23 +267,16 @@ pub unsafe fn syscall5(
     a4: SyscallWord,
     a5: SyscallWord,
 ) -> Result<SyscallWord, Errno> {
-    let ret = unsafe { raw::syscall5(nr as SyscallWord, a1, a2, a3, a4, a5) };
-
-    // x86_64 x32 ABI
-    #[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
-    return Errno::from_ret_u64(ret as u64);
-
-    #[cfg(all(
-        not(all(target_arch = "x86_64", target_pointer_width = "32")),
-        target_pointer_width = "64"
-    ))]
-    return Errno::from_ret_u64(ret as u64);
-
-    #[cfg(all(
-        not(all(target_arch = "x86_64", target_pointer_width = "32")),
-        target_pointer_width = "32"
-    ))]
-    return Errno::from_ret_u32(ret as u32);
+    #[cfg(feature = "profile")]
+    let start = profile::start();
+    let result = unsafe {
+        syscall::syscall5_checked(nr as SyscallWord, a1, a2, a3, a4, a5)
+    };
+    #[cfg(feature = "profile")]
+    profile::record(nr, start);
+    #[cfg(feature = "tracing")]
+    instrument::record(nr, &[a1, a2, a3, a4, a5], &result);
+    result
 }
 
 /// Issues a system call with 6 arguments.
@@ -267,6 +286,7 @@ pub unsafe fn syscall5(
 /// Running a system call is inherently unsafe. It is the caller's
 /// responsibility to ensure safety.
 #[inline]
+#[cfg(not(rawsys_backend = "none"))]
 pub unsafe fn syscall6(
     nr: Sysno,
     a1: SyscallWord,
@@ -276,24 +296,16 @@ pub unsafe fn syscall6(
     a5: SyscallWord,
     a6: SyscallWord,
 ) -> Result<SyscallWord, Errno> {
-    let ret =
-        unsafe { raw::syscall6(nr as SyscallWord, a1, a2, a3, a4, a5, a6) };
-
-    // x86_64 x32 ABI
-    #[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
-    return Errno::from_ret_u64(ret as u64);
-
-    #[cfg(all(
-        not(all(target_arch = "x86_64", target_pointer_width = "32")),
-        target_pointer_width = "64"
-    ))]
-    return Errno::from_ret_u64(ret as u64);
-
-    #[cfg(all(
-        not(all(target_arch = "x86_64", target_pointer_width = "32")),
-        target_pointer_width = "32"
-    ))]
-    return Errno::from_ret_u32(ret as u32);
+    #[cfg(feature = "profile")]
+    let start = profile::start();
+    let result = unsafe {
+        syscall::syscall6_checked(nr as SyscallWord, a1, a2, a3, a4, a5, a6)
+    };
+    #[cfg(feature = "profile")]
+    profile::record(nr, start);
+    #[cfg(feature = "tracing")]
+    instrument::record(nr, &[a1, a2, a3, a4, a5, a6], &result);
+    result
 }
 
 /// Does a raw syscall.
@@ -310,6 +322,7 @@ pub unsafe fn syscall6(
 ///
 /// Running a system call is inherently unsafe. It is the caller's
 /// responsibility to ensure safety.
+#[cfg(not(rawsys_backend = "none"))]
 pub unsafe fn syscall(
     nr: Sysno,
     args: &SyscallArgs,
@@ -321,8 +334,75 @@ pub unsafe fn syscall(
         )
     }
 }
+
+/// Issues a system call with 0 arguments that returns two values.
+///
+/// Some legacy syscalls (e.g. `pipe`) return their second result in another
+/// register instead of through memory: `$v1` on MIPS and `%o1` on SPARC. This
+/// returns both registers.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+#[cfg(all(
+    any(
+        target_arch = "mips",
+        target_arch = "mips64",
+        target_arch = "sparc",
+        target_arch = "sparc64"
+    ),
+    rawsys_backend = "inline"
+))]
+pub unsafe fn syscall0_2ret(
+    nr: Sysno,
+) -> Result<(SyscallWord, SyscallWord), Errno> {
+    unsafe { syscall::syscall0_2ret(nr as SyscallWord) }
+}
+
+/// Issues a system call with 1 argument that returns two values. See
+/// [`syscall0_2ret`].
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+#[cfg(all(
+    any(
+        target_arch = "mips",
+        target_arch = "mips64",
+        target_arch = "sparc",
+        target_arch = "sparc64"
+    ),
+    rawsys_backend = "inline"
+))]
+pub unsafe fn syscall1_2ret(
+    nr: Sysno,
+    a1: SyscallWord,
+) -> Result<(SyscallWord, SyscallWord), Errno> {
+    unsafe { syscall::syscall1_2ret(nr as SyscallWord, a1) }
+}
+
+/// Creates a pipe with the legacy `pipe` syscall, which returns both file
+/// descriptors in registers on these architectures. Returns
+/// `[read_end, write_end]`.
+#[cfg(all(
+    any(
+        target_arch = "mips",
+        target_arch = "mips64",
+        target_arch = "sparc",
+        target_arch = "sparc64"
+    ),
+    rawsys_backend = "inline"
+))]
+pub fn pipe_legacy() -> Result<[i32; 2], Errno> {
+    let (read, write) = unsafe { syscall0_2ret(Sysno::pipe) }?;
+    Ok([read as i32, write as i32])
+}
 //
-#[cfg(test)]
+#[cfg(all(test, not(rawsys_backend = "none")))]
 mod tests {
     use super::*;
 
@@ -0,0 +1,308 @@
+//! `/proc/<pid>` files
+//!
+//! The kernel reports what a blocked thread is doing in
+//! `/proc/<pid>/syscall`: the syscall and its arguments, or only the stack
+//! pointer and program counter if it is blocked outside of a syscall (e.g. in
+//! a page fault), or `running`. Reading it is much cheaper than attaching
+//! with ptrace, which makes it suitable for sampling profilers and
+//! debuggers. [`read_syscall`] reads and parses it:
+//!
+//! ```no_run
+//! use rawsys_linux::id::Tid;
+//! use rawsys_linux::procfs::{self, ProcSyscall};
+//!
+//! # let tid = Tid::from_raw(0);
+//! if let ProcSyscall::Syscall { nr, args, .. } = procfs::read_syscall(tid)? {
+//!     println!("blocked in {nr}({:#x}, ...)", args.arg0);
+//! }
+//! # Ok::<(), rawsys_linux::Errno>(())
+//! ```
+//!
+//! Reading the file of another process needs the same permissions as
+//! attaching to it with ptrace.
+//!
+//! [`tgid`] tells which process a thread belongs to, from
+//! `/proc/<tid>/status`, e.g. for the thread ids in seccomp notifications.
+
+#[cfg(not(rawsys_backend = "none"))]
+use crate::id::{Pid, Tid};
+#[cfg(not(rawsys_backend = "none"))]
+use crate::{Errno, syscall1, syscall3, syscall4};
+use crate::{SyscallArgs, SyscallWord, Sysno};
+
+/// The contents of `/proc/<pid>/syscall`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcSyscall {
+    /// The thread is running, so there is nothing to report.
+    Running,
+    /// The thread is blocked, but not in a syscall (`-1 sp pc`).
+    Blocked { sp: u64, pc: u64 },
+    /// The thread is blocked in the syscall `nr`, which is the raw number
+    /// since it may be made with another calling convention than the
+    /// target's (e.g. an i386 syscall of an `x86_64` process).
+    Syscall {
+        nr: i32,
+        args: SyscallArgs,
+        sp: u64,
+        pc: u64,
+    },
+}
+
+impl ProcSyscall {
+    /// Parses the contents of `/proc/<pid>/syscall`, e.g.
+    /// `0 0x3 0x7ffc 0x400 0x0 0x0 0x0 0x7ffc10 0x7f1e20\n`.
+    pub fn parse(s: &[u8]) -> Option<Self> {
+        let s = s.strip_suffix(b"\n").unwrap_or(s);
+        if s == b"running" {
+            return Some(Self::Running);
+        }
+
+        let mut fields = s.split(|&c| c == b' ');
+        let nr = parse_dec(fields.next()?)?;
+        let mut hex = [0u64; 8];
+        let len = if nr < 0 { 2 } else { 8 };
+        for word in &mut hex[..len] {
+            *word = parse_hex(fields.next()?)?;
+        }
+        if fields.next().is_some() {
+            return None;
+        }
+
+        Some(if nr < 0 {
+            Self::Blocked {
+                sp: hex[0],
+                pc: hex[1],
+            }
+        } else {
+            // On 32-bit targets, the upper halves are zero.
+            let args: [SyscallWord; 6] = [
+                hex[0] as _,
+                hex[1] as _,
+                hex[2] as _,
+                hex[3] as _,
+                hex[4] as _,
+                hex[5] as _,
+            ];
+            Self::Syscall {
+                nr,
+                args: SyscallArgs::from(&args),
+                sp: hex[6],
+                pc: hex[7],
+            }
+        })
+    }
+
+    /// The syscall the thread is blocked in, if it is in one that the
+    /// target's table knows.
+    ///
+    /// The file doesn't say which calling convention the syscall was made
+    /// with, so a thread of a process of another arch (e.g. i386 under
+    /// `x86_64`) gets its number misinterpreted.
+    pub fn sysno(&self) -> Option<Sysno> {
+        match *self {
+            Self::Syscall { nr, .. } => Sysno::new(usize::try_from(nr).ok()?),
+            _ => None,
+        }
+    }
+}
+
+fn parse_dec(s: &[u8]) -> Option<i32> {
+    core::str::from_utf8(s).ok()?.parse().ok()
+}
+
+fn parse_hex(s: &[u8]) -> Option<u64> {
+    let s = core::str::from_utf8(s.strip_prefix(b"0x")?).ok()?;
+    u64::from_str_radix(s, 16).ok()
+}
+
+#[cfg(not(rawsys_backend = "none"))]
+const AT_FDCWD: SyscallWord = -100isize as SyscallWord;
+#[cfg(all(
+    not(rawsys_backend = "none"),
+    not(any(target_arch = "sparc", target_arch = "sparc64"))
+))]
+const O_CLOEXEC: SyscallWord = 0o200_0000;
+#[cfg(all(
+    not(rawsys_backend = "none"),
+    any(target_arch = "sparc", target_arch = "sparc64")
+))]
+const O_CLOEXEC: SyscallWord = 0x40_0000;
+
+/// Reads `/proc/<tid>/syscall`, for a process or any of its threads.
+#[cfg(not(rawsys_backend = "none"))]
+pub fn read_syscall(tid: Tid) -> Result<ProcSyscall, Errno> {
+    let fd = open(tid, b"syscall", 0)?;
+    // 9 words of up to 18 characters, and a few more.
+    let mut buf = [0u8; 256];
+    let read = unsafe {
+        syscall3(
+            Sysno::read,
+            fd,
+            buf.as_mut_ptr() as SyscallWord,
+            buf.len() as SyscallWord,
+        )
+    };
+    let _ = unsafe { syscall1(Sysno::close, fd) };
+    let read = read? as usize;
+
+    ProcSyscall::parse(&buf[..read]).ok_or(Errno::EINVAL)
+}
+
+/// Returns the thread group (process) of the thread `tid`, from the `Tgid`
+/// line of `/proc/<tid>/status`.
+#[cfg(not(rawsys_backend = "none"))]
+pub fn tgid(tid: Tid) -> Result<Pid, Errno> {
+    let fd = open(tid, b"status", 0)?;
+    // `Tgid` comes after `Name`, `Umask`, and `State`.
+    let mut buf = [0u8; 512];
+    let read = unsafe {
+        syscall3(
+            Sysno::read,
+            fd,
+            buf.as_mut_ptr() as SyscallWord,
+            buf.len() as SyscallWord,
+        )
+    };
+    let _ = unsafe { syscall1(Sysno::close, fd) };
+    let read = read? as usize;
+
+    parse_tgid(&buf[..read])
+        .map(Pid::from_raw)
+        .ok_or(Errno::EINVAL)
+}
+
+#[cfg(not(rawsys_backend = "none"))]
+fn parse_tgid(status: &[u8]) -> Option<i32> {
+    let line = status
+        .split(|&c| c == b'\n')
+        .find_map(|line| line.strip_prefix(b"Tgid:"))?;
+    parse_dec(line.trim_ascii())
+}
+
+/// Opens `/proc/<tid>/<file>` with `flags` and `O_CLOEXEC`, and returns the
+/// fd.
+#[cfg(not(rawsys_backend = "none"))]
+pub(crate) fn open(
+    tid: Tid,
+    file: &[u8],
+    flags: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    // "/proc/" + up to 11 characters + "/" + `file` + "\0"
+    let mut path = [0u8; 64];
+    let mut len = 0;
+    for part in [
+        b"/proc/".as_slice(),
+        itoa(tid.as_raw(), &mut [0; 11]),
+        b"/",
+        file,
+    ] {
+        path.get_mut(len..len + part.len())
+            .ok_or(Errno::ENAMETOOLONG)?
+            .copy_from_slice(part);
+        len += part.len();
+    }
+    if len == path.len() {
+        return Err(Errno::ENAMETOOLONG);
+    }
+
+    unsafe {
+        syscall4(
+            Sysno::openat,
+            AT_FDCWD,
+            path.as_ptr() as SyscallWord,
+            flags | O_CLOEXEC,
+            0,
+        )
+    }
+}
+
+/// Formats `n` in decimal into `buf`.
+#[cfg(not(rawsys_backend = "none"))]
+fn itoa(n: i32, buf: &mut [u8; 11]) -> &[u8] {
+    let mut start = buf.len();
+    let mut rest = n.unsigned_abs();
+    loop {
+        start -= 1;
+        let digit = (rest % 10) as u8;
+        buf[start] = b'0' + digit;
+        rest /= 10;
+        if rest == 0 {
+            break;
+        }
+    }
+    if n < 0 {
+        start -= 1;
+        buf[start] = b'-';
+    }
+    &buf[start..]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            ProcSyscall::parse(b"running\n"),
+            Some(ProcSyscall::Running)
+        );
+        assert_eq!(
+            ProcSyscall::parse(b"-1 0x7ffd8a3c1e28 0x55d1c2a4b0d0\n"),
+            Some(ProcSyscall::Blocked {
+                sp: 0x7ffd_8a3c_1e28,
+                pc: 0x55d1_c2a4_b0d0,
+            })
+        );
+
+        let syscall = ProcSyscall::parse(
+            b"3 0x4 0x0 0x0 0x0 0x0 0x0 0x7ffd8a3c1e28 0x55d1c2a4b0d0\n",
+        )
+        .unwrap();
+        assert_eq!(
+            syscall,
+            ProcSyscall::Syscall {
+                nr: 3,
+                args: SyscallArgs::new(4, 0, 0, 0, 0, 0),
+                sp: 0x7ffd_8a3c_1e28,
+                pc: 0x55d1_c2a4_b0d0,
+            }
+        );
+        assert_eq!(syscall.sysno(), Sysno::new(3));
+
+        assert_eq!(ProcSyscall::parse(b""), None);
+        assert_eq!(ProcSyscall::parse(b"-1 0x1\n"), None);
+        assert_eq!(ProcSyscall::parse(b"-1 0x1 0x2 0x3\n"), None);
+        assert_eq!(ProcSyscall::parse(b"0 1 2 3 4 5 6 7 8\n"), None);
+    }
+
+    #[cfg(not(rawsys_backend = "none"))]
+    #[test]
+    fn tgid() {
+        let status = b"Name:\tcat\nUmask:\t0022\nState:\tR (running)\nTgid:\t4321\nNgid:\t0\n";
+        assert_eq!(parse_tgid(status), Some(4321));
+        assert_eq!(parse_tgid(b"Name:\tTgid: 1\n"), None);
+    }
+
+    #[cfg(not(rawsys_backend = "none"))]
+    #[test]
+    fn itoa() {
+        assert_eq!(super::itoa(0, &mut [0; 11]), b"0");
+        assert_eq!(super::itoa(4321, &mut [0; 11]), b"4321");
+        assert_eq!(super::itoa(i32::MIN, &mut [0; 11]), b"-2147483648");
+    }
+
+    #[cfg(not(rawsys_backend = "none"))]
+    #[test]
+    fn read_own_thread() {
+        // The thread is in the `read` of its own file.
+        let tid = unsafe { crate::syscall0(Sysno::gettid) }.unwrap();
+        let tid = Tid::from_raw(tid as i32);
+        let syscall = read_syscall(tid).unwrap();
+        assert_eq!(syscall.sysno(), Some(Sysno::read));
+        assert_eq!(read_syscall(Tid::from_raw(-1)), Err(Errno::ENOENT));
+
+        let pid = unsafe { crate::syscall0(Sysno::getpid) }.unwrap();
+        assert_eq!(super::tgid(tid), Ok(Pid::from_raw(pid as i32)));
+    }
+}
@@ -0,0 +1,264 @@
+//! Per-syscall latency profiling
+//!
+//! While profiling is [enabled](enable), every syscall made through
+//! [`syscall0`](crate::syscall0) to [`syscall6`](crate::syscall6), which the
+//! `syscall!` macro and the modules of this crate use too, is timed with
+//! `clock_gettime(CLOCK_MONOTONIC)` (through the vDSO) and counted. A
+//! [`snapshot`] tells which syscalls took the most time, without the cost of
+//! stopping the process in a tracer like `strace -c` does:
+//!
+//! ```no_run
+//! use rawsys_linux::profile;
+//!
+//! profile::enable();
+//! // ... the code to profile ...
+//! profile::disable();
+//! print!("{}", profile::snapshot());
+//! ```
+//!
+//! ```text
+//! % time     seconds  usecs/call     calls   max usecs syscall
+//! ------ ----------- ----------- --------- ----------- ----------------
+//!  92.31    0.000120          60         2          97 openat
+//!   7.69    0.000010           1        10           2 read
+//! ------ ----------- ----------- --------- ----------- ----------------
+//! 100.00    0.000130          10        12          97 total
+//! ```
+//!
+//! The counts are shared by all threads. The `raw` functions aren't timed.
+
+use core::fmt;
+use core::time::Duration;
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::Instant;
+
+use crate::{Sysno, SysnoMap};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+static COUNTERS: OnceLock<SysnoMap<Counter>> = OnceLock::new();
+
+/// The counts of a syscall, in nanoseconds.
+#[derive(Debug, Default)]
+struct Counter {
+    count: AtomicU64,
+    total: AtomicU64,
+    max: AtomicU64,
+}
+
+fn counters() -> &'static SysnoMap<Counter> {
+    COUNTERS.get_or_init(|| {
+        Sysno::iter()
+            .map(|sysno| (sysno, Counter::default()))
+            .collect()
+    })
+}
+
+/// Starts timing syscalls.
+pub fn enable() {
+    // The first syscall shouldn't pay for the allocation.
+    counters();
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Stops timing syscalls. The counts so far are kept.
+pub fn disable() {
+    ENABLED.store(false, Ordering::Relaxed);
+}
+
+/// Whether syscalls are being timed.
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Clears the counts.
+pub fn reset() {
+    for counter in counters().values() {
+        counter.count.store(0, Ordering::Relaxed);
+        counter.total.store(0, Ordering::Relaxed);
+        counter.max.store(0, Ordering::Relaxed);
+    }
+}
+
+/// Returns the counts so far. Syscalls that are running while it is taken
+/// may be counted in some fields but not others.
+pub fn snapshot() -> Profile {
+    let stats = counters()
+        .iter()
+        .filter_map(|(sysno, counter)| {
+            let count = counter.count.load(Ordering::Relaxed);
+            (count > 0).then(|| {
+                let stats = SyscallStats {
+                    count,
+                    total: Duration::from_nanos(
+                        counter.total.load(Ordering::Relaxed),
+                    ),
+                    max: Duration::from_nanos(
+                        counter.max.load(Ordering::Relaxed),
+                    ),
+                };
+                (sysno, stats)
+            })
+        })
+        .collect();
+    Profile { stats }
+}
+
+/// Returns when a syscall starts, if it is timed.
+#[inline]
+pub(crate) fn start() -> Option<Instant> {
+    is_enabled().then(Instant::now)
+}
+
+/// Counts the syscall `sysno` that started at `start`.
+#[inline]
+pub(crate) fn record(sysno: Sysno, start: Option<Instant>) {
+    let Some(start) = start else {
+        return;
+    };
+    let nanos = u64::try_from(start.elapsed().as_nanos()).unwrap_or(u64::MAX);
+    if let Some(counter) = counters().get(sysno) {
+        counter.count.fetch_add(1, Ordering::Relaxed);
+        counter.total.fetch_add(nanos, Ordering::Relaxed);
+        counter.max.fetch_max(nanos, Ordering::Relaxed);
+    }
+}
+
+/// The counts of a syscall.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SyscallStats {
+    /// How many times it was made.
+    pub count: u64,
+    /// How long it took in total.
+    pub total: Duration,
+    /// How long it took at most.
+    pub max: Duration,
+}
+
+impl SyscallStats {
+    /// How long it took on average.
+    pub fn mean(&self) -> Duration {
+        match self.count {
+            0 => Duration::ZERO,
+            count => Duration::from_nanos(
+                (self.total.as_nanos() / u128::from(count)) as u64,
+            ),
+        }
+    }
+}
+
+/// The counts of the syscalls that were made, from [`snapshot`].
+///
+/// It displays as a table like the one of `strace -c`, with the syscalls
+/// that took the most time first.
+#[derive(Debug)]
+pub struct Profile {
+    stats: SysnoMap<SyscallStats>,
+}
+
+impl Profile {
+    /// Returns the counts of `sysno`, if it was made.
+    pub fn get(&self, sysno: Sysno) -> Option<&SyscallStats> {
+        self.stats.get(sysno)
+    }
+
+    /// Iterates over the syscalls that were made, in the table's order.
+    pub fn iter(&self) -> impl Iterator<Item = (Sysno, &SyscallStats)> {
+        self.stats.iter()
+    }
+
+    /// The counts of all syscalls together.
+    pub fn total(&self) -> SyscallStats {
+        self.stats
+            .values()
+            .fold(SyscallStats::default(), |sum, stats| SyscallStats {
+                count: sum.count + stats.count,
+                total: sum.total + stats.total,
+                max: sum.max.max(stats.max),
+            })
+    }
+}
+
+impl fmt::Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const RULE: &str = "------ ----------- ----------- --------- ----------- ----------------";
+
+        let total = self.total();
+        let mut rows: Vec<_> = self.iter().collect();
+        rows.sort_by_key(|(_, stats)| core::cmp::Reverse(stats.total));
+
+        writeln!(
+            f,
+            "% time     seconds  usecs/call     calls   max usecs syscall"
+        )?;
+        writeln!(f, "{RULE}")?;
+        for (sysno, stats) in rows {
+            write_row(f, stats, &total, sysno.name())?;
+        }
+        writeln!(f, "{RULE}")?;
+        write_row(f, &total, &total, "total")
+    }
+}
+
+fn write_row(
+    f: &mut fmt::Formatter<'_>,
+    stats: &SyscallStats,
+    total: &SyscallStats,
+    name: &str,
+) -> fmt::Result {
+    #[allow(clippy::cast_precision_loss)]
+    let percent = if total.total.is_zero() {
+        0.0
+    } else {
+        stats.total.as_nanos() as f64 * 100.0 / total.total.as_nanos() as f64
+    };
+    writeln!(
+        f,
+        "{percent:6.2} {:11.6} {:11} {:9} {:11} {name}",
+        stats.total.as_secs_f64(),
+        stats.mean().as_micros(),
+        stats.count,
+        stats.max.as_micros(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report() {
+        let mut stats = SysnoMap::new();
+        stats.insert(
+            Sysno::read,
+            SyscallStats {
+                count: 10,
+                total: Duration::from_micros(10),
+                max: Duration::from_micros(2),
+            },
+        );
+        stats.insert(
+            Sysno::openat,
+            SyscallStats {
+                count: 2,
+                total: Duration::from_micros(120),
+                max: Duration::from_micros(97),
+            },
+        );
+        let profile = Profile { stats };
+        assert_eq!(profile.get(Sysno::openat).unwrap().mean().as_micros(), 60);
+        assert_eq!(profile.get(Sysno::close), None);
+        assert_eq!(
+            profile.to_string(),
+            "\
+% time     seconds  usecs/call     calls   max usecs syscall
+------ ----------- ----------- --------- ----------- ----------------
+ 92.31    0.000120          60         2          97 openat
+  7.69    0.000010           1        10           2 read
+------ ----------- ----------- --------- ----------- ----------------
+100.00    0.000130          10        12          97 total
+"
+        );
+    }
+}
@@ -0,0 +1,603 @@
+//! ptrace helpers
+//!
+//! `PTRACE_GET_SYSCALL_INFO` (Linux 5.3) tells a tracer stopped at a syscall
+//! which stop it is and what the syscall is, without decoding registers by
+//! arch (compare [`regs`](crate::regs)). [`syscall_info`] makes the request
+//! and decodes the answer into a [`SyscallInfo`]:
+//!
+//! ```no_run
+//! use rawsys_linux::id::Tid;
+//! use rawsys_linux::ptrace::{self, SyscallStop};
+//!
+//! # let tid = Tid::from_raw(0);
+//! // The tracee is in a syscall stop.
+//! let info = ptrace::syscall_info(tid).unwrap();
+//! match info.stop {
+//!     SyscallStop::Entry { sysno: Some(sysno), args, .. } => {
+//!         println!("{sysno}({:#x}, ...)", args.arg0);
+//!     }
+//!     SyscallStop::Exit { ret } => println!(" = {ret:?}"),
+//!     _ => {}
+//! }
+//! ```
+//!
+//! [`PtraceRequest`] has the request numbers, with those that only some
+//! architectures have (e.g. `PTRACE_GETREGS`) behind their `target_arch`,
+//! and the `PTRACE_O_*` options and `PTRACE_EVENT_*` stops are here as
+//! constants. [`ptrace`] makes any request, and functions like [`seize`],
+//! [`cont`], and [`peek_data`] make the common ones with typed arguments:
+//!
+//! ```no_run
+//! use rawsys_linux::id::Tid;
+//! use rawsys_linux::ptrace::{self, PTRACE_O_TRACESYSGOOD};
+//!
+//! # let tid = Tid::from_raw(0);
+//! ptrace::seize(tid, PTRACE_O_TRACESYSGOOD)?;
+//! ptrace::interrupt(tid)?;
+//! // ... wait for the stop ...
+//! let word = ptrace::peek_data(tid, 0x1000)?;
+//! ptrace::syscall(tid, 0)?;
+//! # Ok::<(), rawsys_linux::Errno>(())
+//! ```
+
+use core::mem;
+
+use crate::audit::{AUDIT_ARCH, Arch};
+#[cfg(not(rawsys_backend = "none"))]
+use crate::id::Tid;
+use crate::{Errno, SyscallArgs, SyscallWord, Sysno};
+
+/// A ptrace request (`PTRACE_*`).
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PtraceRequest {
+    TraceMe = 0,
+    PeekText = 1,
+    PeekData = 2,
+    PeekUser = 3,
+    PokeText = 4,
+    PokeData = 5,
+    PokeUser = 6,
+    Cont = 7,
+    Kill = 8,
+    SingleStep = 9,
+    /// `PTRACE_GETREGS`, or `PTRACE_GETREGS64` on sparc64.
+    #[cfg(any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "arm",
+        target_arch = "mips",
+        target_arch = "mips64",
+        target_arch = "powerpc",
+        target_arch = "powerpc64",
+        target_arch = "sparc"
+    ))]
+    GetRegs = 12,
+    #[cfg(target_arch = "sparc64")]
+    GetRegs = 22,
+    /// `PTRACE_SETREGS`, or `PTRACE_SETREGS64` on sparc64.
+    #[cfg(any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "arm",
+        target_arch = "mips",
+        target_arch = "mips64",
+        target_arch = "powerpc",
+        target_arch = "powerpc64",
+        target_arch = "sparc"
+    ))]
+    SetRegs = 13,
+    #[cfg(target_arch = "sparc64")]
+    SetRegs = 23,
+    /// `PTRACE_GETFPREGS`, or `PTRACE_GETFPREGS64` on sparc64.
+    #[cfg(any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "arm",
+        target_arch = "mips",
+        target_arch = "mips64",
+        target_arch = "powerpc",
+        target_arch = "powerpc64",
+        target_arch = "sparc"
+    ))]
+    GetFpRegs = 14,
+    #[cfg(target_arch = "sparc64")]
+    GetFpRegs = 25,
+    /// `PTRACE_SETFPREGS`, or `PTRACE_SETFPREGS64` on sparc64.
+    #[cfg(any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "arm",
+        target_arch = "mips",
+        target_arch = "mips64",
+        target_arch = "powerpc",
+        target_arch = "powerpc64",
+        target_arch = "sparc"
+    ))]
+    SetFpRegs = 15,
+    #[cfg(target_arch = "sparc64")]
+    SetFpRegs = 26,
+    Attach = 16,
+    Detach = 17,
+    /// Changes the number of the syscall the kernel runs, see
+    /// [`regs`](crate::regs).
+    #[cfg(target_arch = "arm")]
+    SetSyscall = 23,
+    Syscall = 24,
+    #[cfg(target_arch = "x86_64")]
+    ArchPrctl = 30,
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    Sysemu = 31,
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    SysemuSingleStep = 32,
+    SetOptions = 0x4200,
+    GetEventMsg = 0x4201,
+    GetSigInfo = 0x4202,
+    SetSigInfo = 0x4203,
+    GetRegSet = 0x4204,
+    SetRegSet = 0x4205,
+    Seize = 0x4206,
+    Interrupt = 0x4207,
+    Listen = 0x4208,
+    PeekSigInfo = 0x4209,
+    GetSigMask = 0x420a,
+    SetSigMask = 0x420b,
+    SeccompGetFilter = 0x420c,
+    SeccompGetMetadata = 0x420d,
+    /// See [`syscall_info`].
+    GetSyscallInfo = 0x420e,
+    GetRseqConfiguration = 0x420f,
+    SetSyscallUserDispatchConfig = 0x4210,
+    GetSyscallUserDispatchConfig = 0x4211,
+}
+
+/// Stops at syscalls with `SIGTRAP | 0x80`, to tell them from other
+/// `SIGTRAP`s.
+pub const PTRACE_O_TRACESYSGOOD: u32 = 0x1;
+/// Traces the children of `fork`, with a `PTRACE_EVENT_FORK` stop.
+pub const PTRACE_O_TRACEFORK: u32 = 0x2;
+/// Traces the children of `vfork`, with a `PTRACE_EVENT_VFORK` stop.
+pub const PTRACE_O_TRACEVFORK: u32 = 0x4;
+/// Traces the threads and children of `clone`, with a
+/// `PTRACE_EVENT_CLONE` stop.
+pub const PTRACE_O_TRACECLONE: u32 = 0x8;
+/// Stops with `PTRACE_EVENT_EXEC` at `execve`.
+pub const PTRACE_O_TRACEEXEC: u32 = 0x10;
+/// Stops with `PTRACE_EVENT_VFORK_DONE` when a `vfork` child releases the
+/// parent.
+pub const PTRACE_O_TRACEVFORKDONE: u32 = 0x20;
+/// Stops with `PTRACE_EVENT_EXIT` before exiting.
+pub const PTRACE_O_TRACEEXIT: u32 = 0x40;
+/// Stops with `PTRACE_EVENT_SECCOMP` at `SECCOMP_RET_TRACE`.
+pub const PTRACE_O_TRACESECCOMP: u32 = 0x80;
+/// Kills the tracees when the tracer exits.
+pub const PTRACE_O_EXITKILL: u32 = 0x10_0000;
+/// Makes seccomp filters of the tracee ignore it.
+pub const PTRACE_O_SUSPEND_SECCOMP: u32 = 0x20_0000;
+
+// The `PTRACE_EVENT_*` stops are reported in bits 16 and up of the `wait`
+// status, with `SIGTRAP` as the signal (or the stopping signal for
+// `PTRACE_EVENT_STOP`).
+
+/// The `fork` of [`PTRACE_O_TRACEFORK`].
+pub const PTRACE_EVENT_FORK: u32 = 1;
+/// The `vfork` of [`PTRACE_O_TRACEVFORK`].
+pub const PTRACE_EVENT_VFORK: u32 = 2;
+/// The `clone` of [`PTRACE_O_TRACECLONE`].
+pub const PTRACE_EVENT_CLONE: u32 = 3;
+/// The `execve` of [`PTRACE_O_TRACEEXEC`].
+pub const PTRACE_EVENT_EXEC: u32 = 4;
+/// The end of the `vfork` of [`PTRACE_O_TRACEVFORKDONE`].
+pub const PTRACE_EVENT_VFORK_DONE: u32 = 5;
+/// The exit of [`PTRACE_O_TRACEEXIT`].
+pub const PTRACE_EVENT_EXIT: u32 = 6;
+/// The `SECCOMP_RET_TRACE` of [`PTRACE_O_TRACESECCOMP`].
+pub const PTRACE_EVENT_SECCOMP: u32 = 7;
+/// A group-stop or the stop of `PTRACE_INTERRUPT`, of a seized tracee.
+pub const PTRACE_EVENT_STOP: u32 = 128;
+
+/// The request that returns a [`PtraceSyscallInfo`].
+pub const PTRACE_GET_SYSCALL_INFO: u32 = PtraceRequest::GetSyscallInfo as u32;
+
+/// `op` of a [`PtraceSyscallInfo`] outside of a syscall stop.
+pub const PTRACE_SYSCALL_INFO_NONE: u8 = 0;
+/// `op` at a syscall entry stop.
+pub const PTRACE_SYSCALL_INFO_ENTRY: u8 = 1;
+/// `op` at a syscall exit stop.
+pub const PTRACE_SYSCALL_INFO_EXIT: u8 = 2;
+/// `op` at a `PTRACE_EVENT_SECCOMP` stop.
+pub const PTRACE_SYSCALL_INFO_SECCOMP: u8 = 3;
+
+/// The answer to `PTRACE_GET_SYSCALL_INFO` (`struct ptrace_syscall_info`).
+/// Which member of `data` is valid depends on `op`.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct PtraceSyscallInfo {
+    /// `PTRACE_SYSCALL_INFO_*`.
+    pub op: u8,
+    pub pad: [u8; 3],
+    /// The `AUDIT_ARCH_*` token of the syscall's calling convention.
+    pub arch: u32,
+    pub instruction_pointer: u64,
+    pub stack_pointer: u64,
+    pub data: PtraceSyscallInfoData,
+}
+
+/// The `op`-specific part of a [`PtraceSyscallInfo`].
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union PtraceSyscallInfoData {
+    pub entry: PtraceSyscallInfoEntry,
+    pub exit: PtraceSyscallInfoExit,
+    pub seccomp: PtraceSyscallInfoSeccomp,
+}
+
+/// The syscall at an entry stop.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PtraceSyscallInfoEntry {
+    pub nr: u64,
+    pub args: [u64; 6],
+}
+
+/// The result at an exit stop.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PtraceSyscallInfoExit {
+    /// The return value, or the negated errno if `is_error` is set.
+    pub rval: i64,
+    pub is_error: u8,
+}
+
+/// The syscall at a `PTRACE_EVENT_SECCOMP` stop.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PtraceSyscallInfoSeccomp {
+    pub nr: u64,
+    pub args: [u64; 6],
+    /// The data of the filter's `SECCOMP_RET_TRACE`.
+    pub ret_data: u32,
+}
+
+impl Default for PtraceSyscallInfo {
+    fn default() -> Self {
+        // All-zero is `PTRACE_SYSCALL_INFO_NONE`.
+        unsafe { mem::zeroed() }
+    }
+}
+
+impl core::fmt::Debug for PtraceSyscallInfo {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        SyscallInfo::from(self).fmt(f)
+    }
+}
+
+/// A decoded [`PtraceSyscallInfo`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyscallInfo {
+    /// The architecture of the syscall's calling convention, or `None` for
+    /// an unknown `AUDIT_ARCH_*` token.
+    pub arch: Option<Arch>,
+    pub instruction_pointer: u64,
+    pub stack_pointer: u64,
+    pub stop: SyscallStop,
+}
+
+/// The kind of stop and what it says about the syscall.
+///
+/// `sysno` is `None` if the syscall was made with another calling
+/// convention than the target's (e.g. an i386 syscall of an `x86_64`
+/// tracee, see [`SyscallInfo::arch`]) or isn't in the table; `nr` has the
+/// raw number either way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyscallStop {
+    /// Not a syscall stop.
+    None,
+    /// A syscall entry stop.
+    Entry {
+        sysno: Option<Sysno>,
+        nr: u64,
+        args: SyscallArgs,
+    },
+    /// A syscall exit stop.
+    Exit { ret: Result<i64, Errno> },
+    /// A `PTRACE_EVENT_SECCOMP` stop, with the data of the filter's
+    /// `SECCOMP_RET_TRACE`.
+    Seccomp {
+        sysno: Option<Sysno>,
+        nr: u64,
+        args: SyscallArgs,
+        ret_data: u32,
+    },
+}
+
+impl From<&PtraceSyscallInfo> for SyscallInfo {
+    fn from(info: &PtraceSyscallInfo) -> Self {
+        let sysno = |nr: u64| {
+            if info.arch != AUDIT_ARCH {
+                return None;
+            }
+            Sysno::new(usize::try_from(nr).ok()?)
+        };
+        // On 32-bit targets, the upper halves are zero.
+        #[allow(clippy::cast_possible_truncation)]
+        let args = |args: [u64; 6]| {
+            SyscallArgs::from(&args.map(|arg| arg as SyscallWord))
+        };
+
+        // The kernel fills in the member that `op` names.
+        let stop = unsafe {
+            match info.op {
+                PTRACE_SYSCALL_INFO_ENTRY => {
+                    let entry = info.data.entry;
+                    SyscallStop::Entry {
+                        sysno: sysno(entry.nr),
+                        nr: entry.nr,
+                        args: args(entry.args),
+                    }
+                }
+                PTRACE_SYSCALL_INFO_EXIT => {
+                    let exit = info.data.exit;
+                    SyscallStop::Exit {
+                        ret: if exit.is_error == 0 {
+                            Ok(exit.rval)
+                        } else {
+                            #[allow(clippy::cast_possible_truncation)]
+                            Err(Errno::new(-exit.rval as i32))
+                        },
+                    }
+                }
+                PTRACE_SYSCALL_INFO_SECCOMP => {
+                    let seccomp = info.data.seccomp;
+                    SyscallStop::Seccomp {
+                        sysno: sysno(seccomp.nr),
+                        nr: seccomp.nr,
+                        args: args(seccomp.args),
+                        ret_data: seccomp.ret_data,
+                    }
+                }
+                _ => SyscallStop::None,
+            }
+        };
+
+        Self {
+            arch: Arch::from_audit_arch(info.arch),
+            instruction_pointer: info.instruction_pointer,
+            stack_pointer: info.stack_pointer,
+            stop,
+        }
+    }
+}
+
+/// Gets the syscall of the stopped tracee `tid` with
+/// `PTRACE_GET_SYSCALL_INFO`. Syscall stops are only recognized with the
+/// `PTRACE_O_TRACESYSGOOD` option; otherwise they report
+/// [`SyscallStop::None`].
+#[cfg(not(rawsys_backend = "none"))]
+pub fn syscall_info(tid: Tid) -> Result<SyscallInfo, Errno> {
+    let mut info = PtraceSyscallInfo::default();
+    unsafe {
+        ptrace(
+            PtraceRequest::GetSyscallInfo,
+            tid,
+            mem::size_of::<PtraceSyscallInfo>(),
+            core::ptr::from_mut(&mut info) as usize,
+        )
+    }?;
+    Ok(SyscallInfo::from(&info))
+}
+
+/// Makes the ptrace `request` for the tracee `tid`.
+///
+/// This is the syscall, not the glibc function, so the `PEEK` requests
+/// store the word they read at `data` and return 0.
+///
+/// # Safety
+///
+/// `addr` and `data` must be valid for `request`; some are pointers that the
+/// kernel reads or writes.
+#[cfg(not(rawsys_backend = "none"))]
+pub unsafe fn ptrace(
+    request: PtraceRequest,
+    tid: Tid,
+    addr: usize,
+    data: usize,
+) -> Result<SyscallWord, Errno> {
+    unsafe {
+        crate::syscall4(
+            Sysno::ptrace,
+            request as SyscallWord,
+            SyscallWord::from(tid),
+            addr as SyscallWord,
+            data as SyscallWord,
+        )
+    }
+}
+
+/// Makes the calling thread a tracee of its parent (`PTRACE_TRACEME`).
+#[cfg(not(rawsys_backend = "none"))]
+pub fn traceme() -> Result<(), Errno> {
+    unsafe { ptrace(PtraceRequest::TraceMe, Tid::from_raw(0), 0, 0) }.map(drop)
+}
+
+/// Attaches to `tid`, which stops it with `SIGSTOP` (`PTRACE_ATTACH`).
+#[cfg(not(rawsys_backend = "none"))]
+pub fn attach(tid: Tid) -> Result<(), Errno> {
+    unsafe { ptrace(PtraceRequest::Attach, tid, 0, 0) }.map(drop)
+}
+
+/// Attaches to `tid` without stopping it, with the `PTRACE_O_*` `options`
+/// (`PTRACE_SEIZE`).
+#[cfg(not(rawsys_backend = "none"))]
+pub fn seize(tid: Tid, options: u32) -> Result<(), Errno> {
+    unsafe { ptrace(PtraceRequest::Seize, tid, 0, options as usize) }.map(drop)
+}
+
+/// Stops the seized tracee `tid` with a `PTRACE_EVENT_STOP`
+/// (`PTRACE_INTERRUPT`).
+#[cfg(not(rawsys_backend = "none"))]
+pub fn interrupt(tid: Tid) -> Result<(), Errno> {
+    unsafe { ptrace(PtraceRequest::Interrupt, tid, 0, 0) }.map(drop)
+}
+
+/// Lets the seized tracee `tid` stay in its group-stop while the tracer
+/// waits for its next event (`PTRACE_LISTEN`).
+#[cfg(not(rawsys_backend = "none"))]
+pub fn listen(tid: Tid) -> Result<(), Errno> {
+    unsafe { ptrace(PtraceRequest::Listen, tid, 0, 0) }.map(drop)
+}
+
+/// Detaches from the stopped tracee `tid` and resumes it with `signal`, or
+/// none if 0 (`PTRACE_DETACH`).
+#[cfg(not(rawsys_backend = "none"))]
+pub fn detach(tid: Tid, signal: i32) -> Result<(), Errno> {
+    unsafe { ptrace(PtraceRequest::Detach, tid, 0, signal as usize) }.map(drop)
+}
+
+/// Resumes the stopped tracee `tid` with `signal`, or none if 0
+/// (`PTRACE_CONT`).
+#[cfg(not(rawsys_backend = "none"))]
+pub fn cont(tid: Tid, signal: i32) -> Result<(), Errno> {
+    unsafe { ptrace(PtraceRequest::Cont, tid, 0, signal as usize) }.map(drop)
+}
+
+/// Resumes the stopped tracee `tid` with `signal` until its next syscall
+/// entry or exit (`PTRACE_SYSCALL`).
+#[cfg(not(rawsys_backend = "none"))]
+pub fn syscall(tid: Tid, signal: i32) -> Result<(), Errno> {
+    unsafe { ptrace(PtraceRequest::Syscall, tid, 0, signal as usize) }.map(drop)
+}
+
+/// Resumes the stopped tracee `tid` with `signal` for one instruction
+/// (`PTRACE_SINGLESTEP`).
+#[cfg(not(rawsys_backend = "none"))]
+pub fn single_step(tid: Tid, signal: i32) -> Result<(), Errno> {
+    unsafe { ptrace(PtraceRequest::SingleStep, tid, 0, signal as usize) }
+        .map(drop)
+}
+
+/// Kills the tracee `tid` (`PTRACE_KILL`). Sending it `SIGKILL` works
+/// better, since this only works for stopped tracees.
+#[cfg(not(rawsys_backend = "none"))]
+pub fn kill(tid: Tid) -> Result<(), Errno> {
+    unsafe { ptrace(PtraceRequest::Kill, tid, 0, 0) }.map(drop)
+}
+
+/// Sets the `PTRACE_O_*` `options` of the stopped tracee `tid`
+/// (`PTRACE_SETOPTIONS`).
+#[cfg(not(rawsys_backend = "none"))]
+pub fn set_options(tid: Tid, options: u32) -> Result<(), Errno> {
+    unsafe { ptrace(PtraceRequest::SetOptions, tid, 0, options as usize) }
+        .map(drop)
+}
+
+/// Returns the message of the `PTRACE_EVENT_*` stop of `tid`, e.g. the pid
+/// of the new child at `PTRACE_EVENT_FORK` (`PTRACE_GETEVENTMSG`).
+#[cfg(not(rawsys_backend = "none"))]
+pub fn get_event_msg(tid: Tid) -> Result<usize, Errno> {
+    // An `unsigned long`.
+    let mut msg = 0usize;
+    unsafe {
+        ptrace(
+            PtraceRequest::GetEventMsg,
+            tid,
+            0,
+            core::ptr::from_mut(&mut msg) as usize,
+        )
+    }?;
+    Ok(msg)
+}
+
+/// Reads the `siginfo_t` of the signal that stopped `tid` into `siginfo`
+/// (`PTRACE_GETSIGINFO`). Fails with `EINVAL` at a group-stop.
+#[cfg(not(rawsys_backend = "none"))]
+pub fn get_siginfo(tid: Tid, siginfo: &mut [u8; 128]) -> Result<(), Errno> {
+    unsafe {
+        ptrace(
+            PtraceRequest::GetSigInfo,
+            tid,
+            0,
+            siginfo.as_mut_ptr() as usize,
+        )
+    }
+    .map(drop)
+}
+
+/// Reads the word at `addr` in the memory of the stopped tracee `tid`
+/// (`PTRACE_PEEKDATA`).
+#[cfg(not(rawsys_backend = "none"))]
+pub fn peek_data(tid: Tid, addr: usize) -> Result<usize, Errno> {
+    let mut word = 0usize;
+    unsafe {
+        ptrace(
+            PtraceRequest::PeekData,
+            tid,
+            addr,
+            core::ptr::from_mut(&mut word) as usize,
+        )
+    }?;
+    Ok(word)
+}
+
+/// Writes `word` at `addr` in the memory of the stopped tracee `tid`, even
+/// if it is read-only (`PTRACE_POKEDATA`).
+#[cfg(not(rawsys_backend = "none"))]
+pub fn poke_data(tid: Tid, addr: usize, word: usize) -> Result<(), Errno> {
+    unsafe { ptrace(PtraceRequest::PokeData, tid, addr, word) }.map(drop)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layout() {
+        assert_eq!(mem::size_of::<PtraceSyscallInfo>(), 88);
+        assert_eq!(mem::offset_of!(PtraceSyscallInfo, data), 24);
+        assert_eq!(mem::offset_of!(PtraceSyscallInfoSeccomp, ret_data), 56);
+    }
+
+    #[test]
+    fn decodes() {
+        let mut info = PtraceSyscallInfo {
+            op: PTRACE_SYSCALL_INFO_ENTRY,
+            arch: AUDIT_ARCH,
+            ..Default::default()
+        };
+        info.data.entry = PtraceSyscallInfoEntry {
+            nr: Sysno::close as u64,
+            args: [3, 0, 0, 0, 0, 0],
+        };
+        let decoded = SyscallInfo::from(&info);
+        assert_eq!(decoded.arch, Some(Arch::TARGET));
+        assert_eq!(
+            decoded.stop,
+            SyscallStop::Entry {
+                sysno: Some(Sysno::close),
+                nr: Sysno::close as u64,
+                args: SyscallArgs::new(3, 0, 0, 0, 0, 0),
+            }
+        );
+
+        info.op = PTRACE_SYSCALL_INFO_EXIT;
+        info.data.exit = PtraceSyscallInfoExit {
+            rval: -9,
+            is_error: 1,
+        };
+        assert_eq!(
+            SyscallInfo::from(&info).stop,
+            SyscallStop::Exit {
+                ret: Err(Errno::EBADF)
+            }
+        );
+
+        info.op = PTRACE_SYSCALL_INFO_SECCOMP;
+        info.arch = 0;
+        assert!(matches!(
+            SyscallInfo::from(&info).stop,
+            SyscallStop::Seccomp { sysno: None, .. }
+        ));
+        assert_eq!(SyscallInfo::from(&info).arch, None);
+    }
+}
@@ -0,0 +1,16 @@
+//! The `NT_PRSTATUS` registers of `aarch64`.
+
+/// The general-purpose registers (`struct user_pt_regs`).
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct UserRegs {
+    pub regs: [u64; 31],
+    pub sp: u64,
+    pub pc: u64,
+    pub pstate: u64,
+}
+
+/// The size of the `NT_PRSTATUS` regset.
+pub const NT_PRSTATUS_SIZE: usize = 272;
+
+const _: () = assert!(size_of::<UserRegs>() == NT_PRSTATUS_SIZE);
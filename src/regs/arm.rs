@@ -0,0 +1,15 @@
+//! The `NT_PRSTATUS` registers of `arm`.
+
+/// The general-purpose registers (`struct pt_regs`).
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct UserRegs {
+    pub regs: [u32; 16],
+    pub cpsr: u32,
+    pub orig_r0: u32,
+}
+
+/// The size of the `NT_PRSTATUS` regset.
+pub const NT_PRSTATUS_SIZE: usize = 72;
+
+const _: () = assert!(size_of::<UserRegs>() == NT_PRSTATUS_SIZE);
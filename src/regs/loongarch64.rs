@@ -0,0 +1,17 @@
+//! The `NT_PRSTATUS` registers of `loongarch64`.
+
+/// The general-purpose registers (`struct user_pt_regs`).
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct UserRegs {
+    pub regs: [u64; 32],
+    pub orig_a0: u64,
+    pub csr_era: u64,
+    pub csr_badv: u64,
+    pub reserved: [u64; 10],
+}
+
+/// The size of the `NT_PRSTATUS` regset.
+pub const NT_PRSTATUS_SIZE: usize = 360;
+
+const _: () = assert!(size_of::<UserRegs>() == NT_PRSTATUS_SIZE);
@@ -0,0 +1,21 @@
+//! The `NT_PRSTATUS` registers of `mips` (o32).
+
+/// The general-purpose registers (`elf_gregset_t`).
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct UserRegs {
+    pub pad: [u32; 6],
+    pub regs: [u32; 32],
+    pub lo: u32,
+    pub hi: u32,
+    pub cp0_epc: u32,
+    pub cp0_badvaddr: u32,
+    pub cp0_status: u32,
+    pub cp0_cause: u32,
+    pub unused: u32,
+}
+
+/// The size of the `NT_PRSTATUS` regset.
+pub const NT_PRSTATUS_SIZE: usize = 180;
+
+const _: () = assert!(size_of::<UserRegs>() == NT_PRSTATUS_SIZE);
@@ -0,0 +1,20 @@
+//! The `NT_PRSTATUS` registers of `mips64` (n64).
+
+/// The general-purpose registers (`elf_gregset_t`).
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct UserRegs {
+    pub regs: [u64; 32],
+    pub lo: u64,
+    pub hi: u64,
+    pub cp0_epc: u64,
+    pub cp0_badvaddr: u64,
+    pub cp0_status: u64,
+    pub cp0_cause: u64,
+    pub unused: [u64; 7],
+}
+
+/// The size of the `NT_PRSTATUS` regset.
+pub const NT_PRSTATUS_SIZE: usize = 360;
+
+const _: () = assert!(size_of::<UserRegs>() == NT_PRSTATUS_SIZE);
@@ -0,0 +1,527 @@
+//! Decoding syscalls from a tracee's registers
+//!
+//! A ptrace tracer stopped at a syscall (`PTRACE_SYSCALL`,
+//! `PTRACE_EVENT_SECCOMP`) finds the syscall in the tracee's general-purpose
+//! registers, in the same places that the backends in `src/syscall/*` put
+//! it: the number in one register, the arguments in up to six others, and,
+//! at the exit stop, the return value (and on some arches an error flag).
+//!
+//! [`Regs`] is that register set as `PTRACE_GETREGSET` with [`NT_PRSTATUS`]
+//! returns it for the target, viewed as an array of words (`elf_gregset_t`).
+//! [`UserRegs`] is the same set with named fields, and converts to and from
+//! [`Regs`]; the modules named after arches define it for every supported
+//! arch, whatever the target, without libc:
+//!
+//! ```no_run
+//! use rawsys_linux::regs::{self, Regs};
+//!
+//! # fn getregset(_: &mut Regs) {}
+//! let mut regs: Regs = [0; regs::NGREG];
+//! getregset(&mut regs); // ptrace(PTRACE_GETREGSET, pid, NT_PRSTATUS, &iov)
+//! if let Some((sysno, args)) = regs::syscall(&regs) {
+//!     println!("{sysno}({:#x}, ...)", args.arg0);
+//! }
+//! ```
+//!
+//! The number is only reliable at the entry stop, since some arches return
+//! the result in the same register. The first argument is read from the
+//! register the kernel saves it in (`orig_*`) where there is one, so it is
+//! still there at the exit stop; on `aarch64`, `riscv*`, `mips*`, and
+//! `sparc*`, the exit stop has the return value in its place.
+//!
+//! On `mips` (o32), the fifth and sixth arguments are passed on the stack,
+//! at `$sp + 16` and `$sp + 20`, and are reported as 0 here.
+//!
+//! The `set_*` functions go the other way, for tracers that rewrite
+//! syscalls: change the registers, then write them back with
+//! `PTRACE_SETREGSET`. To replace a syscall with a made-up result, call
+//! [`skip_syscall`] at the entry stop and [`set_return_value`] at the exit
+//! stop, which works the same on every arch:
+//!
+//! ```no_run
+//! use rawsys_linux::regs::{self, Regs};
+//! use rawsys_linux::{Errno, Sysno};
+//!
+//! # fn getregset(_: &mut Regs) {}
+//! # fn setregset(_: &Regs) {}
+//! # let mut regs: Regs = [0; regs::NGREG];
+//! // At the entry stop of an `unlink`.
+//! regs::skip_syscall(&mut regs);
+//! setregset(&regs);
+//! // At the exit stop.
+//! getregset(&mut regs);
+//! regs::set_return_value(&mut regs, Err(Errno::EPERM));
+//! setregset(&regs);
+//! ```
+//!
+//! A tracer that rewrites the arguments has to put the original ones back
+//! at the exit stop, before it resumes the tracee: the registers that
+//! aren't clobbered by the syscall are preserved, and the tracee may still
+//! use them, e.g. for the same argument of its next call. Since the first
+//! argument shares its register with the result on some arches, set the
+//! result again afterwards:
+//!
+//! ```no_run
+//! use rawsys_linux::regs::{self, Regs};
+//!
+//! # fn getregset(_: &mut Regs) {}
+//! # fn setregset(_: &Regs) {}
+//! # let mut regs: Regs = [0; regs::NGREG];
+//! // At the entry stop.
+//! let original = regs::syscall_args(&regs);
+//! let mut args = original;
+//! args.arg0 = 0;
+//! regs::set_syscall_args(&mut regs, &args);
+//! setregset(&regs);
+//! // At the exit stop.
+//! getregset(&mut regs);
+//! let ret = regs::return_value(&regs);
+//! regs::set_syscall_args(&mut regs, &original);
+//! regs::set_return_value(&mut regs, ret);
+//! setregset(&regs);
+//! ```
+//!
+//! On `aarch64` and `arm`, the kernel doesn't read the number back from the
+//! registers: it has to be changed with the `NT_ARM_SYSTEM_CALL` regset or
+//! `PTRACE_SET_SYSCALL` as well.
+
+use crate::{Errno, SyscallArgs, SyscallWord, Sysno};
+
+pub mod aarch64;
+pub mod arm;
+pub mod loongarch64;
+pub mod mips;
+pub mod mips64;
+pub mod powerpc;
+pub mod powerpc64;
+pub mod riscv32;
+pub mod riscv64;
+pub mod s390x;
+pub mod sparc;
+pub mod sparc64;
+pub mod x86;
+pub mod x86_64;
+
+#[cfg(target_arch = "aarch64")]
+pub use self::aarch64::UserRegs;
+#[cfg(target_arch = "arm")]
+pub use self::arm::UserRegs;
+#[cfg(target_arch = "loongarch64")]
+pub use self::loongarch64::UserRegs;
+#[cfg(target_arch = "mips")]
+pub use self::mips::UserRegs;
+#[cfg(target_arch = "mips64")]
+pub use self::mips64::UserRegs;
+#[cfg(target_arch = "powerpc")]
+pub use self::powerpc::UserRegs;
+#[cfg(target_arch = "powerpc64")]
+pub use self::powerpc64::UserRegs;
+#[cfg(target_arch = "riscv32")]
+pub use self::riscv32::UserRegs;
+#[cfg(target_arch = "riscv64")]
+pub use self::riscv64::UserRegs;
+#[cfg(target_arch = "s390x")]
+pub use self::s390x::UserRegs;
+#[cfg(target_arch = "sparc")]
+pub use self::sparc::UserRegs;
+#[cfg(target_arch = "sparc64")]
+pub use self::sparc64::UserRegs;
+#[cfg(target_arch = "x86")]
+pub use self::x86::UserRegs;
+#[cfg(target_arch = "x86_64")]
+pub use self::x86_64::UserRegs;
+
+/// The `PTRACE_GETREGSET` regset of the general-purpose registers.
+pub const NT_PRSTATUS: u32 = 1;
+
+/// The tracee's general-purpose registers (`NT_PRSTATUS`), as words.
+pub type Regs = [SyscallWord; NGREG];
+
+// Indices into `Regs` (see the arch's `user_regs_struct` or `ELF_NGREG`
+// layout) of the syscall number, the arguments, and the return value.
+#[cfg(target_arch = "x86_64")]
+mod layout {
+    // r15, r14, r13, r12, rbp, rbx, r11, r10, r9, r8, rax, rcx, rdx, rsi,
+    // rdi, orig_rax, rip, cs, eflags, rsp, ss, fs_base, gs_base, ds, es, fs,
+    // gs.
+    pub const NGREG: usize = 27;
+    pub const NR: usize = 15;
+    pub const ARGS: [usize; 6] = [14, 13, 12, 7, 9, 8];
+    pub const RET: usize = 10;
+}
+#[cfg(target_arch = "x86")]
+mod layout {
+    // ebx, ecx, edx, esi, edi, ebp, eax, ds, es, fs, gs, orig_eax, eip, cs,
+    // eflags, esp, ss.
+    pub const NGREG: usize = 17;
+    pub const NR: usize = 11;
+    pub const ARGS: [usize; 6] = [0, 1, 2, 3, 4, 5];
+    pub const RET: usize = 6;
+}
+#[cfg(target_arch = "aarch64")]
+mod layout {
+    // x0-x30, sp, pc, pstate.
+    pub const NGREG: usize = 34;
+    pub const NR: usize = 8;
+    pub const ARGS: [usize; 6] = [0, 1, 2, 3, 4, 5];
+    pub const RET: usize = 0;
+}
+#[cfg(target_arch = "arm")]
+mod layout {
+    // r0-r15, cpsr, orig_r0.
+    pub const NGREG: usize = 18;
+    pub const NR: usize = 7;
+    pub const ARGS: [usize; 6] = [17, 1, 2, 3, 4, 5];
+    pub const RET: usize = 0;
+}
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+mod layout {
+    // pc, ra, sp, gp, tp, t0-t2, s0, s1, a0-a7, s2-s11, t3-t6.
+    pub const NGREG: usize = 32;
+    pub const NR: usize = 17;
+    pub const ARGS: [usize; 6] = [10, 11, 12, 13, 14, 15];
+    pub const RET: usize = 10;
+}
+#[cfg(target_arch = "loongarch64")]
+mod layout {
+    // r0-r31, orig_a0, csr_era, csr_badv, 10 reserved.
+    pub const NGREG: usize = 45;
+    pub const NR: usize = 11;
+    pub const ARGS: [usize; 6] = [32, 5, 6, 7, 8, 9];
+    pub const RET: usize = 4;
+}
+#[cfg(any(target_arch = "powerpc", target_arch = "powerpc64"))]
+mod layout {
+    // gpr0-gpr31, nip, msr, orig_gpr3, ctr, link, xer, ccr, softe (mq),
+    // trap, dar, dsisr, result, 4 unused.
+    pub const NGREG: usize = 48;
+    pub const NR: usize = 0;
+    pub const ARGS: [usize; 6] = [34, 4, 5, 6, 7, 8];
+    pub const RET: usize = 3;
+    pub const CCR: usize = 38;
+    pub const TRAP: usize = 40;
+}
+#[cfg(target_arch = "s390x")]
+mod layout {
+    // psw mask and address, gprs 0-15, acrs 0-15 (two per word), orig_gpr2.
+    // The kernel puts the number in gpr2 at the entry stop.
+    pub const NGREG: usize = 27;
+    pub const NR: usize = 4;
+    pub const ARGS: [usize; 6] = [26, 5, 6, 7, 8, 9];
+    pub const RET: usize = 4;
+}
+#[cfg(target_arch = "mips")]
+mod layout {
+    // 6 unused, r0-r31, lo, hi, cp0_epc, cp0_badvaddr, cp0_status,
+    // cp0_cause, 1 unused.
+    pub const NGREG: usize = 45;
+    pub const NR: usize = 8;
+    pub const ARGS: [usize; 4] = [10, 11, 12, 13];
+    pub const RET: usize = 8;
+    pub const A3: usize = 13;
+}
+#[cfg(target_arch = "mips64")]
+mod layout {
+    // r0-r31, lo, hi, cp0_epc, cp0_badvaddr, cp0_status, cp0_cause,
+    // 7 unused.
+    pub const NGREG: usize = 45;
+    pub const NR: usize = 2;
+    pub const ARGS: [usize; 6] = [4, 5, 6, 7, 8, 9];
+    pub const RET: usize = 2;
+    pub const A3: usize = 7;
+}
+#[cfg(target_arch = "sparc")]
+mod layout {
+    // g0-g7, o0-o7, l0-l7, i0-i7, psr, pc, npc, y, wim, tbr.
+    pub const NGREG: usize = 38;
+    pub const NR: usize = 1;
+    pub const ARGS: [usize; 6] = [8, 9, 10, 11, 12, 13];
+    pub const RET: usize = 8;
+    pub const FLAGS: usize = 32;
+    /// `PSR_C`
+    pub const CARRY: u32 = 0x0010_0000;
+}
+#[cfg(target_arch = "sparc64")]
+mod layout {
+    // g0-g7, o0-o7, l0-l7, i0-i7, tstate, tpc, tnpc, y.
+    pub const NGREG: usize = 36;
+    pub const NR: usize = 1;
+    pub const ARGS: [usize; 6] = [8, 9, 10, 11, 12, 13];
+    pub const RET: usize = 8;
+    pub const FLAGS: usize = 32;
+    /// `TSTATE_XCARRY | TSTATE_ICARRY`
+    pub const CARRY: u64 = 0x0000_1100_0000_0000;
+}
+
+/// The number of words in [`Regs`] (`ELF_NGREG`).
+pub const NGREG: usize = layout::NGREG;
+
+impl From<Regs> for UserRegs {
+    fn from(regs: Regs) -> Self {
+        // Both are the `NT_PRSTATUS` layout of the target.
+        unsafe { core::mem::transmute(regs) }
+    }
+}
+
+impl From<UserRegs> for Regs {
+    fn from(regs: UserRegs) -> Self {
+        unsafe { core::mem::transmute(regs) }
+    }
+}
+
+/// The regset that holds the number of the syscall the kernel runs, as an
+/// `i32`.
+#[cfg(target_arch = "aarch64")]
+pub const NT_ARM_SYSTEM_CALL: u32 = 0x404;
+
+/// The ptrace request that changes the number of the syscall the kernel
+/// runs, passed as `data`.
+#[cfg(target_arch = "arm")]
+pub const PTRACE_SET_SYSCALL: u32 = 23;
+
+/// The register that the kernel takes the first argument from after the
+/// entry stop, where `layout::ARGS[0]` is a copy it keeps for restarts.
+#[cfg(target_arch = "arm")]
+const ARG0_LIVE: Option<usize> = Some(0);
+#[cfg(any(target_arch = "powerpc", target_arch = "powerpc64"))]
+const ARG0_LIVE: Option<usize> = Some(3);
+#[cfg(not(any(
+    target_arch = "arm",
+    target_arch = "powerpc",
+    target_arch = "powerpc64"
+)))]
+const ARG0_LIVE: Option<usize> = None;
+
+// Every index of the layout is in `Regs`.
+const _: () = {
+    assert!(layout::NR < NGREG && layout::RET < NGREG);
+    let mut i = 0;
+    while i < layout::ARGS.len() {
+        assert!(layout::ARGS[i] < NGREG);
+        i += 1;
+    }
+};
+
+/// The raw syscall number, as the tracee passed it. See [`sysno`] to look
+/// it up.
+pub fn syscall_nr(regs: &Regs) -> SyscallWord {
+    regs[layout::NR]
+}
+
+/// The syscall, or `None` if the number isn't in the table (e.g. -1 to skip
+/// the syscall, or an x32 syscall on `x86_64`).
+pub fn sysno(regs: &Regs) -> Option<Sysno> {
+    Sysno::new(usize::try_from(syscall_nr(regs)).ok()?)
+}
+
+/// The arguments of the syscall.
+pub fn syscall_args(regs: &Regs) -> SyscallArgs {
+    let mut args = [0; 6];
+    for (arg, &index) in args.iter_mut().zip(&layout::ARGS) {
+        *arg = regs[index];
+    }
+    SyscallArgs::from(&args)
+}
+
+/// The syscall and its arguments, see [`sysno`] and [`syscall_args`].
+pub fn syscall(regs: &Regs) -> Option<(Sysno, SyscallArgs)> {
+    Some((sysno(regs)?, syscall_args(regs)))
+}
+
+/// The result of the syscall, at the exit stop.
+pub fn return_value(regs: &Regs) -> Result<SyscallWord, Errno> {
+    let ret = regs[layout::RET];
+    match error_flag(regs) {
+        // The flag says whether `ret` is a (positive) errno.
+        #[allow(clippy::cast_possible_truncation)]
+        Some(true) => Err(Errno::new(ret as i32)),
+        Some(false) => Ok(ret),
+        None => from_ret(ret),
+    }
+}
+
+/// Changes the number of the syscall, at the entry stop. See the module
+/// docs for `aarch64` and `arm`.
+pub fn set_syscall_nr(regs: &mut Regs, nr: SyscallWord) {
+    regs[layout::NR] = nr;
+}
+
+/// Changes the arguments of the syscall, at the entry stop. On `mips`
+/// (o32), the fifth and sixth arguments have to be written to the stack
+/// instead.
+///
+/// The original arguments have to be put back at the exit stop, before the
+/// tracee is resumed, since it may still rely on the registers it passed
+/// them in. See the module docs.
+pub fn set_syscall_args(regs: &mut Regs, args: &SyscallArgs) {
+    let args = [
+        args.arg0, args.arg1, args.arg2, args.arg3, args.arg4, args.arg5,
+    ];
+    for (&arg, &index) in args.iter().zip(&layout::ARGS) {
+        regs[index] = arg;
+    }
+    if let Some(index) = ARG0_LIVE {
+        regs[index] = args[0];
+    }
+}
+
+/// Replaces the syscall with another one, at the entry stop. As with
+/// [`set_syscall_args`], the original arguments have to be put back at the
+/// exit stop.
+pub fn set_syscall(regs: &mut Regs, sysno: Sysno, args: &SyscallArgs) {
+    set_syscall_nr(regs, sysno.id() as SyscallWord);
+    set_syscall_args(regs, args);
+}
+
+/// Makes the kernel skip the syscall, at the entry stop, by changing its
+/// number to -1. The exit stop still follows, where [`set_return_value`]
+/// sets what the tracee sees; otherwise it gets `ENOSYS` or, on some arches,
+/// whatever is in the return register.
+pub fn skip_syscall(regs: &mut Regs) {
+    set_syscall_nr(regs, SyscallWord::MAX);
+}
+
+/// Changes the result of the syscall, at the exit stop, including the
+/// arch's error flag.
+#[allow(clippy::cast_sign_loss)] // Errnos are positive.
+pub fn set_return_value(regs: &mut Regs, ret: Result<SyscallWord, Errno>) {
+    let (value, failed) = match ret {
+        Ok(value) => (value, false),
+        Err(errno) => (errno.into_raw() as SyscallWord, true),
+    };
+    // With an error flag, the errno stays positive.
+    regs[layout::RET] = if set_error_flag(regs, failed) || !failed {
+        value
+    } else {
+        value.wrapping_neg()
+    };
+}
+
+/// Sets or clears the arch's error flag and returns `true`, or returns
+/// `false` if the arch returns a negative errno instead.
+#[allow(clippy::unnecessary_wraps, clippy::needless_pass_by_ref_mut)]
+fn set_error_flag(regs: &mut Regs, failed: bool) -> bool {
+    #[cfg(any(target_arch = "mips", target_arch = "mips64"))]
+    {
+        regs[layout::A3] = failed.into();
+        return true;
+    }
+
+    #[cfg(any(target_arch = "powerpc", target_arch = "powerpc64"))]
+    {
+        if regs[layout::TRAP] == 0x3000 {
+            return false;
+        }
+        if failed {
+            regs[layout::CCR] |= 0x1000_0000;
+        } else {
+            regs[layout::CCR] &= !0x1000_0000;
+        }
+        return true;
+    }
+
+    #[cfg(any(target_arch = "sparc", target_arch = "sparc64"))]
+    {
+        if failed {
+            regs[layout::FLAGS] |= layout::CARRY;
+        } else {
+            regs[layout::FLAGS] &= !layout::CARRY;
+        }
+        return true;
+    }
+
+    #[allow(unreachable_code)]
+    {
+        let _ = (regs, failed);
+        false
+    }
+}
+
+/// Whether the arch's error flag is set, or `None` if the arch returns a
+/// negative errno instead.
+#[allow(clippy::unnecessary_wraps)]
+fn error_flag(regs: &Regs) -> Option<bool> {
+    #[cfg(any(target_arch = "mips", target_arch = "mips64"))]
+    return Some(regs[layout::A3] != 0);
+
+    // `scv` returns negative errnos; `sc` sets the summary overflow bit of
+    // cr0.
+    #[cfg(any(target_arch = "powerpc", target_arch = "powerpc64"))]
+    return (regs[layout::TRAP] != 0x3000)
+        .then_some(regs[layout::CCR] & 0x1000_0000 != 0);
+
+    #[cfg(any(target_arch = "sparc", target_arch = "sparc64"))]
+    return Some(regs[layout::FLAGS] & layout::CARRY != 0);
+
+    #[allow(unreachable_code)]
+    {
+        let _ = regs;
+        None
+    }
+}
+
+/// Converts a negative-errno return value.
+#[allow(clippy::unnecessary_cast)] // `SyscallWord` differs per target.
+fn from_ret(ret: SyscallWord) -> Result<SyscallWord, Errno> {
+    #[cfg(target_pointer_width = "64")]
+    return Errno::from_ret_u64(ret as u64).map(|ret| ret as SyscallWord);
+
+    #[cfg(not(target_pointer_width = "64"))]
+    #[allow(clippy::cast_possible_truncation)]
+    return Errno::from_ret_u32(ret as u32).map(|ret| ret as SyscallWord);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(target_arch = "x86_64")]
+    #[test]
+    fn decodes_x86_64() {
+        let mut regs: Regs = [0; NGREG];
+        // openat(AT_FDCWD, path, O_RDONLY, 0) returning ENOENT.
+        regs[15] = Sysno::openat.id() as u64; // orig_rax
+        regs[14] = (-100i64) as u64; // rdi
+        regs[13] = 0x1000; // rsi
+        regs[10] = (-2i64) as u64; // rax
+
+        let (nr, args) = syscall(&regs).unwrap();
+        assert_eq!(nr, Sysno::openat);
+        assert_eq!(args.arg0 as i64, -100);
+        assert_eq!(args.arg1, 0x1000);
+        assert_eq!(args.arg2, 0);
+        assert_eq!(return_value(&regs), Err(Errno::ENOENT));
+
+        let user = UserRegs::from(regs);
+        assert_eq!(user.orig_rax, Sysno::openat.id() as u64);
+        assert_eq!(user.rsi, 0x1000);
+        assert_eq!(Regs::from(user), regs);
+
+        regs[10] = 3;
+        assert_eq!(return_value(&regs), Ok(3));
+        regs[15] = u64::MAX;
+        assert_eq!(sysno(&regs), None);
+    }
+
+    #[test]
+    fn encodes_syscalls() {
+        let mut regs: Regs = [0; NGREG];
+        let args = SyscallArgs::new(1, 2, 3, 4, 5, 6);
+        set_syscall(&mut regs, Sysno::openat, &args);
+        let (nr, decoded) = syscall(&regs).unwrap();
+        assert_eq!(nr, Sysno::openat);
+        if cfg!(target_arch = "mips") {
+            assert_eq!(decoded, SyscallArgs::new(1, 2, 3, 4, 0, 0));
+        } else {
+            assert_eq!(decoded, args);
+        }
+
+        skip_syscall(&mut regs);
+        assert_eq!(sysno(&regs), None);
+
+        for ret in [Ok(0), Err(Errno::ENOENT), Ok(42), Err(Errno::EPERM)] {
+            set_return_value(&mut regs, ret);
+            assert_eq!(return_value(&regs), ret);
+        }
+    }
+}
@@ -0,0 +1,26 @@
+//! The `NT_PRSTATUS` registers of `powerpc`.
+
+/// The general-purpose registers (`struct pt_regs`).
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct UserRegs {
+    pub gpr: [u32; 32],
+    pub nip: u32,
+    pub msr: u32,
+    pub orig_gpr3: u32,
+    pub ctr: u32,
+    pub link: u32,
+    pub xer: u32,
+    pub ccr: u32,
+    pub mq: u32,
+    pub trap: u32,
+    pub dar: u32,
+    pub dsisr: u32,
+    pub result: u32,
+    pub unused: [u32; 4],
+}
+
+/// The size of the `NT_PRSTATUS` regset.
+pub const NT_PRSTATUS_SIZE: usize = 192;
+
+const _: () = assert!(size_of::<UserRegs>() == NT_PRSTATUS_SIZE);
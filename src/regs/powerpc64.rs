@@ -0,0 +1,26 @@
+//! The `NT_PRSTATUS` registers of `powerpc64`.
+
+/// The general-purpose registers (`struct pt_regs`).
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct UserRegs {
+    pub gpr: [u64; 32],
+    pub nip: u64,
+    pub msr: u64,
+    pub orig_gpr3: u64,
+    pub ctr: u64,
+    pub link: u64,
+    pub xer: u64,
+    pub ccr: u64,
+    pub softe: u64,
+    pub trap: u64,
+    pub dar: u64,
+    pub dsisr: u64,
+    pub result: u64,
+    pub unused: [u64; 4],
+}
+
+/// The size of the `NT_PRSTATUS` regset.
+pub const NT_PRSTATUS_SIZE: usize = 384;
+
+const _: () = assert!(size_of::<UserRegs>() == NT_PRSTATUS_SIZE);
@@ -0,0 +1,44 @@
+//! The `NT_PRSTATUS` registers of `riscv32`.
+
+/// The general-purpose registers (`struct user_regs_struct`).
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct UserRegs {
+    pub pc: u32,
+    pub ra: u32,
+    pub sp: u32,
+    pub gp: u32,
+    pub tp: u32,
+    pub t0: u32,
+    pub t1: u32,
+    pub t2: u32,
+    pub s0: u32,
+    pub s1: u32,
+    pub a0: u32,
+    pub a1: u32,
+    pub a2: u32,
+    pub a3: u32,
+    pub a4: u32,
+    pub a5: u32,
+    pub a6: u32,
+    pub a7: u32,
+    pub s2: u32,
+    pub s3: u32,
+    pub s4: u32,
+    pub s5: u32,
+    pub s6: u32,
+    pub s7: u32,
+    pub s8: u32,
+    pub s9: u32,
+    pub s10: u32,
+    pub s11: u32,
+    pub t3: u32,
+    pub t4: u32,
+    pub t5: u32,
+    pub t6: u32,
+}
+
+/// The size of the `NT_PRSTATUS` regset.
+pub const NT_PRSTATUS_SIZE: usize = 128;
+
+const _: () = assert!(size_of::<UserRegs>() == NT_PRSTATUS_SIZE);
@@ -0,0 +1,17 @@
+//! The `NT_PRSTATUS` registers of `s390x`.
+
+/// The general-purpose registers (`s390_regs`).
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct UserRegs {
+    pub psw_mask: u64,
+    pub psw_addr: u64,
+    pub gprs: [u64; 16],
+    pub acrs: [u32; 16],
+    pub orig_gpr2: u64,
+}
+
+/// The size of the `NT_PRSTATUS` regset.
+pub const NT_PRSTATUS_SIZE: usize = 216;
+
+const _: () = assert!(size_of::<UserRegs>() == NT_PRSTATUS_SIZE);
@@ -0,0 +1,22 @@
+//! The `NT_PRSTATUS` registers of `sparc`.
+
+/// The general-purpose registers (`elf_gregset_t`).
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct UserRegs {
+    pub g: [u32; 8],
+    pub o: [u32; 8],
+    pub l: [u32; 8],
+    pub i: [u32; 8],
+    pub psr: u32,
+    pub pc: u32,
+    pub npc: u32,
+    pub y: u32,
+    pub wim: u32,
+    pub tbr: u32,
+}
+
+/// The size of the `NT_PRSTATUS` regset.
+pub const NT_PRSTATUS_SIZE: usize = 152;
+
+const _: () = assert!(size_of::<UserRegs>() == NT_PRSTATUS_SIZE);
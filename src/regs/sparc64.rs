@@ -0,0 +1,20 @@
+//! The `NT_PRSTATUS` registers of `sparc64`.
+
+/// The general-purpose registers (`elf_gregset_t`).
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct UserRegs {
+    pub g: [u64; 8],
+    pub o: [u64; 8],
+    pub l: [u64; 8],
+    pub i: [u64; 8],
+    pub tstate: u64,
+    pub tpc: u64,
+    pub tnpc: u64,
+    pub y: u64,
+}
+
+/// The size of the `NT_PRSTATUS` regset.
+pub const NT_PRSTATUS_SIZE: usize = 288;
+
+const _: () = assert!(size_of::<UserRegs>() == NT_PRSTATUS_SIZE);
@@ -0,0 +1,29 @@
+//! The `NT_PRSTATUS` registers of `x86`.
+
+/// The general-purpose registers (`struct user_regs_struct`).
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct UserRegs {
+    pub ebx: u32,
+    pub ecx: u32,
+    pub edx: u32,
+    pub esi: u32,
+    pub edi: u32,
+    pub ebp: u32,
+    pub eax: u32,
+    pub xds: u32,
+    pub xes: u32,
+    pub xfs: u32,
+    pub xgs: u32,
+    pub orig_eax: u32,
+    pub eip: u32,
+    pub xcs: u32,
+    pub eflags: u32,
+    pub esp: u32,
+    pub xss: u32,
+}
+
+/// The size of the `NT_PRSTATUS` regset.
+pub const NT_PRSTATUS_SIZE: usize = 68;
+
+const _: () = assert!(size_of::<UserRegs>() == NT_PRSTATUS_SIZE);
@@ -0,0 +1,279 @@
+//! Memory of other processes
+//!
+//! Syscall arguments are often pointers, and a tracer or seccomp supervisor
+//! has to read what they point to in the memory of the process that made the
+//! syscall. [`RemoteMem`] reads and writes that memory, with two
+//! implementations:
+//!
+//! - [`ProcessVm`] uses `process_vm_readv`/`process_vm_writev`, which need no
+//!   fd but can't write to read-only mappings.
+//! - [`ProcMem`] uses `/proc/<pid>/mem`, whose fd can be kept and passed to
+//!   another process, and which writes to read-only mappings like
+//!   `PTRACE_POKEDATA` does (e.g. for breakpoints).
+//!
+//! Both need the same permissions as attaching with ptrace.
+//!
+//! ```no_run
+//! use rawsys_linux::id::Pid;
+//! use rawsys_linux::remote::{ProcessVm, RemoteMem};
+//!
+//! # let (pid, addr) = (Pid::from_raw(0), 0);
+//! // The path argument of an `openat` call.
+//! let mut buf = [0u8; 4096];
+//! let path = ProcessVm::new(pid).read_cstr(addr, &mut buf)?;
+//! # Ok::<(), rawsys_linux::Errno>(())
+//! ```
+//!
+//! Unless the process is stopped, its memory can change between the syscall
+//! and the read, so what is read must not be trusted to be what the syscall
+//! uses. A seccomp supervisor must also check that the notification is still
+//! valid after reading (see
+//! [`Supervisor::id_valid`](crate::seccomp::Supervisor::id_valid)), since the pid may have
+//! been reused.
+
+use core::ffi::CStr;
+
+use crate::Errno;
+#[cfg(not(rawsys_backend = "none"))]
+use crate::id::{Pid, Tid};
+#[cfg(not(rawsys_backend = "none"))]
+use crate::iovec::{IoVec, IoVecMut, RawIoVec};
+#[cfg(not(rawsys_backend = "none"))]
+use crate::{SyscallWord, Sysno, syscall1, syscall5, syscall6};
+
+/// Reads and writes the memory of a process. See the module docs.
+pub trait RemoteMem {
+    /// Reads the memory at `addr` into `buf`, and returns the number of
+    /// bytes read, which is less than `buf.len()` if the range runs into
+    /// memory that isn't mapped. Fails if `addr` itself isn't mapped.
+    fn read(&self, addr: u64, buf: &mut [u8]) -> Result<usize, Errno>;
+
+    /// Writes `buf` to the memory at `addr`, and returns the number of bytes
+    /// written, like [`read`](Self::read).
+    fn write(&self, addr: u64, buf: &[u8]) -> Result<usize, Errno>;
+
+    /// Fills `buf` with the memory at `addr`, or fails with `EFAULT` if that
+    /// isn't all mapped.
+    fn read_exact(&self, addr: u64, buf: &mut [u8]) -> Result<(), Errno> {
+        let mut done = 0;
+        while done < buf.len() {
+            match self.read(addr + done as u64, &mut buf[done..])? {
+                0 => return Err(Errno::EFAULT),
+                read => done += read,
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads the NUL-terminated string at `addr` into `buf`. Fails with
+    /// `ENAMETOOLONG` if it doesn't fit (with its NUL), and with `EFAULT` if
+    /// it runs into memory that isn't mapped.
+    ///
+    /// The string is read a page at a time, so memory past its end is only
+    /// read within the page of the NUL.
+    fn read_cstr<'a>(
+        &self,
+        addr: u64,
+        buf: &'a mut [u8],
+    ) -> Result<&'a CStr, Errno> {
+        // The smallest page size of any arch.
+        const PAGE: u64 = 4096;
+
+        let mut len = 0;
+        while len < buf.len() {
+            let at = addr + len as u64;
+            let chunk = (buf.len() - len).min((PAGE - at % PAGE) as usize);
+            let read = self.read(at, &mut buf[len..len + chunk])?;
+            if read == 0 {
+                return Err(Errno::EFAULT);
+            }
+            if let Some(nul) = buf[len..len + read].iter().position(|&c| c == 0)
+            {
+                let end = len + nul + 1;
+                return Ok(CStr::from_bytes_with_nul(&buf[..end]).unwrap());
+            }
+            len += read;
+        }
+        Err(Errno::ENAMETOOLONG)
+    }
+}
+
+#[cfg(not(rawsys_backend = "none"))]
+/// The `struct iovec` of `len` bytes at `addr` in another process.
+fn remote_iovec(addr: u64, len: usize) -> Result<RawIoVec, Errno> {
+    let base = usize::try_from(addr).map_err(|_| Errno::EFAULT)?;
+    Ok(RawIoVec { base, len })
+}
+
+#[cfg(not(rawsys_backend = "none"))]
+/// Accesses the memory of `pid` with `process_vm_readv` and
+/// `process_vm_writev` (Linux 3.2).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProcessVm {
+    pid: Pid,
+}
+
+#[cfg(not(rawsys_backend = "none"))]
+impl ProcessVm {
+    /// Accesses the memory of `pid`, without checking anything yet.
+    pub const fn new(pid: Pid) -> Self {
+        Self { pid }
+    }
+
+    /// The pid whose memory is accessed.
+    pub const fn pid(&self) -> Pid {
+        self.pid
+    }
+
+    fn transfer(
+        &self,
+        sysno: Sysno,
+        local: &RawIoVec,
+        remote: &RawIoVec,
+    ) -> Result<usize, Errno> {
+        let transferred = unsafe {
+            syscall6(
+                sysno,
+                SyscallWord::from(self.pid),
+                core::ptr::from_ref(local) as SyscallWord,
+                1,
+                core::ptr::from_ref(remote) as SyscallWord,
+                1,
+                0,
+            )
+        }?;
+        Ok(transferred as usize)
+    }
+}
+
+#[cfg(not(rawsys_backend = "none"))]
+impl RemoteMem for ProcessVm {
+    fn read(&self, addr: u64, buf: &mut [u8]) -> Result<usize, Errno> {
+        let local = IoVecMut::new(buf).as_raw();
+        let remote = remote_iovec(addr, local.len)?;
+        self.transfer(Sysno::process_vm_readv, &local, &remote)
+    }
+
+    fn write(&self, addr: u64, buf: &[u8]) -> Result<usize, Errno> {
+        let local = IoVec::new(buf).as_raw();
+        let remote = remote_iovec(addr, local.len)?;
+        self.transfer(Sysno::process_vm_writev, &local, &remote)
+    }
+}
+
+#[cfg(not(rawsys_backend = "none"))]
+/// Accesses the memory of a process through an fd of its `/proc/<pid>/mem`.
+/// The fd is closed on drop.
+///
+/// Reading memory that isn't mapped fails with `EIO` rather than `EFAULT`.
+#[derive(Debug)]
+pub struct ProcMem {
+    fd: i32,
+}
+
+#[cfg(not(rawsys_backend = "none"))]
+impl ProcMem {
+    /// Opens `/proc/<pid>/mem` for reading and writing.
+    pub fn open(pid: Pid) -> Result<Self, Errno> {
+        const O_RDWR: SyscallWord = 2;
+        let fd = crate::procfs::open(Tid::main_thread(pid), b"mem", O_RDWR)?;
+        Ok(Self { fd: fd as i32 })
+    }
+
+    /// Takes ownership of an fd of a `/proc/<pid>/mem` file.
+    ///
+    /// # Safety
+    ///
+    /// `fd` must be an open fd that nothing else closes.
+    pub const unsafe fn from_raw_fd(fd: i32) -> Self {
+        Self { fd }
+    }
+
+    /// Returns the fd.
+    pub const fn as_raw_fd(&self) -> i32 {
+        self.fd
+    }
+
+    fn transfer(
+        &self,
+        sysno: Sysno,
+        iov: &RawIoVec,
+        addr: u64,
+    ) -> Result<usize, Errno> {
+        // The offset is passed as two words, of which the high one is
+        // ignored on 64-bit targets.
+        let transferred = unsafe {
+            syscall5(
+                sysno,
+                self.fd as SyscallWord,
+                core::ptr::from_ref(iov) as SyscallWord,
+                1,
+                addr as SyscallWord,
+                (addr >> 32) as SyscallWord,
+            )
+        }?;
+        Ok(transferred as usize)
+    }
+}
+
+#[cfg(not(rawsys_backend = "none"))]
+impl RemoteMem for ProcMem {
+    fn read(&self, addr: u64, buf: &mut [u8]) -> Result<usize, Errno> {
+        let iov = IoVecMut::new(buf).as_raw();
+        self.transfer(Sysno::preadv, &iov, addr)
+    }
+
+    fn write(&self, addr: u64, buf: &[u8]) -> Result<usize, Errno> {
+        let iov = IoVec::new(buf).as_raw();
+        self.transfer(Sysno::pwritev, &iov, addr)
+    }
+}
+
+#[cfg(not(rawsys_backend = "none"))]
+impl Drop for ProcMem {
+    fn drop(&mut self) {
+        let _ = unsafe { syscall1(Sysno::close, self.fd as SyscallWord) };
+    }
+}
+
+#[cfg(all(test, not(rawsys_backend = "none")))]
+mod tests {
+    use super::*;
+
+    static HELLO: &[u8] = b"hello\0world";
+
+    fn own_pid() -> Pid {
+        Pid::from_raw(unsafe { crate::syscall0(Sysno::getpid) }.unwrap() as i32)
+    }
+
+    fn check(mem: &impl RemoteMem, unmapped: Errno) {
+        let addr = HELLO.as_ptr() as u64;
+        let mut buf = [0u8; 16];
+        assert_eq!(mem.read(addr, &mut buf[..11]), Ok(11));
+        assert_eq!(&buf[..11], HELLO);
+        assert_eq!(mem.read_cstr(addr, &mut buf), Ok(c"hello"));
+        assert_eq!(
+            mem.read_cstr(addr + 6, &mut [0; 5]),
+            Err(Errno::ENAMETOOLONG)
+        );
+        assert_eq!(mem.read(0, &mut buf), Err(unmapped));
+        assert_eq!(mem.read_cstr(0, &mut buf), Err(unmapped));
+
+        let mut target = [0u8; 4];
+        mem.write(target.as_mut_ptr() as u64, b"abcd").unwrap();
+        assert_eq!(core::hint::black_box(&target), b"abcd");
+        let mut out = [0u8; 4];
+        mem.read_exact(target.as_ptr() as u64, &mut out).unwrap();
+        assert_eq!(&out, b"abcd");
+    }
+
+    #[test]
+    fn process_vm() {
+        check(&ProcessVm::new(own_pid()), Errno::EFAULT);
+    }
+
+    #[test]
+    fn proc_mem() {
+        check(&ProcMem::open(own_pid()).unwrap(), Errno::EIO);
+    }
+}
@@ -0,0 +1,270 @@
+//! Restartable sequences (`rseq`)
+//!
+//! A thread registers an [`Rseq`] area with the kernel, which then keeps the
+//! `cpu_id` fields up to date on every return to user space and aborts any
+//! critical section described by `rseq_cs` that gets preempted. Reading the
+//! current CPU from that area is much cheaper than `getcpu`.
+//!
+//! - [`Rseq`] and [`RseqCs`] mirror the kernel's `struct rseq` and
+//!   `struct rseq_cs`.
+//! - [`register`]/[`unregister`] wrap `Sysno::rseq` for a caller-owned area.
+//! - With `std`, [`current_cpu`] registers a per-thread area on first use and
+//!   falls back to `getcpu` when that is not possible.
+//!
+//! Only one area can be registered per thread. glibc 2.35+ registers its own
+//! (exported as `__rseq_offset`/`__rseq_size`) unless disabled with
+//! `GLIBC_TUNABLES=glibc.pthread.rseq=0`, in which case [`register`] fails
+//! with `EBUSY` or `EINVAL`.
+//!
+//! Abort handlers must be preceded by the [`RSEQ_SIG`] signature, and the same
+//! value has to be passed at registration. [`RSEQ_SIG`] uses the values
+//! established by librseq and glibc, so areas registered by either are
+//! compatible with critical sections written against this crate.
+
+use core::cell::UnsafeCell;
+use core::ptr;
+
+use crate::{Errno, Sysno, syscall4};
+
+/// `cpu_id` value before the area has been registered.
+pub const RSEQ_CPU_ID_UNINITIALIZED: i32 = -1;
+/// `cpu_id` value after a failed registration.
+pub const RSEQ_CPU_ID_REGISTRATION_FAILED: i32 = -2;
+
+/// `flags` for [`unregister`].
+const RSEQ_FLAG_UNREGISTER: usize = 1;
+
+/// Signature that must precede abort handlers (`__rseq_abi` convention).
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub const RSEQ_SIG: u32 = 0x5305_3053;
+/// Signature that must precede abort handlers (`__rseq_abi` convention).
+#[cfg(target_arch = "aarch64")]
+pub const RSEQ_SIG: u32 = 0xd428_bc00;
+/// Signature that must precede abort handlers (`__rseq_abi` convention).
+#[cfg(target_arch = "arm")]
+pub const RSEQ_SIG: u32 = 0xe7f5_def3;
+/// Signature that must precede abort handlers (`__rseq_abi` convention).
+#[cfg(any(target_arch = "powerpc", target_arch = "powerpc64"))]
+pub const RSEQ_SIG: u32 = 0x0fe5_000b;
+/// Signature that must precede abort handlers (`__rseq_abi` convention).
+#[cfg(any(target_arch = "mips", target_arch = "mips64"))]
+pub const RSEQ_SIG: u32 = 0x0350_000d;
+/// Signature that must precede abort handlers (`__rseq_abi` convention).
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+pub const RSEQ_SIG: u32 = 0xf140_1073;
+/// Signature that must precede abort handlers (`__rseq_abi` convention).
+#[cfg(target_arch = "s390x")]
+pub const RSEQ_SIG: u32 = 0xb2ff_0fff;
+/// Signature that must precede abort handlers (`__rseq_abi` convention).
+#[cfg(target_arch = "loongarch64")]
+pub const RSEQ_SIG: u32 = 0x002a_0010;
+
+/// Describes a restartable critical section (`struct rseq_cs`).
+#[repr(C, align(32))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RseqCs {
+    /// Structure version, currently 0.
+    pub version: u32,
+    /// `RSEQ_CS_FLAG_*`.
+    pub flags: u32,
+    /// First instruction of the critical section.
+    pub start_ip: u64,
+    /// Length of the critical section, in bytes.
+    pub post_commit_offset: u64,
+    /// Abort handler; must be preceded by [`RSEQ_SIG`].
+    pub abort_ip: u64,
+}
+
+/// The per-thread area shared with the kernel (`struct rseq`).
+///
+/// The kernel writes to it asynchronously, so the fields are interior-mutable
+/// and only accessed with volatile loads and stores through the accessors.
+#[repr(C, align(32))]
+#[derive(Debug)]
+#[allow(clippy::struct_field_names)] // Named after the kernel's fields.
+pub struct Rseq {
+    cpu_id_start: UnsafeCell<u32>,
+    cpu_id: UnsafeCell<u32>,
+    rseq_cs: UnsafeCell<u64>,
+    flags: UnsafeCell<u32>,
+    node_id: UnsafeCell<u32>,
+    mm_cid: UnsafeCell<u32>,
+}
+
+impl Rseq {
+    /// Size passed to the kernel. This is the original 32-byte layout, which
+    /// every kernel with rseq support accepts.
+    pub const LEN: u32 = 32;
+
+    /// Creates an unregistered area.
+    pub const fn new() -> Self {
+        Self {
+            cpu_id_start: UnsafeCell::new(0),
+            cpu_id: UnsafeCell::new(RSEQ_CPU_ID_UNINITIALIZED as u32),
+            rseq_cs: UnsafeCell::new(0),
+            flags: UnsafeCell::new(0),
+            node_id: UnsafeCell::new(0),
+            mm_cid: UnsafeCell::new(0),
+        }
+    }
+
+    /// Returns the CPU the thread is running on, or `None` if the area is not
+    /// registered.
+    #[inline]
+    pub fn cpu_id(&self) -> Option<u32> {
+        let cpu = unsafe { ptr::read_volatile(self.cpu_id.get()) };
+        if (cpu as i32) < 0 { None } else { Some(cpu) }
+    }
+
+    /// Like [`Rseq::cpu_id`], but always a valid CPU number (0 before
+    /// registration). Meant to be read at the start of a critical section.
+    #[inline]
+    pub fn cpu_id_start(&self) -> u32 {
+        unsafe { ptr::read_volatile(self.cpu_id_start.get()) }
+    }
+
+    /// NUMA node of the current CPU (Linux 6.3+, 0 on older kernels).
+    #[inline]
+    pub fn node_id(&self) -> u32 {
+        unsafe { ptr::read_volatile(self.node_id.get()) }
+    }
+
+    /// Concurrency ID of the thread within its memory map (Linux 6.3+, 0 on
+    /// older kernels).
+    #[inline]
+    pub fn mm_cid(&self) -> u32 {
+        unsafe { ptr::read_volatile(self.mm_cid.get()) }
+    }
+
+    /// Points the area at a critical section descriptor, or clears it with
+    /// `null`.
+    ///
+    /// # Safety
+    ///
+    /// `cs` must stay valid while it is set, and its addresses must describe
+    /// a correct critical section.
+    #[inline]
+    pub unsafe fn set_rseq_cs(&self, cs: *const RseqCs) {
+        unsafe {
+            ptr::write_volatile(self.rseq_cs.get(), cs as usize as u64);
+        }
+    }
+}
+
+impl Default for Rseq {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Registers `rseq` for the calling thread with the given signature.
+///
+/// # Safety
+///
+/// The area must stay at the same address, and must not be freed, until it
+/// is unregistered or the thread exits.
+pub unsafe fn register(rseq: *const Rseq, sig: u32) -> Result<(), Errno> {
+    unsafe { syscall4(Sysno::rseq, rseq as _, Rseq::LEN.into(), 0, sig.into()) }
+        .map(drop)
+}
+
+/// Unregisters `rseq` for the calling thread. `sig` must match the one used
+/// for registration.
+///
+/// # Safety
+///
+/// `rseq` must be the area registered for this thread.
+pub unsafe fn unregister(rseq: *const Rseq, sig: u32) -> Result<(), Errno> {
+    unsafe {
+        syscall4(
+            Sysno::rseq,
+            rseq as _,
+            Rseq::LEN.into(),
+            RSEQ_FLAG_UNREGISTER as _,
+            sig.into(),
+        )
+    }
+    .map(drop)
+}
+
+// sparc has no rseq support (and so no signature).
+#[cfg(all(
+    feature = "std",
+    not(any(target_arch = "sparc", target_arch = "sparc64"))
+))]
+std::thread_local! {
+    static AREA: Rseq = const { Rseq::new() };
+    static REGISTERED: core::cell::Cell<Option<bool>> =
+        const { core::cell::Cell::new(None) };
+}
+
+/// Returns the CPU the calling thread is running on.
+///
+/// The first call on each thread tries to register a thread-local [`Rseq`]
+/// area (with [`RSEQ_SIG`]); later calls read it directly. If registration is
+/// not possible (e.g. libc already registered an area), `getcpu` is used
+/// instead.
+#[cfg(all(
+    feature = "std",
+    not(any(target_arch = "sparc", target_arch = "sparc64"))
+))]
+pub fn current_cpu() -> Result<u32, Errno> {
+    let registered = REGISTERED.with(|registered| {
+        registered.get().unwrap_or_else(|| {
+            let ok = AREA.with(|area| unsafe {
+                register(core::ptr::from_ref(area), RSEQ_SIG).is_ok()
+            });
+            registered.set(Some(ok));
+            ok
+        })
+    });
+
+    if let Some(cpu) = registered.then(|| AREA.with(Rseq::cpu_id)).flatten() {
+        return Ok(cpu);
+    }
+
+    let mut cpu: u32 = 0;
+    unsafe { crate::syscall3(Sysno::getcpu, &raw mut cpu as _, 0, 0) }?;
+    Ok(cpu)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layout() {
+        assert_eq!(core::mem::size_of::<Rseq>(), Rseq::LEN as usize);
+        assert_eq!(core::mem::align_of::<Rseq>(), 32);
+        assert_eq!(core::mem::size_of::<RseqCs>(), 32);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn current_cpu_works() {
+        // The second call takes the registered (or fallback) fast path.
+        current_cpu().unwrap();
+        current_cpu().unwrap();
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn register_or_busy() {
+        std::thread::spawn(|| {
+            let area = Box::leak(Box::new(Rseq::new()));
+            match unsafe { register(area, RSEQ_SIG) } {
+                Ok(()) => {
+                    assert!(area.cpu_id().is_some());
+                    unsafe { unregister(area, RSEQ_SIG) }.unwrap();
+                }
+                // Already registered by libc.
+                Err(err) => {
+                    assert!(err == Errno::EBUSY || err == Errno::EINVAL);
+                    assert_eq!(area.cpu_id(), None);
+                }
+            }
+        })
+        .join()
+        .unwrap();
+    }
+}
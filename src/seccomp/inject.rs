@@ -0,0 +1,214 @@
+//! Error injection for tests
+//!
+//! [`ErrnoInjection`] makes syscalls fail with a chosen errno, so that tests
+//! can check how real code paths handle e.g. `write` failing with `ENOSPC`
+//! or `openat` with `EMFILE`:
+//!
+//! ```no_run
+//! use rawsys_linux::seccomp::ErrnoInjection;
+//! use rawsys_linux::{Errno, Sysno, SysnoSet};
+//! use std::io::Write;
+//!
+//! let mut file = std::fs::File::create("/tmp/out").unwrap();
+//! let set = SysnoSet::new(&[Sysno::write]);
+//! let injection =
+//!     unsafe { ErrnoInjection::current_thread(&set, Errno::ENOSPC) }.unwrap();
+//! assert!(file.write_all(b"data").is_err());
+//! drop(injection);
+//! file.write_all(b"data").unwrap();
+//! ```
+//!
+//! A seccomp filter can't be removed, so the filter sends the syscalls to a
+//! supervisor thread ([`Action::UserNotif`]) instead of failing them itself.
+//! The supervisor fails them while an [`ErrnoInjection`] for them exists,
+//! and lets them run otherwise, which keeps costing a round trip to the
+//! supervisor.
+//!
+//! A process can only have one filter with a supervisor, so the filter is
+//! installed by the first injection, for its syscalls. Later injections
+//! share it, and can only fail syscalls that it covers. Injections can
+//! overlap, in which case the last one made for a syscall wins.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, PoisonError, mpsc};
+
+use super::{
+    Action, Program, SECCOMP_FILTER_FLAG_TSYNC,
+    SECCOMP_FILTER_FLAG_TSYNC_ESRCH, SeccompNotif, SeccompNotifResp,
+    Supervisor, no_new_privs,
+};
+use crate::{Errno, Sysno, SysnoMap, SysnoSet};
+
+/// The injections that the supervisor applies.
+type Rules = Arc<Mutex<Vec<Rule>>>;
+
+/// The installed filter: the syscalls it covers and its rules.
+static FILTER: Mutex<Option<(SysnoSet, Rules)>> = Mutex::new(None);
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+/// An injection, as applied by the supervisor.
+#[derive(Debug)]
+struct Rule {
+    id: u64,
+    syscalls: SysnoSet,
+    errno: Errno,
+    /// The only thread to fail the syscalls of, if any.
+    tid: Option<u32>,
+}
+
+impl Rule {
+    fn matches(&self, notif: &SeccompNotif) -> bool {
+        notif
+            .sysno()
+            .is_some_and(|sysno| self.syscalls.contains(sysno))
+            && self.tid.is_none_or(|tid| tid == notif.pid)
+    }
+}
+
+/// Makes syscalls fail with an errno until it is dropped. See the module
+/// docs.
+#[derive(Debug)]
+pub struct ErrnoInjection {
+    id: u64,
+    rules: Rules,
+}
+
+impl ErrnoInjection {
+    /// Makes `syscalls` fail with `errno` in the calling thread. The other
+    /// threads (e.g. other tests) run them as usual.
+    ///
+    /// # Safety
+    ///
+    /// See [`all_threads`](Self::all_threads).
+    pub unsafe fn current_thread(
+        syscalls: &SysnoSet,
+        errno: Errno,
+    ) -> Result<Self, Errno> {
+        let tid = unsafe { crate::syscall0(Sysno::gettid) }?;
+        unsafe { Self::install(syscalls, errno, Some(tid as u32)) }
+    }
+
+    /// Makes `syscalls` fail with `errno` in all threads of the process.
+    ///
+    /// The first injection sets `no_new_privs`, starts the supervisor
+    /// thread, and installs the filter for all threads, all of which stay
+    /// for the life of the process. Later injections fail with `EBUSY` if
+    /// the filter doesn't cover all of `syscalls`.
+    ///
+    /// # Safety
+    ///
+    /// The supervisor thread goes through the filter as well, so `syscalls`
+    /// must not include those it makes while waiting for and answering
+    /// notifications, such as `ioctl` and `futex`, nor those made to
+    /// allocate memory. The code under test must cope with the failures,
+    /// see [`Program::install`].
+    pub unsafe fn all_threads(
+        syscalls: &SysnoSet,
+        errno: Errno,
+    ) -> Result<Self, Errno> {
+        unsafe { Self::install(syscalls, errno, None) }
+    }
+
+    /// Fails `syscalls` of the thread `tid`, or of all threads.
+    unsafe fn install(
+        syscalls: &SysnoSet,
+        errno: Errno,
+        tid: Option<u32>,
+    ) -> Result<Self, Errno> {
+        let mut filter = FILTER.lock().unwrap_or_else(PoisonError::into_inner);
+        let rules = if let Some((covered, rules)) = &*filter {
+            if !syscalls.clone().difference(covered).is_empty() {
+                return Err(Errno::EBUSY);
+            }
+            Arc::clone(rules)
+        } else {
+            let rules = unsafe { install_filter(syscalls) }?;
+            *filter = Some((syscalls.clone(), Arc::clone(&rules)));
+            rules
+        };
+
+        let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+        rules
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(Rule {
+                id,
+                syscalls: syscalls.clone(),
+                errno,
+                tid,
+            });
+        Ok(Self { id, rules })
+    }
+}
+
+impl Drop for ErrnoInjection {
+    fn drop(&mut self) {
+        let mut rules =
+            self.rules.lock().unwrap_or_else(PoisonError::into_inner);
+        rules.retain(|rule| rule.id != self.id);
+    }
+}
+
+/// Installs the filter for all threads, with a supervisor thread that
+/// applies the returned rules.
+unsafe fn install_filter(syscalls: &SysnoSet) -> Result<Rules, Errno> {
+    let mut overrides = SysnoMap::new();
+    for sysno in syscalls {
+        overrides.insert(sysno, Action::UserNotif);
+    }
+    let program =
+        Program::compile(&SysnoSet::empty(), Action::Allow, &overrides);
+
+    // The thread is started before the filter is installed, which would
+    // block any syscalls of the set that starting it makes.
+    let rules = Arc::new(Mutex::new(Vec::new()));
+    let (sender, receiver) = mpsc::channel::<Supervisor>();
+    std::thread::spawn({
+        let rules = Arc::clone(&rules);
+        move || {
+            if let Ok(supervisor) = receiver.recv() {
+                supervise(&supervisor, &rules);
+            }
+        }
+    });
+
+    no_new_privs()?;
+    let supervisor = unsafe {
+        Supervisor::install(
+            &program,
+            SECCOMP_FILTER_FLAG_TSYNC | SECCOMP_FILTER_FLAG_TSYNC_ESRCH,
+        )
+    }?;
+    // Without a supervisor, the syscalls of the set would block forever.
+    sender
+        .send(supervisor)
+        .expect("the supervisor thread is waiting");
+    Ok(rules)
+}
+
+/// Answers the notifications of the filter for the rest of the process.
+fn supervise(supervisor: &Supervisor, rules: &Mutex<Vec<Rule>>) {
+    loop {
+        let notif = match supervisor.recv() {
+            Ok(notif) => notif,
+            // The caller went away.
+            Err(Errno::ENOENT | Errno::EINTR) => continue,
+            Err(_) => return,
+        };
+        let errno = rules
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .rev()
+            .find(|rule| rule.matches(&notif))
+            .map(|rule| rule.errno);
+        let resp = match errno {
+            Some(errno) => SeccompNotifResp::errno(notif.id, errno),
+            // Nothing is decided by looking at the arguments, so continuing
+            // is fine here.
+            None => SeccompNotifResp::continue_syscall(notif.id),
+        };
+        let _ = supervisor.send(&resp);
+    }
+}
@@ -0,0 +1,1070 @@
+//! seccomp filters
+//!
+//! A seccomp filter is a classic BPF program that the kernel runs on every
+//! syscall of a thread (and of the threads and processes it creates). It
+//! looks at the syscall number, the calling convention, and the arguments,
+//! and returns what to do: run the syscall, fail it with an errno, kill the
+//! process, and so on.
+//!
+//! [`Program::compile`] builds such a program from the crate's tables: the
+//! syscalls of a [`SysnoSet`] are allowed, a [`SysnoMap`] can give other
+//! syscalls actions of their own, and everything else gets a default action.
+//! The program first checks that the syscall was made with the target's
+//! calling convention ([`AUDIT_ARCH`]), since the same number means different
+//! syscalls in different conventions (e.g. i386 syscalls made from an
+//! `x86_64` process), and kills the process otherwise.
+//!
+//! ```no_run
+//! use rawsys_linux::seccomp::{Action, Program};
+//! use rawsys_linux::{Errno, Sysno, SysnoMap, SysnoSet};
+//!
+//! let allowed =
+//!     SysnoSet::new(&[Sysno::read, Sysno::write, Sysno::exit_group]);
+//! let mut overrides = SysnoMap::new();
+//! overrides.insert(Sysno::openat, Action::Errno(Errno::EACCES));
+//!
+//! let program = Program::compile(&allowed, Action::KillProcess, &overrides);
+//! unsafe { program.install() }.unwrap();
+//! ```
+//!
+//! [`Program::install`] covers the common case. [`no_new_privs`] and
+//! [`install`] take it apart, e.g. to install a filter for all threads
+//! ([`SECCOMP_FILTER_FLAG_TSYNC`]) or to get the listener fd for
+//! [`Action::UserNotif`] ([`SECCOMP_FILTER_FLAG_NEW_LISTENER`]).
+//! `Supervisor` wraps that fd: it receives the syscalls sent to it as
+//! [`SeccompNotif`]s and answers them with a [`SeccompNotifResp`], or by
+//! installing an fd in the caller ([`SeccompNotifAddfd`]). With `std`,
+//! `ErrnoInjection` uses a supervisor to fail syscalls in tests.
+//!
+//! [`Filter`] builds the same programs and can also look at the arguments:
+//! a [`Rule`] gives a syscall an action when its arguments pass some
+//! comparisons ([`ArgCmp`]), e.g. to allow `ioctl` only for `TCGETS`:
+//!
+//! ```
+//! use rawsys_linux::seccomp::{Action, ArgCmp, Filter, Rule};
+//! use rawsys_linux::{Errno, Sysno, SysnoSet};
+//!
+//! const TCGETS: u64 = 0x5401;
+//! static RULES: &[Rule] = &[Rule::new(
+//!     Sysno::ioctl,
+//!     &[ArgCmp::eq(1, TCGETS)],
+//!     Action::Allow,
+//! )];
+//!
+//! let program = Filter::new(Action::Errno(Errno::EPERM))
+//!     .allow(&SysnoSet::new(&[Sysno::read, Sysno::write]))
+//!     .rules(RULES)
+//!     .compile()
+//!     .unwrap();
+//! ```
+
+use core::fmt;
+
+use crate::audit::AUDIT_ARCH;
+use crate::{Errno, SyscallArgs, SyscallWord, Sysno, SysnoMap, SysnoSet};
+
+#[cfg(all(feature = "std", not(rawsys_backend = "none")))]
+mod inject;
+mod notify;
+
+#[cfg(all(feature = "std", not(rawsys_backend = "none")))]
+pub use self::inject::ErrnoInjection;
+#[cfg(not(rawsys_backend = "none"))]
+pub use self::notify::Supervisor;
+pub use self::notify::{
+    SECCOMP_ADDFD_FLAG_SEND, SECCOMP_ADDFD_FLAG_SETFD,
+    SECCOMP_USER_NOTIF_FLAG_CONTINUE, SeccompNotif, SeccompNotifAddfd,
+    SeccompNotifResp,
+};
+
+// Instruction classes and fields of classic BPF (`linux/bpf_common.h`).
+const BPF_LD: u16 = 0x00;
+const BPF_ALU: u16 = 0x04;
+const BPF_JMP: u16 = 0x05;
+const BPF_RET: u16 = 0x06;
+const BPF_W: u16 = 0x00;
+const BPF_ABS: u16 = 0x20;
+const BPF_AND: u16 = 0x50;
+const BPF_JA: u16 = 0x00;
+const BPF_JEQ: u16 = 0x10;
+const BPF_JGT: u16 = 0x20;
+const BPF_JGE: u16 = 0x30;
+const BPF_K: u16 = 0x00;
+
+/// Set in the numbers of x32 syscalls, which use the same `AUDIT_ARCH` as
+/// `x86_64` ones.
+#[cfg(target_arch = "x86_64")]
+const X32_SYSCALL_BIT: u32 = 0x4000_0000;
+
+#[cfg(not(rawsys_backend = "none"))]
+const SECCOMP_SET_MODE_FILTER: usize = 1;
+#[cfg(not(rawsys_backend = "none"))]
+const PR_SET_NO_NEW_PRIVS: usize = 38;
+
+/// `flags` for [`install`]: installs the filter for all threads of the
+/// process, not only the calling one.
+pub const SECCOMP_FILTER_FLAG_TSYNC: u32 = 1 << 0;
+/// `flags` for [`install`]: logs all actions except [`Action::Allow`].
+pub const SECCOMP_FILTER_FLAG_LOG: u32 = 1 << 1;
+/// `flags` for [`install`]: keeps the Speculative Store Bypass mitigation
+/// off, which seccomp otherwise turns on.
+pub const SECCOMP_FILTER_FLAG_SPEC_ALLOW: u32 = 1 << 2;
+/// `flags` for [`install`]: returns a listener fd for the notifications of
+/// [`Action::UserNotif`].
+pub const SECCOMP_FILTER_FLAG_NEW_LISTENER: u32 = 1 << 3;
+/// `flags` for [`install`]: with [`SECCOMP_FILTER_FLAG_TSYNC`], fails with
+/// `ESRCH` instead of returning the id of a thread that can't be synced.
+pub const SECCOMP_FILTER_FLAG_TSYNC_ESRCH: u32 = 1 << 4;
+
+/// Instructions for rules, on top of the two per syscall that a filter
+/// without rules can need.
+const RULE_CAPACITY: usize = 768;
+
+/// The most instructions a [`Program`] can have. The kernel accepts up to
+/// 4096 (`BPF_MAXINSNS`).
+const CAPACITY: usize = 8 + 2 * Sysno::count() + RULE_CAPACITY;
+
+/// What the kernel does with a syscall (`SECCOMP_RET_*`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    /// Kills the whole process, as if by `SIGSYS`.
+    KillProcess,
+    /// Kills the calling thread only.
+    KillThread,
+    /// Raises `SIGSYS` in the calling thread, without running the syscall.
+    Trap,
+    /// Fails the syscall with the error code, without running it.
+    Errno(Errno),
+    /// Lets a supervisor holding the filter's listener fd handle it, see
+    /// [`SeccompNotif`].
+    UserNotif,
+    /// Stops a ptrace tracer with `PTRACE_EVENT_SECCOMP` and this message.
+    /// Without a tracer, the syscall fails with `ENOSYS`.
+    Trace(u16),
+    /// Runs the syscall and logs it.
+    Log,
+    /// Runs the syscall.
+    Allow,
+}
+
+impl Action {
+    /// The value returned by the filter for this action.
+    pub fn to_raw(self) -> u32 {
+        match self {
+            Self::KillProcess => 0x8000_0000,
+            Self::KillThread => 0,
+            Self::Trap => 0x0003_0000,
+            Self::Errno(errno) => {
+                0x0005_0000 | (errno.into_raw() as u32 & 0xffff)
+            }
+            Self::UserNotif => 0x7fc0_0000,
+            Self::Trace(data) => 0x7ff0_0000 | u32::from(data),
+            Self::Log => 0x7ffc_0000,
+            Self::Allow => 0x7fff_0000,
+        }
+    }
+
+    /// The action of a value returned by a filter, e.g. from an audit
+    /// record. The data of [`Action::Trap`] is dropped, and unknown actions
+    /// (which the kernel treats as [`Action::KillProcess`]) are `None`.
+    pub fn from_raw(raw: u32) -> Option<Self> {
+        let data = raw & 0xffff;
+        Some(match raw & 0xffff_0000 {
+            0x8000_0000 => Self::KillProcess,
+            0 => Self::KillThread,
+            0x0003_0000 => Self::Trap,
+            0x0005_0000 => Self::Errno(Errno::new(data as i32)),
+            0x7fc0_0000 => Self::UserNotif,
+            0x7ff0_0000 => Self::Trace(data as u16),
+            0x7ffc_0000 => Self::Log,
+            0x7fff_0000 => Self::Allow,
+            _ => return None,
+        })
+    }
+}
+
+/// One instruction of a classic BPF program (`struct sock_filter`).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SockFilter {
+    pub code: u16,
+    pub jt: u8,
+    pub jf: u8,
+    pub k: u32,
+}
+
+impl SockFilter {
+    const fn stmt(code: u16, k: u32) -> Self {
+        Self {
+            code,
+            jt: 0,
+            jf: 0,
+            k,
+        }
+    }
+
+    const fn jump(code: u16, k: u32, jt: u8, jf: u8) -> Self {
+        Self { code, jt, jf, k }
+    }
+}
+
+/// A comparison of a syscall argument with a value, see [`ArgCmp`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cmp {
+    /// The argument equals the value.
+    Eq(u64),
+    /// The argument differs from the value.
+    Ne(u64),
+    /// The argument is greater than the value, as unsigned numbers.
+    Gt(u64),
+    /// The bits of the argument in `mask` equal `value`.
+    MaskedEq { mask: u64, value: u64 },
+}
+
+/// A condition on argument `arg` (0 to 5) of a syscall.
+///
+/// Arguments are compared as whole registers: on 64-bit targets, 64-bit
+/// values made of the two halves that classic BPF can load, and on 32-bit
+/// targets 32-bit values, where comparing with a value that doesn't fit is an
+/// error. On 64-bit targets the upper half of an `int` argument may hold
+/// anything (e.g. a sign extension), so use [`ArgCmp::masked_eq`] with
+/// `0xffff_ffff` to look at the lower half only.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArgCmp {
+    pub arg: u8,
+    pub cmp: Cmp,
+}
+
+impl ArgCmp {
+    /// Argument `arg` equals `value`.
+    pub const fn eq(arg: u8, value: u64) -> Self {
+        Self {
+            arg,
+            cmp: Cmp::Eq(value),
+        }
+    }
+
+    /// Argument `arg` differs from `value`.
+    pub const fn ne(arg: u8, value: u64) -> Self {
+        Self {
+            arg,
+            cmp: Cmp::Ne(value),
+        }
+    }
+
+    /// Argument `arg` is greater than `value`.
+    pub const fn gt(arg: u8, value: u64) -> Self {
+        Self {
+            arg,
+            cmp: Cmp::Gt(value),
+        }
+    }
+
+    /// The bits of argument `arg` in `mask` equal `value`.
+    pub const fn masked_eq(arg: u8, mask: u64, value: u64) -> Self {
+        Self {
+            arg,
+            cmp: Cmp::MaskedEq { mask, value },
+        }
+    }
+}
+
+/// Gives `sysno` the `action` when all the `conditions` hold.
+#[derive(Debug, Clone, Copy)]
+pub struct Rule<'a> {
+    pub sysno: Sysno,
+    pub conditions: &'a [ArgCmp],
+    pub action: Action,
+}
+
+impl<'a> Rule<'a> {
+    pub const fn new(
+        sysno: Sysno,
+        conditions: &'a [ArgCmp],
+        action: Action,
+    ) -> Self {
+        Self {
+            sysno,
+            conditions,
+            action,
+        }
+    }
+}
+
+/// Why a [`Filter`] can't be compiled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// A condition names an argument other than 0 to 5.
+    InvalidArg(u8),
+    /// A condition compares with a value that doesn't fit in the target's
+    /// registers.
+    ValueTooLarge(u64),
+    /// The program would have more instructions than the kernel accepts, or
+    /// a rule has too many conditions to jump over.
+    TooLong,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidArg(arg) => {
+                write!(f, "syscalls have no argument {arg}")
+            }
+            Self::ValueTooLarge(value) => {
+                write!(f, "{value:#x} doesn't fit in a register")
+            }
+            Self::TooLong => f.write_str("the seccomp program is too long"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+/// A seccomp filter to compile into a [`Program`].
+///
+/// Each syscall gets the action of its first [`Rule`] whose conditions hold.
+/// Otherwise, it gets the action set with [`action`](Self::action) or
+/// [`allow`](Self::allow), whichever came last, or the default action.
+pub struct Filter<'a> {
+    default: Action,
+    actions: SysnoMap<Action>,
+    rules: &'a [Rule<'a>],
+}
+
+impl<'a> Filter<'a> {
+    /// Creates a filter that gives every syscall the `default` action.
+    pub fn new(default: Action) -> Self {
+        Self {
+            default,
+            actions: SysnoMap::new(),
+            rules: &[],
+        }
+    }
+
+    /// Allows the `syscalls`.
+    #[must_use]
+    pub fn allow(mut self, syscalls: &SysnoSet) -> Self {
+        for sysno in syscalls {
+            self.actions.insert(sysno, Action::Allow);
+        }
+        self
+    }
+
+    /// Gives `sysno` the `action`.
+    #[must_use]
+    pub fn action(mut self, sysno: Sysno, action: Action) -> Self {
+        self.actions.insert(sysno, action);
+        self
+    }
+
+    /// Sets the rules that look at the arguments, in the order they are
+    /// tried.
+    #[must_use]
+    pub fn rules(mut self, rules: &'a [Rule<'a>]) -> Self {
+        self.rules = rules;
+        self
+    }
+
+    /// Compiles the filter.
+    ///
+    /// Syscalls made with another calling convention than the target's kill
+    /// the process, as do x32 syscalls on `x86_64`. Syscall number -1, which
+    /// tracers use to skip a syscall, gets the default action.
+    pub fn compile(&self) -> Result<Program, Error> {
+        let mut program = Program {
+            len: 0,
+            insns: [SockFilter::stmt(0, 0); CAPACITY],
+        };
+        let kill =
+            SockFilter::stmt(BPF_RET | BPF_K, Action::KillProcess.to_raw());
+
+        program.push(SockFilter::stmt(
+            BPF_LD | BPF_W | BPF_ABS,
+            SeccompData::ARCH_OFFSET,
+        ))?;
+        program.push(SockFilter::jump(
+            BPF_JMP | BPF_JEQ | BPF_K,
+            AUDIT_ARCH,
+            1,
+            0,
+        ))?;
+        program.push(kill)?;
+        program.push(SockFilter::stmt(
+            BPF_LD | BPF_W | BPF_ABS,
+            SeccompData::NR_OFFSET,
+        ))?;
+        #[cfg(target_arch = "x86_64")]
+        {
+            // -1 is not an x32 syscall: it is what a tracer sets to skip a
+            // syscall (after which the filter runs again), and programs
+            // call it to get `ENOSYS`. It gets the default action.
+            program.push(SockFilter::jump(
+                BPF_JMP | BPF_JEQ | BPF_K,
+                u32::MAX,
+                2,
+                0,
+            ))?;
+            program.push(SockFilter::jump(
+                BPF_JMP | BPF_JGE | BPF_K,
+                X32_SYSCALL_BIT,
+                0,
+                1,
+            ))?;
+            program.push(kill)?;
+        }
+
+        for &sysno in Sysno::ALL {
+            let action =
+                self.actions.get(sysno).copied().unwrap_or(self.default);
+            let mut rules = self
+                .rules
+                .iter()
+                .filter(|rule| rule.sysno == sysno)
+                .peekable();
+            if rules.peek().is_none() {
+                if action != self.default {
+                    program.push(SockFilter::jump(
+                        BPF_JMP | BPF_JEQ | BPF_K,
+                        sysno.id() as u32,
+                        0,
+                        1,
+                    ))?;
+                    program.push(SockFilter::stmt(
+                        BPF_RET | BPF_K,
+                        action.to_raw(),
+                    ))?;
+                }
+                continue;
+            }
+
+            // The rules can be too long for the 8-bit offset of a
+            // conditional jump, so other syscalls jump over them with `ja`.
+            program.push(SockFilter::jump(
+                BPF_JMP | BPF_JEQ | BPF_K,
+                sysno.id() as u32,
+                1,
+                0,
+            ))?;
+            let skip = program.len;
+            program.push(SockFilter::stmt(BPF_JMP | BPF_JA, 0))?;
+            for rule in rules {
+                program.push_rule(rule)?;
+            }
+            program.push(SockFilter::stmt(BPF_RET | BPF_K, action.to_raw()))?;
+            program.insns[skip].k = (program.len - skip - 1) as u32;
+        }
+
+        program
+            .push(SockFilter::stmt(BPF_RET | BPF_K, self.default.to_raw()))?;
+        Ok(program)
+    }
+}
+
+/// A syscall as a filter sees it (`struct seccomp_data`), and as `SIGSYS`
+/// handlers and [`Supervisor`]s get it.
+///
+/// Filters read it with `BPF_LD | BPF_W | BPF_ABS` at the offsets below, 32
+/// bits at a time.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SeccompData {
+    /// The syscall number, in the calling convention of `arch`.
+    pub nr: i32,
+    /// The `AUDIT_ARCH_*` token of the calling convention.
+    pub arch: u32,
+    pub instruction_pointer: u64,
+    pub args: [u64; 6],
+}
+
+impl SeccompData {
+    /// Offset of `nr`.
+    pub const NR_OFFSET: u32 = 0;
+    /// Offset of `arch`.
+    pub const ARCH_OFFSET: u32 = 4;
+    /// Offset of `instruction_pointer`.
+    pub const INSTRUCTION_POINTER_OFFSET: u32 = 8;
+    /// Offset of `args`.
+    pub const ARGS_OFFSET: u32 = 16;
+
+    /// Offset of argument `arg` (0 to 5).
+    ///
+    /// # Panics
+    ///
+    /// If `arg` is greater than 5.
+    pub const fn arg_offset(arg: u8) -> u32 {
+        assert!(arg < 6, "syscalls have 6 arguments");
+        Self::ARGS_OFFSET + 8 * arg as u32
+    }
+
+    /// Offset of the lower 32 bits of argument `arg`, which depends on the
+    /// byte order.
+    ///
+    /// # Panics
+    ///
+    /// If `arg` is greater than 5.
+    pub const fn arg_low_offset(arg: u8) -> u32 {
+        if cfg!(target_endian = "little") {
+            Self::arg_offset(arg)
+        } else {
+            Self::arg_offset(arg) + 4
+        }
+    }
+
+    /// Offset of the upper 32 bits of argument `arg`.
+    ///
+    /// # Panics
+    ///
+    /// If `arg` is greater than 5.
+    pub const fn arg_high_offset(arg: u8) -> u32 {
+        if cfg!(target_endian = "little") {
+            Self::arg_offset(arg) + 4
+        } else {
+            Self::arg_offset(arg)
+        }
+    }
+
+    /// The syscall, or `None` if it was made with another calling
+    /// convention than the target's (see [`AUDIT_ARCH`]) or isn't in the
+    /// table.
+    pub fn sysno(&self) -> Option<Sysno> {
+        if self.arch != AUDIT_ARCH {
+            return None;
+        }
+        Sysno::new(usize::try_from(self.nr).ok()?)
+    }
+
+    /// The arguments of the syscall.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn args(&self) -> SyscallArgs {
+        // On 32-bit targets, the upper halves are zero.
+        SyscallArgs::from(&self.args.map(|arg| arg as SyscallWord))
+    }
+}
+
+/// Splits a syscall into its raw number and its arguments, whatever the
+/// calling convention. See [`SeccompData::sysno`] to look the number up.
+impl From<SeccompData> for (i32, SyscallArgs) {
+    fn from(data: SeccompData) -> Self {
+        (data.nr, data.args())
+    }
+}
+
+/// A program as passed to the kernel (`struct sock_fprog`). See
+/// [`Program::fprog`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct SockFprog<'a> {
+    pub len: u16,
+    pub filter: *const SockFilter,
+    _program: core::marker::PhantomData<&'a Program>,
+}
+
+/// A compiled seccomp filter.
+///
+/// The instructions are stored inline, so no allocation is needed: there is
+/// room for two per syscall, and 768 more for the conditions of [`Rule`]s.
+#[derive(Clone)]
+pub struct Program {
+    len: usize,
+    insns: [SockFilter; CAPACITY],
+}
+
+impl Program {
+    /// Compiles a filter that allows the syscalls in `allowed`, gives the
+    /// syscalls in `overrides` their own action (even if they are also in
+    /// `allowed`), and applies `default` to all other syscalls. See
+    /// [`Filter`] for conditions on the arguments.
+    ///
+    /// Syscalls made with another calling convention than the target's kill
+    /// the process, as do x32 syscalls on `x86_64`. Syscall number -1, which
+    /// tracers use to skip a syscall, gets the default action.
+    ///
+    /// # Panics
+    ///
+    /// Never: without rules, the program always fits.
+    pub fn compile(
+        allowed: &SysnoSet,
+        default: Action,
+        overrides: &SysnoMap<Action>,
+    ) -> Self {
+        let mut filter = Filter::new(default).allow(allowed);
+        for (sysno, &action) in overrides {
+            filter = filter.action(sysno, action);
+        }
+        filter.compile().expect("a filter without rules fits")
+    }
+
+    fn push(&mut self, insn: SockFilter) -> Result<(), Error> {
+        *self.insns.get_mut(self.len).ok_or(Error::TooLong)? = insn;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Appends the instructions of a rule: its conditions, which jump past
+    /// the rule when one fails, and the return of its action.
+    fn push_rule(&mut self, rule: &Rule) -> Result<(), Error> {
+        let mut len = 1;
+        for condition in rule.conditions {
+            len += condition_len(condition)?;
+        }
+        // The jumps of the first condition must reach past the rule.
+        if len > usize::from(u8::MAX) {
+            return Err(Error::TooLong);
+        }
+        let end = self.len + len;
+
+        for condition in rule.conditions {
+            self.push_condition(condition, end)?;
+        }
+        self.push(SockFilter::stmt(BPF_RET | BPF_K, rule.action.to_raw()))
+    }
+
+    /// Appends the check of one condition, which jumps to `end` if it fails.
+    ///
+    /// On 64-bit targets, the upper halves are compared first: the lower
+    /// halves only decide when the upper ones are equal.
+    fn push_condition(
+        &mut self,
+        condition: &ArgCmp,
+        end: usize,
+    ) -> Result<(), Error> {
+        const WIDE: bool = cfg!(target_pointer_width = "64");
+        let (value, mask) = match condition.cmp {
+            Cmp::Eq(value) | Cmp::Ne(value) | Cmp::Gt(value) => (value, None),
+            Cmp::MaskedEq { mask, value } => (value, Some(mask)),
+        };
+        let halves: &[bool] = if WIDE { &[true, false] } else { &[false] };
+
+        for &upper in halves {
+            let half = |value: u64| {
+                if upper {
+                    (value >> 32) as u32
+                } else {
+                    value as u32
+                }
+            };
+            let last = !upper;
+            let offset = if upper {
+                SeccompData::arg_high_offset(condition.arg)
+            } else {
+                SeccompData::arg_low_offset(condition.arg)
+            };
+            self.push(SockFilter::stmt(BPF_LD | BPF_W | BPF_ABS, offset))?;
+            if let Some(mask) = mask {
+                self.push(SockFilter::stmt(
+                    BPF_ALU | BPF_AND | BPF_K,
+                    half(mask),
+                ))?;
+            }
+            // Offset of `end` from the next jump.
+            let fail = |program: &Self| (end - program.len - 1) as u8;
+            match condition.cmp {
+                Cmp::Eq(_) | Cmp::MaskedEq { .. } => {
+                    let fail = fail(self);
+                    self.push(SockFilter::jump(
+                        BPF_JMP | BPF_JEQ | BPF_K,
+                        half(value),
+                        0,
+                        fail,
+                    ))?;
+                }
+                // Different upper halves pass (skipping the lower ones),
+                // equal lower halves fail.
+                Cmp::Ne(_) if last => {
+                    let fail = fail(self);
+                    self.push(SockFilter::jump(
+                        BPF_JMP | BPF_JEQ | BPF_K,
+                        half(value),
+                        fail,
+                        0,
+                    ))?;
+                }
+                Cmp::Ne(_) => self.push(SockFilter::jump(
+                    BPF_JMP | BPF_JEQ | BPF_K,
+                    half(value),
+                    0,
+                    2,
+                ))?,
+                Cmp::Gt(_) if last => {
+                    let fail = fail(self);
+                    self.push(SockFilter::jump(
+                        BPF_JMP | BPF_JGT | BPF_K,
+                        half(value),
+                        0,
+                        fail,
+                    ))?;
+                }
+                // A greater upper half passes (skipping the lower halves), a
+                // smaller one fails.
+                Cmp::Gt(_) => {
+                    self.push(SockFilter::jump(
+                        BPF_JMP | BPF_JGT | BPF_K,
+                        half(value),
+                        3,
+                        0,
+                    ))?;
+                    let fail = fail(self);
+                    self.push(SockFilter::jump(
+                        BPF_JMP | BPF_JEQ | BPF_K,
+                        half(value),
+                        0,
+                        fail,
+                    ))?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the instructions of the program.
+    pub fn as_slice(&self) -> &[SockFilter] {
+        &self.insns[..self.len]
+    }
+
+    /// Returns the program in the form that `seccomp(SECCOMP_SET_MODE_FILTER)`
+    /// and `prctl(PR_SET_SECCOMP)` take, e.g. to pass flags that
+    /// [`install`](Self::install) doesn't.
+    pub fn fprog(&self) -> SockFprog<'_> {
+        SockFprog {
+            len: self.len as u16,
+            filter: self.insns.as_ptr(),
+            _program: core::marker::PhantomData,
+        }
+    }
+
+    /// Installs the filter for the calling thread, after setting
+    /// `no_new_privs` (which an unprivileged process needs to install a
+    /// filter). The filter can't be removed again, and is inherited by new
+    /// threads and child processes. See [`install`] for flags.
+    ///
+    /// # Safety
+    ///
+    /// From then on, every syscall of the thread goes through the filter,
+    /// including those made by libc, the allocator, and the Rust runtime.
+    /// The caller must make sure that they keep working, and that a
+    /// `SIGSYS` handler is installed if [`Action::Trap`] is used.
+    #[cfg(not(rawsys_backend = "none"))]
+    pub unsafe fn install(&self) -> Result<(), Errno> {
+        no_new_privs()?;
+        unsafe { install(self, 0) }.map(drop)
+    }
+}
+
+/// Sets `no_new_privs` for the calling thread (`PR_SET_NO_NEW_PRIVS`), so
+/// that `execve` can't grant privileges it didn't have, e.g. through setuid
+/// binaries. It can't be unset, and an unprivileged process needs it to
+/// install a seccomp filter.
+#[cfg(not(rawsys_backend = "none"))]
+pub fn no_new_privs() -> Result<(), Errno> {
+    unsafe {
+        crate::syscall5(Sysno::prctl, PR_SET_NO_NEW_PRIVS as _, 1, 0, 0, 0)
+    }
+    .map(drop)
+}
+
+/// Installs `program` with `seccomp(SECCOMP_SET_MODE_FILTER)` and the
+/// `SECCOMP_FILTER_FLAG_*` `flags`. Unlike [`Program::install`], it doesn't
+/// set `no_new_privs`, so without `CAP_SYS_ADMIN` call [`no_new_privs`]
+/// first.
+///
+/// Returns the listener fd with [`SECCOMP_FILTER_FLAG_NEW_LISTENER`], and
+/// `None` otherwise. If [`SECCOMP_FILTER_FLAG_TSYNC`] fails because another
+/// thread can't be synced, the kernel returns that thread's id, which this
+/// reports as `ESRCH`, the same as [`SECCOMP_FILTER_FLAG_TSYNC_ESRCH`] does.
+///
+/// # Safety
+///
+/// See [`Program::install`]. With [`SECCOMP_FILTER_FLAG_TSYNC`], that
+/// applies to all threads of the process.
+#[cfg(not(rawsys_backend = "none"))]
+pub unsafe fn install(
+    program: &Program,
+    flags: u32,
+) -> Result<Option<i32>, Errno> {
+    let ret = unsafe { set_mode_filter(program, flags) }?;
+    if flags & SECCOMP_FILTER_FLAG_NEW_LISTENER != 0 {
+        Ok(Some(ret as i32))
+    } else if ret != 0 {
+        Err(Errno::ESRCH)
+    } else {
+        Ok(None)
+    }
+}
+
+/// `seccomp(SECCOMP_SET_MODE_FILTER, flags, program)`.
+#[cfg(not(rawsys_backend = "none"))]
+unsafe fn set_mode_filter(
+    program: &Program,
+    flags: u32,
+) -> Result<crate::SyscallWord, Errno> {
+    let fprog = program.fprog();
+    unsafe {
+        crate::syscall3(
+            Sysno::seccomp,
+            SECCOMP_SET_MODE_FILTER as _,
+            flags.into(),
+            core::ptr::from_ref(&fprog) as _,
+        )
+    }
+}
+
+/// The number of instructions of a condition, see
+/// [`Program::push_condition`].
+fn condition_len(condition: &ArgCmp) -> Result<usize, Error> {
+    if condition.arg > 5 {
+        return Err(Error::InvalidArg(condition.arg));
+    }
+    let (per_half, values) = match condition.cmp {
+        Cmp::Eq(value) | Cmp::Ne(value) | Cmp::Gt(value) => (2, [value, 0]),
+        Cmp::MaskedEq { mask, value } => (3, [value, mask]),
+    };
+    if cfg!(target_pointer_width = "64") {
+        // `Gt` needs a second jump for the upper half.
+        let extra = usize::from(matches!(condition.cmp, Cmp::Gt(_)));
+        Ok(2 * per_half + extra)
+    } else if let Some(&value) = values.iter().find(|&&v| v > u32::MAX.into()) {
+        Err(Error::ValueTooLarge(value))
+    } else {
+        Ok(per_half)
+    }
+}
+
+impl core::ops::Deref for Program {
+    type Target = [SockFilter];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl fmt::Debug for Program {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seccomp_data() {
+        use core::mem::{offset_of, size_of};
+
+        assert_eq!(size_of::<SeccompData>(), 64);
+        assert_eq!(offset_of!(SeccompData, nr), 0);
+        assert_eq!(offset_of!(SeccompData, arch), 4);
+        assert_eq!(offset_of!(SeccompData, instruction_pointer), 8);
+        assert_eq!(offset_of!(SeccompData, args), 16);
+        assert_eq!(SeccompData::arg_offset(5), 56);
+
+        let data = SeccompData {
+            nr: Sysno::close.id(),
+            arch: AUDIT_ARCH,
+            instruction_pointer: 0,
+            args: [3, 0x1_0000_0002, 0, 0, 0, 0],
+        };
+        let bytes: [u8; 64] = unsafe { core::mem::transmute(data) };
+        let word = |offset: u32| {
+            let offset = offset as usize;
+            u32::from_ne_bytes(bytes[offset..offset + 4].try_into().unwrap())
+        };
+        assert_eq!(word(SeccompData::arg_low_offset(1)), 2);
+        assert_eq!(word(SeccompData::arg_high_offset(1)), 1);
+
+        assert_eq!(data.sysno(), Some(Sysno::close));
+        let (nr, args) = data.into();
+        assert_eq!(nr, Sysno::close.id());
+        assert_eq!(args.arg0, 3);
+        assert_eq!(
+            SeccompData { arch: 0, ..data }.sysno(),
+            None,
+            "another calling convention"
+        );
+    }
+
+    /// Runs `program` on a `seccomp_data` with `nr`, `arch`, and `args` set,
+    /// the way the kernel would.
+    fn run_with(program: &Program, nr: u32, arch: u32, args: [u64; 6]) -> u32 {
+        let mut data = [0u8; 64];
+        data[0..4].copy_from_slice(&nr.to_ne_bytes());
+        data[4..8].copy_from_slice(&arch.to_ne_bytes());
+        for (i, arg) in args.iter().enumerate() {
+            data[16 + 8 * i..24 + 8 * i].copy_from_slice(&arg.to_ne_bytes());
+        }
+
+        let mut pc = 0;
+        let mut acc = 0;
+        loop {
+            let insn = program[pc];
+            pc += 1;
+            let jump = |taken: bool| {
+                usize::from(if taken { insn.jt } else { insn.jf })
+            };
+            match insn.code {
+                0x20 => {
+                    let k = insn.k as usize;
+                    acc =
+                        u32::from_ne_bytes(data[k..k + 4].try_into().unwrap());
+                }
+                0x54 => acc &= insn.k,
+                0x06 => return insn.k,
+                0x05 => pc += insn.k as usize,
+                0x15 => pc += jump(acc == insn.k),
+                0x25 => pc += jump(acc > insn.k),
+                0x35 => pc += jump(acc >= insn.k),
+                code => panic!("unexpected instruction {code:#x}"),
+            }
+        }
+    }
+
+    fn run(program: &Program, nr: u32, arch: u32) -> u32 {
+        run_with(program, nr, arch, [0; 6])
+    }
+
+    #[test]
+    fn actions() {
+        let allowed = SysnoSet::new(&[Sysno::read, Sysno::write, Sysno::close]);
+        let mut overrides = SysnoMap::new();
+        overrides.insert(Sysno::close, Action::Errno(Errno::EPERM));
+        overrides.insert(Sysno::openat, Action::Trace(7));
+        overrides.insert(Sysno::last(), Action::Log);
+        let program =
+            Program::compile(&allowed, Action::KillThread, &overrides);
+
+        let action =
+            |sysno: Sysno| run(&program, sysno.id() as u32, AUDIT_ARCH);
+        assert_eq!(action(Sysno::read), 0x7fff_0000);
+        assert_eq!(action(Sysno::write), 0x7fff_0000);
+        assert_eq!(action(Sysno::close), 0x0005_0001);
+        assert_eq!(action(Sysno::openat), 0x7ff0_0007);
+        assert_eq!(action(Sysno::exit_group), 0);
+        assert_eq!(action(Sysno::last()), 0x7ffc_0000);
+        assert_eq!(
+            run(&program, Sysno::read.id() as u32, AUDIT_ARCH ^ 1),
+            Action::KillProcess.to_raw()
+        );
+        #[cfg(target_arch = "x86_64")]
+        assert_eq!(
+            run(
+                &program,
+                Sysno::read.id() as u32 | X32_SYSCALL_BIT,
+                AUDIT_ARCH
+            ),
+            Action::KillProcess.to_raw()
+        );
+        assert_eq!(run(&program, u32::MAX, AUDIT_ARCH), 0);
+    }
+
+    #[test]
+    fn raw_actions() {
+        for action in [
+            Action::KillProcess,
+            Action::KillThread,
+            Action::Trap,
+            Action::Errno(Errno::EPERM),
+            Action::UserNotif,
+            Action::Trace(7),
+            Action::Log,
+            Action::Allow,
+        ] {
+            assert_eq!(Action::from_raw(action.to_raw()), Some(action));
+        }
+        assert_eq!(Action::from_raw(0x0003_0005), Some(Action::Trap));
+        assert_eq!(Action::from_raw(0x0004_0000), None);
+    }
+
+    #[test]
+    fn skips_default_actions() {
+        let program =
+            Program::compile(&SysnoSet::all(), Action::Allow, &SysnoMap::new());
+        let prologue = if cfg!(target_arch = "x86_64") { 7 } else { 4 };
+        assert_eq!(program.len(), prologue + 1);
+
+        let program =
+            Program::compile(&SysnoSet::all(), Action::Log, &SysnoMap::new());
+        assert_eq!(program.len(), prologue + 1 + 2 * Sysno::count());
+        assert_eq!(program.fprog().len as usize, program.len());
+    }
+
+    #[test]
+    fn argument_rules() {
+        const TCGETS: u64 = 0x5401;
+        const DENY: Action = Action::Errno(Errno::EPERM);
+        static RULES: &[Rule] = &[
+            Rule::new(Sysno::ioctl, &[ArgCmp::eq(1, TCGETS)], Action::Allow),
+            Rule::new(
+                Sysno::ioctl,
+                &[ArgCmp::ne(0, 3), ArgCmp::gt(2, 0x1_0000_0000)],
+                Action::Log,
+            ),
+            Rule::new(
+                Sysno::close,
+                &[ArgCmp::masked_eq(0, 0xffff_ffff, 0xffff_ff9c)],
+                Action::Trap,
+            ),
+        ];
+        let program = Filter::new(Action::KillProcess)
+            .allow(&SysnoSet::new(&[Sysno::close]))
+            .action(Sysno::ioctl, DENY)
+            .rules(RULES)
+            .compile()
+            .unwrap();
+
+        let ioctl = |args| {
+            run_with(&program, Sysno::ioctl.id() as u32, AUDIT_ARCH, args)
+        };
+        assert_eq!(ioctl([1, TCGETS, 0, 0, 0, 0]), 0x7fff_0000);
+        assert_eq!(ioctl([1, 0x5402, 0, 0, 0, 0]), DENY.to_raw());
+
+        // The comparisons with values above 32 bits only exist on 64-bit
+        // targets.
+        if cfg!(target_pointer_width = "64") {
+            assert_eq!(ioctl([1, TCGETS | 1 << 32, 0, 0, 0, 0]), DENY.to_raw());
+            assert_eq!(ioctl([1, 0, 0x1_0000_0001, 0, 0, 0]), 0x7ffc_0000);
+            assert_eq!(ioctl([1, 0, 0x2_0000_0000, 0, 0, 0]), 0x7ffc_0000);
+            assert_eq!(ioctl([1, 0, 0x1_0000_0000, 0, 0, 0]), DENY.to_raw());
+            assert_eq!(ioctl([1, 0, 0xffff_ffff, 0, 0, 0]), DENY.to_raw());
+            assert_eq!(ioctl([3, 0, 0x2_0000_0000, 0, 0, 0]), DENY.to_raw());
+            assert_eq!(ioctl([1 << 32 | 3, 0, 1 << 33, 0, 0, 0]), 0x7ffc_0000);
+        }
+
+        let close = |fd| {
+            run_with(
+                &program,
+                Sysno::close.id() as u32,
+                AUDIT_ARCH,
+                [fd, 0, 0, 0, 0, 0],
+            )
+        };
+        assert_eq!(close(0xffff_ff9c), 0x0003_0000);
+        assert_eq!(close(0xffff_ffff_ffff_ff9c), 0x0003_0000);
+        assert_eq!(close(3), 0x7fff_0000);
+        assert_eq!(
+            run(&program, Sysno::read.id() as u32, AUDIT_ARCH),
+            Action::KillProcess.to_raw()
+        );
+    }
+
+    #[test]
+    fn invalid_rules() {
+        let compile = |conditions: &[ArgCmp]| {
+            let rules = [Rule::new(Sysno::ioctl, conditions, Action::Allow)];
+            Filter::new(Action::KillProcess)
+                .rules(&rules)
+                .compile()
+                .map(drop)
+        };
+        assert_eq!(compile(&[ArgCmp::eq(6, 0)]), Err(Error::InvalidArg(6)));
+        assert_eq!(compile(&[ArgCmp::eq(0, 1); 64]), Err(Error::TooLong));
+        assert_eq!(
+            compile(&[ArgCmp::eq(0, 1 << 32)]),
+            if cfg!(target_pointer_width = "64") {
+                Ok(())
+            } else {
+                Err(Error::ValueTooLarge(1 << 32))
+            }
+        );
+    }
+}
@@ -0,0 +1,309 @@
+//! User notifications (`SECCOMP_RET_USER_NOTIF`)
+//!
+//! A filter returning [`Action::UserNotif`](super::Action::UserNotif) blocks
+//! the syscall and hands it to a supervisor holding the filter's listener fd
+//! (see `Supervisor::install`). The supervisor receives the call, looks at
+//! it (and at the memory of the caller, e.g. through `/proc/<pid>/mem`), and
+//! decides what the syscall returns, or lets it run after all.
+
+#[cfg(not(rawsys_backend = "none"))]
+use core::mem;
+
+use super::SeccompData;
+#[cfg(not(rawsys_backend = "none"))]
+use super::{Program, SECCOMP_FILTER_FLAG_NEW_LISTENER, set_mode_filter};
+#[cfg(not(rawsys_backend = "none"))]
+use crate::SyscallWord;
+#[cfg(not(rawsys_backend = "none"))]
+use crate::ioctl::{iow, iowr};
+use crate::{Errno, SyscallArgs, Sysno};
+
+/// `flags` of a [`SeccompNotifResp`]: runs the syscall instead of returning
+/// `val`/`error`.
+pub const SECCOMP_USER_NOTIF_FLAG_CONTINUE: u32 = 1 << 0;
+
+/// `flags` of a [`SeccompNotifAddfd`]: installs the fd as `newfd`, like
+/// `dup2`, instead of at the lowest free number.
+pub const SECCOMP_ADDFD_FLAG_SETFD: u32 = 1 << 0;
+/// `flags` of a [`SeccompNotifAddfd`]: also responds to the notification,
+/// with the number of the new fd as the syscall's return value.
+pub const SECCOMP_ADDFD_FLAG_SEND: u32 = 1 << 1;
+
+#[cfg(not(rawsys_backend = "none"))]
+const SECCOMP_IOC_MAGIC: u8 = b'!';
+
+#[cfg(not(rawsys_backend = "none"))]
+const SECCOMP_IOCTL_NOTIF_RECV: u32 =
+    iowr::<SeccompNotif>(SECCOMP_IOC_MAGIC, 0);
+#[cfg(not(rawsys_backend = "none"))]
+const SECCOMP_IOCTL_NOTIF_SEND: u32 =
+    iowr::<SeccompNotifResp>(SECCOMP_IOC_MAGIC, 1);
+#[cfg(not(rawsys_backend = "none"))]
+const SECCOMP_IOCTL_NOTIF_ID_VALID: u32 = iow::<u64>(SECCOMP_IOC_MAGIC, 2);
+#[cfg(not(rawsys_backend = "none"))]
+const SECCOMP_IOCTL_NOTIF_ADDFD: u32 =
+    iow::<SeccompNotifAddfd>(SECCOMP_IOC_MAGIC, 3);
+
+/// A notification received by a [`Supervisor`] (`struct seccomp_notif`).
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SeccompNotif {
+    /// Identifies the notification in the response.
+    pub id: u64,
+    /// The thread that made the syscall, in the supervisor's pid namespace
+    /// (0 if it isn't visible there).
+    pub pid: u32,
+    pub flags: u32,
+    pub data: SeccompData,
+}
+
+impl SeccompNotif {
+    /// The syscall, see [`SeccompData::sysno`].
+    pub fn sysno(&self) -> Option<Sysno> {
+        self.data.sysno()
+    }
+
+    /// The arguments of the syscall.
+    pub fn args(&self) -> SyscallArgs {
+        self.data.args()
+    }
+
+    /// The intercepted call as `(syscall, arguments, pid)`, or `None` for
+    /// syscalls that [`sysno`](Self::sysno) doesn't know.
+    pub fn call(&self) -> Option<(Sysno, SyscallArgs, u32)> {
+        Some((self.sysno()?, self.args(), self.pid))
+    }
+}
+
+/// The response to a notification (`struct seccomp_notif_resp`).
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SeccompNotifResp {
+    /// The [`SeccompNotif::id`] of the notification.
+    pub id: u64,
+    /// The return value of the syscall, if `error` is 0.
+    pub val: i64,
+    /// The negated errno that the syscall fails with, or 0.
+    pub error: i32,
+    /// `SECCOMP_USER_NOTIF_FLAG_*`.
+    pub flags: u32,
+}
+
+impl SeccompNotifResp {
+    /// Makes the syscall return `val`.
+    pub const fn value(id: u64, val: i64) -> Self {
+        Self {
+            id,
+            val,
+            error: 0,
+            flags: 0,
+        }
+    }
+
+    /// Makes the syscall fail with `errno`.
+    pub fn errno(id: u64, errno: Errno) -> Self {
+        Self {
+            id,
+            val: 0,
+            error: -errno.into_raw(),
+            flags: 0,
+        }
+    }
+
+    /// Runs the syscall ([`SECCOMP_USER_NOTIF_FLAG_CONTINUE`]).
+    ///
+    /// The caller can change the memory the arguments point to between the
+    /// supervisor's checks and the syscall, so this must not be used to
+    /// allow syscalls based on what that memory contains.
+    pub const fn continue_syscall(id: u64) -> Self {
+        Self {
+            id,
+            val: 0,
+            error: 0,
+            flags: SECCOMP_USER_NOTIF_FLAG_CONTINUE,
+        }
+    }
+}
+
+/// Installs an fd of the supervisor in the caller of a notification
+/// (`struct seccomp_notif_addfd`), see [`Supervisor::add_fd`].
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SeccompNotifAddfd {
+    /// The [`SeccompNotif::id`] of the notification.
+    pub id: u64,
+    /// `SECCOMP_ADDFD_FLAG_*`.
+    pub flags: u32,
+    /// The fd of the supervisor to install.
+    pub srcfd: u32,
+    /// The number to install it as, with [`SECCOMP_ADDFD_FLAG_SETFD`].
+    pub newfd: u32,
+    /// `O_CLOEXEC` or 0.
+    pub newfd_flags: u32,
+}
+
+impl SeccompNotifAddfd {
+    /// Installs `srcfd` at the lowest free number of the caller.
+    pub const fn new(id: u64, srcfd: u32) -> Self {
+        Self {
+            id,
+            flags: 0,
+            srcfd,
+            newfd: 0,
+            newfd_flags: 0,
+        }
+    }
+}
+
+/// The listener fd of a filter, which receives the notifications of its
+/// [`Action::UserNotif`](super::Action::UserNotif) syscalls and responds to
+/// them. The fd is closed on drop.
+///
+/// Notifications are answered in any order, possibly from several threads.
+/// A caller that dies or is interrupted by a signal while waiting makes its
+/// notification invalid, and responding to it fails with `ENOENT`.
+#[cfg(not(rawsys_backend = "none"))]
+#[derive(Debug)]
+pub struct Supervisor {
+    fd: i32,
+}
+
+#[cfg(not(rawsys_backend = "none"))]
+impl Supervisor {
+    /// Installs `program` like [`install`](super::install) with `flags`
+    /// and [`SECCOMP_FILTER_FLAG_NEW_LISTENER`], and returns the listener.
+    ///
+    /// The filter applies to the calling thread, so the listener usually
+    /// goes to another process (e.g. over a unix socket with
+    /// `SCM_RIGHTS`), or to a thread that is created before the filter is
+    /// installed.
+    ///
+    /// # Safety
+    ///
+    /// See [`Program::install`]. Also, a syscall that the filter sends to
+    /// the supervisor blocks until it is answered, so the calling thread
+    /// must not make such syscalls while it is the one supposed to answer.
+    pub unsafe fn install(
+        program: &Program,
+        flags: u32,
+    ) -> Result<Self, Errno> {
+        let fd = unsafe {
+            set_mode_filter(program, flags | SECCOMP_FILTER_FLAG_NEW_LISTENER)
+        }?;
+        #[allow(clippy::cast_possible_truncation)]
+        Ok(Self { fd: fd as i32 })
+    }
+
+    /// Takes ownership of a listener fd.
+    ///
+    /// # Safety
+    ///
+    /// `fd` must be an open listener fd that nothing else closes.
+    pub const unsafe fn from_raw_fd(fd: i32) -> Self {
+        Self { fd }
+    }
+
+    /// Returns the listener fd.
+    pub const fn as_raw_fd(&self) -> i32 {
+        self.fd
+    }
+
+    /// Returns the listener fd, which is no longer closed on drop.
+    pub const fn into_raw_fd(self) -> i32 {
+        let fd = self.fd;
+        mem::forget(self);
+        fd
+    }
+
+    /// Waits for the next notification. Fails with `ENOENT` if the caller
+    /// went away in the meantime, which is worth retrying.
+    pub fn recv(&self) -> Result<SeccompNotif, Errno> {
+        // The kernel requires a zeroed buffer.
+        let mut notif = SeccompNotif::default();
+        unsafe {
+            self.ioctl(
+                SECCOMP_IOCTL_NOTIF_RECV,
+                core::ptr::from_mut(&mut notif) as _,
+            )
+        }?;
+        Ok(notif)
+    }
+
+    /// Answers a notification.
+    pub fn send(&self, resp: &SeccompNotifResp) -> Result<(), Errno> {
+        unsafe {
+            self.ioctl(SECCOMP_IOCTL_NOTIF_SEND, core::ptr::from_ref(resp) as _)
+        }
+        .map(drop)
+    }
+
+    /// Checks that the notification `id` is still pending, i.e. that its
+    /// caller is still waiting. Supervisors read the caller's memory by pid,
+    /// and the pid may have been reused if the caller died, so this must be
+    /// checked after such reads and before trusting what they returned.
+    pub fn id_valid(&self, id: u64) -> Result<(), Errno> {
+        unsafe {
+            self.ioctl(
+                SECCOMP_IOCTL_NOTIF_ID_VALID,
+                core::ptr::from_ref(&id) as _,
+            )
+        }
+        .map(drop)
+    }
+
+    /// Installs an fd of the supervisor in the caller of a notification,
+    /// e.g. to emulate `openat`, and returns its number in the caller.
+    pub fn add_fd(&self, addfd: &SeccompNotifAddfd) -> Result<i32, Errno> {
+        let fd = unsafe {
+            self.ioctl(
+                SECCOMP_IOCTL_NOTIF_ADDFD,
+                core::ptr::from_ref(addfd) as _,
+            )
+        }?;
+        #[allow(clippy::cast_possible_truncation)]
+        Ok(fd as i32)
+    }
+
+    unsafe fn ioctl(
+        &self,
+        request: u32,
+        arg: SyscallWord,
+    ) -> Result<SyscallWord, Errno> {
+        unsafe { crate::ioctl::ioctl(self.fd, request, arg) }
+    }
+}
+
+#[cfg(not(rawsys_backend = "none"))]
+impl Drop for Supervisor {
+    fn drop(&mut self) {
+        let _ =
+            unsafe { crate::syscall1(Sysno::close, self.fd as SyscallWord) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::mem;
+
+    use super::*;
+
+    #[test]
+    fn layouts() {
+        assert_eq!(mem::size_of::<SeccompNotif>(), 80);
+        assert_eq!(mem::size_of::<SeccompNotifResp>(), 24);
+        assert_eq!(mem::size_of::<SeccompNotifAddfd>(), 24);
+    }
+
+    #[cfg(all(
+        not(rawsys_backend = "none"),
+        any(target_arch = "x86_64", target_arch = "aarch64")
+    ))]
+    #[test]
+    fn ioctl_numbers() {
+        // From the kernel's `linux/seccomp.h`.
+        assert_eq!(SECCOMP_IOCTL_NOTIF_RECV, 0xc050_2100);
+        assert_eq!(SECCOMP_IOCTL_NOTIF_SEND, 0xc018_2101);
+        assert_eq!(SECCOMP_IOCTL_NOTIF_ID_VALID, 0x4008_2102);
+        assert_eq!(SECCOMP_IOCTL_NOTIF_ADDFD, 0x4018_2103);
+    }
+}
@@ -90,6 +90,56 @@ impl SysnoSet {
         set
     }
 
+    /// Initialize the syscall set with the syscalls of the given names, as
+    /// returned by [`Sysno::name`]. Names that are not in the syscall table
+    /// are skipped.
+    ///
+    /// Since this is a `const fn`, this can be used at compile-time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rawsys_linux::{Sysno, SysnoSet};
+    /// const SYSCALLS: SysnoSet = SysnoSet::from_names(&["read", "write"]);
+    /// assert_eq!(SYSCALLS, SysnoSet::new(&[Sysno::read, Sysno::write]));
+    /// assert!(SysnoSet::from_names(&["not_a_syscall"]).is_empty());
+    /// ```
+    pub const fn from_names(names: &[&str]) -> Self {
+        const fn str_eq(a: &str, b: &str) -> bool {
+            let (a, b) = (a.as_bytes(), b.as_bytes());
+            if a.len() != b.len() {
+                return false;
+            }
+            let mut i = 0;
+            while i < a.len() {
+                if a[i] != b[i] {
+                    return false;
+                }
+                i += 1;
+            }
+            true
+        }
+
+        let mut set = Self::empty();
+
+        let mut i = 0;
+        while i < names.len() {
+            let mut j = 0;
+            while j < Sysno::ALL.len() {
+                let sysno = Sysno::ALL[j];
+                if str_eq(sysno.name(), names[i]) {
+                    let (idx, mask) = Self::get_idx_mask(sysno);
+                    set.data[idx] |= mask;
+                    break;
+                }
+                j += 1;
+            }
+            i += 1;
+        }
+
+        set
+    }
+
     /// Creates an empty set of syscalls.
     pub const fn empty() -> Self {
         Self {
@@ -465,6 +515,35 @@ mod tests {
         assert!(!SYSCALLS.contains(Sysno::write));
     }
 
+    #[test]
+    fn test_from_names() {
+        static SYSCALLS: SysnoSet =
+            SysnoSet::from_names(&["openat", "read", "not_a_syscall"]);
+
+        assert_eq!(SYSCALLS, SysnoSet::new(&[Sysno::openat, Sysno::read]));
+        assert_eq!(
+            SysnoSet::from_names(&[
+                Sysno::first().name(),
+                Sysno::last().name()
+            ]),
+            SysnoSet::new(&[Sysno::first(), Sysno::last()])
+        );
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    #[test]
+    fn test_version_diff_sets() {
+        use crate::arch::x86_64::versions;
+
+        // The set only has the syscalls of the selected table.
+        let added = versions::ADDED_IN_V5_10_SET;
+        assert!(added.count() <= versions::ADDED_IN_V5_10.len());
+        for sysno in &added {
+            assert!(versions::ADDED_IN_V5_10.contains(&sysno.name()));
+        }
+        assert!(versions::REMOVED_IN_V5_10_SET.is_empty());
+    }
+
     #[test]
     fn test_contains() {
         let set = SysnoSet::empty();
@@ -0,0 +1,175 @@
+//! What a signal carries (`siginfo_t`)
+//!
+//! [`Siginfo`] is the kernel's layout of a `siginfo_t`, 128 bytes, whose
+//! fields depend on the signal and its `code`. [`Siginfo::syscall`] decodes
+//! the `SIGSYS` of a seccomp filter or of Syscall User Dispatch, for
+//! [`audit::FilteredSyscall`](crate::audit::FilteredSyscall) and `sud`;
+//! `helpers` adds the accessors of the other signals.
+//!
+//! ```
+//! use rawsys_linux::siginfo::Siginfo;
+//!
+//! assert_eq!(size_of::<Siginfo>(), 128);
+//! assert_eq!(Siginfo::default().syscall(), None);
+//! ```
+
+use core::fmt;
+
+/// `code` of a `SIGSYS`: seccomp trapped the syscall.
+pub const SYS_SECCOMP: i32 = 1;
+/// `code` of a `SIGSYS`: Syscall User Dispatch trapped the syscall.
+pub const SYS_USER_DISPATCH: i32 = 2;
+
+/// `SIGSYS`, which is 12 on mips and sparc.
+#[cfg(not(any(
+    target_arch = "mips",
+    target_arch = "mips64",
+    target_arch = "sparc",
+    target_arch = "sparc64"
+)))]
+pub(crate) const SIGSYS: i32 = 31;
+#[cfg(any(
+    target_arch = "mips",
+    target_arch = "mips64",
+    target_arch = "sparc",
+    target_arch = "sparc64"
+))]
+pub(crate) const SIGSYS: i32 = 12;
+
+/// The size of the union of `siginfo_t` in words, which starts after the
+/// three `int`s, aligned for a pointer.
+const FIELDS: usize = if cfg!(target_pointer_width = "64") {
+    112 / 8
+} else {
+    116 / 4
+};
+
+/// What a signal carries (`siginfo_t`). See the module docs.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Siginfo {
+    pub(crate) signo: i32,
+    #[cfg(not(any(target_arch = "mips", target_arch = "mips64")))]
+    pub(crate) errno: i32,
+    pub(crate) code: i32,
+    #[cfg(any(target_arch = "mips", target_arch = "mips64"))]
+    pub(crate) errno: i32,
+    #[cfg(target_pointer_width = "64")]
+    pub(crate) pad: i32,
+    pub(crate) fields: [usize; FIELDS],
+}
+
+/// The fields of a `SIGSYS`, from [`Siginfo::syscall`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sigsys {
+    /// The syscall number, in the calling convention of `arch`.
+    pub nr: i32,
+    /// The `AUDIT_ARCH_*` of the calling convention of the syscall.
+    pub arch: u32,
+    /// The address of the instruction after the syscall instruction.
+    pub call_addr: usize,
+}
+
+impl Siginfo {
+    /// The `siginfo_t` in `bytes`, e.g. from `PTRACE_GETSIGINFO`.
+    pub fn from_bytes(bytes: &[u8; 128]) -> Self {
+        unsafe { bytes.as_ptr().cast::<Self>().read_unaligned() }
+    }
+
+    pub const fn signo(&self) -> i32 {
+        self.signo
+    }
+
+    /// Where the signal came from, e.g. [`SYS_SECCOMP`], which is a code of
+    /// the signal or a source shared by all of them.
+    pub const fn code(&self) -> i32 {
+        self.code
+    }
+
+    /// An error number that some signals carry, which is usually 0.
+    pub const fn errno(&self) -> i32 {
+        self.errno
+    }
+
+    /// The syscall of a `SIGSYS` from seccomp ([`SYS_SECCOMP`]) or Syscall
+    /// User Dispatch ([`SYS_USER_DISPATCH`]).
+    pub fn syscall(&self) -> Option<Sigsys> {
+        if self.signo != SIGSYS
+            || !matches!(self.code, SYS_SECCOMP | SYS_USER_DISPATCH)
+        {
+            return None;
+        }
+        let word = size_of::<usize>();
+        Some(Sigsys {
+            nr: self.int(word),
+            arch: self.int(word + 4).cast_unsigned(),
+            call_addr: self.fields[0],
+        })
+    }
+
+    /// The `int` at `offset` in the union.
+    pub(crate) fn int(&self, offset: usize) -> i32 {
+        unsafe { self.fields.as_ptr().byte_add(offset).cast::<i32>().read() }
+    }
+
+    // For `Siginfo::queue` of `helpers`.
+    #[cfg_attr(
+        not(all(feature = "helpers", not(rawsys_backend = "none"))),
+        allow(dead_code)
+    )]
+    pub(crate) fn set_int(&mut self, offset: usize, value: i32) {
+        let fields = self.fields.as_mut_ptr();
+        unsafe { fields.byte_add(offset).cast::<i32>().write(value) };
+    }
+}
+
+impl Default for Siginfo {
+    fn default() -> Self {
+        Self {
+            signo: 0,
+            errno: 0,
+            code: 0,
+            #[cfg(target_pointer_width = "64")]
+            pad: 0,
+            fields: [0; FIELDS],
+        }
+    }
+}
+
+impl fmt::Debug for Siginfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Siginfo")
+            .field("signo", &self.signo)
+            .field("errno", &self.errno)
+            .field("code", &self.code)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sigsys() {
+        let mut info = Siginfo {
+            signo: SIGSYS,
+            code: SYS_USER_DISPATCH,
+            ..Siginfo::default()
+        };
+        info.fields[0] = 0x1234;
+        info.set_int(size_of::<usize>(), 39);
+        info.set_int(size_of::<usize>() + 4, 0xc000_003e_u32.cast_signed());
+        let sigsys = Sigsys {
+            nr: 39,
+            arch: 0xc000_003e,
+            call_addr: 0x1234,
+        };
+        assert_eq!(info.syscall(), Some(sigsys));
+
+        let bytes = unsafe { core::mem::transmute::<Siginfo, [u8; 128]>(info) };
+        assert_eq!(Siginfo::from_bytes(&bytes).syscall(), Some(sigsys));
+        info.code = 0;
+        assert_eq!(info.syscall(), None);
+    }
+}
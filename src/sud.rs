@@ -0,0 +1,259 @@
+//! Syscall User Dispatch (SUD)
+//!
+//! With `PR_SET_SYSCALL_USER_DISPATCH` (Linux 5.11+), a thread can ask the
+//! kernel to deliver `SIGSYS` for every syscall it makes, instead of running
+//! it. Dispatch is switched on and off per thread through a one-byte
+//! [`Selector`] in user memory, and syscalls issued from an allowed code
+//! region are never dispatched. This is the building block for emulating
+//! syscalls in user space.
+//!
+//! - [`enable`]/[`disable`] configure dispatch for the calling thread.
+//! - With the `stub-section` feature, `enable_for_stub` allows the crate's
+//!   own syscall stub region, so that the handler can keep using this crate to
+//!   perform real syscalls.
+//! - [`Intercepted::decode`] (`x86` and `x86_64`) turns the `SIGSYS` handler's
+//!   `siginfo_t`/`ucontext_t` into a [`Sysno`] and [`SyscallArgs`] (only
+//!   the raw number for syscalls of another ABI, such as `int 0x80` on
+//!   `x86_64`), and [`set_return`] writes the emulated result back.
+//!
+//! Installing the `SIGSYS` handler itself is left to the caller (e.g. through
+//! `libc::sigaction` with `SA_SIGINFO`). The handler must set the selector to
+//! [`Selector::allow`] before returning, or the `rt_sigreturn` syscall would
+//! be dispatched as well.
+//!
+//! Example
+//! ```no_run
+//! use rawsys_linux::sud::{self, Selector};
+//!
+//! static SELECTOR: Selector = Selector::new();
+//!
+//! // The region here is empty, so every syscall is dispatched while blocked.
+//! unsafe { sud::enable(0..0, &SELECTOR) }.unwrap();
+//! SELECTOR.block();
+//! // ... syscalls now raise SIGSYS ...
+//! SELECTOR.allow();
+//! sud::disable().unwrap();
+//! ```
+
+use core::ops::Range;
+use core::sync::atomic::{AtomicU8, Ordering};
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+use crate::audit::AUDIT_ARCH;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+use crate::siginfo::Siginfo;
+use crate::{Errno, Sysno, syscall5};
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+use crate::{SyscallArgs, SyscallWord};
+
+const PR_SET_SYSCALL_USER_DISPATCH: usize = 59;
+const PR_SYS_DISPATCH_OFF: usize = 0;
+const PR_SYS_DISPATCH_ON: usize = 1;
+
+pub use crate::siginfo::SYS_USER_DISPATCH;
+
+/// The per-thread dispatch switch read by the kernel on every syscall.
+///
+/// It must stay alive (and at the same address) for as long as dispatch is
+/// enabled, hence the `'static` requirement in [`enable`]. Use one selector
+/// per thread, e.g. a `static` for a single-threaded emulator or a leaked
+/// allocation per thread.
+#[repr(transparent)]
+#[derive(Debug, Default)]
+pub struct Selector(AtomicU8);
+
+impl Selector {
+    /// `SYSCALL_DISPATCH_FILTER_ALLOW`: syscalls run normally.
+    pub const ALLOW: u8 = 0;
+    /// `SYSCALL_DISPATCH_FILTER_BLOCK`: syscalls raise `SIGSYS`.
+    pub const BLOCK: u8 = 1;
+
+    /// Creates a selector that allows syscalls.
+    pub const fn new() -> Self {
+        Self(AtomicU8::new(Self::ALLOW))
+    }
+
+    /// Lets syscalls through.
+    #[inline]
+    pub fn allow(&self) {
+        self.0.store(Self::ALLOW, Ordering::SeqCst);
+    }
+
+    /// Dispatches syscalls to the `SIGSYS` handler.
+    #[inline]
+    pub fn block(&self) {
+        self.0.store(Self::BLOCK, Ordering::SeqCst);
+    }
+
+    /// Returns `true` if syscalls are currently dispatched.
+    #[inline]
+    pub fn is_blocked(&self) -> bool {
+        self.0.load(Ordering::SeqCst) == Self::BLOCK
+    }
+}
+
+/// Enables Syscall User Dispatch for the calling thread.
+///
+/// Syscalls issued from `region` are always allowed. Other syscalls raise
+/// `SIGSYS` while `selector` is blocked.
+///
+/// # Safety
+///
+/// Once the selector is blocked, every syscall outside of `region` (including
+/// those made by libc, the allocator, or the signal return path) is turned
+/// into a `SIGSYS`. The caller must have a handler installed that copes with
+/// this.
+pub unsafe fn enable(
+    region: Range<usize>,
+    selector: &'static Selector,
+) -> Result<(), Errno> {
+    unsafe {
+        syscall5(
+            Sysno::prctl,
+            PR_SET_SYSCALL_USER_DISPATCH as _,
+            PR_SYS_DISPATCH_ON as _,
+            region.start as _,
+            region.end.saturating_sub(region.start) as _,
+            core::ptr::from_ref(selector) as _,
+        )
+    }
+    .map(drop)
+}
+
+/// Enables Syscall User Dispatch for the calling thread, allowing the crate's
+/// own syscall stub (see [`crate::raw::stub_region`]).
+///
+/// # Safety
+///
+/// See [`enable`].
+#[cfg(rawsys_backend = "stub")]
+pub unsafe fn enable_for_stub(
+    selector: &'static Selector,
+) -> Result<(), Errno> {
+    unsafe { enable(crate::raw::stub_region(), selector) }
+}
+
+/// Disables Syscall User Dispatch for the calling thread.
+pub fn disable() -> Result<(), Errno> {
+    unsafe {
+        syscall5(
+            Sysno::prctl,
+            PR_SET_SYSCALL_USER_DISPATCH as _,
+            PR_SYS_DISPATCH_OFF as _,
+            0,
+            0,
+            0,
+        )
+    }
+    .map(drop)
+}
+
+// Offsets of the general purpose registers in `ucontext_t`, in units of
+// machine words.
+#[cfg(target_arch = "x86_64")]
+mod regs {
+    // uc_flags, uc_link, uc_stack (3 words), then mcontext gregs.
+    const GREGS: usize = 5;
+    pub const ARGS: [usize; 6] = [
+        GREGS + 8,  // rdi
+        GREGS + 9,  // rsi
+        GREGS + 12, // rdx
+        GREGS + 2,  // r10
+        GREGS,      // r8
+        GREGS + 1,  // r9
+    ];
+    pub const RET: usize = GREGS + 13; // rax
+}
+
+#[cfg(target_arch = "x86")]
+mod regs {
+    // uc_flags, uc_link, uc_stack (3 words), then mcontext gregs.
+    const GREGS: usize = 5;
+    pub const ARGS: [usize; 6] = [
+        GREGS + 8,  // ebx
+        GREGS + 10, // ecx
+        GREGS + 9,  // edx
+        GREGS + 5,  // esi
+        GREGS + 4,  // edi
+        GREGS + 6,  // ebp
+    ];
+    pub const RET: usize = GREGS + 11; // eax
+}
+
+/// A syscall intercepted by Syscall User Dispatch.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Intercepted {
+    /// The syscall number as seen by the kernel.
+    pub nr: i32,
+    /// The `AUDIT_ARCH_*` of the calling convention of the syscall, which
+    /// is not [`AUDIT_ARCH`] for the `int 0x80` syscalls of a 64-bit
+    /// process, for example.
+    pub arch: u32,
+    /// The syscall, if it was made with the native calling convention and
+    /// the number is known for this architecture.
+    pub sysno: Option<Sysno>,
+    /// The syscall arguments.
+    pub args: SyscallArgs,
+    /// Address of the instruction following the syscall.
+    pub call_addr: usize,
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl Intercepted {
+    /// Decodes the syscall from the arguments of an `SA_SIGINFO` `SIGSYS`
+    /// handler. Returns `None` if the signal was not raised by Syscall User
+    /// Dispatch (e.g. it came from seccomp).
+    ///
+    /// # Safety
+    ///
+    /// `info` and `ucontext` must be the pointers passed to the handler.
+    pub unsafe fn decode(
+        info: *const core::ffi::c_void,
+        ucontext: *const core::ffi::c_void,
+    ) -> Option<Self> {
+        let info = unsafe { &*info.cast::<Siginfo>() };
+        if info.code() != SYS_USER_DISPATCH {
+            return None;
+        }
+        let info = info.syscall()?;
+
+        let gregs = ucontext.cast::<SyscallWord>();
+        let arg = |i: usize| unsafe { gregs.add(regs::ARGS[i]).read() };
+
+        // The numbers of other ABIs, such as i386 under x86_64, aren't the
+        // ones of `Sysno`.
+        let sysno = if info.arch == AUDIT_ARCH {
+            Sysno::new(info.nr as usize)
+        } else {
+            None
+        };
+        Some(Self {
+            nr: info.nr,
+            arch: info.arch,
+            sysno,
+            args: SyscallArgs::new(
+                arg(0),
+                arg(1),
+                arg(2),
+                arg(3),
+                arg(4),
+                arg(5),
+            ),
+            call_addr: info.call_addr,
+        })
+    }
+}
+
+/// Sets the value that the intercepted syscall returns once the `SIGSYS`
+/// handler returns. Use a negative errno to report an error.
+///
+/// # Safety
+///
+/// `ucontext` must be the pointer passed to the handler.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub unsafe fn set_return(ucontext: *mut core::ffi::c_void, ret: SyscallWord) {
+    unsafe {
+        ucontext.cast::<SyscallWord>().add(regs::RET).write(ret);
+    }
+}
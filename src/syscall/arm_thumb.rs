@@ -7,11 +7,17 @@
 // arg6: %r5
 //
 // %r7 is used for the syscall number. In thumb mode, it is also used as the
-// frame pointer, so we need to save/restore this register.
+// frame pointer, so LLVM does not allow it as an asm operand. Instead, r7 is
+// saved in %ip (r12), loaded with the syscall number, and restored right after
+// the `svc`. Using ip rather than a register picked by the compiler keeps this
+// working when every low register is taken (6 arguments plus the syscall
+// number on Thumb-1). The `mov`s to and from ip are encodable on ARMv4T, but
+// a `mov` between two low registers is not before ARMv6, so r7 is loaded with
+// `movs`, which clobbers the flags.
 //
 // %r0 is reused for the syscall return value.
 //
-// No other registers are clobbered.
+// %ip and the flags are clobbered; no other registers are.
 use core::arch::asm;
 
 /// System call argument/return type for ARM (32-bit)
@@ -28,12 +34,12 @@ pub unsafe fn syscall0(n: SyscallWord) -> SyscallWord {
     let mut ret: SyscallWord;
     unsafe {
         asm!(
-            "movs {temp}, r7",
+            "mov ip, r7",
             "movs r7, {n}",
             "svc 0",
-            "movs r7, {temp}",
+            "mov r7, ip",
             n = in(reg) n,
-            temp = out(reg) _,
+            out("r12") _,
             lateout("r0") ret,
             options(nostack)
         );
@@ -52,12 +58,12 @@ pub unsafe fn syscall1(n: SyscallWord, arg1: SyscallWord) -> SyscallWord {
     let mut ret: SyscallWord;
     unsafe {
         asm!(
-            "movs {temp}, r7",
+            "mov ip, r7",
             "movs r7, {n}",
             "svc 0",
-            "movs r7, {temp}",
+            "mov r7, ip",
             n = in(reg) n,
-            temp = out(reg) _,
+            out("r12") _,
             inlateout("r0") arg1 => ret,
             options(nostack)
         );
@@ -80,12 +86,12 @@ pub unsafe fn syscall2(
     let mut ret: SyscallWord;
     unsafe {
         asm!(
-            "movs {temp}, r7",
+            "mov ip, r7",
             "movs r7, {n}",
             "svc 0",
-            "movs r7, {temp}",
+            "mov r7, ip",
             n = in(reg) n,
-            temp = out(reg) _,
+            out("r12") _,
             inlateout("r0") arg1 => ret,
             in("r1") arg2,
             options(nostack)
@@ -110,12 +116,12 @@ pub unsafe fn syscall3(
     let mut ret: SyscallWord;
     unsafe {
         asm!(
-            "movs {temp}, r7",
+            "mov ip, r7",
             "movs r7, {n}",
             "svc 0",
-            "movs r7, {temp}",
+            "mov r7, ip",
             n = in(reg) n,
-            temp = out(reg) _,
+            out("r12") _,
             inlateout("r0") arg1 => ret,
             in("r1") arg2,
             in("r2") arg3,
@@ -142,12 +148,12 @@ pub unsafe fn syscall4(
     let mut ret: SyscallWord;
     unsafe {
         asm!(
-            "movs {temp}, r7",
+            "mov ip, r7",
             "movs r7, {n}",
             "svc 0",
-            "movs r7, {temp}",
+            "mov r7, ip",
             n = in(reg) n,
-            temp = out(reg) _,
+            out("r12") _,
             inlateout("r0") arg1 => ret,
             in("r1") arg2,
             in("r2") arg3,
@@ -176,12 +182,12 @@ pub unsafe fn syscall5(
     let mut ret: SyscallWord;
     unsafe {
         asm!(
-            "movs {temp}, r7",
+            "mov ip, r7",
             "movs r7, {n}",
             "svc 0",
-            "movs r7, {temp}",
+            "mov r7, ip",
             n = in(reg) n,
-            temp = out(reg) _,
+            out("r12") _,
             inlateout("r0") arg1 => ret,
             in("r1") arg2,
             in("r2") arg3,
@@ -211,15 +217,14 @@ pub unsafe fn syscall6(
 ) -> SyscallWord {
     let mut ret: SyscallWord;
 
-    // NOTE: On ARMv4t, `movs` must be used instead of `mov`.
     unsafe {
         asm!(
-            "movs {temp}, r7",
+            "mov ip, r7",
             "movs r7, {n}",
             "svc 0",
-            "movs r7, {temp}",
+            "mov r7, ip",
             n = in(reg) n,
-            temp = out(reg) _,
+            out("r12") _,
             inlateout("r0") arg1 => ret,
             in("r1") arg2,
             in("r2") arg3,
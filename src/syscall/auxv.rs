@@ -0,0 +1,76 @@
+//! Minimal `/proc/self/auxv` lookup, used by `crate::auxv` when no vector
+//! was recorded, and through it by backends that choose their kernel entry
+//! sequence at runtime.
+//!
+//! The lookup issues syscalls through the regular backend functions, so a
+//! backend must already be routing calls through its fallback path (e.g.
+//! `int 0x80` or `sc`) before it probes for a faster one.
+
+use super::{SyscallWord, syscall1, syscall3, syscall4};
+use crate::Sysno;
+
+const AT_NULL: usize = 0;
+
+const AT_FDCWD: SyscallWord = -100isize as SyscallWord;
+const O_RDONLY: SyscallWord = 0;
+#[cfg(any(target_arch = "sparc", target_arch = "sparc64"))]
+const O_CLOEXEC: SyscallWord = 0x0040_0000;
+#[cfg(not(any(target_arch = "sparc", target_arch = "sparc64")))]
+const O_CLOEXEC: SyscallWord = 0x0008_0000;
+
+/// Returns true if a raw return value is an error code.
+#[inline]
+fn is_err(ret: SyscallWord) -> bool {
+    ret > -4096isize as SyscallWord
+}
+
+/// Looks up `key` in the auxiliary vector of the current process. Returns
+/// `None` if the key is absent or `/proc/self/auxv` cannot be read.
+pub(crate) fn getauxval(key: usize) -> Option<usize> {
+    let path = b"/proc/self/auxv\0";
+    let fd = unsafe {
+        syscall4(
+            Sysno::openat as SyscallWord,
+            AT_FDCWD,
+            path.as_ptr() as SyscallWord,
+            O_RDONLY | O_CLOEXEC,
+            0,
+        )
+    };
+    if is_err(fd) {
+        return None;
+    }
+
+    let mut value = None;
+    // The buffer holds a whole number of (key, value) pairs, so entries are
+    // never split across reads.
+    let mut buf = [0usize; 64];
+    'outer: loop {
+        let len = unsafe {
+            syscall3(
+                Sysno::read as SyscallWord,
+                fd,
+                buf.as_mut_ptr() as SyscallWord,
+                core::mem::size_of_val(&buf) as SyscallWord,
+            )
+        };
+        if len == 0 || is_err(len) {
+            break;
+        }
+        let words = len as usize / core::mem::size_of::<usize>();
+        for pair in buf[..words].chunks_exact(2) {
+            if pair[0] == AT_NULL {
+                break 'outer;
+            }
+            if pair[0] == key {
+                value = Some(pair[1]);
+                break 'outer;
+            }
+        }
+    }
+
+    unsafe {
+        syscall1(Sysno::close as SyscallWord, fd);
+    }
+    value
+}
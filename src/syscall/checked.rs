@@ -0,0 +1,121 @@
+//! `Result`-returning syscalls for architectures that report errors by
+//! returning a negative errno in the result register.
+//!
+//! Architectures with a separate error flag (e.g. MIPS `$a3`) provide their
+//! own `syscallN_checked` functions instead.
+
+use super::{
+    SyscallWord, syscall0, syscall1, syscall2, syscall3, syscall4, syscall5,
+    syscall6,
+};
+use crate::Errno;
+
+// NOTE on x86_64 x32 ABI
+// -----------------------
+// Some targets use 32-bit pointers but still return syscall results in a
+// 64-bit register width. In particular, the x86_64 x32 ABI has
+// `target_arch = "x86_64"` with `target_pointer_width = "32"`, but the
+// syscall return value is still delivered in a 64-bit register (RAX), and the
+// kernel reports errors by returning negative values truncated to the machine
+// word size. If we naïvely keyed off pointer width alone (treating all 32-bit
+// targets as returning a 32-bit value), negative return codes on x32 would be
+// misinterpreted and error conversion (Errno) would be wrong.
+//
+// To handle this correctly, we special-case x86_64 + 32-bit pointer-width to
+// convert using Errno::from_ret_u64, while other 32-bit targets continue to
+// use Errno::from_ret_u32 and 64-bit targets use Errno::from_ret_u64.
+//
+// Test status: we have not run CI on an actual x32 target here. The logic is
+// based on the ABI specification and should be correct, but x32-specific
+// testing remains outstanding.
+
+/// Converts a raw return value according to the ABI return width.
+#[inline(always)]
+#[allow(clippy::unnecessary_cast)] // `SyscallWord` differs per target.
+fn from_ret(ret: SyscallWord) -> Result<SyscallWord, Errno> {
+    // x86_64 x32 ABI: 32-bit pointers with 64-bit syscall return width.
+    #[cfg(all(target_arch = "x86_64", target_pointer_width = "32"))]
+    return Errno::from_ret_u64(ret as u64);
+
+    #[cfg(all(
+        not(all(target_arch = "x86_64", target_pointer_width = "32")),
+        target_pointer_width = "64"
+    ))]
+    return Errno::from_ret_u64(ret as u64);
+
+    #[cfg(all(
+        not(all(target_arch = "x86_64", target_pointer_width = "32")),
+        target_pointer_width = "32"
+    ))]
+    return Errno::from_ret_u32(ret as u32);
+}
+
+#[inline]
+pub(crate) unsafe fn syscall0_checked(
+    n: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    from_ret(unsafe { syscall0(n) })
+}
+
+#[inline]
+pub(crate) unsafe fn syscall1_checked(
+    n: SyscallWord,
+    a1: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    from_ret(unsafe { syscall1(n, a1) })
+}
+
+#[inline]
+pub(crate) unsafe fn syscall2_checked(
+    n: SyscallWord,
+    a1: SyscallWord,
+    a2: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    from_ret(unsafe { syscall2(n, a1, a2) })
+}
+
+#[inline]
+pub(crate) unsafe fn syscall3_checked(
+    n: SyscallWord,
+    a1: SyscallWord,
+    a2: SyscallWord,
+    a3: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    from_ret(unsafe { syscall3(n, a1, a2, a3) })
+}
+
+#[inline]
+pub(crate) unsafe fn syscall4_checked(
+    n: SyscallWord,
+    a1: SyscallWord,
+    a2: SyscallWord,
+    a3: SyscallWord,
+    a4: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    from_ret(unsafe { syscall4(n, a1, a2, a3, a4) })
+}
+
+#[inline]
+pub(crate) unsafe fn syscall5_checked(
+    n: SyscallWord,
+    a1: SyscallWord,
+    a2: SyscallWord,
+    a3: SyscallWord,
+    a4: SyscallWord,
+    a5: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    from_ret(unsafe { syscall5(n, a1, a2, a3, a4, a5) })
+}
+
+#[inline]
+pub(crate) unsafe fn syscall6_checked(
+    n: SyscallWord,
+    a1: SyscallWord,
+    a2: SyscallWord,
+    a3: SyscallWord,
+    a4: SyscallWord,
+    a5: SyscallWord,
+    a6: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    from_ret(unsafe { syscall6(n, a1, a2, a3, a4, a5, a6) })
+}
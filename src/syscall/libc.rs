@@ -0,0 +1,237 @@
+// libc-backed fallback, used on every architecture when the `libc-backend`
+// feature is enabled. Syscalls are forwarded to `libc::syscall`, so no inline
+// assembly is involved. This is useful for sanitizer builds and toolchains
+// without (stable) inline asm, at the cost of a function call and an errno
+// round trip per syscall.
+//
+// `libc::syscall` returns -1 and sets `errno` on failure. The raw functions
+// turn that back into the kernel's "negative errno" convention so they behave
+// like the asm backends; the `_checked` functions read `errno` directly.
+//
+// All six arguments are always passed; the kernel ignores the ones a syscall
+// does not use.
+use crate::Errno;
+use ::libc::c_long;
+
+/// System call argument/return type (matches the asm backend for the target)
+#[cfg(any(target_pointer_width = "64", target_arch = "x86_64"))]
+pub type SyscallWord = u64;
+
+/// System call argument/return type (matches the asm backend for the target)
+#[cfg(not(any(target_pointer_width = "64", target_arch = "x86_64")))]
+pub type SyscallWord = u32;
+
+/// Calls `libc::syscall`, returning the errno on failure.
+#[inline(always)]
+unsafe fn call(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+    arg4: SyscallWord,
+    arg5: SyscallWord,
+    arg6: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    let ret = unsafe {
+        ::libc::syscall(
+            n as c_long,
+            arg1 as c_long,
+            arg2 as c_long,
+            arg3 as c_long,
+            arg4 as c_long,
+            arg5 as c_long,
+            arg6 as c_long,
+        )
+    };
+    if ret == -1 {
+        Err(Errno::new(unsafe { *crate::errno::last::errno() }))
+    } else {
+        Ok(ret as SyscallWord)
+    }
+}
+
+/// Folds a result back into the "negative errno" convention.
+#[inline(always)]
+fn normalize(res: Result<SyscallWord, Errno>) -> SyscallWord {
+    match res {
+        Ok(ret) => ret,
+        Err(err) => (err.into_raw() as SyscallWord).wrapping_neg(),
+    }
+}
+
+/// Issues a raw system call with 0 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall0(n: SyscallWord) -> SyscallWord {
+    normalize(unsafe { call(n, 0, 0, 0, 0, 0, 0) })
+}
+
+/// Issues a raw system call with 1 argument.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall1(n: SyscallWord, arg1: SyscallWord) -> SyscallWord {
+    normalize(unsafe { call(n, arg1, 0, 0, 0, 0, 0) })
+}
+
+/// Issues a raw system call with 2 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall2(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+) -> SyscallWord {
+    normalize(unsafe { call(n, arg1, arg2, 0, 0, 0, 0) })
+}
+
+/// Issues a raw system call with 3 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall3(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+) -> SyscallWord {
+    normalize(unsafe { call(n, arg1, arg2, arg3, 0, 0, 0) })
+}
+
+/// Issues a raw system call with 4 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall4(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+    arg4: SyscallWord,
+) -> SyscallWord {
+    normalize(unsafe { call(n, arg1, arg2, arg3, arg4, 0, 0) })
+}
+
+/// Issues a raw system call with 5 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall5(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+    arg4: SyscallWord,
+    arg5: SyscallWord,
+) -> SyscallWord {
+    normalize(unsafe { call(n, arg1, arg2, arg3, arg4, arg5, 0) })
+}
+
+/// Issues a raw system call with 6 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall6(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+    arg4: SyscallWord,
+    arg5: SyscallWord,
+    arg6: SyscallWord,
+) -> SyscallWord {
+    normalize(unsafe { call(n, arg1, arg2, arg3, arg4, arg5, arg6) })
+}
+
+#[inline]
+pub(crate) unsafe fn syscall0_checked(
+    n: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    unsafe { call(n, 0, 0, 0, 0, 0, 0) }
+}
+
+#[inline]
+pub(crate) unsafe fn syscall1_checked(
+    n: SyscallWord,
+    arg1: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    unsafe { call(n, arg1, 0, 0, 0, 0, 0) }
+}
+
+#[inline]
+pub(crate) unsafe fn syscall2_checked(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    unsafe { call(n, arg1, arg2, 0, 0, 0, 0) }
+}
+
+#[inline]
+pub(crate) unsafe fn syscall3_checked(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    unsafe { call(n, arg1, arg2, arg3, 0, 0, 0) }
+}
+
+#[inline]
+pub(crate) unsafe fn syscall4_checked(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+    arg4: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    unsafe { call(n, arg1, arg2, arg3, arg4, 0, 0) }
+}
+
+#[inline]
+pub(crate) unsafe fn syscall5_checked(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+    arg4: SyscallWord,
+    arg5: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    unsafe { call(n, arg1, arg2, arg3, arg4, arg5, 0) }
+}
+
+#[inline]
+pub(crate) unsafe fn syscall6_checked(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+    arg4: SyscallWord,
+    arg5: SyscallWord,
+    arg6: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    unsafe { call(n, arg1, arg2, arg3, arg4, arg5, arg6) }
+}
@@ -26,15 +26,37 @@
 //
 // %v0 is the syscall number.
 // %v0 is the return value.
+// %v1 is clobbered (it carries the second result of e.g. `pipe`).
 // %a3 is a boolean indicating that an error occurred.
 //
 //
 // All temporary registers are clobbered (8-15, 24-25).
+use crate::Errno;
 use core::arch::asm;
 
 /// System call argument/return type for MIPS (32-bit)
 pub type SyscallWord = u32;
 
+/// Folds a `($v0, $a3)` pair into the usual "negative errno" convention.
+#[inline(always)]
+fn normalize((ret, err): (SyscallWord, SyscallWord)) -> SyscallWord {
+    if err == 0 { ret } else { ret.wrapping_neg() }
+}
+
+/// Converts a `($v0, $a3)` pair into a `Result`. When `$a3` is set, `$v0`
+/// holds the (positive) errno; otherwise `$v0` is the result, even if it
+/// looks like a negative errno.
+#[inline(always)]
+fn to_result(
+    (ret, err): (SyscallWord, SyscallWord),
+) -> Result<SyscallWord, Errno> {
+    if err == 0 {
+        Ok(ret)
+    } else {
+        Err(Errno::new(ret as i32))
+    }
+}
+
 /// Issues a raw system call with 0 arguments.
 ///
 /// # Safety
@@ -43,6 +65,20 @@ pub type SyscallWord = u32;
 /// responsibility to ensure safety.
 #[inline]
 pub unsafe fn syscall0(n: SyscallWord) -> SyscallWord {
+    normalize(unsafe { syscall0_pair(n) })
+}
+
+/// Issues a system call with 0 arguments, taking the error status from `$a3`.
+#[inline]
+pub(crate) unsafe fn syscall0_checked(
+    n: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    to_result(unsafe { syscall0_pair(n) })
+}
+
+/// Issues a system call with 0 arguments and returns `($v0, $a3)`.
+#[inline]
+unsafe fn syscall0_pair(n: SyscallWord) -> (SyscallWord, SyscallWord) {
     let mut err: SyscallWord;
     let mut ret: SyscallWord;
     unsafe {
@@ -50,6 +86,7 @@ pub unsafe fn syscall0(n: SyscallWord) -> SyscallWord {
             "syscall",
             inlateout("$2") n => ret,
             lateout("$7") err,
+            lateout("$3") _,
             // All temporary registers are always clobbered
             lateout("$8") _,
             lateout("$9") _,
@@ -64,7 +101,7 @@ pub unsafe fn syscall0(n: SyscallWord) -> SyscallWord {
             options(nostack, preserves_flags)
         );
     }
-    if err == 0 { ret } else { ret.wrapping_neg() }
+    (ret, err)
 }
 
 /// Issues a raw system call with 1 argument.
@@ -75,6 +112,24 @@ pub unsafe fn syscall0(n: SyscallWord) -> SyscallWord {
 /// responsibility to ensure safety.
 #[inline]
 pub unsafe fn syscall1(n: SyscallWord, arg1: SyscallWord) -> SyscallWord {
+    normalize(unsafe { syscall1_pair(n, arg1) })
+}
+
+/// Issues a system call with 1 argument, taking the error status from `$a3`.
+#[inline]
+pub(crate) unsafe fn syscall1_checked(
+    n: SyscallWord,
+    arg1: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    to_result(unsafe { syscall1_pair(n, arg1) })
+}
+
+/// Issues a system call with 1 argument and returns `($v0, $a3)`.
+#[inline]
+unsafe fn syscall1_pair(
+    n: SyscallWord,
+    arg1: SyscallWord,
+) -> (SyscallWord, SyscallWord) {
     let mut err: SyscallWord;
     let mut ret: SyscallWord;
     unsafe {
@@ -82,6 +137,7 @@ pub unsafe fn syscall1(n: SyscallWord, arg1: SyscallWord) -> SyscallWord {
             "syscall",
             inlateout("$2") n => ret,
             lateout("$7") err,
+            lateout("$3") _,
             in("$4") arg1,
             // All temporary registers are always clobbered
             lateout("$8") _,
@@ -97,7 +153,7 @@ pub unsafe fn syscall1(n: SyscallWord, arg1: SyscallWord) -> SyscallWord {
             options(nostack, preserves_flags)
         );
     }
-    if err == 0 { ret } else { ret.wrapping_neg() }
+    (ret, err)
 }
 
 /// Issues a raw system call with 2 arguments.
@@ -112,6 +168,26 @@ pub unsafe fn syscall2(
     arg1: SyscallWord,
     arg2: SyscallWord,
 ) -> SyscallWord {
+    normalize(unsafe { syscall2_pair(n, arg1, arg2) })
+}
+
+/// Issues a system call with 2 arguments, taking the error status from `$a3`.
+#[inline]
+pub(crate) unsafe fn syscall2_checked(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    to_result(unsafe { syscall2_pair(n, arg1, arg2) })
+}
+
+/// Issues a system call with 2 arguments and returns `($v0, $a3)`.
+#[inline]
+unsafe fn syscall2_pair(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+) -> (SyscallWord, SyscallWord) {
     let mut err: SyscallWord;
     let mut ret: SyscallWord;
     unsafe {
@@ -119,6 +195,7 @@ pub unsafe fn syscall2(
             "syscall",
             inlateout("$2") n => ret,
             lateout("$7") err,
+            lateout("$3") _,
             in("$4") arg1,
             in("$5") arg2,
             // All temporary registers are always clobbered
@@ -135,7 +212,7 @@ pub unsafe fn syscall2(
             options(nostack, preserves_flags)
         );
     }
-    if err == 0 { ret } else { ret.wrapping_neg() }
+    (ret, err)
 }
 
 /// Issues a raw system call with 3 arguments.
@@ -151,6 +228,28 @@ pub unsafe fn syscall3(
     arg2: SyscallWord,
     arg3: SyscallWord,
 ) -> SyscallWord {
+    normalize(unsafe { syscall3_pair(n, arg1, arg2, arg3) })
+}
+
+/// Issues a system call with 3 arguments, taking the error status from `$a3`.
+#[inline]
+pub(crate) unsafe fn syscall3_checked(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    to_result(unsafe { syscall3_pair(n, arg1, arg2, arg3) })
+}
+
+/// Issues a system call with 3 arguments and returns `($v0, $a3)`.
+#[inline]
+unsafe fn syscall3_pair(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+) -> (SyscallWord, SyscallWord) {
     let mut err: SyscallWord;
     let mut ret: SyscallWord;
     unsafe {
@@ -158,6 +257,7 @@ pub unsafe fn syscall3(
             "syscall",
             inlateout("$2") n => ret,
             lateout("$7") err,
+            lateout("$3") _,
             in("$4") arg1,
             in("$5") arg2,
             in("$6") arg3,
@@ -175,7 +275,7 @@ pub unsafe fn syscall3(
             options(nostack, preserves_flags)
         );
     }
-    if err == 0 { ret } else { ret.wrapping_neg() }
+    (ret, err)
 }
 
 /// Issues a raw system call with 4 arguments.
@@ -192,6 +292,30 @@ pub unsafe fn syscall4(
     arg3: SyscallWord,
     arg4: SyscallWord,
 ) -> SyscallWord {
+    normalize(unsafe { syscall4_pair(n, arg1, arg2, arg3, arg4) })
+}
+
+/// Issues a system call with 4 arguments, taking the error status from `$a3`.
+#[inline]
+pub(crate) unsafe fn syscall4_checked(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+    arg4: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    to_result(unsafe { syscall4_pair(n, arg1, arg2, arg3, arg4) })
+}
+
+/// Issues a system call with 4 arguments and returns `($v0, $a3)`.
+#[inline]
+unsafe fn syscall4_pair(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+    arg4: SyscallWord,
+) -> (SyscallWord, SyscallWord) {
     let mut err: SyscallWord;
     let mut ret: SyscallWord;
     unsafe {
@@ -203,6 +327,7 @@ pub unsafe fn syscall4(
             in("$6") arg3,
             // $7 is now used for both input and output.
             inlateout("$7") arg4 => err,
+            lateout("$3") _,
             // All temporary registers are always clobbered
             lateout("$8") _,
             lateout("$9") _,
@@ -217,7 +342,7 @@ pub unsafe fn syscall4(
             options(nostack, preserves_flags)
         );
     }
-    if err == 0 { ret } else { ret.wrapping_neg() }
+    (ret, err)
 }
 
 /// Issues a raw system call with 5 arguments.
@@ -235,6 +360,32 @@ pub unsafe fn syscall5(
     arg4: SyscallWord,
     arg5: SyscallWord,
 ) -> SyscallWord {
+    normalize(unsafe { syscall5_pair(n, arg1, arg2, arg3, arg4, arg5) })
+}
+
+/// Issues a system call with 5 arguments, taking the error status from `$a3`.
+#[inline]
+pub(crate) unsafe fn syscall5_checked(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+    arg4: SyscallWord,
+    arg5: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    to_result(unsafe { syscall5_pair(n, arg1, arg2, arg3, arg4, arg5) })
+}
+
+/// Issues a system call with 5 arguments and returns `($v0, $a3)`.
+#[inline]
+unsafe fn syscall5_pair(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+    arg4: SyscallWord,
+    arg5: SyscallWord,
+) -> (SyscallWord, SyscallWord) {
     // NOTE: Arg numbers >=5 args get passed via the stack.
     let mut err: SyscallWord;
     let mut ret: SyscallWord;
@@ -256,6 +407,7 @@ pub unsafe fn syscall5(
             in("$6") arg3,
             // $7 is now used for both input and output.
             inlateout("$7") arg4 => err,
+            lateout("$3") _,
             // All temporary registers are always clobbered
             lateout("$8") _,
             lateout("$9") _,
@@ -270,7 +422,7 @@ pub unsafe fn syscall5(
             options(preserves_flags)
         );
     }
-    if err == 0 { ret } else { ret.wrapping_neg() }
+    (ret, err)
 }
 
 /// Issues a raw system call with 6 arguments.
@@ -289,6 +441,34 @@ pub unsafe fn syscall6(
     arg5: SyscallWord,
     arg6: SyscallWord,
 ) -> SyscallWord {
+    normalize(unsafe { syscall6_pair(n, arg1, arg2, arg3, arg4, arg5, arg6) })
+}
+
+/// Issues a system call with 6 arguments, taking the error status from `$a3`.
+#[inline]
+pub(crate) unsafe fn syscall6_checked(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+    arg4: SyscallWord,
+    arg5: SyscallWord,
+    arg6: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    to_result(unsafe { syscall6_pair(n, arg1, arg2, arg3, arg4, arg5, arg6) })
+}
+
+/// Issues a system call with 6 arguments and returns `($v0, $a3)`.
+#[inline]
+unsafe fn syscall6_pair(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+    arg4: SyscallWord,
+    arg5: SyscallWord,
+    arg6: SyscallWord,
+) -> (SyscallWord, SyscallWord) {
     // Things get trickier with >=5 args. arg5 and arg6 are now passed via the
     // stack.
     let mut err: SyscallWord;
@@ -313,6 +493,7 @@ pub unsafe fn syscall6(
             in("$6") arg3,
             // $7 is now used for both input and output.
             inlateout("$7") arg4 => err,
+            lateout("$3") _,
             // All temporary registers are always clobbered
             lateout("$8") _,
             lateout("$9") _,
@@ -327,7 +508,7 @@ pub unsafe fn syscall6(
             options(preserves_flags)
         );
     }
-    if err == 0 { ret } else { ret.wrapping_neg() }
+    (ret, err)
 }
 
 /// Issues a raw system call with 7 arguments.
@@ -348,6 +529,24 @@ pub unsafe fn syscall7(
     arg6: SyscallWord,
     arg7: SyscallWord,
 ) -> SyscallWord {
+    normalize(unsafe {
+        syscall7_pair(n, arg1, arg2, arg3, arg4, arg5, arg6, arg7)
+    })
+}
+
+/// Issues a system call with 7 arguments and returns `($v0, $a3)`.
+#[allow(unused)]
+#[inline]
+unsafe fn syscall7_pair(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+    arg4: SyscallWord,
+    arg5: SyscallWord,
+    arg6: SyscallWord,
+    arg7: SyscallWord,
+) -> (SyscallWord, SyscallWord) {
     // Things get trickier with >=5 args. arg5 and arg6 are now passed via the
     // stack.
     let mut err: SyscallWord;
@@ -374,6 +573,7 @@ pub unsafe fn syscall7(
             in("$6") arg3,
             // $7 is now used for both input and output.
             inlateout("$7") arg4 => err,
+            lateout("$3") _,
             // All temporary registers are always clobbered
             lateout("$8") _,
             lateout("$9") _,
@@ -388,5 +588,71 @@ pub unsafe fn syscall7(
             options(preserves_flags)
         );
     }
-    if err == 0 { ret } else { ret.wrapping_neg() }
+    (ret, err)
+}
+
+/// Issues a system call with 0 arguments that also returns a second result in
+/// `$v1` (e.g. the legacy `pipe`), taking the error status from `$a3`.
+#[inline]
+pub(crate) unsafe fn syscall0_2ret(
+    n: SyscallWord,
+) -> Result<(SyscallWord, SyscallWord), Errno> {
+    let mut err: SyscallWord;
+    let mut ret: SyscallWord;
+    let mut ret2: SyscallWord;
+    unsafe {
+        asm!(
+            "syscall",
+            inlateout("$2") n => ret,
+            lateout("$3") ret2,
+            lateout("$7") err,
+            // All temporary registers are always clobbered
+            lateout("$8") _,
+            lateout("$9") _,
+            lateout("$10") _,
+            lateout("$11") _,
+            lateout("$12") _,
+            lateout("$13") _,
+            lateout("$14") _,
+            lateout("$15") _,
+            lateout("$24") _,
+            lateout("$25") _,
+            options(nostack, preserves_flags)
+        );
+    }
+    to_result((ret, err)).map(|ret| (ret, ret2))
+}
+
+/// Issues a system call with 1 argument that also returns a second result in
+/// `$v1` (e.g. the legacy `pipe`), taking the error status from `$a3`.
+#[inline]
+pub(crate) unsafe fn syscall1_2ret(
+    n: SyscallWord,
+    arg1: SyscallWord,
+) -> Result<(SyscallWord, SyscallWord), Errno> {
+    let mut err: SyscallWord;
+    let mut ret: SyscallWord;
+    let mut ret2: SyscallWord;
+    unsafe {
+        asm!(
+            "syscall",
+            inlateout("$2") n => ret,
+            lateout("$3") ret2,
+            lateout("$7") err,
+            in("$4") arg1,
+            // All temporary registers are always clobbered
+            lateout("$8") _,
+            lateout("$9") _,
+            lateout("$10") _,
+            lateout("$11") _,
+            lateout("$12") _,
+            lateout("$13") _,
+            lateout("$14") _,
+            lateout("$15") _,
+            lateout("$24") _,
+            lateout("$25") _,
+            options(nostack, preserves_flags)
+        );
+    }
+    to_result((ret, err)).map(|ret| (ret, ret2))
 }
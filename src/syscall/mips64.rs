@@ -25,6 +25,7 @@
 //
 // %v0 is the syscall number.
 // %v0 is the return value.
+// %v1 is clobbered (it carries the second result of e.g. `pipe`).
 // %a3 is a boolean indicating that an error occurred.
 //
 // All temporary registers are clobbered (8-15, 24-25).
@@ -32,11 +33,32 @@
 // NOTE: The main difference between MIPS and MIPS64 is that MIPS64 doesn't use
 // the stack to pass in args 5-6. Instead, it uses the temporary registers t0
 // and t1, which still get clobbered.
+use crate::Errno;
 use core::arch::asm;
 
 /// System call argument/return type for mips64 (64-bit)
 pub type SyscallWord = u64;
 
+/// Folds a `($v0, $a3)` pair into the usual "negative errno" convention.
+#[inline(always)]
+fn normalize((ret, err): (SyscallWord, SyscallWord)) -> SyscallWord {
+    if err == 0 { ret } else { ret.wrapping_neg() }
+}
+
+/// Converts a `($v0, $a3)` pair into a `Result`. When `$a3` is set, `$v0`
+/// holds the (positive) errno; otherwise `$v0` is the result, even if it
+/// looks like a negative errno.
+#[inline(always)]
+fn to_result(
+    (ret, err): (SyscallWord, SyscallWord),
+) -> Result<SyscallWord, Errno> {
+    if err == 0 {
+        Ok(ret)
+    } else {
+        Err(Errno::new(ret as i32))
+    }
+}
+
 /// Issues a raw system call with 0 arguments.
 ///
 /// # Safety
@@ -45,6 +67,20 @@ pub type SyscallWord = u64;
 /// responsibility to ensure safety.
 #[inline]
 pub unsafe fn syscall0(n: SyscallWord) -> SyscallWord {
+    normalize(unsafe { syscall0_pair(n) })
+}
+
+/// Issues a system call with 0 arguments, taking the error status from `$a3`.
+#[inline]
+pub(crate) unsafe fn syscall0_checked(
+    n: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    to_result(unsafe { syscall0_pair(n) })
+}
+
+/// Issues a system call with 0 arguments and returns `($v0, $a3)`.
+#[inline]
+unsafe fn syscall0_pair(n: SyscallWord) -> (SyscallWord, SyscallWord) {
     let mut err: SyscallWord;
     let mut ret: SyscallWord;
     unsafe {
@@ -52,6 +88,7 @@ pub unsafe fn syscall0(n: SyscallWord) -> SyscallWord {
             "syscall",
             inlateout("$2") n => ret,
             lateout("$7") err,
+            lateout("$3") _,
             // All temporary registers are always clobbered
             lateout("$8") _,
             lateout("$9") _,
@@ -66,7 +103,7 @@ pub unsafe fn syscall0(n: SyscallWord) -> SyscallWord {
             options(nostack, preserves_flags)
         );
     }
-    if err == 0 { ret } else { ret.wrapping_neg() }
+    (ret, err)
 }
 
 /// Issues a raw system call with 1 argument.
@@ -77,6 +114,24 @@ pub unsafe fn syscall0(n: SyscallWord) -> SyscallWord {
 /// responsibility to ensure safety.
 #[inline]
 pub unsafe fn syscall1(n: SyscallWord, arg1: SyscallWord) -> SyscallWord {
+    normalize(unsafe { syscall1_pair(n, arg1) })
+}
+
+/// Issues a system call with 1 argument, taking the error status from `$a3`.
+#[inline]
+pub(crate) unsafe fn syscall1_checked(
+    n: SyscallWord,
+    arg1: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    to_result(unsafe { syscall1_pair(n, arg1) })
+}
+
+/// Issues a system call with 1 argument and returns `($v0, $a3)`.
+#[inline]
+unsafe fn syscall1_pair(
+    n: SyscallWord,
+    arg1: SyscallWord,
+) -> (SyscallWord, SyscallWord) {
     let mut err: SyscallWord;
     let mut ret: SyscallWord;
     unsafe {
@@ -84,6 +139,7 @@ pub unsafe fn syscall1(n: SyscallWord, arg1: SyscallWord) -> SyscallWord {
             "syscall",
             inlateout("$2") n => ret,
             lateout("$7") err,
+            lateout("$3") _,
             in("$4") arg1,
             // All temporary registers are always clobbered
             lateout("$8") _,
@@ -99,7 +155,7 @@ pub unsafe fn syscall1(n: SyscallWord, arg1: SyscallWord) -> SyscallWord {
             options(nostack, preserves_flags)
         );
     }
-    if err == 0 { ret } else { ret.wrapping_neg() }
+    (ret, err)
 }
 
 /// Issues a raw system call with 2 arguments.
@@ -114,6 +170,26 @@ pub unsafe fn syscall2(
     arg1: SyscallWord,
     arg2: SyscallWord,
 ) -> SyscallWord {
+    normalize(unsafe { syscall2_pair(n, arg1, arg2) })
+}
+
+/// Issues a system call with 2 arguments, taking the error status from `$a3`.
+#[inline]
+pub(crate) unsafe fn syscall2_checked(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    to_result(unsafe { syscall2_pair(n, arg1, arg2) })
+}
+
+/// Issues a system call with 2 arguments and returns `($v0, $a3)`.
+#[inline]
+unsafe fn syscall2_pair(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+) -> (SyscallWord, SyscallWord) {
     let mut err: SyscallWord;
     let mut ret: SyscallWord;
     unsafe {
@@ -121,6 +197,7 @@ pub unsafe fn syscall2(
             "syscall",
             inlateout("$2") n => ret,
             lateout("$7") err,
+            lateout("$3") _,
             in("$4") arg1,
             in("$5") arg2,
             // All temporary registers are always clobbered
@@ -137,7 +214,7 @@ pub unsafe fn syscall2(
             options(nostack, preserves_flags)
         );
     }
-    if err == 0 { ret } else { ret.wrapping_neg() }
+    (ret, err)
 }
 
 /// Issues a raw system call with 3 arguments.
@@ -153,6 +230,28 @@ pub unsafe fn syscall3(
     arg2: SyscallWord,
     arg3: SyscallWord,
 ) -> SyscallWord {
+    normalize(unsafe { syscall3_pair(n, arg1, arg2, arg3) })
+}
+
+/// Issues a system call with 3 arguments, taking the error status from `$a3`.
+#[inline]
+pub(crate) unsafe fn syscall3_checked(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    to_result(unsafe { syscall3_pair(n, arg1, arg2, arg3) })
+}
+
+/// Issues a system call with 3 arguments and returns `($v0, $a3)`.
+#[inline]
+unsafe fn syscall3_pair(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+) -> (SyscallWord, SyscallWord) {
     let mut err: SyscallWord;
     let mut ret: SyscallWord;
     unsafe {
@@ -160,6 +259,7 @@ pub unsafe fn syscall3(
             "syscall",
             inlateout("$2") n => ret,
             lateout("$7") err,
+            lateout("$3") _,
             in("$4") arg1,
             in("$5") arg2,
             in("$6") arg3,
@@ -177,7 +277,7 @@ pub unsafe fn syscall3(
             options(nostack, preserves_flags)
         );
     }
-    if err == 0 { ret } else { ret.wrapping_neg() }
+    (ret, err)
 }
 
 /// Issues a raw system call with 4 arguments.
@@ -194,6 +294,30 @@ pub unsafe fn syscall4(
     arg3: SyscallWord,
     arg4: SyscallWord,
 ) -> SyscallWord {
+    normalize(unsafe { syscall4_pair(n, arg1, arg2, arg3, arg4) })
+}
+
+/// Issues a system call with 4 arguments, taking the error status from `$a3`.
+#[inline]
+pub(crate) unsafe fn syscall4_checked(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+    arg4: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    to_result(unsafe { syscall4_pair(n, arg1, arg2, arg3, arg4) })
+}
+
+/// Issues a system call with 4 arguments and returns `($v0, $a3)`.
+#[inline]
+unsafe fn syscall4_pair(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+    arg4: SyscallWord,
+) -> (SyscallWord, SyscallWord) {
     let mut err: SyscallWord;
     let mut ret: SyscallWord;
     unsafe {
@@ -205,6 +329,7 @@ pub unsafe fn syscall4(
             in("$6") arg3,
             // $7 is now used for both input and output.
             inlateout("$7") arg4 => err,
+            lateout("$3") _,
             // All temporary registers are always clobbered
             lateout("$8") _,
             lateout("$9") _,
@@ -219,7 +344,7 @@ pub unsafe fn syscall4(
             options(nostack, preserves_flags)
         );
     }
-    if err == 0 { ret } else { ret.wrapping_neg() }
+    (ret, err)
 }
 
 /// Issues a raw system call with 5 arguments.
@@ -237,6 +362,32 @@ pub unsafe fn syscall5(
     arg4: SyscallWord,
     arg5: SyscallWord,
 ) -> SyscallWord {
+    normalize(unsafe { syscall5_pair(n, arg1, arg2, arg3, arg4, arg5) })
+}
+
+/// Issues a system call with 5 arguments, taking the error status from `$a3`.
+#[inline]
+pub(crate) unsafe fn syscall5_checked(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+    arg4: SyscallWord,
+    arg5: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    to_result(unsafe { syscall5_pair(n, arg1, arg2, arg3, arg4, arg5) })
+}
+
+/// Issues a system call with 5 arguments and returns `($v0, $a3)`.
+#[inline]
+unsafe fn syscall5_pair(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+    arg4: SyscallWord,
+    arg5: SyscallWord,
+) -> (SyscallWord, SyscallWord) {
     let mut err: SyscallWord;
     let mut ret: SyscallWord;
     unsafe {
@@ -248,6 +399,7 @@ pub unsafe fn syscall5(
             in("$6") arg3,
             // $7 is now used for both input and output.
             inlateout("$7") arg4 => err,
+            lateout("$3") _,
             inlateout("$8") arg5 => _,
             // All temporary registers are always clobbered
             lateout("$9") _,
@@ -262,7 +414,7 @@ pub unsafe fn syscall5(
             options(nostack, preserves_flags)
         );
     }
-    if err == 0 { ret } else { ret.wrapping_neg() }
+    (ret, err)
 }
 
 /// Issues a raw system call with 6 arguments.
@@ -281,6 +433,34 @@ pub unsafe fn syscall6(
     arg5: SyscallWord,
     arg6: SyscallWord,
 ) -> SyscallWord {
+    normalize(unsafe { syscall6_pair(n, arg1, arg2, arg3, arg4, arg5, arg6) })
+}
+
+/// Issues a system call with 6 arguments, taking the error status from `$a3`.
+#[inline]
+pub(crate) unsafe fn syscall6_checked(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+    arg4: SyscallWord,
+    arg5: SyscallWord,
+    arg6: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    to_result(unsafe { syscall6_pair(n, arg1, arg2, arg3, arg4, arg5, arg6) })
+}
+
+/// Issues a system call with 6 arguments and returns `($v0, $a3)`.
+#[inline]
+unsafe fn syscall6_pair(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+    arg4: SyscallWord,
+    arg5: SyscallWord,
+    arg6: SyscallWord,
+) -> (SyscallWord, SyscallWord) {
     let mut err: SyscallWord;
     let mut ret: SyscallWord;
     unsafe {
@@ -292,6 +472,7 @@ pub unsafe fn syscall6(
             in("$6") arg3,
             // $7 is now used for both input and output.
             inlateout("$7") arg4 => err,
+            lateout("$3") _,
             inlateout("$8") arg5 => _,
             inlateout("$9") arg6 => _,
             // All temporary registers are always clobbered
@@ -306,5 +487,71 @@ pub unsafe fn syscall6(
             options(nostack, preserves_flags)
         );
     }
-    if err == 0 { ret } else { ret.wrapping_neg() }
+    (ret, err)
+}
+
+/// Issues a system call with 0 arguments that also returns a second result in
+/// `$v1` (e.g. the legacy `pipe`), taking the error status from `$a3`.
+#[inline]
+pub(crate) unsafe fn syscall0_2ret(
+    n: SyscallWord,
+) -> Result<(SyscallWord, SyscallWord), Errno> {
+    let mut err: SyscallWord;
+    let mut ret: SyscallWord;
+    let mut ret2: SyscallWord;
+    unsafe {
+        asm!(
+            "syscall",
+            inlateout("$2") n => ret,
+            lateout("$3") ret2,
+            lateout("$7") err,
+            // All temporary registers are always clobbered
+            lateout("$8") _,
+            lateout("$9") _,
+            lateout("$10") _,
+            lateout("$11") _,
+            lateout("$12") _,
+            lateout("$13") _,
+            lateout("$14") _,
+            lateout("$15") _,
+            lateout("$24") _,
+            lateout("$25") _,
+            options(nostack, preserves_flags)
+        );
+    }
+    to_result((ret, err)).map(|ret| (ret, ret2))
+}
+
+/// Issues a system call with 1 argument that also returns a second result in
+/// `$v1` (e.g. the legacy `pipe`), taking the error status from `$a3`.
+#[inline]
+pub(crate) unsafe fn syscall1_2ret(
+    n: SyscallWord,
+    arg1: SyscallWord,
+) -> Result<(SyscallWord, SyscallWord), Errno> {
+    let mut err: SyscallWord;
+    let mut ret: SyscallWord;
+    let mut ret2: SyscallWord;
+    unsafe {
+        asm!(
+            "syscall",
+            inlateout("$2") n => ret,
+            lateout("$3") ret2,
+            lateout("$7") err,
+            in("$4") arg1,
+            // All temporary registers are always clobbered
+            lateout("$8") _,
+            lateout("$9") _,
+            lateout("$10") _,
+            lateout("$11") _,
+            lateout("$12") _,
+            lateout("$13") _,
+            lateout("$14") _,
+            lateout("$15") _,
+            lateout("$24") _,
+            lateout("$25") _,
+            options(nostack, preserves_flags)
+        );
+    }
+    to_result((ret, err)).map(|ret| (ret, ret2))
 }
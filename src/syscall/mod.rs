@@ -10,96 +10,187 @@
 //! - For ARM, `thumb-mode` is detected by `build.rs` and enabled via a config
 //!   flag so that the correct instruction encoding is generated.
 //!
+//! - With the `outline-asm` feature, architectures whose inline assembly is
+//!   still unstable use `outline.rs` instead, which calls a C stub built by
+//!   `build.rs`.
+//!
+//! - With the `stub-section` feature, `stub.rs` issues every syscall from a
+//!   single out-of-line stub in its own linker section (see `raw::stub_region`).
+//!
+//! - With the `libc-backend` feature, every architecture uses `libc.rs`
+//!   instead, which forwards to `libc::syscall`. It takes precedence over all
+//!   of the above.
+//!
+//! - With the `tables-only` feature, or when the target OS is not Linux,
+//!   `none.rs` only defines `SyscallWord` and no syscalls can be made.
+//!
+//! - `build.rs` picks one of these and sets the `rawsys_backend` cfg to
+//!   `"inline"`, `"outline"`, `"stub"`, `"libc"`, or `"none"`.
+//!
+//! - Each backend also provides crate-internal `syscallN_checked` functions
+//!   that return `Result<SyscallWord, Errno>`. Most architectures share the
+//!   generic "negative errno" conversion in `checked.rs`; architectures that
+//!   flag errors separately (MIPS `$a3`, the SPARC carry bit) decode the flag
+//!   themselves.
+//!
 //! Unless you are writing arch-specific code, prefer using the top-level
 //! `syscall!`/`raw_syscall!` macros and `syscallN` wrappers re-exported by the
 //! crate root; those pick the correct backend automatically.
 //!
 #![allow(clippy::doc_markdown, clippy::pedantic)]
 
-#[cfg(target_arch = "aarch64")]
+#[cfg(all(target_arch = "aarch64", rawsys_backend = "inline"))]
 mod aarch64;
 #[cfg(all(
     target_arch = "arm",
-    not(any(target_feature = "thumb-mode", feature = "thumb-mode"))
+    not(any(target_feature = "thumb-mode", feature = "thumb-mode")),
+    rawsys_backend = "inline"
 ))]
 mod arm;
 #[cfg(all(
     target_arch = "arm",
-    any(target_feature = "thumb-mode", feature = "thumb-mode")
+    any(target_feature = "thumb-mode", feature = "thumb-mode"),
+    rawsys_backend = "inline"
 ))]
 mod arm_thumb;
-#[cfg(target_arch = "loongarch64")]
+#[cfg(not(rawsys_backend = "none"))]
+pub(crate) mod auxv;
+#[cfg(not(any(
+    all(
+        any(
+            target_arch = "mips",
+            target_arch = "mips64",
+            target_arch = "sparc",
+            target_arch = "sparc64"
+        ),
+        rawsys_backend = "inline"
+    ),
+    rawsys_backend = "outline",
+    rawsys_backend = "libc",
+    rawsys_backend = "none"
+)))]
+mod checked;
+#[cfg(rawsys_backend = "libc")]
+mod libc;
+#[cfg(all(target_arch = "loongarch64", rawsys_backend = "inline"))]
 mod loongarch64;
-#[cfg(target_arch = "mips")]
+#[cfg(all(target_arch = "mips", rawsys_backend = "inline"))]
 mod mips;
-#[cfg(target_arch = "mips64")]
+#[cfg(all(target_arch = "mips64", rawsys_backend = "inline"))]
 mod mips64;
-#[cfg(target_arch = "powerpc")]
+#[cfg(rawsys_backend = "none")]
+mod none;
+#[cfg(rawsys_backend = "outline")]
+mod outline;
+#[cfg(all(target_arch = "powerpc", rawsys_backend = "inline"))]
 mod powerpc;
-#[cfg(target_arch = "powerpc64")]
+#[cfg(all(target_arch = "powerpc64", rawsys_backend = "inline"))]
 mod powerpc64;
-#[cfg(target_arch = "riscv32")]
+#[cfg(all(target_arch = "riscv32", rawsys_backend = "inline"))]
 mod riscv32;
-#[cfg(target_arch = "riscv64")]
+#[cfg(all(target_arch = "riscv64", rawsys_backend = "inline"))]
 mod riscv64;
-#[cfg(target_arch = "s390x")]
+#[cfg(all(target_arch = "s390x", rawsys_backend = "inline"))]
 mod s390x;
-#[cfg(target_arch = "sparc")]
+#[cfg(all(target_arch = "sparc", rawsys_backend = "inline"))]
 mod sparc;
-#[cfg(target_arch = "sparc64")]
+#[cfg(all(target_arch = "sparc64", rawsys_backend = "inline"))]
 mod sparc64;
-#[cfg(target_arch = "x86")]
+#[cfg(rawsys_backend = "stub")]
+mod stub;
+#[cfg(all(target_arch = "x86", rawsys_backend = "inline"))]
 mod x86;
-#[cfg(target_arch = "x86_64")]
+#[cfg(all(target_arch = "x86_64", rawsys_backend = "inline"))]
 mod x86_64;
 
-#[cfg(target_arch = "aarch64")]
+#[cfg(all(target_arch = "aarch64", rawsys_backend = "inline"))]
 pub use aarch64::*;
 
 #[cfg(all(
     target_arch = "arm",
-    not(any(target_feature = "thumb-mode", feature = "thumb-mode"))
+    not(any(target_feature = "thumb-mode", feature = "thumb-mode")),
+    rawsys_backend = "inline"
 ))]
 pub use arm::*;
 
 #[cfg(all(
     target_arch = "arm",
-    any(target_feature = "thumb-mode", feature = "thumb-mode")
+    any(target_feature = "thumb-mode", feature = "thumb-mode"),
+    rawsys_backend = "inline"
 ))]
 pub use arm_thumb::*;
 
-#[cfg(target_arch = "loongarch64")]
+#[cfg(all(target_arch = "loongarch64", rawsys_backend = "inline"))]
 pub use loongarch64::*;
 
-#[cfg(target_arch = "mips")]
+#[cfg(all(target_arch = "mips", rawsys_backend = "inline"))]
 pub use mips::*;
 
-#[cfg(target_arch = "mips64")]
+#[cfg(all(target_arch = "mips64", rawsys_backend = "inline"))]
 pub use mips64::*;
 
-#[cfg(target_arch = "powerpc")]
+#[cfg(all(target_arch = "powerpc", rawsys_backend = "inline"))]
 pub use powerpc::*;
 
-#[cfg(target_arch = "powerpc64")]
+#[cfg(all(target_arch = "powerpc64", rawsys_backend = "inline"))]
 pub use powerpc64::*;
 
-#[cfg(target_arch = "riscv32")]
+#[cfg(all(target_arch = "riscv32", rawsys_backend = "inline"))]
 pub use riscv32::*;
 
-#[cfg(target_arch = "riscv64")]
+#[cfg(all(target_arch = "riscv64", rawsys_backend = "inline"))]
 pub use riscv64::*;
 
-#[cfg(target_arch = "s390x")]
+#[cfg(all(target_arch = "s390x", rawsys_backend = "inline"))]
 pub use s390x::*;
 
-#[cfg(target_arch = "sparc")]
+#[cfg(all(target_arch = "sparc", rawsys_backend = "inline"))]
 pub use sparc::*;
 
-#[cfg(target_arch = "sparc64")]
+#[cfg(all(target_arch = "sparc64", rawsys_backend = "inline"))]
 pub use sparc64::*;
 
-#[cfg(target_arch = "x86")]
+#[cfg(all(target_arch = "x86", rawsys_backend = "inline"))]
 pub use x86::*;
 
-#[cfg(target_arch = "x86_64")]
+#[cfg(all(target_arch = "x86_64", rawsys_backend = "inline"))]
 pub use x86_64::*;
+
+#[cfg(rawsys_backend = "outline")]
+pub use outline::*;
+
+#[cfg(rawsys_backend = "libc")]
+pub use self::libc::*;
+
+#[cfg(rawsys_backend = "stub")]
+pub use stub::*;
+
+#[cfg(rawsys_backend = "none")]
+pub use none::*;
+
+#[cfg(not(any(
+    all(
+        any(
+            target_arch = "mips",
+            target_arch = "mips64",
+            target_arch = "sparc",
+            target_arch = "sparc64"
+        ),
+        rawsys_backend = "inline"
+    ),
+    rawsys_backend = "outline",
+    rawsys_backend = "libc",
+    rawsys_backend = "none"
+)))]
+pub(crate) use checked::*;
+
+#[cfg(all(test, not(rawsys_backend = "none")))]
+mod tests {
+    #[test]
+    fn getauxval_pagesz() {
+        const AT_PAGESZ: usize = 6;
+        let pagesz = super::auxv::getauxval(AT_PAGESZ).unwrap();
+        assert!(pagesz.is_power_of_two());
+        assert_eq!(super::auxv::getauxval(usize::MAX - 1), None);
+    }
+}
@@ -0,0 +1,12 @@
+// Tables-only mode, used when the target is not Linux or the `tables-only`
+// feature is enabled. There is no way to enter the kernel here, so only the
+// argument type is defined; everything that issues syscalls is compiled out
+// at the crate root.
+
+/// System call argument/return type (matches the asm backend for the target)
+#[cfg(any(target_pointer_width = "64", target_arch = "x86_64"))]
+pub type SyscallWord = u64;
+
+/// System call argument/return type (matches the asm backend for the target)
+#[cfg(not(any(target_pointer_width = "64", target_arch = "x86_64")))]
+pub type SyscallWord = u32;
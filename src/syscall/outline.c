@@ -0,0 +1,122 @@
+/*
+ * Out-of-line syscall stub for the `outline-asm` feature.
+ *
+ * Rust's `asm!` is still unstable on these architectures, so the syscall
+ * instruction is issued from C (GCC-style inline asm) instead and linked in
+ * as a regular `extern "C"` function. Every call passes all six arguments;
+ * the kernel ignores the ones a syscall does not use.
+ *
+ * The result is normalized to the "negative errno" convention, and `*err` is
+ * set to the architecture's own error indicator so that large successful
+ * results are not mistaken for errors.
+ */
+
+typedef unsigned long word;
+
+word rawsys_linux_syscall6(word n, word a1, word a2, word a3, word a4,
+                           word a5, word a6, word *err)
+{
+#if defined(__mips__) && _MIPS_SIM == _ABIO32
+	/* o32 passes args 5-6 on the stack; $a3 is the error flag. */
+	register word r2 __asm__("$2");
+	register word r4 __asm__("$4") = a1;
+	register word r5 __asm__("$5") = a2;
+	register word r6 __asm__("$6") = a3;
+	register word r7 __asm__("$7") = a4;
+	register word r8 __asm__("$8") = a5;
+	register word r9 __asm__("$9") = a6;
+	__asm__ __volatile__(
+		"subu $sp, $sp, 32\n\t"
+		"sw $8, 16($sp)\n\t"
+		"sw $9, 20($sp)\n\t"
+		"addu $2, $0, %4\n\t"
+		"syscall\n\t"
+		"addu $sp, $sp, 32"
+		: "=&r"(r2), "+r"(r7), "+r"(r8), "+r"(r9)
+		: "r"(n), "r"(r4), "r"(r5), "r"(r6)
+		: "$1", "$3", "$10", "$11", "$12", "$13", "$14", "$15",
+		  "$24", "$25", "hi", "lo", "memory");
+	*err = r7 != 0;
+	return r7 ? -r2 : r2;
+#elif defined(__mips__)
+	/* n64: args in $a0-$a5; $a3 is the error flag. */
+	register word r2 __asm__("$2") = n;
+	register word r4 __asm__("$4") = a1;
+	register word r5 __asm__("$5") = a2;
+	register word r6 __asm__("$6") = a3;
+	register word r7 __asm__("$7") = a4;
+	register word r8 __asm__("$8") = a5;
+	register word r9 __asm__("$9") = a6;
+	__asm__ __volatile__(
+		"syscall"
+		: "+r"(r2), "+r"(r7), "+r"(r8), "+r"(r9)
+		: "r"(r4), "r"(r5), "r"(r6)
+		: "$1", "$3", "$10", "$11", "$12", "$13", "$14", "$15",
+		  "$24", "$25", "hi", "lo", "memory");
+	*err = r7 != 0;
+	return r7 ? -r2 : r2;
+#elif defined(__powerpc__)
+	/* cr0.SO is set on error. */
+	register word r0 __asm__("r0") = n;
+	register word r3 __asm__("r3") = a1;
+	register word r4 __asm__("r4") = a2;
+	register word r5 __asm__("r5") = a3;
+	register word r6 __asm__("r6") = a4;
+	register word r7 __asm__("r7") = a5;
+	register word r8 __asm__("r8") = a6;
+	word e = 0;
+	__asm__ __volatile__(
+		"sc\n\t"
+		"bns+ 1f\n\t"
+		"li %7, 1\n\t"
+		"neg %1, %1\n"
+		"1:"
+		: "+r"(r0), "+r"(r3), "+r"(r4), "+r"(r5), "+r"(r6),
+		  "+r"(r7), "+r"(r8), "+r"(e)
+		:
+		: "r9", "r10", "r11", "r12", "cr0", "ctr", "memory");
+	*err = e;
+	return r3;
+#elif defined(__s390x__)
+	/* No error flag; errors are returned as -4095..-1. */
+	register word r1 __asm__("r1") = n;
+	register word r2 __asm__("r2") = a1;
+	register word r3 __asm__("r3") = a2;
+	register word r4 __asm__("r4") = a3;
+	register word r5 __asm__("r5") = a4;
+	register word r6 __asm__("r6") = a5;
+	register word r7 __asm__("r7") = a6;
+	__asm__ __volatile__(
+		"svc 0"
+		: "+r"(r2)
+		: "r"(r1), "r"(r3), "r"(r4), "r"(r5), "r"(r6), "r"(r7)
+		: "memory");
+	*err = r2 > -4096UL;
+	return r2;
+#elif defined(__sparc__)
+	/* The carry bit is set on error and %o0 holds a positive errno. */
+	register word g1 __asm__("g1") = n;
+	register word o0 __asm__("o0") = a1;
+	register word o1 __asm__("o1") = a2;
+	register word o2 __asm__("o2") = a3;
+	register word o3 __asm__("o3") = a4;
+	register word o4 __asm__("o4") = a5;
+	register word o5 __asm__("o5") = a6;
+	word e = 0;
+	__asm__ __volatile__(
+#if defined(__arch64__)
+		"ta 0x6d\n\t"
+		"movcs %%xcc, 1, %2"
+#else
+		"ta 0x10\n\t"
+		"addx %%g0, 0, %2"
+#endif
+		: "+r"(o0), "+r"(o1), "+r"(e)
+		: "r"(g1), "r"(o2), "r"(o3), "r"(o4), "r"(o5)
+		: "cc", "memory");
+	*err = e;
+	return e ? -o0 : o0;
+#else
+#error "outline-asm: unsupported architecture"
+#endif
+}
@@ -0,0 +1,240 @@
+// Out-of-line backend, used instead of the inline `asm!` backends when the
+// `outline-asm` feature is enabled on an architecture whose inline assembly
+// is not yet stable (mips, mips64, powerpc, powerpc64, s390x, sparc,
+// sparc64). See `outline.c` for the per-arch stubs, which `build.rs` compiles
+// with the `cc` crate.
+//
+// Every syscall goes through a single six-argument stub; unused arguments are
+// passed as 0. The stub returns the result in the "negative errno" convention
+// and separately reports the architecture's error indicator, which is used by
+// the `_checked` functions.
+use crate::Errno;
+
+/// System call argument/return type (pointer-sized on these targets)
+#[cfg(target_pointer_width = "32")]
+pub type SyscallWord = u32;
+
+/// System call argument/return type (pointer-sized on these targets)
+#[cfg(target_pointer_width = "64")]
+pub type SyscallWord = u64;
+
+unsafe extern "C" {
+    fn rawsys_linux_syscall6(
+        n: SyscallWord,
+        arg1: SyscallWord,
+        arg2: SyscallWord,
+        arg3: SyscallWord,
+        arg4: SyscallWord,
+        arg5: SyscallWord,
+        arg6: SyscallWord,
+        err: *mut SyscallWord,
+    ) -> SyscallWord;
+}
+
+/// Calls the stub and returns `(ret, err)`.
+#[inline(always)]
+unsafe fn pair(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+    arg4: SyscallWord,
+    arg5: SyscallWord,
+    arg6: SyscallWord,
+) -> (SyscallWord, SyscallWord) {
+    let mut err: SyscallWord = 0;
+    let ret = unsafe {
+        rawsys_linux_syscall6(n, arg1, arg2, arg3, arg4, arg5, arg6, &mut err)
+    };
+    (ret, err)
+}
+
+/// Converts a `(ret, err)` pair into a `Result`. `ret` is already a negative
+/// errno when `err` is set.
+#[inline(always)]
+fn to_result(
+    (ret, err): (SyscallWord, SyscallWord),
+) -> Result<SyscallWord, Errno> {
+    if err == 0 {
+        Ok(ret)
+    } else {
+        Err(Errno::new(ret.wrapping_neg() as i32))
+    }
+}
+
+/// Issues a raw system call with 0 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall0(n: SyscallWord) -> SyscallWord {
+    unsafe { pair(n, 0, 0, 0, 0, 0, 0) }.0
+}
+
+/// Issues a raw system call with 1 argument.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall1(n: SyscallWord, arg1: SyscallWord) -> SyscallWord {
+    unsafe { pair(n, arg1, 0, 0, 0, 0, 0) }.0
+}
+
+/// Issues a raw system call with 2 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall2(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+) -> SyscallWord {
+    unsafe { pair(n, arg1, arg2, 0, 0, 0, 0) }.0
+}
+
+/// Issues a raw system call with 3 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall3(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+) -> SyscallWord {
+    unsafe { pair(n, arg1, arg2, arg3, 0, 0, 0) }.0
+}
+
+/// Issues a raw system call with 4 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall4(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+    arg4: SyscallWord,
+) -> SyscallWord {
+    unsafe { pair(n, arg1, arg2, arg3, arg4, 0, 0) }.0
+}
+
+/// Issues a raw system call with 5 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall5(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+    arg4: SyscallWord,
+    arg5: SyscallWord,
+) -> SyscallWord {
+    unsafe { pair(n, arg1, arg2, arg3, arg4, arg5, 0) }.0
+}
+
+/// Issues a raw system call with 6 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall6(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+    arg4: SyscallWord,
+    arg5: SyscallWord,
+    arg6: SyscallWord,
+) -> SyscallWord {
+    unsafe { pair(n, arg1, arg2, arg3, arg4, arg5, arg6) }.0
+}
+
+#[inline]
+pub(crate) unsafe fn syscall0_checked(
+    n: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    to_result(unsafe { pair(n, 0, 0, 0, 0, 0, 0) })
+}
+
+#[inline]
+pub(crate) unsafe fn syscall1_checked(
+    n: SyscallWord,
+    arg1: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    to_result(unsafe { pair(n, arg1, 0, 0, 0, 0, 0) })
+}
+
+#[inline]
+pub(crate) unsafe fn syscall2_checked(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    to_result(unsafe { pair(n, arg1, arg2, 0, 0, 0, 0) })
+}
+
+#[inline]
+pub(crate) unsafe fn syscall3_checked(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    to_result(unsafe { pair(n, arg1, arg2, arg3, 0, 0, 0) })
+}
+
+#[inline]
+pub(crate) unsafe fn syscall4_checked(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+    arg4: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    to_result(unsafe { pair(n, arg1, arg2, arg3, arg4, 0, 0) })
+}
+
+#[inline]
+pub(crate) unsafe fn syscall5_checked(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+    arg4: SyscallWord,
+    arg5: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    to_result(unsafe { pair(n, arg1, arg2, arg3, arg4, arg5, 0) })
+}
+
+#[inline]
+pub(crate) unsafe fn syscall6_checked(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+    arg4: SyscallWord,
+    arg5: SyscallWord,
+    arg6: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    to_result(unsafe { pair(n, arg1, arg2, arg3, arg4, arg5, arg6) })
+}
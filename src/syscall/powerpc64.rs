@@ -14,6 +14,14 @@
 // The `sc` instruction is used to perform the syscall. If successful, then it
 // sets the summary overflow bit (S0) in field 0 of the condition register
 // (cr0). This is then used to decide if the return value should be negated.
+//
+// With the `scv` feature, POWER9+ kernels (>= 5.9) that advertise
+// `PPC_FEATURE2_SCV` in AT_HWCAP2 are entered through `scv 0` instead. `scv`
+// is considerably cheaper than `sc` and returns errors as negative values in
+// r3 directly, so no cr0 check is needed. It follows the ELF ABI for
+// volatile registers: r0, r3-r12, cr0, cr1, cr5-cr7, ctr, lr and xer are
+// clobbered. Floating point and vector registers are preserved. Support is
+// detected once at runtime and `sc` is used as a fallback.
 use core::arch::asm;
 
 /// System call argument/return type for powerpc64 (64-bit)
@@ -27,6 +35,11 @@ pub type SyscallWord = u64;
 /// responsibility to ensure safety.
 #[inline]
 pub unsafe fn syscall0(n: SyscallWord) -> SyscallWord {
+    #[cfg(feature = "scv")]
+    if scv::available() {
+        return unsafe { scv::syscall0(n) };
+    }
+
     let mut ret: SyscallWord;
     unsafe {
         asm!(
@@ -60,6 +73,11 @@ pub unsafe fn syscall0(n: SyscallWord) -> SyscallWord {
 /// responsibility to ensure safety.
 #[inline]
 pub unsafe fn syscall1(n: SyscallWord, arg1: SyscallWord) -> SyscallWord {
+    #[cfg(feature = "scv")]
+    if scv::available() {
+        return unsafe { scv::syscall1(n, arg1) };
+    }
+
     let mut ret: SyscallWord;
     unsafe {
         asm!(
@@ -97,6 +115,11 @@ pub unsafe fn syscall2(
     arg1: SyscallWord,
     arg2: SyscallWord,
 ) -> SyscallWord {
+    #[cfg(feature = "scv")]
+    if scv::available() {
+        return unsafe { scv::syscall2(n, arg1, arg2) };
+    }
+
     let mut ret: SyscallWord;
     unsafe {
         asm!(
@@ -135,6 +158,11 @@ pub unsafe fn syscall3(
     arg2: SyscallWord,
     arg3: SyscallWord,
 ) -> SyscallWord {
+    #[cfg(feature = "scv")]
+    if scv::available() {
+        return unsafe { scv::syscall3(n, arg1, arg2, arg3) };
+    }
+
     let mut ret: SyscallWord;
     unsafe {
         asm!(
@@ -174,6 +202,11 @@ pub unsafe fn syscall4(
     arg3: SyscallWord,
     arg4: SyscallWord,
 ) -> SyscallWord {
+    #[cfg(feature = "scv")]
+    if scv::available() {
+        return unsafe { scv::syscall4(n, arg1, arg2, arg3, arg4) };
+    }
+
     let mut ret: SyscallWord;
     unsafe {
         asm!(
@@ -214,6 +247,11 @@ pub unsafe fn syscall5(
     arg4: SyscallWord,
     arg5: SyscallWord,
 ) -> SyscallWord {
+    #[cfg(feature = "scv")]
+    if scv::available() {
+        return unsafe { scv::syscall5(n, arg1, arg2, arg3, arg4, arg5) };
+    }
+
     let mut ret: SyscallWord;
     unsafe {
         asm!(
@@ -255,6 +293,11 @@ pub unsafe fn syscall6(
     arg5: SyscallWord,
     arg6: SyscallWord,
 ) -> SyscallWord {
+    #[cfg(feature = "scv")]
+    if scv::available() {
+        return unsafe { scv::syscall6(n, arg1, arg2, arg3, arg4, arg5, arg6) };
+    }
+
     let mut ret: SyscallWord;
     unsafe {
         asm!(
@@ -279,3 +322,294 @@ pub unsafe fn syscall6(
     }
     ret
 }
+
+#[cfg(feature = "scv")]
+mod scv {
+    //! `scv 0` entry points and runtime detection.
+
+    use super::SyscallWord;
+    use core::arch::asm;
+    use core::sync::atomic::{AtomicU8, Ordering};
+
+    const UNKNOWN: u8 = 0;
+    const SC: u8 = 1;
+    const SCV: u8 = 2;
+
+    /// Cached result of the HWCAP2 probe.
+    static MODE: AtomicU8 = AtomicU8::new(UNKNOWN);
+
+    const PPC_FEATURE2_SCV: usize = 0x0010_0000;
+
+    /// Returns true if `scv 0` can be used on this system.
+    #[inline]
+    pub(super) fn available() -> bool {
+        match MODE.load(Ordering::Relaxed) {
+            SCV => true,
+            SC => false,
+            _ => detect(),
+        }
+    }
+
+    #[cold]
+    fn detect() -> bool {
+        // The probe issues syscalls itself, so route everything through `sc`
+        // until it completes.
+        MODE.store(SC, Ordering::Relaxed);
+        let hwcap2 = crate::auxv::hwcap2();
+        let supported = hwcap2 & PPC_FEATURE2_SCV != 0;
+        if supported {
+            MODE.store(SCV, Ordering::Relaxed);
+        }
+        supported
+    }
+
+    #[inline]
+    pub(super) unsafe fn syscall0(n: SyscallWord) -> SyscallWord {
+        let mut ret: SyscallWord;
+        unsafe {
+            asm!(
+                "scv 0",
+                inlateout("r0") n => _,
+                lateout("r3") ret,
+                lateout("r4") _,
+                lateout("r5") _,
+                lateout("r6") _,
+                lateout("r7") _,
+                lateout("r8") _,
+                lateout("r9") _,
+                lateout("r10") _,
+                lateout("r11") _,
+                lateout("r12") _,
+                lateout("cr0") _,
+                lateout("cr1") _,
+                lateout("cr5") _,
+                lateout("cr6") _,
+                lateout("cr7") _,
+                out("ctr") _,
+                out("lr") _,
+                out("xer") _,
+                options(nostack)
+            );
+        }
+        ret
+    }
+
+    #[inline]
+    pub(super) unsafe fn syscall1(
+        n: SyscallWord,
+        arg1: SyscallWord,
+    ) -> SyscallWord {
+        let mut ret: SyscallWord;
+        unsafe {
+            asm!(
+                "scv 0",
+                inlateout("r0") n => _,
+                inlateout("r3") arg1 => ret,
+                lateout("r4") _,
+                lateout("r5") _,
+                lateout("r6") _,
+                lateout("r7") _,
+                lateout("r8") _,
+                lateout("r9") _,
+                lateout("r10") _,
+                lateout("r11") _,
+                lateout("r12") _,
+                lateout("cr0") _,
+                lateout("cr1") _,
+                lateout("cr5") _,
+                lateout("cr6") _,
+                lateout("cr7") _,
+                out("ctr") _,
+                out("lr") _,
+                out("xer") _,
+                options(nostack)
+            );
+        }
+        ret
+    }
+
+    #[inline]
+    pub(super) unsafe fn syscall2(
+        n: SyscallWord,
+        arg1: SyscallWord,
+        arg2: SyscallWord,
+    ) -> SyscallWord {
+        let mut ret: SyscallWord;
+        unsafe {
+            asm!(
+                "scv 0",
+                inlateout("r0") n => _,
+                inlateout("r3") arg1 => ret,
+                inlateout("r4") arg2 => _,
+                lateout("r5") _,
+                lateout("r6") _,
+                lateout("r7") _,
+                lateout("r8") _,
+                lateout("r9") _,
+                lateout("r10") _,
+                lateout("r11") _,
+                lateout("r12") _,
+                lateout("cr0") _,
+                lateout("cr1") _,
+                lateout("cr5") _,
+                lateout("cr6") _,
+                lateout("cr7") _,
+                out("ctr") _,
+                out("lr") _,
+                out("xer") _,
+                options(nostack)
+            );
+        }
+        ret
+    }
+
+    #[inline]
+    pub(super) unsafe fn syscall3(
+        n: SyscallWord,
+        arg1: SyscallWord,
+        arg2: SyscallWord,
+        arg3: SyscallWord,
+    ) -> SyscallWord {
+        let mut ret: SyscallWord;
+        unsafe {
+            asm!(
+                "scv 0",
+                inlateout("r0") n => _,
+                inlateout("r3") arg1 => ret,
+                inlateout("r4") arg2 => _,
+                inlateout("r5") arg3 => _,
+                lateout("r6") _,
+                lateout("r7") _,
+                lateout("r8") _,
+                lateout("r9") _,
+                lateout("r10") _,
+                lateout("r11") _,
+                lateout("r12") _,
+                lateout("cr0") _,
+                lateout("cr1") _,
+                lateout("cr5") _,
+                lateout("cr6") _,
+                lateout("cr7") _,
+                out("ctr") _,
+                out("lr") _,
+                out("xer") _,
+                options(nostack)
+            );
+        }
+        ret
+    }
+
+    #[inline]
+    pub(super) unsafe fn syscall4(
+        n: SyscallWord,
+        arg1: SyscallWord,
+        arg2: SyscallWord,
+        arg3: SyscallWord,
+        arg4: SyscallWord,
+    ) -> SyscallWord {
+        let mut ret: SyscallWord;
+        unsafe {
+            asm!(
+                "scv 0",
+                inlateout("r0") n => _,
+                inlateout("r3") arg1 => ret,
+                inlateout("r4") arg2 => _,
+                inlateout("r5") arg3 => _,
+                inlateout("r6") arg4 => _,
+                lateout("r7") _,
+                lateout("r8") _,
+                lateout("r9") _,
+                lateout("r10") _,
+                lateout("r11") _,
+                lateout("r12") _,
+                lateout("cr0") _,
+                lateout("cr1") _,
+                lateout("cr5") _,
+                lateout("cr6") _,
+                lateout("cr7") _,
+                out("ctr") _,
+                out("lr") _,
+                out("xer") _,
+                options(nostack)
+            );
+        }
+        ret
+    }
+
+    #[inline]
+    pub(super) unsafe fn syscall5(
+        n: SyscallWord,
+        arg1: SyscallWord,
+        arg2: SyscallWord,
+        arg3: SyscallWord,
+        arg4: SyscallWord,
+        arg5: SyscallWord,
+    ) -> SyscallWord {
+        let mut ret: SyscallWord;
+        unsafe {
+            asm!(
+                "scv 0",
+                inlateout("r0") n => _,
+                inlateout("r3") arg1 => ret,
+                inlateout("r4") arg2 => _,
+                inlateout("r5") arg3 => _,
+                inlateout("r6") arg4 => _,
+                inlateout("r7") arg5 => _,
+                lateout("r8") _,
+                lateout("r9") _,
+                lateout("r10") _,
+                lateout("r11") _,
+                lateout("r12") _,
+                lateout("cr0") _,
+                lateout("cr1") _,
+                lateout("cr5") _,
+                lateout("cr6") _,
+                lateout("cr7") _,
+                out("ctr") _,
+                out("lr") _,
+                out("xer") _,
+                options(nostack)
+            );
+        }
+        ret
+    }
+
+    #[inline]
+    pub(super) unsafe fn syscall6(
+        n: SyscallWord,
+        arg1: SyscallWord,
+        arg2: SyscallWord,
+        arg3: SyscallWord,
+        arg4: SyscallWord,
+        arg5: SyscallWord,
+        arg6: SyscallWord,
+    ) -> SyscallWord {
+        let mut ret: SyscallWord;
+        unsafe {
+            asm!(
+                "scv 0",
+                inlateout("r0") n => _,
+                inlateout("r3") arg1 => ret,
+                inlateout("r4") arg2 => _,
+                inlateout("r5") arg3 => _,
+                inlateout("r6") arg4 => _,
+                inlateout("r7") arg5 => _,
+                inlateout("r8") arg6 => _,
+                lateout("r9") _,
+                lateout("r10") _,
+                lateout("r11") _,
+                lateout("r12") _,
+                lateout("cr0") _,
+                lateout("cr1") _,
+                lateout("cr5") _,
+                lateout("cr6") _,
+                lateout("cr7") _,
+                out("ctr") _,
+                out("lr") _,
+                out("xer") _,
+                options(nostack)
+            );
+        }
+        ret
+    }
+}
@@ -1 +1,471 @@
+// SPARC (32-bit) uses the following registers for args 1-6:
+//
+// arg1: %o0
+// arg2: %o1
+// arg3: %o2
+// arg4: %o3
+// arg5: %o4
+// arg6: %o5
+//
+// %g1 is the syscall number.
+// %o0 is the return value.
+// %o1 may be clobbered (it carries the second result of e.g. `pipe`).
+//
+// The kernel is entered with the `ta 0x10` software trap. On failure it sets
+// the carry bit in the PSR and returns a *positive* errno in %o0, so the sign
+// of %o0 alone cannot tell an error from a large successful result. The carry
+// bit is captured with `addx %g0, 0, reg` right after the trap.
+//
+// LLVM reserves %g1 and does not allow it as an asm operand, so the syscall
+// number is moved into it inside the asm block instead. Condition codes are
+// clobbered.
+use crate::Errno;
+use core::arch::asm;
 
+/// System call argument/return type for SPARC (32-bit)
+pub type SyscallWord = u32;
+
+/// Folds a `(%o0, carry)` pair into the usual "negative errno" convention.
+#[inline(always)]
+fn normalize((ret, err): (SyscallWord, SyscallWord)) -> SyscallWord {
+    if err == 0 { ret } else { ret.wrapping_neg() }
+}
+
+/// Converts a `(%o0, carry)` pair into a `Result`. When the carry bit is set,
+/// `%o0` holds the (positive) errno; otherwise `%o0` is the result, even if it
+/// looks like a negative errno.
+#[inline(always)]
+fn to_result(
+    (ret, err): (SyscallWord, SyscallWord),
+) -> Result<SyscallWord, Errno> {
+    if err == 0 {
+        Ok(ret)
+    } else {
+        Err(Errno::new(ret as i32))
+    }
+}
+
+/// Issues a raw system call with 0 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall0(n: SyscallWord) -> SyscallWord {
+    normalize(unsafe { syscall0_pair(n) })
+}
+
+/// Issues a system call with 0 arguments, taking the error status from the
+/// carry bit.
+#[inline]
+pub(crate) unsafe fn syscall0_checked(
+    n: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    to_result(unsafe { syscall0_pair(n) })
+}
+
+/// Issues a system call with 0 arguments and returns `(%o0, carry)`.
+#[inline]
+unsafe fn syscall0_pair(n: SyscallWord) -> (SyscallWord, SyscallWord) {
+    let mut ret: SyscallWord;
+    let mut err: SyscallWord;
+    unsafe {
+        asm!(
+            "mov {n}, %g1",
+            "ta 0x10",
+            "addx %g0, 0, {err}",
+            n = in(reg) n,
+            err = lateout(reg) err,
+            lateout("o0") ret,
+            lateout("o1") _,
+            options(nostack)
+        );
+    }
+    (ret, err)
+}
+
+/// Issues a raw system call with 1 argument.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall1(n: SyscallWord, arg1: SyscallWord) -> SyscallWord {
+    normalize(unsafe { syscall1_pair(n, arg1) })
+}
+
+/// Issues a system call with 1 argument, taking the error status from the
+/// carry bit.
+#[inline]
+pub(crate) unsafe fn syscall1_checked(
+    n: SyscallWord,
+    arg1: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    to_result(unsafe { syscall1_pair(n, arg1) })
+}
+
+/// Issues a system call with 1 argument and returns `(%o0, carry)`.
+#[inline]
+unsafe fn syscall1_pair(
+    n: SyscallWord,
+    arg1: SyscallWord,
+) -> (SyscallWord, SyscallWord) {
+    let mut ret: SyscallWord;
+    let mut err: SyscallWord;
+    unsafe {
+        asm!(
+            "mov {n}, %g1",
+            "ta 0x10",
+            "addx %g0, 0, {err}",
+            n = in(reg) n,
+            err = lateout(reg) err,
+            inlateout("o0") arg1 => ret,
+            lateout("o1") _,
+            options(nostack)
+        );
+    }
+    (ret, err)
+}
+
+/// Issues a raw system call with 2 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall2(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+) -> SyscallWord {
+    normalize(unsafe { syscall2_pair(n, arg1, arg2) })
+}
+
+/// Issues a system call with 2 arguments, taking the error status from the
+/// carry bit.
+#[inline]
+pub(crate) unsafe fn syscall2_checked(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    to_result(unsafe { syscall2_pair(n, arg1, arg2) })
+}
+
+/// Issues a system call with 2 arguments and returns `(%o0, carry)`.
+#[inline]
+unsafe fn syscall2_pair(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+) -> (SyscallWord, SyscallWord) {
+    let mut ret: SyscallWord;
+    let mut err: SyscallWord;
+    unsafe {
+        asm!(
+            "mov {n}, %g1",
+            "ta 0x10",
+            "addx %g0, 0, {err}",
+            n = in(reg) n,
+            err = lateout(reg) err,
+            inlateout("o0") arg1 => ret,
+            inlateout("o1") arg2 => _,
+            options(nostack)
+        );
+    }
+    (ret, err)
+}
+
+/// Issues a raw system call with 3 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall3(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+) -> SyscallWord {
+    normalize(unsafe { syscall3_pair(n, arg1, arg2, arg3) })
+}
+
+/// Issues a system call with 3 arguments, taking the error status from the
+/// carry bit.
+#[inline]
+pub(crate) unsafe fn syscall3_checked(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    to_result(unsafe { syscall3_pair(n, arg1, arg2, arg3) })
+}
+
+/// Issues a system call with 3 arguments and returns `(%o0, carry)`.
+#[inline]
+unsafe fn syscall3_pair(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+) -> (SyscallWord, SyscallWord) {
+    let mut ret: SyscallWord;
+    let mut err: SyscallWord;
+    unsafe {
+        asm!(
+            "mov {n}, %g1",
+            "ta 0x10",
+            "addx %g0, 0, {err}",
+            n = in(reg) n,
+            err = lateout(reg) err,
+            inlateout("o0") arg1 => ret,
+            inlateout("o1") arg2 => _,
+            in("o2") arg3,
+            options(nostack)
+        );
+    }
+    (ret, err)
+}
+
+/// Issues a raw system call with 4 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall4(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+    arg4: SyscallWord,
+) -> SyscallWord {
+    normalize(unsafe { syscall4_pair(n, arg1, arg2, arg3, arg4) })
+}
+
+/// Issues a system call with 4 arguments, taking the error status from the
+/// carry bit.
+#[inline]
+pub(crate) unsafe fn syscall4_checked(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+    arg4: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    to_result(unsafe { syscall4_pair(n, arg1, arg2, arg3, arg4) })
+}
+
+/// Issues a system call with 4 arguments and returns `(%o0, carry)`.
+#[inline]
+unsafe fn syscall4_pair(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+    arg4: SyscallWord,
+) -> (SyscallWord, SyscallWord) {
+    let mut ret: SyscallWord;
+    let mut err: SyscallWord;
+    unsafe {
+        asm!(
+            "mov {n}, %g1",
+            "ta 0x10",
+            "addx %g0, 0, {err}",
+            n = in(reg) n,
+            err = lateout(reg) err,
+            inlateout("o0") arg1 => ret,
+            inlateout("o1") arg2 => _,
+            in("o2") arg3,
+            in("o3") arg4,
+            options(nostack)
+        );
+    }
+    (ret, err)
+}
+
+/// Issues a raw system call with 5 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall5(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+    arg4: SyscallWord,
+    arg5: SyscallWord,
+) -> SyscallWord {
+    normalize(unsafe { syscall5_pair(n, arg1, arg2, arg3, arg4, arg5) })
+}
+
+/// Issues a system call with 5 arguments, taking the error status from the
+/// carry bit.
+#[inline]
+pub(crate) unsafe fn syscall5_checked(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+    arg4: SyscallWord,
+    arg5: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    to_result(unsafe { syscall5_pair(n, arg1, arg2, arg3, arg4, arg5) })
+}
+
+/// Issues a system call with 5 arguments and returns `(%o0, carry)`.
+#[inline]
+unsafe fn syscall5_pair(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+    arg4: SyscallWord,
+    arg5: SyscallWord,
+) -> (SyscallWord, SyscallWord) {
+    let mut ret: SyscallWord;
+    let mut err: SyscallWord;
+    unsafe {
+        asm!(
+            "mov {n}, %g1",
+            "ta 0x10",
+            "addx %g0, 0, {err}",
+            n = in(reg) n,
+            err = lateout(reg) err,
+            inlateout("o0") arg1 => ret,
+            inlateout("o1") arg2 => _,
+            in("o2") arg3,
+            in("o3") arg4,
+            in("o4") arg5,
+            options(nostack)
+        );
+    }
+    (ret, err)
+}
+
+/// Issues a raw system call with 6 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall6(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+    arg4: SyscallWord,
+    arg5: SyscallWord,
+    arg6: SyscallWord,
+) -> SyscallWord {
+    normalize(unsafe { syscall6_pair(n, arg1, arg2, arg3, arg4, arg5, arg6) })
+}
+
+/// Issues a system call with 6 arguments, taking the error status from the
+/// carry bit.
+#[inline]
+pub(crate) unsafe fn syscall6_checked(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+    arg4: SyscallWord,
+    arg5: SyscallWord,
+    arg6: SyscallWord,
+) -> Result<SyscallWord, Errno> {
+    to_result(unsafe { syscall6_pair(n, arg1, arg2, arg3, arg4, arg5, arg6) })
+}
+
+/// Issues a system call with 6 arguments and returns `(%o0, carry)`.
+#[inline]
+unsafe fn syscall6_pair(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+    arg4: SyscallWord,
+    arg5: SyscallWord,
+    arg6: SyscallWord,
+) -> (SyscallWord, SyscallWord) {
+    let mut ret: SyscallWord;
+    let mut err: SyscallWord;
+    unsafe {
+        asm!(
+            "mov {n}, %g1",
+            "ta 0x10",
+            "addx %g0, 0, {err}",
+            n = in(reg) n,
+            err = lateout(reg) err,
+            inlateout("o0") arg1 => ret,
+            inlateout("o1") arg2 => _,
+            in("o2") arg3,
+            in("o3") arg4,
+            in("o4") arg5,
+            in("o5") arg6,
+            options(nostack)
+        );
+    }
+    (ret, err)
+}
+
+/// Issues a system call with 0 arguments that also returns a second result in
+/// `%o1` (e.g. the legacy `pipe`), taking the error status from the carry
+/// bit.
+#[inline]
+pub(crate) unsafe fn syscall0_2ret(
+    n: SyscallWord,
+) -> Result<(SyscallWord, SyscallWord), Errno> {
+    let mut ret: SyscallWord;
+    let mut ret2: SyscallWord;
+    let mut err: SyscallWord;
+    unsafe {
+        asm!(
+            "mov {n}, %g1",
+            "ta 0x10",
+            "addx %g0, 0, {err}",
+            n = in(reg) n,
+            err = lateout(reg) err,
+            lateout("o0") ret,
+            lateout("o1") ret2,
+            options(nostack)
+        );
+    }
+    to_result((ret, err)).map(|ret| (ret, ret2))
+}
+
+/// Issues a system call with 1 argument that also returns a second result in
+/// `%o1` (e.g. the legacy `pipe`), taking the error status from the carry
+/// bit.
+#[inline]
+pub(crate) unsafe fn syscall1_2ret(
+    n: SyscallWord,
+    arg1: SyscallWord,
+) -> Result<(SyscallWord, SyscallWord), Errno> {
+    let mut ret: SyscallWord;
+    let mut ret2: SyscallWord;
+    let mut err: SyscallWord;
+    unsafe {
+        asm!(
+            "mov {n}, %g1",
+            "ta 0x10",
+            "addx %g0, 0, {err}",
+            n = in(reg) n,
+            err = lateout(reg) err,
+            inlateout("o0") arg1 => ret,
+            lateout("o1") ret2,
+            options(nostack)
+        );
+    }
+    to_result((ret, err)).map(|ret| (ret, ret2))
+}
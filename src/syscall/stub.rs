@@ -0,0 +1,248 @@
+// Stub-section backend, used when the `stub-section` feature is enabled.
+//
+// Every syscall is issued from one out-of-line `extern "C"` function,
+// `rawsys_linux_syscall_stub(n, arg1, ..., arg6)`, which lives alone in the
+// `rawsys_syscalls` section. Because the section name is a valid C identifier,
+// the linker defines `__start_rawsys_syscalls` and `__stop_rawsys_syscalls`
+// around it, which gives the exact code range that syscalls come from. That
+// range can be handed to a seccomp filter (checking the instruction pointer)
+// or to Syscall User Dispatch.
+//
+// The stub returns the kernel's raw result, so error conversion goes through
+// the generic `checked.rs`. Unused arguments are passed as 0.
+use core::arch::global_asm;
+use core::ops::Range;
+
+/// System call argument/return type (pointer-sized on these targets)
+#[cfg(target_pointer_width = "32")]
+pub type SyscallWord = u32;
+
+/// System call argument/return type (pointer-sized on these targets)
+#[cfg(target_pointer_width = "64")]
+pub type SyscallWord = u64;
+
+// System V: n in rdi, args in rsi, rdx, rcx, r8, r9, and the stack.
+#[cfg(target_arch = "x86_64")]
+global_asm!(
+    ".pushsection rawsys_syscalls,\"ax\",%progbits",
+    ".globl rawsys_linux_syscall_stub",
+    ".type rawsys_linux_syscall_stub,%function",
+    ".p2align 4",
+    "rawsys_linux_syscall_stub:",
+    "mov rax, rdi",
+    "mov rdi, rsi",
+    "mov rsi, rdx",
+    "mov rdx, rcx",
+    "mov r10, r8",
+    "mov r8, r9",
+    "mov r9, [rsp + 8]",
+    "syscall",
+    "ret",
+    ".size rawsys_linux_syscall_stub, . - rawsys_linux_syscall_stub",
+    ".popsection",
+);
+
+// cdecl: everything is on the stack. ebx, esi, edi, and ebp are callee-saved.
+#[cfg(target_arch = "x86")]
+global_asm!(
+    ".pushsection rawsys_syscalls,\"ax\",%progbits",
+    ".globl rawsys_linux_syscall_stub",
+    ".type rawsys_linux_syscall_stub,%function",
+    ".p2align 4",
+    "rawsys_linux_syscall_stub:",
+    "push ebx",
+    "push esi",
+    "push edi",
+    "push ebp",
+    "mov eax, [esp + 20]",
+    "mov ebx, [esp + 24]",
+    "mov ecx, [esp + 28]",
+    "mov edx, [esp + 32]",
+    "mov esi, [esp + 36]",
+    "mov edi, [esp + 40]",
+    "mov ebp, [esp + 44]",
+    "int 0x80",
+    "pop ebp",
+    "pop edi",
+    "pop esi",
+    "pop ebx",
+    "ret",
+    ".size rawsys_linux_syscall_stub, . - rawsys_linux_syscall_stub",
+    ".popsection",
+);
+
+// AAPCS64: n in x0, args in x1-x6.
+#[cfg(target_arch = "aarch64")]
+global_asm!(
+    ".pushsection rawsys_syscalls,\"ax\",%progbits",
+    ".globl rawsys_linux_syscall_stub",
+    ".type rawsys_linux_syscall_stub,%function",
+    ".p2align 2",
+    "rawsys_linux_syscall_stub:",
+    "mov x8, x0",
+    "mov x0, x1",
+    "mov x1, x2",
+    "mov x2, x3",
+    "mov x3, x4",
+    "mov x4, x5",
+    "mov x5, x6",
+    "svc 0",
+    "ret",
+    ".size rawsys_linux_syscall_stub, . - rawsys_linux_syscall_stub",
+    ".popsection",
+);
+
+// RISC-V: n in a0, args in a1-a6.
+#[cfg(target_arch = "riscv64")]
+global_asm!(
+    ".pushsection rawsys_syscalls,\"ax\",%progbits",
+    ".globl rawsys_linux_syscall_stub",
+    ".type rawsys_linux_syscall_stub,%function",
+    ".p2align 2",
+    "rawsys_linux_syscall_stub:",
+    "mv a7, a0",
+    "mv a0, a1",
+    "mv a1, a2",
+    "mv a2, a3",
+    "mv a3, a4",
+    "mv a4, a5",
+    "mv a5, a6",
+    "ecall",
+    "ret",
+    ".size rawsys_linux_syscall_stub, . - rawsys_linux_syscall_stub",
+    ".popsection",
+);
+
+unsafe extern "C" {
+    fn rawsys_linux_syscall_stub(
+        n: SyscallWord,
+        arg1: SyscallWord,
+        arg2: SyscallWord,
+        arg3: SyscallWord,
+        arg4: SyscallWord,
+        arg5: SyscallWord,
+        arg6: SyscallWord,
+    ) -> SyscallWord;
+
+    static __start_rawsys_syscalls: u8;
+    static __stop_rawsys_syscalls: u8;
+}
+
+/// Returns the address range of the code that issues every syscall made
+/// through this crate.
+///
+/// This is the `rawsys_syscalls` linker section holding the syscall stub. Pass
+/// it to a seccomp filter that checks the instruction pointer, or to
+/// `PR_SET_SYSCALL_USER_DISPATCH` as the allowed region.
+pub fn stub_region() -> Range<usize> {
+    let start = (&raw const __start_rawsys_syscalls) as usize;
+    let end = (&raw const __stop_rawsys_syscalls) as usize;
+    start..end
+}
+
+/// Issues a raw system call with 0 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall0(n: SyscallWord) -> SyscallWord {
+    unsafe { rawsys_linux_syscall_stub(n, 0, 0, 0, 0, 0, 0) }
+}
+
+/// Issues a raw system call with 1 argument.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall1(n: SyscallWord, arg1: SyscallWord) -> SyscallWord {
+    unsafe { rawsys_linux_syscall_stub(n, arg1, 0, 0, 0, 0, 0) }
+}
+
+/// Issues a raw system call with 2 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall2(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+) -> SyscallWord {
+    unsafe { rawsys_linux_syscall_stub(n, arg1, arg2, 0, 0, 0, 0) }
+}
+
+/// Issues a raw system call with 3 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall3(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+) -> SyscallWord {
+    unsafe { rawsys_linux_syscall_stub(n, arg1, arg2, arg3, 0, 0, 0) }
+}
+
+/// Issues a raw system call with 4 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall4(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+    arg4: SyscallWord,
+) -> SyscallWord {
+    unsafe { rawsys_linux_syscall_stub(n, arg1, arg2, arg3, arg4, 0, 0) }
+}
+
+/// Issues a raw system call with 5 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall5(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+    arg4: SyscallWord,
+    arg5: SyscallWord,
+) -> SyscallWord {
+    unsafe { rawsys_linux_syscall_stub(n, arg1, arg2, arg3, arg4, arg5, 0) }
+}
+
+/// Issues a raw system call with 6 arguments.
+///
+/// # Safety
+///
+/// Running a system call is inherently unsafe. It is the caller's
+/// responsibility to ensure safety.
+#[inline]
+pub unsafe fn syscall6(
+    n: SyscallWord,
+    arg1: SyscallWord,
+    arg2: SyscallWord,
+    arg3: SyscallWord,
+    arg4: SyscallWord,
+    arg5: SyscallWord,
+    arg6: SyscallWord,
+) -> SyscallWord {
+    unsafe { rawsys_linux_syscall_stub(n, arg1, arg2, arg3, arg4, arg5, arg6) }
+}
@@ -11,6 +11,13 @@
 // No other registers are clobbered. syscalls can also modify memory. With the
 // `asm!()` macro, it is assumed that memory is clobbered unless the nomem
 // option is specified.
+//
+// With the `vsyscall` feature, syscalls are made by calling the vDSO's
+// `__kernel_vsyscall` instead of `int 0x80`. The kernel picks the fastest
+// entry instruction (`sysenter` or `syscall`) for the CPU and uses the same
+// register convention, so only the call itself differs. The entry point is
+// taken from `AT_SYSINFO` in the auxiliary vector on first use. If it is
+// missing (e.g. the vDSO is disabled), `int 0x80` is used.
 use core::arch::asm;
 
 /// System call argument/return type for x86
@@ -24,6 +31,11 @@ pub type SyscallWord = u32;
 /// responsibility to ensure safety.
 #[inline]
 pub unsafe fn syscall0(n: SyscallWord) -> SyscallWord {
+    #[cfg(feature = "vsyscall")]
+    if let Some(entry) = vsyscall::entry() {
+        return unsafe { vsyscall::syscall0(entry, n) };
+    }
+
     let mut ret: SyscallWord;
     unsafe {
         asm!(
@@ -43,6 +55,11 @@ pub unsafe fn syscall0(n: SyscallWord) -> SyscallWord {
 /// responsibility to ensure safety.
 #[inline]
 pub unsafe fn syscall1(n: SyscallWord, arg1: SyscallWord) -> SyscallWord {
+    #[cfg(feature = "vsyscall")]
+    if let Some(entry) = vsyscall::entry() {
+        return unsafe { vsyscall::syscall1(entry, n, arg1) };
+    }
+
     let mut ret: SyscallWord;
     unsafe {
         asm!(
@@ -67,6 +84,11 @@ pub unsafe fn syscall2(
     arg1: SyscallWord,
     arg2: SyscallWord,
 ) -> SyscallWord {
+    #[cfg(feature = "vsyscall")]
+    if let Some(entry) = vsyscall::entry() {
+        return unsafe { vsyscall::syscall2(entry, n, arg1, arg2) };
+    }
+
     let mut ret: SyscallWord;
     unsafe {
         asm!(
@@ -93,6 +115,11 @@ pub unsafe fn syscall3(
     arg2: SyscallWord,
     arg3: SyscallWord,
 ) -> SyscallWord {
+    #[cfg(feature = "vsyscall")]
+    if let Some(entry) = vsyscall::entry() {
+        return unsafe { vsyscall::syscall3(entry, n, arg1, arg2, arg3) };
+    }
+
     let mut ret: SyscallWord;
     unsafe {
         asm!(
@@ -121,6 +148,13 @@ pub unsafe fn syscall4(
     arg3: SyscallWord,
     arg4: SyscallWord,
 ) -> SyscallWord {
+    #[cfg(feature = "vsyscall")]
+    if let Some(entry) = vsyscall::entry() {
+        return unsafe {
+            vsyscall::syscall6(entry, n, arg1, arg2, arg3, arg4, 0, 0)
+        };
+    }
+
     let mut ret: SyscallWord;
     unsafe {
         asm!(
@@ -155,6 +189,13 @@ pub unsafe fn syscall5(
     arg4: SyscallWord,
     arg5: SyscallWord,
 ) -> SyscallWord {
+    #[cfg(feature = "vsyscall")]
+    if let Some(entry) = vsyscall::entry() {
+        return unsafe {
+            vsyscall::syscall6(entry, n, arg1, arg2, arg3, arg4, arg5, 0)
+        };
+    }
+
     let mut ret: SyscallWord;
     unsafe {
         asm!(
@@ -191,6 +232,13 @@ pub unsafe fn syscall6(
     arg5: SyscallWord,
     arg6: SyscallWord,
 ) -> SyscallWord {
+    #[cfg(feature = "vsyscall")]
+    if let Some(entry) = vsyscall::entry() {
+        return unsafe {
+            vsyscall::syscall6(entry, n, arg1, arg2, arg3, arg4, arg5, arg6)
+        };
+    }
+
     // Since using esi and ebp are not allowed and because x86 only has 6
     // general purpose registers (excluding ESP and EBP), we need to push them
     // onto the stack and then set them using a pointer to memory (our input
@@ -217,3 +265,151 @@ pub unsafe fn syscall6(
     }
     ret
 }
+
+#[cfg(feature = "vsyscall")]
+mod vsyscall {
+    //! Calls through the vDSO's `__kernel_vsyscall`.
+
+    use super::SyscallWord;
+    use core::arch::asm;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Entry point not looked up yet.
+    const UNKNOWN: usize = usize::MAX;
+    /// No entry point; use `int 0x80`.
+    const ABSENT: usize = 0;
+
+    static ENTRY: AtomicUsize = AtomicUsize::new(UNKNOWN);
+
+    /// Returns the address of `__kernel_vsyscall`, if there is one.
+    #[inline]
+    pub(super) fn entry() -> Option<usize> {
+        match ENTRY.load(Ordering::Relaxed) {
+            ABSENT => None,
+            UNKNOWN => detect(),
+            entry => Some(entry),
+        }
+    }
+
+    #[cold]
+    fn detect() -> Option<usize> {
+        // The lookup issues syscalls itself, so route everything through
+        // `int 0x80` until it completes.
+        ENTRY.store(ABSENT, Ordering::Relaxed);
+        let entry = crate::auxv::getauxval(crate::auxv::AT_SYSINFO)
+            .filter(|&entry| entry != ABSENT && entry != UNKNOWN)?;
+        ENTRY.store(entry, Ordering::Relaxed);
+        Some(entry)
+    }
+
+    #[inline]
+    pub(super) unsafe fn syscall0(entry: usize, n: SyscallWord) -> SyscallWord {
+        let mut ret: SyscallWord;
+        unsafe {
+            asm!(
+                "call {entry}",
+                entry = in(reg) entry,
+                inlateout("eax") n => ret,
+            );
+        }
+        ret
+    }
+
+    #[inline]
+    pub(super) unsafe fn syscall1(
+        entry: usize,
+        n: SyscallWord,
+        arg1: SyscallWord,
+    ) -> SyscallWord {
+        let mut ret: SyscallWord;
+        unsafe {
+            asm!(
+                "call {entry}",
+                entry = in(reg) entry,
+                inlateout("eax") n => ret,
+                in("ebx") arg1,
+            );
+        }
+        ret
+    }
+
+    #[inline]
+    pub(super) unsafe fn syscall2(
+        entry: usize,
+        n: SyscallWord,
+        arg1: SyscallWord,
+        arg2: SyscallWord,
+    ) -> SyscallWord {
+        let mut ret: SyscallWord;
+        unsafe {
+            asm!(
+                "call {entry}",
+                entry = in(reg) entry,
+                inlateout("eax") n => ret,
+                in("ebx") arg1,
+                in("ecx") arg2,
+            );
+        }
+        ret
+    }
+
+    #[inline]
+    pub(super) unsafe fn syscall3(
+        entry: usize,
+        n: SyscallWord,
+        arg1: SyscallWord,
+        arg2: SyscallWord,
+        arg3: SyscallWord,
+    ) -> SyscallWord {
+        let mut ret: SyscallWord;
+        unsafe {
+            asm!(
+                "call {entry}",
+                entry = in(reg) entry,
+                inlateout("eax") n => ret,
+                in("ebx") arg1,
+                in("ecx") arg2,
+                in("edx") arg3,
+            );
+        }
+        ret
+    }
+
+    /// Also used for 4 and 5 arguments; unused registers are passed as 0.
+    #[inline]
+    pub(super) unsafe fn syscall6(
+        entry: usize,
+        n: SyscallWord,
+        arg1: SyscallWord,
+        arg2: SyscallWord,
+        arg3: SyscallWord,
+        arg4: SyscallWord,
+        arg5: SyscallWord,
+        arg6: SyscallWord,
+    ) -> SyscallWord {
+        // Same register pressure as `super::syscall6`, with the entry point
+        // passed through the input array as well.
+        let mut ret: SyscallWord;
+        unsafe {
+            asm!(
+                "push ebp",
+                "push esi",
+                "push DWORD PTR [eax + 12]", // Push the entry point.
+                "mov esi, DWORD PTR [eax + 0]", // Set esi to arg4
+                "mov ebp, DWORD PTR [eax + 4]", // Set ebp to arg6
+                "mov eax, DWORD PTR [eax + 8]", // Lastly, set eax to the syscall number.
+                "call DWORD PTR [esp]",
+                "add esp, 4",
+                "pop esi",
+                "pop ebp",
+                // Set eax to a pointer to our input array.
+                inout("eax") &[arg4, arg6, n, entry as SyscallWord] => ret,
+                in("ebx") arg1,
+                in("ecx") arg2,
+                in("edx") arg3,
+                in("edi") arg5,
+            );
+        }
+        ret
+    }
+}
@@ -213,3 +213,243 @@ pub unsafe fn syscall6(
     }
     ret
 }
+
+/// 32-bit compat syscalls (`int 0x80`) from 64-bit code.
+///
+/// These enter the kernel through the ia32 emulation layer, exactly as a
+/// 32-bit process would: the syscall numbers are the **x86** ones (see
+/// `rawsys_linux::x86::Sysno` with the `x86` feature), arguments are passed in
+/// `ebx`, `ecx`, `edx`, `esi`, `edi`, `ebp`, and only the low 32 bits of each
+/// register are seen by the kernel. Pointers passed to these calls must
+/// therefore lie below 4 GiB.
+///
+/// This is mostly useful for exercising the compat entry path, e.g. to test
+/// how a seccomp filter treats `AUDIT_ARCH_I386` syscalls. If the kernel was
+/// built without `CONFIG_IA32_EMULATION` (or booted with
+/// `ia32_emulation=0`), `int 0x80` raises `SIGSEGV`.
+pub mod compat {
+    // The kernel clobbers r8-r11 on return from the compat entry path. rbx
+    // and rbp cannot be used as asm operands, so arg1 is swapped into ebx
+    // and arg6 is loaded into ebp around the interrupt. Their scratch
+    // registers are declared as (non-late) clobbers of r8-r11 so that they
+    // are never allocated to one of those.
+    use core::arch::asm;
+
+    /// Issues a 32-bit compat system call with 0 arguments.
+    ///
+    /// # Safety
+    ///
+    /// Running a system call is inherently unsafe. It is the caller's
+    /// responsibility to ensure safety.
+    #[inline]
+    pub unsafe fn syscall0(n: u32) -> u32 {
+        let mut ret: u32;
+        unsafe {
+            asm!(
+                "int 0x80",
+                inlateout("eax") n => ret,
+                out("r8") _,
+                out("r9") _,
+                out("r10") _,
+                out("r11") _,
+                options(nostack, preserves_flags)
+            );
+        }
+        ret
+    }
+
+    /// Issues a 32-bit compat system call with 1 argument.
+    ///
+    /// # Safety
+    ///
+    /// Running a system call is inherently unsafe. It is the caller's
+    /// responsibility to ensure safety.
+    #[inline]
+    pub unsafe fn syscall1(n: u32, arg1: u32) -> u32 {
+        let mut ret: u32;
+        unsafe {
+            asm!(
+                "xchg {arg1:r}, rbx",
+                "int 0x80",
+                "xchg {arg1:r}, rbx",
+                arg1 = inout(reg) u64::from(arg1) => _,
+                inlateout("eax") n => ret,
+                out("r8") _,
+                out("r9") _,
+                out("r10") _,
+                out("r11") _,
+                options(nostack, preserves_flags)
+            );
+        }
+        ret
+    }
+
+    /// Issues a 32-bit compat system call with 2 arguments.
+    ///
+    /// # Safety
+    ///
+    /// Running a system call is inherently unsafe. It is the caller's
+    /// responsibility to ensure safety.
+    #[inline]
+    pub unsafe fn syscall2(n: u32, arg1: u32, arg2: u32) -> u32 {
+        let mut ret: u32;
+        unsafe {
+            asm!(
+                "xchg {arg1:r}, rbx",
+                "int 0x80",
+                "xchg {arg1:r}, rbx",
+                arg1 = inout(reg) u64::from(arg1) => _,
+                inlateout("eax") n => ret,
+                in("ecx") arg2,
+                out("r8") _,
+                out("r9") _,
+                out("r10") _,
+                out("r11") _,
+                options(nostack, preserves_flags)
+            );
+        }
+        ret
+    }
+
+    /// Issues a 32-bit compat system call with 3 arguments.
+    ///
+    /// # Safety
+    ///
+    /// Running a system call is inherently unsafe. It is the caller's
+    /// responsibility to ensure safety.
+    #[inline]
+    pub unsafe fn syscall3(n: u32, arg1: u32, arg2: u32, arg3: u32) -> u32 {
+        let mut ret: u32;
+        unsafe {
+            asm!(
+                "xchg {arg1:r}, rbx",
+                "int 0x80",
+                "xchg {arg1:r}, rbx",
+                arg1 = inout(reg) u64::from(arg1) => _,
+                inlateout("eax") n => ret,
+                in("ecx") arg2,
+                in("edx") arg3,
+                out("r8") _,
+                out("r9") _,
+                out("r10") _,
+                out("r11") _,
+                options(nostack, preserves_flags)
+            );
+        }
+        ret
+    }
+
+    /// Issues a 32-bit compat system call with 4 arguments.
+    ///
+    /// # Safety
+    ///
+    /// Running a system call is inherently unsafe. It is the caller's
+    /// responsibility to ensure safety.
+    #[inline]
+    pub unsafe fn syscall4(
+        n: u32,
+        arg1: u32,
+        arg2: u32,
+        arg3: u32,
+        arg4: u32,
+    ) -> u32 {
+        let mut ret: u32;
+        unsafe {
+            asm!(
+                "xchg {arg1:r}, rbx",
+                "int 0x80",
+                "xchg {arg1:r}, rbx",
+                arg1 = inout(reg) u64::from(arg1) => _,
+                inlateout("eax") n => ret,
+                in("ecx") arg2,
+                in("edx") arg3,
+                in("esi") arg4,
+                out("r8") _,
+                out("r9") _,
+                out("r10") _,
+                out("r11") _,
+                options(nostack, preserves_flags)
+            );
+        }
+        ret
+    }
+
+    /// Issues a 32-bit compat system call with 5 arguments.
+    ///
+    /// # Safety
+    ///
+    /// Running a system call is inherently unsafe. It is the caller's
+    /// responsibility to ensure safety.
+    #[inline]
+    pub unsafe fn syscall5(
+        n: u32,
+        arg1: u32,
+        arg2: u32,
+        arg3: u32,
+        arg4: u32,
+        arg5: u32,
+    ) -> u32 {
+        let mut ret: u32;
+        unsafe {
+            asm!(
+                "xchg {arg1:r}, rbx",
+                "int 0x80",
+                "xchg {arg1:r}, rbx",
+                arg1 = inout(reg) u64::from(arg1) => _,
+                inlateout("eax") n => ret,
+                in("ecx") arg2,
+                in("edx") arg3,
+                in("esi") arg4,
+                in("edi") arg5,
+                out("r8") _,
+                out("r9") _,
+                out("r10") _,
+                out("r11") _,
+                options(nostack, preserves_flags)
+            );
+        }
+        ret
+    }
+
+    /// Issues a 32-bit compat system call with 6 arguments.
+    ///
+    /// # Safety
+    ///
+    /// Running a system call is inherently unsafe. It is the caller's
+    /// responsibility to ensure safety.
+    #[inline]
+    pub unsafe fn syscall6(
+        n: u32,
+        arg1: u32,
+        arg2: u32,
+        arg3: u32,
+        arg4: u32,
+        arg5: u32,
+        arg6: u32,
+    ) -> u32 {
+        let mut ret: u32;
+        unsafe {
+            asm!(
+                "xchg {arg1:r}, rbx",
+                "push rbp",
+                "mov ebp, {arg6:e}",
+                "int 0x80",
+                "pop rbp",
+                "xchg {arg1:r}, rbx",
+                arg1 = inout(reg) u64::from(arg1) => _,
+                arg6 = in(reg) arg6,
+                inlateout("eax") n => ret,
+                in("ecx") arg2,
+                in("edx") arg3,
+                in("esi") arg4,
+                in("edi") arg5,
+                out("r8") _,
+                out("r9") _,
+                out("r10") _,
+                out("r11") _,
+                options(preserves_flags)
+            );
+        }
+        ret
+    }
+}
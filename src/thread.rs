@@ -0,0 +1,788 @@
+//! Raw `clone`-based thread and process spawning
+//!
+//! [`spawn_raw`] starts `f(arg)` on a caller-provided stack with `clone`,
+//! without going through libc. The child has to begin executing on the new
+//! stack before any compiled code runs, which the generic `syscallN` wrappers
+//! cannot do: on return from `clone` the child would unwind into the parent's
+//! frames on a stack it does not own. Each architecture therefore has a small
+//! trampoline that issues `clone`, and in the child calls `f` and then `exit`s
+//! with its return value.
+//!
+//! This is meant for `no_std` runtimes and init-like programs. The child does
+//! not get thread-local storage, a guard page, or any libc state, so `f` must
+//! not use `std` (or libc) facilities that rely on them. `clone3` is not
+//! needed here, since every flag the trampoline can support fits in `clone`'s
+//! `flags` argument.
+//!
+//! [`fork_and_exec`] and [`raw_vfork`] cover the `CLONE_VM | CLONE_VFORK`
+//! case, where the child runs on the parent's stack: no compiled code may run
+//! in the child between `clone` and `execve`/`exit`, so both are written in
+//! assembly.
+//!
+//! Only `x86_64`, `aarch64`, `riscv64`, and `loongarch64` have trampolines,
+//! so this module is not available on other architectures (including `x86`
+//! and `arm`).
+//!
+//! Example
+//! ```no_run
+//! use core::ffi::c_void;
+//! use rawsys_linux::thread::{self, CLONE_VM};
+//!
+//! extern "C" fn child(_arg: *mut c_void) -> i32 {
+//!     0
+//! }
+//!
+//! static mut STACK: [u8; 64 * 1024] = [0; 64 * 1024];
+//!
+//! let stack = unsafe { &mut *(&raw mut STACK) };
+//! let flags = CLONE_VM | 17; // SIGCHLD on exit.
+//! let tid = unsafe { thread::spawn_raw(stack, flags, child, core::ptr::null_mut()) };
+//! ```
+
+use core::ffi::{c_char, c_void};
+
+use crate::{Errno, Sysno};
+
+/// Share the address space with the parent.
+pub const CLONE_VM: usize = 0x0000_0100;
+/// Share filesystem information (root, cwd, umask).
+pub const CLONE_FS: usize = 0x0000_0200;
+/// Share the file descriptor table.
+pub const CLONE_FILES: usize = 0x0000_0400;
+/// Share signal handlers.
+pub const CLONE_SIGHAND: usize = 0x0000_0800;
+/// Suspend the parent until the child exits or calls `execve`.
+pub const CLONE_VFORK: usize = 0x0000_4000;
+/// Put the child in the parent's thread group.
+pub const CLONE_THREAD: usize = 0x0001_0000;
+/// Share System V semaphore adjustments.
+pub const CLONE_SYSVSEM: usize = 0x0004_0000;
+
+/// Exit signal reported to the parent by [`fork_and_exec`] and [`raw_vfork`].
+const SIGCHLD: usize = 17;
+
+/// `how` argument of `rt_sigprocmask` that replaces the whole mask.
+const SIG_SETMASK: usize = 2;
+
+/// Size of the kernel's signal set on the supported architectures.
+const SIGSET_SIZE: usize = 8;
+
+/// Flags for a thread in the sense of `pthread_create` (without TLS or tid
+/// notification).
+pub const THREAD_FLAGS: usize = CLONE_VM
+    | CLONE_FS
+    | CLONE_FILES
+    | CLONE_SIGHAND
+    | CLONE_THREAD
+    | CLONE_SYSVSEM;
+
+/// Starts `f(arg)` in a new task running on `stack`, and returns the child's
+/// thread ID.
+///
+/// `flags` are passed to `clone` unchanged, including the exit signal in the
+/// low byte (e.g. `SIGCHLD` to be able to `wait` for the child). The flags
+/// that make the kernel write through extra pointers (`CLONE_SETTLS`,
+/// `CLONE_PARENT_SETTID`, `CLONE_CHILD_SETTID`, `CLONE_CHILD_CLEARTID`,
+/// `CLONE_PIDFD`) are not supported, since those pointers are passed as null.
+///
+/// The child exits (`exit`, not `exit_group`) with the value returned by `f`.
+/// The top of `stack` is aligned down to 16 bytes.
+///
+/// # Safety
+///
+/// - `stack` must stay valid and must not be used by anything else until the
+///   child exits. With `CLONE_VM`, this outlives the borrow.
+/// - `f` runs without thread-local storage or libc state, and must not
+///   unwind.
+/// - `flags` must describe a valid combination for `clone`.
+pub unsafe fn spawn_raw(
+    stack: &mut [u8],
+    flags: usize,
+    f: extern "C" fn(*mut c_void) -> i32,
+    arg: *mut c_void,
+) -> Result<usize, Errno> {
+    let top = stack.as_mut_ptr_range().end as usize & !15;
+    Errno::from_ret_u64(unsafe { trampoline(flags, top, f, arg) } as u64)
+        .map(|tid| tid as usize)
+}
+
+#[cfg(target_arch = "x86_64")]
+unsafe fn trampoline(
+    flags: usize,
+    stack: usize,
+    f: extern "C" fn(*mut c_void) -> i32,
+    arg: *mut c_void,
+) -> usize {
+    let ret: usize;
+    unsafe {
+        // `f` and `arg` live in registers that the kernel copies to the child.
+        // The stack is 16-byte aligned at the `call`, as required.
+        core::arch::asm!(
+            "syscall",
+            "test rax, rax",
+            "jnz 2f",
+            "xor ebp, ebp",
+            "mov rdi, r13",
+            "call r12",
+            "mov edi, eax",
+            "mov eax, {exit}",
+            "syscall",
+            "ud2",
+            "2:",
+            exit = const Sysno::exit as usize,
+            inlateout("rax") Sysno::clone as usize => ret,
+            in("rdi") flags,
+            in("rsi") stack,
+            in("rdx") 0,
+            in("r10") 0,
+            in("r8") 0,
+            in("r12") f,
+            in("r13") arg,
+            out("rcx") _,
+            out("r11") _,
+            options(nostack)
+        );
+    }
+    ret
+}
+
+#[cfg(target_arch = "aarch64")]
+unsafe fn trampoline(
+    flags: usize,
+    stack: usize,
+    f: extern "C" fn(*mut c_void) -> i32,
+    arg: *mut c_void,
+) -> usize {
+    let ret: usize;
+    unsafe {
+        core::arch::asm!(
+            "svc 0",
+            "cbnz x0, 2f",
+            "mov x29, xzr",
+            "mov x30, xzr",
+            "mov x0, x10",
+            "blr x9",
+            "mov x8, {exit}",
+            "svc 0",
+            "udf #0",
+            "2:",
+            exit = const Sysno::exit as usize,
+            in("x8") Sysno::clone as usize,
+            inlateout("x0") flags => ret,
+            in("x1") stack,
+            in("x2") 0,
+            in("x3") 0,
+            in("x4") 0,
+            in("x9") f,
+            in("x10") arg,
+            options(nostack)
+        );
+    }
+    ret
+}
+
+#[cfg(target_arch = "riscv64")]
+unsafe fn trampoline(
+    flags: usize,
+    stack: usize,
+    f: extern "C" fn(*mut c_void) -> i32,
+    arg: *mut c_void,
+) -> usize {
+    let ret: usize;
+    unsafe {
+        core::arch::asm!(
+            "ecall",
+            "bnez a0, 2f",
+            "mv fp, zero",
+            "mv a0, t1",
+            "jalr t0",
+            "li a7, {exit}",
+            "ecall",
+            "unimp",
+            "2:",
+            exit = const Sysno::exit as usize,
+            in("a7") Sysno::clone as usize,
+            inlateout("a0") flags => ret,
+            in("a1") stack,
+            in("a2") 0,
+            in("a3") 0,
+            in("a4") 0,
+            in("t0") f,
+            in("t1") arg,
+            options(nostack)
+        );
+    }
+    ret
+}
+
+#[cfg(target_arch = "loongarch64")]
+unsafe fn trampoline(
+    flags: usize,
+    stack: usize,
+    f: extern "C" fn(*mut c_void) -> i32,
+    arg: *mut c_void,
+) -> usize {
+    let ret: usize;
+    unsafe {
+        core::arch::asm!(
+            "syscall 0",
+            "bnez $a0, 2f",
+            "move $fp, $zero",
+            "move $a0, $t1",
+            "jirl $ra, $t0, 0",
+            "li.w $a7, {exit}",
+            "syscall 0",
+            "break 0",
+            "2:",
+            exit = const Sysno::exit as usize,
+            in("$a7") Sysno::clone as usize,
+            inlateout("$a0") flags => ret,
+            in("$a1") stack,
+            in("$a2") 0,
+            in("$a3") 0,
+            in("$a4") 0,
+            in("$t0") f,
+            in("$t1") arg,
+            options(nostack)
+        );
+    }
+    ret
+}
+
+/// Starts `path` with `argv` and `envp` in a child process that shares the
+/// parent's memory until it calls `execve` (like `posix_spawn`), and returns
+/// the child's PID.
+///
+/// This uses `clone(CLONE_VM | CLONE_VFORK | SIGCHLD)` without a new stack.
+/// Everything the child needs is loaded into registers before the `clone`,
+/// so it never touches the (shared) stack between `clone` and `execve`. If
+/// `execve` fails, the child reports the error through a register-held
+/// pointer and exits with status 127; the parent then reaps it and returns
+/// that error.
+///
+/// As in `posix_spawn`, all signals are blocked around the `clone`, so that
+/// no handler runs in the child on the parent's stack. The child restores
+/// the caller's mask right before `execve`, and the parent once `clone`
+/// returns. Handlers are not reset in the child, though: a signal sent to
+/// it between the two (e.g. to its process group) still runs the parent's
+/// handler.
+///
+/// The child is a regular child process: the caller has to `wait` for it.
+///
+/// # Safety
+///
+/// `path` must be a NUL-terminated string, and `argv` and `envp` must be
+/// null-terminated arrays of NUL-terminated strings, as for `execve`.
+pub unsafe fn fork_and_exec(
+    path: *const c_char,
+    argv: *const *const c_char,
+    envp: *const *const c_char,
+) -> Result<usize, Errno> {
+    let all: u64 = !0;
+    let mut old: u64 = 0;
+    unsafe {
+        crate::syscall4(
+            Sysno::rt_sigprocmask,
+            SIG_SETMASK as _,
+            &raw const all as _,
+            &raw mut old as _,
+            SIGSET_SIZE as _,
+        )?;
+    }
+
+    let mut err: i32 = 0;
+    let ret =
+        unsafe { vfork_exec(path, argv, envp, &raw const old, &raw mut err) };
+
+    // This can't fail: `old` is the mask the kernel just returned.
+    let _ = unsafe {
+        crate::syscall4(
+            Sysno::rt_sigprocmask,
+            SIG_SETMASK as _,
+            &raw const old as _,
+            0,
+            SIGSET_SIZE as _,
+        )
+    };
+    let pid = Errno::from_ret_u64(ret as u64)? as usize;
+
+    // With `CLONE_VFORK`, the child has either called `execve` or exited by
+    // now, so `err` is final.
+    if err != 0 {
+        unsafe {
+            crate::syscall4(Sysno::wait4, pid as _, 0, 0, 0)?;
+        }
+        return Err(Errno::new(err));
+    }
+    Ok(pid)
+}
+
+/// Flags for [`fork_and_exec`] and [`raw_vfork`].
+const VFORK_FLAGS: usize = CLONE_VM | CLONE_VFORK | SIGCHLD;
+
+#[cfg(target_arch = "x86_64")]
+unsafe fn vfork_exec(
+    path: *const c_char,
+    argv: *const *const c_char,
+    envp: *const *const c_char,
+    mask: *const u64,
+    err: *mut i32,
+) -> usize {
+    let ret: usize;
+    unsafe {
+        core::arch::asm!(
+            "syscall",
+            "test rax, rax",
+            "jnz 2f",
+            "mov eax, {sigprocmask}",
+            "mov edi, {setmask}",
+            "mov rsi, r9",
+            "xor edx, edx",
+            "mov r10d, {sigsetsize}",
+            "syscall",
+            "mov eax, {execve}",
+            "mov rdi, r12",
+            "mov rsi, r13",
+            "mov rdx, r14",
+            "syscall",
+            "neg eax",
+            "mov dword ptr [r15], eax",
+            "mov edi, 127",
+            "mov eax, {exit}",
+            "syscall",
+            "ud2",
+            "2:",
+            sigprocmask = const Sysno::rt_sigprocmask as usize,
+            setmask = const SIG_SETMASK,
+            sigsetsize = const SIGSET_SIZE,
+            execve = const Sysno::execve as usize,
+            exit = const Sysno::exit as usize,
+            inlateout("rax") Sysno::clone as usize => ret,
+            inlateout("rdi") VFORK_FLAGS => _,
+            inlateout("rsi") 0usize => _,
+            inlateout("rdx") 0usize => _,
+            in("r10") 0,
+            in("r8") 0,
+            in("r12") path,
+            in("r13") argv,
+            in("r14") envp,
+            in("r15") err,
+            in("r9") mask,
+            out("rcx") _,
+            out("r11") _,
+            options(nostack)
+        );
+    }
+    ret
+}
+
+#[cfg(target_arch = "aarch64")]
+unsafe fn vfork_exec(
+    path: *const c_char,
+    argv: *const *const c_char,
+    envp: *const *const c_char,
+    mask: *const u64,
+    err: *mut i32,
+) -> usize {
+    let ret: usize;
+    unsafe {
+        core::arch::asm!(
+            "svc 0",
+            "cbnz x0, 2f",
+            "mov x8, {sigprocmask}",
+            "mov x0, {setmask}",
+            "mov x1, x13",
+            "mov x2, xzr",
+            "mov x3, {sigsetsize}",
+            "svc 0",
+            "mov x8, {execve}",
+            "mov x0, x9",
+            "mov x1, x10",
+            "mov x2, x11",
+            "svc 0",
+            "neg w0, w0",
+            "str w0, [x12]",
+            "mov x0, 127",
+            "mov x8, {exit}",
+            "svc 0",
+            "udf #0",
+            "2:",
+            sigprocmask = const Sysno::rt_sigprocmask as usize,
+            setmask = const SIG_SETMASK,
+            sigsetsize = const SIGSET_SIZE,
+            execve = const Sysno::execve as usize,
+            exit = const Sysno::exit as usize,
+            inlateout("x8") Sysno::clone as usize => _,
+            inlateout("x0") VFORK_FLAGS => ret,
+            inlateout("x1") 0usize => _,
+            inlateout("x2") 0usize => _,
+            in("x3") 0,
+            in("x4") 0,
+            in("x9") path,
+            in("x10") argv,
+            in("x11") envp,
+            in("x12") err,
+            in("x13") mask,
+            options(nostack)
+        );
+    }
+    ret
+}
+
+#[cfg(target_arch = "riscv64")]
+unsafe fn vfork_exec(
+    path: *const c_char,
+    argv: *const *const c_char,
+    envp: *const *const c_char,
+    mask: *const u64,
+    err: *mut i32,
+) -> usize {
+    let ret: usize;
+    unsafe {
+        core::arch::asm!(
+            "ecall",
+            "bnez a0, 2f",
+            "li a7, {sigprocmask}",
+            "li a0, {setmask}",
+            "mv a1, t4",
+            "li a2, 0",
+            "li a3, {sigsetsize}",
+            "ecall",
+            "li a7, {execve}",
+            "mv a0, t0",
+            "mv a1, t1",
+            "mv a2, t2",
+            "ecall",
+            "negw a0, a0",
+            "sw a0, 0(t3)",
+            "li a0, 127",
+            "li a7, {exit}",
+            "ecall",
+            "unimp",
+            "2:",
+            sigprocmask = const Sysno::rt_sigprocmask as usize,
+            setmask = const SIG_SETMASK,
+            sigsetsize = const SIGSET_SIZE,
+            execve = const Sysno::execve as usize,
+            exit = const Sysno::exit as usize,
+            inlateout("a7") Sysno::clone as usize => _,
+            inlateout("a0") VFORK_FLAGS => ret,
+            inlateout("a1") 0usize => _,
+            inlateout("a2") 0usize => _,
+            in("a3") 0,
+            in("a4") 0,
+            in("t0") path,
+            in("t1") argv,
+            in("t2") envp,
+            in("t3") err,
+            in("t4") mask,
+            options(nostack)
+        );
+    }
+    ret
+}
+
+#[cfg(target_arch = "loongarch64")]
+unsafe fn vfork_exec(
+    path: *const c_char,
+    argv: *const *const c_char,
+    envp: *const *const c_char,
+    mask: *const u64,
+    err: *mut i32,
+) -> usize {
+    let ret: usize;
+    unsafe {
+        core::arch::asm!(
+            "syscall 0",
+            "bnez $a0, 2f",
+            "li.w $a7, {sigprocmask}",
+            "li.w $a0, {setmask}",
+            "move $a1, $t4",
+            "move $a2, $zero",
+            "li.w $a3, {sigsetsize}",
+            "syscall 0",
+            "li.w $a7, {execve}",
+            "move $a0, $t0",
+            "move $a1, $t1",
+            "move $a2, $t2",
+            "syscall 0",
+            "sub.w $a0, $zero, $a0",
+            "st.w $a0, $t3, 0",
+            "li.w $a0, 127",
+            "li.w $a7, {exit}",
+            "syscall 0",
+            "break 0",
+            "2:",
+            sigprocmask = const Sysno::rt_sigprocmask as usize,
+            setmask = const SIG_SETMASK,
+            sigsetsize = const SIGSET_SIZE,
+            execve = const Sysno::execve as usize,
+            exit = const Sysno::exit as usize,
+            inlateout("$a7") Sysno::clone as usize => _,
+            inlateout("$a0") VFORK_FLAGS => ret,
+            inlateout("$a1") 0usize => _,
+            inlateout("$a2") 0usize => _,
+            in("$a3") 0,
+            in("$a4") 0,
+            in("$t0") path,
+            in("$t1") argv,
+            in("$t2") envp,
+            in("$t3") err,
+            in("$t4") mask,
+            options(nostack)
+        );
+    }
+    ret
+}
+
+/// `vfork` that is safe to call from Rust: it keeps its return address in a
+/// register instead of on the (shared) stack, so the parent can still return
+/// after the child has run. Returns the kernel's raw result, like the
+/// functions in [`crate::raw`].
+///
+/// Prefer [`fork_and_exec`], which does not run any compiled code in the
+/// child.
+///
+/// # Safety
+///
+/// The child borrows the parent's stack and memory until it calls `execve`
+/// or exits, and the parent is suspended meanwhile. In the child, the caller
+/// may only issue syscalls through this crate (typically `execve` followed
+/// by `exit`): it must not return from the calling function, write to
+/// variables the parent uses, allocate, or unwind.
+#[cfg(target_arch = "x86_64")]
+#[unsafe(naked)]
+pub unsafe extern "C" fn raw_vfork() -> usize {
+    core::arch::naked_asm!(
+        "pop rdx",
+        "mov eax, {vfork}",
+        "syscall",
+        "push rdx",
+        "ret",
+        vfork = const Sysno::vfork as usize,
+    )
+}
+
+/// `vfork` that is safe to call from Rust: it keeps its return address in a
+/// register instead of on the (shared) stack, so the parent can still return
+/// after the child has run. Returns the kernel's raw result, like the
+/// functions in [`crate::raw`].
+///
+/// Prefer [`fork_and_exec`], which does not run any compiled code in the
+/// child.
+///
+/// # Safety
+///
+/// The child borrows the parent's stack and memory until it calls `execve`
+/// or exits, and the parent is suspended meanwhile. In the child, the caller
+/// may only issue syscalls through this crate (typically `execve` followed
+/// by `exit`): it must not return from the calling function, write to
+/// variables the parent uses, allocate, or unwind.
+#[cfg(target_arch = "aarch64")]
+#[unsafe(naked)]
+pub unsafe extern "C" fn raw_vfork() -> usize {
+    // There is no `vfork` syscall; the return address stays in x30.
+    core::arch::naked_asm!(
+        "mov x8, {clone}",
+        "mov x0, {flags}",
+        "mov x1, xzr",
+        "mov x2, xzr",
+        "mov x3, xzr",
+        "mov x4, xzr",
+        "svc 0",
+        "ret",
+        clone = const Sysno::clone as usize,
+        flags = const VFORK_FLAGS,
+    )
+}
+
+/// `vfork` that is safe to call from Rust: it keeps its return address in a
+/// register instead of on the (shared) stack, so the parent can still return
+/// after the child has run. Returns the kernel's raw result, like the
+/// functions in [`crate::raw`].
+///
+/// Prefer [`fork_and_exec`], which does not run any compiled code in the
+/// child.
+///
+/// # Safety
+///
+/// The child borrows the parent's stack and memory until it calls `execve`
+/// or exits, and the parent is suspended meanwhile. In the child, the caller
+/// may only issue syscalls through this crate (typically `execve` followed
+/// by `exit`): it must not return from the calling function, write to
+/// variables the parent uses, allocate, or unwind.
+#[cfg(target_arch = "riscv64")]
+#[unsafe(naked)]
+pub unsafe extern "C" fn raw_vfork() -> usize {
+    // There is no `vfork` syscall; the return address stays in ra.
+    core::arch::naked_asm!(
+        "li a7, {clone}",
+        "li a0, {flags}",
+        "li a1, 0",
+        "li a2, 0",
+        "li a3, 0",
+        "li a4, 0",
+        "ecall",
+        "ret",
+        clone = const Sysno::clone as usize,
+        flags = const VFORK_FLAGS,
+    )
+}
+
+/// `vfork` that is safe to call from Rust: it keeps its return address in a
+/// register instead of on the (shared) stack, so the parent can still return
+/// after the child has run. Returns the kernel's raw result, like the
+/// functions in [`crate::raw`].
+///
+/// Prefer [`fork_and_exec`], which does not run any compiled code in the
+/// child.
+///
+/// # Safety
+///
+/// The child borrows the parent's stack and memory until it calls `execve`
+/// or exits, and the parent is suspended meanwhile. In the child, the caller
+/// may only issue syscalls through this crate (typically `execve` followed
+/// by `exit`): it must not return from the calling function, write to
+/// variables the parent uses, allocate, or unwind.
+#[cfg(target_arch = "loongarch64")]
+#[unsafe(naked)]
+pub unsafe extern "C" fn raw_vfork() -> usize {
+    // There is no `vfork` syscall; the return address stays in $ra.
+    core::arch::naked_asm!(
+        "li.w $a7, {clone}",
+        "li.w $a0, {flags}",
+        "move $a1, $zero",
+        "move $a2, $zero",
+        "move $a3, $zero",
+        "move $a4, $zero",
+        "syscall 0",
+        "jr $ra",
+        clone = const Sysno::clone as usize,
+        flags = const VFORK_FLAGS,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    extern "C" fn child(arg: *mut c_void) -> i32 {
+        let counter = unsafe { &*arg.cast::<AtomicUsize>() };
+        counter.fetch_add(1, Ordering::SeqCst);
+        42
+    }
+
+    #[test]
+    fn spawn_and_wait() {
+        let counter = AtomicUsize::new(0);
+        let mut stack = [0u8; 16 * 1024];
+
+        let tid = unsafe {
+            spawn_raw(
+                &mut stack,
+                CLONE_VM | SIGCHLD,
+                child,
+                core::ptr::from_ref(&counter).cast_mut().cast(),
+            )
+        }
+        .unwrap();
+
+        let mut status: i32 = 0;
+        let waited = unsafe {
+            crate::syscall4(Sysno::wait4, tid as _, &raw mut status as _, 0, 0)
+        }
+        .unwrap();
+        assert_eq!(waited as usize, tid);
+        // WIFEXITED && WEXITSTATUS == 42
+        assert_eq!(status & 0x7f, 0);
+        assert_eq!((status >> 8) & 0xff, 42);
+        assert_eq!(counter.load(Ordering::SeqCst), 1);
+    }
+
+    fn wait(pid: usize) -> i32 {
+        let mut status: i32 = 0;
+        let waited = unsafe {
+            crate::syscall4(Sysno::wait4, pid as _, &raw mut status as _, 0, 0)
+        }
+        .unwrap();
+        assert_eq!(waited as usize, pid);
+        status
+    }
+
+    #[test]
+    fn fork_and_exec_true() {
+        let argv = [c"true".as_ptr(), core::ptr::null()];
+        let envp = [core::ptr::null()];
+        let pid = unsafe {
+            fork_and_exec(c"/bin/true".as_ptr(), argv.as_ptr(), envp.as_ptr())
+        }
+        .unwrap();
+        assert_eq!(wait(pid), 0);
+    }
+
+    #[test]
+    fn fork_and_exec_missing() {
+        let argv = [core::ptr::null()];
+        let ret = unsafe {
+            fork_and_exec(
+                c"/nonexistent".as_ptr(),
+                argv.as_ptr(),
+                argv.as_ptr(),
+            )
+        };
+        assert_eq!(ret, Err(Errno::ENOENT));
+    }
+
+    fn sigprocmask(how: usize, set: Option<u64>) -> u64 {
+        let mut old: u64 = 0;
+        let set = set.as_ref().map_or(0, |set| core::ptr::from_ref(set) as _);
+        unsafe {
+            crate::syscall4(
+                Sysno::rt_sigprocmask,
+                how as _,
+                set,
+                &raw mut old as _,
+                SIGSET_SIZE as _,
+            )
+        }
+        .unwrap();
+        old
+    }
+
+    #[test]
+    fn fork_and_exec_mask() {
+        // Block SIGUSR2 (12) only. The child must see that mask rather than
+        // the one blocking every signal around the `clone`.
+        let old = sigprocmask(SIG_SETMASK, Some(1 << 11));
+
+        let argv = [
+            c"grep".as_ptr(),
+            c"-q".as_ptr(),
+            c"^SigBlk:.0000000000000800$".as_ptr(),
+            c"/proc/self/status".as_ptr(),
+            core::ptr::null(),
+        ];
+        let envp = [core::ptr::null()];
+        let pid = unsafe {
+            fork_and_exec(c"/bin/grep".as_ptr(), argv.as_ptr(), envp.as_ptr())
+        }
+        .unwrap();
+        let mask = sigprocmask(SIG_SETMASK, Some(old));
+
+        assert_eq!(mask, 1 << 11);
+        assert_eq!(wait(pid), 0);
+    }
+
+    #[test]
+    fn raw_vfork_exit() {
+        let ret = unsafe { raw_vfork() };
+        if ret == 0 {
+            unsafe {
+                crate::raw::syscall1(Sysno::exit as _, 7);
+            }
+        }
+        let pid = Errno::from_ret_u64(ret as u64).unwrap() as usize;
+        assert_eq!((wait(pid) >> 8) & 0xff, 7);
+    }
+}
@@ -0,0 +1,138 @@
+//! Times, and the clocks that tell them
+//!
+//! [`Timespec`] and [`Timeval`] are the kernel's layouts of a time, which
+//! `helpers` and `vdso` take, and the `CLOCK_*` constants
+//! are the ids of its clocks.
+
+use core::time::Duration;
+
+/// The wall-clock time, which can be set and jump.
+pub const CLOCK_REALTIME: i32 = 0;
+/// The time since boot, without suspend, which only moves forward.
+pub const CLOCK_MONOTONIC: i32 = 1;
+/// The CPU time of the process.
+pub const CLOCK_PROCESS_CPUTIME_ID: i32 = 2;
+/// The CPU time of the thread.
+pub const CLOCK_THREAD_CPUTIME_ID: i32 = 3;
+/// [`CLOCK_MONOTONIC`] without the NTP adjustments of its rate.
+pub const CLOCK_MONOTONIC_RAW: i32 = 4;
+/// [`CLOCK_REALTIME`] as of the last tick, which is faster to read.
+pub const CLOCK_REALTIME_COARSE: i32 = 5;
+/// [`CLOCK_MONOTONIC`] as of the last tick, which is faster to read.
+pub const CLOCK_MONOTONIC_COARSE: i32 = 6;
+/// [`CLOCK_MONOTONIC`] with suspend.
+pub const CLOCK_BOOTTIME: i32 = 7;
+/// [`CLOCK_REALTIME`], whose timers wake the system from suspend.
+pub const CLOCK_REALTIME_ALARM: i32 = 8;
+/// [`CLOCK_BOOTTIME`], whose timers wake the system from suspend.
+pub const CLOCK_BOOTTIME_ALARM: i32 = 9;
+/// International Atomic Time, [`CLOCK_REALTIME`] without leap seconds.
+pub const CLOCK_TAI: i32 = 11;
+
+/// `long` of the kernel, which is 64 bits on x32 as well.
+#[cfg(any(target_pointer_width = "64", target_arch = "x86_64"))]
+pub(crate) type Long = i64;
+#[cfg(not(any(target_pointer_width = "64", target_arch = "x86_64")))]
+pub(crate) type Long = i32;
+/// A time or timeout in seconds and nanoseconds, as the syscalls with
+/// timeouts take it (`struct __kernel_timespec`), which is 64 bits wide on
+/// every target, unlike the `struct timespec` of the C library on 32-bit
+/// ones.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Timespec {
+    pub sec: i64,
+    /// Less than a second.
+    pub nsec: i64,
+}
+
+impl Timespec {
+    /// The time as a duration, or `None` if it is negative or the
+    /// nanoseconds are out of range.
+    pub fn as_duration(&self) -> Option<Duration> {
+        let sec = u64::try_from(self.sec).ok()?;
+        let nsec = u32::try_from(self.nsec).ok()?;
+        (nsec < 1_000_000_000).then(|| Duration::new(sec, nsec))
+    }
+}
+
+impl From<Duration> for Timespec {
+    fn from(duration: Duration) -> Self {
+        Self {
+            sec: i64::try_from(duration.as_secs()).unwrap_or(i64::MAX),
+            nsec: i64::from(duration.subsec_nanos()),
+        }
+    }
+}
+
+impl core::ops::Add<Duration> for Timespec {
+    type Output = Self;
+
+    /// Adds `duration`, saturating at the largest time, e.g. to make a
+    /// deadline from the current time.
+    fn add(self, duration: Duration) -> Self {
+        let duration = Self::from(duration);
+        let nsec = self.nsec + duration.nsec;
+        let carry = i64::from(nsec >= 1_000_000_000);
+        Self {
+            sec: self.sec.saturating_add(duration.sec).saturating_add(carry),
+            nsec: nsec - carry * 1_000_000_000,
+        }
+    }
+}
+
+/// A time in seconds and microseconds (`struct __kernel_old_timeval`), as
+/// in `struct rusage` and the socket timeouts. Its fields are `long`s,
+/// which overflow in 2038 on 32-bit targets.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Timeval {
+    pub sec: Long,
+    /// Less than a second.
+    pub usec: Long,
+}
+
+impl Timeval {
+    /// The time as a duration, or `None` if it is negative or the
+    /// microseconds are out of range.
+    pub fn as_duration(&self) -> Option<Duration> {
+        let sec = u64::try_from(self.sec).ok()?;
+        let usec = u32::try_from(self.usec).ok()?;
+        (usec < 1_000_000).then(|| Duration::new(sec, usec * 1000))
+    }
+}
+
+impl From<Duration> for Timeval {
+    /// Rounds `duration` down to microseconds, saturating at the largest
+    /// time.
+    #[allow(clippy::cast_lossless, clippy::cast_possible_wrap)]
+    fn from(duration: Duration) -> Self {
+        Self {
+            sec: Long::try_from(duration.as_secs()).unwrap_or(Long::MAX),
+            // Less than a million, which fits in any `long`.
+            usec: duration.subsec_micros() as Long,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn durations() {
+        let duration = Duration::new(3, 4_005_006);
+        let timespec = Timespec::from(duration);
+        assert_eq!((timespec.sec, timespec.nsec), (3, 4_005_006));
+        assert_eq!(timespec.as_duration(), Some(duration));
+        let timeval = Timeval::from(duration);
+        assert_eq!((timeval.sec, timeval.usec), (3, 4005));
+        assert_eq!(timeval.as_duration(), Some(Duration::new(3, 4_005_000)));
+        assert_eq!(Timespec { sec: -1, nsec: 0 }.as_duration(), None);
+        let timespec = Timespec {
+            sec: 0,
+            nsec: 1_000_000_000,
+        };
+        assert_eq!(timespec.as_duration(), None);
+    }
+}
@@ -0,0 +1,326 @@
+//! A ptrace syscall tracer
+//!
+//! [`Tracer`] runs a command, or attaches to a running process, under
+//! `PTRACE_SYSCALL` and yields a [`SyscallEvent`] for every syscall that
+//! returns, like `strace`:
+//!
+//! ```no_run
+//! use rawsys_linux::format::Call;
+//! use rawsys_linux::remote::ProcessVm;
+//! use rawsys_linux::tracer::Tracer;
+//! use std::process::Command;
+//!
+//! let tracer = Tracer::spawn(&mut Command::new("true")).unwrap();
+//! for event in tracer {
+//!     let event = event.unwrap();
+//!     let mem = ProcessVm::new(event.pid);
+//!     let call = Call::new(event.sysno, &event.args).mem(&mem);
+//!     match event.result {
+//!         Some(result) => println!("[{}] {}", event.pid, call.result(result)),
+//!         None => println!("[{}] {call} = ?", event.pid),
+//!     }
+//! }
+//! ```
+//!
+//! Threads and child processes are followed. Signals are passed on to the
+//! tracees. Group-stops (e.g. `SIGSTOP`) of attached tracees stop them as
+//! they would without the tracer, but spawned tracees aren't seized, so
+//! they can only be resumed from a group-stop and keep running. Syscalls are decoded with [`ptrace::syscall_info`]
+//! (Linux 5.3), and only those made with the target's calling convention are
+//! reported.
+//!
+//! The tracer waits for any child (`wait4(-1)`), so the process using it
+//! should have no other children that it waits for.
+//!
+//! Dropping the [`Tracer`] before it is done kills the spawned tracees and
+//! waits for them, or detaches from the attached ones, which keep running.
+
+use std::collections::HashMap;
+use std::io;
+use std::os::unix::process::CommandExt;
+use std::process::Command;
+use std::time::Instant;
+
+pub use crate::event::SyscallEvent;
+use crate::id::{Pid, Tid};
+use crate::procfs;
+use crate::ptrace::{
+    self, PTRACE_EVENT_STOP, PTRACE_O_EXITKILL, PTRACE_O_TRACECLONE,
+    PTRACE_O_TRACEEXEC, PTRACE_O_TRACEFORK, PTRACE_O_TRACESYSGOOD,
+    PTRACE_O_TRACEVFORK, SyscallStop, syscall_info,
+};
+use crate::{Errno, SyscallWord, Sysno};
+
+/// Syscall stops are reported as `SIGTRAP | 0x80`.
+const OPTIONS: u32 = PTRACE_O_TRACESYSGOOD
+    | PTRACE_O_TRACEFORK
+    | PTRACE_O_TRACEVFORK
+    | PTRACE_O_TRACECLONE
+    | PTRACE_O_TRACEEXEC;
+
+const SIGTRAP: i32 = 5;
+const SIGKILL: i32 = 9;
+const SIGSTOP: i32 = 19;
+const SIGTSTP: i32 = 20;
+const SIGTTIN: i32 = 21;
+const SIGTTOU: i32 = 22;
+
+/// `wait4` option to wait for threads too.
+const WALL: usize = 0x4000_0000;
+
+/// A traced thread.
+#[derive(Debug, Default)]
+struct Tracee {
+    /// Its process, once looked up.
+    pid: Option<Pid>,
+    /// The syscall it is in, from its entry stop on, and when it entered.
+    syscall: Option<(SyscallEvent, Instant)>,
+}
+
+/// Traces processes and yields their syscalls. See the module docs.
+#[derive(Debug)]
+pub struct Tracer {
+    tracees: HashMap<Tid, Tracee>,
+    /// Whether the tracees were spawned, and are killed on drop.
+    spawned: bool,
+    done: bool,
+}
+
+impl Tracer {
+    /// Spawns `command` traced from its `execve` on. If the tracer goes
+    /// away or is dropped, the tracees are killed.
+    pub fn spawn(command: &mut Command) -> io::Result<Self> {
+        unsafe {
+            command.pre_exec(|| {
+                ptrace::traceme().map_err(io_error)?;
+                Ok(())
+            });
+        }
+        let pid = i32::try_from(command.spawn()?.id())
+            .map_err(|_| io::Error::from(io::ErrorKind::InvalidData))?;
+
+        // The child stops with `SIGTRAP` after its `execve`.
+        let (tid, status) = wait4(pid).map_err(io_error)?;
+        if stop_signal(status) != Some(SIGTRAP) {
+            return Err(io::Error::other("the child didn't stop at execve"));
+        }
+        ptrace::set_options(tid, OPTIONS | PTRACE_O_EXITKILL)
+            .map_err(io_error)?;
+        ptrace::syscall(tid, 0).map_err(io_error)?;
+
+        Ok(Self {
+            tracees: HashMap::from([(tid, Tracee::default())]),
+            spawned: true,
+            done: false,
+        })
+    }
+
+    /// Attaches to all threads of the running process `pid`, with
+    /// `PTRACE_SEIZE`. Threads it creates afterwards are followed as well.
+    /// Dropping the tracer detaches from them.
+    pub fn attach(pid: Pid) -> io::Result<Self> {
+        let mut tracees = HashMap::new();
+        for task in std::fs::read_dir(format!("/proc/{pid}/task"))? {
+            let Some(tid) = task?
+                .file_name()
+                .to_str()
+                .and_then(|tid| tid.parse().ok())
+                .map(Tid::from_raw)
+            else {
+                continue;
+            };
+            match ptrace::seize(tid, OPTIONS)
+                .and_then(|()| ptrace::interrupt(tid))
+            {
+                Ok(()) => {
+                    tracees.insert(tid, Tracee::default());
+                }
+                // The thread exited in the meantime.
+                Err(Errno::ESRCH) => {}
+                Err(errno) => return Err(io_error(errno)),
+            }
+        }
+        Ok(Self {
+            tracees,
+            spawned: false,
+            done: false,
+        })
+    }
+
+    /// Handles the stop of the thread `tid` and returns the syscall it
+    /// completed, if any. The tracee is resumed (or left in its group-stop).
+    fn on_stop(
+        &mut self,
+        tid: Tid,
+        signal: i32,
+        event: u32,
+    ) -> Option<SyscallEvent> {
+        // New tracees start with a stop that isn't meant for them.
+        let new = !self.tracees.contains_key(&tid);
+        let tracee = self.tracees.entry(tid).or_default();
+        let mut inject = 0;
+        let mut completed = None;
+        if signal == SIGTRAP | 0x80 {
+            let info = syscall_info(tid);
+            tracee.syscall = match info.as_ref().map(|info| info.stop) {
+                Ok(SyscallStop::Entry { .. }) => {
+                    let pid = *tracee.pid.get_or_insert_with(|| {
+                        procfs::tgid(tid).unwrap_or(Pid::from_raw(tid.as_raw()))
+                    });
+                    SyscallEvent::from_syscall_info(
+                        pid,
+                        tid,
+                        info.as_ref().unwrap(),
+                    )
+                    .map(|event| (event, Instant::now()))
+                }
+                Ok(SyscallStop::Exit { ret }) => {
+                    completed =
+                        tracee.syscall.take().map(|(event, entered)| {
+                            SyscallEvent {
+                                result: Some(ret.map(|ret| ret as SyscallWord)),
+                                duration: Some(entered.elapsed()),
+                                ..event
+                            }
+                        });
+                    None
+                }
+                _ => None,
+            };
+        } else if event == PTRACE_EVENT_STOP {
+            // A group-stop of a seized tracee, which `PTRACE_LISTEN` keeps
+            // until `SIGCONT`, or the stop after attaching.
+            if is_stopping(signal) && !new {
+                let _ = ptrace::listen(tid);
+                return None;
+            }
+        } else if event == 0 {
+            // A signal about to be delivered, unless `PTRACE_GETSIGINFO`
+            // fails, which means a group-stop of a tracee that isn't seized,
+            // which can only be resumed.
+            let group_stop =
+                ptrace::get_siginfo(tid, &mut [0; 128]) == Err(Errno::EINVAL);
+            if !(group_stop || new && signal == SIGSTOP) {
+                inject = signal;
+            }
+        }
+        // Other `PTRACE_EVENT_*` stops (fork, clone, exec) only need to be
+        // resumed; the new tracees show up with stops of their own.
+
+        let _ = ptrace::syscall(tid, inject);
+        completed
+    }
+}
+
+impl Iterator for Tracer {
+    type Item = Result<SyscallEvent, Errno>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            let (tid, status) = match wait4(-1) {
+                Ok(stop) => stop,
+                Err(Errno::EINTR) => continue,
+                Err(errno) => {
+                    self.done = true;
+                    return (errno != Errno::ECHILD).then_some(Err(errno));
+                }
+            };
+
+            let Some(signal) = stop_signal(status) else {
+                // The tracee exited or was killed, maybe in a syscall.
+                if let Some((event, _)) =
+                    self.tracees.remove(&tid).and_then(|tracee| tracee.syscall)
+                {
+                    return Some(Ok(event));
+                }
+                continue;
+            };
+            if let Some(event) =
+                self.on_stop(tid, signal, (status >> 16) as u32)
+            {
+                return Some(Ok(event));
+            }
+        }
+        None
+    }
+}
+
+impl Drop for Tracer {
+    /// Kills the spawned tracees and reaps them, or stops the attached ones
+    /// with `PTRACE_INTERRUPT` to detach from them, passing on the signals
+    /// they were stopped for.
+    fn drop(&mut self) {
+        if self.done {
+            return;
+        }
+        for &tid in self.tracees.keys() {
+            if self.spawned {
+                let _ = unsafe {
+                    crate::syscall2(
+                        Sysno::kill,
+                        tid.as_raw() as _,
+                        SIGKILL as _,
+                    )
+                };
+            } else {
+                let _ = ptrace::interrupt(tid);
+            }
+        }
+
+        // New tracees may still show up, to be killed or detached as well.
+        while self.spawned || !self.tracees.is_empty() {
+            let (tid, status) = match wait4(-1) {
+                Ok(stop) => stop,
+                Err(Errno::EINTR) => continue,
+                // With `ECHILD`, all spawned tracees are reaped.
+                Err(_) => break,
+            };
+            let known = self.tracees.remove(&tid).is_some();
+            let Some(signal) = stop_signal(status) else {
+                continue;
+            };
+            if self.spawned {
+                let _ = ptrace::kill(tid);
+                continue;
+            }
+            // Only signal-delivery-stops have a signal to pass on.
+            let deliver = known
+                && status >> 16 == 0
+                && signal != SIGTRAP | 0x80
+                && ptrace::get_siginfo(tid, &mut [0; 128]).is_ok();
+            let _ = ptrace::detach(tid, if deliver { signal } else { 0 });
+        }
+    }
+}
+
+/// Waits for a state change of `pid` (or any tracee if -1), and returns
+/// which thread and its `wait` status.
+fn wait4(pid: i32) -> Result<(Tid, i32), Errno> {
+    let mut status = 0i32;
+    let pid = unsafe {
+        crate::syscall4(
+            Sysno::wait4,
+            pid as _,
+            core::ptr::from_mut(&mut status) as _,
+            WALL as _,
+            0,
+        )
+    }?;
+    #[allow(clippy::cast_possible_truncation)]
+    Ok((Tid::from_raw(pid as i32), status))
+}
+
+/// The signal of a stopped `wait` status (`WSTOPSIG`), or `None` if the
+/// tracee exited or was killed.
+fn stop_signal(status: i32) -> Option<i32> {
+    (status & 0xff == 0x7f).then_some((status >> 8) & 0xff)
+}
+
+/// Whether `signal` stops the process by default.
+fn is_stopping(signal: i32) -> bool {
+    matches!(signal, SIGSTOP | SIGTSTP | SIGTTIN | SIGTTOU)
+}
+
+fn io_error(errno: Errno) -> io::Error {
+    io::Error::from_raw_os_error(errno.into_raw())
+}
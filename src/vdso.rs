@@ -0,0 +1,599 @@
+//! vDSO lookup and fast-path time functions
+//!
+//! The kernel maps a small shared object, the vDSO, into every process. It
+//! implements a few read-only syscalls (`clock_gettime`, `gettimeofday`,
+//! `getcpu`, ...) entirely in user space, which is much cheaper than entering
+//! the kernel.
+//!
+//! - [`Vdso`] locates the vDSO through `AT_SYSINFO_EHDR` (see [`crate::auxv`])
+//!   and looks up its exported symbols from the ELF dynamic symbol table.
+//! - [`clock_gettime`], [`gettimeofday`], and [`getcpu`] call the vDSO
+//!   function when the kernel provides it, and fall back to the real syscall
+//!   otherwise. The lookup happens once per function.
+//!
+//! Supported on `x86`, `x86_64`, `arm`, `aarch64`, `riscv64`, `loongarch64`,
+//! `mips`, `mips64`, and `s390x`. (The powerpc vDSO uses a non-standard
+//! calling convention and is not supported.)
+//!
+//! Example
+//! ```no_run
+//! use rawsys_linux::vdso::{self, CLOCK_MONOTONIC, Timespec};
+//!
+//! let mut ts = Timespec::default();
+//! vdso::clock_gettime(CLOCK_MONOTONIC, &mut ts).unwrap();
+//! ```
+
+use core::ffi::{CStr, c_void};
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::{Errno, Sysno, syscall2};
+
+pub use crate::time::{
+    CLOCK_BOOTTIME, CLOCK_MONOTONIC, CLOCK_MONOTONIC_COARSE,
+    CLOCK_MONOTONIC_RAW, CLOCK_PROCESS_CPUTIME_ID, CLOCK_REALTIME,
+    CLOCK_REALTIME_COARSE, CLOCK_THREAD_CPUTIME_ID, Timespec, Timeval,
+};
+
+/// The `clock_gettime` that takes a [`Timespec`], which is
+/// `clock_gettime64` where `long` is 32 bits wide.
+#[cfg(any(target_pointer_width = "64", target_arch = "x86_64"))]
+const CLOCK_GETTIME_SYSNO: Sysno = Sysno::clock_gettime;
+#[cfg(not(any(target_pointer_width = "64", target_arch = "x86_64")))]
+const CLOCK_GETTIME_SYSNO: Sysno = Sysno::clock_gettime64;
+
+// ELF structures for the native word size.
+#[cfg(target_pointer_width = "64")]
+#[allow(clippy::struct_field_names)] // Named after the ELF specification.
+mod elf {
+    #[repr(C)]
+    pub struct Ehdr {
+        pub e_ident: [u8; 16],
+        pub e_type: u16,
+        pub e_machine: u16,
+        pub e_version: u32,
+        pub e_entry: u64,
+        pub e_phoff: u64,
+        pub e_shoff: u64,
+        pub e_flags: u32,
+        pub e_ehsize: u16,
+        pub e_phentsize: u16,
+        pub e_phnum: u16,
+    }
+
+    #[repr(C)]
+    pub struct Phdr {
+        pub p_type: u32,
+        pub p_flags: u32,
+        pub p_offset: u64,
+        pub p_vaddr: u64,
+        pub p_paddr: u64,
+        pub p_filesz: u64,
+        pub p_memsz: u64,
+        pub p_align: u64,
+    }
+
+    #[repr(C)]
+    pub struct Sym {
+        pub st_name: u32,
+        pub st_info: u8,
+        pub st_other: u8,
+        pub st_shndx: u16,
+        pub st_value: u64,
+        pub st_size: u64,
+    }
+
+    pub const CLASS: u8 = 2;
+}
+
+#[cfg(target_pointer_width = "32")]
+#[allow(clippy::struct_field_names)] // Named after the ELF specification.
+mod elf {
+    #[repr(C)]
+    pub struct Ehdr {
+        pub e_ident: [u8; 16],
+        pub e_type: u16,
+        pub e_machine: u16,
+        pub e_version: u32,
+        pub e_entry: u32,
+        pub e_phoff: u32,
+        pub e_shoff: u32,
+        pub e_flags: u32,
+        pub e_ehsize: u16,
+        pub e_phentsize: u16,
+        pub e_phnum: u16,
+    }
+
+    #[repr(C)]
+    pub struct Phdr {
+        pub p_type: u32,
+        pub p_offset: u32,
+        pub p_vaddr: u32,
+        pub p_paddr: u32,
+        pub p_filesz: u32,
+        pub p_memsz: u32,
+        pub p_flags: u32,
+        pub p_align: u32,
+    }
+
+    #[repr(C)]
+    pub struct Sym {
+        pub st_name: u32,
+        pub st_value: u32,
+        pub st_size: u32,
+        pub st_info: u8,
+        pub st_other: u8,
+        pub st_shndx: u16,
+    }
+
+    pub const CLASS: u8 = 1;
+}
+
+const PT_LOAD: u32 = 1;
+const PT_DYNAMIC: u32 = 2;
+
+const DT_NULL: usize = 0;
+const DT_HASH: usize = 4;
+const DT_STRTAB: usize = 5;
+const DT_SYMTAB: usize = 6;
+const DT_GNU_HASH: usize = 0x6fff_fef5;
+
+const STT_FUNC: u8 = 2;
+const STB_GLOBAL: u8 = 1;
+const STB_WEAK: u8 = 2;
+
+/// The parsed dynamic symbol table of the vDSO.
+#[derive(Debug, Clone, Copy)]
+pub struct Vdso {
+    load_offset: usize,
+    symtab: *const elf::Sym,
+    strtab: *const u8,
+    nsyms: usize,
+}
+
+// The vDSO is mapped read-only for the lifetime of the process.
+unsafe impl Send for Vdso {}
+unsafe impl Sync for Vdso {}
+
+impl Vdso {
+    /// Parses the vDSO of the current process, if the kernel mapped one.
+    pub fn current() -> Option<Self> {
+        let ehdr = crate::auxv::sysinfo_ehdr()?;
+        unsafe { Self::from_ehdr(ehdr) }
+    }
+
+    /// Parses a vDSO image mapped at `ehdr`.
+    ///
+    /// # Safety
+    ///
+    /// `ehdr` must be the address of a mapped vDSO (or an equivalent, fully
+    /// mapped ELF shared object) that stays mapped for as long as the result
+    /// is used.
+    pub unsafe fn from_ehdr(ehdr: usize) -> Option<Self> {
+        let header = unsafe { &*(ehdr as *const elf::Ehdr) };
+        if header.e_ident[..4] != *b"\x7fELF" || header.e_ident[4] != elf::CLASS
+        {
+            return None;
+        }
+
+        let phdrs = unsafe {
+            core::slice::from_raw_parts(
+                (ehdr + header.e_phoff as usize) as *const elf::Phdr,
+                header.e_phnum.into(),
+            )
+        };
+        let load = phdrs.iter().find(|p| p.p_type == PT_LOAD)?;
+        let dynamic = phdrs.iter().find(|p| p.p_type == PT_DYNAMIC)?;
+        let load_offset =
+            (ehdr + load.p_offset as usize).wrapping_sub(load.p_vaddr as usize);
+
+        let mut symtab = 0;
+        let mut strtab = 0;
+        let mut hash = 0;
+        let mut gnu_hash = 0;
+        let mut entry = (ehdr + dynamic.p_offset as usize) as *const usize;
+        loop {
+            let (tag, value) = unsafe { (*entry, *entry.add(1)) };
+            let addr = load_offset.wrapping_add(value);
+            match tag {
+                DT_NULL => break,
+                DT_SYMTAB => symtab = addr,
+                DT_STRTAB => strtab = addr,
+                DT_HASH => hash = addr,
+                DT_GNU_HASH => gnu_hash = addr,
+                _ => {}
+            }
+            entry = unsafe { entry.add(2) };
+        }
+
+        if symtab == 0 || strtab == 0 {
+            return None;
+        }
+        let nsyms = if hash != 0 {
+            // nbucket, nchain, ...: there is one chain entry per symbol.
+            unsafe { *(hash as *const u32).add(1) as usize }
+        } else if gnu_hash != 0 {
+            unsafe { gnu_hash_nsyms(gnu_hash) }
+        } else {
+            return None;
+        };
+
+        Some(Self {
+            load_offset,
+            symtab: symtab as *const elf::Sym,
+            strtab: strtab as *const u8,
+            nsyms,
+        })
+    }
+
+    /// Returns the address of the function `name`, if the vDSO exports it.
+    pub fn lookup(&self, name: &CStr) -> Option<usize> {
+        let syms =
+            unsafe { core::slice::from_raw_parts(self.symtab, self.nsyms) };
+        syms.iter()
+            .find(|sym| {
+                let bind = sym.st_info >> 4;
+                sym.st_info & 0xf == STT_FUNC
+                    && (bind == STB_GLOBAL || bind == STB_WEAK)
+                    && sym.st_shndx != 0
+                    && unsafe {
+                        CStr::from_ptr(
+                            self.strtab.add(sym.st_name as usize).cast(),
+                        )
+                    } == name
+            })
+            .map(|sym| self.load_offset.wrapping_add(sym.st_value as usize))
+    }
+}
+
+/// Counts the symbols described by a `DT_GNU_HASH` table.
+unsafe fn gnu_hash_nsyms(table: usize) -> usize {
+    unsafe {
+        let header = table as *const u32;
+        let nbuckets = *header as usize;
+        let symoffset = *header.add(1) as usize;
+        let bloom_size = *header.add(2) as usize;
+        let buckets = (table + 16 + bloom_size * core::mem::size_of::<usize>())
+            as *const u32;
+        let chains = buckets.add(nbuckets);
+
+        let last = (0..nbuckets).map(|i| *buckets.add(i) as usize).max();
+        match last {
+            Some(mut sym) if sym >= symoffset => {
+                // The last chain ends with an entry that has its low bit set.
+                while *chains.add(sym - symoffset) & 1 == 0 {
+                    sym += 1;
+                }
+                sym + 1
+            }
+            _ => symoffset,
+        }
+    }
+}
+
+#[cfg(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "arm",
+    target_arch = "riscv64",
+    target_arch = "loongarch64",
+    target_arch = "mips",
+    target_arch = "mips64"
+))]
+mod names {
+    #[cfg(any(target_pointer_width = "64", target_arch = "x86_64"))]
+    pub const CLOCK_GETTIME: &core::ffi::CStr = c"__vdso_clock_gettime";
+    // The one of `__vdso_clock_gettime` takes a 32-bit `timespec`.
+    #[cfg(not(any(target_pointer_width = "64", target_arch = "x86_64")))]
+    pub const CLOCK_GETTIME: &core::ffi::CStr = c"__vdso_clock_gettime64";
+    pub const GETTIMEOFDAY: &core::ffi::CStr = c"__vdso_gettimeofday";
+    pub const GETCPU: &core::ffi::CStr = c"__vdso_getcpu";
+    #[cfg_attr(target_pointer_width = "32", allow(dead_code))]
+    pub const GETRANDOM: &core::ffi::CStr = c"__vdso_getrandom";
+}
+
+#[cfg(any(target_arch = "aarch64", target_arch = "s390x"))]
+mod names {
+    pub const CLOCK_GETTIME: &core::ffi::CStr = c"__kernel_clock_gettime";
+    pub const GETTIMEOFDAY: &core::ffi::CStr = c"__kernel_gettimeofday";
+    pub const GETCPU: &core::ffi::CStr = c"__kernel_getcpu";
+    #[cfg_attr(target_pointer_width = "32", allow(dead_code))]
+    pub const GETRANDOM: &core::ffi::CStr = c"__kernel_getrandom";
+}
+
+/// Function not looked up yet.
+const UNKNOWN: usize = usize::MAX;
+/// The vDSO does not provide the function; use the syscall.
+const ABSENT: usize = 0;
+
+static CLOCK_GETTIME: AtomicUsize = AtomicUsize::new(UNKNOWN);
+static GETTIMEOFDAY: AtomicUsize = AtomicUsize::new(UNKNOWN);
+static GETCPU: AtomicUsize = AtomicUsize::new(UNKNOWN);
+#[cfg_attr(target_pointer_width = "32", allow(dead_code))]
+static GETRANDOM: AtomicUsize = AtomicUsize::new(UNKNOWN);
+
+/// Returns the cached address of a vDSO function, looking it up on first use.
+#[inline]
+fn resolve(cache: &AtomicUsize, name: &CStr) -> Option<usize> {
+    match cache.load(Ordering::Relaxed) {
+        ABSENT => None,
+        UNKNOWN => detect(cache, name),
+        addr => Some(addr),
+    }
+}
+
+#[cold]
+fn detect(cache: &AtomicUsize, name: &CStr) -> Option<usize> {
+    let addr = Vdso::current()
+        .and_then(|vdso| vdso.lookup(name))
+        .filter(|&addr| addr != ABSENT && addr != UNKNOWN);
+    cache.store(addr.unwrap_or(ABSENT), Ordering::Relaxed);
+    addr
+}
+
+/// Converts the "negative errno" result of a vDSO function.
+#[inline]
+fn from_vdso(ret: i32) -> Result<(), Errno> {
+    if ret < 0 {
+        Err(Errno::new(-ret))
+    } else {
+        Ok(())
+    }
+}
+
+/// Reads the clock `clock` into `ts`, through the vDSO if possible, with
+/// 64-bit seconds on every target.
+pub fn clock_gettime(clock: i32, ts: &mut Timespec) -> Result<(), Errno> {
+    if let Some(addr) = resolve(&CLOCK_GETTIME, names::CLOCK_GETTIME) {
+        let f: extern "C" fn(i32, *mut Timespec) -> i32 =
+            unsafe { core::mem::transmute(addr) };
+        return from_vdso(f(clock, ts));
+    }
+    unsafe {
+        syscall2(
+            CLOCK_GETTIME_SYSNO,
+            clock as _,
+            core::ptr::from_mut(ts) as _,
+        )
+    }
+    .map(drop)
+}
+
+/// Reads the time of day into `tv`, through the vDSO if possible.
+pub fn gettimeofday(tv: &mut Timeval) -> Result<(), Errno> {
+    if let Some(addr) = resolve(&GETTIMEOFDAY, names::GETTIMEOFDAY) {
+        let f: extern "C" fn(*mut Timeval, usize) -> i32 =
+            unsafe { core::mem::transmute(addr) };
+        return from_vdso(f(tv, 0));
+    }
+    unsafe { syscall2(Sysno::gettimeofday, core::ptr::from_mut(tv) as _, 0) }
+        .map(drop)
+}
+
+/// Returns the CPU and NUMA node the calling thread is running on, through
+/// the vDSO if possible.
+pub fn getcpu() -> Result<(u32, u32), Errno> {
+    let mut cpu: u32 = 0;
+    let mut node: u32 = 0;
+    if let Some(addr) = resolve(&GETCPU, names::GETCPU) {
+        let f: extern "C" fn(*mut u32, *mut u32, usize) -> i32 =
+            unsafe { core::mem::transmute(addr) };
+        from_vdso(f(&raw mut cpu, &raw mut node, 0))?;
+    } else {
+        unsafe {
+            crate::syscall3(
+                Sysno::getcpu,
+                &raw mut cpu as _,
+                &raw mut node as _,
+                0,
+            )
+        }?;
+    }
+    Ok((cpu, node))
+}
+
+/// `__vdso_getrandom(buf, len, flags, opaque_state, opaque_len)`.
+type VgetrandomFn =
+    extern "C" fn(*mut u8, usize, u32, *mut c_void, usize) -> isize;
+
+/// `struct vgetrandom_opaque_params`, returned by the vDSO when it is called
+/// with an `opaque_len` of `usize::MAX`.
+#[repr(C)]
+#[derive(Default)]
+struct OpaqueParams {
+    size_of_opaque_state: u32,
+    mmap_prot: u32,
+    mmap_flags: u32,
+    reserved: [u32; 13],
+}
+
+/// Per-thread state for the vDSO `getrandom` (Linux 6.11+).
+///
+/// The vDSO keeps its CSPRNG state in memory that the caller allocates with
+/// the protection and flags the kernel asks for. A state must only be used by
+/// one thread at a time, which is why [`GetrandomState::fill`] takes
+/// `&mut self`. The memory is unmapped on drop.
+#[derive(Debug)]
+pub struct GetrandomState {
+    state: *mut c_void,
+    state_len: usize,
+    map_len: usize,
+    f: VgetrandomFn,
+}
+
+// The state may move between threads, as long as only one uses it.
+unsafe impl Send for GetrandomState {}
+
+impl GetrandomState {
+    /// Allocates a state. Fails with `ENOSYS` if the vDSO has no `getrandom`.
+    #[cfg(target_pointer_width = "64")]
+    pub fn new() -> Result<Self, Errno> {
+        let addr =
+            resolve(&GETRANDOM, names::GETRANDOM).ok_or(Errno::ENOSYS)?;
+        let f: VgetrandomFn = unsafe { core::mem::transmute(addr) };
+
+        let mut params = OpaqueParams::default();
+        let ret = f(
+            core::ptr::null_mut(),
+            0,
+            0,
+            (&raw mut params).cast(),
+            usize::MAX,
+        );
+        if ret != 0 {
+            return Err(Errno::ENOSYS);
+        }
+
+        // A state must not cross a page boundary, so it gets its own pages.
+        let state_len = params.size_of_opaque_state as usize;
+        let page = crate::auxv::page_size().unwrap_or(4096);
+        let map_len = state_len.div_ceil(page) * page;
+        let state = unsafe {
+            crate::syscall6(
+                Sysno::mmap,
+                0,
+                map_len as _,
+                params.mmap_prot.into(),
+                params.mmap_flags.into(),
+                -1i64 as _,
+                0,
+            )
+        }? as *mut c_void;
+
+        Ok(Self {
+            state,
+            state_len,
+            map_len,
+            f,
+        })
+    }
+
+    /// Allocates a state. The vDSO `getrandom` is only available on 64-bit
+    /// architectures, so this always fails with `ENOSYS`.
+    #[cfg(target_pointer_width = "32")]
+    pub fn new() -> Result<Self, Errno> {
+        Err(Errno::ENOSYS)
+    }
+
+    /// Fills `buf` with random bytes like `getrandom(2)`, and returns the
+    /// number of bytes written.
+    pub fn fill(&mut self, buf: &mut [u8], flags: u32) -> Result<usize, Errno> {
+        let ret = (self.f)(
+            buf.as_mut_ptr(),
+            buf.len(),
+            flags,
+            self.state,
+            self.state_len,
+        );
+        if ret < 0 {
+            Err(Errno::new(-ret as i32))
+        } else {
+            Ok(ret as usize)
+        }
+    }
+}
+
+impl Drop for GetrandomState {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = crate::syscall2(
+                Sysno::munmap,
+                self.state as _,
+                self.map_len as _,
+            );
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static GETRANDOM_STATE: core::cell::RefCell<Option<GetrandomState>> =
+        const { core::cell::RefCell::new(None) };
+}
+
+/// Fills `buf` with random bytes like `getrandom(2)`, and returns the number
+/// of bytes written.
+///
+/// With `std`, each thread lazily allocates a [`GetrandomState`] and uses the
+/// vDSO when the kernel provides it (Linux 6.11+). Otherwise, or if the state
+/// is already in use (e.g. from a signal handler), the `getrandom` syscall is
+/// used.
+pub fn getrandom(buf: &mut [u8], flags: u32) -> Result<usize, Errno> {
+    #[cfg(feature = "std")]
+    {
+        let fast = GETRANDOM_STATE.try_with(|cell| {
+            let mut slot = cell.try_borrow_mut().ok()?;
+            if slot.is_none() {
+                *slot = Some(GetrandomState::new().ok()?);
+            }
+            slot.as_mut().map(|state| state.fill(buf, flags))
+        });
+        if let Ok(Some(ret)) = fast {
+            return ret;
+        }
+    }
+
+    unsafe {
+        crate::syscall3(
+            Sysno::getrandom,
+            buf.as_mut_ptr() as _,
+            buf.len() as _,
+            flags.into(),
+        )
+    }
+    .map(|len| len as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookup() {
+        let vdso = Vdso::current().unwrap();
+        assert!(vdso.lookup(names::CLOCK_GETTIME).is_some());
+        assert_eq!(vdso.lookup(c"__vdso_does_not_exist"), None);
+    }
+
+    #[test]
+    fn matches_syscall() {
+        let mut fast = Timespec::default();
+        let mut slow = Timespec::default();
+        clock_gettime(CLOCK_MONOTONIC, &mut fast).unwrap();
+        unsafe {
+            syscall2(
+                CLOCK_GETTIME_SYSNO,
+                CLOCK_MONOTONIC as _,
+                (&raw mut slow) as _,
+            )
+        }
+        .unwrap();
+        assert!(slow >= fast);
+        assert!(slow.sec - fast.sec <= 1);
+
+        assert_eq!(
+            clock_gettime(-1, &mut fast),
+            Err(Errno::EINVAL),
+            "invalid clocks are reported like the syscall does"
+        );
+
+        let mut tv = Timeval::default();
+        gettimeofday(&mut tv).unwrap();
+        assert!(tv.sec > 0);
+
+        getcpu().unwrap();
+    }
+
+    #[test]
+    fn vgetrandom() {
+        let mut buf = [0u8; 64];
+        assert_eq!(getrandom(&mut buf, 0), Ok(64));
+        assert_ne!(buf, [0; 64]);
+
+        // Not available before Linux 6.11.
+        if let Ok(mut state) = GetrandomState::new() {
+            let mut other = [0u8; 64];
+            assert_eq!(state.fill(&mut other, 0), Ok(64));
+            assert_ne!(buf, other);
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use crate::consts::Preprocessor;
+use crate::formats::hex_literal;
+use color_eyre::eyre::{Result, WrapErr, eyre};
+use std::fmt::Write as _;
+use std::fs::{self, create_dir_all};
+use std::path::PathBuf;
+
+/// The `AUDIT_ARCH_*` token (without the prefix) of each arch with a syscall
+/// table, with the extra `cfg` that picks it for that arch, if any. Archs
+/// that can be either endian have a token for each.
+static AUDIT_ARCHES: &[(&str, &str, Option<&str>)] = &[
+    ("aarch64", "AARCH64", None),
+    ("arm", "ARM", Some("target_endian = \"little\"")),
+    ("arm", "ARMEB", Some("target_endian = \"big\"")),
+    ("ia64", "IA64", None),
+    ("loongarch64", "LOONGARCH64", None),
+    ("mips", "MIPS", Some("target_endian = \"big\"")),
+    ("mips", "MIPSEL", Some("target_endian = \"little\"")),
+    ("mips64", "MIPS64", Some("target_endian = \"big\"")),
+    ("mips64", "MIPSEL64", Some("target_endian = \"little\"")),
+    ("powerpc", "PPC", None),
+    ("powerpc64", "PPC64", Some("target_endian = \"big\"")),
+    ("powerpc64", "PPC64LE", Some("target_endian = \"little\"")),
+    ("riscv32", "RISCV32", None),
+    ("riscv64", "RISCV64", None),
+    ("s390x", "S390X", None),
+    ("sparc", "SPARC", None),
+    ("sparc64", "SPARC64", None),
+    ("x86", "I386", None),
+    ("x86_64", "X86_64", None),
+];
+
+/// Generates `<dir>/generated.rs` with the `AUDIT_ARCH_*` tokens of
+/// `AUDIT_ARCHES`, as defined by `linux/audit.h`, and the `AUDIT_ARCH` of
+/// the target.
+pub async fn generate_audit(dir: PathBuf, version: String) -> Result<()> {
+    // `audit.h` is the same for every arch and includes no `asm/` headers.
+    let mut cpp = Preprocessor::new("", &version);
+    for header in ["linux/elf-em.h", "linux/audit.h"] {
+        cpp.include(header)
+            .await
+            .wrap_err_with(|| eyre!("Failed reading {header}"))?;
+    }
+
+    let mut tokens = Vec::new();
+    for &(arch, token, cfg) in AUDIT_ARCHES {
+        let name = format!("AUDIT_ARCH_{token}");
+        let Some(value) = cpp.value(&name) else {
+            println!("Skipping {name} (not defined in {version})");
+            continue;
+        };
+        let value = u32::try_from(value)
+            .wrap_err_with(|| eyre!("{name} = {value} does not fit in u32"))?;
+        tokens.push((arch, name, value, cfg));
+    }
+
+    let mut out = String::new();
+    writeln!(
+        out,
+        "//! `AUDIT_ARCH_*` tokens from `linux/audit.h` (Linux {version}).\n"
+    )?;
+    writeln!(out, "// This file is automatically generated. Do not edit!")?;
+    writeln!(out)?;
+    for (arch, name, value, cfg) in &tokens {
+        let endian = match cfg {
+            Some(cfg) if cfg.contains("big") => "big-endian ",
+            Some(_) => "little-endian ",
+            None => "",
+        };
+        writeln!(out, "/// The token of {endian}`{arch}` syscalls.")?;
+        writeln!(
+            out,
+            "pub const {name}: u32 = {};",
+            hex_literal((*value).into())
+        )?;
+    }
+    for (arch, name, _, cfg) in &tokens {
+        // ia64 has no Rust target.
+        if *arch == "ia64" {
+            continue;
+        }
+        writeln!(out)?;
+        writeln!(out, "/// The token of the target's syscalls.")?;
+        let cfg = match cfg {
+            Some(cfg) => format!("all(target_arch = \"{arch}\", {cfg})"),
+            None => format!("target_arch = \"{arch}\""),
+        };
+        writeln!(out, "#[cfg({cfg})]")?;
+        writeln!(out, "pub const AUDIT_ARCH: u32 = {name};")?;
+    }
+
+    create_dir_all(&dir).wrap_err_with(|| {
+        eyre!("Failed to create directory {}", dir.display())
+    })?;
+    let path = dir.join("generated.rs");
+    fs::write(&path, out)
+        .wrap_err_with(|| eyre!("Failed to write {}", path.display()))?;
+
+    println!("Generated audit tokens at {}", path.display());
+    Ok(())
+}
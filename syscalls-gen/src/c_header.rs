@@ -0,0 +1,96 @@
+use crate::errors::{Errno, describe};
+use crate::tables::TableEntry;
+use std::fmt;
+
+/// A C header with the syscall numbers and error codes of one architecture,
+/// for C code that needs to agree with the crate (e.g. seccomp filters).
+pub struct CHeader<'a> {
+    pub arch: &'a str,
+    pub version: &'a str,
+    pub syscalls: &'a [TableEntry],
+    pub errnos: &'a [Errno],
+}
+
+impl CHeader<'_> {
+    /// Definitions of the error codes. Aliases are left out, like in the Rust
+    /// table.
+    fn errnos(&self) -> impl Iterator<Item = (&str, u32, &str)> {
+        self.errnos.iter().filter_map(|errno| match errno {
+            Errno::Definition {
+                name,
+                num,
+                description,
+            } => Some((
+                name.as_str(),
+                *num,
+                describe(name, description.as_deref()),
+            )),
+            Errno::Alias { .. } => None,
+        })
+    }
+}
+
+impl fmt::Display for CHeader<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Self { arch, version, .. } = self;
+        let guard = format!("RAWSYS_LINUX_{}_H", arch.to_uppercase());
+
+        writeln!(
+            f,
+            "/* Syscalls and error codes for the `{arch}` architecture (Linux {version}). */"
+        )?;
+        writeln!(
+            f,
+            "/* This file is automatically generated. Do not edit! */"
+        )?;
+        writeln!(f)?;
+        writeln!(f, "#ifndef {guard}")?;
+        writeln!(f, "#define {guard}")?;
+
+        writeln!(f)?;
+        for entry in self.syscalls {
+            let name = entry.ident();
+            if entry.entry_point.is_some() {
+                writeln!(f, "#define RAWSYS_NR_{name} {}", entry.id)?;
+            } else {
+                writeln!(
+                    f,
+                    "#define RAWSYS_NR_{name} {} /* not implemented */",
+                    entry.id
+                )?;
+            }
+        }
+
+        writeln!(f)?;
+        for (name, num, description) in self.errnos() {
+            writeln!(f, "#define RAWSYS_{name} {num} /* {description} */")?;
+        }
+
+        // X-macros for generating tables, e.g.
+        // `#define X(name, nr) [nr] = #name,` followed by
+        // `RAWSYS_SYSCALLS(X)`.
+        writeln!(f)?;
+        writeln!(f, "/* X(name, number) for every syscall. */")?;
+        writeln!(f, "#define RAWSYS_SYSCALLS(X) \\")?;
+        for entry in self.syscalls {
+            writeln!(f, "    X({}, {}) \\", entry.ident(), entry.id)?;
+        }
+        writeln!(f)?;
+
+        writeln!(f)?;
+        writeln!(
+            f,
+            "/* X(name, number, description) for every error code. */"
+        )?;
+        writeln!(f, "#define RAWSYS_ERRNOS(X) \\")?;
+        for (name, num, description) in self.errnos() {
+            writeln!(f, "    X({name}, {num}, {description:?}) \\")?;
+        }
+        writeln!(f)?;
+
+        writeln!(f)?;
+        writeln!(f, "#endif /* {guard} */")?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,250 @@
+use color_eyre::eyre::{Result, WrapErr, bail, eyre};
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+/// The files and directories the generator writes, relative to the crate.
+static GENERATED: &[&str] = &[
+    "src/arch",
+    "src/errno",
+    "src/consts",
+    "src/audit",
+    "Cargo.toml",
+    "build.rs",
+    "tests/generated_libc_consistency.rs",
+];
+
+/// Copies the crate in `dir` to a temporary directory for `--check` to
+/// generate into. The generator then sees the committed tables (for
+/// validation, version diffs, and `mod.rs` docs) and the workspace that
+/// `cargo metadata` needs.
+pub fn scratch_copy(dir: &Path) -> Result<TempDir> {
+    let scratch = tempfile::tempdir()
+        .wrap_err("Failed to create a temporary directory")?;
+    copy_crate(dir, scratch.path())?;
+    println!(
+        "Checking against a copy of the crate in {}",
+        scratch.path().display()
+    );
+    Ok(scratch)
+}
+
+/// Fails if the files generated into the copy differ from the committed ones
+/// in `dir`, after printing what would change.
+pub fn check_generated(dir: &Path, scratch: &Path) -> Result<()> {
+    println!();
+    let changed = compare(dir, scratch)?;
+    if changed > 0 {
+        bail!("{changed} generated file(s) differ from the committed ones");
+    }
+    println!("The generated files match the committed ones");
+    Ok(())
+}
+
+/// Copies the crate in `from` to `to`, skipping build output and hidden
+/// directories such as `.git`.
+fn copy_crate(from: &Path, to: &Path) -> Result<()> {
+    for entry in fs::read_dir(from).wrap_err_with(|| {
+        eyre!("Failed to read directory {}", from.display())
+    })? {
+        let entry = entry?;
+        let name = entry.file_name();
+        let path = entry.path();
+        let target = to.join(&name);
+        if entry.file_type()?.is_dir() {
+            if name == "target" || name.to_string_lossy().starts_with('.') {
+                continue;
+            }
+            fs::create_dir_all(&target)?;
+            copy_crate(&path, &target)?;
+        } else {
+            fs::copy(&path, &target).wrap_err_with(|| {
+                eyre!(
+                    "Failed to copy {} to {}",
+                    path.display(),
+                    target.display()
+                )
+            })?;
+        }
+    }
+    Ok(())
+}
+
+/// Compares the generated files in `generated` with the committed ones in
+/// `committed`, prints a diff of every file that would change, and returns
+/// how many do.
+fn compare(committed: &Path, generated: &Path) -> Result<usize> {
+    let mut files = Vec::new();
+    for path in GENERATED {
+        list_files(committed, Path::new(path), &mut files)?;
+        list_files(generated, Path::new(path), &mut files)?;
+    }
+    files.sort();
+    files.dedup();
+
+    let mut changed = 0;
+    for file in &files {
+        let old = read_optional(&committed.join(file))?;
+        let new = read_optional(&generated.join(file))?;
+        if old == new {
+            continue;
+        }
+        changed += 1;
+        let file = file.display();
+        match (old, new) {
+            (Some(_), None) => println!("Would remove {file}"),
+            (None, Some(new)) => {
+                println!("Would add {file} ({} lines)", new.lines().count());
+            }
+            (old, new) => {
+                println!("--- a/{file}\n+++ b/{file}");
+                print!(
+                    "{}",
+                    line_diff(
+                        &old.unwrap_or_default(),
+                        &new.unwrap_or_default()
+                    )
+                );
+            }
+        }
+    }
+    Ok(changed)
+}
+
+/// Adds the files under `root/path` (or `path` itself) to `files`, relative
+/// to `root`.
+fn list_files(
+    root: &Path,
+    path: &Path,
+    files: &mut Vec<PathBuf>,
+) -> Result<()> {
+    let full = root.join(path);
+    if full.is_file() {
+        files.push(path.to_path_buf());
+    } else if full.is_dir() {
+        for entry in fs::read_dir(&full)? {
+            list_files(root, &path.join(entry?.file_name()), files)?;
+        }
+    }
+    Ok(())
+}
+
+fn read_optional(path: &Path) -> Result<Option<String>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    fs::read_to_string(path)
+        .map(Some)
+        .wrap_err_with(|| eyre!("Failed to read {}", path.display()))
+}
+
+/// A diff of the lines of `old` and `new`, as `@@` hunks without context.
+fn line_diff(old: &str, new: &str) -> String {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    // Only the part between the common prefix and suffix needs the
+    // quadratic LCS table.
+    let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let a = &old[prefix..old.len() - suffix];
+    let b = &new[prefix..new.len() - suffix];
+
+    // `lcs[i][j]` is the length of the longest common subsequence of
+    // `a[i..]` and `b[j..]`.
+    let mut lcs = vec![vec![0u32; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut out = String::new();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() || j < b.len() {
+        if i < a.len() && j < b.len() && a[i] == b[j] {
+            i += 1;
+            j += 1;
+            continue;
+        }
+        // A hunk: everything up to the next common line.
+        let (start_i, start_j) = (i, j);
+        while i < a.len() || j < b.len() {
+            if i < a.len() && j < b.len() && a[i] == b[j] {
+                break;
+            }
+            // Drop an old line if that keeps the longest common subsequence.
+            let drop_old =
+                i < a.len() && (j == b.len() || lcs[i + 1][j] >= lcs[i][j + 1]);
+            if drop_old {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        writeln!(
+            out,
+            "@@ -{},{} +{},{} @@",
+            prefix + start_i + 1,
+            i - start_i,
+            prefix + start_j + 1,
+            j - start_j
+        )
+        .unwrap();
+        for line in &a[start_i..i] {
+            writeln!(out, "-{line}").unwrap();
+        }
+        for line in &b[start_j..j] {
+            writeln!(out, "+{line}").unwrap();
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diffs_lines() {
+        assert_eq!(line_diff("a\nb\nc\n", "a\nb\nc\n"), "");
+        assert_eq!(
+            line_diff("a\nb\nc\nd\n", "a\nx\nc\nd\ne\n"),
+            "@@ -2,1 +2,1 @@\n-b\n+x\n@@ -5,0 +5,1 @@\n+e\n"
+        );
+        assert_eq!(line_diff("a\nb\n", "b\n"), "@@ -1,1 +1,0 @@\n-a\n");
+    }
+
+    #[test]
+    fn compares_generated_files() {
+        let committed = tempfile::tempdir().unwrap();
+        let arch = committed.path().join("src/arch/x86_64");
+        fs::create_dir_all(&arch).unwrap();
+        fs::write(arch.join("v6_10.rs"), "read = 0,\n").unwrap();
+        fs::write(arch.join("v6_6.rs"), "read = 0,\n").unwrap();
+        fs::write(committed.path().join("build.rs"), "fn main() {}\n").unwrap();
+        fs::write(committed.path().join("README.md"), "Hi\n").unwrap();
+
+        let generated = tempfile::tempdir().unwrap();
+        copy_crate(committed.path(), generated.path()).unwrap();
+        assert_eq!(compare(committed.path(), generated.path()).unwrap(), 0);
+
+        let arch = generated.path().join("src/arch/x86_64");
+        fs::write(arch.join("v6_10.rs"), "read = 0,\nwrite = 1,\n").unwrap();
+        fs::write(arch.join("v6_12.rs"), "read = 0,\n").unwrap();
+        fs::remove_file(arch.join("v6_6.rs")).unwrap();
+        // Not generated, so not compared.
+        fs::write(generated.path().join("README.md"), "Hello\n").unwrap();
+        assert_eq!(compare(committed.path(), generated.path()).unwrap(), 3);
+    }
+}
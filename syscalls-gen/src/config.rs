@@ -0,0 +1,465 @@
+use crate::tables::TableEntry;
+use color_eyre::eyre::{Result, WrapErr, bail, eyre};
+use std::collections::HashMap;
+use std::iter::Peekable;
+use std::path::Path;
+use std::str::Chars;
+
+/// Per-arch customizations of the generated tables, read from a TOML file
+/// (`syscalls-gen.toml` by default):
+///
+/// ```toml
+/// # Applies to every version of aarch64.
+/// [aarch64]
+/// blocklist = ["sync_file_range2"]
+///
+/// # Applies to v6.6 of x86_64 only, on top of `[x86_64]`.
+/// [x86_64."v6.6"]
+/// rename = { old_name = "new_name" }
+/// include = { my_syscall = 463 }
+/// removed = ["old_syscall"]
+/// ```
+///
+/// `blocklist` drops syscalls from the table, `rename` changes the name of a
+/// syscall, and `include` adds syscalls (by name and number) that are missing
+/// from the kernel's table. `removed` lists syscalls that the kernel really
+/// dropped, so that validation doesn't flag them as missing compared with the
+/// previous version. Only the subset of TOML needed for this is
+/// supported: tables, strings, integers, arrays, and inline tables.
+#[derive(Debug, Default)]
+pub struct Config {
+    /// Keyed by `(arch, version)`. `version` is `None` for `[arch]`.
+    overrides: HashMap<(String, Option<String>), Overrides>,
+}
+
+#[derive(Debug, Default)]
+struct Overrides {
+    blocklist: Vec<String>,
+    rename: Vec<(String, String)>,
+    include: Vec<(String, u32)>,
+    removed: Vec<String>,
+}
+
+impl Config {
+    /// Reads a config file.
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .wrap_err_with(|| eyre!("Failed to read {}", path.display()))?;
+        Self::parse(&contents)
+            .wrap_err_with(|| eyre!("Invalid config {}", path.display()))
+    }
+
+    fn parse(contents: &str) -> Result<Self> {
+        let mut config = Self::default();
+
+        for (table, entries) in parse_toml(contents)? {
+            let key = match table.as_slice() {
+                [arch] => (arch.clone(), None),
+                [arch, version] => (arch.clone(), Some(version.clone())),
+                _ => bail!("Invalid table [{}]", table.join(".")),
+            };
+            let overrides = config.overrides.entry(key).or_default();
+
+            for (name, value) in entries {
+                match (name.as_str(), value) {
+                    (key @ ("blocklist" | "removed"), Value::Array(values)) => {
+                        for value in values {
+                            let Value::String(name) = value else {
+                                bail!("`{key}` must only contain strings");
+                            };
+                            if key == "blocklist" {
+                                overrides.blocklist.push(name);
+                            } else {
+                                overrides.removed.push(name);
+                            }
+                        }
+                    }
+                    ("rename", Value::Table(entries)) => {
+                        for (from, to) in entries {
+                            let Value::String(to) = to else {
+                                bail!("`rename.{from}` must be a string");
+                            };
+                            overrides.rename.push((from, to));
+                        }
+                    }
+                    ("include", Value::Table(entries)) => {
+                        for (name, id) in entries {
+                            let Value::Integer(id) = id else {
+                                bail!("`include.{name}` must be a number");
+                            };
+                            overrides.include.push((name, id));
+                        }
+                    }
+                    (name, _) => bail!(
+                        "Unknown or invalid key `{name}` in [{}]",
+                        table.join(".")
+                    ),
+                }
+            }
+        }
+
+        Ok(config)
+    }
+
+    /// Applies the overrides for `arch` and `version` to a table.
+    pub fn apply(
+        &self,
+        arch: &str,
+        version: &str,
+        table: &mut Vec<TableEntry>,
+    ) -> Result<()> {
+        let keys = [
+            (arch.to_string(), None),
+            (arch.to_string(), Some(version.to_string())),
+        ];
+
+        for overrides in keys.iter().filter_map(|key| self.overrides.get(key)) {
+            table.retain(|entry| !overrides.blocklist.contains(&entry.name));
+
+            for (from, to) in &overrides.rename {
+                match table.iter_mut().find(|entry| entry.name == *from) {
+                    Some(entry) => entry.name.clone_from(to),
+                    None => println!(
+                        "Warning: cannot rename {from} for {arch} {version}, \
+                         it is not in the table"
+                    ),
+                }
+            }
+
+            for (name, id) in &overrides.include {
+                if let Some(entry) = table.iter().find(|entry| entry.id == *id)
+                {
+                    if entry.name == *name {
+                        continue;
+                    }
+                    bail!(
+                        "Cannot include {name} = {id} for {arch} {version}: \
+                         {id} is {}",
+                        entry.name
+                    );
+                }
+                table.push(TableEntry {
+                    id: *id,
+                    name: name.clone(),
+                    entry_point: Some(format!("sys_{name}")),
+                });
+            }
+        }
+
+        table.sort();
+        Ok(())
+    }
+
+    /// Syscalls that may be missing for `arch` and `version` compared with
+    /// the previous version: the `removed` ones, and the ones dropped or
+    /// renamed by the config.
+    pub fn expected_removals(&self, arch: &str, version: &str) -> Vec<&str> {
+        let keys = [
+            (arch.to_string(), None),
+            (arch.to_string(), Some(version.to_string())),
+        ];
+
+        keys.iter()
+            .filter_map(|key| self.overrides.get(key))
+            .flat_map(|overrides| {
+                overrides
+                    .removed
+                    .iter()
+                    .chain(&overrides.blocklist)
+                    .chain(overrides.rename.iter().map(|(from, _)| from))
+                    .map(String::as_str)
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    String(String),
+    Integer(u32),
+    Array(Vec<Value>),
+    Table(Vec<(String, Value)>),
+}
+
+type Tables = Vec<(Vec<String>, Vec<(String, Value)>)>;
+
+/// Parses the TOML subset described on [`Config`] into its tables. Keys
+/// before the first table header are an error.
+fn parse_toml(contents: &str) -> Result<Tables> {
+    let mut parser = Parser {
+        chars: contents.chars().peekable(),
+        line: 1,
+    };
+    let mut tables: Tables = Vec::new();
+
+    loop {
+        parser.skip_whitespace(true);
+        match parser.peek() {
+            None => break,
+            Some('[') => {
+                parser.next();
+                let mut keys = vec![parser.key()?];
+                while parser.eat('.') {
+                    keys.push(parser.key()?);
+                }
+                parser.expect(']')?;
+                tables.push((keys, Vec::new()));
+            }
+            Some(_) => {
+                let key = parser.key()?;
+                parser.expect('=')?;
+                let value = parser.value()?;
+                let Some((_, entries)) = tables.last_mut() else {
+                    bail!(
+                        "line {}: `{key}` is outside of a table",
+                        parser.line
+                    );
+                };
+                entries.push((key, value));
+            }
+        }
+        parser.end_of_line()?;
+    }
+
+    Ok(tables)
+}
+
+struct Parser<'a> {
+    chars: Peekable<Chars<'a>>,
+    line: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&mut self) -> Option<char> {
+        self.chars.peek().copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.chars.next();
+        if c == Some('\n') {
+            self.line += 1;
+        }
+        c
+    }
+
+    /// Skips spaces and comments, and also newlines if `newlines` is set.
+    fn skip_whitespace(&mut self, newlines: bool) {
+        while let Some(c) = self.peek() {
+            match c {
+                ' ' | '\t' | '\r' => {}
+                '\n' if newlines => {}
+                '#' => {
+                    while self.peek().is_some_and(|c| c != '\n') {
+                        self.next();
+                    }
+                    continue;
+                }
+                _ => break,
+            }
+            self.next();
+        }
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        self.skip_whitespace(false);
+        if self.peek() == Some(expected) {
+            self.next();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<()> {
+        if !self.eat(expected) {
+            bail!("line {}: expected `{expected}`", self.line);
+        }
+        Ok(())
+    }
+
+    fn end_of_line(&mut self) -> Result<()> {
+        self.skip_whitespace(false);
+        match self.next() {
+            None | Some('\n') => Ok(()),
+            Some(c) => bail!("line {}: unexpected `{c}`", self.line),
+        }
+    }
+
+    /// A bare (`name`) or quoted (`"v6.6"`) key.
+    fn key(&mut self) -> Result<String> {
+        self.skip_whitespace(false);
+        if self.peek() == Some('"') {
+            return self.string();
+        }
+
+        let mut key = String::new();
+        while let Some(c) = self
+            .peek()
+            .filter(|&c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        {
+            key.push(c);
+            self.next();
+        }
+        if key.is_empty() {
+            bail!("line {}: expected a key", self.line);
+        }
+        Ok(key)
+    }
+
+    /// A basic string with the common escapes.
+    fn string(&mut self) -> Result<String> {
+        self.expect('"')?;
+        let mut string = String::new();
+        loop {
+            match self.next() {
+                Some('"') => return Ok(string),
+                Some('\\') => match self.next() {
+                    Some('"') => string.push('"'),
+                    Some('\\') => string.push('\\'),
+                    Some('n') => string.push('\n'),
+                    Some('t') => string.push('\t'),
+                    _ => bail!("line {}: unsupported escape", self.line),
+                },
+                Some('\n') | None => {
+                    bail!("line {}: unterminated string", self.line)
+                }
+                Some(c) => string.push(c),
+            }
+        }
+    }
+
+    fn value(&mut self) -> Result<Value> {
+        self.skip_whitespace(false);
+        match self.peek() {
+            Some('"') => Ok(Value::String(self.string()?)),
+            Some('[') => {
+                self.next();
+                let mut values = Vec::new();
+                loop {
+                    self.skip_whitespace(true);
+                    if self.peek() == Some(']') {
+                        self.next();
+                        break;
+                    }
+                    values.push(self.value()?);
+                    self.skip_whitespace(true);
+                    if !self.eat(',') {
+                        self.skip_whitespace(true);
+                        self.expect(']')?;
+                        break;
+                    }
+                }
+                Ok(Value::Array(values))
+            }
+            Some('{') => {
+                self.next();
+                let mut entries = Vec::new();
+                if self.eat('}') {
+                    return Ok(Value::Table(entries));
+                }
+                loop {
+                    let key = self.key()?;
+                    self.expect('=')?;
+                    entries.push((key, self.value()?));
+                    if !self.eat(',') {
+                        self.expect('}')?;
+                        break;
+                    }
+                }
+                Ok(Value::Table(entries))
+            }
+            Some(c) if c.is_ascii_digit() => {
+                let mut digits = String::new();
+                while let Some(c) =
+                    self.peek().filter(|&c| c.is_ascii_digit() || c == '_')
+                {
+                    if c != '_' {
+                        digits.push(c);
+                    }
+                    self.next();
+                }
+                let value = digits.parse().wrap_err_with(|| {
+                    eyre!("line {}: invalid number", self.line)
+                })?;
+                Ok(Value::Integer(value))
+            }
+            _ => bail!("line {}: expected a value", self.line),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(id: u32, name: &str) -> TableEntry {
+        TableEntry {
+            id,
+            name: name.into(),
+            entry_point: Some(format!("sys_{name}")),
+        }
+    }
+
+    #[test]
+    fn parses_and_applies() {
+        let config = Config::parse(
+            r#"
+# Comment
+[aarch64]
+blocklist = [
+    "sync_file_range2", # trailing comment
+]
+
+[aarch64."v6.6"]
+rename = { old = "new" }
+include = { extra = 500 }
+removed = ["gone"]
+"#,
+        )
+        .unwrap();
+
+        let table = || {
+            vec![
+                entry(0, "read"),
+                entry(1, "old"),
+                entry(2, "sync_file_range2"),
+            ]
+        };
+
+        let mut v6_1 = table();
+        config.apply("aarch64", "v6.1", &mut v6_1).unwrap();
+        assert_eq!(v6_1, [entry(0, "read"), entry(1, "old")]);
+
+        let mut v6_6 = table();
+        config.apply("aarch64", "v6.6", &mut v6_6).unwrap();
+        let mut new = entry(1, "new");
+        new.entry_point = Some("sys_old".into());
+        assert_eq!(v6_6, [entry(0, "read"), new, entry(500, "extra")]);
+
+        let mut x86 = table();
+        config.apply("x86", "v6.6", &mut x86).unwrap();
+        assert_eq!(x86, table());
+
+        assert_eq!(
+            config.expected_removals("aarch64", "v6.6"),
+            ["sync_file_range2", "gone", "old"]
+        );
+        assert_eq!(
+            config.expected_removals("aarch64", "v6.1"),
+            ["sync_file_range2"]
+        );
+    }
+
+    #[test]
+    fn rejects_invalid() {
+        assert!(Config::parse("blocklist = []").is_err());
+        assert!(Config::parse("[x86]\nblocklist = \"read\"").is_err());
+        assert!(Config::parse("[x86]\nunknown = []").is_err());
+        assert!(Config::parse("[x86]\nremoved = [1]").is_err());
+        assert!(Config::parse("[x86]\nblocklist = [\"read\"").is_err());
+
+        let config = Config::parse("[x86]\ninclude = { write = 0 }").unwrap();
+        let mut table = vec![entry(0, "read")];
+        assert!(config.apply("x86", "v6.6", &mut table).is_err());
+    }
+}
@@ -0,0 +1,611 @@
+use crate::fetch_optional_path;
+use crate::signatures::arch_dir;
+use color_eyre::eyre::{Result, WrapErr, eyre};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write as _;
+use std::fs::{self, create_dir_all};
+use std::path::{Path, PathBuf};
+
+/// Prefixes of the constants to emit, with the Rust type used for them.
+/// `AT_` is signed for `AT_FDCWD`; `CLONE_` has flags above bit 31 (for
+/// `clone3`); `MOUNT_ATTR_` and `RESOLVE_` are for the `u64` fields of
+/// `struct mount_attr` and `struct open_how`.
+static FAMILIES: &[(&str, &str)] = &[
+    ("O_", "u32"),
+    ("AT_", "i32"),
+    ("CLONE_", "u64"),
+    ("PROT_", "u32"),
+    ("MAP_", "u32"),
+    ("MS_", "u32"),
+    ("OPEN_TREE_", "u32"),
+    ("MOVE_MOUNT_", "u32"),
+    ("FSOPEN_", "u32"),
+    ("FSPICK_", "u32"),
+    ("FSMOUNT_", "u32"),
+    ("MOUNT_ATTR_", "u64"),
+    ("RESOLVE_", "u64"),
+];
+
+/// Headers the constants are read from. Includes are followed for the
+/// headers in `FOLLOWED_INCLUDES` only.
+static HEADERS: &[&str] = &[
+    "linux/fcntl.h",
+    "linux/sched.h",
+    "linux/mman.h",
+    "linux/mount.h",
+    "linux/openat2.h",
+];
+
+/// File names of the headers that are followed when included. Other
+/// includes (`linux/types.h`, ...) don't define any of the constants.
+static FOLLOWED_INCLUDES: &[&str] =
+    &["fcntl.h", "mman.h", "mman-common.h", "hugetlb_encode.h"];
+
+/// The constants of one of the `FAMILIES`, sorted by name.
+pub struct Family {
+    pub prefix: &'static str,
+    pub ty: &'static str,
+    pub values: Vec<(String, i128)>,
+}
+
+/// Reads the `FAMILIES` constants as seen by `arch`.
+pub async fn read_families(arch: &str, version: &str) -> Result<Vec<Family>> {
+    let mut cpp = Preprocessor::new(arch_dir(arch), version);
+    for header in HEADERS {
+        cpp.include(header)
+            .await
+            .wrap_err_with(|| eyre!("Failed reading {header} for {arch}"))?;
+    }
+
+    let mut families = Vec::new();
+    for &(prefix, ty) in FAMILIES {
+        let mut names: Vec<&String> = cpp
+            .defines
+            .keys()
+            .filter(|name| name.starts_with(prefix))
+            .collect();
+        names.sort();
+
+        let mut values = Vec::new();
+        for name in names {
+            let Some(value) = cpp.value(name) else {
+                println!("Skipping {name} for {arch} (not a constant)");
+                continue;
+            };
+            let value = match ty {
+                "i32" => i32::try_from(value).map(i128::from).ok(),
+                "u32" => u32::try_from(value).map(i128::from).ok(),
+                _ => u64::try_from(value).map(i128::from).ok(),
+            }
+            .ok_or_else(|| eyre!("{name} = {value} does not fit in {ty}"))?;
+            values.push((name.clone(), value));
+        }
+        families.push(Family { prefix, ty, values });
+    }
+
+    Ok(families)
+}
+
+/// Generates `src/consts/<arch>.rs` with the `FAMILIES` constants as seen by
+/// `arch`.
+pub async fn generate_consts(
+    dir: &Path,
+    arch: &str,
+    version: &str,
+) -> Result<()> {
+    let families = read_families(arch, version).await?;
+
+    let mut out = String::new();
+    writeln!(
+        out,
+        "//! UAPI constants for the `{arch}` architecture (Linux {version}).\n"
+    )?;
+    writeln!(out, "// This file is automatically generated. Do not edit!")?;
+
+    for Family { ty, values, .. } in &families {
+        writeln!(out)?;
+        for (name, value) in values {
+            if *value < 16 {
+                writeln!(out, "pub const {name}: {ty} = {value};")?;
+            } else {
+                writeln!(out, "pub const {name}: {ty} = {value:#x};")?;
+            }
+        }
+    }
+
+    let consts_dir = dir.join("src/consts");
+    create_dir_all(&consts_dir).wrap_err_with(|| {
+        eyre!("Failed to create directory {}", consts_dir.display())
+    })?;
+    let path = consts_dir.join(format!("{arch}.rs"));
+    fs::write(&path, out)
+        .wrap_err_with(|| eyre!("Failed to write {}", path.display()))?;
+
+    println!(
+        "Generated constants for {arch} {version} at {}",
+        path.display()
+    );
+    Ok(())
+}
+
+/// Just enough of a C preprocessor to read object-like `#define`s from the
+/// uapi headers, honoring `#if`/`#ifdef`/`#ifndef` (the arch headers
+/// override the generic ones through `#ifndef`) and `#include`.
+pub struct Preprocessor<'a> {
+    headers: Headers<'a>,
+    defines: HashMap<String, String>,
+    included: HashSet<String>,
+}
+
+/// Where a [`Preprocessor`] reads headers from.
+enum Headers<'a> {
+    /// The uapi headers of a kernel tree, for one arch. Only the includes in
+    /// `FOLLOWED_INCLUDES` are followed.
+    Kernel { arch_dir: &'a str, version: &'a str },
+    /// Headers installed in `root` (e.g. `/usr/include`), with the `asm/`
+    /// headers in the first of `asm_dirs` that has them. Every include that
+    /// exists is followed.
+    Installed {
+        root: &'a Path,
+        asm_dirs: &'a [PathBuf],
+    },
+}
+
+/// State of one `#if` block.
+struct Cond {
+    /// Whether lines are currently processed.
+    active: bool,
+    /// Whether a branch of this block was taken already.
+    taken: bool,
+    /// Whether the enclosing block is active.
+    outer: bool,
+}
+
+impl<'a> Preprocessor<'a> {
+    pub fn new(arch_dir: &'a str, version: &'a str) -> Self {
+        Self {
+            headers: Headers::Kernel { arch_dir, version },
+            defines: HashMap::new(),
+            included: HashSet::new(),
+        }
+    }
+
+    /// A preprocessor for installed headers, see [`Headers::Installed`].
+    pub fn installed(root: &'a Path, asm_dirs: &'a [PathBuf]) -> Self {
+        Self {
+            headers: Headers::Installed { root, asm_dirs },
+            defines: HashMap::new(),
+            included: HashSet::new(),
+        }
+    }
+
+    /// Defines a macro, as the compiler does for `__x86_64__` and the like.
+    pub fn define(&mut self, name: &str, body: &str) {
+        self.defines.insert(name.to_string(), body.to_string());
+    }
+
+    /// Names of the macros defined so far.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.defines.keys().map(String::as_str)
+    }
+
+    /// Reads an included header such as `asm/mman.h`, see [`Headers`].
+    pub async fn include(&mut self, header: &str) -> Result<()> {
+        if !self.included.insert(header.to_string()) {
+            return Ok(());
+        }
+
+        let contents = match self.headers {
+            Headers::Kernel { arch_dir, version } => {
+                read_kernel_header(arch_dir, version, header).await?
+            }
+            Headers::Installed { root, asm_dirs } => {
+                read_installed_header(root, asm_dirs, header)?
+            }
+        };
+
+        match contents {
+            Some(contents) => Box::pin(self.process(&contents)).await,
+            None => Ok(()),
+        }
+    }
+
+    async fn process(&mut self, contents: &str) -> Result<()> {
+        let source = strip_comments(contents).replace("\\\n", " ");
+        let mut conds: Vec<Cond> = Vec::new();
+
+        for line in source.lines() {
+            let Some(directive) = line.trim().strip_prefix('#') else {
+                continue;
+            };
+            let directive = directive.trim_start();
+            let (keyword, rest) = directive
+                .split_once(char::is_whitespace)
+                .unwrap_or((directive, ""));
+            let rest = rest.trim();
+            let active = conds.last().is_none_or(|cond| cond.active);
+
+            match keyword {
+                "if" | "ifdef" | "ifndef" => {
+                    let cond = active
+                        && match keyword {
+                            "ifdef" => self.defines.contains_key(rest),
+                            "ifndef" => !self.defines.contains_key(rest),
+                            _ => self.condition(rest),
+                        };
+                    conds.push(Cond {
+                        active: cond,
+                        taken: cond,
+                        outer: active,
+                    });
+                }
+                "elif" | "else" => {
+                    let cond = conds.last().is_some_and(|cond| {
+                        cond.outer
+                            && !cond.taken
+                            && (keyword == "else" || self.condition(rest))
+                    });
+                    if let Some(last) = conds.last_mut() {
+                        last.active = cond;
+                        last.taken |= cond;
+                    }
+                }
+                "endif" => {
+                    conds.pop();
+                }
+                "define" if active => {
+                    let end = rest
+                        .find(|c: char| {
+                            !(c.is_ascii_alphanumeric() || c == '_')
+                        })
+                        .unwrap_or(rest.len());
+                    let (name, body) = rest.split_at(end);
+                    // Skip function-like macros.
+                    if !name.is_empty() && !body.starts_with('(') {
+                        self.defines
+                            .entry(name.to_string())
+                            .or_insert_with(|| body.trim().to_string());
+                    }
+                }
+                "undef" if active => {
+                    self.defines.remove(rest);
+                }
+                "include" if active => {
+                    let header = rest.trim_matches(['<', '>', '"']);
+                    let file = header.rsplit('/').next().unwrap_or(header);
+                    let follow = match self.headers {
+                        Headers::Kernel { .. } => {
+                            FOLLOWED_INCLUDES.contains(&file)
+                        }
+                        Headers::Installed { .. } => true,
+                    };
+                    if follow {
+                        Box::pin(self.include(header)).await?;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Evaluates an `#if` condition. Like in C, unknown identifiers are 0.
+    fn condition(&self, expr: &str) -> bool {
+        Eval::new(self, expr, true)
+            .and_then(|mut eval| eval.run())
+            .is_some_and(|value| value != 0)
+    }
+
+    /// Evaluates a macro to an integer, if it is an integer constant.
+    pub fn value(&self, name: &str) -> Option<i128> {
+        Eval::new(self, self.defines.get(name)?, false)?.run()
+    }
+}
+
+/// Reads a uapi header such as `asm/mman.h` from a kernel tree. `asm/`
+/// headers come from the arch if it has its own, and from `asm-generic/`
+/// otherwise.
+async fn read_kernel_header(
+    arch_dir: &str,
+    version: &str,
+    header: &str,
+) -> Result<Option<String>> {
+    if let Some(name) = header.strip_prefix("asm/") {
+        let arch = format!("arch/{arch_dir}/include/uapi/asm/{name}");
+        if let Some(contents) = fetch_optional_path(&arch, version).await? {
+            return Ok(Some(contents));
+        }
+        let generic = format!("include/uapi/asm-generic/{name}");
+        fetch_optional_path(&generic, version).await
+    } else {
+        let path = format!("include/uapi/{header}");
+        fetch_optional_path(&path, version).await
+    }
+}
+
+/// Reads an installed header, or returns `None` if it doesn't exist (such
+/// as the libc headers that some kernel headers include).
+fn read_installed_header(
+    root: &Path,
+    asm_dirs: &[PathBuf],
+    header: &str,
+) -> Result<Option<String>> {
+    let candidates: Vec<PathBuf> = match header.strip_prefix("asm/") {
+        Some(name) => asm_dirs.iter().map(|dir| dir.join(name)).collect(),
+        None => vec![root.join(header)],
+    };
+    for path in candidates {
+        match fs::read_to_string(&path) {
+            Ok(contents) => {
+                println!("Reading {}", path.display());
+                return Ok(Some(contents));
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+            Err(err) => {
+                return Err(err).wrap_err_with(|| {
+                    eyre!("Failed to read '{}'", path.display())
+                });
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Removes `/* */` and `//` comments.
+fn strip_comments(source: &str) -> String {
+    let mut out = String::with_capacity(source.len());
+    let mut rest = source;
+
+    while let Some(start) = rest.find(['/']) {
+        out.push_str(&rest[..start]);
+        let tail = &rest[start..];
+        if let Some(tail) = tail.strip_prefix("/*") {
+            rest = tail.find("*/").map_or("", |end| &tail[end + 2..]);
+            out.push(' ');
+        } else if tail.starts_with("//") {
+            rest = tail.find('\n').map_or("", |end| &tail[end..]);
+        } else {
+            out.push('/');
+            rest = &tail[1..];
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Num(i128),
+    Ident(String),
+    Op(&'static str),
+}
+
+fn tokenize(expr: &str) -> Option<Vec<Token>> {
+    const OPS: &[&str] = &[
+        "<<", ">>", "==", "!=", "<=", ">=", "&&", "||", "|", "&", "^", "+",
+        "-", "*", "/", "%", "~", "!", "(", ")", "<", ">",
+    ];
+
+    let mut tokens = Vec::new();
+    let mut rest = expr.trim_start();
+
+    while let Some(c) = rest.chars().next() {
+        if c.is_ascii_digit() {
+            let end = rest
+                .find(|c: char| !c.is_ascii_alphanumeric())
+                .unwrap_or(rest.len());
+            let literal = rest[..end].trim_end_matches(['u', 'U', 'l', 'L']);
+            let value = if let Some(hex) = literal
+                .strip_prefix("0x")
+                .or_else(|| literal.strip_prefix("0X"))
+            {
+                i128::from_str_radix(hex, 16).ok()?
+            } else if literal.len() > 1 && literal.starts_with('0') {
+                i128::from_str_radix(&literal[1..], 8).ok()?
+            } else {
+                literal.parse().ok()?
+            };
+            tokens.push(Token::Num(value));
+            rest = &rest[end..];
+        } else if c.is_ascii_alphabetic() || c == '_' {
+            let end = rest
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            tokens.push(Token::Ident(rest[..end].to_string()));
+            rest = &rest[end..];
+        } else {
+            let op = OPS.iter().find(|op| rest.starts_with(**op))?;
+            tokens.push(Token::Op(op));
+            rest = &rest[op.len()..];
+        }
+        rest = rest.trim_start();
+    }
+
+    Some(tokens)
+}
+
+/// Evaluates an integer constant expression.
+struct Eval<'a, 'b> {
+    cpp: &'b Preprocessor<'a>,
+    tokens: Vec<Token>,
+    pos: usize,
+    /// In `#if` conditions, `defined` is allowed and unknown identifiers
+    /// are 0.
+    condition: bool,
+    depth: usize,
+}
+
+impl<'a, 'b> Eval<'a, 'b> {
+    fn new(
+        cpp: &'b Preprocessor<'a>,
+        expr: &str,
+        condition: bool,
+    ) -> Option<Self> {
+        Some(Self {
+            cpp,
+            tokens: tokenize(expr)?,
+            pos: 0,
+            condition,
+            depth: 0,
+        })
+    }
+
+    fn run(&mut self) -> Option<i128> {
+        let value = self.binary(0)?;
+        (self.pos == self.tokens.len()).then_some(value)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn peek_op(&self) -> Option<&'static str> {
+        match self.tokens.get(self.pos) {
+            Some(Token::Op(op)) => Some(op),
+            _ => None,
+        }
+    }
+
+    fn binary(&mut self, min_prec: u8) -> Option<i128> {
+        let mut lhs = self.unary()?;
+
+        while let Some(op) = self.peek_op() {
+            let prec = match op {
+                "||" => 1,
+                "&&" => 2,
+                "|" => 3,
+                "^" => 4,
+                "&" => 5,
+                "==" | "!=" => 6,
+                "<" | ">" | "<=" | ">=" => 7,
+                "<<" | ">>" => 8,
+                "+" | "-" => 9,
+                "*" | "/" | "%" => 10,
+                _ => break,
+            };
+            if prec < min_prec {
+                break;
+            }
+            self.pos += 1;
+            let rhs = self.binary(prec + 1)?;
+
+            lhs = match op {
+                "||" => i128::from(lhs != 0 || rhs != 0),
+                "&&" => i128::from(lhs != 0 && rhs != 0),
+                "|" => lhs | rhs,
+                "^" => lhs ^ rhs,
+                "&" => lhs & rhs,
+                "==" => i128::from(lhs == rhs),
+                "!=" => i128::from(lhs != rhs),
+                "<" => i128::from(lhs < rhs),
+                ">" => i128::from(lhs > rhs),
+                "<=" => i128::from(lhs <= rhs),
+                ">=" => i128::from(lhs >= rhs),
+                "<<" => lhs.checked_shl(u32::try_from(rhs).ok()?)?,
+                ">>" => lhs.checked_shr(u32::try_from(rhs).ok()?)?,
+                "+" => lhs.checked_add(rhs)?,
+                "-" => lhs.checked_sub(rhs)?,
+                "*" => lhs.checked_mul(rhs)?,
+                "/" => lhs.checked_div(rhs)?,
+                _ => lhs.checked_rem(rhs)?,
+            };
+        }
+
+        Some(lhs)
+    }
+
+    fn unary(&mut self) -> Option<i128> {
+        match self.next()? {
+            Token::Num(value) => Some(value),
+            Token::Op("-") => self.unary()?.checked_neg(),
+            Token::Op("+") => self.unary(),
+            Token::Op("~") => Some(!self.unary()?),
+            Token::Op("!") => Some(i128::from(self.unary()? == 0)),
+            Token::Op("(") => {
+                let value = self.binary(0)?;
+                (self.next()? == Token::Op(")")).then_some(value)
+            }
+            Token::Ident(name) if self.condition && name == "defined" => {
+                let parens = self.peek_op() == Some("(");
+                if parens {
+                    self.pos += 1;
+                }
+                let Token::Ident(name) = self.next()? else {
+                    return None;
+                };
+                if parens && self.next()? != Token::Op(")") {
+                    return None;
+                }
+                Some(i128::from(self.cpp.defines.contains_key(&name)))
+            }
+            Token::Ident(name) => {
+                let Some(body) = self.cpp.defines.get(&name) else {
+                    return self.condition.then_some(0);
+                };
+                if self.depth > 16 {
+                    return None;
+                }
+                let mut eval = Eval::new(self.cpp, body, self.condition)?;
+                eval.depth = self.depth + 1;
+                eval.run()
+            }
+            Token::Op(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn preprocess(source: &str) -> Preprocessor<'static> {
+        let mut cpp = Preprocessor::new("x86", "v6.12");
+        // No includes are followed, so this never fetches anything.
+        futures::executor::block_on(cpp.process(source)).unwrap();
+        cpp
+    }
+
+    #[test]
+    fn defines_and_conditionals() {
+        let cpp = preprocess(
+            r"
+#define O_DIRECTORY	00200000	/* must be a directory */
+#ifndef O_DIRECTORY
+#define O_DIRECTORY	00040000
+#endif
+#define __O_TMPFILE	020000000
+#define O_TMPFILE (__O_TMPFILE | O_DIRECTORY)
+#define AT_FDCWD		-100
+#ifdef __KERNEL__
+#define O_KERNEL_ONLY 1
+#elif defined(O_DIRECTORY) && !defined(O_NOPE)
+#define O_ELIF 2
+#else
+#define O_ELSE 3
+#endif
+#define CLONE_INTO_CGROUP 0x200000000ULL
+#define HUGETLB_FLAG_ENCODE_SHIFT	26
+#define MAP_HUGE_2MB	(21U << HUGETLB_FLAG_ENCODE_SHIFT)
+#define MS_RMT_MASK	(MS_RDONLY|\
+			 MS_SYNCHRONOUS)
+#define MS_RDONLY	 1
+#define MS_SYNCHRONOUS	16
+#define _IOW(a, b) ((a) | (b))
+",
+        );
+
+        assert_eq!(cpp.value("O_DIRECTORY"), Some(0o200_000));
+        assert_eq!(cpp.value("O_TMPFILE"), Some(0o20_200_000));
+        assert_eq!(cpp.value("AT_FDCWD"), Some(-100));
+        assert_eq!(cpp.value("O_KERNEL_ONLY"), None);
+        assert_eq!(cpp.value("O_ELIF"), Some(2));
+        assert_eq!(cpp.value("O_ELSE"), None);
+        assert_eq!(cpp.value("CLONE_INTO_CGROUP"), Some(1 << 33));
+        assert_eq!(cpp.value("MAP_HUGE_2MB"), Some(21 << 26));
+        assert_eq!(cpp.value("MS_RMT_MASK"), Some(17));
+        assert!(!cpp.defines.contains_key("_IOW"));
+    }
+}
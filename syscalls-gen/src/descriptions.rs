@@ -0,0 +1,274 @@
+use color_eyre::eyre::{Result, WrapErr, eyre};
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+/// One-line descriptions of syscalls, taken from the `NAME` section of their
+/// man pages (`open, openat, creat \- open and possibly create a file`).
+static BUNDLED: &str = include_str!("../man-pages.txt");
+
+/// Where `--man-pages` writes the descriptions it reads, so later runs don't
+/// need a man-pages checkout.
+static BUNDLED_PATH: &str = "man-pages.txt";
+
+#[derive(Debug, Default)]
+pub struct Descriptions {
+    /// Keyed by the names listed in the `NAME` section.
+    descriptions: BTreeMap<String, String>,
+}
+
+impl Descriptions {
+    /// The descriptions shipped with the generator in `man-pages.txt`.
+    pub fn bundled() -> Self {
+        let descriptions = BUNDLED
+            .lines()
+            .filter(|line| !line.starts_with('#'))
+            .filter_map(|line| line.split_once('\t'))
+            .map(|(name, description)| (name.into(), description.into()))
+            .collect();
+        Self { descriptions }
+    }
+
+    /// Reads the descriptions from the `man2` pages of a man-pages checkout
+    /// and refreshes `man-pages.txt` with them.
+    pub fn from_man_pages(root: &Path) -> Result<Self> {
+        let dir = root.join("man2");
+        let entries = fs::read_dir(&dir)
+            .wrap_err_with(|| eyre!("Failed to read {}", dir.display()))?;
+
+        let mut descriptions = BTreeMap::new();
+        let mut release = None;
+        for entry in entries {
+            let path = entry?.path();
+            if path.extension().is_none_or(|ext| ext != "2") {
+                continue;
+            }
+            let page = fs::read_to_string(&path)
+                .wrap_err_with(|| eyre!("Failed to read {}", path.display()))?;
+            if release.is_none() {
+                release = parse_release(&page);
+            }
+            if let Some((names, description)) = parse_name_section(&page) {
+                for name in names {
+                    descriptions.insert(name, description.clone());
+                }
+            }
+        }
+        println!(
+            "Read {} descriptions from {}",
+            descriptions.len(),
+            dir.display()
+        );
+
+        let descriptions = Self { descriptions };
+        fs::write(BUNDLED_PATH, descriptions.to_bundled(release.as_deref()))
+            .wrap_err_with(|| eyre!("Failed to write {BUNDLED_PATH}"))?;
+        println!("Updated {BUNDLED_PATH}");
+
+        Ok(descriptions)
+    }
+
+    /// Returns the description of a syscall, formatted as a sentence.
+    ///
+    /// Syscalls that are variants of another one (`pread64`,
+    /// `clock_gettime64`, `newfstatat`, `oldumount`, ...) fall back to the
+    /// description of the plain syscall when they have no page of their own.
+    pub fn get(&self, name: &str) -> Option<String> {
+        let candidates = [
+            Some(name),
+            name.strip_suffix("_time64"),
+            name.strip_suffix("64"),
+            name.strip_prefix("new"),
+            name.strip_prefix("old"),
+        ];
+        let description = candidates
+            .into_iter()
+            .flatten()
+            .find_map(|name| self.descriptions.get(name))?;
+
+        let mut chars = description.chars();
+        let first = chars.next()?;
+        let sentence = format!("{}{}.", first.to_uppercase(), chars.as_str());
+        Some(quote_identifiers(&sentence))
+    }
+
+    fn to_bundled(&self, release: Option<&str>) -> String {
+        let mut out = String::new();
+        out.push_str(
+            "# One-line syscall descriptions from the NAME sections of the \
+             Linux man-pages\n# project (man2), as `name<TAB>description`. \
+             Regenerate with\n# `syscalls-gen --man-pages <checkout>`.\n",
+        );
+        if let Some(release) = release {
+            writeln!(out, "# Source: Linux man-pages {release}").unwrap();
+        }
+        for (name, description) in &self.descriptions {
+            writeln!(out, "{name}\t{description}").unwrap();
+        }
+        out
+    }
+}
+
+/// Wraps identifiers such as `SCHED_RR` in backticks, as rustdoc (and
+/// `clippy::doc_markdown`) expect.
+fn quote_identifiers(text: &str) -> String {
+    text.split(' ')
+        .map(|word| {
+            let core = word.trim_end_matches(['.', ',', ';', ':', ')']);
+            let suffix = &word[core.len()..];
+            let ident = core.trim_start_matches('(');
+            let prefix = &core[..core.len() - ident.len()];
+            if ident.contains('_') {
+                format!("{prefix}`{ident}`{suffix}")
+            } else {
+                word.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Extracts the man-pages release from the `.TH` line of a page, e.g. `6.03`
+/// from `.TH open 2 2023-02-05 "Linux man-pages 6.03"`.
+fn parse_release(page: &str) -> Option<String> {
+    let line = page.lines().find(|line| line.starts_with(".TH "))?;
+    let (_, release) = line.split_once("Linux man-pages ")?;
+    Some(release.trim_end_matches('"').to_string())
+}
+
+/// Parses the `NAME` section of a man page into the names it documents and
+/// their description.
+fn parse_name_section(page: &str) -> Option<(Vec<String>, String)> {
+    let mut lines = page.lines().skip_while(|line| *line != ".SH NAME");
+    lines.next()?;
+
+    let mut text = String::new();
+    for line in lines.take_while(|line| !line.starts_with(".SH")) {
+        if line.starts_with(".\\\"") {
+            continue;
+        }
+        // Drop the request of lines such as `.BR name`, keep its arguments.
+        let line = match line.strip_prefix('.') {
+            Some(rest) => rest.split_once(' ').map_or("", |(_, args)| args),
+            None => line,
+        };
+        if !text.is_empty() {
+            text.push(' ');
+        }
+        text.push_str(&line.replace('"', ""));
+    }
+
+    let (names, description) = text.split_once("\\-")?;
+    let names = names
+        .split(',')
+        .map(|name| unescape(name).trim().to_string())
+        .filter(|name| !name.is_empty())
+        .collect();
+    let description = unescape(description).trim().to_string();
+    if description.is_empty() {
+        return None;
+    }
+    Some((names, description))
+}
+
+/// Removes the roff escapes that appear in `NAME` sections.
+fn unescape(text: &str) -> String {
+    let mut out = String::new();
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            // Font changes: `\fB`, `\fP`, `\f[B]`.
+            Some('f') if chars.next() == Some('[') => {
+                chars.by_ref().find(|&c| c == ']');
+            }
+            // Special characters: `\[aq]`, `\(aq`.
+            Some('[') => {
+                let name: String =
+                    chars.by_ref().take_while(|&c| c != ']').collect();
+                out.push_str(special_char(&name));
+            }
+            Some('(') => {
+                let name: String = chars.by_ref().take(2).collect();
+                out.push_str(special_char(&name));
+            }
+            Some('-') => out.push('-'),
+            Some('e') => out.push('\\'),
+            // Zero-width (`\&`) and unknown escapes.
+            _ => {}
+        }
+    }
+    out
+}
+
+fn special_char(name: &str) -> &'static str {
+    match name {
+        "aq" => "'",
+        "dq" => "\"",
+        "em" => "\u{2014}",
+        "en" => "\u{2013}",
+        "ti" => "~",
+        _ => "",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_name_section() {
+        let page = r#".\" Copyright
+.TH open 2 2023-02-05 "Linux man-pages 6.03"
+.SH NAME
+open, openat, creat \- open and possibly create a file
+.SH LIBRARY
+"#;
+        assert_eq!(parse_release(page).as_deref(), Some("6.03"));
+        let (names, description) = parse_name_section(page).unwrap();
+        assert_eq!(names, ["open", "openat", "creat"]);
+        assert_eq!(description, "open and possibly create a file");
+
+        let page = ".SH NAME\n\\fBfoo\\fP, \\f[B]bar\\f[R] \\- don\\[aq]t\n\
+                    do it\n.SH SYNOPSIS\n";
+        let (names, description) = parse_name_section(page).unwrap();
+        assert_eq!(names, ["foo", "bar"]);
+        assert_eq!(description, "don't do it");
+    }
+
+    #[test]
+    fn falls_back_to_plain_syscall() {
+        let descriptions = Descriptions {
+            descriptions: [
+                ("pread".into(), "read from a file descriptor".into()),
+                ("umount".into(), "unmount filesystem".into()),
+            ]
+            .into(),
+        };
+        assert_eq!(
+            descriptions.get("pread64").as_deref(),
+            Some("Read from a file descriptor.")
+        );
+        assert_eq!(
+            descriptions.get("oldumount").as_deref(),
+            Some("Unmount filesystem.")
+        );
+        assert_eq!(descriptions.get("unknown"), None);
+
+        let descriptions = Descriptions {
+            descriptions: [(
+                "sched_rr_get_interval".into(),
+                "get the SCHED_RR interval (see sched_setscheduler)".into(),
+            )]
+            .into(),
+        };
+        assert_eq!(
+            descriptions.get("sched_rr_get_interval").as_deref(),
+            Some("Get the `SCHED_RR` interval (see `sched_setscheduler`).")
+        );
+    }
+}
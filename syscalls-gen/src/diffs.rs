@@ -0,0 +1,116 @@
+use crate::REMOVED_ARCHS;
+use crate::modules::{Version, arch_versions, parse_variants, rustfmt};
+use color_eyre::eyre::{Result, WrapErr, eyre};
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+/// Generates `src/arch/<arch>/versions.rs` with the syscalls added and
+/// removed between each pair of consecutive `versions`, for every arch that
+/// has at least two of them.
+pub fn generate_diffs(dir: &Path, versions: &[String]) -> Result<()> {
+    let mut requested: Vec<_> = versions
+        .iter()
+        .filter_map(|tag| Version::from_tag(tag))
+        .collect();
+    requested.sort();
+    requested.dedup();
+
+    let arch_root = dir.join("src/arch");
+    for (arch, available) in arch_versions(&arch_root)? {
+        let versions: Vec<_> = requested
+            .iter()
+            .copied()
+            .filter(|version| available.contains(version))
+            .collect();
+        if versions.len() < 2 {
+            continue;
+        }
+
+        let mut tables = Vec::new();
+        for version in &versions {
+            let path = arch_root
+                .join(&arch)
+                .join(format!("{}.rs", version.module()));
+            let contents = fs::read_to_string(&path)
+                .wrap_err_with(|| eyre!("Failed to read {}", path.display()))?;
+            let variants: Vec<String> = parse_variants(&contents)
+                .into_iter()
+                .map(String::from)
+                .collect();
+            tables.push((*version, variants));
+        }
+
+        let path = arch_root.join(&arch).join("versions.rs");
+        fs::write(&path, diff_module(&arch, &tables))
+            .wrap_err_with(|| eyre!("Failed to write {}", path.display()))?;
+        rustfmt(&path)?;
+        println!("Generated version diffs for {arch} at {}", path.display());
+    }
+
+    Ok(())
+}
+
+/// The contents of `versions.rs` for the tables of consecutive versions.
+fn diff_module(arch: &str, tables: &[(Version, Vec<String>)]) -> String {
+    // The sets are built from the crate's `Sysno`, which only matches this
+    // table on its own target (and ia64 has no target at all).
+    let sets = !REMOVED_ARCHS.iter().any(|&(name, _)| name == arch);
+
+    let mut out = String::new();
+    out.push_str("//! Syscalls added and removed between kernel versions.\n");
+    out.push('\n');
+    out.push_str("// This file is automatically generated. Do not edit!\n");
+
+    for pair in tables.windows(2) {
+        let (old, old_table) = &pair[0];
+        let (new, new_table) = &pair[1];
+        let name = new.module().to_uppercase();
+
+        let lists = [
+            ("ADDED", "added", new_table, old_table),
+            ("REMOVED", "removed", old_table, new_table),
+        ];
+        for (prefix, verb, from, without) in lists {
+            let names: Vec<String> = from
+                .iter()
+                .filter(|name| !without.contains(name))
+                .map(|name| format!("{name:?}"))
+                .collect();
+
+            out.push('\n');
+            writeln!(
+                out,
+                "/// Syscalls {verb} in Linux {} (since {}).",
+                new.tag(),
+                old.tag()
+            )
+            .unwrap();
+            writeln!(
+                out,
+                "pub const {prefix}_IN_{name}: &[&str] = &[{}];",
+                names.join(", ")
+            )
+            .unwrap();
+
+            if sets {
+                out.push('\n');
+                writeln!(
+                    out,
+                    "/// [`{prefix}_IN_{name}`] as a set. Only the syscalls in \
+                     the selected table are\n/// included."
+                )
+                .unwrap();
+                writeln!(out, "#[cfg(target_arch = \"{arch}\")]").unwrap();
+                writeln!(
+                    out,
+                    "pub const {prefix}_IN_{name}_SET: crate::SysnoSet =\n    \
+                     crate::SysnoSet::from_names({prefix}_IN_{name});"
+                )
+                .unwrap();
+            }
+        }
+    }
+
+    out
+}
@@ -1,16 +1,16 @@
 use crate::fetch_path;
 use color_eyre::eyre::{Result, WrapErr, eyre};
 use futures::future::try_join_all;
-use lazy_static::lazy_static;
 use regex::Regex;
 use std::fmt;
-use std::fs::File;
+use std::fs::{File, create_dir_all};
 use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
 
 pub struct ErrnoFile<'a>(&'a Vec<Errno>);
 
-impl<'a> fmt::Display for ErrnoFile<'a> {
+impl fmt::Display for ErrnoFile<'_> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         writeln!(f, "// This file is automatically generated. Do not edit!")?;
         writeln!(f)?;
@@ -18,28 +18,14 @@ impl<'a> fmt::Display for ErrnoFile<'a> {
         writeln!(f, "errno_enum! {{")?;
         writeln!(f, "    pub enum Errno {{")?;
 
-        for value in self.0.iter() {
+        for value in self.0 {
             match value {
                 Errno::Definition {
                     name,
                     num,
                     description,
                 } => {
-                    let description = description.as_ref().map_or_else(
-                        || {
-                            // Try to make a best-effort guess for error codes that
-                            // don't have a description.
-                            match name.as_str() {
-                                "ERESTARTSYS" => "Restart syscall",
-                                "ERESTARTNOINTR" => "Restart if no interrupt",
-                                _ => panic!(
-                                    "Could not find a description for {name}"
-                                ),
-                            }
-                        },
-                        |s| s.as_str(),
-                    );
-
+                    let description = describe(name, description.as_deref());
                     writeln!(f, r#"        {name}({num}) = "{description}","#)?;
                 }
                 Errno::Alias { .. } => {
@@ -56,24 +42,89 @@ impl<'a> fmt::Display for ErrnoFile<'a> {
     }
 }
 
-pub async fn generate_errno(path: PathBuf, version: String) -> Result<()> {
-    let table = fetch_errno(
-        &[
-            "include/uapi/asm-generic/errno-base.h",
-            "include/uapi/asm-generic/errno.h",
-            // error codes private to the Kernel, but are still useful when
-            // ptracing.
-            "include/linux/errno.h",
-        ],
-        &version,
-    )
-    .await?;
-
-    let mut file = File::create(&path)
-        .wrap_err_with(|| eyre!("Failed to create file {}", &path.display()))?;
+/// Returns the description of an error code, making a best-effort guess for
+/// those that don't have one in the header.
+pub fn describe<'a>(name: &str, description: Option<&'a str>) -> &'a str {
+    description.unwrap_or_else(|| match name {
+        "ERESTARTSYS" => "Restart syscall",
+        "ERESTARTNOINTR" => "Restart if no interrupt",
+        _ => panic!("Could not find a description for {name}"),
+    })
+}
+
+/// Errno headers shared by all architectures.
+static GENERIC_HEADERS: &[&str] = &[
+    "include/uapi/asm-generic/errno-base.h",
+    "include/uapi/asm-generic/errno.h",
+];
+
+/// Error codes private to the kernel, which are still useful when ptracing.
+/// These are the same on every architecture.
+static KERNEL_HEADER: &str = "include/linux/errno.h";
+
+/// Architectures whose `errno.h` replaces `asm-generic/errno.h` with its own
+/// numbers, as `(module, header)`. The module is written to
+/// `src/errno/arch/<module>.rs`. (alpha and parisc do this too, but are not
+/// supported by this crate.)
+static ARCH_HEADERS: &[(&str, &str)] = &[
+    ("mips", "arch/mips/include/uapi/asm/errno.h"),
+    ("sparc", "arch/sparc/include/uapi/asm/errno.h"),
+];
+
+/// Generates `src/errno/generated.rs` with the `asm-generic` numbers and a
+/// module under `src/errno/arch/` for each architecture in [`ARCH_HEADERS`].
+pub async fn generate_errno(dir: PathBuf, version: String) -> Result<()> {
+    write_errno(&dir.join("generated.rs"), GENERIC_HEADERS, &version).await?;
+
+    let arch_dir = dir.join("arch");
+    create_dir_all(&arch_dir).wrap_err_with(|| {
+        eyre!("Failed to create directory {}", arch_dir.display())
+    })?;
+    for (module, header) in ARCH_HEADERS {
+        let headers = [GENERIC_HEADERS[0], header];
+        let path = arch_dir.join(format!("{module}.rs"));
+        write_errno(&path, &headers, &version).await?;
+    }
+
+    Ok(())
+}
+
+/// Fetches the errno table of `arch` (a syscall table architecture such as
+/// `mips64`), in the order it is generated in.
+pub async fn errno_table(arch: &str, version: &str) -> Result<Vec<Errno>> {
+    let module = match arch {
+        "mips64" => "mips",
+        "sparc64" => "sparc",
+        arch => arch,
+    };
+    match ARCH_HEADERS.iter().find(|(name, _)| *name == module) {
+        Some((_, header)) => {
+            fetch_table(&[GENERIC_HEADERS[0], header], version).await
+        }
+        None => fetch_table(GENERIC_HEADERS, version).await,
+    }
+}
+
+async fn fetch_table(headers: &[&str], version: &str) -> Result<Vec<Errno>> {
+    let mut table = fetch_errno(headers, version).await?;
+    // Arch headers are not always in numeric order (e.g. mips' `EDQUOT`).
+    table.sort_by_key(Errno::num);
+    table.extend(fetch_errno(&[KERNEL_HEADER], version).await?);
+    Ok(table)
+}
+
+async fn write_errno(
+    path: &Path,
+    headers: &[&str],
+    version: &str,
+) -> Result<()> {
+    let table = fetch_table(headers, version).await?;
+
+    let mut file = File::create(path)
+        .wrap_err_with(|| eyre!("Failed to create file {}", path.display()))?;
     write!(file, "{}", ErrnoFile(&table))?;
 
-    println!("Generated errno table at {}", &path.display());
+    println!("Generated errno table at {}", path.display());
     Ok(())
 }
 
@@ -92,6 +143,16 @@ pub enum Errno {
     },
 }
 
+impl Errno {
+    /// Sort key: the number of a definition. Aliases sort last.
+    fn num(&self) -> u32 {
+        match self {
+            Self::Definition { num, .. } => *num,
+            Self::Alias { .. } => u32::MAX,
+        }
+    }
+}
+
 async fn fetch_errno(paths: &[&str], version: &str) -> Result<Vec<Errno>> {
     let futures: Vec<_> =
         paths.iter().map(|path| fetch_path(path, version)).collect();
@@ -105,12 +166,15 @@ async fn fetch_errno(paths: &[&str], version: &str) -> Result<Vec<Errno>> {
 }
 
 fn parse_errno(contents: &str, errnos: &mut Vec<Errno>) -> Result<()> {
-    lazy_static! {
-        // Pattern for matching the errno definition
-        static ref RE_DEFINITION: Regex = Regex::new(r"^#define\s+(E\w+)\s+(\d+)(?:\s+/\*([^\\*]+)\*/)?").unwrap();
-        // Pattern for matching errno aliases
-        static ref RE_ALIAS: Regex = Regex::new(r#"^#define\s+(E\w+)\s+(E\w+)(?:\s+/\*([^\\*]+)\*/)?"#).unwrap();
-    }
+    // Pattern for matching the errno definition
+    static RE_DEFINITION: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(r"^#define\s+(E\w+)\s+(\d+)(?:\s+/\*([^\\*]+)\*/)?").unwrap()
+    });
+    // Pattern for matching errno aliases
+    static RE_ALIAS: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(r"^#define\s+(E\w+)\s+(E\w+)(?:\s+/\*([^\\*]+)\*/)?")
+            .unwrap()
+    });
 
     for line in contents.lines() {
         if let Some(cap) = RE_DEFINITION.captures(line) {
@@ -0,0 +1,328 @@
+use crate::consts::Family;
+use std::fmt;
+
+/// How an argument is shown, mirroring the crate's `format::ArgFormat`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Format {
+    Int,
+    Uint,
+    Hex,
+    Fd,
+    Str,
+    Buf,
+    Struct(String),
+    /// Flags of the constant family with this prefix (e.g. `O_`).
+    Flags(&'static str),
+    Mode,
+}
+
+impl Format {
+    /// Name of the generated flag table for a family prefix: `O_FLAGS`.
+    pub fn flag_table(prefix: &str) -> String {
+        format!("{prefix}FLAGS")
+    }
+
+    /// Name of the `ArgFormat` variant.
+    pub fn variant(&self) -> &'static str {
+        match self {
+            Self::Int => "Int",
+            Self::Uint => "Uint",
+            Self::Hex => "Hex",
+            Self::Fd => "Fd",
+            Self::Str => "Str",
+            Self::Buf => "Buf",
+            Self::Struct(_) => "Struct",
+            Self::Flags(_) => "Flags",
+            Self::Mode => "Mode",
+        }
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.variant())?;
+        match self {
+            Self::Struct(name) => write!(f, "({name:?})"),
+            Self::Flags(prefix) => write!(f, "({})", Self::flag_table(prefix)),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Arguments holding flags, as `(syscalls, argument, family prefix)`. An
+/// empty list of syscalls matches the argument of any syscall.
+static FLAG_ARGS: &[(&[&str], &str, &str)] = &[
+    (&["open", "openat", "dup3", "pipe2"], "flags", "O_"),
+    (&["mmap", "mmap2"], "flags", "MAP_"),
+    (&[], "prot", "PROT_"),
+    (&[], "clone_flags", "CLONE_"),
+    (&[], "unshare_flags", "CLONE_"),
+    (&["setns"], "flags", "CLONE_"),
+    (&["mount"], "flags", "MS_"),
+    (
+        &[
+            "fstatat64",
+            "newfstatat",
+            "unlinkat",
+            "fchownat",
+            "name_to_handle_at",
+        ],
+        "flag",
+        "AT_",
+    ),
+    (
+        &[
+            "linkat",
+            "statx",
+            "utimensat",
+            "faccessat2",
+            "fchmodat2",
+            "execveat",
+        ],
+        "flags",
+        "AT_",
+    ),
+];
+
+/// Names of file descriptor arguments.
+static FD_ARGS: &[&str] = &[
+    "fd", "dfd", "olddfd", "newdfd", "oldfd", "newfd", "epfd", "fd_in",
+    "fd_out", "in_fd", "out_fd", "pidfd", "fildes",
+];
+
+/// Names of integer arguments that hold addresses.
+static ADDR_ARGS: &[&str] =
+    &["addr", "start", "brk", "new_addr", "old_addr", "addr_hint"];
+
+/// Integer types (and typedefs of them) that are signed.
+static SIGNED_TYPES: &[&str] = &[
+    "int",
+    "long",
+    "pid_t",
+    "off_t",
+    "loff_t",
+    "ssize_t",
+    "clockid_t",
+    "timer_t",
+    "key_serial_t",
+    "rwf_t",
+    "s32",
+    "__s32",
+    "s64",
+    "__s64",
+];
+
+/// Integer types (and typedefs of them) that are unsigned, other than the
+/// `unsigned ...` and `u*`/`__u*` ones.
+static UNSIGNED_TYPES: &[&str] = &[
+    "size_t",
+    "uid_t",
+    "gid_t",
+    "old_uid_t",
+    "old_gid_t",
+    "qid_t",
+    "umode_t",
+    "aio_context_t",
+    "key_t",
+    "mqd_t",
+];
+
+/// Decides how argument `name` of type `ty` of `syscall` is shown.
+pub fn classify(syscall: &str, ty: &str, name: &str) -> Format {
+    let flags = FLAG_ARGS.iter().find(|(syscalls, arg, _)| {
+        *arg == name && (syscalls.is_empty() || syscalls.contains(&syscall))
+    });
+    if let Some(&(_, _, prefix)) = flags {
+        return Format::Flags(prefix);
+    }
+
+    let words: Vec<&str> = ty
+        .split(|c: char| c.is_whitespace() || c == '*')
+        .filter(|word| !word.is_empty() && !matches!(*word, "const" | "__user"))
+        .collect();
+
+    match ty.matches('*').count() {
+        0 => {}
+        1 => {
+            return if let ["struct", name, ..] = words[..] {
+                Format::Struct(name.to_string())
+            } else if words == ["char"] && name.contains("buf") {
+                Format::Buf
+            } else if words == ["char"] {
+                Format::Str
+            } else {
+                Format::Hex
+            };
+        }
+        // Arrays of pointers, such as `argv`.
+        _ => return Format::Hex,
+    }
+
+    if FD_ARGS.contains(&name) {
+        return Format::Fd;
+    }
+    if name == "mode" && words.iter().any(|word| word.ends_with("mode_t")) {
+        return Format::Mode;
+    }
+    if ADDR_ARGS.contains(&name) {
+        return Format::Hex;
+    }
+
+    let ty = words.join(" ");
+    if ty.starts_with("unsigned")
+        || ty.starts_with('u')
+        || ty.starts_with("__u")
+        || UNSIGNED_TYPES.contains(&&*ty)
+    {
+        Format::Uint
+    } else if SIGNED_TYPES.contains(&&*ty) || ty.starts_with("long") {
+        Format::Int
+    } else {
+        // Opaque typedefs such as `cap_user_header_t`.
+        Format::Hex
+    }
+}
+
+/// Constants that share a family's prefix but are not flags of it: masks,
+/// shifts, sizes, magic numbers, aliases, and flags of other syscalls.
+static NOT_FLAGS: &[&str] = &[
+    "AT_EACCESS",
+    "AT_STATX_SYNC_AS_STAT",
+    "MAP_ANON",
+    "MAP_FILE",
+    "MAP_TYPE",
+    "MS_ASYNC",
+    "MS_INVALIDATE",
+    "MS_SYNC",
+    "MS_VERBOSE",
+    "O_NDELAY",
+    "O_RSYNC",
+];
+
+/// Prefixes of constants that are not flags, see [`NOT_FLAGS`].
+static NOT_FLAG_PREFIXES: &[&str] = &["AT_HANDLE_", "AT_RENAME_", "MAP_HUGE_"];
+
+/// Parts of constant names that are not flags, see [`NOT_FLAGS`].
+static NOT_FLAG_PARTS: &[&str] = &[
+    "ACCMODE",
+    "MASK",
+    "MGC",
+    "MSK",
+    "SHIFT",
+    "SIZE",
+    "SYNC_TYPE",
+];
+
+/// The flags of a constant family, ordered for the crate's
+/// `ArgFormat::Flags`: flags spanning more bits first.
+pub fn flag_table(family: &Family) -> Vec<(&str, u64)> {
+    let mut flags: Vec<(&str, u64)> = family
+        .values
+        .iter()
+        .filter(|(name, _)| {
+            !NOT_FLAGS.contains(&name.as_str())
+                && !NOT_FLAG_PREFIXES
+                    .iter()
+                    .any(|prefix| name.starts_with(prefix))
+                && !NOT_FLAG_PARTS.iter().any(|part| name.contains(part))
+        })
+        .filter_map(|(name, value)| {
+            Some((name.as_str(), (*value).try_into().ok()?))
+        })
+        .collect();
+    flags.sort_by(|(a_name, a), (b_name, b)| {
+        b.count_ones().cmp(&a.count_ones()).then(a_name.cmp(b_name))
+    });
+    flags
+}
+
+/// Formats a flag value as a hex literal, with the digits in groups of four
+/// as `clippy::unreadable_literal` wants (`0x0010_1000`).
+pub fn hex_literal(value: u64) -> String {
+    let digits = format!("{value:x}");
+    if digits.len() <= 4 {
+        return format!("{value:#x}");
+    }
+    let digits =
+        format!("{value:0width$x}", width = digits.len().div_ceil(4) * 4);
+    let groups: Vec<&str> = digits
+        .as_bytes()
+        .chunks(4)
+        .map(|chunk| std::str::from_utf8(chunk).unwrap())
+        .collect();
+    format!("0x{}", groups.join("_"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_arguments() {
+        let args = [
+            ("openat", "int", "dfd", Format::Fd),
+            ("openat", "const char __user *", "filename", Format::Str),
+            ("openat", "int", "flags", Format::Flags("O_")),
+            ("openat", "umode_t", "mode", Format::Mode),
+            ("read", "char __user *", "buf", Format::Buf),
+            ("read", "size_t", "count", Format::Uint),
+            (
+                "fstat",
+                "struct stat __user *",
+                "statbuf",
+                Format::Struct("stat".into()),
+            ),
+            (
+                "execve",
+                "const char __user *const __user *",
+                "argv",
+                Format::Hex,
+            ),
+            ("mmap", "unsigned long", "addr", Format::Hex),
+            ("mmap", "unsigned long", "prot", Format::Flags("PROT_")),
+            ("mmap", "unsigned long", "flags", Format::Flags("MAP_")),
+            ("lseek", "off_t", "offset", Format::Int),
+            ("capget", "cap_user_header_t", "header", Format::Hex),
+            ("msync", "int", "flags", Format::Int),
+        ];
+        for (syscall, ty, name, format) in args {
+            assert_eq!(classify(syscall, ty, name), format, "{syscall} {name}");
+        }
+    }
+
+    #[test]
+    fn hex_literals() {
+        assert_eq!(hex_literal(0), "0x0");
+        assert_eq!(hex_literal(0x1000), "0x1000");
+        assert_eq!(hex_literal(0x10_1000), "0x0010_1000");
+        assert_eq!(hex_literal(0x2_0000_0000), "0x0002_0000_0000");
+    }
+
+    #[test]
+    fn orders_flags() {
+        let family = Family {
+            prefix: "O_",
+            ty: "u32",
+            values: [
+                ("O_ACCMODE", 3),
+                ("O_DSYNC", 0x1000),
+                ("O_NDELAY", 0x800),
+                ("O_NONBLOCK", 0x800),
+                ("O_RDONLY", 0),
+                ("O_SYNC", 0x10_1000),
+            ]
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), value))
+            .collect(),
+        };
+        assert_eq!(
+            flag_table(&family),
+            [
+                ("O_SYNC", 0x10_1000),
+                ("O_DSYNC", 0x1000),
+                ("O_NONBLOCK", 0x800),
+                ("O_RDONLY", 0),
+            ]
+        );
+    }
+}
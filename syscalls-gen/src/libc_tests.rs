@@ -0,0 +1,155 @@
+use crate::OFFLINE;
+use crate::modules::{arch_versions, parse_variants, rustfmt};
+use crate::tables::ident;
+use color_eyre::eyre::{Result, WrapErr, bail, eyre};
+use regex::Regex;
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::LazyLock;
+use std::sync::atomic::Ordering;
+
+/// Where libc defines the `SYS_*` constants of each arch for glibc targets,
+/// as `(arch, pointer width, file under libc's src/unix/linux_like/linux)`.
+/// The pointer width keeps x32 and n32 targets out.
+static LIBC_SOURCES: &[(&str, u32, &str)] = &[
+    ("aarch64", 64, "gnu/b64/aarch64/mod.rs"),
+    ("arm", 32, "gnu/b32/arm/mod.rs"),
+    ("loongarch64", 64, "gnu/b64/loongarch64/mod.rs"),
+    ("mips", 32, "gnu/b32/mips/mod.rs"),
+    ("mips64", 64, "gnu/b64/mips64/mod.rs"),
+    ("powerpc", 32, "gnu/b32/powerpc.rs"),
+    ("powerpc64", 64, "gnu/b64/powerpc64/mod.rs"),
+    ("riscv32", 32, "gnu/b32/riscv32/mod.rs"),
+    ("riscv64", 64, "gnu/b64/riscv64/mod.rs"),
+    ("s390x", 64, "gnu/b64/s390x.rs"),
+    ("sparc", 32, "gnu/b32/sparc/mod.rs"),
+    ("sparc64", 64, "gnu/b64/sparc64/mod.rs"),
+    ("x86", 32, "gnu/b32/x86/mod.rs"),
+    ("x86_64", 64, "gnu/b64/x86_64/not_x32.rs"),
+];
+
+/// Generates `tests/generated_libc_consistency.rs`, which checks the newest
+/// table of each arch against every `SYS_*` constant that libc defines for
+/// it.
+pub fn generate_libc_tests(dir: &Path) -> Result<()> {
+    let (libc_version, libc_src) = locate_libc(dir)?;
+    let linux_src = libc_src.join("src/unix/linux_like/linux");
+    let archs: HashMap<_, _> =
+        arch_versions(&dir.join("src/arch"))?.into_iter().collect();
+
+    let mut out = String::new();
+    writeln!(
+        out,
+        "// This file is automatically generated by syscalls-gen. Do not edit!"
+    )?;
+    writeln!(out, "//")?;
+    writeln!(
+        out,
+        "// Checks the syscall tables against the `SYS_*` constants of libc \
+         {libc_version}."
+    )?;
+    writeln!(out)?;
+    writeln!(
+        out,
+        "#![cfg(all(target_os = \"linux\", target_env = \"gnu\"))]"
+    )?;
+
+    for (arch, bits, path) in LIBC_SOURCES {
+        let Some(version) = archs.get(*arch).and_then(|v| v.last()) else {
+            continue;
+        };
+        let module = version.module();
+
+        let table = fs::read_to_string(
+            dir.join(format!("src/arch/{arch}/{module}.rs")),
+        )?;
+        let variants = parse_variants(&table);
+
+        let file = linux_src.join(path);
+        let libc = fs::read_to_string(&file)
+            .wrap_err_with(|| eyre!("Failed to read {}", file.display()))?;
+        let mut names = parse_libc(&libc);
+        names.sort_unstable();
+        names.dedup();
+
+        writeln!(out)?;
+        writeln!(
+            out,
+            "#[cfg(all(target_arch = \"{arch}\", target_pointer_width = \"{bits}\"))]"
+        )?;
+        writeln!(out, "#[test]")?;
+        writeln!(out, "fn {arch}() {{")?;
+        writeln!(out, "    use rawsys_linux::{arch}::{module}::Sysno;")?;
+        writeln!(out)?;
+        for name in names {
+            // Syscalls that were removed from the kernel, or that libc knows
+            // under another name, can't be compared.
+            let variant = ident(name);
+            if variants.contains(&&*variant) {
+                writeln!(
+                    out,
+                    "    assert_eq!(Sysno::{variant}.id() as libc::c_long, libc::SYS_{name});"
+                )?;
+            }
+        }
+        writeln!(out, "}}")?;
+    }
+
+    let path = dir.join("tests/generated_libc_consistency.rs");
+    fs::write(&path, out)
+        .wrap_err_with(|| eyre!("Failed to write {}", path.display()))?;
+    rustfmt(&path)?;
+    println!("Generated libc consistency tests at {}", path.display());
+
+    Ok(())
+}
+
+/// Finds the version and source directory of the libc crate that the crate
+/// in `dir` is tested with.
+fn locate_libc(dir: &Path) -> Result<(String, PathBuf)> {
+    let mut command = Command::new("cargo");
+    command
+        .args(["metadata", "--format-version", "1", "--manifest-path"])
+        .arg(dir.join("Cargo.toml"));
+    if OFFLINE.load(Ordering::Relaxed) {
+        command.arg("--offline");
+    }
+    let output = command
+        .output()
+        .wrap_err("Failed to run `cargo metadata`")?;
+    if !output.status.success() {
+        bail!(
+            "`cargo metadata` failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    let metadata: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    let libc = metadata["packages"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .find(|package| package["name"] == "libc")
+        .ok_or_else(|| eyre!("libc not found in the dependencies"))?;
+
+    let version = libc["version"].as_str().unwrap_or_default().to_string();
+    let manifest = libc["manifest_path"]
+        .as_str()
+        .ok_or_else(|| eyre!("libc has no manifest path"))?;
+    let src = Path::new(manifest).parent().unwrap().to_path_buf();
+    Ok((version, src))
+}
+
+/// Extracts the syscall names of libc's `SYS_*` constants.
+fn parse_libc(contents: &str) -> Vec<&str> {
+    static RE_SYS: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"(?m)^pub const SYS_(\w+):").unwrap());
+
+    RE_SYS
+        .captures_iter(contents)
+        .map(|cap| cap.get(1).unwrap().as_str())
+        .collect()
+}
@@ -1,18 +1,41 @@
 #![deny(clippy::all, clippy::pedantic)]
 #![allow(clippy::upper_case_acronyms)]
 
+use crate::config::Config;
+use crate::descriptions::Descriptions;
 use crate::tables::Source;
-use color_eyre::eyre::{Result, WrapErr, eyre};
-use futures::future::try_join_all;
-use lazy_static::lazy_static;
+use color_eyre::eyre::{Result, WrapErr, bail, eyre};
+use futures::StreamExt;
+use futures::future::try_join;
+use futures::stream::FuturesUnordered;
 use std::collections::HashSet;
 use std::future::Future;
-use std::path::Path;
+use std::io::Write;
+use std::path::{Path, PathBuf};
 use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{LazyLock, OnceLock};
+use std::time::Duration;
 use tables::{Header, Table};
+use tempfile::TempDir;
+use tokio::sync::Semaphore;
 
+mod audit;
+mod c_header;
+mod check;
+mod config;
+mod consts;
+mod descriptions;
+mod diffs;
 mod errors;
+mod formats;
+mod libc_tests;
+mod modules;
+mod releases;
+mod signatures;
+mod sysroot;
 mod tables;
+mod validate;
 
 /// URL of the Linux repository to pull the syscall tables from.
 static LINUX_REPO: &str = "https://raw.githubusercontent.com/torvalds/linux";
@@ -21,9 +44,50 @@ static LINUX_REPO: &str = "https://raw.githubusercontent.com/torvalds/linux";
 /// Multiple versions can be specified via CLI flags.
 static DEFAULT_LINUX_VERSION: &str = "v6.10";
 
-lazy_static! {
-    /// List of syscall tables for each architecture.
-    static ref SOURCES: Vec<Source<'static>> = vec![
+/// Local kernel source tree given with `--kernel-src`. When set, files are
+/// read from it instead of being fetched from `LINUX_REPO`.
+static KERNEL_SRC: OnceLock<PathBuf> = OnceLock::new();
+
+/// Installed kernel headers given with `--from-sysroot` (e.g.
+/// `/usr/include`). When set, the syscall tables are read from their
+/// `asm/unistd.h` instead of from the kernel's tables.
+static SYSROOT: OnceLock<PathBuf> = OnceLock::new();
+
+/// Directory given with `--cache-dir`. Fetched files are stored under
+/// `<dir>/<version>/<path>` and reused on later runs.
+static CACHE_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// Per-arch overrides, from `--config` or `syscalls-gen.toml`.
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+/// Config file read when `--config` is not given, if it exists.
+static DEFAULT_CONFIG: &str = "syscalls-gen.toml";
+
+/// Syscall descriptions for the generated doc comments, from `--man-pages` or
+/// the bundled `man-pages.txt`.
+static DESCRIPTIONS: OnceLock<Descriptions> = OnceLock::new();
+
+/// Set by `--offline`: only the cache may be used.
+static OFFLINE: AtomicBool = AtomicBool::new(false);
+
+/// Limits concurrent downloads. `main` adds `--jobs` permits.
+static FETCH_PERMITS: Semaphore = Semaphore::const_new(0);
+
+/// Default for `--jobs`.
+const DEFAULT_JOBS: usize = 8;
+
+/// Number of times a failed download is retried, set by `--retries`.
+static RETRIES: AtomicU32 = AtomicU32::new(DEFAULT_RETRIES);
+
+/// Default for `--retries`.
+const DEFAULT_RETRIES: u32 = 3;
+
+/// Delay before the first retry. It doubles with every further attempt.
+const RETRY_DELAY: Duration = Duration::from_secs(1);
+
+/// List of syscall tables for each architecture.
+static SOURCES: LazyLock<Vec<Source<'static>>> = LazyLock::new(|| {
+    vec![
         Source::Table(Table {
             arch: "x86",
             path: "arch/x86/entry/syscalls/syscall_32.tbl",
@@ -48,11 +112,7 @@ lazy_static! {
                 "include/uapi/asm-generic/unistd.h",
                 //"arch/arm64/include/asm/unistd.h",
             ],
-            blocklist: &[
-                // NOTE: On aarch64 platforms, `sync_file_range2` only provides
-                // compatibility for aarch32.
-                "sync_file_range2",
-            ],
+            bits: 64,
         }),
         Source::Table(Table {
             arch: "sparc",
@@ -84,6 +144,14 @@ lazy_static! {
             path: "arch/mips/kernel/syscalls/syscall_n64.tbl",
             abi: &[ABI::N64],
         }),
+        // NOTE: ia64 was removed in Linux 6.7 (see `REMOVED_ARCHS`). Its
+        // tables are still generated for older kernels so that recorded
+        // syscall numbers can be decoded.
+        Source::Table(Table {
+            arch: "ia64",
+            path: "arch/ia64/kernel/syscalls/syscall.tbl",
+            abi: &[ABI::IA64],
+        }),
         Source::Table(Table {
             arch: "s390x",
             path: "arch/s390/kernel/syscalls/syscall.tbl",
@@ -95,12 +163,7 @@ lazy_static! {
                 "include/uapi/asm-generic/unistd.h",
                 "arch/riscv/include/uapi/asm/unistd.h",
             ],
-            blocklist: &[
-                // It doesn't have defines `__NR_sync_file_range2` or
-                // `__ARCH_WANT_SYNC_FILE_RANGE2` in
-                // `arch/riscv/include/uapi/asm/unistd.h` header file
-                "sync_file_range2",
-            ],
+            bits: 32,
         }),
         Source::Header(Header {
             arch: "riscv64",
@@ -108,10 +171,7 @@ lazy_static! {
                 "include/uapi/asm-generic/unistd.h",
                 "arch/riscv/include/uapi/asm/unistd.h",
             ],
-            blocklist: &[
-                // For riscv64, see riscv32's explanation.
-                "sync_file_range2",
-            ],
+            bits: 64,
         }),
         Source::Header(Header {
             arch: "loongarch64",
@@ -119,13 +179,10 @@ lazy_static! {
                 "include/uapi/asm-generic/unistd.h",
                 "arch/loongarch/include/uapi/asm/unistd.h",
             ],
-            blocklist: &[
-                // For loongarch64, see riscv32's explanation.
-                "sync_file_range2",
-            ],
+            bits: 64,
         }),
-    ];
-}
+    ]
+});
 
 pub struct ABI<'a> {
     name: &'a str,
@@ -143,6 +200,7 @@ impl<'a> ABI<'a> {
     pub const B64: Self = Self::new("64", 0);
     pub const O32: Self = Self::new("o32", 4000);
     pub const N64: Self = Self::new("n64", 5000);
+    pub const IA64: Self = Self::new("common", 1024);
 
     #[must_use]
     pub const fn new(name: &'a str, offset: u32) -> Self {
@@ -150,30 +208,245 @@ impl<'a> ABI<'a> {
     }
 }
 
-/// Fetches a file path from the repository.
+/// Architectures that have been removed from the kernel, along with the first
+/// version that no longer contains them.
+static REMOVED_ARCHS: &[(&str, (u32, u32))] = &[("ia64", (6, 7))];
+
+/// Parses a tag such as `v6.10` into `(major, minor)`.
+fn parse_version(version: &str) -> Option<(u32, u32)> {
+    let v = version.strip_prefix('v').unwrap_or(version);
+    let mut parts = v.split(['.', '-']);
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    Some((major, minor))
+}
+
+/// Returns true if `arch` still exists in the given kernel version.
+fn arch_exists_in(arch: &str, version: &str) -> bool {
+    let Some(version) = parse_version(version) else {
+        return true;
+    };
+    REMOVED_ARCHS
+        .iter()
+        .all(|&(name, removed)| name != arch || version < removed)
+}
+
+/// Fetches a file path from the repository, or reads it from the local
+/// kernel tree if one was given.
 async fn fetch_path(path: &str, version: &str) -> Result<String> {
+    fetch_optional_path(path, version)
+        .await?
+        .ok_or_else(|| eyre!("'{path}' does not exist in {version}"))
+}
+
+/// Like [`fetch_path`], but returns `None` if the file does not exist in this
+/// version instead of failing.
+async fn fetch_optional_path(
+    path: &str,
+    version: &str,
+) -> Result<Option<String>> {
+    if let Some(root) = KERNEL_SRC.get() {
+        let file = root.join(path);
+
+        println!("Reading {}", file.display());
+        return match tokio::fs::read_to_string(&file).await {
+            Ok(contents) => Ok(Some(contents)),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err)
+                .wrap_err_with(|| eyre!("Failed to read '{}'", file.display())),
+        };
+    }
+
+    let cached = CACHE_DIR.get().map(|dir| dir.join(version).join(path));
+    if let Some(file) = &cached {
+        if let Ok(contents) = tokio::fs::read_to_string(file).await {
+            println!("Using cached {}", file.display());
+            return Ok(Some(contents));
+        }
+        if missing_marker(file).exists() {
+            return Ok(None);
+        }
+    }
+
+    if OFFLINE.load(Ordering::Relaxed) {
+        bail!("'{path}' ({version}) is not in the cache and --offline is set");
+    }
+
     let url = format!("{LINUX_REPO}/{version}/{path}");
+    let Some(contents) = fetch_url(&url).await? else {
+        if let Some(file) = &cached {
+            // Remember that the file is missing so `--offline` works too.
+            store_cached(&missing_marker(file), "").wrap_err_with(|| {
+                eyre!("Failed to cache '{}'", file.display())
+            })?;
+        }
+        return Ok(None);
+    };
 
-    println!("Fetching {url}");
-    let contents = reqwest::get(&url)
-        .await
-        .wrap_err_with(|| eyre!("Failed to fetch URL '{url}'"))?
-        .text()
-        .await
-        .wrap_err_with(|| eyre!("Failed to parse contents of URL '{url}'"))?;
+    if let Some(file) = &cached {
+        store_cached(file, &contents)
+            .wrap_err_with(|| eyre!("Failed to cache '{}'", file.display()))?;
+    }
+
+    Ok(Some(contents))
+}
+
+/// Downloads `url`, or returns `None` if it does not exist. At most `--jobs`
+/// downloads run at once, and transient failures (network errors, 5xx and
+/// 429 responses) are retried `--retries` times with exponential backoff.
+async fn fetch_url(url: &str) -> Result<Option<String>> {
+    let retries = RETRIES.load(Ordering::Relaxed);
+    let mut delay = RETRY_DELAY;
+    let mut attempt = 0;
+
+    loop {
+        let result = {
+            let _permit = FETCH_PERMITS.acquire().await?;
+            println!("Fetching {url}");
+            try_fetch_url(url).await
+        };
+
+        match result {
+            Ok(contents) => return Ok(contents),
+            Err(err) if attempt < retries && is_transient(&err) => {
+                println!(
+                    "Failed to fetch {url} ({err}), retrying in {delay:?} \
+                     ({}/{retries})",
+                    attempt + 1
+                );
+                tokio::time::sleep(delay).await;
+                delay *= 2;
+                attempt += 1;
+            }
+            Err(err) => {
+                return Err(err)
+                    .wrap_err_with(|| eyre!("Failed to fetch URL '{url}'"));
+            }
+        }
+    }
+}
+
+async fn try_fetch_url(url: &str) -> reqwest::Result<Option<String>> {
+    let response = reqwest::get(url).await?;
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+    response.error_for_status()?.text().await.map(Some)
+}
+
+/// Whether a failed request is worth retrying.
+fn is_transient(err: &reqwest::Error) -> bool {
+    match err.status() {
+        Some(status) => {
+            status.is_server_error()
+                || status == reqwest::StatusCode::TOO_MANY_REQUESTS
+        }
+        None => !err.is_builder(),
+    }
+}
 
-    Ok(contents)
+/// Path of the cache entry recording that `file` does not exist upstream.
+fn missing_marker(file: &Path) -> PathBuf {
+    let mut marker = file.as_os_str().to_owned();
+    marker.push(".missing");
+    PathBuf::from(marker)
 }
 
-fn parse_args() -> (Vec<String>, Option<HashSet<String>>) {
+/// Writes a fetched file to the cache. The file is renamed into place so
+/// that an interrupted run never leaves a truncated entry behind.
+fn store_cached(file: &Path, contents: &str) -> Result<()> {
+    let dir = file
+        .parent()
+        .expect("cache entries have a parent directory");
+    std::fs::create_dir_all(dir)?;
+
+    let mut tmp = tempfile::NamedTempFile::new_in(dir)?;
+    tmp.write_all(contents.as_bytes())?;
+    tmp.persist(file)?;
+    Ok(())
+}
+
+/// Reads the version of a kernel tree from its top-level `Makefile`
+/// (`VERSION` and `PATCHLEVEL`), e.g. `v6.10`.
+fn kernel_src_version(root: &Path) -> Result<String> {
+    let path = root.join("Makefile");
+    let makefile = std::fs::read_to_string(&path)
+        .wrap_err_with(|| eyre!("Failed to read '{}'", path.display()))?;
+
+    let var = |name: &str| {
+        makefile.lines().find_map(|line| {
+            let (key, value) = line.split_once('=')?;
+            (key.trim() == name).then(|| value.trim().to_string())
+        })
+    };
+
+    match (var("VERSION"), var("PATCHLEVEL")) {
+        (Some(major), Some(minor)) => Ok(format!("v{major}.{minor}")),
+        _ => Err(eyre!(
+            "'{}' does not define VERSION and PATCHLEVEL",
+            path.display()
+        )),
+    }
+}
+
+#[allow(clippy::struct_excessive_bools)]
+struct Args {
+    versions: Vec<String>,
+    archs: Option<HashSet<String>>,
+    kernel_src: Option<PathBuf>,
+    from_sysroot: Option<PathBuf>,
+    cache_dir: Option<PathBuf>,
+    offline: bool,
+    signatures: bool,
+    json: Option<PathBuf>,
+    c_header: Option<PathBuf>,
+    check: bool,
+    latest_stable: bool,
+    all_longterm: bool,
+    config: Option<PathBuf>,
+    man_pages: Option<PathBuf>,
+    jobs: usize,
+    retries: u32,
+}
+
+#[allow(clippy::too_many_lines)]
+fn parse_args() -> Args {
     // Simple CLI parser to avoid extra dependencies.
     // Supported flags:
     //   --versions v6.8,v6.10   (comma-separated)
     //   --version v6.10         (repeatable)
+    //   --latest-stable         (add the current stable release)
+    //   --all-longterm          (add every longterm release)
     //   --archs x86_64,aarch64  (comma-separated)
     //   --arch x86_64           (repeatable)
+    //   --kernel-src ../linux   (local kernel tree instead of GitHub)
+    //   --from-sysroot /usr/include (installed headers instead of tables)
+    //   --cache-dir .cache      (reuse fetched files across runs)
+    //   --offline               (only use the cache)
+    //   --jobs 8                (max concurrent downloads)
+    //   --retries 3             (retries for failed downloads)
+    //   --config custom.toml    (per-arch overrides)
+    //   --man-pages ../man-pages (syscall descriptions from a checkout)
+    //   --signatures            (also emit argument metadata)
+    //   --json ../json          (also emit the tables as JSON)
+    //   --c-header ../include   (also emit C headers)
+    //   --check                 (compare with the committed files instead)
     let mut versions: Vec<String> = Vec::new();
     let mut archs: HashSet<String> = HashSet::new();
+    let mut kernel_src: Option<PathBuf> = None;
+    let mut from_sysroot: Option<PathBuf> = None;
+    let mut cache_dir: Option<PathBuf> = None;
+    let mut offline = false;
+    let mut signatures = false;
+    let mut json: Option<PathBuf> = None;
+    let mut c_header: Option<PathBuf> = None;
+    let mut check = false;
+    let mut latest_stable = false;
+    let mut all_longterm = false;
+    let mut config: Option<PathBuf> = None;
+    let mut man_pages: Option<PathBuf> = None;
+    let mut jobs = DEFAULT_JOBS;
+    let mut retries = DEFAULT_RETRIES;
 
     let mut args = std::env::args().skip(1);
     while let Some(arg) = args.next() {
@@ -193,6 +466,8 @@ fn parse_args() -> (Vec<String>, Option<HashSet<String>>) {
                     versions.push(v);
                 }
             }
+            "--latest-stable" => latest_stable = true,
+            "--all-longterm" => all_longterm = true,
             "--archs" => {
                 if let Some(v) = args.next() {
                     for s in v.split(',') {
@@ -208,16 +483,228 @@ fn parse_args() -> (Vec<String>, Option<HashSet<String>>) {
                     archs.insert(v);
                 }
             }
+            "--kernel-src" => {
+                if let Some(v) = args.next() {
+                    kernel_src = Some(PathBuf::from(v));
+                }
+            }
+            "--from-sysroot" => {
+                if let Some(v) = args.next() {
+                    from_sysroot = Some(PathBuf::from(v));
+                }
+            }
+            "--cache-dir" => {
+                if let Some(v) = args.next() {
+                    cache_dir = Some(PathBuf::from(v));
+                }
+            }
+            "--offline" => offline = true,
+            "--jobs" => {
+                if let Some(v) = args.next().and_then(|v| v.parse().ok()) {
+                    jobs = v;
+                }
+            }
+            "--retries" => {
+                if let Some(v) = args.next().and_then(|v| v.parse().ok()) {
+                    retries = v;
+                }
+            }
+            "--config" => {
+                if let Some(v) = args.next() {
+                    config = Some(PathBuf::from(v));
+                }
+            }
+            "--man-pages" => {
+                if let Some(v) = args.next() {
+                    man_pages = Some(PathBuf::from(v));
+                }
+            }
+            "--signatures" => signatures = true,
+            "--json" => {
+                if let Some(v) = args.next() {
+                    json = Some(PathBuf::from(v));
+                }
+            }
+            "--c-header" => {
+                if let Some(v) = args.next() {
+                    c_header = Some(PathBuf::from(v));
+                }
+            }
+            "--check" => check = true,
             _ => {}
         }
     }
 
-    if versions.is_empty() {
-        versions.push(DEFAULT_LINUX_VERSION.to_string());
+    let archs = if archs.is_empty() { None } else { Some(archs) };
+    Args {
+        versions,
+        archs,
+        kernel_src,
+        from_sysroot,
+        cache_dir,
+        offline,
+        signatures,
+        json,
+        c_header,
+        check,
+        latest_stable,
+        all_longterm,
+        config,
+        man_pages,
+        jobs,
+        retries,
     }
+}
 
-    let archs = if archs.is_empty() { None } else { Some(archs) };
-    (versions, archs)
+/// A generation job for one target of a version.
+type Job<'a> = Pin<Box<dyn Future<Output = Result<()>> + 'a>>;
+
+/// The result of the job for `(version, target)`.
+type Outcome = (String, String, Result<()>);
+
+/// The jobs that generate `version`: one per selected arch (its table and
+/// constants), plus the errno table.
+fn version_jobs<'a>(
+    base_dir: &'a Path,
+    version: &'a str,
+    arch_filter: Option<&HashSet<String>>,
+    signatures: bool,
+    json: Option<&'a Path>,
+    c_header: Option<&'a Path>,
+) -> Vec<(&'static str, Job<'a>)> {
+    let mut jobs: Vec<(&str, Job<'a>)> = Vec::new();
+
+    for source in SOURCES.iter() {
+        let arch = source.arch();
+        if let Some(filter) = arch_filter
+            && !filter.contains(arch)
+        {
+            continue;
+        }
+        if !arch_exists_in(arch, version) {
+            println!("Skipping {arch} for {version} (removed from the kernel)");
+            continue;
+        }
+        let table =
+            source.generate(base_dir, version, signatures, json, c_header);
+        // ia64 has no Rust target, so nothing would use its constants. The
+        // constants are read from a kernel tree, which a sysroot isn't.
+        let consts = (arch != "ia64" && SYSROOT.get().is_none())
+            .then(|| consts::generate_consts(base_dir, arch, version));
+        jobs.push((
+            arch,
+            Box::pin(async move {
+                match consts {
+                    Some(consts) => try_join(table, consts).await.map(|_| ()),
+                    None => table.await,
+                }
+            }),
+        ));
+    }
+
+    if SYSROOT.get().is_some() {
+        return jobs;
+    }
+
+    let errno = base_dir.join("src/errno");
+    jobs.push((
+        "errno",
+        Box::pin(errors::generate_errno(errno, version.to_string())),
+    ));
+    let audit = base_dir.join("src/audit");
+    jobs.push((
+        "audit",
+        Box::pin(audit::generate_audit(audit, version.to_string())),
+    ));
+    jobs
+}
+
+/// Runs the jobs of a version concurrently, reporting each as it finishes.
+/// A failing job doesn't stop the others, so that one transient error
+/// doesn't throw away the rest of a long run.
+async fn run_jobs(version: &str, jobs: Vec<(&str, Job<'_>)>) -> Vec<Outcome> {
+    let total = jobs.len();
+    let mut pending: FuturesUnordered<_> = jobs
+        .into_iter()
+        .map(|(target, job)| async move { (target, job.await) })
+        .collect();
+
+    let mut outcomes = Vec::new();
+    while let Some((target, result)) = pending.next().await {
+        let status = if result.is_ok() { "done" } else { "FAILED" };
+        println!(
+            "[{}/{total}] {version} {target}: {status}",
+            outcomes.len() + 1
+        );
+        outcomes.push((version.to_string(), target.to_string(), result));
+    }
+    outcomes
+}
+
+/// Lists which targets succeeded and failed, and fails if any did.
+fn print_summary(outcomes: &[Outcome]) -> Result<()> {
+    println!();
+    println!("Summary:");
+    let mut failed = 0;
+    for (version, target, result) in outcomes {
+        match result {
+            Ok(()) => println!("  ok      {version} {target}"),
+            Err(err) => {
+                failed += 1;
+                println!("  FAILED  {version} {target}: {err:#}");
+            }
+        }
+    }
+    if failed > 0 {
+        bail!("{failed} of {} jobs failed", outcomes.len());
+    }
+    Ok(())
+}
+
+/// Reads the tables from the installed headers in `root` (`--from-sysroot`).
+/// They are those of one kernel, usually for the host only, so the version
+/// is taken from `linux/version.h` and the arch is the host's, unless given.
+fn use_sysroot(
+    root: PathBuf,
+    versions: &mut Vec<String>,
+    arch_filter: &mut Option<HashSet<String>>,
+) -> Result<()> {
+    match versions.len() {
+        0 => versions.push(sysroot::sysroot_version(&root)?),
+        1 => {}
+        _ => bail!("--from-sysroot can only be used with a single version"),
+    }
+    arch_filter.get_or_insert_with(|| {
+        HashSet::from([std::env::consts::ARCH.to_string()])
+    });
+    println!("Using installed headers in {}", root.display());
+    SYSROOT.set(root).expect("sysroot set twice");
+    Ok(())
+}
+
+/// Loads the per-arch overrides (`--config`, or `syscalls-gen.toml` if it
+/// exists) and the syscall descriptions (`--man-pages`, or the bundled ones).
+fn load_config(
+    config: Option<PathBuf>,
+    man_pages: Option<PathBuf>,
+) -> Result<()> {
+    let config = match config {
+        Some(path) => Config::load(&path)?,
+        None if Path::new(DEFAULT_CONFIG).exists() => {
+            Config::load(Path::new(DEFAULT_CONFIG))?
+        }
+        None => Config::default(),
+    };
+    CONFIG.set(config).expect("config set twice");
+
+    let descriptions = match man_pages {
+        Some(root) => Descriptions::from_man_pages(&root)?,
+        None => Descriptions::bundled(),
+    };
+    DESCRIPTIONS
+        .set(descriptions)
+        .expect("descriptions set twice");
+    Ok(())
 }
 
 #[tokio::main]
@@ -226,26 +713,111 @@ async fn main() -> Result<()> {
 
     let base_dir = Path::new("..");
 
-    let (versions, arch_filter) = parse_args();
+    let Args {
+        mut versions,
+        archs: mut arch_filter,
+        kernel_src,
+        from_sysroot,
+        cache_dir,
+        offline,
+        signatures,
+        json,
+        c_header,
+        check,
+        latest_stable,
+        all_longterm,
+        config,
+        man_pages,
+        jobs,
+        retries,
+    } = parse_args();
 
-    for version in &versions {
-        let mut futures: Vec<Pin<Box<dyn Future<Output = Result<()>>>>> =
-            Vec::new();
+    FETCH_PERMITS.add_permits(jobs.max(1));
+    RETRIES.store(retries, Ordering::Relaxed);
 
-        for source in SOURCES.iter() {
-            if let Some(filter) = &arch_filter {
-                if !filter.contains(source.arch()) {
-                    continue;
-                }
+    load_config(config, man_pages)?;
+
+    if latest_stable || all_longterm {
+        if kernel_src.is_some() || from_sysroot.is_some() {
+            bail!(
+                "--latest-stable and --all-longterm can't be used with --kernel-src or --from-sysroot"
+            );
+        }
+        if offline {
+            bail!("--latest-stable and --all-longterm need network access");
+        }
+        for version in
+            releases::discover_versions(latest_stable, all_longterm).await?
+        {
+            if !versions.contains(&version) {
+                versions.push(version);
             }
-            futures.push(Box::pin(source.generate(base_dir, version)));
         }
+    }
 
-        let errno = base_dir.join("src/errno/generated.rs");
-        futures.push(Box::pin(errors::generate_errno(errno, version.clone())));
+    if let Some(root) = from_sysroot {
+        // Signatures and C headers (errno) need kernel sources.
+        if kernel_src.is_some() || c_header.is_some() || signatures {
+            bail!(
+                "--from-sysroot can't be used with --kernel-src, \
+                 --signatures, or --c-header"
+            );
+        }
+        use_sysroot(root, &mut versions, &mut arch_filter)?;
+    } else if let Some(root) = kernel_src {
+        // A local tree holds exactly one kernel version. It is taken from the
+        // tree's Makefile unless given explicitly (e.g. to name a vendor
+        // kernel after the upstream release it is based on).
+        match versions.len() {
+            0 => versions.push(kernel_src_version(&root)?),
+            1 => {}
+            _ => bail!("--kernel-src can only be used with a single version"),
+        }
+        println!("Using kernel source tree {}", root.display());
+        KERNEL_SRC.set(root).expect("kernel source set twice");
+    } else if versions.is_empty() {
+        versions.push(DEFAULT_LINUX_VERSION.to_string());
+    }
 
-        try_join_all(futures).await?;
+    if let Some(dir) = cache_dir {
+        CACHE_DIR.set(dir).expect("cache directory set twice");
+    } else if offline && KERNEL_SRC.get().is_none() && SYSROOT.get().is_none() {
+        bail!("--offline needs a --cache-dir (or --kernel-src) to read from");
     }
+    OFFLINE.store(offline, Ordering::Relaxed);
 
+    // With `--check`, everything is generated into a copy of the crate, which
+    // is then compared with the committed files.
+    if check && (json.is_some() || c_header.is_some()) {
+        bail!("--check can't be used with --json or --c-header");
+    }
+    let scratch = check.then(|| check::scratch_copy(base_dir)).transpose()?;
+    let out_dir = scratch.as_ref().map_or(base_dir, TempDir::path);
+
+    // Outcome of every `(version, target)` job, where a target is an arch or
+    // the shared errno table.
+    let mut outcomes: Vec<Outcome> = Vec::new();
+
+    for version in &versions {
+        let jobs = version_jobs(
+            out_dir,
+            version,
+            arch_filter.as_ref(),
+            signatures,
+            json.as_deref(),
+            c_header.as_deref(),
+        );
+        outcomes.extend(run_jobs(version, jobs).await);
+    }
+
+    print_summary(&outcomes)?;
+
+    diffs::generate_diffs(out_dir, &versions)?;
+    modules::update_modules(out_dir)?;
+    libc_tests::generate_libc_tests(out_dir)?;
+
+    if scratch.is_some() {
+        check::check_generated(base_dir, out_dir)?;
+    }
     Ok(())
 }
@@ -0,0 +1,350 @@
+use crate::{arch_exists_in, parse_version};
+use color_eyre::eyre::{Result, WrapErr, bail, eyre};
+use regex::Regex;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use std::sync::LazyLock;
+
+/// A generated version module, such as `v6_10`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Version(u32, u32);
+
+impl Version {
+    /// Parses a module name such as `v6_10`.
+    fn from_module(module: &str) -> Option<Self> {
+        let (major, minor) = module.strip_prefix('v')?.split_once('_')?;
+        Some(Self(major.parse().ok()?, minor.parse().ok()?))
+    }
+
+    /// Parses a tag such as `v6.10`.
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        parse_version(tag).map(|(major, minor)| Self(major, minor))
+    }
+
+    pub fn tag(self) -> String {
+        format!("v{}.{}", self.0, self.1)
+    }
+
+    pub fn module(self) -> String {
+        format!("v{}_{}", self.0, self.1)
+    }
+
+    fn feature(self) -> String {
+        format!("default_kernel_{}_{}", self.0, self.1)
+    }
+}
+
+/// Rewrites `src/arch/<arch>/mod.rs` for every arch so that it declares and
+/// re-exports exactly the version modules found next to it, and lists every
+/// version in the `default_kernel_*` features of `Cargo.toml` and `build.rs`.
+///
+/// The newest version of each arch is the fallback when no feature is chosen
+/// (for archs removed from the kernel, this is the last one that had them).
+pub fn update_modules(dir: &Path) -> Result<()> {
+    let arch_root = dir.join("src/arch");
+    let archs = arch_versions(&arch_root)?;
+
+    let mut all_versions: Vec<_> = archs
+        .iter()
+        .flat_map(|(_, versions)| versions.iter().copied())
+        .collect();
+    all_versions.sort();
+    all_versions.dedup();
+    let Some(&latest) = all_versions.last() else {
+        bail!("No version modules found in {}", arch_root.display());
+    };
+
+    for (arch, versions) in archs {
+        if versions.is_empty() {
+            continue;
+        }
+        let mod_rs = arch_root.join(&arch).join("mod.rs");
+        // Keep the module documentation, which may be written by hand.
+        let header = match fs::read_to_string(&mod_rs) {
+            Ok(contents) => contents
+                .lines()
+                .take_while(|line| line.starts_with("//!"))
+                .fold(String::new(), |header, line| header + line + "\n"),
+            Err(_) => {
+                format!("//! `{arch}` architecture syscall definitions.\n")
+            }
+        };
+        let diffs = arch_root.join(&arch).join("versions.rs").exists();
+        let contents =
+            header + "\n" + &arch_module(&arch, &versions, latest, diffs);
+        write_if_changed(&mod_rs, &contents)?;
+    }
+
+    let cargo_toml = dir.join("Cargo.toml");
+    let contents = read(&cargo_toml)?;
+    write_if_changed(&cargo_toml, &cargo_features(&contents, &all_versions)?)?;
+
+    let build_rs = dir.join("build.rs");
+    let contents = read(&build_rs)?;
+    write_if_changed(&build_rs, &build_features(&contents, &all_versions)?)?;
+
+    Ok(())
+}
+
+/// Lists the version modules of each arch under `src/arch`, oldest first.
+pub fn arch_versions(arch_root: &Path) -> Result<Vec<(String, Vec<Version>)>> {
+    let mut archs = Vec::new();
+
+    for entry in fs::read_dir(arch_root).wrap_err_with(|| {
+        eyre!("Failed to read directory {}", arch_root.display())
+    })? {
+        let path = entry?.path();
+        if !path.is_dir() {
+            continue;
+        }
+
+        let mut versions = Vec::new();
+        for entry in fs::read_dir(&path)? {
+            let file = entry?.path();
+            if let Some(version) = file
+                .file_name()
+                .and_then(|name| name.to_str()?.strip_suffix(".rs"))
+                .and_then(Version::from_module)
+            {
+                versions.push(version);
+            }
+        }
+        versions.sort();
+        let arch = path.file_name().unwrap().to_string_lossy().into_owned();
+        archs.push((arch, versions));
+    }
+
+    archs.sort();
+    Ok(archs)
+}
+
+/// Extracts the variant names from a generated `vX_Y.rs` table.
+pub fn parse_variants(contents: &str) -> Vec<&str> {
+    static RE_VARIANT: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"(?m)^\s+(\w+) = \d+,$").unwrap());
+
+    RE_VARIANT
+        .captures_iter(contents)
+        .map(|cap| cap.get(1).unwrap().as_str())
+        .collect()
+}
+
+/// Formats a generated file with rustfmt, so that it matches the rest of the
+/// crate.
+pub fn rustfmt(path: &Path) -> Result<()> {
+    let status = Command::new("rustfmt")
+        .args(["--edition", "2024"])
+        .arg(path)
+        .status()
+        .wrap_err("Failed to run rustfmt")?;
+    if !status.success() {
+        bail!("rustfmt failed on {}", path.display());
+    }
+    Ok(())
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path)
+        .wrap_err_with(|| eyre!("Failed to read {}", path.display()))
+}
+
+fn write_if_changed(path: &Path, contents: &str) -> Result<()> {
+    if fs::read_to_string(path).is_ok_and(|old| old == contents) {
+        return Ok(());
+    }
+    fs::write(path, contents)
+        .wrap_err_with(|| eyre!("Failed to write {}", path.display()))?;
+    println!("Updated {}", path.display());
+    Ok(())
+}
+
+/// The body of `src/arch/<arch>/mod.rs` (everything after the docs).
+/// With `diffs`, the `versions` module generated by `--versions` is declared
+/// as well.
+fn arch_module(
+    arch: &str,
+    versions: &[Version],
+    latest: Version,
+    diffs: bool,
+) -> String {
+    let last = *versions.last().unwrap();
+    let supported =
+        arch_exists_in(arch, &format!("v{}.{}", latest.0, latest.1));
+    let mut out = String::new();
+
+    // rustfmt orders module declarations by name.
+    let mut modules: Vec<_> = versions.iter().map(|v| v.module()).collect();
+    if diffs {
+        modules.push("versions".to_string());
+    }
+    modules.sort();
+    for module in &modules {
+        writeln!(out, "pub mod {module};").unwrap();
+    }
+    out.push('\n');
+
+    if supported {
+        writeln!(
+            out,
+            "// Select kernel version by feature; default to latest (v{}.{}).",
+            last.0, last.1
+        )
+        .unwrap();
+    } else {
+        writeln!(
+            out,
+            "// Select kernel version by feature; default to the last version \
+             that still\n// shipped {arch} (v{}.{}).",
+            last.0, last.1
+        )
+        .unwrap();
+    }
+    for version in versions {
+        writeln!(
+            out,
+            "#[cfg(all(not(docsrs), feature = \"{}\"))]",
+            version.feature()
+        )
+        .unwrap();
+        writeln!(out, "pub use {}::*;", version.module()).unwrap();
+    }
+    out.push('\n');
+
+    if supported {
+        out.push_str("// Fallback if no default_kernel_* feature is chosen.\n");
+    } else {
+        writeln!(
+            out,
+            "// Fallback if no default_kernel_* feature is chosen, or if the \
+             chosen kernel\n// no longer supports {arch}."
+        )
+        .unwrap();
+    }
+    out.push_str("#[cfg(all(\n    not(docsrs),\n    not(any(\n");
+    for version in versions {
+        writeln!(out, "        feature = \"{}\",", version.feature()).unwrap();
+    }
+    out.push_str("    ))\n))]\n");
+    writeln!(out, "pub use {}::*;", last.module()).unwrap();
+    out.push('\n');
+
+    out.push_str(
+        "// On docs.rs, avoid enabling multiple versions; always show latest.\n",
+    );
+    out.push_str("#[cfg(docsrs)]\n");
+    writeln!(out, "pub use {}::*;", last.module()).unwrap();
+
+    out
+}
+
+/// Replaces the `default_kernel_*` features in `Cargo.toml`.
+fn cargo_features(contents: &str, versions: &[Version]) -> Result<String> {
+    const START: &str = "# Kernel version selection";
+
+    let latest = versions.last().unwrap();
+    let mut block = format!(
+        "# By default, we target Linux {}.{} across all ISAs.\n",
+        latest.0, latest.1
+    );
+    for version in versions {
+        writeln!(block, "{} = []", version.feature()).unwrap();
+    }
+
+    let Some(start) = contents.find(START) else {
+        bail!("Kernel version features not found in Cargo.toml");
+    };
+    // Keep the first line of the comment.
+    let start = start + contents[start..].find('\n').unwrap() + 1;
+    // The block ends at the first line that is neither a comment nor a
+    // feature.
+    let end = contents[start..]
+        .split_inclusive('\n')
+        .take_while(|line| {
+            line.starts_with("# ") || line.starts_with("default_kernel_")
+        })
+        .map(str::len)
+        .sum::<usize>()
+        + start;
+
+    Ok(format!("{}{block}{}", &contents[..start], &contents[end..]))
+}
+
+/// Replaces the list of `default_kernel_*` features checked by `build.rs`.
+fn build_features(contents: &str, versions: &[Version]) -> Result<String> {
+    const START: &str = "let kernel_features = [\n";
+
+    let Some(start) = contents.find(START).map(|start| start + START.len())
+    else {
+        bail!("`kernel_features` not found in build.rs");
+    };
+    let Some(end) = contents[start..].find("];").map(|end| start + end) else {
+        bail!("End of `kernel_features` not found in build.rs");
+    };
+    // Indentation of the closing bracket.
+    let indent = &contents[contents[..end].rfind('\n').unwrap() + 1..end];
+
+    let mut list = String::new();
+    for version in versions {
+        writeln!(
+            list,
+            "{indent}    \"CARGO_FEATURE_{}\",",
+            version.feature().to_uppercase()
+        )
+        .unwrap();
+    }
+
+    Ok(format!(
+        "{}{list}{indent}{}",
+        &contents[..start],
+        &contents[end..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn features() {
+        let versions = [Version(5, 4), Version(6, 13)];
+
+        let cargo = "\
+thumb-mode = []
+
+# Kernel version selection (pick exactly one).
+# By default, we target Linux 6.12 across all ISAs.
+default_kernel_5_4 = []
+default_kernel_6_12 = []
+
+[dependencies]
+";
+        assert_eq!(
+            cargo_features(cargo, &versions).unwrap(),
+            "\
+thumb-mode = []
+
+# Kernel version selection (pick exactly one).
+# By default, we target Linux 6.13 across all ISAs.
+default_kernel_5_4 = []
+default_kernel_6_13 = []
+
+[dependencies]
+"
+        );
+
+        let build = "    let kernel_features = [
+        \"CARGO_FEATURE_DEFAULT_KERNEL_6_12\",
+    ];
+";
+        assert_eq!(
+            build_features(build, &versions).unwrap(),
+            "    let kernel_features = [
+        \"CARGO_FEATURE_DEFAULT_KERNEL_5_4\",
+        \"CARGO_FEATURE_DEFAULT_KERNEL_6_13\",
+    ];
+"
+        );
+    }
+}
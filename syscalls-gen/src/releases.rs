@@ -0,0 +1,102 @@
+use crate::fetch_url;
+use color_eyre::eyre::{Result, WrapErr, bail, eyre};
+use serde_json::Value;
+
+/// Current releases, as published by kernel.org.
+static RELEASES_URL: &str = "https://www.kernel.org/releases.json";
+
+/// Resolves `--latest-stable` and `--all-longterm` to the tags of the
+/// corresponding mainline releases (e.g. `v6.12` for stable 6.12.4), since
+/// only those are tagged in the repository the files are fetched from.
+pub async fn discover_versions(
+    latest_stable: bool,
+    all_longterm: bool,
+) -> Result<Vec<String>> {
+    let contents = fetch_url(RELEASES_URL)
+        .await?
+        .ok_or_else(|| eyre!("'{RELEASES_URL}' does not exist"))?;
+    let releases: Value = serde_json::from_str(&contents)
+        .wrap_err_with(|| eyre!("Failed to parse '{RELEASES_URL}'"))?;
+
+    let versions = select_versions(&releases, latest_stable, all_longterm)?;
+    println!("Resolved kernel versions: {}", versions.join(", "));
+    Ok(versions)
+}
+
+/// Picks the requested tags out of `releases.json`, oldest first.
+fn select_versions(
+    releases: &Value,
+    latest_stable: bool,
+    all_longterm: bool,
+) -> Result<Vec<String>> {
+    let mut versions = Vec::new();
+
+    if all_longterm {
+        let longterm = releases["releases"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter(|release| release["moniker"] == "longterm")
+            .filter_map(|release| release["version"].as_str());
+        for version in longterm {
+            versions.push(mainline_tag(version)?);
+        }
+        if versions.is_empty() {
+            bail!("No longterm releases found in {RELEASES_URL}");
+        }
+    }
+
+    if latest_stable {
+        let version = releases["latest_stable"]["version"]
+            .as_str()
+            .ok_or_else(|| {
+                eyre!("No latest stable release in {RELEASES_URL}")
+            })?;
+        versions.push(mainline_tag(version)?);
+    }
+
+    versions.sort_by_key(|tag| crate::parse_version(tag));
+    versions.dedup();
+    Ok(versions)
+}
+
+/// Turns a release such as `6.12.4` into its mainline tag, `v6.12`.
+fn mainline_tag(version: &str) -> Result<String> {
+    let mut parts = version.split('.');
+    match (parts.next(), parts.next()) {
+        (Some(major), Some(minor))
+            if major.parse::<u32>().is_ok() && minor.parse::<u32>().is_ok() =>
+        {
+            Ok(format!("v{major}.{minor}"))
+        }
+        _ => bail!("Unexpected kernel release '{version}'"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn selects_versions() {
+        let releases = json!({
+            "latest_stable": { "version": "6.12.4" },
+            "releases": [
+                { "moniker": "mainline", "version": "6.13-rc2" },
+                { "moniker": "stable", "version": "6.12.4" },
+                { "moniker": "longterm", "version": "6.6.64" },
+                { "moniker": "longterm", "version": "6.1.119" },
+                { "moniker": "longterm", "version": "5.10.230" },
+                { "moniker": "linux-next", "version": "next-20241210" },
+            ],
+        });
+
+        assert_eq!(select_versions(&releases, true, false).unwrap(), ["v6.12"]);
+        assert_eq!(
+            select_versions(&releases, true, true).unwrap(),
+            ["v5.10", "v6.1", "v6.6", "v6.12"]
+        );
+        assert!(mainline_tag("next-20241210").is_err());
+    }
+}
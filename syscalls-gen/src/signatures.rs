@@ -0,0 +1,430 @@
+use crate::{KERNEL_SRC, fetch_optional_path};
+use color_eyre::eyre::{Result, WrapErr, eyre};
+use futures::future::try_join_all;
+use regex::Regex;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::sync::LazyLock;
+
+/// Files outside of `arch/` that contain `SYSCALL_DEFINEn` definitions.
+///
+/// Used when fetching from GitHub, where the tree cannot be searched. Files
+/// that don't exist in a given version (because they were added, moved, or
+/// removed) are skipped. With `--kernel-src` the whole tree is searched
+/// instead.
+static GENERIC_SOURCES: &[&str] = &[
+    "block/ioprio.c",
+    "fs/aio.c",
+    "fs/d_path.c",
+    "fs/dcookies.c",
+    "fs/eventfd.c",
+    "fs/eventpoll.c",
+    "fs/exec.c",
+    "fs/fcntl.c",
+    "fs/fhandle.c",
+    "fs/file.c",
+    "fs/filesystems.c",
+    "fs/fsopen.c",
+    "fs/io_uring.c",
+    "fs/ioctl.c",
+    "fs/locks.c",
+    "fs/namei.c",
+    "fs/namespace.c",
+    "fs/notify/fanotify/fanotify_user.c",
+    "fs/notify/inotify/inotify_user.c",
+    "fs/open.c",
+    "fs/pipe.c",
+    "fs/quota/quota.c",
+    "fs/read_write.c",
+    "fs/readdir.c",
+    "fs/select.c",
+    "fs/signalfd.c",
+    "fs/splice.c",
+    "fs/stat.c",
+    "fs/statfs.c",
+    "fs/sync.c",
+    "fs/timerfd.c",
+    "fs/userfaultfd.c",
+    "fs/utimes.c",
+    "fs/xattr.c",
+    "io_uring/io_uring.c",
+    "io_uring/register.c",
+    "ipc/mqueue.c",
+    "ipc/msg.c",
+    "ipc/sem.c",
+    "ipc/shm.c",
+    "ipc/syscall.c",
+    "kernel/acct.c",
+    "kernel/bpf/syscall.c",
+    "kernel/capability.c",
+    "kernel/events/core.c",
+    "kernel/exec_domain.c",
+    "kernel/exit.c",
+    "kernel/fork.c",
+    "kernel/futex.c",
+    "kernel/futex/syscalls.c",
+    "kernel/groups.c",
+    "kernel/kcmp.c",
+    "kernel/kexec.c",
+    "kernel/kexec_file.c",
+    "kernel/module.c",
+    "kernel/module/main.c",
+    "kernel/nsproxy.c",
+    "kernel/pid.c",
+    "kernel/printk/printk.c",
+    "kernel/ptrace.c",
+    "kernel/reboot.c",
+    "kernel/rseq.c",
+    "kernel/sched/core.c",
+    "kernel/sched/membarrier.c",
+    "kernel/sched/syscalls.c",
+    "kernel/seccomp.c",
+    "kernel/signal.c",
+    "kernel/sys.c",
+    "kernel/time/hrtimer.c",
+    "kernel/time/itimer.c",
+    "kernel/time/posix-timers.c",
+    "kernel/time/time.c",
+    "kernel/uid16.c",
+    "mm/fadvise.c",
+    "mm/filemap.c",
+    "mm/madvise.c",
+    "mm/memfd.c",
+    "mm/mempolicy.c",
+    "mm/migrate.c",
+    "mm/mincore.c",
+    "mm/mlock.c",
+    "mm/mmap.c",
+    "mm/mprotect.c",
+    "mm/mremap.c",
+    "mm/mseal.c",
+    "mm/msync.c",
+    "mm/oom_kill.c",
+    "mm/process_vm_access.c",
+    "mm/readahead.c",
+    "mm/secretmem.c",
+    "mm/swapfile.c",
+    "net/socket.c",
+    "security/keys/keyctl.c",
+    "security/landlock/syscalls.c",
+    "security/lsm_syscalls.c",
+];
+
+/// Architecture-specific files with `SYSCALL_DEFINEn` definitions. These
+/// take precedence over the generic ones (e.g. `mmap`).
+fn arch_sources(arch: &str) -> &'static [&'static str] {
+    match arch {
+        "x86" | "x86_64" => &[
+            "arch/x86/kernel/ioport.c",
+            "arch/x86/kernel/ldt.c",
+            "arch/x86/kernel/process_64.c",
+            "arch/x86/kernel/shstk.c",
+            "arch/x86/kernel/signal_32.c",
+            "arch/x86/kernel/signal_64.c",
+            "arch/x86/kernel/sys_ia32.c",
+            "arch/x86/kernel/sys_x86_64.c",
+            "arch/x86/kernel/tls.c",
+            "arch/x86/kernel/vm86_32.c",
+        ],
+        "arm" => &["arch/arm/kernel/sys_oabi-compat.c"],
+        "aarch64" => &["arch/arm64/kernel/signal.c", "arch/arm64/kernel/sys.c"],
+        "sparc" | "sparc64" => &[
+            "arch/sparc/kernel/sys_sparc_32.c",
+            "arch/sparc/kernel/sys_sparc_64.c",
+        ],
+        "powerpc" | "powerpc64" => &[
+            "arch/powerpc/kernel/rtas.c",
+            "arch/powerpc/kernel/signal_32.c",
+            "arch/powerpc/kernel/signal_64.c",
+            "arch/powerpc/kernel/syscalls.c",
+        ],
+        "mips" | "mips64" => {
+            &["arch/mips/kernel/linux32.c", "arch/mips/kernel/syscall.c"]
+        }
+        "ia64" => &["arch/ia64/kernel/sys_ia64.c"],
+        "s390x" => &[
+            "arch/s390/kernel/guarded_storage.c",
+            "arch/s390/kernel/runtime_instr.c",
+            "arch/s390/kernel/signal.c",
+            "arch/s390/kernel/sys_s390.c",
+        ],
+        "riscv32" | "riscv64" => &[
+            "arch/riscv/kernel/signal.c",
+            "arch/riscv/kernel/sys_hwprobe.c",
+            "arch/riscv/kernel/sys_riscv.c",
+        ],
+        "loongarch64" => &[
+            "arch/loongarch/kernel/signal.c",
+            "arch/loongarch/kernel/syscall.c",
+        ],
+        _ => &[],
+    }
+}
+
+/// Directory under `arch/` holding the kernel sources for `arch`.
+pub(crate) fn arch_dir(arch: &str) -> &str {
+    match arch {
+        "x86_64" => "x86",
+        "aarch64" => "arm64",
+        "sparc64" => "sparc",
+        "powerpc64" => "powerpc",
+        "mips64" => "mips",
+        "s390x" => "s390",
+        "riscv32" | "riscv64" => "riscv",
+        "loongarch64" => "loongarch",
+        arch => arch,
+    }
+}
+
+/// A syscall signature from `SYSCALL_DEFINEn(name, type1, arg1, ...)`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Signature {
+    /// `(type, name)` of each argument.
+    pub args: Vec<(String, String)>,
+}
+
+/// Syscall signatures of one architecture, keyed by the name given to
+/// `SYSCALL_DEFINEn`.
+pub struct Signatures(HashMap<String, Signature>);
+
+impl Signatures {
+    /// Finds the signature for a syscall table entry point such as
+    /// `sys_read`, `__x64_sys_read`, or `sys_clone/ptregs`.
+    pub fn get(&self, entry_point: &str) -> Option<&Signature> {
+        let entry_point = entry_point.split('/').next()?;
+        let (_, name) = entry_point.split_once("sys_")?;
+        self.0.get(name)
+    }
+
+    /// Like [`Signatures::get`], but falls back to the definitions that arch
+    /// wrappers forward to when the entry point has none of its own:
+    ///
+    /// - the syscall's own name (`sys_rt_sigreturn_wrapper` for
+    ///   `rt_sigreturn`),
+    /// - the entry point without a `_wrapper` suffix or an arch prefix
+    ///   (`sys_mips_mmap` for `mmap`, `ppc_fadvise64_64` for `fadvise64_64`).
+    pub fn lookup(&self, entry_point: &str, name: &str) -> Option<&Signature> {
+        if let Some(signature) = self.get(entry_point) {
+            return Some(signature);
+        }
+        if let Some(signature) = self.0.get(name) {
+            return Some(signature);
+        }
+
+        let base = entry_point.split('/').next()?;
+        let base = base.split_once("sys_").map_or(base, |(_, base)| base);
+        let base = base.strip_suffix("_wrapper").unwrap_or(base);
+        WRAPPER_PREFIXES
+            .iter()
+            .filter_map(|prefix| base.strip_prefix(prefix))
+            .chain([base])
+            .find_map(|name| self.0.get(name))
+    }
+}
+
+/// Prefixes of arch-specific wrappers around generic syscalls.
+static WRAPPER_PREFIXES: &[&str] = &[
+    "arm64_",
+    "arm_",
+    "ia32_",
+    "ia64_",
+    "loongarch_",
+    "mips_",
+    "ppc32_",
+    "ppc64_",
+    "ppc_",
+    "riscv_",
+    "s390_",
+    "sparc64_",
+    "sparc_",
+    "x86_",
+];
+
+/// Collects the syscall signatures for `arch` from the kernel sources.
+pub async fn fetch_signatures(arch: &str, version: &str) -> Result<Signatures> {
+    let mut signatures = HashMap::new();
+
+    if let Some(root) = KERNEL_SRC.get() {
+        let mut files = Vec::new();
+        find_sources(root, root, arch_dir(arch), &mut files)?;
+        // Parse the arch-specific files last so that they take precedence.
+        files.sort_by(|a, b| {
+            (a.starts_with("arch/"), a).cmp(&(b.starts_with("arch/"), b))
+        });
+
+        for path in files {
+            let file = root.join(&path);
+            let contents = fs::read_to_string(&file).wrap_err_with(|| {
+                eyre!("Failed to read '{}'", file.display())
+            })?;
+            parse_signatures(&contents, &mut signatures);
+        }
+    } else {
+        let paths = GENERIC_SOURCES.iter().chain(arch_sources(arch));
+        let futures: Vec<_> = paths
+            .map(|path| fetch_optional_path(path, version))
+            .collect();
+
+        for contents in try_join_all(futures).await?.into_iter().flatten() {
+            parse_signatures(&contents, &mut signatures);
+        }
+    }
+
+    Ok(Signatures(signatures))
+}
+
+/// Finds the C files of a local kernel tree that may define syscalls for the
+/// architecture in `arch/<arch_dir>`, relative to `root`.
+fn find_sources(
+    root: &Path,
+    dir: &Path,
+    arch_dir: &str,
+    files: &mut Vec<String>,
+) -> Result<()> {
+    let entries = fs::read_dir(dir)
+        .wrap_err_with(|| eyre!("Failed to read '{}'", dir.display()))?;
+
+    for entry in entries {
+        let path = entry?.path();
+        let Ok(relative) = path.strip_prefix(root) else {
+            continue;
+        };
+        let relative = relative.to_string_lossy();
+
+        if path.is_dir() {
+            let skip = match relative.split_once('/') {
+                Some(("arch", sub)) => sub.split('/').next() != Some(arch_dir),
+                None => matches!(
+                    &*relative,
+                    "Documentation" | "samples" | "scripts" | "tools"
+                ),
+                _ => false,
+            };
+            if !skip {
+                find_sources(root, &path, arch_dir, files)?;
+            }
+        } else if relative.ends_with(".c") {
+            files.push(relative.into_owned());
+        }
+    }
+
+    Ok(())
+}
+
+/// Extracts all `SYSCALL_DEFINEn` signatures from a C source file. Later
+/// definitions of the same syscall replace earlier ones.
+fn parse_signatures(contents: &str, out: &mut HashMap<String, Signature>) {
+    // `\b` keeps `COMPAT_SYSCALL_DEFINEn` from matching. The argument list
+    // never contains parentheses, so it ends at the first `)`.
+    static RE_DEFINE: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(r"\bSYSCALL_DEFINE([0-6])\(\s*(\w+)\s*((?:,[^)]*)?)\)")
+            .unwrap()
+    });
+
+    for cap in RE_DEFINE.captures_iter(contents) {
+        let count: usize = cap[1].parse().unwrap();
+        let name = &cap[2];
+
+        let fields: Vec<String> = cap[3]
+            .split(',')
+            .skip(1)
+            .map(|field| field.split_whitespace().collect::<Vec<_>>().join(" "))
+            .collect();
+
+        if fields.len() != count * 2 {
+            // Not a definition (e.g. the macro itself, or a use inside
+            // another macro); ignore it.
+            continue;
+        }
+
+        let args = fields
+            .chunks(2)
+            .map(|pair| (pair[0].clone(), pair[1].clone()))
+            .collect();
+        out.insert(name.to_string(), Signature { args });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_definitions() {
+        let source = r"
+SYSCALL_DEFINE3(read, unsigned int, fd, char __user *, buf, size_t, count)
+{
+}
+
+SYSCALL_DEFINE0(getpid)
+{
+}
+
+COMPAT_SYSCALL_DEFINE3(read, int, fd, char __user *, buf, u32, count)
+{
+}
+
+SYSCALL_DEFINE6(mmap_pgoff, unsigned long, addr, unsigned long, len,
+		unsigned long, prot, unsigned long, flags,
+		unsigned long, fd, unsigned long, pgoff)
+{
+}
+";
+        let mut map = HashMap::new();
+        parse_signatures(source, &mut map);
+        let signatures = Signatures(map);
+
+        let read = signatures.get("sys_read").unwrap();
+        assert_eq!(
+            read.args,
+            [
+                ("unsigned int".into(), "fd".into()),
+                ("char __user *".into(), "buf".into()),
+                ("size_t".into(), "count".into()),
+            ]
+        );
+        assert!(signatures.get("__x64_sys_getpid").unwrap().args.is_empty());
+        assert_eq!(
+            signatures.get("sys_mmap_pgoff/ptregs").unwrap().args[5],
+            ("unsigned long".into(), "pgoff".into())
+        );
+        assert!(signatures.get("sys_ni_syscall").is_none());
+    }
+
+    #[test]
+    fn looks_up_wrappers() {
+        let source = r"
+SYSCALL_DEFINE6(mmap, unsigned long, addr, unsigned long, len,
+		unsigned long, prot, unsigned long, flags,
+		unsigned long, fd, unsigned long, off)
+SYSCALL_DEFINE0(rt_sigreturn)
+SYSCALL_DEFINE4(fadvise64_64, int, fd, loff_t, offset, loff_t, len, int, advice)
+";
+        let mut map = HashMap::new();
+        parse_signatures(source, &mut map);
+        let signatures = Signatures(map);
+
+        assert!(signatures.get("sys_mips_mmap").is_none());
+        assert_eq!(
+            signatures.lookup("sys_mips_mmap", "mmap").unwrap().args[5].1,
+            "off"
+        );
+        assert_eq!(
+            signatures
+                .lookup("sys_rt_sigreturn_wrapper", "rt_sigreturn")
+                .unwrap()
+                .args,
+            []
+        );
+        assert_eq!(
+            signatures
+                .lookup("ppc_fadvise64_64", "fadvise64")
+                .unwrap()
+                .args
+                .len(),
+            4
+        );
+        assert!(signatures.lookup("sys_ni_syscall", "afs_syscall").is_none());
+    }
+}
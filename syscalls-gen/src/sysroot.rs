@@ -0,0 +1,264 @@
+use crate::consts::Preprocessor;
+use crate::tables::TableEntry;
+use color_eyre::eyre::{Result, WrapErr, bail, eyre};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// How an arch's compiler sees the installed headers.
+struct SysrootArch {
+    arch: &'static str,
+    /// Debian multiarch directories that may hold the `asm/` headers, tried
+    /// after `<root>/asm`. The `asm/` headers of an arch cover all of its
+    /// ABIs (`asm/unistd.h` picks one with the predefined macros), so the
+    /// directory of the other word size works too.
+    triplets: &'static [&'static str],
+    /// Macros the compiler predefines that `asm/unistd.h` depends on.
+    macros: &'static [(&'static str, &'static str)],
+}
+
+static ARCHES: &[SysrootArch] = &[
+    SysrootArch {
+        arch: "x86",
+        triplets: &["i386-linux-gnu", "x86_64-linux-gnu"],
+        macros: &[("__i386__", "1")],
+    },
+    SysrootArch {
+        arch: "x86_64",
+        triplets: &["x86_64-linux-gnu", "i386-linux-gnu"],
+        macros: &[("__x86_64__", "1"), ("__LP64__", "1")],
+    },
+    SysrootArch {
+        arch: "arm",
+        triplets: &["arm-linux-gnueabihf", "arm-linux-gnueabi"],
+        macros: &[("__arm__", "1"), ("__ARM_EABI__", "1")],
+    },
+    SysrootArch {
+        arch: "aarch64",
+        triplets: &["aarch64-linux-gnu"],
+        macros: &[("__aarch64__", "1"), ("__LP64__", "1")],
+    },
+    SysrootArch {
+        arch: "sparc",
+        triplets: &["sparc-linux-gnu", "sparc64-linux-gnu"],
+        macros: &[("__sparc__", "1")],
+    },
+    SysrootArch {
+        arch: "sparc64",
+        triplets: &["sparc64-linux-gnu", "sparc-linux-gnu"],
+        macros: &[("__sparc__", "1"), ("__arch64__", "1"), ("__LP64__", "1")],
+    },
+    SysrootArch {
+        arch: "powerpc",
+        triplets: &["powerpc-linux-gnu", "powerpc64-linux-gnu"],
+        macros: &[("__powerpc__", "1")],
+    },
+    SysrootArch {
+        arch: "powerpc64",
+        triplets: &["powerpc64le-linux-gnu", "powerpc64-linux-gnu"],
+        macros: &[
+            ("__powerpc__", "1"),
+            ("__powerpc64__", "1"),
+            ("__LP64__", "1"),
+        ],
+    },
+    SysrootArch {
+        arch: "mips",
+        triplets: &["mips-linux-gnu", "mipsel-linux-gnu"],
+        macros: &[("__mips__", "1"), ("_MIPS_SIM", "_MIPS_SIM_ABI32")],
+    },
+    SysrootArch {
+        arch: "mips64",
+        triplets: &["mips64-linux-gnuabi64", "mips64el-linux-gnuabi64"],
+        macros: &[
+            ("__mips__", "1"),
+            ("__mips64", "1"),
+            ("_MIPS_SIM", "_MIPS_SIM_ABI64"),
+            ("__LP64__", "1"),
+        ],
+    },
+    SysrootArch {
+        arch: "s390x",
+        triplets: &["s390x-linux-gnu"],
+        macros: &[("__s390__", "1"), ("__s390x__", "1"), ("__LP64__", "1")],
+    },
+    SysrootArch {
+        arch: "riscv32",
+        triplets: &["riscv32-linux-gnu"],
+        macros: &[("__riscv", "1"), ("__riscv_xlen", "32")],
+    },
+    SysrootArch {
+        arch: "riscv64",
+        triplets: &["riscv64-linux-gnu"],
+        macros: &[("__riscv", "1"), ("__riscv_xlen", "64"), ("__LP64__", "1")],
+    },
+    SysrootArch {
+        arch: "loongarch64",
+        triplets: &["loongarch64-linux-gnu"],
+        macros: &[
+            ("__loongarch__", "1"),
+            ("__loongarch64", "1"),
+            ("__loongarch_grlen", "64"),
+            ("__LP64__", "1"),
+        ],
+    },
+];
+
+/// Macros predefined for every arch. `_MIPS_SIM_*` normally come from the
+/// compiler too; `__SIZEOF_LONG__` is added per arch.
+static COMMON_MACROS: &[(&str, &str)] = &[
+    ("__linux__", "1"),
+    ("__CHAR_BIT__", "8"),
+    ("_MIPS_SIM_ABI32", "1"),
+    ("_MIPS_SIM_NABI32", "2"),
+    ("_MIPS_SIM_ABI64", "3"),
+];
+
+/// Reads the syscall table of `arch` from the kernel headers installed in
+/// `root` (`--from-sysroot`), by preprocessing `asm/unistd.h` the way the
+/// arch's compiler would and collecting the `__NR_*` numbers. Headers don't
+/// record entry points, so every syscall gets `sys_<name>`.
+pub async fn fetch_table(root: &Path, arch: &str) -> Result<Vec<TableEntry>> {
+    let Some(&SysrootArch {
+        triplets, macros, ..
+    }) = ARCHES.iter().find(|sysroot| sysroot.arch == arch)
+    else {
+        bail!("{arch} is not supported with --from-sysroot");
+    };
+
+    let asm_dirs: Vec<PathBuf> = std::iter::once(root.join("asm"))
+        .chain(
+            triplets
+                .iter()
+                .map(|triplet| root.join(triplet).join("asm")),
+        )
+        .collect();
+    if !asm_dirs.iter().any(|dir| dir.join("unistd.h").exists()) {
+        bail!(
+            "No asm/unistd.h for {arch} in {} (tried {})",
+            root.display(),
+            asm_dirs
+                .iter()
+                .map(|dir| dir.display().to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
+
+    let mut cpp = Preprocessor::installed(root, &asm_dirs);
+    for (name, body) in COMMON_MACROS.iter().chain(macros) {
+        cpp.define(name, body);
+    }
+    let long_size = if macros.iter().any(|(name, _)| *name == "__LP64__") {
+        "8"
+    } else {
+        "4"
+    };
+    cpp.define("__SIZEOF_LONG__", long_size);
+    cpp.include("asm/unistd.h")
+        .await
+        .wrap_err_with(|| eyre!("Failed reading asm/unistd.h for {arch}"))?;
+
+    let mut table = Vec::new();
+    for name in cpp.names() {
+        let Some(syscall) = name.strip_prefix("__NR_") else {
+            continue;
+        };
+        // Also skips markers such as `__NR_Linux` and `__NR_SYSCALL_BASE`.
+        if !syscall
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+            || matches!(syscall, "syscalls" | "arch_specific_syscall")
+        {
+            continue;
+        }
+        let id = cpp
+            .value(name)
+            .and_then(|id| u32::try_from(id).ok())
+            .ok_or_else(|| eyre!("{name} is not a syscall number"))?;
+        table.push(TableEntry {
+            id,
+            name: syscall.to_string(),
+            entry_point: Some(format!("sys_{syscall}")),
+        });
+    }
+    if table.is_empty() {
+        bail!("No syscalls found in asm/unistd.h for {arch}");
+    }
+
+    table.sort();
+    Ok(table)
+}
+
+/// Reads the version of the installed headers from `linux/version.h`, e.g.
+/// `v6.1` for `LINUX_VERSION_CODE 393612` (6.1.140).
+pub fn sysroot_version(root: &Path) -> Result<String> {
+    let path = root.join("linux/version.h");
+    let header = fs::read_to_string(&path)
+        .wrap_err_with(|| eyre!("Failed to read '{}'", path.display()))?;
+
+    let code = header
+        .lines()
+        .find_map(|line| {
+            line.trim()
+                .strip_prefix("#define LINUX_VERSION_CODE")?
+                .trim()
+                .parse::<u32>()
+                .ok()
+        })
+        .ok_or_else(|| {
+            eyre!("'{}' does not define LINUX_VERSION_CODE", path.display())
+        })?;
+    Ok(format!("v{}.{}", code >> 16, (code >> 8) & 0xff))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_installed_headers() {
+        let root = tempfile::tempdir().unwrap();
+        let asm = root.path().join("x86_64-linux-gnu/asm");
+        fs::create_dir_all(&asm).unwrap();
+        fs::write(
+            asm.join("unistd.h"),
+            "#ifndef _ASM_X86_UNISTD_H\n#define _ASM_X86_UNISTD_H\n\
+             #define __X32_SYSCALL_BIT\t0x40000000\n\
+             # ifdef __i386__\n#  include <asm/unistd_32.h>\n\
+             # else\n#  include <asm/unistd_64.h>\n# endif\n#endif\n",
+        )
+        .unwrap();
+        fs::write(
+            asm.join("unistd_64.h"),
+            "#define __NR_read 0\n#define __NR_write 1\n\
+             #define __NR_vendor_call 1000\n",
+        )
+        .unwrap();
+        fs::write(asm.join("unistd_32.h"), "#define __NR_exit 1\n").unwrap();
+        fs::create_dir(root.path().join("linux")).unwrap();
+        fs::write(
+            root.path().join("linux/version.h"),
+            "#define LINUX_VERSION_CODE 393612\n",
+        )
+        .unwrap();
+
+        let entry = |id, name: &str| TableEntry {
+            id,
+            name: name.into(),
+            entry_point: Some(format!("sys_{name}")),
+        };
+        let table =
+            |arch| futures::executor::block_on(fetch_table(root.path(), arch));
+        assert_eq!(
+            table("x86_64").unwrap(),
+            [
+                entry(0, "read"),
+                entry(1, "write"),
+                entry(1000, "vendor_call")
+            ]
+        );
+        assert_eq!(table("x86").unwrap(), [entry(1, "exit")]);
+        assert!(table("aarch64").is_err());
+        assert_eq!(sysroot_version(root.path()).unwrap(), "v6.1");
+    }
+}
@@ -1,12 +1,20 @@
-use crate::{ABI, fetch_path};
+use crate::c_header::CHeader;
+use crate::consts::{Family, read_families};
+use crate::descriptions::Descriptions;
+use crate::errors::errno_table;
+use crate::formats::{Format, classify, flag_table, hex_literal};
+use crate::signatures::{Signatures, fetch_signatures};
+use crate::validate::validate;
+use crate::{ABI, CONFIG, DESCRIPTIONS, SYSROOT, fetch_path, sysroot};
 use color_eyre::eyre::{Result, WrapErr, bail, eyre};
-use lazy_static::lazy_static;
 use regex::Regex;
+use serde_json::{Value, json};
 use std::borrow::Cow;
 use std::fmt;
 use std::fs::{File, create_dir_all};
 use std::io::Write;
 use std::path::Path;
+use std::sync::LazyLock;
 
 pub struct Table<'a> {
     pub arch: &'a str,
@@ -17,7 +25,9 @@ pub struct Table<'a> {
 pub struct Header<'a> {
     pub arch: &'a str,
     pub headers: &'a [&'a str],
-    pub blocklist: &'a [&'a str],
+    /// Word size of the architecture. Picks the entry point of
+    /// `__SC_3264` definitions, which differ between 32- and 64-bit.
+    pub bits: u32,
 }
 
 pub enum Source<'a> {
@@ -35,53 +45,52 @@ pub struct TableEntry {
 }
 
 impl TableEntry {
-    fn ident(&self) -> Cow<str> {
-        // Produce a Rust identifier without using raw id syntax (r#...).
-        // 1) Replace any non [A-Za-z0-9_] with '_'.
-        // 2) If it starts with a digit, prefix with '_'.
-        // 3) If it matches a Rust reserved keyword, append an underscore.
-        let mut out: String = self
-            .name
-            .chars()
-            .map(|c| match c {
-                'a'..='z' | 'A'..='Z' | '0'..='9' | '_' => c,
-                _ => '_',
-            })
-            .collect();
-
-        if out
-            .chars()
-            .next()
-            .map(|c| c.is_ascii_digit())
-            .unwrap_or(false)
-        {
-            out.insert(0, '_');
-        }
+    pub(crate) fn ident(&self) -> Cow<'_, str> {
+        ident(&self.name)
+    }
+}
 
-        // Rust reserved keywords (2018 edition + reserved).
-        const KEYWORDS: &[&str] = &[
-            "as", "break", "const", "continue", "crate", "else", "enum",
-            "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop",
-            "match", "mod", "move", "mut", "pub", "ref", "return", "self",
-            "Self", "static", "struct", "super", "trait", "true", "type",
-            "unsafe", "use", "where", "while", "async", "await", "dyn",
-            "abstract", "become", "box", "do", "final", "macro", "override",
-            "priv", "try", "typeof", "unsized", "virtual", "yield",
-        ];
+/// Turns a syscall name into the name of its `Sysno` variant.
+pub(crate) fn ident(name: &str) -> Cow<'_, str> {
+    // Rust reserved keywords (2018 edition + reserved).
+    const KEYWORDS: &[&str] = &[
+        "as", "break", "const", "continue", "crate", "else", "enum", "extern",
+        "false", "fn", "for", "if", "impl", "in", "let", "loop", "match",
+        "mod", "move", "mut", "pub", "ref", "return", "self", "Self", "static",
+        "struct", "super", "trait", "true", "type", "unsafe", "use", "where",
+        "while", "async", "await", "dyn", "abstract", "become", "box", "do",
+        "final", "macro", "override", "priv", "try", "typeof", "unsized",
+        "virtual", "yield",
+    ];
+
+    // Produce a Rust identifier without using raw id syntax (r#...).
+    // 1) Replace any non [A-Za-z0-9_] with '_'.
+    // 2) If it starts with a digit, prefix with '_'.
+    // 3) If it matches a Rust reserved keyword, append an underscore.
+    let mut out: String = name
+        .chars()
+        .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '_' => c,
+            _ => '_',
+        })
+        .collect();
+
+    if out.chars().next().is_some_and(|c| c.is_ascii_digit()) {
+        out.insert(0, '_');
+    }
 
-        if KEYWORDS.contains(&out.as_str()) {
-            out.push('_');
-        }
+    if KEYWORDS.contains(&out.as_str()) {
+        out.push('_');
+    }
 
-        if out == self.name {
-            Cow::Borrowed(&self.name)
-        } else {
-            Cow::Owned(out)
-        }
+    if out == name {
+        Cow::Borrowed(name)
+    } else {
+        Cow::Owned(out)
     }
 }
 
-impl<'a> Table<'a> {
+impl Table<'_> {
     async fn fetch_table(&self, version: &str) -> Result<Vec<TableEntry>> {
         let contents = fetch_path(self.path, version).await?;
 
@@ -133,13 +142,16 @@ impl<'a> Table<'a> {
     }
 }
 
-impl<'a> Header<'a> {
+impl Header<'_> {
     async fn fetch_table(&self, version: &str) -> Result<Vec<TableEntry>> {
-        lazy_static! {
-            // Pattern for matching the syscall definition.
-            static ref RE_SYSCALLNR: Regex = Regex::new(r"^#define\s+__NR(?:3264)?_([a-z0-9_]+)\s+(\d+)").unwrap();
-            static ref RE_SYSCALLNR_ARCH: Regex = Regex::new(r"^#define\s+__NR(?:3264)?_([a-z0-9_]+)\s+\(__NR_arch_specific_syscall\s*\+\s*(\d+)\)").unwrap();
-        }
+        // Pattern for matching the syscall definition.
+        static RE_SYSCALLNR: LazyLock<Regex> = LazyLock::new(|| {
+            Regex::new(r"^#define\s+__NR(?:3264)?_([a-z0-9_]+)\s+(\d+)")
+                .unwrap()
+        });
+        static RE_SYSCALLNR_ARCH: LazyLock<Regex> = LazyLock::new(|| {
+            Regex::new(r"^#define\s+__NR(?:3264)?_([a-z0-9_]+)\s+\(__NR_arch_specific_syscall\s*\+\s*(\d+)\)").unwrap()
+        });
 
         let mut table = Vec::new();
         let mut arch_specific_syscall: Option<u32> = None;
@@ -173,29 +185,32 @@ impl<'a> Header<'a> {
                         continue;
                     }
 
-                    if self.blocklist.contains(&name) {
-                        continue;
-                    }
-
                     table.push(TableEntry {
                         id,
                         name: name.into(),
                         entry_point: Some(format!("sys_{name}")),
                     });
+                } else if let Some((name, entry_point)) =
+                    header_entry_point(line, self.bits)
+                {
+                    // The `__SYSCALL` line follows the `#define` it refers
+                    // to and names the real entry point (e.g. `sys_umount`
+                    // for `umount2`).
+                    if let Some(entry) =
+                        table.iter_mut().rev().find(|entry| entry.name == name)
+                    {
+                        entry.entry_point = Some(entry_point.into());
+                    }
                 } else if let Some(cap) = RE_SYSCALLNR_ARCH.captures(line) {
                     if let Some(offset) = arch_specific_syscall {
                         let name: &str = cap[1].into();
                         let id: u32 = cap[2].parse()?;
 
-                        if self.blocklist.contains(&name) {
-                            continue;
-                        }
-
                         table.push(TableEntry {
                             id: id + offset,
                             name: name.into(),
                             entry_point: Some(format!("sys_{name}")),
-                        })
+                        });
                     } else {
                         bail!(
                             "__NR_arch_specific_syscall definition not found before usage. \
@@ -213,6 +228,29 @@ impl<'a> Header<'a> {
     }
 }
 
+/// Parses the entry point out of a unistd.h line such as
+/// `__SYSCALL(__NR_umount2, sys_umount)` or
+/// `__SC_3264(__NR3264_lseek, sys_llseek, sys_lseek)`, returning the syscall
+/// name and its entry point. Compat entry points are ignored.
+fn header_entry_point(line: &str, bits: u32) -> Option<(&str, &str)> {
+    static RE_SYSCALL: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(
+            r"^(__SYSCALL|__SC_COMP|__SC_3264|__SC_COMP_3264)\(\s*__NR(?:3264)?_([a-z0-9_]+)\s*,\s*(\w+)\s*(?:,\s*(\w+)\s*)?",
+        )
+        .unwrap()
+    });
+
+    let cap = RE_SYSCALL.captures(line)?;
+    let name = cap.get(2)?.as_str();
+    let entry_point = match (&cap[1], cap.get(4)) {
+        ("__SC_3264" | "__SC_COMP_3264", Some(entry_64)) if bits == 64 => {
+            entry_64.as_str()
+        }
+        _ => cap.get(3)?.as_str(),
+    };
+    Some((name, entry_point))
+}
+
 impl<'a> Source<'a> {
     pub fn arch(&self) -> &'a str {
         match self {
@@ -222,28 +260,86 @@ impl<'a> Source<'a> {
     }
 
     async fn fetch_table(&self, version: &str) -> Result<Vec<TableEntry>> {
+        if let Some(root) = SYSROOT.get() {
+            return sysroot::fetch_table(root, self.arch()).await;
+        }
         match self {
             Self::Table(table) => table.fetch_table(version).await,
             Self::Header(header) => header.fetch_table(version).await,
         }
     }
 
+    /// Validates a fetched table and prints the report, failing if the table
+    /// looks broken.
+    fn validate(
+        &self,
+        dir: &Path,
+        version: &str,
+        table: &[TableEntry],
+    ) -> Result<()> {
+        let arch = self.arch();
+        let offset = match self {
+            Self::Table(table) => {
+                table.abi.iter().map(|abi| abi.offset).min().unwrap_or(0)
+            }
+            Self::Header(_) => 0,
+        };
+        let expected_removals = CONFIG.get().map_or_else(Vec::new, |config| {
+            config.expected_removals(arch, version)
+        });
+
+        let report =
+            validate(dir, arch, version, offset, table, &expected_removals)?;
+        println!("{report}");
+        if !report.is_ok() {
+            bail!(
+                "{} problem(s) in the {arch} {version} table",
+                report.problems()
+            );
+        }
+        Ok(())
+    }
+
     fn version_to_module(version: &str) -> String {
         let v = version.strip_prefix('v').unwrap_or(version);
         format!("v{}", v.replace('.', "_"))
     }
 
     /// Generates the source file for a specific arch and kernel version.
+    /// With `signatures`, argument metadata from the kernel's
+    /// `SYSCALL_DEFINEn` definitions is appended. With `json`, the same data
+    /// is also written to `<json>/syscalls-<arch>-<version>.json`. With
+    /// `c_header`, the numbers and the arch's error codes are written to
+    /// `<c_header>/rawsys_linux_<arch>.h`.
     pub(crate) async fn generate(
         &self,
         dir: &Path,
         version: &str,
+        signatures: bool,
+        json: Option<&Path>,
+        c_header: Option<&Path>,
     ) -> Result<()> {
         let arch = self.arch();
-        let table = self
+        let mut table = self
             .fetch_table(version)
             .await
             .wrap_err_with(|| eyre!("Failed fetching table for {arch}"))?;
+        if let Some(config) = CONFIG.get() {
+            config.apply(arch, version, &mut table)?;
+        }
+        self.validate(dir, version, &table)?;
+        let signatures = if signatures {
+            let signatures =
+                fetch_signatures(arch, version).await.wrap_err_with(|| {
+                    eyre!("Failed fetching signatures for {arch}")
+                })?;
+            let families = read_families(arch, version)
+                .await
+                .wrap_err_with(|| eyre!("Failed reading flags for {arch}"))?;
+            Some((signatures, families))
+        } else {
+            None
+        };
 
         // Generate `src/arch/{arch}/vX_Y.rs`
         let module = Self::version_to_module(version);
@@ -260,19 +356,143 @@ impl<'a> Source<'a> {
             file,
             "//! Syscalls for the `{arch}` architecture (Linux {version}).\n"
         )?;
-        write!(file, "{}", SyscallFile(&table))?;
+        write!(
+            file,
+            "{}",
+            SyscallFile(
+                &table,
+                DESCRIPTIONS.get().unwrap(),
+                signatures.is_some()
+            )
+        )?;
+        if let Some((signatures, families)) = &signatures {
+            write!(file, "{}", SignatureTables(&table, signatures, families))?;
+        }
 
         println!(
             "Generated syscalls for {arch} {version} at {}",
             path.display()
         );
+
+        if let Some(json_dir) = json {
+            create_dir_all(json_dir).wrap_err_with(|| {
+                eyre!("Failed to create directory {}", json_dir.display())
+            })?;
+            let path = json_dir.join(format!("syscalls-{arch}-{version}.json"));
+            let value = json_table(
+                arch,
+                version,
+                &table,
+                signatures.as_ref().map(|(signatures, _)| signatures),
+            );
+            let mut file = File::create(&path).wrap_err_with(|| {
+                eyre!("Failed to create file {}", path.display())
+            })?;
+            serde_json::to_writer_pretty(&mut file, &value)?;
+            writeln!(file)?;
+
+            println!(
+                "Generated JSON for {arch} {version} at {}",
+                path.display()
+            );
+        }
+
+        if let Some(header_dir) = c_header {
+            let errnos =
+                errno_table(arch, version).await.wrap_err_with(|| {
+                    eyre!("Failed fetching error codes for {arch}")
+                })?;
+            create_dir_all(header_dir).wrap_err_with(|| {
+                eyre!("Failed to create directory {}", header_dir.display())
+            })?;
+            let path = header_dir.join(format!("rawsys_linux_{arch}.h"));
+            let mut file = File::create(&path).wrap_err_with(|| {
+                eyre!("Failed to create file {}", path.display())
+            })?;
+            let header = CHeader {
+                arch,
+                version,
+                syscalls: &table,
+                errnos: &errnos,
+            };
+            write!(file, "{header}")?;
+
+            println!(
+                "Generated C header for {arch} {version} at {}",
+                path.display()
+            );
+        }
+
         Ok(())
     }
 }
 
-struct SyscallFile<'a>(&'a [TableEntry]);
+/// Builds the JSON form of a syscall table:
+///
+/// ```json
+/// {
+///   "arch": "x86_64",
+///   "version": "v6.10",
+///   "syscalls": [
+///     {
+///       "id": 0,
+///       "name": "read",
+///       "entry_point": "sys_read",
+///       "args": [{ "type": "unsigned int", "name": "fd" }, ...]
+///     },
+///     ...
+///   ]
+/// }
+/// ```
+///
+/// `entry_point` is `null` for syscalls that are not implemented. `args` is
+/// only present when signatures were collected, and is `null` for syscalls
+/// without a known `SYSCALL_DEFINEn` definition. Unlike the Rust tables,
+/// gaps in the numbering are not filled.
+fn json_table(
+    arch: &str,
+    version: &str,
+    table: &[TableEntry],
+    signatures: Option<&Signatures>,
+) -> Value {
+    let syscalls: Vec<Value> = table
+        .iter()
+        .map(|entry| {
+            let mut syscall = json!({
+                "id": entry.id,
+                "name": entry.name,
+                "entry_point": entry.entry_point,
+            });
+            if let Some(signatures) = signatures {
+                let args = entry
+                    .entry_point
+                    .as_deref()
+                    .and_then(|entry_point| signatures.lookup(entry_point, &entry.name))
+                    .map(|signature| {
+                        signature
+                            .args
+                            .iter()
+                            .map(|(ty, name)| json!({ "type": ty, "name": name }))
+                            .collect::<Vec<_>>()
+                    });
+                syscall["args"] = json!(args);
+            }
+            syscall
+        })
+        .collect();
+
+    json!({
+        "arch": arch,
+        "version": version,
+        "syscalls": syscalls,
+    })
+}
+
+/// A table, documented with `Descriptions`. The `bool` tells whether a
+/// `signatures` module (see [`SignatureTables`]) follows it.
+struct SyscallFile<'a>(&'a [TableEntry], &'a Descriptions, bool);
 
-impl<'a> fmt::Display for SyscallFile<'a> {
+impl fmt::Display for SyscallFile<'_> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         writeln!(f, "// This file is automatically generated. Do not edit!")?;
         writeln!(f)?;
@@ -281,6 +501,10 @@ impl<'a> fmt::Display for SyscallFile<'a> {
         writeln!(f, "    pub enum Sysno {{")?;
         for entry in self.0 {
             if entry.entry_point.is_some() {
+                if let Some(description) = self.1.get(&entry.name) {
+                    writeln!(f, "        /// {description}")?;
+                    writeln!(f, "        ///")?;
+                }
                 writeln!(
                     f,
                     "        /// See [{name}(2)](https://man7.org/linux/man-pages/man2/{name}.2.html) for more info on this syscall.",
@@ -313,8 +537,254 @@ impl<'a> fmt::Display for SyscallFile<'a> {
         }
         writeln!(f, "    }}")?;
         writeln!(f, "    LAST: {};", self.0.last().unwrap().ident())?;
+        if self.2 {
+            writeln!(f, "    SIGNATURES: signatures;")?;
+        }
         writeln!(f, "}}")?;
 
         Ok(())
     }
 }
+
+/// Argument metadata for a syscall table, indexed like the table itself
+/// (`id - first id`) so that gaps stay gaps. The constant families provide
+/// the names of flags.
+struct SignatureTables<'a>(&'a [TableEntry], &'a Signatures, &'a [Family]);
+
+impl SignatureTables<'_> {
+    /// Returns the name and signature of each slot of the table.
+    fn slots(
+        &self,
+    ) -> Vec<Option<(&str, Option<&crate::signatures::Signature>)>> {
+        let first = self.0.first().map_or(0, |entry| entry.id);
+        let last = self.0.last().map_or(0, |entry| entry.id);
+        let mut slots = vec![None; (last - first + 1) as usize];
+
+        for entry in self.0 {
+            let signature =
+                entry.entry_point.as_deref().and_then(|entry_point| {
+                    self.1.lookup(entry_point, &entry.name)
+                });
+            slots[(entry.id - first) as usize] =
+                Some((entry.name.as_str(), signature));
+        }
+
+        slots
+    }
+}
+
+impl fmt::Display for SignatureTables<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let slots = self.slots();
+        let len = slots.len();
+
+        writeln!(f)?;
+        writeln!(
+            f,
+            "/// Syscall signatures from the kernel's `SYSCALL_DEFINEn` definitions,"
+        )?;
+        writeln!(f, "/// indexed by `id - Sysno::first().id()`.")?;
+        writeln!(f, "pub(crate) mod signatures {{")?;
+
+        writeln!(
+            f,
+            "    /// Number of arguments, or `None` for gaps in the table and syscalls"
+        )?;
+        writeln!(f, "    /// without a known definition.")?;
+        writeln!(
+            f,
+            "    pub(crate) static ARG_COUNTS: [Option<u8>; {len}] = ["
+        )?;
+        for slot in &slots {
+            match slot {
+                Some((name, Some(signature))) => writeln!(
+                    f,
+                    "        Some({}), // {name}",
+                    signature.args.len()
+                )?,
+                Some((name, None)) => writeln!(f, "        None, // {name}")?,
+                None => writeln!(f, "        None,")?,
+            }
+        }
+        writeln!(f, "    ];")?;
+
+        let columns = [
+            (
+                "ARG_TYPES",
+                "Argument types, as written in the kernel source.",
+            ),
+            ("ARG_NAMES", "Argument names."),
+        ];
+        for (table, doc) in columns {
+            writeln!(f)?;
+            writeln!(f, "    /// {doc}")?;
+            if table == "ARG_TYPES" {
+                // Only used to derive `ARG_FORMATS` for now.
+                writeln!(f, "    #[allow(dead_code)]")?;
+            }
+            writeln!(f, "    pub(crate) static {table}: [&[&str]; {len}] = [")?;
+            for slot in &slots {
+                let Some((name, signature)) = slot else {
+                    writeln!(f, "        &[],")?;
+                    continue;
+                };
+                let values: Vec<String> = signature
+                    .iter()
+                    .flat_map(|signature| &signature.args)
+                    .map(|(ty, name)| {
+                        let value =
+                            if table == "ARG_TYPES" { ty } else { name };
+                        format!("{value:?}")
+                    })
+                    .collect();
+                writeln!(f, "        &[{}], // {name}", values.join(", "))?;
+            }
+            writeln!(f, "    ];")?;
+        }
+
+        self.fmt_formats(f, &slots)?;
+
+        writeln!(f, "}}")?;
+
+        Ok(())
+    }
+}
+
+impl SignatureTables<'_> {
+    /// Writes `ARG_FORMATS`, followed by the flag tables it refers to.
+    fn fmt_formats(
+        &self,
+        f: &mut fmt::Formatter,
+        slots: &[Option<(&str, Option<&crate::signatures::Signature>)>],
+    ) -> fmt::Result {
+        let rows: Vec<Option<(&str, Vec<Format>)>> = slots
+            .iter()
+            .map(|slot| {
+                let (name, signature) = (*slot)?;
+                let formats = signature
+                    .iter()
+                    .flat_map(|signature| &signature.args)
+                    .map(|(ty, arg)| classify(name, ty, arg))
+                    .collect();
+                Some((name, formats))
+            })
+            .collect();
+        let formats = || rows.iter().flatten().flat_map(|(_, row)| row);
+
+        let mut variants: Vec<&str> = formats().map(Format::variant).collect();
+        variants.sort_unstable();
+        variants.dedup();
+        let mut used: Vec<&str> = formats()
+            .filter_map(|format| match format {
+                Format::Flags(prefix) => Some(*prefix),
+                _ => None,
+            })
+            .collect();
+        used.sort_unstable();
+        used.dedup();
+
+        writeln!(f)?;
+        writeln!(
+            f,
+            "    use crate::format::ArgFormat::{{self, {}}};",
+            variants.join(", ")
+        )?;
+        writeln!(f)?;
+        writeln!(f, "    /// How arguments are shown by `format::Call`.")?;
+        writeln!(
+            f,
+            "    pub(crate) static ARG_FORMATS: [&[ArgFormat]; {}] = [",
+            slots.len()
+        )?;
+        for row in &rows {
+            let Some((name, row)) = row else {
+                writeln!(f, "        &[],")?;
+                continue;
+            };
+            let row: Vec<String> =
+                row.iter().map(ToString::to_string).collect();
+            writeln!(f, "        &[{}], // {name}", row.join(", "))?;
+        }
+        writeln!(f, "    ];")?;
+
+        for family in self.2 {
+            if !used.contains(&family.prefix) {
+                continue;
+            }
+            writeln!(f)?;
+            writeln!(
+                f,
+                "    static {}: &[(&str, u64)] = &[",
+                Format::flag_table(family.prefix)
+            )?;
+            for (name, value) in flag_table(family) {
+                writeln!(f, "        ({name:?}, {}),", hex_literal(value))?;
+            }
+            writeln!(f, "    ];")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn header_entry_points() {
+        assert_eq!(
+            header_entry_point("__SYSCALL(__NR_umount2, sys_umount)", 64),
+            Some(("umount2", "sys_umount"))
+        );
+        assert_eq!(
+            header_entry_point(
+                "__SC_COMP(__NR_io_setup, sys_io_setup, compat_sys_io_setup)",
+                64
+            ),
+            Some(("io_setup", "sys_io_setup"))
+        );
+        let lseek = "__SC_3264(__NR3264_lseek, sys_llseek, sys_lseek)";
+        assert_eq!(
+            header_entry_point(lseek, 32),
+            Some(("lseek", "sys_llseek"))
+        );
+        assert_eq!(header_entry_point(lseek, 64), Some(("lseek", "sys_lseek")));
+        assert_eq!(
+            header_entry_point(
+                "__SC_COMP_3264(__NR3264_fcntl, sys_fcntl64, sys_fcntl, \
+                 compat_sys_fcntl64)",
+                64
+            ),
+            Some(("fcntl", "sys_fcntl"))
+        );
+        assert_eq!(header_entry_point("#define __NR_read 63", 64), None);
+    }
+
+    #[test]
+    fn json_entries() {
+        let table = [
+            TableEntry {
+                id: 0,
+                name: "read".into(),
+                entry_point: Some("sys_read".into()),
+            },
+            TableEntry {
+                id: 3,
+                name: "afs_syscall".into(),
+                entry_point: None,
+            },
+        ];
+
+        let value = json_table("x86_64", "v6.10", &table, None);
+        assert_eq!(value["arch"], "x86_64");
+        assert_eq!(value["version"], "v6.10");
+        assert_eq!(
+            value["syscalls"],
+            json!([
+                { "id": 0, "name": "read", "entry_point": "sys_read" },
+                { "id": 3, "name": "afs_syscall", "entry_point": null },
+            ])
+        );
+    }
+}
@@ -0,0 +1,292 @@
+use crate::modules::{Version, arch_versions, parse_variants};
+use crate::tables::{TableEntry, ident};
+use color_eyre::eyre::{Result, WrapErr, eyre};
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::ops::Range;
+use std::path::Path;
+
+/// Ids of a table must lie in this many numbers above the ABI offset.
+/// Tables are far smaller, so an id past this means the offset was applied
+/// twice or a number was misread.
+const MAX_SYSCALLS: u32 = 1000;
+
+/// The outcome of validating a fetched table before it is written.
+#[derive(Debug)]
+pub struct Report {
+    arch: String,
+    version: String,
+    syscalls: usize,
+    ids: Option<(u32, u32)>,
+    /// The version the table was compared with, and the syscalls it had
+    /// that are expected to be gone.
+    previous: Option<(Version, Vec<String>)>,
+    problems: Vec<String>,
+}
+
+impl Report {
+    pub fn is_ok(&self) -> bool {
+        self.problems.is_empty()
+    }
+
+    pub fn problems(&self) -> usize {
+        self.problems.len()
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Self { arch, version, .. } = self;
+        if !self.is_ok() {
+            write!(f, "Validation of the {arch} {version} table failed:")?;
+            for problem in &self.problems {
+                write!(f, "\n  - {problem}")?;
+            }
+            return Ok(());
+        }
+
+        write!(f, "Validated {arch} {version}: {} syscalls", self.syscalls)?;
+        if let Some((first, last)) = self.ids {
+            write!(f, ", ids {first}..={last}")?;
+        }
+        match &self.previous {
+            Some((previous, removed)) if removed.is_empty() => {
+                write!(f, ", none removed since {}", previous.tag())
+            }
+            Some((previous, removed)) => write!(
+                f,
+                ", {} removed since {} as expected",
+                removed.join(", "),
+                previous.tag()
+            ),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Checks a fetched table (after the config is applied) for signs of a
+/// broken source or parser:
+///
+/// - every id and every name is unique,
+/// - every id is in `offset..offset + MAX_SYSCALLS`, so the ABI offset was
+///   applied exactly once,
+/// - every syscall of the newest table generated for an earlier version in
+///   `dir` is still there, unless it is listed in `expected_removals`.
+pub fn validate(
+    dir: &Path,
+    arch: &str,
+    version: &str,
+    offset: u32,
+    table: &[TableEntry],
+    expected_removals: &[&str],
+) -> Result<Report> {
+    let mut problems = check_table(table, offset..offset + MAX_SYSCALLS);
+
+    let previous = match previous_table(dir, arch, version)? {
+        Some((previous, variants)) => {
+            let (expected, unexpected) =
+                removals(table, &variants, expected_removals);
+            if !unexpected.is_empty() {
+                problems.push(format!(
+                    "removed since {} without being listed in `removed` of \
+                     the config: {}",
+                    previous.tag(),
+                    unexpected.join(", ")
+                ));
+            }
+            Some((previous, expected))
+        }
+        None => None,
+    };
+    if table.is_empty() {
+        problems.push("the table is empty".into());
+    }
+
+    Ok(Report {
+        arch: arch.into(),
+        version: version.into(),
+        syscalls: table.len(),
+        ids: table.first().zip(table.last()).map(|(a, b)| (a.id, b.id)),
+        previous,
+        problems,
+    })
+}
+
+/// Finds duplicate ids and names, and ids outside of `range`.
+fn check_table(table: &[TableEntry], range: Range<u32>) -> Vec<String> {
+    let mut problems = Vec::new();
+
+    let mut ids: HashMap<u32, Vec<&str>> = HashMap::new();
+    let mut names: HashMap<&str, Vec<u32>> = HashMap::new();
+    for entry in table {
+        ids.entry(entry.id).or_default().push(&entry.name);
+        names.entry(&entry.name).or_default().push(entry.id);
+        if !range.contains(&entry.id) {
+            problems.push(format!(
+                "{} = {} is outside of {}..{} (ABI offset not applied, or \
+                 applied twice?)",
+                entry.name, entry.id, range.start, range.end
+            ));
+        }
+    }
+
+    let mut duplicates: Vec<String> = ids
+        .into_iter()
+        .filter(|(_, names)| names.len() > 1)
+        .map(|(id, names)| format!("id {id} is used by {}", names.join(", ")))
+        .chain(names.into_iter().filter(|(_, ids)| ids.len() > 1).map(
+            |(name, ids)| {
+                let ids: Vec<String> =
+                    ids.iter().map(ToString::to_string).collect();
+                format!("{name} has several ids: {}", ids.join(", "))
+            },
+        ))
+        .collect();
+    duplicates.sort();
+    problems.extend(duplicates);
+    problems
+}
+
+/// Splits the `previous` variants missing from `table` into the expected
+/// and the unexpected removals.
+fn removals(
+    table: &[TableEntry],
+    previous: &[String],
+    expected_removals: &[&str],
+) -> (Vec<String>, Vec<String>) {
+    let current: Vec<_> = table.iter().map(TableEntry::ident).collect();
+    let expected: Vec<_> =
+        expected_removals.iter().map(|name| ident(name)).collect();
+
+    previous
+        .iter()
+        .filter(|variant| !current.iter().any(|name| name == *variant))
+        .cloned()
+        .partition(|variant| expected.iter().any(|name| name == variant))
+}
+
+/// Reads the variants of the newest table of `arch` in `dir` for a version
+/// before `version`.
+fn previous_table(
+    dir: &Path,
+    arch: &str,
+    version: &str,
+) -> Result<Option<(Version, Vec<String>)>> {
+    let arch_root = dir.join("src/arch");
+    let Some(current) = Version::from_tag(version) else {
+        return Ok(None);
+    };
+    if !arch_root.exists() {
+        return Ok(None);
+    }
+
+    let Some(previous) = arch_versions(&arch_root)?
+        .into_iter()
+        .find(|(name, _)| name == arch)
+        .and_then(|(_, versions)| versions.into_iter().rfind(|v| *v < current))
+    else {
+        return Ok(None);
+    };
+
+    let path = arch_root
+        .join(arch)
+        .join(format!("{}.rs", previous.module()));
+    let contents = fs::read_to_string(&path)
+        .wrap_err_with(|| eyre!("Failed to read {}", path.display()))?;
+    let variants = parse_variants(&contents)
+        .into_iter()
+        .map(String::from)
+        .collect();
+    Ok(Some((previous, variants)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fmt::Write as _;
+
+    fn entry(id: u32, name: &str) -> TableEntry {
+        TableEntry {
+            id,
+            name: name.into(),
+            entry_point: Some(format!("sys_{name}")),
+        }
+    }
+
+    #[test]
+    fn finds_anomalies() {
+        let table = [
+            entry(4000, "read"),
+            entry(4001, "write"),
+            entry(4001, "open"),
+            entry(4002, "write"),
+            entry(5003, "close"),
+        ];
+        assert_eq!(
+            check_table(&table, 4000..5000),
+            [
+                "close = 5003 is outside of 4000..5000 (ABI offset not \
+                 applied, or applied twice?)",
+                "id 4001 is used by write, open",
+                "write has several ids: 4001, 4002",
+            ]
+        );
+        assert!(check_table(&table[..2], 4000..5000).is_empty());
+    }
+
+    #[test]
+    fn compares_with_previous_version() {
+        let dir = tempfile::tempdir().unwrap();
+        let arch_dir = dir.path().join("src/arch/riscv64");
+        fs::create_dir_all(&arch_dir).unwrap();
+        let module = |variants: &[&str]| {
+            let mut module = String::from("    pub enum Sysno {\n");
+            for (id, name) in variants.iter().enumerate() {
+                writeln!(module, "        {name} = {id},").unwrap();
+            }
+            module + "    }\n"
+        };
+        fs::write(arch_dir.join("v6_6.rs"), module(&["read", "old"])).unwrap();
+        fs::write(
+            arch_dir.join("v6_10.rs"),
+            module(&["read", "write", "riscv_hwprobe", "yield_"]),
+        )
+        .unwrap();
+        fs::write(arch_dir.join("v6_14.rs"), module(&["gone"])).unwrap();
+
+        let table = [entry(0, "read"), entry(1, "yield")];
+        let report =
+            validate(dir.path(), "riscv64", "v6.12", 0, &table, &["write"])
+                .unwrap();
+        assert!(!report.is_ok());
+        assert_eq!(
+            report.to_string(),
+            "Validation of the riscv64 v6.12 table failed:\n  - removed \
+             since v6.10 without being listed in `removed` of the config: \
+             riscv_hwprobe"
+        );
+
+        let report = validate(
+            dir.path(),
+            "riscv64",
+            "v6.12",
+            0,
+            &table,
+            &["write", "riscv_hwprobe"],
+        )
+        .unwrap();
+        assert_eq!(
+            report.to_string(),
+            "Validated riscv64 v6.12: 2 syscalls, ids 0..=1, write, \
+             riscv_hwprobe removed since v6.10 as expected"
+        );
+
+        let report =
+            validate(dir.path(), "riscv64", "v6.6", 0, &table, &[]).unwrap();
+        assert_eq!(
+            report.to_string(),
+            "Validated riscv64 v6.6: 2 syscalls, ids 0..=1"
+        );
+    }
+}
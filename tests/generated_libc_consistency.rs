@@ -0,0 +1,9454 @@
+// This file is automatically generated by syscalls-gen. Do not edit!
+//
+// Checks the syscall tables against the `SYS_*` constants of libc 0.2.190.
+
+#![cfg(all(target_os = "linux", target_env = "gnu"))]
+
+#[cfg(all(target_arch = "aarch64", target_pointer_width = "64"))]
+#[test]
+fn aarch64() {
+    use rawsys_linux::aarch64::v6_12::Sysno;
+
+    assert_eq!(Sysno::accept.id() as libc::c_long, libc::SYS_accept);
+    assert_eq!(Sysno::accept4.id() as libc::c_long, libc::SYS_accept4);
+    assert_eq!(Sysno::acct.id() as libc::c_long, libc::SYS_acct);
+    assert_eq!(Sysno::add_key.id() as libc::c_long, libc::SYS_add_key);
+    assert_eq!(Sysno::adjtimex.id() as libc::c_long, libc::SYS_adjtimex);
+    assert_eq!(Sysno::bind.id() as libc::c_long, libc::SYS_bind);
+    assert_eq!(Sysno::bpf.id() as libc::c_long, libc::SYS_bpf);
+    assert_eq!(Sysno::brk.id() as libc::c_long, libc::SYS_brk);
+    assert_eq!(Sysno::capget.id() as libc::c_long, libc::SYS_capget);
+    assert_eq!(Sysno::capset.id() as libc::c_long, libc::SYS_capset);
+    assert_eq!(Sysno::chdir.id() as libc::c_long, libc::SYS_chdir);
+    assert_eq!(Sysno::chroot.id() as libc::c_long, libc::SYS_chroot);
+    assert_eq!(
+        Sysno::clock_adjtime.id() as libc::c_long,
+        libc::SYS_clock_adjtime
+    );
+    assert_eq!(
+        Sysno::clock_getres.id() as libc::c_long,
+        libc::SYS_clock_getres
+    );
+    assert_eq!(
+        Sysno::clock_gettime.id() as libc::c_long,
+        libc::SYS_clock_gettime
+    );
+    assert_eq!(
+        Sysno::clock_nanosleep.id() as libc::c_long,
+        libc::SYS_clock_nanosleep
+    );
+    assert_eq!(
+        Sysno::clock_settime.id() as libc::c_long,
+        libc::SYS_clock_settime
+    );
+    assert_eq!(Sysno::clone.id() as libc::c_long, libc::SYS_clone);
+    assert_eq!(Sysno::clone3.id() as libc::c_long, libc::SYS_clone3);
+    assert_eq!(Sysno::close.id() as libc::c_long, libc::SYS_close);
+    assert_eq!(
+        Sysno::close_range.id() as libc::c_long,
+        libc::SYS_close_range
+    );
+    assert_eq!(Sysno::connect.id() as libc::c_long, libc::SYS_connect);
+    assert_eq!(
+        Sysno::copy_file_range.id() as libc::c_long,
+        libc::SYS_copy_file_range
+    );
+    assert_eq!(
+        Sysno::delete_module.id() as libc::c_long,
+        libc::SYS_delete_module
+    );
+    assert_eq!(Sysno::dup.id() as libc::c_long, libc::SYS_dup);
+    assert_eq!(Sysno::dup3.id() as libc::c_long, libc::SYS_dup3);
+    assert_eq!(
+        Sysno::epoll_create1.id() as libc::c_long,
+        libc::SYS_epoll_create1
+    );
+    assert_eq!(Sysno::epoll_ctl.id() as libc::c_long, libc::SYS_epoll_ctl);
+    assert_eq!(
+        Sysno::epoll_pwait.id() as libc::c_long,
+        libc::SYS_epoll_pwait
+    );
+    assert_eq!(
+        Sysno::epoll_pwait2.id() as libc::c_long,
+        libc::SYS_epoll_pwait2
+    );
+    assert_eq!(Sysno::eventfd2.id() as libc::c_long, libc::SYS_eventfd2);
+    assert_eq!(Sysno::execve.id() as libc::c_long, libc::SYS_execve);
+    assert_eq!(Sysno::execveat.id() as libc::c_long, libc::SYS_execveat);
+    assert_eq!(Sysno::exit.id() as libc::c_long, libc::SYS_exit);
+    assert_eq!(Sysno::exit_group.id() as libc::c_long, libc::SYS_exit_group);
+    assert_eq!(Sysno::faccessat.id() as libc::c_long, libc::SYS_faccessat);
+    assert_eq!(Sysno::faccessat2.id() as libc::c_long, libc::SYS_faccessat2);
+    assert_eq!(Sysno::fadvise64.id() as libc::c_long, libc::SYS_fadvise64);
+    assert_eq!(Sysno::fallocate.id() as libc::c_long, libc::SYS_fallocate);
+    assert_eq!(
+        Sysno::fanotify_init.id() as libc::c_long,
+        libc::SYS_fanotify_init
+    );
+    assert_eq!(
+        Sysno::fanotify_mark.id() as libc::c_long,
+        libc::SYS_fanotify_mark
+    );
+    assert_eq!(Sysno::fchdir.id() as libc::c_long, libc::SYS_fchdir);
+    assert_eq!(Sysno::fchmod.id() as libc::c_long, libc::SYS_fchmod);
+    assert_eq!(Sysno::fchmodat.id() as libc::c_long, libc::SYS_fchmodat);
+    assert_eq!(Sysno::fchown.id() as libc::c_long, libc::SYS_fchown);
+    assert_eq!(Sysno::fchownat.id() as libc::c_long, libc::SYS_fchownat);
+    assert_eq!(Sysno::fcntl.id() as libc::c_long, libc::SYS_fcntl);
+    assert_eq!(Sysno::fdatasync.id() as libc::c_long, libc::SYS_fdatasync);
+    assert_eq!(Sysno::fgetxattr.id() as libc::c_long, libc::SYS_fgetxattr);
+    assert_eq!(
+        Sysno::finit_module.id() as libc::c_long,
+        libc::SYS_finit_module
+    );
+    assert_eq!(Sysno::flistxattr.id() as libc::c_long, libc::SYS_flistxattr);
+    assert_eq!(Sysno::flock.id() as libc::c_long, libc::SYS_flock);
+    assert_eq!(
+        Sysno::fremovexattr.id() as libc::c_long,
+        libc::SYS_fremovexattr
+    );
+    assert_eq!(Sysno::fsconfig.id() as libc::c_long, libc::SYS_fsconfig);
+    assert_eq!(Sysno::fsetxattr.id() as libc::c_long, libc::SYS_fsetxattr);
+    assert_eq!(Sysno::fsmount.id() as libc::c_long, libc::SYS_fsmount);
+    assert_eq!(Sysno::fsopen.id() as libc::c_long, libc::SYS_fsopen);
+    assert_eq!(Sysno::fspick.id() as libc::c_long, libc::SYS_fspick);
+    assert_eq!(Sysno::fstat.id() as libc::c_long, libc::SYS_fstat);
+    assert_eq!(Sysno::fstatfs.id() as libc::c_long, libc::SYS_fstatfs);
+    assert_eq!(Sysno::fsync.id() as libc::c_long, libc::SYS_fsync);
+    assert_eq!(Sysno::ftruncate.id() as libc::c_long, libc::SYS_ftruncate);
+    assert_eq!(Sysno::futex.id() as libc::c_long, libc::SYS_futex);
+    assert_eq!(
+        Sysno::futex_waitv.id() as libc::c_long,
+        libc::SYS_futex_waitv
+    );
+    assert_eq!(
+        Sysno::get_mempolicy.id() as libc::c_long,
+        libc::SYS_get_mempolicy
+    );
+    assert_eq!(
+        Sysno::get_robust_list.id() as libc::c_long,
+        libc::SYS_get_robust_list
+    );
+    assert_eq!(Sysno::getcpu.id() as libc::c_long, libc::SYS_getcpu);
+    assert_eq!(Sysno::getcwd.id() as libc::c_long, libc::SYS_getcwd);
+    assert_eq!(Sysno::getdents64.id() as libc::c_long, libc::SYS_getdents64);
+    assert_eq!(Sysno::getegid.id() as libc::c_long, libc::SYS_getegid);
+    assert_eq!(Sysno::geteuid.id() as libc::c_long, libc::SYS_geteuid);
+    assert_eq!(Sysno::getgid.id() as libc::c_long, libc::SYS_getgid);
+    assert_eq!(Sysno::getgroups.id() as libc::c_long, libc::SYS_getgroups);
+    assert_eq!(Sysno::getitimer.id() as libc::c_long, libc::SYS_getitimer);
+    assert_eq!(
+        Sysno::getpeername.id() as libc::c_long,
+        libc::SYS_getpeername
+    );
+    assert_eq!(Sysno::getpgid.id() as libc::c_long, libc::SYS_getpgid);
+    assert_eq!(Sysno::getpid.id() as libc::c_long, libc::SYS_getpid);
+    assert_eq!(Sysno::getppid.id() as libc::c_long, libc::SYS_getppid);
+    assert_eq!(
+        Sysno::getpriority.id() as libc::c_long,
+        libc::SYS_getpriority
+    );
+    assert_eq!(Sysno::getrandom.id() as libc::c_long, libc::SYS_getrandom);
+    assert_eq!(Sysno::getresgid.id() as libc::c_long, libc::SYS_getresgid);
+    assert_eq!(Sysno::getresuid.id() as libc::c_long, libc::SYS_getresuid);
+    assert_eq!(Sysno::getrusage.id() as libc::c_long, libc::SYS_getrusage);
+    assert_eq!(Sysno::getsid.id() as libc::c_long, libc::SYS_getsid);
+    assert_eq!(
+        Sysno::getsockname.id() as libc::c_long,
+        libc::SYS_getsockname
+    );
+    assert_eq!(Sysno::getsockopt.id() as libc::c_long, libc::SYS_getsockopt);
+    assert_eq!(Sysno::gettid.id() as libc::c_long, libc::SYS_gettid);
+    assert_eq!(
+        Sysno::gettimeofday.id() as libc::c_long,
+        libc::SYS_gettimeofday
+    );
+    assert_eq!(Sysno::getuid.id() as libc::c_long, libc::SYS_getuid);
+    assert_eq!(Sysno::getxattr.id() as libc::c_long, libc::SYS_getxattr);
+    assert_eq!(
+        Sysno::init_module.id() as libc::c_long,
+        libc::SYS_init_module
+    );
+    assert_eq!(
+        Sysno::inotify_add_watch.id() as libc::c_long,
+        libc::SYS_inotify_add_watch
+    );
+    assert_eq!(
+        Sysno::inotify_init1.id() as libc::c_long,
+        libc::SYS_inotify_init1
+    );
+    assert_eq!(
+        Sysno::inotify_rm_watch.id() as libc::c_long,
+        libc::SYS_inotify_rm_watch
+    );
+    assert_eq!(Sysno::io_cancel.id() as libc::c_long, libc::SYS_io_cancel);
+    assert_eq!(Sysno::io_destroy.id() as libc::c_long, libc::SYS_io_destroy);
+    assert_eq!(
+        Sysno::io_getevents.id() as libc::c_long,
+        libc::SYS_io_getevents
+    );
+    assert_eq!(Sysno::io_setup.id() as libc::c_long, libc::SYS_io_setup);
+    assert_eq!(Sysno::io_submit.id() as libc::c_long, libc::SYS_io_submit);
+    assert_eq!(
+        Sysno::io_uring_enter.id() as libc::c_long,
+        libc::SYS_io_uring_enter
+    );
+    assert_eq!(
+        Sysno::io_uring_register.id() as libc::c_long,
+        libc::SYS_io_uring_register
+    );
+    assert_eq!(
+        Sysno::io_uring_setup.id() as libc::c_long,
+        libc::SYS_io_uring_setup
+    );
+    assert_eq!(Sysno::ioctl.id() as libc::c_long, libc::SYS_ioctl);
+    assert_eq!(Sysno::ioprio_get.id() as libc::c_long, libc::SYS_ioprio_get);
+    assert_eq!(Sysno::ioprio_set.id() as libc::c_long, libc::SYS_ioprio_set);
+    assert_eq!(Sysno::kcmp.id() as libc::c_long, libc::SYS_kcmp);
+    assert_eq!(
+        Sysno::kexec_file_load.id() as libc::c_long,
+        libc::SYS_kexec_file_load
+    );
+    assert_eq!(Sysno::kexec_load.id() as libc::c_long, libc::SYS_kexec_load);
+    assert_eq!(Sysno::keyctl.id() as libc::c_long, libc::SYS_keyctl);
+    assert_eq!(Sysno::kill.id() as libc::c_long, libc::SYS_kill);
+    assert_eq!(
+        Sysno::landlock_add_rule.id() as libc::c_long,
+        libc::SYS_landlock_add_rule
+    );
+    assert_eq!(
+        Sysno::landlock_create_ruleset.id() as libc::c_long,
+        libc::SYS_landlock_create_ruleset
+    );
+    assert_eq!(
+        Sysno::landlock_restrict_self.id() as libc::c_long,
+        libc::SYS_landlock_restrict_self
+    );
+    assert_eq!(Sysno::lgetxattr.id() as libc::c_long, libc::SYS_lgetxattr);
+    assert_eq!(Sysno::linkat.id() as libc::c_long, libc::SYS_linkat);
+    assert_eq!(Sysno::listen.id() as libc::c_long, libc::SYS_listen);
+    assert_eq!(Sysno::listxattr.id() as libc::c_long, libc::SYS_listxattr);
+    assert_eq!(Sysno::llistxattr.id() as libc::c_long, libc::SYS_llistxattr);
+    assert_eq!(
+        Sysno::lookup_dcookie.id() as libc::c_long,
+        libc::SYS_lookup_dcookie
+    );
+    assert_eq!(
+        Sysno::lremovexattr.id() as libc::c_long,
+        libc::SYS_lremovexattr
+    );
+    assert_eq!(Sysno::lseek.id() as libc::c_long, libc::SYS_lseek);
+    assert_eq!(Sysno::lsetxattr.id() as libc::c_long, libc::SYS_lsetxattr);
+    assert_eq!(Sysno::madvise.id() as libc::c_long, libc::SYS_madvise);
+    assert_eq!(Sysno::mbind.id() as libc::c_long, libc::SYS_mbind);
+    assert_eq!(Sysno::membarrier.id() as libc::c_long, libc::SYS_membarrier);
+    assert_eq!(
+        Sysno::memfd_create.id() as libc::c_long,
+        libc::SYS_memfd_create
+    );
+    assert_eq!(
+        Sysno::memfd_secret.id() as libc::c_long,
+        libc::SYS_memfd_secret
+    );
+    assert_eq!(
+        Sysno::migrate_pages.id() as libc::c_long,
+        libc::SYS_migrate_pages
+    );
+    assert_eq!(Sysno::mincore.id() as libc::c_long, libc::SYS_mincore);
+    assert_eq!(Sysno::mkdirat.id() as libc::c_long, libc::SYS_mkdirat);
+    assert_eq!(Sysno::mknodat.id() as libc::c_long, libc::SYS_mknodat);
+    assert_eq!(Sysno::mlock.id() as libc::c_long, libc::SYS_mlock);
+    assert_eq!(Sysno::mlock2.id() as libc::c_long, libc::SYS_mlock2);
+    assert_eq!(Sysno::mlockall.id() as libc::c_long, libc::SYS_mlockall);
+    assert_eq!(Sysno::mmap.id() as libc::c_long, libc::SYS_mmap);
+    assert_eq!(Sysno::mount.id() as libc::c_long, libc::SYS_mount);
+    assert_eq!(
+        Sysno::mount_setattr.id() as libc::c_long,
+        libc::SYS_mount_setattr
+    );
+    assert_eq!(Sysno::move_mount.id() as libc::c_long, libc::SYS_move_mount);
+    assert_eq!(Sysno::move_pages.id() as libc::c_long, libc::SYS_move_pages);
+    assert_eq!(Sysno::mprotect.id() as libc::c_long, libc::SYS_mprotect);
+    assert_eq!(
+        Sysno::mq_getsetattr.id() as libc::c_long,
+        libc::SYS_mq_getsetattr
+    );
+    assert_eq!(Sysno::mq_notify.id() as libc::c_long, libc::SYS_mq_notify);
+    assert_eq!(Sysno::mq_open.id() as libc::c_long, libc::SYS_mq_open);
+    assert_eq!(
+        Sysno::mq_timedreceive.id() as libc::c_long,
+        libc::SYS_mq_timedreceive
+    );
+    assert_eq!(
+        Sysno::mq_timedsend.id() as libc::c_long,
+        libc::SYS_mq_timedsend
+    );
+    assert_eq!(Sysno::mq_unlink.id() as libc::c_long, libc::SYS_mq_unlink);
+    assert_eq!(Sysno::mremap.id() as libc::c_long, libc::SYS_mremap);
+    assert_eq!(Sysno::mseal.id() as libc::c_long, libc::SYS_mseal);
+    assert_eq!(Sysno::msgctl.id() as libc::c_long, libc::SYS_msgctl);
+    assert_eq!(Sysno::msgget.id() as libc::c_long, libc::SYS_msgget);
+    assert_eq!(Sysno::msgrcv.id() as libc::c_long, libc::SYS_msgrcv);
+    assert_eq!(Sysno::msgsnd.id() as libc::c_long, libc::SYS_msgsnd);
+    assert_eq!(Sysno::msync.id() as libc::c_long, libc::SYS_msync);
+    assert_eq!(Sysno::munlock.id() as libc::c_long, libc::SYS_munlock);
+    assert_eq!(Sysno::munlockall.id() as libc::c_long, libc::SYS_munlockall);
+    assert_eq!(Sysno::munmap.id() as libc::c_long, libc::SYS_munmap);
+    assert_eq!(
+        Sysno::name_to_handle_at.id() as libc::c_long,
+        libc::SYS_name_to_handle_at
+    );
+    assert_eq!(Sysno::nanosleep.id() as libc::c_long, libc::SYS_nanosleep);
+    assert_eq!(Sysno::nfsservctl.id() as libc::c_long, libc::SYS_nfsservctl);
+    assert_eq!(
+        Sysno::open_by_handle_at.id() as libc::c_long,
+        libc::SYS_open_by_handle_at
+    );
+    assert_eq!(Sysno::open_tree.id() as libc::c_long, libc::SYS_open_tree);
+    assert_eq!(Sysno::openat.id() as libc::c_long, libc::SYS_openat);
+    assert_eq!(Sysno::openat2.id() as libc::c_long, libc::SYS_openat2);
+    assert_eq!(
+        Sysno::perf_event_open.id() as libc::c_long,
+        libc::SYS_perf_event_open
+    );
+    assert_eq!(
+        Sysno::personality.id() as libc::c_long,
+        libc::SYS_personality
+    );
+    assert_eq!(
+        Sysno::pidfd_getfd.id() as libc::c_long,
+        libc::SYS_pidfd_getfd
+    );
+    assert_eq!(Sysno::pidfd_open.id() as libc::c_long, libc::SYS_pidfd_open);
+    assert_eq!(
+        Sysno::pidfd_send_signal.id() as libc::c_long,
+        libc::SYS_pidfd_send_signal
+    );
+    assert_eq!(Sysno::pipe2.id() as libc::c_long, libc::SYS_pipe2);
+    assert_eq!(Sysno::pivot_root.id() as libc::c_long, libc::SYS_pivot_root);
+    assert_eq!(Sysno::pkey_alloc.id() as libc::c_long, libc::SYS_pkey_alloc);
+    assert_eq!(Sysno::pkey_free.id() as libc::c_long, libc::SYS_pkey_free);
+    assert_eq!(
+        Sysno::pkey_mprotect.id() as libc::c_long,
+        libc::SYS_pkey_mprotect
+    );
+    assert_eq!(Sysno::ppoll.id() as libc::c_long, libc::SYS_ppoll);
+    assert_eq!(Sysno::prctl.id() as libc::c_long, libc::SYS_prctl);
+    assert_eq!(Sysno::pread64.id() as libc::c_long, libc::SYS_pread64);
+    assert_eq!(Sysno::preadv.id() as libc::c_long, libc::SYS_preadv);
+    assert_eq!(Sysno::preadv2.id() as libc::c_long, libc::SYS_preadv2);
+    assert_eq!(Sysno::prlimit64.id() as libc::c_long, libc::SYS_prlimit64);
+    assert_eq!(
+        Sysno::process_madvise.id() as libc::c_long,
+        libc::SYS_process_madvise
+    );
+    assert_eq!(
+        Sysno::process_mrelease.id() as libc::c_long,
+        libc::SYS_process_mrelease
+    );
+    assert_eq!(
+        Sysno::process_vm_readv.id() as libc::c_long,
+        libc::SYS_process_vm_readv
+    );
+    assert_eq!(
+        Sysno::process_vm_writev.id() as libc::c_long,
+        libc::SYS_process_vm_writev
+    );
+    assert_eq!(Sysno::pselect6.id() as libc::c_long, libc::SYS_pselect6);
+    assert_eq!(Sysno::ptrace.id() as libc::c_long, libc::SYS_ptrace);
+    assert_eq!(Sysno::pwrite64.id() as libc::c_long, libc::SYS_pwrite64);
+    assert_eq!(Sysno::pwritev.id() as libc::c_long, libc::SYS_pwritev);
+    assert_eq!(Sysno::pwritev2.id() as libc::c_long, libc::SYS_pwritev2);
+    assert_eq!(Sysno::quotactl.id() as libc::c_long, libc::SYS_quotactl);
+    assert_eq!(
+        Sysno::quotactl_fd.id() as libc::c_long,
+        libc::SYS_quotactl_fd
+    );
+    assert_eq!(Sysno::read.id() as libc::c_long, libc::SYS_read);
+    assert_eq!(Sysno::readahead.id() as libc::c_long, libc::SYS_readahead);
+    assert_eq!(Sysno::readlinkat.id() as libc::c_long, libc::SYS_readlinkat);
+    assert_eq!(Sysno::readv.id() as libc::c_long, libc::SYS_readv);
+    assert_eq!(Sysno::reboot.id() as libc::c_long, libc::SYS_reboot);
+    assert_eq!(Sysno::recvfrom.id() as libc::c_long, libc::SYS_recvfrom);
+    assert_eq!(Sysno::recvmmsg.id() as libc::c_long, libc::SYS_recvmmsg);
+    assert_eq!(Sysno::recvmsg.id() as libc::c_long, libc::SYS_recvmsg);
+    assert_eq!(
+        Sysno::remap_file_pages.id() as libc::c_long,
+        libc::SYS_remap_file_pages
+    );
+    assert_eq!(
+        Sysno::removexattr.id() as libc::c_long,
+        libc::SYS_removexattr
+    );
+    assert_eq!(Sysno::renameat2.id() as libc::c_long, libc::SYS_renameat2);
+    assert_eq!(
+        Sysno::request_key.id() as libc::c_long,
+        libc::SYS_request_key
+    );
+    assert_eq!(
+        Sysno::restart_syscall.id() as libc::c_long,
+        libc::SYS_restart_syscall
+    );
+    assert_eq!(Sysno::rseq.id() as libc::c_long, libc::SYS_rseq);
+    assert_eq!(
+        Sysno::rt_sigaction.id() as libc::c_long,
+        libc::SYS_rt_sigaction
+    );
+    assert_eq!(
+        Sysno::rt_sigpending.id() as libc::c_long,
+        libc::SYS_rt_sigpending
+    );
+    assert_eq!(
+        Sysno::rt_sigprocmask.id() as libc::c_long,
+        libc::SYS_rt_sigprocmask
+    );
+    assert_eq!(
+        Sysno::rt_sigqueueinfo.id() as libc::c_long,
+        libc::SYS_rt_sigqueueinfo
+    );
+    assert_eq!(
+        Sysno::rt_sigreturn.id() as libc::c_long,
+        libc::SYS_rt_sigreturn
+    );
+    assert_eq!(
+        Sysno::rt_sigsuspend.id() as libc::c_long,
+        libc::SYS_rt_sigsuspend
+    );
+    assert_eq!(
+        Sysno::rt_sigtimedwait.id() as libc::c_long,
+        libc::SYS_rt_sigtimedwait
+    );
+    assert_eq!(
+        Sysno::rt_tgsigqueueinfo.id() as libc::c_long,
+        libc::SYS_rt_tgsigqueueinfo
+    );
+    assert_eq!(
+        Sysno::sched_get_priority_max.id() as libc::c_long,
+        libc::SYS_sched_get_priority_max
+    );
+    assert_eq!(
+        Sysno::sched_get_priority_min.id() as libc::c_long,
+        libc::SYS_sched_get_priority_min
+    );
+    assert_eq!(
+        Sysno::sched_getaffinity.id() as libc::c_long,
+        libc::SYS_sched_getaffinity
+    );
+    assert_eq!(
+        Sysno::sched_getattr.id() as libc::c_long,
+        libc::SYS_sched_getattr
+    );
+    assert_eq!(
+        Sysno::sched_getparam.id() as libc::c_long,
+        libc::SYS_sched_getparam
+    );
+    assert_eq!(
+        Sysno::sched_getscheduler.id() as libc::c_long,
+        libc::SYS_sched_getscheduler
+    );
+    assert_eq!(
+        Sysno::sched_rr_get_interval.id() as libc::c_long,
+        libc::SYS_sched_rr_get_interval
+    );
+    assert_eq!(
+        Sysno::sched_setaffinity.id() as libc::c_long,
+        libc::SYS_sched_setaffinity
+    );
+    assert_eq!(
+        Sysno::sched_setattr.id() as libc::c_long,
+        libc::SYS_sched_setattr
+    );
+    assert_eq!(
+        Sysno::sched_setparam.id() as libc::c_long,
+        libc::SYS_sched_setparam
+    );
+    assert_eq!(
+        Sysno::sched_setscheduler.id() as libc::c_long,
+        libc::SYS_sched_setscheduler
+    );
+    assert_eq!(
+        Sysno::sched_yield.id() as libc::c_long,
+        libc::SYS_sched_yield
+    );
+    assert_eq!(Sysno::seccomp.id() as libc::c_long, libc::SYS_seccomp);
+    assert_eq!(Sysno::semctl.id() as libc::c_long, libc::SYS_semctl);
+    assert_eq!(Sysno::semget.id() as libc::c_long, libc::SYS_semget);
+    assert_eq!(Sysno::semop.id() as libc::c_long, libc::SYS_semop);
+    assert_eq!(Sysno::semtimedop.id() as libc::c_long, libc::SYS_semtimedop);
+    assert_eq!(Sysno::sendfile.id() as libc::c_long, libc::SYS_sendfile);
+    assert_eq!(Sysno::sendmmsg.id() as libc::c_long, libc::SYS_sendmmsg);
+    assert_eq!(Sysno::sendmsg.id() as libc::c_long, libc::SYS_sendmsg);
+    assert_eq!(Sysno::sendto.id() as libc::c_long, libc::SYS_sendto);
+    assert_eq!(
+        Sysno::set_mempolicy.id() as libc::c_long,
+        libc::SYS_set_mempolicy
+    );
+    assert_eq!(
+        Sysno::set_mempolicy_home_node.id() as libc::c_long,
+        libc::SYS_set_mempolicy_home_node
+    );
+    assert_eq!(
+        Sysno::set_robust_list.id() as libc::c_long,
+        libc::SYS_set_robust_list
+    );
+    assert_eq!(
+        Sysno::set_tid_address.id() as libc::c_long,
+        libc::SYS_set_tid_address
+    );
+    assert_eq!(
+        Sysno::setdomainname.id() as libc::c_long,
+        libc::SYS_setdomainname
+    );
+    assert_eq!(Sysno::setfsgid.id() as libc::c_long, libc::SYS_setfsgid);
+    assert_eq!(Sysno::setfsuid.id() as libc::c_long, libc::SYS_setfsuid);
+    assert_eq!(Sysno::setgid.id() as libc::c_long, libc::SYS_setgid);
+    assert_eq!(Sysno::setgroups.id() as libc::c_long, libc::SYS_setgroups);
+    assert_eq!(
+        Sysno::sethostname.id() as libc::c_long,
+        libc::SYS_sethostname
+    );
+    assert_eq!(Sysno::setitimer.id() as libc::c_long, libc::SYS_setitimer);
+    assert_eq!(Sysno::setns.id() as libc::c_long, libc::SYS_setns);
+    assert_eq!(Sysno::setpgid.id() as libc::c_long, libc::SYS_setpgid);
+    assert_eq!(
+        Sysno::setpriority.id() as libc::c_long,
+        libc::SYS_setpriority
+    );
+    assert_eq!(Sysno::setregid.id() as libc::c_long, libc::SYS_setregid);
+    assert_eq!(Sysno::setresgid.id() as libc::c_long, libc::SYS_setresgid);
+    assert_eq!(Sysno::setresuid.id() as libc::c_long, libc::SYS_setresuid);
+    assert_eq!(Sysno::setreuid.id() as libc::c_long, libc::SYS_setreuid);
+    assert_eq!(Sysno::setsid.id() as libc::c_long, libc::SYS_setsid);
+    assert_eq!(Sysno::setsockopt.id() as libc::c_long, libc::SYS_setsockopt);
+    assert_eq!(
+        Sysno::settimeofday.id() as libc::c_long,
+        libc::SYS_settimeofday
+    );
+    assert_eq!(Sysno::setuid.id() as libc::c_long, libc::SYS_setuid);
+    assert_eq!(Sysno::setxattr.id() as libc::c_long, libc::SYS_setxattr);
+    assert_eq!(Sysno::shmat.id() as libc::c_long, libc::SYS_shmat);
+    assert_eq!(Sysno::shmctl.id() as libc::c_long, libc::SYS_shmctl);
+    assert_eq!(Sysno::shmdt.id() as libc::c_long, libc::SYS_shmdt);
+    assert_eq!(Sysno::shmget.id() as libc::c_long, libc::SYS_shmget);
+    assert_eq!(Sysno::shutdown.id() as libc::c_long, libc::SYS_shutdown);
+    assert_eq!(
+        Sysno::sigaltstack.id() as libc::c_long,
+        libc::SYS_sigaltstack
+    );
+    assert_eq!(Sysno::signalfd4.id() as libc::c_long, libc::SYS_signalfd4);
+    assert_eq!(Sysno::socket.id() as libc::c_long, libc::SYS_socket);
+    assert_eq!(Sysno::socketpair.id() as libc::c_long, libc::SYS_socketpair);
+    assert_eq!(Sysno::splice.id() as libc::c_long, libc::SYS_splice);
+    assert_eq!(Sysno::statfs.id() as libc::c_long, libc::SYS_statfs);
+    assert_eq!(Sysno::statx.id() as libc::c_long, libc::SYS_statx);
+    assert_eq!(Sysno::swapoff.id() as libc::c_long, libc::SYS_swapoff);
+    assert_eq!(Sysno::swapon.id() as libc::c_long, libc::SYS_swapon);
+    assert_eq!(Sysno::symlinkat.id() as libc::c_long, libc::SYS_symlinkat);
+    assert_eq!(Sysno::sync.id() as libc::c_long, libc::SYS_sync);
+    assert_eq!(Sysno::syncfs.id() as libc::c_long, libc::SYS_syncfs);
+    assert_eq!(Sysno::sysinfo.id() as libc::c_long, libc::SYS_sysinfo);
+    assert_eq!(Sysno::syslog.id() as libc::c_long, libc::SYS_syslog);
+    assert_eq!(Sysno::tee.id() as libc::c_long, libc::SYS_tee);
+    assert_eq!(Sysno::tgkill.id() as libc::c_long, libc::SYS_tgkill);
+    assert_eq!(
+        Sysno::timer_create.id() as libc::c_long,
+        libc::SYS_timer_create
+    );
+    assert_eq!(
+        Sysno::timer_delete.id() as libc::c_long,
+        libc::SYS_timer_delete
+    );
+    assert_eq!(
+        Sysno::timer_getoverrun.id() as libc::c_long,
+        libc::SYS_timer_getoverrun
+    );
+    assert_eq!(
+        Sysno::timer_gettime.id() as libc::c_long,
+        libc::SYS_timer_gettime
+    );
+    assert_eq!(
+        Sysno::timer_settime.id() as libc::c_long,
+        libc::SYS_timer_settime
+    );
+    assert_eq!(
+        Sysno::timerfd_create.id() as libc::c_long,
+        libc::SYS_timerfd_create
+    );
+    assert_eq!(
+        Sysno::timerfd_gettime.id() as libc::c_long,
+        libc::SYS_timerfd_gettime
+    );
+    assert_eq!(
+        Sysno::timerfd_settime.id() as libc::c_long,
+        libc::SYS_timerfd_settime
+    );
+    assert_eq!(Sysno::times.id() as libc::c_long, libc::SYS_times);
+    assert_eq!(Sysno::tkill.id() as libc::c_long, libc::SYS_tkill);
+    assert_eq!(Sysno::truncate.id() as libc::c_long, libc::SYS_truncate);
+    assert_eq!(Sysno::umask.id() as libc::c_long, libc::SYS_umask);
+    assert_eq!(Sysno::umount2.id() as libc::c_long, libc::SYS_umount2);
+    assert_eq!(Sysno::uname.id() as libc::c_long, libc::SYS_uname);
+    assert_eq!(Sysno::unlinkat.id() as libc::c_long, libc::SYS_unlinkat);
+    assert_eq!(Sysno::unshare.id() as libc::c_long, libc::SYS_unshare);
+    assert_eq!(
+        Sysno::userfaultfd.id() as libc::c_long,
+        libc::SYS_userfaultfd
+    );
+    assert_eq!(Sysno::utimensat.id() as libc::c_long, libc::SYS_utimensat);
+    assert_eq!(Sysno::vhangup.id() as libc::c_long, libc::SYS_vhangup);
+    assert_eq!(Sysno::vmsplice.id() as libc::c_long, libc::SYS_vmsplice);
+    assert_eq!(Sysno::wait4.id() as libc::c_long, libc::SYS_wait4);
+    assert_eq!(Sysno::waitid.id() as libc::c_long, libc::SYS_waitid);
+    assert_eq!(Sysno::write.id() as libc::c_long, libc::SYS_write);
+    assert_eq!(Sysno::writev.id() as libc::c_long, libc::SYS_writev);
+}
+
+#[cfg(all(target_arch = "arm", target_pointer_width = "32"))]
+#[test]
+fn arm() {
+    use rawsys_linux::arm::v6_12::Sysno;
+
+    assert_eq!(Sysno::_llseek.id() as libc::c_long, libc::SYS__llseek);
+    assert_eq!(Sysno::_newselect.id() as libc::c_long, libc::SYS__newselect);
+    assert_eq!(Sysno::_sysctl.id() as libc::c_long, libc::SYS__sysctl);
+    assert_eq!(Sysno::accept.id() as libc::c_long, libc::SYS_accept);
+    assert_eq!(Sysno::accept4.id() as libc::c_long, libc::SYS_accept4);
+    assert_eq!(Sysno::access.id() as libc::c_long, libc::SYS_access);
+    assert_eq!(Sysno::acct.id() as libc::c_long, libc::SYS_acct);
+    assert_eq!(Sysno::add_key.id() as libc::c_long, libc::SYS_add_key);
+    assert_eq!(Sysno::adjtimex.id() as libc::c_long, libc::SYS_adjtimex);
+    assert_eq!(
+        Sysno::arm_fadvise64_64.id() as libc::c_long,
+        libc::SYS_arm_fadvise64_64
+    );
+    assert_eq!(
+        Sysno::arm_sync_file_range.id() as libc::c_long,
+        libc::SYS_arm_sync_file_range
+    );
+    assert_eq!(Sysno::bdflush.id() as libc::c_long, libc::SYS_bdflush);
+    assert_eq!(Sysno::bind.id() as libc::c_long, libc::SYS_bind);
+    assert_eq!(Sysno::bpf.id() as libc::c_long, libc::SYS_bpf);
+    assert_eq!(Sysno::brk.id() as libc::c_long, libc::SYS_brk);
+    assert_eq!(Sysno::capget.id() as libc::c_long, libc::SYS_capget);
+    assert_eq!(Sysno::capset.id() as libc::c_long, libc::SYS_capset);
+    assert_eq!(Sysno::chdir.id() as libc::c_long, libc::SYS_chdir);
+    assert_eq!(Sysno::chmod.id() as libc::c_long, libc::SYS_chmod);
+    assert_eq!(Sysno::chown.id() as libc::c_long, libc::SYS_chown);
+    assert_eq!(Sysno::chown32.id() as libc::c_long, libc::SYS_chown32);
+    assert_eq!(Sysno::chroot.id() as libc::c_long, libc::SYS_chroot);
+    assert_eq!(
+        Sysno::clock_adjtime.id() as libc::c_long,
+        libc::SYS_clock_adjtime
+    );
+    assert_eq!(
+        Sysno::clock_getres.id() as libc::c_long,
+        libc::SYS_clock_getres
+    );
+    assert_eq!(
+        Sysno::clock_gettime.id() as libc::c_long,
+        libc::SYS_clock_gettime
+    );
+    assert_eq!(
+        Sysno::clock_nanosleep.id() as libc::c_long,
+        libc::SYS_clock_nanosleep
+    );
+    assert_eq!(
+        Sysno::clock_settime.id() as libc::c_long,
+        libc::SYS_clock_settime
+    );
+    assert_eq!(Sysno::clone.id() as libc::c_long, libc::SYS_clone);
+    assert_eq!(Sysno::clone3.id() as libc::c_long, libc::SYS_clone3);
+    assert_eq!(Sysno::close.id() as libc::c_long, libc::SYS_close);
+    assert_eq!(
+        Sysno::close_range.id() as libc::c_long,
+        libc::SYS_close_range
+    );
+    assert_eq!(Sysno::connect.id() as libc::c_long, libc::SYS_connect);
+    assert_eq!(
+        Sysno::copy_file_range.id() as libc::c_long,
+        libc::SYS_copy_file_range
+    );
+    assert_eq!(Sysno::creat.id() as libc::c_long, libc::SYS_creat);
+    assert_eq!(
+        Sysno::delete_module.id() as libc::c_long,
+        libc::SYS_delete_module
+    );
+    assert_eq!(Sysno::dup.id() as libc::c_long, libc::SYS_dup);
+    assert_eq!(Sysno::dup2.id() as libc::c_long, libc::SYS_dup2);
+    assert_eq!(Sysno::dup3.id() as libc::c_long, libc::SYS_dup3);
+    assert_eq!(
+        Sysno::epoll_create.id() as libc::c_long,
+        libc::SYS_epoll_create
+    );
+    assert_eq!(
+        Sysno::epoll_create1.id() as libc::c_long,
+        libc::SYS_epoll_create1
+    );
+    assert_eq!(Sysno::epoll_ctl.id() as libc::c_long, libc::SYS_epoll_ctl);
+    assert_eq!(
+        Sysno::epoll_pwait.id() as libc::c_long,
+        libc::SYS_epoll_pwait
+    );
+    assert_eq!(
+        Sysno::epoll_pwait2.id() as libc::c_long,
+        libc::SYS_epoll_pwait2
+    );
+    assert_eq!(Sysno::epoll_wait.id() as libc::c_long, libc::SYS_epoll_wait);
+    assert_eq!(Sysno::eventfd.id() as libc::c_long, libc::SYS_eventfd);
+    assert_eq!(Sysno::eventfd2.id() as libc::c_long, libc::SYS_eventfd2);
+    assert_eq!(Sysno::execve.id() as libc::c_long, libc::SYS_execve);
+    assert_eq!(Sysno::execveat.id() as libc::c_long, libc::SYS_execveat);
+    assert_eq!(Sysno::exit.id() as libc::c_long, libc::SYS_exit);
+    assert_eq!(Sysno::exit_group.id() as libc::c_long, libc::SYS_exit_group);
+    assert_eq!(Sysno::faccessat.id() as libc::c_long, libc::SYS_faccessat);
+    assert_eq!(Sysno::faccessat2.id() as libc::c_long, libc::SYS_faccessat2);
+    assert_eq!(Sysno::fallocate.id() as libc::c_long, libc::SYS_fallocate);
+    assert_eq!(
+        Sysno::fanotify_init.id() as libc::c_long,
+        libc::SYS_fanotify_init
+    );
+    assert_eq!(
+        Sysno::fanotify_mark.id() as libc::c_long,
+        libc::SYS_fanotify_mark
+    );
+    assert_eq!(Sysno::fchdir.id() as libc::c_long, libc::SYS_fchdir);
+    assert_eq!(Sysno::fchmod.id() as libc::c_long, libc::SYS_fchmod);
+    assert_eq!(Sysno::fchmodat.id() as libc::c_long, libc::SYS_fchmodat);
+    assert_eq!(Sysno::fchown.id() as libc::c_long, libc::SYS_fchown);
+    assert_eq!(Sysno::fchown32.id() as libc::c_long, libc::SYS_fchown32);
+    assert_eq!(Sysno::fchownat.id() as libc::c_long, libc::SYS_fchownat);
+    assert_eq!(Sysno::fcntl.id() as libc::c_long, libc::SYS_fcntl);
+    assert_eq!(Sysno::fcntl64.id() as libc::c_long, libc::SYS_fcntl64);
+    assert_eq!(Sysno::fdatasync.id() as libc::c_long, libc::SYS_fdatasync);
+    assert_eq!(Sysno::fgetxattr.id() as libc::c_long, libc::SYS_fgetxattr);
+    assert_eq!(
+        Sysno::finit_module.id() as libc::c_long,
+        libc::SYS_finit_module
+    );
+    assert_eq!(Sysno::flistxattr.id() as libc::c_long, libc::SYS_flistxattr);
+    assert_eq!(Sysno::flock.id() as libc::c_long, libc::SYS_flock);
+    assert_eq!(Sysno::fork.id() as libc::c_long, libc::SYS_fork);
+    assert_eq!(
+        Sysno::fremovexattr.id() as libc::c_long,
+        libc::SYS_fremovexattr
+    );
+    assert_eq!(Sysno::fsconfig.id() as libc::c_long, libc::SYS_fsconfig);
+    assert_eq!(Sysno::fsetxattr.id() as libc::c_long, libc::SYS_fsetxattr);
+    assert_eq!(Sysno::fsmount.id() as libc::c_long, libc::SYS_fsmount);
+    assert_eq!(Sysno::fsopen.id() as libc::c_long, libc::SYS_fsopen);
+    assert_eq!(Sysno::fspick.id() as libc::c_long, libc::SYS_fspick);
+    assert_eq!(Sysno::fstat.id() as libc::c_long, libc::SYS_fstat);
+    assert_eq!(Sysno::fstat64.id() as libc::c_long, libc::SYS_fstat64);
+    assert_eq!(Sysno::fstatat64.id() as libc::c_long, libc::SYS_fstatat64);
+    assert_eq!(Sysno::fstatfs.id() as libc::c_long, libc::SYS_fstatfs);
+    assert_eq!(Sysno::fstatfs64.id() as libc::c_long, libc::SYS_fstatfs64);
+    assert_eq!(Sysno::fsync.id() as libc::c_long, libc::SYS_fsync);
+    assert_eq!(Sysno::ftruncate.id() as libc::c_long, libc::SYS_ftruncate);
+    assert_eq!(
+        Sysno::ftruncate64.id() as libc::c_long,
+        libc::SYS_ftruncate64
+    );
+    assert_eq!(Sysno::futex.id() as libc::c_long, libc::SYS_futex);
+    assert_eq!(
+        Sysno::futex_waitv.id() as libc::c_long,
+        libc::SYS_futex_waitv
+    );
+    assert_eq!(Sysno::futimesat.id() as libc::c_long, libc::SYS_futimesat);
+    assert_eq!(
+        Sysno::get_mempolicy.id() as libc::c_long,
+        libc::SYS_get_mempolicy
+    );
+    assert_eq!(
+        Sysno::get_robust_list.id() as libc::c_long,
+        libc::SYS_get_robust_list
+    );
+    assert_eq!(Sysno::getcpu.id() as libc::c_long, libc::SYS_getcpu);
+    assert_eq!(Sysno::getcwd.id() as libc::c_long, libc::SYS_getcwd);
+    assert_eq!(Sysno::getdents.id() as libc::c_long, libc::SYS_getdents);
+    assert_eq!(Sysno::getdents64.id() as libc::c_long, libc::SYS_getdents64);
+    assert_eq!(Sysno::getegid.id() as libc::c_long, libc::SYS_getegid);
+    assert_eq!(Sysno::getegid32.id() as libc::c_long, libc::SYS_getegid32);
+    assert_eq!(Sysno::geteuid.id() as libc::c_long, libc::SYS_geteuid);
+    assert_eq!(Sysno::geteuid32.id() as libc::c_long, libc::SYS_geteuid32);
+    assert_eq!(Sysno::getgid.id() as libc::c_long, libc::SYS_getgid);
+    assert_eq!(Sysno::getgid32.id() as libc::c_long, libc::SYS_getgid32);
+    assert_eq!(Sysno::getgroups.id() as libc::c_long, libc::SYS_getgroups);
+    assert_eq!(
+        Sysno::getgroups32.id() as libc::c_long,
+        libc::SYS_getgroups32
+    );
+    assert_eq!(Sysno::getitimer.id() as libc::c_long, libc::SYS_getitimer);
+    assert_eq!(
+        Sysno::getpeername.id() as libc::c_long,
+        libc::SYS_getpeername
+    );
+    assert_eq!(Sysno::getpgid.id() as libc::c_long, libc::SYS_getpgid);
+    assert_eq!(Sysno::getpgrp.id() as libc::c_long, libc::SYS_getpgrp);
+    assert_eq!(Sysno::getpid.id() as libc::c_long, libc::SYS_getpid);
+    assert_eq!(Sysno::getppid.id() as libc::c_long, libc::SYS_getppid);
+    assert_eq!(
+        Sysno::getpriority.id() as libc::c_long,
+        libc::SYS_getpriority
+    );
+    assert_eq!(Sysno::getrandom.id() as libc::c_long, libc::SYS_getrandom);
+    assert_eq!(Sysno::getresgid.id() as libc::c_long, libc::SYS_getresgid);
+    assert_eq!(
+        Sysno::getresgid32.id() as libc::c_long,
+        libc::SYS_getresgid32
+    );
+    assert_eq!(Sysno::getresuid.id() as libc::c_long, libc::SYS_getresuid);
+    assert_eq!(
+        Sysno::getresuid32.id() as libc::c_long,
+        libc::SYS_getresuid32
+    );
+    assert_eq!(Sysno::getrusage.id() as libc::c_long, libc::SYS_getrusage);
+    assert_eq!(Sysno::getsid.id() as libc::c_long, libc::SYS_getsid);
+    assert_eq!(
+        Sysno::getsockname.id() as libc::c_long,
+        libc::SYS_getsockname
+    );
+    assert_eq!(Sysno::getsockopt.id() as libc::c_long, libc::SYS_getsockopt);
+    assert_eq!(Sysno::gettid.id() as libc::c_long, libc::SYS_gettid);
+    assert_eq!(
+        Sysno::gettimeofday.id() as libc::c_long,
+        libc::SYS_gettimeofday
+    );
+    assert_eq!(Sysno::getuid.id() as libc::c_long, libc::SYS_getuid);
+    assert_eq!(Sysno::getuid32.id() as libc::c_long, libc::SYS_getuid32);
+    assert_eq!(Sysno::getxattr.id() as libc::c_long, libc::SYS_getxattr);
+    assert_eq!(
+        Sysno::init_module.id() as libc::c_long,
+        libc::SYS_init_module
+    );
+    assert_eq!(
+        Sysno::inotify_add_watch.id() as libc::c_long,
+        libc::SYS_inotify_add_watch
+    );
+    assert_eq!(
+        Sysno::inotify_init.id() as libc::c_long,
+        libc::SYS_inotify_init
+    );
+    assert_eq!(
+        Sysno::inotify_init1.id() as libc::c_long,
+        libc::SYS_inotify_init1
+    );
+    assert_eq!(
+        Sysno::inotify_rm_watch.id() as libc::c_long,
+        libc::SYS_inotify_rm_watch
+    );
+    assert_eq!(Sysno::io_cancel.id() as libc::c_long, libc::SYS_io_cancel);
+    assert_eq!(Sysno::io_destroy.id() as libc::c_long, libc::SYS_io_destroy);
+    assert_eq!(
+        Sysno::io_getevents.id() as libc::c_long,
+        libc::SYS_io_getevents
+    );
+    assert_eq!(Sysno::io_setup.id() as libc::c_long, libc::SYS_io_setup);
+    assert_eq!(Sysno::io_submit.id() as libc::c_long, libc::SYS_io_submit);
+    assert_eq!(
+        Sysno::io_uring_enter.id() as libc::c_long,
+        libc::SYS_io_uring_enter
+    );
+    assert_eq!(
+        Sysno::io_uring_register.id() as libc::c_long,
+        libc::SYS_io_uring_register
+    );
+    assert_eq!(
+        Sysno::io_uring_setup.id() as libc::c_long,
+        libc::SYS_io_uring_setup
+    );
+    assert_eq!(Sysno::ioctl.id() as libc::c_long, libc::SYS_ioctl);
+    assert_eq!(Sysno::ioprio_get.id() as libc::c_long, libc::SYS_ioprio_get);
+    assert_eq!(Sysno::ioprio_set.id() as libc::c_long, libc::SYS_ioprio_set);
+    assert_eq!(Sysno::kcmp.id() as libc::c_long, libc::SYS_kcmp);
+    assert_eq!(
+        Sysno::kexec_file_load.id() as libc::c_long,
+        libc::SYS_kexec_file_load
+    );
+    assert_eq!(Sysno::kexec_load.id() as libc::c_long, libc::SYS_kexec_load);
+    assert_eq!(Sysno::keyctl.id() as libc::c_long, libc::SYS_keyctl);
+    assert_eq!(Sysno::kill.id() as libc::c_long, libc::SYS_kill);
+    assert_eq!(
+        Sysno::landlock_add_rule.id() as libc::c_long,
+        libc::SYS_landlock_add_rule
+    );
+    assert_eq!(
+        Sysno::landlock_create_ruleset.id() as libc::c_long,
+        libc::SYS_landlock_create_ruleset
+    );
+    assert_eq!(
+        Sysno::landlock_restrict_self.id() as libc::c_long,
+        libc::SYS_landlock_restrict_self
+    );
+    assert_eq!(Sysno::lchown.id() as libc::c_long, libc::SYS_lchown);
+    assert_eq!(Sysno::lchown32.id() as libc::c_long, libc::SYS_lchown32);
+    assert_eq!(Sysno::lgetxattr.id() as libc::c_long, libc::SYS_lgetxattr);
+    assert_eq!(Sysno::link.id() as libc::c_long, libc::SYS_link);
+    assert_eq!(Sysno::linkat.id() as libc::c_long, libc::SYS_linkat);
+    assert_eq!(Sysno::listen.id() as libc::c_long, libc::SYS_listen);
+    assert_eq!(Sysno::listxattr.id() as libc::c_long, libc::SYS_listxattr);
+    assert_eq!(Sysno::llistxattr.id() as libc::c_long, libc::SYS_llistxattr);
+    assert_eq!(
+        Sysno::lookup_dcookie.id() as libc::c_long,
+        libc::SYS_lookup_dcookie
+    );
+    assert_eq!(
+        Sysno::lremovexattr.id() as libc::c_long,
+        libc::SYS_lremovexattr
+    );
+    assert_eq!(Sysno::lseek.id() as libc::c_long, libc::SYS_lseek);
+    assert_eq!(Sysno::lsetxattr.id() as libc::c_long, libc::SYS_lsetxattr);
+    assert_eq!(Sysno::lstat.id() as libc::c_long, libc::SYS_lstat);
+    assert_eq!(Sysno::lstat64.id() as libc::c_long, libc::SYS_lstat64);
+    assert_eq!(Sysno::madvise.id() as libc::c_long, libc::SYS_madvise);
+    assert_eq!(Sysno::mbind.id() as libc::c_long, libc::SYS_mbind);
+    assert_eq!(Sysno::membarrier.id() as libc::c_long, libc::SYS_membarrier);
+    assert_eq!(
+        Sysno::memfd_create.id() as libc::c_long,
+        libc::SYS_memfd_create
+    );
+    assert_eq!(Sysno::mincore.id() as libc::c_long, libc::SYS_mincore);
+    assert_eq!(Sysno::mkdir.id() as libc::c_long, libc::SYS_mkdir);
+    assert_eq!(Sysno::mkdirat.id() as libc::c_long, libc::SYS_mkdirat);
+    assert_eq!(Sysno::mknod.id() as libc::c_long, libc::SYS_mknod);
+    assert_eq!(Sysno::mknodat.id() as libc::c_long, libc::SYS_mknodat);
+    assert_eq!(Sysno::mlock.id() as libc::c_long, libc::SYS_mlock);
+    assert_eq!(Sysno::mlock2.id() as libc::c_long, libc::SYS_mlock2);
+    assert_eq!(Sysno::mlockall.id() as libc::c_long, libc::SYS_mlockall);
+    assert_eq!(Sysno::mmap2.id() as libc::c_long, libc::SYS_mmap2);
+    assert_eq!(Sysno::mount.id() as libc::c_long, libc::SYS_mount);
+    assert_eq!(
+        Sysno::mount_setattr.id() as libc::c_long,
+        libc::SYS_mount_setattr
+    );
+    assert_eq!(Sysno::move_mount.id() as libc::c_long, libc::SYS_move_mount);
+    assert_eq!(Sysno::move_pages.id() as libc::c_long, libc::SYS_move_pages);
+    assert_eq!(Sysno::mprotect.id() as libc::c_long, libc::SYS_mprotect);
+    assert_eq!(
+        Sysno::mq_getsetattr.id() as libc::c_long,
+        libc::SYS_mq_getsetattr
+    );
+    assert_eq!(Sysno::mq_notify.id() as libc::c_long, libc::SYS_mq_notify);
+    assert_eq!(Sysno::mq_open.id() as libc::c_long, libc::SYS_mq_open);
+    assert_eq!(
+        Sysno::mq_timedreceive.id() as libc::c_long,
+        libc::SYS_mq_timedreceive
+    );
+    assert_eq!(
+        Sysno::mq_timedsend.id() as libc::c_long,
+        libc::SYS_mq_timedsend
+    );
+    assert_eq!(Sysno::mq_unlink.id() as libc::c_long, libc::SYS_mq_unlink);
+    assert_eq!(Sysno::mremap.id() as libc::c_long, libc::SYS_mremap);
+    assert_eq!(Sysno::mseal.id() as libc::c_long, libc::SYS_mseal);
+    assert_eq!(Sysno::msgctl.id() as libc::c_long, libc::SYS_msgctl);
+    assert_eq!(Sysno::msgget.id() as libc::c_long, libc::SYS_msgget);
+    assert_eq!(Sysno::msgrcv.id() as libc::c_long, libc::SYS_msgrcv);
+    assert_eq!(Sysno::msgsnd.id() as libc::c_long, libc::SYS_msgsnd);
+    assert_eq!(Sysno::msync.id() as libc::c_long, libc::SYS_msync);
+    assert_eq!(Sysno::munlock.id() as libc::c_long, libc::SYS_munlock);
+    assert_eq!(Sysno::munlockall.id() as libc::c_long, libc::SYS_munlockall);
+    assert_eq!(Sysno::munmap.id() as libc::c_long, libc::SYS_munmap);
+    assert_eq!(
+        Sysno::name_to_handle_at.id() as libc::c_long,
+        libc::SYS_name_to_handle_at
+    );
+    assert_eq!(Sysno::nanosleep.id() as libc::c_long, libc::SYS_nanosleep);
+    assert_eq!(Sysno::nfsservctl.id() as libc::c_long, libc::SYS_nfsservctl);
+    assert_eq!(Sysno::nice.id() as libc::c_long, libc::SYS_nice);
+    assert_eq!(Sysno::open.id() as libc::c_long, libc::SYS_open);
+    assert_eq!(
+        Sysno::open_by_handle_at.id() as libc::c_long,
+        libc::SYS_open_by_handle_at
+    );
+    assert_eq!(Sysno::open_tree.id() as libc::c_long, libc::SYS_open_tree);
+    assert_eq!(Sysno::openat.id() as libc::c_long, libc::SYS_openat);
+    assert_eq!(Sysno::openat2.id() as libc::c_long, libc::SYS_openat2);
+    assert_eq!(Sysno::pause.id() as libc::c_long, libc::SYS_pause);
+    assert_eq!(
+        Sysno::pciconfig_iobase.id() as libc::c_long,
+        libc::SYS_pciconfig_iobase
+    );
+    assert_eq!(
+        Sysno::pciconfig_read.id() as libc::c_long,
+        libc::SYS_pciconfig_read
+    );
+    assert_eq!(
+        Sysno::pciconfig_write.id() as libc::c_long,
+        libc::SYS_pciconfig_write
+    );
+    assert_eq!(
+        Sysno::perf_event_open.id() as libc::c_long,
+        libc::SYS_perf_event_open
+    );
+    assert_eq!(
+        Sysno::personality.id() as libc::c_long,
+        libc::SYS_personality
+    );
+    assert_eq!(
+        Sysno::pidfd_getfd.id() as libc::c_long,
+        libc::SYS_pidfd_getfd
+    );
+    assert_eq!(Sysno::pidfd_open.id() as libc::c_long, libc::SYS_pidfd_open);
+    assert_eq!(
+        Sysno::pidfd_send_signal.id() as libc::c_long,
+        libc::SYS_pidfd_send_signal
+    );
+    assert_eq!(Sysno::pipe.id() as libc::c_long, libc::SYS_pipe);
+    assert_eq!(Sysno::pipe2.id() as libc::c_long, libc::SYS_pipe2);
+    assert_eq!(Sysno::pivot_root.id() as libc::c_long, libc::SYS_pivot_root);
+    assert_eq!(Sysno::pkey_alloc.id() as libc::c_long, libc::SYS_pkey_alloc);
+    assert_eq!(Sysno::pkey_free.id() as libc::c_long, libc::SYS_pkey_free);
+    assert_eq!(
+        Sysno::pkey_mprotect.id() as libc::c_long,
+        libc::SYS_pkey_mprotect
+    );
+    assert_eq!(Sysno::poll.id() as libc::c_long, libc::SYS_poll);
+    assert_eq!(Sysno::ppoll.id() as libc::c_long, libc::SYS_ppoll);
+    assert_eq!(Sysno::prctl.id() as libc::c_long, libc::SYS_prctl);
+    assert_eq!(Sysno::pread64.id() as libc::c_long, libc::SYS_pread64);
+    assert_eq!(Sysno::preadv.id() as libc::c_long, libc::SYS_preadv);
+    assert_eq!(Sysno::preadv2.id() as libc::c_long, libc::SYS_preadv2);
+    assert_eq!(Sysno::prlimit64.id() as libc::c_long, libc::SYS_prlimit64);
+    assert_eq!(
+        Sysno::process_madvise.id() as libc::c_long,
+        libc::SYS_process_madvise
+    );
+    assert_eq!(
+        Sysno::process_mrelease.id() as libc::c_long,
+        libc::SYS_process_mrelease
+    );
+    assert_eq!(
+        Sysno::process_vm_readv.id() as libc::c_long,
+        libc::SYS_process_vm_readv
+    );
+    assert_eq!(
+        Sysno::process_vm_writev.id() as libc::c_long,
+        libc::SYS_process_vm_writev
+    );
+    assert_eq!(Sysno::pselect6.id() as libc::c_long, libc::SYS_pselect6);
+    assert_eq!(Sysno::ptrace.id() as libc::c_long, libc::SYS_ptrace);
+    assert_eq!(Sysno::pwrite64.id() as libc::c_long, libc::SYS_pwrite64);
+    assert_eq!(Sysno::pwritev.id() as libc::c_long, libc::SYS_pwritev);
+    assert_eq!(Sysno::pwritev2.id() as libc::c_long, libc::SYS_pwritev2);
+    assert_eq!(Sysno::quotactl.id() as libc::c_long, libc::SYS_quotactl);
+    assert_eq!(
+        Sysno::quotactl_fd.id() as libc::c_long,
+        libc::SYS_quotactl_fd
+    );
+    assert_eq!(Sysno::read.id() as libc::c_long, libc::SYS_read);
+    assert_eq!(Sysno::readahead.id() as libc::c_long, libc::SYS_readahead);
+    assert_eq!(Sysno::readlink.id() as libc::c_long, libc::SYS_readlink);
+    assert_eq!(Sysno::readlinkat.id() as libc::c_long, libc::SYS_readlinkat);
+    assert_eq!(Sysno::readv.id() as libc::c_long, libc::SYS_readv);
+    assert_eq!(Sysno::reboot.id() as libc::c_long, libc::SYS_reboot);
+    assert_eq!(Sysno::recv.id() as libc::c_long, libc::SYS_recv);
+    assert_eq!(Sysno::recvfrom.id() as libc::c_long, libc::SYS_recvfrom);
+    assert_eq!(Sysno::recvmmsg.id() as libc::c_long, libc::SYS_recvmmsg);
+    assert_eq!(Sysno::recvmsg.id() as libc::c_long, libc::SYS_recvmsg);
+    assert_eq!(
+        Sysno::remap_file_pages.id() as libc::c_long,
+        libc::SYS_remap_file_pages
+    );
+    assert_eq!(
+        Sysno::removexattr.id() as libc::c_long,
+        libc::SYS_removexattr
+    );
+    assert_eq!(Sysno::rename.id() as libc::c_long, libc::SYS_rename);
+    assert_eq!(Sysno::renameat.id() as libc::c_long, libc::SYS_renameat);
+    assert_eq!(Sysno::renameat2.id() as libc::c_long, libc::SYS_renameat2);
+    assert_eq!(
+        Sysno::request_key.id() as libc::c_long,
+        libc::SYS_request_key
+    );
+    assert_eq!(
+        Sysno::restart_syscall.id() as libc::c_long,
+        libc::SYS_restart_syscall
+    );
+    assert_eq!(Sysno::rmdir.id() as libc::c_long, libc::SYS_rmdir);
+    assert_eq!(Sysno::rseq.id() as libc::c_long, libc::SYS_rseq);
+    assert_eq!(
+        Sysno::rt_sigaction.id() as libc::c_long,
+        libc::SYS_rt_sigaction
+    );
+    assert_eq!(
+        Sysno::rt_sigpending.id() as libc::c_long,
+        libc::SYS_rt_sigpending
+    );
+    assert_eq!(
+        Sysno::rt_sigprocmask.id() as libc::c_long,
+        libc::SYS_rt_sigprocmask
+    );
+    assert_eq!(
+        Sysno::rt_sigqueueinfo.id() as libc::c_long,
+        libc::SYS_rt_sigqueueinfo
+    );
+    assert_eq!(
+        Sysno::rt_sigreturn.id() as libc::c_long,
+        libc::SYS_rt_sigreturn
+    );
+    assert_eq!(
+        Sysno::rt_sigsuspend.id() as libc::c_long,
+        libc::SYS_rt_sigsuspend
+    );
+    assert_eq!(
+        Sysno::rt_sigtimedwait.id() as libc::c_long,
+        libc::SYS_rt_sigtimedwait
+    );
+    assert_eq!(
+        Sysno::rt_tgsigqueueinfo.id() as libc::c_long,
+        libc::SYS_rt_tgsigqueueinfo
+    );
+    assert_eq!(
+        Sysno::sched_get_priority_max.id() as libc::c_long,
+        libc::SYS_sched_get_priority_max
+    );
+    assert_eq!(
+        Sysno::sched_get_priority_min.id() as libc::c_long,
+        libc::SYS_sched_get_priority_min
+    );
+    assert_eq!(
+        Sysno::sched_getaffinity.id() as libc::c_long,
+        libc::SYS_sched_getaffinity
+    );
+    assert_eq!(
+        Sysno::sched_getattr.id() as libc::c_long,
+        libc::SYS_sched_getattr
+    );
+    assert_eq!(
+        Sysno::sched_getparam.id() as libc::c_long,
+        libc::SYS_sched_getparam
+    );
+    assert_eq!(
+        Sysno::sched_getscheduler.id() as libc::c_long,
+        libc::SYS_sched_getscheduler
+    );
+    assert_eq!(
+        Sysno::sched_rr_get_interval.id() as libc::c_long,
+        libc::SYS_sched_rr_get_interval
+    );
+    assert_eq!(
+        Sysno::sched_setaffinity.id() as libc::c_long,
+        libc::SYS_sched_setaffinity
+    );
+    assert_eq!(
+        Sysno::sched_setattr.id() as libc::c_long,
+        libc::SYS_sched_setattr
+    );
+    assert_eq!(
+        Sysno::sched_setparam.id() as libc::c_long,
+        libc::SYS_sched_setparam
+    );
+    assert_eq!(
+        Sysno::sched_setscheduler.id() as libc::c_long,
+        libc::SYS_sched_setscheduler
+    );
+    assert_eq!(
+        Sysno::sched_yield.id() as libc::c_long,
+        libc::SYS_sched_yield
+    );
+    assert_eq!(Sysno::seccomp.id() as libc::c_long, libc::SYS_seccomp);
+    assert_eq!(Sysno::semctl.id() as libc::c_long, libc::SYS_semctl);
+    assert_eq!(Sysno::semget.id() as libc::c_long, libc::SYS_semget);
+    assert_eq!(Sysno::semop.id() as libc::c_long, libc::SYS_semop);
+    assert_eq!(Sysno::semtimedop.id() as libc::c_long, libc::SYS_semtimedop);
+    assert_eq!(Sysno::send.id() as libc::c_long, libc::SYS_send);
+    assert_eq!(Sysno::sendfile.id() as libc::c_long, libc::SYS_sendfile);
+    assert_eq!(Sysno::sendfile64.id() as libc::c_long, libc::SYS_sendfile64);
+    assert_eq!(Sysno::sendmmsg.id() as libc::c_long, libc::SYS_sendmmsg);
+    assert_eq!(Sysno::sendmsg.id() as libc::c_long, libc::SYS_sendmsg);
+    assert_eq!(Sysno::sendto.id() as libc::c_long, libc::SYS_sendto);
+    assert_eq!(
+        Sysno::set_mempolicy.id() as libc::c_long,
+        libc::SYS_set_mempolicy
+    );
+    assert_eq!(
+        Sysno::set_mempolicy_home_node.id() as libc::c_long,
+        libc::SYS_set_mempolicy_home_node
+    );
+    assert_eq!(
+        Sysno::set_robust_list.id() as libc::c_long,
+        libc::SYS_set_robust_list
+    );
+    assert_eq!(
+        Sysno::set_tid_address.id() as libc::c_long,
+        libc::SYS_set_tid_address
+    );
+    assert_eq!(
+        Sysno::setdomainname.id() as libc::c_long,
+        libc::SYS_setdomainname
+    );
+    assert_eq!(Sysno::setfsgid.id() as libc::c_long, libc::SYS_setfsgid);
+    assert_eq!(Sysno::setfsgid32.id() as libc::c_long, libc::SYS_setfsgid32);
+    assert_eq!(Sysno::setfsuid.id() as libc::c_long, libc::SYS_setfsuid);
+    assert_eq!(Sysno::setfsuid32.id() as libc::c_long, libc::SYS_setfsuid32);
+    assert_eq!(Sysno::setgid.id() as libc::c_long, libc::SYS_setgid);
+    assert_eq!(Sysno::setgid32.id() as libc::c_long, libc::SYS_setgid32);
+    assert_eq!(Sysno::setgroups.id() as libc::c_long, libc::SYS_setgroups);
+    assert_eq!(
+        Sysno::setgroups32.id() as libc::c_long,
+        libc::SYS_setgroups32
+    );
+    assert_eq!(
+        Sysno::sethostname.id() as libc::c_long,
+        libc::SYS_sethostname
+    );
+    assert_eq!(Sysno::setitimer.id() as libc::c_long, libc::SYS_setitimer);
+    assert_eq!(Sysno::setns.id() as libc::c_long, libc::SYS_setns);
+    assert_eq!(Sysno::setpgid.id() as libc::c_long, libc::SYS_setpgid);
+    assert_eq!(
+        Sysno::setpriority.id() as libc::c_long,
+        libc::SYS_setpriority
+    );
+    assert_eq!(Sysno::setregid.id() as libc::c_long, libc::SYS_setregid);
+    assert_eq!(Sysno::setregid32.id() as libc::c_long, libc::SYS_setregid32);
+    assert_eq!(Sysno::setresgid.id() as libc::c_long, libc::SYS_setresgid);
+    assert_eq!(
+        Sysno::setresgid32.id() as libc::c_long,
+        libc::SYS_setresgid32
+    );
+    assert_eq!(Sysno::setresuid.id() as libc::c_long, libc::SYS_setresuid);
+    assert_eq!(
+        Sysno::setresuid32.id() as libc::c_long,
+        libc::SYS_setresuid32
+    );
+    assert_eq!(Sysno::setreuid.id() as libc::c_long, libc::SYS_setreuid);
+    assert_eq!(Sysno::setreuid32.id() as libc::c_long, libc::SYS_setreuid32);
+    assert_eq!(Sysno::setrlimit.id() as libc::c_long, libc::SYS_setrlimit);
+    assert_eq!(Sysno::setsid.id() as libc::c_long, libc::SYS_setsid);
+    assert_eq!(Sysno::setsockopt.id() as libc::c_long, libc::SYS_setsockopt);
+    assert_eq!(
+        Sysno::settimeofday.id() as libc::c_long,
+        libc::SYS_settimeofday
+    );
+    assert_eq!(Sysno::setuid.id() as libc::c_long, libc::SYS_setuid);
+    assert_eq!(Sysno::setuid32.id() as libc::c_long, libc::SYS_setuid32);
+    assert_eq!(Sysno::setxattr.id() as libc::c_long, libc::SYS_setxattr);
+    assert_eq!(Sysno::shmat.id() as libc::c_long, libc::SYS_shmat);
+    assert_eq!(Sysno::shmctl.id() as libc::c_long, libc::SYS_shmctl);
+    assert_eq!(Sysno::shmdt.id() as libc::c_long, libc::SYS_shmdt);
+    assert_eq!(Sysno::shmget.id() as libc::c_long, libc::SYS_shmget);
+    assert_eq!(Sysno::shutdown.id() as libc::c_long, libc::SYS_shutdown);
+    assert_eq!(Sysno::sigaction.id() as libc::c_long, libc::SYS_sigaction);
+    assert_eq!(
+        Sysno::sigaltstack.id() as libc::c_long,
+        libc::SYS_sigaltstack
+    );
+    assert_eq!(Sysno::signalfd.id() as libc::c_long, libc::SYS_signalfd);
+    assert_eq!(Sysno::signalfd4.id() as libc::c_long, libc::SYS_signalfd4);
+    assert_eq!(Sysno::sigpending.id() as libc::c_long, libc::SYS_sigpending);
+    assert_eq!(
+        Sysno::sigprocmask.id() as libc::c_long,
+        libc::SYS_sigprocmask
+    );
+    assert_eq!(Sysno::sigreturn.id() as libc::c_long, libc::SYS_sigreturn);
+    assert_eq!(Sysno::sigsuspend.id() as libc::c_long, libc::SYS_sigsuspend);
+    assert_eq!(Sysno::socket.id() as libc::c_long, libc::SYS_socket);
+    assert_eq!(Sysno::socketpair.id() as libc::c_long, libc::SYS_socketpair);
+    assert_eq!(Sysno::splice.id() as libc::c_long, libc::SYS_splice);
+    assert_eq!(Sysno::stat.id() as libc::c_long, libc::SYS_stat);
+    assert_eq!(Sysno::stat64.id() as libc::c_long, libc::SYS_stat64);
+    assert_eq!(Sysno::statfs.id() as libc::c_long, libc::SYS_statfs);
+    assert_eq!(Sysno::statfs64.id() as libc::c_long, libc::SYS_statfs64);
+    assert_eq!(Sysno::statx.id() as libc::c_long, libc::SYS_statx);
+    assert_eq!(Sysno::swapoff.id() as libc::c_long, libc::SYS_swapoff);
+    assert_eq!(Sysno::swapon.id() as libc::c_long, libc::SYS_swapon);
+    assert_eq!(Sysno::symlink.id() as libc::c_long, libc::SYS_symlink);
+    assert_eq!(Sysno::symlinkat.id() as libc::c_long, libc::SYS_symlinkat);
+    assert_eq!(Sysno::sync.id() as libc::c_long, libc::SYS_sync);
+    assert_eq!(Sysno::syncfs.id() as libc::c_long, libc::SYS_syncfs);
+    assert_eq!(Sysno::sysfs.id() as libc::c_long, libc::SYS_sysfs);
+    assert_eq!(Sysno::sysinfo.id() as libc::c_long, libc::SYS_sysinfo);
+    assert_eq!(Sysno::syslog.id() as libc::c_long, libc::SYS_syslog);
+    assert_eq!(Sysno::tee.id() as libc::c_long, libc::SYS_tee);
+    assert_eq!(Sysno::tgkill.id() as libc::c_long, libc::SYS_tgkill);
+    assert_eq!(
+        Sysno::timer_create.id() as libc::c_long,
+        libc::SYS_timer_create
+    );
+    assert_eq!(
+        Sysno::timer_delete.id() as libc::c_long,
+        libc::SYS_timer_delete
+    );
+    assert_eq!(
+        Sysno::timer_getoverrun.id() as libc::c_long,
+        libc::SYS_timer_getoverrun
+    );
+    assert_eq!(
+        Sysno::timer_gettime.id() as libc::c_long,
+        libc::SYS_timer_gettime
+    );
+    assert_eq!(
+        Sysno::timer_settime.id() as libc::c_long,
+        libc::SYS_timer_settime
+    );
+    assert_eq!(
+        Sysno::timerfd_create.id() as libc::c_long,
+        libc::SYS_timerfd_create
+    );
+    assert_eq!(
+        Sysno::timerfd_gettime.id() as libc::c_long,
+        libc::SYS_timerfd_gettime
+    );
+    assert_eq!(
+        Sysno::timerfd_settime.id() as libc::c_long,
+        libc::SYS_timerfd_settime
+    );
+    assert_eq!(Sysno::times.id() as libc::c_long, libc::SYS_times);
+    assert_eq!(Sysno::tkill.id() as libc::c_long, libc::SYS_tkill);
+    assert_eq!(Sysno::truncate.id() as libc::c_long, libc::SYS_truncate);
+    assert_eq!(Sysno::truncate64.id() as libc::c_long, libc::SYS_truncate64);
+    assert_eq!(Sysno::ugetrlimit.id() as libc::c_long, libc::SYS_ugetrlimit);
+    assert_eq!(Sysno::umask.id() as libc::c_long, libc::SYS_umask);
+    assert_eq!(Sysno::umount2.id() as libc::c_long, libc::SYS_umount2);
+    assert_eq!(Sysno::uname.id() as libc::c_long, libc::SYS_uname);
+    assert_eq!(Sysno::unlink.id() as libc::c_long, libc::SYS_unlink);
+    assert_eq!(Sysno::unlinkat.id() as libc::c_long, libc::SYS_unlinkat);
+    assert_eq!(Sysno::unshare.id() as libc::c_long, libc::SYS_unshare);
+    assert_eq!(Sysno::uselib.id() as libc::c_long, libc::SYS_uselib);
+    assert_eq!(
+        Sysno::userfaultfd.id() as libc::c_long,
+        libc::SYS_userfaultfd
+    );
+    assert_eq!(Sysno::ustat.id() as libc::c_long, libc::SYS_ustat);
+    assert_eq!(Sysno::utimensat.id() as libc::c_long, libc::SYS_utimensat);
+    assert_eq!(Sysno::utimes.id() as libc::c_long, libc::SYS_utimes);
+    assert_eq!(Sysno::vfork.id() as libc::c_long, libc::SYS_vfork);
+    assert_eq!(Sysno::vhangup.id() as libc::c_long, libc::SYS_vhangup);
+    assert_eq!(Sysno::vmsplice.id() as libc::c_long, libc::SYS_vmsplice);
+    assert_eq!(Sysno::vserver.id() as libc::c_long, libc::SYS_vserver);
+    assert_eq!(Sysno::wait4.id() as libc::c_long, libc::SYS_wait4);
+    assert_eq!(Sysno::waitid.id() as libc::c_long, libc::SYS_waitid);
+    assert_eq!(Sysno::write.id() as libc::c_long, libc::SYS_write);
+    assert_eq!(Sysno::writev.id() as libc::c_long, libc::SYS_writev);
+}
+
+#[cfg(all(target_arch = "loongarch64", target_pointer_width = "64"))]
+#[test]
+fn loongarch64() {
+    use rawsys_linux::loongarch64::v6_12::Sysno;
+
+    assert_eq!(Sysno::accept.id() as libc::c_long, libc::SYS_accept);
+    assert_eq!(Sysno::accept4.id() as libc::c_long, libc::SYS_accept4);
+    assert_eq!(Sysno::acct.id() as libc::c_long, libc::SYS_acct);
+    assert_eq!(Sysno::add_key.id() as libc::c_long, libc::SYS_add_key);
+    assert_eq!(Sysno::adjtimex.id() as libc::c_long, libc::SYS_adjtimex);
+    assert_eq!(Sysno::bind.id() as libc::c_long, libc::SYS_bind);
+    assert_eq!(Sysno::bpf.id() as libc::c_long, libc::SYS_bpf);
+    assert_eq!(Sysno::brk.id() as libc::c_long, libc::SYS_brk);
+    assert_eq!(Sysno::capget.id() as libc::c_long, libc::SYS_capget);
+    assert_eq!(Sysno::capset.id() as libc::c_long, libc::SYS_capset);
+    assert_eq!(Sysno::chdir.id() as libc::c_long, libc::SYS_chdir);
+    assert_eq!(Sysno::chroot.id() as libc::c_long, libc::SYS_chroot);
+    assert_eq!(
+        Sysno::clock_adjtime.id() as libc::c_long,
+        libc::SYS_clock_adjtime
+    );
+    assert_eq!(
+        Sysno::clock_getres.id() as libc::c_long,
+        libc::SYS_clock_getres
+    );
+    assert_eq!(
+        Sysno::clock_gettime.id() as libc::c_long,
+        libc::SYS_clock_gettime
+    );
+    assert_eq!(
+        Sysno::clock_nanosleep.id() as libc::c_long,
+        libc::SYS_clock_nanosleep
+    );
+    assert_eq!(
+        Sysno::clock_settime.id() as libc::c_long,
+        libc::SYS_clock_settime
+    );
+    assert_eq!(Sysno::clone.id() as libc::c_long, libc::SYS_clone);
+    assert_eq!(Sysno::clone3.id() as libc::c_long, libc::SYS_clone3);
+    assert_eq!(Sysno::close.id() as libc::c_long, libc::SYS_close);
+    assert_eq!(
+        Sysno::close_range.id() as libc::c_long,
+        libc::SYS_close_range
+    );
+    assert_eq!(Sysno::connect.id() as libc::c_long, libc::SYS_connect);
+    assert_eq!(
+        Sysno::copy_file_range.id() as libc::c_long,
+        libc::SYS_copy_file_range
+    );
+    assert_eq!(
+        Sysno::delete_module.id() as libc::c_long,
+        libc::SYS_delete_module
+    );
+    assert_eq!(Sysno::dup.id() as libc::c_long, libc::SYS_dup);
+    assert_eq!(Sysno::dup3.id() as libc::c_long, libc::SYS_dup3);
+    assert_eq!(
+        Sysno::epoll_create1.id() as libc::c_long,
+        libc::SYS_epoll_create1
+    );
+    assert_eq!(Sysno::epoll_ctl.id() as libc::c_long, libc::SYS_epoll_ctl);
+    assert_eq!(
+        Sysno::epoll_pwait.id() as libc::c_long,
+        libc::SYS_epoll_pwait
+    );
+    assert_eq!(
+        Sysno::epoll_pwait2.id() as libc::c_long,
+        libc::SYS_epoll_pwait2
+    );
+    assert_eq!(Sysno::eventfd2.id() as libc::c_long, libc::SYS_eventfd2);
+    assert_eq!(Sysno::execve.id() as libc::c_long, libc::SYS_execve);
+    assert_eq!(Sysno::execveat.id() as libc::c_long, libc::SYS_execveat);
+    assert_eq!(Sysno::exit.id() as libc::c_long, libc::SYS_exit);
+    assert_eq!(Sysno::exit_group.id() as libc::c_long, libc::SYS_exit_group);
+    assert_eq!(Sysno::faccessat.id() as libc::c_long, libc::SYS_faccessat);
+    assert_eq!(Sysno::faccessat2.id() as libc::c_long, libc::SYS_faccessat2);
+    assert_eq!(Sysno::fadvise64.id() as libc::c_long, libc::SYS_fadvise64);
+    assert_eq!(Sysno::fallocate.id() as libc::c_long, libc::SYS_fallocate);
+    assert_eq!(
+        Sysno::fanotify_init.id() as libc::c_long,
+        libc::SYS_fanotify_init
+    );
+    assert_eq!(
+        Sysno::fanotify_mark.id() as libc::c_long,
+        libc::SYS_fanotify_mark
+    );
+    assert_eq!(Sysno::fchdir.id() as libc::c_long, libc::SYS_fchdir);
+    assert_eq!(Sysno::fchmod.id() as libc::c_long, libc::SYS_fchmod);
+    assert_eq!(Sysno::fchmodat.id() as libc::c_long, libc::SYS_fchmodat);
+    assert_eq!(Sysno::fchown.id() as libc::c_long, libc::SYS_fchown);
+    assert_eq!(Sysno::fchownat.id() as libc::c_long, libc::SYS_fchownat);
+    assert_eq!(Sysno::fcntl.id() as libc::c_long, libc::SYS_fcntl);
+    assert_eq!(Sysno::fdatasync.id() as libc::c_long, libc::SYS_fdatasync);
+    assert_eq!(Sysno::fgetxattr.id() as libc::c_long, libc::SYS_fgetxattr);
+    assert_eq!(
+        Sysno::finit_module.id() as libc::c_long,
+        libc::SYS_finit_module
+    );
+    assert_eq!(Sysno::flistxattr.id() as libc::c_long, libc::SYS_flistxattr);
+    assert_eq!(Sysno::flock.id() as libc::c_long, libc::SYS_flock);
+    assert_eq!(
+        Sysno::fremovexattr.id() as libc::c_long,
+        libc::SYS_fremovexattr
+    );
+    assert_eq!(Sysno::fsconfig.id() as libc::c_long, libc::SYS_fsconfig);
+    assert_eq!(Sysno::fsetxattr.id() as libc::c_long, libc::SYS_fsetxattr);
+    assert_eq!(Sysno::fsmount.id() as libc::c_long, libc::SYS_fsmount);
+    assert_eq!(Sysno::fsopen.id() as libc::c_long, libc::SYS_fsopen);
+    assert_eq!(Sysno::fspick.id() as libc::c_long, libc::SYS_fspick);
+    assert_eq!(Sysno::fstatfs.id() as libc::c_long, libc::SYS_fstatfs);
+    assert_eq!(Sysno::fsync.id() as libc::c_long, libc::SYS_fsync);
+    assert_eq!(Sysno::ftruncate.id() as libc::c_long, libc::SYS_ftruncate);
+    assert_eq!(Sysno::futex.id() as libc::c_long, libc::SYS_futex);
+    assert_eq!(
+        Sysno::futex_waitv.id() as libc::c_long,
+        libc::SYS_futex_waitv
+    );
+    assert_eq!(
+        Sysno::get_mempolicy.id() as libc::c_long,
+        libc::SYS_get_mempolicy
+    );
+    assert_eq!(
+        Sysno::get_robust_list.id() as libc::c_long,
+        libc::SYS_get_robust_list
+    );
+    assert_eq!(Sysno::getcpu.id() as libc::c_long, libc::SYS_getcpu);
+    assert_eq!(Sysno::getcwd.id() as libc::c_long, libc::SYS_getcwd);
+    assert_eq!(Sysno::getdents64.id() as libc::c_long, libc::SYS_getdents64);
+    assert_eq!(Sysno::getegid.id() as libc::c_long, libc::SYS_getegid);
+    assert_eq!(Sysno::geteuid.id() as libc::c_long, libc::SYS_geteuid);
+    assert_eq!(Sysno::getgid.id() as libc::c_long, libc::SYS_getgid);
+    assert_eq!(Sysno::getgroups.id() as libc::c_long, libc::SYS_getgroups);
+    assert_eq!(Sysno::getitimer.id() as libc::c_long, libc::SYS_getitimer);
+    assert_eq!(
+        Sysno::getpeername.id() as libc::c_long,
+        libc::SYS_getpeername
+    );
+    assert_eq!(Sysno::getpgid.id() as libc::c_long, libc::SYS_getpgid);
+    assert_eq!(Sysno::getpid.id() as libc::c_long, libc::SYS_getpid);
+    assert_eq!(Sysno::getppid.id() as libc::c_long, libc::SYS_getppid);
+    assert_eq!(
+        Sysno::getpriority.id() as libc::c_long,
+        libc::SYS_getpriority
+    );
+    assert_eq!(Sysno::getrandom.id() as libc::c_long, libc::SYS_getrandom);
+    assert_eq!(Sysno::getresgid.id() as libc::c_long, libc::SYS_getresgid);
+    assert_eq!(Sysno::getresuid.id() as libc::c_long, libc::SYS_getresuid);
+    assert_eq!(Sysno::getrusage.id() as libc::c_long, libc::SYS_getrusage);
+    assert_eq!(Sysno::getsid.id() as libc::c_long, libc::SYS_getsid);
+    assert_eq!(
+        Sysno::getsockname.id() as libc::c_long,
+        libc::SYS_getsockname
+    );
+    assert_eq!(Sysno::getsockopt.id() as libc::c_long, libc::SYS_getsockopt);
+    assert_eq!(Sysno::gettid.id() as libc::c_long, libc::SYS_gettid);
+    assert_eq!(
+        Sysno::gettimeofday.id() as libc::c_long,
+        libc::SYS_gettimeofday
+    );
+    assert_eq!(Sysno::getuid.id() as libc::c_long, libc::SYS_getuid);
+    assert_eq!(Sysno::getxattr.id() as libc::c_long, libc::SYS_getxattr);
+    assert_eq!(
+        Sysno::init_module.id() as libc::c_long,
+        libc::SYS_init_module
+    );
+    assert_eq!(
+        Sysno::inotify_add_watch.id() as libc::c_long,
+        libc::SYS_inotify_add_watch
+    );
+    assert_eq!(
+        Sysno::inotify_init1.id() as libc::c_long,
+        libc::SYS_inotify_init1
+    );
+    assert_eq!(
+        Sysno::inotify_rm_watch.id() as libc::c_long,
+        libc::SYS_inotify_rm_watch
+    );
+    assert_eq!(Sysno::io_cancel.id() as libc::c_long, libc::SYS_io_cancel);
+    assert_eq!(Sysno::io_destroy.id() as libc::c_long, libc::SYS_io_destroy);
+    assert_eq!(
+        Sysno::io_getevents.id() as libc::c_long,
+        libc::SYS_io_getevents
+    );
+    assert_eq!(
+        Sysno::io_pgetevents.id() as libc::c_long,
+        libc::SYS_io_pgetevents
+    );
+    assert_eq!(Sysno::io_setup.id() as libc::c_long, libc::SYS_io_setup);
+    assert_eq!(Sysno::io_submit.id() as libc::c_long, libc::SYS_io_submit);
+    assert_eq!(
+        Sysno::io_uring_enter.id() as libc::c_long,
+        libc::SYS_io_uring_enter
+    );
+    assert_eq!(
+        Sysno::io_uring_register.id() as libc::c_long,
+        libc::SYS_io_uring_register
+    );
+    assert_eq!(
+        Sysno::io_uring_setup.id() as libc::c_long,
+        libc::SYS_io_uring_setup
+    );
+    assert_eq!(Sysno::ioctl.id() as libc::c_long, libc::SYS_ioctl);
+    assert_eq!(Sysno::ioprio_get.id() as libc::c_long, libc::SYS_ioprio_get);
+    assert_eq!(Sysno::ioprio_set.id() as libc::c_long, libc::SYS_ioprio_set);
+    assert_eq!(Sysno::kcmp.id() as libc::c_long, libc::SYS_kcmp);
+    assert_eq!(
+        Sysno::kexec_file_load.id() as libc::c_long,
+        libc::SYS_kexec_file_load
+    );
+    assert_eq!(Sysno::kexec_load.id() as libc::c_long, libc::SYS_kexec_load);
+    assert_eq!(Sysno::keyctl.id() as libc::c_long, libc::SYS_keyctl);
+    assert_eq!(Sysno::kill.id() as libc::c_long, libc::SYS_kill);
+    assert_eq!(
+        Sysno::landlock_add_rule.id() as libc::c_long,
+        libc::SYS_landlock_add_rule
+    );
+    assert_eq!(
+        Sysno::landlock_create_ruleset.id() as libc::c_long,
+        libc::SYS_landlock_create_ruleset
+    );
+    assert_eq!(
+        Sysno::landlock_restrict_self.id() as libc::c_long,
+        libc::SYS_landlock_restrict_self
+    );
+    assert_eq!(Sysno::lgetxattr.id() as libc::c_long, libc::SYS_lgetxattr);
+    assert_eq!(Sysno::linkat.id() as libc::c_long, libc::SYS_linkat);
+    assert_eq!(Sysno::listen.id() as libc::c_long, libc::SYS_listen);
+    assert_eq!(Sysno::listxattr.id() as libc::c_long, libc::SYS_listxattr);
+    assert_eq!(Sysno::llistxattr.id() as libc::c_long, libc::SYS_llistxattr);
+    assert_eq!(
+        Sysno::lookup_dcookie.id() as libc::c_long,
+        libc::SYS_lookup_dcookie
+    );
+    assert_eq!(
+        Sysno::lremovexattr.id() as libc::c_long,
+        libc::SYS_lremovexattr
+    );
+    assert_eq!(Sysno::lseek.id() as libc::c_long, libc::SYS_lseek);
+    assert_eq!(Sysno::lsetxattr.id() as libc::c_long, libc::SYS_lsetxattr);
+    assert_eq!(Sysno::madvise.id() as libc::c_long, libc::SYS_madvise);
+    assert_eq!(Sysno::mbind.id() as libc::c_long, libc::SYS_mbind);
+    assert_eq!(Sysno::membarrier.id() as libc::c_long, libc::SYS_membarrier);
+    assert_eq!(
+        Sysno::memfd_create.id() as libc::c_long,
+        libc::SYS_memfd_create
+    );
+    assert_eq!(
+        Sysno::migrate_pages.id() as libc::c_long,
+        libc::SYS_migrate_pages
+    );
+    assert_eq!(Sysno::mincore.id() as libc::c_long, libc::SYS_mincore);
+    assert_eq!(Sysno::mkdirat.id() as libc::c_long, libc::SYS_mkdirat);
+    assert_eq!(Sysno::mknodat.id() as libc::c_long, libc::SYS_mknodat);
+    assert_eq!(Sysno::mlock.id() as libc::c_long, libc::SYS_mlock);
+    assert_eq!(Sysno::mlock2.id() as libc::c_long, libc::SYS_mlock2);
+    assert_eq!(Sysno::mlockall.id() as libc::c_long, libc::SYS_mlockall);
+    assert_eq!(Sysno::mmap.id() as libc::c_long, libc::SYS_mmap);
+    assert_eq!(Sysno::mount.id() as libc::c_long, libc::SYS_mount);
+    assert_eq!(
+        Sysno::mount_setattr.id() as libc::c_long,
+        libc::SYS_mount_setattr
+    );
+    assert_eq!(Sysno::move_mount.id() as libc::c_long, libc::SYS_move_mount);
+    assert_eq!(Sysno::move_pages.id() as libc::c_long, libc::SYS_move_pages);
+    assert_eq!(Sysno::mprotect.id() as libc::c_long, libc::SYS_mprotect);
+    assert_eq!(
+        Sysno::mq_getsetattr.id() as libc::c_long,
+        libc::SYS_mq_getsetattr
+    );
+    assert_eq!(Sysno::mq_notify.id() as libc::c_long, libc::SYS_mq_notify);
+    assert_eq!(Sysno::mq_open.id() as libc::c_long, libc::SYS_mq_open);
+    assert_eq!(
+        Sysno::mq_timedreceive.id() as libc::c_long,
+        libc::SYS_mq_timedreceive
+    );
+    assert_eq!(
+        Sysno::mq_timedsend.id() as libc::c_long,
+        libc::SYS_mq_timedsend
+    );
+    assert_eq!(Sysno::mq_unlink.id() as libc::c_long, libc::SYS_mq_unlink);
+    assert_eq!(Sysno::mremap.id() as libc::c_long, libc::SYS_mremap);
+    assert_eq!(Sysno::msgctl.id() as libc::c_long, libc::SYS_msgctl);
+    assert_eq!(Sysno::msgget.id() as libc::c_long, libc::SYS_msgget);
+    assert_eq!(Sysno::msgrcv.id() as libc::c_long, libc::SYS_msgrcv);
+    assert_eq!(Sysno::msgsnd.id() as libc::c_long, libc::SYS_msgsnd);
+    assert_eq!(Sysno::msync.id() as libc::c_long, libc::SYS_msync);
+    assert_eq!(Sysno::munlock.id() as libc::c_long, libc::SYS_munlock);
+    assert_eq!(Sysno::munlockall.id() as libc::c_long, libc::SYS_munlockall);
+    assert_eq!(Sysno::munmap.id() as libc::c_long, libc::SYS_munmap);
+    assert_eq!(
+        Sysno::name_to_handle_at.id() as libc::c_long,
+        libc::SYS_name_to_handle_at
+    );
+    assert_eq!(Sysno::nanosleep.id() as libc::c_long, libc::SYS_nanosleep);
+    assert_eq!(Sysno::nfsservctl.id() as libc::c_long, libc::SYS_nfsservctl);
+    assert_eq!(
+        Sysno::open_by_handle_at.id() as libc::c_long,
+        libc::SYS_open_by_handle_at
+    );
+    assert_eq!(Sysno::open_tree.id() as libc::c_long, libc::SYS_open_tree);
+    assert_eq!(Sysno::openat.id() as libc::c_long, libc::SYS_openat);
+    assert_eq!(Sysno::openat2.id() as libc::c_long, libc::SYS_openat2);
+    assert_eq!(
+        Sysno::perf_event_open.id() as libc::c_long,
+        libc::SYS_perf_event_open
+    );
+    assert_eq!(
+        Sysno::personality.id() as libc::c_long,
+        libc::SYS_personality
+    );
+    assert_eq!(
+        Sysno::pidfd_getfd.id() as libc::c_long,
+        libc::SYS_pidfd_getfd
+    );
+    assert_eq!(Sysno::pidfd_open.id() as libc::c_long, libc::SYS_pidfd_open);
+    assert_eq!(
+        Sysno::pidfd_send_signal.id() as libc::c_long,
+        libc::SYS_pidfd_send_signal
+    );
+    assert_eq!(Sysno::pipe2.id() as libc::c_long, libc::SYS_pipe2);
+    assert_eq!(Sysno::pivot_root.id() as libc::c_long, libc::SYS_pivot_root);
+    assert_eq!(Sysno::pkey_alloc.id() as libc::c_long, libc::SYS_pkey_alloc);
+    assert_eq!(Sysno::pkey_free.id() as libc::c_long, libc::SYS_pkey_free);
+    assert_eq!(
+        Sysno::pkey_mprotect.id() as libc::c_long,
+        libc::SYS_pkey_mprotect
+    );
+    assert_eq!(Sysno::ppoll.id() as libc::c_long, libc::SYS_ppoll);
+    assert_eq!(Sysno::prctl.id() as libc::c_long, libc::SYS_prctl);
+    assert_eq!(Sysno::pread64.id() as libc::c_long, libc::SYS_pread64);
+    assert_eq!(Sysno::preadv.id() as libc::c_long, libc::SYS_preadv);
+    assert_eq!(Sysno::preadv2.id() as libc::c_long, libc::SYS_preadv2);
+    assert_eq!(Sysno::prlimit64.id() as libc::c_long, libc::SYS_prlimit64);
+    assert_eq!(
+        Sysno::process_madvise.id() as libc::c_long,
+        libc::SYS_process_madvise
+    );
+    assert_eq!(
+        Sysno::process_mrelease.id() as libc::c_long,
+        libc::SYS_process_mrelease
+    );
+    assert_eq!(
+        Sysno::process_vm_readv.id() as libc::c_long,
+        libc::SYS_process_vm_readv
+    );
+    assert_eq!(
+        Sysno::process_vm_writev.id() as libc::c_long,
+        libc::SYS_process_vm_writev
+    );
+    assert_eq!(Sysno::pselect6.id() as libc::c_long, libc::SYS_pselect6);
+    assert_eq!(Sysno::ptrace.id() as libc::c_long, libc::SYS_ptrace);
+    assert_eq!(Sysno::pwrite64.id() as libc::c_long, libc::SYS_pwrite64);
+    assert_eq!(Sysno::pwritev.id() as libc::c_long, libc::SYS_pwritev);
+    assert_eq!(Sysno::pwritev2.id() as libc::c_long, libc::SYS_pwritev2);
+    assert_eq!(Sysno::quotactl.id() as libc::c_long, libc::SYS_quotactl);
+    assert_eq!(
+        Sysno::quotactl_fd.id() as libc::c_long,
+        libc::SYS_quotactl_fd
+    );
+    assert_eq!(Sysno::read.id() as libc::c_long, libc::SYS_read);
+    assert_eq!(Sysno::readahead.id() as libc::c_long, libc::SYS_readahead);
+    assert_eq!(Sysno::readlinkat.id() as libc::c_long, libc::SYS_readlinkat);
+    assert_eq!(Sysno::readv.id() as libc::c_long, libc::SYS_readv);
+    assert_eq!(Sysno::reboot.id() as libc::c_long, libc::SYS_reboot);
+    assert_eq!(Sysno::recvfrom.id() as libc::c_long, libc::SYS_recvfrom);
+    assert_eq!(Sysno::recvmmsg.id() as libc::c_long, libc::SYS_recvmmsg);
+    assert_eq!(Sysno::recvmsg.id() as libc::c_long, libc::SYS_recvmsg);
+    assert_eq!(
+        Sysno::remap_file_pages.id() as libc::c_long,
+        libc::SYS_remap_file_pages
+    );
+    assert_eq!(
+        Sysno::removexattr.id() as libc::c_long,
+        libc::SYS_removexattr
+    );
+    assert_eq!(Sysno::renameat2.id() as libc::c_long, libc::SYS_renameat2);
+    assert_eq!(
+        Sysno::request_key.id() as libc::c_long,
+        libc::SYS_request_key
+    );
+    assert_eq!(
+        Sysno::restart_syscall.id() as libc::c_long,
+        libc::SYS_restart_syscall
+    );
+    assert_eq!(Sysno::rseq.id() as libc::c_long, libc::SYS_rseq);
+    assert_eq!(
+        Sysno::rt_sigaction.id() as libc::c_long,
+        libc::SYS_rt_sigaction
+    );
+    assert_eq!(
+        Sysno::rt_sigpending.id() as libc::c_long,
+        libc::SYS_rt_sigpending
+    );
+    assert_eq!(
+        Sysno::rt_sigprocmask.id() as libc::c_long,
+        libc::SYS_rt_sigprocmask
+    );
+    assert_eq!(
+        Sysno::rt_sigqueueinfo.id() as libc::c_long,
+        libc::SYS_rt_sigqueueinfo
+    );
+    assert_eq!(
+        Sysno::rt_sigreturn.id() as libc::c_long,
+        libc::SYS_rt_sigreturn
+    );
+    assert_eq!(
+        Sysno::rt_sigsuspend.id() as libc::c_long,
+        libc::SYS_rt_sigsuspend
+    );
+    assert_eq!(
+        Sysno::rt_sigtimedwait.id() as libc::c_long,
+        libc::SYS_rt_sigtimedwait
+    );
+    assert_eq!(
+        Sysno::rt_tgsigqueueinfo.id() as libc::c_long,
+        libc::SYS_rt_tgsigqueueinfo
+    );
+    assert_eq!(
+        Sysno::sched_get_priority_max.id() as libc::c_long,
+        libc::SYS_sched_get_priority_max
+    );
+    assert_eq!(
+        Sysno::sched_get_priority_min.id() as libc::c_long,
+        libc::SYS_sched_get_priority_min
+    );
+    assert_eq!(
+        Sysno::sched_getaffinity.id() as libc::c_long,
+        libc::SYS_sched_getaffinity
+    );
+    assert_eq!(
+        Sysno::sched_getattr.id() as libc::c_long,
+        libc::SYS_sched_getattr
+    );
+    assert_eq!(
+        Sysno::sched_getparam.id() as libc::c_long,
+        libc::SYS_sched_getparam
+    );
+    assert_eq!(
+        Sysno::sched_getscheduler.id() as libc::c_long,
+        libc::SYS_sched_getscheduler
+    );
+    assert_eq!(
+        Sysno::sched_rr_get_interval.id() as libc::c_long,
+        libc::SYS_sched_rr_get_interval
+    );
+    assert_eq!(
+        Sysno::sched_setaffinity.id() as libc::c_long,
+        libc::SYS_sched_setaffinity
+    );
+    assert_eq!(
+        Sysno::sched_setattr.id() as libc::c_long,
+        libc::SYS_sched_setattr
+    );
+    assert_eq!(
+        Sysno::sched_setparam.id() as libc::c_long,
+        libc::SYS_sched_setparam
+    );
+    assert_eq!(
+        Sysno::sched_setscheduler.id() as libc::c_long,
+        libc::SYS_sched_setscheduler
+    );
+    assert_eq!(
+        Sysno::sched_yield.id() as libc::c_long,
+        libc::SYS_sched_yield
+    );
+    assert_eq!(Sysno::seccomp.id() as libc::c_long, libc::SYS_seccomp);
+    assert_eq!(Sysno::semctl.id() as libc::c_long, libc::SYS_semctl);
+    assert_eq!(Sysno::semget.id() as libc::c_long, libc::SYS_semget);
+    assert_eq!(Sysno::semop.id() as libc::c_long, libc::SYS_semop);
+    assert_eq!(Sysno::semtimedop.id() as libc::c_long, libc::SYS_semtimedop);
+    assert_eq!(Sysno::sendfile.id() as libc::c_long, libc::SYS_sendfile);
+    assert_eq!(Sysno::sendmmsg.id() as libc::c_long, libc::SYS_sendmmsg);
+    assert_eq!(Sysno::sendmsg.id() as libc::c_long, libc::SYS_sendmsg);
+    assert_eq!(Sysno::sendto.id() as libc::c_long, libc::SYS_sendto);
+    assert_eq!(
+        Sysno::set_mempolicy.id() as libc::c_long,
+        libc::SYS_set_mempolicy
+    );
+    assert_eq!(
+        Sysno::set_mempolicy_home_node.id() as libc::c_long,
+        libc::SYS_set_mempolicy_home_node
+    );
+    assert_eq!(
+        Sysno::set_robust_list.id() as libc::c_long,
+        libc::SYS_set_robust_list
+    );
+    assert_eq!(
+        Sysno::set_tid_address.id() as libc::c_long,
+        libc::SYS_set_tid_address
+    );
+    assert_eq!(
+        Sysno::setdomainname.id() as libc::c_long,
+        libc::SYS_setdomainname
+    );
+    assert_eq!(Sysno::setfsgid.id() as libc::c_long, libc::SYS_setfsgid);
+    assert_eq!(Sysno::setfsuid.id() as libc::c_long, libc::SYS_setfsuid);
+    assert_eq!(Sysno::setgid.id() as libc::c_long, libc::SYS_setgid);
+    assert_eq!(Sysno::setgroups.id() as libc::c_long, libc::SYS_setgroups);
+    assert_eq!(
+        Sysno::sethostname.id() as libc::c_long,
+        libc::SYS_sethostname
+    );
+    assert_eq!(Sysno::setitimer.id() as libc::c_long, libc::SYS_setitimer);
+    assert_eq!(Sysno::setns.id() as libc::c_long, libc::SYS_setns);
+    assert_eq!(Sysno::setpgid.id() as libc::c_long, libc::SYS_setpgid);
+    assert_eq!(
+        Sysno::setpriority.id() as libc::c_long,
+        libc::SYS_setpriority
+    );
+    assert_eq!(Sysno::setregid.id() as libc::c_long, libc::SYS_setregid);
+    assert_eq!(Sysno::setresgid.id() as libc::c_long, libc::SYS_setresgid);
+    assert_eq!(Sysno::setresuid.id() as libc::c_long, libc::SYS_setresuid);
+    assert_eq!(Sysno::setreuid.id() as libc::c_long, libc::SYS_setreuid);
+    assert_eq!(Sysno::setsid.id() as libc::c_long, libc::SYS_setsid);
+    assert_eq!(Sysno::setsockopt.id() as libc::c_long, libc::SYS_setsockopt);
+    assert_eq!(
+        Sysno::settimeofday.id() as libc::c_long,
+        libc::SYS_settimeofday
+    );
+    assert_eq!(Sysno::setuid.id() as libc::c_long, libc::SYS_setuid);
+    assert_eq!(Sysno::setxattr.id() as libc::c_long, libc::SYS_setxattr);
+    assert_eq!(Sysno::shmat.id() as libc::c_long, libc::SYS_shmat);
+    assert_eq!(Sysno::shmctl.id() as libc::c_long, libc::SYS_shmctl);
+    assert_eq!(Sysno::shmdt.id() as libc::c_long, libc::SYS_shmdt);
+    assert_eq!(Sysno::shmget.id() as libc::c_long, libc::SYS_shmget);
+    assert_eq!(Sysno::shutdown.id() as libc::c_long, libc::SYS_shutdown);
+    assert_eq!(
+        Sysno::sigaltstack.id() as libc::c_long,
+        libc::SYS_sigaltstack
+    );
+    assert_eq!(Sysno::signalfd4.id() as libc::c_long, libc::SYS_signalfd4);
+    assert_eq!(Sysno::socket.id() as libc::c_long, libc::SYS_socket);
+    assert_eq!(Sysno::socketpair.id() as libc::c_long, libc::SYS_socketpair);
+    assert_eq!(Sysno::splice.id() as libc::c_long, libc::SYS_splice);
+    assert_eq!(Sysno::statfs.id() as libc::c_long, libc::SYS_statfs);
+    assert_eq!(Sysno::statx.id() as libc::c_long, libc::SYS_statx);
+    assert_eq!(Sysno::swapoff.id() as libc::c_long, libc::SYS_swapoff);
+    assert_eq!(Sysno::swapon.id() as libc::c_long, libc::SYS_swapon);
+    assert_eq!(Sysno::symlinkat.id() as libc::c_long, libc::SYS_symlinkat);
+    assert_eq!(Sysno::sync.id() as libc::c_long, libc::SYS_sync);
+    assert_eq!(
+        Sysno::sync_file_range.id() as libc::c_long,
+        libc::SYS_sync_file_range
+    );
+    assert_eq!(Sysno::syncfs.id() as libc::c_long, libc::SYS_syncfs);
+    assert_eq!(Sysno::sysinfo.id() as libc::c_long, libc::SYS_sysinfo);
+    assert_eq!(Sysno::syslog.id() as libc::c_long, libc::SYS_syslog);
+    assert_eq!(Sysno::tee.id() as libc::c_long, libc::SYS_tee);
+    assert_eq!(Sysno::tgkill.id() as libc::c_long, libc::SYS_tgkill);
+    assert_eq!(
+        Sysno::timer_create.id() as libc::c_long,
+        libc::SYS_timer_create
+    );
+    assert_eq!(
+        Sysno::timer_delete.id() as libc::c_long,
+        libc::SYS_timer_delete
+    );
+    assert_eq!(
+        Sysno::timer_getoverrun.id() as libc::c_long,
+        libc::SYS_timer_getoverrun
+    );
+    assert_eq!(
+        Sysno::timer_gettime.id() as libc::c_long,
+        libc::SYS_timer_gettime
+    );
+    assert_eq!(
+        Sysno::timer_settime.id() as libc::c_long,
+        libc::SYS_timer_settime
+    );
+    assert_eq!(
+        Sysno::timerfd_create.id() as libc::c_long,
+        libc::SYS_timerfd_create
+    );
+    assert_eq!(
+        Sysno::timerfd_gettime.id() as libc::c_long,
+        libc::SYS_timerfd_gettime
+    );
+    assert_eq!(
+        Sysno::timerfd_settime.id() as libc::c_long,
+        libc::SYS_timerfd_settime
+    );
+    assert_eq!(Sysno::times.id() as libc::c_long, libc::SYS_times);
+    assert_eq!(Sysno::tkill.id() as libc::c_long, libc::SYS_tkill);
+    assert_eq!(Sysno::truncate.id() as libc::c_long, libc::SYS_truncate);
+    assert_eq!(Sysno::umask.id() as libc::c_long, libc::SYS_umask);
+    assert_eq!(Sysno::umount2.id() as libc::c_long, libc::SYS_umount2);
+    assert_eq!(Sysno::uname.id() as libc::c_long, libc::SYS_uname);
+    assert_eq!(Sysno::unlinkat.id() as libc::c_long, libc::SYS_unlinkat);
+    assert_eq!(Sysno::unshare.id() as libc::c_long, libc::SYS_unshare);
+    assert_eq!(
+        Sysno::userfaultfd.id() as libc::c_long,
+        libc::SYS_userfaultfd
+    );
+    assert_eq!(Sysno::utimensat.id() as libc::c_long, libc::SYS_utimensat);
+    assert_eq!(Sysno::vhangup.id() as libc::c_long, libc::SYS_vhangup);
+    assert_eq!(Sysno::vmsplice.id() as libc::c_long, libc::SYS_vmsplice);
+    assert_eq!(Sysno::wait4.id() as libc::c_long, libc::SYS_wait4);
+    assert_eq!(Sysno::waitid.id() as libc::c_long, libc::SYS_waitid);
+    assert_eq!(Sysno::write.id() as libc::c_long, libc::SYS_write);
+    assert_eq!(Sysno::writev.id() as libc::c_long, libc::SYS_writev);
+}
+
+#[cfg(all(target_arch = "mips", target_pointer_width = "32"))]
+#[test]
+fn mips() {
+    use rawsys_linux::mips::v6_12::Sysno;
+
+    assert_eq!(Sysno::_llseek.id() as libc::c_long, libc::SYS__llseek);
+    assert_eq!(Sysno::_newselect.id() as libc::c_long, libc::SYS__newselect);
+    assert_eq!(Sysno::_sysctl.id() as libc::c_long, libc::SYS__sysctl);
+    assert_eq!(Sysno::accept.id() as libc::c_long, libc::SYS_accept);
+    assert_eq!(Sysno::accept4.id() as libc::c_long, libc::SYS_accept4);
+    assert_eq!(Sysno::access.id() as libc::c_long, libc::SYS_access);
+    assert_eq!(Sysno::acct.id() as libc::c_long, libc::SYS_acct);
+    assert_eq!(Sysno::add_key.id() as libc::c_long, libc::SYS_add_key);
+    assert_eq!(Sysno::adjtimex.id() as libc::c_long, libc::SYS_adjtimex);
+    assert_eq!(
+        Sysno::afs_syscall.id() as libc::c_long,
+        libc::SYS_afs_syscall
+    );
+    assert_eq!(Sysno::alarm.id() as libc::c_long, libc::SYS_alarm);
+    assert_eq!(Sysno::bdflush.id() as libc::c_long, libc::SYS_bdflush);
+    assert_eq!(Sysno::bind.id() as libc::c_long, libc::SYS_bind);
+    assert_eq!(Sysno::bpf.id() as libc::c_long, libc::SYS_bpf);
+    assert_eq!(Sysno::break_.id() as libc::c_long, libc::SYS_break);
+    assert_eq!(Sysno::brk.id() as libc::c_long, libc::SYS_brk);
+    assert_eq!(Sysno::cachectl.id() as libc::c_long, libc::SYS_cachectl);
+    assert_eq!(Sysno::cacheflush.id() as libc::c_long, libc::SYS_cacheflush);
+    assert_eq!(Sysno::capget.id() as libc::c_long, libc::SYS_capget);
+    assert_eq!(Sysno::capset.id() as libc::c_long, libc::SYS_capset);
+    assert_eq!(Sysno::chdir.id() as libc::c_long, libc::SYS_chdir);
+    assert_eq!(Sysno::chmod.id() as libc::c_long, libc::SYS_chmod);
+    assert_eq!(Sysno::chown.id() as libc::c_long, libc::SYS_chown);
+    assert_eq!(Sysno::chroot.id() as libc::c_long, libc::SYS_chroot);
+    assert_eq!(
+        Sysno::clock_adjtime.id() as libc::c_long,
+        libc::SYS_clock_adjtime
+    );
+    assert_eq!(
+        Sysno::clock_getres.id() as libc::c_long,
+        libc::SYS_clock_getres
+    );
+    assert_eq!(
+        Sysno::clock_gettime.id() as libc::c_long,
+        libc::SYS_clock_gettime
+    );
+    assert_eq!(
+        Sysno::clock_nanosleep.id() as libc::c_long,
+        libc::SYS_clock_nanosleep
+    );
+    assert_eq!(
+        Sysno::clock_settime.id() as libc::c_long,
+        libc::SYS_clock_settime
+    );
+    assert_eq!(Sysno::clone.id() as libc::c_long, libc::SYS_clone);
+    assert_eq!(Sysno::clone3.id() as libc::c_long, libc::SYS_clone3);
+    assert_eq!(Sysno::close.id() as libc::c_long, libc::SYS_close);
+    assert_eq!(
+        Sysno::close_range.id() as libc::c_long,
+        libc::SYS_close_range
+    );
+    assert_eq!(Sysno::connect.id() as libc::c_long, libc::SYS_connect);
+    assert_eq!(
+        Sysno::copy_file_range.id() as libc::c_long,
+        libc::SYS_copy_file_range
+    );
+    assert_eq!(Sysno::creat.id() as libc::c_long, libc::SYS_creat);
+    assert_eq!(
+        Sysno::delete_module.id() as libc::c_long,
+        libc::SYS_delete_module
+    );
+    assert_eq!(Sysno::dup.id() as libc::c_long, libc::SYS_dup);
+    assert_eq!(Sysno::dup2.id() as libc::c_long, libc::SYS_dup2);
+    assert_eq!(Sysno::dup3.id() as libc::c_long, libc::SYS_dup3);
+    assert_eq!(
+        Sysno::epoll_create.id() as libc::c_long,
+        libc::SYS_epoll_create
+    );
+    assert_eq!(
+        Sysno::epoll_create1.id() as libc::c_long,
+        libc::SYS_epoll_create1
+    );
+    assert_eq!(Sysno::epoll_ctl.id() as libc::c_long, libc::SYS_epoll_ctl);
+    assert_eq!(
+        Sysno::epoll_pwait.id() as libc::c_long,
+        libc::SYS_epoll_pwait
+    );
+    assert_eq!(
+        Sysno::epoll_pwait2.id() as libc::c_long,
+        libc::SYS_epoll_pwait2
+    );
+    assert_eq!(Sysno::epoll_wait.id() as libc::c_long, libc::SYS_epoll_wait);
+    assert_eq!(Sysno::eventfd.id() as libc::c_long, libc::SYS_eventfd);
+    assert_eq!(Sysno::eventfd2.id() as libc::c_long, libc::SYS_eventfd2);
+    assert_eq!(Sysno::execve.id() as libc::c_long, libc::SYS_execve);
+    assert_eq!(Sysno::execveat.id() as libc::c_long, libc::SYS_execveat);
+    assert_eq!(Sysno::exit.id() as libc::c_long, libc::SYS_exit);
+    assert_eq!(Sysno::exit_group.id() as libc::c_long, libc::SYS_exit_group);
+    assert_eq!(Sysno::faccessat.id() as libc::c_long, libc::SYS_faccessat);
+    assert_eq!(Sysno::faccessat2.id() as libc::c_long, libc::SYS_faccessat2);
+    assert_eq!(Sysno::fadvise64.id() as libc::c_long, libc::SYS_fadvise64);
+    assert_eq!(Sysno::fallocate.id() as libc::c_long, libc::SYS_fallocate);
+    assert_eq!(
+        Sysno::fanotify_init.id() as libc::c_long,
+        libc::SYS_fanotify_init
+    );
+    assert_eq!(
+        Sysno::fanotify_mark.id() as libc::c_long,
+        libc::SYS_fanotify_mark
+    );
+    assert_eq!(Sysno::fchdir.id() as libc::c_long, libc::SYS_fchdir);
+    assert_eq!(Sysno::fchmod.id() as libc::c_long, libc::SYS_fchmod);
+    assert_eq!(Sysno::fchmodat.id() as libc::c_long, libc::SYS_fchmodat);
+    assert_eq!(Sysno::fchown.id() as libc::c_long, libc::SYS_fchown);
+    assert_eq!(Sysno::fchownat.id() as libc::c_long, libc::SYS_fchownat);
+    assert_eq!(Sysno::fcntl.id() as libc::c_long, libc::SYS_fcntl);
+    assert_eq!(Sysno::fcntl64.id() as libc::c_long, libc::SYS_fcntl64);
+    assert_eq!(Sysno::fdatasync.id() as libc::c_long, libc::SYS_fdatasync);
+    assert_eq!(Sysno::fgetxattr.id() as libc::c_long, libc::SYS_fgetxattr);
+    assert_eq!(
+        Sysno::finit_module.id() as libc::c_long,
+        libc::SYS_finit_module
+    );
+    assert_eq!(Sysno::flistxattr.id() as libc::c_long, libc::SYS_flistxattr);
+    assert_eq!(Sysno::flock.id() as libc::c_long, libc::SYS_flock);
+    assert_eq!(Sysno::fork.id() as libc::c_long, libc::SYS_fork);
+    assert_eq!(
+        Sysno::fremovexattr.id() as libc::c_long,
+        libc::SYS_fremovexattr
+    );
+    assert_eq!(Sysno::fsconfig.id() as libc::c_long, libc::SYS_fsconfig);
+    assert_eq!(Sysno::fsetxattr.id() as libc::c_long, libc::SYS_fsetxattr);
+    assert_eq!(Sysno::fsmount.id() as libc::c_long, libc::SYS_fsmount);
+    assert_eq!(Sysno::fsopen.id() as libc::c_long, libc::SYS_fsopen);
+    assert_eq!(Sysno::fspick.id() as libc::c_long, libc::SYS_fspick);
+    assert_eq!(Sysno::fstat.id() as libc::c_long, libc::SYS_fstat);
+    assert_eq!(Sysno::fstat64.id() as libc::c_long, libc::SYS_fstat64);
+    assert_eq!(Sysno::fstatat64.id() as libc::c_long, libc::SYS_fstatat64);
+    assert_eq!(Sysno::fstatfs.id() as libc::c_long, libc::SYS_fstatfs);
+    assert_eq!(Sysno::fstatfs64.id() as libc::c_long, libc::SYS_fstatfs64);
+    assert_eq!(Sysno::fsync.id() as libc::c_long, libc::SYS_fsync);
+    assert_eq!(Sysno::ftime.id() as libc::c_long, libc::SYS_ftime);
+    assert_eq!(Sysno::ftruncate.id() as libc::c_long, libc::SYS_ftruncate);
+    assert_eq!(
+        Sysno::ftruncate64.id() as libc::c_long,
+        libc::SYS_ftruncate64
+    );
+    assert_eq!(Sysno::futex.id() as libc::c_long, libc::SYS_futex);
+    assert_eq!(
+        Sysno::futex_waitv.id() as libc::c_long,
+        libc::SYS_futex_waitv
+    );
+    assert_eq!(Sysno::futimesat.id() as libc::c_long, libc::SYS_futimesat);
+    assert_eq!(
+        Sysno::get_mempolicy.id() as libc::c_long,
+        libc::SYS_get_mempolicy
+    );
+    assert_eq!(
+        Sysno::get_robust_list.id() as libc::c_long,
+        libc::SYS_get_robust_list
+    );
+    assert_eq!(Sysno::getcpu.id() as libc::c_long, libc::SYS_getcpu);
+    assert_eq!(Sysno::getcwd.id() as libc::c_long, libc::SYS_getcwd);
+    assert_eq!(Sysno::getdents.id() as libc::c_long, libc::SYS_getdents);
+    assert_eq!(Sysno::getdents64.id() as libc::c_long, libc::SYS_getdents64);
+    assert_eq!(Sysno::getegid.id() as libc::c_long, libc::SYS_getegid);
+    assert_eq!(Sysno::geteuid.id() as libc::c_long, libc::SYS_geteuid);
+    assert_eq!(Sysno::getgid.id() as libc::c_long, libc::SYS_getgid);
+    assert_eq!(Sysno::getgroups.id() as libc::c_long, libc::SYS_getgroups);
+    assert_eq!(Sysno::getitimer.id() as libc::c_long, libc::SYS_getitimer);
+    assert_eq!(
+        Sysno::getpeername.id() as libc::c_long,
+        libc::SYS_getpeername
+    );
+    assert_eq!(Sysno::getpgid.id() as libc::c_long, libc::SYS_getpgid);
+    assert_eq!(Sysno::getpgrp.id() as libc::c_long, libc::SYS_getpgrp);
+    assert_eq!(Sysno::getpid.id() as libc::c_long, libc::SYS_getpid);
+    assert_eq!(Sysno::getpmsg.id() as libc::c_long, libc::SYS_getpmsg);
+    assert_eq!(Sysno::getppid.id() as libc::c_long, libc::SYS_getppid);
+    assert_eq!(
+        Sysno::getpriority.id() as libc::c_long,
+        libc::SYS_getpriority
+    );
+    assert_eq!(Sysno::getrandom.id() as libc::c_long, libc::SYS_getrandom);
+    assert_eq!(Sysno::getresgid.id() as libc::c_long, libc::SYS_getresgid);
+    assert_eq!(Sysno::getresuid.id() as libc::c_long, libc::SYS_getresuid);
+    assert_eq!(Sysno::getrlimit.id() as libc::c_long, libc::SYS_getrlimit);
+    assert_eq!(Sysno::getrusage.id() as libc::c_long, libc::SYS_getrusage);
+    assert_eq!(Sysno::getsid.id() as libc::c_long, libc::SYS_getsid);
+    assert_eq!(
+        Sysno::getsockname.id() as libc::c_long,
+        libc::SYS_getsockname
+    );
+    assert_eq!(Sysno::getsockopt.id() as libc::c_long, libc::SYS_getsockopt);
+    assert_eq!(Sysno::gettid.id() as libc::c_long, libc::SYS_gettid);
+    assert_eq!(
+        Sysno::gettimeofday.id() as libc::c_long,
+        libc::SYS_gettimeofday
+    );
+    assert_eq!(Sysno::getuid.id() as libc::c_long, libc::SYS_getuid);
+    assert_eq!(Sysno::getxattr.id() as libc::c_long, libc::SYS_getxattr);
+    assert_eq!(Sysno::gtty.id() as libc::c_long, libc::SYS_gtty);
+    assert_eq!(Sysno::idle.id() as libc::c_long, libc::SYS_idle);
+    assert_eq!(
+        Sysno::init_module.id() as libc::c_long,
+        libc::SYS_init_module
+    );
+    assert_eq!(
+        Sysno::inotify_add_watch.id() as libc::c_long,
+        libc::SYS_inotify_add_watch
+    );
+    assert_eq!(
+        Sysno::inotify_init.id() as libc::c_long,
+        libc::SYS_inotify_init
+    );
+    assert_eq!(
+        Sysno::inotify_init1.id() as libc::c_long,
+        libc::SYS_inotify_init1
+    );
+    assert_eq!(
+        Sysno::inotify_rm_watch.id() as libc::c_long,
+        libc::SYS_inotify_rm_watch
+    );
+    assert_eq!(Sysno::io_cancel.id() as libc::c_long, libc::SYS_io_cancel);
+    assert_eq!(Sysno::io_destroy.id() as libc::c_long, libc::SYS_io_destroy);
+    assert_eq!(
+        Sysno::io_getevents.id() as libc::c_long,
+        libc::SYS_io_getevents
+    );
+    assert_eq!(Sysno::io_setup.id() as libc::c_long, libc::SYS_io_setup);
+    assert_eq!(Sysno::io_submit.id() as libc::c_long, libc::SYS_io_submit);
+    assert_eq!(
+        Sysno::io_uring_enter.id() as libc::c_long,
+        libc::SYS_io_uring_enter
+    );
+    assert_eq!(
+        Sysno::io_uring_register.id() as libc::c_long,
+        libc::SYS_io_uring_register
+    );
+    assert_eq!(
+        Sysno::io_uring_setup.id() as libc::c_long,
+        libc::SYS_io_uring_setup
+    );
+    assert_eq!(Sysno::ioctl.id() as libc::c_long, libc::SYS_ioctl);
+    assert_eq!(Sysno::ioperm.id() as libc::c_long, libc::SYS_ioperm);
+    assert_eq!(Sysno::iopl.id() as libc::c_long, libc::SYS_iopl);
+    assert_eq!(Sysno::ioprio_get.id() as libc::c_long, libc::SYS_ioprio_get);
+    assert_eq!(Sysno::ioprio_set.id() as libc::c_long, libc::SYS_ioprio_set);
+    assert_eq!(Sysno::ipc.id() as libc::c_long, libc::SYS_ipc);
+    assert_eq!(Sysno::kcmp.id() as libc::c_long, libc::SYS_kcmp);
+    assert_eq!(Sysno::kexec_load.id() as libc::c_long, libc::SYS_kexec_load);
+    assert_eq!(Sysno::keyctl.id() as libc::c_long, libc::SYS_keyctl);
+    assert_eq!(Sysno::kill.id() as libc::c_long, libc::SYS_kill);
+    assert_eq!(
+        Sysno::landlock_add_rule.id() as libc::c_long,
+        libc::SYS_landlock_add_rule
+    );
+    assert_eq!(
+        Sysno::landlock_create_ruleset.id() as libc::c_long,
+        libc::SYS_landlock_create_ruleset
+    );
+    assert_eq!(
+        Sysno::landlock_restrict_self.id() as libc::c_long,
+        libc::SYS_landlock_restrict_self
+    );
+    assert_eq!(Sysno::lchown.id() as libc::c_long, libc::SYS_lchown);
+    assert_eq!(Sysno::lgetxattr.id() as libc::c_long, libc::SYS_lgetxattr);
+    assert_eq!(Sysno::link.id() as libc::c_long, libc::SYS_link);
+    assert_eq!(Sysno::linkat.id() as libc::c_long, libc::SYS_linkat);
+    assert_eq!(Sysno::listen.id() as libc::c_long, libc::SYS_listen);
+    assert_eq!(Sysno::listxattr.id() as libc::c_long, libc::SYS_listxattr);
+    assert_eq!(Sysno::llistxattr.id() as libc::c_long, libc::SYS_llistxattr);
+    assert_eq!(Sysno::lock.id() as libc::c_long, libc::SYS_lock);
+    assert_eq!(
+        Sysno::lookup_dcookie.id() as libc::c_long,
+        libc::SYS_lookup_dcookie
+    );
+    assert_eq!(
+        Sysno::lremovexattr.id() as libc::c_long,
+        libc::SYS_lremovexattr
+    );
+    assert_eq!(Sysno::lseek.id() as libc::c_long, libc::SYS_lseek);
+    assert_eq!(Sysno::lsetxattr.id() as libc::c_long, libc::SYS_lsetxattr);
+    assert_eq!(Sysno::lstat.id() as libc::c_long, libc::SYS_lstat);
+    assert_eq!(Sysno::lstat64.id() as libc::c_long, libc::SYS_lstat64);
+    assert_eq!(Sysno::madvise.id() as libc::c_long, libc::SYS_madvise);
+    assert_eq!(Sysno::mbind.id() as libc::c_long, libc::SYS_mbind);
+    assert_eq!(Sysno::membarrier.id() as libc::c_long, libc::SYS_membarrier);
+    assert_eq!(
+        Sysno::memfd_create.id() as libc::c_long,
+        libc::SYS_memfd_create
+    );
+    assert_eq!(
+        Sysno::migrate_pages.id() as libc::c_long,
+        libc::SYS_migrate_pages
+    );
+    assert_eq!(Sysno::mincore.id() as libc::c_long, libc::SYS_mincore);
+    assert_eq!(Sysno::mkdir.id() as libc::c_long, libc::SYS_mkdir);
+    assert_eq!(Sysno::mkdirat.id() as libc::c_long, libc::SYS_mkdirat);
+    assert_eq!(Sysno::mknod.id() as libc::c_long, libc::SYS_mknod);
+    assert_eq!(Sysno::mknodat.id() as libc::c_long, libc::SYS_mknodat);
+    assert_eq!(Sysno::mlock.id() as libc::c_long, libc::SYS_mlock);
+    assert_eq!(Sysno::mlock2.id() as libc::c_long, libc::SYS_mlock2);
+    assert_eq!(Sysno::mlockall.id() as libc::c_long, libc::SYS_mlockall);
+    assert_eq!(Sysno::mmap.id() as libc::c_long, libc::SYS_mmap);
+    assert_eq!(Sysno::mmap2.id() as libc::c_long, libc::SYS_mmap2);
+    assert_eq!(Sysno::modify_ldt.id() as libc::c_long, libc::SYS_modify_ldt);
+    assert_eq!(Sysno::mount.id() as libc::c_long, libc::SYS_mount);
+    assert_eq!(
+        Sysno::mount_setattr.id() as libc::c_long,
+        libc::SYS_mount_setattr
+    );
+    assert_eq!(Sysno::move_mount.id() as libc::c_long, libc::SYS_move_mount);
+    assert_eq!(Sysno::move_pages.id() as libc::c_long, libc::SYS_move_pages);
+    assert_eq!(Sysno::mprotect.id() as libc::c_long, libc::SYS_mprotect);
+    assert_eq!(Sysno::mpx.id() as libc::c_long, libc::SYS_mpx);
+    assert_eq!(
+        Sysno::mq_getsetattr.id() as libc::c_long,
+        libc::SYS_mq_getsetattr
+    );
+    assert_eq!(Sysno::mq_notify.id() as libc::c_long, libc::SYS_mq_notify);
+    assert_eq!(Sysno::mq_open.id() as libc::c_long, libc::SYS_mq_open);
+    assert_eq!(
+        Sysno::mq_timedreceive.id() as libc::c_long,
+        libc::SYS_mq_timedreceive
+    );
+    assert_eq!(
+        Sysno::mq_timedsend.id() as libc::c_long,
+        libc::SYS_mq_timedsend
+    );
+    assert_eq!(Sysno::mq_unlink.id() as libc::c_long, libc::SYS_mq_unlink);
+    assert_eq!(Sysno::mremap.id() as libc::c_long, libc::SYS_mremap);
+    assert_eq!(Sysno::msync.id() as libc::c_long, libc::SYS_msync);
+    assert_eq!(Sysno::munlock.id() as libc::c_long, libc::SYS_munlock);
+    assert_eq!(Sysno::munlockall.id() as libc::c_long, libc::SYS_munlockall);
+    assert_eq!(Sysno::munmap.id() as libc::c_long, libc::SYS_munmap);
+    assert_eq!(
+        Sysno::name_to_handle_at.id() as libc::c_long,
+        libc::SYS_name_to_handle_at
+    );
+    assert_eq!(Sysno::nanosleep.id() as libc::c_long, libc::SYS_nanosleep);
+    assert_eq!(Sysno::nfsservctl.id() as libc::c_long, libc::SYS_nfsservctl);
+    assert_eq!(Sysno::nice.id() as libc::c_long, libc::SYS_nice);
+    assert_eq!(Sysno::open.id() as libc::c_long, libc::SYS_open);
+    assert_eq!(
+        Sysno::open_by_handle_at.id() as libc::c_long,
+        libc::SYS_open_by_handle_at
+    );
+    assert_eq!(Sysno::open_tree.id() as libc::c_long, libc::SYS_open_tree);
+    assert_eq!(Sysno::openat.id() as libc::c_long, libc::SYS_openat);
+    assert_eq!(Sysno::openat2.id() as libc::c_long, libc::SYS_openat2);
+    assert_eq!(Sysno::pause.id() as libc::c_long, libc::SYS_pause);
+    assert_eq!(
+        Sysno::perf_event_open.id() as libc::c_long,
+        libc::SYS_perf_event_open
+    );
+    assert_eq!(
+        Sysno::personality.id() as libc::c_long,
+        libc::SYS_personality
+    );
+    assert_eq!(
+        Sysno::pidfd_getfd.id() as libc::c_long,
+        libc::SYS_pidfd_getfd
+    );
+    assert_eq!(Sysno::pidfd_open.id() as libc::c_long, libc::SYS_pidfd_open);
+    assert_eq!(
+        Sysno::pidfd_send_signal.id() as libc::c_long,
+        libc::SYS_pidfd_send_signal
+    );
+    assert_eq!(Sysno::pipe.id() as libc::c_long, libc::SYS_pipe);
+    assert_eq!(Sysno::pipe2.id() as libc::c_long, libc::SYS_pipe2);
+    assert_eq!(Sysno::pivot_root.id() as libc::c_long, libc::SYS_pivot_root);
+    assert_eq!(Sysno::pkey_alloc.id() as libc::c_long, libc::SYS_pkey_alloc);
+    assert_eq!(Sysno::pkey_free.id() as libc::c_long, libc::SYS_pkey_free);
+    assert_eq!(
+        Sysno::pkey_mprotect.id() as libc::c_long,
+        libc::SYS_pkey_mprotect
+    );
+    assert_eq!(Sysno::poll.id() as libc::c_long, libc::SYS_poll);
+    assert_eq!(Sysno::ppoll.id() as libc::c_long, libc::SYS_ppoll);
+    assert_eq!(Sysno::prctl.id() as libc::c_long, libc::SYS_prctl);
+    assert_eq!(Sysno::pread64.id() as libc::c_long, libc::SYS_pread64);
+    assert_eq!(Sysno::preadv.id() as libc::c_long, libc::SYS_preadv);
+    assert_eq!(Sysno::preadv2.id() as libc::c_long, libc::SYS_preadv2);
+    assert_eq!(Sysno::prlimit64.id() as libc::c_long, libc::SYS_prlimit64);
+    assert_eq!(
+        Sysno::process_madvise.id() as libc::c_long,
+        libc::SYS_process_madvise
+    );
+    assert_eq!(
+        Sysno::process_mrelease.id() as libc::c_long,
+        libc::SYS_process_mrelease
+    );
+    assert_eq!(
+        Sysno::process_vm_readv.id() as libc::c_long,
+        libc::SYS_process_vm_readv
+    );
+    assert_eq!(
+        Sysno::process_vm_writev.id() as libc::c_long,
+        libc::SYS_process_vm_writev
+    );
+    assert_eq!(Sysno::prof.id() as libc::c_long, libc::SYS_prof);
+    assert_eq!(Sysno::profil.id() as libc::c_long, libc::SYS_profil);
+    assert_eq!(Sysno::pselect6.id() as libc::c_long, libc::SYS_pselect6);
+    assert_eq!(Sysno::ptrace.id() as libc::c_long, libc::SYS_ptrace);
+    assert_eq!(Sysno::putpmsg.id() as libc::c_long, libc::SYS_putpmsg);
+    assert_eq!(Sysno::pwrite64.id() as libc::c_long, libc::SYS_pwrite64);
+    assert_eq!(Sysno::pwritev.id() as libc::c_long, libc::SYS_pwritev);
+    assert_eq!(Sysno::pwritev2.id() as libc::c_long, libc::SYS_pwritev2);
+    assert_eq!(Sysno::quotactl.id() as libc::c_long, libc::SYS_quotactl);
+    assert_eq!(
+        Sysno::quotactl_fd.id() as libc::c_long,
+        libc::SYS_quotactl_fd
+    );
+    assert_eq!(Sysno::read.id() as libc::c_long, libc::SYS_read);
+    assert_eq!(Sysno::readahead.id() as libc::c_long, libc::SYS_readahead);
+    assert_eq!(Sysno::readdir.id() as libc::c_long, libc::SYS_readdir);
+    assert_eq!(Sysno::readlink.id() as libc::c_long, libc::SYS_readlink);
+    assert_eq!(Sysno::readlinkat.id() as libc::c_long, libc::SYS_readlinkat);
+    assert_eq!(Sysno::readv.id() as libc::c_long, libc::SYS_readv);
+    assert_eq!(Sysno::reboot.id() as libc::c_long, libc::SYS_reboot);
+    assert_eq!(Sysno::recv.id() as libc::c_long, libc::SYS_recv);
+    assert_eq!(Sysno::recvfrom.id() as libc::c_long, libc::SYS_recvfrom);
+    assert_eq!(Sysno::recvmmsg.id() as libc::c_long, libc::SYS_recvmmsg);
+    assert_eq!(Sysno::recvmsg.id() as libc::c_long, libc::SYS_recvmsg);
+    assert_eq!(
+        Sysno::remap_file_pages.id() as libc::c_long,
+        libc::SYS_remap_file_pages
+    );
+    assert_eq!(
+        Sysno::removexattr.id() as libc::c_long,
+        libc::SYS_removexattr
+    );
+    assert_eq!(Sysno::rename.id() as libc::c_long, libc::SYS_rename);
+    assert_eq!(Sysno::renameat.id() as libc::c_long, libc::SYS_renameat);
+    assert_eq!(Sysno::renameat2.id() as libc::c_long, libc::SYS_renameat2);
+    assert_eq!(
+        Sysno::request_key.id() as libc::c_long,
+        libc::SYS_request_key
+    );
+    assert_eq!(
+        Sysno::restart_syscall.id() as libc::c_long,
+        libc::SYS_restart_syscall
+    );
+    assert_eq!(Sysno::rmdir.id() as libc::c_long, libc::SYS_rmdir);
+    assert_eq!(Sysno::rseq.id() as libc::c_long, libc::SYS_rseq);
+    assert_eq!(
+        Sysno::rt_sigaction.id() as libc::c_long,
+        libc::SYS_rt_sigaction
+    );
+    assert_eq!(
+        Sysno::rt_sigpending.id() as libc::c_long,
+        libc::SYS_rt_sigpending
+    );
+    assert_eq!(
+        Sysno::rt_sigprocmask.id() as libc::c_long,
+        libc::SYS_rt_sigprocmask
+    );
+    assert_eq!(
+        Sysno::rt_sigqueueinfo.id() as libc::c_long,
+        libc::SYS_rt_sigqueueinfo
+    );
+    assert_eq!(
+        Sysno::rt_sigreturn.id() as libc::c_long,
+        libc::SYS_rt_sigreturn
+    );
+    assert_eq!(
+        Sysno::rt_sigsuspend.id() as libc::c_long,
+        libc::SYS_rt_sigsuspend
+    );
+    assert_eq!(
+        Sysno::rt_sigtimedwait.id() as libc::c_long,
+        libc::SYS_rt_sigtimedwait
+    );
+    assert_eq!(
+        Sysno::rt_tgsigqueueinfo.id() as libc::c_long,
+        libc::SYS_rt_tgsigqueueinfo
+    );
+    assert_eq!(
+        Sysno::sched_get_priority_max.id() as libc::c_long,
+        libc::SYS_sched_get_priority_max
+    );
+    assert_eq!(
+        Sysno::sched_get_priority_min.id() as libc::c_long,
+        libc::SYS_sched_get_priority_min
+    );
+    assert_eq!(
+        Sysno::sched_getaffinity.id() as libc::c_long,
+        libc::SYS_sched_getaffinity
+    );
+    assert_eq!(
+        Sysno::sched_getattr.id() as libc::c_long,
+        libc::SYS_sched_getattr
+    );
+    assert_eq!(
+        Sysno::sched_getparam.id() as libc::c_long,
+        libc::SYS_sched_getparam
+    );
+    assert_eq!(
+        Sysno::sched_getscheduler.id() as libc::c_long,
+        libc::SYS_sched_getscheduler
+    );
+    assert_eq!(
+        Sysno::sched_rr_get_interval.id() as libc::c_long,
+        libc::SYS_sched_rr_get_interval
+    );
+    assert_eq!(
+        Sysno::sched_setaffinity.id() as libc::c_long,
+        libc::SYS_sched_setaffinity
+    );
+    assert_eq!(
+        Sysno::sched_setattr.id() as libc::c_long,
+        libc::SYS_sched_setattr
+    );
+    assert_eq!(
+        Sysno::sched_setparam.id() as libc::c_long,
+        libc::SYS_sched_setparam
+    );
+    assert_eq!(
+        Sysno::sched_setscheduler.id() as libc::c_long,
+        libc::SYS_sched_setscheduler
+    );
+    assert_eq!(
+        Sysno::sched_yield.id() as libc::c_long,
+        libc::SYS_sched_yield
+    );
+    assert_eq!(Sysno::seccomp.id() as libc::c_long, libc::SYS_seccomp);
+    assert_eq!(Sysno::send.id() as libc::c_long, libc::SYS_send);
+    assert_eq!(Sysno::sendfile.id() as libc::c_long, libc::SYS_sendfile);
+    assert_eq!(Sysno::sendfile64.id() as libc::c_long, libc::SYS_sendfile64);
+    assert_eq!(Sysno::sendmmsg.id() as libc::c_long, libc::SYS_sendmmsg);
+    assert_eq!(Sysno::sendmsg.id() as libc::c_long, libc::SYS_sendmsg);
+    assert_eq!(Sysno::sendto.id() as libc::c_long, libc::SYS_sendto);
+    assert_eq!(
+        Sysno::set_mempolicy.id() as libc::c_long,
+        libc::SYS_set_mempolicy
+    );
+    assert_eq!(
+        Sysno::set_mempolicy_home_node.id() as libc::c_long,
+        libc::SYS_set_mempolicy_home_node
+    );
+    assert_eq!(
+        Sysno::set_robust_list.id() as libc::c_long,
+        libc::SYS_set_robust_list
+    );
+    assert_eq!(
+        Sysno::set_thread_area.id() as libc::c_long,
+        libc::SYS_set_thread_area
+    );
+    assert_eq!(
+        Sysno::set_tid_address.id() as libc::c_long,
+        libc::SYS_set_tid_address
+    );
+    assert_eq!(
+        Sysno::setdomainname.id() as libc::c_long,
+        libc::SYS_setdomainname
+    );
+    assert_eq!(Sysno::setfsgid.id() as libc::c_long, libc::SYS_setfsgid);
+    assert_eq!(Sysno::setfsuid.id() as libc::c_long, libc::SYS_setfsuid);
+    assert_eq!(Sysno::setgid.id() as libc::c_long, libc::SYS_setgid);
+    assert_eq!(Sysno::setgroups.id() as libc::c_long, libc::SYS_setgroups);
+    assert_eq!(
+        Sysno::sethostname.id() as libc::c_long,
+        libc::SYS_sethostname
+    );
+    assert_eq!(Sysno::setitimer.id() as libc::c_long, libc::SYS_setitimer);
+    assert_eq!(Sysno::setns.id() as libc::c_long, libc::SYS_setns);
+    assert_eq!(Sysno::setpgid.id() as libc::c_long, libc::SYS_setpgid);
+    assert_eq!(
+        Sysno::setpriority.id() as libc::c_long,
+        libc::SYS_setpriority
+    );
+    assert_eq!(Sysno::setregid.id() as libc::c_long, libc::SYS_setregid);
+    assert_eq!(Sysno::setresgid.id() as libc::c_long, libc::SYS_setresgid);
+    assert_eq!(Sysno::setresuid.id() as libc::c_long, libc::SYS_setresuid);
+    assert_eq!(Sysno::setreuid.id() as libc::c_long, libc::SYS_setreuid);
+    assert_eq!(Sysno::setrlimit.id() as libc::c_long, libc::SYS_setrlimit);
+    assert_eq!(Sysno::setsid.id() as libc::c_long, libc::SYS_setsid);
+    assert_eq!(Sysno::setsockopt.id() as libc::c_long, libc::SYS_setsockopt);
+    assert_eq!(
+        Sysno::settimeofday.id() as libc::c_long,
+        libc::SYS_settimeofday
+    );
+    assert_eq!(Sysno::setuid.id() as libc::c_long, libc::SYS_setuid);
+    assert_eq!(Sysno::setxattr.id() as libc::c_long, libc::SYS_setxattr);
+    assert_eq!(Sysno::sgetmask.id() as libc::c_long, libc::SYS_sgetmask);
+    assert_eq!(Sysno::shutdown.id() as libc::c_long, libc::SYS_shutdown);
+    assert_eq!(Sysno::sigaction.id() as libc::c_long, libc::SYS_sigaction);
+    assert_eq!(
+        Sysno::sigaltstack.id() as libc::c_long,
+        libc::SYS_sigaltstack
+    );
+    assert_eq!(Sysno::signal.id() as libc::c_long, libc::SYS_signal);
+    assert_eq!(Sysno::signalfd.id() as libc::c_long, libc::SYS_signalfd);
+    assert_eq!(Sysno::signalfd4.id() as libc::c_long, libc::SYS_signalfd4);
+    assert_eq!(Sysno::sigpending.id() as libc::c_long, libc::SYS_sigpending);
+    assert_eq!(
+        Sysno::sigprocmask.id() as libc::c_long,
+        libc::SYS_sigprocmask
+    );
+    assert_eq!(Sysno::sigreturn.id() as libc::c_long, libc::SYS_sigreturn);
+    assert_eq!(Sysno::sigsuspend.id() as libc::c_long, libc::SYS_sigsuspend);
+    assert_eq!(Sysno::socket.id() as libc::c_long, libc::SYS_socket);
+    assert_eq!(Sysno::socketcall.id() as libc::c_long, libc::SYS_socketcall);
+    assert_eq!(Sysno::socketpair.id() as libc::c_long, libc::SYS_socketpair);
+    assert_eq!(Sysno::splice.id() as libc::c_long, libc::SYS_splice);
+    assert_eq!(Sysno::ssetmask.id() as libc::c_long, libc::SYS_ssetmask);
+    assert_eq!(Sysno::stat.id() as libc::c_long, libc::SYS_stat);
+    assert_eq!(Sysno::stat64.id() as libc::c_long, libc::SYS_stat64);
+    assert_eq!(Sysno::statfs.id() as libc::c_long, libc::SYS_statfs);
+    assert_eq!(Sysno::statfs64.id() as libc::c_long, libc::SYS_statfs64);
+    assert_eq!(Sysno::statx.id() as libc::c_long, libc::SYS_statx);
+    assert_eq!(Sysno::stime.id() as libc::c_long, libc::SYS_stime);
+    assert_eq!(Sysno::stty.id() as libc::c_long, libc::SYS_stty);
+    assert_eq!(Sysno::swapoff.id() as libc::c_long, libc::SYS_swapoff);
+    assert_eq!(Sysno::swapon.id() as libc::c_long, libc::SYS_swapon);
+    assert_eq!(Sysno::symlink.id() as libc::c_long, libc::SYS_symlink);
+    assert_eq!(Sysno::symlinkat.id() as libc::c_long, libc::SYS_symlinkat);
+    assert_eq!(Sysno::sync.id() as libc::c_long, libc::SYS_sync);
+    assert_eq!(
+        Sysno::sync_file_range.id() as libc::c_long,
+        libc::SYS_sync_file_range
+    );
+    assert_eq!(Sysno::syncfs.id() as libc::c_long, libc::SYS_syncfs);
+    assert_eq!(Sysno::syscall.id() as libc::c_long, libc::SYS_syscall);
+    assert_eq!(Sysno::sysfs.id() as libc::c_long, libc::SYS_sysfs);
+    assert_eq!(Sysno::sysinfo.id() as libc::c_long, libc::SYS_sysinfo);
+    assert_eq!(Sysno::syslog.id() as libc::c_long, libc::SYS_syslog);
+    assert_eq!(Sysno::sysmips.id() as libc::c_long, libc::SYS_sysmips);
+    assert_eq!(Sysno::tee.id() as libc::c_long, libc::SYS_tee);
+    assert_eq!(Sysno::tgkill.id() as libc::c_long, libc::SYS_tgkill);
+    assert_eq!(Sysno::time.id() as libc::c_long, libc::SYS_time);
+    assert_eq!(
+        Sysno::timer_create.id() as libc::c_long,
+        libc::SYS_timer_create
+    );
+    assert_eq!(
+        Sysno::timer_delete.id() as libc::c_long,
+        libc::SYS_timer_delete
+    );
+    assert_eq!(
+        Sysno::timer_getoverrun.id() as libc::c_long,
+        libc::SYS_timer_getoverrun
+    );
+    assert_eq!(
+        Sysno::timer_gettime.id() as libc::c_long,
+        libc::SYS_timer_gettime
+    );
+    assert_eq!(
+        Sysno::timer_settime.id() as libc::c_long,
+        libc::SYS_timer_settime
+    );
+    assert_eq!(Sysno::timerfd.id() as libc::c_long, libc::SYS_timerfd);
+    assert_eq!(
+        Sysno::timerfd_create.id() as libc::c_long,
+        libc::SYS_timerfd_create
+    );
+    assert_eq!(
+        Sysno::timerfd_gettime.id() as libc::c_long,
+        libc::SYS_timerfd_gettime
+    );
+    assert_eq!(
+        Sysno::timerfd_settime.id() as libc::c_long,
+        libc::SYS_timerfd_settime
+    );
+    assert_eq!(Sysno::times.id() as libc::c_long, libc::SYS_times);
+    assert_eq!(Sysno::tkill.id() as libc::c_long, libc::SYS_tkill);
+    assert_eq!(Sysno::truncate.id() as libc::c_long, libc::SYS_truncate);
+    assert_eq!(Sysno::truncate64.id() as libc::c_long, libc::SYS_truncate64);
+    assert_eq!(Sysno::ulimit.id() as libc::c_long, libc::SYS_ulimit);
+    assert_eq!(Sysno::umask.id() as libc::c_long, libc::SYS_umask);
+    assert_eq!(Sysno::umount.id() as libc::c_long, libc::SYS_umount);
+    assert_eq!(Sysno::umount2.id() as libc::c_long, libc::SYS_umount2);
+    assert_eq!(Sysno::uname.id() as libc::c_long, libc::SYS_uname);
+    assert_eq!(Sysno::unlink.id() as libc::c_long, libc::SYS_unlink);
+    assert_eq!(Sysno::unlinkat.id() as libc::c_long, libc::SYS_unlinkat);
+    assert_eq!(Sysno::unshare.id() as libc::c_long, libc::SYS_unshare);
+    assert_eq!(Sysno::uselib.id() as libc::c_long, libc::SYS_uselib);
+    assert_eq!(
+        Sysno::userfaultfd.id() as libc::c_long,
+        libc::SYS_userfaultfd
+    );
+    assert_eq!(Sysno::ustat.id() as libc::c_long, libc::SYS_ustat);
+    assert_eq!(Sysno::utime.id() as libc::c_long, libc::SYS_utime);
+    assert_eq!(Sysno::utimensat.id() as libc::c_long, libc::SYS_utimensat);
+    assert_eq!(Sysno::utimes.id() as libc::c_long, libc::SYS_utimes);
+    assert_eq!(Sysno::vhangup.id() as libc::c_long, libc::SYS_vhangup);
+    assert_eq!(Sysno::vm86.id() as libc::c_long, libc::SYS_vm86);
+    assert_eq!(Sysno::vmsplice.id() as libc::c_long, libc::SYS_vmsplice);
+    assert_eq!(Sysno::vserver.id() as libc::c_long, libc::SYS_vserver);
+    assert_eq!(Sysno::wait4.id() as libc::c_long, libc::SYS_wait4);
+    assert_eq!(Sysno::waitid.id() as libc::c_long, libc::SYS_waitid);
+    assert_eq!(Sysno::waitpid.id() as libc::c_long, libc::SYS_waitpid);
+    assert_eq!(Sysno::write.id() as libc::c_long, libc::SYS_write);
+    assert_eq!(Sysno::writev.id() as libc::c_long, libc::SYS_writev);
+}
+
+#[cfg(all(target_arch = "mips64", target_pointer_width = "64"))]
+#[test]
+fn mips64() {
+    use rawsys_linux::mips64::v6_12::Sysno;
+
+    assert_eq!(Sysno::_newselect.id() as libc::c_long, libc::SYS__newselect);
+    assert_eq!(Sysno::_sysctl.id() as libc::c_long, libc::SYS__sysctl);
+    assert_eq!(Sysno::accept.id() as libc::c_long, libc::SYS_accept);
+    assert_eq!(Sysno::accept4.id() as libc::c_long, libc::SYS_accept4);
+    assert_eq!(Sysno::access.id() as libc::c_long, libc::SYS_access);
+    assert_eq!(Sysno::acct.id() as libc::c_long, libc::SYS_acct);
+    assert_eq!(Sysno::add_key.id() as libc::c_long, libc::SYS_add_key);
+    assert_eq!(Sysno::adjtimex.id() as libc::c_long, libc::SYS_adjtimex);
+    assert_eq!(
+        Sysno::afs_syscall.id() as libc::c_long,
+        libc::SYS_afs_syscall
+    );
+    assert_eq!(Sysno::alarm.id() as libc::c_long, libc::SYS_alarm);
+    assert_eq!(Sysno::bind.id() as libc::c_long, libc::SYS_bind);
+    assert_eq!(Sysno::bpf.id() as libc::c_long, libc::SYS_bpf);
+    assert_eq!(Sysno::brk.id() as libc::c_long, libc::SYS_brk);
+    assert_eq!(Sysno::cachectl.id() as libc::c_long, libc::SYS_cachectl);
+    assert_eq!(Sysno::cacheflush.id() as libc::c_long, libc::SYS_cacheflush);
+    assert_eq!(Sysno::capget.id() as libc::c_long, libc::SYS_capget);
+    assert_eq!(Sysno::capset.id() as libc::c_long, libc::SYS_capset);
+    assert_eq!(Sysno::chdir.id() as libc::c_long, libc::SYS_chdir);
+    assert_eq!(Sysno::chmod.id() as libc::c_long, libc::SYS_chmod);
+    assert_eq!(Sysno::chown.id() as libc::c_long, libc::SYS_chown);
+    assert_eq!(Sysno::chroot.id() as libc::c_long, libc::SYS_chroot);
+    assert_eq!(
+        Sysno::clock_adjtime.id() as libc::c_long,
+        libc::SYS_clock_adjtime
+    );
+    assert_eq!(
+        Sysno::clock_getres.id() as libc::c_long,
+        libc::SYS_clock_getres
+    );
+    assert_eq!(
+        Sysno::clock_gettime.id() as libc::c_long,
+        libc::SYS_clock_gettime
+    );
+    assert_eq!(
+        Sysno::clock_nanosleep.id() as libc::c_long,
+        libc::SYS_clock_nanosleep
+    );
+    assert_eq!(
+        Sysno::clock_settime.id() as libc::c_long,
+        libc::SYS_clock_settime
+    );
+    assert_eq!(Sysno::clone.id() as libc::c_long, libc::SYS_clone);
+    assert_eq!(Sysno::clone3.id() as libc::c_long, libc::SYS_clone3);
+    assert_eq!(Sysno::close.id() as libc::c_long, libc::SYS_close);
+    assert_eq!(
+        Sysno::close_range.id() as libc::c_long,
+        libc::SYS_close_range
+    );
+    assert_eq!(Sysno::connect.id() as libc::c_long, libc::SYS_connect);
+    assert_eq!(
+        Sysno::copy_file_range.id() as libc::c_long,
+        libc::SYS_copy_file_range
+    );
+    assert_eq!(Sysno::creat.id() as libc::c_long, libc::SYS_creat);
+    assert_eq!(
+        Sysno::delete_module.id() as libc::c_long,
+        libc::SYS_delete_module
+    );
+    assert_eq!(Sysno::dup.id() as libc::c_long, libc::SYS_dup);
+    assert_eq!(Sysno::dup2.id() as libc::c_long, libc::SYS_dup2);
+    assert_eq!(Sysno::dup3.id() as libc::c_long, libc::SYS_dup3);
+    assert_eq!(
+        Sysno::epoll_create.id() as libc::c_long,
+        libc::SYS_epoll_create
+    );
+    assert_eq!(
+        Sysno::epoll_create1.id() as libc::c_long,
+        libc::SYS_epoll_create1
+    );
+    assert_eq!(Sysno::epoll_ctl.id() as libc::c_long, libc::SYS_epoll_ctl);
+    assert_eq!(
+        Sysno::epoll_pwait.id() as libc::c_long,
+        libc::SYS_epoll_pwait
+    );
+    assert_eq!(
+        Sysno::epoll_pwait2.id() as libc::c_long,
+        libc::SYS_epoll_pwait2
+    );
+    assert_eq!(Sysno::epoll_wait.id() as libc::c_long, libc::SYS_epoll_wait);
+    assert_eq!(Sysno::eventfd.id() as libc::c_long, libc::SYS_eventfd);
+    assert_eq!(Sysno::eventfd2.id() as libc::c_long, libc::SYS_eventfd2);
+    assert_eq!(Sysno::execve.id() as libc::c_long, libc::SYS_execve);
+    assert_eq!(Sysno::execveat.id() as libc::c_long, libc::SYS_execveat);
+    assert_eq!(Sysno::exit.id() as libc::c_long, libc::SYS_exit);
+    assert_eq!(Sysno::exit_group.id() as libc::c_long, libc::SYS_exit_group);
+    assert_eq!(Sysno::faccessat.id() as libc::c_long, libc::SYS_faccessat);
+    assert_eq!(Sysno::faccessat2.id() as libc::c_long, libc::SYS_faccessat2);
+    assert_eq!(Sysno::fadvise64.id() as libc::c_long, libc::SYS_fadvise64);
+    assert_eq!(Sysno::fallocate.id() as libc::c_long, libc::SYS_fallocate);
+    assert_eq!(
+        Sysno::fanotify_init.id() as libc::c_long,
+        libc::SYS_fanotify_init
+    );
+    assert_eq!(
+        Sysno::fanotify_mark.id() as libc::c_long,
+        libc::SYS_fanotify_mark
+    );
+    assert_eq!(Sysno::fchdir.id() as libc::c_long, libc::SYS_fchdir);
+    assert_eq!(Sysno::fchmod.id() as libc::c_long, libc::SYS_fchmod);
+    assert_eq!(Sysno::fchmodat.id() as libc::c_long, libc::SYS_fchmodat);
+    assert_eq!(Sysno::fchown.id() as libc::c_long, libc::SYS_fchown);
+    assert_eq!(Sysno::fchownat.id() as libc::c_long, libc::SYS_fchownat);
+    assert_eq!(Sysno::fcntl.id() as libc::c_long, libc::SYS_fcntl);
+    assert_eq!(Sysno::fdatasync.id() as libc::c_long, libc::SYS_fdatasync);
+    assert_eq!(Sysno::fgetxattr.id() as libc::c_long, libc::SYS_fgetxattr);
+    assert_eq!(
+        Sysno::finit_module.id() as libc::c_long,
+        libc::SYS_finit_module
+    );
+    assert_eq!(Sysno::flistxattr.id() as libc::c_long, libc::SYS_flistxattr);
+    assert_eq!(Sysno::flock.id() as libc::c_long, libc::SYS_flock);
+    assert_eq!(Sysno::fork.id() as libc::c_long, libc::SYS_fork);
+    assert_eq!(
+        Sysno::fremovexattr.id() as libc::c_long,
+        libc::SYS_fremovexattr
+    );
+    assert_eq!(Sysno::fsconfig.id() as libc::c_long, libc::SYS_fsconfig);
+    assert_eq!(Sysno::fsetxattr.id() as libc::c_long, libc::SYS_fsetxattr);
+    assert_eq!(Sysno::fsmount.id() as libc::c_long, libc::SYS_fsmount);
+    assert_eq!(Sysno::fsopen.id() as libc::c_long, libc::SYS_fsopen);
+    assert_eq!(Sysno::fspick.id() as libc::c_long, libc::SYS_fspick);
+    assert_eq!(Sysno::fstat.id() as libc::c_long, libc::SYS_fstat);
+    assert_eq!(Sysno::fstatfs.id() as libc::c_long, libc::SYS_fstatfs);
+    assert_eq!(Sysno::fsync.id() as libc::c_long, libc::SYS_fsync);
+    assert_eq!(Sysno::ftruncate.id() as libc::c_long, libc::SYS_ftruncate);
+    assert_eq!(Sysno::futex.id() as libc::c_long, libc::SYS_futex);
+    assert_eq!(
+        Sysno::futex_waitv.id() as libc::c_long,
+        libc::SYS_futex_waitv
+    );
+    assert_eq!(Sysno::futimesat.id() as libc::c_long, libc::SYS_futimesat);
+    assert_eq!(
+        Sysno::get_mempolicy.id() as libc::c_long,
+        libc::SYS_get_mempolicy
+    );
+    assert_eq!(
+        Sysno::get_robust_list.id() as libc::c_long,
+        libc::SYS_get_robust_list
+    );
+    assert_eq!(Sysno::getcpu.id() as libc::c_long, libc::SYS_getcpu);
+    assert_eq!(Sysno::getcwd.id() as libc::c_long, libc::SYS_getcwd);
+    assert_eq!(Sysno::getdents.id() as libc::c_long, libc::SYS_getdents);
+    assert_eq!(Sysno::getdents64.id() as libc::c_long, libc::SYS_getdents64);
+    assert_eq!(Sysno::getegid.id() as libc::c_long, libc::SYS_getegid);
+    assert_eq!(Sysno::geteuid.id() as libc::c_long, libc::SYS_geteuid);
+    assert_eq!(Sysno::getgid.id() as libc::c_long, libc::SYS_getgid);
+    assert_eq!(Sysno::getgroups.id() as libc::c_long, libc::SYS_getgroups);
+    assert_eq!(Sysno::getitimer.id() as libc::c_long, libc::SYS_getitimer);
+    assert_eq!(
+        Sysno::getpeername.id() as libc::c_long,
+        libc::SYS_getpeername
+    );
+    assert_eq!(Sysno::getpgid.id() as libc::c_long, libc::SYS_getpgid);
+    assert_eq!(Sysno::getpgrp.id() as libc::c_long, libc::SYS_getpgrp);
+    assert_eq!(Sysno::getpid.id() as libc::c_long, libc::SYS_getpid);
+    assert_eq!(Sysno::getpmsg.id() as libc::c_long, libc::SYS_getpmsg);
+    assert_eq!(Sysno::getppid.id() as libc::c_long, libc::SYS_getppid);
+    assert_eq!(
+        Sysno::getpriority.id() as libc::c_long,
+        libc::SYS_getpriority
+    );
+    assert_eq!(Sysno::getrandom.id() as libc::c_long, libc::SYS_getrandom);
+    assert_eq!(Sysno::getresgid.id() as libc::c_long, libc::SYS_getresgid);
+    assert_eq!(Sysno::getresuid.id() as libc::c_long, libc::SYS_getresuid);
+    assert_eq!(Sysno::getrlimit.id() as libc::c_long, libc::SYS_getrlimit);
+    assert_eq!(Sysno::getrusage.id() as libc::c_long, libc::SYS_getrusage);
+    assert_eq!(Sysno::getsid.id() as libc::c_long, libc::SYS_getsid);
+    assert_eq!(
+        Sysno::getsockname.id() as libc::c_long,
+        libc::SYS_getsockname
+    );
+    assert_eq!(Sysno::getsockopt.id() as libc::c_long, libc::SYS_getsockopt);
+    assert_eq!(Sysno::gettid.id() as libc::c_long, libc::SYS_gettid);
+    assert_eq!(
+        Sysno::gettimeofday.id() as libc::c_long,
+        libc::SYS_gettimeofday
+    );
+    assert_eq!(Sysno::getuid.id() as libc::c_long, libc::SYS_getuid);
+    assert_eq!(Sysno::getxattr.id() as libc::c_long, libc::SYS_getxattr);
+    assert_eq!(
+        Sysno::init_module.id() as libc::c_long,
+        libc::SYS_init_module
+    );
+    assert_eq!(
+        Sysno::inotify_add_watch.id() as libc::c_long,
+        libc::SYS_inotify_add_watch
+    );
+    assert_eq!(
+        Sysno::inotify_init.id() as libc::c_long,
+        libc::SYS_inotify_init
+    );
+    assert_eq!(
+        Sysno::inotify_init1.id() as libc::c_long,
+        libc::SYS_inotify_init1
+    );
+    assert_eq!(
+        Sysno::inotify_rm_watch.id() as libc::c_long,
+        libc::SYS_inotify_rm_watch
+    );
+    assert_eq!(Sysno::io_cancel.id() as libc::c_long, libc::SYS_io_cancel);
+    assert_eq!(Sysno::io_destroy.id() as libc::c_long, libc::SYS_io_destroy);
+    assert_eq!(
+        Sysno::io_getevents.id() as libc::c_long,
+        libc::SYS_io_getevents
+    );
+    assert_eq!(Sysno::io_setup.id() as libc::c_long, libc::SYS_io_setup);
+    assert_eq!(Sysno::io_submit.id() as libc::c_long, libc::SYS_io_submit);
+    assert_eq!(
+        Sysno::io_uring_enter.id() as libc::c_long,
+        libc::SYS_io_uring_enter
+    );
+    assert_eq!(
+        Sysno::io_uring_register.id() as libc::c_long,
+        libc::SYS_io_uring_register
+    );
+    assert_eq!(
+        Sysno::io_uring_setup.id() as libc::c_long,
+        libc::SYS_io_uring_setup
+    );
+    assert_eq!(Sysno::ioctl.id() as libc::c_long, libc::SYS_ioctl);
+    assert_eq!(Sysno::ioprio_get.id() as libc::c_long, libc::SYS_ioprio_get);
+    assert_eq!(Sysno::ioprio_set.id() as libc::c_long, libc::SYS_ioprio_set);
+    assert_eq!(Sysno::kcmp.id() as libc::c_long, libc::SYS_kcmp);
+    assert_eq!(Sysno::kexec_load.id() as libc::c_long, libc::SYS_kexec_load);
+    assert_eq!(Sysno::keyctl.id() as libc::c_long, libc::SYS_keyctl);
+    assert_eq!(Sysno::kill.id() as libc::c_long, libc::SYS_kill);
+    assert_eq!(
+        Sysno::landlock_add_rule.id() as libc::c_long,
+        libc::SYS_landlock_add_rule
+    );
+    assert_eq!(
+        Sysno::landlock_create_ruleset.id() as libc::c_long,
+        libc::SYS_landlock_create_ruleset
+    );
+    assert_eq!(
+        Sysno::landlock_restrict_self.id() as libc::c_long,
+        libc::SYS_landlock_restrict_self
+    );
+    assert_eq!(Sysno::lchown.id() as libc::c_long, libc::SYS_lchown);
+    assert_eq!(Sysno::lgetxattr.id() as libc::c_long, libc::SYS_lgetxattr);
+    assert_eq!(Sysno::link.id() as libc::c_long, libc::SYS_link);
+    assert_eq!(Sysno::linkat.id() as libc::c_long, libc::SYS_linkat);
+    assert_eq!(Sysno::listen.id() as libc::c_long, libc::SYS_listen);
+    assert_eq!(Sysno::listxattr.id() as libc::c_long, libc::SYS_listxattr);
+    assert_eq!(Sysno::llistxattr.id() as libc::c_long, libc::SYS_llistxattr);
+    assert_eq!(
+        Sysno::lookup_dcookie.id() as libc::c_long,
+        libc::SYS_lookup_dcookie
+    );
+    assert_eq!(
+        Sysno::lremovexattr.id() as libc::c_long,
+        libc::SYS_lremovexattr
+    );
+    assert_eq!(Sysno::lseek.id() as libc::c_long, libc::SYS_lseek);
+    assert_eq!(Sysno::lsetxattr.id() as libc::c_long, libc::SYS_lsetxattr);
+    assert_eq!(Sysno::lstat.id() as libc::c_long, libc::SYS_lstat);
+    assert_eq!(Sysno::madvise.id() as libc::c_long, libc::SYS_madvise);
+    assert_eq!(Sysno::mbind.id() as libc::c_long, libc::SYS_mbind);
+    assert_eq!(Sysno::membarrier.id() as libc::c_long, libc::SYS_membarrier);
+    assert_eq!(
+        Sysno::memfd_create.id() as libc::c_long,
+        libc::SYS_memfd_create
+    );
+    assert_eq!(
+        Sysno::migrate_pages.id() as libc::c_long,
+        libc::SYS_migrate_pages
+    );
+    assert_eq!(Sysno::mincore.id() as libc::c_long, libc::SYS_mincore);
+    assert_eq!(Sysno::mkdir.id() as libc::c_long, libc::SYS_mkdir);
+    assert_eq!(Sysno::mkdirat.id() as libc::c_long, libc::SYS_mkdirat);
+    assert_eq!(Sysno::mknod.id() as libc::c_long, libc::SYS_mknod);
+    assert_eq!(Sysno::mknodat.id() as libc::c_long, libc::SYS_mknodat);
+    assert_eq!(Sysno::mlock.id() as libc::c_long, libc::SYS_mlock);
+    assert_eq!(Sysno::mlock2.id() as libc::c_long, libc::SYS_mlock2);
+    assert_eq!(Sysno::mlockall.id() as libc::c_long, libc::SYS_mlockall);
+    assert_eq!(Sysno::mmap.id() as libc::c_long, libc::SYS_mmap);
+    assert_eq!(Sysno::mount.id() as libc::c_long, libc::SYS_mount);
+    assert_eq!(
+        Sysno::mount_setattr.id() as libc::c_long,
+        libc::SYS_mount_setattr
+    );
+    assert_eq!(Sysno::move_mount.id() as libc::c_long, libc::SYS_move_mount);
+    assert_eq!(Sysno::move_pages.id() as libc::c_long, libc::SYS_move_pages);
+    assert_eq!(Sysno::mprotect.id() as libc::c_long, libc::SYS_mprotect);
+    assert_eq!(
+        Sysno::mq_getsetattr.id() as libc::c_long,
+        libc::SYS_mq_getsetattr
+    );
+    assert_eq!(Sysno::mq_notify.id() as libc::c_long, libc::SYS_mq_notify);
+    assert_eq!(Sysno::mq_open.id() as libc::c_long, libc::SYS_mq_open);
+    assert_eq!(
+        Sysno::mq_timedreceive.id() as libc::c_long,
+        libc::SYS_mq_timedreceive
+    );
+    assert_eq!(
+        Sysno::mq_timedsend.id() as libc::c_long,
+        libc::SYS_mq_timedsend
+    );
+    assert_eq!(Sysno::mq_unlink.id() as libc::c_long, libc::SYS_mq_unlink);
+    assert_eq!(Sysno::mremap.id() as libc::c_long, libc::SYS_mremap);
+    assert_eq!(Sysno::msgctl.id() as libc::c_long, libc::SYS_msgctl);
+    assert_eq!(Sysno::msgget.id() as libc::c_long, libc::SYS_msgget);
+    assert_eq!(Sysno::msgrcv.id() as libc::c_long, libc::SYS_msgrcv);
+    assert_eq!(Sysno::msgsnd.id() as libc::c_long, libc::SYS_msgsnd);
+    assert_eq!(Sysno::msync.id() as libc::c_long, libc::SYS_msync);
+    assert_eq!(Sysno::munlock.id() as libc::c_long, libc::SYS_munlock);
+    assert_eq!(Sysno::munlockall.id() as libc::c_long, libc::SYS_munlockall);
+    assert_eq!(Sysno::munmap.id() as libc::c_long, libc::SYS_munmap);
+    assert_eq!(
+        Sysno::name_to_handle_at.id() as libc::c_long,
+        libc::SYS_name_to_handle_at
+    );
+    assert_eq!(Sysno::nanosleep.id() as libc::c_long, libc::SYS_nanosleep);
+    assert_eq!(Sysno::newfstatat.id() as libc::c_long, libc::SYS_newfstatat);
+    assert_eq!(Sysno::nfsservctl.id() as libc::c_long, libc::SYS_nfsservctl);
+    assert_eq!(Sysno::open.id() as libc::c_long, libc::SYS_open);
+    assert_eq!(
+        Sysno::open_by_handle_at.id() as libc::c_long,
+        libc::SYS_open_by_handle_at
+    );
+    assert_eq!(Sysno::open_tree.id() as libc::c_long, libc::SYS_open_tree);
+    assert_eq!(Sysno::openat.id() as libc::c_long, libc::SYS_openat);
+    assert_eq!(Sysno::openat2.id() as libc::c_long, libc::SYS_openat2);
+    assert_eq!(Sysno::pause.id() as libc::c_long, libc::SYS_pause);
+    assert_eq!(
+        Sysno::perf_event_open.id() as libc::c_long,
+        libc::SYS_perf_event_open
+    );
+    assert_eq!(
+        Sysno::personality.id() as libc::c_long,
+        libc::SYS_personality
+    );
+    assert_eq!(
+        Sysno::pidfd_getfd.id() as libc::c_long,
+        libc::SYS_pidfd_getfd
+    );
+    assert_eq!(Sysno::pidfd_open.id() as libc::c_long, libc::SYS_pidfd_open);
+    assert_eq!(
+        Sysno::pidfd_send_signal.id() as libc::c_long,
+        libc::SYS_pidfd_send_signal
+    );
+    assert_eq!(Sysno::pipe.id() as libc::c_long, libc::SYS_pipe);
+    assert_eq!(Sysno::pipe2.id() as libc::c_long, libc::SYS_pipe2);
+    assert_eq!(Sysno::pivot_root.id() as libc::c_long, libc::SYS_pivot_root);
+    assert_eq!(Sysno::pkey_alloc.id() as libc::c_long, libc::SYS_pkey_alloc);
+    assert_eq!(Sysno::pkey_free.id() as libc::c_long, libc::SYS_pkey_free);
+    assert_eq!(
+        Sysno::pkey_mprotect.id() as libc::c_long,
+        libc::SYS_pkey_mprotect
+    );
+    assert_eq!(Sysno::poll.id() as libc::c_long, libc::SYS_poll);
+    assert_eq!(Sysno::ppoll.id() as libc::c_long, libc::SYS_ppoll);
+    assert_eq!(Sysno::prctl.id() as libc::c_long, libc::SYS_prctl);
+    assert_eq!(Sysno::pread64.id() as libc::c_long, libc::SYS_pread64);
+    assert_eq!(Sysno::preadv.id() as libc::c_long, libc::SYS_preadv);
+    assert_eq!(Sysno::preadv2.id() as libc::c_long, libc::SYS_preadv2);
+    assert_eq!(Sysno::prlimit64.id() as libc::c_long, libc::SYS_prlimit64);
+    assert_eq!(
+        Sysno::process_madvise.id() as libc::c_long,
+        libc::SYS_process_madvise
+    );
+    assert_eq!(
+        Sysno::process_mrelease.id() as libc::c_long,
+        libc::SYS_process_mrelease
+    );
+    assert_eq!(
+        Sysno::process_vm_readv.id() as libc::c_long,
+        libc::SYS_process_vm_readv
+    );
+    assert_eq!(
+        Sysno::process_vm_writev.id() as libc::c_long,
+        libc::SYS_process_vm_writev
+    );
+    assert_eq!(Sysno::pselect6.id() as libc::c_long, libc::SYS_pselect6);
+    assert_eq!(Sysno::ptrace.id() as libc::c_long, libc::SYS_ptrace);
+    assert_eq!(Sysno::putpmsg.id() as libc::c_long, libc::SYS_putpmsg);
+    assert_eq!(Sysno::pwrite64.id() as libc::c_long, libc::SYS_pwrite64);
+    assert_eq!(Sysno::pwritev.id() as libc::c_long, libc::SYS_pwritev);
+    assert_eq!(Sysno::pwritev2.id() as libc::c_long, libc::SYS_pwritev2);
+    assert_eq!(Sysno::quotactl.id() as libc::c_long, libc::SYS_quotactl);
+    assert_eq!(
+        Sysno::quotactl_fd.id() as libc::c_long,
+        libc::SYS_quotactl_fd
+    );
+    assert_eq!(Sysno::read.id() as libc::c_long, libc::SYS_read);
+    assert_eq!(Sysno::readahead.id() as libc::c_long, libc::SYS_readahead);
+    assert_eq!(Sysno::readlink.id() as libc::c_long, libc::SYS_readlink);
+    assert_eq!(Sysno::readlinkat.id() as libc::c_long, libc::SYS_readlinkat);
+    assert_eq!(Sysno::readv.id() as libc::c_long, libc::SYS_readv);
+    assert_eq!(Sysno::reboot.id() as libc::c_long, libc::SYS_reboot);
+    assert_eq!(Sysno::recvfrom.id() as libc::c_long, libc::SYS_recvfrom);
+    assert_eq!(Sysno::recvmmsg.id() as libc::c_long, libc::SYS_recvmmsg);
+    assert_eq!(Sysno::recvmsg.id() as libc::c_long, libc::SYS_recvmsg);
+    assert_eq!(
+        Sysno::remap_file_pages.id() as libc::c_long,
+        libc::SYS_remap_file_pages
+    );
+    assert_eq!(
+        Sysno::removexattr.id() as libc::c_long,
+        libc::SYS_removexattr
+    );
+    assert_eq!(Sysno::rename.id() as libc::c_long, libc::SYS_rename);
+    assert_eq!(Sysno::renameat.id() as libc::c_long, libc::SYS_renameat);
+    assert_eq!(Sysno::renameat2.id() as libc::c_long, libc::SYS_renameat2);
+    assert_eq!(
+        Sysno::request_key.id() as libc::c_long,
+        libc::SYS_request_key
+    );
+    assert_eq!(
+        Sysno::restart_syscall.id() as libc::c_long,
+        libc::SYS_restart_syscall
+    );
+    assert_eq!(Sysno::rmdir.id() as libc::c_long, libc::SYS_rmdir);
+    assert_eq!(Sysno::rseq.id() as libc::c_long, libc::SYS_rseq);
+    assert_eq!(
+        Sysno::rt_sigaction.id() as libc::c_long,
+        libc::SYS_rt_sigaction
+    );
+    assert_eq!(
+        Sysno::rt_sigpending.id() as libc::c_long,
+        libc::SYS_rt_sigpending
+    );
+    assert_eq!(
+        Sysno::rt_sigprocmask.id() as libc::c_long,
+        libc::SYS_rt_sigprocmask
+    );
+    assert_eq!(
+        Sysno::rt_sigqueueinfo.id() as libc::c_long,
+        libc::SYS_rt_sigqueueinfo
+    );
+    assert_eq!(
+        Sysno::rt_sigreturn.id() as libc::c_long,
+        libc::SYS_rt_sigreturn
+    );
+    assert_eq!(
+        Sysno::rt_sigsuspend.id() as libc::c_long,
+        libc::SYS_rt_sigsuspend
+    );
+    assert_eq!(
+        Sysno::rt_sigtimedwait.id() as libc::c_long,
+        libc::SYS_rt_sigtimedwait
+    );
+    assert_eq!(
+        Sysno::rt_tgsigqueueinfo.id() as libc::c_long,
+        libc::SYS_rt_tgsigqueueinfo
+    );
+    assert_eq!(
+        Sysno::sched_get_priority_max.id() as libc::c_long,
+        libc::SYS_sched_get_priority_max
+    );
+    assert_eq!(
+        Sysno::sched_get_priority_min.id() as libc::c_long,
+        libc::SYS_sched_get_priority_min
+    );
+    assert_eq!(
+        Sysno::sched_getaffinity.id() as libc::c_long,
+        libc::SYS_sched_getaffinity
+    );
+    assert_eq!(
+        Sysno::sched_getattr.id() as libc::c_long,
+        libc::SYS_sched_getattr
+    );
+    assert_eq!(
+        Sysno::sched_getparam.id() as libc::c_long,
+        libc::SYS_sched_getparam
+    );
+    assert_eq!(
+        Sysno::sched_getscheduler.id() as libc::c_long,
+        libc::SYS_sched_getscheduler
+    );
+    assert_eq!(
+        Sysno::sched_rr_get_interval.id() as libc::c_long,
+        libc::SYS_sched_rr_get_interval
+    );
+    assert_eq!(
+        Sysno::sched_setaffinity.id() as libc::c_long,
+        libc::SYS_sched_setaffinity
+    );
+    assert_eq!(
+        Sysno::sched_setattr.id() as libc::c_long,
+        libc::SYS_sched_setattr
+    );
+    assert_eq!(
+        Sysno::sched_setparam.id() as libc::c_long,
+        libc::SYS_sched_setparam
+    );
+    assert_eq!(
+        Sysno::sched_setscheduler.id() as libc::c_long,
+        libc::SYS_sched_setscheduler
+    );
+    assert_eq!(
+        Sysno::sched_yield.id() as libc::c_long,
+        libc::SYS_sched_yield
+    );
+    assert_eq!(Sysno::seccomp.id() as libc::c_long, libc::SYS_seccomp);
+    assert_eq!(Sysno::semctl.id() as libc::c_long, libc::SYS_semctl);
+    assert_eq!(Sysno::semget.id() as libc::c_long, libc::SYS_semget);
+    assert_eq!(Sysno::semop.id() as libc::c_long, libc::SYS_semop);
+    assert_eq!(Sysno::semtimedop.id() as libc::c_long, libc::SYS_semtimedop);
+    assert_eq!(Sysno::sendfile.id() as libc::c_long, libc::SYS_sendfile);
+    assert_eq!(Sysno::sendmmsg.id() as libc::c_long, libc::SYS_sendmmsg);
+    assert_eq!(Sysno::sendmsg.id() as libc::c_long, libc::SYS_sendmsg);
+    assert_eq!(Sysno::sendto.id() as libc::c_long, libc::SYS_sendto);
+    assert_eq!(
+        Sysno::set_mempolicy.id() as libc::c_long,
+        libc::SYS_set_mempolicy
+    );
+    assert_eq!(
+        Sysno::set_robust_list.id() as libc::c_long,
+        libc::SYS_set_robust_list
+    );
+    assert_eq!(
+        Sysno::set_thread_area.id() as libc::c_long,
+        libc::SYS_set_thread_area
+    );
+    assert_eq!(
+        Sysno::set_tid_address.id() as libc::c_long,
+        libc::SYS_set_tid_address
+    );
+    assert_eq!(
+        Sysno::setdomainname.id() as libc::c_long,
+        libc::SYS_setdomainname
+    );
+    assert_eq!(Sysno::setfsgid.id() as libc::c_long, libc::SYS_setfsgid);
+    assert_eq!(Sysno::setfsuid.id() as libc::c_long, libc::SYS_setfsuid);
+    assert_eq!(Sysno::setgid.id() as libc::c_long, libc::SYS_setgid);
+    assert_eq!(Sysno::setgroups.id() as libc::c_long, libc::SYS_setgroups);
+    assert_eq!(
+        Sysno::sethostname.id() as libc::c_long,
+        libc::SYS_sethostname
+    );
+    assert_eq!(Sysno::setitimer.id() as libc::c_long, libc::SYS_setitimer);
+    assert_eq!(Sysno::setns.id() as libc::c_long, libc::SYS_setns);
+    assert_eq!(Sysno::setpgid.id() as libc::c_long, libc::SYS_setpgid);
+    assert_eq!(
+        Sysno::setpriority.id() as libc::c_long,
+        libc::SYS_setpriority
+    );
+    assert_eq!(Sysno::setregid.id() as libc::c_long, libc::SYS_setregid);
+    assert_eq!(Sysno::setresgid.id() as libc::c_long, libc::SYS_setresgid);
+    assert_eq!(Sysno::setresuid.id() as libc::c_long, libc::SYS_setresuid);
+    assert_eq!(Sysno::setreuid.id() as libc::c_long, libc::SYS_setreuid);
+    assert_eq!(Sysno::setrlimit.id() as libc::c_long, libc::SYS_setrlimit);
+    assert_eq!(Sysno::setsid.id() as libc::c_long, libc::SYS_setsid);
+    assert_eq!(Sysno::setsockopt.id() as libc::c_long, libc::SYS_setsockopt);
+    assert_eq!(
+        Sysno::settimeofday.id() as libc::c_long,
+        libc::SYS_settimeofday
+    );
+    assert_eq!(Sysno::setuid.id() as libc::c_long, libc::SYS_setuid);
+    assert_eq!(Sysno::setxattr.id() as libc::c_long, libc::SYS_setxattr);
+    assert_eq!(Sysno::shmat.id() as libc::c_long, libc::SYS_shmat);
+    assert_eq!(Sysno::shmctl.id() as libc::c_long, libc::SYS_shmctl);
+    assert_eq!(Sysno::shmdt.id() as libc::c_long, libc::SYS_shmdt);
+    assert_eq!(Sysno::shmget.id() as libc::c_long, libc::SYS_shmget);
+    assert_eq!(Sysno::shutdown.id() as libc::c_long, libc::SYS_shutdown);
+    assert_eq!(
+        Sysno::sigaltstack.id() as libc::c_long,
+        libc::SYS_sigaltstack
+    );
+    assert_eq!(Sysno::signalfd.id() as libc::c_long, libc::SYS_signalfd);
+    assert_eq!(Sysno::signalfd4.id() as libc::c_long, libc::SYS_signalfd4);
+    assert_eq!(Sysno::socket.id() as libc::c_long, libc::SYS_socket);
+    assert_eq!(Sysno::socketpair.id() as libc::c_long, libc::SYS_socketpair);
+    assert_eq!(Sysno::splice.id() as libc::c_long, libc::SYS_splice);
+    assert_eq!(Sysno::stat.id() as libc::c_long, libc::SYS_stat);
+    assert_eq!(Sysno::statfs.id() as libc::c_long, libc::SYS_statfs);
+    assert_eq!(Sysno::statx.id() as libc::c_long, libc::SYS_statx);
+    assert_eq!(Sysno::swapoff.id() as libc::c_long, libc::SYS_swapoff);
+    assert_eq!(Sysno::swapon.id() as libc::c_long, libc::SYS_swapon);
+    assert_eq!(Sysno::symlink.id() as libc::c_long, libc::SYS_symlink);
+    assert_eq!(Sysno::symlinkat.id() as libc::c_long, libc::SYS_symlinkat);
+    assert_eq!(Sysno::sync.id() as libc::c_long, libc::SYS_sync);
+    assert_eq!(
+        Sysno::sync_file_range.id() as libc::c_long,
+        libc::SYS_sync_file_range
+    );
+    assert_eq!(Sysno::syncfs.id() as libc::c_long, libc::SYS_syncfs);
+    assert_eq!(Sysno::sysfs.id() as libc::c_long, libc::SYS_sysfs);
+    assert_eq!(Sysno::sysinfo.id() as libc::c_long, libc::SYS_sysinfo);
+    assert_eq!(Sysno::syslog.id() as libc::c_long, libc::SYS_syslog);
+    assert_eq!(Sysno::sysmips.id() as libc::c_long, libc::SYS_sysmips);
+    assert_eq!(Sysno::tee.id() as libc::c_long, libc::SYS_tee);
+    assert_eq!(Sysno::tgkill.id() as libc::c_long, libc::SYS_tgkill);
+    assert_eq!(
+        Sysno::timer_create.id() as libc::c_long,
+        libc::SYS_timer_create
+    );
+    assert_eq!(
+        Sysno::timer_delete.id() as libc::c_long,
+        libc::SYS_timer_delete
+    );
+    assert_eq!(
+        Sysno::timer_getoverrun.id() as libc::c_long,
+        libc::SYS_timer_getoverrun
+    );
+    assert_eq!(
+        Sysno::timer_gettime.id() as libc::c_long,
+        libc::SYS_timer_gettime
+    );
+    assert_eq!(
+        Sysno::timer_settime.id() as libc::c_long,
+        libc::SYS_timer_settime
+    );
+    assert_eq!(Sysno::timerfd.id() as libc::c_long, libc::SYS_timerfd);
+    assert_eq!(
+        Sysno::timerfd_create.id() as libc::c_long,
+        libc::SYS_timerfd_create
+    );
+    assert_eq!(
+        Sysno::timerfd_gettime.id() as libc::c_long,
+        libc::SYS_timerfd_gettime
+    );
+    assert_eq!(
+        Sysno::timerfd_settime.id() as libc::c_long,
+        libc::SYS_timerfd_settime
+    );
+    assert_eq!(Sysno::times.id() as libc::c_long, libc::SYS_times);
+    assert_eq!(Sysno::tkill.id() as libc::c_long, libc::SYS_tkill);
+    assert_eq!(Sysno::truncate.id() as libc::c_long, libc::SYS_truncate);
+    assert_eq!(Sysno::umask.id() as libc::c_long, libc::SYS_umask);
+    assert_eq!(Sysno::umount2.id() as libc::c_long, libc::SYS_umount2);
+    assert_eq!(Sysno::uname.id() as libc::c_long, libc::SYS_uname);
+    assert_eq!(Sysno::unlink.id() as libc::c_long, libc::SYS_unlink);
+    assert_eq!(Sysno::unlinkat.id() as libc::c_long, libc::SYS_unlinkat);
+    assert_eq!(Sysno::unshare.id() as libc::c_long, libc::SYS_unshare);
+    assert_eq!(
+        Sysno::userfaultfd.id() as libc::c_long,
+        libc::SYS_userfaultfd
+    );
+    assert_eq!(Sysno::ustat.id() as libc::c_long, libc::SYS_ustat);
+    assert_eq!(Sysno::utime.id() as libc::c_long, libc::SYS_utime);
+    assert_eq!(Sysno::utimensat.id() as libc::c_long, libc::SYS_utimensat);
+    assert_eq!(Sysno::utimes.id() as libc::c_long, libc::SYS_utimes);
+    assert_eq!(Sysno::vhangup.id() as libc::c_long, libc::SYS_vhangup);
+    assert_eq!(Sysno::vmsplice.id() as libc::c_long, libc::SYS_vmsplice);
+    assert_eq!(Sysno::vserver.id() as libc::c_long, libc::SYS_vserver);
+    assert_eq!(Sysno::wait4.id() as libc::c_long, libc::SYS_wait4);
+    assert_eq!(Sysno::waitid.id() as libc::c_long, libc::SYS_waitid);
+    assert_eq!(Sysno::write.id() as libc::c_long, libc::SYS_write);
+    assert_eq!(Sysno::writev.id() as libc::c_long, libc::SYS_writev);
+}
+
+#[cfg(all(target_arch = "powerpc", target_pointer_width = "32"))]
+#[test]
+fn powerpc() {
+    use rawsys_linux::powerpc::v6_12::Sysno;
+
+    assert_eq!(Sysno::_llseek.id() as libc::c_long, libc::SYS__llseek);
+    assert_eq!(Sysno::_newselect.id() as libc::c_long, libc::SYS__newselect);
+    assert_eq!(Sysno::_sysctl.id() as libc::c_long, libc::SYS__sysctl);
+    assert_eq!(Sysno::accept.id() as libc::c_long, libc::SYS_accept);
+    assert_eq!(Sysno::accept4.id() as libc::c_long, libc::SYS_accept4);
+    assert_eq!(Sysno::access.id() as libc::c_long, libc::SYS_access);
+    assert_eq!(Sysno::acct.id() as libc::c_long, libc::SYS_acct);
+    assert_eq!(Sysno::add_key.id() as libc::c_long, libc::SYS_add_key);
+    assert_eq!(Sysno::adjtimex.id() as libc::c_long, libc::SYS_adjtimex);
+    assert_eq!(
+        Sysno::afs_syscall.id() as libc::c_long,
+        libc::SYS_afs_syscall
+    );
+    assert_eq!(Sysno::alarm.id() as libc::c_long, libc::SYS_alarm);
+    assert_eq!(Sysno::bdflush.id() as libc::c_long, libc::SYS_bdflush);
+    assert_eq!(Sysno::bind.id() as libc::c_long, libc::SYS_bind);
+    assert_eq!(Sysno::bpf.id() as libc::c_long, libc::SYS_bpf);
+    assert_eq!(Sysno::break_.id() as libc::c_long, libc::SYS_break);
+    assert_eq!(Sysno::brk.id() as libc::c_long, libc::SYS_brk);
+    assert_eq!(Sysno::capget.id() as libc::c_long, libc::SYS_capget);
+    assert_eq!(Sysno::capset.id() as libc::c_long, libc::SYS_capset);
+    assert_eq!(Sysno::chdir.id() as libc::c_long, libc::SYS_chdir);
+    assert_eq!(Sysno::chmod.id() as libc::c_long, libc::SYS_chmod);
+    assert_eq!(Sysno::chown.id() as libc::c_long, libc::SYS_chown);
+    assert_eq!(Sysno::chroot.id() as libc::c_long, libc::SYS_chroot);
+    assert_eq!(
+        Sysno::clock_adjtime.id() as libc::c_long,
+        libc::SYS_clock_adjtime
+    );
+    assert_eq!(
+        Sysno::clock_getres.id() as libc::c_long,
+        libc::SYS_clock_getres
+    );
+    assert_eq!(
+        Sysno::clock_gettime.id() as libc::c_long,
+        libc::SYS_clock_gettime
+    );
+    assert_eq!(
+        Sysno::clock_nanosleep.id() as libc::c_long,
+        libc::SYS_clock_nanosleep
+    );
+    assert_eq!(
+        Sysno::clock_settime.id() as libc::c_long,
+        libc::SYS_clock_settime
+    );
+    assert_eq!(Sysno::clone.id() as libc::c_long, libc::SYS_clone);
+    assert_eq!(Sysno::clone3.id() as libc::c_long, libc::SYS_clone3);
+    assert_eq!(Sysno::close.id() as libc::c_long, libc::SYS_close);
+    assert_eq!(
+        Sysno::close_range.id() as libc::c_long,
+        libc::SYS_close_range
+    );
+    assert_eq!(Sysno::connect.id() as libc::c_long, libc::SYS_connect);
+    assert_eq!(
+        Sysno::copy_file_range.id() as libc::c_long,
+        libc::SYS_copy_file_range
+    );
+    assert_eq!(Sysno::creat.id() as libc::c_long, libc::SYS_creat);
+    assert_eq!(
+        Sysno::delete_module.id() as libc::c_long,
+        libc::SYS_delete_module
+    );
+    assert_eq!(Sysno::dup.id() as libc::c_long, libc::SYS_dup);
+    assert_eq!(Sysno::dup2.id() as libc::c_long, libc::SYS_dup2);
+    assert_eq!(Sysno::dup3.id() as libc::c_long, libc::SYS_dup3);
+    assert_eq!(
+        Sysno::epoll_create.id() as libc::c_long,
+        libc::SYS_epoll_create
+    );
+    assert_eq!(
+        Sysno::epoll_create1.id() as libc::c_long,
+        libc::SYS_epoll_create1
+    );
+    assert_eq!(Sysno::epoll_ctl.id() as libc::c_long, libc::SYS_epoll_ctl);
+    assert_eq!(
+        Sysno::epoll_pwait.id() as libc::c_long,
+        libc::SYS_epoll_pwait
+    );
+    assert_eq!(
+        Sysno::epoll_pwait2.id() as libc::c_long,
+        libc::SYS_epoll_pwait2
+    );
+    assert_eq!(Sysno::epoll_wait.id() as libc::c_long, libc::SYS_epoll_wait);
+    assert_eq!(Sysno::eventfd.id() as libc::c_long, libc::SYS_eventfd);
+    assert_eq!(Sysno::eventfd2.id() as libc::c_long, libc::SYS_eventfd2);
+    assert_eq!(Sysno::execve.id() as libc::c_long, libc::SYS_execve);
+    assert_eq!(Sysno::execveat.id() as libc::c_long, libc::SYS_execveat);
+    assert_eq!(Sysno::exit.id() as libc::c_long, libc::SYS_exit);
+    assert_eq!(Sysno::exit_group.id() as libc::c_long, libc::SYS_exit_group);
+    assert_eq!(Sysno::faccessat.id() as libc::c_long, libc::SYS_faccessat);
+    assert_eq!(Sysno::faccessat2.id() as libc::c_long, libc::SYS_faccessat2);
+    assert_eq!(Sysno::fadvise64.id() as libc::c_long, libc::SYS_fadvise64);
+    assert_eq!(
+        Sysno::fadvise64_64.id() as libc::c_long,
+        libc::SYS_fadvise64_64
+    );
+    assert_eq!(Sysno::fallocate.id() as libc::c_long, libc::SYS_fallocate);
+    assert_eq!(
+        Sysno::fanotify_init.id() as libc::c_long,
+        libc::SYS_fanotify_init
+    );
+    assert_eq!(
+        Sysno::fanotify_mark.id() as libc::c_long,
+        libc::SYS_fanotify_mark
+    );
+    assert_eq!(Sysno::fchdir.id() as libc::c_long, libc::SYS_fchdir);
+    assert_eq!(Sysno::fchmod.id() as libc::c_long, libc::SYS_fchmod);
+    assert_eq!(Sysno::fchmodat.id() as libc::c_long, libc::SYS_fchmodat);
+    assert_eq!(Sysno::fchown.id() as libc::c_long, libc::SYS_fchown);
+    assert_eq!(Sysno::fchownat.id() as libc::c_long, libc::SYS_fchownat);
+    assert_eq!(Sysno::fcntl.id() as libc::c_long, libc::SYS_fcntl);
+    assert_eq!(Sysno::fcntl64.id() as libc::c_long, libc::SYS_fcntl64);
+    assert_eq!(Sysno::fdatasync.id() as libc::c_long, libc::SYS_fdatasync);
+    assert_eq!(Sysno::fgetxattr.id() as libc::c_long, libc::SYS_fgetxattr);
+    assert_eq!(
+        Sysno::finit_module.id() as libc::c_long,
+        libc::SYS_finit_module
+    );
+    assert_eq!(Sysno::flistxattr.id() as libc::c_long, libc::SYS_flistxattr);
+    assert_eq!(Sysno::flock.id() as libc::c_long, libc::SYS_flock);
+    assert_eq!(Sysno::fork.id() as libc::c_long, libc::SYS_fork);
+    assert_eq!(
+        Sysno::fremovexattr.id() as libc::c_long,
+        libc::SYS_fremovexattr
+    );
+    assert_eq!(Sysno::fsconfig.id() as libc::c_long, libc::SYS_fsconfig);
+    assert_eq!(Sysno::fsetxattr.id() as libc::c_long, libc::SYS_fsetxattr);
+    assert_eq!(Sysno::fsmount.id() as libc::c_long, libc::SYS_fsmount);
+    assert_eq!(Sysno::fsopen.id() as libc::c_long, libc::SYS_fsopen);
+    assert_eq!(Sysno::fspick.id() as libc::c_long, libc::SYS_fspick);
+    assert_eq!(Sysno::fstat.id() as libc::c_long, libc::SYS_fstat);
+    assert_eq!(Sysno::fstat64.id() as libc::c_long, libc::SYS_fstat64);
+    assert_eq!(Sysno::fstatat64.id() as libc::c_long, libc::SYS_fstatat64);
+    assert_eq!(Sysno::fstatfs.id() as libc::c_long, libc::SYS_fstatfs);
+    assert_eq!(Sysno::fstatfs64.id() as libc::c_long, libc::SYS_fstatfs64);
+    assert_eq!(Sysno::fsync.id() as libc::c_long, libc::SYS_fsync);
+    assert_eq!(Sysno::ftime.id() as libc::c_long, libc::SYS_ftime);
+    assert_eq!(Sysno::ftruncate.id() as libc::c_long, libc::SYS_ftruncate);
+    assert_eq!(
+        Sysno::ftruncate64.id() as libc::c_long,
+        libc::SYS_ftruncate64
+    );
+    assert_eq!(Sysno::futex.id() as libc::c_long, libc::SYS_futex);
+    assert_eq!(
+        Sysno::futex_waitv.id() as libc::c_long,
+        libc::SYS_futex_waitv
+    );
+    assert_eq!(Sysno::futimesat.id() as libc::c_long, libc::SYS_futimesat);
+    assert_eq!(
+        Sysno::get_mempolicy.id() as libc::c_long,
+        libc::SYS_get_mempolicy
+    );
+    assert_eq!(
+        Sysno::get_robust_list.id() as libc::c_long,
+        libc::SYS_get_robust_list
+    );
+    assert_eq!(Sysno::getcpu.id() as libc::c_long, libc::SYS_getcpu);
+    assert_eq!(Sysno::getcwd.id() as libc::c_long, libc::SYS_getcwd);
+    assert_eq!(Sysno::getdents.id() as libc::c_long, libc::SYS_getdents);
+    assert_eq!(Sysno::getdents64.id() as libc::c_long, libc::SYS_getdents64);
+    assert_eq!(Sysno::getegid.id() as libc::c_long, libc::SYS_getegid);
+    assert_eq!(Sysno::geteuid.id() as libc::c_long, libc::SYS_geteuid);
+    assert_eq!(Sysno::getgid.id() as libc::c_long, libc::SYS_getgid);
+    assert_eq!(Sysno::getgroups.id() as libc::c_long, libc::SYS_getgroups);
+    assert_eq!(Sysno::getitimer.id() as libc::c_long, libc::SYS_getitimer);
+    assert_eq!(
+        Sysno::getpeername.id() as libc::c_long,
+        libc::SYS_getpeername
+    );
+    assert_eq!(Sysno::getpgid.id() as libc::c_long, libc::SYS_getpgid);
+    assert_eq!(Sysno::getpgrp.id() as libc::c_long, libc::SYS_getpgrp);
+    assert_eq!(Sysno::getpid.id() as libc::c_long, libc::SYS_getpid);
+    assert_eq!(Sysno::getpmsg.id() as libc::c_long, libc::SYS_getpmsg);
+    assert_eq!(Sysno::getppid.id() as libc::c_long, libc::SYS_getppid);
+    assert_eq!(
+        Sysno::getpriority.id() as libc::c_long,
+        libc::SYS_getpriority
+    );
+    assert_eq!(Sysno::getrandom.id() as libc::c_long, libc::SYS_getrandom);
+    assert_eq!(Sysno::getresgid.id() as libc::c_long, libc::SYS_getresgid);
+    assert_eq!(Sysno::getresuid.id() as libc::c_long, libc::SYS_getresuid);
+    assert_eq!(Sysno::getrlimit.id() as libc::c_long, libc::SYS_getrlimit);
+    assert_eq!(Sysno::getrusage.id() as libc::c_long, libc::SYS_getrusage);
+    assert_eq!(Sysno::getsid.id() as libc::c_long, libc::SYS_getsid);
+    assert_eq!(
+        Sysno::getsockname.id() as libc::c_long,
+        libc::SYS_getsockname
+    );
+    assert_eq!(Sysno::getsockopt.id() as libc::c_long, libc::SYS_getsockopt);
+    assert_eq!(Sysno::gettid.id() as libc::c_long, libc::SYS_gettid);
+    assert_eq!(
+        Sysno::gettimeofday.id() as libc::c_long,
+        libc::SYS_gettimeofday
+    );
+    assert_eq!(Sysno::getuid.id() as libc::c_long, libc::SYS_getuid);
+    assert_eq!(Sysno::getxattr.id() as libc::c_long, libc::SYS_getxattr);
+    assert_eq!(Sysno::gtty.id() as libc::c_long, libc::SYS_gtty);
+    assert_eq!(Sysno::idle.id() as libc::c_long, libc::SYS_idle);
+    assert_eq!(
+        Sysno::init_module.id() as libc::c_long,
+        libc::SYS_init_module
+    );
+    assert_eq!(
+        Sysno::inotify_add_watch.id() as libc::c_long,
+        libc::SYS_inotify_add_watch
+    );
+    assert_eq!(
+        Sysno::inotify_init.id() as libc::c_long,
+        libc::SYS_inotify_init
+    );
+    assert_eq!(
+        Sysno::inotify_init1.id() as libc::c_long,
+        libc::SYS_inotify_init1
+    );
+    assert_eq!(
+        Sysno::inotify_rm_watch.id() as libc::c_long,
+        libc::SYS_inotify_rm_watch
+    );
+    assert_eq!(Sysno::io_cancel.id() as libc::c_long, libc::SYS_io_cancel);
+    assert_eq!(Sysno::io_destroy.id() as libc::c_long, libc::SYS_io_destroy);
+    assert_eq!(
+        Sysno::io_getevents.id() as libc::c_long,
+        libc::SYS_io_getevents
+    );
+    assert_eq!(Sysno::io_setup.id() as libc::c_long, libc::SYS_io_setup);
+    assert_eq!(Sysno::io_submit.id() as libc::c_long, libc::SYS_io_submit);
+    assert_eq!(
+        Sysno::io_uring_enter.id() as libc::c_long,
+        libc::SYS_io_uring_enter
+    );
+    assert_eq!(
+        Sysno::io_uring_register.id() as libc::c_long,
+        libc::SYS_io_uring_register
+    );
+    assert_eq!(
+        Sysno::io_uring_setup.id() as libc::c_long,
+        libc::SYS_io_uring_setup
+    );
+    assert_eq!(Sysno::ioctl.id() as libc::c_long, libc::SYS_ioctl);
+    assert_eq!(Sysno::ioperm.id() as libc::c_long, libc::SYS_ioperm);
+    assert_eq!(Sysno::iopl.id() as libc::c_long, libc::SYS_iopl);
+    assert_eq!(Sysno::ioprio_get.id() as libc::c_long, libc::SYS_ioprio_get);
+    assert_eq!(Sysno::ioprio_set.id() as libc::c_long, libc::SYS_ioprio_set);
+    assert_eq!(Sysno::ipc.id() as libc::c_long, libc::SYS_ipc);
+    assert_eq!(Sysno::kcmp.id() as libc::c_long, libc::SYS_kcmp);
+    assert_eq!(
+        Sysno::kexec_file_load.id() as libc::c_long,
+        libc::SYS_kexec_file_load
+    );
+    assert_eq!(Sysno::kexec_load.id() as libc::c_long, libc::SYS_kexec_load);
+    assert_eq!(Sysno::keyctl.id() as libc::c_long, libc::SYS_keyctl);
+    assert_eq!(Sysno::kill.id() as libc::c_long, libc::SYS_kill);
+    assert_eq!(
+        Sysno::landlock_add_rule.id() as libc::c_long,
+        libc::SYS_landlock_add_rule
+    );
+    assert_eq!(
+        Sysno::landlock_create_ruleset.id() as libc::c_long,
+        libc::SYS_landlock_create_ruleset
+    );
+    assert_eq!(
+        Sysno::landlock_restrict_self.id() as libc::c_long,
+        libc::SYS_landlock_restrict_self
+    );
+    assert_eq!(Sysno::lchown.id() as libc::c_long, libc::SYS_lchown);
+    assert_eq!(Sysno::lgetxattr.id() as libc::c_long, libc::SYS_lgetxattr);
+    assert_eq!(Sysno::link.id() as libc::c_long, libc::SYS_link);
+    assert_eq!(Sysno::linkat.id() as libc::c_long, libc::SYS_linkat);
+    assert_eq!(Sysno::listen.id() as libc::c_long, libc::SYS_listen);
+    assert_eq!(Sysno::listxattr.id() as libc::c_long, libc::SYS_listxattr);
+    assert_eq!(Sysno::llistxattr.id() as libc::c_long, libc::SYS_llistxattr);
+    assert_eq!(Sysno::lock.id() as libc::c_long, libc::SYS_lock);
+    assert_eq!(
+        Sysno::lookup_dcookie.id() as libc::c_long,
+        libc::SYS_lookup_dcookie
+    );
+    assert_eq!(
+        Sysno::lremovexattr.id() as libc::c_long,
+        libc::SYS_lremovexattr
+    );
+    assert_eq!(Sysno::lseek.id() as libc::c_long, libc::SYS_lseek);
+    assert_eq!(Sysno::lsetxattr.id() as libc::c_long, libc::SYS_lsetxattr);
+    assert_eq!(Sysno::lstat.id() as libc::c_long, libc::SYS_lstat);
+    assert_eq!(Sysno::lstat64.id() as libc::c_long, libc::SYS_lstat64);
+    assert_eq!(Sysno::madvise.id() as libc::c_long, libc::SYS_madvise);
+    assert_eq!(Sysno::mbind.id() as libc::c_long, libc::SYS_mbind);
+    assert_eq!(Sysno::membarrier.id() as libc::c_long, libc::SYS_membarrier);
+    assert_eq!(
+        Sysno::memfd_create.id() as libc::c_long,
+        libc::SYS_memfd_create
+    );
+    assert_eq!(
+        Sysno::migrate_pages.id() as libc::c_long,
+        libc::SYS_migrate_pages
+    );
+    assert_eq!(Sysno::mincore.id() as libc::c_long, libc::SYS_mincore);
+    assert_eq!(Sysno::mkdir.id() as libc::c_long, libc::SYS_mkdir);
+    assert_eq!(Sysno::mkdirat.id() as libc::c_long, libc::SYS_mkdirat);
+    assert_eq!(Sysno::mknod.id() as libc::c_long, libc::SYS_mknod);
+    assert_eq!(Sysno::mknodat.id() as libc::c_long, libc::SYS_mknodat);
+    assert_eq!(Sysno::mlock.id() as libc::c_long, libc::SYS_mlock);
+    assert_eq!(Sysno::mlock2.id() as libc::c_long, libc::SYS_mlock2);
+    assert_eq!(Sysno::mlockall.id() as libc::c_long, libc::SYS_mlockall);
+    assert_eq!(Sysno::mmap.id() as libc::c_long, libc::SYS_mmap);
+    assert_eq!(Sysno::mmap2.id() as libc::c_long, libc::SYS_mmap2);
+    assert_eq!(Sysno::modify_ldt.id() as libc::c_long, libc::SYS_modify_ldt);
+    assert_eq!(Sysno::mount.id() as libc::c_long, libc::SYS_mount);
+    assert_eq!(
+        Sysno::mount_setattr.id() as libc::c_long,
+        libc::SYS_mount_setattr
+    );
+    assert_eq!(Sysno::move_mount.id() as libc::c_long, libc::SYS_move_mount);
+    assert_eq!(Sysno::move_pages.id() as libc::c_long, libc::SYS_move_pages);
+    assert_eq!(Sysno::mprotect.id() as libc::c_long, libc::SYS_mprotect);
+    assert_eq!(Sysno::mpx.id() as libc::c_long, libc::SYS_mpx);
+    assert_eq!(
+        Sysno::mq_getsetattr.id() as libc::c_long,
+        libc::SYS_mq_getsetattr
+    );
+    assert_eq!(Sysno::mq_notify.id() as libc::c_long, libc::SYS_mq_notify);
+    assert_eq!(Sysno::mq_open.id() as libc::c_long, libc::SYS_mq_open);
+    assert_eq!(
+        Sysno::mq_timedreceive.id() as libc::c_long,
+        libc::SYS_mq_timedreceive
+    );
+    assert_eq!(
+        Sysno::mq_timedsend.id() as libc::c_long,
+        libc::SYS_mq_timedsend
+    );
+    assert_eq!(Sysno::mq_unlink.id() as libc::c_long, libc::SYS_mq_unlink);
+    assert_eq!(Sysno::mremap.id() as libc::c_long, libc::SYS_mremap);
+    assert_eq!(Sysno::mseal.id() as libc::c_long, libc::SYS_mseal);
+    assert_eq!(Sysno::msync.id() as libc::c_long, libc::SYS_msync);
+    assert_eq!(
+        Sysno::multiplexer.id() as libc::c_long,
+        libc::SYS_multiplexer
+    );
+    assert_eq!(Sysno::munlock.id() as libc::c_long, libc::SYS_munlock);
+    assert_eq!(Sysno::munlockall.id() as libc::c_long, libc::SYS_munlockall);
+    assert_eq!(Sysno::munmap.id() as libc::c_long, libc::SYS_munmap);
+    assert_eq!(
+        Sysno::name_to_handle_at.id() as libc::c_long,
+        libc::SYS_name_to_handle_at
+    );
+    assert_eq!(Sysno::nanosleep.id() as libc::c_long, libc::SYS_nanosleep);
+    assert_eq!(Sysno::nfsservctl.id() as libc::c_long, libc::SYS_nfsservctl);
+    assert_eq!(Sysno::nice.id() as libc::c_long, libc::SYS_nice);
+    assert_eq!(Sysno::oldfstat.id() as libc::c_long, libc::SYS_oldfstat);
+    assert_eq!(Sysno::oldlstat.id() as libc::c_long, libc::SYS_oldlstat);
+    assert_eq!(
+        Sysno::oldolduname.id() as libc::c_long,
+        libc::SYS_oldolduname
+    );
+    assert_eq!(Sysno::oldstat.id() as libc::c_long, libc::SYS_oldstat);
+    assert_eq!(Sysno::olduname.id() as libc::c_long, libc::SYS_olduname);
+    assert_eq!(Sysno::open.id() as libc::c_long, libc::SYS_open);
+    assert_eq!(
+        Sysno::open_by_handle_at.id() as libc::c_long,
+        libc::SYS_open_by_handle_at
+    );
+    assert_eq!(Sysno::open_tree.id() as libc::c_long, libc::SYS_open_tree);
+    assert_eq!(Sysno::openat.id() as libc::c_long, libc::SYS_openat);
+    assert_eq!(Sysno::openat2.id() as libc::c_long, libc::SYS_openat2);
+    assert_eq!(Sysno::pause.id() as libc::c_long, libc::SYS_pause);
+    assert_eq!(
+        Sysno::pciconfig_iobase.id() as libc::c_long,
+        libc::SYS_pciconfig_iobase
+    );
+    assert_eq!(
+        Sysno::pciconfig_read.id() as libc::c_long,
+        libc::SYS_pciconfig_read
+    );
+    assert_eq!(
+        Sysno::pciconfig_write.id() as libc::c_long,
+        libc::SYS_pciconfig_write
+    );
+    assert_eq!(
+        Sysno::perf_event_open.id() as libc::c_long,
+        libc::SYS_perf_event_open
+    );
+    assert_eq!(
+        Sysno::personality.id() as libc::c_long,
+        libc::SYS_personality
+    );
+    assert_eq!(
+        Sysno::pidfd_getfd.id() as libc::c_long,
+        libc::SYS_pidfd_getfd
+    );
+    assert_eq!(Sysno::pidfd_open.id() as libc::c_long, libc::SYS_pidfd_open);
+    assert_eq!(
+        Sysno::pidfd_send_signal.id() as libc::c_long,
+        libc::SYS_pidfd_send_signal
+    );
+    assert_eq!(Sysno::pipe.id() as libc::c_long, libc::SYS_pipe);
+    assert_eq!(Sysno::pipe2.id() as libc::c_long, libc::SYS_pipe2);
+    assert_eq!(Sysno::pivot_root.id() as libc::c_long, libc::SYS_pivot_root);
+    assert_eq!(Sysno::poll.id() as libc::c_long, libc::SYS_poll);
+    assert_eq!(Sysno::ppoll.id() as libc::c_long, libc::SYS_ppoll);
+    assert_eq!(Sysno::prctl.id() as libc::c_long, libc::SYS_prctl);
+    assert_eq!(Sysno::pread64.id() as libc::c_long, libc::SYS_pread64);
+    assert_eq!(Sysno::preadv.id() as libc::c_long, libc::SYS_preadv);
+    assert_eq!(Sysno::preadv2.id() as libc::c_long, libc::SYS_preadv2);
+    assert_eq!(Sysno::prlimit64.id() as libc::c_long, libc::SYS_prlimit64);
+    assert_eq!(
+        Sysno::process_madvise.id() as libc::c_long,
+        libc::SYS_process_madvise
+    );
+    assert_eq!(
+        Sysno::process_mrelease.id() as libc::c_long,
+        libc::SYS_process_mrelease
+    );
+    assert_eq!(
+        Sysno::process_vm_readv.id() as libc::c_long,
+        libc::SYS_process_vm_readv
+    );
+    assert_eq!(
+        Sysno::process_vm_writev.id() as libc::c_long,
+        libc::SYS_process_vm_writev
+    );
+    assert_eq!(Sysno::prof.id() as libc::c_long, libc::SYS_prof);
+    assert_eq!(Sysno::profil.id() as libc::c_long, libc::SYS_profil);
+    assert_eq!(Sysno::pselect6.id() as libc::c_long, libc::SYS_pselect6);
+    assert_eq!(Sysno::ptrace.id() as libc::c_long, libc::SYS_ptrace);
+    assert_eq!(Sysno::putpmsg.id() as libc::c_long, libc::SYS_putpmsg);
+    assert_eq!(Sysno::pwrite64.id() as libc::c_long, libc::SYS_pwrite64);
+    assert_eq!(Sysno::pwritev.id() as libc::c_long, libc::SYS_pwritev);
+    assert_eq!(Sysno::pwritev2.id() as libc::c_long, libc::SYS_pwritev2);
+    assert_eq!(Sysno::quotactl.id() as libc::c_long, libc::SYS_quotactl);
+    assert_eq!(
+        Sysno::quotactl_fd.id() as libc::c_long,
+        libc::SYS_quotactl_fd
+    );
+    assert_eq!(Sysno::read.id() as libc::c_long, libc::SYS_read);
+    assert_eq!(Sysno::readahead.id() as libc::c_long, libc::SYS_readahead);
+    assert_eq!(Sysno::readdir.id() as libc::c_long, libc::SYS_readdir);
+    assert_eq!(Sysno::readlink.id() as libc::c_long, libc::SYS_readlink);
+    assert_eq!(Sysno::readlinkat.id() as libc::c_long, libc::SYS_readlinkat);
+    assert_eq!(Sysno::readv.id() as libc::c_long, libc::SYS_readv);
+    assert_eq!(Sysno::reboot.id() as libc::c_long, libc::SYS_reboot);
+    assert_eq!(Sysno::recv.id() as libc::c_long, libc::SYS_recv);
+    assert_eq!(Sysno::recvfrom.id() as libc::c_long, libc::SYS_recvfrom);
+    assert_eq!(Sysno::recvmmsg.id() as libc::c_long, libc::SYS_recvmmsg);
+    assert_eq!(Sysno::recvmsg.id() as libc::c_long, libc::SYS_recvmsg);
+    assert_eq!(
+        Sysno::remap_file_pages.id() as libc::c_long,
+        libc::SYS_remap_file_pages
+    );
+    assert_eq!(
+        Sysno::removexattr.id() as libc::c_long,
+        libc::SYS_removexattr
+    );
+    assert_eq!(Sysno::rename.id() as libc::c_long, libc::SYS_rename);
+    assert_eq!(Sysno::renameat.id() as libc::c_long, libc::SYS_renameat);
+    assert_eq!(Sysno::renameat2.id() as libc::c_long, libc::SYS_renameat2);
+    assert_eq!(
+        Sysno::request_key.id() as libc::c_long,
+        libc::SYS_request_key
+    );
+    assert_eq!(
+        Sysno::restart_syscall.id() as libc::c_long,
+        libc::SYS_restart_syscall
+    );
+    assert_eq!(Sysno::rmdir.id() as libc::c_long, libc::SYS_rmdir);
+    assert_eq!(Sysno::rseq.id() as libc::c_long, libc::SYS_rseq);
+    assert_eq!(
+        Sysno::rt_sigaction.id() as libc::c_long,
+        libc::SYS_rt_sigaction
+    );
+    assert_eq!(
+        Sysno::rt_sigpending.id() as libc::c_long,
+        libc::SYS_rt_sigpending
+    );
+    assert_eq!(
+        Sysno::rt_sigprocmask.id() as libc::c_long,
+        libc::SYS_rt_sigprocmask
+    );
+    assert_eq!(
+        Sysno::rt_sigqueueinfo.id() as libc::c_long,
+        libc::SYS_rt_sigqueueinfo
+    );
+    assert_eq!(
+        Sysno::rt_sigreturn.id() as libc::c_long,
+        libc::SYS_rt_sigreturn
+    );
+    assert_eq!(
+        Sysno::rt_sigsuspend.id() as libc::c_long,
+        libc::SYS_rt_sigsuspend
+    );
+    assert_eq!(
+        Sysno::rt_sigtimedwait.id() as libc::c_long,
+        libc::SYS_rt_sigtimedwait
+    );
+    assert_eq!(
+        Sysno::rt_tgsigqueueinfo.id() as libc::c_long,
+        libc::SYS_rt_tgsigqueueinfo
+    );
+    assert_eq!(Sysno::rtas.id() as libc::c_long, libc::SYS_rtas);
+    assert_eq!(
+        Sysno::sched_get_priority_max.id() as libc::c_long,
+        libc::SYS_sched_get_priority_max
+    );
+    assert_eq!(
+        Sysno::sched_get_priority_min.id() as libc::c_long,
+        libc::SYS_sched_get_priority_min
+    );
+    assert_eq!(
+        Sysno::sched_getaffinity.id() as libc::c_long,
+        libc::SYS_sched_getaffinity
+    );
+    assert_eq!(
+        Sysno::sched_getattr.id() as libc::c_long,
+        libc::SYS_sched_getattr
+    );
+    assert_eq!(
+        Sysno::sched_getparam.id() as libc::c_long,
+        libc::SYS_sched_getparam
+    );
+    assert_eq!(
+        Sysno::sched_getscheduler.id() as libc::c_long,
+        libc::SYS_sched_getscheduler
+    );
+    assert_eq!(
+        Sysno::sched_rr_get_interval.id() as libc::c_long,
+        libc::SYS_sched_rr_get_interval
+    );
+    assert_eq!(
+        Sysno::sched_setaffinity.id() as libc::c_long,
+        libc::SYS_sched_setaffinity
+    );
+    assert_eq!(
+        Sysno::sched_setattr.id() as libc::c_long,
+        libc::SYS_sched_setattr
+    );
+    assert_eq!(
+        Sysno::sched_setparam.id() as libc::c_long,
+        libc::SYS_sched_setparam
+    );
+    assert_eq!(
+        Sysno::sched_setscheduler.id() as libc::c_long,
+        libc::SYS_sched_setscheduler
+    );
+    assert_eq!(
+        Sysno::sched_yield.id() as libc::c_long,
+        libc::SYS_sched_yield
+    );
+    assert_eq!(Sysno::seccomp.id() as libc::c_long, libc::SYS_seccomp);
+    assert_eq!(Sysno::select.id() as libc::c_long, libc::SYS_select);
+    assert_eq!(Sysno::send.id() as libc::c_long, libc::SYS_send);
+    assert_eq!(Sysno::sendfile.id() as libc::c_long, libc::SYS_sendfile);
+    assert_eq!(Sysno::sendfile64.id() as libc::c_long, libc::SYS_sendfile64);
+    assert_eq!(Sysno::sendmmsg.id() as libc::c_long, libc::SYS_sendmmsg);
+    assert_eq!(Sysno::sendmsg.id() as libc::c_long, libc::SYS_sendmsg);
+    assert_eq!(Sysno::sendto.id() as libc::c_long, libc::SYS_sendto);
+    assert_eq!(
+        Sysno::set_mempolicy.id() as libc::c_long,
+        libc::SYS_set_mempolicy
+    );
+    assert_eq!(
+        Sysno::set_mempolicy_home_node.id() as libc::c_long,
+        libc::SYS_set_mempolicy_home_node
+    );
+    assert_eq!(
+        Sysno::set_robust_list.id() as libc::c_long,
+        libc::SYS_set_robust_list
+    );
+    assert_eq!(
+        Sysno::set_tid_address.id() as libc::c_long,
+        libc::SYS_set_tid_address
+    );
+    assert_eq!(
+        Sysno::setdomainname.id() as libc::c_long,
+        libc::SYS_setdomainname
+    );
+    assert_eq!(Sysno::setfsgid.id() as libc::c_long, libc::SYS_setfsgid);
+    assert_eq!(Sysno::setfsuid.id() as libc::c_long, libc::SYS_setfsuid);
+    assert_eq!(Sysno::setgid.id() as libc::c_long, libc::SYS_setgid);
+    assert_eq!(Sysno::setgroups.id() as libc::c_long, libc::SYS_setgroups);
+    assert_eq!(
+        Sysno::sethostname.id() as libc::c_long,
+        libc::SYS_sethostname
+    );
+    assert_eq!(Sysno::setitimer.id() as libc::c_long, libc::SYS_setitimer);
+    assert_eq!(Sysno::setns.id() as libc::c_long, libc::SYS_setns);
+    assert_eq!(Sysno::setpgid.id() as libc::c_long, libc::SYS_setpgid);
+    assert_eq!(
+        Sysno::setpriority.id() as libc::c_long,
+        libc::SYS_setpriority
+    );
+    assert_eq!(Sysno::setregid.id() as libc::c_long, libc::SYS_setregid);
+    assert_eq!(Sysno::setresgid.id() as libc::c_long, libc::SYS_setresgid);
+    assert_eq!(Sysno::setresuid.id() as libc::c_long, libc::SYS_setresuid);
+    assert_eq!(Sysno::setreuid.id() as libc::c_long, libc::SYS_setreuid);
+    assert_eq!(Sysno::setrlimit.id() as libc::c_long, libc::SYS_setrlimit);
+    assert_eq!(Sysno::setsid.id() as libc::c_long, libc::SYS_setsid);
+    assert_eq!(Sysno::setsockopt.id() as libc::c_long, libc::SYS_setsockopt);
+    assert_eq!(
+        Sysno::settimeofday.id() as libc::c_long,
+        libc::SYS_settimeofday
+    );
+    assert_eq!(Sysno::setuid.id() as libc::c_long, libc::SYS_setuid);
+    assert_eq!(Sysno::setxattr.id() as libc::c_long, libc::SYS_setxattr);
+    assert_eq!(Sysno::sgetmask.id() as libc::c_long, libc::SYS_sgetmask);
+    assert_eq!(Sysno::shutdown.id() as libc::c_long, libc::SYS_shutdown);
+    assert_eq!(Sysno::sigaction.id() as libc::c_long, libc::SYS_sigaction);
+    assert_eq!(
+        Sysno::sigaltstack.id() as libc::c_long,
+        libc::SYS_sigaltstack
+    );
+    assert_eq!(Sysno::signal.id() as libc::c_long, libc::SYS_signal);
+    assert_eq!(Sysno::signalfd.id() as libc::c_long, libc::SYS_signalfd);
+    assert_eq!(Sysno::signalfd4.id() as libc::c_long, libc::SYS_signalfd4);
+    assert_eq!(Sysno::sigpending.id() as libc::c_long, libc::SYS_sigpending);
+    assert_eq!(
+        Sysno::sigprocmask.id() as libc::c_long,
+        libc::SYS_sigprocmask
+    );
+    assert_eq!(Sysno::sigreturn.id() as libc::c_long, libc::SYS_sigreturn);
+    assert_eq!(Sysno::sigsuspend.id() as libc::c_long, libc::SYS_sigsuspend);
+    assert_eq!(Sysno::socket.id() as libc::c_long, libc::SYS_socket);
+    assert_eq!(Sysno::socketcall.id() as libc::c_long, libc::SYS_socketcall);
+    assert_eq!(Sysno::socketpair.id() as libc::c_long, libc::SYS_socketpair);
+    assert_eq!(Sysno::splice.id() as libc::c_long, libc::SYS_splice);
+    assert_eq!(Sysno::spu_create.id() as libc::c_long, libc::SYS_spu_create);
+    assert_eq!(Sysno::spu_run.id() as libc::c_long, libc::SYS_spu_run);
+    assert_eq!(Sysno::ssetmask.id() as libc::c_long, libc::SYS_ssetmask);
+    assert_eq!(Sysno::stat.id() as libc::c_long, libc::SYS_stat);
+    assert_eq!(Sysno::stat64.id() as libc::c_long, libc::SYS_stat64);
+    assert_eq!(Sysno::statfs.id() as libc::c_long, libc::SYS_statfs);
+    assert_eq!(Sysno::statfs64.id() as libc::c_long, libc::SYS_statfs64);
+    assert_eq!(Sysno::statx.id() as libc::c_long, libc::SYS_statx);
+    assert_eq!(Sysno::stime.id() as libc::c_long, libc::SYS_stime);
+    assert_eq!(Sysno::stty.id() as libc::c_long, libc::SYS_stty);
+    assert_eq!(
+        Sysno::subpage_prot.id() as libc::c_long,
+        libc::SYS_subpage_prot
+    );
+    assert_eq!(
+        Sysno::swapcontext.id() as libc::c_long,
+        libc::SYS_swapcontext
+    );
+    assert_eq!(Sysno::swapoff.id() as libc::c_long, libc::SYS_swapoff);
+    assert_eq!(Sysno::swapon.id() as libc::c_long, libc::SYS_swapon);
+    assert_eq!(
+        Sysno::switch_endian.id() as libc::c_long,
+        libc::SYS_switch_endian
+    );
+    assert_eq!(Sysno::symlink.id() as libc::c_long, libc::SYS_symlink);
+    assert_eq!(Sysno::symlinkat.id() as libc::c_long, libc::SYS_symlinkat);
+    assert_eq!(Sysno::sync.id() as libc::c_long, libc::SYS_sync);
+    assert_eq!(
+        Sysno::sync_file_range2.id() as libc::c_long,
+        libc::SYS_sync_file_range2
+    );
+    assert_eq!(Sysno::syncfs.id() as libc::c_long, libc::SYS_syncfs);
+    assert_eq!(
+        Sysno::sys_debug_setcontext.id() as libc::c_long,
+        libc::SYS_sys_debug_setcontext
+    );
+    assert_eq!(Sysno::sysfs.id() as libc::c_long, libc::SYS_sysfs);
+    assert_eq!(Sysno::sysinfo.id() as libc::c_long, libc::SYS_sysinfo);
+    assert_eq!(Sysno::syslog.id() as libc::c_long, libc::SYS_syslog);
+    assert_eq!(Sysno::tee.id() as libc::c_long, libc::SYS_tee);
+    assert_eq!(Sysno::tgkill.id() as libc::c_long, libc::SYS_tgkill);
+    assert_eq!(Sysno::time.id() as libc::c_long, libc::SYS_time);
+    assert_eq!(
+        Sysno::timer_create.id() as libc::c_long,
+        libc::SYS_timer_create
+    );
+    assert_eq!(
+        Sysno::timer_delete.id() as libc::c_long,
+        libc::SYS_timer_delete
+    );
+    assert_eq!(
+        Sysno::timer_getoverrun.id() as libc::c_long,
+        libc::SYS_timer_getoverrun
+    );
+    assert_eq!(
+        Sysno::timer_gettime.id() as libc::c_long,
+        libc::SYS_timer_gettime
+    );
+    assert_eq!(
+        Sysno::timer_settime.id() as libc::c_long,
+        libc::SYS_timer_settime
+    );
+    assert_eq!(
+        Sysno::timerfd_create.id() as libc::c_long,
+        libc::SYS_timerfd_create
+    );
+    assert_eq!(
+        Sysno::timerfd_gettime.id() as libc::c_long,
+        libc::SYS_timerfd_gettime
+    );
+    assert_eq!(
+        Sysno::timerfd_settime.id() as libc::c_long,
+        libc::SYS_timerfd_settime
+    );
+    assert_eq!(Sysno::times.id() as libc::c_long, libc::SYS_times);
+    assert_eq!(Sysno::tkill.id() as libc::c_long, libc::SYS_tkill);
+    assert_eq!(Sysno::truncate.id() as libc::c_long, libc::SYS_truncate);
+    assert_eq!(Sysno::truncate64.id() as libc::c_long, libc::SYS_truncate64);
+    assert_eq!(Sysno::tuxcall.id() as libc::c_long, libc::SYS_tuxcall);
+    assert_eq!(Sysno::ugetrlimit.id() as libc::c_long, libc::SYS_ugetrlimit);
+    assert_eq!(Sysno::ulimit.id() as libc::c_long, libc::SYS_ulimit);
+    assert_eq!(Sysno::umask.id() as libc::c_long, libc::SYS_umask);
+    assert_eq!(Sysno::umount.id() as libc::c_long, libc::SYS_umount);
+    assert_eq!(Sysno::umount2.id() as libc::c_long, libc::SYS_umount2);
+    assert_eq!(Sysno::uname.id() as libc::c_long, libc::SYS_uname);
+    assert_eq!(Sysno::unlink.id() as libc::c_long, libc::SYS_unlink);
+    assert_eq!(Sysno::unlinkat.id() as libc::c_long, libc::SYS_unlinkat);
+    assert_eq!(Sysno::unshare.id() as libc::c_long, libc::SYS_unshare);
+    assert_eq!(Sysno::uselib.id() as libc::c_long, libc::SYS_uselib);
+    assert_eq!(
+        Sysno::userfaultfd.id() as libc::c_long,
+        libc::SYS_userfaultfd
+    );
+    assert_eq!(Sysno::ustat.id() as libc::c_long, libc::SYS_ustat);
+    assert_eq!(Sysno::utime.id() as libc::c_long, libc::SYS_utime);
+    assert_eq!(Sysno::utimensat.id() as libc::c_long, libc::SYS_utimensat);
+    assert_eq!(Sysno::utimes.id() as libc::c_long, libc::SYS_utimes);
+    assert_eq!(Sysno::vfork.id() as libc::c_long, libc::SYS_vfork);
+    assert_eq!(Sysno::vhangup.id() as libc::c_long, libc::SYS_vhangup);
+    assert_eq!(Sysno::vm86.id() as libc::c_long, libc::SYS_vm86);
+    assert_eq!(Sysno::vmsplice.id() as libc::c_long, libc::SYS_vmsplice);
+    assert_eq!(Sysno::wait4.id() as libc::c_long, libc::SYS_wait4);
+    assert_eq!(Sysno::waitid.id() as libc::c_long, libc::SYS_waitid);
+    assert_eq!(Sysno::waitpid.id() as libc::c_long, libc::SYS_waitpid);
+    assert_eq!(Sysno::write.id() as libc::c_long, libc::SYS_write);
+    assert_eq!(Sysno::writev.id() as libc::c_long, libc::SYS_writev);
+}
+
+#[cfg(all(target_arch = "powerpc64", target_pointer_width = "64"))]
+#[test]
+fn powerpc64() {
+    use rawsys_linux::powerpc64::v6_12::Sysno;
+
+    assert_eq!(Sysno::_llseek.id() as libc::c_long, libc::SYS__llseek);
+    assert_eq!(Sysno::_newselect.id() as libc::c_long, libc::SYS__newselect);
+    assert_eq!(Sysno::_sysctl.id() as libc::c_long, libc::SYS__sysctl);
+    assert_eq!(Sysno::accept.id() as libc::c_long, libc::SYS_accept);
+    assert_eq!(Sysno::accept4.id() as libc::c_long, libc::SYS_accept4);
+    assert_eq!(Sysno::access.id() as libc::c_long, libc::SYS_access);
+    assert_eq!(Sysno::acct.id() as libc::c_long, libc::SYS_acct);
+    assert_eq!(Sysno::add_key.id() as libc::c_long, libc::SYS_add_key);
+    assert_eq!(Sysno::adjtimex.id() as libc::c_long, libc::SYS_adjtimex);
+    assert_eq!(
+        Sysno::afs_syscall.id() as libc::c_long,
+        libc::SYS_afs_syscall
+    );
+    assert_eq!(Sysno::alarm.id() as libc::c_long, libc::SYS_alarm);
+    assert_eq!(Sysno::bdflush.id() as libc::c_long, libc::SYS_bdflush);
+    assert_eq!(Sysno::bind.id() as libc::c_long, libc::SYS_bind);
+    assert_eq!(Sysno::bpf.id() as libc::c_long, libc::SYS_bpf);
+    assert_eq!(Sysno::break_.id() as libc::c_long, libc::SYS_break);
+    assert_eq!(Sysno::brk.id() as libc::c_long, libc::SYS_brk);
+    assert_eq!(Sysno::capget.id() as libc::c_long, libc::SYS_capget);
+    assert_eq!(Sysno::capset.id() as libc::c_long, libc::SYS_capset);
+    assert_eq!(Sysno::chdir.id() as libc::c_long, libc::SYS_chdir);
+    assert_eq!(Sysno::chmod.id() as libc::c_long, libc::SYS_chmod);
+    assert_eq!(Sysno::chown.id() as libc::c_long, libc::SYS_chown);
+    assert_eq!(Sysno::chroot.id() as libc::c_long, libc::SYS_chroot);
+    assert_eq!(
+        Sysno::clock_adjtime.id() as libc::c_long,
+        libc::SYS_clock_adjtime
+    );
+    assert_eq!(
+        Sysno::clock_getres.id() as libc::c_long,
+        libc::SYS_clock_getres
+    );
+    assert_eq!(
+        Sysno::clock_gettime.id() as libc::c_long,
+        libc::SYS_clock_gettime
+    );
+    assert_eq!(
+        Sysno::clock_nanosleep.id() as libc::c_long,
+        libc::SYS_clock_nanosleep
+    );
+    assert_eq!(
+        Sysno::clock_settime.id() as libc::c_long,
+        libc::SYS_clock_settime
+    );
+    assert_eq!(Sysno::clone.id() as libc::c_long, libc::SYS_clone);
+    assert_eq!(Sysno::clone3.id() as libc::c_long, libc::SYS_clone3);
+    assert_eq!(Sysno::close.id() as libc::c_long, libc::SYS_close);
+    assert_eq!(
+        Sysno::close_range.id() as libc::c_long,
+        libc::SYS_close_range
+    );
+    assert_eq!(Sysno::connect.id() as libc::c_long, libc::SYS_connect);
+    assert_eq!(
+        Sysno::copy_file_range.id() as libc::c_long,
+        libc::SYS_copy_file_range
+    );
+    assert_eq!(Sysno::creat.id() as libc::c_long, libc::SYS_creat);
+    assert_eq!(
+        Sysno::delete_module.id() as libc::c_long,
+        libc::SYS_delete_module
+    );
+    assert_eq!(Sysno::dup.id() as libc::c_long, libc::SYS_dup);
+    assert_eq!(Sysno::dup2.id() as libc::c_long, libc::SYS_dup2);
+    assert_eq!(Sysno::dup3.id() as libc::c_long, libc::SYS_dup3);
+    assert_eq!(
+        Sysno::epoll_create.id() as libc::c_long,
+        libc::SYS_epoll_create
+    );
+    assert_eq!(
+        Sysno::epoll_create1.id() as libc::c_long,
+        libc::SYS_epoll_create1
+    );
+    assert_eq!(Sysno::epoll_ctl.id() as libc::c_long, libc::SYS_epoll_ctl);
+    assert_eq!(
+        Sysno::epoll_pwait.id() as libc::c_long,
+        libc::SYS_epoll_pwait
+    );
+    assert_eq!(
+        Sysno::epoll_pwait2.id() as libc::c_long,
+        libc::SYS_epoll_pwait2
+    );
+    assert_eq!(Sysno::epoll_wait.id() as libc::c_long, libc::SYS_epoll_wait);
+    assert_eq!(Sysno::eventfd.id() as libc::c_long, libc::SYS_eventfd);
+    assert_eq!(Sysno::eventfd2.id() as libc::c_long, libc::SYS_eventfd2);
+    assert_eq!(Sysno::execve.id() as libc::c_long, libc::SYS_execve);
+    assert_eq!(Sysno::execveat.id() as libc::c_long, libc::SYS_execveat);
+    assert_eq!(Sysno::exit.id() as libc::c_long, libc::SYS_exit);
+    assert_eq!(Sysno::exit_group.id() as libc::c_long, libc::SYS_exit_group);
+    assert_eq!(Sysno::faccessat.id() as libc::c_long, libc::SYS_faccessat);
+    assert_eq!(Sysno::faccessat2.id() as libc::c_long, libc::SYS_faccessat2);
+    assert_eq!(Sysno::fallocate.id() as libc::c_long, libc::SYS_fallocate);
+    assert_eq!(
+        Sysno::fanotify_init.id() as libc::c_long,
+        libc::SYS_fanotify_init
+    );
+    assert_eq!(
+        Sysno::fanotify_mark.id() as libc::c_long,
+        libc::SYS_fanotify_mark
+    );
+    assert_eq!(Sysno::fchdir.id() as libc::c_long, libc::SYS_fchdir);
+    assert_eq!(Sysno::fchmod.id() as libc::c_long, libc::SYS_fchmod);
+    assert_eq!(Sysno::fchmodat.id() as libc::c_long, libc::SYS_fchmodat);
+    assert_eq!(Sysno::fchown.id() as libc::c_long, libc::SYS_fchown);
+    assert_eq!(Sysno::fchownat.id() as libc::c_long, libc::SYS_fchownat);
+    assert_eq!(Sysno::fcntl.id() as libc::c_long, libc::SYS_fcntl);
+    assert_eq!(Sysno::fdatasync.id() as libc::c_long, libc::SYS_fdatasync);
+    assert_eq!(Sysno::fgetxattr.id() as libc::c_long, libc::SYS_fgetxattr);
+    assert_eq!(
+        Sysno::finit_module.id() as libc::c_long,
+        libc::SYS_finit_module
+    );
+    assert_eq!(Sysno::flistxattr.id() as libc::c_long, libc::SYS_flistxattr);
+    assert_eq!(Sysno::flock.id() as libc::c_long, libc::SYS_flock);
+    assert_eq!(Sysno::fork.id() as libc::c_long, libc::SYS_fork);
+    assert_eq!(
+        Sysno::fremovexattr.id() as libc::c_long,
+        libc::SYS_fremovexattr
+    );
+    assert_eq!(Sysno::fsconfig.id() as libc::c_long, libc::SYS_fsconfig);
+    assert_eq!(Sysno::fsetxattr.id() as libc::c_long, libc::SYS_fsetxattr);
+    assert_eq!(Sysno::fsmount.id() as libc::c_long, libc::SYS_fsmount);
+    assert_eq!(Sysno::fsopen.id() as libc::c_long, libc::SYS_fsopen);
+    assert_eq!(Sysno::fspick.id() as libc::c_long, libc::SYS_fspick);
+    assert_eq!(Sysno::fstat.id() as libc::c_long, libc::SYS_fstat);
+    assert_eq!(Sysno::fstatfs.id() as libc::c_long, libc::SYS_fstatfs);
+    assert_eq!(Sysno::fstatfs64.id() as libc::c_long, libc::SYS_fstatfs64);
+    assert_eq!(Sysno::fsync.id() as libc::c_long, libc::SYS_fsync);
+    assert_eq!(Sysno::ftime.id() as libc::c_long, libc::SYS_ftime);
+    assert_eq!(Sysno::ftruncate.id() as libc::c_long, libc::SYS_ftruncate);
+    assert_eq!(Sysno::futex.id() as libc::c_long, libc::SYS_futex);
+    assert_eq!(
+        Sysno::futex_waitv.id() as libc::c_long,
+        libc::SYS_futex_waitv
+    );
+    assert_eq!(Sysno::futimesat.id() as libc::c_long, libc::SYS_futimesat);
+    assert_eq!(
+        Sysno::get_mempolicy.id() as libc::c_long,
+        libc::SYS_get_mempolicy
+    );
+    assert_eq!(
+        Sysno::get_robust_list.id() as libc::c_long,
+        libc::SYS_get_robust_list
+    );
+    assert_eq!(Sysno::getcpu.id() as libc::c_long, libc::SYS_getcpu);
+    assert_eq!(Sysno::getcwd.id() as libc::c_long, libc::SYS_getcwd);
+    assert_eq!(Sysno::getdents.id() as libc::c_long, libc::SYS_getdents);
+    assert_eq!(Sysno::getdents64.id() as libc::c_long, libc::SYS_getdents64);
+    assert_eq!(Sysno::getegid.id() as libc::c_long, libc::SYS_getegid);
+    assert_eq!(Sysno::geteuid.id() as libc::c_long, libc::SYS_geteuid);
+    assert_eq!(Sysno::getgid.id() as libc::c_long, libc::SYS_getgid);
+    assert_eq!(Sysno::getgroups.id() as libc::c_long, libc::SYS_getgroups);
+    assert_eq!(Sysno::getitimer.id() as libc::c_long, libc::SYS_getitimer);
+    assert_eq!(
+        Sysno::getpeername.id() as libc::c_long,
+        libc::SYS_getpeername
+    );
+    assert_eq!(Sysno::getpgid.id() as libc::c_long, libc::SYS_getpgid);
+    assert_eq!(Sysno::getpgrp.id() as libc::c_long, libc::SYS_getpgrp);
+    assert_eq!(Sysno::getpid.id() as libc::c_long, libc::SYS_getpid);
+    assert_eq!(Sysno::getpmsg.id() as libc::c_long, libc::SYS_getpmsg);
+    assert_eq!(Sysno::getppid.id() as libc::c_long, libc::SYS_getppid);
+    assert_eq!(
+        Sysno::getpriority.id() as libc::c_long,
+        libc::SYS_getpriority
+    );
+    assert_eq!(Sysno::getrandom.id() as libc::c_long, libc::SYS_getrandom);
+    assert_eq!(Sysno::getresgid.id() as libc::c_long, libc::SYS_getresgid);
+    assert_eq!(Sysno::getresuid.id() as libc::c_long, libc::SYS_getresuid);
+    assert_eq!(Sysno::getrlimit.id() as libc::c_long, libc::SYS_getrlimit);
+    assert_eq!(Sysno::getrusage.id() as libc::c_long, libc::SYS_getrusage);
+    assert_eq!(Sysno::getsid.id() as libc::c_long, libc::SYS_getsid);
+    assert_eq!(
+        Sysno::getsockname.id() as libc::c_long,
+        libc::SYS_getsockname
+    );
+    assert_eq!(Sysno::getsockopt.id() as libc::c_long, libc::SYS_getsockopt);
+    assert_eq!(Sysno::gettid.id() as libc::c_long, libc::SYS_gettid);
+    assert_eq!(
+        Sysno::gettimeofday.id() as libc::c_long,
+        libc::SYS_gettimeofday
+    );
+    assert_eq!(Sysno::getuid.id() as libc::c_long, libc::SYS_getuid);
+    assert_eq!(Sysno::getxattr.id() as libc::c_long, libc::SYS_getxattr);
+    assert_eq!(Sysno::gtty.id() as libc::c_long, libc::SYS_gtty);
+    assert_eq!(Sysno::idle.id() as libc::c_long, libc::SYS_idle);
+    assert_eq!(
+        Sysno::init_module.id() as libc::c_long,
+        libc::SYS_init_module
+    );
+    assert_eq!(
+        Sysno::inotify_add_watch.id() as libc::c_long,
+        libc::SYS_inotify_add_watch
+    );
+    assert_eq!(
+        Sysno::inotify_init.id() as libc::c_long,
+        libc::SYS_inotify_init
+    );
+    assert_eq!(
+        Sysno::inotify_init1.id() as libc::c_long,
+        libc::SYS_inotify_init1
+    );
+    assert_eq!(
+        Sysno::inotify_rm_watch.id() as libc::c_long,
+        libc::SYS_inotify_rm_watch
+    );
+    assert_eq!(Sysno::io_cancel.id() as libc::c_long, libc::SYS_io_cancel);
+    assert_eq!(Sysno::io_destroy.id() as libc::c_long, libc::SYS_io_destroy);
+    assert_eq!(
+        Sysno::io_getevents.id() as libc::c_long,
+        libc::SYS_io_getevents
+    );
+    assert_eq!(Sysno::io_setup.id() as libc::c_long, libc::SYS_io_setup);
+    assert_eq!(Sysno::io_submit.id() as libc::c_long, libc::SYS_io_submit);
+    assert_eq!(
+        Sysno::io_uring_enter.id() as libc::c_long,
+        libc::SYS_io_uring_enter
+    );
+    assert_eq!(
+        Sysno::io_uring_register.id() as libc::c_long,
+        libc::SYS_io_uring_register
+    );
+    assert_eq!(
+        Sysno::io_uring_setup.id() as libc::c_long,
+        libc::SYS_io_uring_setup
+    );
+    assert_eq!(Sysno::ioctl.id() as libc::c_long, libc::SYS_ioctl);
+    assert_eq!(Sysno::ioperm.id() as libc::c_long, libc::SYS_ioperm);
+    assert_eq!(Sysno::iopl.id() as libc::c_long, libc::SYS_iopl);
+    assert_eq!(Sysno::ioprio_get.id() as libc::c_long, libc::SYS_ioprio_get);
+    assert_eq!(Sysno::ioprio_set.id() as libc::c_long, libc::SYS_ioprio_set);
+    assert_eq!(Sysno::ipc.id() as libc::c_long, libc::SYS_ipc);
+    assert_eq!(Sysno::kcmp.id() as libc::c_long, libc::SYS_kcmp);
+    assert_eq!(
+        Sysno::kexec_file_load.id() as libc::c_long,
+        libc::SYS_kexec_file_load
+    );
+    assert_eq!(Sysno::kexec_load.id() as libc::c_long, libc::SYS_kexec_load);
+    assert_eq!(Sysno::keyctl.id() as libc::c_long, libc::SYS_keyctl);
+    assert_eq!(Sysno::kill.id() as libc::c_long, libc::SYS_kill);
+    assert_eq!(
+        Sysno::landlock_add_rule.id() as libc::c_long,
+        libc::SYS_landlock_add_rule
+    );
+    assert_eq!(
+        Sysno::landlock_create_ruleset.id() as libc::c_long,
+        libc::SYS_landlock_create_ruleset
+    );
+    assert_eq!(
+        Sysno::landlock_restrict_self.id() as libc::c_long,
+        libc::SYS_landlock_restrict_self
+    );
+    assert_eq!(Sysno::lchown.id() as libc::c_long, libc::SYS_lchown);
+    assert_eq!(Sysno::lgetxattr.id() as libc::c_long, libc::SYS_lgetxattr);
+    assert_eq!(Sysno::link.id() as libc::c_long, libc::SYS_link);
+    assert_eq!(Sysno::linkat.id() as libc::c_long, libc::SYS_linkat);
+    assert_eq!(Sysno::listen.id() as libc::c_long, libc::SYS_listen);
+    assert_eq!(Sysno::listxattr.id() as libc::c_long, libc::SYS_listxattr);
+    assert_eq!(Sysno::llistxattr.id() as libc::c_long, libc::SYS_llistxattr);
+    assert_eq!(Sysno::lock.id() as libc::c_long, libc::SYS_lock);
+    assert_eq!(
+        Sysno::lookup_dcookie.id() as libc::c_long,
+        libc::SYS_lookup_dcookie
+    );
+    assert_eq!(
+        Sysno::lremovexattr.id() as libc::c_long,
+        libc::SYS_lremovexattr
+    );
+    assert_eq!(Sysno::lseek.id() as libc::c_long, libc::SYS_lseek);
+    assert_eq!(Sysno::lsetxattr.id() as libc::c_long, libc::SYS_lsetxattr);
+    assert_eq!(Sysno::lstat.id() as libc::c_long, libc::SYS_lstat);
+    assert_eq!(Sysno::madvise.id() as libc::c_long, libc::SYS_madvise);
+    assert_eq!(Sysno::mbind.id() as libc::c_long, libc::SYS_mbind);
+    assert_eq!(Sysno::membarrier.id() as libc::c_long, libc::SYS_membarrier);
+    assert_eq!(
+        Sysno::memfd_create.id() as libc::c_long,
+        libc::SYS_memfd_create
+    );
+    assert_eq!(
+        Sysno::migrate_pages.id() as libc::c_long,
+        libc::SYS_migrate_pages
+    );
+    assert_eq!(Sysno::mincore.id() as libc::c_long, libc::SYS_mincore);
+    assert_eq!(Sysno::mkdir.id() as libc::c_long, libc::SYS_mkdir);
+    assert_eq!(Sysno::mkdirat.id() as libc::c_long, libc::SYS_mkdirat);
+    assert_eq!(Sysno::mknod.id() as libc::c_long, libc::SYS_mknod);
+    assert_eq!(Sysno::mknodat.id() as libc::c_long, libc::SYS_mknodat);
+    assert_eq!(Sysno::mlock.id() as libc::c_long, libc::SYS_mlock);
+    assert_eq!(Sysno::mlock2.id() as libc::c_long, libc::SYS_mlock2);
+    assert_eq!(Sysno::mlockall.id() as libc::c_long, libc::SYS_mlockall);
+    assert_eq!(Sysno::mmap.id() as libc::c_long, libc::SYS_mmap);
+    assert_eq!(Sysno::modify_ldt.id() as libc::c_long, libc::SYS_modify_ldt);
+    assert_eq!(Sysno::mount.id() as libc::c_long, libc::SYS_mount);
+    assert_eq!(
+        Sysno::mount_setattr.id() as libc::c_long,
+        libc::SYS_mount_setattr
+    );
+    assert_eq!(Sysno::move_mount.id() as libc::c_long, libc::SYS_move_mount);
+    assert_eq!(Sysno::move_pages.id() as libc::c_long, libc::SYS_move_pages);
+    assert_eq!(Sysno::mprotect.id() as libc::c_long, libc::SYS_mprotect);
+    assert_eq!(Sysno::mpx.id() as libc::c_long, libc::SYS_mpx);
+    assert_eq!(
+        Sysno::mq_getsetattr.id() as libc::c_long,
+        libc::SYS_mq_getsetattr
+    );
+    assert_eq!(Sysno::mq_notify.id() as libc::c_long, libc::SYS_mq_notify);
+    assert_eq!(Sysno::mq_open.id() as libc::c_long, libc::SYS_mq_open);
+    assert_eq!(
+        Sysno::mq_timedreceive.id() as libc::c_long,
+        libc::SYS_mq_timedreceive
+    );
+    assert_eq!(
+        Sysno::mq_timedsend.id() as libc::c_long,
+        libc::SYS_mq_timedsend
+    );
+    assert_eq!(Sysno::mq_unlink.id() as libc::c_long, libc::SYS_mq_unlink);
+    assert_eq!(Sysno::mremap.id() as libc::c_long, libc::SYS_mremap);
+    assert_eq!(Sysno::msync.id() as libc::c_long, libc::SYS_msync);
+    assert_eq!(
+        Sysno::multiplexer.id() as libc::c_long,
+        libc::SYS_multiplexer
+    );
+    assert_eq!(Sysno::munlock.id() as libc::c_long, libc::SYS_munlock);
+    assert_eq!(Sysno::munlockall.id() as libc::c_long, libc::SYS_munlockall);
+    assert_eq!(Sysno::munmap.id() as libc::c_long, libc::SYS_munmap);
+    assert_eq!(
+        Sysno::name_to_handle_at.id() as libc::c_long,
+        libc::SYS_name_to_handle_at
+    );
+    assert_eq!(Sysno::nanosleep.id() as libc::c_long, libc::SYS_nanosleep);
+    assert_eq!(Sysno::newfstatat.id() as libc::c_long, libc::SYS_newfstatat);
+    assert_eq!(Sysno::nfsservctl.id() as libc::c_long, libc::SYS_nfsservctl);
+    assert_eq!(Sysno::nice.id() as libc::c_long, libc::SYS_nice);
+    assert_eq!(Sysno::oldfstat.id() as libc::c_long, libc::SYS_oldfstat);
+    assert_eq!(Sysno::oldlstat.id() as libc::c_long, libc::SYS_oldlstat);
+    assert_eq!(
+        Sysno::oldolduname.id() as libc::c_long,
+        libc::SYS_oldolduname
+    );
+    assert_eq!(Sysno::oldstat.id() as libc::c_long, libc::SYS_oldstat);
+    assert_eq!(Sysno::olduname.id() as libc::c_long, libc::SYS_olduname);
+    assert_eq!(Sysno::open.id() as libc::c_long, libc::SYS_open);
+    assert_eq!(
+        Sysno::open_by_handle_at.id() as libc::c_long,
+        libc::SYS_open_by_handle_at
+    );
+    assert_eq!(Sysno::open_tree.id() as libc::c_long, libc::SYS_open_tree);
+    assert_eq!(Sysno::openat.id() as libc::c_long, libc::SYS_openat);
+    assert_eq!(Sysno::openat2.id() as libc::c_long, libc::SYS_openat2);
+    assert_eq!(Sysno::pause.id() as libc::c_long, libc::SYS_pause);
+    assert_eq!(
+        Sysno::pciconfig_iobase.id() as libc::c_long,
+        libc::SYS_pciconfig_iobase
+    );
+    assert_eq!(
+        Sysno::pciconfig_read.id() as libc::c_long,
+        libc::SYS_pciconfig_read
+    );
+    assert_eq!(
+        Sysno::pciconfig_write.id() as libc::c_long,
+        libc::SYS_pciconfig_write
+    );
+    assert_eq!(
+        Sysno::perf_event_open.id() as libc::c_long,
+        libc::SYS_perf_event_open
+    );
+    assert_eq!(
+        Sysno::personality.id() as libc::c_long,
+        libc::SYS_personality
+    );
+    assert_eq!(
+        Sysno::pidfd_getfd.id() as libc::c_long,
+        libc::SYS_pidfd_getfd
+    );
+    assert_eq!(Sysno::pidfd_open.id() as libc::c_long, libc::SYS_pidfd_open);
+    assert_eq!(
+        Sysno::pidfd_send_signal.id() as libc::c_long,
+        libc::SYS_pidfd_send_signal
+    );
+    assert_eq!(Sysno::pipe.id() as libc::c_long, libc::SYS_pipe);
+    assert_eq!(Sysno::pipe2.id() as libc::c_long, libc::SYS_pipe2);
+    assert_eq!(Sysno::pivot_root.id() as libc::c_long, libc::SYS_pivot_root);
+    assert_eq!(Sysno::poll.id() as libc::c_long, libc::SYS_poll);
+    assert_eq!(Sysno::ppoll.id() as libc::c_long, libc::SYS_ppoll);
+    assert_eq!(Sysno::prctl.id() as libc::c_long, libc::SYS_prctl);
+    assert_eq!(Sysno::pread64.id() as libc::c_long, libc::SYS_pread64);
+    assert_eq!(Sysno::preadv.id() as libc::c_long, libc::SYS_preadv);
+    assert_eq!(Sysno::preadv2.id() as libc::c_long, libc::SYS_preadv2);
+    assert_eq!(Sysno::prlimit64.id() as libc::c_long, libc::SYS_prlimit64);
+    assert_eq!(
+        Sysno::process_madvise.id() as libc::c_long,
+        libc::SYS_process_madvise
+    );
+    assert_eq!(
+        Sysno::process_mrelease.id() as libc::c_long,
+        libc::SYS_process_mrelease
+    );
+    assert_eq!(
+        Sysno::process_vm_readv.id() as libc::c_long,
+        libc::SYS_process_vm_readv
+    );
+    assert_eq!(
+        Sysno::process_vm_writev.id() as libc::c_long,
+        libc::SYS_process_vm_writev
+    );
+    assert_eq!(Sysno::prof.id() as libc::c_long, libc::SYS_prof);
+    assert_eq!(Sysno::profil.id() as libc::c_long, libc::SYS_profil);
+    assert_eq!(Sysno::pselect6.id() as libc::c_long, libc::SYS_pselect6);
+    assert_eq!(Sysno::ptrace.id() as libc::c_long, libc::SYS_ptrace);
+    assert_eq!(Sysno::putpmsg.id() as libc::c_long, libc::SYS_putpmsg);
+    assert_eq!(Sysno::pwrite64.id() as libc::c_long, libc::SYS_pwrite64);
+    assert_eq!(Sysno::pwritev.id() as libc::c_long, libc::SYS_pwritev);
+    assert_eq!(Sysno::pwritev2.id() as libc::c_long, libc::SYS_pwritev2);
+    assert_eq!(Sysno::quotactl.id() as libc::c_long, libc::SYS_quotactl);
+    assert_eq!(
+        Sysno::quotactl_fd.id() as libc::c_long,
+        libc::SYS_quotactl_fd
+    );
+    assert_eq!(Sysno::read.id() as libc::c_long, libc::SYS_read);
+    assert_eq!(Sysno::readahead.id() as libc::c_long, libc::SYS_readahead);
+    assert_eq!(Sysno::readdir.id() as libc::c_long, libc::SYS_readdir);
+    assert_eq!(Sysno::readlink.id() as libc::c_long, libc::SYS_readlink);
+    assert_eq!(Sysno::readlinkat.id() as libc::c_long, libc::SYS_readlinkat);
+    assert_eq!(Sysno::readv.id() as libc::c_long, libc::SYS_readv);
+    assert_eq!(Sysno::reboot.id() as libc::c_long, libc::SYS_reboot);
+    assert_eq!(Sysno::recv.id() as libc::c_long, libc::SYS_recv);
+    assert_eq!(Sysno::recvfrom.id() as libc::c_long, libc::SYS_recvfrom);
+    assert_eq!(Sysno::recvmmsg.id() as libc::c_long, libc::SYS_recvmmsg);
+    assert_eq!(Sysno::recvmsg.id() as libc::c_long, libc::SYS_recvmsg);
+    assert_eq!(
+        Sysno::remap_file_pages.id() as libc::c_long,
+        libc::SYS_remap_file_pages
+    );
+    assert_eq!(
+        Sysno::removexattr.id() as libc::c_long,
+        libc::SYS_removexattr
+    );
+    assert_eq!(Sysno::rename.id() as libc::c_long, libc::SYS_rename);
+    assert_eq!(Sysno::renameat.id() as libc::c_long, libc::SYS_renameat);
+    assert_eq!(Sysno::renameat2.id() as libc::c_long, libc::SYS_renameat2);
+    assert_eq!(
+        Sysno::request_key.id() as libc::c_long,
+        libc::SYS_request_key
+    );
+    assert_eq!(
+        Sysno::restart_syscall.id() as libc::c_long,
+        libc::SYS_restart_syscall
+    );
+    assert_eq!(Sysno::rmdir.id() as libc::c_long, libc::SYS_rmdir);
+    assert_eq!(Sysno::rseq.id() as libc::c_long, libc::SYS_rseq);
+    assert_eq!(
+        Sysno::rt_sigaction.id() as libc::c_long,
+        libc::SYS_rt_sigaction
+    );
+    assert_eq!(
+        Sysno::rt_sigpending.id() as libc::c_long,
+        libc::SYS_rt_sigpending
+    );
+    assert_eq!(
+        Sysno::rt_sigprocmask.id() as libc::c_long,
+        libc::SYS_rt_sigprocmask
+    );
+    assert_eq!(
+        Sysno::rt_sigqueueinfo.id() as libc::c_long,
+        libc::SYS_rt_sigqueueinfo
+    );
+    assert_eq!(
+        Sysno::rt_sigreturn.id() as libc::c_long,
+        libc::SYS_rt_sigreturn
+    );
+    assert_eq!(
+        Sysno::rt_sigsuspend.id() as libc::c_long,
+        libc::SYS_rt_sigsuspend
+    );
+    assert_eq!(
+        Sysno::rt_sigtimedwait.id() as libc::c_long,
+        libc::SYS_rt_sigtimedwait
+    );
+    assert_eq!(
+        Sysno::rt_tgsigqueueinfo.id() as libc::c_long,
+        libc::SYS_rt_tgsigqueueinfo
+    );
+    assert_eq!(Sysno::rtas.id() as libc::c_long, libc::SYS_rtas);
+    assert_eq!(
+        Sysno::sched_get_priority_max.id() as libc::c_long,
+        libc::SYS_sched_get_priority_max
+    );
+    assert_eq!(
+        Sysno::sched_get_priority_min.id() as libc::c_long,
+        libc::SYS_sched_get_priority_min
+    );
+    assert_eq!(
+        Sysno::sched_getaffinity.id() as libc::c_long,
+        libc::SYS_sched_getaffinity
+    );
+    assert_eq!(
+        Sysno::sched_getattr.id() as libc::c_long,
+        libc::SYS_sched_getattr
+    );
+    assert_eq!(
+        Sysno::sched_getparam.id() as libc::c_long,
+        libc::SYS_sched_getparam
+    );
+    assert_eq!(
+        Sysno::sched_getscheduler.id() as libc::c_long,
+        libc::SYS_sched_getscheduler
+    );
+    assert_eq!(
+        Sysno::sched_rr_get_interval.id() as libc::c_long,
+        libc::SYS_sched_rr_get_interval
+    );
+    assert_eq!(
+        Sysno::sched_setaffinity.id() as libc::c_long,
+        libc::SYS_sched_setaffinity
+    );
+    assert_eq!(
+        Sysno::sched_setattr.id() as libc::c_long,
+        libc::SYS_sched_setattr
+    );
+    assert_eq!(
+        Sysno::sched_setparam.id() as libc::c_long,
+        libc::SYS_sched_setparam
+    );
+    assert_eq!(
+        Sysno::sched_setscheduler.id() as libc::c_long,
+        libc::SYS_sched_setscheduler
+    );
+    assert_eq!(
+        Sysno::sched_yield.id() as libc::c_long,
+        libc::SYS_sched_yield
+    );
+    assert_eq!(Sysno::seccomp.id() as libc::c_long, libc::SYS_seccomp);
+    assert_eq!(Sysno::select.id() as libc::c_long, libc::SYS_select);
+    assert_eq!(Sysno::send.id() as libc::c_long, libc::SYS_send);
+    assert_eq!(Sysno::sendfile.id() as libc::c_long, libc::SYS_sendfile);
+    assert_eq!(Sysno::sendmmsg.id() as libc::c_long, libc::SYS_sendmmsg);
+    assert_eq!(Sysno::sendmsg.id() as libc::c_long, libc::SYS_sendmsg);
+    assert_eq!(Sysno::sendto.id() as libc::c_long, libc::SYS_sendto);
+    assert_eq!(
+        Sysno::set_mempolicy.id() as libc::c_long,
+        libc::SYS_set_mempolicy
+    );
+    assert_eq!(
+        Sysno::set_mempolicy_home_node.id() as libc::c_long,
+        libc::SYS_set_mempolicy_home_node
+    );
+    assert_eq!(
+        Sysno::set_robust_list.id() as libc::c_long,
+        libc::SYS_set_robust_list
+    );
+    assert_eq!(
+        Sysno::set_tid_address.id() as libc::c_long,
+        libc::SYS_set_tid_address
+    );
+    assert_eq!(
+        Sysno::setdomainname.id() as libc::c_long,
+        libc::SYS_setdomainname
+    );
+    assert_eq!(Sysno::setfsgid.id() as libc::c_long, libc::SYS_setfsgid);
+    assert_eq!(Sysno::setfsuid.id() as libc::c_long, libc::SYS_setfsuid);
+    assert_eq!(Sysno::setgid.id() as libc::c_long, libc::SYS_setgid);
+    assert_eq!(Sysno::setgroups.id() as libc::c_long, libc::SYS_setgroups);
+    assert_eq!(
+        Sysno::sethostname.id() as libc::c_long,
+        libc::SYS_sethostname
+    );
+    assert_eq!(Sysno::setitimer.id() as libc::c_long, libc::SYS_setitimer);
+    assert_eq!(Sysno::setns.id() as libc::c_long, libc::SYS_setns);
+    assert_eq!(Sysno::setpgid.id() as libc::c_long, libc::SYS_setpgid);
+    assert_eq!(
+        Sysno::setpriority.id() as libc::c_long,
+        libc::SYS_setpriority
+    );
+    assert_eq!(Sysno::setregid.id() as libc::c_long, libc::SYS_setregid);
+    assert_eq!(Sysno::setresgid.id() as libc::c_long, libc::SYS_setresgid);
+    assert_eq!(Sysno::setresuid.id() as libc::c_long, libc::SYS_setresuid);
+    assert_eq!(Sysno::setreuid.id() as libc::c_long, libc::SYS_setreuid);
+    assert_eq!(Sysno::setrlimit.id() as libc::c_long, libc::SYS_setrlimit);
+    assert_eq!(Sysno::setsid.id() as libc::c_long, libc::SYS_setsid);
+    assert_eq!(Sysno::setsockopt.id() as libc::c_long, libc::SYS_setsockopt);
+    assert_eq!(
+        Sysno::settimeofday.id() as libc::c_long,
+        libc::SYS_settimeofday
+    );
+    assert_eq!(Sysno::setuid.id() as libc::c_long, libc::SYS_setuid);
+    assert_eq!(Sysno::setxattr.id() as libc::c_long, libc::SYS_setxattr);
+    assert_eq!(Sysno::sgetmask.id() as libc::c_long, libc::SYS_sgetmask);
+    assert_eq!(Sysno::shutdown.id() as libc::c_long, libc::SYS_shutdown);
+    assert_eq!(Sysno::sigaction.id() as libc::c_long, libc::SYS_sigaction);
+    assert_eq!(
+        Sysno::sigaltstack.id() as libc::c_long,
+        libc::SYS_sigaltstack
+    );
+    assert_eq!(Sysno::signal.id() as libc::c_long, libc::SYS_signal);
+    assert_eq!(Sysno::signalfd.id() as libc::c_long, libc::SYS_signalfd);
+    assert_eq!(Sysno::signalfd4.id() as libc::c_long, libc::SYS_signalfd4);
+    assert_eq!(Sysno::sigpending.id() as libc::c_long, libc::SYS_sigpending);
+    assert_eq!(
+        Sysno::sigprocmask.id() as libc::c_long,
+        libc::SYS_sigprocmask
+    );
+    assert_eq!(Sysno::sigreturn.id() as libc::c_long, libc::SYS_sigreturn);
+    assert_eq!(Sysno::sigsuspend.id() as libc::c_long, libc::SYS_sigsuspend);
+    assert_eq!(Sysno::socket.id() as libc::c_long, libc::SYS_socket);
+    assert_eq!(Sysno::socketcall.id() as libc::c_long, libc::SYS_socketcall);
+    assert_eq!(Sysno::socketpair.id() as libc::c_long, libc::SYS_socketpair);
+    assert_eq!(Sysno::splice.id() as libc::c_long, libc::SYS_splice);
+    assert_eq!(Sysno::spu_create.id() as libc::c_long, libc::SYS_spu_create);
+    assert_eq!(Sysno::spu_run.id() as libc::c_long, libc::SYS_spu_run);
+    assert_eq!(Sysno::ssetmask.id() as libc::c_long, libc::SYS_ssetmask);
+    assert_eq!(Sysno::stat.id() as libc::c_long, libc::SYS_stat);
+    assert_eq!(Sysno::statfs.id() as libc::c_long, libc::SYS_statfs);
+    assert_eq!(Sysno::statfs64.id() as libc::c_long, libc::SYS_statfs64);
+    assert_eq!(Sysno::statx.id() as libc::c_long, libc::SYS_statx);
+    assert_eq!(Sysno::stime.id() as libc::c_long, libc::SYS_stime);
+    assert_eq!(Sysno::stty.id() as libc::c_long, libc::SYS_stty);
+    assert_eq!(
+        Sysno::subpage_prot.id() as libc::c_long,
+        libc::SYS_subpage_prot
+    );
+    assert_eq!(
+        Sysno::swapcontext.id() as libc::c_long,
+        libc::SYS_swapcontext
+    );
+    assert_eq!(Sysno::swapoff.id() as libc::c_long, libc::SYS_swapoff);
+    assert_eq!(Sysno::swapon.id() as libc::c_long, libc::SYS_swapon);
+    assert_eq!(
+        Sysno::switch_endian.id() as libc::c_long,
+        libc::SYS_switch_endian
+    );
+    assert_eq!(Sysno::symlink.id() as libc::c_long, libc::SYS_symlink);
+    assert_eq!(Sysno::symlinkat.id() as libc::c_long, libc::SYS_symlinkat);
+    assert_eq!(Sysno::sync.id() as libc::c_long, libc::SYS_sync);
+    assert_eq!(
+        Sysno::sync_file_range2.id() as libc::c_long,
+        libc::SYS_sync_file_range2
+    );
+    assert_eq!(Sysno::syncfs.id() as libc::c_long, libc::SYS_syncfs);
+    assert_eq!(
+        Sysno::sys_debug_setcontext.id() as libc::c_long,
+        libc::SYS_sys_debug_setcontext
+    );
+    assert_eq!(Sysno::sysfs.id() as libc::c_long, libc::SYS_sysfs);
+    assert_eq!(Sysno::sysinfo.id() as libc::c_long, libc::SYS_sysinfo);
+    assert_eq!(Sysno::syslog.id() as libc::c_long, libc::SYS_syslog);
+    assert_eq!(Sysno::tee.id() as libc::c_long, libc::SYS_tee);
+    assert_eq!(Sysno::tgkill.id() as libc::c_long, libc::SYS_tgkill);
+    assert_eq!(Sysno::time.id() as libc::c_long, libc::SYS_time);
+    assert_eq!(
+        Sysno::timer_create.id() as libc::c_long,
+        libc::SYS_timer_create
+    );
+    assert_eq!(
+        Sysno::timer_delete.id() as libc::c_long,
+        libc::SYS_timer_delete
+    );
+    assert_eq!(
+        Sysno::timer_getoverrun.id() as libc::c_long,
+        libc::SYS_timer_getoverrun
+    );
+    assert_eq!(
+        Sysno::timer_gettime.id() as libc::c_long,
+        libc::SYS_timer_gettime
+    );
+    assert_eq!(
+        Sysno::timer_settime.id() as libc::c_long,
+        libc::SYS_timer_settime
+    );
+    assert_eq!(
+        Sysno::timerfd_create.id() as libc::c_long,
+        libc::SYS_timerfd_create
+    );
+    assert_eq!(
+        Sysno::timerfd_gettime.id() as libc::c_long,
+        libc::SYS_timerfd_gettime
+    );
+    assert_eq!(
+        Sysno::timerfd_settime.id() as libc::c_long,
+        libc::SYS_timerfd_settime
+    );
+    assert_eq!(Sysno::times.id() as libc::c_long, libc::SYS_times);
+    assert_eq!(Sysno::tkill.id() as libc::c_long, libc::SYS_tkill);
+    assert_eq!(Sysno::truncate.id() as libc::c_long, libc::SYS_truncate);
+    assert_eq!(Sysno::tuxcall.id() as libc::c_long, libc::SYS_tuxcall);
+    assert_eq!(Sysno::ugetrlimit.id() as libc::c_long, libc::SYS_ugetrlimit);
+    assert_eq!(Sysno::ulimit.id() as libc::c_long, libc::SYS_ulimit);
+    assert_eq!(Sysno::umask.id() as libc::c_long, libc::SYS_umask);
+    assert_eq!(Sysno::umount.id() as libc::c_long, libc::SYS_umount);
+    assert_eq!(Sysno::umount2.id() as libc::c_long, libc::SYS_umount2);
+    assert_eq!(Sysno::uname.id() as libc::c_long, libc::SYS_uname);
+    assert_eq!(Sysno::unlink.id() as libc::c_long, libc::SYS_unlink);
+    assert_eq!(Sysno::unlinkat.id() as libc::c_long, libc::SYS_unlinkat);
+    assert_eq!(Sysno::unshare.id() as libc::c_long, libc::SYS_unshare);
+    assert_eq!(Sysno::uselib.id() as libc::c_long, libc::SYS_uselib);
+    assert_eq!(
+        Sysno::userfaultfd.id() as libc::c_long,
+        libc::SYS_userfaultfd
+    );
+    assert_eq!(Sysno::ustat.id() as libc::c_long, libc::SYS_ustat);
+    assert_eq!(Sysno::utime.id() as libc::c_long, libc::SYS_utime);
+    assert_eq!(Sysno::utimensat.id() as libc::c_long, libc::SYS_utimensat);
+    assert_eq!(Sysno::utimes.id() as libc::c_long, libc::SYS_utimes);
+    assert_eq!(Sysno::vfork.id() as libc::c_long, libc::SYS_vfork);
+    assert_eq!(Sysno::vhangup.id() as libc::c_long, libc::SYS_vhangup);
+    assert_eq!(Sysno::vm86.id() as libc::c_long, libc::SYS_vm86);
+    assert_eq!(Sysno::vmsplice.id() as libc::c_long, libc::SYS_vmsplice);
+    assert_eq!(Sysno::wait4.id() as libc::c_long, libc::SYS_wait4);
+    assert_eq!(Sysno::waitid.id() as libc::c_long, libc::SYS_waitid);
+    assert_eq!(Sysno::waitpid.id() as libc::c_long, libc::SYS_waitpid);
+    assert_eq!(Sysno::write.id() as libc::c_long, libc::SYS_write);
+    assert_eq!(Sysno::writev.id() as libc::c_long, libc::SYS_writev);
+}
+
+#[cfg(all(target_arch = "riscv32", target_pointer_width = "32"))]
+#[test]
+fn riscv32() {
+    use rawsys_linux::riscv32::v6_12::Sysno;
+
+    assert_eq!(Sysno::accept.id() as libc::c_long, libc::SYS_accept);
+    assert_eq!(Sysno::accept4.id() as libc::c_long, libc::SYS_accept4);
+    assert_eq!(Sysno::acct.id() as libc::c_long, libc::SYS_acct);
+    assert_eq!(Sysno::add_key.id() as libc::c_long, libc::SYS_add_key);
+    assert_eq!(Sysno::bind.id() as libc::c_long, libc::SYS_bind);
+    assert_eq!(Sysno::bpf.id() as libc::c_long, libc::SYS_bpf);
+    assert_eq!(Sysno::brk.id() as libc::c_long, libc::SYS_brk);
+    assert_eq!(Sysno::capget.id() as libc::c_long, libc::SYS_capget);
+    assert_eq!(Sysno::capset.id() as libc::c_long, libc::SYS_capset);
+    assert_eq!(Sysno::chdir.id() as libc::c_long, libc::SYS_chdir);
+    assert_eq!(Sysno::chroot.id() as libc::c_long, libc::SYS_chroot);
+    assert_eq!(
+        Sysno::clock_adjtime64.id() as libc::c_long,
+        libc::SYS_clock_adjtime64
+    );
+    assert_eq!(
+        Sysno::clock_getres_time64.id() as libc::c_long,
+        libc::SYS_clock_getres_time64
+    );
+    assert_eq!(
+        Sysno::clock_gettime64.id() as libc::c_long,
+        libc::SYS_clock_gettime64
+    );
+    assert_eq!(
+        Sysno::clock_nanosleep_time64.id() as libc::c_long,
+        libc::SYS_clock_nanosleep_time64
+    );
+    assert_eq!(
+        Sysno::clock_settime64.id() as libc::c_long,
+        libc::SYS_clock_settime64
+    );
+    assert_eq!(Sysno::clone.id() as libc::c_long, libc::SYS_clone);
+    assert_eq!(Sysno::clone3.id() as libc::c_long, libc::SYS_clone3);
+    assert_eq!(Sysno::close.id() as libc::c_long, libc::SYS_close);
+    assert_eq!(
+        Sysno::close_range.id() as libc::c_long,
+        libc::SYS_close_range
+    );
+    assert_eq!(Sysno::connect.id() as libc::c_long, libc::SYS_connect);
+    assert_eq!(
+        Sysno::copy_file_range.id() as libc::c_long,
+        libc::SYS_copy_file_range
+    );
+    assert_eq!(
+        Sysno::delete_module.id() as libc::c_long,
+        libc::SYS_delete_module
+    );
+    assert_eq!(Sysno::dup.id() as libc::c_long, libc::SYS_dup);
+    assert_eq!(Sysno::dup3.id() as libc::c_long, libc::SYS_dup3);
+    assert_eq!(
+        Sysno::epoll_create1.id() as libc::c_long,
+        libc::SYS_epoll_create1
+    );
+    assert_eq!(Sysno::epoll_ctl.id() as libc::c_long, libc::SYS_epoll_ctl);
+    assert_eq!(
+        Sysno::epoll_pwait.id() as libc::c_long,
+        libc::SYS_epoll_pwait
+    );
+    assert_eq!(
+        Sysno::epoll_pwait2.id() as libc::c_long,
+        libc::SYS_epoll_pwait2
+    );
+    assert_eq!(Sysno::eventfd2.id() as libc::c_long, libc::SYS_eventfd2);
+    assert_eq!(Sysno::execve.id() as libc::c_long, libc::SYS_execve);
+    assert_eq!(Sysno::execveat.id() as libc::c_long, libc::SYS_execveat);
+    assert_eq!(Sysno::exit.id() as libc::c_long, libc::SYS_exit);
+    assert_eq!(Sysno::exit_group.id() as libc::c_long, libc::SYS_exit_group);
+    assert_eq!(Sysno::faccessat.id() as libc::c_long, libc::SYS_faccessat);
+    assert_eq!(Sysno::faccessat2.id() as libc::c_long, libc::SYS_faccessat2);
+    assert_eq!(Sysno::fadvise64.id() as libc::c_long, libc::SYS_fadvise64);
+    assert_eq!(Sysno::fallocate.id() as libc::c_long, libc::SYS_fallocate);
+    assert_eq!(
+        Sysno::fanotify_init.id() as libc::c_long,
+        libc::SYS_fanotify_init
+    );
+    assert_eq!(
+        Sysno::fanotify_mark.id() as libc::c_long,
+        libc::SYS_fanotify_mark
+    );
+    assert_eq!(Sysno::fchdir.id() as libc::c_long, libc::SYS_fchdir);
+    assert_eq!(Sysno::fchmod.id() as libc::c_long, libc::SYS_fchmod);
+    assert_eq!(Sysno::fchmodat.id() as libc::c_long, libc::SYS_fchmodat);
+    assert_eq!(Sysno::fchown.id() as libc::c_long, libc::SYS_fchown);
+    assert_eq!(Sysno::fchownat.id() as libc::c_long, libc::SYS_fchownat);
+    assert_eq!(Sysno::fcntl.id() as libc::c_long, libc::SYS_fcntl);
+    assert_eq!(Sysno::fdatasync.id() as libc::c_long, libc::SYS_fdatasync);
+    assert_eq!(Sysno::fgetxattr.id() as libc::c_long, libc::SYS_fgetxattr);
+    assert_eq!(
+        Sysno::finit_module.id() as libc::c_long,
+        libc::SYS_finit_module
+    );
+    assert_eq!(Sysno::flistxattr.id() as libc::c_long, libc::SYS_flistxattr);
+    assert_eq!(Sysno::flock.id() as libc::c_long, libc::SYS_flock);
+    assert_eq!(
+        Sysno::fremovexattr.id() as libc::c_long,
+        libc::SYS_fremovexattr
+    );
+    assert_eq!(Sysno::fsconfig.id() as libc::c_long, libc::SYS_fsconfig);
+    assert_eq!(Sysno::fsetxattr.id() as libc::c_long, libc::SYS_fsetxattr);
+    assert_eq!(Sysno::fsmount.id() as libc::c_long, libc::SYS_fsmount);
+    assert_eq!(Sysno::fsopen.id() as libc::c_long, libc::SYS_fsopen);
+    assert_eq!(Sysno::fspick.id() as libc::c_long, libc::SYS_fspick);
+    assert_eq!(Sysno::fstat.id() as libc::c_long, libc::SYS_fstat);
+    assert_eq!(Sysno::fstatfs.id() as libc::c_long, libc::SYS_fstatfs);
+    assert_eq!(Sysno::fsync.id() as libc::c_long, libc::SYS_fsync);
+    assert_eq!(Sysno::ftruncate.id() as libc::c_long, libc::SYS_ftruncate);
+    assert_eq!(
+        Sysno::futex_time64.id() as libc::c_long,
+        libc::SYS_futex_time64
+    );
+    assert_eq!(
+        Sysno::futex_waitv.id() as libc::c_long,
+        libc::SYS_futex_waitv
+    );
+    assert_eq!(
+        Sysno::get_mempolicy.id() as libc::c_long,
+        libc::SYS_get_mempolicy
+    );
+    assert_eq!(
+        Sysno::get_robust_list.id() as libc::c_long,
+        libc::SYS_get_robust_list
+    );
+    assert_eq!(Sysno::getcpu.id() as libc::c_long, libc::SYS_getcpu);
+    assert_eq!(Sysno::getcwd.id() as libc::c_long, libc::SYS_getcwd);
+    assert_eq!(Sysno::getdents64.id() as libc::c_long, libc::SYS_getdents64);
+    assert_eq!(Sysno::getegid.id() as libc::c_long, libc::SYS_getegid);
+    assert_eq!(Sysno::geteuid.id() as libc::c_long, libc::SYS_geteuid);
+    assert_eq!(Sysno::getgid.id() as libc::c_long, libc::SYS_getgid);
+    assert_eq!(Sysno::getgroups.id() as libc::c_long, libc::SYS_getgroups);
+    assert_eq!(Sysno::getitimer.id() as libc::c_long, libc::SYS_getitimer);
+    assert_eq!(
+        Sysno::getpeername.id() as libc::c_long,
+        libc::SYS_getpeername
+    );
+    assert_eq!(Sysno::getpgid.id() as libc::c_long, libc::SYS_getpgid);
+    assert_eq!(Sysno::getpid.id() as libc::c_long, libc::SYS_getpid);
+    assert_eq!(Sysno::getppid.id() as libc::c_long, libc::SYS_getppid);
+    assert_eq!(
+        Sysno::getpriority.id() as libc::c_long,
+        libc::SYS_getpriority
+    );
+    assert_eq!(Sysno::getrandom.id() as libc::c_long, libc::SYS_getrandom);
+    assert_eq!(Sysno::getresgid.id() as libc::c_long, libc::SYS_getresgid);
+    assert_eq!(Sysno::getresuid.id() as libc::c_long, libc::SYS_getresuid);
+    assert_eq!(Sysno::getrlimit.id() as libc::c_long, libc::SYS_getrlimit);
+    assert_eq!(Sysno::getrusage.id() as libc::c_long, libc::SYS_getrusage);
+    assert_eq!(Sysno::getsid.id() as libc::c_long, libc::SYS_getsid);
+    assert_eq!(
+        Sysno::getsockname.id() as libc::c_long,
+        libc::SYS_getsockname
+    );
+    assert_eq!(Sysno::getsockopt.id() as libc::c_long, libc::SYS_getsockopt);
+    assert_eq!(Sysno::gettid.id() as libc::c_long, libc::SYS_gettid);
+    assert_eq!(Sysno::getuid.id() as libc::c_long, libc::SYS_getuid);
+    assert_eq!(Sysno::getxattr.id() as libc::c_long, libc::SYS_getxattr);
+    assert_eq!(
+        Sysno::init_module.id() as libc::c_long,
+        libc::SYS_init_module
+    );
+    assert_eq!(
+        Sysno::inotify_add_watch.id() as libc::c_long,
+        libc::SYS_inotify_add_watch
+    );
+    assert_eq!(
+        Sysno::inotify_init1.id() as libc::c_long,
+        libc::SYS_inotify_init1
+    );
+    assert_eq!(
+        Sysno::inotify_rm_watch.id() as libc::c_long,
+        libc::SYS_inotify_rm_watch
+    );
+    assert_eq!(Sysno::io_cancel.id() as libc::c_long, libc::SYS_io_cancel);
+    assert_eq!(Sysno::io_destroy.id() as libc::c_long, libc::SYS_io_destroy);
+    assert_eq!(
+        Sysno::io_pgetevents_time64.id() as libc::c_long,
+        libc::SYS_io_pgetevents_time64
+    );
+    assert_eq!(Sysno::io_setup.id() as libc::c_long, libc::SYS_io_setup);
+    assert_eq!(Sysno::io_submit.id() as libc::c_long, libc::SYS_io_submit);
+    assert_eq!(
+        Sysno::io_uring_enter.id() as libc::c_long,
+        libc::SYS_io_uring_enter
+    );
+    assert_eq!(
+        Sysno::io_uring_register.id() as libc::c_long,
+        libc::SYS_io_uring_register
+    );
+    assert_eq!(
+        Sysno::io_uring_setup.id() as libc::c_long,
+        libc::SYS_io_uring_setup
+    );
+    assert_eq!(Sysno::ioctl.id() as libc::c_long, libc::SYS_ioctl);
+    assert_eq!(Sysno::ioprio_get.id() as libc::c_long, libc::SYS_ioprio_get);
+    assert_eq!(Sysno::ioprio_set.id() as libc::c_long, libc::SYS_ioprio_set);
+    assert_eq!(Sysno::kcmp.id() as libc::c_long, libc::SYS_kcmp);
+    assert_eq!(Sysno::kexec_load.id() as libc::c_long, libc::SYS_kexec_load);
+    assert_eq!(Sysno::keyctl.id() as libc::c_long, libc::SYS_keyctl);
+    assert_eq!(Sysno::kill.id() as libc::c_long, libc::SYS_kill);
+    assert_eq!(
+        Sysno::landlock_add_rule.id() as libc::c_long,
+        libc::SYS_landlock_add_rule
+    );
+    assert_eq!(
+        Sysno::landlock_create_ruleset.id() as libc::c_long,
+        libc::SYS_landlock_create_ruleset
+    );
+    assert_eq!(
+        Sysno::landlock_restrict_self.id() as libc::c_long,
+        libc::SYS_landlock_restrict_self
+    );
+    assert_eq!(Sysno::lgetxattr.id() as libc::c_long, libc::SYS_lgetxattr);
+    assert_eq!(Sysno::linkat.id() as libc::c_long, libc::SYS_linkat);
+    assert_eq!(Sysno::listen.id() as libc::c_long, libc::SYS_listen);
+    assert_eq!(Sysno::listxattr.id() as libc::c_long, libc::SYS_listxattr);
+    assert_eq!(Sysno::llistxattr.id() as libc::c_long, libc::SYS_llistxattr);
+    assert_eq!(
+        Sysno::lookup_dcookie.id() as libc::c_long,
+        libc::SYS_lookup_dcookie
+    );
+    assert_eq!(
+        Sysno::lremovexattr.id() as libc::c_long,
+        libc::SYS_lremovexattr
+    );
+    assert_eq!(Sysno::lseek.id() as libc::c_long, libc::SYS_lseek);
+    assert_eq!(Sysno::lsetxattr.id() as libc::c_long, libc::SYS_lsetxattr);
+    assert_eq!(Sysno::madvise.id() as libc::c_long, libc::SYS_madvise);
+    assert_eq!(Sysno::mbind.id() as libc::c_long, libc::SYS_mbind);
+    assert_eq!(Sysno::membarrier.id() as libc::c_long, libc::SYS_membarrier);
+    assert_eq!(
+        Sysno::memfd_create.id() as libc::c_long,
+        libc::SYS_memfd_create
+    );
+    assert_eq!(
+        Sysno::memfd_secret.id() as libc::c_long,
+        libc::SYS_memfd_secret
+    );
+    assert_eq!(
+        Sysno::migrate_pages.id() as libc::c_long,
+        libc::SYS_migrate_pages
+    );
+    assert_eq!(Sysno::mincore.id() as libc::c_long, libc::SYS_mincore);
+    assert_eq!(Sysno::mkdirat.id() as libc::c_long, libc::SYS_mkdirat);
+    assert_eq!(Sysno::mknodat.id() as libc::c_long, libc::SYS_mknodat);
+    assert_eq!(Sysno::mlock.id() as libc::c_long, libc::SYS_mlock);
+    assert_eq!(Sysno::mlock2.id() as libc::c_long, libc::SYS_mlock2);
+    assert_eq!(Sysno::mlockall.id() as libc::c_long, libc::SYS_mlockall);
+    assert_eq!(Sysno::mmap.id() as libc::c_long, libc::SYS_mmap);
+    assert_eq!(Sysno::mount.id() as libc::c_long, libc::SYS_mount);
+    assert_eq!(
+        Sysno::mount_setattr.id() as libc::c_long,
+        libc::SYS_mount_setattr
+    );
+    assert_eq!(Sysno::move_mount.id() as libc::c_long, libc::SYS_move_mount);
+    assert_eq!(Sysno::move_pages.id() as libc::c_long, libc::SYS_move_pages);
+    assert_eq!(Sysno::mprotect.id() as libc::c_long, libc::SYS_mprotect);
+    assert_eq!(
+        Sysno::mq_getsetattr.id() as libc::c_long,
+        libc::SYS_mq_getsetattr
+    );
+    assert_eq!(Sysno::mq_notify.id() as libc::c_long, libc::SYS_mq_notify);
+    assert_eq!(Sysno::mq_open.id() as libc::c_long, libc::SYS_mq_open);
+    assert_eq!(
+        Sysno::mq_timedreceive_time64.id() as libc::c_long,
+        libc::SYS_mq_timedreceive_time64
+    );
+    assert_eq!(
+        Sysno::mq_timedsend_time64.id() as libc::c_long,
+        libc::SYS_mq_timedsend_time64
+    );
+    assert_eq!(Sysno::mq_unlink.id() as libc::c_long, libc::SYS_mq_unlink);
+    assert_eq!(Sysno::mremap.id() as libc::c_long, libc::SYS_mremap);
+    assert_eq!(Sysno::msgctl.id() as libc::c_long, libc::SYS_msgctl);
+    assert_eq!(Sysno::msgget.id() as libc::c_long, libc::SYS_msgget);
+    assert_eq!(Sysno::msgrcv.id() as libc::c_long, libc::SYS_msgrcv);
+    assert_eq!(Sysno::msgsnd.id() as libc::c_long, libc::SYS_msgsnd);
+    assert_eq!(Sysno::msync.id() as libc::c_long, libc::SYS_msync);
+    assert_eq!(Sysno::munlock.id() as libc::c_long, libc::SYS_munlock);
+    assert_eq!(Sysno::munlockall.id() as libc::c_long, libc::SYS_munlockall);
+    assert_eq!(Sysno::munmap.id() as libc::c_long, libc::SYS_munmap);
+    assert_eq!(
+        Sysno::name_to_handle_at.id() as libc::c_long,
+        libc::SYS_name_to_handle_at
+    );
+    assert_eq!(Sysno::nfsservctl.id() as libc::c_long, libc::SYS_nfsservctl);
+    assert_eq!(
+        Sysno::open_by_handle_at.id() as libc::c_long,
+        libc::SYS_open_by_handle_at
+    );
+    assert_eq!(Sysno::open_tree.id() as libc::c_long, libc::SYS_open_tree);
+    assert_eq!(Sysno::openat.id() as libc::c_long, libc::SYS_openat);
+    assert_eq!(Sysno::openat2.id() as libc::c_long, libc::SYS_openat2);
+    assert_eq!(
+        Sysno::perf_event_open.id() as libc::c_long,
+        libc::SYS_perf_event_open
+    );
+    assert_eq!(
+        Sysno::personality.id() as libc::c_long,
+        libc::SYS_personality
+    );
+    assert_eq!(
+        Sysno::pidfd_getfd.id() as libc::c_long,
+        libc::SYS_pidfd_getfd
+    );
+    assert_eq!(Sysno::pidfd_open.id() as libc::c_long, libc::SYS_pidfd_open);
+    assert_eq!(
+        Sysno::pidfd_send_signal.id() as libc::c_long,
+        libc::SYS_pidfd_send_signal
+    );
+    assert_eq!(Sysno::pipe2.id() as libc::c_long, libc::SYS_pipe2);
+    assert_eq!(Sysno::pivot_root.id() as libc::c_long, libc::SYS_pivot_root);
+    assert_eq!(Sysno::pkey_alloc.id() as libc::c_long, libc::SYS_pkey_alloc);
+    assert_eq!(Sysno::pkey_free.id() as libc::c_long, libc::SYS_pkey_free);
+    assert_eq!(
+        Sysno::pkey_mprotect.id() as libc::c_long,
+        libc::SYS_pkey_mprotect
+    );
+    assert_eq!(
+        Sysno::ppoll_time64.id() as libc::c_long,
+        libc::SYS_ppoll_time64
+    );
+    assert_eq!(Sysno::prctl.id() as libc::c_long, libc::SYS_prctl);
+    assert_eq!(Sysno::pread64.id() as libc::c_long, libc::SYS_pread64);
+    assert_eq!(Sysno::preadv.id() as libc::c_long, libc::SYS_preadv);
+    assert_eq!(Sysno::preadv2.id() as libc::c_long, libc::SYS_preadv2);
+    assert_eq!(Sysno::prlimit64.id() as libc::c_long, libc::SYS_prlimit64);
+    assert_eq!(
+        Sysno::process_madvise.id() as libc::c_long,
+        libc::SYS_process_madvise
+    );
+    assert_eq!(
+        Sysno::process_mrelease.id() as libc::c_long,
+        libc::SYS_process_mrelease
+    );
+    assert_eq!(
+        Sysno::process_vm_readv.id() as libc::c_long,
+        libc::SYS_process_vm_readv
+    );
+    assert_eq!(
+        Sysno::process_vm_writev.id() as libc::c_long,
+        libc::SYS_process_vm_writev
+    );
+    assert_eq!(
+        Sysno::pselect6_time64.id() as libc::c_long,
+        libc::SYS_pselect6_time64
+    );
+    assert_eq!(Sysno::ptrace.id() as libc::c_long, libc::SYS_ptrace);
+    assert_eq!(Sysno::pwrite64.id() as libc::c_long, libc::SYS_pwrite64);
+    assert_eq!(Sysno::pwritev.id() as libc::c_long, libc::SYS_pwritev);
+    assert_eq!(Sysno::pwritev2.id() as libc::c_long, libc::SYS_pwritev2);
+    assert_eq!(Sysno::quotactl.id() as libc::c_long, libc::SYS_quotactl);
+    assert_eq!(
+        Sysno::quotactl_fd.id() as libc::c_long,
+        libc::SYS_quotactl_fd
+    );
+    assert_eq!(Sysno::read.id() as libc::c_long, libc::SYS_read);
+    assert_eq!(Sysno::readahead.id() as libc::c_long, libc::SYS_readahead);
+    assert_eq!(Sysno::readlinkat.id() as libc::c_long, libc::SYS_readlinkat);
+    assert_eq!(Sysno::readv.id() as libc::c_long, libc::SYS_readv);
+    assert_eq!(Sysno::reboot.id() as libc::c_long, libc::SYS_reboot);
+    assert_eq!(Sysno::recvfrom.id() as libc::c_long, libc::SYS_recvfrom);
+    assert_eq!(
+        Sysno::recvmmsg_time64.id() as libc::c_long,
+        libc::SYS_recvmmsg_time64
+    );
+    assert_eq!(Sysno::recvmsg.id() as libc::c_long, libc::SYS_recvmsg);
+    assert_eq!(
+        Sysno::remap_file_pages.id() as libc::c_long,
+        libc::SYS_remap_file_pages
+    );
+    assert_eq!(
+        Sysno::removexattr.id() as libc::c_long,
+        libc::SYS_removexattr
+    );
+    assert_eq!(Sysno::renameat2.id() as libc::c_long, libc::SYS_renameat2);
+    assert_eq!(
+        Sysno::request_key.id() as libc::c_long,
+        libc::SYS_request_key
+    );
+    assert_eq!(
+        Sysno::restart_syscall.id() as libc::c_long,
+        libc::SYS_restart_syscall
+    );
+    assert_eq!(Sysno::rseq.id() as libc::c_long, libc::SYS_rseq);
+    assert_eq!(
+        Sysno::rt_sigaction.id() as libc::c_long,
+        libc::SYS_rt_sigaction
+    );
+    assert_eq!(
+        Sysno::rt_sigpending.id() as libc::c_long,
+        libc::SYS_rt_sigpending
+    );
+    assert_eq!(
+        Sysno::rt_sigprocmask.id() as libc::c_long,
+        libc::SYS_rt_sigprocmask
+    );
+    assert_eq!(
+        Sysno::rt_sigqueueinfo.id() as libc::c_long,
+        libc::SYS_rt_sigqueueinfo
+    );
+    assert_eq!(
+        Sysno::rt_sigreturn.id() as libc::c_long,
+        libc::SYS_rt_sigreturn
+    );
+    assert_eq!(
+        Sysno::rt_sigsuspend.id() as libc::c_long,
+        libc::SYS_rt_sigsuspend
+    );
+    assert_eq!(
+        Sysno::rt_sigtimedwait_time64.id() as libc::c_long,
+        libc::SYS_rt_sigtimedwait_time64
+    );
+    assert_eq!(
+        Sysno::rt_tgsigqueueinfo.id() as libc::c_long,
+        libc::SYS_rt_tgsigqueueinfo
+    );
+    assert_eq!(
+        Sysno::sched_get_priority_max.id() as libc::c_long,
+        libc::SYS_sched_get_priority_max
+    );
+    assert_eq!(
+        Sysno::sched_get_priority_min.id() as libc::c_long,
+        libc::SYS_sched_get_priority_min
+    );
+    assert_eq!(
+        Sysno::sched_getaffinity.id() as libc::c_long,
+        libc::SYS_sched_getaffinity
+    );
+    assert_eq!(
+        Sysno::sched_getattr.id() as libc::c_long,
+        libc::SYS_sched_getattr
+    );
+    assert_eq!(
+        Sysno::sched_getparam.id() as libc::c_long,
+        libc::SYS_sched_getparam
+    );
+    assert_eq!(
+        Sysno::sched_getscheduler.id() as libc::c_long,
+        libc::SYS_sched_getscheduler
+    );
+    assert_eq!(
+        Sysno::sched_rr_get_interval_time64.id() as libc::c_long,
+        libc::SYS_sched_rr_get_interval_time64
+    );
+    assert_eq!(
+        Sysno::sched_setaffinity.id() as libc::c_long,
+        libc::SYS_sched_setaffinity
+    );
+    assert_eq!(
+        Sysno::sched_setattr.id() as libc::c_long,
+        libc::SYS_sched_setattr
+    );
+    assert_eq!(
+        Sysno::sched_setparam.id() as libc::c_long,
+        libc::SYS_sched_setparam
+    );
+    assert_eq!(
+        Sysno::sched_setscheduler.id() as libc::c_long,
+        libc::SYS_sched_setscheduler
+    );
+    assert_eq!(
+        Sysno::sched_yield.id() as libc::c_long,
+        libc::SYS_sched_yield
+    );
+    assert_eq!(Sysno::seccomp.id() as libc::c_long, libc::SYS_seccomp);
+    assert_eq!(Sysno::semctl.id() as libc::c_long, libc::SYS_semctl);
+    assert_eq!(Sysno::semget.id() as libc::c_long, libc::SYS_semget);
+    assert_eq!(Sysno::semop.id() as libc::c_long, libc::SYS_semop);
+    assert_eq!(
+        Sysno::semtimedop_time64.id() as libc::c_long,
+        libc::SYS_semtimedop_time64
+    );
+    assert_eq!(Sysno::sendfile.id() as libc::c_long, libc::SYS_sendfile);
+    assert_eq!(Sysno::sendmmsg.id() as libc::c_long, libc::SYS_sendmmsg);
+    assert_eq!(Sysno::sendmsg.id() as libc::c_long, libc::SYS_sendmsg);
+    assert_eq!(Sysno::sendto.id() as libc::c_long, libc::SYS_sendto);
+    assert_eq!(
+        Sysno::set_mempolicy.id() as libc::c_long,
+        libc::SYS_set_mempolicy
+    );
+    assert_eq!(
+        Sysno::set_mempolicy_home_node.id() as libc::c_long,
+        libc::SYS_set_mempolicy_home_node
+    );
+    assert_eq!(
+        Sysno::set_robust_list.id() as libc::c_long,
+        libc::SYS_set_robust_list
+    );
+    assert_eq!(
+        Sysno::set_tid_address.id() as libc::c_long,
+        libc::SYS_set_tid_address
+    );
+    assert_eq!(
+        Sysno::setdomainname.id() as libc::c_long,
+        libc::SYS_setdomainname
+    );
+    assert_eq!(Sysno::setfsgid.id() as libc::c_long, libc::SYS_setfsgid);
+    assert_eq!(Sysno::setfsuid.id() as libc::c_long, libc::SYS_setfsuid);
+    assert_eq!(Sysno::setgid.id() as libc::c_long, libc::SYS_setgid);
+    assert_eq!(Sysno::setgroups.id() as libc::c_long, libc::SYS_setgroups);
+    assert_eq!(
+        Sysno::sethostname.id() as libc::c_long,
+        libc::SYS_sethostname
+    );
+    assert_eq!(Sysno::setitimer.id() as libc::c_long, libc::SYS_setitimer);
+    assert_eq!(Sysno::setns.id() as libc::c_long, libc::SYS_setns);
+    assert_eq!(Sysno::setpgid.id() as libc::c_long, libc::SYS_setpgid);
+    assert_eq!(
+        Sysno::setpriority.id() as libc::c_long,
+        libc::SYS_setpriority
+    );
+    assert_eq!(Sysno::setregid.id() as libc::c_long, libc::SYS_setregid);
+    assert_eq!(Sysno::setresgid.id() as libc::c_long, libc::SYS_setresgid);
+    assert_eq!(Sysno::setresuid.id() as libc::c_long, libc::SYS_setresuid);
+    assert_eq!(Sysno::setreuid.id() as libc::c_long, libc::SYS_setreuid);
+    assert_eq!(Sysno::setrlimit.id() as libc::c_long, libc::SYS_setrlimit);
+    assert_eq!(Sysno::setsid.id() as libc::c_long, libc::SYS_setsid);
+    assert_eq!(Sysno::setsockopt.id() as libc::c_long, libc::SYS_setsockopt);
+    assert_eq!(Sysno::setuid.id() as libc::c_long, libc::SYS_setuid);
+    assert_eq!(Sysno::setxattr.id() as libc::c_long, libc::SYS_setxattr);
+    assert_eq!(Sysno::shmat.id() as libc::c_long, libc::SYS_shmat);
+    assert_eq!(Sysno::shmctl.id() as libc::c_long, libc::SYS_shmctl);
+    assert_eq!(Sysno::shmdt.id() as libc::c_long, libc::SYS_shmdt);
+    assert_eq!(Sysno::shmget.id() as libc::c_long, libc::SYS_shmget);
+    assert_eq!(Sysno::shutdown.id() as libc::c_long, libc::SYS_shutdown);
+    assert_eq!(
+        Sysno::sigaltstack.id() as libc::c_long,
+        libc::SYS_sigaltstack
+    );
+    assert_eq!(Sysno::signalfd4.id() as libc::c_long, libc::SYS_signalfd4);
+    assert_eq!(Sysno::socket.id() as libc::c_long, libc::SYS_socket);
+    assert_eq!(Sysno::socketpair.id() as libc::c_long, libc::SYS_socketpair);
+    assert_eq!(Sysno::splice.id() as libc::c_long, libc::SYS_splice);
+    assert_eq!(Sysno::statfs.id() as libc::c_long, libc::SYS_statfs);
+    assert_eq!(Sysno::statx.id() as libc::c_long, libc::SYS_statx);
+    assert_eq!(Sysno::swapoff.id() as libc::c_long, libc::SYS_swapoff);
+    assert_eq!(Sysno::swapon.id() as libc::c_long, libc::SYS_swapon);
+    assert_eq!(Sysno::symlinkat.id() as libc::c_long, libc::SYS_symlinkat);
+    assert_eq!(Sysno::sync.id() as libc::c_long, libc::SYS_sync);
+    assert_eq!(
+        Sysno::sync_file_range.id() as libc::c_long,
+        libc::SYS_sync_file_range
+    );
+    assert_eq!(Sysno::syncfs.id() as libc::c_long, libc::SYS_syncfs);
+    assert_eq!(Sysno::sysinfo.id() as libc::c_long, libc::SYS_sysinfo);
+    assert_eq!(Sysno::syslog.id() as libc::c_long, libc::SYS_syslog);
+    assert_eq!(Sysno::tee.id() as libc::c_long, libc::SYS_tee);
+    assert_eq!(Sysno::tgkill.id() as libc::c_long, libc::SYS_tgkill);
+    assert_eq!(
+        Sysno::timer_create.id() as libc::c_long,
+        libc::SYS_timer_create
+    );
+    assert_eq!(
+        Sysno::timer_delete.id() as libc::c_long,
+        libc::SYS_timer_delete
+    );
+    assert_eq!(
+        Sysno::timer_getoverrun.id() as libc::c_long,
+        libc::SYS_timer_getoverrun
+    );
+    assert_eq!(
+        Sysno::timer_gettime64.id() as libc::c_long,
+        libc::SYS_timer_gettime64
+    );
+    assert_eq!(
+        Sysno::timer_settime64.id() as libc::c_long,
+        libc::SYS_timer_settime64
+    );
+    assert_eq!(
+        Sysno::timerfd_create.id() as libc::c_long,
+        libc::SYS_timerfd_create
+    );
+    assert_eq!(
+        Sysno::timerfd_gettime64.id() as libc::c_long,
+        libc::SYS_timerfd_gettime64
+    );
+    assert_eq!(
+        Sysno::timerfd_settime64.id() as libc::c_long,
+        libc::SYS_timerfd_settime64
+    );
+    assert_eq!(Sysno::times.id() as libc::c_long, libc::SYS_times);
+    assert_eq!(Sysno::tkill.id() as libc::c_long, libc::SYS_tkill);
+    assert_eq!(Sysno::truncate.id() as libc::c_long, libc::SYS_truncate);
+    assert_eq!(Sysno::umask.id() as libc::c_long, libc::SYS_umask);
+    assert_eq!(Sysno::umount2.id() as libc::c_long, libc::SYS_umount2);
+    assert_eq!(Sysno::uname.id() as libc::c_long, libc::SYS_uname);
+    assert_eq!(Sysno::unlinkat.id() as libc::c_long, libc::SYS_unlinkat);
+    assert_eq!(Sysno::unshare.id() as libc::c_long, libc::SYS_unshare);
+    assert_eq!(
+        Sysno::userfaultfd.id() as libc::c_long,
+        libc::SYS_userfaultfd
+    );
+    assert_eq!(
+        Sysno::utimensat_time64.id() as libc::c_long,
+        libc::SYS_utimensat_time64
+    );
+    assert_eq!(Sysno::vhangup.id() as libc::c_long, libc::SYS_vhangup);
+    assert_eq!(Sysno::vmsplice.id() as libc::c_long, libc::SYS_vmsplice);
+    assert_eq!(Sysno::waitid.id() as libc::c_long, libc::SYS_waitid);
+    assert_eq!(Sysno::write.id() as libc::c_long, libc::SYS_write);
+    assert_eq!(Sysno::writev.id() as libc::c_long, libc::SYS_writev);
+}
+
+#[cfg(all(target_arch = "riscv64", target_pointer_width = "64"))]
+#[test]
+fn riscv64() {
+    use rawsys_linux::riscv64::v6_12::Sysno;
+
+    assert_eq!(Sysno::accept.id() as libc::c_long, libc::SYS_accept);
+    assert_eq!(Sysno::accept4.id() as libc::c_long, libc::SYS_accept4);
+    assert_eq!(Sysno::acct.id() as libc::c_long, libc::SYS_acct);
+    assert_eq!(Sysno::add_key.id() as libc::c_long, libc::SYS_add_key);
+    assert_eq!(Sysno::adjtimex.id() as libc::c_long, libc::SYS_adjtimex);
+    assert_eq!(Sysno::bind.id() as libc::c_long, libc::SYS_bind);
+    assert_eq!(Sysno::bpf.id() as libc::c_long, libc::SYS_bpf);
+    assert_eq!(Sysno::brk.id() as libc::c_long, libc::SYS_brk);
+    assert_eq!(Sysno::capget.id() as libc::c_long, libc::SYS_capget);
+    assert_eq!(Sysno::capset.id() as libc::c_long, libc::SYS_capset);
+    assert_eq!(Sysno::chdir.id() as libc::c_long, libc::SYS_chdir);
+    assert_eq!(Sysno::chroot.id() as libc::c_long, libc::SYS_chroot);
+    assert_eq!(
+        Sysno::clock_adjtime.id() as libc::c_long,
+        libc::SYS_clock_adjtime
+    );
+    assert_eq!(
+        Sysno::clock_getres.id() as libc::c_long,
+        libc::SYS_clock_getres
+    );
+    assert_eq!(
+        Sysno::clock_gettime.id() as libc::c_long,
+        libc::SYS_clock_gettime
+    );
+    assert_eq!(
+        Sysno::clock_nanosleep.id() as libc::c_long,
+        libc::SYS_clock_nanosleep
+    );
+    assert_eq!(
+        Sysno::clock_settime.id() as libc::c_long,
+        libc::SYS_clock_settime
+    );
+    assert_eq!(Sysno::clone.id() as libc::c_long, libc::SYS_clone);
+    assert_eq!(Sysno::clone3.id() as libc::c_long, libc::SYS_clone3);
+    assert_eq!(Sysno::close.id() as libc::c_long, libc::SYS_close);
+    assert_eq!(
+        Sysno::close_range.id() as libc::c_long,
+        libc::SYS_close_range
+    );
+    assert_eq!(Sysno::connect.id() as libc::c_long, libc::SYS_connect);
+    assert_eq!(
+        Sysno::copy_file_range.id() as libc::c_long,
+        libc::SYS_copy_file_range
+    );
+    assert_eq!(
+        Sysno::delete_module.id() as libc::c_long,
+        libc::SYS_delete_module
+    );
+    assert_eq!(Sysno::dup.id() as libc::c_long, libc::SYS_dup);
+    assert_eq!(Sysno::dup3.id() as libc::c_long, libc::SYS_dup3);
+    assert_eq!(
+        Sysno::epoll_create1.id() as libc::c_long,
+        libc::SYS_epoll_create1
+    );
+    assert_eq!(Sysno::epoll_ctl.id() as libc::c_long, libc::SYS_epoll_ctl);
+    assert_eq!(
+        Sysno::epoll_pwait.id() as libc::c_long,
+        libc::SYS_epoll_pwait
+    );
+    assert_eq!(
+        Sysno::epoll_pwait2.id() as libc::c_long,
+        libc::SYS_epoll_pwait2
+    );
+    assert_eq!(Sysno::eventfd2.id() as libc::c_long, libc::SYS_eventfd2);
+    assert_eq!(Sysno::execve.id() as libc::c_long, libc::SYS_execve);
+    assert_eq!(Sysno::execveat.id() as libc::c_long, libc::SYS_execveat);
+    assert_eq!(Sysno::exit.id() as libc::c_long, libc::SYS_exit);
+    assert_eq!(Sysno::exit_group.id() as libc::c_long, libc::SYS_exit_group);
+    assert_eq!(Sysno::faccessat.id() as libc::c_long, libc::SYS_faccessat);
+    assert_eq!(Sysno::faccessat2.id() as libc::c_long, libc::SYS_faccessat2);
+    assert_eq!(Sysno::fadvise64.id() as libc::c_long, libc::SYS_fadvise64);
+    assert_eq!(Sysno::fallocate.id() as libc::c_long, libc::SYS_fallocate);
+    assert_eq!(
+        Sysno::fanotify_init.id() as libc::c_long,
+        libc::SYS_fanotify_init
+    );
+    assert_eq!(
+        Sysno::fanotify_mark.id() as libc::c_long,
+        libc::SYS_fanotify_mark
+    );
+    assert_eq!(Sysno::fchdir.id() as libc::c_long, libc::SYS_fchdir);
+    assert_eq!(Sysno::fchmod.id() as libc::c_long, libc::SYS_fchmod);
+    assert_eq!(Sysno::fchmodat.id() as libc::c_long, libc::SYS_fchmodat);
+    assert_eq!(Sysno::fchown.id() as libc::c_long, libc::SYS_fchown);
+    assert_eq!(Sysno::fchownat.id() as libc::c_long, libc::SYS_fchownat);
+    assert_eq!(Sysno::fcntl.id() as libc::c_long, libc::SYS_fcntl);
+    assert_eq!(Sysno::fdatasync.id() as libc::c_long, libc::SYS_fdatasync);
+    assert_eq!(Sysno::fgetxattr.id() as libc::c_long, libc::SYS_fgetxattr);
+    assert_eq!(
+        Sysno::finit_module.id() as libc::c_long,
+        libc::SYS_finit_module
+    );
+    assert_eq!(Sysno::flistxattr.id() as libc::c_long, libc::SYS_flistxattr);
+    assert_eq!(Sysno::flock.id() as libc::c_long, libc::SYS_flock);
+    assert_eq!(
+        Sysno::fremovexattr.id() as libc::c_long,
+        libc::SYS_fremovexattr
+    );
+    assert_eq!(Sysno::fsconfig.id() as libc::c_long, libc::SYS_fsconfig);
+    assert_eq!(Sysno::fsetxattr.id() as libc::c_long, libc::SYS_fsetxattr);
+    assert_eq!(Sysno::fsmount.id() as libc::c_long, libc::SYS_fsmount);
+    assert_eq!(Sysno::fsopen.id() as libc::c_long, libc::SYS_fsopen);
+    assert_eq!(Sysno::fspick.id() as libc::c_long, libc::SYS_fspick);
+    assert_eq!(Sysno::fstat.id() as libc::c_long, libc::SYS_fstat);
+    assert_eq!(Sysno::fstatfs.id() as libc::c_long, libc::SYS_fstatfs);
+    assert_eq!(Sysno::fsync.id() as libc::c_long, libc::SYS_fsync);
+    assert_eq!(Sysno::ftruncate.id() as libc::c_long, libc::SYS_ftruncate);
+    assert_eq!(Sysno::futex.id() as libc::c_long, libc::SYS_futex);
+    assert_eq!(
+        Sysno::futex_waitv.id() as libc::c_long,
+        libc::SYS_futex_waitv
+    );
+    assert_eq!(
+        Sysno::get_mempolicy.id() as libc::c_long,
+        libc::SYS_get_mempolicy
+    );
+    assert_eq!(
+        Sysno::get_robust_list.id() as libc::c_long,
+        libc::SYS_get_robust_list
+    );
+    assert_eq!(Sysno::getcpu.id() as libc::c_long, libc::SYS_getcpu);
+    assert_eq!(Sysno::getcwd.id() as libc::c_long, libc::SYS_getcwd);
+    assert_eq!(Sysno::getdents64.id() as libc::c_long, libc::SYS_getdents64);
+    assert_eq!(Sysno::getegid.id() as libc::c_long, libc::SYS_getegid);
+    assert_eq!(Sysno::geteuid.id() as libc::c_long, libc::SYS_geteuid);
+    assert_eq!(Sysno::getgid.id() as libc::c_long, libc::SYS_getgid);
+    assert_eq!(Sysno::getgroups.id() as libc::c_long, libc::SYS_getgroups);
+    assert_eq!(Sysno::getitimer.id() as libc::c_long, libc::SYS_getitimer);
+    assert_eq!(
+        Sysno::getpeername.id() as libc::c_long,
+        libc::SYS_getpeername
+    );
+    assert_eq!(Sysno::getpgid.id() as libc::c_long, libc::SYS_getpgid);
+    assert_eq!(Sysno::getpid.id() as libc::c_long, libc::SYS_getpid);
+    assert_eq!(Sysno::getppid.id() as libc::c_long, libc::SYS_getppid);
+    assert_eq!(
+        Sysno::getpriority.id() as libc::c_long,
+        libc::SYS_getpriority
+    );
+    assert_eq!(Sysno::getrandom.id() as libc::c_long, libc::SYS_getrandom);
+    assert_eq!(Sysno::getresgid.id() as libc::c_long, libc::SYS_getresgid);
+    assert_eq!(Sysno::getresuid.id() as libc::c_long, libc::SYS_getresuid);
+    assert_eq!(Sysno::getrlimit.id() as libc::c_long, libc::SYS_getrlimit);
+    assert_eq!(Sysno::getrusage.id() as libc::c_long, libc::SYS_getrusage);
+    assert_eq!(Sysno::getsid.id() as libc::c_long, libc::SYS_getsid);
+    assert_eq!(
+        Sysno::getsockname.id() as libc::c_long,
+        libc::SYS_getsockname
+    );
+    assert_eq!(Sysno::getsockopt.id() as libc::c_long, libc::SYS_getsockopt);
+    assert_eq!(Sysno::gettid.id() as libc::c_long, libc::SYS_gettid);
+    assert_eq!(
+        Sysno::gettimeofday.id() as libc::c_long,
+        libc::SYS_gettimeofday
+    );
+    assert_eq!(Sysno::getuid.id() as libc::c_long, libc::SYS_getuid);
+    assert_eq!(Sysno::getxattr.id() as libc::c_long, libc::SYS_getxattr);
+    assert_eq!(
+        Sysno::init_module.id() as libc::c_long,
+        libc::SYS_init_module
+    );
+    assert_eq!(
+        Sysno::inotify_add_watch.id() as libc::c_long,
+        libc::SYS_inotify_add_watch
+    );
+    assert_eq!(
+        Sysno::inotify_init1.id() as libc::c_long,
+        libc::SYS_inotify_init1
+    );
+    assert_eq!(
+        Sysno::inotify_rm_watch.id() as libc::c_long,
+        libc::SYS_inotify_rm_watch
+    );
+    assert_eq!(Sysno::io_cancel.id() as libc::c_long, libc::SYS_io_cancel);
+    assert_eq!(Sysno::io_destroy.id() as libc::c_long, libc::SYS_io_destroy);
+    assert_eq!(
+        Sysno::io_getevents.id() as libc::c_long,
+        libc::SYS_io_getevents
+    );
+    assert_eq!(Sysno::io_setup.id() as libc::c_long, libc::SYS_io_setup);
+    assert_eq!(Sysno::io_submit.id() as libc::c_long, libc::SYS_io_submit);
+    assert_eq!(
+        Sysno::io_uring_enter.id() as libc::c_long,
+        libc::SYS_io_uring_enter
+    );
+    assert_eq!(
+        Sysno::io_uring_register.id() as libc::c_long,
+        libc::SYS_io_uring_register
+    );
+    assert_eq!(
+        Sysno::io_uring_setup.id() as libc::c_long,
+        libc::SYS_io_uring_setup
+    );
+    assert_eq!(Sysno::ioctl.id() as libc::c_long, libc::SYS_ioctl);
+    assert_eq!(Sysno::ioprio_get.id() as libc::c_long, libc::SYS_ioprio_get);
+    assert_eq!(Sysno::ioprio_set.id() as libc::c_long, libc::SYS_ioprio_set);
+    assert_eq!(Sysno::kcmp.id() as libc::c_long, libc::SYS_kcmp);
+    assert_eq!(Sysno::kexec_load.id() as libc::c_long, libc::SYS_kexec_load);
+    assert_eq!(Sysno::keyctl.id() as libc::c_long, libc::SYS_keyctl);
+    assert_eq!(Sysno::kill.id() as libc::c_long, libc::SYS_kill);
+    assert_eq!(
+        Sysno::landlock_add_rule.id() as libc::c_long,
+        libc::SYS_landlock_add_rule
+    );
+    assert_eq!(
+        Sysno::landlock_create_ruleset.id() as libc::c_long,
+        libc::SYS_landlock_create_ruleset
+    );
+    assert_eq!(
+        Sysno::landlock_restrict_self.id() as libc::c_long,
+        libc::SYS_landlock_restrict_self
+    );
+    assert_eq!(Sysno::lgetxattr.id() as libc::c_long, libc::SYS_lgetxattr);
+    assert_eq!(Sysno::linkat.id() as libc::c_long, libc::SYS_linkat);
+    assert_eq!(Sysno::listen.id() as libc::c_long, libc::SYS_listen);
+    assert_eq!(Sysno::listxattr.id() as libc::c_long, libc::SYS_listxattr);
+    assert_eq!(Sysno::llistxattr.id() as libc::c_long, libc::SYS_llistxattr);
+    assert_eq!(
+        Sysno::lookup_dcookie.id() as libc::c_long,
+        libc::SYS_lookup_dcookie
+    );
+    assert_eq!(
+        Sysno::lremovexattr.id() as libc::c_long,
+        libc::SYS_lremovexattr
+    );
+    assert_eq!(Sysno::lseek.id() as libc::c_long, libc::SYS_lseek);
+    assert_eq!(Sysno::lsetxattr.id() as libc::c_long, libc::SYS_lsetxattr);
+    assert_eq!(Sysno::madvise.id() as libc::c_long, libc::SYS_madvise);
+    assert_eq!(Sysno::mbind.id() as libc::c_long, libc::SYS_mbind);
+    assert_eq!(Sysno::membarrier.id() as libc::c_long, libc::SYS_membarrier);
+    assert_eq!(
+        Sysno::memfd_create.id() as libc::c_long,
+        libc::SYS_memfd_create
+    );
+    assert_eq!(
+        Sysno::memfd_secret.id() as libc::c_long,
+        libc::SYS_memfd_secret
+    );
+    assert_eq!(
+        Sysno::migrate_pages.id() as libc::c_long,
+        libc::SYS_migrate_pages
+    );
+    assert_eq!(Sysno::mincore.id() as libc::c_long, libc::SYS_mincore);
+    assert_eq!(Sysno::mkdirat.id() as libc::c_long, libc::SYS_mkdirat);
+    assert_eq!(Sysno::mknodat.id() as libc::c_long, libc::SYS_mknodat);
+    assert_eq!(Sysno::mlock.id() as libc::c_long, libc::SYS_mlock);
+    assert_eq!(Sysno::mlock2.id() as libc::c_long, libc::SYS_mlock2);
+    assert_eq!(Sysno::mlockall.id() as libc::c_long, libc::SYS_mlockall);
+    assert_eq!(Sysno::mmap.id() as libc::c_long, libc::SYS_mmap);
+    assert_eq!(Sysno::mount.id() as libc::c_long, libc::SYS_mount);
+    assert_eq!(
+        Sysno::mount_setattr.id() as libc::c_long,
+        libc::SYS_mount_setattr
+    );
+    assert_eq!(Sysno::move_mount.id() as libc::c_long, libc::SYS_move_mount);
+    assert_eq!(Sysno::move_pages.id() as libc::c_long, libc::SYS_move_pages);
+    assert_eq!(Sysno::mprotect.id() as libc::c_long, libc::SYS_mprotect);
+    assert_eq!(
+        Sysno::mq_getsetattr.id() as libc::c_long,
+        libc::SYS_mq_getsetattr
+    );
+    assert_eq!(Sysno::mq_notify.id() as libc::c_long, libc::SYS_mq_notify);
+    assert_eq!(Sysno::mq_open.id() as libc::c_long, libc::SYS_mq_open);
+    assert_eq!(
+        Sysno::mq_timedreceive.id() as libc::c_long,
+        libc::SYS_mq_timedreceive
+    );
+    assert_eq!(
+        Sysno::mq_timedsend.id() as libc::c_long,
+        libc::SYS_mq_timedsend
+    );
+    assert_eq!(Sysno::mq_unlink.id() as libc::c_long, libc::SYS_mq_unlink);
+    assert_eq!(Sysno::mremap.id() as libc::c_long, libc::SYS_mremap);
+    assert_eq!(Sysno::msgctl.id() as libc::c_long, libc::SYS_msgctl);
+    assert_eq!(Sysno::msgget.id() as libc::c_long, libc::SYS_msgget);
+    assert_eq!(Sysno::msgrcv.id() as libc::c_long, libc::SYS_msgrcv);
+    assert_eq!(Sysno::msgsnd.id() as libc::c_long, libc::SYS_msgsnd);
+    assert_eq!(Sysno::msync.id() as libc::c_long, libc::SYS_msync);
+    assert_eq!(Sysno::munlock.id() as libc::c_long, libc::SYS_munlock);
+    assert_eq!(Sysno::munlockall.id() as libc::c_long, libc::SYS_munlockall);
+    assert_eq!(Sysno::munmap.id() as libc::c_long, libc::SYS_munmap);
+    assert_eq!(
+        Sysno::name_to_handle_at.id() as libc::c_long,
+        libc::SYS_name_to_handle_at
+    );
+    assert_eq!(Sysno::nanosleep.id() as libc::c_long, libc::SYS_nanosleep);
+    assert_eq!(Sysno::nfsservctl.id() as libc::c_long, libc::SYS_nfsservctl);
+    assert_eq!(
+        Sysno::open_by_handle_at.id() as libc::c_long,
+        libc::SYS_open_by_handle_at
+    );
+    assert_eq!(Sysno::open_tree.id() as libc::c_long, libc::SYS_open_tree);
+    assert_eq!(Sysno::openat.id() as libc::c_long, libc::SYS_openat);
+    assert_eq!(Sysno::openat2.id() as libc::c_long, libc::SYS_openat2);
+    assert_eq!(
+        Sysno::perf_event_open.id() as libc::c_long,
+        libc::SYS_perf_event_open
+    );
+    assert_eq!(
+        Sysno::personality.id() as libc::c_long,
+        libc::SYS_personality
+    );
+    assert_eq!(
+        Sysno::pidfd_getfd.id() as libc::c_long,
+        libc::SYS_pidfd_getfd
+    );
+    assert_eq!(Sysno::pidfd_open.id() as libc::c_long, libc::SYS_pidfd_open);
+    assert_eq!(
+        Sysno::pidfd_send_signal.id() as libc::c_long,
+        libc::SYS_pidfd_send_signal
+    );
+    assert_eq!(Sysno::pipe2.id() as libc::c_long, libc::SYS_pipe2);
+    assert_eq!(Sysno::pivot_root.id() as libc::c_long, libc::SYS_pivot_root);
+    assert_eq!(Sysno::pkey_alloc.id() as libc::c_long, libc::SYS_pkey_alloc);
+    assert_eq!(Sysno::pkey_free.id() as libc::c_long, libc::SYS_pkey_free);
+    assert_eq!(
+        Sysno::pkey_mprotect.id() as libc::c_long,
+        libc::SYS_pkey_mprotect
+    );
+    assert_eq!(Sysno::ppoll.id() as libc::c_long, libc::SYS_ppoll);
+    assert_eq!(Sysno::prctl.id() as libc::c_long, libc::SYS_prctl);
+    assert_eq!(Sysno::pread64.id() as libc::c_long, libc::SYS_pread64);
+    assert_eq!(Sysno::preadv.id() as libc::c_long, libc::SYS_preadv);
+    assert_eq!(Sysno::preadv2.id() as libc::c_long, libc::SYS_preadv2);
+    assert_eq!(Sysno::prlimit64.id() as libc::c_long, libc::SYS_prlimit64);
+    assert_eq!(
+        Sysno::process_madvise.id() as libc::c_long,
+        libc::SYS_process_madvise
+    );
+    assert_eq!(
+        Sysno::process_mrelease.id() as libc::c_long,
+        libc::SYS_process_mrelease
+    );
+    assert_eq!(
+        Sysno::process_vm_readv.id() as libc::c_long,
+        libc::SYS_process_vm_readv
+    );
+    assert_eq!(
+        Sysno::process_vm_writev.id() as libc::c_long,
+        libc::SYS_process_vm_writev
+    );
+    assert_eq!(Sysno::pselect6.id() as libc::c_long, libc::SYS_pselect6);
+    assert_eq!(Sysno::ptrace.id() as libc::c_long, libc::SYS_ptrace);
+    assert_eq!(Sysno::pwrite64.id() as libc::c_long, libc::SYS_pwrite64);
+    assert_eq!(Sysno::pwritev.id() as libc::c_long, libc::SYS_pwritev);
+    assert_eq!(Sysno::pwritev2.id() as libc::c_long, libc::SYS_pwritev2);
+    assert_eq!(Sysno::quotactl.id() as libc::c_long, libc::SYS_quotactl);
+    assert_eq!(
+        Sysno::quotactl_fd.id() as libc::c_long,
+        libc::SYS_quotactl_fd
+    );
+    assert_eq!(Sysno::read.id() as libc::c_long, libc::SYS_read);
+    assert_eq!(Sysno::readahead.id() as libc::c_long, libc::SYS_readahead);
+    assert_eq!(Sysno::readlinkat.id() as libc::c_long, libc::SYS_readlinkat);
+    assert_eq!(Sysno::readv.id() as libc::c_long, libc::SYS_readv);
+    assert_eq!(Sysno::reboot.id() as libc::c_long, libc::SYS_reboot);
+    assert_eq!(Sysno::recvfrom.id() as libc::c_long, libc::SYS_recvfrom);
+    assert_eq!(Sysno::recvmmsg.id() as libc::c_long, libc::SYS_recvmmsg);
+    assert_eq!(Sysno::recvmsg.id() as libc::c_long, libc::SYS_recvmsg);
+    assert_eq!(
+        Sysno::remap_file_pages.id() as libc::c_long,
+        libc::SYS_remap_file_pages
+    );
+    assert_eq!(
+        Sysno::removexattr.id() as libc::c_long,
+        libc::SYS_removexattr
+    );
+    assert_eq!(Sysno::renameat2.id() as libc::c_long, libc::SYS_renameat2);
+    assert_eq!(
+        Sysno::request_key.id() as libc::c_long,
+        libc::SYS_request_key
+    );
+    assert_eq!(
+        Sysno::restart_syscall.id() as libc::c_long,
+        libc::SYS_restart_syscall
+    );
+    assert_eq!(Sysno::rseq.id() as libc::c_long, libc::SYS_rseq);
+    assert_eq!(
+        Sysno::rt_sigaction.id() as libc::c_long,
+        libc::SYS_rt_sigaction
+    );
+    assert_eq!(
+        Sysno::rt_sigpending.id() as libc::c_long,
+        libc::SYS_rt_sigpending
+    );
+    assert_eq!(
+        Sysno::rt_sigprocmask.id() as libc::c_long,
+        libc::SYS_rt_sigprocmask
+    );
+    assert_eq!(
+        Sysno::rt_sigqueueinfo.id() as libc::c_long,
+        libc::SYS_rt_sigqueueinfo
+    );
+    assert_eq!(
+        Sysno::rt_sigreturn.id() as libc::c_long,
+        libc::SYS_rt_sigreturn
+    );
+    assert_eq!(
+        Sysno::rt_sigsuspend.id() as libc::c_long,
+        libc::SYS_rt_sigsuspend
+    );
+    assert_eq!(
+        Sysno::rt_sigtimedwait.id() as libc::c_long,
+        libc::SYS_rt_sigtimedwait
+    );
+    assert_eq!(
+        Sysno::rt_tgsigqueueinfo.id() as libc::c_long,
+        libc::SYS_rt_tgsigqueueinfo
+    );
+    assert_eq!(
+        Sysno::sched_get_priority_max.id() as libc::c_long,
+        libc::SYS_sched_get_priority_max
+    );
+    assert_eq!(
+        Sysno::sched_get_priority_min.id() as libc::c_long,
+        libc::SYS_sched_get_priority_min
+    );
+    assert_eq!(
+        Sysno::sched_getaffinity.id() as libc::c_long,
+        libc::SYS_sched_getaffinity
+    );
+    assert_eq!(
+        Sysno::sched_getattr.id() as libc::c_long,
+        libc::SYS_sched_getattr
+    );
+    assert_eq!(
+        Sysno::sched_getparam.id() as libc::c_long,
+        libc::SYS_sched_getparam
+    );
+    assert_eq!(
+        Sysno::sched_getscheduler.id() as libc::c_long,
+        libc::SYS_sched_getscheduler
+    );
+    assert_eq!(
+        Sysno::sched_rr_get_interval.id() as libc::c_long,
+        libc::SYS_sched_rr_get_interval
+    );
+    assert_eq!(
+        Sysno::sched_setaffinity.id() as libc::c_long,
+        libc::SYS_sched_setaffinity
+    );
+    assert_eq!(
+        Sysno::sched_setattr.id() as libc::c_long,
+        libc::SYS_sched_setattr
+    );
+    assert_eq!(
+        Sysno::sched_setparam.id() as libc::c_long,
+        libc::SYS_sched_setparam
+    );
+    assert_eq!(
+        Sysno::sched_setscheduler.id() as libc::c_long,
+        libc::SYS_sched_setscheduler
+    );
+    assert_eq!(
+        Sysno::sched_yield.id() as libc::c_long,
+        libc::SYS_sched_yield
+    );
+    assert_eq!(Sysno::seccomp.id() as libc::c_long, libc::SYS_seccomp);
+    assert_eq!(Sysno::semctl.id() as libc::c_long, libc::SYS_semctl);
+    assert_eq!(Sysno::semget.id() as libc::c_long, libc::SYS_semget);
+    assert_eq!(Sysno::semop.id() as libc::c_long, libc::SYS_semop);
+    assert_eq!(Sysno::semtimedop.id() as libc::c_long, libc::SYS_semtimedop);
+    assert_eq!(Sysno::sendfile.id() as libc::c_long, libc::SYS_sendfile);
+    assert_eq!(Sysno::sendmmsg.id() as libc::c_long, libc::SYS_sendmmsg);
+    assert_eq!(Sysno::sendmsg.id() as libc::c_long, libc::SYS_sendmsg);
+    assert_eq!(Sysno::sendto.id() as libc::c_long, libc::SYS_sendto);
+    assert_eq!(
+        Sysno::set_mempolicy.id() as libc::c_long,
+        libc::SYS_set_mempolicy
+    );
+    assert_eq!(
+        Sysno::set_mempolicy_home_node.id() as libc::c_long,
+        libc::SYS_set_mempolicy_home_node
+    );
+    assert_eq!(
+        Sysno::set_robust_list.id() as libc::c_long,
+        libc::SYS_set_robust_list
+    );
+    assert_eq!(
+        Sysno::set_tid_address.id() as libc::c_long,
+        libc::SYS_set_tid_address
+    );
+    assert_eq!(
+        Sysno::setdomainname.id() as libc::c_long,
+        libc::SYS_setdomainname
+    );
+    assert_eq!(Sysno::setfsgid.id() as libc::c_long, libc::SYS_setfsgid);
+    assert_eq!(Sysno::setfsuid.id() as libc::c_long, libc::SYS_setfsuid);
+    assert_eq!(Sysno::setgid.id() as libc::c_long, libc::SYS_setgid);
+    assert_eq!(Sysno::setgroups.id() as libc::c_long, libc::SYS_setgroups);
+    assert_eq!(
+        Sysno::sethostname.id() as libc::c_long,
+        libc::SYS_sethostname
+    );
+    assert_eq!(Sysno::setitimer.id() as libc::c_long, libc::SYS_setitimer);
+    assert_eq!(Sysno::setns.id() as libc::c_long, libc::SYS_setns);
+    assert_eq!(Sysno::setpgid.id() as libc::c_long, libc::SYS_setpgid);
+    assert_eq!(
+        Sysno::setpriority.id() as libc::c_long,
+        libc::SYS_setpriority
+    );
+    assert_eq!(Sysno::setregid.id() as libc::c_long, libc::SYS_setregid);
+    assert_eq!(Sysno::setresgid.id() as libc::c_long, libc::SYS_setresgid);
+    assert_eq!(Sysno::setresuid.id() as libc::c_long, libc::SYS_setresuid);
+    assert_eq!(Sysno::setreuid.id() as libc::c_long, libc::SYS_setreuid);
+    assert_eq!(Sysno::setrlimit.id() as libc::c_long, libc::SYS_setrlimit);
+    assert_eq!(Sysno::setsid.id() as libc::c_long, libc::SYS_setsid);
+    assert_eq!(Sysno::setsockopt.id() as libc::c_long, libc::SYS_setsockopt);
+    assert_eq!(
+        Sysno::settimeofday.id() as libc::c_long,
+        libc::SYS_settimeofday
+    );
+    assert_eq!(Sysno::setuid.id() as libc::c_long, libc::SYS_setuid);
+    assert_eq!(Sysno::setxattr.id() as libc::c_long, libc::SYS_setxattr);
+    assert_eq!(Sysno::shmat.id() as libc::c_long, libc::SYS_shmat);
+    assert_eq!(Sysno::shmctl.id() as libc::c_long, libc::SYS_shmctl);
+    assert_eq!(Sysno::shmdt.id() as libc::c_long, libc::SYS_shmdt);
+    assert_eq!(Sysno::shmget.id() as libc::c_long, libc::SYS_shmget);
+    assert_eq!(Sysno::shutdown.id() as libc::c_long, libc::SYS_shutdown);
+    assert_eq!(
+        Sysno::sigaltstack.id() as libc::c_long,
+        libc::SYS_sigaltstack
+    );
+    assert_eq!(Sysno::signalfd4.id() as libc::c_long, libc::SYS_signalfd4);
+    assert_eq!(Sysno::socket.id() as libc::c_long, libc::SYS_socket);
+    assert_eq!(Sysno::socketpair.id() as libc::c_long, libc::SYS_socketpair);
+    assert_eq!(Sysno::splice.id() as libc::c_long, libc::SYS_splice);
+    assert_eq!(Sysno::statfs.id() as libc::c_long, libc::SYS_statfs);
+    assert_eq!(Sysno::statx.id() as libc::c_long, libc::SYS_statx);
+    assert_eq!(Sysno::swapoff.id() as libc::c_long, libc::SYS_swapoff);
+    assert_eq!(Sysno::swapon.id() as libc::c_long, libc::SYS_swapon);
+    assert_eq!(Sysno::symlinkat.id() as libc::c_long, libc::SYS_symlinkat);
+    assert_eq!(Sysno::sync.id() as libc::c_long, libc::SYS_sync);
+    assert_eq!(
+        Sysno::sync_file_range.id() as libc::c_long,
+        libc::SYS_sync_file_range
+    );
+    assert_eq!(Sysno::syncfs.id() as libc::c_long, libc::SYS_syncfs);
+    assert_eq!(Sysno::sysinfo.id() as libc::c_long, libc::SYS_sysinfo);
+    assert_eq!(Sysno::syslog.id() as libc::c_long, libc::SYS_syslog);
+    assert_eq!(Sysno::tee.id() as libc::c_long, libc::SYS_tee);
+    assert_eq!(Sysno::tgkill.id() as libc::c_long, libc::SYS_tgkill);
+    assert_eq!(
+        Sysno::timer_create.id() as libc::c_long,
+        libc::SYS_timer_create
+    );
+    assert_eq!(
+        Sysno::timer_delete.id() as libc::c_long,
+        libc::SYS_timer_delete
+    );
+    assert_eq!(
+        Sysno::timer_getoverrun.id() as libc::c_long,
+        libc::SYS_timer_getoverrun
+    );
+    assert_eq!(
+        Sysno::timer_gettime.id() as libc::c_long,
+        libc::SYS_timer_gettime
+    );
+    assert_eq!(
+        Sysno::timer_settime.id() as libc::c_long,
+        libc::SYS_timer_settime
+    );
+    assert_eq!(
+        Sysno::timerfd_create.id() as libc::c_long,
+        libc::SYS_timerfd_create
+    );
+    assert_eq!(
+        Sysno::timerfd_gettime.id() as libc::c_long,
+        libc::SYS_timerfd_gettime
+    );
+    assert_eq!(
+        Sysno::timerfd_settime.id() as libc::c_long,
+        libc::SYS_timerfd_settime
+    );
+    assert_eq!(Sysno::times.id() as libc::c_long, libc::SYS_times);
+    assert_eq!(Sysno::tkill.id() as libc::c_long, libc::SYS_tkill);
+    assert_eq!(Sysno::truncate.id() as libc::c_long, libc::SYS_truncate);
+    assert_eq!(Sysno::umask.id() as libc::c_long, libc::SYS_umask);
+    assert_eq!(Sysno::umount2.id() as libc::c_long, libc::SYS_umount2);
+    assert_eq!(Sysno::uname.id() as libc::c_long, libc::SYS_uname);
+    assert_eq!(Sysno::unlinkat.id() as libc::c_long, libc::SYS_unlinkat);
+    assert_eq!(Sysno::unshare.id() as libc::c_long, libc::SYS_unshare);
+    assert_eq!(
+        Sysno::userfaultfd.id() as libc::c_long,
+        libc::SYS_userfaultfd
+    );
+    assert_eq!(Sysno::utimensat.id() as libc::c_long, libc::SYS_utimensat);
+    assert_eq!(Sysno::vhangup.id() as libc::c_long, libc::SYS_vhangup);
+    assert_eq!(Sysno::vmsplice.id() as libc::c_long, libc::SYS_vmsplice);
+    assert_eq!(Sysno::wait4.id() as libc::c_long, libc::SYS_wait4);
+    assert_eq!(Sysno::waitid.id() as libc::c_long, libc::SYS_waitid);
+    assert_eq!(Sysno::write.id() as libc::c_long, libc::SYS_write);
+    assert_eq!(Sysno::writev.id() as libc::c_long, libc::SYS_writev);
+}
+
+#[cfg(all(target_arch = "s390x", target_pointer_width = "64"))]
+#[test]
+fn s390x() {
+    use rawsys_linux::s390x::v6_12::Sysno;
+
+    assert_eq!(Sysno::_sysctl.id() as libc::c_long, libc::SYS__sysctl);
+    assert_eq!(Sysno::accept4.id() as libc::c_long, libc::SYS_accept4);
+    assert_eq!(Sysno::access.id() as libc::c_long, libc::SYS_access);
+    assert_eq!(Sysno::acct.id() as libc::c_long, libc::SYS_acct);
+    assert_eq!(Sysno::add_key.id() as libc::c_long, libc::SYS_add_key);
+    assert_eq!(Sysno::adjtimex.id() as libc::c_long, libc::SYS_adjtimex);
+    assert_eq!(
+        Sysno::afs_syscall.id() as libc::c_long,
+        libc::SYS_afs_syscall
+    );
+    assert_eq!(Sysno::alarm.id() as libc::c_long, libc::SYS_alarm);
+    assert_eq!(Sysno::bdflush.id() as libc::c_long, libc::SYS_bdflush);
+    assert_eq!(Sysno::bind.id() as libc::c_long, libc::SYS_bind);
+    assert_eq!(Sysno::bpf.id() as libc::c_long, libc::SYS_bpf);
+    assert_eq!(Sysno::brk.id() as libc::c_long, libc::SYS_brk);
+    assert_eq!(Sysno::capget.id() as libc::c_long, libc::SYS_capget);
+    assert_eq!(Sysno::capset.id() as libc::c_long, libc::SYS_capset);
+    assert_eq!(Sysno::chdir.id() as libc::c_long, libc::SYS_chdir);
+    assert_eq!(Sysno::chmod.id() as libc::c_long, libc::SYS_chmod);
+    assert_eq!(Sysno::chown.id() as libc::c_long, libc::SYS_chown);
+    assert_eq!(Sysno::chroot.id() as libc::c_long, libc::SYS_chroot);
+    assert_eq!(
+        Sysno::clock_adjtime.id() as libc::c_long,
+        libc::SYS_clock_adjtime
+    );
+    assert_eq!(
+        Sysno::clock_getres.id() as libc::c_long,
+        libc::SYS_clock_getres
+    );
+    assert_eq!(
+        Sysno::clock_gettime.id() as libc::c_long,
+        libc::SYS_clock_gettime
+    );
+    assert_eq!(
+        Sysno::clock_nanosleep.id() as libc::c_long,
+        libc::SYS_clock_nanosleep
+    );
+    assert_eq!(
+        Sysno::clock_settime.id() as libc::c_long,
+        libc::SYS_clock_settime
+    );
+    assert_eq!(Sysno::clone.id() as libc::c_long, libc::SYS_clone);
+    assert_eq!(Sysno::clone3.id() as libc::c_long, libc::SYS_clone3);
+    assert_eq!(Sysno::close.id() as libc::c_long, libc::SYS_close);
+    assert_eq!(
+        Sysno::close_range.id() as libc::c_long,
+        libc::SYS_close_range
+    );
+    assert_eq!(Sysno::connect.id() as libc::c_long, libc::SYS_connect);
+    assert_eq!(
+        Sysno::copy_file_range.id() as libc::c_long,
+        libc::SYS_copy_file_range
+    );
+    assert_eq!(Sysno::creat.id() as libc::c_long, libc::SYS_creat);
+    assert_eq!(
+        Sysno::delete_module.id() as libc::c_long,
+        libc::SYS_delete_module
+    );
+    assert_eq!(Sysno::dup.id() as libc::c_long, libc::SYS_dup);
+    assert_eq!(Sysno::dup2.id() as libc::c_long, libc::SYS_dup2);
+    assert_eq!(Sysno::dup3.id() as libc::c_long, libc::SYS_dup3);
+    assert_eq!(
+        Sysno::epoll_create.id() as libc::c_long,
+        libc::SYS_epoll_create
+    );
+    assert_eq!(
+        Sysno::epoll_create1.id() as libc::c_long,
+        libc::SYS_epoll_create1
+    );
+    assert_eq!(Sysno::epoll_ctl.id() as libc::c_long, libc::SYS_epoll_ctl);
+    assert_eq!(
+        Sysno::epoll_pwait.id() as libc::c_long,
+        libc::SYS_epoll_pwait
+    );
+    assert_eq!(
+        Sysno::epoll_pwait2.id() as libc::c_long,
+        libc::SYS_epoll_pwait2
+    );
+    assert_eq!(Sysno::epoll_wait.id() as libc::c_long, libc::SYS_epoll_wait);
+    assert_eq!(Sysno::eventfd.id() as libc::c_long, libc::SYS_eventfd);
+    assert_eq!(Sysno::eventfd2.id() as libc::c_long, libc::SYS_eventfd2);
+    assert_eq!(Sysno::execve.id() as libc::c_long, libc::SYS_execve);
+    assert_eq!(Sysno::execveat.id() as libc::c_long, libc::SYS_execveat);
+    assert_eq!(Sysno::exit.id() as libc::c_long, libc::SYS_exit);
+    assert_eq!(Sysno::exit_group.id() as libc::c_long, libc::SYS_exit_group);
+    assert_eq!(Sysno::faccessat.id() as libc::c_long, libc::SYS_faccessat);
+    assert_eq!(Sysno::faccessat2.id() as libc::c_long, libc::SYS_faccessat2);
+    assert_eq!(Sysno::fadvise64.id() as libc::c_long, libc::SYS_fadvise64);
+    assert_eq!(Sysno::fallocate.id() as libc::c_long, libc::SYS_fallocate);
+    assert_eq!(
+        Sysno::fanotify_init.id() as libc::c_long,
+        libc::SYS_fanotify_init
+    );
+    assert_eq!(
+        Sysno::fanotify_mark.id() as libc::c_long,
+        libc::SYS_fanotify_mark
+    );
+    assert_eq!(Sysno::fchdir.id() as libc::c_long, libc::SYS_fchdir);
+    assert_eq!(Sysno::fchmod.id() as libc::c_long, libc::SYS_fchmod);
+    assert_eq!(Sysno::fchmodat.id() as libc::c_long, libc::SYS_fchmodat);
+    assert_eq!(Sysno::fchown.id() as libc::c_long, libc::SYS_fchown);
+    assert_eq!(Sysno::fchownat.id() as libc::c_long, libc::SYS_fchownat);
+    assert_eq!(Sysno::fcntl.id() as libc::c_long, libc::SYS_fcntl);
+    assert_eq!(Sysno::fdatasync.id() as libc::c_long, libc::SYS_fdatasync);
+    assert_eq!(Sysno::fgetxattr.id() as libc::c_long, libc::SYS_fgetxattr);
+    assert_eq!(
+        Sysno::finit_module.id() as libc::c_long,
+        libc::SYS_finit_module
+    );
+    assert_eq!(Sysno::flistxattr.id() as libc::c_long, libc::SYS_flistxattr);
+    assert_eq!(Sysno::flock.id() as libc::c_long, libc::SYS_flock);
+    assert_eq!(Sysno::fork.id() as libc::c_long, libc::SYS_fork);
+    assert_eq!(
+        Sysno::fremovexattr.id() as libc::c_long,
+        libc::SYS_fremovexattr
+    );
+    assert_eq!(Sysno::fsconfig.id() as libc::c_long, libc::SYS_fsconfig);
+    assert_eq!(Sysno::fsetxattr.id() as libc::c_long, libc::SYS_fsetxattr);
+    assert_eq!(Sysno::fsmount.id() as libc::c_long, libc::SYS_fsmount);
+    assert_eq!(Sysno::fsopen.id() as libc::c_long, libc::SYS_fsopen);
+    assert_eq!(Sysno::fspick.id() as libc::c_long, libc::SYS_fspick);
+    assert_eq!(Sysno::fstat.id() as libc::c_long, libc::SYS_fstat);
+    assert_eq!(Sysno::fstatfs.id() as libc::c_long, libc::SYS_fstatfs);
+    assert_eq!(Sysno::fstatfs64.id() as libc::c_long, libc::SYS_fstatfs64);
+    assert_eq!(Sysno::fsync.id() as libc::c_long, libc::SYS_fsync);
+    assert_eq!(Sysno::ftruncate.id() as libc::c_long, libc::SYS_ftruncate);
+    assert_eq!(Sysno::futex.id() as libc::c_long, libc::SYS_futex);
+    assert_eq!(
+        Sysno::futex_waitv.id() as libc::c_long,
+        libc::SYS_futex_waitv
+    );
+    assert_eq!(Sysno::futimesat.id() as libc::c_long, libc::SYS_futimesat);
+    assert_eq!(
+        Sysno::get_mempolicy.id() as libc::c_long,
+        libc::SYS_get_mempolicy
+    );
+    assert_eq!(
+        Sysno::get_robust_list.id() as libc::c_long,
+        libc::SYS_get_robust_list
+    );
+    assert_eq!(Sysno::getcpu.id() as libc::c_long, libc::SYS_getcpu);
+    assert_eq!(Sysno::getcwd.id() as libc::c_long, libc::SYS_getcwd);
+    assert_eq!(Sysno::getdents.id() as libc::c_long, libc::SYS_getdents);
+    assert_eq!(Sysno::getdents64.id() as libc::c_long, libc::SYS_getdents64);
+    assert_eq!(Sysno::getegid.id() as libc::c_long, libc::SYS_getegid);
+    assert_eq!(Sysno::geteuid.id() as libc::c_long, libc::SYS_geteuid);
+    assert_eq!(Sysno::getgid.id() as libc::c_long, libc::SYS_getgid);
+    assert_eq!(Sysno::getgroups.id() as libc::c_long, libc::SYS_getgroups);
+    assert_eq!(Sysno::getitimer.id() as libc::c_long, libc::SYS_getitimer);
+    assert_eq!(
+        Sysno::getpeername.id() as libc::c_long,
+        libc::SYS_getpeername
+    );
+    assert_eq!(Sysno::getpgid.id() as libc::c_long, libc::SYS_getpgid);
+    assert_eq!(Sysno::getpgrp.id() as libc::c_long, libc::SYS_getpgrp);
+    assert_eq!(Sysno::getpid.id() as libc::c_long, libc::SYS_getpid);
+    assert_eq!(Sysno::getpmsg.id() as libc::c_long, libc::SYS_getpmsg);
+    assert_eq!(Sysno::getppid.id() as libc::c_long, libc::SYS_getppid);
+    assert_eq!(
+        Sysno::getpriority.id() as libc::c_long,
+        libc::SYS_getpriority
+    );
+    assert_eq!(Sysno::getrandom.id() as libc::c_long, libc::SYS_getrandom);
+    assert_eq!(Sysno::getresgid.id() as libc::c_long, libc::SYS_getresgid);
+    assert_eq!(Sysno::getresuid.id() as libc::c_long, libc::SYS_getresuid);
+    assert_eq!(Sysno::getrlimit.id() as libc::c_long, libc::SYS_getrlimit);
+    assert_eq!(Sysno::getrusage.id() as libc::c_long, libc::SYS_getrusage);
+    assert_eq!(Sysno::getsid.id() as libc::c_long, libc::SYS_getsid);
+    assert_eq!(
+        Sysno::getsockname.id() as libc::c_long,
+        libc::SYS_getsockname
+    );
+    assert_eq!(Sysno::getsockopt.id() as libc::c_long, libc::SYS_getsockopt);
+    assert_eq!(Sysno::gettid.id() as libc::c_long, libc::SYS_gettid);
+    assert_eq!(
+        Sysno::gettimeofday.id() as libc::c_long,
+        libc::SYS_gettimeofday
+    );
+    assert_eq!(Sysno::getuid.id() as libc::c_long, libc::SYS_getuid);
+    assert_eq!(Sysno::getxattr.id() as libc::c_long, libc::SYS_getxattr);
+    assert_eq!(Sysno::idle.id() as libc::c_long, libc::SYS_idle);
+    assert_eq!(
+        Sysno::init_module.id() as libc::c_long,
+        libc::SYS_init_module
+    );
+    assert_eq!(
+        Sysno::inotify_add_watch.id() as libc::c_long,
+        libc::SYS_inotify_add_watch
+    );
+    assert_eq!(
+        Sysno::inotify_init.id() as libc::c_long,
+        libc::SYS_inotify_init
+    );
+    assert_eq!(
+        Sysno::inotify_init1.id() as libc::c_long,
+        libc::SYS_inotify_init1
+    );
+    assert_eq!(
+        Sysno::inotify_rm_watch.id() as libc::c_long,
+        libc::SYS_inotify_rm_watch
+    );
+    assert_eq!(Sysno::io_cancel.id() as libc::c_long, libc::SYS_io_cancel);
+    assert_eq!(Sysno::io_destroy.id() as libc::c_long, libc::SYS_io_destroy);
+    assert_eq!(
+        Sysno::io_getevents.id() as libc::c_long,
+        libc::SYS_io_getevents
+    );
+    assert_eq!(Sysno::io_setup.id() as libc::c_long, libc::SYS_io_setup);
+    assert_eq!(Sysno::io_submit.id() as libc::c_long, libc::SYS_io_submit);
+    assert_eq!(
+        Sysno::io_uring_enter.id() as libc::c_long,
+        libc::SYS_io_uring_enter
+    );
+    assert_eq!(
+        Sysno::io_uring_register.id() as libc::c_long,
+        libc::SYS_io_uring_register
+    );
+    assert_eq!(
+        Sysno::io_uring_setup.id() as libc::c_long,
+        libc::SYS_io_uring_setup
+    );
+    assert_eq!(Sysno::ioctl.id() as libc::c_long, libc::SYS_ioctl);
+    assert_eq!(Sysno::ioprio_get.id() as libc::c_long, libc::SYS_ioprio_get);
+    assert_eq!(Sysno::ioprio_set.id() as libc::c_long, libc::SYS_ioprio_set);
+    assert_eq!(Sysno::ipc.id() as libc::c_long, libc::SYS_ipc);
+    assert_eq!(Sysno::kcmp.id() as libc::c_long, libc::SYS_kcmp);
+    assert_eq!(Sysno::kexec_load.id() as libc::c_long, libc::SYS_kexec_load);
+    assert_eq!(Sysno::keyctl.id() as libc::c_long, libc::SYS_keyctl);
+    assert_eq!(Sysno::kill.id() as libc::c_long, libc::SYS_kill);
+    assert_eq!(
+        Sysno::landlock_add_rule.id() as libc::c_long,
+        libc::SYS_landlock_add_rule
+    );
+    assert_eq!(
+        Sysno::landlock_create_ruleset.id() as libc::c_long,
+        libc::SYS_landlock_create_ruleset
+    );
+    assert_eq!(
+        Sysno::landlock_restrict_self.id() as libc::c_long,
+        libc::SYS_landlock_restrict_self
+    );
+    assert_eq!(Sysno::lchown.id() as libc::c_long, libc::SYS_lchown);
+    assert_eq!(Sysno::lgetxattr.id() as libc::c_long, libc::SYS_lgetxattr);
+    assert_eq!(Sysno::link.id() as libc::c_long, libc::SYS_link);
+    assert_eq!(Sysno::linkat.id() as libc::c_long, libc::SYS_linkat);
+    assert_eq!(Sysno::listen.id() as libc::c_long, libc::SYS_listen);
+    assert_eq!(Sysno::listxattr.id() as libc::c_long, libc::SYS_listxattr);
+    assert_eq!(Sysno::llistxattr.id() as libc::c_long, libc::SYS_llistxattr);
+    assert_eq!(
+        Sysno::lookup_dcookie.id() as libc::c_long,
+        libc::SYS_lookup_dcookie
+    );
+    assert_eq!(
+        Sysno::lremovexattr.id() as libc::c_long,
+        libc::SYS_lremovexattr
+    );
+    assert_eq!(Sysno::lseek.id() as libc::c_long, libc::SYS_lseek);
+    assert_eq!(Sysno::lsetxattr.id() as libc::c_long, libc::SYS_lsetxattr);
+    assert_eq!(Sysno::lstat.id() as libc::c_long, libc::SYS_lstat);
+    assert_eq!(Sysno::madvise.id() as libc::c_long, libc::SYS_madvise);
+    assert_eq!(Sysno::mbind.id() as libc::c_long, libc::SYS_mbind);
+    assert_eq!(Sysno::membarrier.id() as libc::c_long, libc::SYS_membarrier);
+    assert_eq!(
+        Sysno::memfd_create.id() as libc::c_long,
+        libc::SYS_memfd_create
+    );
+    assert_eq!(
+        Sysno::memfd_secret.id() as libc::c_long,
+        libc::SYS_memfd_secret
+    );
+    assert_eq!(
+        Sysno::migrate_pages.id() as libc::c_long,
+        libc::SYS_migrate_pages
+    );
+    assert_eq!(Sysno::mincore.id() as libc::c_long, libc::SYS_mincore);
+    assert_eq!(Sysno::mkdir.id() as libc::c_long, libc::SYS_mkdir);
+    assert_eq!(Sysno::mkdirat.id() as libc::c_long, libc::SYS_mkdirat);
+    assert_eq!(Sysno::mknod.id() as libc::c_long, libc::SYS_mknod);
+    assert_eq!(Sysno::mknodat.id() as libc::c_long, libc::SYS_mknodat);
+    assert_eq!(Sysno::mlock.id() as libc::c_long, libc::SYS_mlock);
+    assert_eq!(Sysno::mlock2.id() as libc::c_long, libc::SYS_mlock2);
+    assert_eq!(Sysno::mlockall.id() as libc::c_long, libc::SYS_mlockall);
+    assert_eq!(Sysno::mmap.id() as libc::c_long, libc::SYS_mmap);
+    assert_eq!(Sysno::mount.id() as libc::c_long, libc::SYS_mount);
+    assert_eq!(
+        Sysno::mount_setattr.id() as libc::c_long,
+        libc::SYS_mount_setattr
+    );
+    assert_eq!(Sysno::move_mount.id() as libc::c_long, libc::SYS_move_mount);
+    assert_eq!(Sysno::move_pages.id() as libc::c_long, libc::SYS_move_pages);
+    assert_eq!(Sysno::mprotect.id() as libc::c_long, libc::SYS_mprotect);
+    assert_eq!(
+        Sysno::mq_getsetattr.id() as libc::c_long,
+        libc::SYS_mq_getsetattr
+    );
+    assert_eq!(Sysno::mq_notify.id() as libc::c_long, libc::SYS_mq_notify);
+    assert_eq!(Sysno::mq_open.id() as libc::c_long, libc::SYS_mq_open);
+    assert_eq!(
+        Sysno::mq_timedreceive.id() as libc::c_long,
+        libc::SYS_mq_timedreceive
+    );
+    assert_eq!(
+        Sysno::mq_timedsend.id() as libc::c_long,
+        libc::SYS_mq_timedsend
+    );
+    assert_eq!(Sysno::mq_unlink.id() as libc::c_long, libc::SYS_mq_unlink);
+    assert_eq!(Sysno::mremap.id() as libc::c_long, libc::SYS_mremap);
+    assert_eq!(Sysno::mseal.id() as libc::c_long, libc::SYS_mseal);
+    assert_eq!(Sysno::msync.id() as libc::c_long, libc::SYS_msync);
+    assert_eq!(Sysno::munlock.id() as libc::c_long, libc::SYS_munlock);
+    assert_eq!(Sysno::munlockall.id() as libc::c_long, libc::SYS_munlockall);
+    assert_eq!(Sysno::munmap.id() as libc::c_long, libc::SYS_munmap);
+    assert_eq!(
+        Sysno::name_to_handle_at.id() as libc::c_long,
+        libc::SYS_name_to_handle_at
+    );
+    assert_eq!(Sysno::nanosleep.id() as libc::c_long, libc::SYS_nanosleep);
+    assert_eq!(Sysno::newfstatat.id() as libc::c_long, libc::SYS_newfstatat);
+    assert_eq!(Sysno::nfsservctl.id() as libc::c_long, libc::SYS_nfsservctl);
+    assert_eq!(Sysno::nice.id() as libc::c_long, libc::SYS_nice);
+    assert_eq!(Sysno::open.id() as libc::c_long, libc::SYS_open);
+    assert_eq!(
+        Sysno::open_by_handle_at.id() as libc::c_long,
+        libc::SYS_open_by_handle_at
+    );
+    assert_eq!(Sysno::open_tree.id() as libc::c_long, libc::SYS_open_tree);
+    assert_eq!(Sysno::openat.id() as libc::c_long, libc::SYS_openat);
+    assert_eq!(Sysno::openat2.id() as libc::c_long, libc::SYS_openat2);
+    assert_eq!(Sysno::pause.id() as libc::c_long, libc::SYS_pause);
+    assert_eq!(
+        Sysno::perf_event_open.id() as libc::c_long,
+        libc::SYS_perf_event_open
+    );
+    assert_eq!(
+        Sysno::personality.id() as libc::c_long,
+        libc::SYS_personality
+    );
+    assert_eq!(
+        Sysno::pidfd_getfd.id() as libc::c_long,
+        libc::SYS_pidfd_getfd
+    );
+    assert_eq!(Sysno::pidfd_open.id() as libc::c_long, libc::SYS_pidfd_open);
+    assert_eq!(
+        Sysno::pidfd_send_signal.id() as libc::c_long,
+        libc::SYS_pidfd_send_signal
+    );
+    assert_eq!(Sysno::pipe.id() as libc::c_long, libc::SYS_pipe);
+    assert_eq!(Sysno::pipe2.id() as libc::c_long, libc::SYS_pipe2);
+    assert_eq!(Sysno::pivot_root.id() as libc::c_long, libc::SYS_pivot_root);
+    assert_eq!(Sysno::poll.id() as libc::c_long, libc::SYS_poll);
+    assert_eq!(Sysno::ppoll.id() as libc::c_long, libc::SYS_ppoll);
+    assert_eq!(Sysno::prctl.id() as libc::c_long, libc::SYS_prctl);
+    assert_eq!(Sysno::pread64.id() as libc::c_long, libc::SYS_pread64);
+    assert_eq!(Sysno::preadv.id() as libc::c_long, libc::SYS_preadv);
+    assert_eq!(Sysno::preadv2.id() as libc::c_long, libc::SYS_preadv2);
+    assert_eq!(Sysno::prlimit64.id() as libc::c_long, libc::SYS_prlimit64);
+    assert_eq!(
+        Sysno::process_madvise.id() as libc::c_long,
+        libc::SYS_process_madvise
+    );
+    assert_eq!(
+        Sysno::process_mrelease.id() as libc::c_long,
+        libc::SYS_process_mrelease
+    );
+    assert_eq!(
+        Sysno::process_vm_readv.id() as libc::c_long,
+        libc::SYS_process_vm_readv
+    );
+    assert_eq!(
+        Sysno::process_vm_writev.id() as libc::c_long,
+        libc::SYS_process_vm_writev
+    );
+    assert_eq!(Sysno::pselect6.id() as libc::c_long, libc::SYS_pselect6);
+    assert_eq!(Sysno::ptrace.id() as libc::c_long, libc::SYS_ptrace);
+    assert_eq!(Sysno::putpmsg.id() as libc::c_long, libc::SYS_putpmsg);
+    assert_eq!(Sysno::pwrite64.id() as libc::c_long, libc::SYS_pwrite64);
+    assert_eq!(Sysno::pwritev.id() as libc::c_long, libc::SYS_pwritev);
+    assert_eq!(Sysno::pwritev2.id() as libc::c_long, libc::SYS_pwritev2);
+    assert_eq!(Sysno::quotactl.id() as libc::c_long, libc::SYS_quotactl);
+    assert_eq!(
+        Sysno::quotactl_fd.id() as libc::c_long,
+        libc::SYS_quotactl_fd
+    );
+    assert_eq!(Sysno::read.id() as libc::c_long, libc::SYS_read);
+    assert_eq!(Sysno::readahead.id() as libc::c_long, libc::SYS_readahead);
+    assert_eq!(Sysno::readdir.id() as libc::c_long, libc::SYS_readdir);
+    assert_eq!(Sysno::readlink.id() as libc::c_long, libc::SYS_readlink);
+    assert_eq!(Sysno::readlinkat.id() as libc::c_long, libc::SYS_readlinkat);
+    assert_eq!(Sysno::readv.id() as libc::c_long, libc::SYS_readv);
+    assert_eq!(Sysno::reboot.id() as libc::c_long, libc::SYS_reboot);
+    assert_eq!(Sysno::recvfrom.id() as libc::c_long, libc::SYS_recvfrom);
+    assert_eq!(Sysno::recvmmsg.id() as libc::c_long, libc::SYS_recvmmsg);
+    assert_eq!(Sysno::recvmsg.id() as libc::c_long, libc::SYS_recvmsg);
+    assert_eq!(
+        Sysno::remap_file_pages.id() as libc::c_long,
+        libc::SYS_remap_file_pages
+    );
+    assert_eq!(
+        Sysno::removexattr.id() as libc::c_long,
+        libc::SYS_removexattr
+    );
+    assert_eq!(Sysno::rename.id() as libc::c_long, libc::SYS_rename);
+    assert_eq!(Sysno::renameat.id() as libc::c_long, libc::SYS_renameat);
+    assert_eq!(Sysno::renameat2.id() as libc::c_long, libc::SYS_renameat2);
+    assert_eq!(
+        Sysno::request_key.id() as libc::c_long,
+        libc::SYS_request_key
+    );
+    assert_eq!(
+        Sysno::restart_syscall.id() as libc::c_long,
+        libc::SYS_restart_syscall
+    );
+    assert_eq!(Sysno::rmdir.id() as libc::c_long, libc::SYS_rmdir);
+    assert_eq!(Sysno::rseq.id() as libc::c_long, libc::SYS_rseq);
+    assert_eq!(
+        Sysno::rt_sigaction.id() as libc::c_long,
+        libc::SYS_rt_sigaction
+    );
+    assert_eq!(
+        Sysno::rt_sigpending.id() as libc::c_long,
+        libc::SYS_rt_sigpending
+    );
+    assert_eq!(
+        Sysno::rt_sigprocmask.id() as libc::c_long,
+        libc::SYS_rt_sigprocmask
+    );
+    assert_eq!(
+        Sysno::rt_sigqueueinfo.id() as libc::c_long,
+        libc::SYS_rt_sigqueueinfo
+    );
+    assert_eq!(
+        Sysno::rt_sigreturn.id() as libc::c_long,
+        libc::SYS_rt_sigreturn
+    );
+    assert_eq!(
+        Sysno::rt_sigsuspend.id() as libc::c_long,
+        libc::SYS_rt_sigsuspend
+    );
+    assert_eq!(
+        Sysno::rt_sigtimedwait.id() as libc::c_long,
+        libc::SYS_rt_sigtimedwait
+    );
+    assert_eq!(
+        Sysno::rt_tgsigqueueinfo.id() as libc::c_long,
+        libc::SYS_rt_tgsigqueueinfo
+    );
+    assert_eq!(
+        Sysno::s390_pci_mmio_read.id() as libc::c_long,
+        libc::SYS_s390_pci_mmio_read
+    );
+    assert_eq!(
+        Sysno::s390_pci_mmio_write.id() as libc::c_long,
+        libc::SYS_s390_pci_mmio_write
+    );
+    assert_eq!(
+        Sysno::s390_runtime_instr.id() as libc::c_long,
+        libc::SYS_s390_runtime_instr
+    );
+    assert_eq!(
+        Sysno::sched_get_priority_max.id() as libc::c_long,
+        libc::SYS_sched_get_priority_max
+    );
+    assert_eq!(
+        Sysno::sched_get_priority_min.id() as libc::c_long,
+        libc::SYS_sched_get_priority_min
+    );
+    assert_eq!(
+        Sysno::sched_getaffinity.id() as libc::c_long,
+        libc::SYS_sched_getaffinity
+    );
+    assert_eq!(
+        Sysno::sched_getattr.id() as libc::c_long,
+        libc::SYS_sched_getattr
+    );
+    assert_eq!(
+        Sysno::sched_getparam.id() as libc::c_long,
+        libc::SYS_sched_getparam
+    );
+    assert_eq!(
+        Sysno::sched_getscheduler.id() as libc::c_long,
+        libc::SYS_sched_getscheduler
+    );
+    assert_eq!(
+        Sysno::sched_rr_get_interval.id() as libc::c_long,
+        libc::SYS_sched_rr_get_interval
+    );
+    assert_eq!(
+        Sysno::sched_setaffinity.id() as libc::c_long,
+        libc::SYS_sched_setaffinity
+    );
+    assert_eq!(
+        Sysno::sched_setattr.id() as libc::c_long,
+        libc::SYS_sched_setattr
+    );
+    assert_eq!(
+        Sysno::sched_setparam.id() as libc::c_long,
+        libc::SYS_sched_setparam
+    );
+    assert_eq!(
+        Sysno::sched_setscheduler.id() as libc::c_long,
+        libc::SYS_sched_setscheduler
+    );
+    assert_eq!(
+        Sysno::sched_yield.id() as libc::c_long,
+        libc::SYS_sched_yield
+    );
+    assert_eq!(Sysno::seccomp.id() as libc::c_long, libc::SYS_seccomp);
+    assert_eq!(Sysno::select.id() as libc::c_long, libc::SYS_select);
+    assert_eq!(Sysno::sendfile.id() as libc::c_long, libc::SYS_sendfile);
+    assert_eq!(Sysno::sendmmsg.id() as libc::c_long, libc::SYS_sendmmsg);
+    assert_eq!(Sysno::sendmsg.id() as libc::c_long, libc::SYS_sendmsg);
+    assert_eq!(Sysno::sendto.id() as libc::c_long, libc::SYS_sendto);
+    assert_eq!(
+        Sysno::set_mempolicy.id() as libc::c_long,
+        libc::SYS_set_mempolicy
+    );
+    assert_eq!(
+        Sysno::set_mempolicy_home_node.id() as libc::c_long,
+        libc::SYS_set_mempolicy_home_node
+    );
+    assert_eq!(
+        Sysno::set_robust_list.id() as libc::c_long,
+        libc::SYS_set_robust_list
+    );
+    assert_eq!(
+        Sysno::set_tid_address.id() as libc::c_long,
+        libc::SYS_set_tid_address
+    );
+    assert_eq!(
+        Sysno::setdomainname.id() as libc::c_long,
+        libc::SYS_setdomainname
+    );
+    assert_eq!(Sysno::setfsgid.id() as libc::c_long, libc::SYS_setfsgid);
+    assert_eq!(Sysno::setfsuid.id() as libc::c_long, libc::SYS_setfsuid);
+    assert_eq!(Sysno::setgid.id() as libc::c_long, libc::SYS_setgid);
+    assert_eq!(Sysno::setgroups.id() as libc::c_long, libc::SYS_setgroups);
+    assert_eq!(
+        Sysno::sethostname.id() as libc::c_long,
+        libc::SYS_sethostname
+    );
+    assert_eq!(Sysno::setitimer.id() as libc::c_long, libc::SYS_setitimer);
+    assert_eq!(Sysno::setns.id() as libc::c_long, libc::SYS_setns);
+    assert_eq!(Sysno::setpgid.id() as libc::c_long, libc::SYS_setpgid);
+    assert_eq!(
+        Sysno::setpriority.id() as libc::c_long,
+        libc::SYS_setpriority
+    );
+    assert_eq!(Sysno::setregid.id() as libc::c_long, libc::SYS_setregid);
+    assert_eq!(Sysno::setresgid.id() as libc::c_long, libc::SYS_setresgid);
+    assert_eq!(Sysno::setresuid.id() as libc::c_long, libc::SYS_setresuid);
+    assert_eq!(Sysno::setreuid.id() as libc::c_long, libc::SYS_setreuid);
+    assert_eq!(Sysno::setrlimit.id() as libc::c_long, libc::SYS_setrlimit);
+    assert_eq!(Sysno::setsid.id() as libc::c_long, libc::SYS_setsid);
+    assert_eq!(Sysno::setsockopt.id() as libc::c_long, libc::SYS_setsockopt);
+    assert_eq!(
+        Sysno::settimeofday.id() as libc::c_long,
+        libc::SYS_settimeofday
+    );
+    assert_eq!(Sysno::setuid.id() as libc::c_long, libc::SYS_setuid);
+    assert_eq!(Sysno::setxattr.id() as libc::c_long, libc::SYS_setxattr);
+    assert_eq!(Sysno::shutdown.id() as libc::c_long, libc::SYS_shutdown);
+    assert_eq!(Sysno::sigaction.id() as libc::c_long, libc::SYS_sigaction);
+    assert_eq!(
+        Sysno::sigaltstack.id() as libc::c_long,
+        libc::SYS_sigaltstack
+    );
+    assert_eq!(Sysno::signal.id() as libc::c_long, libc::SYS_signal);
+    assert_eq!(Sysno::signalfd.id() as libc::c_long, libc::SYS_signalfd);
+    assert_eq!(Sysno::signalfd4.id() as libc::c_long, libc::SYS_signalfd4);
+    assert_eq!(Sysno::sigpending.id() as libc::c_long, libc::SYS_sigpending);
+    assert_eq!(
+        Sysno::sigprocmask.id() as libc::c_long,
+        libc::SYS_sigprocmask
+    );
+    assert_eq!(Sysno::sigreturn.id() as libc::c_long, libc::SYS_sigreturn);
+    assert_eq!(Sysno::sigsuspend.id() as libc::c_long, libc::SYS_sigsuspend);
+    assert_eq!(Sysno::socket.id() as libc::c_long, libc::SYS_socket);
+    assert_eq!(Sysno::socketcall.id() as libc::c_long, libc::SYS_socketcall);
+    assert_eq!(Sysno::socketpair.id() as libc::c_long, libc::SYS_socketpair);
+    assert_eq!(Sysno::splice.id() as libc::c_long, libc::SYS_splice);
+    assert_eq!(Sysno::stat.id() as libc::c_long, libc::SYS_stat);
+    assert_eq!(Sysno::statfs.id() as libc::c_long, libc::SYS_statfs);
+    assert_eq!(Sysno::statfs64.id() as libc::c_long, libc::SYS_statfs64);
+    assert_eq!(Sysno::statx.id() as libc::c_long, libc::SYS_statx);
+    assert_eq!(Sysno::swapoff.id() as libc::c_long, libc::SYS_swapoff);
+    assert_eq!(Sysno::swapon.id() as libc::c_long, libc::SYS_swapon);
+    assert_eq!(Sysno::symlink.id() as libc::c_long, libc::SYS_symlink);
+    assert_eq!(Sysno::symlinkat.id() as libc::c_long, libc::SYS_symlinkat);
+    assert_eq!(Sysno::sync.id() as libc::c_long, libc::SYS_sync);
+    assert_eq!(
+        Sysno::sync_file_range.id() as libc::c_long,
+        libc::SYS_sync_file_range
+    );
+    assert_eq!(Sysno::syncfs.id() as libc::c_long, libc::SYS_syncfs);
+    assert_eq!(Sysno::sysfs.id() as libc::c_long, libc::SYS_sysfs);
+    assert_eq!(Sysno::sysinfo.id() as libc::c_long, libc::SYS_sysinfo);
+    assert_eq!(Sysno::syslog.id() as libc::c_long, libc::SYS_syslog);
+    assert_eq!(Sysno::tee.id() as libc::c_long, libc::SYS_tee);
+    assert_eq!(Sysno::tgkill.id() as libc::c_long, libc::SYS_tgkill);
+    assert_eq!(
+        Sysno::timer_create.id() as libc::c_long,
+        libc::SYS_timer_create
+    );
+    assert_eq!(
+        Sysno::timer_delete.id() as libc::c_long,
+        libc::SYS_timer_delete
+    );
+    assert_eq!(
+        Sysno::timer_getoverrun.id() as libc::c_long,
+        libc::SYS_timer_getoverrun
+    );
+    assert_eq!(
+        Sysno::timer_gettime.id() as libc::c_long,
+        libc::SYS_timer_gettime
+    );
+    assert_eq!(
+        Sysno::timer_settime.id() as libc::c_long,
+        libc::SYS_timer_settime
+    );
+    assert_eq!(Sysno::timerfd.id() as libc::c_long, libc::SYS_timerfd);
+    assert_eq!(
+        Sysno::timerfd_create.id() as libc::c_long,
+        libc::SYS_timerfd_create
+    );
+    assert_eq!(
+        Sysno::timerfd_gettime.id() as libc::c_long,
+        libc::SYS_timerfd_gettime
+    );
+    assert_eq!(
+        Sysno::timerfd_settime.id() as libc::c_long,
+        libc::SYS_timerfd_settime
+    );
+    assert_eq!(Sysno::times.id() as libc::c_long, libc::SYS_times);
+    assert_eq!(Sysno::tkill.id() as libc::c_long, libc::SYS_tkill);
+    assert_eq!(Sysno::truncate.id() as libc::c_long, libc::SYS_truncate);
+    assert_eq!(Sysno::umask.id() as libc::c_long, libc::SYS_umask);
+    assert_eq!(Sysno::umount.id() as libc::c_long, libc::SYS_umount);
+    assert_eq!(Sysno::umount2.id() as libc::c_long, libc::SYS_umount2);
+    assert_eq!(Sysno::uname.id() as libc::c_long, libc::SYS_uname);
+    assert_eq!(Sysno::unlink.id() as libc::c_long, libc::SYS_unlink);
+    assert_eq!(Sysno::unlinkat.id() as libc::c_long, libc::SYS_unlinkat);
+    assert_eq!(Sysno::unshare.id() as libc::c_long, libc::SYS_unshare);
+    assert_eq!(Sysno::uselib.id() as libc::c_long, libc::SYS_uselib);
+    assert_eq!(
+        Sysno::userfaultfd.id() as libc::c_long,
+        libc::SYS_userfaultfd
+    );
+    assert_eq!(Sysno::ustat.id() as libc::c_long, libc::SYS_ustat);
+    assert_eq!(Sysno::utime.id() as libc::c_long, libc::SYS_utime);
+    assert_eq!(Sysno::utimensat.id() as libc::c_long, libc::SYS_utimensat);
+    assert_eq!(Sysno::utimes.id() as libc::c_long, libc::SYS_utimes);
+    assert_eq!(Sysno::vfork.id() as libc::c_long, libc::SYS_vfork);
+    assert_eq!(Sysno::vhangup.id() as libc::c_long, libc::SYS_vhangup);
+    assert_eq!(Sysno::vmsplice.id() as libc::c_long, libc::SYS_vmsplice);
+    assert_eq!(Sysno::wait4.id() as libc::c_long, libc::SYS_wait4);
+    assert_eq!(Sysno::waitid.id() as libc::c_long, libc::SYS_waitid);
+    assert_eq!(Sysno::write.id() as libc::c_long, libc::SYS_write);
+    assert_eq!(Sysno::writev.id() as libc::c_long, libc::SYS_writev);
+}
+
+#[cfg(all(target_arch = "sparc", target_pointer_width = "32"))]
+#[test]
+fn sparc() {
+    use rawsys_linux::sparc::v6_12::Sysno;
+
+    assert_eq!(Sysno::_llseek.id() as libc::c_long, libc::SYS__llseek);
+    assert_eq!(Sysno::_newselect.id() as libc::c_long, libc::SYS__newselect);
+    assert_eq!(Sysno::_sysctl.id() as libc::c_long, libc::SYS__sysctl);
+    assert_eq!(Sysno::accept.id() as libc::c_long, libc::SYS_accept);
+    assert_eq!(Sysno::accept4.id() as libc::c_long, libc::SYS_accept4);
+    assert_eq!(Sysno::access.id() as libc::c_long, libc::SYS_access);
+    assert_eq!(Sysno::acct.id() as libc::c_long, libc::SYS_acct);
+    assert_eq!(Sysno::add_key.id() as libc::c_long, libc::SYS_add_key);
+    assert_eq!(Sysno::adjtimex.id() as libc::c_long, libc::SYS_adjtimex);
+    assert_eq!(
+        Sysno::afs_syscall.id() as libc::c_long,
+        libc::SYS_afs_syscall
+    );
+    assert_eq!(Sysno::alarm.id() as libc::c_long, libc::SYS_alarm);
+    assert_eq!(Sysno::bdflush.id() as libc::c_long, libc::SYS_bdflush);
+    assert_eq!(Sysno::bind.id() as libc::c_long, libc::SYS_bind);
+    assert_eq!(Sysno::bpf.id() as libc::c_long, libc::SYS_bpf);
+    assert_eq!(Sysno::brk.id() as libc::c_long, libc::SYS_brk);
+    assert_eq!(Sysno::capget.id() as libc::c_long, libc::SYS_capget);
+    assert_eq!(Sysno::capset.id() as libc::c_long, libc::SYS_capset);
+    assert_eq!(Sysno::chdir.id() as libc::c_long, libc::SYS_chdir);
+    assert_eq!(Sysno::chmod.id() as libc::c_long, libc::SYS_chmod);
+    assert_eq!(Sysno::chown.id() as libc::c_long, libc::SYS_chown);
+    assert_eq!(Sysno::chown32.id() as libc::c_long, libc::SYS_chown32);
+    assert_eq!(Sysno::chroot.id() as libc::c_long, libc::SYS_chroot);
+    assert_eq!(
+        Sysno::clock_adjtime.id() as libc::c_long,
+        libc::SYS_clock_adjtime
+    );
+    assert_eq!(
+        Sysno::clock_getres.id() as libc::c_long,
+        libc::SYS_clock_getres
+    );
+    assert_eq!(
+        Sysno::clock_gettime.id() as libc::c_long,
+        libc::SYS_clock_gettime
+    );
+    assert_eq!(
+        Sysno::clock_nanosleep.id() as libc::c_long,
+        libc::SYS_clock_nanosleep
+    );
+    assert_eq!(
+        Sysno::clock_settime.id() as libc::c_long,
+        libc::SYS_clock_settime
+    );
+    assert_eq!(Sysno::clone.id() as libc::c_long, libc::SYS_clone);
+    assert_eq!(Sysno::close.id() as libc::c_long, libc::SYS_close);
+    assert_eq!(
+        Sysno::close_range.id() as libc::c_long,
+        libc::SYS_close_range
+    );
+    assert_eq!(Sysno::connect.id() as libc::c_long, libc::SYS_connect);
+    assert_eq!(
+        Sysno::copy_file_range.id() as libc::c_long,
+        libc::SYS_copy_file_range
+    );
+    assert_eq!(Sysno::creat.id() as libc::c_long, libc::SYS_creat);
+    assert_eq!(
+        Sysno::delete_module.id() as libc::c_long,
+        libc::SYS_delete_module
+    );
+    assert_eq!(Sysno::dup.id() as libc::c_long, libc::SYS_dup);
+    assert_eq!(Sysno::dup2.id() as libc::c_long, libc::SYS_dup2);
+    assert_eq!(Sysno::dup3.id() as libc::c_long, libc::SYS_dup3);
+    assert_eq!(
+        Sysno::epoll_create.id() as libc::c_long,
+        libc::SYS_epoll_create
+    );
+    assert_eq!(
+        Sysno::epoll_create1.id() as libc::c_long,
+        libc::SYS_epoll_create1
+    );
+    assert_eq!(Sysno::epoll_ctl.id() as libc::c_long, libc::SYS_epoll_ctl);
+    assert_eq!(
+        Sysno::epoll_pwait.id() as libc::c_long,
+        libc::SYS_epoll_pwait
+    );
+    assert_eq!(
+        Sysno::epoll_pwait2.id() as libc::c_long,
+        libc::SYS_epoll_pwait2
+    );
+    assert_eq!(Sysno::epoll_wait.id() as libc::c_long, libc::SYS_epoll_wait);
+    assert_eq!(Sysno::eventfd.id() as libc::c_long, libc::SYS_eventfd);
+    assert_eq!(Sysno::eventfd2.id() as libc::c_long, libc::SYS_eventfd2);
+    assert_eq!(Sysno::execv.id() as libc::c_long, libc::SYS_execv);
+    assert_eq!(Sysno::execve.id() as libc::c_long, libc::SYS_execve);
+    assert_eq!(Sysno::execveat.id() as libc::c_long, libc::SYS_execveat);
+    assert_eq!(Sysno::exit.id() as libc::c_long, libc::SYS_exit);
+    assert_eq!(Sysno::exit_group.id() as libc::c_long, libc::SYS_exit_group);
+    assert_eq!(Sysno::faccessat.id() as libc::c_long, libc::SYS_faccessat);
+    assert_eq!(Sysno::faccessat2.id() as libc::c_long, libc::SYS_faccessat2);
+    assert_eq!(Sysno::fadvise64.id() as libc::c_long, libc::SYS_fadvise64);
+    assert_eq!(
+        Sysno::fadvise64_64.id() as libc::c_long,
+        libc::SYS_fadvise64_64
+    );
+    assert_eq!(Sysno::fallocate.id() as libc::c_long, libc::SYS_fallocate);
+    assert_eq!(
+        Sysno::fanotify_init.id() as libc::c_long,
+        libc::SYS_fanotify_init
+    );
+    assert_eq!(
+        Sysno::fanotify_mark.id() as libc::c_long,
+        libc::SYS_fanotify_mark
+    );
+    assert_eq!(Sysno::fchdir.id() as libc::c_long, libc::SYS_fchdir);
+    assert_eq!(Sysno::fchmod.id() as libc::c_long, libc::SYS_fchmod);
+    assert_eq!(Sysno::fchmodat.id() as libc::c_long, libc::SYS_fchmodat);
+    assert_eq!(Sysno::fchown.id() as libc::c_long, libc::SYS_fchown);
+    assert_eq!(Sysno::fchown32.id() as libc::c_long, libc::SYS_fchown32);
+    assert_eq!(Sysno::fchownat.id() as libc::c_long, libc::SYS_fchownat);
+    assert_eq!(Sysno::fcntl.id() as libc::c_long, libc::SYS_fcntl);
+    assert_eq!(Sysno::fcntl64.id() as libc::c_long, libc::SYS_fcntl64);
+    assert_eq!(Sysno::fdatasync.id() as libc::c_long, libc::SYS_fdatasync);
+    assert_eq!(Sysno::fgetxattr.id() as libc::c_long, libc::SYS_fgetxattr);
+    assert_eq!(
+        Sysno::finit_module.id() as libc::c_long,
+        libc::SYS_finit_module
+    );
+    assert_eq!(Sysno::flistxattr.id() as libc::c_long, libc::SYS_flistxattr);
+    assert_eq!(Sysno::flock.id() as libc::c_long, libc::SYS_flock);
+    assert_eq!(Sysno::fork.id() as libc::c_long, libc::SYS_fork);
+    assert_eq!(
+        Sysno::fremovexattr.id() as libc::c_long,
+        libc::SYS_fremovexattr
+    );
+    assert_eq!(Sysno::fsconfig.id() as libc::c_long, libc::SYS_fsconfig);
+    assert_eq!(Sysno::fsetxattr.id() as libc::c_long, libc::SYS_fsetxattr);
+    assert_eq!(Sysno::fsmount.id() as libc::c_long, libc::SYS_fsmount);
+    assert_eq!(Sysno::fsopen.id() as libc::c_long, libc::SYS_fsopen);
+    assert_eq!(Sysno::fspick.id() as libc::c_long, libc::SYS_fspick);
+    assert_eq!(Sysno::fstat.id() as libc::c_long, libc::SYS_fstat);
+    assert_eq!(Sysno::fstat64.id() as libc::c_long, libc::SYS_fstat64);
+    assert_eq!(Sysno::fstatat64.id() as libc::c_long, libc::SYS_fstatat64);
+    assert_eq!(Sysno::fstatfs.id() as libc::c_long, libc::SYS_fstatfs);
+    assert_eq!(Sysno::fstatfs64.id() as libc::c_long, libc::SYS_fstatfs64);
+    assert_eq!(Sysno::fsync.id() as libc::c_long, libc::SYS_fsync);
+    assert_eq!(Sysno::ftruncate.id() as libc::c_long, libc::SYS_ftruncate);
+    assert_eq!(
+        Sysno::ftruncate64.id() as libc::c_long,
+        libc::SYS_ftruncate64
+    );
+    assert_eq!(Sysno::futex.id() as libc::c_long, libc::SYS_futex);
+    assert_eq!(
+        Sysno::futex_waitv.id() as libc::c_long,
+        libc::SYS_futex_waitv
+    );
+    assert_eq!(Sysno::futimesat.id() as libc::c_long, libc::SYS_futimesat);
+    assert_eq!(
+        Sysno::get_mempolicy.id() as libc::c_long,
+        libc::SYS_get_mempolicy
+    );
+    assert_eq!(
+        Sysno::get_robust_list.id() as libc::c_long,
+        libc::SYS_get_robust_list
+    );
+    assert_eq!(Sysno::getcpu.id() as libc::c_long, libc::SYS_getcpu);
+    assert_eq!(Sysno::getcwd.id() as libc::c_long, libc::SYS_getcwd);
+    assert_eq!(Sysno::getdents.id() as libc::c_long, libc::SYS_getdents);
+    assert_eq!(Sysno::getdents64.id() as libc::c_long, libc::SYS_getdents64);
+    assert_eq!(
+        Sysno::getdomainname.id() as libc::c_long,
+        libc::SYS_getdomainname
+    );
+    assert_eq!(Sysno::getegid.id() as libc::c_long, libc::SYS_getegid);
+    assert_eq!(Sysno::getegid32.id() as libc::c_long, libc::SYS_getegid32);
+    assert_eq!(Sysno::geteuid.id() as libc::c_long, libc::SYS_geteuid);
+    assert_eq!(Sysno::geteuid32.id() as libc::c_long, libc::SYS_geteuid32);
+    assert_eq!(Sysno::getgid.id() as libc::c_long, libc::SYS_getgid);
+    assert_eq!(Sysno::getgid32.id() as libc::c_long, libc::SYS_getgid32);
+    assert_eq!(Sysno::getgroups.id() as libc::c_long, libc::SYS_getgroups);
+    assert_eq!(
+        Sysno::getgroups32.id() as libc::c_long,
+        libc::SYS_getgroups32
+    );
+    assert_eq!(Sysno::getitimer.id() as libc::c_long, libc::SYS_getitimer);
+    assert_eq!(
+        Sysno::getpagesize.id() as libc::c_long,
+        libc::SYS_getpagesize
+    );
+    assert_eq!(
+        Sysno::getpeername.id() as libc::c_long,
+        libc::SYS_getpeername
+    );
+    assert_eq!(Sysno::getpgid.id() as libc::c_long, libc::SYS_getpgid);
+    assert_eq!(Sysno::getpgrp.id() as libc::c_long, libc::SYS_getpgrp);
+    assert_eq!(Sysno::getpid.id() as libc::c_long, libc::SYS_getpid);
+    assert_eq!(Sysno::getppid.id() as libc::c_long, libc::SYS_getppid);
+    assert_eq!(
+        Sysno::getpriority.id() as libc::c_long,
+        libc::SYS_getpriority
+    );
+    assert_eq!(Sysno::getrandom.id() as libc::c_long, libc::SYS_getrandom);
+    assert_eq!(
+        Sysno::getresgid32.id() as libc::c_long,
+        libc::SYS_getresgid32
+    );
+    assert_eq!(
+        Sysno::getresuid32.id() as libc::c_long,
+        libc::SYS_getresuid32
+    );
+    assert_eq!(Sysno::getrlimit.id() as libc::c_long, libc::SYS_getrlimit);
+    assert_eq!(Sysno::getrusage.id() as libc::c_long, libc::SYS_getrusage);
+    assert_eq!(Sysno::getsid.id() as libc::c_long, libc::SYS_getsid);
+    assert_eq!(
+        Sysno::getsockname.id() as libc::c_long,
+        libc::SYS_getsockname
+    );
+    assert_eq!(Sysno::getsockopt.id() as libc::c_long, libc::SYS_getsockopt);
+    assert_eq!(Sysno::gettid.id() as libc::c_long, libc::SYS_gettid);
+    assert_eq!(
+        Sysno::gettimeofday.id() as libc::c_long,
+        libc::SYS_gettimeofday
+    );
+    assert_eq!(Sysno::getuid.id() as libc::c_long, libc::SYS_getuid);
+    assert_eq!(Sysno::getuid32.id() as libc::c_long, libc::SYS_getuid32);
+    assert_eq!(Sysno::getxattr.id() as libc::c_long, libc::SYS_getxattr);
+    assert_eq!(
+        Sysno::init_module.id() as libc::c_long,
+        libc::SYS_init_module
+    );
+    assert_eq!(
+        Sysno::inotify_add_watch.id() as libc::c_long,
+        libc::SYS_inotify_add_watch
+    );
+    assert_eq!(
+        Sysno::inotify_init.id() as libc::c_long,
+        libc::SYS_inotify_init
+    );
+    assert_eq!(
+        Sysno::inotify_init1.id() as libc::c_long,
+        libc::SYS_inotify_init1
+    );
+    assert_eq!(
+        Sysno::inotify_rm_watch.id() as libc::c_long,
+        libc::SYS_inotify_rm_watch
+    );
+    assert_eq!(Sysno::io_cancel.id() as libc::c_long, libc::SYS_io_cancel);
+    assert_eq!(Sysno::io_destroy.id() as libc::c_long, libc::SYS_io_destroy);
+    assert_eq!(
+        Sysno::io_getevents.id() as libc::c_long,
+        libc::SYS_io_getevents
+    );
+    assert_eq!(Sysno::io_setup.id() as libc::c_long, libc::SYS_io_setup);
+    assert_eq!(Sysno::io_submit.id() as libc::c_long, libc::SYS_io_submit);
+    assert_eq!(
+        Sysno::io_uring_enter.id() as libc::c_long,
+        libc::SYS_io_uring_enter
+    );
+    assert_eq!(
+        Sysno::io_uring_register.id() as libc::c_long,
+        libc::SYS_io_uring_register
+    );
+    assert_eq!(
+        Sysno::io_uring_setup.id() as libc::c_long,
+        libc::SYS_io_uring_setup
+    );
+    assert_eq!(Sysno::ioctl.id() as libc::c_long, libc::SYS_ioctl);
+    assert_eq!(Sysno::ioprio_get.id() as libc::c_long, libc::SYS_ioprio_get);
+    assert_eq!(Sysno::ioprio_set.id() as libc::c_long, libc::SYS_ioprio_set);
+    assert_eq!(Sysno::ipc.id() as libc::c_long, libc::SYS_ipc);
+    assert_eq!(Sysno::kcmp.id() as libc::c_long, libc::SYS_kcmp);
+    assert_eq!(
+        Sysno::kern_features.id() as libc::c_long,
+        libc::SYS_kern_features
+    );
+    assert_eq!(Sysno::kexec_load.id() as libc::c_long, libc::SYS_kexec_load);
+    assert_eq!(Sysno::keyctl.id() as libc::c_long, libc::SYS_keyctl);
+    assert_eq!(Sysno::kill.id() as libc::c_long, libc::SYS_kill);
+    assert_eq!(
+        Sysno::landlock_add_rule.id() as libc::c_long,
+        libc::SYS_landlock_add_rule
+    );
+    assert_eq!(
+        Sysno::landlock_create_ruleset.id() as libc::c_long,
+        libc::SYS_landlock_create_ruleset
+    );
+    assert_eq!(
+        Sysno::landlock_restrict_self.id() as libc::c_long,
+        libc::SYS_landlock_restrict_self
+    );
+    assert_eq!(Sysno::lchown.id() as libc::c_long, libc::SYS_lchown);
+    assert_eq!(Sysno::lchown32.id() as libc::c_long, libc::SYS_lchown32);
+    assert_eq!(Sysno::lgetxattr.id() as libc::c_long, libc::SYS_lgetxattr);
+    assert_eq!(Sysno::link.id() as libc::c_long, libc::SYS_link);
+    assert_eq!(Sysno::linkat.id() as libc::c_long, libc::SYS_linkat);
+    assert_eq!(Sysno::listen.id() as libc::c_long, libc::SYS_listen);
+    assert_eq!(Sysno::listxattr.id() as libc::c_long, libc::SYS_listxattr);
+    assert_eq!(Sysno::llistxattr.id() as libc::c_long, libc::SYS_llistxattr);
+    assert_eq!(
+        Sysno::lookup_dcookie.id() as libc::c_long,
+        libc::SYS_lookup_dcookie
+    );
+    assert_eq!(
+        Sysno::lremovexattr.id() as libc::c_long,
+        libc::SYS_lremovexattr
+    );
+    assert_eq!(Sysno::lseek.id() as libc::c_long, libc::SYS_lseek);
+    assert_eq!(Sysno::lsetxattr.id() as libc::c_long, libc::SYS_lsetxattr);
+    assert_eq!(Sysno::lstat.id() as libc::c_long, libc::SYS_lstat);
+    assert_eq!(Sysno::lstat64.id() as libc::c_long, libc::SYS_lstat64);
+    assert_eq!(Sysno::madvise.id() as libc::c_long, libc::SYS_madvise);
+    assert_eq!(Sysno::mbind.id() as libc::c_long, libc::SYS_mbind);
+    assert_eq!(Sysno::membarrier.id() as libc::c_long, libc::SYS_membarrier);
+    assert_eq!(
+        Sysno::memfd_create.id() as libc::c_long,
+        libc::SYS_memfd_create
+    );
+    assert_eq!(
+        Sysno::migrate_pages.id() as libc::c_long,
+        libc::SYS_migrate_pages
+    );
+    assert_eq!(Sysno::mincore.id() as libc::c_long, libc::SYS_mincore);
+    assert_eq!(Sysno::mkdir.id() as libc::c_long, libc::SYS_mkdir);
+    assert_eq!(Sysno::mkdirat.id() as libc::c_long, libc::SYS_mkdirat);
+    assert_eq!(Sysno::mknod.id() as libc::c_long, libc::SYS_mknod);
+    assert_eq!(Sysno::mknodat.id() as libc::c_long, libc::SYS_mknodat);
+    assert_eq!(Sysno::mlock.id() as libc::c_long, libc::SYS_mlock);
+    assert_eq!(Sysno::mlock2.id() as libc::c_long, libc::SYS_mlock2);
+    assert_eq!(Sysno::mlockall.id() as libc::c_long, libc::SYS_mlockall);
+    assert_eq!(Sysno::mmap.id() as libc::c_long, libc::SYS_mmap);
+    assert_eq!(Sysno::mmap2.id() as libc::c_long, libc::SYS_mmap2);
+    assert_eq!(Sysno::mount.id() as libc::c_long, libc::SYS_mount);
+    assert_eq!(
+        Sysno::mount_setattr.id() as libc::c_long,
+        libc::SYS_mount_setattr
+    );
+    assert_eq!(Sysno::move_mount.id() as libc::c_long, libc::SYS_move_mount);
+    assert_eq!(Sysno::move_pages.id() as libc::c_long, libc::SYS_move_pages);
+    assert_eq!(Sysno::mprotect.id() as libc::c_long, libc::SYS_mprotect);
+    assert_eq!(
+        Sysno::mq_getsetattr.id() as libc::c_long,
+        libc::SYS_mq_getsetattr
+    );
+    assert_eq!(Sysno::mq_notify.id() as libc::c_long, libc::SYS_mq_notify);
+    assert_eq!(Sysno::mq_open.id() as libc::c_long, libc::SYS_mq_open);
+    assert_eq!(
+        Sysno::mq_timedreceive.id() as libc::c_long,
+        libc::SYS_mq_timedreceive
+    );
+    assert_eq!(
+        Sysno::mq_timedsend.id() as libc::c_long,
+        libc::SYS_mq_timedsend
+    );
+    assert_eq!(Sysno::mq_unlink.id() as libc::c_long, libc::SYS_mq_unlink);
+    assert_eq!(Sysno::mremap.id() as libc::c_long, libc::SYS_mremap);
+    assert_eq!(Sysno::msync.id() as libc::c_long, libc::SYS_msync);
+    assert_eq!(Sysno::munlock.id() as libc::c_long, libc::SYS_munlock);
+    assert_eq!(Sysno::munlockall.id() as libc::c_long, libc::SYS_munlockall);
+    assert_eq!(Sysno::munmap.id() as libc::c_long, libc::SYS_munmap);
+    assert_eq!(
+        Sysno::name_to_handle_at.id() as libc::c_long,
+        libc::SYS_name_to_handle_at
+    );
+    assert_eq!(Sysno::nanosleep.id() as libc::c_long, libc::SYS_nanosleep);
+    assert_eq!(Sysno::nfsservctl.id() as libc::c_long, libc::SYS_nfsservctl);
+    assert_eq!(Sysno::nice.id() as libc::c_long, libc::SYS_nice);
+    assert_eq!(Sysno::oldlstat.id() as libc::c_long, libc::SYS_oldlstat);
+    assert_eq!(Sysno::open.id() as libc::c_long, libc::SYS_open);
+    assert_eq!(
+        Sysno::open_by_handle_at.id() as libc::c_long,
+        libc::SYS_open_by_handle_at
+    );
+    assert_eq!(Sysno::open_tree.id() as libc::c_long, libc::SYS_open_tree);
+    assert_eq!(Sysno::openat.id() as libc::c_long, libc::SYS_openat);
+    assert_eq!(Sysno::openat2.id() as libc::c_long, libc::SYS_openat2);
+    assert_eq!(Sysno::pause.id() as libc::c_long, libc::SYS_pause);
+    assert_eq!(
+        Sysno::pciconfig_read.id() as libc::c_long,
+        libc::SYS_pciconfig_read
+    );
+    assert_eq!(
+        Sysno::pciconfig_write.id() as libc::c_long,
+        libc::SYS_pciconfig_write
+    );
+    assert_eq!(
+        Sysno::perf_event_open.id() as libc::c_long,
+        libc::SYS_perf_event_open
+    );
+    assert_eq!(Sysno::perfctr.id() as libc::c_long, libc::SYS_perfctr);
+    assert_eq!(
+        Sysno::personality.id() as libc::c_long,
+        libc::SYS_personality
+    );
+    assert_eq!(
+        Sysno::pidfd_getfd.id() as libc::c_long,
+        libc::SYS_pidfd_getfd
+    );
+    assert_eq!(Sysno::pidfd_open.id() as libc::c_long, libc::SYS_pidfd_open);
+    assert_eq!(
+        Sysno::pidfd_send_signal.id() as libc::c_long,
+        libc::SYS_pidfd_send_signal
+    );
+    assert_eq!(Sysno::pipe.id() as libc::c_long, libc::SYS_pipe);
+    assert_eq!(Sysno::pipe2.id() as libc::c_long, libc::SYS_pipe2);
+    assert_eq!(Sysno::pivot_root.id() as libc::c_long, libc::SYS_pivot_root);
+    assert_eq!(Sysno::poll.id() as libc::c_long, libc::SYS_poll);
+    assert_eq!(Sysno::ppoll.id() as libc::c_long, libc::SYS_ppoll);
+    assert_eq!(Sysno::prctl.id() as libc::c_long, libc::SYS_prctl);
+    assert_eq!(Sysno::pread64.id() as libc::c_long, libc::SYS_pread64);
+    assert_eq!(Sysno::preadv.id() as libc::c_long, libc::SYS_preadv);
+    assert_eq!(Sysno::preadv2.id() as libc::c_long, libc::SYS_preadv2);
+    assert_eq!(Sysno::prlimit64.id() as libc::c_long, libc::SYS_prlimit64);
+    assert_eq!(
+        Sysno::process_madvise.id() as libc::c_long,
+        libc::SYS_process_madvise
+    );
+    assert_eq!(
+        Sysno::process_mrelease.id() as libc::c_long,
+        libc::SYS_process_mrelease
+    );
+    assert_eq!(
+        Sysno::process_vm_readv.id() as libc::c_long,
+        libc::SYS_process_vm_readv
+    );
+    assert_eq!(
+        Sysno::process_vm_writev.id() as libc::c_long,
+        libc::SYS_process_vm_writev
+    );
+    assert_eq!(Sysno::pselect6.id() as libc::c_long, libc::SYS_pselect6);
+    assert_eq!(Sysno::ptrace.id() as libc::c_long, libc::SYS_ptrace);
+    assert_eq!(Sysno::pwrite64.id() as libc::c_long, libc::SYS_pwrite64);
+    assert_eq!(Sysno::pwritev.id() as libc::c_long, libc::SYS_pwritev);
+    assert_eq!(Sysno::pwritev2.id() as libc::c_long, libc::SYS_pwritev2);
+    assert_eq!(Sysno::quotactl.id() as libc::c_long, libc::SYS_quotactl);
+    assert_eq!(
+        Sysno::quotactl_fd.id() as libc::c_long,
+        libc::SYS_quotactl_fd
+    );
+    assert_eq!(Sysno::read.id() as libc::c_long, libc::SYS_read);
+    assert_eq!(Sysno::readahead.id() as libc::c_long, libc::SYS_readahead);
+    assert_eq!(Sysno::readdir.id() as libc::c_long, libc::SYS_readdir);
+    assert_eq!(Sysno::readlink.id() as libc::c_long, libc::SYS_readlink);
+    assert_eq!(Sysno::readlinkat.id() as libc::c_long, libc::SYS_readlinkat);
+    assert_eq!(Sysno::readv.id() as libc::c_long, libc::SYS_readv);
+    assert_eq!(Sysno::reboot.id() as libc::c_long, libc::SYS_reboot);
+    assert_eq!(Sysno::recvfrom.id() as libc::c_long, libc::SYS_recvfrom);
+    assert_eq!(Sysno::recvmmsg.id() as libc::c_long, libc::SYS_recvmmsg);
+    assert_eq!(Sysno::recvmsg.id() as libc::c_long, libc::SYS_recvmsg);
+    assert_eq!(
+        Sysno::remap_file_pages.id() as libc::c_long,
+        libc::SYS_remap_file_pages
+    );
+    assert_eq!(
+        Sysno::removexattr.id() as libc::c_long,
+        libc::SYS_removexattr
+    );
+    assert_eq!(Sysno::rename.id() as libc::c_long, libc::SYS_rename);
+    assert_eq!(Sysno::renameat.id() as libc::c_long, libc::SYS_renameat);
+    assert_eq!(Sysno::renameat2.id() as libc::c_long, libc::SYS_renameat2);
+    assert_eq!(
+        Sysno::request_key.id() as libc::c_long,
+        libc::SYS_request_key
+    );
+    assert_eq!(
+        Sysno::restart_syscall.id() as libc::c_long,
+        libc::SYS_restart_syscall
+    );
+    assert_eq!(Sysno::rmdir.id() as libc::c_long, libc::SYS_rmdir);
+    assert_eq!(Sysno::rseq.id() as libc::c_long, libc::SYS_rseq);
+    assert_eq!(
+        Sysno::rt_sigaction.id() as libc::c_long,
+        libc::SYS_rt_sigaction
+    );
+    assert_eq!(
+        Sysno::rt_sigpending.id() as libc::c_long,
+        libc::SYS_rt_sigpending
+    );
+    assert_eq!(
+        Sysno::rt_sigprocmask.id() as libc::c_long,
+        libc::SYS_rt_sigprocmask
+    );
+    assert_eq!(
+        Sysno::rt_sigqueueinfo.id() as libc::c_long,
+        libc::SYS_rt_sigqueueinfo
+    );
+    assert_eq!(
+        Sysno::rt_sigreturn.id() as libc::c_long,
+        libc::SYS_rt_sigreturn
+    );
+    assert_eq!(
+        Sysno::rt_sigsuspend.id() as libc::c_long,
+        libc::SYS_rt_sigsuspend
+    );
+    assert_eq!(
+        Sysno::rt_sigtimedwait.id() as libc::c_long,
+        libc::SYS_rt_sigtimedwait
+    );
+    assert_eq!(
+        Sysno::rt_tgsigqueueinfo.id() as libc::c_long,
+        libc::SYS_rt_tgsigqueueinfo
+    );
+    assert_eq!(
+        Sysno::sched_get_affinity.id() as libc::c_long,
+        libc::SYS_sched_get_affinity
+    );
+    assert_eq!(
+        Sysno::sched_get_priority_max.id() as libc::c_long,
+        libc::SYS_sched_get_priority_max
+    );
+    assert_eq!(
+        Sysno::sched_get_priority_min.id() as libc::c_long,
+        libc::SYS_sched_get_priority_min
+    );
+    assert_eq!(
+        Sysno::sched_getaffinity.id() as libc::c_long,
+        libc::SYS_sched_getaffinity
+    );
+    assert_eq!(
+        Sysno::sched_getattr.id() as libc::c_long,
+        libc::SYS_sched_getattr
+    );
+    assert_eq!(
+        Sysno::sched_getparam.id() as libc::c_long,
+        libc::SYS_sched_getparam
+    );
+    assert_eq!(
+        Sysno::sched_getscheduler.id() as libc::c_long,
+        libc::SYS_sched_getscheduler
+    );
+    assert_eq!(
+        Sysno::sched_rr_get_interval.id() as libc::c_long,
+        libc::SYS_sched_rr_get_interval
+    );
+    assert_eq!(
+        Sysno::sched_set_affinity.id() as libc::c_long,
+        libc::SYS_sched_set_affinity
+    );
+    assert_eq!(
+        Sysno::sched_setaffinity.id() as libc::c_long,
+        libc::SYS_sched_setaffinity
+    );
+    assert_eq!(
+        Sysno::sched_setattr.id() as libc::c_long,
+        libc::SYS_sched_setattr
+    );
+    assert_eq!(
+        Sysno::sched_setparam.id() as libc::c_long,
+        libc::SYS_sched_setparam
+    );
+    assert_eq!(
+        Sysno::sched_setscheduler.id() as libc::c_long,
+        libc::SYS_sched_setscheduler
+    );
+    assert_eq!(
+        Sysno::sched_yield.id() as libc::c_long,
+        libc::SYS_sched_yield
+    );
+    assert_eq!(Sysno::seccomp.id() as libc::c_long, libc::SYS_seccomp);
+    assert_eq!(Sysno::select.id() as libc::c_long, libc::SYS_select);
+    assert_eq!(Sysno::sendfile.id() as libc::c_long, libc::SYS_sendfile);
+    assert_eq!(Sysno::sendfile64.id() as libc::c_long, libc::SYS_sendfile64);
+    assert_eq!(Sysno::sendmmsg.id() as libc::c_long, libc::SYS_sendmmsg);
+    assert_eq!(Sysno::sendmsg.id() as libc::c_long, libc::SYS_sendmsg);
+    assert_eq!(Sysno::sendto.id() as libc::c_long, libc::SYS_sendto);
+    assert_eq!(
+        Sysno::set_mempolicy.id() as libc::c_long,
+        libc::SYS_set_mempolicy
+    );
+    assert_eq!(
+        Sysno::set_mempolicy_home_node.id() as libc::c_long,
+        libc::SYS_set_mempolicy_home_node
+    );
+    assert_eq!(
+        Sysno::set_robust_list.id() as libc::c_long,
+        libc::SYS_set_robust_list
+    );
+    assert_eq!(
+        Sysno::set_tid_address.id() as libc::c_long,
+        libc::SYS_set_tid_address
+    );
+    assert_eq!(
+        Sysno::setdomainname.id() as libc::c_long,
+        libc::SYS_setdomainname
+    );
+    assert_eq!(Sysno::setfsgid.id() as libc::c_long, libc::SYS_setfsgid);
+    assert_eq!(Sysno::setfsgid32.id() as libc::c_long, libc::SYS_setfsgid32);
+    assert_eq!(Sysno::setfsuid.id() as libc::c_long, libc::SYS_setfsuid);
+    assert_eq!(Sysno::setfsuid32.id() as libc::c_long, libc::SYS_setfsuid32);
+    assert_eq!(Sysno::setgid.id() as libc::c_long, libc::SYS_setgid);
+    assert_eq!(Sysno::setgid32.id() as libc::c_long, libc::SYS_setgid32);
+    assert_eq!(Sysno::setgroups.id() as libc::c_long, libc::SYS_setgroups);
+    assert_eq!(
+        Sysno::setgroups32.id() as libc::c_long,
+        libc::SYS_setgroups32
+    );
+    assert_eq!(
+        Sysno::sethostname.id() as libc::c_long,
+        libc::SYS_sethostname
+    );
+    assert_eq!(Sysno::setitimer.id() as libc::c_long, libc::SYS_setitimer);
+    assert_eq!(Sysno::setns.id() as libc::c_long, libc::SYS_setns);
+    assert_eq!(Sysno::setpgid.id() as libc::c_long, libc::SYS_setpgid);
+    assert_eq!(
+        Sysno::setpriority.id() as libc::c_long,
+        libc::SYS_setpriority
+    );
+    assert_eq!(Sysno::setregid.id() as libc::c_long, libc::SYS_setregid);
+    assert_eq!(Sysno::setregid32.id() as libc::c_long, libc::SYS_setregid32);
+    assert_eq!(
+        Sysno::setresgid32.id() as libc::c_long,
+        libc::SYS_setresgid32
+    );
+    assert_eq!(
+        Sysno::setresuid32.id() as libc::c_long,
+        libc::SYS_setresuid32
+    );
+    assert_eq!(Sysno::setreuid.id() as libc::c_long, libc::SYS_setreuid);
+    assert_eq!(Sysno::setreuid32.id() as libc::c_long, libc::SYS_setreuid32);
+    assert_eq!(Sysno::setrlimit.id() as libc::c_long, libc::SYS_setrlimit);
+    assert_eq!(Sysno::setsid.id() as libc::c_long, libc::SYS_setsid);
+    assert_eq!(Sysno::setsockopt.id() as libc::c_long, libc::SYS_setsockopt);
+    assert_eq!(
+        Sysno::settimeofday.id() as libc::c_long,
+        libc::SYS_settimeofday
+    );
+    assert_eq!(Sysno::setuid.id() as libc::c_long, libc::SYS_setuid);
+    assert_eq!(Sysno::setuid32.id() as libc::c_long, libc::SYS_setuid32);
+    assert_eq!(Sysno::setxattr.id() as libc::c_long, libc::SYS_setxattr);
+    assert_eq!(Sysno::sgetmask.id() as libc::c_long, libc::SYS_sgetmask);
+    assert_eq!(Sysno::shutdown.id() as libc::c_long, libc::SYS_shutdown);
+    assert_eq!(Sysno::sigaction.id() as libc::c_long, libc::SYS_sigaction);
+    assert_eq!(
+        Sysno::sigaltstack.id() as libc::c_long,
+        libc::SYS_sigaltstack
+    );
+    assert_eq!(Sysno::signal.id() as libc::c_long, libc::SYS_signal);
+    assert_eq!(Sysno::signalfd.id() as libc::c_long, libc::SYS_signalfd);
+    assert_eq!(Sysno::signalfd4.id() as libc::c_long, libc::SYS_signalfd4);
+    assert_eq!(Sysno::sigpending.id() as libc::c_long, libc::SYS_sigpending);
+    assert_eq!(
+        Sysno::sigprocmask.id() as libc::c_long,
+        libc::SYS_sigprocmask
+    );
+    assert_eq!(Sysno::sigreturn.id() as libc::c_long, libc::SYS_sigreturn);
+    assert_eq!(Sysno::sigsuspend.id() as libc::c_long, libc::SYS_sigsuspend);
+    assert_eq!(Sysno::socket.id() as libc::c_long, libc::SYS_socket);
+    assert_eq!(Sysno::socketcall.id() as libc::c_long, libc::SYS_socketcall);
+    assert_eq!(Sysno::socketpair.id() as libc::c_long, libc::SYS_socketpair);
+    assert_eq!(Sysno::splice.id() as libc::c_long, libc::SYS_splice);
+    assert_eq!(Sysno::ssetmask.id() as libc::c_long, libc::SYS_ssetmask);
+    assert_eq!(Sysno::stat.id() as libc::c_long, libc::SYS_stat);
+    assert_eq!(Sysno::stat64.id() as libc::c_long, libc::SYS_stat64);
+    assert_eq!(Sysno::statfs.id() as libc::c_long, libc::SYS_statfs);
+    assert_eq!(Sysno::statfs64.id() as libc::c_long, libc::SYS_statfs64);
+    assert_eq!(Sysno::statx.id() as libc::c_long, libc::SYS_statx);
+    assert_eq!(Sysno::stime.id() as libc::c_long, libc::SYS_stime);
+    assert_eq!(Sysno::swapoff.id() as libc::c_long, libc::SYS_swapoff);
+    assert_eq!(Sysno::swapon.id() as libc::c_long, libc::SYS_swapon);
+    assert_eq!(Sysno::symlink.id() as libc::c_long, libc::SYS_symlink);
+    assert_eq!(Sysno::symlinkat.id() as libc::c_long, libc::SYS_symlinkat);
+    assert_eq!(Sysno::sync.id() as libc::c_long, libc::SYS_sync);
+    assert_eq!(
+        Sysno::sync_file_range.id() as libc::c_long,
+        libc::SYS_sync_file_range
+    );
+    assert_eq!(Sysno::syncfs.id() as libc::c_long, libc::SYS_syncfs);
+    assert_eq!(Sysno::sysfs.id() as libc::c_long, libc::SYS_sysfs);
+    assert_eq!(Sysno::sysinfo.id() as libc::c_long, libc::SYS_sysinfo);
+    assert_eq!(Sysno::syslog.id() as libc::c_long, libc::SYS_syslog);
+    assert_eq!(Sysno::tee.id() as libc::c_long, libc::SYS_tee);
+    assert_eq!(Sysno::tgkill.id() as libc::c_long, libc::SYS_tgkill);
+    assert_eq!(Sysno::time.id() as libc::c_long, libc::SYS_time);
+    assert_eq!(
+        Sysno::timer_create.id() as libc::c_long,
+        libc::SYS_timer_create
+    );
+    assert_eq!(
+        Sysno::timer_delete.id() as libc::c_long,
+        libc::SYS_timer_delete
+    );
+    assert_eq!(
+        Sysno::timer_getoverrun.id() as libc::c_long,
+        libc::SYS_timer_getoverrun
+    );
+    assert_eq!(
+        Sysno::timer_gettime.id() as libc::c_long,
+        libc::SYS_timer_gettime
+    );
+    assert_eq!(
+        Sysno::timer_settime.id() as libc::c_long,
+        libc::SYS_timer_settime
+    );
+    assert_eq!(
+        Sysno::timerfd_create.id() as libc::c_long,
+        libc::SYS_timerfd_create
+    );
+    assert_eq!(
+        Sysno::timerfd_gettime.id() as libc::c_long,
+        libc::SYS_timerfd_gettime
+    );
+    assert_eq!(
+        Sysno::timerfd_settime.id() as libc::c_long,
+        libc::SYS_timerfd_settime
+    );
+    assert_eq!(Sysno::times.id() as libc::c_long, libc::SYS_times);
+    assert_eq!(Sysno::tkill.id() as libc::c_long, libc::SYS_tkill);
+    assert_eq!(Sysno::truncate.id() as libc::c_long, libc::SYS_truncate);
+    assert_eq!(Sysno::truncate64.id() as libc::c_long, libc::SYS_truncate64);
+    assert_eq!(Sysno::umask.id() as libc::c_long, libc::SYS_umask);
+    assert_eq!(Sysno::umount.id() as libc::c_long, libc::SYS_umount);
+    assert_eq!(Sysno::umount2.id() as libc::c_long, libc::SYS_umount2);
+    assert_eq!(Sysno::uname.id() as libc::c_long, libc::SYS_uname);
+    assert_eq!(Sysno::unlink.id() as libc::c_long, libc::SYS_unlink);
+    assert_eq!(Sysno::unlinkat.id() as libc::c_long, libc::SYS_unlinkat);
+    assert_eq!(Sysno::unshare.id() as libc::c_long, libc::SYS_unshare);
+    assert_eq!(Sysno::uselib.id() as libc::c_long, libc::SYS_uselib);
+    assert_eq!(
+        Sysno::userfaultfd.id() as libc::c_long,
+        libc::SYS_userfaultfd
+    );
+    assert_eq!(Sysno::ustat.id() as libc::c_long, libc::SYS_ustat);
+    assert_eq!(Sysno::utime.id() as libc::c_long, libc::SYS_utime);
+    assert_eq!(Sysno::utimensat.id() as libc::c_long, libc::SYS_utimensat);
+    assert_eq!(Sysno::utimes.id() as libc::c_long, libc::SYS_utimes);
+    assert_eq!(Sysno::vfork.id() as libc::c_long, libc::SYS_vfork);
+    assert_eq!(Sysno::vhangup.id() as libc::c_long, libc::SYS_vhangup);
+    assert_eq!(Sysno::vmsplice.id() as libc::c_long, libc::SYS_vmsplice);
+    assert_eq!(Sysno::wait4.id() as libc::c_long, libc::SYS_wait4);
+    assert_eq!(Sysno::waitid.id() as libc::c_long, libc::SYS_waitid);
+    assert_eq!(Sysno::waitpid.id() as libc::c_long, libc::SYS_waitpid);
+    assert_eq!(Sysno::write.id() as libc::c_long, libc::SYS_write);
+    assert_eq!(Sysno::writev.id() as libc::c_long, libc::SYS_writev);
+}
+
+#[cfg(all(target_arch = "sparc64", target_pointer_width = "64"))]
+#[test]
+fn sparc64() {
+    use rawsys_linux::sparc64::v6_12::Sysno;
+
+    assert_eq!(Sysno::_llseek.id() as libc::c_long, libc::SYS__llseek);
+    assert_eq!(Sysno::_newselect.id() as libc::c_long, libc::SYS__newselect);
+    assert_eq!(Sysno::_sysctl.id() as libc::c_long, libc::SYS__sysctl);
+    assert_eq!(Sysno::accept.id() as libc::c_long, libc::SYS_accept);
+    assert_eq!(Sysno::accept4.id() as libc::c_long, libc::SYS_accept4);
+    assert_eq!(Sysno::access.id() as libc::c_long, libc::SYS_access);
+    assert_eq!(Sysno::acct.id() as libc::c_long, libc::SYS_acct);
+    assert_eq!(Sysno::add_key.id() as libc::c_long, libc::SYS_add_key);
+    assert_eq!(Sysno::adjtimex.id() as libc::c_long, libc::SYS_adjtimex);
+    assert_eq!(
+        Sysno::afs_syscall.id() as libc::c_long,
+        libc::SYS_afs_syscall
+    );
+    assert_eq!(Sysno::alarm.id() as libc::c_long, libc::SYS_alarm);
+    assert_eq!(Sysno::bdflush.id() as libc::c_long, libc::SYS_bdflush);
+    assert_eq!(Sysno::bind.id() as libc::c_long, libc::SYS_bind);
+    assert_eq!(Sysno::bpf.id() as libc::c_long, libc::SYS_bpf);
+    assert_eq!(Sysno::brk.id() as libc::c_long, libc::SYS_brk);
+    assert_eq!(Sysno::capget.id() as libc::c_long, libc::SYS_capget);
+    assert_eq!(Sysno::capset.id() as libc::c_long, libc::SYS_capset);
+    assert_eq!(Sysno::chdir.id() as libc::c_long, libc::SYS_chdir);
+    assert_eq!(Sysno::chmod.id() as libc::c_long, libc::SYS_chmod);
+    assert_eq!(Sysno::chown.id() as libc::c_long, libc::SYS_chown);
+    assert_eq!(Sysno::chroot.id() as libc::c_long, libc::SYS_chroot);
+    assert_eq!(
+        Sysno::clock_adjtime.id() as libc::c_long,
+        libc::SYS_clock_adjtime
+    );
+    assert_eq!(
+        Sysno::clock_getres.id() as libc::c_long,
+        libc::SYS_clock_getres
+    );
+    assert_eq!(
+        Sysno::clock_gettime.id() as libc::c_long,
+        libc::SYS_clock_gettime
+    );
+    assert_eq!(
+        Sysno::clock_nanosleep.id() as libc::c_long,
+        libc::SYS_clock_nanosleep
+    );
+    assert_eq!(
+        Sysno::clock_settime.id() as libc::c_long,
+        libc::SYS_clock_settime
+    );
+    assert_eq!(Sysno::clone.id() as libc::c_long, libc::SYS_clone);
+    assert_eq!(Sysno::close.id() as libc::c_long, libc::SYS_close);
+    assert_eq!(
+        Sysno::close_range.id() as libc::c_long,
+        libc::SYS_close_range
+    );
+    assert_eq!(Sysno::connect.id() as libc::c_long, libc::SYS_connect);
+    assert_eq!(
+        Sysno::copy_file_range.id() as libc::c_long,
+        libc::SYS_copy_file_range
+    );
+    assert_eq!(Sysno::creat.id() as libc::c_long, libc::SYS_creat);
+    assert_eq!(
+        Sysno::delete_module.id() as libc::c_long,
+        libc::SYS_delete_module
+    );
+    assert_eq!(Sysno::dup.id() as libc::c_long, libc::SYS_dup);
+    assert_eq!(Sysno::dup2.id() as libc::c_long, libc::SYS_dup2);
+    assert_eq!(Sysno::dup3.id() as libc::c_long, libc::SYS_dup3);
+    assert_eq!(
+        Sysno::epoll_create.id() as libc::c_long,
+        libc::SYS_epoll_create
+    );
+    assert_eq!(
+        Sysno::epoll_create1.id() as libc::c_long,
+        libc::SYS_epoll_create1
+    );
+    assert_eq!(Sysno::epoll_ctl.id() as libc::c_long, libc::SYS_epoll_ctl);
+    assert_eq!(
+        Sysno::epoll_pwait.id() as libc::c_long,
+        libc::SYS_epoll_pwait
+    );
+    assert_eq!(
+        Sysno::epoll_pwait2.id() as libc::c_long,
+        libc::SYS_epoll_pwait2
+    );
+    assert_eq!(Sysno::epoll_wait.id() as libc::c_long, libc::SYS_epoll_wait);
+    assert_eq!(Sysno::eventfd.id() as libc::c_long, libc::SYS_eventfd);
+    assert_eq!(Sysno::eventfd2.id() as libc::c_long, libc::SYS_eventfd2);
+    assert_eq!(Sysno::execv.id() as libc::c_long, libc::SYS_execv);
+    assert_eq!(Sysno::execve.id() as libc::c_long, libc::SYS_execve);
+    assert_eq!(Sysno::execveat.id() as libc::c_long, libc::SYS_execveat);
+    assert_eq!(Sysno::exit.id() as libc::c_long, libc::SYS_exit);
+    assert_eq!(Sysno::exit_group.id() as libc::c_long, libc::SYS_exit_group);
+    assert_eq!(Sysno::faccessat.id() as libc::c_long, libc::SYS_faccessat);
+    assert_eq!(Sysno::faccessat2.id() as libc::c_long, libc::SYS_faccessat2);
+    assert_eq!(Sysno::fadvise64.id() as libc::c_long, libc::SYS_fadvise64);
+    assert_eq!(
+        Sysno::fadvise64_64.id() as libc::c_long,
+        libc::SYS_fadvise64_64
+    );
+    assert_eq!(Sysno::fallocate.id() as libc::c_long, libc::SYS_fallocate);
+    assert_eq!(
+        Sysno::fanotify_init.id() as libc::c_long,
+        libc::SYS_fanotify_init
+    );
+    assert_eq!(
+        Sysno::fanotify_mark.id() as libc::c_long,
+        libc::SYS_fanotify_mark
+    );
+    assert_eq!(Sysno::fchdir.id() as libc::c_long, libc::SYS_fchdir);
+    assert_eq!(Sysno::fchmod.id() as libc::c_long, libc::SYS_fchmod);
+    assert_eq!(Sysno::fchmodat.id() as libc::c_long, libc::SYS_fchmodat);
+    assert_eq!(Sysno::fchown.id() as libc::c_long, libc::SYS_fchown);
+    assert_eq!(Sysno::fchownat.id() as libc::c_long, libc::SYS_fchownat);
+    assert_eq!(Sysno::fcntl.id() as libc::c_long, libc::SYS_fcntl);
+    assert_eq!(Sysno::fdatasync.id() as libc::c_long, libc::SYS_fdatasync);
+    assert_eq!(Sysno::fgetxattr.id() as libc::c_long, libc::SYS_fgetxattr);
+    assert_eq!(
+        Sysno::finit_module.id() as libc::c_long,
+        libc::SYS_finit_module
+    );
+    assert_eq!(Sysno::flistxattr.id() as libc::c_long, libc::SYS_flistxattr);
+    assert_eq!(Sysno::flock.id() as libc::c_long, libc::SYS_flock);
+    assert_eq!(Sysno::fork.id() as libc::c_long, libc::SYS_fork);
+    assert_eq!(
+        Sysno::fremovexattr.id() as libc::c_long,
+        libc::SYS_fremovexattr
+    );
+    assert_eq!(Sysno::fsconfig.id() as libc::c_long, libc::SYS_fsconfig);
+    assert_eq!(Sysno::fsetxattr.id() as libc::c_long, libc::SYS_fsetxattr);
+    assert_eq!(Sysno::fsmount.id() as libc::c_long, libc::SYS_fsmount);
+    assert_eq!(Sysno::fsopen.id() as libc::c_long, libc::SYS_fsopen);
+    assert_eq!(Sysno::fspick.id() as libc::c_long, libc::SYS_fspick);
+    assert_eq!(Sysno::fstat.id() as libc::c_long, libc::SYS_fstat);
+    assert_eq!(Sysno::fstat64.id() as libc::c_long, libc::SYS_fstat64);
+    assert_eq!(Sysno::fstatat64.id() as libc::c_long, libc::SYS_fstatat64);
+    assert_eq!(Sysno::fstatfs.id() as libc::c_long, libc::SYS_fstatfs);
+    assert_eq!(Sysno::fstatfs64.id() as libc::c_long, libc::SYS_fstatfs64);
+    assert_eq!(Sysno::fsync.id() as libc::c_long, libc::SYS_fsync);
+    assert_eq!(Sysno::ftruncate.id() as libc::c_long, libc::SYS_ftruncate);
+    assert_eq!(Sysno::futex.id() as libc::c_long, libc::SYS_futex);
+    assert_eq!(
+        Sysno::futex_waitv.id() as libc::c_long,
+        libc::SYS_futex_waitv
+    );
+    assert_eq!(Sysno::futimesat.id() as libc::c_long, libc::SYS_futimesat);
+    assert_eq!(
+        Sysno::get_mempolicy.id() as libc::c_long,
+        libc::SYS_get_mempolicy
+    );
+    assert_eq!(
+        Sysno::get_robust_list.id() as libc::c_long,
+        libc::SYS_get_robust_list
+    );
+    assert_eq!(Sysno::getcpu.id() as libc::c_long, libc::SYS_getcpu);
+    assert_eq!(Sysno::getcwd.id() as libc::c_long, libc::SYS_getcwd);
+    assert_eq!(Sysno::getdents.id() as libc::c_long, libc::SYS_getdents);
+    assert_eq!(Sysno::getdents64.id() as libc::c_long, libc::SYS_getdents64);
+    assert_eq!(
+        Sysno::getdomainname.id() as libc::c_long,
+        libc::SYS_getdomainname
+    );
+    assert_eq!(Sysno::getegid.id() as libc::c_long, libc::SYS_getegid);
+    assert_eq!(Sysno::geteuid.id() as libc::c_long, libc::SYS_geteuid);
+    assert_eq!(Sysno::getgid.id() as libc::c_long, libc::SYS_getgid);
+    assert_eq!(Sysno::getgroups.id() as libc::c_long, libc::SYS_getgroups);
+    assert_eq!(Sysno::getitimer.id() as libc::c_long, libc::SYS_getitimer);
+    assert_eq!(
+        Sysno::getpagesize.id() as libc::c_long,
+        libc::SYS_getpagesize
+    );
+    assert_eq!(
+        Sysno::getpeername.id() as libc::c_long,
+        libc::SYS_getpeername
+    );
+    assert_eq!(Sysno::getpgid.id() as libc::c_long, libc::SYS_getpgid);
+    assert_eq!(Sysno::getpgrp.id() as libc::c_long, libc::SYS_getpgrp);
+    assert_eq!(Sysno::getpid.id() as libc::c_long, libc::SYS_getpid);
+    assert_eq!(Sysno::getppid.id() as libc::c_long, libc::SYS_getppid);
+    assert_eq!(
+        Sysno::getpriority.id() as libc::c_long,
+        libc::SYS_getpriority
+    );
+    assert_eq!(Sysno::getrandom.id() as libc::c_long, libc::SYS_getrandom);
+    assert_eq!(Sysno::getresgid.id() as libc::c_long, libc::SYS_getresgid);
+    assert_eq!(Sysno::getresuid.id() as libc::c_long, libc::SYS_getresuid);
+    assert_eq!(Sysno::getrlimit.id() as libc::c_long, libc::SYS_getrlimit);
+    assert_eq!(Sysno::getrusage.id() as libc::c_long, libc::SYS_getrusage);
+    assert_eq!(Sysno::getsid.id() as libc::c_long, libc::SYS_getsid);
+    assert_eq!(
+        Sysno::getsockname.id() as libc::c_long,
+        libc::SYS_getsockname
+    );
+    assert_eq!(Sysno::getsockopt.id() as libc::c_long, libc::SYS_getsockopt);
+    assert_eq!(Sysno::gettid.id() as libc::c_long, libc::SYS_gettid);
+    assert_eq!(
+        Sysno::gettimeofday.id() as libc::c_long,
+        libc::SYS_gettimeofday
+    );
+    assert_eq!(Sysno::getuid.id() as libc::c_long, libc::SYS_getuid);
+    assert_eq!(Sysno::getxattr.id() as libc::c_long, libc::SYS_getxattr);
+    assert_eq!(
+        Sysno::init_module.id() as libc::c_long,
+        libc::SYS_init_module
+    );
+    assert_eq!(
+        Sysno::inotify_add_watch.id() as libc::c_long,
+        libc::SYS_inotify_add_watch
+    );
+    assert_eq!(
+        Sysno::inotify_init.id() as libc::c_long,
+        libc::SYS_inotify_init
+    );
+    assert_eq!(
+        Sysno::inotify_init1.id() as libc::c_long,
+        libc::SYS_inotify_init1
+    );
+    assert_eq!(
+        Sysno::inotify_rm_watch.id() as libc::c_long,
+        libc::SYS_inotify_rm_watch
+    );
+    assert_eq!(Sysno::io_cancel.id() as libc::c_long, libc::SYS_io_cancel);
+    assert_eq!(Sysno::io_destroy.id() as libc::c_long, libc::SYS_io_destroy);
+    assert_eq!(
+        Sysno::io_getevents.id() as libc::c_long,
+        libc::SYS_io_getevents
+    );
+    assert_eq!(Sysno::io_setup.id() as libc::c_long, libc::SYS_io_setup);
+    assert_eq!(Sysno::io_submit.id() as libc::c_long, libc::SYS_io_submit);
+    assert_eq!(
+        Sysno::io_uring_enter.id() as libc::c_long,
+        libc::SYS_io_uring_enter
+    );
+    assert_eq!(
+        Sysno::io_uring_register.id() as libc::c_long,
+        libc::SYS_io_uring_register
+    );
+    assert_eq!(
+        Sysno::io_uring_setup.id() as libc::c_long,
+        libc::SYS_io_uring_setup
+    );
+    assert_eq!(Sysno::ioctl.id() as libc::c_long, libc::SYS_ioctl);
+    assert_eq!(Sysno::ioprio_get.id() as libc::c_long, libc::SYS_ioprio_get);
+    assert_eq!(Sysno::ioprio_set.id() as libc::c_long, libc::SYS_ioprio_set);
+    assert_eq!(Sysno::ipc.id() as libc::c_long, libc::SYS_ipc);
+    assert_eq!(Sysno::kcmp.id() as libc::c_long, libc::SYS_kcmp);
+    assert_eq!(
+        Sysno::kern_features.id() as libc::c_long,
+        libc::SYS_kern_features
+    );
+    assert_eq!(Sysno::kexec_load.id() as libc::c_long, libc::SYS_kexec_load);
+    assert_eq!(Sysno::keyctl.id() as libc::c_long, libc::SYS_keyctl);
+    assert_eq!(Sysno::kill.id() as libc::c_long, libc::SYS_kill);
+    assert_eq!(
+        Sysno::landlock_add_rule.id() as libc::c_long,
+        libc::SYS_landlock_add_rule
+    );
+    assert_eq!(
+        Sysno::landlock_create_ruleset.id() as libc::c_long,
+        libc::SYS_landlock_create_ruleset
+    );
+    assert_eq!(
+        Sysno::landlock_restrict_self.id() as libc::c_long,
+        libc::SYS_landlock_restrict_self
+    );
+    assert_eq!(Sysno::lchown.id() as libc::c_long, libc::SYS_lchown);
+    assert_eq!(Sysno::lgetxattr.id() as libc::c_long, libc::SYS_lgetxattr);
+    assert_eq!(Sysno::link.id() as libc::c_long, libc::SYS_link);
+    assert_eq!(Sysno::linkat.id() as libc::c_long, libc::SYS_linkat);
+    assert_eq!(Sysno::listen.id() as libc::c_long, libc::SYS_listen);
+    assert_eq!(Sysno::listxattr.id() as libc::c_long, libc::SYS_listxattr);
+    assert_eq!(Sysno::llistxattr.id() as libc::c_long, libc::SYS_llistxattr);
+    assert_eq!(
+        Sysno::lookup_dcookie.id() as libc::c_long,
+        libc::SYS_lookup_dcookie
+    );
+    assert_eq!(
+        Sysno::lremovexattr.id() as libc::c_long,
+        libc::SYS_lremovexattr
+    );
+    assert_eq!(Sysno::lseek.id() as libc::c_long, libc::SYS_lseek);
+    assert_eq!(Sysno::lsetxattr.id() as libc::c_long, libc::SYS_lsetxattr);
+    assert_eq!(Sysno::lstat.id() as libc::c_long, libc::SYS_lstat);
+    assert_eq!(Sysno::lstat64.id() as libc::c_long, libc::SYS_lstat64);
+    assert_eq!(Sysno::madvise.id() as libc::c_long, libc::SYS_madvise);
+    assert_eq!(Sysno::mbind.id() as libc::c_long, libc::SYS_mbind);
+    assert_eq!(Sysno::membarrier.id() as libc::c_long, libc::SYS_membarrier);
+    assert_eq!(
+        Sysno::memfd_create.id() as libc::c_long,
+        libc::SYS_memfd_create
+    );
+    assert_eq!(
+        Sysno::memory_ordering.id() as libc::c_long,
+        libc::SYS_memory_ordering
+    );
+    assert_eq!(
+        Sysno::migrate_pages.id() as libc::c_long,
+        libc::SYS_migrate_pages
+    );
+    assert_eq!(Sysno::mincore.id() as libc::c_long, libc::SYS_mincore);
+    assert_eq!(Sysno::mkdir.id() as libc::c_long, libc::SYS_mkdir);
+    assert_eq!(Sysno::mkdirat.id() as libc::c_long, libc::SYS_mkdirat);
+    assert_eq!(Sysno::mknod.id() as libc::c_long, libc::SYS_mknod);
+    assert_eq!(Sysno::mknodat.id() as libc::c_long, libc::SYS_mknodat);
+    assert_eq!(Sysno::mlock.id() as libc::c_long, libc::SYS_mlock);
+    assert_eq!(Sysno::mlock2.id() as libc::c_long, libc::SYS_mlock2);
+    assert_eq!(Sysno::mlockall.id() as libc::c_long, libc::SYS_mlockall);
+    assert_eq!(Sysno::mmap.id() as libc::c_long, libc::SYS_mmap);
+    assert_eq!(Sysno::mount.id() as libc::c_long, libc::SYS_mount);
+    assert_eq!(
+        Sysno::mount_setattr.id() as libc::c_long,
+        libc::SYS_mount_setattr
+    );
+    assert_eq!(Sysno::move_mount.id() as libc::c_long, libc::SYS_move_mount);
+    assert_eq!(Sysno::move_pages.id() as libc::c_long, libc::SYS_move_pages);
+    assert_eq!(Sysno::mprotect.id() as libc::c_long, libc::SYS_mprotect);
+    assert_eq!(
+        Sysno::mq_getsetattr.id() as libc::c_long,
+        libc::SYS_mq_getsetattr
+    );
+    assert_eq!(Sysno::mq_notify.id() as libc::c_long, libc::SYS_mq_notify);
+    assert_eq!(Sysno::mq_open.id() as libc::c_long, libc::SYS_mq_open);
+    assert_eq!(
+        Sysno::mq_timedreceive.id() as libc::c_long,
+        libc::SYS_mq_timedreceive
+    );
+    assert_eq!(
+        Sysno::mq_timedsend.id() as libc::c_long,
+        libc::SYS_mq_timedsend
+    );
+    assert_eq!(Sysno::mq_unlink.id() as libc::c_long, libc::SYS_mq_unlink);
+    assert_eq!(Sysno::mremap.id() as libc::c_long, libc::SYS_mremap);
+    assert_eq!(Sysno::msync.id() as libc::c_long, libc::SYS_msync);
+    assert_eq!(Sysno::munlock.id() as libc::c_long, libc::SYS_munlock);
+    assert_eq!(Sysno::munlockall.id() as libc::c_long, libc::SYS_munlockall);
+    assert_eq!(Sysno::munmap.id() as libc::c_long, libc::SYS_munmap);
+    assert_eq!(
+        Sysno::name_to_handle_at.id() as libc::c_long,
+        libc::SYS_name_to_handle_at
+    );
+    assert_eq!(Sysno::nanosleep.id() as libc::c_long, libc::SYS_nanosleep);
+    assert_eq!(Sysno::nfsservctl.id() as libc::c_long, libc::SYS_nfsservctl);
+    assert_eq!(Sysno::nice.id() as libc::c_long, libc::SYS_nice);
+    assert_eq!(Sysno::oldlstat.id() as libc::c_long, libc::SYS_oldlstat);
+    assert_eq!(Sysno::open.id() as libc::c_long, libc::SYS_open);
+    assert_eq!(
+        Sysno::open_by_handle_at.id() as libc::c_long,
+        libc::SYS_open_by_handle_at
+    );
+    assert_eq!(Sysno::open_tree.id() as libc::c_long, libc::SYS_open_tree);
+    assert_eq!(Sysno::openat.id() as libc::c_long, libc::SYS_openat);
+    assert_eq!(Sysno::openat2.id() as libc::c_long, libc::SYS_openat2);
+    assert_eq!(Sysno::pause.id() as libc::c_long, libc::SYS_pause);
+    assert_eq!(
+        Sysno::pciconfig_read.id() as libc::c_long,
+        libc::SYS_pciconfig_read
+    );
+    assert_eq!(
+        Sysno::pciconfig_write.id() as libc::c_long,
+        libc::SYS_pciconfig_write
+    );
+    assert_eq!(
+        Sysno::perf_event_open.id() as libc::c_long,
+        libc::SYS_perf_event_open
+    );
+    assert_eq!(Sysno::perfctr.id() as libc::c_long, libc::SYS_perfctr);
+    assert_eq!(
+        Sysno::personality.id() as libc::c_long,
+        libc::SYS_personality
+    );
+    assert_eq!(
+        Sysno::pidfd_getfd.id() as libc::c_long,
+        libc::SYS_pidfd_getfd
+    );
+    assert_eq!(Sysno::pidfd_open.id() as libc::c_long, libc::SYS_pidfd_open);
+    assert_eq!(
+        Sysno::pidfd_send_signal.id() as libc::c_long,
+        libc::SYS_pidfd_send_signal
+    );
+    assert_eq!(Sysno::pipe.id() as libc::c_long, libc::SYS_pipe);
+    assert_eq!(Sysno::pipe2.id() as libc::c_long, libc::SYS_pipe2);
+    assert_eq!(Sysno::pivot_root.id() as libc::c_long, libc::SYS_pivot_root);
+    assert_eq!(Sysno::poll.id() as libc::c_long, libc::SYS_poll);
+    assert_eq!(Sysno::ppoll.id() as libc::c_long, libc::SYS_ppoll);
+    assert_eq!(Sysno::prctl.id() as libc::c_long, libc::SYS_prctl);
+    assert_eq!(Sysno::pread64.id() as libc::c_long, libc::SYS_pread64);
+    assert_eq!(Sysno::preadv.id() as libc::c_long, libc::SYS_preadv);
+    assert_eq!(Sysno::preadv2.id() as libc::c_long, libc::SYS_preadv2);
+    assert_eq!(Sysno::prlimit64.id() as libc::c_long, libc::SYS_prlimit64);
+    assert_eq!(
+        Sysno::process_madvise.id() as libc::c_long,
+        libc::SYS_process_madvise
+    );
+    assert_eq!(
+        Sysno::process_mrelease.id() as libc::c_long,
+        libc::SYS_process_mrelease
+    );
+    assert_eq!(
+        Sysno::process_vm_readv.id() as libc::c_long,
+        libc::SYS_process_vm_readv
+    );
+    assert_eq!(
+        Sysno::process_vm_writev.id() as libc::c_long,
+        libc::SYS_process_vm_writev
+    );
+    assert_eq!(Sysno::pselect6.id() as libc::c_long, libc::SYS_pselect6);
+    assert_eq!(Sysno::ptrace.id() as libc::c_long, libc::SYS_ptrace);
+    assert_eq!(Sysno::pwrite64.id() as libc::c_long, libc::SYS_pwrite64);
+    assert_eq!(Sysno::pwritev.id() as libc::c_long, libc::SYS_pwritev);
+    assert_eq!(Sysno::pwritev2.id() as libc::c_long, libc::SYS_pwritev2);
+    assert_eq!(Sysno::quotactl.id() as libc::c_long, libc::SYS_quotactl);
+    assert_eq!(
+        Sysno::quotactl_fd.id() as libc::c_long,
+        libc::SYS_quotactl_fd
+    );
+    assert_eq!(Sysno::read.id() as libc::c_long, libc::SYS_read);
+    assert_eq!(Sysno::readahead.id() as libc::c_long, libc::SYS_readahead);
+    assert_eq!(Sysno::readdir.id() as libc::c_long, libc::SYS_readdir);
+    assert_eq!(Sysno::readlink.id() as libc::c_long, libc::SYS_readlink);
+    assert_eq!(Sysno::readlinkat.id() as libc::c_long, libc::SYS_readlinkat);
+    assert_eq!(Sysno::readv.id() as libc::c_long, libc::SYS_readv);
+    assert_eq!(Sysno::reboot.id() as libc::c_long, libc::SYS_reboot);
+    assert_eq!(Sysno::recvfrom.id() as libc::c_long, libc::SYS_recvfrom);
+    assert_eq!(Sysno::recvmmsg.id() as libc::c_long, libc::SYS_recvmmsg);
+    assert_eq!(Sysno::recvmsg.id() as libc::c_long, libc::SYS_recvmsg);
+    assert_eq!(
+        Sysno::remap_file_pages.id() as libc::c_long,
+        libc::SYS_remap_file_pages
+    );
+    assert_eq!(
+        Sysno::removexattr.id() as libc::c_long,
+        libc::SYS_removexattr
+    );
+    assert_eq!(Sysno::rename.id() as libc::c_long, libc::SYS_rename);
+    assert_eq!(Sysno::renameat.id() as libc::c_long, libc::SYS_renameat);
+    assert_eq!(Sysno::renameat2.id() as libc::c_long, libc::SYS_renameat2);
+    assert_eq!(
+        Sysno::request_key.id() as libc::c_long,
+        libc::SYS_request_key
+    );
+    assert_eq!(
+        Sysno::restart_syscall.id() as libc::c_long,
+        libc::SYS_restart_syscall
+    );
+    assert_eq!(Sysno::rmdir.id() as libc::c_long, libc::SYS_rmdir);
+    assert_eq!(Sysno::rseq.id() as libc::c_long, libc::SYS_rseq);
+    assert_eq!(
+        Sysno::rt_sigaction.id() as libc::c_long,
+        libc::SYS_rt_sigaction
+    );
+    assert_eq!(
+        Sysno::rt_sigpending.id() as libc::c_long,
+        libc::SYS_rt_sigpending
+    );
+    assert_eq!(
+        Sysno::rt_sigprocmask.id() as libc::c_long,
+        libc::SYS_rt_sigprocmask
+    );
+    assert_eq!(
+        Sysno::rt_sigqueueinfo.id() as libc::c_long,
+        libc::SYS_rt_sigqueueinfo
+    );
+    assert_eq!(
+        Sysno::rt_sigreturn.id() as libc::c_long,
+        libc::SYS_rt_sigreturn
+    );
+    assert_eq!(
+        Sysno::rt_sigsuspend.id() as libc::c_long,
+        libc::SYS_rt_sigsuspend
+    );
+    assert_eq!(
+        Sysno::rt_sigtimedwait.id() as libc::c_long,
+        libc::SYS_rt_sigtimedwait
+    );
+    assert_eq!(
+        Sysno::rt_tgsigqueueinfo.id() as libc::c_long,
+        libc::SYS_rt_tgsigqueueinfo
+    );
+    assert_eq!(
+        Sysno::sched_get_affinity.id() as libc::c_long,
+        libc::SYS_sched_get_affinity
+    );
+    assert_eq!(
+        Sysno::sched_get_priority_max.id() as libc::c_long,
+        libc::SYS_sched_get_priority_max
+    );
+    assert_eq!(
+        Sysno::sched_get_priority_min.id() as libc::c_long,
+        libc::SYS_sched_get_priority_min
+    );
+    assert_eq!(
+        Sysno::sched_getaffinity.id() as libc::c_long,
+        libc::SYS_sched_getaffinity
+    );
+    assert_eq!(
+        Sysno::sched_getattr.id() as libc::c_long,
+        libc::SYS_sched_getattr
+    );
+    assert_eq!(
+        Sysno::sched_getparam.id() as libc::c_long,
+        libc::SYS_sched_getparam
+    );
+    assert_eq!(
+        Sysno::sched_getscheduler.id() as libc::c_long,
+        libc::SYS_sched_getscheduler
+    );
+    assert_eq!(
+        Sysno::sched_rr_get_interval.id() as libc::c_long,
+        libc::SYS_sched_rr_get_interval
+    );
+    assert_eq!(
+        Sysno::sched_set_affinity.id() as libc::c_long,
+        libc::SYS_sched_set_affinity
+    );
+    assert_eq!(
+        Sysno::sched_setaffinity.id() as libc::c_long,
+        libc::SYS_sched_setaffinity
+    );
+    assert_eq!(
+        Sysno::sched_setattr.id() as libc::c_long,
+        libc::SYS_sched_setattr
+    );
+    assert_eq!(
+        Sysno::sched_setparam.id() as libc::c_long,
+        libc::SYS_sched_setparam
+    );
+    assert_eq!(
+        Sysno::sched_setscheduler.id() as libc::c_long,
+        libc::SYS_sched_setscheduler
+    );
+    assert_eq!(
+        Sysno::sched_yield.id() as libc::c_long,
+        libc::SYS_sched_yield
+    );
+    assert_eq!(Sysno::seccomp.id() as libc::c_long, libc::SYS_seccomp);
+    assert_eq!(Sysno::select.id() as libc::c_long, libc::SYS_select);
+    assert_eq!(Sysno::sendfile.id() as libc::c_long, libc::SYS_sendfile);
+    assert_eq!(Sysno::sendfile64.id() as libc::c_long, libc::SYS_sendfile64);
+    assert_eq!(Sysno::sendmmsg.id() as libc::c_long, libc::SYS_sendmmsg);
+    assert_eq!(Sysno::sendmsg.id() as libc::c_long, libc::SYS_sendmsg);
+    assert_eq!(Sysno::sendto.id() as libc::c_long, libc::SYS_sendto);
+    assert_eq!(
+        Sysno::set_mempolicy.id() as libc::c_long,
+        libc::SYS_set_mempolicy
+    );
+    assert_eq!(
+        Sysno::set_mempolicy_home_node.id() as libc::c_long,
+        libc::SYS_set_mempolicy_home_node
+    );
+    assert_eq!(
+        Sysno::set_robust_list.id() as libc::c_long,
+        libc::SYS_set_robust_list
+    );
+    assert_eq!(
+        Sysno::set_tid_address.id() as libc::c_long,
+        libc::SYS_set_tid_address
+    );
+    assert_eq!(
+        Sysno::setdomainname.id() as libc::c_long,
+        libc::SYS_setdomainname
+    );
+    assert_eq!(Sysno::setfsgid.id() as libc::c_long, libc::SYS_setfsgid);
+    assert_eq!(Sysno::setfsuid.id() as libc::c_long, libc::SYS_setfsuid);
+    assert_eq!(Sysno::setgid.id() as libc::c_long, libc::SYS_setgid);
+    assert_eq!(Sysno::setgroups.id() as libc::c_long, libc::SYS_setgroups);
+    assert_eq!(
+        Sysno::sethostname.id() as libc::c_long,
+        libc::SYS_sethostname
+    );
+    assert_eq!(Sysno::setitimer.id() as libc::c_long, libc::SYS_setitimer);
+    assert_eq!(Sysno::setns.id() as libc::c_long, libc::SYS_setns);
+    assert_eq!(Sysno::setpgid.id() as libc::c_long, libc::SYS_setpgid);
+    assert_eq!(
+        Sysno::setpriority.id() as libc::c_long,
+        libc::SYS_setpriority
+    );
+    assert_eq!(Sysno::setregid.id() as libc::c_long, libc::SYS_setregid);
+    assert_eq!(Sysno::setresgid.id() as libc::c_long, libc::SYS_setresgid);
+    assert_eq!(Sysno::setresuid.id() as libc::c_long, libc::SYS_setresuid);
+    assert_eq!(Sysno::setreuid.id() as libc::c_long, libc::SYS_setreuid);
+    assert_eq!(Sysno::setrlimit.id() as libc::c_long, libc::SYS_setrlimit);
+    assert_eq!(Sysno::setsid.id() as libc::c_long, libc::SYS_setsid);
+    assert_eq!(Sysno::setsockopt.id() as libc::c_long, libc::SYS_setsockopt);
+    assert_eq!(
+        Sysno::settimeofday.id() as libc::c_long,
+        libc::SYS_settimeofday
+    );
+    assert_eq!(Sysno::setuid.id() as libc::c_long, libc::SYS_setuid);
+    assert_eq!(Sysno::setxattr.id() as libc::c_long, libc::SYS_setxattr);
+    assert_eq!(Sysno::sgetmask.id() as libc::c_long, libc::SYS_sgetmask);
+    assert_eq!(Sysno::shutdown.id() as libc::c_long, libc::SYS_shutdown);
+    assert_eq!(Sysno::sigaction.id() as libc::c_long, libc::SYS_sigaction);
+    assert_eq!(
+        Sysno::sigaltstack.id() as libc::c_long,
+        libc::SYS_sigaltstack
+    );
+    assert_eq!(Sysno::signal.id() as libc::c_long, libc::SYS_signal);
+    assert_eq!(Sysno::signalfd.id() as libc::c_long, libc::SYS_signalfd);
+    assert_eq!(Sysno::signalfd4.id() as libc::c_long, libc::SYS_signalfd4);
+    assert_eq!(Sysno::sigpending.id() as libc::c_long, libc::SYS_sigpending);
+    assert_eq!(
+        Sysno::sigprocmask.id() as libc::c_long,
+        libc::SYS_sigprocmask
+    );
+    assert_eq!(Sysno::sigreturn.id() as libc::c_long, libc::SYS_sigreturn);
+    assert_eq!(Sysno::sigsuspend.id() as libc::c_long, libc::SYS_sigsuspend);
+    assert_eq!(Sysno::socket.id() as libc::c_long, libc::SYS_socket);
+    assert_eq!(Sysno::socketcall.id() as libc::c_long, libc::SYS_socketcall);
+    assert_eq!(Sysno::socketpair.id() as libc::c_long, libc::SYS_socketpair);
+    assert_eq!(Sysno::splice.id() as libc::c_long, libc::SYS_splice);
+    assert_eq!(Sysno::ssetmask.id() as libc::c_long, libc::SYS_ssetmask);
+    assert_eq!(Sysno::stat.id() as libc::c_long, libc::SYS_stat);
+    assert_eq!(Sysno::stat64.id() as libc::c_long, libc::SYS_stat64);
+    assert_eq!(Sysno::statfs.id() as libc::c_long, libc::SYS_statfs);
+    assert_eq!(Sysno::statfs64.id() as libc::c_long, libc::SYS_statfs64);
+    assert_eq!(Sysno::statx.id() as libc::c_long, libc::SYS_statx);
+    assert_eq!(Sysno::stime.id() as libc::c_long, libc::SYS_stime);
+    assert_eq!(Sysno::swapoff.id() as libc::c_long, libc::SYS_swapoff);
+    assert_eq!(Sysno::swapon.id() as libc::c_long, libc::SYS_swapon);
+    assert_eq!(Sysno::symlink.id() as libc::c_long, libc::SYS_symlink);
+    assert_eq!(Sysno::symlinkat.id() as libc::c_long, libc::SYS_symlinkat);
+    assert_eq!(Sysno::sync.id() as libc::c_long, libc::SYS_sync);
+    assert_eq!(
+        Sysno::sync_file_range.id() as libc::c_long,
+        libc::SYS_sync_file_range
+    );
+    assert_eq!(Sysno::syncfs.id() as libc::c_long, libc::SYS_syncfs);
+    assert_eq!(Sysno::sysfs.id() as libc::c_long, libc::SYS_sysfs);
+    assert_eq!(Sysno::sysinfo.id() as libc::c_long, libc::SYS_sysinfo);
+    assert_eq!(Sysno::syslog.id() as libc::c_long, libc::SYS_syslog);
+    assert_eq!(Sysno::tee.id() as libc::c_long, libc::SYS_tee);
+    assert_eq!(Sysno::tgkill.id() as libc::c_long, libc::SYS_tgkill);
+    assert_eq!(
+        Sysno::timer_create.id() as libc::c_long,
+        libc::SYS_timer_create
+    );
+    assert_eq!(
+        Sysno::timer_delete.id() as libc::c_long,
+        libc::SYS_timer_delete
+    );
+    assert_eq!(
+        Sysno::timer_getoverrun.id() as libc::c_long,
+        libc::SYS_timer_getoverrun
+    );
+    assert_eq!(
+        Sysno::timer_gettime.id() as libc::c_long,
+        libc::SYS_timer_gettime
+    );
+    assert_eq!(
+        Sysno::timer_settime.id() as libc::c_long,
+        libc::SYS_timer_settime
+    );
+    assert_eq!(
+        Sysno::timerfd_create.id() as libc::c_long,
+        libc::SYS_timerfd_create
+    );
+    assert_eq!(
+        Sysno::timerfd_gettime.id() as libc::c_long,
+        libc::SYS_timerfd_gettime
+    );
+    assert_eq!(
+        Sysno::timerfd_settime.id() as libc::c_long,
+        libc::SYS_timerfd_settime
+    );
+    assert_eq!(Sysno::times.id() as libc::c_long, libc::SYS_times);
+    assert_eq!(Sysno::tkill.id() as libc::c_long, libc::SYS_tkill);
+    assert_eq!(Sysno::truncate.id() as libc::c_long, libc::SYS_truncate);
+    assert_eq!(Sysno::umask.id() as libc::c_long, libc::SYS_umask);
+    assert_eq!(Sysno::umount.id() as libc::c_long, libc::SYS_umount);
+    assert_eq!(Sysno::umount2.id() as libc::c_long, libc::SYS_umount2);
+    assert_eq!(Sysno::uname.id() as libc::c_long, libc::SYS_uname);
+    assert_eq!(Sysno::unlink.id() as libc::c_long, libc::SYS_unlink);
+    assert_eq!(Sysno::unlinkat.id() as libc::c_long, libc::SYS_unlinkat);
+    assert_eq!(Sysno::unshare.id() as libc::c_long, libc::SYS_unshare);
+    assert_eq!(Sysno::uselib.id() as libc::c_long, libc::SYS_uselib);
+    assert_eq!(
+        Sysno::userfaultfd.id() as libc::c_long,
+        libc::SYS_userfaultfd
+    );
+    assert_eq!(Sysno::ustat.id() as libc::c_long, libc::SYS_ustat);
+    assert_eq!(Sysno::utime.id() as libc::c_long, libc::SYS_utime);
+    assert_eq!(Sysno::utimensat.id() as libc::c_long, libc::SYS_utimensat);
+    assert_eq!(Sysno::utimes.id() as libc::c_long, libc::SYS_utimes);
+    assert_eq!(
+        Sysno::utrap_install.id() as libc::c_long,
+        libc::SYS_utrap_install
+    );
+    assert_eq!(Sysno::vfork.id() as libc::c_long, libc::SYS_vfork);
+    assert_eq!(Sysno::vhangup.id() as libc::c_long, libc::SYS_vhangup);
+    assert_eq!(Sysno::vmsplice.id() as libc::c_long, libc::SYS_vmsplice);
+    assert_eq!(Sysno::wait4.id() as libc::c_long, libc::SYS_wait4);
+    assert_eq!(Sysno::waitid.id() as libc::c_long, libc::SYS_waitid);
+    assert_eq!(Sysno::waitpid.id() as libc::c_long, libc::SYS_waitpid);
+    assert_eq!(Sysno::write.id() as libc::c_long, libc::SYS_write);
+    assert_eq!(Sysno::writev.id() as libc::c_long, libc::SYS_writev);
+}
+
+#[cfg(all(target_arch = "x86", target_pointer_width = "32"))]
+#[test]
+fn x86() {
+    use rawsys_linux::x86::v6_12::Sysno;
+
+    assert_eq!(Sysno::_llseek.id() as libc::c_long, libc::SYS__llseek);
+    assert_eq!(Sysno::_newselect.id() as libc::c_long, libc::SYS__newselect);
+    assert_eq!(Sysno::_sysctl.id() as libc::c_long, libc::SYS__sysctl);
+    assert_eq!(Sysno::accept4.id() as libc::c_long, libc::SYS_accept4);
+    assert_eq!(Sysno::access.id() as libc::c_long, libc::SYS_access);
+    assert_eq!(Sysno::acct.id() as libc::c_long, libc::SYS_acct);
+    assert_eq!(Sysno::add_key.id() as libc::c_long, libc::SYS_add_key);
+    assert_eq!(Sysno::adjtimex.id() as libc::c_long, libc::SYS_adjtimex);
+    assert_eq!(
+        Sysno::afs_syscall.id() as libc::c_long,
+        libc::SYS_afs_syscall
+    );
+    assert_eq!(Sysno::alarm.id() as libc::c_long, libc::SYS_alarm);
+    assert_eq!(Sysno::bdflush.id() as libc::c_long, libc::SYS_bdflush);
+    assert_eq!(Sysno::bind.id() as libc::c_long, libc::SYS_bind);
+    assert_eq!(Sysno::bpf.id() as libc::c_long, libc::SYS_bpf);
+    assert_eq!(Sysno::break_.id() as libc::c_long, libc::SYS_break);
+    assert_eq!(Sysno::brk.id() as libc::c_long, libc::SYS_brk);
+    assert_eq!(Sysno::capget.id() as libc::c_long, libc::SYS_capget);
+    assert_eq!(Sysno::capset.id() as libc::c_long, libc::SYS_capset);
+    assert_eq!(Sysno::chdir.id() as libc::c_long, libc::SYS_chdir);
+    assert_eq!(Sysno::chmod.id() as libc::c_long, libc::SYS_chmod);
+    assert_eq!(Sysno::chown.id() as libc::c_long, libc::SYS_chown);
+    assert_eq!(Sysno::chown32.id() as libc::c_long, libc::SYS_chown32);
+    assert_eq!(Sysno::chroot.id() as libc::c_long, libc::SYS_chroot);
+    assert_eq!(
+        Sysno::clock_adjtime.id() as libc::c_long,
+        libc::SYS_clock_adjtime
+    );
+    assert_eq!(
+        Sysno::clock_getres.id() as libc::c_long,
+        libc::SYS_clock_getres
+    );
+    assert_eq!(
+        Sysno::clock_gettime.id() as libc::c_long,
+        libc::SYS_clock_gettime
+    );
+    assert_eq!(
+        Sysno::clock_nanosleep.id() as libc::c_long,
+        libc::SYS_clock_nanosleep
+    );
+    assert_eq!(
+        Sysno::clock_settime.id() as libc::c_long,
+        libc::SYS_clock_settime
+    );
+    assert_eq!(Sysno::clone.id() as libc::c_long, libc::SYS_clone);
+    assert_eq!(Sysno::clone3.id() as libc::c_long, libc::SYS_clone3);
+    assert_eq!(Sysno::close.id() as libc::c_long, libc::SYS_close);
+    assert_eq!(
+        Sysno::close_range.id() as libc::c_long,
+        libc::SYS_close_range
+    );
+    assert_eq!(Sysno::connect.id() as libc::c_long, libc::SYS_connect);
+    assert_eq!(
+        Sysno::copy_file_range.id() as libc::c_long,
+        libc::SYS_copy_file_range
+    );
+    assert_eq!(Sysno::creat.id() as libc::c_long, libc::SYS_creat);
+    assert_eq!(
+        Sysno::delete_module.id() as libc::c_long,
+        libc::SYS_delete_module
+    );
+    assert_eq!(Sysno::dup.id() as libc::c_long, libc::SYS_dup);
+    assert_eq!(Sysno::dup2.id() as libc::c_long, libc::SYS_dup2);
+    assert_eq!(Sysno::dup3.id() as libc::c_long, libc::SYS_dup3);
+    assert_eq!(
+        Sysno::epoll_create.id() as libc::c_long,
+        libc::SYS_epoll_create
+    );
+    assert_eq!(
+        Sysno::epoll_create1.id() as libc::c_long,
+        libc::SYS_epoll_create1
+    );
+    assert_eq!(Sysno::epoll_ctl.id() as libc::c_long, libc::SYS_epoll_ctl);
+    assert_eq!(
+        Sysno::epoll_pwait.id() as libc::c_long,
+        libc::SYS_epoll_pwait
+    );
+    assert_eq!(
+        Sysno::epoll_pwait2.id() as libc::c_long,
+        libc::SYS_epoll_pwait2
+    );
+    assert_eq!(Sysno::epoll_wait.id() as libc::c_long, libc::SYS_epoll_wait);
+    assert_eq!(Sysno::eventfd.id() as libc::c_long, libc::SYS_eventfd);
+    assert_eq!(Sysno::eventfd2.id() as libc::c_long, libc::SYS_eventfd2);
+    assert_eq!(Sysno::execve.id() as libc::c_long, libc::SYS_execve);
+    assert_eq!(Sysno::execveat.id() as libc::c_long, libc::SYS_execveat);
+    assert_eq!(Sysno::exit.id() as libc::c_long, libc::SYS_exit);
+    assert_eq!(Sysno::exit_group.id() as libc::c_long, libc::SYS_exit_group);
+    assert_eq!(Sysno::faccessat.id() as libc::c_long, libc::SYS_faccessat);
+    assert_eq!(Sysno::faccessat2.id() as libc::c_long, libc::SYS_faccessat2);
+    assert_eq!(Sysno::fadvise64.id() as libc::c_long, libc::SYS_fadvise64);
+    assert_eq!(
+        Sysno::fadvise64_64.id() as libc::c_long,
+        libc::SYS_fadvise64_64
+    );
+    assert_eq!(Sysno::fallocate.id() as libc::c_long, libc::SYS_fallocate);
+    assert_eq!(
+        Sysno::fanotify_init.id() as libc::c_long,
+        libc::SYS_fanotify_init
+    );
+    assert_eq!(
+        Sysno::fanotify_mark.id() as libc::c_long,
+        libc::SYS_fanotify_mark
+    );
+    assert_eq!(Sysno::fchdir.id() as libc::c_long, libc::SYS_fchdir);
+    assert_eq!(Sysno::fchmod.id() as libc::c_long, libc::SYS_fchmod);
+    assert_eq!(Sysno::fchmodat.id() as libc::c_long, libc::SYS_fchmodat);
+    assert_eq!(Sysno::fchmodat2.id() as libc::c_long, libc::SYS_fchmodat2);
+    assert_eq!(Sysno::fchown.id() as libc::c_long, libc::SYS_fchown);
+    assert_eq!(Sysno::fchown32.id() as libc::c_long, libc::SYS_fchown32);
+    assert_eq!(Sysno::fchownat.id() as libc::c_long, libc::SYS_fchownat);
+    assert_eq!(Sysno::fcntl.id() as libc::c_long, libc::SYS_fcntl);
+    assert_eq!(Sysno::fcntl64.id() as libc::c_long, libc::SYS_fcntl64);
+    assert_eq!(Sysno::fdatasync.id() as libc::c_long, libc::SYS_fdatasync);
+    assert_eq!(Sysno::fgetxattr.id() as libc::c_long, libc::SYS_fgetxattr);
+    assert_eq!(
+        Sysno::finit_module.id() as libc::c_long,
+        libc::SYS_finit_module
+    );
+    assert_eq!(Sysno::flistxattr.id() as libc::c_long, libc::SYS_flistxattr);
+    assert_eq!(Sysno::flock.id() as libc::c_long, libc::SYS_flock);
+    assert_eq!(Sysno::fork.id() as libc::c_long, libc::SYS_fork);
+    assert_eq!(
+        Sysno::fremovexattr.id() as libc::c_long,
+        libc::SYS_fremovexattr
+    );
+    assert_eq!(Sysno::fsconfig.id() as libc::c_long, libc::SYS_fsconfig);
+    assert_eq!(Sysno::fsetxattr.id() as libc::c_long, libc::SYS_fsetxattr);
+    assert_eq!(Sysno::fsmount.id() as libc::c_long, libc::SYS_fsmount);
+    assert_eq!(Sysno::fsopen.id() as libc::c_long, libc::SYS_fsopen);
+    assert_eq!(Sysno::fspick.id() as libc::c_long, libc::SYS_fspick);
+    assert_eq!(Sysno::fstat.id() as libc::c_long, libc::SYS_fstat);
+    assert_eq!(Sysno::fstat64.id() as libc::c_long, libc::SYS_fstat64);
+    assert_eq!(Sysno::fstatat64.id() as libc::c_long, libc::SYS_fstatat64);
+    assert_eq!(Sysno::fstatfs.id() as libc::c_long, libc::SYS_fstatfs);
+    assert_eq!(Sysno::fstatfs64.id() as libc::c_long, libc::SYS_fstatfs64);
+    assert_eq!(Sysno::fsync.id() as libc::c_long, libc::SYS_fsync);
+    assert_eq!(Sysno::ftime.id() as libc::c_long, libc::SYS_ftime);
+    assert_eq!(Sysno::ftruncate.id() as libc::c_long, libc::SYS_ftruncate);
+    assert_eq!(
+        Sysno::ftruncate64.id() as libc::c_long,
+        libc::SYS_ftruncate64
+    );
+    assert_eq!(Sysno::futex.id() as libc::c_long, libc::SYS_futex);
+    assert_eq!(
+        Sysno::futex_waitv.id() as libc::c_long,
+        libc::SYS_futex_waitv
+    );
+    assert_eq!(Sysno::futimesat.id() as libc::c_long, libc::SYS_futimesat);
+    assert_eq!(
+        Sysno::get_mempolicy.id() as libc::c_long,
+        libc::SYS_get_mempolicy
+    );
+    assert_eq!(
+        Sysno::get_robust_list.id() as libc::c_long,
+        libc::SYS_get_robust_list
+    );
+    assert_eq!(
+        Sysno::get_thread_area.id() as libc::c_long,
+        libc::SYS_get_thread_area
+    );
+    assert_eq!(Sysno::getcpu.id() as libc::c_long, libc::SYS_getcpu);
+    assert_eq!(Sysno::getcwd.id() as libc::c_long, libc::SYS_getcwd);
+    assert_eq!(Sysno::getdents.id() as libc::c_long, libc::SYS_getdents);
+    assert_eq!(Sysno::getdents64.id() as libc::c_long, libc::SYS_getdents64);
+    assert_eq!(Sysno::getegid.id() as libc::c_long, libc::SYS_getegid);
+    assert_eq!(Sysno::getegid32.id() as libc::c_long, libc::SYS_getegid32);
+    assert_eq!(Sysno::geteuid.id() as libc::c_long, libc::SYS_geteuid);
+    assert_eq!(Sysno::geteuid32.id() as libc::c_long, libc::SYS_geteuid32);
+    assert_eq!(Sysno::getgid.id() as libc::c_long, libc::SYS_getgid);
+    assert_eq!(Sysno::getgid32.id() as libc::c_long, libc::SYS_getgid32);
+    assert_eq!(Sysno::getgroups.id() as libc::c_long, libc::SYS_getgroups);
+    assert_eq!(
+        Sysno::getgroups32.id() as libc::c_long,
+        libc::SYS_getgroups32
+    );
+    assert_eq!(Sysno::getitimer.id() as libc::c_long, libc::SYS_getitimer);
+    assert_eq!(
+        Sysno::getpeername.id() as libc::c_long,
+        libc::SYS_getpeername
+    );
+    assert_eq!(Sysno::getpgid.id() as libc::c_long, libc::SYS_getpgid);
+    assert_eq!(Sysno::getpgrp.id() as libc::c_long, libc::SYS_getpgrp);
+    assert_eq!(Sysno::getpid.id() as libc::c_long, libc::SYS_getpid);
+    assert_eq!(Sysno::getpmsg.id() as libc::c_long, libc::SYS_getpmsg);
+    assert_eq!(Sysno::getppid.id() as libc::c_long, libc::SYS_getppid);
+    assert_eq!(
+        Sysno::getpriority.id() as libc::c_long,
+        libc::SYS_getpriority
+    );
+    assert_eq!(Sysno::getrandom.id() as libc::c_long, libc::SYS_getrandom);
+    assert_eq!(Sysno::getresgid.id() as libc::c_long, libc::SYS_getresgid);
+    assert_eq!(
+        Sysno::getresgid32.id() as libc::c_long,
+        libc::SYS_getresgid32
+    );
+    assert_eq!(Sysno::getresuid.id() as libc::c_long, libc::SYS_getresuid);
+    assert_eq!(
+        Sysno::getresuid32.id() as libc::c_long,
+        libc::SYS_getresuid32
+    );
+    assert_eq!(Sysno::getrlimit.id() as libc::c_long, libc::SYS_getrlimit);
+    assert_eq!(Sysno::getrusage.id() as libc::c_long, libc::SYS_getrusage);
+    assert_eq!(Sysno::getsid.id() as libc::c_long, libc::SYS_getsid);
+    assert_eq!(
+        Sysno::getsockname.id() as libc::c_long,
+        libc::SYS_getsockname
+    );
+    assert_eq!(Sysno::getsockopt.id() as libc::c_long, libc::SYS_getsockopt);
+    assert_eq!(Sysno::gettid.id() as libc::c_long, libc::SYS_gettid);
+    assert_eq!(
+        Sysno::gettimeofday.id() as libc::c_long,
+        libc::SYS_gettimeofday
+    );
+    assert_eq!(Sysno::getuid.id() as libc::c_long, libc::SYS_getuid);
+    assert_eq!(Sysno::getuid32.id() as libc::c_long, libc::SYS_getuid32);
+    assert_eq!(Sysno::getxattr.id() as libc::c_long, libc::SYS_getxattr);
+    assert_eq!(Sysno::gtty.id() as libc::c_long, libc::SYS_gtty);
+    assert_eq!(Sysno::idle.id() as libc::c_long, libc::SYS_idle);
+    assert_eq!(
+        Sysno::init_module.id() as libc::c_long,
+        libc::SYS_init_module
+    );
+    assert_eq!(
+        Sysno::inotify_add_watch.id() as libc::c_long,
+        libc::SYS_inotify_add_watch
+    );
+    assert_eq!(
+        Sysno::inotify_init.id() as libc::c_long,
+        libc::SYS_inotify_init
+    );
+    assert_eq!(
+        Sysno::inotify_init1.id() as libc::c_long,
+        libc::SYS_inotify_init1
+    );
+    assert_eq!(
+        Sysno::inotify_rm_watch.id() as libc::c_long,
+        libc::SYS_inotify_rm_watch
+    );
+    assert_eq!(Sysno::io_cancel.id() as libc::c_long, libc::SYS_io_cancel);
+    assert_eq!(Sysno::io_destroy.id() as libc::c_long, libc::SYS_io_destroy);
+    assert_eq!(
+        Sysno::io_getevents.id() as libc::c_long,
+        libc::SYS_io_getevents
+    );
+    assert_eq!(Sysno::io_setup.id() as libc::c_long, libc::SYS_io_setup);
+    assert_eq!(Sysno::io_submit.id() as libc::c_long, libc::SYS_io_submit);
+    assert_eq!(
+        Sysno::io_uring_enter.id() as libc::c_long,
+        libc::SYS_io_uring_enter
+    );
+    assert_eq!(
+        Sysno::io_uring_register.id() as libc::c_long,
+        libc::SYS_io_uring_register
+    );
+    assert_eq!(
+        Sysno::io_uring_setup.id() as libc::c_long,
+        libc::SYS_io_uring_setup
+    );
+    assert_eq!(Sysno::ioctl.id() as libc::c_long, libc::SYS_ioctl);
+    assert_eq!(Sysno::ioperm.id() as libc::c_long, libc::SYS_ioperm);
+    assert_eq!(Sysno::iopl.id() as libc::c_long, libc::SYS_iopl);
+    assert_eq!(Sysno::ioprio_get.id() as libc::c_long, libc::SYS_ioprio_get);
+    assert_eq!(Sysno::ioprio_set.id() as libc::c_long, libc::SYS_ioprio_set);
+    assert_eq!(Sysno::ipc.id() as libc::c_long, libc::SYS_ipc);
+    assert_eq!(Sysno::kcmp.id() as libc::c_long, libc::SYS_kcmp);
+    assert_eq!(Sysno::kexec_load.id() as libc::c_long, libc::SYS_kexec_load);
+    assert_eq!(Sysno::keyctl.id() as libc::c_long, libc::SYS_keyctl);
+    assert_eq!(Sysno::kill.id() as libc::c_long, libc::SYS_kill);
+    assert_eq!(
+        Sysno::landlock_add_rule.id() as libc::c_long,
+        libc::SYS_landlock_add_rule
+    );
+    assert_eq!(
+        Sysno::landlock_create_ruleset.id() as libc::c_long,
+        libc::SYS_landlock_create_ruleset
+    );
+    assert_eq!(
+        Sysno::landlock_restrict_self.id() as libc::c_long,
+        libc::SYS_landlock_restrict_self
+    );
+    assert_eq!(Sysno::lchown.id() as libc::c_long, libc::SYS_lchown);
+    assert_eq!(Sysno::lchown32.id() as libc::c_long, libc::SYS_lchown32);
+    assert_eq!(Sysno::lgetxattr.id() as libc::c_long, libc::SYS_lgetxattr);
+    assert_eq!(Sysno::link.id() as libc::c_long, libc::SYS_link);
+    assert_eq!(Sysno::linkat.id() as libc::c_long, libc::SYS_linkat);
+    assert_eq!(Sysno::listen.id() as libc::c_long, libc::SYS_listen);
+    assert_eq!(Sysno::listxattr.id() as libc::c_long, libc::SYS_listxattr);
+    assert_eq!(Sysno::llistxattr.id() as libc::c_long, libc::SYS_llistxattr);
+    assert_eq!(Sysno::lock.id() as libc::c_long, libc::SYS_lock);
+    assert_eq!(
+        Sysno::lookup_dcookie.id() as libc::c_long,
+        libc::SYS_lookup_dcookie
+    );
+    assert_eq!(
+        Sysno::lremovexattr.id() as libc::c_long,
+        libc::SYS_lremovexattr
+    );
+    assert_eq!(Sysno::lseek.id() as libc::c_long, libc::SYS_lseek);
+    assert_eq!(Sysno::lsetxattr.id() as libc::c_long, libc::SYS_lsetxattr);
+    assert_eq!(Sysno::lstat.id() as libc::c_long, libc::SYS_lstat);
+    assert_eq!(Sysno::lstat64.id() as libc::c_long, libc::SYS_lstat64);
+    assert_eq!(Sysno::madvise.id() as libc::c_long, libc::SYS_madvise);
+    assert_eq!(Sysno::mbind.id() as libc::c_long, libc::SYS_mbind);
+    assert_eq!(Sysno::membarrier.id() as libc::c_long, libc::SYS_membarrier);
+    assert_eq!(
+        Sysno::memfd_create.id() as libc::c_long,
+        libc::SYS_memfd_create
+    );
+    assert_eq!(
+        Sysno::memfd_secret.id() as libc::c_long,
+        libc::SYS_memfd_secret
+    );
+    assert_eq!(
+        Sysno::migrate_pages.id() as libc::c_long,
+        libc::SYS_migrate_pages
+    );
+    assert_eq!(Sysno::mincore.id() as libc::c_long, libc::SYS_mincore);
+    assert_eq!(Sysno::mkdir.id() as libc::c_long, libc::SYS_mkdir);
+    assert_eq!(Sysno::mkdirat.id() as libc::c_long, libc::SYS_mkdirat);
+    assert_eq!(Sysno::mknod.id() as libc::c_long, libc::SYS_mknod);
+    assert_eq!(Sysno::mknodat.id() as libc::c_long, libc::SYS_mknodat);
+    assert_eq!(Sysno::mlock.id() as libc::c_long, libc::SYS_mlock);
+    assert_eq!(Sysno::mlock2.id() as libc::c_long, libc::SYS_mlock2);
+    assert_eq!(Sysno::mlockall.id() as libc::c_long, libc::SYS_mlockall);
+    assert_eq!(Sysno::mmap.id() as libc::c_long, libc::SYS_mmap);
+    assert_eq!(Sysno::mmap2.id() as libc::c_long, libc::SYS_mmap2);
+    assert_eq!(Sysno::modify_ldt.id() as libc::c_long, libc::SYS_modify_ldt);
+    assert_eq!(Sysno::mount.id() as libc::c_long, libc::SYS_mount);
+    assert_eq!(
+        Sysno::mount_setattr.id() as libc::c_long,
+        libc::SYS_mount_setattr
+    );
+    assert_eq!(Sysno::move_mount.id() as libc::c_long, libc::SYS_move_mount);
+    assert_eq!(Sysno::move_pages.id() as libc::c_long, libc::SYS_move_pages);
+    assert_eq!(Sysno::mprotect.id() as libc::c_long, libc::SYS_mprotect);
+    assert_eq!(Sysno::mpx.id() as libc::c_long, libc::SYS_mpx);
+    assert_eq!(
+        Sysno::mq_getsetattr.id() as libc::c_long,
+        libc::SYS_mq_getsetattr
+    );
+    assert_eq!(Sysno::mq_notify.id() as libc::c_long, libc::SYS_mq_notify);
+    assert_eq!(Sysno::mq_open.id() as libc::c_long, libc::SYS_mq_open);
+    assert_eq!(
+        Sysno::mq_timedreceive.id() as libc::c_long,
+        libc::SYS_mq_timedreceive
+    );
+    assert_eq!(
+        Sysno::mq_timedsend.id() as libc::c_long,
+        libc::SYS_mq_timedsend
+    );
+    assert_eq!(Sysno::mq_unlink.id() as libc::c_long, libc::SYS_mq_unlink);
+    assert_eq!(Sysno::mremap.id() as libc::c_long, libc::SYS_mremap);
+    assert_eq!(Sysno::mseal.id() as libc::c_long, libc::SYS_mseal);
+    assert_eq!(Sysno::msync.id() as libc::c_long, libc::SYS_msync);
+    assert_eq!(Sysno::munlock.id() as libc::c_long, libc::SYS_munlock);
+    assert_eq!(Sysno::munlockall.id() as libc::c_long, libc::SYS_munlockall);
+    assert_eq!(Sysno::munmap.id() as libc::c_long, libc::SYS_munmap);
+    assert_eq!(
+        Sysno::name_to_handle_at.id() as libc::c_long,
+        libc::SYS_name_to_handle_at
+    );
+    assert_eq!(Sysno::nanosleep.id() as libc::c_long, libc::SYS_nanosleep);
+    assert_eq!(Sysno::nfsservctl.id() as libc::c_long, libc::SYS_nfsservctl);
+    assert_eq!(Sysno::nice.id() as libc::c_long, libc::SYS_nice);
+    assert_eq!(Sysno::oldfstat.id() as libc::c_long, libc::SYS_oldfstat);
+    assert_eq!(Sysno::oldlstat.id() as libc::c_long, libc::SYS_oldlstat);
+    assert_eq!(
+        Sysno::oldolduname.id() as libc::c_long,
+        libc::SYS_oldolduname
+    );
+    assert_eq!(Sysno::oldstat.id() as libc::c_long, libc::SYS_oldstat);
+    assert_eq!(Sysno::olduname.id() as libc::c_long, libc::SYS_olduname);
+    assert_eq!(Sysno::open.id() as libc::c_long, libc::SYS_open);
+    assert_eq!(
+        Sysno::open_by_handle_at.id() as libc::c_long,
+        libc::SYS_open_by_handle_at
+    );
+    assert_eq!(Sysno::open_tree.id() as libc::c_long, libc::SYS_open_tree);
+    assert_eq!(Sysno::openat.id() as libc::c_long, libc::SYS_openat);
+    assert_eq!(Sysno::openat2.id() as libc::c_long, libc::SYS_openat2);
+    assert_eq!(Sysno::pause.id() as libc::c_long, libc::SYS_pause);
+    assert_eq!(
+        Sysno::perf_event_open.id() as libc::c_long,
+        libc::SYS_perf_event_open
+    );
+    assert_eq!(
+        Sysno::personality.id() as libc::c_long,
+        libc::SYS_personality
+    );
+    assert_eq!(
+        Sysno::pidfd_getfd.id() as libc::c_long,
+        libc::SYS_pidfd_getfd
+    );
+    assert_eq!(Sysno::pidfd_open.id() as libc::c_long, libc::SYS_pidfd_open);
+    assert_eq!(
+        Sysno::pidfd_send_signal.id() as libc::c_long,
+        libc::SYS_pidfd_send_signal
+    );
+    assert_eq!(Sysno::pipe.id() as libc::c_long, libc::SYS_pipe);
+    assert_eq!(Sysno::pipe2.id() as libc::c_long, libc::SYS_pipe2);
+    assert_eq!(Sysno::pivot_root.id() as libc::c_long, libc::SYS_pivot_root);
+    assert_eq!(Sysno::pkey_alloc.id() as libc::c_long, libc::SYS_pkey_alloc);
+    assert_eq!(Sysno::pkey_free.id() as libc::c_long, libc::SYS_pkey_free);
+    assert_eq!(
+        Sysno::pkey_mprotect.id() as libc::c_long,
+        libc::SYS_pkey_mprotect
+    );
+    assert_eq!(Sysno::poll.id() as libc::c_long, libc::SYS_poll);
+    assert_eq!(Sysno::ppoll.id() as libc::c_long, libc::SYS_ppoll);
+    assert_eq!(Sysno::prctl.id() as libc::c_long, libc::SYS_prctl);
+    assert_eq!(Sysno::pread64.id() as libc::c_long, libc::SYS_pread64);
+    assert_eq!(Sysno::preadv.id() as libc::c_long, libc::SYS_preadv);
+    assert_eq!(Sysno::preadv2.id() as libc::c_long, libc::SYS_preadv2);
+    assert_eq!(Sysno::prlimit64.id() as libc::c_long, libc::SYS_prlimit64);
+    assert_eq!(
+        Sysno::process_madvise.id() as libc::c_long,
+        libc::SYS_process_madvise
+    );
+    assert_eq!(
+        Sysno::process_mrelease.id() as libc::c_long,
+        libc::SYS_process_mrelease
+    );
+    assert_eq!(
+        Sysno::process_vm_readv.id() as libc::c_long,
+        libc::SYS_process_vm_readv
+    );
+    assert_eq!(
+        Sysno::process_vm_writev.id() as libc::c_long,
+        libc::SYS_process_vm_writev
+    );
+    assert_eq!(Sysno::prof.id() as libc::c_long, libc::SYS_prof);
+    assert_eq!(Sysno::profil.id() as libc::c_long, libc::SYS_profil);
+    assert_eq!(Sysno::pselect6.id() as libc::c_long, libc::SYS_pselect6);
+    assert_eq!(Sysno::ptrace.id() as libc::c_long, libc::SYS_ptrace);
+    assert_eq!(Sysno::putpmsg.id() as libc::c_long, libc::SYS_putpmsg);
+    assert_eq!(Sysno::pwrite64.id() as libc::c_long, libc::SYS_pwrite64);
+    assert_eq!(Sysno::pwritev.id() as libc::c_long, libc::SYS_pwritev);
+    assert_eq!(Sysno::pwritev2.id() as libc::c_long, libc::SYS_pwritev2);
+    assert_eq!(Sysno::quotactl.id() as libc::c_long, libc::SYS_quotactl);
+    assert_eq!(
+        Sysno::quotactl_fd.id() as libc::c_long,
+        libc::SYS_quotactl_fd
+    );
+    assert_eq!(Sysno::read.id() as libc::c_long, libc::SYS_read);
+    assert_eq!(Sysno::readahead.id() as libc::c_long, libc::SYS_readahead);
+    assert_eq!(Sysno::readdir.id() as libc::c_long, libc::SYS_readdir);
+    assert_eq!(Sysno::readlink.id() as libc::c_long, libc::SYS_readlink);
+    assert_eq!(Sysno::readlinkat.id() as libc::c_long, libc::SYS_readlinkat);
+    assert_eq!(Sysno::readv.id() as libc::c_long, libc::SYS_readv);
+    assert_eq!(Sysno::reboot.id() as libc::c_long, libc::SYS_reboot);
+    assert_eq!(Sysno::recvfrom.id() as libc::c_long, libc::SYS_recvfrom);
+    assert_eq!(Sysno::recvmmsg.id() as libc::c_long, libc::SYS_recvmmsg);
+    assert_eq!(Sysno::recvmsg.id() as libc::c_long, libc::SYS_recvmsg);
+    assert_eq!(
+        Sysno::remap_file_pages.id() as libc::c_long,
+        libc::SYS_remap_file_pages
+    );
+    assert_eq!(
+        Sysno::removexattr.id() as libc::c_long,
+        libc::SYS_removexattr
+    );
+    assert_eq!(Sysno::rename.id() as libc::c_long, libc::SYS_rename);
+    assert_eq!(Sysno::renameat.id() as libc::c_long, libc::SYS_renameat);
+    assert_eq!(Sysno::renameat2.id() as libc::c_long, libc::SYS_renameat2);
+    assert_eq!(
+        Sysno::request_key.id() as libc::c_long,
+        libc::SYS_request_key
+    );
+    assert_eq!(
+        Sysno::restart_syscall.id() as libc::c_long,
+        libc::SYS_restart_syscall
+    );
+    assert_eq!(Sysno::rmdir.id() as libc::c_long, libc::SYS_rmdir);
+    assert_eq!(Sysno::rseq.id() as libc::c_long, libc::SYS_rseq);
+    assert_eq!(
+        Sysno::rt_sigaction.id() as libc::c_long,
+        libc::SYS_rt_sigaction
+    );
+    assert_eq!(
+        Sysno::rt_sigpending.id() as libc::c_long,
+        libc::SYS_rt_sigpending
+    );
+    assert_eq!(
+        Sysno::rt_sigprocmask.id() as libc::c_long,
+        libc::SYS_rt_sigprocmask
+    );
+    assert_eq!(
+        Sysno::rt_sigqueueinfo.id() as libc::c_long,
+        libc::SYS_rt_sigqueueinfo
+    );
+    assert_eq!(
+        Sysno::rt_sigreturn.id() as libc::c_long,
+        libc::SYS_rt_sigreturn
+    );
+    assert_eq!(
+        Sysno::rt_sigsuspend.id() as libc::c_long,
+        libc::SYS_rt_sigsuspend
+    );
+    assert_eq!(
+        Sysno::rt_sigtimedwait.id() as libc::c_long,
+        libc::SYS_rt_sigtimedwait
+    );
+    assert_eq!(
+        Sysno::rt_tgsigqueueinfo.id() as libc::c_long,
+        libc::SYS_rt_tgsigqueueinfo
+    );
+    assert_eq!(
+        Sysno::sched_get_priority_max.id() as libc::c_long,
+        libc::SYS_sched_get_priority_max
+    );
+    assert_eq!(
+        Sysno::sched_get_priority_min.id() as libc::c_long,
+        libc::SYS_sched_get_priority_min
+    );
+    assert_eq!(
+        Sysno::sched_getaffinity.id() as libc::c_long,
+        libc::SYS_sched_getaffinity
+    );
+    assert_eq!(
+        Sysno::sched_getattr.id() as libc::c_long,
+        libc::SYS_sched_getattr
+    );
+    assert_eq!(
+        Sysno::sched_getparam.id() as libc::c_long,
+        libc::SYS_sched_getparam
+    );
+    assert_eq!(
+        Sysno::sched_getscheduler.id() as libc::c_long,
+        libc::SYS_sched_getscheduler
+    );
+    assert_eq!(
+        Sysno::sched_rr_get_interval.id() as libc::c_long,
+        libc::SYS_sched_rr_get_interval
+    );
+    assert_eq!(
+        Sysno::sched_setaffinity.id() as libc::c_long,
+        libc::SYS_sched_setaffinity
+    );
+    assert_eq!(
+        Sysno::sched_setattr.id() as libc::c_long,
+        libc::SYS_sched_setattr
+    );
+    assert_eq!(
+        Sysno::sched_setparam.id() as libc::c_long,
+        libc::SYS_sched_setparam
+    );
+    assert_eq!(
+        Sysno::sched_setscheduler.id() as libc::c_long,
+        libc::SYS_sched_setscheduler
+    );
+    assert_eq!(
+        Sysno::sched_yield.id() as libc::c_long,
+        libc::SYS_sched_yield
+    );
+    assert_eq!(Sysno::seccomp.id() as libc::c_long, libc::SYS_seccomp);
+    assert_eq!(Sysno::select.id() as libc::c_long, libc::SYS_select);
+    assert_eq!(Sysno::sendfile.id() as libc::c_long, libc::SYS_sendfile);
+    assert_eq!(Sysno::sendfile64.id() as libc::c_long, libc::SYS_sendfile64);
+    assert_eq!(Sysno::sendmmsg.id() as libc::c_long, libc::SYS_sendmmsg);
+    assert_eq!(Sysno::sendmsg.id() as libc::c_long, libc::SYS_sendmsg);
+    assert_eq!(Sysno::sendto.id() as libc::c_long, libc::SYS_sendto);
+    assert_eq!(
+        Sysno::set_mempolicy.id() as libc::c_long,
+        libc::SYS_set_mempolicy
+    );
+    assert_eq!(
+        Sysno::set_mempolicy_home_node.id() as libc::c_long,
+        libc::SYS_set_mempolicy_home_node
+    );
+    assert_eq!(
+        Sysno::set_robust_list.id() as libc::c_long,
+        libc::SYS_set_robust_list
+    );
+    assert_eq!(
+        Sysno::set_thread_area.id() as libc::c_long,
+        libc::SYS_set_thread_area
+    );
+    assert_eq!(
+        Sysno::set_tid_address.id() as libc::c_long,
+        libc::SYS_set_tid_address
+    );
+    assert_eq!(
+        Sysno::setdomainname.id() as libc::c_long,
+        libc::SYS_setdomainname
+    );
+    assert_eq!(Sysno::setfsgid.id() as libc::c_long, libc::SYS_setfsgid);
+    assert_eq!(Sysno::setfsgid32.id() as libc::c_long, libc::SYS_setfsgid32);
+    assert_eq!(Sysno::setfsuid.id() as libc::c_long, libc::SYS_setfsuid);
+    assert_eq!(Sysno::setfsuid32.id() as libc::c_long, libc::SYS_setfsuid32);
+    assert_eq!(Sysno::setgid.id() as libc::c_long, libc::SYS_setgid);
+    assert_eq!(Sysno::setgid32.id() as libc::c_long, libc::SYS_setgid32);
+    assert_eq!(Sysno::setgroups.id() as libc::c_long, libc::SYS_setgroups);
+    assert_eq!(
+        Sysno::setgroups32.id() as libc::c_long,
+        libc::SYS_setgroups32
+    );
+    assert_eq!(
+        Sysno::sethostname.id() as libc::c_long,
+        libc::SYS_sethostname
+    );
+    assert_eq!(Sysno::setitimer.id() as libc::c_long, libc::SYS_setitimer);
+    assert_eq!(Sysno::setns.id() as libc::c_long, libc::SYS_setns);
+    assert_eq!(Sysno::setpgid.id() as libc::c_long, libc::SYS_setpgid);
+    assert_eq!(
+        Sysno::setpriority.id() as libc::c_long,
+        libc::SYS_setpriority
+    );
+    assert_eq!(Sysno::setregid.id() as libc::c_long, libc::SYS_setregid);
+    assert_eq!(Sysno::setregid32.id() as libc::c_long, libc::SYS_setregid32);
+    assert_eq!(Sysno::setresgid.id() as libc::c_long, libc::SYS_setresgid);
+    assert_eq!(
+        Sysno::setresgid32.id() as libc::c_long,
+        libc::SYS_setresgid32
+    );
+    assert_eq!(Sysno::setresuid.id() as libc::c_long, libc::SYS_setresuid);
+    assert_eq!(
+        Sysno::setresuid32.id() as libc::c_long,
+        libc::SYS_setresuid32
+    );
+    assert_eq!(Sysno::setreuid.id() as libc::c_long, libc::SYS_setreuid);
+    assert_eq!(Sysno::setreuid32.id() as libc::c_long, libc::SYS_setreuid32);
+    assert_eq!(Sysno::setrlimit.id() as libc::c_long, libc::SYS_setrlimit);
+    assert_eq!(Sysno::setsid.id() as libc::c_long, libc::SYS_setsid);
+    assert_eq!(Sysno::setsockopt.id() as libc::c_long, libc::SYS_setsockopt);
+    assert_eq!(
+        Sysno::settimeofday.id() as libc::c_long,
+        libc::SYS_settimeofday
+    );
+    assert_eq!(Sysno::setuid.id() as libc::c_long, libc::SYS_setuid);
+    assert_eq!(Sysno::setuid32.id() as libc::c_long, libc::SYS_setuid32);
+    assert_eq!(Sysno::setxattr.id() as libc::c_long, libc::SYS_setxattr);
+    assert_eq!(Sysno::sgetmask.id() as libc::c_long, libc::SYS_sgetmask);
+    assert_eq!(Sysno::shutdown.id() as libc::c_long, libc::SYS_shutdown);
+    assert_eq!(Sysno::sigaction.id() as libc::c_long, libc::SYS_sigaction);
+    assert_eq!(
+        Sysno::sigaltstack.id() as libc::c_long,
+        libc::SYS_sigaltstack
+    );
+    assert_eq!(Sysno::signal.id() as libc::c_long, libc::SYS_signal);
+    assert_eq!(Sysno::signalfd.id() as libc::c_long, libc::SYS_signalfd);
+    assert_eq!(Sysno::signalfd4.id() as libc::c_long, libc::SYS_signalfd4);
+    assert_eq!(Sysno::sigpending.id() as libc::c_long, libc::SYS_sigpending);
+    assert_eq!(
+        Sysno::sigprocmask.id() as libc::c_long,
+        libc::SYS_sigprocmask
+    );
+    assert_eq!(Sysno::sigreturn.id() as libc::c_long, libc::SYS_sigreturn);
+    assert_eq!(Sysno::sigsuspend.id() as libc::c_long, libc::SYS_sigsuspend);
+    assert_eq!(Sysno::socket.id() as libc::c_long, libc::SYS_socket);
+    assert_eq!(Sysno::socketcall.id() as libc::c_long, libc::SYS_socketcall);
+    assert_eq!(Sysno::socketpair.id() as libc::c_long, libc::SYS_socketpair);
+    assert_eq!(Sysno::splice.id() as libc::c_long, libc::SYS_splice);
+    assert_eq!(Sysno::ssetmask.id() as libc::c_long, libc::SYS_ssetmask);
+    assert_eq!(Sysno::stat.id() as libc::c_long, libc::SYS_stat);
+    assert_eq!(Sysno::stat64.id() as libc::c_long, libc::SYS_stat64);
+    assert_eq!(Sysno::statfs.id() as libc::c_long, libc::SYS_statfs);
+    assert_eq!(Sysno::statfs64.id() as libc::c_long, libc::SYS_statfs64);
+    assert_eq!(Sysno::statx.id() as libc::c_long, libc::SYS_statx);
+    assert_eq!(Sysno::stime.id() as libc::c_long, libc::SYS_stime);
+    assert_eq!(Sysno::stty.id() as libc::c_long, libc::SYS_stty);
+    assert_eq!(Sysno::swapoff.id() as libc::c_long, libc::SYS_swapoff);
+    assert_eq!(Sysno::swapon.id() as libc::c_long, libc::SYS_swapon);
+    assert_eq!(Sysno::symlink.id() as libc::c_long, libc::SYS_symlink);
+    assert_eq!(Sysno::symlinkat.id() as libc::c_long, libc::SYS_symlinkat);
+    assert_eq!(Sysno::sync.id() as libc::c_long, libc::SYS_sync);
+    assert_eq!(
+        Sysno::sync_file_range.id() as libc::c_long,
+        libc::SYS_sync_file_range
+    );
+    assert_eq!(Sysno::syncfs.id() as libc::c_long, libc::SYS_syncfs);
+    assert_eq!(Sysno::sysfs.id() as libc::c_long, libc::SYS_sysfs);
+    assert_eq!(Sysno::sysinfo.id() as libc::c_long, libc::SYS_sysinfo);
+    assert_eq!(Sysno::syslog.id() as libc::c_long, libc::SYS_syslog);
+    assert_eq!(Sysno::tee.id() as libc::c_long, libc::SYS_tee);
+    assert_eq!(Sysno::tgkill.id() as libc::c_long, libc::SYS_tgkill);
+    assert_eq!(Sysno::time.id() as libc::c_long, libc::SYS_time);
+    assert_eq!(
+        Sysno::timer_create.id() as libc::c_long,
+        libc::SYS_timer_create
+    );
+    assert_eq!(
+        Sysno::timer_delete.id() as libc::c_long,
+        libc::SYS_timer_delete
+    );
+    assert_eq!(
+        Sysno::timer_getoverrun.id() as libc::c_long,
+        libc::SYS_timer_getoverrun
+    );
+    assert_eq!(
+        Sysno::timer_gettime.id() as libc::c_long,
+        libc::SYS_timer_gettime
+    );
+    assert_eq!(
+        Sysno::timer_settime.id() as libc::c_long,
+        libc::SYS_timer_settime
+    );
+    assert_eq!(
+        Sysno::timerfd_create.id() as libc::c_long,
+        libc::SYS_timerfd_create
+    );
+    assert_eq!(
+        Sysno::timerfd_gettime.id() as libc::c_long,
+        libc::SYS_timerfd_gettime
+    );
+    assert_eq!(
+        Sysno::timerfd_settime.id() as libc::c_long,
+        libc::SYS_timerfd_settime
+    );
+    assert_eq!(Sysno::times.id() as libc::c_long, libc::SYS_times);
+    assert_eq!(Sysno::tkill.id() as libc::c_long, libc::SYS_tkill);
+    assert_eq!(Sysno::truncate.id() as libc::c_long, libc::SYS_truncate);
+    assert_eq!(Sysno::truncate64.id() as libc::c_long, libc::SYS_truncate64);
+    assert_eq!(Sysno::ugetrlimit.id() as libc::c_long, libc::SYS_ugetrlimit);
+    assert_eq!(Sysno::ulimit.id() as libc::c_long, libc::SYS_ulimit);
+    assert_eq!(Sysno::umask.id() as libc::c_long, libc::SYS_umask);
+    assert_eq!(Sysno::umount.id() as libc::c_long, libc::SYS_umount);
+    assert_eq!(Sysno::umount2.id() as libc::c_long, libc::SYS_umount2);
+    assert_eq!(Sysno::uname.id() as libc::c_long, libc::SYS_uname);
+    assert_eq!(Sysno::unlink.id() as libc::c_long, libc::SYS_unlink);
+    assert_eq!(Sysno::unlinkat.id() as libc::c_long, libc::SYS_unlinkat);
+    assert_eq!(Sysno::unshare.id() as libc::c_long, libc::SYS_unshare);
+    assert_eq!(Sysno::uselib.id() as libc::c_long, libc::SYS_uselib);
+    assert_eq!(
+        Sysno::userfaultfd.id() as libc::c_long,
+        libc::SYS_userfaultfd
+    );
+    assert_eq!(Sysno::ustat.id() as libc::c_long, libc::SYS_ustat);
+    assert_eq!(Sysno::utime.id() as libc::c_long, libc::SYS_utime);
+    assert_eq!(Sysno::utimensat.id() as libc::c_long, libc::SYS_utimensat);
+    assert_eq!(Sysno::utimes.id() as libc::c_long, libc::SYS_utimes);
+    assert_eq!(Sysno::vfork.id() as libc::c_long, libc::SYS_vfork);
+    assert_eq!(Sysno::vhangup.id() as libc::c_long, libc::SYS_vhangup);
+    assert_eq!(Sysno::vm86.id() as libc::c_long, libc::SYS_vm86);
+    assert_eq!(Sysno::vm86old.id() as libc::c_long, libc::SYS_vm86old);
+    assert_eq!(Sysno::vmsplice.id() as libc::c_long, libc::SYS_vmsplice);
+    assert_eq!(Sysno::vserver.id() as libc::c_long, libc::SYS_vserver);
+    assert_eq!(Sysno::wait4.id() as libc::c_long, libc::SYS_wait4);
+    assert_eq!(Sysno::waitid.id() as libc::c_long, libc::SYS_waitid);
+    assert_eq!(Sysno::waitpid.id() as libc::c_long, libc::SYS_waitpid);
+    assert_eq!(Sysno::write.id() as libc::c_long, libc::SYS_write);
+    assert_eq!(Sysno::writev.id() as libc::c_long, libc::SYS_writev);
+}
+
+#[cfg(all(target_arch = "x86_64", target_pointer_width = "64"))]
+#[test]
+fn x86_64() {
+    use rawsys_linux::x86_64::v6_12::Sysno;
+
+    assert_eq!(Sysno::_sysctl.id() as libc::c_long, libc::SYS__sysctl);
+    assert_eq!(Sysno::accept.id() as libc::c_long, libc::SYS_accept);
+    assert_eq!(Sysno::accept4.id() as libc::c_long, libc::SYS_accept4);
+    assert_eq!(Sysno::access.id() as libc::c_long, libc::SYS_access);
+    assert_eq!(Sysno::acct.id() as libc::c_long, libc::SYS_acct);
+    assert_eq!(Sysno::add_key.id() as libc::c_long, libc::SYS_add_key);
+    assert_eq!(Sysno::adjtimex.id() as libc::c_long, libc::SYS_adjtimex);
+    assert_eq!(
+        Sysno::afs_syscall.id() as libc::c_long,
+        libc::SYS_afs_syscall
+    );
+    assert_eq!(Sysno::alarm.id() as libc::c_long, libc::SYS_alarm);
+    assert_eq!(Sysno::arch_prctl.id() as libc::c_long, libc::SYS_arch_prctl);
+    assert_eq!(Sysno::bind.id() as libc::c_long, libc::SYS_bind);
+    assert_eq!(Sysno::bpf.id() as libc::c_long, libc::SYS_bpf);
+    assert_eq!(Sysno::brk.id() as libc::c_long, libc::SYS_brk);
+    assert_eq!(Sysno::capget.id() as libc::c_long, libc::SYS_capget);
+    assert_eq!(Sysno::capset.id() as libc::c_long, libc::SYS_capset);
+    assert_eq!(Sysno::chdir.id() as libc::c_long, libc::SYS_chdir);
+    assert_eq!(Sysno::chmod.id() as libc::c_long, libc::SYS_chmod);
+    assert_eq!(Sysno::chown.id() as libc::c_long, libc::SYS_chown);
+    assert_eq!(Sysno::chroot.id() as libc::c_long, libc::SYS_chroot);
+    assert_eq!(
+        Sysno::clock_adjtime.id() as libc::c_long,
+        libc::SYS_clock_adjtime
+    );
+    assert_eq!(
+        Sysno::clock_getres.id() as libc::c_long,
+        libc::SYS_clock_getres
+    );
+    assert_eq!(
+        Sysno::clock_gettime.id() as libc::c_long,
+        libc::SYS_clock_gettime
+    );
+    assert_eq!(
+        Sysno::clock_nanosleep.id() as libc::c_long,
+        libc::SYS_clock_nanosleep
+    );
+    assert_eq!(
+        Sysno::clock_settime.id() as libc::c_long,
+        libc::SYS_clock_settime
+    );
+    assert_eq!(Sysno::clone.id() as libc::c_long, libc::SYS_clone);
+    assert_eq!(Sysno::clone3.id() as libc::c_long, libc::SYS_clone3);
+    assert_eq!(Sysno::close.id() as libc::c_long, libc::SYS_close);
+    assert_eq!(
+        Sysno::close_range.id() as libc::c_long,
+        libc::SYS_close_range
+    );
+    assert_eq!(Sysno::connect.id() as libc::c_long, libc::SYS_connect);
+    assert_eq!(
+        Sysno::copy_file_range.id() as libc::c_long,
+        libc::SYS_copy_file_range
+    );
+    assert_eq!(Sysno::creat.id() as libc::c_long, libc::SYS_creat);
+    assert_eq!(
+        Sysno::delete_module.id() as libc::c_long,
+        libc::SYS_delete_module
+    );
+    assert_eq!(Sysno::dup.id() as libc::c_long, libc::SYS_dup);
+    assert_eq!(Sysno::dup2.id() as libc::c_long, libc::SYS_dup2);
+    assert_eq!(Sysno::dup3.id() as libc::c_long, libc::SYS_dup3);
+    assert_eq!(
+        Sysno::epoll_create.id() as libc::c_long,
+        libc::SYS_epoll_create
+    );
+    assert_eq!(
+        Sysno::epoll_create1.id() as libc::c_long,
+        libc::SYS_epoll_create1
+    );
+    assert_eq!(Sysno::epoll_ctl.id() as libc::c_long, libc::SYS_epoll_ctl);
+    assert_eq!(
+        Sysno::epoll_ctl_old.id() as libc::c_long,
+        libc::SYS_epoll_ctl_old
+    );
+    assert_eq!(
+        Sysno::epoll_pwait.id() as libc::c_long,
+        libc::SYS_epoll_pwait
+    );
+    assert_eq!(
+        Sysno::epoll_pwait2.id() as libc::c_long,
+        libc::SYS_epoll_pwait2
+    );
+    assert_eq!(Sysno::epoll_wait.id() as libc::c_long, libc::SYS_epoll_wait);
+    assert_eq!(
+        Sysno::epoll_wait_old.id() as libc::c_long,
+        libc::SYS_epoll_wait_old
+    );
+    assert_eq!(Sysno::eventfd.id() as libc::c_long, libc::SYS_eventfd);
+    assert_eq!(Sysno::eventfd2.id() as libc::c_long, libc::SYS_eventfd2);
+    assert_eq!(Sysno::execve.id() as libc::c_long, libc::SYS_execve);
+    assert_eq!(Sysno::execveat.id() as libc::c_long, libc::SYS_execveat);
+    assert_eq!(Sysno::exit.id() as libc::c_long, libc::SYS_exit);
+    assert_eq!(Sysno::exit_group.id() as libc::c_long, libc::SYS_exit_group);
+    assert_eq!(Sysno::faccessat.id() as libc::c_long, libc::SYS_faccessat);
+    assert_eq!(Sysno::faccessat2.id() as libc::c_long, libc::SYS_faccessat2);
+    assert_eq!(Sysno::fadvise64.id() as libc::c_long, libc::SYS_fadvise64);
+    assert_eq!(Sysno::fallocate.id() as libc::c_long, libc::SYS_fallocate);
+    assert_eq!(
+        Sysno::fanotify_init.id() as libc::c_long,
+        libc::SYS_fanotify_init
+    );
+    assert_eq!(
+        Sysno::fanotify_mark.id() as libc::c_long,
+        libc::SYS_fanotify_mark
+    );
+    assert_eq!(Sysno::fchdir.id() as libc::c_long, libc::SYS_fchdir);
+    assert_eq!(Sysno::fchmod.id() as libc::c_long, libc::SYS_fchmod);
+    assert_eq!(Sysno::fchmodat.id() as libc::c_long, libc::SYS_fchmodat);
+    assert_eq!(Sysno::fchmodat2.id() as libc::c_long, libc::SYS_fchmodat2);
+    assert_eq!(Sysno::fchown.id() as libc::c_long, libc::SYS_fchown);
+    assert_eq!(Sysno::fchownat.id() as libc::c_long, libc::SYS_fchownat);
+    assert_eq!(Sysno::fcntl.id() as libc::c_long, libc::SYS_fcntl);
+    assert_eq!(Sysno::fdatasync.id() as libc::c_long, libc::SYS_fdatasync);
+    assert_eq!(Sysno::fgetxattr.id() as libc::c_long, libc::SYS_fgetxattr);
+    assert_eq!(
+        Sysno::finit_module.id() as libc::c_long,
+        libc::SYS_finit_module
+    );
+    assert_eq!(Sysno::flistxattr.id() as libc::c_long, libc::SYS_flistxattr);
+    assert_eq!(Sysno::flock.id() as libc::c_long, libc::SYS_flock);
+    assert_eq!(Sysno::fork.id() as libc::c_long, libc::SYS_fork);
+    assert_eq!(
+        Sysno::fremovexattr.id() as libc::c_long,
+        libc::SYS_fremovexattr
+    );
+    assert_eq!(Sysno::fsconfig.id() as libc::c_long, libc::SYS_fsconfig);
+    assert_eq!(Sysno::fsetxattr.id() as libc::c_long, libc::SYS_fsetxattr);
+    assert_eq!(Sysno::fsmount.id() as libc::c_long, libc::SYS_fsmount);
+    assert_eq!(Sysno::fsopen.id() as libc::c_long, libc::SYS_fsopen);
+    assert_eq!(Sysno::fspick.id() as libc::c_long, libc::SYS_fspick);
+    assert_eq!(Sysno::fstat.id() as libc::c_long, libc::SYS_fstat);
+    assert_eq!(Sysno::fstatfs.id() as libc::c_long, libc::SYS_fstatfs);
+    assert_eq!(Sysno::fsync.id() as libc::c_long, libc::SYS_fsync);
+    assert_eq!(Sysno::ftruncate.id() as libc::c_long, libc::SYS_ftruncate);
+    assert_eq!(Sysno::futex.id() as libc::c_long, libc::SYS_futex);
+    assert_eq!(
+        Sysno::futex_waitv.id() as libc::c_long,
+        libc::SYS_futex_waitv
+    );
+    assert_eq!(Sysno::futimesat.id() as libc::c_long, libc::SYS_futimesat);
+    assert_eq!(
+        Sysno::get_mempolicy.id() as libc::c_long,
+        libc::SYS_get_mempolicy
+    );
+    assert_eq!(
+        Sysno::get_robust_list.id() as libc::c_long,
+        libc::SYS_get_robust_list
+    );
+    assert_eq!(
+        Sysno::get_thread_area.id() as libc::c_long,
+        libc::SYS_get_thread_area
+    );
+    assert_eq!(Sysno::getcpu.id() as libc::c_long, libc::SYS_getcpu);
+    assert_eq!(Sysno::getcwd.id() as libc::c_long, libc::SYS_getcwd);
+    assert_eq!(Sysno::getdents.id() as libc::c_long, libc::SYS_getdents);
+    assert_eq!(Sysno::getdents64.id() as libc::c_long, libc::SYS_getdents64);
+    assert_eq!(Sysno::getegid.id() as libc::c_long, libc::SYS_getegid);
+    assert_eq!(Sysno::geteuid.id() as libc::c_long, libc::SYS_geteuid);
+    assert_eq!(Sysno::getgid.id() as libc::c_long, libc::SYS_getgid);
+    assert_eq!(Sysno::getgroups.id() as libc::c_long, libc::SYS_getgroups);
+    assert_eq!(Sysno::getitimer.id() as libc::c_long, libc::SYS_getitimer);
+    assert_eq!(
+        Sysno::getpeername.id() as libc::c_long,
+        libc::SYS_getpeername
+    );
+    assert_eq!(Sysno::getpgid.id() as libc::c_long, libc::SYS_getpgid);
+    assert_eq!(Sysno::getpgrp.id() as libc::c_long, libc::SYS_getpgrp);
+    assert_eq!(Sysno::getpid.id() as libc::c_long, libc::SYS_getpid);
+    assert_eq!(Sysno::getpmsg.id() as libc::c_long, libc::SYS_getpmsg);
+    assert_eq!(Sysno::getppid.id() as libc::c_long, libc::SYS_getppid);
+    assert_eq!(
+        Sysno::getpriority.id() as libc::c_long,
+        libc::SYS_getpriority
+    );
+    assert_eq!(Sysno::getrandom.id() as libc::c_long, libc::SYS_getrandom);
+    assert_eq!(Sysno::getresgid.id() as libc::c_long, libc::SYS_getresgid);
+    assert_eq!(Sysno::getresuid.id() as libc::c_long, libc::SYS_getresuid);
+    assert_eq!(Sysno::getrlimit.id() as libc::c_long, libc::SYS_getrlimit);
+    assert_eq!(Sysno::getrusage.id() as libc::c_long, libc::SYS_getrusage);
+    assert_eq!(Sysno::getsid.id() as libc::c_long, libc::SYS_getsid);
+    assert_eq!(
+        Sysno::getsockname.id() as libc::c_long,
+        libc::SYS_getsockname
+    );
+    assert_eq!(Sysno::getsockopt.id() as libc::c_long, libc::SYS_getsockopt);
+    assert_eq!(Sysno::gettid.id() as libc::c_long, libc::SYS_gettid);
+    assert_eq!(
+        Sysno::gettimeofday.id() as libc::c_long,
+        libc::SYS_gettimeofday
+    );
+    assert_eq!(Sysno::getuid.id() as libc::c_long, libc::SYS_getuid);
+    assert_eq!(Sysno::getxattr.id() as libc::c_long, libc::SYS_getxattr);
+    assert_eq!(
+        Sysno::init_module.id() as libc::c_long,
+        libc::SYS_init_module
+    );
+    assert_eq!(
+        Sysno::inotify_add_watch.id() as libc::c_long,
+        libc::SYS_inotify_add_watch
+    );
+    assert_eq!(
+        Sysno::inotify_init.id() as libc::c_long,
+        libc::SYS_inotify_init
+    );
+    assert_eq!(
+        Sysno::inotify_init1.id() as libc::c_long,
+        libc::SYS_inotify_init1
+    );
+    assert_eq!(
+        Sysno::inotify_rm_watch.id() as libc::c_long,
+        libc::SYS_inotify_rm_watch
+    );
+    assert_eq!(Sysno::io_cancel.id() as libc::c_long, libc::SYS_io_cancel);
+    assert_eq!(Sysno::io_destroy.id() as libc::c_long, libc::SYS_io_destroy);
+    assert_eq!(
+        Sysno::io_getevents.id() as libc::c_long,
+        libc::SYS_io_getevents
+    );
+    assert_eq!(Sysno::io_setup.id() as libc::c_long, libc::SYS_io_setup);
+    assert_eq!(Sysno::io_submit.id() as libc::c_long, libc::SYS_io_submit);
+    assert_eq!(
+        Sysno::io_uring_enter.id() as libc::c_long,
+        libc::SYS_io_uring_enter
+    );
+    assert_eq!(
+        Sysno::io_uring_register.id() as libc::c_long,
+        libc::SYS_io_uring_register
+    );
+    assert_eq!(
+        Sysno::io_uring_setup.id() as libc::c_long,
+        libc::SYS_io_uring_setup
+    );
+    assert_eq!(Sysno::ioctl.id() as libc::c_long, libc::SYS_ioctl);
+    assert_eq!(Sysno::ioperm.id() as libc::c_long, libc::SYS_ioperm);
+    assert_eq!(Sysno::iopl.id() as libc::c_long, libc::SYS_iopl);
+    assert_eq!(Sysno::ioprio_get.id() as libc::c_long, libc::SYS_ioprio_get);
+    assert_eq!(Sysno::ioprio_set.id() as libc::c_long, libc::SYS_ioprio_set);
+    assert_eq!(Sysno::kcmp.id() as libc::c_long, libc::SYS_kcmp);
+    assert_eq!(
+        Sysno::kexec_file_load.id() as libc::c_long,
+        libc::SYS_kexec_file_load
+    );
+    assert_eq!(Sysno::kexec_load.id() as libc::c_long, libc::SYS_kexec_load);
+    assert_eq!(Sysno::keyctl.id() as libc::c_long, libc::SYS_keyctl);
+    assert_eq!(Sysno::kill.id() as libc::c_long, libc::SYS_kill);
+    assert_eq!(
+        Sysno::landlock_add_rule.id() as libc::c_long,
+        libc::SYS_landlock_add_rule
+    );
+    assert_eq!(
+        Sysno::landlock_create_ruleset.id() as libc::c_long,
+        libc::SYS_landlock_create_ruleset
+    );
+    assert_eq!(
+        Sysno::landlock_restrict_self.id() as libc::c_long,
+        libc::SYS_landlock_restrict_self
+    );
+    assert_eq!(Sysno::lchown.id() as libc::c_long, libc::SYS_lchown);
+    assert_eq!(Sysno::lgetxattr.id() as libc::c_long, libc::SYS_lgetxattr);
+    assert_eq!(Sysno::link.id() as libc::c_long, libc::SYS_link);
+    assert_eq!(Sysno::linkat.id() as libc::c_long, libc::SYS_linkat);
+    assert_eq!(Sysno::listen.id() as libc::c_long, libc::SYS_listen);
+    assert_eq!(Sysno::listxattr.id() as libc::c_long, libc::SYS_listxattr);
+    assert_eq!(Sysno::llistxattr.id() as libc::c_long, libc::SYS_llistxattr);
+    assert_eq!(
+        Sysno::lookup_dcookie.id() as libc::c_long,
+        libc::SYS_lookup_dcookie
+    );
+    assert_eq!(
+        Sysno::lremovexattr.id() as libc::c_long,
+        libc::SYS_lremovexattr
+    );
+    assert_eq!(Sysno::lseek.id() as libc::c_long, libc::SYS_lseek);
+    assert_eq!(Sysno::lsetxattr.id() as libc::c_long, libc::SYS_lsetxattr);
+    assert_eq!(Sysno::lstat.id() as libc::c_long, libc::SYS_lstat);
+    assert_eq!(Sysno::madvise.id() as libc::c_long, libc::SYS_madvise);
+    assert_eq!(Sysno::mbind.id() as libc::c_long, libc::SYS_mbind);
+    assert_eq!(Sysno::membarrier.id() as libc::c_long, libc::SYS_membarrier);
+    assert_eq!(
+        Sysno::memfd_create.id() as libc::c_long,
+        libc::SYS_memfd_create
+    );
+    assert_eq!(
+        Sysno::memfd_secret.id() as libc::c_long,
+        libc::SYS_memfd_secret
+    );
+    assert_eq!(
+        Sysno::migrate_pages.id() as libc::c_long,
+        libc::SYS_migrate_pages
+    );
+    assert_eq!(Sysno::mincore.id() as libc::c_long, libc::SYS_mincore);
+    assert_eq!(Sysno::mkdir.id() as libc::c_long, libc::SYS_mkdir);
+    assert_eq!(Sysno::mkdirat.id() as libc::c_long, libc::SYS_mkdirat);
+    assert_eq!(Sysno::mknod.id() as libc::c_long, libc::SYS_mknod);
+    assert_eq!(Sysno::mknodat.id() as libc::c_long, libc::SYS_mknodat);
+    assert_eq!(Sysno::mlock.id() as libc::c_long, libc::SYS_mlock);
+    assert_eq!(Sysno::mlock2.id() as libc::c_long, libc::SYS_mlock2);
+    assert_eq!(Sysno::mlockall.id() as libc::c_long, libc::SYS_mlockall);
+    assert_eq!(Sysno::mmap.id() as libc::c_long, libc::SYS_mmap);
+    assert_eq!(Sysno::modify_ldt.id() as libc::c_long, libc::SYS_modify_ldt);
+    assert_eq!(Sysno::mount.id() as libc::c_long, libc::SYS_mount);
+    assert_eq!(
+        Sysno::mount_setattr.id() as libc::c_long,
+        libc::SYS_mount_setattr
+    );
+    assert_eq!(Sysno::move_mount.id() as libc::c_long, libc::SYS_move_mount);
+    assert_eq!(Sysno::move_pages.id() as libc::c_long, libc::SYS_move_pages);
+    assert_eq!(Sysno::mprotect.id() as libc::c_long, libc::SYS_mprotect);
+    assert_eq!(
+        Sysno::mq_getsetattr.id() as libc::c_long,
+        libc::SYS_mq_getsetattr
+    );
+    assert_eq!(Sysno::mq_notify.id() as libc::c_long, libc::SYS_mq_notify);
+    assert_eq!(Sysno::mq_open.id() as libc::c_long, libc::SYS_mq_open);
+    assert_eq!(
+        Sysno::mq_timedreceive.id() as libc::c_long,
+        libc::SYS_mq_timedreceive
+    );
+    assert_eq!(
+        Sysno::mq_timedsend.id() as libc::c_long,
+        libc::SYS_mq_timedsend
+    );
+    assert_eq!(Sysno::mq_unlink.id() as libc::c_long, libc::SYS_mq_unlink);
+    assert_eq!(Sysno::mremap.id() as libc::c_long, libc::SYS_mremap);
+    assert_eq!(Sysno::mseal.id() as libc::c_long, libc::SYS_mseal);
+    assert_eq!(Sysno::msgctl.id() as libc::c_long, libc::SYS_msgctl);
+    assert_eq!(Sysno::msgget.id() as libc::c_long, libc::SYS_msgget);
+    assert_eq!(Sysno::msgrcv.id() as libc::c_long, libc::SYS_msgrcv);
+    assert_eq!(Sysno::msgsnd.id() as libc::c_long, libc::SYS_msgsnd);
+    assert_eq!(Sysno::msync.id() as libc::c_long, libc::SYS_msync);
+    assert_eq!(Sysno::munlock.id() as libc::c_long, libc::SYS_munlock);
+    assert_eq!(Sysno::munlockall.id() as libc::c_long, libc::SYS_munlockall);
+    assert_eq!(Sysno::munmap.id() as libc::c_long, libc::SYS_munmap);
+    assert_eq!(
+        Sysno::name_to_handle_at.id() as libc::c_long,
+        libc::SYS_name_to_handle_at
+    );
+    assert_eq!(Sysno::nanosleep.id() as libc::c_long, libc::SYS_nanosleep);
+    assert_eq!(Sysno::newfstatat.id() as libc::c_long, libc::SYS_newfstatat);
+    assert_eq!(Sysno::nfsservctl.id() as libc::c_long, libc::SYS_nfsservctl);
+    assert_eq!(Sysno::open.id() as libc::c_long, libc::SYS_open);
+    assert_eq!(
+        Sysno::open_by_handle_at.id() as libc::c_long,
+        libc::SYS_open_by_handle_at
+    );
+    assert_eq!(Sysno::open_tree.id() as libc::c_long, libc::SYS_open_tree);
+    assert_eq!(Sysno::openat.id() as libc::c_long, libc::SYS_openat);
+    assert_eq!(Sysno::openat2.id() as libc::c_long, libc::SYS_openat2);
+    assert_eq!(Sysno::pause.id() as libc::c_long, libc::SYS_pause);
+    assert_eq!(
+        Sysno::perf_event_open.id() as libc::c_long,
+        libc::SYS_perf_event_open
+    );
+    assert_eq!(
+        Sysno::personality.id() as libc::c_long,
+        libc::SYS_personality
+    );
+    assert_eq!(
+        Sysno::pidfd_getfd.id() as libc::c_long,
+        libc::SYS_pidfd_getfd
+    );
+    assert_eq!(Sysno::pidfd_open.id() as libc::c_long, libc::SYS_pidfd_open);
+    assert_eq!(
+        Sysno::pidfd_send_signal.id() as libc::c_long,
+        libc::SYS_pidfd_send_signal
+    );
+    assert_eq!(Sysno::pipe.id() as libc::c_long, libc::SYS_pipe);
+    assert_eq!(Sysno::pipe2.id() as libc::c_long, libc::SYS_pipe2);
+    assert_eq!(Sysno::pivot_root.id() as libc::c_long, libc::SYS_pivot_root);
+    assert_eq!(Sysno::pkey_alloc.id() as libc::c_long, libc::SYS_pkey_alloc);
+    assert_eq!(Sysno::pkey_free.id() as libc::c_long, libc::SYS_pkey_free);
+    assert_eq!(
+        Sysno::pkey_mprotect.id() as libc::c_long,
+        libc::SYS_pkey_mprotect
+    );
+    assert_eq!(Sysno::poll.id() as libc::c_long, libc::SYS_poll);
+    assert_eq!(Sysno::ppoll.id() as libc::c_long, libc::SYS_ppoll);
+    assert_eq!(Sysno::prctl.id() as libc::c_long, libc::SYS_prctl);
+    assert_eq!(Sysno::pread64.id() as libc::c_long, libc::SYS_pread64);
+    assert_eq!(Sysno::preadv.id() as libc::c_long, libc::SYS_preadv);
+    assert_eq!(Sysno::preadv2.id() as libc::c_long, libc::SYS_preadv2);
+    assert_eq!(Sysno::prlimit64.id() as libc::c_long, libc::SYS_prlimit64);
+    assert_eq!(
+        Sysno::process_madvise.id() as libc::c_long,
+        libc::SYS_process_madvise
+    );
+    assert_eq!(
+        Sysno::process_mrelease.id() as libc::c_long,
+        libc::SYS_process_mrelease
+    );
+    assert_eq!(
+        Sysno::process_vm_readv.id() as libc::c_long,
+        libc::SYS_process_vm_readv
+    );
+    assert_eq!(
+        Sysno::process_vm_writev.id() as libc::c_long,
+        libc::SYS_process_vm_writev
+    );
+    assert_eq!(Sysno::pselect6.id() as libc::c_long, libc::SYS_pselect6);
+    assert_eq!(Sysno::ptrace.id() as libc::c_long, libc::SYS_ptrace);
+    assert_eq!(Sysno::putpmsg.id() as libc::c_long, libc::SYS_putpmsg);
+    assert_eq!(Sysno::pwrite64.id() as libc::c_long, libc::SYS_pwrite64);
+    assert_eq!(Sysno::pwritev.id() as libc::c_long, libc::SYS_pwritev);
+    assert_eq!(Sysno::pwritev2.id() as libc::c_long, libc::SYS_pwritev2);
+    assert_eq!(Sysno::quotactl.id() as libc::c_long, libc::SYS_quotactl);
+    assert_eq!(
+        Sysno::quotactl_fd.id() as libc::c_long,
+        libc::SYS_quotactl_fd
+    );
+    assert_eq!(Sysno::read.id() as libc::c_long, libc::SYS_read);
+    assert_eq!(Sysno::readahead.id() as libc::c_long, libc::SYS_readahead);
+    assert_eq!(Sysno::readlink.id() as libc::c_long, libc::SYS_readlink);
+    assert_eq!(Sysno::readlinkat.id() as libc::c_long, libc::SYS_readlinkat);
+    assert_eq!(Sysno::readv.id() as libc::c_long, libc::SYS_readv);
+    assert_eq!(Sysno::reboot.id() as libc::c_long, libc::SYS_reboot);
+    assert_eq!(Sysno::recvfrom.id() as libc::c_long, libc::SYS_recvfrom);
+    assert_eq!(Sysno::recvmmsg.id() as libc::c_long, libc::SYS_recvmmsg);
+    assert_eq!(Sysno::recvmsg.id() as libc::c_long, libc::SYS_recvmsg);
+    assert_eq!(
+        Sysno::remap_file_pages.id() as libc::c_long,
+        libc::SYS_remap_file_pages
+    );
+    assert_eq!(
+        Sysno::removexattr.id() as libc::c_long,
+        libc::SYS_removexattr
+    );
+    assert_eq!(Sysno::rename.id() as libc::c_long, libc::SYS_rename);
+    assert_eq!(Sysno::renameat.id() as libc::c_long, libc::SYS_renameat);
+    assert_eq!(Sysno::renameat2.id() as libc::c_long, libc::SYS_renameat2);
+    assert_eq!(
+        Sysno::request_key.id() as libc::c_long,
+        libc::SYS_request_key
+    );
+    assert_eq!(
+        Sysno::restart_syscall.id() as libc::c_long,
+        libc::SYS_restart_syscall
+    );
+    assert_eq!(Sysno::rmdir.id() as libc::c_long, libc::SYS_rmdir);
+    assert_eq!(Sysno::rseq.id() as libc::c_long, libc::SYS_rseq);
+    assert_eq!(
+        Sysno::rt_sigaction.id() as libc::c_long,
+        libc::SYS_rt_sigaction
+    );
+    assert_eq!(
+        Sysno::rt_sigpending.id() as libc::c_long,
+        libc::SYS_rt_sigpending
+    );
+    assert_eq!(
+        Sysno::rt_sigprocmask.id() as libc::c_long,
+        libc::SYS_rt_sigprocmask
+    );
+    assert_eq!(
+        Sysno::rt_sigqueueinfo.id() as libc::c_long,
+        libc::SYS_rt_sigqueueinfo
+    );
+    assert_eq!(
+        Sysno::rt_sigreturn.id() as libc::c_long,
+        libc::SYS_rt_sigreturn
+    );
+    assert_eq!(
+        Sysno::rt_sigsuspend.id() as libc::c_long,
+        libc::SYS_rt_sigsuspend
+    );
+    assert_eq!(
+        Sysno::rt_sigtimedwait.id() as libc::c_long,
+        libc::SYS_rt_sigtimedwait
+    );
+    assert_eq!(
+        Sysno::rt_tgsigqueueinfo.id() as libc::c_long,
+        libc::SYS_rt_tgsigqueueinfo
+    );
+    assert_eq!(
+        Sysno::sched_get_priority_max.id() as libc::c_long,
+        libc::SYS_sched_get_priority_max
+    );
+    assert_eq!(
+        Sysno::sched_get_priority_min.id() as libc::c_long,
+        libc::SYS_sched_get_priority_min
+    );
+    assert_eq!(
+        Sysno::sched_getaffinity.id() as libc::c_long,
+        libc::SYS_sched_getaffinity
+    );
+    assert_eq!(
+        Sysno::sched_getattr.id() as libc::c_long,
+        libc::SYS_sched_getattr
+    );
+    assert_eq!(
+        Sysno::sched_getparam.id() as libc::c_long,
+        libc::SYS_sched_getparam
+    );
+    assert_eq!(
+        Sysno::sched_getscheduler.id() as libc::c_long,
+        libc::SYS_sched_getscheduler
+    );
+    assert_eq!(
+        Sysno::sched_rr_get_interval.id() as libc::c_long,
+        libc::SYS_sched_rr_get_interval
+    );
+    assert_eq!(
+        Sysno::sched_setaffinity.id() as libc::c_long,
+        libc::SYS_sched_setaffinity
+    );
+    assert_eq!(
+        Sysno::sched_setattr.id() as libc::c_long,
+        libc::SYS_sched_setattr
+    );
+    assert_eq!(
+        Sysno::sched_setparam.id() as libc::c_long,
+        libc::SYS_sched_setparam
+    );
+    assert_eq!(
+        Sysno::sched_setscheduler.id() as libc::c_long,
+        libc::SYS_sched_setscheduler
+    );
+    assert_eq!(
+        Sysno::sched_yield.id() as libc::c_long,
+        libc::SYS_sched_yield
+    );
+    assert_eq!(Sysno::seccomp.id() as libc::c_long, libc::SYS_seccomp);
+    assert_eq!(Sysno::security.id() as libc::c_long, libc::SYS_security);
+    assert_eq!(Sysno::select.id() as libc::c_long, libc::SYS_select);
+    assert_eq!(Sysno::semctl.id() as libc::c_long, libc::SYS_semctl);
+    assert_eq!(Sysno::semget.id() as libc::c_long, libc::SYS_semget);
+    assert_eq!(Sysno::semop.id() as libc::c_long, libc::SYS_semop);
+    assert_eq!(Sysno::semtimedop.id() as libc::c_long, libc::SYS_semtimedop);
+    assert_eq!(Sysno::sendfile.id() as libc::c_long, libc::SYS_sendfile);
+    assert_eq!(Sysno::sendmmsg.id() as libc::c_long, libc::SYS_sendmmsg);
+    assert_eq!(Sysno::sendmsg.id() as libc::c_long, libc::SYS_sendmsg);
+    assert_eq!(Sysno::sendto.id() as libc::c_long, libc::SYS_sendto);
+    assert_eq!(
+        Sysno::set_mempolicy.id() as libc::c_long,
+        libc::SYS_set_mempolicy
+    );
+    assert_eq!(
+        Sysno::set_mempolicy_home_node.id() as libc::c_long,
+        libc::SYS_set_mempolicy_home_node
+    );
+    assert_eq!(
+        Sysno::set_robust_list.id() as libc::c_long,
+        libc::SYS_set_robust_list
+    );
+    assert_eq!(
+        Sysno::set_thread_area.id() as libc::c_long,
+        libc::SYS_set_thread_area
+    );
+    assert_eq!(
+        Sysno::set_tid_address.id() as libc::c_long,
+        libc::SYS_set_tid_address
+    );
+    assert_eq!(
+        Sysno::setdomainname.id() as libc::c_long,
+        libc::SYS_setdomainname
+    );
+    assert_eq!(Sysno::setfsgid.id() as libc::c_long, libc::SYS_setfsgid);
+    assert_eq!(Sysno::setfsuid.id() as libc::c_long, libc::SYS_setfsuid);
+    assert_eq!(Sysno::setgid.id() as libc::c_long, libc::SYS_setgid);
+    assert_eq!(Sysno::setgroups.id() as libc::c_long, libc::SYS_setgroups);
+    assert_eq!(
+        Sysno::sethostname.id() as libc::c_long,
+        libc::SYS_sethostname
+    );
+    assert_eq!(Sysno::setitimer.id() as libc::c_long, libc::SYS_setitimer);
+    assert_eq!(Sysno::setns.id() as libc::c_long, libc::SYS_setns);
+    assert_eq!(Sysno::setpgid.id() as libc::c_long, libc::SYS_setpgid);
+    assert_eq!(
+        Sysno::setpriority.id() as libc::c_long,
+        libc::SYS_setpriority
+    );
+    assert_eq!(Sysno::setregid.id() as libc::c_long, libc::SYS_setregid);
+    assert_eq!(Sysno::setresgid.id() as libc::c_long, libc::SYS_setresgid);
+    assert_eq!(Sysno::setresuid.id() as libc::c_long, libc::SYS_setresuid);
+    assert_eq!(Sysno::setreuid.id() as libc::c_long, libc::SYS_setreuid);
+    assert_eq!(Sysno::setrlimit.id() as libc::c_long, libc::SYS_setrlimit);
+    assert_eq!(Sysno::setsid.id() as libc::c_long, libc::SYS_setsid);
+    assert_eq!(Sysno::setsockopt.id() as libc::c_long, libc::SYS_setsockopt);
+    assert_eq!(
+        Sysno::settimeofday.id() as libc::c_long,
+        libc::SYS_settimeofday
+    );
+    assert_eq!(Sysno::setuid.id() as libc::c_long, libc::SYS_setuid);
+    assert_eq!(Sysno::setxattr.id() as libc::c_long, libc::SYS_setxattr);
+    assert_eq!(Sysno::shmat.id() as libc::c_long, libc::SYS_shmat);
+    assert_eq!(Sysno::shmctl.id() as libc::c_long, libc::SYS_shmctl);
+    assert_eq!(Sysno::shmdt.id() as libc::c_long, libc::SYS_shmdt);
+    assert_eq!(Sysno::shmget.id() as libc::c_long, libc::SYS_shmget);
+    assert_eq!(Sysno::shutdown.id() as libc::c_long, libc::SYS_shutdown);
+    assert_eq!(
+        Sysno::sigaltstack.id() as libc::c_long,
+        libc::SYS_sigaltstack
+    );
+    assert_eq!(Sysno::signalfd.id() as libc::c_long, libc::SYS_signalfd);
+    assert_eq!(Sysno::signalfd4.id() as libc::c_long, libc::SYS_signalfd4);
+    assert_eq!(Sysno::socket.id() as libc::c_long, libc::SYS_socket);
+    assert_eq!(Sysno::socketpair.id() as libc::c_long, libc::SYS_socketpair);
+    assert_eq!(Sysno::splice.id() as libc::c_long, libc::SYS_splice);
+    assert_eq!(Sysno::stat.id() as libc::c_long, libc::SYS_stat);
+    assert_eq!(Sysno::statfs.id() as libc::c_long, libc::SYS_statfs);
+    assert_eq!(Sysno::statx.id() as libc::c_long, libc::SYS_statx);
+    assert_eq!(Sysno::swapoff.id() as libc::c_long, libc::SYS_swapoff);
+    assert_eq!(Sysno::swapon.id() as libc::c_long, libc::SYS_swapon);
+    assert_eq!(Sysno::symlink.id() as libc::c_long, libc::SYS_symlink);
+    assert_eq!(Sysno::symlinkat.id() as libc::c_long, libc::SYS_symlinkat);
+    assert_eq!(Sysno::sync.id() as libc::c_long, libc::SYS_sync);
+    assert_eq!(
+        Sysno::sync_file_range.id() as libc::c_long,
+        libc::SYS_sync_file_range
+    );
+    assert_eq!(Sysno::syncfs.id() as libc::c_long, libc::SYS_syncfs);
+    assert_eq!(Sysno::sysfs.id() as libc::c_long, libc::SYS_sysfs);
+    assert_eq!(Sysno::sysinfo.id() as libc::c_long, libc::SYS_sysinfo);
+    assert_eq!(Sysno::syslog.id() as libc::c_long, libc::SYS_syslog);
+    assert_eq!(Sysno::tee.id() as libc::c_long, libc::SYS_tee);
+    assert_eq!(Sysno::tgkill.id() as libc::c_long, libc::SYS_tgkill);
+    assert_eq!(Sysno::time.id() as libc::c_long, libc::SYS_time);
+    assert_eq!(
+        Sysno::timer_create.id() as libc::c_long,
+        libc::SYS_timer_create
+    );
+    assert_eq!(
+        Sysno::timer_delete.id() as libc::c_long,
+        libc::SYS_timer_delete
+    );
+    assert_eq!(
+        Sysno::timer_getoverrun.id() as libc::c_long,
+        libc::SYS_timer_getoverrun
+    );
+    assert_eq!(
+        Sysno::timer_gettime.id() as libc::c_long,
+        libc::SYS_timer_gettime
+    );
+    assert_eq!(
+        Sysno::timer_settime.id() as libc::c_long,
+        libc::SYS_timer_settime
+    );
+    assert_eq!(
+        Sysno::timerfd_create.id() as libc::c_long,
+        libc::SYS_timerfd_create
+    );
+    assert_eq!(
+        Sysno::timerfd_gettime.id() as libc::c_long,
+        libc::SYS_timerfd_gettime
+    );
+    assert_eq!(
+        Sysno::timerfd_settime.id() as libc::c_long,
+        libc::SYS_timerfd_settime
+    );
+    assert_eq!(Sysno::times.id() as libc::c_long, libc::SYS_times);
+    assert_eq!(Sysno::tkill.id() as libc::c_long, libc::SYS_tkill);
+    assert_eq!(Sysno::truncate.id() as libc::c_long, libc::SYS_truncate);
+    assert_eq!(Sysno::tuxcall.id() as libc::c_long, libc::SYS_tuxcall);
+    assert_eq!(Sysno::umask.id() as libc::c_long, libc::SYS_umask);
+    assert_eq!(Sysno::umount2.id() as libc::c_long, libc::SYS_umount2);
+    assert_eq!(Sysno::uname.id() as libc::c_long, libc::SYS_uname);
+    assert_eq!(Sysno::unlink.id() as libc::c_long, libc::SYS_unlink);
+    assert_eq!(Sysno::unlinkat.id() as libc::c_long, libc::SYS_unlinkat);
+    assert_eq!(Sysno::unshare.id() as libc::c_long, libc::SYS_unshare);
+    assert_eq!(Sysno::uselib.id() as libc::c_long, libc::SYS_uselib);
+    assert_eq!(
+        Sysno::userfaultfd.id() as libc::c_long,
+        libc::SYS_userfaultfd
+    );
+    assert_eq!(Sysno::ustat.id() as libc::c_long, libc::SYS_ustat);
+    assert_eq!(Sysno::utime.id() as libc::c_long, libc::SYS_utime);
+    assert_eq!(Sysno::utimensat.id() as libc::c_long, libc::SYS_utimensat);
+    assert_eq!(Sysno::utimes.id() as libc::c_long, libc::SYS_utimes);
+    assert_eq!(Sysno::vfork.id() as libc::c_long, libc::SYS_vfork);
+    assert_eq!(Sysno::vhangup.id() as libc::c_long, libc::SYS_vhangup);
+    assert_eq!(Sysno::vmsplice.id() as libc::c_long, libc::SYS_vmsplice);
+    assert_eq!(Sysno::vserver.id() as libc::c_long, libc::SYS_vserver);
+    assert_eq!(Sysno::wait4.id() as libc::c_long, libc::SYS_wait4);
+    assert_eq!(Sysno::waitid.id() as libc::c_long, libc::SYS_waitid);
+    assert_eq!(Sysno::write.id() as libc::c_long, libc::SYS_write);
+    assert_eq!(Sysno::writev.id() as libc::c_long, libc::SYS_writev);
+}
@@ -0,0 +1,71 @@
+#![cfg(all(target_arch = "x86_64", rawsys_backend = "inline"))]
+
+// Checks that the wrappers compile down to the bare syscall sequence, by
+// looking at the machine code of the probe functions below. Only meaningful
+// with optimizations: `cargo test --release --test test_codegen`.
+
+use rawsys_linux::{Errno, SyscallWord, Sysno, raw_syscall, syscall};
+
+#[inline(never)]
+fn checked_getppid() -> Result<SyscallWord, Errno> {
+    unsafe { syscall!(Sysno::getppid) }
+}
+
+#[inline(never)]
+fn raw_getppid() -> SyscallWord {
+    unsafe { raw_syscall!(Sysno::getppid) }
+}
+
+/// Returns the first bytes of the machine code of `f`.
+fn code(f: *const ()) -> &'static [u8] {
+    unsafe { core::slice::from_raw_parts(f.cast::<u8>(), 32) }
+}
+
+/// `mov eax, getppid; syscall`
+fn syscall_sequence() -> Vec<u8> {
+    let mut seq = vec![0xb8];
+    seq.extend_from_slice(&(Sysno::getppid as u32).to_le_bytes());
+    seq.extend_from_slice(&[0x0f, 0x05]);
+    seq
+}
+
+#[test]
+fn probes_agree() {
+    assert_eq!(checked_getppid().unwrap(), raw_getppid());
+}
+
+#[test]
+#[cfg_attr(debug_assertions, ignore = "needs optimizations (--release)")]
+fn raw_is_bare_syscall() {
+    let code = code(raw_getppid as *const ());
+    let seq = syscall_sequence();
+
+    assert_eq!(&code[..seq.len()], seq.as_slice());
+    // ret
+    assert_eq!(code[seq.len()], 0xc3);
+}
+
+// The instrumentation of `tracing` and the counters of `profile` add calls
+// to the checked wrappers.
+#[cfg(not(any(feature = "tracing", feature = "profile")))]
+#[test]
+#[cfg_attr(debug_assertions, ignore = "needs optimizations (--release)")]
+fn checked_adds_only_compare_and_branch() {
+    let code = code(checked_getppid as *const ());
+    let seq = syscall_sequence();
+    assert_eq!(&code[..seq.len()], seq.as_slice());
+
+    // cmp rax, imm32 with the errno range bound (-4096 or -4095) ...
+    let cmp = &code[seq.len()..];
+    assert_eq!(&cmp[..2], &[0x48, 0x3d]);
+    let bound = i32::from_le_bytes(cmp[2..6].try_into().unwrap());
+    assert!(bound == -4096 || bound == -4095, "unexpected bound {bound}");
+
+    // ... immediately followed by a conditional branch (short or near).
+    let jcc = &cmp[6..];
+    assert!(
+        (0x70..=0x7f).contains(&jcc[0])
+            || (jcc[0] == 0x0f && (0x80..=0x8f).contains(&jcc[1])),
+        "expected a conditional branch, found {jcc:02x?}"
+    );
+}
@@ -0,0 +1,47 @@
+#![cfg(all(target_arch = "x86_64", rawsys_backend = "inline"))]
+
+use rawsys_linux::raw::compat;
+
+// i386 syscall numbers.
+const NR_GETPID: u32 = 20;
+const NR_CLOSE: u32 = 6;
+const NR_MUNMAP: u32 = 91;
+const NR_MMAP2: u32 = 192;
+
+#[test]
+fn compat_getpid() {
+    let pid = unsafe { compat::syscall0(NR_GETPID) };
+    assert_eq!(pid, std::process::id());
+}
+
+#[test]
+fn compat_close_bad_fd() {
+    let ret = unsafe { compat::syscall1(NR_CLOSE, u32::MAX) };
+    assert_eq!(
+        rawsys_linux::Errno::from_ret_u32(ret),
+        Err(rawsys_linux::Errno::EBADF)
+    );
+}
+
+#[test]
+fn compat_mmap2_six_args() {
+    const PROT_READ_WRITE: u32 = 0x3;
+    const MAP_PRIVATE_ANONYMOUS: u32 = 0x22;
+
+    let addr = unsafe {
+        compat::syscall6(
+            NR_MMAP2,
+            0,
+            4096,
+            PROT_READ_WRITE,
+            MAP_PRIVATE_ANONYMOUS,
+            u32::MAX, // fd = -1
+            0,
+        )
+    };
+    let addr = rawsys_linux::Errno::from_ret_u32(addr).unwrap();
+    assert_eq!(addr % 4096, 0);
+
+    let ret = unsafe { compat::syscall2(NR_MUNMAP, addr, 4096) };
+    assert_eq!(ret, 0);
+}
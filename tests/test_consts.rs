@@ -0,0 +1,46 @@
+#![cfg(all(feature = "consts", not(rawsys_backend = "none")))]
+
+use rawsys_linux::consts::*;
+use rawsys_linux::{Errno, Sysno, syscall};
+
+#[test]
+fn match_libc() {
+    assert_eq!(O_RDONLY as i32, libc::O_RDONLY);
+    assert_eq!(O_CREAT as i32, libc::O_CREAT);
+    assert_eq!(O_DIRECTORY as i32, libc::O_DIRECTORY);
+    assert_eq!(O_DIRECT as i32, libc::O_DIRECT);
+    assert_eq!(O_CLOEXEC as i32, libc::O_CLOEXEC);
+    assert_eq!(O_TMPFILE as i32, libc::O_TMPFILE);
+    assert_eq!(AT_FDCWD, libc::AT_FDCWD);
+    assert_eq!(AT_REMOVEDIR, libc::AT_REMOVEDIR);
+    assert_eq!(CLONE_NEWUSER as i32, libc::CLONE_NEWUSER);
+    assert_eq!(PROT_READ as i32, libc::PROT_READ);
+    assert_eq!(MAP_ANONYMOUS as i32, libc::MAP_ANONYMOUS);
+    assert_eq!(MAP_STACK as i32, libc::MAP_STACK);
+    assert_eq!(MS_BIND as libc::c_ulong, libc::MS_BIND);
+    assert_eq!(MS_SYNC as i32, libc::MS_SYNC);
+}
+
+#[test]
+fn openat() {
+    let fd = unsafe {
+        syscall!(
+            Sysno::openat,
+            AT_FDCWD,
+            c"/".as_ptr(),
+            O_RDONLY | O_DIRECTORY | O_CLOEXEC
+        )
+    }
+    .unwrap();
+    unsafe { syscall!(Sysno::close, fd) }.unwrap();
+
+    let err = unsafe {
+        syscall!(
+            Sysno::openat,
+            AT_FDCWD,
+            c"/proc/self/exe".as_ptr(),
+            O_DIRECTORY
+        )
+    };
+    assert_eq!(err, Err(Errno::ENOTDIR));
+}
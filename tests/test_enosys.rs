@@ -1,3 +1,5 @@
+#![cfg(not(rawsys_backend = "none"))]
+
 use rawsys_linux;
 
 // Intentionally invoke an invalid syscall number and ensure ENOSYS is returned.
@@ -0,0 +1,19 @@
+#![cfg(feature = "ia64")]
+
+use rawsys_linux::ia64;
+
+#[test]
+fn ia64_numbers_are_offset() {
+    // ia64 numbers start at __NR_Linux (1024).
+    assert_eq!(ia64::v6_6::Sysno::first().id(), 1024);
+    assert_eq!(ia64::v6_6::Sysno::read.id(), 1026);
+    assert_eq!(ia64::v6_6::Sysno::clone2.id(), 1213);
+    assert_eq!(ia64::v6_6::Sysno::fchmodat2.id(), 1476);
+}
+
+#[test]
+fn ia64_versions_grow() {
+    assert_eq!(ia64::v5_4::Sysno::last(), ia64::v5_4::Sysno::pidfd_open);
+    assert_eq!(ia64::v6_6::Sysno::last(), ia64::v6_6::Sysno::fchmodat2);
+    assert!(ia64::v5_4::Sysno::count() < ia64::v6_6::Sysno::count());
+}
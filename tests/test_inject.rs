@@ -0,0 +1,50 @@
+#![cfg(all(feature = "std", not(rawsys_backend = "none")))]
+
+use std::fs::File;
+use std::io::{ErrorKind, Write};
+
+use rawsys_linux::seccomp::ErrnoInjection;
+use rawsys_linux::{Errno, Sysno, SysnoSet};
+
+fn raw_error<T>(result: std::io::Result<T>) -> Option<Errno> {
+    result.err()?.raw_os_error().map(Errno::new)
+}
+
+/// Fails `write` and `openat` of the test's thread, through `std`. The
+/// filter stays for the rest of the process, so this is the only test.
+#[test]
+fn inject_errno() {
+    let mut file = File::create("/dev/null").unwrap();
+    let set = SysnoSet::new(&[Sysno::write, Sysno::openat]);
+    let injection =
+        unsafe { ErrnoInjection::current_thread(&set, Errno::ENOSPC) }.unwrap();
+    let err = file.write_all(b"data").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::StorageFull);
+    assert_eq!(raw_error(File::open("/dev/null")), Some(Errno::ENOSPC));
+
+    // The last injection for a syscall wins, until it is dropped.
+    let set = SysnoSet::new(&[Sysno::openat]);
+    let nested =
+        unsafe { ErrnoInjection::current_thread(&set, Errno::EMFILE) }.unwrap();
+    assert_eq!(raw_error(File::open("/dev/null")), Some(Errno::EMFILE));
+    drop(nested);
+    assert_eq!(raw_error(File::open("/dev/null")), Some(Errno::ENOSPC));
+
+    // Other threads aren't affected.
+    let mut other = file.try_clone().unwrap();
+    std::thread::spawn(move || other.write_all(b"data"))
+        .join()
+        .unwrap()
+        .unwrap();
+
+    // The filter only covers the syscalls of the first injection.
+    let set = SysnoSet::new(&[Sysno::read]);
+    assert_eq!(
+        unsafe { ErrnoInjection::all_threads(&set, Errno::EIO) }.unwrap_err(),
+        Errno::EBUSY
+    );
+
+    drop(injection);
+    file.write_all(b"data").unwrap();
+    File::open("/dev/null").unwrap();
+}
@@ -0,0 +1,20 @@
+#![cfg(rawsys_backend = "libc")]
+
+use rawsys_linux::{Errno, SyscallWord, Sysno, raw};
+
+#[test]
+fn raw_returns_negative_errno() {
+    // close(-1) fails with EBADF; the raw functions must still report it in
+    // the kernel's "negative errno" form rather than libc's -1.
+    let ret = unsafe { raw::syscall1(Sysno::close as SyscallWord, -1i32 as _) };
+    assert_eq!(ret, (Errno::EBADF.into_raw() as SyscallWord).wrapping_neg());
+}
+
+#[test]
+fn checked_returns_errno() {
+    let ret = unsafe { rawsys_linux::syscall1(Sysno::close, -1i32 as _) };
+    assert_eq!(ret, Err(Errno::EBADF));
+
+    let pid = unsafe { rawsys_linux::syscall0(Sysno::getpid) };
+    assert_eq!(pid, Ok(std::process::id() as SyscallWord));
+}
@@ -0,0 +1,33 @@
+#![cfg(all(feature = "profile", not(rawsys_backend = "none")))]
+
+use rawsys_linux::{Sysno, profile, syscall};
+
+/// The counts are global, so this is the only test.
+#[test]
+fn profile() {
+    profile::reset();
+    let _ = unsafe { syscall!(Sysno::getpid) };
+    assert_eq!(profile::snapshot().get(Sysno::getpid), None);
+
+    profile::enable();
+    assert!(profile::is_enabled());
+    for _ in 0..10 {
+        unsafe { syscall!(Sysno::getpid) }.unwrap();
+    }
+    let _ = unsafe { syscall!(Sysno::close, -1i32) };
+    profile::disable();
+    let _ = unsafe { syscall!(Sysno::getpid) };
+
+    let snapshot = profile::snapshot();
+    let getpid = snapshot.get(Sysno::getpid).unwrap();
+    assert_eq!(getpid.count, 10);
+    assert!(getpid.max <= getpid.total);
+    assert!(getpid.mean() <= getpid.max);
+    assert_eq!(snapshot.get(Sysno::close).unwrap().count, 1);
+    assert_eq!(snapshot.total().count, 11);
+    let report = snapshot.to_string();
+    assert!(report.lines().any(|line| line.ends_with(" getpid")));
+
+    profile::reset();
+    assert_eq!(profile::snapshot().total().count, 0);
+}
@@ -0,0 +1,65 @@
+#![cfg(not(rawsys_backend = "none"))]
+
+use rawsys_linux::id::Tid;
+use rawsys_linux::ptrace::{self, PtraceRequest};
+use rawsys_linux::{Errno, Sysno, raw};
+
+/// A word that the child's copy of the memory is read and written at.
+static WORD: usize = 0x1234_5678;
+
+/// The request numbers match libc's.
+#[test]
+#[allow(clippy::unnecessary_cast)] // libc's types differ by target.
+fn requests() {
+    for (request, libc) in [
+        (PtraceRequest::TraceMe, libc::PTRACE_TRACEME),
+        (PtraceRequest::PeekData, libc::PTRACE_PEEKDATA),
+        (PtraceRequest::Cont, libc::PTRACE_CONT),
+        (PtraceRequest::Syscall, libc::PTRACE_SYSCALL),
+        (PtraceRequest::SetOptions, libc::PTRACE_SETOPTIONS),
+        (PtraceRequest::GetRegSet, libc::PTRACE_GETREGSET),
+        (PtraceRequest::Seize, libc::PTRACE_SEIZE),
+        (PtraceRequest::Listen, libc::PTRACE_LISTEN),
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        (PtraceRequest::GetRegs, libc::PTRACE_GETREGS),
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        (PtraceRequest::Sysemu, libc::PTRACE_SYSEMU),
+    ] {
+        assert_eq!(request as u32, libc as u32, "{request:?}");
+    }
+    assert_eq!(ptrace::PTRACE_O_EXITKILL, libc::PTRACE_O_EXITKILL as u32);
+    assert_eq!(ptrace::PTRACE_EVENT_STOP, libc::PTRACE_EVENT_STOP as u32);
+}
+
+/// Reads and writes the memory of a stopped child.
+#[test]
+fn peek_poke() {
+    unsafe {
+        let pid = libc::fork();
+        assert!(pid >= 0);
+        if pid == 0 {
+            let _ = ptrace::traceme();
+            libc::raise(libc::SIGSTOP);
+            raw::syscall1(Sysno::exit_group as _, 0);
+            unreachable!();
+        }
+
+        let mut status = 0;
+        assert_eq!(libc::waitpid(pid, &mut status, 0), pid);
+        assert!(libc::WIFSTOPPED(status));
+
+        let tid = Tid::from_raw(pid);
+        let addr = &raw const WORD as usize;
+        assert_eq!(ptrace::peek_data(tid, addr), Ok(WORD));
+        // The static is read-only, which ptrace ignores.
+        ptrace::poke_data(tid, addr, 42).unwrap();
+        assert_eq!(ptrace::peek_data(tid, addr), Ok(42));
+        assert_eq!(WORD, 0x1234_5678);
+        assert_eq!(ptrace::peek_data(tid, 0), Err(Errno::EIO));
+
+        ptrace::cont(tid, 0).unwrap();
+        assert_eq!(libc::waitpid(pid, &mut status, 0), pid);
+        assert!(libc::WIFEXITED(status));
+        assert_eq!(ptrace::cont(tid, 0), Err(Errno::ESRCH));
+    }
+}
@@ -0,0 +1,178 @@
+#![cfg(not(rawsys_backend = "none"))]
+
+use rawsys_linux::audit::Arch;
+use rawsys_linux::id::Tid;
+use rawsys_linux::ptrace::{self, SyscallStop};
+use rawsys_linux::regs::{self, NGREG, Regs};
+use rawsys_linux::{Errno, Sysno, raw};
+
+/// Larger than any `pid_max`, so `getpgid` fails with `ESRCH`.
+const NO_PID: i32 = 0x7fff_fff0;
+
+/// Waits for the next stop of `pid` and returns its registers.
+unsafe fn stop(pid: i32) -> Regs {
+    unsafe {
+        let mut status = 0;
+        assert_eq!(libc::waitpid(pid, &mut status, 0), pid);
+        assert!(libc::WIFSTOPPED(status), "status {status:#x}");
+
+        let mut regs: Regs = [0; NGREG];
+        let mut iov = libc::iovec {
+            iov_base: regs.as_mut_ptr().cast(),
+            iov_len: size_of::<Regs>(),
+        };
+        assert_eq!(
+            libc::ptrace(
+                libc::PTRACE_GETREGSET,
+                pid,
+                libc::NT_PRSTATUS,
+                &mut iov,
+            ),
+            0
+        );
+        regs
+    }
+}
+
+/// Traces a child to a `getpgid` call and decodes it at the entry and exit
+/// stops, from the registers and with `PTRACE_GET_SYSCALL_INFO`.
+#[test]
+fn decode_tracee_syscall() {
+    unsafe {
+        let pid = libc::fork();
+        assert!(pid >= 0);
+        if pid == 0 {
+            libc::ptrace(libc::PTRACE_TRACEME, 0, 0, 0);
+            libc::raise(libc::SIGSTOP);
+            raw::syscall1(Sysno::getpgid as _, NO_PID as _);
+            raw::syscall1(Sysno::exit_group as _, 0);
+            unreachable!();
+        }
+
+        stop(pid);
+        // PTRACE_GET_SYSCALL_INFO needs syscall stops marked as such.
+        libc::ptrace(
+            libc::PTRACE_SETOPTIONS,
+            pid,
+            0,
+            libc::PTRACE_O_TRACESYSGOOD,
+        );
+        let syscall = || {
+            libc::ptrace(libc::PTRACE_SYSCALL, pid, 0, 0);
+            stop(pid)
+        };
+        // Syscall stops come in entry/exit pairs.
+        let entry = loop {
+            let regs = syscall();
+            if regs::sysno(&regs) == Some(Sysno::getpgid) {
+                break regs;
+            }
+            syscall();
+        };
+        let (_, args) = regs::syscall(&entry).unwrap();
+        assert_eq!(args.arg0, NO_PID as _);
+        let info = ptrace::syscall_info(Tid::from_raw(pid)).unwrap();
+        assert_eq!(info.arch, Some(Arch::TARGET));
+        assert!(matches!(
+            info.stop,
+            SyscallStop::Entry { sysno: Some(Sysno::getpgid), args: a, .. }
+                if a == args
+        ));
+
+        let exit = syscall();
+        assert_eq!(regs::return_value(&exit), Err(Errno::ESRCH));
+        assert_eq!(
+            ptrace::syscall_info(Tid::from_raw(pid)).unwrap().stop,
+            SyscallStop::Exit {
+                ret: Err(Errno::ESRCH)
+            }
+        );
+
+        libc::kill(pid, libc::SIGKILL);
+        libc::waitpid(pid, core::ptr::null_mut(), 0);
+    }
+}
+
+/// Rewrites the argument of one `getpgid` call and replaces another with a
+/// made-up result. Skipping needs another request on `aarch64` and `arm`.
+#[cfg(not(any(target_arch = "aarch64", target_arch = "arm")))]
+#[test]
+fn rewrite_tracee_syscall() {
+    unsafe {
+        let pid = libc::fork();
+        assert!(pid >= 0);
+        if pid == 0 {
+            libc::ptrace(libc::PTRACE_TRACEME, 0, 0, 0);
+            libc::raise(libc::SIGSTOP);
+            let own = raw::syscall1(Sysno::getpgid as _, 0);
+            let status = match (
+                rawsys_linux::syscall1(Sysno::getpgid, NO_PID as _),
+                rawsys_linux::syscall1(Sysno::getpgid, NO_PID as _),
+            ) {
+                (Ok(pgid), Err(Errno::EPERM)) if pgid == own => 0,
+                _ => 1,
+            };
+            raw::syscall1(Sysno::exit_group as _, status);
+            unreachable!();
+        }
+
+        stop(pid);
+        let syscall = || {
+            libc::ptrace(libc::PTRACE_SYSCALL, pid, 0, 0);
+            stop(pid)
+        };
+        let setregs = |regs: &Regs| {
+            let mut iov = libc::iovec {
+                iov_base: regs.as_ptr().cast_mut().cast(),
+                iov_len: size_of::<Regs>(),
+            };
+            assert_eq!(
+                libc::ptrace(
+                    libc::PTRACE_SETREGSET,
+                    pid,
+                    libc::NT_PRSTATUS,
+                    &mut iov,
+                ),
+                0
+            );
+        };
+        let getpgid = || loop {
+            let regs = syscall();
+            if regs::sysno(&regs) == Some(Sysno::getpgid)
+                && regs::syscall_args(&regs).arg0 == NO_PID as _
+            {
+                break regs;
+            }
+            syscall();
+        };
+
+        // getpgid(NO_PID) becomes getpgid(0).
+        let mut regs = getpgid();
+        let original = regs::syscall_args(&regs);
+        let mut args = original;
+        args.arg0 = 0;
+        regs::set_syscall_args(&mut regs, &args);
+        setregs(&regs);
+        // The tracee may still use the argument registers after the call,
+        // so they get the original arguments back at the exit stop.
+        let mut regs = syscall();
+        let ret = regs::return_value(&regs);
+        regs::set_syscall_args(&mut regs, &original);
+        regs::set_return_value(&mut regs, ret);
+        setregs(&regs);
+
+        // getpgid(NO_PID) is skipped and fails with EPERM.
+        let mut regs = getpgid();
+        regs::skip_syscall(&mut regs);
+        setregs(&regs);
+        let mut regs = syscall();
+        regs::set_return_value(&mut regs, Err(Errno::EPERM));
+        setregs(&regs);
+
+        libc::ptrace(libc::PTRACE_CONT, pid, 0, 0);
+        let mut status = 0;
+        assert_eq!(libc::waitpid(pid, &mut status, 0), pid);
+        assert!(libc::WIFEXITED(status));
+        assert_eq!(libc::WEXITSTATUS(status), 0);
+    }
+}
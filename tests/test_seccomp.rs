@@ -0,0 +1,146 @@
+#![cfg(not(rawsys_backend = "none"))]
+
+use rawsys_linux::seccomp::{
+    self, Action, Program, SECCOMP_ADDFD_FLAG_SEND,
+    SECCOMP_FILTER_FLAG_NEW_LISTENER, SECCOMP_FILTER_FLAG_TSYNC,
+    SeccompNotifAddfd, SeccompNotifResp, Supervisor,
+};
+use rawsys_linux::{Errno, SyscallWord, Sysno, SysnoMap, SysnoSet, raw};
+
+/// Runs `child` in a forked child process, where a filter can't affect the
+/// test harness, and checks that it exits with 0.
+fn in_child(child: impl FnOnce() -> SyscallWord) {
+    unsafe {
+        let pid = libc::fork();
+        assert!(pid >= 0);
+        if pid == 0 {
+            // Only raw syscalls from here on.
+            let status = child();
+            raw::syscall1(Sysno::exit_group as _, status);
+            unreachable!();
+        }
+
+        let mut status = 0;
+        assert_eq!(libc::waitpid(pid, &mut status, 0), pid);
+        assert!(libc::WIFEXITED(status));
+        assert_eq!(libc::WEXITSTATUS(status), 0);
+    }
+}
+
+/// Installs a filter that fails `getppid` and lets the rest through.
+#[test]
+fn filter_getppid() {
+    let mut overrides = SysnoMap::new();
+    overrides.insert(Sysno::getppid, Action::Errno(Errno::EPERM));
+    let program =
+        Program::compile(&SysnoSet::empty(), Action::Allow, &overrides);
+
+    in_child(|| match unsafe { program.install() } {
+        Ok(()) => match unsafe { rawsys_linux::syscall0(Sysno::getppid) } {
+            Err(Errno::EPERM) => 0,
+            _ => 1,
+        },
+        Err(_) => 2,
+    });
+}
+
+/// Installs a filter for all threads with a notification listener, and
+/// checks that the listener fd comes back.
+#[test]
+fn install_with_flags() {
+    let mut overrides = SysnoMap::new();
+    overrides.insert(Sysno::getppid, Action::Errno(Errno::EPERM));
+    let program =
+        Program::compile(&SysnoSet::empty(), Action::Allow, &overrides);
+
+    in_child(|| {
+        if seccomp::no_new_privs().is_err() {
+            return 1;
+        }
+        let flags =
+            SECCOMP_FILTER_FLAG_TSYNC | SECCOMP_FILTER_FLAG_NEW_LISTENER;
+        match unsafe { seccomp::install(&program, flags) } {
+            // TSYNC together with NEW_LISTENER needs TSYNC_ESRCH.
+            Err(Errno::EINVAL) => {}
+            _ => return 2,
+        }
+        let flags = flags | seccomp::SECCOMP_FILTER_FLAG_TSYNC_ESRCH;
+        match unsafe { seccomp::install(&program, flags) } {
+            Ok(Some(fd)) if fd > 0 => {}
+            _ => return 3,
+        }
+        match unsafe { rawsys_linux::syscall0(Sysno::getppid) } {
+            Err(Errno::EPERM) => 0,
+            _ => 4,
+        }
+    });
+}
+
+/// Sends `getppid` and `dup` of a child to a supervisor, which answers them
+/// with a value and with an fd of its own.
+#[test]
+fn supervise_child() {
+    let mut overrides = SysnoMap::new();
+    overrides.insert(Sysno::getppid, Action::UserNotif);
+    overrides.insert(Sysno::dup, Action::UserNotif);
+    let program =
+        Program::compile(&SysnoSet::empty(), Action::Allow, &overrides);
+
+    in_child(|| unsafe {
+        if seccomp::no_new_privs().is_err() {
+            return 1;
+        }
+        let Ok(supervisor) = Supervisor::install(&program, 0) else {
+            return 2;
+        };
+        let pid = libc::fork();
+        if pid == 0 {
+            let status = match (
+                rawsys_linux::syscall0(Sysno::getppid),
+                rawsys_linux::syscall1(Sysno::dup, 0),
+            ) {
+                (Ok(4242), Ok(fd)) if fd > 2 => 0,
+                _ => 1,
+            };
+            raw::syscall1(Sysno::exit_group as _, status);
+        }
+
+        let Ok(notif) = supervisor.recv() else {
+            return 3;
+        };
+        match notif.call() {
+            Some((Sysno::getppid, _, child)) if child == pid as u32 => {}
+            _ => return 4,
+        }
+        if supervisor.id_valid(notif.id).is_err()
+            || supervisor
+                .send(&SeccompNotifResp::value(notif.id, 4242))
+                .is_err()
+        {
+            return 5;
+        }
+
+        let Ok(notif) = supervisor.recv() else {
+            return 6;
+        };
+        if notif.sysno() != Some(Sysno::dup) || notif.args().arg0 != 0 {
+            return 7;
+        }
+        let addfd = SeccompNotifAddfd {
+            flags: SECCOMP_ADDFD_FLAG_SEND,
+            ..SeccompNotifAddfd::new(notif.id, 1)
+        };
+        if supervisor.add_fd(&addfd).is_err() {
+            return 8;
+        }
+
+        let mut status = 0;
+        if libc::waitpid(pid, &mut status, 0) != pid
+            || !libc::WIFEXITED(status)
+            || libc::WEXITSTATUS(status) != 0
+        {
+            return 9;
+        }
+        0
+    });
+}
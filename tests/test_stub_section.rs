@@ -0,0 +1,15 @@
+#![cfg(rawsys_backend = "stub")]
+
+use rawsys_linux::{Sysno, raw};
+
+#[test]
+fn stub_region_is_not_empty() {
+    let region = raw::stub_region();
+    assert!(region.start < region.end);
+}
+
+#[test]
+fn syscalls_go_through_the_stub() {
+    let pid = unsafe { rawsys_linux::syscall0(Sysno::getpid) };
+    assert_eq!(pid, Ok(std::process::id().into()));
+}
@@ -0,0 +1,51 @@
+#![cfg(all(target_arch = "x86_64", not(rawsys_backend = "none")))]
+
+use core::ffi::c_void;
+use core::sync::atomic::{AtomicI32, Ordering};
+
+use rawsys_linux::audit::AUDIT_ARCH;
+use rawsys_linux::sud::{self, Intercepted, Selector};
+use rawsys_linux::{SyscallWord, Sysno};
+
+static SELECTOR: Selector = Selector::new();
+static SEEN: AtomicI32 = AtomicI32::new(-1);
+
+const MAGIC: SyscallWord = 0x5ca1ab1e;
+
+extern "C" fn handle_sigsys(
+    _: i32,
+    info: *mut libc::siginfo_t,
+    ctx: *mut c_void,
+) {
+    // Let the handler's own syscalls (and rt_sigreturn) through.
+    SELECTOR.allow();
+
+    let call = unsafe { Intercepted::decode(info.cast(), ctx) }.unwrap();
+    assert_eq!(call.arch, AUDIT_ARCH);
+    assert_eq!(call.sysno, Some(Sysno::getppid));
+    assert_eq!(call.args.arg0, 1);
+    SEEN.store(call.nr, Ordering::SeqCst);
+    unsafe { sud::set_return(ctx, MAGIC) };
+}
+
+#[test]
+fn dispatch_getppid() {
+    unsafe {
+        let mut sa: libc::sigaction = core::mem::zeroed();
+        sa.sa_sigaction = handle_sigsys as *const () as usize;
+        sa.sa_flags = libc::SA_SIGINFO;
+        assert_eq!(
+            libc::sigaction(libc::SIGSYS, &sa, core::ptr::null_mut()),
+            0
+        );
+
+        sud::enable(0..0, &SELECTOR).unwrap();
+        SELECTOR.block();
+        let ret = rawsys_linux::raw::syscall1(Sysno::getppid as SyscallWord, 1);
+        assert!(!SELECTOR.is_blocked());
+        sud::disable().unwrap();
+
+        assert_eq!(ret, MAGIC);
+        assert_eq!(SEEN.load(Ordering::SeqCst), Sysno::getppid.id());
+    }
+}
@@ -1,3 +1,5 @@
+#![cfg(not(rawsys_backend = "none"))]
+
 use rawsys_linux::*;
 
 #[test]
@@ -31,3 +31,87 @@ fn sysno_new_roundtrip_all_iter() {
         assert_eq!(Sysno::new(id), Some(s));
     }
 }
+
+#[test]
+fn sysno_arg_names_fit_registers() {
+    // No syscall takes more than six register arguments.
+    for s in Sysno::iter() {
+        if let Some(names) = s.arg_names() {
+            assert!(names.len() <= 6, "{s}: {names:?}");
+        }
+    }
+}
+
+#[test]
+fn sysno_arg_names() {
+    // Every table is generated with argument metadata.
+    assert_eq!(Sysno::write.arg_names(), Some(&["fd", "buf", "count"][..]));
+    assert_eq!(
+        Sysno::openat.arg_names(),
+        Some(&["dfd", "filename", "flags", "mode"][..])
+    );
+}
+
+#[cfg(target_arch = "x86_64")]
+#[test]
+fn sysno_arg_names_x86_64() {
+    assert_eq!(Sysno::write.arg_names(), Some(&["fd", "buf", "count"][..]));
+    assert_eq!(
+        Sysno::openat.arg_names(),
+        Some(&["dfd", "filename", "flags", "mode"][..])
+    );
+    // `stat` is defined as `newstat` in the kernel.
+    assert_eq!(Sysno::stat.arg_names(), Some(&["filename", "statbuf"][..]));
+    assert_eq!(Sysno::getpid.arg_names(), Some(&[][..]));
+    // Not implemented in the kernel.
+    assert_eq!(Sysno::uselib.arg_names(), None);
+}
+
+#[cfg(target_arch = "x86_64")]
+#[test]
+fn sysno_arg_formats_x86_64() {
+    use rawsys_linux::format::ArgFormat::{
+        Buf, Fd, Flags, Hex, Mode, Str, Uint,
+    };
+
+    assert_eq!(Sysno::write.arg_formats(), Some(&[Fd, Buf, Uint][..]));
+    let Some(&[Hex, Uint, Flags(prot), Flags(map), Fd, Uint]) =
+        Sysno::mmap.arg_formats()
+    else {
+        panic!("{:?}", Sysno::mmap.arg_formats());
+    };
+    assert!(prot.contains(&("PROT_READ", 0x1)));
+    assert!(map.contains(&("MAP_PRIVATE", 0x2)));
+    assert!(matches!(
+        Sysno::openat.arg_formats(),
+        Some(&[Fd, Str, Flags(_), Mode])
+    ));
+    assert_eq!(Sysno::uselib.arg_formats(), None);
+}
+
+#[cfg(target_arch = "x86_64")]
+#[test]
+fn sysno_arg_names_x86_64_v5_4() {
+    use rawsys_linux::x86_64::v5_4::Sysno;
+
+    // `_sysctl` was removed in v5.5.
+    assert_eq!(Sysno::_sysctl.arg_names(), Some(&["args"][..]));
+    assert_eq!(Sysno::write.arg_names(), Some(&["fd", "buf", "count"][..]));
+}
+
+#[cfg(any(target_arch = "aarch64", feature = "aarch64"))]
+#[test]
+fn sysno_arg_names_aarch64() {
+    use rawsys_linux::aarch64::v6_12::Sysno;
+
+    // The entry points come from `__SYSCALL` in unistd.h.
+    assert_eq!(Sysno::umount2.arg_names(), Some(&["name", "flags"][..]));
+    assert_eq!(
+        Sysno::fstatat.arg_names(),
+        Some(&["dfd", "filename", "statbuf", "flag"][..])
+    );
+    assert_eq!(
+        Sysno::clock_gettime64.arg_names(),
+        Some(&["which_clock", "tp"][..])
+    );
+}
@@ -0,0 +1,101 @@
+#![cfg(all(feature = "std", not(rawsys_backend = "none")))]
+
+use std::process::Command;
+use std::sync::Mutex;
+
+use rawsys_linux::id::Pid;
+use rawsys_linux::tracer::{SyscallEvent, Tracer};
+use rawsys_linux::{Errno, Sysno};
+
+/// A tracer waits for any child, so the tests can't trace concurrently.
+static TRACING: Mutex<()> = Mutex::new(());
+
+fn trace(tracer: Tracer) -> Vec<SyscallEvent> {
+    tracer.map(Result::unwrap).collect()
+}
+
+/// Traces a shell that runs a command that fails to open a file.
+#[test]
+fn spawn() {
+    let _tracing = TRACING.lock().unwrap();
+    let tracer = Tracer::spawn(
+        Command::new("sh").args(["-c", "cat /nonexistent; exit 3"]),
+    )
+    .unwrap();
+    let events = trace(tracer);
+
+    // The shell and its child exit without returning from `exit_group`.
+    let exits: Vec<_> = events
+        .iter()
+        .filter(|event| event.sysno == Sysno::exit_group)
+        .collect();
+    assert_eq!(exits.len(), 2, "{events:#?}");
+    assert!(exits.iter().all(|event| event.result.is_none()));
+    assert_ne!(exits[0].pid, exits[1].pid);
+    assert_eq!(exits[0].args.arg0, 1);
+    assert_eq!(exits[1].args.arg0, 3);
+
+    assert!(events.iter().any(|event| {
+        event.result == Some(Err(Errno::ENOENT))
+            && matches!(event.sysno, Sysno::open | Sysno::openat)
+    }));
+    for event in &events {
+        // Neither runs threads.
+        assert_eq!(event.pid.as_raw(), event.tid.as_raw());
+        assert_eq!(event.result.is_some(), event.duration.is_some());
+    }
+}
+
+/// Attaches to a running process.
+#[test]
+fn attach() {
+    let _tracing = TRACING.lock().unwrap();
+    // The tracer reaps it.
+    let pid = Command::new("sleep").arg("0.2").spawn().unwrap().id();
+    let pid = Pid::from_raw(pid.try_into().unwrap());
+    let tracer = Tracer::attach(pid).unwrap();
+    let events = trace(tracer);
+
+    let last = events.last().unwrap();
+    assert_eq!(last.sysno, Sysno::exit_group);
+    assert_eq!(last.args.arg0, 0);
+}
+
+/// Dropping the tracer kills a spawned process and reaps it.
+#[test]
+fn drop_spawned() {
+    let _tracing = TRACING.lock().unwrap();
+    let mut tracer = Tracer::spawn(Command::new("sleep").arg("10")).unwrap();
+    let pid = tracer.next().unwrap().unwrap().pid;
+    drop(tracer);
+
+    let alive = unsafe { libc::kill(pid.as_raw(), 0) };
+    assert_eq!(alive, -1);
+}
+
+/// Dropping the tracer detaches from an attached process, which keeps
+/// running.
+#[test]
+fn drop_attached() {
+    let _tracing = TRACING.lock().unwrap();
+    let mut child = Command::new("yes")
+        .stdout(std::process::Stdio::null())
+        .spawn()
+        .unwrap();
+    let pid = Pid::from_raw(child.id().try_into().unwrap());
+    let mut tracer = Tracer::attach(pid).unwrap();
+    // Once it writes, it runs its loop.
+    tracer
+        .by_ref()
+        .map(Result::unwrap)
+        .find(|event| event.sysno == Sysno::write)
+        .unwrap();
+    drop(tracer);
+
+    let status = std::fs::read_to_string(format!("/proc/{pid}/status"));
+    child.kill().unwrap();
+    child.wait().unwrap();
+    let status = status.unwrap();
+    assert!(status.contains("TracerPid:\t0\n"), "{status}");
+    assert!(!status.contains("(tracing stop)"), "{status}");
+}
@@ -0,0 +1,71 @@
+#![cfg(all(feature = "tracing", not(rawsys_backend = "none")))]
+
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use rawsys_linux::{Errno, Sysno, syscall};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Level, Metadata, Subscriber};
+
+/// Records the fields of the events as `name=value` lines.
+#[derive(Default)]
+struct Recorder(Arc<Mutex<Vec<String>>>);
+
+struct Line(String);
+
+impl Visit for Line {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if !self.0.is_empty() {
+            self.0.push(' ');
+        }
+        self.0 += &format!("{}={value:?}", field.name());
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.target() == "rawsys_linux::syscall"
+            && *metadata.level() == Level::TRACE
+    }
+
+    fn new_span(&self, _: &Attributes<'_>) -> Id {
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut line = Line(String::new());
+        event.record(&mut line);
+        self.0.lock().unwrap().push(line.0);
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+#[test]
+fn events() {
+    let recorder = Recorder::default();
+    let lines = Arc::clone(&recorder.0);
+    tracing::subscriber::with_default(recorder, || {
+        let pid = unsafe { syscall!(Sysno::getpid) }.unwrap();
+        assert_eq!(unsafe { syscall!(Sysno::close, -1i32) }, Err(Errno::EBADF));
+
+        let lines = lines.lock().unwrap();
+        assert_eq!(
+            *lines,
+            [
+                format!(r#"sysno="getpid" args=[] ret={pid}"#),
+                format!(
+                    r#"sysno="close" args=[{:#x}] errno="EBADF" code=9"#,
+                    -1i32 as rawsys_linux::SyscallWord
+                ),
+            ]
+        );
+    });
+}